@@ -2,7 +2,8 @@
 //
 // Applies a list of edit ops to a project by driving the real timeline action
 // modules inside headless Chrome (via window.freecut.editProject), then writes
-// the edited project back out. No rendering, no media needed.
+// the edited project back out. No rendering; media is only served to the page
+// for ops that capture frames (freezeFrame).
 //
 // Usage:
 //   node headless/edit.mjs --workspace <dir> --project <id|project.json> --ops <ops.json> [--out <path> | --in-place]
@@ -28,10 +29,26 @@
 //   trimStart    { id, amount }
 //   trimEnd      { id, amount }
 //   addTransition{ leftClipId, rightClipId, type?, durationInFrames? }
+//   rippleDelete { ids: [<id>...] }
+//   rippleTrim   { id, handle: "start"|"end", amount }
+//   roll         { leftClipId, rightClipId, delta }
+//   slip         { id, delta }
+//   slide        { id, delta, leftNeighborId?, rightNeighborId? }
+//   rateStretch  { id, speed? | durationInFrames?, from? }
+//   resetSpeed   { ids: [<id>...] }
+//   removeRanges { ids: [<id>...], ranges: { <mediaId>: [{ start, end }] } }  (source seconds)
+//   join         { ids: [<id>...] }
+//   freezeFrame  { id, frame }  (captures the frame; the still is saved under media/ on write)
+//   setLinkedSelection { enabled }
 import { chromium } from 'playwright'
 import fs from 'node:fs'
 import path from 'node:path'
-import { loadProject, collectAddClipMedia } from './lib/workspace.mjs'
+import {
+  loadProject,
+  collectOpMedia,
+  opsNeedMediaSources,
+  writeGeneratedMedia,
+} from './lib/workspace.mjs'
 import { parseArgs } from './lib/cli.mjs'
 import { startHarness } from './lib/render-core.mjs'
 
@@ -55,18 +72,20 @@ async function main() {
   console.log(`Project: ${project.name ?? project.id} (${projectJsonPath})`)
   console.log(`Ops: ${ops.length}`)
 
-  // Collect metadata for media referenced by addClip ops (for duration/fps/codec).
-  const media = collectAddClipMedia(args.workspace, ops)
+  // Media is served only when an op decodes frames (freezeFrame); otherwise omit workspace.
+  const { harnessUrl, mediaUrlOf, closeServers } = await startHarness({
+    workspace: opsNeedMediaSources(ops) ? args.workspace : undefined,
+    devUrl: args['harness-url'],
+    build: args.build,
+  })
+  // Metadata (+ source URLs for frame capture) for media the ops reference.
+  const media = collectOpMedia(args.workspace, ops, project, mediaUrlOf)
   const missingMeta = media.filter((m) => !m.metadata).map((m) => m.mediaId)
   if (missingMeta.length > 0) {
-    throw new Error(`addClip media not found in workspace: ${missingMeta.join(', ')}`)
+    await closeServers()
+    throw new Error(`Op media not found in workspace: ${missingMeta.join(', ')}`)
   }
 
-  // Edit needs no media serving (no rendering) — omit workspace.
-  const { harnessUrl, closeServers } = await startHarness({
-    devUrl: args['harness-url'],
-    build: args.build,
-  })
   const browser = await chromium.launch({ channel: 'chrome', headless: !args.head })
   let result
   try {
@@ -107,6 +126,10 @@ async function main() {
     return
   }
 
+  for (const file of writeGeneratedMedia(args.workspace, edited.id, result.generatedMedia)) {
+    console.log(`Wrote media: ${file}`)
+  }
+
   const toWrite = { ...edited, updatedAt: Date.now() }
   fs.writeFileSync(outPath, JSON.stringify(toWrite, null, 2))
   console.log(`\nWrote: ${outPath}`)
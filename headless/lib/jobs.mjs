@@ -0,0 +1,162 @@
+// In-memory render job registry for the render service (serve.mjs): job
+// state and its moves (queued -> running -> completed | failed | canceled),
+// progress/ETA bookkeeping, and change subscriptions for SSE streams. Jobs
+// live for the life of the service; their outputs live in the workspace.
+import { EventEmitter } from 'node:events'
+import crypto from 'node:crypto'
+
+const TERMINAL = new Set(['completed', 'failed', 'canceled'])
+
+export const isTerminal = (status) => TERMINAL.has(status)
+
+/**
+ * Estimate seconds remaining from the elapsed render time and the pipeline's
+ * 0-100 progress. Null until there's enough progress to extrapolate from.
+ */
+function estimateEta(startedAt, percent, now) {
+  if (!startedAt || !(percent > 1) || percent >= 100) return null
+  const elapsed = (now - startedAt) / 1000
+  return Math.max(0, Math.round((elapsed * (100 - percent)) / percent))
+}
+
+export function createJobStore() {
+  const jobs = new Map()
+  const events = new EventEmitter()
+  events.setMaxListeners(0)
+
+  const snapshot = (job) => ({ ...job, progress: { ...job.progress } })
+
+  const update = (id, patch) => {
+    const job = jobs.get(id)
+    if (!job) return null
+    Object.assign(job, patch)
+    events.emit(id, snapshot(job))
+    return job
+  }
+
+  return {
+    create(fields) {
+      const job = {
+        id: crypto.randomUUID(),
+        status: 'queued',
+        createdAt: Date.now(),
+        startedAt: null,
+        finishedAt: null,
+        progress: { phase: 'queued', percent: 0, currentFrame: null, totalFrames: null },
+        etaSeconds: null,
+        output: null,
+        warnings: [],
+        error: null,
+        ...fields,
+      }
+      jobs.set(job.id, job)
+      return job
+    },
+
+    get: (id) => jobs.get(id) ?? null,
+
+    /** All jobs, newest first. */
+    list: () => [...jobs.values()].sort((a, b) => b.createdAt - a.createdAt).map(snapshot),
+
+    snapshot,
+
+    update,
+
+    /** queued -> running. False when the job left the queue another way (canceled). */
+    start(id) {
+      const job = jobs.get(id)
+      if (!job || job.status !== 'queued') return false
+      update(id, {
+        status: 'running',
+        startedAt: Date.now(),
+        progress: { phase: 'preparing', percent: 0, currentFrame: null, totalFrames: null },
+      })
+      return true
+    },
+
+    /**
+     * Cancel a job. A queued job is canceled on the spot; a running one is
+     * flagged and ends `canceled` once its render settles (see `fail`). False
+     * when the job has already finished.
+     */
+    cancel(id) {
+      const job = jobs.get(id)
+      if (!job || isTerminal(job.status)) return false
+      if (job.status === 'queued') update(id, { status: 'canceled', finishedAt: Date.now() })
+      else job.cancelRequested = true
+      return true
+    },
+
+    /** running -> completed, with the job's `output` (and any other fields). */
+    complete(id, fields) {
+      const job = jobs.get(id)
+      if (!job) return
+      update(id, {
+        status: 'completed',
+        finishedAt: Date.now(),
+        progress: { ...job.progress, phase: 'finalizing', percent: 100 },
+        etaSeconds: 0,
+        ...fields,
+      })
+    },
+
+    /**
+     * running -> failed, or -> canceled when the client asked to cancel or the
+     * render aborted. Returns the status the job ended in.
+     */
+    fail(id, error, fields) {
+      const job = jobs.get(id)
+      if (!job) return null
+      const canceled = Boolean(job.cancelRequested) || error?.name === 'AbortError'
+      update(id, {
+        status: canceled ? 'canceled' : 'failed',
+        finishedAt: Date.now(),
+        etaSeconds: null,
+        error: canceled ? null : (error?.message ?? String(error)),
+        ...fields,
+      })
+      return job.status
+    },
+
+    /** Record a RenderProgress callback from the harness. */
+    progress(id, progress) {
+      const job = jobs.get(id)
+      if (!job || job.status !== 'running') return
+      const percent = Math.max(0, Math.min(100, progress.progress ?? 0))
+      update(id, {
+        progress: {
+          phase: progress.phase,
+          percent,
+          currentFrame: progress.currentFrame ?? null,
+          totalFrames: progress.totalFrames ?? null,
+          ...(progress.message ? { message: progress.message } : {}),
+        },
+        etaSeconds: estimateEta(job.startedAt, percent, Date.now()),
+      })
+    },
+
+    /**
+     * Stream a job's snapshots to an SSE response: the current one, then every
+     * change until the job finishes or the client disconnects.
+     */
+    stream(id, req, res) {
+      const job = jobs.get(id)
+      if (!job) return
+      res.writeHead(200, {
+        'Content-Type': 'text/event-stream',
+        'Cache-Control': 'no-cache',
+        Connection: 'keep-alive',
+      })
+      const send = (next) => {
+        res.write(`event: job\ndata: ${JSON.stringify(next)}\n\n`)
+        if (isTerminal(next.status)) {
+          events.off(id, send)
+          res.end()
+        }
+      }
+      events.on(id, send)
+      req.on('close', () => events.off(id, send))
+      send(snapshot(job))
+    },
+  }
+}
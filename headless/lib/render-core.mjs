@@ -14,13 +14,72 @@ const REPO_ROOT = path.resolve(path.dirname(fileURLToPath(import.meta.url)), '..
 const CODEC_MAP = { h264: 'avc', avc: 'avc', h265: 'hevc', hevc: 'hevc', vp9: 'vp9', vp8: 'vp8', av1: 'av1' }
 const DEFAULT_CONTAINER = { avc: 'mp4', hevc: 'mp4', vp9: 'webm', vp8: 'webm', av1: 'webm' }
 const VIDEO_BITRATE_BY_QUALITY = { low: 2_500_000, medium: 5_000_000, high: 10_000_000, ultra: 20_000_000 }
+// Mirrors LOUDNESS_TARGET_PRESETS in src/features/export/utils/client-renderer.ts.
+const LOUDNESS_TARGET_PRESETS = {
+  streaming: { integratedLufs: -14, truePeakDbtp: -1 },
+  podcast: { integratedLufs: -16, truePeakDbtp: -1 },
+  'ebu-r128': { integratedLufs: -23, truePeakDbtp: -1 },
+  'atsc-a85': { integratedLufs: -24, truePeakDbtp: -2 },
+}
+
+// Mirrors CANVAS_VARIANT_ASPECTS in src/features/projects/utils/canvas-variants.ts.
+const CANVAS_VARIANT_RATIOS = { '16:9': 16 / 9, '9:16': 9 / 16, '4:5': 4 / 5, '1:1': 1 }
+
+/** Find a project's canvas variant by id, name (case-insensitive) or aspect ("9:16"). */
+function findCanvasVariant(project, ref) {
+  const variants = project.canvasVariants ?? []
+  const needle = String(ref).toLowerCase()
+  const variant =
+    variants.find((v) => v.id === ref) ??
+    variants.find((v) => v.name?.toLowerCase() === needle) ??
+    variants.find((v) => v.aspect === ref)
+  if (!variant) {
+    const known = variants.map((v) => `${v.name} (${v.aspect})`).join(', ') || 'none'
+    throw new Error(`Unknown canvas variant "${ref}" (project variants: ${known})`)
+  }
+  return variant
+}
+
+/** Canvas size of a variant: the base short side at the variant's aspect (even dimensions). */
+function variantCanvasSize(meta, variant) {
+  const width = meta.width ?? 1920
+  const height = meta.height ?? 1080
+  if (!variant) return { width, height }
+  const ratio = CANVAS_VARIANT_RATIOS[variant.aspect] ?? width / height
+  const shortSide = Math.min(width, height)
+  const even = (v) => Math.max(2, Math.round(v / 2) * 2)
+  return ratio >= 1
+    ? { width: even(shortSide * ratio), height: even(shortSide) }
+    : { width: even(shortSide), height: even(shortSide / ratio) }
+}
+
+/**
+ * Expand a job with `variant: "all"` into one job per canvas: the base canvas
+ * plus each of the project's variants. Other jobs are returned unchanged.
+ */
+export function expandCanvasVariantJobs(workspace, jobArgs) {
+  if (jobArgs.variant !== 'all') return [jobArgs]
+  const { project } = jobArgs.projectObject
+    ? { project: jobArgs.projectObject }
+    : loadProject(workspace, jobArgs.project)
+  const variants = project.canvasVariants ?? []
+  // Explicit output paths would collide, so they gain the variant as a suffix.
+  const outFor = (suffix) => {
+    if (!jobArgs.out || !suffix) return jobArgs.out
+    const ext = path.extname(jobArgs.out)
+    return `${jobArgs.out.slice(0, jobArgs.out.length - ext.length)}_${suffix}${ext}`
+  }
+  return [
+    { ...jobArgs, variant: undefined },
+    ...variants.map((v) => ({ ...jobArgs, variant: v.id, out: outFor(v.aspect.replace(':', 'x')) })),
+  ]
+}
 
 /** Build ClientExportSettings from a job's options (same keys as the CLI flags). */
-function buildSettings(project, opts) {
+function buildSettings(project, opts, variant) {
   const meta = project.metadata ?? {}
   const fps = opts.fps ? Number(opts.fps) : (meta.fps ?? 30)
-  let width = meta.width ?? 1920
-  let height = meta.height ?? 1080
+  let { width, height } = variantCanvasSize(meta, variant)
   if (opts.resolution) {
     const m = /^(\d+)x(\d+)$/.exec(opts.resolution)
     if (!m) throw new Error(`Invalid resolution "${opts.resolution}" (expected WxH, e.g. 1920x1080)`)
@@ -28,6 +87,51 @@ function buildSettings(project, opts) {
     height = Number(m[2])
   }
   const quality = opts.quality ?? 'high'
+  const alpha = Boolean(opts.alpha)
+  const loudnessTarget = opts.loudness ? LOUDNESS_TARGET_PRESETS[opts.loudness] : undefined
+  if (opts.loudness && !loudnessTarget) {
+    throw new Error(`Invalid --loudness "${opts.loudness}" (use ${Object.keys(LOUDNESS_TARGET_PRESETS).join('|')})`)
+  }
+
+  if (opts['image-sequence'] || opts.imageSequence) {
+    return {
+      mode: 'image-sequence',
+      codec: 'avc',
+      container: 'mp4',
+      quality,
+      resolution: { width, height },
+      fps,
+      alpha,
+    }
+  }
+
+  if (opts.container === 'gif' || opts.container === 'webp') {
+    const animFps = Number(opts['anim-fps'] ?? opts.animFps ?? 15)
+    const loopCount = Number(opts.loop ?? 0)
+    const palette = opts.palette ?? 'global'
+    const dither = opts.dither ?? 'floyd-steinberg'
+    if (!(animFps >= 1 && animFps <= 50)) throw new Error(`Invalid --anim-fps "${animFps}" (use 1-50)`)
+    if (!Number.isInteger(loopCount) || loopCount < 0) {
+      throw new Error(`Invalid --loop "${opts.loop}" (0 = forever, or a play count)`)
+    }
+    if (palette !== 'global' && palette !== 'per-frame') {
+      throw new Error(`Invalid --palette "${palette}" (use global|per-frame)`)
+    }
+    if (!['floyd-steinberg', 'ordered', 'none'].includes(dither)) {
+      throw new Error(`Invalid --dither "${dither}" (use floyd-steinberg|ordered|none)`)
+    }
+    return {
+      mode: 'animated-image',
+      codec: 'avc',
+      container: opts.container,
+      quality,
+      resolution: { width, height },
+      fps,
+      animatedImage: { fps: animFps, loopCount, palette, dither },
+      // GIF transparency is 1-bit at best; only WebP keeps alpha.
+      ...(alpha && opts.container === 'webp' ? { alpha } : {}),
+    }
+  }
 
   if (opts['audio-only'] || opts.audioOnly) {
     const container = opts.container ?? 'mp3'
@@ -40,13 +144,18 @@ function buildSettings(project, opts) {
       resolution: { width, height },
       fps,
       audioBitrate: 192_000,
+      ...(loudnessTarget ? { loudnessTarget } : {}),
     }
   }
 
-  const codecInput = (opts.codec ?? 'h264').toLowerCase()
+  // Alpha video is VP8/VP9-in-Matroska only, so --alpha defaults to VP9 WebM.
+  const codecInput = (opts.codec ?? (alpha ? 'vp9' : 'h264')).toLowerCase()
   const codec = CODEC_MAP[codecInput]
   if (!codec) throw new Error(`Unknown codec "${opts.codec}" (use h264|h265|vp9|vp8|av1)`)
   const container = opts.container ?? DEFAULT_CONTAINER[codec]
+  if (alpha && !((codec === 'vp9' || codec === 'vp8') && (container === 'webm' || container === 'mkv'))) {
+    throw new Error('--alpha video needs --codec vp9|vp8 with --container webm|mkv (or use --image-sequence)')
+  }
   return {
     mode: 'video',
     codec,
@@ -57,6 +166,8 @@ function buildSettings(project, opts) {
     fps,
     videoBitrate: VIDEO_BITRATE_BY_QUALITY[quality] ?? 10_000_000,
     audioBitrate: 192_000,
+    ...(alpha ? { alpha } : {}),
+    ...(loudnessTarget ? { loudnessTarget } : {}),
   }
 }
 
@@ -121,7 +232,8 @@ export function prepareJob(workspace, jobArgs, mediaUrlOf) {
   const { project, projectJsonPath } = jobArgs.projectObject
     ? { project: jobArgs.projectObject, projectJsonPath: '(inline)' }
     : loadProject(workspace, jobArgs.project)
-  const settings = buildSettings(project, jobArgs)
+  const variant = jobArgs.variant ? findCanvasVariant(project, jobArgs.variant) : undefined
+  const settings = buildSettings(project, jobArgs, variant)
   const { hasRange, inPoint, outPoint } = computeRange(jobArgs, settings.fps)
 
   const mediaIds = collectMediaIds(
@@ -135,13 +247,17 @@ export function prepareJob(workspace, jobArgs, mediaUrlOf) {
     metadata: readMediaMetadata(workspace, id) ?? undefined,
   }))
 
-  const outName = `${(project.name ?? 'freecut-export').replace(/[^\w.-]+/g, '_')}.${settings.container}`
+  // Image sequences are written as a folder of numbered PNGs.
+  const projectName = project.name ?? 'freecut-export'
+  const baseName = (variant ? `${projectName} - ${variant.name}` : projectName).replace(/[^\w.-]+/g, '_')
+  const outName = settings.mode === 'image-sequence' ? baseName : `${baseName}.${settings.container}`
   const outPath = path.resolve(jobArgs.out ?? path.join('headless', 'output', outName))
 
   return {
     project,
     projectJsonPath,
     settings,
+    canvasVariant: variant,
     hasRange,
     inPoint,
     outPoint,
@@ -153,7 +269,28 @@ export function prepareJob(workspace, jobArgs, mediaUrlOf) {
   }
 }
 
-/** Render one prepared job through an already-loaded harness page; saves to job.outPath. */
+// The folder the page's current image-sequence render writes into, per page.
+const sequenceFolders = new WeakMap()
+
+/**
+ * Expose `__freecutSequenceFrame` on the page (once): the harness hands it each
+ * PNG as soon as it is encoded and it is written straight into the job's
+ * folder, so no more than one frame is in flight.
+ */
+async function ensureSequenceFrameWriter(page) {
+  if (sequenceFolders.has(page)) return
+  sequenceFolders.set(page, null)
+  await page.exposeBinding('__freecutSequenceFrame', async (_source, name, base64) => {
+    const dir = sequenceFolders.get(page)
+    if (!dir) throw new Error('No image-sequence render is writing frames')
+    await fs.promises.writeFile(path.join(dir, path.basename(name)), Buffer.from(base64, 'base64'))
+  })
+}
+
+/**
+ * Render one prepared job through an already-loaded harness page; saves to
+ * job.outPath (a folder of PNG frames for image sequences).
+ */
 export async function renderJob(page, job, { setProgressLabel, onWarn } = {}) {
   fs.mkdirSync(path.dirname(job.outPath), { recursive: true })
   const warn = onWarn ?? ((m) => console.warn(m))
@@ -169,18 +306,35 @@ export async function renderJob(page, job, { setProgressLabel, onWarn } = {}) {
   }
 
   setProgressLabel?.(path.basename(job.outPath))
-  const downloadPromise = page.waitForEvent('download', { timeout: 30 * 60_000 })
-  downloadPromise.catch(() => {})
-  const summary = await page.evaluate((payload) => window.freecut.renderProject(payload), {
+  const payload = {
     project: job.project,
     settings: job.settings,
     media: job.media,
     renderWholeProject: !job.hasRange,
     inPoint: job.inPoint,
     outPoint: job.outPoint,
-  })
-  const download = await downloadPromise
-  await download.saveAs(job.outPath)
+    canvasVariantId: job.canvasVariant?.id,
+    jobId: job.id,
+  }
+
+  let summary
+  if (job.settings.mode === 'image-sequence') {
+    // Frames arrive one at a time through the writer; there is no download.
+    await ensureSequenceFrameWriter(page)
+    fs.mkdirSync(job.outPath, { recursive: true })
+    sequenceFolders.set(page, job.outPath)
+    try {
+      summary = await page.evaluate((input) => window.freecut.renderProject(input), payload)
+    } finally {
+      sequenceFolders.set(page, null)
+    }
+  } else {
+    const downloadPromise = page.waitForEvent('download', { timeout: 30 * 60_000 })
+    downloadPromise.catch(() => {})
+    summary = await page.evaluate((input) => window.freecut.renderProject(input), payload)
+    const download = await downloadPromise
+    await download.saveAs(job.outPath)
+  }
   for (const w of summary.warnings ?? []) warn(`  WARNING: ${w}`)
   return summary
 }
@@ -16,6 +16,9 @@ const NON_SOURCE_NAMES = new Set([
 
 const MEDIA_ITEM_TYPES = new Set(['video', 'audio', 'image'])
 
+// Characters workspace-fs's sanitizeWorkspaceFileName replaces (Windows-reserved + control).
+const INVALID_FILENAME_CHARS = /[<>:"/\\|?*\x00-\x1f]/g
+
 /** Load + parse a project. Accepts a project id (under the workspace) or a direct project.json path. */
 export function loadProject(workspaceDir, projectIdOrFile) {
   let projectJsonPath
@@ -108,10 +111,73 @@ export function readMediaMetadata(workspaceDir, mediaId) {
   }
 }
 
-/** Collect `{ mediaId, metadata }` for media referenced by addClip ops (deduped). */
-export function collectAddClipMedia(workspaceDir, ops) {
-  const ids = [...new Set(ops.filter((o) => o.op === 'addClip' && o.mediaId).map((o) => o.mediaId))]
-  return ids.map((mediaId) => ({ mediaId, metadata: readMediaMetadata(workspaceDir, mediaId) ?? undefined }))
+/** Ops that decode frames from a clip's source (its media must be served to the page). */
+const FRAME_CAPTURE_OPS = new Set(['freezeFrame'])
+
+/** Whether any op decodes frames, so the driver has to serve workspace media. */
+export function opsNeedMediaSources(ops) {
+  return ops.some((o) => FRAME_CAPTURE_OPS.has(o.op))
+}
+
+/**
+ * Collect `{ mediaId, url, metadata }` for media the ops touch (deduped):
+ * addClip's `mediaId`, plus — when an op captures frames — every clip in the
+ * project that has metadata, since the clip may only exist after an earlier
+ * op (e.g. a split). `url` is set when a `mediaUrlOf` resolver is given.
+ */
+export function collectOpMedia(workspaceDir, ops, project, mediaUrlOf) {
+  const entry = (mediaId) => ({
+    mediaId,
+    url: mediaUrlOf?.(mediaId),
+    metadata: readMediaMetadata(workspaceDir, mediaId) ?? undefined,
+  })
+  const clipIds = new Set(ops.filter((o) => o.op === 'addClip' && o.mediaId).map((o) => o.mediaId))
+  const media = [...clipIds].map(entry)
+  if (opsNeedMediaSources(ops)) {
+    for (const mediaId of collectMediaIds(project)) {
+      if (clipIds.has(mediaId)) continue
+      const source = entry(mediaId)
+      if (source.metadata) media.push(source)
+    }
+  }
+  return media
+}
+
+/**
+ * Write media an edit generated (e.g. freeze-frame stills) into the workspace
+ * as `media/{id}/{fileName}` + `metadata.json`, and link it to the project in
+ * `projects/{projectId}/media-links.json` like workspace-fs's
+ * associateMediaWithProject. Returns the written source paths.
+ */
+export function writeGeneratedMedia(workspaceDir, projectId, generatedMedia) {
+  const written = []
+  for (const { metadata, base64 } of generatedMedia ?? []) {
+    const mediaDir = path.join(workspaceDir, 'media', metadata.id)
+    fs.mkdirSync(mediaDir, { recursive: true })
+    const fileName = metadata.fileName.trim().replace(INVALID_FILENAME_CHARS, '_') || 'source.bin'
+    const filePath = path.join(mediaDir, fileName)
+    fs.writeFileSync(filePath, Buffer.from(base64, 'base64'))
+    fs.writeFileSync(path.join(mediaDir, 'metadata.json'), JSON.stringify(metadata, null, 2))
+    written.push(filePath)
+  }
+  if (projectId && written.length > 0) {
+    const linksPath = path.join(workspaceDir, 'projects', projectId, 'media-links.json')
+    let links = { version: '1.0', mediaIds: [] }
+    try {
+      const existing = JSON.parse(fs.readFileSync(linksPath, 'utf8'))
+      if (Array.isArray(existing.mediaIds)) links = existing
+    } catch {
+      // no links yet
+    }
+    for (const { metadata } of generatedMedia) {
+      if (!links.mediaIds.some((entry) => entry.id === metadata.id)) {
+        links.mediaIds.push({ id: metadata.id, addedAt: Date.now() })
+      }
+    }
+    fs.mkdirSync(path.dirname(linksPath), { recursive: true })
+    fs.writeFileSync(linksPath, JSON.stringify(links, null, 2))
+  }
+  return written
 }
 
 /** Resolve a media id to its source file path under media/{id}/ (first non-reserved file). */
@@ -140,3 +206,67 @@ export function resolveMediaFiles(workspaceDir, mediaIds) {
   }
   return { files, missing }
 }
+
+/**
+ * A project's exports folder (`projects/{id}/exports/`), mirroring
+ * workspace-fs/exports.ts. Falls back to the top-level `exports/` when there's
+ * no project id (inline project objects).
+ */
+export function exportsDir(workspaceDir, projectId) {
+  return projectId
+    ? path.join(workspaceDir, 'projects', projectId, 'exports')
+    : path.join(workspaceDir, 'exports')
+}
+
+/**
+ * A free path for a new export: `clip.mp4`, else `clip (2).mp4`, `clip (3).mp4`, …
+ * (same de-duplication as saveExportFile, so re-renders never overwrite).
+ */
+export function uniqueExportPath(workspaceDir, projectId, fileName) {
+  const dir = exportsDir(workspaceDir, projectId)
+  const safe = fileName.trim().replace(INVALID_FILENAME_CHARS, '_') || 'export.bin'
+  const ext = path.extname(safe)
+  const stem = safe.slice(0, safe.length - ext.length)
+  for (let n = 1; n < 1000; n++) {
+    const candidate = path.join(dir, n === 1 ? safe : `${stem} (${n})${ext}`)
+    if (!fs.existsSync(candidate)) return candidate
+  }
+  return path.join(dir, `${stem} (${Date.now()})${ext}`)
+}
+
+/** Total size, newest mtime and frame count of an image-sequence folder. */
+function sequenceFolderStats(dirPath) {
+  let size = 0
+  let lastModified = fs.statSync(dirPath).mtimeMs
+  let frameCount = 0
+  for (const entry of fs.readdirSync(dirPath, { withFileTypes: true })) {
+    if (!entry.isFile()) continue
+    const stat = fs.statSync(path.join(dirPath, entry.name))
+    size += stat.size
+    lastModified = Math.max(lastModified, stat.mtimeMs)
+    frameCount++
+  }
+  return { size, lastModified, frameCount }
+}
+
+/**
+ * List a project's saved exports as { name, size, lastModified, path }, newest
+ * first. Image-sequence folders are listed too, with their total size and a
+ * `frameCount`.
+ */
+export function listExportFiles(workspaceDir, projectId) {
+  const dir = exportsDir(workspaceDir, projectId)
+  if (!fs.existsSync(dir)) return []
+  const out = []
+  for (const entry of fs.readdirSync(dir, { withFileTypes: true })) {
+    const filePath = path.join(dir, entry.name)
+    if (entry.isDirectory()) {
+      out.push({ name: entry.name, ...sequenceFolderStats(filePath), path: filePath })
+      continue
+    }
+    if (!entry.isFile()) continue
+    const stat = fs.statSync(filePath)
+    out.push({ name: entry.name, size: stat.size, lastModified: stat.mtimeMs, path: filePath })
+  }
+  return out.sort((a, b) => b.lastModified - a.lastModified)
+}
@@ -0,0 +1,179 @@
+// FreeCut headless MCP server.
+//
+// Serves the editor tool registry (window.freecut.mcpListTools / mcpCallTool)
+// as a Model Context Protocol server over stdio, so an MCP client (Claude
+// Desktop, an IDE agent, a script) can inspect and edit a workspace project
+// with the same tools the in-app agent uses. One warm headless Chrome + harness
+// is kept for the whole session; calls are serialized.
+//
+// Usage:
+//   node headless/mcp.mjs --workspace <dir> --project <id|project.json> [--out <path> | --in-place]
+//
+// Options:
+//   --out <path>        Write the project here after every successful editing call
+//   --in-place          Overwrite the source project.json after every successful editing call
+//   --build             Build dist/ first if the harness isn't built
+//   --harness-url <url> Dev mode: drive a running Vite dev server instead of dist/
+//   --head              Run headed (visible browser) for debugging
+//
+// With neither --out nor --in-place edits live only for the session (the
+// in-memory project carries over between calls) and nothing is written.
+//
+// Transport: newline-delimited JSON-RPC 2.0 on stdin/stdout. stdout carries
+// protocol messages only — all logging goes to stderr.
+import { chromium } from 'playwright'
+import fs from 'node:fs'
+import path from 'node:path'
+import readline from 'node:readline'
+import { loadProject, collectMediaIds, readMediaMetadata } from './lib/workspace.mjs'
+import { parseArgs } from './lib/cli.mjs'
+import { startHarness } from './lib/render-core.mjs'
+
+const PROTOCOL_VERSION = '2025-06-18'
+const SERVER_INFO = { name: 'freecut-headless', version: '1.0.0' }
+
+const log = (...parts) => console.error('[mcp]', ...parts)
+
+function rpcResult(id, result) {
+  return { jsonrpc: '2.0', id, result }
+}
+
+function rpcError(id, code, message) {
+  return { jsonrpc: '2.0', id, error: { code, message } }
+}
+
+/** Metadata for every media the project references, so tools see fps/duration/codecs. */
+function collectProjectMedia(workspaceDir, project) {
+  return collectMediaIds(project).map((mediaId) => ({
+    mediaId,
+    metadata: readMediaMetadata(workspaceDir, mediaId) ?? undefined,
+  }))
+}
+
+async function main() {
+  const args = parseArgs(process.argv.slice(2))
+  if (!args.workspace) throw new Error('Missing --workspace <dir>')
+  if (!args.project) throw new Error('Missing --project <id|project.json>')
+
+  const loaded = loadProject(args.workspace, args.project)
+  let project = loaded.project
+  log(`Project: ${project.name ?? project.id} (${loaded.projectJsonPath})`)
+
+  let outPath = null
+  if (args.out) outPath = path.resolve(args.out)
+  else if (args['in-place']) outPath = loaded.projectJsonPath
+  log(
+    outPath
+      ? `Persisting edits to: ${outPath}`
+      : 'DRY RUN (no --out / --in-place): edits are not written',
+  )
+
+  const { harnessUrl, closeServers } = await startHarness({
+    devUrl: args['harness-url'],
+    build: args.build,
+  })
+  const browser = await chromium.launch({ channel: 'chrome', headless: !args.head })
+  const page = await browser.newPage()
+  page.on('pageerror', (e) => log('[pageerror]', e.message))
+  page.on('console', (m) => {
+    if (m.type() === 'error' && !m.text().includes('favicon')) log('[page:error]', m.text())
+  })
+  await page.goto(harnessUrl, { waitUntil: 'load', timeout: 60_000 })
+  await page.waitForFunction(() => Boolean(window.freecut?.ready), { timeout: 30_000 })
+
+  const tools = await page.evaluate(() => window.freecut.mcpListTools())
+  const readOnly = new Set(tools.filter((t) => t.annotations?.readOnlyHint).map((t) => t.name))
+  log(`Ready: ${tools.length} tools`)
+
+  // Editor session state that tools read between calls (playhead, selection).
+  let session = { currentFrame: 0, selectedItemIds: [] }
+
+  async function callTool(params) {
+    const name = params?.name
+    if (typeof name !== 'string') {
+      throw Object.assign(new Error('tools/call requires `name`'), { code: -32602 })
+    }
+
+    const call = await page.evaluate((payload) => window.freecut.mcpCallTool(payload), {
+      project,
+      name,
+      args: params.arguments ?? {},
+      media: collectProjectMedia(args.workspace, project),
+      ...session,
+    })
+    session = { currentFrame: call.currentFrame, selectedItemIds: call.selectedItemIds }
+    log(`${call.result.isError ? 'ERR' : 'ok '} ${name}`)
+
+    if (!call.result.isError && !readOnly.has(name)) {
+      project = { ...call.project, updatedAt: Date.now() }
+      if (outPath) fs.writeFileSync(outPath, JSON.stringify(project, null, 2))
+    }
+    return call.result
+  }
+
+  async function handle(message) {
+    const { id, method, params } = message
+    switch (method) {
+      case 'initialize':
+        return {
+          protocolVersion: params?.protocolVersion ?? PROTOCOL_VERSION,
+          capabilities: { tools: { listChanged: false } },
+          serverInfo: SERVER_INFO,
+        }
+      case 'ping':
+        return {}
+      case 'tools/list':
+        return { tools }
+      case 'tools/call':
+        return callTool(params)
+      default:
+        if (id === undefined) return undefined
+        throw Object.assign(new Error(`Method not found: ${method}`), { code: -32601 })
+    }
+  }
+
+  const send = (response) => process.stdout.write(JSON.stringify(response) + '\n')
+
+  // Serialize: one page op at a time, responses in request order.
+  let queue = Promise.resolve()
+  const rl = readline.createInterface({ input: process.stdin })
+  rl.on('line', (line) => {
+    if (!line.trim()) return
+    let message
+    try {
+      message = JSON.parse(line)
+    } catch {
+      send(rpcError(null, -32700, 'Parse error'))
+      return
+    }
+    queue = queue.then(async () => {
+      try {
+        const result = await handle(message)
+        // Notifications (no id) get no response.
+        if (message.id !== undefined) send(rpcResult(message.id, result))
+      } catch (e) {
+        if (message.id !== undefined) {
+          send(rpcError(message.id, e.code ?? -32603, e.message ?? String(e)))
+        } else log('Notification failed:', e.message ?? e)
+      }
+    })
+  })
+
+  let closing = false
+  const shutdown = async () => {
+    if (closing) return
+    closing = true
+    await queue
+    await browser.close()
+    await closeServers()
+    process.exit(0)
+  }
+  rl.on('close', shutdown)
+  process.on('SIGINT', shutdown)
+  process.on('SIGTERM', shutdown)
+}
+
+main().catch((e) => {
+  console.error('\nMCP server failed:', e.message ?? e)
+  process.exit(1)
+})
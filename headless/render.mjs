@@ -10,18 +10,33 @@
 //
 // --batch <jobs.json>: an array of job objects, each with the same keys as the
 // CLI flags (project, out, codec, container, resolution, fps, quality, in,
-// out-sec, duration, audio-only). All jobs share one --workspace and reuse a
+// out-sec, duration, audio-only, image-sequence, alpha, anim-fps, loop, palette,
+// dither, loudness, variant). All jobs share one --workspace and reuse a
 // single warm browser.
 //
 // Options:
-//   --out <path>           Output file (default: ./headless/output/<name>.<ext>)
+//   --out <path>           Output file or sequence folder (default: ./headless/output/<name>[.<ext>])
 //   --codec <c>            h264|h265|vp9|vp8|av1 (default: h264, auto-fallback)
-//   --container <c>        mp4|webm|mov|mkv (default: derived from codec)
+//   --container <c>        mp4|webm|mov|mkv (default: derived from codec), or
+//                          gif|webp for an animated image (no audio)
 //   --resolution <WxH>     Override output resolution (default: project metadata)
 //   --fps <n>              Override fps (default: project metadata)
 //   --quality <q>          low|medium|high|ultra (default: high)
 //   --in/--out-sec/--duration <sec>   Render only a slice
 //   --audio-only           Render audio only (container default: mp3)
+//   --image-sequence       Write numbered PNG frames (frame_000000.png, ...) into
+//                          the --out folder instead of an encoded video
+//   --alpha                Keep transparency where nothing is drawn (unless the
+//                          project sets a background colour): RGBA PNG frames,
+//                          or VP9/VP8 alpha video (defaults to VP9 WebM)
+//   --anim-fps <n>         Frame rate for gif|webp, capped at the project fps (default: 15)
+//   --loop <n>             gif|webp play count, 0 = forever (default: 0)
+//   --palette <p>          GIF palette: global|per-frame (default: global)
+//   --dither <d>           GIF dithering: floyd-steinberg|ordered|none (default: floyd-steinberg)
+//   --loudness <t>         Normalize audio to streaming|podcast|ebu-r128|atsc-a85
+//                          (-14/-16/-23/-24 LUFS, capped at the true-peak ceiling)
+//   --variant <v>          Render a canvas variant by id, name or aspect (e.g. 9:16),
+//                          or `all` for the base canvas plus every variant
 //   --head                 Run headed (visible browser) for debugging
 //   --build                Build dist/ first if the harness isn't built
 //   --harness-url <url>    Dev mode: drive a running Vite dev server instead of dist/
@@ -32,7 +47,7 @@ import { chromium } from 'playwright'
 import fs from 'node:fs'
 import { listProjects } from './lib/workspace.mjs'
 import { parseArgs, chromeLaunchArgs } from './lib/cli.mjs'
-import { prepareJob, renderJob, startHarness } from './lib/render-core.mjs'
+import { expandCanvasVariantJobs, prepareJob, renderJob, startHarness } from './lib/render-core.mjs'
 
 async function main() {
   const args = parseArgs(process.argv.slice(2))
@@ -65,6 +80,7 @@ async function main() {
     if (!args.project) throw new Error('Missing --project <id|project.json> (or --batch <file>)')
     jobArgsList = [args]
   }
+  jobArgsList = jobArgsList.flatMap((jobArgs) => expandCanvasVariantJobs(workspace, jobArgs))
 
   const { harnessUrl, mediaUrlOf, closeServers } = await startHarness({
     workspace,
@@ -109,16 +125,30 @@ async function main() {
       const job = prepareJob(workspace, jobArgsList[i], mediaUrlOf)
       const range = job.hasRange ? ` frames ${job.inPoint}..${job.outPoint ?? 'end'}` : ''
       console.log(
-        `\n[${i + 1}/${jobArgsList.length}] ${job.project.name ?? job.project.id} -> ` +
-          `${job.settings.mode} ${job.settings.codec}/${job.settings.container} ` +
+        `\n[${i + 1}/${jobArgsList.length}] ${job.project.name ?? job.project.id}` +
+          `${job.canvasVariant ? ` [${job.canvasVariant.name}]` : ''} -> ` +
+          (job.settings.mode === 'image-sequence'
+            ? `png-sequence${job.settings.alpha ? ' alpha' : ''} `
+            : job.settings.mode === 'animated-image'
+              ? `${job.settings.container} ${job.settings.animatedImage.fps}fps loop=${job.settings.animatedImage.loopCount} `
+              : `${job.settings.mode} ${job.settings.codec}/${job.settings.container}${job.settings.alpha ? ' alpha' : ''} `) +
           `${job.settings.resolution.width}x${job.settings.resolution.height}@${job.settings.fps}${range} ` +
           `| media ${job.mediaResolved}/${job.mediaTotal}`,
       )
       const summary = await renderJob(page, job, { setProgressLabel })
       process.stdout.write('\n')
+      const kind = summary.frameCount !== undefined ? `${summary.frameCount} PNG frames` : summary.mimeType
       console.log(
-        `  Done: ${job.outPath}  (${summary.mimeType}, ${(summary.fileSize / 1_000_000).toFixed(2)} MB, ${summary.durationSeconds.toFixed(2)}s)`,
+        `  Done: ${job.outPath}  (${kind}, ${(summary.fileSize / 1_000_000).toFixed(2)} MB, ${summary.durationSeconds.toFixed(2)}s)`,
       )
+      if (summary.loudness) {
+        const { integratedLufs, outputIntegratedLufs, outputTruePeakDbtp, gainDb } = summary.loudness
+        const fmt = (v) => (Number.isFinite(v) ? v.toFixed(1) : '-inf')
+        console.log(
+          `  Loudness: ${fmt(outputIntegratedLufs)} LUFS, ${fmt(outputTruePeakDbtp)} dBTP` +
+            (gainDb !== 0 ? ` (measured ${fmt(integratedLufs)} LUFS, gain ${gainDb > 0 ? '+' : ''}${gainDb.toFixed(1)} dB)` : ''),
+        )
+      }
     }
   } finally {
     await browser.close()
@@ -2,7 +2,8 @@
 //
 // Launches one warm headless Chrome + harness over a workspace and exposes a
 // small HTTP API, so renders/edits avoid the per-call browser cold start.
-// Requests are serialized (one page op at a time) to avoid GPU/CPU contention.
+// Page operations are serialized (one at a time) to avoid GPU/CPU contention;
+// render jobs wait in that same queue and report progress while they run.
 //
 // Usage:
 //   node headless/serve.mjs --workspace <dir> [--port 8787] [--build] [--head] [--harness-url <url>]
@@ -15,6 +16,18 @@
 //                                      -> the rendered video/audio file (attachment)
 //   POST /edit    { project|projectObject, ops, ... }
 //                                      -> { ok, project, applied, results } (edited project JSON)
+//   POST /jobs    { same body as /render } -> 202 { id, status, ... } (queued render job)
+//   GET  /jobs                        -> [job]  (newest first)
+//   GET  /jobs/:id                    -> job { status, progress: { phase, percent, currentFrame,
+//                                        totalFrames }, etaSeconds, output?, warnings, error? }
+//   GET  /jobs/:id/events             -> text/event-stream of `job` events until it finishes
+//   GET  /jobs/:id/output             -> the finished job's file (attachment)
+//   DELETE /jobs/:id                  -> cancels a queued or running job
+//   GET  /exports?project=<id>        -> [{ name, size, lastModified, relPath, frameCount? }] saved
+//                                        outputs (frameCount for image-sequence folders)
+//
+// Job outputs are kept in the workspace like in-app exports:
+// projects/<id>/exports/<name> (de-duplicated), or exports/ for inline projects.
 //
 // Example:
 //   curl -X POST localhost:8787/render -H 'content-type: application/json' \
@@ -24,9 +37,17 @@ import os from 'node:os'
 import fs from 'node:fs'
 import path from 'node:path'
 import { chromium } from 'playwright'
-import { loadProject, listProjects, collectAddClipMedia } from './lib/workspace.mjs'
+import {
+  loadProject,
+  listProjects,
+  collectOpMedia,
+  writeGeneratedMedia,
+  uniqueExportPath,
+  listExportFiles,
+} from './lib/workspace.mjs'
 import { parseArgs, chromeLaunchArgs } from './lib/cli.mjs'
 import { prepareJob, renderJob, startHarness } from './lib/render-core.mjs'
+import { createJobStore } from './lib/jobs.mjs'
 
 const CONTAINER_MIME = {
   mp4: 'video/mp4',
@@ -108,7 +129,12 @@ async function main() {
   const context = await browser.newContext({ acceptDownloads: true })
   const page = await context.newPage()
   page.on('pageerror', (e) => console.error('[pageerror]', e.message))
-  await page.exposeBinding('__freecutProgress', () => {})
+  // Progress callbacks belong to whichever job currently holds the page.
+  const jobs = createJobStore()
+  let activeJobId = null
+  await page.exposeBinding('__freecutProgress', (_src, progress) => {
+    if (activeJobId) jobs.progress(activeJobId, progress)
+  })
   await page.goto(harnessUrl, { waitUntil: 'load', timeout: 60_000 })
   await page.waitForFunction(() => Boolean(window.freecut?.ready), { timeout: 30_000 })
 
@@ -171,20 +197,135 @@ async function main() {
     stream.on('close', () => fs.rm(finalOut, () => {}))
   }
 
+  const toRelPath = (filePath) => path.relative(workspace, filePath).split(path.sep).join('/')
+
+  /** Run a queued job on the page; outputs land in the workspace exports folder. */
+  const runJob = async (job, prepared) => {
+    if (!jobs.start(job.id)) return
+    activeJobId = job.id
+    const warnings = []
+    try {
+      prepared.outPath = uniqueExportPath(workspace, job.projectId, job.fileName)
+      const summary = await renderJob(page, prepared, { onWarn: (m) => warnings.push(m.trim()) })
+      // A cancel can land after the page finished rendering but before this point;
+      // the client was told 202, so honour it rather than report a completed job.
+      if (job.cancelRequested) {
+        const error = new Error('Job canceled')
+        error.name = 'AbortError'
+        throw error
+      }
+      // The harness may fall back to another container; keep the extension honest.
+      // Image sequences are a folder of frames, so there's no extension to fix.
+      let outPath = prepared.outPath
+      const isSequence = prepared.settings.mode === 'image-sequence'
+      const actualExt = isSequence ? '' : path.extname(summary.fileName)
+      if (actualExt && actualExt !== path.extname(outPath)) {
+        const renamed = uniqueExportPath(
+          workspace,
+          job.projectId,
+          path.basename(outPath, path.extname(outPath)) + actualExt,
+        )
+        fs.renameSync(outPath, renamed)
+        outPath = renamed
+      }
+      jobs.complete(job.id, {
+        warnings,
+        output: {
+          name: path.basename(outPath),
+          relPath: toRelPath(outPath),
+          size: isSequence ? summary.fileSize : fs.statSync(outPath).size,
+          mimeType: summary.mimeType,
+          ...(isSequence ? { frameCount: summary.frameCount } : {}),
+          durationSeconds: summary.durationSeconds,
+        },
+      })
+      console.log(`job ${job.id} completed -> ${toRelPath(outPath)}`)
+    } catch (e) {
+      // Drop whatever was written: a finished file, or a partial frame folder.
+      if (prepared.outPath) fs.rmSync(prepared.outPath, { recursive: true, force: true })
+      const status = jobs.fail(job.id, e, { warnings })
+      console.log(`job ${job.id} ${status === 'failed' ? `failed: ${e.message ?? e}` : status}`)
+    } finally {
+      activeJobId = null
+    }
+  }
+
+  const handleSubmitJob = async (req, res) => {
+    const body = await readJsonBody(req)
+    // Resolve (and validate) everything up front so bad requests fail at submit time.
+    const prepared = prepareJob(workspace, body, mediaUrlOf)
+    const projectId = body.projectObject ? undefined : prepared.project.id
+    const job = jobs.create({
+      projectId: projectId ?? null,
+      projectName: prepared.project.name ?? prepared.project.id ?? null,
+      fileName: path.basename(prepared.outPath),
+    })
+    prepared.id = job.id
+    enqueue(() => runJob(job, prepared))
+    sendJson(res, 202, jobs.snapshot(job))
+  }
+
+  const handleCancelJob = async (res, job) => {
+    if (!jobs.cancel(job.id)) {
+      sendJson(res, 409, { error: `Job already ${job.status}` })
+      return
+    }
+    if (job.status === 'running') {
+      // Bypasses the queue on purpose: the page is busy with this very render.
+      await page.evaluate((id) => window.freecut.cancelRender(id), job.id)
+    }
+    sendJson(res, 202, jobs.snapshot(job))
+  }
+
+  const handleJobOutput = (res, job) => {
+    if (job.status !== 'completed' || !job.output) {
+      sendJson(res, 409, { error: `Job is ${job.status}` })
+      return
+    }
+    const filePath = path.join(workspace, job.output.relPath)
+    if (!fs.existsSync(filePath)) {
+      sendJson(res, 410, { error: 'Output was removed from the workspace' })
+      return
+    }
+    if (fs.statSync(filePath).isDirectory()) {
+      sendJson(res, 409, { error: `Image sequence output is a folder: ${job.output.relPath}` })
+      return
+    }
+    const asciiName = job.output.name.replace(/[^\x20-\x7E]|"/g, '_')
+    res.writeHead(200, {
+      'Content-Type': job.output.mimeType ?? 'application/octet-stream',
+      'Content-Length': fs.statSync(filePath).size,
+      'Content-Disposition': `attachment; filename="${asciiName}"`,
+    })
+    fs.createReadStream(filePath).pipe(res)
+  }
+
   const handleEdit = async (req, res) => {
     const body = await readJsonBody(req)
     const project = body.projectObject ?? loadProject(workspace, body.project).project
     const ops = Array.isArray(body.ops) ? body.ops : []
-    const media = collectAddClipMedia(workspace, ops)
+    const media = collectOpMedia(workspace, ops, project, mediaUrlOf)
     const result = await enqueue(() =>
       page.evaluate((payload) => window.freecut.editProject(payload), { project, ops, media }),
     )
-    sendJson(res, 200, result)
+    // Generated stills must exist in the workspace for the returned project to resolve.
+    writeGeneratedMedia(workspace, result.project.id, result.generatedMedia)
+    sendJson(res, 200, {
+      ...result,
+      generatedMedia: result.generatedMedia.map((m) => m.metadata),
+    })
   }
 
   const server = http.createServer((req, res) => {
     const url = new URL(req.url ?? '/', 'http://localhost')
     const route = `${req.method} ${url.pathname}`
+    const jobMatch = /^\/jobs\/([^/]+)(\/events|\/output)?$/.exec(url.pathname)
+    const job = jobMatch ? jobs.get(jobMatch[1]) : null
+    if (jobMatch && !job) {
+      sendJson(res, 404, { error: `No job: ${jobMatch[1]}` })
+      return
+    }
+    const jobRoute = jobMatch ? `${req.method} /jobs/:id${jobMatch[2] ?? ''}` : null
     const handler =
       route === 'GET /health'
         ? async () => {
@@ -197,7 +338,32 @@ async function main() {
             ? () => handleRender(req, res)
             : route === 'POST /edit'
               ? () => handleEdit(req, res)
-              : null
+              : route === 'POST /jobs'
+                ? () => handleSubmitJob(req, res)
+                : route === 'GET /jobs'
+                  ? async () => sendJson(res, 200, jobs.list())
+                  : route === 'GET /exports'
+                    ? async () => {
+                        const projectId = url.searchParams.get('project') ?? undefined
+                        const files = listExportFiles(workspace, projectId)
+                        sendJson(
+                          res,
+                          200,
+                          files.map(({ path: filePath, ...file }) => ({
+                            ...file,
+                            relPath: toRelPath(filePath),
+                          })),
+                        )
+                      }
+                    : jobRoute === 'GET /jobs/:id'
+                      ? async () => sendJson(res, 200, jobs.snapshot(job))
+                      : jobRoute === 'GET /jobs/:id/events'
+                        ? async () => jobs.stream(job.id, req, res)
+                        : jobRoute === 'GET /jobs/:id/output'
+                          ? async () => handleJobOutput(res, job)
+                          : jobRoute === 'DELETE /jobs/:id'
+                            ? () => handleCancelJob(res, job)
+                            : null
     if (!handler) {
       sendJson(res, 404, { error: `No route: ${route}` })
       return
@@ -214,6 +380,7 @@ async function main() {
   await new Promise((resolve) => server.listen(port, '127.0.0.1', resolve))
   console.log(`FreeCut render service on http://localhost:${port}  (workspace: ${workspace})`)
   console.log(`  GET /health  GET /projects  POST /render  POST /edit`)
+  console.log(`  POST /jobs  GET /jobs[/:id[/events|/output]]  DELETE /jobs/:id  GET /exports`)
 
   const shutdown = async () => {
     console.log('\nShutting down...')
@@ -1,17 +1,23 @@
 // Headless regression test. Builds the harness, then exercises both the render
-// and edit paths inside headless Chrome and asserts the results. Self-contained:
-// no workspace, no media, no ffprobe — so it runs in CI. Exits non-zero on any
-// failed check.
+// and edit paths inside headless Chrome and asserts the results, plus the
+// render service's job API and the MCP stdio server over a throwaway
+// workspace. Self-contained: no media, no ffprobe — so it runs in CI. Exits
+// non-zero on any failed check. The job store's state moves are checked in
+// plain Node first.
 //
 // Run: node headless/test.mjs   (or: npm run headless:test)
 import { chromium } from 'playwright'
-import { execSync } from 'node:child_process'
+import { execSync, spawn } from 'node:child_process'
 import { fileURLToPath } from 'node:url'
+import { EventEmitter, once } from 'node:events'
 import fs from 'node:fs'
+import net from 'node:net'
 import path from 'node:path'
 import os from 'node:os'
+import readline from 'node:readline'
 import { createHarnessServer } from './server.mjs'
 import { chromeLaunchArgs } from './lib/cli.mjs'
+import { createJobStore } from './lib/jobs.mjs'
 
 const REPO_ROOT = path.resolve(path.dirname(fileURLToPath(import.meta.url)), '..')
 
@@ -114,6 +120,57 @@ const SAMPLE_PROJECT = {
   },
 }
 
+/** A cut of media-1 (30 s at 30 fps), `durationInFrames` long from `sourceStart`, at speed 1. */
+function mediaClip(id, type, from, durationInFrames, sourceStart, linkedGroupId) {
+  return {
+    id,
+    trackId: type === 'audio' ? 'track-2' : 'track-1',
+    from,
+    durationInFrames,
+    label: 'clip.mp4',
+    type,
+    src: '',
+    mediaId: 'media-1',
+    sourceStart,
+    sourceEnd: sourceStart + durationInFrames,
+    sourceDuration: 900,
+    sourceFps: 30,
+    speed: 1,
+    ...(type === 'audio' ? { volume: 0 } : { transform: {} }),
+    ...(linkedGroupId ? { linkedGroupId } : {}),
+  }
+}
+
+// Five back-to-back cuts on V1 (d and e are contiguous in the source) plus a's
+// linked audio on A1. Sync lock is off so only the edited track ripples.
+const EDIT_OPS_PROJECT = {
+  ...SAMPLE_PROJECT,
+  id: 'edit-ops-project',
+  duration: 270,
+  timeline: {
+    ...SAMPLE_PROJECT.timeline,
+    tracks: [
+      { ...SAMPLE_PROJECT.timeline.tracks[0], syncLock: false },
+      {
+        ...SAMPLE_PROJECT.timeline.tracks[0],
+        id: 'track-2',
+        name: 'A1',
+        kind: 'audio',
+        syncLock: false,
+        order: 1,
+      },
+    ],
+    items: [
+      mediaClip('clip-a', 'video', 0, 60, 100, 'link-a'),
+      mediaClip('audio-a', 'audio', 0, 60, 100, 'link-a'),
+      mediaClip('clip-b', 'video', 60, 60, 300),
+      mediaClip('clip-c', 'video', 120, 60, 500),
+      mediaClip('clip-d', 'video', 180, 60, 700),
+      mediaClip('clip-e', 'video', 240, 30, 760),
+    ],
+  },
+}
+
 function textProjectRenderSettings(project) {
   const width = project.metadata?.width ?? 1280
   const height = project.metadata?.height ?? 720
@@ -140,24 +197,115 @@ function check(name, condition, detail) {
   }
 }
 
-async function main() {
-  const distDir = path.join(REPO_ROOT, 'dist')
-  // --skip-build reuses an existing dist/ (e.g. CI, where the build step
-  // already ran); without it the harness always rebuilds.
-  if (process.argv.includes('--skip-build')) {
-    console.log('Skipping build (--skip-build), using existing dist/...')
-  } else {
-    console.log('Building harness (npm run build)...')
-    execSync('npm run build', { cwd: REPO_ROOT, stdio: 'inherit' })
+/** Assert an item's [from, durationInFrames, sourceStart, sourceEnd, speed]; null if it is gone. */
+function checkTiming(name, item, expected) {
+  const actual = item
+    ? [item.from, item.durationInFrames, item.sourceStart, item.sourceEnd, item.speed]
+    : null
+  check(name, JSON.stringify(actual) === JSON.stringify(expected), `got ${JSON.stringify(actual)}`)
+}
+
+/** A free loopback port for a spawned service. */
+async function freePort() {
+  const probe = net.createServer()
+  await new Promise((resolve) => probe.listen(0, '127.0.0.1', resolve))
+  const { port } = probe.address()
+  await new Promise((resolve) => probe.close(resolve))
+  return port
+}
+
+/** A throwaway workspace holding SAMPLE_PROJECT as projects/<id>/project.json. */
+function createTestWorkspace() {
+  const workspace = fs.mkdtempSync(path.join(os.tmpdir(), 'freecut-headless-workspace-'))
+  const projectDir = path.join(workspace, 'projects', SAMPLE_PROJECT.id)
+  fs.mkdirSync(projectDir, { recursive: true })
+  fs.writeFileSync(path.join(projectDir, 'project.json'), JSON.stringify(SAMPLE_PROJECT))
+  return workspace
+}
+
+/** Run serve.mjs over `workspace` on the built harness; resolves once it listens. */
+async function startRenderService(workspace) {
+  const port = await freePort()
+  const child = spawn(
+    process.execPath,
+    [path.join(REPO_ROOT, 'headless', 'serve.mjs'), '--workspace', workspace, '--port', String(port)],
+    { stdio: ['ignore', 'pipe', 'inherit'] },
+  )
+  let output = ''
+  await new Promise((resolve, reject) => {
+    child.stdout.on('data', (chunk) => {
+      output += chunk
+      if (output.includes('render service on')) resolve()
+    })
+    child.once('exit', (code) => reject(new Error(`serve.mjs exited early (${code})`)))
+  })
+  return {
+    url: `http://127.0.0.1:${port}`,
+    close: async () => {
+      child.kill('SIGTERM')
+      await once(child, 'exit')
+    },
   }
-  if (!fs.existsSync(path.join(distDir, 'headless.html'))) {
-    throw new Error(
-      process.argv.includes('--skip-build')
-        ? 'dist/headless.html missing — run npm run build first or drop --skip-build'
-        : 'Build did not produce dist/headless.html',
-    )
+}
+
+/**
+ * Run mcp.mjs over `workspace` with newline-delimited JSON-RPC on its stdio.
+ * `send` writes one raw line and resolves with the next response.
+ */
+function startMcpServer(workspace, outPath) {
+  const child = spawn(
+    process.execPath,
+    [
+      path.join(REPO_ROOT, 'headless', 'mcp.mjs'),
+      '--workspace',
+      workspace,
+      '--project',
+      SAMPLE_PROJECT.id,
+      '--out',
+      outPath,
+    ],
+    { stdio: ['pipe', 'pipe', 'inherit'] },
+  )
+  const waiting = []
+  readline.createInterface({ input: child.stdout }).on('line', (line) => {
+    waiting.shift()?.resolve(JSON.parse(line))
+  })
+  child.once('exit', (code) => {
+    for (const pending of waiting.splice(0)) {
+      pending.reject(new Error(`mcp.mjs exited early (${code})`))
+    }
+  })
+  return {
+    send: (line) =>
+      new Promise((resolve, reject) => {
+        waiting.push({ resolve, reject })
+        child.stdin.write(`${line}\n`)
+      }),
+    close: async () => {
+      child.stdin.end()
+      await once(child, 'exit')
+    },
+  }
+}
+
+async function requestJson(url, init) {
+  const res = await fetch(url, init)
+  return { status: res.status, body: await res.json() }
+}
+
+/** Poll a service job until it completes, fails or is canceled. */
+async function waitForJob(serviceUrl, jobId, timeoutMs = 120_000) {
+  const deadline = Date.now() + timeoutMs
+  for (;;) {
+    const { body } = await requestJson(`${serviceUrl}/jobs/${jobId}`)
+    if (['completed', 'failed', 'canceled'].includes(body.status)) return body
+    if (Date.now() > deadline) throw new Error(`Job ${jobId} still ${body.status} after ${timeoutMs}ms`)
+    await new Promise((resolve) => setTimeout(resolve, 200))
   }
+}
 
+/** Render and edit paths, driven directly through the harness page. */
+async function testHarness(distDir) {
   const server = await createHarnessServer({ distDir })
   const browser = await chromium.launch({ channel: 'chrome', headless: true, args: chromeLaunchArgs() })
   try {
@@ -257,10 +405,295 @@ async function main() {
       `got ${editedSummary.durationSeconds}, expected ${expectedEditedDurationSeconds}`,
     )
     check('edited render produced bytes (>1KB)', editedSize > 1000, `size ${editedSize}`)
+
+    await testEditOps(page)
   } finally {
     await browser.close()
     await server.close()
   }
+}
+
+/** The timeline editing ops, each run on a fresh copy of EDIT_OPS_PROJECT. */
+async function testEditOps(page) {
+  console.log('\nEdit ops:')
+  const run = async (ops) => {
+    const edit = await page.evaluate((input) => window.freecut.editProject(input), {
+      project: EDIT_OPS_PROJECT,
+      ops: [{ op: 'setLinkedSelection', enabled: true }, ...ops],
+    })
+    const items = edit.project.timeline.items
+    return {
+      results: edit.results.slice(1),
+      items,
+      item: (id) => items.find((item) => item.id === id),
+    }
+  }
+
+  const rippleDelete = await run([{ op: 'rippleDelete', ids: ['clip-b'] }])
+  check('rippleDelete removes the clip', rippleDelete.item('clip-b') === undefined)
+  checkTiming('rippleDelete keeps earlier clips', rippleDelete.item('clip-a'), [0, 60, 100, 160, 1])
+  checkTiming('rippleDelete pulls c back', rippleDelete.item('clip-c'), [60, 60, 500, 560, 1])
+  checkTiming('rippleDelete pulls d back', rippleDelete.item('clip-d'), [120, 60, 700, 760, 1])
+  checkTiming('rippleDelete pulls e back', rippleDelete.item('clip-e'), [180, 30, 760, 790, 1])
+
+  const linked = await run([{ op: 'rippleDelete', ids: ['clip-a'] }])
+  check('rippleDelete takes linked audio along', linked.item('audio-a') === undefined)
+  const unlinked = await run([
+    { op: 'setLinkedSelection', enabled: false },
+    { op: 'rippleDelete', ids: ['clip-a'] },
+  ])
+  check('setLinkedSelection reports the state', unlinked.results[0]?.detail?.enabled === false)
+  checkTiming('unlinked rippleDelete keeps audio', unlinked.item('audio-a'), [0, 60, 100, 160, 1])
+  checkTiming('unlinked rippleDelete pulls b back', unlinked.item('clip-b'), [0, 60, 300, 360, 1])
+
+  const trimEnd = await run([{ op: 'rippleTrim', id: 'clip-b', handle: 'end', amount: -20 }])
+  checkTiming('rippleTrim end shortens the clip', trimEnd.item('clip-b'), [60, 40, 300, 340, 1])
+  checkTiming('rippleTrim end pulls c back', trimEnd.item('clip-c'), [100, 60, 500, 560, 1])
+  checkTiming('rippleTrim end pulls e back', trimEnd.item('clip-e'), [220, 30, 760, 790, 1])
+
+  const trimStart = await run([{ op: 'rippleTrim', id: 'clip-b', handle: 'start', amount: 20 }])
+  checkTiming('rippleTrim start holds position', trimStart.item('clip-b'), [60, 40, 320, 360, 1])
+  checkTiming('rippleTrim start pulls c back', trimStart.item('clip-c'), [100, 60, 500, 560, 1])
+  checkTiming('rippleTrim start pulls d back', trimStart.item('clip-d'), [160, 60, 700, 760, 1])
+
+  const roll = await run([{ op: 'roll', leftClipId: 'clip-b', rightClipId: 'clip-c', delta: 10 }])
+  checkTiming('roll extends the left clip', roll.item('clip-b'), [60, 70, 300, 370, 1])
+  checkTiming('roll trims the right clip', roll.item('clip-c'), [130, 50, 510, 560, 1])
+  checkTiming('roll leaves later clips', roll.item('clip-d'), [180, 60, 700, 760, 1])
+
+  const slip = await run([{ op: 'slip', id: 'clip-c', delta: 15 }])
+  checkTiming('slip moves only the source window', slip.item('clip-c'), [120, 60, 515, 575, 1])
+  checkTiming('slip leaves neighbors', slip.item('clip-d'), [180, 60, 700, 760, 1])
+
+  const slide = await run([{ op: 'slide', id: 'clip-c', delta: 10 }])
+  checkTiming('slide moves the clip', slide.item('clip-c'), [130, 60, 500, 560, 1])
+  checkTiming('slide extends the left neighbor', slide.item('clip-b'), [60, 70, 300, 370, 1])
+  checkTiming('slide trims the right neighbor', slide.item('clip-d'), [190, 50, 710, 760, 1])
+
+  const stretch = await run([{ op: 'rateStretch', id: 'clip-c', speed: 2 }])
+  checkTiming('rateStretch retimes the clip', stretch.item('clip-c'), [120, 30, 500, 560, 2])
+  checkTiming('rateStretch pulls d back', stretch.item('clip-d'), [150, 60, 700, 760, 1])
+  checkTiming('rateStretch pulls e back', stretch.item('clip-e'), [210, 30, 760, 790, 1])
+
+  const reset = await run([
+    { op: 'rateStretch', id: 'clip-c', speed: 2 },
+    { op: 'resetSpeed', ids: ['clip-c'] },
+  ])
+  checkTiming('resetSpeed restores 1x', reset.item('clip-c'), [120, 60, 500, 560, 1])
+  checkTiming('resetSpeed pushes d out again', reset.item('clip-d'), [180, 60, 700, 760, 1])
+
+  // 10.5–11.5 s of the source is the middle second of clip-b (frames 300–360).
+  const ranges = await run([
+    { op: 'removeRanges', ids: ['clip-b'], ranges: { 'media-1': [{ start: 10.5, end: 11.5 }] } },
+  ])
+  const pieces = ranges.items
+    .filter((item) => item.trackId === 'track-1' && item.from >= 60 && item.from < 90)
+    .sort((left, right) => left.from - right.from)
+  check('removeRanges removes one piece', ranges.results[0]?.detail?.removedItemCount === 1)
+  checkTiming('removeRanges keeps the head', pieces[0], [60, 15, 300, 315, 1])
+  checkTiming('removeRanges keeps the tail', pieces[1], [75, 15, 345, 360, 1])
+  checkTiming('removeRanges pulls c back', ranges.item('clip-c'), [90, 60, 500, 560, 1])
+  checkTiming('removeRanges pulls e back', ranges.item('clip-e'), [210, 30, 760, 790, 1])
+
+  const join = await run([{ op: 'join', ids: ['clip-d', 'clip-e'] }])
+  check('join reports one joined cut', join.results[0]?.detail?.joined === 1)
+  check('join removes the right cut', join.item('clip-e') === undefined)
+  checkTiming('join spans both cuts', join.item('clip-d'), [180, 90, 700, 790, 1])
+}
+
+/** The render service's job API (serve.mjs), over a throwaway workspace. */
+async function testRenderService() {
+  console.log('\nRender service jobs:')
+  const workspace = createTestWorkspace()
+  const service = await startRenderService(workspace)
+  try {
+    const submit = await requestJson(`${service.url}/jobs`, {
+      method: 'POST',
+      headers: { 'content-type': 'application/json' },
+      body: JSON.stringify({ project: SAMPLE_PROJECT.id, codec: 'vp9' }),
+    })
+    const cancel = await fetch(`${service.url}/jobs/${submit.body.id}`, { method: 'DELETE' })
+    const canceled = await waitForJob(service.url, submit.body.id)
+    const exportsAfterCancel = await requestJson(`${service.url}/exports?project=${SAMPLE_PROJECT.id}`)
+
+    check('job submit is accepted', submit.status === 202, `status ${submit.status}`)
+    check('cancel right after submit is accepted', cancel.status === 202, `status ${cancel.status}`)
+    check('canceled job ends canceled', canceled.status === 'canceled', canceled.status)
+    check('canceled job has no output', canceled.output === null)
+    check(
+      'canceled job leaves no export behind',
+      exportsAfterCancel.body.length === 0,
+      `exports ${JSON.stringify(exportsAfterCancel.body)}`,
+    )
+
+    // Half a second of PNG frames: a folder output rather than a file.
+    const sequenceSubmit = await requestJson(`${service.url}/jobs`, {
+      method: 'POST',
+      headers: { 'content-type': 'application/json' },
+      body: JSON.stringify({ project: SAMPLE_PROJECT.id, imageSequence: true, duration: 0.5 }),
+    })
+    const sequence = await waitForJob(service.url, sequenceSubmit.body.id)
+    const exportsAfterSequence = await requestJson(
+      `${service.url}/exports?project=${SAMPLE_PROJECT.id}`,
+    )
+    const listedSequence = exportsAfterSequence.body.find(
+      (entry) => entry.name === sequence.output?.name,
+    )
+
+    check('image-sequence job completes', sequence.status === 'completed', sequence.error ?? '')
+    check('image-sequence job wrote 15 frames', sequence.output?.frameCount === 15)
+    check(
+      'exports list the sequence folder',
+      listedSequence?.frameCount === 15 && listedSequence.size === sequence.output.size,
+      JSON.stringify(listedSequence),
+    )
+  } finally {
+    await service.close()
+    fs.rmSync(workspace, { recursive: true, force: true })
+  }
+}
+
+/** An SSE request/response pair that records what the server writes. */
+function fakeEventStream() {
+  const req = new EventEmitter()
+  const res = {
+    events: 0,
+    ended: false,
+    writeHead() {},
+    write() {
+      this.events++
+    },
+    end() {
+      this.ended = true
+    },
+  }
+  return { req, res }
+}
+
+/** Job state moves and SSE subscriptions (lib/jobs.mjs), without a browser. */
+function testJobStore() {
+  console.log('\nJob store:')
+  const jobs = createJobStore()
+
+  const queued = jobs.create({})
+  check('a cancel of a queued job is accepted', jobs.cancel(queued.id) === true)
+  check('queued job ends canceled', queued.status === 'canceled' && queued.finishedAt !== null)
+  check('a canceled job never starts', !jobs.start(queued.id) && queued.startedAt === null)
+
+  const running = jobs.create({})
+  check('queued job starts', jobs.start(running.id) === true && running.status === 'running')
+  check('a cancel of a running job is accepted', jobs.cancel(running.id) === true)
+  check('running job stays running until its render settles', running.status === 'running')
+  const aborted = new Error('Render cancelled')
+  aborted.name = 'AbortError'
+  check('running job ends canceled', jobs.fail(running.id, aborted) === 'canceled')
+  check('canceled job carries no error', running.error === null)
+  check('a finished job cannot be canceled', jobs.cancel(running.id) === false)
+
+  const late = jobs.create({})
+  jobs.start(late.id)
+  jobs.cancel(late.id)
+  check(
+    'a requested cancel wins over a render error',
+    jobs.fail(late.id, new Error('encoder closed')) === 'canceled',
+  )
+
+  const failing = jobs.create({})
+  jobs.start(failing.id)
+  check('running job ends failed', jobs.fail(failing.id, new Error('boom')) === 'failed')
+  check('failed job keeps the error', failing.error === 'boom', failing.error)
+
+  const watched = jobs.create({})
+  const left = fakeEventStream()
+  const stayed = fakeEventStream()
+  jobs.stream(watched.id, left.req, left.res)
+  jobs.stream(watched.id, stayed.req, stayed.res)
+  check('event stream opens with the current snapshot', left.res.events === 1)
+  left.req.emit('close')
+  jobs.start(watched.id)
+  jobs.progress(watched.id, { phase: 'rendering', progress: 50 })
+  check('a disconnected client gets no more events', left.res.events === 1, `${left.res.events}`)
+  check('a connected client gets every change', stayed.res.events === 3, `${stayed.res.events}`)
+  jobs.complete(watched.id, { output: null })
+  jobs.update(watched.id, { warnings: ['after the end'] })
+  check(
+    'the stream ends with the finished job',
+    stayed.res.ended && stayed.res.events === 4,
+    `${stayed.res.events}`,
+  )
+}
+
+/** The MCP stdio server (mcp.mjs): handshake, tool listing, one editing call, bad input. */
+async function testMcpServer() {
+  console.log('\nMCP server:')
+  const workspace = createTestWorkspace()
+  const outPath = path.join(workspace, 'mcp-out.json')
+  const mcp = startMcpServer(workspace, outPath)
+  const rpc = (id, method, params) =>
+    mcp.send(JSON.stringify({ jsonrpc: '2.0', id, method, params }))
+  try {
+    const init = await rpc(1, 'initialize', { protocolVersion: '2025-06-18', capabilities: {} })
+    check('initialize names the server', init.result?.serverInfo?.name === 'freecut-headless')
+    check('initialize offers tools', init.result?.capabilities?.tools !== undefined)
+
+    const list = await rpc(2, 'tools/list')
+    const toolNames = list.result?.tools?.map((tool) => tool.name) ?? []
+    check('tools/list includes add_title', toolNames.includes('add_title'), toolNames.join(', '))
+
+    const call = await rpc(3, 'tools/call', {
+      name: 'add_title',
+      arguments: { text: 'from mcp', atSeconds: 4 },
+    })
+    const saved = fs.existsSync(outPath) ? JSON.parse(fs.readFileSync(outPath, 'utf8')) : null
+    const added = saved?.timeline?.items?.find((item) => item.text === 'from mcp')
+    check(
+      'tools/call succeeds',
+      call.id === 3 && call.result?.isError === false,
+      JSON.stringify(call.result),
+    )
+    check('tools/call writes the edited project', added?.from === 120, JSON.stringify(added))
+
+    const malformed = await mcp.send('{"jsonrpc": "2.0", "id": 4, "method": ')
+    check(
+      'malformed JSON gets a parse error',
+      malformed.id === null && malformed.error?.code === -32700,
+      JSON.stringify(malformed),
+    )
+    const nameless = await rpc(5, 'tools/call', { arguments: {} })
+    check(
+      'tools/call without a name gets invalid params',
+      nameless.id === 5 && nameless.error?.code === -32602,
+      JSON.stringify(nameless),
+    )
+  } finally {
+    await mcp.close()
+    fs.rmSync(workspace, { recursive: true, force: true })
+  }
+}
+
+async function main() {
+  testJobStore()
+
+  const distDir = path.join(REPO_ROOT, 'dist')
+  // --skip-build reuses an existing dist/ (e.g. CI, where the build step
+  // already ran); without it the harness always rebuilds.
+  if (process.argv.includes('--skip-build')) {
+    console.log('Skipping build (--skip-build), using existing dist/...')
+  } else {
+    console.log('Building harness (npm run build)...')
+    execSync('npm run build', { cwd: REPO_ROOT, stdio: 'inherit' })
+  }
+  if (!fs.existsSync(path.join(distDir, 'headless.html'))) {
+    throw new Error(
+      process.argv.includes('--skip-build')
+        ? 'dist/headless.html missing — run npm run build first or drop --skip-build'
+        : 'Build did not produce dist/headless.html',
+    )
+  }
+
+  await testHarness(distDir)
+  await testRenderService()
+  await testMcpServer()
 
   if (failures > 0) {
     console.error(`\n${failures} check(s) FAILED`)
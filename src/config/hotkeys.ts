@@ -84,6 +84,17 @@ export const HOTKEYS = {
   KEYFRAME_EDITOR_GRAPH: '1',
   KEYFRAME_EDITOR_DOPESHEET: '2',
 
+  // Multicam
+  MULTICAM_ANGLE_1: '1',
+  MULTICAM_ANGLE_2: '2',
+  MULTICAM_ANGLE_3: '3',
+  MULTICAM_ANGLE_4: '4',
+  MULTICAM_ANGLE_5: '5',
+  MULTICAM_ANGLE_6: '6',
+  MULTICAM_ANGLE_7: '7',
+  MULTICAM_ANGLE_8: '8',
+  MULTICAM_ANGLE_9: '9',
+
   // Source Monitor
   MARK_IN: 'i',
   MARK_OUT: 'o',
@@ -348,6 +359,17 @@ export const HOTKEY_DESCRIPTIONS: Record<HotkeyKey, string> = {
   KEYFRAME_EDITOR_GRAPH: 'Switch keyframe editor to graph view',
   KEYFRAME_EDITOR_DOPESHEET: 'Switch keyframe editor to dopesheet view',
 
+  // Multicam
+  MULTICAM_ANGLE_1: 'Switch multicam to angle 1',
+  MULTICAM_ANGLE_2: 'Switch multicam to angle 2',
+  MULTICAM_ANGLE_3: 'Switch multicam to angle 3',
+  MULTICAM_ANGLE_4: 'Switch multicam to angle 4',
+  MULTICAM_ANGLE_5: 'Switch multicam to angle 5',
+  MULTICAM_ANGLE_6: 'Switch multicam to angle 6',
+  MULTICAM_ANGLE_7: 'Switch multicam to angle 7',
+  MULTICAM_ANGLE_8: 'Switch multicam to angle 8',
+  MULTICAM_ANGLE_9: 'Switch multicam to angle 9',
+
   // Source Monitor
   MARK_IN: 'Mark In point',
   MARK_OUT: 'Mark Out point',
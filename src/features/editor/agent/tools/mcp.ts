@@ -1,18 +1,19 @@
 /**
  * MCP adapter for the editor tool registry.
  *
- * This is the future-facing seam. The Model Context Protocol describes tools as
- * `{ name, description, inputSchema }` (listing) and `tools/call` → content
- * blocks (invocation) — which is exactly the shape our registry already has. The
- * two functions here are everything an MCP *server* would delegate to; standing
- * one up later is just choosing a transport:
+ * The Model Context Protocol describes tools as `{ name, description,
+ * inputSchema }` (listing) and `tools/call` → content blocks (invocation) —
+ * which is exactly the shape our registry already has. The two functions here
+ * are everything an MCP *server* delegates to; each server only picks a
+ * transport:
  *
- *   • in-browser: a `postMessage` / `WebSocket` / WebRTC transport so an external
- *     MCP client (or our own cloud agent) can drive this editor tab;
- *   • headless: the edit CLI wraps the same `callTool` over stdio.
+ *   • headless: `headless/mcp.mjs` serves them over stdio via the harness page
+ *     (`src/headless/mcp.ts`);
+ *   • in-browser (future): a `postMessage` / `WebSocket` / WebRTC transport so an
+ *     external MCP client (or our own cloud agent) can drive this editor tab.
  *
  * Keeping this mapping in-tree (and tested) guarantees the registry stays
- * MCP-compatible as tools are added, without yet shipping a server.
+ * MCP-compatible as tools are added.
  */
 
 import { getEditorTool, listEditorTools } from './registry'
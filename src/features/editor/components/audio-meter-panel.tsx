@@ -26,6 +26,12 @@ import {
   getAudioSkimMeterVersion,
   subscribeAudioSkimMeterLevel,
 } from '@/shared/state/audio-skim-meter'
+import {
+  getPreviewLoudness,
+  getPreviewLoudnessVersion,
+  resetPreviewLoudness,
+  subscribePreviewLoudness,
+} from '@/shared/state/preview-loudness'
 import { usePreviewBridgeStore } from '@/shared/state/preview-bridge'
 import { useEditorStore } from '@/shared/state/editor/store'
 import { EDITOR_LAYOUT_CSS_VALUES } from '@/config/editor-layout'
@@ -195,6 +201,53 @@ const AudioEqPanelSurface = memo(function AudioEqPanelSurface({
   )
 })
 
+function formatLoudnessReading(value: number | undefined): string {
+  return value === undefined || !Number.isFinite(value) ? '--' : value.toFixed(1)
+}
+
+/**
+ * BS.1770 readout of what the preview is actually playing. Subscribing is what
+ * attaches the loudness tap, so it only runs while this is mounted.
+ */
+const PreviewLoudnessReadout = memo(function PreviewLoudnessReadout() {
+  const { t } = useTranslation()
+  const version = useSyncExternalStore(
+    subscribePreviewLoudness,
+    getPreviewLoudnessVersion,
+    getPreviewLoudnessVersion,
+  )
+  const loudness = useMemo(() => {
+    void version
+    return getPreviewLoudness()
+  }, [version])
+
+  const readings: Array<[string, number | undefined]> = [
+    ['M', loudness?.momentaryLufs],
+    ['S', loudness?.shortTermLufs],
+    ['I', loudness?.integratedLufs],
+    ['TP', loudness?.truePeakDbtp],
+  ]
+
+  return (
+    <button
+      type="button"
+      className="mt-2 grid w-full grid-cols-2 gap-x-2 gap-y-0.5 rounded-sm px-1 py-0.5 text-[10px] font-mono text-muted-foreground hover:bg-secondary/40"
+      title={t('editor.audioMeters.loudnessReset')}
+      aria-label={t('editor.audioMeters.loudnessReset')}
+      onClick={resetPreviewLoudness}
+    >
+      {readings.map(([label, value]) => (
+        <span key={label} className="flex justify-between gap-1">
+          <span>{label}</span>
+          <span className={label === 'TP' && (value ?? -Infinity) > -1 ? 'text-red-400' : ''}>
+            {formatLoudnessReading(value)}
+          </span>
+        </span>
+      ))}
+    </button>
+  )
+})
+
 export const AudioMeterPanel = memo(function AudioMeterPanel() {
   const { t } = useTranslation()
   const [panelMode, setPanelMode] = useState<PanelMode>('meter')
@@ -1113,7 +1166,7 @@ export const AudioMeterPanel = memo(function AudioMeterPanel() {
               Master
             </div>
 
-            <div className="flex h-[calc(100%-2.75rem)] items-stretch gap-3">
+            <div className="flex h-[calc(100%-5.5rem)] items-stretch gap-3">
               {/* Scale marks */}
               <div className="relative flex-1 min-w-0">
                 {AUDIO_METER_SCALE_MARKS.map((mark) => {
@@ -1196,6 +1249,7 @@ export const AudioMeterPanel = memo(function AudioMeterPanel() {
             <div className="mt-3 text-center text-[10px] font-mono text-muted-foreground">
               {statusLabel}
             </div>
+            <PreviewLoudnessReadout />
           </div>
         </div>
       </aside>
@@ -106,14 +106,20 @@ vi.mock('./audio-meter-panel', () => ({
 
 vi.mock('@/features/editor/deps/timeline-ui', () => ({
   Timeline: () => <div data-testid="timeline" />,
+  importAutoDuckingDialog: vi.fn().mockResolvedValue({ AutoDuckingDialog: () => null }),
   importBentoLayoutDialog: vi.fn().mockResolvedValue({ BentoLayoutDialog: () => null }),
   importFillerRemovalDialog: vi.fn().mockResolvedValue({ FillerRemovalDialog: () => null }),
+  importMotionTrackingDialog: vi.fn().mockResolvedValue({ MotionTrackingDialog: () => null }),
   importReverseConformDialog: vi.fn().mockResolvedValue({ ReverseConformDialog: () => null }),
   importSilenceRemovalDialog: vi.fn().mockResolvedValue({ SilenceRemovalDialog: () => null }),
+  useAutoDuckingDialogStore: (selector: (state: { isOpen: boolean }) => unknown) =>
+    selector({ isOpen: false }),
   useBentoLayoutDialogStore: (selector: (state: { isOpen: boolean }) => unknown) =>
     selector({ isOpen: false }),
   useFillerRemovalDialogStore: (selector: (state: { isOpen: boolean }) => unknown) =>
     selector({ isOpen: false }),
+  useMotionTrackingDialogStore: (selector: (state: { isOpen: boolean }) => unknown) =>
+    selector({ isOpen: false }),
   useReverseConformDialogStore: (selector: (state: { request: null }) => unknown) =>
     selector({ request: null }),
   useSilenceRemovalDialogStore: (selector: (state: { isOpen: boolean }) => unknown) =>
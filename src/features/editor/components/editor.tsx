@@ -17,12 +17,16 @@ import { InteractionLockRegion } from './interaction-lock-region'
 import { AudioMeterPanel } from './audio-meter-panel'
 import {
   Timeline,
+  importAutoDuckingDialog,
   importBentoLayoutDialog,
   importFillerRemovalDialog,
+  importMotionTrackingDialog,
   importReverseConformDialog,
   importSilenceRemovalDialog,
+  useAutoDuckingDialogStore,
   useBentoLayoutDialogStore,
   useFillerRemovalDialogStore,
+  useMotionTrackingDialogStore,
   useReverseConformDialogStore,
   useSilenceRemovalDialogStore,
 } from '@/features/editor/deps/timeline-ui'
@@ -35,7 +39,11 @@ import {
 } from '@/features/editor/deps/timeline-hooks'
 import { initTransitionChainSubscription } from '@/features/editor/deps/timeline-subscriptions'
 import { useTimelineStore } from '@/features/editor/deps/timeline-store'
-import { importBundleExportDialog } from '@/features/editor/deps/project-bundle'
+import {
+  importBundleExportDialog,
+  importInterchangeService,
+  type InterchangeFormat,
+} from '@/features/editor/deps/project-bundle'
 import { useMediaLibraryStore } from '@/features/editor/deps/media-library'
 import { useSettingsStore } from '@/features/editor/deps/settings'
 import { useMaskEditorStore } from '@/features/editor/deps/preview'
@@ -152,6 +160,16 @@ const LazyFillerRemovalDialog = lazy(() =>
     default: module.FillerRemovalDialog,
   })),
 )
+const LazyAutoDuckingDialog = lazy(() =>
+  importAutoDuckingDialog().then((module) => ({
+    default: module.AutoDuckingDialog,
+  })),
+)
+const LazyMotionTrackingDialog = lazy(() =>
+  importMotionTrackingDialog().then((module) => ({
+    default: module.MotionTrackingDialog,
+  })),
+)
 function preloadExportDialog() {
   return importExportDialog()
 }
@@ -289,6 +307,8 @@ const TimelineDialogHost = memo(function TimelineDialogHost() {
   const reverseConformOpen = useReverseConformDialogStore((s) => s.request !== null)
   const silenceRemovalOpen = useSilenceRemovalDialogStore((s) => s.isOpen)
   const fillerRemovalOpen = useFillerRemovalDialogStore((s) => s.isOpen)
+  const autoDuckingOpen = useAutoDuckingDialogStore((s) => s.isOpen)
+  const motionTrackingOpen = useMotionTrackingDialogStore((s) => s.isOpen)
 
   return (
     <>
@@ -312,6 +332,16 @@ const TimelineDialogHost = memo(function TimelineDialogHost() {
           <LazyFillerRemovalDialog />
         </Suspense>
       )}
+      {autoDuckingOpen && (
+        <Suspense fallback={null}>
+          <LazyAutoDuckingDialog />
+        </Suspense>
+      )}
+      {motionTrackingOpen && (
+        <Suspense fallback={null}>
+          <LazyMotionTrackingDialog />
+        </Suspense>
+      )}
     </>
   )
 })
@@ -591,6 +621,22 @@ export const LoadedEditor = memo(function LoadedEditor({
     setBundleExportDialogOpen(true)
   }, [project.name])
 
+  const handleExportInterchange = useCallback(
+    async (format: InterchangeFormat) => {
+      try {
+        const { downloadProjectInterchange } = await importInterchangeService()
+        const warnings = await downloadProjectInterchange(projectId, format)
+        if (warnings.length > 0) {
+          logger.warn(`${format} export could not represent ${warnings.length} item(s)`, warnings)
+        }
+      } catch (error) {
+        logger.error(`Failed to export ${format}:`, error)
+        toast.error(i18n.t('toolbar.interchangeExportFailed'))
+      }
+    },
+    [projectId],
+  )
+
   // Enable keyboard shortcuts
   useEditorHotkeys({
     onSave: handleSave,
@@ -632,6 +678,7 @@ export const LoadedEditor = memo(function LoadedEditor({
           onSave={handleSave}
           onExport={handleExport}
           onExportBundle={handleExportBundle}
+          onExportInterchange={handleExportInterchange}
           onOpenRenderQueue={handleOpenRenderQueue}
           renderQueueCount={renderQueueActiveCount}
         />
@@ -1,5 +1,6 @@
 import { useState, useEffect, useRef, useCallback, memo, useMemo, lazy, Suspense } from 'react'
 import { Columns2 } from 'lucide-react'
+import type { ProjectColorSpace } from '@/types/color'
 import {
   ColorVideoPreview,
   VideoPreview,
@@ -7,6 +8,7 @@ import {
   AlignmentToolbar,
   TimecodeDisplay,
   PreviewZoomControls,
+  MaskPathAnimationControls,
   importSourceMonitor,
   importInlineSourcePreview,
   importInlineCompositionPreview,
@@ -97,6 +99,7 @@ const ProgramPreviewSurface = memo(function ProgramPreviewSurface({
     height: number
     fps: number
     backgroundColor?: string
+    colorSpace?: ProjectColorSpace
   }
   containerSize: {
     width: number
@@ -196,6 +199,7 @@ export const PreviewArea = memo(function PreviewArea({ project }: PreviewAreaPro
   const projectHeight = useProjectStore((s) => s.currentProject?.metadata.height)
   const projectFps = useProjectStore((s) => s.currentProject?.metadata.fps)
   const projectBgColor = useProjectStore((s) => s.currentProject?.metadata.backgroundColor)
+  const colorSpace = useProjectStore((s) => s.currentProject?.metadata.colorSpace)
 
   const width = projectWidth ?? project.width
   const height = projectHeight ?? project.height
@@ -280,8 +284,8 @@ export const PreviewArea = memo(function PreviewArea({ project }: PreviewAreaPro
   }, [editorLayout.previewPadding])
 
   const liveProject = useMemo(
-    () => ({ width, height, fps, backgroundColor }),
-    [width, height, fps, backgroundColor],
+    () => ({ width, height, fps, backgroundColor, colorSpace }),
+    [width, height, fps, backgroundColor, colorSpace],
   )
 
   const sourcePreviewMediaId = useEditorStore((s) => s.sourcePreviewMediaId)
@@ -645,6 +649,7 @@ export const PreviewArea = memo(function PreviewArea({ project }: PreviewAreaPro
                 >
                   Bezier
                 </Button>
+                <MaskPathAnimationControls canvas={{ width, height, fps }} />
                 <Button
                   type="button"
                   size="sm"
@@ -0,0 +1,136 @@
+import { useCallback, useMemo, memo } from 'react'
+import { useTranslation } from 'react-i18next'
+import { Plus, RectangleVertical, Trash2 } from 'lucide-react'
+import { toast } from 'sonner'
+import { Button } from '@/components/ui/button'
+import {
+  DropdownMenu,
+  DropdownMenuContent,
+  DropdownMenuItem,
+  DropdownMenuTrigger,
+} from '@/components/ui/dropdown-menu'
+import type { CanvasVariantAspect, Project } from '@/types/project'
+import {
+  CANVAS_VARIANT_ASPECTS,
+  createCanvasVariant,
+  getCanvasVariantResolution,
+  isBaseCanvasAspect,
+  useProjectStore,
+} from '@/features/editor/deps/projects'
+import { PropertySection } from '../components'
+
+const ASPECT_LABEL_KEYS: Record<CanvasVariantAspect, string> = {
+  '16:9': 'editor.canvasVariants.landscape',
+  '9:16': 'editor.canvasVariants.vertical',
+  '4:5': 'editor.canvasVariants.portrait',
+  '1:1': 'editor.canvasVariants.square',
+}
+
+interface CanvasVariantsSectionProps {
+  project: Project
+}
+
+/**
+ * Linked aspect-ratio deliverables of the project. Variants share the
+ * timeline; per-clip layout is adjusted from the clip panel.
+ */
+export const CanvasVariantsSection = memo(function CanvasVariantsSection({
+  project,
+}: CanvasVariantsSectionProps) {
+  const { t } = useTranslation()
+  const setCanvasVariants = useProjectStore((s) => s.setCanvasVariants)
+  const variants = useMemo(() => project.canvasVariants ?? [], [project.canvasVariants])
+
+  const availableAspects = useMemo(
+    () =>
+      CANVAS_VARIANT_ASPECTS.filter(
+        (aspect) =>
+          !isBaseCanvasAspect(project.metadata, aspect) &&
+          !variants.some((variant) => variant.aspect === aspect),
+      ),
+    [project.metadata, variants],
+  )
+
+  const commitVariants = useCallback(
+    (next: NonNullable<Project['canvasVariants']>) => {
+      setCanvasVariants(project.id, next).catch((error: unknown) => {
+        toast.error(t('editor.canvasVariants.updateFailed'), {
+          description: error instanceof Error ? error.message : t('editor.canvasPanel.tryAgain'),
+        })
+      })
+    },
+    [project.id, setCanvasVariants, t],
+  )
+
+  const handleAdd = useCallback(
+    (aspect: CanvasVariantAspect) => {
+      const name = t(ASPECT_LABEL_KEYS[aspect], { aspect })
+      commitVariants([...variants, createCanvasVariant(aspect, name)])
+    },
+    [commitVariants, t, variants],
+  )
+
+  const handleRemove = useCallback(
+    (variantId: string) => {
+      commitVariants(variants.filter((variant) => variant.id !== variantId))
+    },
+    [commitVariants, variants],
+  )
+
+  return (
+    <PropertySection
+      title={t('editor.canvasVariants.title')}
+      icon={RectangleVertical}
+      defaultOpen={true}
+    >
+      <div className="space-y-1">
+        {variants.map((variant) => {
+          const resolution = getCanvasVariantResolution(project.metadata, variant.aspect)
+          return (
+            <div key={variant.id} className="flex items-center gap-2 min-h-7">
+              <div className="flex-1 min-w-0">
+                <div className="text-xs truncate">{variant.name}</div>
+                <div className="text-[10px] text-muted-foreground tabular-nums">
+                  {resolution.width}×{resolution.height}
+                  {variant.overrides.length > 0 &&
+                    ` · ${t('editor.canvasVariants.overrideCount', {
+                      count: variant.overrides.length,
+                    })}`}
+                </div>
+              </div>
+              <Button
+                variant="ghost"
+                size="icon"
+                className="h-7 w-7 flex-shrink-0"
+                onClick={() => handleRemove(variant.id)}
+                title={t('editor.canvasVariants.remove')}
+              >
+                <Trash2 className="w-3.5 h-3.5" />
+              </Button>
+            </div>
+          )
+        })}
+      </div>
+
+      {availableAspects.length > 0 && (
+        <DropdownMenu>
+          <DropdownMenuTrigger asChild>
+            <Button variant="outline" size="sm" className="w-full h-7 text-xs mt-2">
+              <Plus className="w-3 h-3 mr-1.5" />
+              {t('editor.canvasVariants.add')}
+            </Button>
+          </DropdownMenuTrigger>
+          <DropdownMenuContent align="start">
+            {availableAspects.map((aspect) => (
+              <DropdownMenuItem key={aspect} onClick={() => handleAdd(aspect)}>
+                {t(ASPECT_LABEL_KEYS[aspect], { aspect })}
+              </DropdownMenuItem>
+            ))}
+          </DropdownMenuContent>
+        </DropdownMenu>
+      )}
+
+      <p className="text-[10px] text-muted-foreground mt-2">{t('editor.canvasVariants.hint')}</p>
+    </PropertySection>
+  )
+})
@@ -1,16 +1,29 @@
 import { useCallback, useState, useRef, useEffect, memo } from 'react'
 import { useTranslation } from 'react-i18next'
 import { Button } from '@/components/ui/button'
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select'
 import { Separator } from '@/components/ui/separator'
 import { ArrowLeftRight, RotateCcw, LayoutDashboard, Clock } from 'lucide-react'
 import { useProjectStore } from '@/features/editor/deps/projects'
 import { DEFAULT_PROJECT_HEIGHT, DEFAULT_PROJECT_WIDTH } from '@/shared/projects/defaults'
+import {
+  DEFAULT_PROJECT_COLOR_SPACE,
+  PROJECT_COLOR_SPACES,
+  type ProjectColorSpace,
+} from '@/types/color'
 import { useTimelineStore } from '@/features/editor/deps/timeline-store'
 import { useGizmoStore } from '@/features/editor/deps/preview'
 import { HexColorPicker } from 'react-colorful'
 import { toast } from 'sonner'
 import { PropertySection, PropertyRow, LinkedDimensions } from '../components'
 import { commitProjectMetadataChange } from '@/features/editor/utils/project-metadata-history'
+import { CanvasVariantsSection } from './canvas-variants-section'
 
 /**
  * Isolated color picker using react-colorful.
@@ -117,6 +130,7 @@ export const CanvasPanel = memo(function CanvasPanel() {
   const width = currentProject?.metadata.width ?? DEFAULT_PROJECT_WIDTH
   const height = currentProject?.metadata.height ?? DEFAULT_PROJECT_HEIGHT
   const storedBackgroundColor = currentProject?.metadata.backgroundColor ?? '#000000'
+  const colorSpace = currentProject?.metadata.colorSpace ?? DEFAULT_PROJECT_COLOR_SPACE
 
   const applyProjectMetadataChange = useCallback(
     async (
@@ -209,6 +223,16 @@ export const CanvasPanel = memo(function CanvasPanel() {
     )
   }, [applyProjectMetadataChange, storedBackgroundColor])
 
+  const handleColorSpaceChange = useCallback(
+    (value: string) => {
+      void applyProjectMetadataChange(
+        { colorSpace: value as ProjectColorSpace },
+        { type: 'UPDATE_PROJECT_METADATA', payload: { fields: ['colorSpace'] } },
+      )
+    },
+    [applyProjectMetadataChange],
+  )
+
   // Format duration as MM:SS.FF
   const formatDuration = (frames: number): string => {
     const totalSeconds = frames / fps
@@ -287,10 +311,31 @@ export const CanvasPanel = memo(function CanvasPanel() {
             </Button>
           </div>
         </PropertyRow>
+
+        {/* Working colour space: SDR Rec.709 or HDR Rec.2100 (PQ / HLG) */}
+        <PropertyRow label={t('editor.canvasPanel.colorSpace')}>
+          <Select value={colorSpace} onValueChange={handleColorSpaceChange}>
+            <SelectTrigger className="h-7 text-xs flex-1 min-w-0">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {PROJECT_COLOR_SPACES.map((space) => (
+                <SelectItem key={space} value={space} className="text-xs">
+                  {t(`editor.canvasPanel.colorSpaces.${space}`)}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        </PropertyRow>
       </PropertySection>
 
       <Separator />
 
+      {/* Linked aspect-ratio variants */}
+      <CanvasVariantsSection project={currentProject} />
+
+      <Separator />
+
       {/* Duration Section */}
       <PropertySection title={t('editor.canvasPanel.duration')} icon={Clock} defaultOpen={true}>
         <PropertyRow label={t('editor.canvasPanel.duration')}>
@@ -0,0 +1,274 @@
+import { useCallback, useMemo, useState, memo } from 'react'
+import { useTranslation } from 'react-i18next'
+import { AlignCenter, AlignLeft, AlignRight, RectangleVertical, RotateCcw } from 'lucide-react'
+import { toast } from 'sonner'
+import { Button } from '@/components/ui/button'
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select'
+import type { TimelineItem } from '@/types/timeline'
+import type { CanvasSettings, CropSettings } from '@/types/transform'
+import type { TextHorizontalAlign } from '@/types/text'
+import type { ProjectCanvasVariant, ProjectCanvasVariantOverride } from '@/types/project'
+import {
+  clearCanvasVariantOverride,
+  resolveCanvasVariant,
+  setCanvasVariantOverride,
+  useProjectStore,
+} from '@/features/editor/deps/projects'
+import { useKeyframesStore } from '@/features/editor/deps/timeline-store'
+import { useThrottledFrame } from '@/features/editor/deps/preview'
+import { resolveTransform, getSourceDimensions } from '@/features/editor/deps/composition-runtime'
+import { resolveAnimatedTransform } from '@/features/editor/deps/keyframes'
+import { PropertySection, PropertyRow, NumberInput } from '../components'
+
+type CropEdge = 'left' | 'right' | 'top' | 'bottom'
+
+const CROP_EDGES: CropEdge[] = ['left', 'right', 'top', 'bottom']
+
+const TEXT_ALIGN_OPTIONS: Array<{
+  value: TextHorizontalAlign
+  icon: typeof AlignLeft
+  labelKey: string
+}> = [
+  { value: 'left', icon: AlignLeft, labelKey: 'editor.textSection.alignLeft' },
+  { value: 'center', icon: AlignCenter, labelKey: 'editor.textSection.alignCenter' },
+  { value: 'right', icon: AlignRight, labelKey: 'editor.textSection.alignRight' },
+]
+
+interface CanvasVariantSectionProps {
+  item: TimelineItem
+  canvas: CanvasSettings
+  variants: ProjectCanvasVariant[]
+}
+
+/**
+ * Layout of the selected clip in one of the project's canvas variants. Shows
+ * the auto layout until a value is changed; changed values are stored as
+ * overrides on the variant and leave the base timeline untouched.
+ */
+export const CanvasVariantSection = memo(function CanvasVariantSection({
+  item,
+  canvas,
+  variants,
+}: CanvasVariantSectionProps) {
+  const { t } = useTranslation()
+  const projectId = useProjectStore((s) => s.currentProject?.id)
+  const setCanvasVariants = useProjectStore((s) => s.setCanvasVariants)
+  const itemKeyframes = useKeyframesStore((s) => s.keyframesByItemId[item.id])
+  const currentFrame = useThrottledFrame()
+  const [selectedVariantId, setSelectedVariantId] = useState<string>()
+
+  const variant = variants.find((entry) => entry.id === selectedVariantId) ?? variants[0]!
+  const override = variant.overrides.find((entry) => entry.itemId === item.id)
+
+  const resolved = useMemo(
+    () =>
+      resolveCanvasVariant(
+        { items: [item], keyframes: itemKeyframes ? [itemKeyframes] : [] },
+        canvas,
+        variant,
+      ),
+    [canvas, item, itemKeyframes, variant],
+  )
+  const variantItem = resolved.items[0]!
+  const transform = useMemo(() => {
+    const base = resolveTransform(
+      variantItem,
+      resolved.resolution,
+      getSourceDimensions(variantItem),
+    )
+    return resolveAnimatedTransform(base, resolved.keyframes[0], currentFrame - item.from)
+  }, [currentFrame, item.from, resolved, variantItem])
+
+  const commitVariant = useCallback(
+    (next: ProjectCanvasVariant) => {
+      if (!projectId) return
+      const nextVariants = variants.map((entry) => (entry.id === next.id ? next : entry))
+      setCanvasVariants(projectId, nextVariants).catch((error: unknown) => {
+        toast.error(t('editor.canvasVariants.updateFailed'), {
+          description: error instanceof Error ? error.message : t('editor.canvasPanel.tryAgain'),
+        })
+      })
+    },
+    [projectId, setCanvasVariants, t, variants],
+  )
+
+  const commitOverride = useCallback(
+    (patch: Omit<ProjectCanvasVariantOverride, 'itemId'>) => {
+      commitVariant(setCanvasVariantOverride(variant, item.id, patch))
+    },
+    [commitVariant, item.id, variant],
+  )
+
+  const handleTransformChange = useCallback(
+    (property: 'x' | 'y' | 'width' | 'height' | 'rotation', value: number) => {
+      commitOverride({ transform: { [property]: value } })
+    },
+    [commitOverride],
+  )
+
+  const handleCropChange = useCallback(
+    (edge: CropEdge, percent: number) => {
+      const crop: CropSettings = { ...variantItem.crop, [edge]: Math.max(0, percent) / 100 }
+      commitOverride({ crop })
+    },
+    [commitOverride, variantItem.crop],
+  )
+
+  const handleReset = useCallback(() => {
+    commitVariant(clearCanvasVariantOverride(variant, item.id))
+  }, [commitVariant, item.id, variant])
+
+  const hasCrop = item.type === 'video' || item.type === 'image' || item.type === 'composition'
+  const textAlign = variantItem.type === 'text' ? (variantItem.textAlign ?? 'center') : undefined
+  const fontSize = variantItem.type === 'text' ? variantItem.fontSize : undefined
+
+  return (
+    <PropertySection
+      title={t('editor.canvasVariants.clipTitle')}
+      icon={RectangleVertical}
+      defaultOpen={false}
+    >
+      <PropertyRow label={t('editor.canvasVariants.variant')}>
+        <div className="flex items-center gap-1 w-full">
+          <Select value={variant.id} onValueChange={setSelectedVariantId}>
+            <SelectTrigger className="h-7 text-xs flex-1 min-w-0">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {variants.map((entry) => (
+                <SelectItem key={entry.id} value={entry.id} className="text-xs">
+                  {entry.name}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          <Button
+            variant="ghost"
+            size="icon"
+            className="h-7 w-7 flex-shrink-0"
+            onClick={handleReset}
+            disabled={!override}
+            title={t('editor.canvasVariants.resetLayout')}
+          >
+            <RotateCcw className="w-3.5 h-3.5" />
+          </Button>
+        </div>
+      </PropertyRow>
+
+      <PropertyRow label={t('editor.layoutSection.position')}>
+        <div className="grid grid-cols-2 gap-1 w-full">
+          <NumberInput
+            value={Math.round(transform.x)}
+            onChange={(value) => handleTransformChange('x', value)}
+            label="X"
+            unit="px"
+            step={1}
+          />
+          <NumberInput
+            value={Math.round(transform.y)}
+            onChange={(value) => handleTransformChange('y', value)}
+            label="Y"
+            unit="px"
+            step={1}
+          />
+        </div>
+      </PropertyRow>
+
+      <PropertyRow label={t('editor.layoutSection.size')}>
+        <div className="grid grid-cols-2 gap-1 w-full">
+          <NumberInput
+            value={Math.round(transform.width)}
+            onChange={(value) => handleTransformChange('width', value)}
+            label="W"
+            unit="px"
+            min={1}
+            max={7680}
+            step={1}
+          />
+          <NumberInput
+            value={Math.round(transform.height)}
+            onChange={(value) => handleTransformChange('height', value)}
+            label="H"
+            unit="px"
+            min={1}
+            max={7680}
+            step={1}
+          />
+        </div>
+      </PropertyRow>
+
+      <PropertyRow label={t('editor.layoutSection.rotation')}>
+        <NumberInput
+          value={Math.round(transform.rotation * 10) / 10}
+          onChange={(value) => handleTransformChange('rotation', value)}
+          unit="°"
+          min={-360}
+          max={360}
+          step={0.1}
+          className="flex-1"
+        />
+      </PropertyRow>
+
+      {hasCrop && (
+        <PropertyRow label={t('editor.canvasVariants.crop')}>
+          <div className="grid grid-cols-2 gap-1 w-full">
+            {CROP_EDGES.map((edge) => (
+              <NumberInput
+                key={edge}
+                value={Math.round((variantItem.crop?.[edge] ?? 0) * 1000) / 10}
+                onChange={(value) => handleCropChange(edge, value)}
+                label={edge[0]!.toUpperCase()}
+                unit="%"
+                min={0}
+                max={100}
+                step={0.1}
+              />
+            ))}
+          </div>
+        </PropertyRow>
+      )}
+
+      {variantItem.type === 'text' && (
+        <>
+          <PropertyRow label={t('editor.textSection.size')}>
+            <NumberInput
+              value={Math.round(fontSize ?? 60)}
+              onChange={(value) => commitOverride({ text: { fontSize: value } })}
+              unit="px"
+              min={1}
+              max={1000}
+              step={1}
+              className="flex-1"
+            />
+          </PropertyRow>
+          <PropertyRow label={t('editor.textSection.align')}>
+            <div className="flex gap-1">
+              {TEXT_ALIGN_OPTIONS.map(({ value, icon: Icon, labelKey }) => (
+                <Button
+                  key={value}
+                  variant={textAlign === value ? 'secondary' : 'ghost'}
+                  size="icon"
+                  className="h-7 w-7"
+                  onClick={() => commitOverride({ text: { textAlign: value } })}
+                  title={t(labelKey)}
+                >
+                  <Icon className="w-3.5 h-3.5" />
+                </Button>
+              ))}
+            </div>
+          </PropertyRow>
+        </>
+      )}
+
+      <p className="text-[10px] text-muted-foreground pt-1">
+        {t('editor.canvasVariants.clipHint')}
+      </p>
+    </PropertySection>
+  )
+})
@@ -5,10 +5,12 @@ import { Separator } from '@/components/ui/separator'
 import { cn } from '@/shared/ui/cn'
 import { useTimelineStore } from '@/features/editor/deps/timeline-store'
 import type { SubtitleSegmentItem, TextItem } from '@/types/timeline'
+import type { CaptionKaraokeStyle } from '@/types/text'
 
 import { ColorPicker, PropertyRow, SliderInput } from '../components'
 import {
   CAPTION_STYLE_PRESETS,
+  DEFAULT_CAPTION_KARAOKE_STYLE,
   type CaptionStylePreset,
   detectActiveCaptionPreset,
   resolveCaptionStylePatch,
@@ -32,8 +34,9 @@ interface CaptionStyleControlsProps {
  *
  * Surfaces the typography subset shared between {@link TextItem} (captions)
  * and {@link SubtitleSegmentItem}: presets, color, font size, vertical
- * position, and a background-box toggle. Designed to be embedded inside a
- * larger section (e.g. {@link SubtitleSection}).
+ * position, and a background-box toggle. Subtitle segments also get the
+ * word-level karaoke settings. Designed to be embedded inside a larger
+ * section (e.g. {@link SubtitleSection}).
  */
 export const CaptionStyleControls = memo(function CaptionStyleControls({
   items,
@@ -62,7 +65,9 @@ export const CaptionStyleControls = memo(function CaptionStyleControls({
       // first-selected item's existing transform so we preserve x/rotation.
       const baseTransform = items[0]?.transform
       const resolved = resolveCaptionStylePatch(preset, canvasWidth, canvasHeight, baseTransform)
-      applyPatch(resolved as Partial<CaptionStylableItem>)
+      // Karaoke only applies to subtitle segments; presets without it turn it off.
+      const karaokePatch = items[0]?.type === 'subtitle' ? { karaoke: preset.karaoke } : {}
+      applyPatch({ ...resolved, ...karaokePatch } as Partial<CaptionStylableItem>)
     },
     [applyPatch, canvasHeight, canvasWidth, items],
   )
@@ -81,6 +86,11 @@ export const CaptionStyleControls = memo(function CaptionStyleControls({
   const verticalY = Math.round(sample.transform?.y ?? 0)
   const hasBackground = !!sample.backgroundColor
   const verticalRange = Math.max(1, Math.round(canvasHeight / 2))
+  const karaoke = sample.type === 'subtitle' ? sample.karaoke : undefined
+
+  const updateKaraoke = (patch: Partial<CaptionKaraokeStyle>) => {
+    applyPatch({ karaoke: { ...karaoke, ...patch } } as Partial<CaptionStylableItem>)
+  }
 
   const updateVerticalPosition = (value: number) => {
     applyPatch({
@@ -162,26 +172,14 @@ export const CaptionStyleControls = memo(function CaptionStyleControls({
       </PropertyRow>
 
       <PropertyRow label={t('editor.captionStyleControls.background')}>
-        <div className="flex flex-1 min-w-0">
-          <button
-            type="button"
-            onClick={() =>
-              applyPatch({
-                backgroundColor: hasBackground ? undefined : 'rgba(0, 0, 0, 0.55)',
-              })
-            }
-            className={cn(
-              'h-7 w-full rounded border text-xs transition-colors',
-              hasBackground
-                ? 'border-primary bg-primary/15'
-                : 'border-border hover:bg-secondary/40 text-muted-foreground',
-            )}
-          >
-            {hasBackground
-              ? t('editor.captionStyleControls.on')
-              : t('editor.captionStyleControls.off')}
-          </button>
-        </div>
+        <OnOffButton
+          on={hasBackground}
+          onToggle={() =>
+            applyPatch({
+              backgroundColor: hasBackground ? undefined : 'rgba(0, 0, 0, 0.55)',
+            })
+          }
+        />
       </PropertyRow>
 
       {hasBackground && (
@@ -198,6 +196,115 @@ export const CaptionStyleControls = memo(function CaptionStyleControls({
           />
         </PropertyRow>
       )}
+
+      {sample.type === 'subtitle' && (
+        <>
+          <Separator className="my-1" />
+
+          <PropertyRow label={t('editor.captionStyleControls.karaoke')}>
+            <OnOffButton
+              on={!!karaoke}
+              onToggle={() =>
+                applyPatch({
+                  karaoke: karaoke ? undefined : { ...DEFAULT_CAPTION_KARAOKE_STYLE },
+                } as Partial<CaptionStylableItem>)
+              }
+            />
+          </PropertyRow>
+
+          {karaoke && (
+            <>
+              <ColorPicker
+                label={t('editor.captionStyleControls.activeWordColor')}
+                color={karaoke.activeColor ?? sampleColor}
+                onChange={(activeColor) => updateKaraoke({ activeColor })}
+                onLiveChange={(activeColor) => updateKaraoke({ activeColor })}
+                onReset={() =>
+                  updateKaraoke({ activeColor: DEFAULT_CAPTION_KARAOKE_STYLE.activeColor })
+                }
+                defaultColor={DEFAULT_CAPTION_KARAOKE_STYLE.activeColor}
+              />
+
+              <PropertyRow label={t('editor.captionStyleControls.activeWordHighlight')}>
+                <OnOffButton
+                  on={!!karaoke.activeBackgroundColor}
+                  onToggle={() =>
+                    updateKaraoke({
+                      activeBackgroundColor: karaoke.activeBackgroundColor ? undefined : '#FFD400',
+                    })
+                  }
+                />
+              </PropertyRow>
+
+              {karaoke.activeBackgroundColor && (
+                <ColorPicker
+                  label={t('editor.captionStyleControls.highlightColor')}
+                  color={karaoke.activeBackgroundColor}
+                  onChange={(activeBackgroundColor) => updateKaraoke({ activeBackgroundColor })}
+                  onLiveChange={(activeBackgroundColor) => updateKaraoke({ activeBackgroundColor })}
+                  onReset={() => updateKaraoke({ activeBackgroundColor: '#FFD400' })}
+                  defaultColor="#FFD400"
+                />
+              )}
+
+              <PropertyRow label={t('editor.captionStyleControls.activeWordScale')}>
+                <SliderInput
+                  value={Math.round((karaoke.activeScale ?? 1) * 100)}
+                  onChange={(percent) => updateKaraoke({ activeScale: percent / 100 })}
+                  onLiveChange={(percent) => updateKaraoke({ activeScale: percent / 100 })}
+                  min={100}
+                  max={150}
+                  step={1}
+                  unit="%"
+                  className="flex-1 min-w-0"
+                />
+              </PropertyRow>
+
+              <PropertyRow label={t('editor.captionStyleControls.wordsPerPage')}>
+                <SliderInput
+                  value={karaoke.wordsPerPage ?? 0}
+                  onChange={(wordsPerPage) => updateKaraoke({ wordsPerPage })}
+                  onLiveChange={(wordsPerPage) => updateKaraoke({ wordsPerPage })}
+                  formatValue={(value) =>
+                    value > 0 ? String(value) : t('editor.captionStyleControls.wholeCue')
+                  }
+                  min={0}
+                  max={10}
+                  step={1}
+                  className="flex-1 min-w-0"
+                />
+              </PropertyRow>
+
+              <PropertyRow label={t('editor.captionStyleControls.popIn')}>
+                <OnOffButton
+                  on={!!karaoke.popIn}
+                  onToggle={() => updateKaraoke({ popIn: !karaoke.popIn })}
+                />
+              </PropertyRow>
+            </>
+          )}
+        </>
+      )}
     </div>
   )
 })
+
+function OnOffButton({ on, onToggle }: { on: boolean; onToggle: () => void }) {
+  const { t } = useTranslation()
+  return (
+    <div className="flex flex-1 min-w-0">
+      <button
+        type="button"
+        onClick={onToggle}
+        className={cn(
+          'h-7 w-full rounded border text-xs transition-colors',
+          on
+            ? 'border-primary bg-primary/15'
+            : 'border-border hover:bg-secondary/40 text-muted-foreground',
+        )}
+      >
+        {on ? t('editor.captionStyleControls.on') : t('editor.captionStyleControls.off')}
+      </button>
+    </div>
+  )
+}
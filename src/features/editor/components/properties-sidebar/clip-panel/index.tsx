@@ -25,6 +25,7 @@ import { VideoSection } from './video-section'
 import { GifSection } from './gif-section'
 import { ShapeSection } from './shape-section'
 import { CornerPinSection } from './corner-pin-section'
+import { CanvasVariantSection } from './canvas-variant-section'
 
 const LazyAudioSection = lazy(() =>
   import('./audio-section').then((module) => ({ default: module.AudioSection })),
@@ -111,6 +112,7 @@ export const ClipPanel = memo(function ClipPanel() {
     (s) => s.currentProject?.metadata.height ?? DEFAULT_PROJECT_HEIGHT,
   )
   const projectFps = useProjectStore((s) => s.currentProject?.metadata.fps ?? DEFAULT_PROJECT_FPS)
+  const canvasVariants = useProjectStore((s) => s.currentProject?.canvasVariants)
   const selectedItems = useItemsStore(
     useShallow(
       useCallback(
@@ -176,6 +178,12 @@ export const ClipPanel = memo(function ClipPanel() {
     [selectedItems],
   )
 
+  // Variant layout is edited one clip at a time
+  const canvasVariantItem =
+    canvasVariants && canvasVariants.length > 0 && layoutFillItems.length === 1
+      ? layoutFillItems[0]
+      : undefined
+
   const visualItems = useMemo(
     () => selectedItems.filter((item: TimelineItem) => item.type !== 'audio'),
     [selectedItems],
@@ -289,6 +297,13 @@ export const ClipPanel = memo(function ClipPanel() {
                 />
               )}
               {showVideoTab && <CornerPinSection items={layoutFillItems} />}
+              {canvasVariantItem && canvasVariants && (
+                <CanvasVariantSection
+                  item={canvasVariantItem}
+                  canvas={canvas}
+                  variants={canvasVariants}
+                />
+              )}
               {hasTextItems && (
                 <Suspense fallback={null}>
                   <LazyTextContentSection items={selectedItems} canvas={canvas} />
@@ -46,6 +46,9 @@ const DIRECTION_OPTIONS: {
   { value: 'right', labelKey: 'editor.shapeSection.directionRight', icon: ChevronRight },
 ]
 
+// Select value for masks without a subject matte (Radix disallows empty values)
+const MATTE_SOURCE_NONE = '__none__'
+
 interface ShapeSectionProps {
   items: TimelineItem[]
 }
@@ -56,6 +59,7 @@ interface ShapeSectionProps {
 export function ShapeSection({ items }: ShapeSectionProps) {
   const { t } = useTranslation()
   const updateItem = useTimelineStore((s) => s.updateItem)
+  const timelineItems = useTimelineStore((s) => s.items)
   const { isEditing, editingItemId, penMode, startEditing, stopEditing } = useMaskEditorStore()
 
   // Gizmo store for live property preview
@@ -68,6 +72,12 @@ export function ShapeSection({ items }: ShapeSectionProps) {
     [items],
   )
 
+  // Video clips whose subject matte can refine the mask
+  const matteSourceItems = useMemo(
+    () => timelineItems.filter((item) => item.type === 'video' && !!item.mediaId),
+    [timelineItems],
+  )
+
   // Memoize item IDs for stable callback dependencies
   const itemIds = useMemo(() => shapeItems.map((item) => item.id), [shapeItems])
 
@@ -111,9 +121,20 @@ export function ShapeSection({ items }: ShapeSectionProps) {
       maskFeather: shapeItems.every((i) => (i.maskFeather ?? 10) === (first.maskFeather ?? 10))
         ? (first.maskFeather ?? 10)
         : ('mixed' as const),
+      maskExpansion: shapeItems.every((i) => (i.maskExpansion ?? 0) === (first.maskExpansion ?? 0))
+        ? (first.maskExpansion ?? 0)
+        : ('mixed' as const),
+      maskMotionBlur: shapeItems.every(
+        (i) => (i.maskMotionBlur ?? 0) === (first.maskMotionBlur ?? 0),
+      )
+        ? (first.maskMotionBlur ?? 0)
+        : ('mixed' as const),
       maskInvert: shapeItems.every((i) => (i.maskInvert ?? false) === (first.maskInvert ?? false))
         ? (first.maskInvert ?? false)
         : ('mixed' as const),
+      maskMatteItemId: shapeItems.every((i) => i.maskMatteItemId === first.maskMatteItemId)
+        ? (first.maskMatteItemId ?? MATTE_SOURCE_NONE)
+        : undefined,
     }
   }, [shapeItems])
 
@@ -297,6 +318,9 @@ export function ShapeSection({ items }: ShapeSectionProps) {
         maskType: checked ? 'clip' : undefined,
         maskFeather: checked ? 0 : undefined,
         maskInvert: checked ? false : undefined,
+        maskExpansion: undefined,
+        maskMotionBlur: undefined,
+        maskMatteItemId: undefined,
       })
     },
     [updateShapeItems],
@@ -343,6 +367,45 @@ export function ShapeSection({ items }: ShapeSectionProps) {
     updateShapeItems({ maskFeather: 10 })
   }, [updateShapeItems])
 
+  // Mask expansion and motion blur handlers with live preview
+  const handleMaskExpansionLiveChange = useCallback(
+    (value: number) => {
+      const previews: Record<string, { maskExpansion: number }> = {}
+      itemIds.forEach((id) => {
+        previews[id] = { maskExpansion: value }
+      })
+      setPropertiesPreviewNew(previews)
+    },
+    [itemIds, setPropertiesPreviewNew],
+  )
+
+  const handleMaskExpansionChange = useCallback(
+    (value: number) => {
+      updateShapeItems({ maskExpansion: value === 0 ? undefined : value })
+      queueMicrotask(() => clearPreview())
+    },
+    [updateShapeItems, clearPreview],
+  )
+
+  const handleMaskMotionBlurLiveChange = useCallback(
+    (value: number) => {
+      const previews: Record<string, { maskMotionBlur: number }> = {}
+      itemIds.forEach((id) => {
+        previews[id] = { maskMotionBlur: value }
+      })
+      setPropertiesPreviewNew(previews)
+    },
+    [itemIds, setPropertiesPreviewNew],
+  )
+
+  const handleMaskMotionBlurChange = useCallback(
+    (value: number) => {
+      updateShapeItems({ maskMotionBlur: value === 0 ? undefined : value })
+      queueMicrotask(() => clearPreview())
+    },
+    [updateShapeItems, clearPreview],
+  )
+
   // Mask invert handler
   const handleMaskInvertChange = useCallback(
     (checked: boolean) => {
@@ -351,6 +414,14 @@ export function ShapeSection({ items }: ShapeSectionProps) {
     [updateShapeItems],
   )
 
+  // Mask matte source handler
+  const handleMaskMatteSourceChange = useCallback(
+    (value: string) => {
+      updateShapeItems({ maskMatteItemId: value === MATTE_SOURCE_NONE ? undefined : value })
+    },
+    [updateShapeItems],
+  )
+
   if (shapeItems.length === 0 || !sharedValues) {
     return null
   }
@@ -583,6 +654,34 @@ export function ShapeSection({ items }: ShapeSectionProps) {
             </PropertyRow>
           )}
 
+          {/* Expansion - grow (positive) or shrink (negative) the mask edge */}
+          <PropertyRow label={t('editor.shapeSection.expansion')}>
+            <SliderInput
+              value={sharedValues.maskExpansion}
+              onChange={handleMaskExpansionChange}
+              onLiveChange={handleMaskExpansionLiveChange}
+              min={-100}
+              max={100}
+              step={1}
+              unit="px"
+              className="flex-1 min-w-0"
+            />
+          </PropertyRow>
+
+          {/* Motion blur - shutter angle for blurring the mask while it moves */}
+          <PropertyRow label={t('editor.shapeSection.motionBlur')}>
+            <SliderInput
+              value={sharedValues.maskMotionBlur}
+              onChange={handleMaskMotionBlurChange}
+              onLiveChange={handleMaskMotionBlurLiveChange}
+              min={0}
+              max={360}
+              step={1}
+              unit="°"
+              className="flex-1 min-w-0"
+            />
+          </PropertyRow>
+
           {/* Invert Mask */}
           <PropertyRow label={t('editor.shapeSection.invert')}>
             <Button
@@ -599,6 +698,32 @@ export function ShapeSection({ items }: ShapeSectionProps) {
                   : t('editor.shapeSection.off')}
             </Button>
           </PropertyRow>
+
+          {/* Subject matte - intersect the mask with a clip's analyzed subject */}
+          <PropertyRow label={t('editor.shapeSection.subjectMatte')}>
+            <Select
+              value={sharedValues.maskMatteItemId}
+              onValueChange={handleMaskMatteSourceChange}
+              disabled={sharedValues.isMask !== true}
+            >
+              <SelectTrigger
+                className="h-7 text-xs flex-1 min-w-0"
+                title={t('editor.shapeSection.subjectMatteHint')}
+              >
+                <SelectValue placeholder={t('editor.shapeSection.mixed')} />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value={MATTE_SOURCE_NONE} className="text-xs">
+                  {t('editor.shapeSection.subjectMatteNone')}
+                </SelectItem>
+                {matteSourceItems.map((item) => (
+                  <SelectItem key={item.id} value={item.id} className="text-xs">
+                    {item.label}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </PropertyRow>
         </>
       )}
     </PropertySection>
@@ -0,0 +1,110 @@
+import { memo, useCallback, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { Download } from 'lucide-react'
+
+import { Button } from '@/components/ui/button'
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select'
+import { useTimelineStore } from '@/features/editor/deps/timeline-store'
+import {
+  SUBTITLE_EXPORT_FORMATS,
+  serializeSubtitleFile,
+  type SubtitleExportFormat,
+} from '@/shared/utils/subtitle-files'
+import type { SubtitleSegmentItem } from '@/types/timeline'
+
+import {
+  buildSubtitleExportDocument,
+  getSubtitleExportFileName,
+} from '@/features/editor/utils/subtitle-file-export'
+import { PropertyRow } from '../components'
+
+interface SubtitleFileExportControlsProps {
+  segment: SubtitleSegmentItem
+  canvasWidth: number
+  canvasHeight: number
+}
+
+/**
+ * Write one segment out as a subtitle file in timeline time, listing the
+ * styling the chosen format could not carry.
+ */
+export const SubtitleFileExportControls = memo(function SubtitleFileExportControls({
+  segment,
+  canvasWidth,
+  canvasHeight,
+}: SubtitleFileExportControlsProps) {
+  const { t } = useTranslation()
+  const fps = useTimelineStore((s) => s.fps)
+  const [format, setFormat] = useState<SubtitleExportFormat>('srt')
+  const [warnings, setWarnings] = useState<string[]>([])
+
+  const handleExport = useCallback(() => {
+    const entry = SUBTITLE_EXPORT_FORMATS.find((candidate) => candidate.format === format)
+    if (!entry) return
+    const exportDocument = buildSubtitleExportDocument(segment, fps, {
+      frameWidth: canvasWidth,
+      frameHeight: canvasHeight,
+    })
+    const result = serializeSubtitleFile(exportDocument, format)
+    setWarnings(result.warnings)
+
+    const url = URL.createObjectURL(new Blob([result.text], { type: entry.mimeType }))
+    const anchor = document.createElement('a')
+    anchor.href = url
+    anchor.download = getSubtitleExportFileName(segment, entry.extension)
+    anchor.click()
+    URL.revokeObjectURL(url)
+  }, [canvasHeight, canvasWidth, format, fps, segment])
+
+  const handleFormatChange = useCallback((value: string) => {
+    setFormat(value as SubtitleExportFormat)
+    setWarnings([])
+  }, [])
+
+  return (
+    <div className="space-y-1.5 rounded border border-border bg-muted/20 px-2 py-1.5">
+      <PropertyRow label={t('editor.subtitleSection.exportFormat')}>
+        <Select value={format} onValueChange={handleFormatChange}>
+          <SelectTrigger className="h-7 text-xs flex-1 min-w-0">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            {SUBTITLE_EXPORT_FORMATS.map((entry) => (
+              <SelectItem key={entry.format} value={entry.format} className="text-xs">
+                {entry.label}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </PropertyRow>
+
+      <Button
+        variant="secondary"
+        size="sm"
+        className="h-7 w-full text-xs"
+        disabled={segment.cues.length === 0}
+        onClick={handleExport}
+      >
+        <Download className="mr-1.5 h-3.5 w-3.5" />
+        {t('editor.subtitleSection.exportFile')}
+      </Button>
+
+      {warnings.length > 0 && (
+        <div className="space-y-0.5 text-[11px] text-muted-foreground">
+          <p>{t('editor.subtitleSection.exportNotes')}</p>
+          <ul className="list-disc pl-4">
+            {warnings.map((warning) => (
+              <li key={warning}>{warning}</li>
+            ))}
+          </ul>
+        </div>
+      )}
+    </div>
+  )
+})
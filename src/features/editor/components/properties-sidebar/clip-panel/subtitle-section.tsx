@@ -16,17 +16,21 @@ import {
   toggleCueFormat,
   type CueFormatFlags,
 } from '@/shared/utils/subtitle-cue-format'
+import { getSubtitleLanguage } from '@/shared/utils/subtitle-languages'
 import { cn } from '@/shared/ui/cn'
 import type {
   AudioItem,
   SubtitleSegmentItem,
+  SubtitleSpeakerStyle,
   TextItem,
   TimelineItem,
   VideoItem,
 } from '@/types/timeline'
 
 import { CaptionStyleControls } from './caption-style-controls'
-import { PropertySection } from '../components'
+import { SubtitleFileExportControls } from './subtitle-file-export-controls'
+import { SubtitleTranslateControls } from './subtitle-translate-controls'
+import { ColorPicker, PropertySection } from '../components'
 
 interface SubtitleSectionProps {
   items: TimelineItem[]
@@ -204,6 +208,26 @@ const VirtualTranscriptSubtitleEditor = memo(function VirtualTranscriptSubtitleE
     [clips, updateItem],
   )
 
+  const updateSpeakerStyle = useCallback(
+    (speakerId: string, patch: Partial<SubtitleSpeakerStyle>) => {
+      for (const clip of clips) {
+        const current = clip.transcriptCaptions.speakerStyles?.[speakerId]
+        if (!current) continue
+        updateItem(clip.id, {
+          transcriptCaptions: {
+            ...clip.transcriptCaptions,
+            speakerStyles: {
+              ...clip.transcriptCaptions.speakerStyles,
+              [speakerId]: { ...current, ...patch },
+            },
+            updatedAt: Date.now(),
+          },
+        } as Partial<TimelineItem>)
+      }
+    },
+    [clips, updateItem],
+  )
+
   const updateCue = useCallback(
     (cueId: string, patch: Partial<{ text: string; startSeconds: number; endSeconds: number }>) => {
       const next = firstClip.transcriptCaptions.cues.map((cue) =>
@@ -259,6 +283,11 @@ const VirtualTranscriptSubtitleEditor = memo(function VirtualTranscriptSubtitleE
             onApplyPatch={applyStylePatch}
           />
 
+          <SpeakerStyleControls
+            speakerStyles={firstClip.transcriptCaptions.speakerStyles}
+            onChange={updateSpeakerStyle}
+          />
+
           <p className="text-xs text-muted-foreground">
             {i18n.t('editor.subtitleSection.multiSelectHint', {
               segments: clips.length,
@@ -304,6 +333,11 @@ const VirtualTranscriptSubtitleEditor = memo(function VirtualTranscriptSubtitleE
           onApplyPatch={applyStylePatch}
         />
 
+        <SpeakerStyleControls
+          speakerStyles={firstClip.transcriptCaptions.speakerStyles}
+          onChange={updateSpeakerStyle}
+        />
+
         <VirtualCueList
           cues={firstClip.transcriptCaptions.cues}
           onChange={updateCue}
@@ -347,6 +381,17 @@ const SingleSubtitleSegmentEditor = memo(function SingleSubtitleSegmentEditor({
     [segment.id, updateItem],
   )
 
+  const updateSpeakerStyle = useCallback(
+    (speakerId: string, patch: Partial<SubtitleSpeakerStyle>) => {
+      const current = segment.speakerStyles?.[speakerId]
+      if (!current) return
+      updateItem(segment.id, {
+        speakerStyles: { ...segment.speakerStyles, [speakerId]: { ...current, ...patch } },
+      })
+    },
+    [segment.id, segment.speakerStyles, updateItem],
+  )
+
   const seekToCue = useCallback(
     (startSeconds: number) => {
       const targetFrame = segment.from + Math.round(startSeconds * fps)
@@ -362,7 +407,12 @@ const SingleSubtitleSegmentEditor = memo(function SingleSubtitleSegmentEditor({
         i18n.t('editor.subtitleSection.trackLabel', { number: segment.source.trackNumber }))
       : segment.source.type === 'subtitle-import'
         ? segment.source.fileName
-        : i18n.t('editor.subtitleSection.transcript')
+        : segment.source.type === 'translation'
+          ? i18n.t('editor.subtitleSection.translation', {
+              language:
+                getSubtitleLanguage(segment.source.language)?.name ?? segment.source.language,
+            })
+          : i18n.t('editor.subtitleSection.transcript')
 
   // Memoize the items array passed to CaptionStyleControls so identity is
   // stable across re-renders that don't actually change the segment object.
@@ -386,12 +436,76 @@ const SingleSubtitleSegmentEditor = memo(function SingleSubtitleSegmentEditor({
           canvasHeight={canvasHeight}
         />
 
+        <SpeakerStyleControls speakerStyles={segment.speakerStyles} onChange={updateSpeakerStyle} />
+
+        <SubtitleTranslateControls segment={segment} />
+
+        <SubtitleFileExportControls
+          segment={segment}
+          canvasWidth={canvasWidth}
+          canvasHeight={canvasHeight}
+        />
+
         <VirtualCueList cues={segment.cues} onChange={updateCue} onSeek={seekToCue} />
       </div>
     </PropertySection>
   )
 })
 
+interface SpeakerStyleControlsProps {
+  speakerStyles: Readonly<Record<string, SubtitleSpeakerStyle>> | undefined
+  onChange: (speakerId: string, patch: Partial<SubtitleSpeakerStyle>) => void
+}
+
+/**
+ * Per-speaker overrides for diarized captions: each voice gets its own color
+ * plus bold/italic toggles layered over the shared caption style. Hidden
+ * until the transcript has been split into speakers.
+ */
+const SpeakerStyleControls = memo(function SpeakerStyleControls({
+  speakerStyles,
+  onChange,
+}: SpeakerStyleControlsProps) {
+  const entries = Object.entries(speakerStyles ?? {})
+  if (entries.length === 0) return null
+
+  return (
+    <div className="space-y-1.5 rounded border border-border bg-muted/20 px-2 py-1.5">
+      <p className="text-xs font-medium">{i18n.t('editor.subtitleSection.speakers')}</p>
+      {entries.map(([speakerId, style]) => {
+        const bold = style.fontWeight === 'bold'
+        const italic = style.fontStyle === 'italic'
+        return (
+          <div key={speakerId} className="flex items-center gap-1.5">
+            <div className="min-w-0 flex-1">
+              <ColorPicker
+                label={style.name ?? speakerId}
+                color={style.color ?? '#ffffff'}
+                onChange={(color) => onChange(speakerId, { color })}
+                onLiveChange={(color) => onChange(speakerId, { color })}
+              />
+            </div>
+            <FormatToggleButton
+              active={bold}
+              onClick={() => onChange(speakerId, { fontWeight: bold ? undefined : 'bold' })}
+              label={i18n.t('editor.subtitleSection.bold')}
+              glyph="B"
+              glyphStyle={{ fontWeight: 700 }}
+            />
+            <FormatToggleButton
+              active={italic}
+              onClick={() => onChange(speakerId, { fontStyle: italic ? undefined : 'italic' })}
+              label={i18n.t('editor.subtitleSection.italic')}
+              glyph="I"
+              glyphStyle={{ fontStyle: 'italic' }}
+            />
+          </div>
+        )
+      })}
+    </div>
+  )
+})
+
 /** Estimated rendered height of a {@link SubtitleCueRow} including the
  *  `gap-2` between siblings. Used as the virtualizer's seed; rows then
  *  self-measure for any variation (e.g. alignment badge present/absent). */
@@ -0,0 +1,146 @@
+import { memo, useCallback, useEffect, useRef, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { Languages, Loader2 } from 'lucide-react'
+
+import { Button } from '@/components/ui/button'
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select'
+import { getDefaultLlmAdapter } from '@/infrastructure/llm'
+import { SUBTITLE_TRANSLATION_LANGUAGES } from '@/shared/utils/subtitle-languages'
+import type { SubtitleSegmentItem } from '@/types/timeline'
+
+import {
+  getSubtitleSegmentLanguage,
+  translateSubtitleSegmentToNewTrack,
+} from '@/features/editor/services/subtitle-translation-service'
+import { PropertyRow } from '../components'
+
+interface SubtitleTranslateControlsProps {
+  segment: SubtitleSegmentItem
+}
+
+type TranslationProgress =
+  | { stage: 'loading'; percent: number }
+  | { stage: 'translating'; translated: number; total: number }
+
+/**
+ * "Translate subtitles" for a single segment: pick a language and the
+ * on-device model writes a translated copy onto a new track above it.
+ */
+export const SubtitleTranslateControls = memo(function SubtitleTranslateControls({
+  segment,
+}: SubtitleTranslateControlsProps) {
+  const { t } = useTranslation()
+  const segmentLanguage = getSubtitleSegmentLanguage(segment)
+  const [targetLanguage, setTargetLanguage] = useState(() =>
+    segmentLanguage?.toLowerCase().startsWith('en') ? 'es' : 'en',
+  )
+  const [progress, setProgress] = useState<TranslationProgress | null>(null)
+  const [error, setError] = useState<string | null>(null)
+  const abortRef = useRef<AbortController | null>(null)
+  const isSupported = getDefaultLlmAdapter().isSupported()
+
+  useEffect(() => () => abortRef.current?.abort(), [])
+
+  const handleTranslate = useCallback(async () => {
+    abortRef.current?.abort()
+    const abortController = new AbortController()
+    abortRef.current = abortController
+
+    setError(null)
+    setProgress({ stage: 'loading', percent: 0 })
+    try {
+      await translateSubtitleSegmentToNewTrack(segment.id, targetLanguage, {
+        signal: abortController.signal,
+        onLoadProgress: (percent) => setProgress({ stage: 'loading', percent }),
+        onProgress: (translated, total) => setProgress({ stage: 'translating', translated, total }),
+      })
+    } catch (translationError) {
+      if (translationError instanceof DOMException && translationError.name === 'AbortError') {
+        // Intentional cancellation — no error shown.
+      } else {
+        setError(
+          translationError instanceof Error
+            ? translationError.message
+            : t('editor.subtitleSection.translateFailed'),
+        )
+      }
+    } finally {
+      if (abortRef.current === abortController) {
+        abortRef.current = null
+        setProgress(null)
+      }
+    }
+  }, [segment.id, targetLanguage, t])
+
+  const handleCancel = useCallback(() => {
+    abortRef.current?.abort()
+  }, [])
+
+  const busy = progress !== null
+
+  return (
+    <div className="space-y-1.5 rounded border border-border bg-muted/20 px-2 py-1.5">
+      <PropertyRow label={t('editor.subtitleSection.translateTo')}>
+        <Select value={targetLanguage} onValueChange={setTargetLanguage} disabled={busy}>
+          <SelectTrigger className="h-7 text-xs flex-1 min-w-0">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            {SUBTITLE_TRANSLATION_LANGUAGES.map((language) => (
+              <SelectItem key={language.code} value={language.code} className="text-xs">
+                {language.name}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+      </PropertyRow>
+
+      <div className="flex items-center gap-1.5">
+        <Button
+          variant="secondary"
+          size="sm"
+          className="h-7 flex-1 text-xs"
+          disabled={busy || !isSupported || segment.cues.length === 0}
+          onClick={() => void handleTranslate()}
+        >
+          {busy ? (
+            <Loader2 className="mr-1.5 h-3.5 w-3.5 animate-spin" />
+          ) : (
+            <Languages className="mr-1.5 h-3.5 w-3.5" />
+          )}
+          {t('editor.subtitleSection.translate')}
+        </Button>
+        {busy && (
+          <Button variant="ghost" size="sm" className="h-7 text-xs" onClick={handleCancel}>
+            {t('common.cancel')}
+          </Button>
+        )}
+      </div>
+
+      {progress && (
+        <p className="text-[11px] text-muted-foreground">
+          {progress.stage === 'loading'
+            ? t('editor.subtitleSection.translateLoadingModel', {
+                percent: Math.round(progress.percent),
+              })
+            : t('editor.subtitleSection.translateProgress', {
+                translated: progress.translated,
+                total: progress.total,
+              })}
+        </p>
+      )}
+      {!isSupported && (
+        <p className="text-[11px] text-muted-foreground">
+          {t('editor.subtitleSection.translateUnsupported')}
+        </p>
+      )}
+      {error && <p className="text-[11px] text-destructive">{error}</p>}
+    </div>
+  )
+})
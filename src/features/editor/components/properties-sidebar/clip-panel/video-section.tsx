@@ -3,7 +3,14 @@ import { useTranslation } from 'react-i18next'
 import { Crop, RotateCcw, Video } from 'lucide-react'
 import { useShallow } from 'zustand/react/shallow'
 import { Button } from '@/components/ui/button'
-import type { TimelineItem, VideoItem, AudioItem } from '@/types/timeline'
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select'
+import type { TimelineItem, VideoItem, AudioItem, FrameBlendingMode } from '@/types/timeline'
 import type { CropSettings } from '@/types/transform'
 import type { ItemKeyframes } from '@/types/keyframe'
 import {
@@ -41,6 +48,12 @@ const MAX_SPEED = 10.0
 const CROP_STEP = 0.1
 const CROP_TOLERANCE = 0.01
 
+const FRAME_BLENDING_OPTIONS: { value: FrameBlendingMode; labelKey: string }[] = [
+  { value: 'nearest', labelKey: 'editor.videoSection.frameBlendingNearest' },
+  { value: 'blend', labelKey: 'editor.videoSection.frameBlendingBlend' },
+  { value: 'optical-flow', labelKey: 'editor.videoSection.frameBlendingOpticalFlow' },
+]
+
 interface VideoSectionProps {
   items: TimelineItem[]
 }
@@ -180,6 +193,7 @@ export function VideoSection({ items }: VideoSectionProps) {
   const speed = getMixedValue(videoItems, (item) => item.speed, 1)
   const fadeIn = getMixedValue(videoItems, (item) => item.fadeIn, 0)
   const fadeOut = getMixedValue(videoItems, (item) => item.fadeOut, 0)
+  const frameBlending = getMixedValue(videoItems, (item) => item.frameBlending, 'nearest')
   const cropLeft = getMixedValue(
     videoItems,
     (item) => getResolvedCropPropertyValue(resolvedCropStatesByItem.get(item.id), 'cropLeft'),
@@ -288,6 +302,14 @@ export function VideoSection({ items }: VideoSectionProps) {
     queueMicrotask(() => clearPreview())
   }, [clearPreview])
 
+  const handleFrameBlendingChange = useCallback(
+    (value: string) => {
+      const frameBlending = value as FrameBlendingMode
+      itemIds.forEach((id) => updateItem(id, { frameBlending }))
+    },
+    [itemIds, updateItem],
+  )
+
   const handleFadeInLiveChange = useCallback(
     (value: number) => {
       const previews: Record<string, { fadeIn: number }> = {}
@@ -515,6 +537,27 @@ export function VideoSection({ items }: VideoSectionProps) {
           </div>
         </PropertyRow>
 
+        <PropertyRow label={t('editor.videoSection.frameBlending')}>
+          <Select
+            value={frameBlending === 'mixed' ? undefined : frameBlending}
+            onValueChange={handleFrameBlendingChange}
+          >
+            <SelectTrigger
+              className="h-7 text-xs flex-1 min-w-0"
+              title={t('editor.videoSection.frameBlendingHint')}
+            >
+              <SelectValue placeholder={t('editor.videoSection.frameBlendingMixed')} />
+            </SelectTrigger>
+            <SelectContent>
+              {FRAME_BLENDING_OPTIONS.map((option) => (
+                <SelectItem key={option.value} value={option.value} className="text-xs">
+                  {t(option.labelKey)}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        </PropertyRow>
+
         <PropertyRow label={t('editor.videoSection.fadeIn')}>
           <div className="flex items-center gap-1 w-full">
             <SliderInput
@@ -6,6 +6,7 @@ import {
   Bug,
   ChevronDown,
   Download,
+  FileCode,
   FolderArchive,
   Github,
   HelpCircle,
@@ -40,6 +41,7 @@ import { useDebugStore } from '@/features/editor/stores/debug-store'
 import { useTimelineStore } from '@/features/editor/deps/timeline-store'
 import { useMediaLibraryStore } from '@/features/editor/deps/media-library'
 import { buildProjectMetadataSummary } from '@/features/editor/utils/project-metadata-summary'
+import type { InterchangeFormat } from '@/features/editor/deps/project-bundle'
 
 const SAVE_ANIMATION_MIN_MS = 1800
 
@@ -63,6 +65,7 @@ interface ToolbarProps {
   onSave?: () => Promise<void>
   onExport?: () => void
   onExportBundle?: () => void
+  onExportInterchange?: (format: InterchangeFormat) => void
   onOpenRenderQueue?: () => void
   /** Number of queued + rendering jobs, shown as a badge on the queue button. */
   renderQueueCount?: number
@@ -75,6 +78,7 @@ export const Toolbar = memo(function Toolbar({
   onSave,
   onExport,
   onExportBundle,
+  onExportInterchange,
   onOpenRenderQueue,
   renderQueueCount = 0,
 }: ToolbarProps) {
@@ -337,6 +341,18 @@ export const Toolbar = memo(function Toolbar({
               <FolderArchive className="h-4 w-4" />
               {t('toolbar.downloadProjectZip')}
             </DropdownMenuItem>
+            <DropdownMenuItem onClick={() => onExportInterchange?.('fcpxml')} className="gap-2">
+              <FileCode className="h-4 w-4" />
+              {t('toolbar.exportFcpxml')}
+            </DropdownMenuItem>
+            <DropdownMenuItem onClick={() => onExportInterchange?.('otio')} className="gap-2">
+              <FileCode className="h-4 w-4" />
+              {t('toolbar.exportOtio')}
+            </DropdownMenuItem>
+            <DropdownMenuItem onClick={() => onExportInterchange?.('edl')} className="gap-2">
+              <FileCode className="h-4 w-4" />
+              {t('toolbar.exportEdl')}
+            </DropdownMenuItem>
           </DropdownMenuContent>
         </DropdownMenu>
       </div>
@@ -8,6 +8,7 @@ export { useEmbeddedSubtitlePickerStore } from '@/features/media-library/stores/
 export { useSubtitleScanProgressStore } from '@/features/media-library/stores/subtitle-scan-progress-store'
 export { getSharedProxyKey } from '@/features/media-library/utils/proxy-key'
 export { resolveMediaUrl } from '@/features/media-library/utils/media-resolver'
+export { buildCaptionTrackAbove } from '@/features/media-library/utils/caption-items'
 export {
   clearMediaDragData,
   setMediaDragData,
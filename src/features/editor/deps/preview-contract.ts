@@ -8,6 +8,9 @@ export { PlaybackControls } from '@/features/preview/components/playback-control
 export { AlignmentToolbar } from '@/features/preview/components/alignment-hud'
 export { TimecodeDisplay } from '@/features/preview/components/timecode-display'
 export { PreviewZoomControls } from '@/features/preview/components/preview-zoom-controls'
+export {
+  MaskPathAnimationControls,
+} from '@/features/preview/components/mask-path-animation-controls'
 
 export const importSourceMonitor = () => import('@/features/preview/components/source-monitor')
 export const importInlineSourcePreview = () =>
@@ -4,6 +4,7 @@
  */
 
 export type { FixtureType } from '@/features/project-bundle/services/test-fixtures'
+export type { InterchangeFormat } from '@/features/project-bundle/types/interchange'
 
 export const importBundleExportDialog = () =>
   import('@/features/project-bundle/components/bundle-export-dialog')
@@ -12,3 +13,5 @@ export const importJsonExportService = () =>
   import('@/features/project-bundle/services/json-export-service')
 export const importJsonImportService = () =>
   import('@/features/project-bundle/services/json-import-service')
+export const importInterchangeService = () =>
+  import('@/features/project-bundle/services/interchange-service')
@@ -7,3 +7,12 @@ export { useProjectStore } from '@/features/projects/stores/project-store'
 export { createProjectUpgradeBackup } from '@/features/projects/services/project-upgrade-service'
 export { formatProjectUpgradeBackupName } from '@/features/projects/utils/project-helpers'
 export { formatFpsValue, resolveAutoMatchProjectFps } from '@/features/projects/utils/project-fps'
+export {
+  CANVAS_VARIANT_ASPECTS,
+  clearCanvasVariantOverride,
+  createCanvasVariant,
+  getCanvasVariantResolution,
+  isBaseCanvasAspect,
+  resolveCanvasVariant,
+  setCanvasVariantOverride,
+} from '@/features/projects/utils/canvas-variants'
@@ -26,10 +26,12 @@ export {
   getDefaultGeneratedLayerDurationInFrames,
   getMaxTransitionDurationForHandles,
   getTrackKind,
+  importAutoDuckingDialog,
   importBentoLayoutDialog,
   importFillerRemovalDialog,
   importFilmstripCache,
   importGifFrameCache,
+  importMotionTrackingDialog,
   importReverseConformDialog,
   importSilenceRemovalDialog,
   importWaveformCache,
@@ -45,12 +47,14 @@ export {
   Timeline,
   TranscriptEditorPanel,
   timelineToSourceFrames,
+  useAutoDuckingDialogStore,
   useBentoLayoutDialogStore,
   useCompositionsStore,
   useFillerRemovalDialogStore,
   useFilmstrip,
   useItemsStore,
   useKeyframesStore,
+  useMotionTrackingDialogStore,
   useReverseConformDialogStore,
   useSilenceRemovalDialogStore,
   useTimelineCommandStore,
@@ -4,13 +4,17 @@
  */
 
 export {
+  importAutoDuckingDialog,
   importBentoLayoutDialog,
   importFillerRemovalDialog,
+  importMotionTrackingDialog,
   importReverseConformDialog,
   importSilenceRemovalDialog,
   Timeline,
+  useAutoDuckingDialogStore,
   useBentoLayoutDialogStore,
   useFillerRemovalDialogStore,
+  useMotionTrackingDialogStore,
   useReverseConformDialogStore,
   useSilenceRemovalDialogStore,
 } from './timeline-contract'
@@ -0,0 +1,176 @@
+import { beforeEach, describe, expect, it, vi } from 'vite-plus/test'
+import { createMockLlmAdapter, type LlmMessage } from '@/infrastructure/llm'
+import type { SubtitleSegmentItem, TimelineItem, TimelineTrack } from '@/types/timeline'
+
+const timelineState = vi.hoisted(() => ({
+  items: [] as TimelineItem[],
+  tracks: [] as TimelineTrack[],
+  setTracks: vi.fn(),
+  _addItem: vi.fn(),
+  markDirty: vi.fn(),
+}))
+
+vi.mock('@/features/editor/deps/timeline-store', () => ({
+  useTimelineStore: { getState: () => timelineState },
+  useItemsStore: { getState: () => timelineState },
+  useTimelineSettingsStore: { getState: () => timelineState },
+  executeTimelineCommand: (_type: string, run: () => void) => run(),
+}))
+
+vi.mock('@/features/editor/deps/media-library', () => ({
+  buildCaptionTrackAbove: (_tracks: TimelineTrack[], referenceOrder: number) => ({
+    id: 'track-new',
+    name: 'V3',
+    kind: 'video',
+    height: 100,
+    locked: false,
+    visible: true,
+    muted: false,
+    solo: false,
+    order: referenceOrder - 0.5,
+    items: [],
+  }),
+}))
+
+import {
+  parseTranslationBatch,
+  translateSubtitleCues,
+  translateSubtitleSegmentToNewTrack,
+  wrapSubtitleText,
+} from './subtitle-translation-service'
+
+/** Deterministic "translation": upper-cases every input subtitle. */
+function upperCaseBatch(messages: LlmMessage[]): string {
+  const texts = JSON.parse(messages.at(-1)!.content) as string[]
+  return JSON.stringify(texts.map((text) => text.toUpperCase()))
+}
+
+function makeSegment(): SubtitleSegmentItem {
+  return {
+    id: 'subtitle-1',
+    type: 'subtitle',
+    trackId: 'track-captions',
+    from: 30,
+    durationInFrames: 120,
+    label: 'Transcript',
+    source: { type: 'transcript', mediaId: 'media-1', clipId: 'clip-1', language: 'en' },
+    cues: [
+      {
+        id: 'cue-1',
+        startSeconds: 0,
+        endSeconds: 1.5,
+        text: 'hello\nthere',
+        speakerId: 'speaker-1',
+        words: [
+          { text: 'hello', startSeconds: 0, endSeconds: 0.5 },
+          { text: 'there', startSeconds: 0.6, endSeconds: 1.5 },
+        ],
+      },
+      { id: 'cue-2', startSeconds: 2, endSeconds: 3, text: 'goodbye' },
+    ],
+    karaoke: { activeColor: '#ffd400' },
+    color: '#ffffff',
+  }
+}
+
+describe('subtitle translation', () => {
+  beforeEach(() => {
+    timelineState.items = []
+    timelineState.tracks = []
+    vi.clearAllMocks()
+  })
+
+  it('wraps translations to the line limit, breaking unspaced runs by character', () => {
+    expect(wrapSubtitleText('the quick brown fox jumps', 10)).toBe('the quick\nbrown fox\njumps')
+    expect(wrapSubtitleText('<i>emphasis</i> ok', 10)).toBe('<i>emphasis</i>\nok')
+    expect(wrapSubtitleText('これは長い字幕のテキストです', 6)).toBe(
+      'これは長い字\n幕のテキスト\nです',
+    )
+  })
+
+  it('only accepts a reply with one string per cue', () => {
+    expect(parseTranslationBatch('Sure! ["uno", "dos"]', 2)).toEqual(['uno', 'dos'])
+    expect(parseTranslationBatch('["uno"]', 2)).toBeNull()
+    expect(parseTranslationBatch('[1, 2]', 2)).toBeNull()
+    expect(parseTranslationBatch('uno, dos', 2)).toBeNull()
+  })
+
+  it('keeps cue timing and speakers while translating text', async () => {
+    const adapter = createMockLlmAdapter(upperCaseBatch)
+    const onProgress = vi.fn()
+
+    const cues = await translateSubtitleCues(adapter, makeSegment().cues, {
+      targetLanguage: 'es',
+      sourceLanguage: 'en',
+      onProgress,
+    })
+
+    expect(cues).toEqual([
+      {
+        id: 'cue-1',
+        startSeconds: 0,
+        endSeconds: 1.5,
+        text: 'HELLO THERE',
+        speakerId: 'speaker-1',
+      },
+      { id: 'cue-2', startSeconds: 2, endSeconds: 3, text: 'GOODBYE' },
+    ])
+    expect(adapter.calls).toHaveLength(1)
+    expect(adapter.calls[0]![0]!.content).toContain('from English into Spanish')
+    expect(onProgress).toHaveBeenLastCalledWith(2, 2)
+  })
+
+  it('retries a miscounted batch, then falls back to one cue per request', async () => {
+    const adapter = createMockLlmAdapter((messages) =>
+      messages[0]!.content.includes('JSON array') ? '["only one"]' : `«${messages[1]!.content}»`,
+    )
+
+    const cues = await translateSubtitleCues(adapter, makeSegment().cues, {
+      targetLanguage: 'fr',
+    })
+
+    expect(cues.map((cue) => cue.text)).toEqual(['«hello there»', '«goodbye»'])
+    expect(adapter.calls).toHaveLength(4)
+  })
+
+  it('adds the translation on its own track above the source segment', async () => {
+    const segment = makeSegment()
+    timelineState.items = [segment]
+    timelineState.tracks = [
+      {
+        id: 'track-captions',
+        name: 'V2',
+        kind: 'video',
+        height: 100,
+        locked: false,
+        visible: true,
+        muted: false,
+        solo: false,
+        order: 1,
+        items: [],
+      },
+    ]
+
+    const translated = await translateSubtitleSegmentToNewTrack('subtitle-1', 'de', {
+      adapter: createMockLlmAdapter(upperCaseBatch),
+    })
+
+    expect(timelineState.setTracks).toHaveBeenCalledWith([
+      timelineState.tracks[0],
+      expect.objectContaining({ id: translated.trackId, order: 0.5 }),
+    ])
+    expect(timelineState._addItem).toHaveBeenCalledWith(translated)
+    expect(translated).toMatchObject({
+      label: 'Transcript (German)',
+      from: 30,
+      source: {
+        type: 'translation',
+        language: 'de',
+        sourceItemId: 'subtitle-1',
+        sourceLanguage: 'en',
+      },
+    })
+    expect(translated.karaoke).toBeUndefined()
+    expect(translated.cues.map((cue) => cue.text)).toEqual(['HELLO THERE', 'GOODBYE'])
+  })
+})
@@ -0,0 +1,291 @@
+/**
+ * Subtitle translation on the on-device LLM.
+ *
+ * Cues go to the model in small batches as a JSON array of strings and come
+ * back as one translation per cue, so timing never passes through the model:
+ * each translated cue keeps its source's id, start and end. A batch whose
+ * reply doesn't parse gets one corrective retry (the same validation-feedback
+ * trick the agent uses), then falls back to translating its cues one by one.
+ * Translations are re-wrapped to the subtitle line-length limit.
+ *
+ * Every target language lands on its own subtitle track above the source, so
+ * the export can embed them side by side as tagged soft-subtitle tracks.
+ */
+
+import { getDefaultLlmAdapter, type LlmAdapter, type LlmMessage } from '@/infrastructure/llm'
+import { createLogger } from '@/shared/logging/logger'
+import { getSubtitleLanguage } from '@/shared/utils/subtitle-languages'
+import type { SubtitleSegmentCue, SubtitleSegmentItem } from '@/types/timeline'
+import {
+  executeTimelineCommand,
+  useItemsStore,
+  useTimelineSettingsStore,
+  useTimelineStore,
+} from '@/features/editor/deps/timeline-store'
+import { buildCaptionTrackAbove } from '@/features/editor/deps/media-library'
+
+const logger = createLogger('SubtitleTranslation')
+
+/** Characters per line for space-separated scripts (streaming-platform norm). */
+export const SUBTITLE_MAX_LINE_CHARS = 42
+/** Characters per line for Chinese and Japanese, which wrap without spaces. */
+export const SUBTITLE_MAX_LINE_CHARS_CJK = 16
+
+const BATCH_SIZE = 8
+const MAX_TOKENS_PER_CUE = 96
+const CJK_LANGUAGES = new Set(['ja', 'zh'])
+/** Inline markup (`<i>`, `{\an8}`) takes no room on screen. */
+const MARKUP_PATTERN = /<[^>]*>|\{[^}]*\}/g
+const HAS_MARKUP_PATTERN = /<[^>]*>|\{[^}]*\}/
+
+export interface TranslateSubtitleCuesOptions {
+  /** BCP 47 target language, e.g. `es`. */
+  targetLanguage: string
+  sourceLanguage?: string
+  /** Defaults to {@link getSubtitleLineLimit} for the target language. */
+  maxLineChars?: number
+  signal?: AbortSignal
+  /** Called after each batch with the number of cues translated so far. */
+  onProgress?: (translated: number, total: number) => void
+}
+
+export function getSubtitleLineLimit(language: string): number {
+  const primary = language.toLowerCase().split('-')[0] ?? ''
+  return CJK_LANGUAGES.has(primary) ? SUBTITLE_MAX_LINE_CHARS_CJK : SUBTITLE_MAX_LINE_CHARS
+}
+
+function visibleLength(text: string): number {
+  return Array.from(text.replace(MARKUP_PATTERN, '')).length
+}
+
+/**
+ * Greedy-wrap `text` into lines of at most `maxLineChars` visible characters.
+ * Words longer than a line (or unspaced CJK runs) are broken by character.
+ */
+export function wrapSubtitleText(text: string, maxLineChars: number): string {
+  const limit = Math.max(1, Math.floor(maxLineChars))
+  const lines: string[] = []
+  let current = ''
+  for (const word of text.split(/\s+/).filter(Boolean)) {
+    const pieces: string[] = []
+    if (visibleLength(word) > limit && !HAS_MARKUP_PATTERN.test(word)) {
+      const chars = Array.from(word)
+      for (let index = 0; index < chars.length; index += limit) {
+        pieces.push(chars.slice(index, index + limit).join(''))
+      }
+    } else {
+      pieces.push(word)
+    }
+    for (const piece of pieces) {
+      const candidate = current ? `${current} ${piece}` : piece
+      if (current && visibleLength(candidate) > limit) {
+        lines.push(current)
+        current = piece
+      } else {
+        current = candidate
+      }
+    }
+  }
+  if (current) lines.push(current)
+  return lines.join('\n')
+}
+
+function languageName(code: string): string {
+  return getSubtitleLanguage(code)?.name ?? code
+}
+
+function buildSystemPrompt(options: TranslateSubtitleCuesOptions, reply: string): string {
+  const from = options.sourceLanguage ? ` from ${languageName(options.sourceLanguage)}` : ''
+  const to = languageName(options.targetLanguage)
+  return [
+    `You are a professional subtitle translator. Translate subtitles${from} into ${to}.`,
+    'Keep the meaning and tone, and keep inline tags such as <i> or <b>.',
+    'Keep each translation about as short as the original so it fits on screen.',
+    reply,
+  ].join(' ')
+}
+
+/** Pull a JSON array of exactly `expected` strings out of a model reply. */
+export function parseTranslationBatch(raw: string, expected: number): string[] | null {
+  const start = raw.indexOf('[')
+  const end = raw.lastIndexOf(']')
+  if (start < 0 || end <= start) return null
+  try {
+    const parsed: unknown = JSON.parse(raw.slice(start, end + 1))
+    if (!Array.isArray(parsed) || parsed.length !== expected) return null
+    if (!parsed.every((entry) => typeof entry === 'string')) return null
+    return parsed
+  } catch {
+    return null
+  }
+}
+
+async function translateBatch(
+  adapter: LlmAdapter,
+  texts: string[],
+  options: TranslateSubtitleCuesOptions,
+): Promise<string[]> {
+  const messages: LlmMessage[] = [
+    {
+      role: 'system',
+      content: buildSystemPrompt(
+        options,
+        'Reply with ONLY a JSON array of strings, one translation per subtitle, in the same order.',
+      ),
+    },
+    { role: 'user', content: JSON.stringify(texts) },
+  ]
+  const generateOptions = {
+    maxTokens: MAX_TOKENS_PER_CUE * texts.length,
+    temperature: 0,
+    signal: options.signal,
+  }
+
+  const raw = await adapter.generate(messages, generateOptions)
+  const parsed = parseTranslationBatch(raw, texts.length)
+  if (parsed) return parsed
+
+  if (!options.signal?.aborted) {
+    logger.info('Translation batch needs correction', { cues: texts.length })
+    const retryRaw = await adapter.generate(
+      [
+        ...messages,
+        { role: 'assistant', content: raw },
+        {
+          role: 'user',
+          content:
+            `Your reply was not a JSON array of exactly ${texts.length} strings. ` +
+            'Respond with ONLY that array.',
+        },
+      ],
+      generateOptions,
+    )
+    const retryParsed = parseTranslationBatch(retryRaw, texts.length)
+    if (retryParsed) return retryParsed
+  }
+
+  // Last resort: one request per cue, where there is nothing to miscount.
+  const translated: string[] = []
+  for (const text of texts) {
+    const single = await adapter.generate(
+      [
+        {
+          role: 'system',
+          content: buildSystemPrompt(options, 'Reply with ONLY the translated subtitle text.'),
+        },
+        { role: 'user', content: text },
+      ],
+      { ...generateOptions, maxTokens: MAX_TOKENS_PER_CUE },
+    )
+    translated.push(single.trim().replace(/^"(.*)"$/s, '$1') || text)
+  }
+  return translated
+}
+
+/**
+ * Translate cue text into `options.targetLanguage`. Cue ids, timing and
+ * speakers are kept; word timings are dropped since they belong to the source
+ * language's audio.
+ */
+export async function translateSubtitleCues(
+  adapter: LlmAdapter,
+  cues: readonly SubtitleSegmentCue[],
+  options: TranslateSubtitleCuesOptions,
+): Promise<SubtitleSegmentCue[]> {
+  const maxLineChars = options.maxLineChars ?? getSubtitleLineLimit(options.targetLanguage)
+  const translated: SubtitleSegmentCue[] = []
+  for (let start = 0; start < cues.length; start += BATCH_SIZE) {
+    if (options.signal?.aborted) throw new DOMException('Aborted', 'AbortError')
+    const batch = cues.slice(start, start + BATCH_SIZE)
+    const texts = batch.map((cue) => cue.text.replace(/\s*\n\s*/g, ' ').trim())
+    const results = await translateBatch(adapter, texts, options)
+    for (const [index, cue] of batch.entries()) {
+      const { words: _words, ...rest } = cue
+      translated.push({ ...rest, text: wrapSubtitleText(results[index]!, maxLineChars) })
+    }
+    options.onProgress?.(translated.length, cues.length)
+  }
+  return translated
+}
+
+/** Language the segment's cues are in, when its source records one. */
+export function getSubtitleSegmentLanguage(segment: SubtitleSegmentItem): string | undefined {
+  const source = segment.source
+  return source.type === 'subtitle-import' ? undefined : source.language
+}
+
+export function buildTranslatedSubtitleSegment(
+  segment: SubtitleSegmentItem,
+  cues: SubtitleSegmentCue[],
+  trackId: string,
+  language: string,
+): SubtitleSegmentItem {
+  const { karaoke: _karaoke, sourceLabel: _sourceLabel, ...rest } = segment
+  const sourceLanguage = getSubtitleSegmentLanguage(segment)
+  return {
+    ...rest,
+    id: crypto.randomUUID(),
+    trackId,
+    label: `${segment.label} (${languageName(language)})`,
+    source: {
+      type: 'translation',
+      language,
+      sourceItemId: segment.id,
+      ...(sourceLanguage ? { sourceLanguage } : {}),
+      translatedAt: Date.now(),
+    },
+    cues,
+  }
+}
+
+export interface TranslateSubtitleSegmentOptions {
+  /** Defaults to the registry's default on-device model. */
+  adapter?: LlmAdapter
+  signal?: AbortSignal
+  onLoadProgress?: (percent: number) => void
+  onProgress?: (translated: number, total: number) => void
+}
+
+/**
+ * "Translate subtitles": translate a subtitle segment and add the result on a
+ * new track directly above it, as one undoable timeline command.
+ */
+export async function translateSubtitleSegmentToNewTrack(
+  segmentId: string,
+  targetLanguage: string,
+  options: TranslateSubtitleSegmentOptions = {},
+): Promise<SubtitleSegmentItem> {
+  const segment = useTimelineStore.getState().items.find((item) => item.id === segmentId)
+  if (segment?.type !== 'subtitle') throw new Error('Subtitle segment not found')
+
+  const adapter = options.adapter ?? getDefaultLlmAdapter()
+  await adapter.load((progress) => options.onLoadProgress?.(progress.percent))
+  const cues = await translateSubtitleCues(adapter, segment.cues, {
+    targetLanguage,
+    sourceLanguage: getSubtitleSegmentLanguage(segment),
+    signal: options.signal,
+    onProgress: options.onProgress,
+  })
+  if (options.signal?.aborted) throw new DOMException('Aborted', 'AbortError')
+
+  const { tracks } = useTimelineStore.getState()
+  const sourceTrack = tracks.find((track) => track.id === segment.trackId)
+  const track = {
+    ...buildCaptionTrackAbove(tracks, sourceTrack?.order ?? 0),
+    id: `track-captions-${crypto.randomUUID()}`,
+  }
+  const translatedSegment = buildTranslatedSubtitleSegment(segment, cues, track.id, targetLanguage)
+
+  executeTimelineCommand(
+    'TRANSLATE_SUBTITLES',
+    () => {
+      const store = useItemsStore.getState()
+      store.setTracks([...store.tracks, track])
+      store._addItem(translatedSegment)
+      useTimelineSettingsStore.getState().markDirty()
+    },
+    { itemId: translatedSegment.id, language: targetLanguage },
+  )
+  logger.info('Translated subtitles', { segmentId, targetLanguage, cues: cues.length })
+  return translatedSegment
+}
@@ -1,4 +1,5 @@
 import { captureSnapshot, useTimelineCommandStore } from '@/features/editor/deps/timeline-store'
+import { DEFAULT_PROJECT_COLOR_SPACE, type ProjectColorSpace } from '@/types/color'
 import type { Project } from '@/types/project'
 
 type ProjectMetadataUpdates = {
@@ -6,6 +7,7 @@ type ProjectMetadataUpdates = {
   height?: number
   fps?: number
   backgroundColor?: string
+  colorSpace?: ProjectColorSpace
 }
 
 interface CommitProjectMetadataChangeParams {
@@ -32,7 +34,9 @@ function hasProjectMetadataChanges(project: Project, updates: ProjectMetadataUpd
     (updates.height !== undefined && updates.height !== metadata.height) ||
     (updates.fps !== undefined && updates.fps !== metadata.fps) ||
     (updates.backgroundColor !== undefined &&
-      normalizeColor(updates.backgroundColor) !== normalizeColor(metadata.backgroundColor))
+      normalizeColor(updates.backgroundColor) !== normalizeColor(metadata.backgroundColor)) ||
+    (updates.colorSpace !== undefined &&
+      updates.colorSpace !== (metadata.colorSpace ?? DEFAULT_PROJECT_COLOR_SPACE))
   )
 }
 
@@ -0,0 +1,77 @@
+import { regionFromTransform, type SubtitleExportDocument } from '@/shared/utils/subtitle-document'
+import type { SubtitleSegmentItem } from '@/types/timeline'
+
+import { getSubtitleSegmentLanguage } from '../services/subtitle-translation-service'
+
+/**
+ * Everything a subtitle file writer needs from one segment, with cue and
+ * word times moved from segment-relative to timeline seconds so the file
+ * lines up with a render of the whole timeline.
+ */
+export function buildSubtitleExportDocument(
+  segment: SubtitleSegmentItem,
+  fps: number,
+  frame: { frameWidth: number; frameHeight: number },
+): SubtitleExportDocument {
+  const offset = segment.from / fps
+  const { transform } = segment
+  return {
+    ...frame,
+    cues: segment.cues.map((cue) => ({
+      ...cue,
+      startSeconds: cue.startSeconds + offset,
+      endSeconds: cue.endSeconds + offset,
+      ...(cue.words
+        ? {
+            words: cue.words.map((word) => ({
+              ...word,
+              startSeconds: word.startSeconds + offset,
+              endSeconds: word.endSeconds + offset,
+            })),
+          }
+        : {}),
+    })),
+    style: {
+      fontSize: segment.fontSize,
+      fontFamily: segment.fontFamily,
+      fontWeight: segment.fontWeight,
+      fontStyle: segment.fontStyle,
+      underline: segment.underline,
+      color: segment.color,
+      letterSpacing: segment.letterSpacing,
+      backgroundColor: segment.backgroundColor,
+      backgroundRadius: segment.backgroundRadius,
+      textAlign: segment.textAlign,
+      verticalAlign: segment.verticalAlign,
+      lineHeight: segment.lineHeight,
+      textPadding: segment.textPadding,
+      textShadow: segment.textShadow,
+      stroke: segment.stroke,
+    },
+    karaoke: segment.karaoke,
+    region:
+      transform?.width !== undefined && transform.height !== undefined
+        ? regionFromTransform(
+            {
+              x: transform.x ?? 0,
+              y: transform.y ?? 0,
+              width: transform.width,
+              height: transform.height,
+            },
+            frame,
+          )
+        : undefined,
+    speakerStyles: segment.speakerStyles,
+    language: getSubtitleSegmentLanguage(segment),
+    title: segment.label,
+  }
+}
+
+/** Download name for `segment` exported with `extension`. */
+export function getSubtitleExportFileName(segment: SubtitleSegmentItem, extension: string) {
+  const base = segment.label
+    .replace(/\.(srt|vtt|ass|ssa|ttml|dfxp)$/i, '')
+    .replace(/[\\/:*?"<>|]+/g, '_')
+    .trim()
+  return `${base || 'subtitles'}.${extension}`
+}
@@ -16,6 +16,8 @@ import {
   GpuLutPanel,
   GpuPowerWindowPanel,
   GpuSecondaryQualifierPanel,
+  GpuStabilizePanel,
+  GpuRemoveBackgroundPanel,
 } from './panels'
 import { getGpuEffect, getGpuEffectDefaultParams } from '@/infrastructure/gpu-effects'
 import { useGpuEffectPreviewData } from '../hooks/use-gpu-effect-preview-data'
@@ -826,6 +828,54 @@ export const EffectsSection = memo(function EffectsSection({
             )
           }
 
+          if (gpuEff.gpuEffectType === 'gpu-stabilize') {
+            return (
+              <GpuStabilizePanel
+                key={effect.id}
+                item={displayItem}
+                itemKeyframes={
+                  displayItem ? (keyframesByItemId.get(displayItem.id) ?? undefined) : undefined
+                }
+                effect={effect}
+                gpuEffect={displayGpuEffect}
+                definition={def}
+                onParamChange={handleGpuParamChange}
+                onParamLiveChange={handleGpuParamLiveChange}
+                onParamsBatchChange={handleGpuParamsBatchChange}
+                onReset={handleResetGpuEffect}
+                onToggle={handleToggle}
+                onRemove={handleRemove}
+                onMove={handleMoveEffect}
+                canMoveUp={effectIndex > 0}
+                canMoveDown={effectIndex < effects.length - 1}
+              />
+            )
+          }
+
+          if (gpuEff.gpuEffectType === 'gpu-remove-background') {
+            return (
+              <GpuRemoveBackgroundPanel
+                key={effect.id}
+                item={displayItem}
+                itemKeyframes={
+                  displayItem ? (keyframesByItemId.get(displayItem.id) ?? undefined) : undefined
+                }
+                effect={effect}
+                gpuEffect={displayGpuEffect}
+                definition={def}
+                onParamChange={handleGpuParamChange}
+                onParamLiveChange={handleGpuParamLiveChange}
+                onParamsBatchChange={handleGpuParamsBatchChange}
+                onReset={handleResetGpuEffect}
+                onToggle={handleToggle}
+                onRemove={handleRemove}
+                onMove={handleMoveEffect}
+                canMoveUp={effectIndex > 0}
+                canMoveDown={effectIndex < effects.length - 1}
+              />
+            )
+          }
+
           if (gpuEff.gpuEffectType === 'gpu-color-wheels') {
             return (
               <GpuWheelsPanel
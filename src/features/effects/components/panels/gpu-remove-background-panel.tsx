@@ -0,0 +1,240 @@
+import { memo, useCallback, useEffect, useRef, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { UserRound, X } from 'lucide-react'
+import { Button } from '@/components/ui/button'
+import { Progress } from '@/components/ui/progress'
+import { useTimelineStore } from '@/features/effects/deps/timeline-contract'
+import { PropertyRow, SliderInput } from '@/shared/ui/property-controls'
+import { createLogger } from '@/shared/logging/logger'
+import { getEffectDefinitionName } from '@/features/effects/utils/effect-i18n'
+import {
+  analyzeClipSegmentation,
+  isClipSegmented,
+} from '@/features/effects/utils/segmentation-analysis'
+import type { TimelineItem } from '@/types/timeline'
+import type { ItemKeyframes } from '@/types/keyframe'
+import { EffectPanelHeaderRow } from './effect-panel-header-actions'
+import type { GpuPanelBaseProps, GpuParamUpdates } from './panel-props'
+
+const logger = createLogger('GpuRemoveBackgroundPanel')
+
+interface GpuRemoveBackgroundPanelProps extends GpuPanelBaseProps {
+  /** Clip the subject mattes are generated for (first selected item) */
+  item: TimelineItem | null
+  itemKeyframes: ItemKeyframes | undefined
+  onParamsBatchChange: (effectId: string, updates: GpuParamUpdates) => void
+}
+
+/**
+ * Panel for the gpu-remove-background effect: generates subject mattes for
+ * the clip with the local segmentation model and exposes the matte refinement
+ * applied at render time.
+ */
+export const GpuRemoveBackgroundPanel = memo(function GpuRemoveBackgroundPanel({
+  item,
+  itemKeyframes,
+  effect,
+  gpuEffect,
+  definition,
+  onParamChange,
+  onParamLiveChange,
+  onParamsBatchChange,
+  onReset,
+  onToggle,
+  onRemove,
+  onMove,
+  canMoveUp,
+  canMoveDown,
+}: GpuRemoveBackgroundPanelProps) {
+  const { t } = useTranslation()
+  const [progress, setProgress] = useState<number | null>(null)
+  const [analyzed, setAnalyzed] = useState(false)
+  const [analyzeError, setAnalyzeError] = useState<string | null>(null)
+  const abortRef = useRef<AbortController | null>(null)
+
+  useEffect(() => () => abortRef.current?.abort(), [])
+
+  const params = gpuEffect.params
+  const threshold = typeof params.threshold === 'number' ? params.threshold : 0.5
+  const softness = typeof params.softness === 'number' ? params.softness : 0.3
+  const removeSubject = params.invert === true
+  const showMatte = params.output === 'matte'
+  const matteRevision = typeof params.matteRevision === 'string' ? params.matteRevision : ''
+  const isDefault = threshold === 0.5 && softness === 0.3 && !removeSubject && !showMatte
+  const canAnalyze = effect.enabled && item?.type === 'video' && !!item.mediaId
+  const isAnalyzing = progress !== null
+
+  useEffect(() => {
+    if (!item) {
+      setAnalyzed(false)
+      return
+    }
+    let cancelled = false
+    void isClipSegmented(item, itemKeyframes, useTimelineStore.getState().fps).then((result) => {
+      if (!cancelled) setAnalyzed(result)
+    })
+    return () => {
+      cancelled = true
+    }
+  }, [item, itemKeyframes, matteRevision])
+
+  const handleAnalyze = useCallback(async () => {
+    if (!item) return
+    abortRef.current?.abort()
+    const controller = new AbortController()
+    abortRef.current = controller
+    setAnalyzeError(null)
+    setProgress(0)
+
+    try {
+      const completed = await analyzeClipSegmentation(item, itemKeyframes, {
+        fps: useTimelineStore.getState().fps,
+        onProgress: setProgress,
+        signal: controller.signal,
+      })
+      if (completed) {
+        setAnalyzed(true)
+        onParamsBatchChange(effect.id, { matteRevision: String(Date.now()) })
+      }
+    } catch (error) {
+      logger.warn('Subject segmentation failed:', error)
+      setAnalyzeError(t('effects.removeBackground.analyzeFailed'))
+    } finally {
+      if (abortRef.current === controller) {
+        abortRef.current = null
+        setProgress(null)
+      }
+    }
+  }, [effect.id, item, itemKeyframes, onParamsBatchChange, t])
+
+  const handleCancel = useCallback(() => {
+    abortRef.current?.abort()
+    abortRef.current = null
+    setProgress(null)
+  }, [])
+
+  return (
+    <div className="space-y-0">
+      <EffectPanelHeaderRow
+        label={getEffectDefinitionName(definition)}
+        effectId={effect.id}
+        enabled={effect.enabled}
+        isDefault={isDefault}
+        onReset={onReset}
+        onToggle={onToggle}
+        onRemove={onRemove}
+        onMove={onMove}
+        canMoveUp={canMoveUp}
+        canMoveDown={canMoveDown}
+      />
+
+      <PropertyRow
+        label={t('effects.removeBackground.analysis')}
+        className={!effect.enabled ? 'opacity-50' : undefined}
+      >
+        <div className="flex items-center gap-1 min-w-0 w-full">
+          {isAnalyzing ? (
+            <>
+              <Progress value={progress} className="h-1.5 flex-1 min-w-0" />
+              <Button
+                variant="ghost"
+                size="icon"
+                className="h-6 w-6 flex-shrink-0"
+                onClick={handleCancel}
+                aria-label={t('effects.removeBackground.cancel')}
+              >
+                <X className="w-3 h-3" />
+              </Button>
+            </>
+          ) : (
+            <Button
+              variant="outline"
+              size="sm"
+              className="h-6 flex-1 min-w-0 justify-start gap-1.5 text-xs"
+              onClick={() => void handleAnalyze()}
+              disabled={!canAnalyze}
+              title={canAnalyze ? undefined : t('effects.removeBackground.videoOnly')}
+            >
+              <UserRound className="w-3 h-3 flex-shrink-0" />
+              <span className="truncate">
+                {analyzed
+                  ? t('effects.removeBackground.reanalyze')
+                  : t('effects.removeBackground.analyze')}
+              </span>
+            </Button>
+          )}
+        </div>
+      </PropertyRow>
+      <div className="px-2 pb-1 text-[11px] text-muted-foreground">
+        {isAnalyzing
+          ? t('effects.removeBackground.analyzing', { percent: Math.round(progress ?? 0) })
+          : analyzed
+            ? t('effects.removeBackground.analyzed')
+            : t('effects.removeBackground.notAnalyzed')}
+      </div>
+      {analyzeError && <div className="px-2 pb-1 text-[11px] text-destructive">{analyzeError}</div>}
+
+      <PropertyRow
+        label={t('effects.removeBackground.threshold')}
+        className={!effect.enabled ? 'opacity-50' : undefined}
+      >
+        <SliderInput
+          value={threshold}
+          onChange={(v) => onParamChange(effect.id, 'threshold', v)}
+          onLiveChange={(v) => onParamLiveChange(effect.id, 'threshold', v)}
+          min={0}
+          max={1}
+          step={0.01}
+          disabled={!effect.enabled}
+          className="flex-1 min-w-0"
+        />
+      </PropertyRow>
+
+      <PropertyRow
+        label={t('effects.removeBackground.softness')}
+        className={!effect.enabled ? 'opacity-50' : undefined}
+      >
+        <SliderInput
+          value={softness}
+          onChange={(v) => onParamChange(effect.id, 'softness', v)}
+          onLiveChange={(v) => onParamLiveChange(effect.id, 'softness', v)}
+          min={0}
+          max={1}
+          step={0.01}
+          disabled={!effect.enabled}
+          className="flex-1 min-w-0"
+        />
+      </PropertyRow>
+
+      <PropertyRow
+        label={t('effects.removeBackground.removeSubject')}
+        className={!effect.enabled ? 'opacity-50' : undefined}
+      >
+        <Button
+          variant={removeSubject ? 'default' : 'outline'}
+          size="sm"
+          className="h-6 text-xs"
+          onClick={() => onParamChange(effect.id, 'invert', !removeSubject)}
+          disabled={!effect.enabled}
+        >
+          {removeSubject ? t('effects.panel.on') : t('effects.panel.off')}
+        </Button>
+      </PropertyRow>
+
+      <PropertyRow
+        label={t('effects.removeBackground.showMatte')}
+        className={!effect.enabled ? 'opacity-50' : undefined}
+      >
+        <Button
+          variant={showMatte ? 'default' : 'outline'}
+          size="sm"
+          className="h-6 text-xs"
+          onClick={() => onParamChange(effect.id, 'output', showMatte ? 'cutout' : 'matte')}
+          disabled={!effect.enabled}
+        >
+          {showMatte ? t('effects.panel.on') : t('effects.panel.off')}
+        </Button>
+      </PropertyRow>
+    </div>
+  )
+})
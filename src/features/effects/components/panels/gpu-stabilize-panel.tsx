@@ -0,0 +1,208 @@
+import { memo, useCallback, useEffect, useRef, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { ScanLine, X } from 'lucide-react'
+import { Button } from '@/components/ui/button'
+import { Progress } from '@/components/ui/progress'
+import { encodeCameraPath } from '@/infrastructure/gpu-effects/stabilization/camera-path'
+import { useTimelineStore } from '@/features/effects/deps/timeline-contract'
+import { PropertyRow, SliderInput } from '@/shared/ui/property-controls'
+import { createLogger } from '@/shared/logging/logger'
+import { getEffectDefinitionName } from '@/features/effects/utils/effect-i18n'
+import { analyzeClipStabilization } from '@/features/effects/utils/stabilization-analysis'
+import type { TimelineItem } from '@/types/timeline'
+import type { ItemKeyframes } from '@/types/keyframe'
+import { EffectPanelHeaderRow } from './effect-panel-header-actions'
+import type { GpuPanelBaseProps, GpuParamUpdates } from './panel-props'
+
+const logger = createLogger('GpuStabilizePanel')
+
+interface GpuStabilizePanelProps extends GpuPanelBaseProps {
+  /** Clip the camera path is measured from (first selected item) */
+  item: TimelineItem | null
+  itemKeyframes: ItemKeyframes | undefined
+  onParamsBatchChange: (effectId: string, updates: GpuParamUpdates) => void
+}
+
+/**
+ * Panel for the gpu-stabilize effect: runs the camera-path analysis for the
+ * clip, embeds the result into the effect params, and exposes the smoothing
+ * controls applied at render time.
+ */
+export const GpuStabilizePanel = memo(function GpuStabilizePanel({
+  item,
+  itemKeyframes,
+  effect,
+  gpuEffect,
+  definition,
+  onParamChange,
+  onParamLiveChange,
+  onParamsBatchChange,
+  onReset,
+  onToggle,
+  onRemove,
+  onMove,
+  canMoveUp,
+  canMoveDown,
+}: GpuStabilizePanelProps) {
+  const { t } = useTranslation()
+  const [progress, setProgress] = useState<number | null>(null)
+  const [analyzeError, setAnalyzeError] = useState<string | null>(null)
+  const abortRef = useRef<AbortController | null>(null)
+
+  useEffect(() => () => abortRef.current?.abort(), [])
+
+  const params = gpuEffect.params
+  const hasPath = typeof params.cameraPath === 'string' && params.cameraPath.length > 0
+  const smoothness = typeof params.smoothness === 'number' ? params.smoothness : 50
+  const cropToFill = params.cropToFill !== false
+  const rollingShutter = typeof params.rollingShutter === 'number' ? params.rollingShutter : 0
+  const isDefault = !hasPath && smoothness === 50 && cropToFill && rollingShutter === 0
+  const canAnalyze = effect.enabled && item?.type === 'video' && !!item.mediaId
+  const isAnalyzing = progress !== null
+
+  const handleAnalyze = useCallback(async () => {
+    if (!item) return
+    abortRef.current?.abort()
+    const controller = new AbortController()
+    abortRef.current = controller
+    setAnalyzeError(null)
+    setProgress(0)
+
+    try {
+      const path = await analyzeClipStabilization(item, itemKeyframes, {
+        fps: useTimelineStore.getState().fps,
+        onProgress: setProgress,
+        signal: controller.signal,
+      })
+      if (path) {
+        onParamsBatchChange(effect.id, { cameraPath: encodeCameraPath(path) })
+      }
+    } catch (error) {
+      logger.warn('Stabilization analysis failed:', error)
+      setAnalyzeError(
+        error instanceof Error && error.message.includes('WebGPU')
+          ? t('effects.stabilize.requiresWebGpu')
+          : t('effects.stabilize.analyzeFailed'),
+      )
+    } finally {
+      if (abortRef.current === controller) {
+        abortRef.current = null
+        setProgress(null)
+      }
+    }
+  }, [effect.id, item, itemKeyframes, onParamsBatchChange, t])
+
+  const handleCancel = useCallback(() => {
+    abortRef.current?.abort()
+    abortRef.current = null
+    setProgress(null)
+  }, [])
+
+  return (
+    <div className="space-y-0">
+      <EffectPanelHeaderRow
+        label={getEffectDefinitionName(definition)}
+        effectId={effect.id}
+        enabled={effect.enabled}
+        isDefault={isDefault}
+        onReset={onReset}
+        onToggle={onToggle}
+        onRemove={onRemove}
+        onMove={onMove}
+        canMoveUp={canMoveUp}
+        canMoveDown={canMoveDown}
+      />
+
+      <PropertyRow
+        label={t('effects.stabilize.analysis')}
+        className={!effect.enabled ? 'opacity-50' : undefined}
+      >
+        <div className="flex items-center gap-1 min-w-0 w-full">
+          {isAnalyzing ? (
+            <>
+              <Progress value={progress} className="h-1.5 flex-1 min-w-0" />
+              <Button
+                variant="ghost"
+                size="icon"
+                className="h-6 w-6 flex-shrink-0"
+                onClick={handleCancel}
+                aria-label={t('effects.stabilize.cancel')}
+              >
+                <X className="w-3 h-3" />
+              </Button>
+            </>
+          ) : (
+            <Button
+              variant="outline"
+              size="sm"
+              className="h-6 flex-1 min-w-0 justify-start gap-1.5 text-xs"
+              onClick={() => void handleAnalyze()}
+              disabled={!canAnalyze}
+              title={canAnalyze ? undefined : t('effects.stabilize.videoOnly')}
+            >
+              <ScanLine className="w-3 h-3 flex-shrink-0" />
+              <span className="truncate">
+                {hasPath ? t('effects.stabilize.reanalyze') : t('effects.stabilize.analyze')}
+              </span>
+            </Button>
+          )}
+        </div>
+      </PropertyRow>
+      <div className="px-2 pb-1 text-[11px] text-muted-foreground">
+        {isAnalyzing
+          ? t('effects.stabilize.analyzing', { percent: Math.round(progress ?? 0) })
+          : hasPath
+            ? t('effects.stabilize.analyzed')
+            : t('effects.stabilize.notAnalyzed')}
+      </div>
+      {analyzeError && <div className="px-2 pb-1 text-[11px] text-destructive">{analyzeError}</div>}
+
+      <PropertyRow
+        label={t('effects.stabilize.smoothness')}
+        className={!effect.enabled ? 'opacity-50' : undefined}
+      >
+        <SliderInput
+          value={smoothness}
+          onChange={(v) => onParamChange(effect.id, 'smoothness', v)}
+          onLiveChange={(v) => onParamLiveChange(effect.id, 'smoothness', v)}
+          min={0}
+          max={100}
+          step={1}
+          disabled={!effect.enabled}
+          className="flex-1 min-w-0"
+        />
+      </PropertyRow>
+
+      <PropertyRow
+        label={t('effects.stabilize.cropToFill')}
+        className={!effect.enabled ? 'opacity-50' : undefined}
+      >
+        <Button
+          variant={cropToFill ? 'default' : 'outline'}
+          size="sm"
+          className="h-6 text-xs"
+          onClick={() => onParamChange(effect.id, 'cropToFill', !cropToFill)}
+          disabled={!effect.enabled}
+        >
+          {cropToFill ? t('effects.panel.on') : t('effects.panel.off')}
+        </Button>
+      </PropertyRow>
+
+      <PropertyRow
+        label={t('effects.stabilize.rollingShutter')}
+        className={!effect.enabled ? 'opacity-50' : undefined}
+      >
+        <SliderInput
+          value={rollingShutter}
+          onChange={(v) => onParamChange(effect.id, 'rollingShutter', v)}
+          onLiveChange={(v) => onParamLiveChange(effect.id, 'rollingShutter', v)}
+          min={0}
+          max={1}
+          step={0.01}
+          disabled={!effect.enabled}
+          className="flex-1 min-w-0"
+        />
+      </PropertyRow>
+    </div>
+  )
+})
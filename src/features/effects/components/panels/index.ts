@@ -4,3 +4,5 @@ export { GpuCurvesPanel } from './gpu-curves-panel'
 export { GpuLutPanel } from './gpu-lut-panel'
 export { GpuSecondaryQualifierPanel } from './gpu-secondary-qualifier-panel'
 export { GpuPowerWindowPanel } from './gpu-power-window-panel'
+export { GpuStabilizePanel } from './gpu-stabilize-panel'
+export { GpuRemoveBackgroundPanel } from './gpu-remove-background-panel'
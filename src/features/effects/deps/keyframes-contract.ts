@@ -5,3 +5,8 @@
 export { KeyframeToggle } from '@/features/keyframes/components/keyframe-toggle'
 export { getAutoKeyframeOperation } from '@/features/keyframes/utils/auto-keyframe'
 export { getResolvedAnimatedEffectParamValue } from '@/features/keyframes/utils/effect-animatable-properties'
+export {
+  getItemNaturalSourceSeconds,
+  getTimeRemapSourceRange,
+  resolveTimeRemapCurve,
+} from '@/features/keyframes/utils/time-remap'
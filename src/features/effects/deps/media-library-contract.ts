@@ -0,0 +1,6 @@
+/**
+ * Adapter exports for media-library dependencies.
+ * Effects modules should import media-library services from here.
+ */
+
+export { resolveMediaUrl } from '@/features/media-library/utils/media-resolver'
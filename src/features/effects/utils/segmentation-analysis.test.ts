@@ -0,0 +1,103 @@
+import { beforeEach, describe, expect, it, vi } from 'vite-plus/test'
+import type { VideoItem } from '@/types/timeline'
+
+const segmentationMocks = vi.hoisted(() => ({
+  deleteSegmentation: vi.fn(),
+  getSegmentation: vi.fn(),
+  saveSegmentation: vi.fn(),
+  saveSegmentationMatte: vi.fn(),
+}))
+const resolveMediaUrl = vi.hoisted(() => vi.fn())
+
+vi.mock('@/infrastructure/storage/workspace-fs/segmentation', () => segmentationMocks)
+vi.mock('@/features/effects/deps/media-library-contract', () => ({ resolveMediaUrl }))
+
+import {
+  createStubSegmentationProvider,
+} from '@/infrastructure/analysis/segmentation/stub-provider'
+import {
+  analyzeClipSegmentation,
+  isClipSegmented,
+  mergeSourceRanges,
+} from './segmentation-analysis'
+
+function createClip(overrides: Partial<VideoItem> = {}): VideoItem {
+  return {
+    id: 'clip-1',
+    type: 'video',
+    trackId: 'track-1',
+    from: 0,
+    durationInFrames: 90,
+    label: 'clip.mp4',
+    src: 'blob:clip',
+    mediaId: 'media-1',
+    sourceStart: 60,
+    sourceEnd: 150,
+    sourceFps: 30,
+    ...overrides,
+  } as VideoItem
+}
+
+const provider = createStubSegmentationProvider()
+
+function createRecord(ranges: Array<{ start: number; end: number }>, model = provider.modelId) {
+  return { model, data: { fps: 30, width: 384, height: 216, ranges } }
+}
+
+describe('mergeSourceRanges', () => {
+  it('sorts and merges overlapping and touching ranges', () => {
+    expect(
+      mergeSourceRanges([
+        { start: 4, end: 6 },
+        { start: 0, end: 1 },
+        { start: 1.02, end: 2 },
+        { start: 5, end: 8 },
+      ]),
+    ).toEqual([
+      { start: 0, end: 2 },
+      { start: 4, end: 8 },
+    ])
+  })
+})
+
+describe('analyzeClipSegmentation', () => {
+  beforeEach(() => {
+    for (const mock of Object.values(segmentationMocks)) mock.mockReset()
+    resolveMediaUrl.mockReset()
+  })
+
+  it('reuses mattes that already cover the clip', async () => {
+    segmentationMocks.getSegmentation.mockResolvedValue(createRecord([{ start: 0, end: 10 }]))
+
+    await expect(
+      analyzeClipSegmentation(createClip(), undefined, { fps: 30, provider }),
+    ).resolves.toBe(true)
+    await expect(isClipSegmented(createClip(), undefined, 30)).resolves.toBe(true)
+    expect(resolveMediaUrl).not.toHaveBeenCalled()
+  })
+
+  it('reports partially analyzed clips as not segmented', async () => {
+    segmentationMocks.getSegmentation.mockResolvedValue(createRecord([{ start: 0, end: 3 }]))
+
+    await expect(isClipSegmented(createClip(), undefined, 30)).resolves.toBe(false)
+  })
+
+  it('discards mattes made by another model', async () => {
+    segmentationMocks.getSegmentation.mockResolvedValue(
+      createRecord([{ start: 0, end: 10 }], 'other-model'),
+    )
+    resolveMediaUrl.mockRejectedValue(new Error('offline'))
+
+    await expect(
+      analyzeClipSegmentation(createClip(), undefined, { fps: 30, provider }),
+    ).rejects.toThrow('offline')
+    expect(segmentationMocks.deleteSegmentation).toHaveBeenCalledWith('media-1')
+  })
+
+  it('skips clips without source media', async () => {
+    await expect(
+      analyzeClipSegmentation(createClip({ mediaId: undefined }), undefined, { fps: 30 }),
+    ).resolves.toBe(false)
+    expect(segmentationMocks.getSegmentation).not.toHaveBeenCalled()
+  })
+})
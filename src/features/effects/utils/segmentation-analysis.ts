@@ -0,0 +1,152 @@
+import type { TimelineItem } from '@/types/timeline'
+import type { ItemKeyframes } from '@/types/keyframe'
+import type { SegmentationProvider } from '@/infrastructure/analysis/segmentation/types'
+import { getMatteSize } from '@/infrastructure/analysis/segmentation/matte'
+import { segmentVideo } from '@/infrastructure/analysis/segmentation/segment-video'
+import {
+  deleteSegmentation,
+  getSegmentation,
+  saveSegmentation,
+  saveSegmentationMatte,
+} from '@/infrastructure/storage/workspace-fs/segmentation'
+import { createLogger } from '@/shared/logging/logger'
+import { resolveMediaUrl } from '@/features/effects/deps/media-library-contract'
+import { getClipSourceRange, loadAnalysisVideo } from './stabilization-analysis'
+
+const logger = createLogger('SegmentationAnalysis')
+
+/** Mattes are generated at most this often per source second. */
+const MAX_SEGMENTATION_FPS = 30
+/** Tolerance when checking whether analyzed ranges cover the clip */
+const RANGE_EPSILON_SECONDS = 0.05
+
+type SourceRange = { start: number; end: number }
+
+// The provider spins up the model worker; only load it when analysis runs
+const importSegmentationProvider = () =>
+  import('@/infrastructure/analysis/segmentation/segmentation-provider')
+
+/** Sort and merge overlapping or touching source ranges. */
+export function mergeSourceRanges(ranges: SourceRange[]): SourceRange[] {
+  const sorted = [...ranges].sort((a, b) => a.start - b.start)
+  const merged: SourceRange[] = []
+  for (const range of sorted) {
+    const last = merged[merged.length - 1]
+    if (last && range.start <= last.end + RANGE_EPSILON_SECONDS) {
+      last.end = Math.max(last.end, range.end)
+    } else {
+      merged.push({ ...range })
+    }
+  }
+  return merged
+}
+
+function coversRange(ranges: SourceRange[], range: SourceRange): boolean {
+  return mergeSourceRanges(ranges).some(
+    (entry) =>
+      entry.start <= range.start + RANGE_EPSILON_SECONDS &&
+      entry.end >= range.end - RANGE_EPSILON_SECONDS,
+  )
+}
+
+/** Whether every source frame the clip shows already has a subject matte. */
+export async function isClipSegmented(
+  item: TimelineItem,
+  itemKeyframes: ItemKeyframes | undefined,
+  fps: number,
+): Promise<boolean> {
+  if (item.type !== 'video' || !item.mediaId) return false
+  const existing = await getSegmentation(item.mediaId).catch(() => undefined)
+  if (!existing) return false
+  return coversRange(existing.data.ranges, getClipSourceRange(item, itemKeyframes, fps))
+}
+
+/**
+ * Generate subject mattes for the source range a video clip shows and store
+ * them in the media's AI cache. Frames analyzed before are skipped; a model
+ * or matte-grid change discards the old mattes first. Resolves with false
+ * when aborted or when the clip has no source media.
+ */
+export async function analyzeClipSegmentation(
+  item: TimelineItem,
+  itemKeyframes: ItemKeyframes | undefined,
+  options: {
+    fps: number
+    provider?: SegmentationProvider
+    onProgress?: (percent: number) => void
+    signal?: AbortSignal
+  },
+): Promise<boolean> {
+  if (item.type !== 'video' || !item.mediaId) return false
+
+  const mediaId = item.mediaId
+  const { fps, onProgress, signal } = options
+  const range = getClipSourceRange(item, itemKeyframes, fps)
+  const analysisFps = Math.min(item.sourceFps ?? fps, MAX_SEGMENTATION_FPS)
+
+  let video: HTMLVideoElement | null = null
+  try {
+    const provider = options.provider ?? (await importSegmentationProvider()).segmentationProvider
+
+    let existing = await getSegmentation(mediaId).catch(() => undefined)
+    if (existing && (existing.model !== provider.modelId || existing.data.fps !== analysisFps)) {
+      await deleteSegmentation(mediaId)
+      existing = undefined
+    }
+    if (existing && coversRange(existing.data.ranges, range)) {
+      return true
+    }
+
+    video = await loadAnalysisVideo(await resolveMediaUrl(mediaId), signal)
+    const grid = getMatteSize(video.videoWidth, video.videoHeight)
+    if (existing && (existing.data.width !== grid.width || existing.data.height !== grid.height)) {
+      await deleteSegmentation(mediaId)
+      existing = undefined
+    }
+
+    const analyzedRanges = existing?.data.ranges ?? []
+    const isAnalyzed = (frameIndex: number) =>
+      analyzedRanges.some(
+        (entry) =>
+          frameIndex >= Math.floor(entry.start * analysisFps) &&
+          frameIndex <= Math.ceil(entry.end * analysisFps),
+      )
+    const endTime = Math.min(range.end, video.duration || range.end)
+
+    await segmentVideo(video, {
+      startTime: range.start,
+      endTime,
+      fps: analysisFps,
+      provider,
+      hasMatte: isAnalyzed,
+      onMatte: (frameIndex, matte) =>
+        saveSegmentationMatte(mediaId, analysisFps, frameIndex, matte),
+      onProgress,
+      signal,
+    })
+    if (signal?.aborted) return false
+
+    await saveSegmentation({
+      mediaId,
+      model: provider.modelId,
+      data: {
+        fps: analysisFps,
+        width: grid.width,
+        height: grid.height,
+        ranges: mergeSourceRanges([...analyzedRanges, { start: range.start, end: endTime }]),
+      },
+    })
+    logger.info('Segmented clip', { mediaId, start: range.start, end: endTime })
+    return true
+  } catch (error) {
+    if (signal?.aborted) return false
+    if (error instanceof DOMException && error.name === 'AbortError') return false
+    throw error
+  } finally {
+    if (video) {
+      video.onloadedmetadata = null
+      video.onerror = null
+      video.src = ''
+    }
+  }
+}
@@ -0,0 +1,77 @@
+import { beforeEach, describe, expect, it, vi } from 'vite-plus/test'
+import type { VideoItem } from '@/types/timeline'
+
+const stabilizationMocks = vi.hoisted(() => ({
+  getStabilization: vi.fn(),
+  saveStabilization: vi.fn(),
+}))
+const resolveMediaUrl = vi.hoisted(() => vi.fn())
+
+vi.mock('@/infrastructure/storage/workspace-fs/stabilization', () => stabilizationMocks)
+vi.mock('@/features/effects/deps/media-library-contract', () => ({ resolveMediaUrl }))
+
+import { analyzeClipStabilization, getClipSourceRange } from './stabilization-analysis'
+
+function createClip(overrides: Partial<VideoItem> = {}): VideoItem {
+  return {
+    id: 'clip-1',
+    type: 'video',
+    trackId: 'track-1',
+    from: 0,
+    durationInFrames: 90,
+    label: 'clip.mp4',
+    src: 'blob:clip',
+    mediaId: 'media-1',
+    sourceStart: 60,
+    sourceEnd: 150,
+    sourceFps: 30,
+    ...overrides,
+  } as VideoItem
+}
+
+const cachedPath = {
+  fps: 30,
+  startTime: 0,
+  aspect: 16 / 9,
+  x: [0],
+  y: [0],
+  rotation: [0],
+  scale: [1],
+}
+
+describe('getClipSourceRange', () => {
+  it('maps the clip span to source seconds', () => {
+    expect(getClipSourceRange(createClip(), undefined, 30)).toEqual({ start: 2, end: 5 })
+  })
+
+  it('accounts for playback speed', () => {
+    expect(getClipSourceRange(createClip({ speed: 2 }), undefined, 30)).toEqual({
+      start: 2,
+      end: 8,
+    })
+  })
+})
+
+describe('analyzeClipStabilization', () => {
+  beforeEach(() => {
+    stabilizationMocks.getStabilization.mockReset()
+    stabilizationMocks.saveStabilization.mockReset()
+    resolveMediaUrl.mockReset()
+  })
+
+  it('reuses a cached path that covers the clip', async () => {
+    stabilizationMocks.getStabilization.mockResolvedValue({ endTime: 10, path: cachedPath })
+
+    await expect(analyzeClipStabilization(createClip(), undefined, { fps: 30 })).resolves.toBe(
+      cachedPath,
+    )
+    expect(resolveMediaUrl).not.toHaveBeenCalled()
+  })
+
+  it('skips clips without source media', async () => {
+    await expect(
+      analyzeClipStabilization(createClip({ mediaId: undefined }), undefined, { fps: 30 }),
+    ).resolves.toBeNull()
+    expect(stabilizationMocks.getStabilization).not.toHaveBeenCalled()
+  })
+})
@@ -0,0 +1,134 @@
+import type { TimelineItem } from '@/types/timeline'
+import type { ItemKeyframes } from '@/types/keyframe'
+import type { CameraPath } from '@/infrastructure/analysis/stabilization'
+import {
+  getStabilization,
+  saveStabilization,
+} from '@/infrastructure/storage/workspace-fs/stabilization'
+import { createLogger } from '@/shared/logging/logger'
+import {
+  getItemNaturalSourceSeconds,
+  getTimeRemapSourceRange,
+  resolveTimeRemapCurve,
+} from '@/features/effects/deps/keyframes-contract'
+import { resolveMediaUrl } from '@/features/effects/deps/media-library-contract'
+
+const logger = createLogger('StabilizationAnalysis')
+
+/** Higher-rate footage is subsampled; the smoothing window is seconds long anyway. */
+const MAX_ANALYSIS_FPS = 30
+/** Tolerance when checking whether a cached path covers the clip */
+const RANGE_EPSILON_SECONDS = 0.05
+
+const importStabilization = () => import('@/infrastructure/analysis/stabilization')
+
+/** Source seconds shown by the clip, including any time-remap excursions. */
+export function getClipSourceRange(
+  item: TimelineItem,
+  itemKeyframes: ItemKeyframes | undefined,
+  fps: number,
+): { start: number; end: number } {
+  const first = getItemNaturalSourceSeconds(item, 0, fps)
+  const last = getItemNaturalSourceSeconds(item, item.durationInFrames, fps)
+  let start = Math.min(first, last)
+  let end = Math.max(first, last)
+
+  const curve = resolveTimeRemapCurve(item, itemKeyframes, fps)
+  if (curve) {
+    const remapped = getTimeRemapSourceRange(curve, 0, item.durationInFrames)
+    start = Math.min(start, remapped.start)
+    end = Math.max(end, remapped.end)
+  }
+
+  return { start: Math.max(0, start), end }
+}
+
+/** Detached, muted video element for frame-by-frame clip analysis. */
+export function loadAnalysisVideo(
+  url: string,
+  signal: AbortSignal | undefined,
+): Promise<HTMLVideoElement> {
+  const video = document.createElement('video')
+  video.src = url
+  video.muted = true
+  video.preload = 'auto'
+
+  return new Promise((resolve, reject) => {
+    if (signal?.aborted) {
+      reject(new DOMException('Aborted', 'AbortError'))
+      return
+    }
+    const onAbort = () => reject(new DOMException('Aborted', 'AbortError'))
+    signal?.addEventListener('abort', onAbort, { once: true })
+    video.onloadedmetadata = () => {
+      signal?.removeEventListener('abort', onAbort)
+      resolve(video)
+    }
+    video.onerror = () => {
+      signal?.removeEventListener('abort', onAbort)
+      reject(new Error('Failed to load video for analysis'))
+    }
+  })
+}
+
+/**
+ * Camera path covering a video clip. Reuses the per-media cache when it spans
+ * the clip's source range, otherwise analyzes the range and caches the result.
+ * Resolves with null when aborted.
+ */
+export async function analyzeClipStabilization(
+  item: TimelineItem,
+  itemKeyframes: ItemKeyframes | undefined,
+  options: {
+    fps: number
+    onProgress?: (percent: number) => void
+    signal?: AbortSignal
+  },
+): Promise<CameraPath | null> {
+  if (item.type !== 'video' || !item.mediaId) return null
+
+  const mediaId = item.mediaId
+  const { fps, onProgress, signal } = options
+  const range = getClipSourceRange(item, itemKeyframes, fps)
+  const analysisFps = Math.min(item.sourceFps ?? fps, MAX_ANALYSIS_FPS)
+
+  const cached = await getStabilization(mediaId).catch(() => undefined)
+  if (
+    cached &&
+    cached.path.fps === analysisFps &&
+    cached.path.startTime <= range.start + RANGE_EPSILON_SECONDS &&
+    cached.endTime >= range.end - RANGE_EPSILON_SECONDS
+  ) {
+    return cached.path
+  }
+
+  let video: HTMLVideoElement | null = null
+  try {
+    video = await loadAnalysisVideo(await resolveMediaUrl(mediaId), signal)
+    const { analyzeCameraPath } = await importStabilization()
+    const endTime = Math.min(range.end, video.duration || range.end)
+    const path = await analyzeCameraPath(video, {
+      startTime: range.start,
+      endTime,
+      fps: analysisFps,
+      onProgress,
+      signal,
+    })
+    if (signal?.aborted) return null
+
+    // Fire-and-forget: the path is embedded in the effect either way
+    void saveStabilization({ mediaId, path, endTime }).catch((error) =>
+      logger.warn('Failed to persist stabilization', error),
+    )
+    return path
+  } catch (error) {
+    if (error instanceof DOMException && error.name === 'AbortError') return null
+    throw error
+  } finally {
+    if (video) {
+      video.onloadedmetadata = null
+      video.onerror = null
+      video.src = ''
+    }
+  }
+}
@@ -33,6 +33,9 @@ import {
   Scissors,
   ListPlus,
   ChevronDown,
+  Image as ImageIcon,
+  Gauge,
+  RectangleVertical,
 } from 'lucide-react'
 import {
   DropdownMenu,
@@ -44,26 +47,35 @@ import {
 } from '@/components/ui/dropdown-menu'
 import { toast } from 'sonner'
 import type {
+  AnimatedImageContainer,
+  AnimatedImageOptions,
   ExportSettings,
   ExportMode,
   ExtendedExportSettings,
   CompositionInputProps,
+  ExportLoudnessReport,
+  LoudnessTargetPreset,
 } from '@/types/export'
 import { useClientRender } from '../hooks/use-client-render'
 import {
+  buildCanvasVariantJobs,
   buildRenderJob,
   buildSegmentJobs,
   rangesFromFixedDuration,
   rangesFromMarkers,
 } from '../utils/build-render-job'
 import { useRenderQueueStore, type RenderJob } from '../stores/render-queue-store'
-import { useProjectStore } from '@/features/export/deps/projects'
+import { getCanvasVariantResolution, useProjectStore } from '@/features/export/deps/projects'
 import { DEFAULT_PROJECT_HEIGHT, DEFAULT_PROJECT_WIDTH } from '@/shared/projects/defaults'
+import { DEFAULT_PROJECT_COLOR_SPACE, isHdrColorSpace } from '@/types/color'
 import { useTimelineStore } from '@/features/export/deps/timeline'
 import { formatTimecode, framesToSeconds } from '@/shared/utils/time-utils'
+import { formatLoudness, getLoudnessNormalizationGain } from '@/shared/utils/audio-loudness'
 import type { ExportPreflightResult } from '../utils/export-preflight'
 import { assessExportPreflight, summarizePreflightSeverity } from '../utils/export-preflight'
 import {
+  DEFAULT_ANIMATED_IMAGE_OPTIONS,
+  LOUDNESS_TARGET_PRESETS,
   getCompatibleVideoCodecs,
   getDefaultVideoCodec,
   mapExportCodecToClientCodec,
@@ -73,6 +85,9 @@ import {
 } from '../utils/client-renderer'
 import { ExportPreviewPlayer } from './export-preview-player'
 import { useBrokenMediaIds } from '../deps/media-library'
+import { convertTimelineToComposition } from '../utils/timeline-to-composition'
+import type { SidecarSubtitleFormat } from '../utils/sidecar-subtitle-export'
+import { getScenes } from '@/infrastructure/storage'
 
 export interface ExportDialogProps {
   open: boolean
@@ -83,6 +98,19 @@ export interface ExportDialogProps {
 
 type DialogView = 'settings' | 'progress' | 'complete' | 'error' | 'cancelled'
 
+/** Select value for the project's own canvas (variant ids are UUIDs) */
+const BASE_CANVAS_VALUE = 'base'
+
+const ANIMATED_IMAGE_FPS_OPTIONS = [10, 12, 15, 24, 30] as const
+const ANIMATED_IMAGE_LOOP_OPTIONS = [0, 1, 2, 3, 5] as const
+
+const LOUDNESS_TARGET_LABEL_KEYS: Record<LoudnessTargetPreset, string> = {
+  streaming: 'export.settings.loudnessTargetStreaming',
+  podcast: 'export.settings.loudnessTargetPodcast',
+  'ebu-r128': 'export.settings.loudnessTargetEbuR128',
+  'atsc-a85': 'export.settings.loudnessTargetAtscA85',
+}
+
 type VideoContainerOption = {
   value: ClientVideoContainer
   label: string
@@ -289,14 +317,113 @@ function ExportPreflightPanel({ preflight }: { preflight: ExportPreflightResult
   )
 }
 
+function formatGain(db: number): string {
+  return `${db >= 0 ? '+' : ''}${db.toFixed(1)} dB`
+}
+
+interface LoudnessSettingsProps {
+  target: LoudnessTargetPreset | null
+  onTargetChange: (target: LoudnessTargetPreset | null) => void
+  analysis: ExportLoudnessReport | null | undefined
+  isAnalyzing: boolean
+  onAnalyze: () => void
+}
+
+/**
+ * Normalize-on-export target plus an on-demand BS.1770 analysis of the export
+ * range. `analysis` is undefined until analyzed and null when there's no audio.
+ */
+function LoudnessSettings({
+  target,
+  onTargetChange,
+  analysis,
+  isAnalyzing,
+  onAnalyze,
+}: LoudnessSettingsProps) {
+  const { t } = useTranslation()
+  const normalization =
+    analysis && target
+      ? getLoudnessNormalizationGain(analysis, LOUDNESS_TARGET_PRESETS[target])
+      : null
+
+  return (
+    <div className="space-y-2">
+      <Label htmlFor="loudness-target">{t('export.settings.loudnessTarget')}</Label>
+      <div className="flex gap-2">
+        <Select
+          value={target ?? 'off'}
+          onValueChange={(value) =>
+            onTargetChange(value === 'off' ? null : (value as LoudnessTargetPreset))
+          }
+        >
+          <SelectTrigger id="loudness-target" className="flex-1">
+            <SelectValue />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value="off">{t('export.settings.loudnessTargetOff')}</SelectItem>
+            {(Object.keys(LOUDNESS_TARGET_LABEL_KEYS) as LoudnessTargetPreset[]).map((preset) => (
+              <SelectItem key={preset} value={preset}>
+                {t(LOUDNESS_TARGET_LABEL_KEYS[preset])}
+              </SelectItem>
+            ))}
+          </SelectContent>
+        </Select>
+        <Button variant="outline" onClick={onAnalyze} disabled={isAnalyzing}>
+          {isAnalyzing ? (
+            <Loader2 className="mr-2 h-4 w-4 animate-spin" />
+          ) : (
+            <Gauge className="mr-2 h-4 w-4" />
+          )}
+          {isAnalyzing
+            ? t('export.settings.analyzingLoudness')
+            : t('export.settings.analyzeLoudness')}
+        </Button>
+      </div>
+
+      {analysis === null && (
+        <p className="text-xs text-muted-foreground">{t('export.settings.loudnessNoAudio')}</p>
+      )}
+      {analysis && (
+        <div className="grid grid-cols-2 gap-x-4 gap-y-1 rounded-lg border border-border bg-muted/20 p-3 font-mono text-xs sm:grid-cols-4">
+          <div>
+            <div className="text-muted-foreground">{t('export.settings.loudnessIntegrated')}</div>
+            <div>{formatLoudness(analysis.integratedLufs)}</div>
+          </div>
+          <div>
+            <div className="text-muted-foreground">{t('export.settings.loudnessShortTermMax')}</div>
+            <div>{formatLoudness(analysis.shortTermMaxLufs)}</div>
+          </div>
+          <div>
+            <div className="text-muted-foreground">{t('export.settings.loudnessMomentaryMax')}</div>
+            <div>{formatLoudness(analysis.momentaryMaxLufs)}</div>
+          </div>
+          <div>
+            <div className="text-muted-foreground">{t('export.settings.loudnessTruePeak')}</div>
+            <div>{formatLoudness(analysis.truePeakDbtp, 'dBTP')}</div>
+          </div>
+          {normalization && (
+            <div className="col-span-full text-muted-foreground">
+              {t('export.settings.loudnessGain', { gain: formatGain(normalization.gainDb) })}
+            </div>
+          )}
+        </div>
+      )}
+    </div>
+  )
+}
+
 export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogProps) {
   const { t } = useTranslation()
-  const projectWidth = useProjectStore(
+  const baseWidth = useProjectStore(
     (s) => s.currentProject?.metadata.width ?? DEFAULT_PROJECT_WIDTH,
   )
-  const projectHeight = useProjectStore(
+  const baseHeight = useProjectStore(
     (s) => s.currentProject?.metadata.height ?? DEFAULT_PROJECT_HEIGHT,
   )
+  const canvasVariants = useProjectStore((s) => s.currentProject?.canvasVariants)
+  const colorSpace = useProjectStore(
+    (s) => s.currentProject?.metadata.colorSpace ?? DEFAULT_PROJECT_COLOR_SPACE,
+  )
   // Timeline state for in/out points and duration calculation
   const fps = useTimelineStore((s) => s.fps)
   const tracks = useTimelineStore((s) => s.tracks ?? [])
@@ -312,19 +439,44 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
   const [settings, setSettings] = useState<ExportSettings>({
     codec: getDefaultCodecForFormat('mp4'),
     quality: 'high',
-    resolution: { width: projectWidth, height: projectHeight },
+    resolution: { width: baseWidth, height: baseHeight },
   })
 
   const [exportMode, setExportMode] = useState<ExportMode>('video')
   const [videoContainer, setVideoContainer] = useState<ClientVideoContainer>('mp4')
   const [audioContainer, setAudioContainer] = useState<ClientAudioContainer>('mp3')
+  const [animatedImageContainer, setAnimatedImageContainer] =
+    useState<AnimatedImageContainer>('gif')
+  const [animatedImage, setAnimatedImage] = useState<AnimatedImageOptions>(
+    DEFAULT_ANIMATED_IMAGE_OPTIONS,
+  )
   const [view, setView] = useState<DialogView>('settings')
   const [startTime, setStartTime] = useState<number | null>(null)
   const [elapsedSeconds, setElapsedSeconds] = useState(0)
   const [embedSubtitles, setEmbedSubtitles] = useState(true)
+  const [sidecarSubtitles, setSidecarSubtitles] = useState<SidecarSubtitleFormat | null>(null)
+  const [sceneCuts, setSceneCuts] = useState<ReadonlyMap<string, readonly number[]>>()
+  const [hdr, setHdr] = useState(true)
   const [renderWholeProject, setRenderWholeProject] = useState(false)
+  const [loudnessTarget, setLoudnessTarget] = useState<LoudnessTargetPreset | null>(null)
+  const [loudnessAnalysis, setLoudnessAnalysis] = useState<ExportLoudnessReport | null>()
+  const [isAnalyzingLoudness, setIsAnalyzingLoudness] = useState(false)
+  const [canvasVariantId, setCanvasVariantId] = useState<string | null>(null)
+  const loudnessAbortRef = useRef<AbortController | null>(null)
   const wasOpenRef = useRef(false)
 
+  // The canvas being exported: the project's own or one of its variants.
+  // Resolution presets and options below are relative to it.
+  const canvasVariant = canvasVariants?.find((variant) => variant.id === canvasVariantId)
+  const canvasResolution = canvasVariant
+    ? getCanvasVariantResolution(
+        { width: baseWidth, height: baseHeight, fps },
+        canvasVariant.aspect,
+      )
+    : null
+  const projectWidth = canvasResolution?.width ?? baseWidth
+  const projectHeight = canvasResolution?.height ?? baseHeight
+
   // Calculate timeline duration from items
   const timelineDurationFrames = useMemo(() => {
     if (items.length === 0) return 0
@@ -337,15 +489,24 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
     () =>
       items.some(
         (item) =>
-          (item.type === 'subtitle' && item.source.type === 'transcript') ||
+          (item.type === 'subtitle' &&
+            (item.source.type === 'transcript' || item.source.type === 'translation')) ||
           ((item.type === 'video' || item.type === 'audio') &&
             item.transcriptCaptions?.enabled === true &&
             item.transcriptCaptions.type === 'transcript'),
       ),
     [items],
   )
+  const hasSubtitleItems = useMemo(
+    () => hasTranscriptSubtitles || items.some((item) => item.type === 'subtitle'),
+    [hasTranscriptSubtitles, items],
+  )
   const containerSupportsEmbeddedSubtitles =
     videoContainer === 'mp4' || videoContainer === 'webm' || videoContainer === 'mkv'
+  // HDR delivery follows the project's PQ/HLG working space; only 10-bit codecs carry it.
+  const isHdrProject = isHdrColorSpace(colorSpace)
+  const codecSupportsHdr = settings.codec === 'h265' || settings.codec === 'av1'
+  const exportHdr = exportMode === 'video' && isHdrProject && codecSupportsHdr && hdr
 
   // Calculate export range
   const exportRange = useMemo(() => {
@@ -355,18 +516,60 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
     return { start: inPoint, end: outPoint, duration: outPoint - inPoint }
   }, [hasInOutPoints, inPoint, outPoint, renderWholeProject, timelineDurationFrames])
 
-  const preflightComposition = useMemo<CompositionInputProps>(
-    () => ({
-      fps,
-      durationInFrames: exportRange.duration,
-      width: projectWidth,
-      height: projectHeight,
+  // Same trimming as the render, so preflight sees the items (and subtitle cues) in range.
+  const preflightComposition = useMemo<CompositionInputProps>(() => {
+    if (!open) {
+      return { fps, durationInFrames: 0, width: projectWidth, height: projectHeight, tracks: [] }
+    }
+    const useRange = !renderWholeProject && hasInOutPoints
+    return convertTimelineToComposition(
       tracks,
+      items,
       transitions,
+      fps,
+      projectWidth,
+      projectHeight,
+      useRange ? inPoint : null,
+      useRange ? outPoint : null,
       keyframes,
-    }),
-    [exportRange.duration, fps, keyframes, projectHeight, projectWidth, tracks, transitions],
-  )
+    )
+  }, [
+    fps,
+    hasInOutPoints,
+    inPoint,
+    items,
+    keyframes,
+    open,
+    outPoint,
+    projectHeight,
+    projectWidth,
+    renderWholeProject,
+    tracks,
+    transitions,
+  ])
+
+  // Detected shot changes for caption QC; media without scene detection are skipped.
+  useEffect(() => {
+    if (!open) return
+    const mediaIds = new Set<string>()
+    for (const item of items) {
+      if (item.type === 'video' && item.mediaId) mediaIds.add(item.mediaId)
+    }
+
+    let cancelled = false
+    void Promise.all(
+      [...mediaIds].map(async (mediaId) => {
+        const scenes = await getScenes(mediaId).catch(() => undefined)
+        return [mediaId, scenes?.cuts.map((cut) => cut.time) ?? []] as const
+      }),
+    ).then((entries) => {
+      if (!cancelled) setSceneCuts(new Map(entries.filter(([, cuts]) => cuts.length > 0)))
+    })
+
+    return () => {
+      cancelled = true
+    }
+  }, [items, open])
 
   const resolutionOptions = useMemo(
     () => getResolutionOptions(projectWidth, projectHeight, t),
@@ -427,9 +630,36 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
     cancelExport,
     downloadVideo,
     resetState,
+    analyzeLoudness,
     getSupportedCodecs,
   } = clientRender
 
+  // A measurement only describes the range it was taken over.
+  useEffect(() => {
+    loudnessAbortRef.current?.abort()
+    setLoudnessAnalysis(undefined)
+  }, [exportRange.start, exportRange.end, open])
+
+  const handleAnalyzeLoudness = async () => {
+    loudnessAbortRef.current?.abort()
+    const controller = new AbortController()
+    loudnessAbortRef.current = controller
+    setIsAnalyzingLoudness(true)
+    try {
+      const report = await analyzeLoudness(renderWholeProject, controller.signal)
+      if (!controller.signal.aborted) setLoudnessAnalysis(report)
+    } catch (err) {
+      if (!controller.signal.aborted) {
+        toast.error(err instanceof Error ? err.message : t('export.settings.loudnessAnalyzeFailed'))
+      }
+    } finally {
+      if (loudnessAbortRef.current === controller) {
+        loudnessAbortRef.current = null
+        setIsAnalyzingLoudness(false)
+      }
+    }
+  }
+
   const [supportedVideoCodecs, setSupportedVideoCodecs] = useState<ClientCodec[] | null>(null)
   const [isCheckingVideoSupport, setIsCheckingVideoSupport] = useState(false)
   const [videoSupportError, setVideoSupportError] = useState<string | null>(null)
@@ -482,11 +712,19 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
     mode: exportMode,
     videoContainer: exportMode === 'video' ? videoContainer : undefined,
     audioContainer: exportMode === 'audio' ? audioContainer : undefined,
+    animatedImageContainer: exportMode === 'animated-image' ? animatedImageContainer : undefined,
+    animatedImage: exportMode === 'animated-image' ? animatedImage : undefined,
     embedSubtitles:
       exportMode === 'video' && hasTranscriptSubtitles && containerSupportsEmbeddedSubtitles
         ? embedSubtitles
         : false,
+    sidecarSubtitles:
+      exportMode === 'video' && hasSubtitleItems ? (sidecarSubtitles ?? undefined) : undefined,
     renderWholeProject,
+    hdr: exportHdr || undefined,
+    loudnessTarget:
+      exportMode === 'video' || exportMode === 'audio' ? (loudnessTarget ?? undefined) : undefined,
+    canvasVariantId: exportMode !== 'audio' ? canvasVariant?.id : undefined,
   })
 
   // Start export
@@ -537,6 +775,11 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
     void enqueueAndReveal(async (settings) => [await buildRenderJob({ settings, ...queueRange() })])
   }
 
+  const handleAddAllCanvases = () => {
+    const { inPoint, outPoint } = queueRange()
+    void enqueueAndReveal((settings) => buildCanvasVariantJobs(settings, inPoint, outPoint))
+  }
+
   const handleAddMarkerSegments = () => {
     const { start, end } = segmentWindow()
     const ranges = rangesFromMarkers(markers, start, end)
@@ -568,12 +811,17 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
       setExportMode('video')
       setVideoContainer('mp4')
       setAudioContainer('mp3')
+      setAnimatedImageContainer('gif')
+      setAnimatedImage(DEFAULT_ANIMATED_IMAGE_OPTIONS)
       setEmbedSubtitles(true)
+      setHdr(true)
       setRenderWholeProject(false)
+      setLoudnessTarget(null)
+      setCanvasVariantId(null)
       setSettings({
         codec: getDefaultCodecForFormat('mp4'),
         quality: 'high',
-        resolution: { width: projectWidth, height: projectHeight },
+        resolution: { width: baseWidth, height: baseHeight },
       })
       resetState()
       setPreflight(null)
@@ -590,7 +838,7 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
     }
 
     wasOpenRef.current = open
-  }, [open, projectHeight, projectWidth, resetState])
+  }, [open, baseHeight, baseWidth, resetState])
 
   const getAudioContainerOptions = () => [
     { value: 'mp3', label: 'MP3', description: t('export.audioContainer.mp3') },
@@ -598,6 +846,11 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
     { value: 'wav', label: 'WAV', description: t('export.audioContainer.wav') },
   ]
 
+  const getAnimatedImageContainerOptions = () => [
+    { value: 'gif', label: 'GIF', description: t('export.animatedImageContainer.gif') },
+    { value: 'webp', label: 'WebP', description: t('export.animatedImageContainer.webp') },
+  ]
+
   useEffect(() => {
     if (!open || view !== 'settings' || exportMode !== 'video') return
 
@@ -704,11 +957,20 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
       mode: exportMode,
       videoContainer: exportMode === 'video' ? videoContainer : undefined,
       audioContainer: exportMode === 'audio' ? audioContainer : undefined,
+      animatedImageContainer: exportMode === 'animated-image' ? animatedImageContainer : undefined,
+      animatedImage: exportMode === 'animated-image' ? animatedImage : undefined,
       embedSubtitles:
         exportMode === 'video' && hasTranscriptSubtitles && containerSupportsEmbeddedSubtitles
           ? embedSubtitles
           : false,
+      sidecarSubtitles:
+        exportMode === 'video' && hasSubtitleItems ? (sidecarSubtitles ?? undefined) : undefined,
       renderWholeProject,
+      hdr: exportHdr || undefined,
+      loudnessTarget:
+        exportMode === 'video' || exportMode === 'audio'
+          ? (loudnessTarget ?? undefined)
+          : undefined,
     }
 
     void assessExportPreflight({
@@ -718,6 +980,9 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
       durationFrames: exportRange.duration,
       supportedVideoCodecs: supportedVideoCodecs ?? [],
       brokenMediaIds,
+      loudness: loudnessAnalysis ?? undefined,
+      sceneCuts,
+      rangeStartFrame: exportRange.start,
     }).then((result) => {
       if (!cancelled) setPreflight(result)
     })
@@ -726,18 +991,27 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
       cancelled = true
     }
   }, [
+    animatedImage,
+    animatedImageContainer,
     audioContainer,
     brokenMediaIds,
     embedSubtitles,
+    exportHdr,
     exportMode,
     exportRange.duration,
+    exportRange.start,
     fps,
+    hasSubtitleItems,
     hasTranscriptSubtitles,
     containerSupportsEmbeddedSubtitles,
+    loudnessAnalysis,
+    loudnessTarget,
     open,
     preflightComposition,
     renderWholeProject,
+    sceneCuts,
     settings,
+    sidecarSubtitles,
     supportedVideoCodecs,
     videoContainer,
     videoSupportError,
@@ -752,6 +1026,7 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
 
   const preventClose = view === 'progress' || view === 'complete'
   const fileSize = clientRender.result?.fileSize
+  const resultLoudness = clientRender.result?.loudness
 
   // Preview blob URL for completed exports
   const [previewUrl, setPreviewUrl] = useState<string | null>(null)
@@ -768,6 +1043,7 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
   }, [clientRender.result?.blob])
 
   const isVideoResult = clientRender.result?.mimeType?.startsWith('video/') ?? false
+  const isImageResult = clientRender.result?.mimeType?.startsWith('image/') ?? false
 
   // Dynamic title and description
   const getTitle = () => {
@@ -826,7 +1102,7 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
         className={`overflow-hidden ${
           view === 'settings'
             ? 'sm:max-w-[900px]'
-            : view === 'complete' && isVideoResult
+            : view === 'complete' && (isVideoResult || isImageResult)
               ? 'sm:max-w-[640px]'
               : 'sm:max-w-[500px]'
         }`}
@@ -872,9 +1148,60 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
                       <Music className="h-3.5 w-3.5" />
                       {t('export.settings.audio')}
                     </button>
+                    <button
+                      type="button"
+                      onClick={() => setExportMode('animated-image')}
+                      className={`flex items-center gap-1.5 px-3 py-1 text-xs font-medium rounded transition-colors ${
+                        exportMode === 'animated-image'
+                          ? 'bg-background text-foreground shadow-sm'
+                          : 'text-muted-foreground hover:text-foreground'
+                      }`}
+                    >
+                      <ImageIcon className="h-3.5 w-3.5" />
+                      {t('export.settings.animatedImage')}
+                    </button>
                   </div>
                 </div>
 
+                {/* Canvas: base or one of the project's linked aspect-ratio variants */}
+                {exportMode !== 'audio' && canvasVariants && canvasVariants.length > 0 && (
+                  <div className="flex items-center justify-between gap-3">
+                    <Label htmlFor="export-canvas" className="flex items-center gap-1.5">
+                      <RectangleVertical className="h-3.5 w-3.5 text-muted-foreground" />
+                      {t('export.settings.canvas')}
+                    </Label>
+                    <Select
+                      value={canvasVariant?.id ?? BASE_CANVAS_VALUE}
+                      onValueChange={(value) =>
+                        setCanvasVariantId(value === BASE_CANVAS_VALUE ? null : value)
+                      }
+                    >
+                      <SelectTrigger id="export-canvas" className="w-56">
+                        <SelectValue />
+                      </SelectTrigger>
+                      <SelectContent>
+                        <SelectItem value={BASE_CANVAS_VALUE}>
+                          {t('export.settings.baseCanvas', {
+                            width: baseWidth,
+                            height: baseHeight,
+                          })}
+                        </SelectItem>
+                        {canvasVariants.map((variant) => {
+                          const resolution = getCanvasVariantResolution(
+                            { width: baseWidth, height: baseHeight, fps },
+                            variant.aspect,
+                          )
+                          return (
+                            <SelectItem key={variant.id} value={variant.id}>
+                              {variant.name} ({resolution.width}×{resolution.height})
+                            </SelectItem>
+                          )
+                        })}
+                      </SelectContent>
+                    </Select>
+                  </div>
+                )}
+
                 {/* Export Range Section */}
                 <div className="space-y-3 p-3 rounded-lg border border-border bg-muted/20">
                   <div className="flex items-center justify-between">
@@ -1129,6 +1456,66 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
                           onCheckedChange={setEmbedSubtitles}
                         />
                       </div>
+
+                      <div className="space-y-2">
+                        <Label htmlFor="sidecar-subtitles">
+                          {t('export.settings.sidecarSubtitles')}
+                        </Label>
+                        <Select
+                          value={sidecarSubtitles ?? 'off'}
+                          onValueChange={(value) =>
+                            setSidecarSubtitles(
+                              value === 'off' ? null : (value as SidecarSubtitleFormat),
+                            )
+                          }
+                          disabled={!hasSubtitleItems}
+                        >
+                          <SelectTrigger id="sidecar-subtitles">
+                            <SelectValue />
+                          </SelectTrigger>
+                          <SelectContent>
+                            <SelectItem value="off">
+                              {t('export.settings.sidecarSubtitlesOff')}
+                            </SelectItem>
+                            <SelectItem value="srt">SubRip (.srt)</SelectItem>
+                            <SelectItem value="vtt">WebVTT (.vtt)</SelectItem>
+                          </SelectContent>
+                        </Select>
+                        <p className="text-xs text-muted-foreground">
+                          {t('export.settings.sidecarSubtitlesDescription')}
+                        </p>
+                      </div>
+
+                      {isHdrProject && (
+                        <div className="flex items-start justify-between gap-3 rounded-lg border border-border bg-muted/20 p-3">
+                          <div className="space-y-1">
+                            <Label htmlFor="export-hdr" className="text-sm font-medium">
+                              {t('export.settings.hdr')}
+                            </Label>
+                            <p className="text-xs text-muted-foreground">
+                              {t('export.settings.hdrDescription', {
+                                transfer: colorSpace === 'rec2100-hlg' ? 'HLG' : 'PQ',
+                              })}
+                            </p>
+                            {!codecSupportsHdr && (
+                              <p className="text-xs text-muted-foreground">
+                                {t('export.settings.hdrCodecRequired')}
+                              </p>
+                            )}
+                            {exportHdr && (
+                              <p className="text-xs text-muted-foreground">
+                                {t('export.settings.hdrMetadataNote')}
+                              </p>
+                            )}
+                          </div>
+                          <Switch
+                            id="export-hdr"
+                            checked={exportHdr}
+                            disabled={!codecSupportsHdr}
+                            onCheckedChange={setHdr}
+                          />
+                        </div>
+                      )}
                     </div>
                   </>
                 )}
@@ -1192,6 +1579,217 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
                     </div>
                   </div>
                 )}
+                {(exportMode === 'video' || exportMode === 'audio') && (
+                  <LoudnessSettings
+                    target={loudnessTarget}
+                    onTargetChange={setLoudnessTarget}
+                    analysis={loudnessAnalysis}
+                    isAnalyzing={isAnalyzingLoudness}
+                    onAnalyze={() => void handleAnalyzeLoudness()}
+                  />
+                )}
+
+                {/* Animated GIF / WebP Settings */}
+                {exportMode === 'animated-image' && (
+                  <div className="space-y-4">
+                    <Alert>
+                      <ImageIcon className="h-4 w-4" />
+                      <AlertDescription>{t('export.settings.animatedImageNote')}</AlertDescription>
+                    </Alert>
+
+                    <div className="grid gap-4 sm:grid-cols-2">
+                      <div className="space-y-2">
+                        <Label htmlFor="animated-image-format">{t('export.settings.format')}</Label>
+                        <Select
+                          value={animatedImageContainer}
+                          onValueChange={(v) =>
+                            setAnimatedImageContainer(v as AnimatedImageContainer)
+                          }
+                        >
+                          <SelectTrigger id="animated-image-format">
+                            <SelectValue placeholder={t('export.settings.selectFormat')} />
+                          </SelectTrigger>
+                          <SelectContent>
+                            {getAnimatedImageContainerOptions().map((option) => (
+                              <SelectItem key={option.value} value={option.value}>
+                                <span>{option.label}</span>
+                                <span className="ml-2 text-xs text-muted-foreground">
+                                  {option.description}
+                                </span>
+                              </SelectItem>
+                            ))}
+                          </SelectContent>
+                        </Select>
+                      </div>
+
+                      <div className="space-y-2">
+                        <Label htmlFor="animated-image-resolution">
+                          {t('export.settings.resolution')}
+                        </Label>
+                        <Select
+                          value={`${settings.resolution.width}x${settings.resolution.height}`}
+                          onValueChange={(value) => {
+                            const parts = value.split('x').map(Number)
+                            const width = parts[0] ?? projectWidth
+                            const height = parts[1] ?? projectHeight
+                            setSettings({ ...settings, resolution: { width, height } })
+                          }}
+                        >
+                          <SelectTrigger id="animated-image-resolution">
+                            <SelectValue placeholder={t('export.settings.selectResolution')} />
+                          </SelectTrigger>
+                          <SelectContent>
+                            {resolutionOptions.map((option) => (
+                              <SelectItem key={option.value} value={option.value}>
+                                {option.label}
+                              </SelectItem>
+                            ))}
+                          </SelectContent>
+                        </Select>
+                      </div>
+
+                      <div className="space-y-2">
+                        <Label htmlFor="animated-image-fps">{t('export.settings.frameRate')}</Label>
+                        <Select
+                          value={String(animatedImage.fps)}
+                          onValueChange={(value) =>
+                            setAnimatedImage({ ...animatedImage, fps: Number(value) })
+                          }
+                        >
+                          <SelectTrigger id="animated-image-fps">
+                            <SelectValue />
+                          </SelectTrigger>
+                          <SelectContent>
+                            {ANIMATED_IMAGE_FPS_OPTIONS.filter((option) => option <= fps).map(
+                              (option) => (
+                                <SelectItem key={option} value={String(option)}>
+                                  {t('export.settings.frameRateValue', { fps: option })}
+                                </SelectItem>
+                              ),
+                            )}
+                          </SelectContent>
+                        </Select>
+                      </div>
+
+                      <div className="space-y-2">
+                        <Label htmlFor="animated-image-loop">{t('export.settings.loop')}</Label>
+                        <Select
+                          value={String(animatedImage.loopCount)}
+                          onValueChange={(value) =>
+                            setAnimatedImage({ ...animatedImage, loopCount: Number(value) })
+                          }
+                        >
+                          <SelectTrigger id="animated-image-loop">
+                            <SelectValue />
+                          </SelectTrigger>
+                          <SelectContent>
+                            {ANIMATED_IMAGE_LOOP_OPTIONS.map((option) => (
+                              <SelectItem key={option} value={String(option)}>
+                                {option === 0
+                                  ? t('export.settings.loopForever')
+                                  : option === 1
+                                    ? t('export.settings.loopOnce')
+                                    : t('export.settings.loopTimes', { count: option })}
+                              </SelectItem>
+                            ))}
+                          </SelectContent>
+                        </Select>
+                      </div>
+
+                      {animatedImageContainer === 'gif' ? (
+                        <>
+                          <div className="space-y-2">
+                            <Label htmlFor="animated-image-palette">
+                              {t('export.settings.palette')}
+                            </Label>
+                            <Select
+                              value={animatedImage.palette}
+                              onValueChange={(value) =>
+                                setAnimatedImage({
+                                  ...animatedImage,
+                                  palette: value as AnimatedImageOptions['palette'],
+                                })
+                              }
+                            >
+                              <SelectTrigger id="animated-image-palette">
+                                <SelectValue />
+                              </SelectTrigger>
+                              <SelectContent>
+                                <SelectItem value="global">
+                                  {t('export.settings.paletteGlobal')}
+                                </SelectItem>
+                                <SelectItem value="per-frame">
+                                  {t('export.settings.palettePerFrame')}
+                                </SelectItem>
+                              </SelectContent>
+                            </Select>
+                          </div>
+
+                          <div className="space-y-2">
+                            <Label htmlFor="animated-image-dither">
+                              {t('export.settings.dither')}
+                            </Label>
+                            <Select
+                              value={animatedImage.dither}
+                              onValueChange={(value) =>
+                                setAnimatedImage({
+                                  ...animatedImage,
+                                  dither: value as AnimatedImageOptions['dither'],
+                                })
+                              }
+                            >
+                              <SelectTrigger id="animated-image-dither">
+                                <SelectValue />
+                              </SelectTrigger>
+                              <SelectContent>
+                                <SelectItem value="floyd-steinberg">
+                                  {t('export.settings.ditherFloydSteinberg')}
+                                </SelectItem>
+                                <SelectItem value="ordered">
+                                  {t('export.settings.ditherOrdered')}
+                                </SelectItem>
+                                <SelectItem value="none">
+                                  {t('export.settings.ditherNone')}
+                                </SelectItem>
+                              </SelectContent>
+                            </Select>
+                          </div>
+                        </>
+                      ) : (
+                        <div className="space-y-2">
+                          <Label htmlFor="animated-image-quality">
+                            {t('export.settings.quality')}
+                          </Label>
+                          <Select
+                            value={settings.quality}
+                            onValueChange={(value) =>
+                              setSettings({
+                                ...settings,
+                                quality: value as ExportSettings['quality'],
+                              })
+                            }
+                          >
+                            <SelectTrigger id="animated-image-quality">
+                              <SelectValue placeholder={t('export.settings.selectQuality')} />
+                            </SelectTrigger>
+                            <SelectContent>
+                              <SelectItem value="low">{t('export.settings.qualityLow')}</SelectItem>
+                              <SelectItem value="medium">
+                                {t('export.settings.qualityMedium')}
+                              </SelectItem>
+                              <SelectItem value="high">
+                                {t('export.settings.qualityHigh')}
+                              </SelectItem>
+                              <SelectItem value="ultra">
+                                {t('export.settings.qualityUltra')}
+                              </SelectItem>
+                            </SelectContent>
+                          </Select>
+                        </div>
+                      )}
+                    </div>
+                  </div>
+                )}
               </div>
             </div>
 
@@ -1211,6 +1809,11 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
                   <DropdownMenuItem onClick={handleAddCurrentRange}>
                     {t('export.renderQueue.addCurrentRange')}
                   </DropdownMenuItem>
+                  {exportMode !== 'audio' && canvasVariants && canvasVariants.length > 0 && (
+                    <DropdownMenuItem onClick={handleAddAllCanvases}>
+                      {t('export.renderQueue.allCanvases', { n: canvasVariants.length + 1 })}
+                    </DropdownMenuItem>
+                  )}
                   <DropdownMenuSeparator />
                   <DropdownMenuLabel className="text-xs text-muted-foreground">
                     {t('export.renderQueue.segmentsHeading')}
@@ -1232,7 +1835,9 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
               <Button onClick={handleStartExport} disabled={exportActionsDisabled}>
                 {exportMode === 'audio'
                   ? t('export.settings.exportAudio')
-                  : t('export.settings.exportVideo')}
+                  : exportMode === 'animated-image'
+                    ? t('export.settings.exportAnimatedImage')
+                    : t('export.settings.exportVideo')}
               </Button>
             </div>
           </div>
@@ -1296,14 +1901,25 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
         {/* Complete View */}
         {view === 'complete' && (
           <div className="space-y-4 py-4">
-            {previewUrl && <ExportPreviewPlayer src={previewUrl} isVideo={isVideoResult} />}
+            {previewUrl &&
+              (isImageResult ? (
+                <img
+                  src={previewUrl}
+                  alt=""
+                  className="max-h-[360px] w-full rounded-lg border border-border bg-black object-contain"
+                />
+              ) : (
+                <ExportPreviewPlayer src={previewUrl} isVideo={isVideoResult} />
+              ))}
 
             <Alert className="border-green-900 bg-green-950">
               <CheckCircle2 className="h-4 w-4 text-green-500" />
               <AlertDescription className="text-green-400">
                 {exportMode === 'audio'
                   ? t('export.complete.audioSuccess')
-                  : t('export.complete.videoSuccess')}
+                  : exportMode === 'animated-image'
+                    ? t('export.complete.imageSuccess')
+                    : t('export.complete.videoSuccess')}
               </AlertDescription>
             </Alert>
 
@@ -1326,6 +1942,23 @@ export function ExportDialog({ open, onClose, onOpenRenderQueue }: ExportDialogP
                   <span className="font-medium">{formatTime(elapsedSeconds)}</span>
                 </div>
               )}
+              {resultLoudness && (
+                <div className="flex items-center gap-2 text-sm">
+                  <Gauge className="h-4 w-4 text-muted-foreground" />
+                  <span className="text-muted-foreground">
+                    {t('export.complete.loudnessLabel')}
+                  </span>
+                  <span className="font-medium">
+                    {formatLoudness(resultLoudness.outputIntegratedLufs)} ·{' '}
+                    {formatLoudness(resultLoudness.outputTruePeakDbtp, 'dBTP')}
+                  </span>
+                  {resultLoudness.gainDb !== 0 && (
+                    <span className="text-xs text-muted-foreground">
+                      ({formatGain(resultLoudness.gainDb)})
+                    </span>
+                  )}
+                </div>
+              )}
             </div>
 
             <div className="flex justify-end gap-2">
@@ -54,10 +54,16 @@ function JobRow({ job }: { job: RenderJob }) {
   const moveJob = useRenderQueueStore((s) => s.moveJob)
 
   const fps = job.snapshot.fps
+  const resolution = `${job.clientSettings.resolution.width}×${job.clientSettings.resolution.height}`
+  const animationFps = Math.min(job.clientSettings.animatedImage?.fps ?? fps, fps)
   const formatBits =
     job.exportMode === 'audio'
       ? job.clientSettings.container.toUpperCase()
-      : `${job.clientSettings.container.toUpperCase()} · ${job.clientSettings.resolution.width}×${job.clientSettings.resolution.height}`
+      : job.exportMode === 'image-sequence'
+        ? `PNG · ${resolution}`
+        : job.exportMode === 'animated-image'
+          ? `${job.clientSettings.container.toUpperCase()} · ${animationFps} fps · ${resolution}`
+          : `${job.clientSettings.container.toUpperCase()} · ${resolution}`
   const rangeText =
     job.inPoint == null || job.outPoint == null
       ? t('export.renderQueue.wholeProject')
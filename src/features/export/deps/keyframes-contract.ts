@@ -7,6 +7,15 @@ export {
   getPropertyKeyframes,
   interpolatePropertyValue,
 } from '@/features/keyframes/utils/interpolation'
+export {
+  getItemNaturalSourceSeconds,
+  getTimeRemapSourceRange,
+  resolveTimeRemapCurve,
+  sampleTimeRemap,
+} from '@/features/keyframes/utils/time-remap'
+export type { TimeRemapCurve } from '@/features/keyframes/utils/time-remap'
 export { resolveAnimatedCrop } from '@/features/keyframes/utils/animated-crop-resolver'
+export { resolveAnimatedCornerPin } from '@/features/keyframes/utils/animated-corner-pin-resolver'
+export { resolveAnimatedMaskShape } from '@/features/keyframes/utils/animated-mask-path'
 export { resolveAnimatedColorEffects } from '@/features/keyframes/utils/effect-animatable-properties'
 export { resolveAnimatedTextItem } from '@/features/keyframes/utils/animated-text-item'
@@ -4,3 +4,8 @@
  */
 
 export { useProjectStore } from '@/features/projects/stores/project-store'
+export {
+  getCanvasVariant,
+  getCanvasVariantResolution,
+  resolveCanvasVariant,
+} from '@/features/projects/utils/canvas-variants'
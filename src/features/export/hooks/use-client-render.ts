@@ -10,7 +10,7 @@
  */
 
 import { useState, useCallback, useRef } from 'react'
-import type { ExportSettings, ExtendedExportSettings } from '@/types/export'
+import type { ExportLoudnessReport, ExportSettings, ExtendedExportSettings } from '@/types/export'
 import type { RenderProgress, ClientRenderResult, ClientCodec } from '../utils/client-renderer'
 import {
   mapToClientSettings,
@@ -21,6 +21,8 @@ import {
 } from '../utils/client-renderer'
 import { isExtendedSettings, resolveClientSettings, runRender } from '../utils/render-pipeline'
 import { convertTimelineToComposition } from '../utils/timeline-to-composition'
+import { resolveExportCanvas } from '../utils/canvas-variant-export'
+import { getSidecarSubtitleFileName } from '../utils/sidecar-subtitle-export'
 import { useTimelineStore } from '@/features/export/deps/timeline'
 import { useProjectStore } from '@/features/export/deps/projects'
 import { DEFAULT_PROJECT_HEIGHT, DEFAULT_PROJECT_WIDTH } from '@/shared/projects/defaults'
@@ -55,6 +57,11 @@ interface UseClientRenderReturn {
   cancelExport: () => void
   downloadVideo: () => void
   resetState: () => void
+  /** Mix the export range on the main thread and measure it (no encode). */
+  analyzeLoudness: (
+    renderWholeProject: boolean,
+    signal?: AbortSignal,
+  ) => Promise<ExportLoudnessReport | null>
 
   // Utilities
   getSupportedCodecs: (options?: {
@@ -65,6 +72,19 @@ interface UseClientRenderReturn {
   estimateFileSize: (settings: ExportSettings, durationSeconds: number) => string
 }
 
+function downloadBlob(blob: Blob, fileName: string): void {
+  const url = URL.createObjectURL(blob)
+  const a = document.createElement('a')
+  a.href = url
+  a.download = fileName
+  document.body.appendChild(a)
+  a.click()
+  document.body.removeChild(a)
+
+  // Revoke during idle — download has already started by then
+  requestIdleCallback(() => URL.revokeObjectURL(url))
+}
+
 export function useClientRender(): UseClientRenderReturn {
   const [isExporting, setIsExporting] = useState(false)
   const [progress, setProgress] = useState(0)
@@ -122,16 +142,23 @@ export function useClientRender(): UseClientRenderReturn {
 
         // Read current state from stores
         const state = useTimelineStore.getState()
-        const { tracks, items, transitions, fps, inPoint, outPoint, keyframes } = state
+        const { tracks, transitions, fps, inPoint, outPoint } = state
 
         // Get project metadata (background color and native resolution)
         const currentProject = useProjectStore.getState().currentProject
         const busAudioEq = usePlaybackStore.getState().busAudioEq
         const masterBusDb = usePlaybackStore.getState().masterBusDb
         const backgroundColor = currentProject?.metadata?.backgroundColor
-        // Use PROJECT resolution for composition (transform calculations match preview)
-        const projectWidth = currentProject?.metadata?.width ?? DEFAULT_PROJECT_WIDTH
-        const projectHeight = currentProject?.metadata?.height ?? DEFAULT_PROJECT_HEIGHT
+        // Use the CANVAS resolution for composition (transform calculations match
+        // preview); a canvas variant lays the timeline out on its own canvas
+        const canvas = resolveExportCanvas(
+          currentProject,
+          { items: state.items, keyframes: state.keyframes ?? [] },
+          isExtendedSettings(settings) ? settings.canvasVariantId : undefined,
+        )
+        const { items, keyframes } = canvas
+        const projectWidth = canvas.width
+        const projectHeight = canvas.height
 
         // Resolve settings + codec fallback (one source of truth with the queue).
         const { clientSettings, exportMode, renderWholeProject, codecFallback } =
@@ -153,6 +180,7 @@ export function useClientRender(): UseClientRenderReturn {
           renderWholeProject,
           keyframes: keyframes?.length ?? 0,
           projectResolution: `${projectWidth}x${projectHeight}`,
+          canvasVariant: canvas.variant?.aspect,
           videoContainer: extended ? settings.videoContainer : undefined,
           audioContainer: extended ? settings.audioContainer : undefined,
           embedSubtitles: clientSettings.embedSubtitles,
@@ -178,6 +206,7 @@ export function useClientRender(): UseClientRenderReturn {
           busAudioEq,
           masterBusDb,
         )
+        composition.colorSpace = currentProject?.metadata?.colorSpace
 
         const totalCompositionItems = composition.tracks.reduce(
           (sum, t) => sum + (t.items?.length ?? 0),
@@ -287,10 +316,6 @@ export function useClientRender(): UseClientRenderReturn {
   const downloadVideo = useCallback(() => {
     if (!result) return
 
-    const url = URL.createObjectURL(result.blob)
-    const a = document.createElement('a')
-    a.href = url
-
     // Determine file extension from MIME type
     let extension = 'mp4'
     const mime = result.mimeType.toLowerCase()
@@ -300,14 +325,20 @@ export function useClientRender(): UseClientRenderReturn {
     else if (mime.includes('audio/mpeg') || mime.includes('mp3')) extension = 'mp3'
     else if (mime.includes('audio/wav') || mime.includes('wave')) extension = 'wav'
     else if (mime.includes('audio/aac') || mime.includes('adts')) extension = 'aac'
-
-    a.download = `export-${Date.now()}.${extension}`
-    document.body.appendChild(a)
-    a.click()
-    document.body.removeChild(a)
-
-    // Revoke during idle — download has already started by then
-    requestIdleCallback(() => URL.revokeObjectURL(url))
+    else if (mime.includes('zip')) extension = 'zip'
+    else if (mime.includes('image/gif')) extension = 'gif'
+    else if (mime.includes('image/webp')) extension = 'webp'
+
+    const fileName = `export-${Date.now()}.${extension}`
+    downloadBlob(result.blob, fileName)
+
+    // Sidecar subtitles share the video's base name so players load them automatically
+    for (const file of result.sidecarSubtitles ?? []) {
+      downloadBlob(
+        new Blob([file.text], { type: file.mimeType }),
+        getSidecarSubtitleFileName(fileName, file),
+      )
+    }
   }, [result])
 
   /**
@@ -325,6 +356,43 @@ export function useClientRender(): UseClientRenderReturn {
     setResult(null)
   }, [])
 
+  /**
+   * Measure the export mix without rendering video or encoding anything.
+   */
+  const analyzeLoudness = useCallback(
+    async (renderWholeProject: boolean, signal?: AbortSignal) => {
+      const { tracks, items, transitions, fps, inPoint, outPoint, keyframes } =
+        useTimelineStore.getState()
+      const currentProject = useProjectStore.getState().currentProject
+      const { busAudioEq, masterBusDb } = usePlaybackStore.getState()
+
+      const composition = convertTimelineToComposition(
+        tracks,
+        items,
+        transitions,
+        fps,
+        currentProject?.metadata?.width ?? DEFAULT_PROJECT_WIDTH,
+        currentProject?.metadata?.height ?? DEFAULT_PROJECT_HEIGHT,
+        renderWholeProject ? null : inPoint,
+        renderWholeProject ? null : outPoint,
+        keyframes,
+        undefined,
+        busAudioEq,
+        masterBusDb,
+      )
+      composition.tracks = await resolveMediaUrls(composition.tracks, { useProxy: false })
+
+      const canvasAudio = await import('../utils/canvas-audio')
+      try {
+        const audio = await canvasAudio.processAudio(composition, signal)
+        return audio?.loudness ?? null
+      } finally {
+        canvasAudio.clearAudioDecodeCache()
+      }
+    },
+    [],
+  )
+
   /**
    * Get supported codecs for the current resolution
    */
@@ -374,6 +442,7 @@ export function useClientRender(): UseClientRenderReturn {
     cancelExport,
     downloadVideo,
     resetState,
+    analyzeLoudness,
     getSupportedCodecs: getSupportedCodecsForResolution,
     estimateFileSize: estimateFileSizeForSettings,
   }
@@ -16,6 +16,7 @@ import { createLogger, createOperationId } from '@/shared/logging/logger'
 import { getNextQueuedJob, useRenderQueueStore } from '../stores/render-queue-store'
 import type { RenderJob } from '../stores/render-queue-store'
 import { registerJobController, unregisterJobController } from '../utils/render-queue-control'
+import type { RunRenderOutcome } from '../utils/render-pipeline'
 
 const log = createLogger('RenderQueue')
 
@@ -48,12 +49,14 @@ async function renderQueuedJob(job: RenderJob): Promise<void> {
       { runRender },
       { convertTimelineToComposition },
       { resolveMediaUrls },
-      { saveExportFile },
+      { saveExportFile, createExportSequenceFolder, deleteExportFile },
+      { getSidecarSubtitleFileName },
     ] = await Promise.all([
       import('../utils/render-pipeline'),
       import('../utils/timeline-to-composition'),
       import('@/features/export/deps/media-library'),
       import('@/infrastructure/storage'),
+      import('../utils/sidecar-subtitle-export'),
     ])
 
     const { snapshot } = job
@@ -71,20 +74,47 @@ async function renderQueuedJob(job: RenderJob): Promise<void> {
       snapshot.busAudioEq,
       snapshot.masterBusDb,
     )
+    composition.colorSpace = snapshot.colorSpace
 
     // Resolve mediaIds → blob URLs fresh at render time (export never proxies).
     composition.tracks = await resolveMediaUrls(composition.tracks, { useProxy: false })
 
-    const { result, renderPath, fallbackReason } = await runRender({
-      clientSettings: job.clientSettings,
-      exportMode: job.exportMode,
-      composition,
-      signal: controller.signal,
-      onProgress: (progress) => useRenderQueueStore.getState().updateJobProgress(job.id, progress),
-    })
+    // Image sequences render straight into a folder of numbered PNGs, one
+    // frame at a time, instead of coming back as a blob.
+    const sequenceFolder =
+      job.exportMode === 'image-sequence'
+        ? await createExportSequenceFolder(job.projectId, job.fileName)
+        : null
+    let outcome: RunRenderOutcome
+    try {
+      outcome = await runRender({
+        clientSettings: job.clientSettings,
+        exportMode: job.exportMode,
+        composition,
+        signal: controller.signal,
+        onProgress: (progress) =>
+          useRenderQueueStore.getState().updateJobProgress(job.id, progress),
+        sequenceFolder: sequenceFolder?.handle,
+      })
+    } catch (renderError) {
+      // Don't leave a half-written sequence behind.
+      if (sequenceFolder) {
+        await deleteExportFile(sequenceFolder.path).catch(() => {})
+      }
+      throw renderError
+    }
+    const { result, renderPath, fallbackReason } = outcome
     if (fallbackReason) event.set('workerFallbackReason', fallbackReason)
 
-    const saved = await saveExportFile(job.projectId, job.fileName, result.blob)
+    const saved = sequenceFolder ?? (await saveExportFile(job.projectId, job.fileName, result.blob))
+    // Named after the saved video (which may have been de-duplicated) so players pair them.
+    for (const file of result.sidecarSubtitles ?? []) {
+      await saveExportFile(
+        job.projectId,
+        getSidecarSubtitleFileName(saved.fileName, file),
+        new Blob([file.text], { type: file.mimeType }),
+      )
+    }
     useRenderQueueStore.getState().markCompleted(job.id, {
       savedPath: saved.relPath,
       fileSize: result.fileSize,
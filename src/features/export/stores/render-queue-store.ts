@@ -13,6 +13,8 @@ import type { TimelineTrack, TimelineItem } from '@/types/timeline'
 import type { Transition } from '@/types/transition'
 import type { ItemKeyframes } from '@/types/keyframe'
 import type { AudioEqSettings } from '@/types/audio'
+import type { ProjectColorSpace } from '@/types/color'
+import type { ExportMode } from '@/types/export'
 import type { ClientExportSettings, RenderProgress } from '../utils/client-renderer'
 import { abortJob } from '../utils/render-queue-control'
 
@@ -28,6 +30,7 @@ export interface RenderJobSnapshot {
   width: number
   height: number
   backgroundColor?: string
+  colorSpace?: ProjectColorSpace
   busAudioEq?: AudioEqSettings
   masterBusDb?: number
 }
@@ -46,7 +49,7 @@ export interface RenderJob {
   inPoint: number | null
   outPoint: number | null
   durationFrames: number
-  exportMode: 'video' | 'audio'
+  exportMode: ExportMode
   clientSettings: ClientExportSettings
   snapshot: RenderJobSnapshot
   /** Suggested on-disk filename (incl. extension). */
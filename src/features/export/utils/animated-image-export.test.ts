@@ -0,0 +1,66 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { createAnimatedImageEncoder, planAnimationFrames } from './animated-image-export'
+
+describe('planAnimationFrames', () => {
+  it('decimates to the output frame rate', () => {
+    const plan = planAnimationFrames(90, 30, 15)
+
+    expect(plan).toHaveLength(45)
+    expect(plan.slice(0, 3).map((frame) => frame.sourceFrame)).toEqual([0, 2, 4])
+    expect(plan.at(-1)!.sourceFrame).toBe(88)
+  })
+
+  it('rounds delays cumulatively so the total length is preserved', () => {
+    const plan = planAnimationFrames(30, 30, 12)
+
+    expect(plan.map((frame) => frame.delayCentiseconds)).toEqual([
+      8, 9, 8, 8, 9, 8, 8, 9, 8, 8, 9, 8,
+    ])
+    expect(plan.reduce((sum, frame) => sum + frame.delayMs, 0)).toBe(1000)
+  })
+
+  it('never exceeds the composition frame rate and keeps at least one frame', () => {
+    expect(planAnimationFrames(30, 24, 30)).toHaveLength(30)
+    expect(planAnimationFrames(1, 30, 15)).toEqual([
+      { sourceFrame: 0, delayMs: 67, delayCentiseconds: 7 },
+    ])
+  })
+})
+
+describe('createAnimatedImageEncoder', () => {
+  it('builds a global GIF palette in a colour pass before indexing any frame', async () => {
+    const encoder = createAnimatedImageEncoder(
+      'gif',
+      { fps: 15, loopCount: 0, palette: 'global', dither: 'none' },
+      { width: 2, height: 1 },
+      'high',
+    )
+    const frames = [
+      new Uint8ClampedArray([255, 0, 0, 255, 0, 0, 255, 255]),
+      new Uint8ClampedArray([0, 255, 0, 255, 0, 0, 255, 255]),
+    ]
+    let reads = 0
+    const canvas = {} as OffscreenCanvas
+    const ctxFor = (rgba: Uint8ClampedArray) =>
+      ({
+        getImageData: () => {
+          reads++
+          return { data: rgba }
+        },
+      }) as unknown as OffscreenCanvasRenderingContext2D
+    const plan = planAnimationFrames(2, 30, 15)
+
+    expect(encoder.passes).toBe(2)
+    expect(() => encoder.finish()).toThrow()
+    for (let pass = 0; pass < encoder.passes; pass++) {
+      for (const rgba of frames) {
+        await encoder.addFrame(canvas, ctxFor(rgba), plan[0]!, pass)
+      }
+    }
+
+    const gif = encoder.finish()
+    expect(reads).toBe(4)
+    expect(String.fromCharCode(...gif.subarray(0, 6))).toBe('GIF89a')
+    expect(gif.at(-1)).toBe(0x3b)
+  })
+})
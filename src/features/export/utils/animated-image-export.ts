@@ -0,0 +1,145 @@
+/**
+ * Animated GIF / WebP export: frame-rate decimation and the per-container
+ * encoders that {@link renderAnimatedImage} feeds composited frames into.
+ */
+
+import type { AnimatedImageContainer, AnimatedImageOptions, ExportSettings } from '@/types/export'
+import {
+  addToHistogram,
+  buildPalette,
+  createColorHistogram,
+  createGifWriter,
+  indexFrame,
+} from './gif-encoder'
+import { muxAnimatedWebp, type AnimatedWebpFrame } from './animated-webp'
+
+export interface AnimationFrame {
+  /** Composition frame to render. */
+  sourceFrame: number
+  delayMs: number
+  /** GIF delays are centiseconds; rounded cumulatively so timing doesn't drift. */
+  delayCentiseconds: number
+}
+
+/**
+ * Pick which composition frames to keep when playing `durationInFrames` at
+ * `outputFps` instead of `fps`. Delays are derived from cumulative timestamps
+ * so the animation's total length matches the range.
+ */
+export function planAnimationFrames(
+  durationInFrames: number,
+  fps: number,
+  outputFps: number,
+): AnimationFrame[] {
+  const rate = Math.min(outputFps, fps)
+  const count = Math.max(1, Math.floor((durationInFrames * rate) / fps))
+  const at = (i: number, unitsPerSecond: number) => Math.round((i * unitsPerSecond) / rate)
+  return Array.from({ length: count }, (_, i) => ({
+    sourceFrame: Math.min(durationInFrames - 1, Math.round((i * fps) / rate)),
+    delayMs: at(i + 1, 1000) - at(i, 1000),
+    delayCentiseconds: at(i + 1, 100) - at(i, 100),
+  }))
+}
+
+const WEBP_QUALITY: Record<ExportSettings['quality'], number> = {
+  low: 0.6,
+  medium: 0.75,
+  high: 0.85,
+  ultra: 0.95,
+}
+
+export interface AnimatedImageEncoder {
+  /**
+   * How many times the frames must be rendered and fed in. A global GIF
+   * palette needs two: one to collect colours, one to index against them.
+   */
+  passes: number
+  addFrame(
+    canvas: OffscreenCanvas,
+    ctx: OffscreenCanvasRenderingContext2D,
+    frame: AnimationFrame,
+    pass: number,
+  ): Promise<void>
+  finish(): Uint8Array
+}
+
+export function createAnimatedImageEncoder(
+  container: AnimatedImageContainer,
+  options: AnimatedImageOptions,
+  size: { width: number; height: number },
+  quality: ExportSettings['quality'],
+): AnimatedImageEncoder {
+  const { width, height } = size
+
+  if (container === 'webp') {
+    const frames: AnimatedWebpFrame[] = []
+    return {
+      passes: 1,
+      async addFrame(canvas, _ctx, frame) {
+        const blob = await canvas.convertToBlob({
+          type: 'image/webp',
+          quality: WEBP_QUALITY[quality],
+        })
+        if (blob.type !== 'image/webp') {
+          throw new Error('This browser cannot encode WebP frames')
+        }
+        frames.push({ webp: new Uint8Array(await blob.arrayBuffer()), durationMs: frame.delayMs })
+      },
+      finish: () => muxAnimatedWebp(frames, { width, height, loopCount: options.loopCount }),
+    }
+  }
+
+  if (options.palette === 'per-frame') {
+    const writer = createGifWriter({ width, height, loopCount: options.loopCount })
+    return {
+      passes: 1,
+      async addFrame(_canvas, ctx, frame) {
+        const rgba = ctx.getImageData(0, 0, width, height).data
+        const histogram = createColorHistogram()
+        addToHistogram(histogram, rgba)
+        const palette = buildPalette(histogram)
+        writer.addFrame(
+          indexFrame(rgba, width, height, palette, options.dither),
+          frame.delayCentiseconds,
+          palette,
+        )
+      },
+      finish: () => writer.finish(),
+    }
+  }
+
+  // Global palette: the palette depends on every frame, so the first pass
+  // only collects colours and the second indexes each frame against the
+  // finished palette. Holding raw RGBA instead would cost width × height × 4
+  // bytes per frame.
+  const histogram = createColorHistogram()
+  let writer: ReturnType<typeof createGifWriter> | null = null
+  let palette: Uint8Array | null = null
+  return {
+    passes: 2,
+    async addFrame(_canvas, ctx, frame, pass) {
+      const rgba = ctx.getImageData(0, 0, width, height).data
+      if (pass === 0) {
+        addToHistogram(histogram, rgba)
+        return
+      }
+      if (!writer || !palette) {
+        palette = buildPalette(histogram)
+        writer = createGifWriter({
+          width,
+          height,
+          loopCount: options.loopCount,
+          globalPalette: palette,
+        })
+      }
+      writer.addFrame(
+        indexFrame(rgba, width, height, palette, options.dither),
+        frame.delayCentiseconds,
+      )
+    },
+    finish() {
+      if (!writer) throw new Error('GIF has no frames')
+      return writer.finish()
+    },
+  }
+}
@@ -0,0 +1,72 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { muxAnimatedWebp, readWebpChunks } from './animated-webp'
+
+/** A still WebP holding the given chunks (payloads are opaque to the muxer). */
+function stillWebp(chunks: Array<[string, number[]]>): Uint8Array {
+  const body: number[] = [...'WEBP'].map((char) => char.charCodeAt(0))
+  for (const [fourcc, data] of chunks) {
+    body.push(...[...fourcc].map((char) => char.charCodeAt(0)))
+    body.push(data.length & 0xff, (data.length >> 8) & 0xff, 0, 0, ...data)
+    if (data.length & 1) body.push(0)
+  }
+  const file = new Uint8Array(8 + body.length)
+  file.set([...'RIFF'].map((char) => char.charCodeAt(0)))
+  new DataView(file.buffer).setUint32(4, body.length, true)
+  file.set(body, 8)
+  return file
+}
+
+const readUint24 = (data: Uint8Array, offset: number) =>
+  data[offset]! | (data[offset + 1]! << 8) | (data[offset + 2]! << 16)
+
+describe('animated webp muxer', () => {
+  it('wraps each frame bitstream in an ANMF chunk behind VP8X + ANIM', () => {
+    const lossy = stillWebp([['VP8 ', [1, 2, 3]]])
+    const withAlpha = stillWebp([
+      ['VP8X', [0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0]],
+      ['ALPH', [9]],
+      ['VP8 ', [4, 5]],
+    ])
+
+    const file = muxAnimatedWebp(
+      [
+        { webp: lossy, durationMs: 67 },
+        { webp: withAlpha, durationMs: 66 },
+      ],
+      { width: 320, height: 180, loopCount: 0 },
+    )
+    const chunks = readWebpChunks(file)
+
+    expect(chunks.map((chunk) => chunk.fourcc)).toEqual(['VP8X', 'ANIM', 'ANMF', 'ANMF'])
+    const vp8x = chunks[0]!.data
+    expect(vp8x[0]).toBe(0x02 | 0x10)
+    expect([readUint24(vp8x, 4) + 1, readUint24(vp8x, 7) + 1]).toEqual([320, 180])
+    const anim = chunks[1]!.data
+    expect(new DataView(anim.buffer, anim.byteOffset).getUint16(4, true)).toBe(0)
+
+    const second = chunks[3]!.data
+    expect([readUint24(second, 6) + 1, readUint24(second, 9) + 1]).toEqual([320, 180])
+    expect(readUint24(second, 12)).toBe(66)
+    expect(second[15]).toBe(0x02)
+    // Frame data: ALPH (1 byte + pad) then VP8; the source VP8X is dropped.
+    const fourccAt = (offset: number) => String.fromCharCode(...second.subarray(offset, offset + 4))
+    expect([fourccAt(16), fourccAt(26)]).toEqual(['ALPH', 'VP8 '])
+    expect(second.length).toBe(16 + 10 + 10)
+  })
+
+  it('clears the alpha flag for opaque lossy frames and writes the loop count', () => {
+    const file = muxAnimatedWebp([{ webp: stillWebp([['VP8 ', [1]]]), durationMs: 100 }], {
+      width: 2,
+      height: 2,
+      loopCount: 3,
+    })
+    const [vp8x, anim] = readWebpChunks(file)
+
+    expect(vp8x!.data[0]).toBe(0x02)
+    expect(new DataView(anim!.data.buffer, anim!.data.byteOffset).getUint16(4, true)).toBe(3)
+  })
+
+  it('rejects files that are not WebP', () => {
+    expect(() => readWebpChunks(new Uint8Array(16))).toThrow('Not a WebP file')
+  })
+})
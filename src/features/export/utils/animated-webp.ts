@@ -0,0 +1,116 @@
+/**
+ * Animated WebP muxing for export. Each frame is encoded as a still WebP by
+ * the browser (`convertToBlob({ type: 'image/webp' })`); this module lifts the
+ * frame's bitstream chunks (VP8 / VP8L / ALPH) out of its RIFF container and
+ * wraps them in ANMF chunks behind a VP8X + ANIM header.
+ */
+
+interface RiffChunk {
+  fourcc: string
+  data: Uint8Array
+}
+
+function readFourcc(bytes: Uint8Array, offset: number): string {
+  return String.fromCharCode(...bytes.subarray(offset, offset + 4))
+}
+
+/** Split a still WebP file into its top-level chunks. */
+export function readWebpChunks(file: Uint8Array): RiffChunk[] {
+  if (file.length < 12 || readFourcc(file, 0) !== 'RIFF' || readFourcc(file, 8) !== 'WEBP') {
+    throw new Error('Not a WebP file')
+  }
+  const view = new DataView(file.buffer, file.byteOffset, file.byteLength)
+  const chunks: RiffChunk[] = []
+  let offset = 12
+  while (offset + 8 <= file.length) {
+    const size = view.getUint32(offset + 4, true)
+    chunks.push({
+      fourcc: readFourcc(file, offset),
+      data: file.subarray(offset + 8, offset + 8 + size),
+    })
+    offset += 8 + size + (size & 1)
+  }
+  return chunks
+}
+
+function writeUint24(target: Uint8Array, offset: number, value: number): void {
+  target[offset] = value & 0xff
+  target[offset + 1] = (value >> 8) & 0xff
+  target[offset + 2] = (value >> 16) & 0xff
+}
+
+function chunk(fourcc: string, data: Uint8Array): Uint8Array {
+  const padded = data.length + (data.length & 1)
+  const out = new Uint8Array(8 + padded)
+  for (let i = 0; i < 4; i++) out[i] = fourcc.charCodeAt(i)
+  new DataView(out.buffer).setUint32(4, data.length, true)
+  out.set(data, 8)
+  return out
+}
+
+function concat(parts: Uint8Array[]): Uint8Array {
+  const out = new Uint8Array(parts.reduce((sum, part) => sum + part.length, 0))
+  let offset = 0
+  for (const part of parts) {
+    out.set(part, offset)
+    offset += part.length
+  }
+  return out
+}
+
+export interface AnimatedWebpFrame {
+  /** A complete still WebP file. */
+  webp: Uint8Array
+  durationMs: number
+}
+
+export interface AnimatedWebpOptions {
+  width: number
+  height: number
+  /** How many times the animation plays; 0 = forever. */
+  loopCount: number
+}
+
+/** Mux still WebP frames into one animated WebP file. */
+export function muxAnimatedWebp(
+  frames: readonly AnimatedWebpFrame[],
+  { width, height, loopCount }: AnimatedWebpOptions,
+): Uint8Array {
+  let hasAlpha = false
+  const anmfChunks = frames.map((frame) => {
+    // Keep only the image bitstream; VP8X/ICCP/EXIF/XMP belong to the file header.
+    const bitstream = readWebpChunks(frame.webp).filter((c) =>
+      ['ALPH', 'VP8 ', 'VP8L'].includes(c.fourcc),
+    )
+    if (bitstream.some((c) => c.fourcc === 'ALPH' || c.fourcc === 'VP8L')) hasAlpha = true
+
+    const header = new Uint8Array(16)
+    // Frame origin (x/2, y/2) stays 0 — every frame covers the full canvas.
+    writeUint24(header, 6, width - 1)
+    writeUint24(header, 9, height - 1)
+    writeUint24(header, 12, Math.max(0, Math.min(0xffffff, Math.round(frame.durationMs))))
+    header[15] = 0x02 // do not blend with the previous frame; no disposal
+    return chunk('ANMF', concat([header, ...bitstream.map((c) => chunk(c.fourcc, c.data))]))
+  })
+
+  const vp8x = new Uint8Array(10)
+  vp8x[0] = 0x02 | (hasAlpha ? 0x10 : 0) // animation (+ alpha) flags
+  writeUint24(vp8x, 4, width - 1)
+  writeUint24(vp8x, 7, height - 1)
+
+  const anim = new Uint8Array(6)
+  // Background colour (BGRA) stays transparent black.
+  new DataView(anim.buffer).setUint16(4, Math.min(0xffff, loopCount), true)
+
+  const body = concat([
+    new Uint8Array([0x57, 0x45, 0x42, 0x50]), // "WEBP"
+    chunk('VP8X', vp8x),
+    chunk('ANIM', anim),
+    ...anmfChunks,
+  ])
+  const riff = new Uint8Array(8 + body.length)
+  riff.set([0x52, 0x49, 0x46, 0x46]) // "RIFF"
+  new DataView(riff.buffer).setUint32(4, body.length, true)
+  riff.set(body, 8)
+  return riff
+}
@@ -7,13 +7,13 @@
  * resolved (codec fallback) up front so the queue shows the real output format.
  */
 
-import type { ExtendedExportSettings } from '@/types/export'
+import type { ExportMode, ExtendedExportSettings } from '@/types/export'
 import type { ProjectMarker } from '@/types/timeline'
 import { useTimelineStore } from '@/features/export/deps/timeline'
 import { useProjectStore } from '@/features/export/deps/projects'
 import { usePlaybackStore } from '@/shared/state/playback'
-import { DEFAULT_PROJECT_HEIGHT, DEFAULT_PROJECT_WIDTH } from '@/shared/projects/defaults'
 import { resolveClientSettings } from './render-pipeline'
+import { resolveExportCanvas, scaleExportResolution } from './canvas-variant-export'
 import type { ClientExportSettings } from './client-renderer'
 import type { RenderJob, RenderJobSnapshot } from '../stores/render-queue-store'
 
@@ -47,24 +47,32 @@ interface TimelineCapture {
   markers: ProjectMarker[]
 }
 
-/** Read + deep-copy everything a render needs from the live stores. */
-function captureTimeline(): TimelineCapture {
+/**
+ * Read + deep-copy everything a render needs from the live stores, laid out
+ * on the requested canvas variant (the base canvas when unset).
+ */
+function captureTimeline(canvasVariantId?: string): TimelineCapture {
   const tl = useTimelineStore.getState()
   const currentProject = useProjectStore.getState().currentProject
   const playback = usePlaybackStore.getState()
 
-  const width = currentProject?.metadata?.width ?? DEFAULT_PROJECT_WIDTH
-  const height = currentProject?.metadata?.height ?? DEFAULT_PROJECT_HEIGHT
+  const canvas = resolveExportCanvas(
+    currentProject,
+    { items: tl.items, keyframes: tl.keyframes ?? [] },
+    canvasVariantId,
+  )
+  const projectName = currentProject?.name ?? 'export'
 
   const snapshot: RenderJobSnapshot = {
     tracks: clone(tl.tracks),
-    items: clone(tl.items),
+    items: clone(canvas.items),
     transitions: clone(tl.transitions ?? []),
-    keyframes: clone(tl.keyframes ?? []),
+    keyframes: clone(canvas.keyframes),
     fps: tl.fps,
-    width,
-    height,
+    width: canvas.width,
+    height: canvas.height,
     backgroundColor: currentProject?.metadata?.backgroundColor,
+    colorSpace: currentProject?.metadata?.colorSpace,
     busAudioEq: playback.busAudioEq,
     masterBusDb: playback.masterBusDb,
   }
@@ -73,7 +81,7 @@ function captureTimeline(): TimelineCapture {
     snapshot,
     fps: tl.fps,
     projectId: currentProject?.id,
-    projectName: currentProject?.name ?? 'export',
+    projectName: canvas.variant ? `${projectName} - ${canvas.variant.name}` : projectName,
     durationFrames: timelineDurationFrames(tl.items),
     storeInPoint: tl.inPoint,
     storeOutPoint: tl.outPoint,
@@ -107,7 +115,7 @@ export interface BuildRenderJobOptions {
 function assembleJob(
   capture: TimelineCapture,
   clientSettings: ClientExportSettings,
-  exportMode: 'video' | 'audio',
+  exportMode: ExportMode,
   inPoint: number | null,
   outPoint: number | null,
   name?: string,
@@ -117,7 +125,11 @@ function assembleJob(
 
   const label = rangeLabel(inPoint, outPoint, capture.fps)
   const displayName = name ?? `${capture.projectName}${label}`
-  const fileName = `${capture.projectName}${label}.${clientSettings.container}`
+  // Image sequences save as a folder named after the job.
+  const fileName =
+    exportMode === 'image-sequence'
+      ? `${capture.projectName}${label}`
+      : `${capture.projectName}${label}.${clientSettings.container}`
 
   return {
     id: crypto.randomUUID(),
@@ -144,7 +156,7 @@ export async function buildRenderJob({
   name,
   capture,
 }: BuildRenderJobOptions): Promise<RenderJob> {
-  const cap = capture ?? captureTimeline()
+  const cap = capture ?? captureTimeline(settings.canvasVariantId)
   const { clientSettings, exportMode } = await resolveClientSettings(settings, cap.fps)
   return assembleJob(cap, clientSettings, exportMode, inPoint, outPoint, name)
 }
@@ -205,9 +217,44 @@ export async function buildSegmentJobs(
   ranges: FrameRange[],
   partLabel: (index: number, range: FrameRange) => string,
 ): Promise<RenderJob[]> {
-  const capture = captureTimeline()
+  const capture = captureTimeline(settings.canvasVariantId)
   const { clientSettings, exportMode } = await resolveClientSettings(settings, capture.fps)
   return ranges.map((range, i) =>
     assembleJob(capture, clientSettings, exportMode, range.start, range.end, partLabel(i, range)),
   )
 }
+
+/**
+ * Build one job per canvas: the base canvas plus each of the project's
+ * canvas variants. The output resolution chosen for the selected canvas is
+ * carried over to the others at the same scale; each canvas resolves its own
+ * codec since the frame size differs.
+ */
+export async function buildCanvasVariantJobs(
+  settings: ExtendedExportSettings,
+  inPoint: number | null = null,
+  outPoint: number | null = null,
+): Promise<RenderJob[]> {
+  const currentProject = useProjectStore.getState().currentProject
+  const variants = currentProject?.canvasVariants ?? []
+  // Only the canvas size is needed here, so skip laying out any items
+  const selected = resolveExportCanvas(
+    currentProject,
+    { items: [], keyframes: [] },
+    settings.canvasVariantId,
+  )
+  const jobs: RenderJob[] = []
+  for (const canvasVariantId of [undefined, ...variants.map((variant) => variant.id)]) {
+    const capture = captureTimeline(canvasVariantId)
+    const resolution = scaleExportResolution(settings.resolution, selected, capture.snapshot)
+    jobs.push(
+      await buildRenderJob({
+        settings: { ...settings, canvasVariantId, resolution },
+        inPoint,
+        outPoint,
+        capture,
+      }),
+    )
+  }
+  return jobs
+}
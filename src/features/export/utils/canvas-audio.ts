@@ -5,7 +5,7 @@
  * Supports audio from video items and standalone audio items.
  */
 
-import type { CompositionInputProps } from '@/types/export'
+import type { CompositionInputProps, ExportLoudnessReport, LoudnessTarget } from '@/types/export'
 import type {
   VideoItem,
   AudioItem,
@@ -26,7 +26,13 @@ import {
   useCompositionsStore,
   collectReachableCompositionIdsFromTracks,
 } from '@/features/export/deps/timeline-compositions'
-import { getPropertyKeyframes, interpolatePropertyValue } from '@/features/export/deps/keyframes'
+import {
+  getPropertyKeyframes,
+  interpolatePropertyValue,
+  resolveTimeRemapCurve,
+  sampleTimeRemap,
+  type TimeRemapCurve,
+} from '@/features/export/deps/keyframes'
 import { blobUrlManager } from '@/infrastructure/browser/blob-url-manager'
 import { getMediaAudioCodecById, resolveMediaUrl } from '@/features/export/deps/media-library'
 import { ensureAc3DecoderRegistered, isAc3AudioCodec } from '@/shared/utils/ac3-decoder'
@@ -42,6 +48,7 @@ import {
   type AudioClipFadeSpan,
 } from '@/shared/utils/audio-fade-curve'
 import { createMediabunnyInputSource } from '@/infrastructure/browser/mediabunny-input-source'
+import { renderTimeRemappedAudio } from '@/infrastructure/audio/time-remap-render'
 import {
   appendResolvedAudioEqSources,
   applyAudioEqStages,
@@ -53,6 +60,7 @@ import {
   getAudioPitchShiftSemitones,
   isAudioPitchShiftActive,
 } from '@/shared/utils/audio-pitch'
+import { getLoudnessNormalizationGain, measureLoudness } from '@/shared/utils/audio-loudness'
 
 const log = createLogger('CanvasAudio')
 
@@ -107,6 +115,8 @@ interface AudioSegment {
   type: 'video' | 'audio'
   audioCodec?: string // Audio codec for lazy AC-3 decoder registration
   volumeKeyframes?: VolumeKeyframe[] // Animated volume keyframes
+  trackVolumeDb?: number // Track gain included in `volume`, kept on top of volumeKeyframes
+  timeRemapCurve?: TimeRemapCurve // Keyframed time remap; replaces speed/isReversed timing
   itemFrom: number // Item's timeline start frame (for keyframe offset)
 }
 
@@ -122,6 +132,7 @@ interface TransitionAudioEntry<TItem extends TransitionAudioItem> {
   type: 'video' | 'audio'
   audioCodec?: string
   volumeKeyframes?: VolumeKeyframe[]
+  timeRemapCurve?: TimeRemapCurve
   itemFrom: number
 }
 
@@ -430,6 +441,7 @@ function buildManagedTransitionAudioSegments<TItem extends TransitionAudioItem>(
       sourceStartFrame: baseTrimBefore - timelineToSourceFrames(before, speed, fps, sourceFps),
       sourceFps,
       volume: (item.volume ?? 0) + entry.trackVolume,
+      trackVolumeDb: entry.trackVolume,
       fadeInFrames: (item.audioFadeIn ?? 0) * fps,
       fadeOutFrames: (item.audioFadeOut ?? 0) * fps,
       fadeInCurve: item.audioFadeInCurve ?? 0,
@@ -468,6 +480,7 @@ function buildManagedTransitionAudioSegments<TItem extends TransitionAudioItem>(
       beforeFrames: before,
       afterFrames: after,
       volumeKeyframes: entry.volumeKeyframes,
+      timeRemapCurve: entry.timeRemapCurve,
       itemFrom: entry.itemFrom,
     })
   }
@@ -494,6 +507,7 @@ function buildManagedTransitionAudioSegments<TItem extends TransitionAudioItem>(
     if (Math.abs(left.pitchShiftSemitones - right.pitchShiftSemitones) > 0.0001) return false
     if (left.afterFrames !== 0 || right.beforeFrames !== 0) return false
     if (left.volumeKeyframes || right.volumeKeyframes) return false
+    if (left.timeRemapCurve || right.timeRemapCurve) return false
     return true
   }
 
@@ -527,6 +541,8 @@ function buildManagedTransitionAudioSegments<TItem extends TransitionAudioItem>(
     type: segment.type,
     audioCodec: segment.audioCodec,
     volumeKeyframes: segment.volumeKeyframes,
+    trackVolumeDb: segment.trackVolumeDb,
+    timeRemapCurve: segment.timeRemapCurve,
     itemFrom: segment.itemFrom,
   })
 
@@ -751,6 +767,7 @@ function appendCompositionAudioSegments(params: {
       sourceStartFrame: effectiveSourceStart,
       sourceFps: subItem.sourceFps ?? fps,
       volume: (subItem.volume ?? 0) + (track.volume ?? 0) + (subTrack?.volume ?? 0),
+      trackVolumeDb: (track.volume ?? 0) + (subTrack?.volume ?? 0),
       fadeInFrames: adjustedFadeInFrames,
       fadeOutFrames: adjustedFadeOutFrames,
       fadeInCurve: subItem.audioFadeInCurve ?? 0,
@@ -864,6 +881,12 @@ export function extractAudioSegments(
             const videoVolumeKfs = getPropertyKeyframes(videoItemKeyframes, 'volume')
             return videoVolumeKfs.length > 0 ? videoVolumeKfs : undefined
           })(),
+          timeRemapCurve:
+            resolveTimeRemapCurve(
+              videoItem,
+              composition.keyframes?.find((k) => k.itemId === item.id),
+              fps,
+            ) ?? undefined,
           itemFrom: videoItem.from,
         })
       } else if (item.type === 'audio') {
@@ -897,6 +920,7 @@ export function extractAudioSegments(
           type: 'audio',
           audioCodec: getMediaAudioCodecById(item.mediaId),
           volumeKeyframes: audioVolumeKfs.length > 0 ? audioVolumeKfs : undefined,
+          timeRemapCurve: resolveTimeRemapCurve(audioItem, audioItemKeyframes, fps) ?? undefined,
           itemFrom: item.from,
         }
 
@@ -919,6 +943,7 @@ export function extractAudioSegments(
           sourceStartFrame: audioItem.sourceStart ?? item.trimStart ?? 0,
           sourceFps: audioItem.sourceFps ?? fps,
           volume: (item.volume ?? 0) + (track.volume ?? 0),
+          trackVolumeDb: track.volume ?? 0,
           fadeInFrames: (item.audioFadeIn ?? 0) * fps,
           fadeOutFrames: (item.audioFadeOut ?? 0) * fps,
           fadeInCurve: item.audioFadeInCurve ?? 0,
@@ -953,6 +978,7 @@ export function extractAudioSegments(
           type: 'audio',
           audioCodec: audioEntry.audioCodec,
           volumeKeyframes: audioEntry.volumeKeyframes,
+          timeRemapCurve: audioEntry.timeRemapCurve,
           itemFrom: item.from,
         })
       }
@@ -1275,7 +1301,8 @@ function applyVolume(samples: Float32Array, volumeDb: number): Float32Array {
  *
  * @param samples - Audio samples for one channel
  * @param volumeKeyframes - Volume keyframes (frame-relative to item start)
- * @param staticVolumeDb - Static volume dB fallback
+ * @param staticVolumeDb - Static item volume dB fallback
+ * @param trackVolumeDb - Track gain added on top of the animated item volume
  * @param segmentStartFrame - Timeline frame where this segment starts
  * @param itemFrom - Timeline frame where the original item starts
  * @param fps - Frames per second
@@ -1285,6 +1312,7 @@ function applyAnimatedVolume(
   samples: Float32Array,
   volumeKeyframes: VolumeKeyframe[],
   staticVolumeDb: number,
+  trackVolumeDb: number,
   segmentStartFrame: number,
   itemFrom: number,
   fps: number,
@@ -1297,7 +1325,8 @@ function applyAnimatedVolume(
     const timelineFrame = segmentStartFrame + (i / sampleRate) * fps
     // Convert to item-relative frame for keyframe interpolation
     const relativeFrame = timelineFrame - itemFrom
-    const db = interpolatePropertyValue(volumeKeyframes, relativeFrame, staticVolumeDb)
+    const db =
+      interpolatePropertyValue(volumeKeyframes, relativeFrame, staticVolumeDb) + trackVolumeDb
     const gain = dbToGain(db)
     output[i] = samples[i]! * gain
   }
@@ -1692,6 +1721,47 @@ function downmixToStereo(source: readonly Float32Array[]): Float32Array[] {
   return [left, right]
 }
 
+/**
+ * Decode and render a time-remapped segment. The remap curve is sampled at
+ * every timeline frame the segment covers (transition handles included), so
+ * speed ramps, reverse runs and freezes all come out of one render.
+ */
+async function decodeTimeRemappedSegment(
+  segment: AudioSegment,
+  curve: TimeRemapCurve,
+  fps: number,
+): Promise<{ samples: Float32Array[]; sampleRate: number }> {
+  const firstItemFrame = segment.startFrame - segment.itemFrom
+  const frameSourceSeconds = new Float64Array(segment.durationFrames + 1)
+  let sourceStartTime = Number.POSITIVE_INFINITY
+  let sourceEndTime = 0
+  for (let frame = 0; frame < frameSourceSeconds.length; frame++) {
+    const seconds = sampleTimeRemap(curve, firstItemFrame + frame)
+    frameSourceSeconds[frame] = seconds
+    sourceStartTime = Math.min(sourceStartTime, seconds)
+    sourceEndTime = Math.max(sourceEndTime, seconds)
+  }
+  // Leave room for the stretcher's padding around each run.
+  sourceStartTime = Math.max(0, sourceStartTime - 0.25)
+  sourceEndTime += 0.25
+
+  const decoded = await decodeAudioFromSource(
+    segment.src,
+    segment.itemId,
+    sourceStartTime,
+    sourceEndTime,
+    segment.audioCodec,
+  )
+  const samples = renderTimeRemappedAudio({
+    channels: decoded.samples,
+    sampleRate: decoded.sampleRate,
+    channelsStartSeconds: sourceStartTime,
+    frameSourceSeconds,
+    fps,
+  })
+  return { samples, sampleRate: decoded.sampleRate }
+}
+
 function reverseAudioChannels(channels: Float32Array[]): Float32Array[] {
   return channels.map((samples) => {
     const reversed = new Float32Array(samples.length)
@@ -1801,17 +1871,23 @@ async function resolveSubCompMediaUrls(composition: CompositionInputProps): Prom
 /**
  * Process all audio for the composition.
  *
+ * The final mix is always measured (BS.1770 loudness + true peak); with a
+ * `loudnessTarget` it is also gained to that target before encoding.
+ *
  * @param composition - The composition with tracks
  * @param signal - Optional abort signal
+ * @param options - Optional loudness normalization target
  * @returns Processed audio ready for encoding
  */
 export async function processAudio(
   composition: CompositionInputProps,
   signal?: AbortSignal,
+  options: { loudnessTarget?: LoudnessTarget } = {},
 ): Promise<{
   samples: Float32Array[]
   sampleRate: number
   channels: number
+  loudness: ExportLoudnessReport
 } | null> {
   const { fps, durationInFrames = 0 } = composition
 
@@ -1862,38 +1938,48 @@ export async function processAudio(
     }
 
     try {
-      // Calculate the time range we actually need from the source
-      // sourceStartFrame is in source-native FPS frames, so divide by sourceFps (not project fps)
-      const sourceStartTime = segment.sourceStartFrame / segment.sourceFps
-      // Account for speed: at 2x speed, we need twice as much source audio
-      const sourceDurationNeeded = (segment.durationFrames / fps) * segment.speed
-      const sourceEndTime = sourceStartTime + sourceDurationNeeded
-
-      // Decode ONLY the needed range using mediabunny (huge performance improvement!)
-      const decoded = await decodeAudioFromSource(
-        segment.src,
-        segment.itemId,
-        sourceStartTime,
-        sourceEndTime,
-        segment.audioCodec,
-      )
+      let decoded: { samples: Float32Array[]; sampleRate: number }
+      let processedChannels: Float32Array[]
+      let playbackSpeed = segment.speed
+      if (segment.timeRemapCurve) {
+        // Remapped timing is baked in by the render; only pitch is left to apply.
+        decoded = await decodeTimeRemappedSegment(segment, segment.timeRemapCurve, fps)
+        processedChannels = decoded.samples
+        playbackSpeed = 1
+      } else {
+        // Calculate the time range we actually need from the source
+        // sourceStartFrame is in source-native FPS frames, so divide by sourceFps (not project fps)
+        const sourceStartTime = segment.sourceStartFrame / segment.sourceFps
+        // Account for speed: at 2x speed, we need twice as much source audio
+        const sourceDurationNeeded = (segment.durationFrames / fps) * segment.speed
+        const sourceEndTime = sourceStartTime + sourceDurationNeeded
+
+        // Decode ONLY the needed range using mediabunny (huge performance improvement!)
+        decoded = await decodeAudioFromSource(
+          segment.src,
+          segment.itemId,
+          sourceStartTime,
+          sourceEndTime,
+          segment.audioCodec,
+        )
 
-      // Process audio channels.
-      // Note: decoded audio is already trimmed to the range we requested.
+        // Process audio channels.
+        // Note: decoded audio is already trimmed to the range we requested.
+        processedChannels = decoded.samples
+        if (segment.isReversed) {
+          processedChannels = reverseAudioChannels(processedChannels)
+        }
+      }
 
       // Apply speed across ALL channels at once to maintain phase coherence
       // between L/R (the WSOLA pipeline finds shared overlap windows).
-      let processedChannels = decoded.samples
-      if (segment.isReversed) {
-        processedChannels = reverseAudioChannels(processedChannels)
-      }
       if (
-        Math.abs(segment.speed - 1) > 0.0001 ||
+        Math.abs(playbackSpeed - 1) > 0.0001 ||
         isAudioPitchShiftActive(segment.pitchShiftSemitones)
       ) {
         processedChannels = await applySpeedAndPitch(
           processedChannels,
-          segment.speed,
+          playbackSpeed,
           segment.pitchShiftSemitones,
           decoded.sampleRate,
         )
@@ -1934,7 +2020,8 @@ export async function processAudio(
           channelSamples = applyAnimatedVolume(
             channelSamples,
             segment.volumeKeyframes,
-            segment.volume,
+            segment.volume - (segment.trackVolumeDb ?? 0),
+            segment.trackVolumeDb ?? 0,
             segment.startFrame,
             segment.itemFrom,
             fps,
@@ -2031,17 +2118,58 @@ export async function processAudio(
     }
   }
 
+  const loudness = normalizeLoudness(mixedSamples, config.sampleRate, options.loudnessTarget)
+
   log.info('Audio processing complete', {
     outputSamples: mixedSamples[0]?.length,
     channels: mixedSamples.length,
     durationSeconds: (mixedSamples[0]?.length ?? 0) / config.sampleRate,
     masterBusDb: masterBusDb ?? 0,
+    integratedLufs: loudness.integratedLufs,
+    loudnessGainDb: loudness.gainDb,
   })
 
   return {
     samples: mixedSamples,
     sampleRate: config.sampleRate,
     channels: config.channels,
+    loudness,
+  }
+}
+
+/**
+ * Measure the mix and, when a target is given, apply the normalization gain in place.
+ * The gain never lifts true peak past the target's ceiling.
+ */
+function normalizeLoudness(
+  samples: Float32Array[],
+  sampleRate: number,
+  target: LoudnessTarget | undefined,
+): ExportLoudnessReport {
+  const measured = measureLoudness(samples, sampleRate)
+  const { gainDb, limitedByTruePeak } = target
+    ? getLoudnessNormalizationGain(measured, target)
+    : { gainDb: 0, limitedByTruePeak: false }
+
+  if (gainDb !== 0) {
+    const gain = dbToGain(gainDb)
+    for (const channel of samples) {
+      for (let i = 0; i < channel.length; i++) {
+        channel[i] = channel[i]! * gain
+      }
+    }
+  }
+
+  return {
+    integratedLufs: measured.integratedLufs,
+    shortTermMaxLufs: measured.shortTermMaxLufs,
+    momentaryMaxLufs: measured.momentaryMaxLufs,
+    truePeakDbtp: measured.truePeakDbtp,
+    target,
+    gainDb,
+    limitedByTruePeak,
+    outputIntegratedLufs: measured.integratedLufs + gainDb,
+    outputTruePeakDbtp: measured.truePeakDbtp + gainDb,
   }
 }
 
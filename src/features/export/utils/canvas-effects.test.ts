@@ -1,6 +1,10 @@
 import { beforeEach, describe, expect, it, vi } from 'vite-plus/test'
-import type { AdjustmentItem } from '@/types/timeline'
+import type { AdjustmentItem, VideoItem } from '@/types/timeline'
 import type { ItemEffect } from '@/types/effects'
+import {
+  encodeCameraPath,
+  getStabilizationCorrection,
+} from '@/infrastructure/gpu-effects/stabilization/camera-path'
 
 const mockFns = vi.hoisted(() => ({
   applyMasksMock: vi.fn(),
@@ -13,6 +17,7 @@ vi.mock('./canvas-masks', () => ({
 import {
   getAdjustmentLayerEffects,
   renderEffectsFromMaskedSource,
+  resolveStabilizationEffects,
   type AdjustmentLayerWithTrackOrder,
 } from './canvas-effects'
 
@@ -168,3 +173,85 @@ describe('renderEffectsFromMaskedSource', () => {
     })
   })
 })
+
+describe('resolveStabilizationEffects', () => {
+  const cameraPath = encodeCameraPath({
+    fps: 30,
+    startTime: 0,
+    aspect: 16 / 9,
+    x: Array.from({ length: 90 }, (_, i) => (i % 2 === 0 ? -0.01 : 0.01)),
+    y: Array.from({ length: 90 }, () => 0),
+    rotation: Array.from({ length: 90 }, () => 0),
+    scale: Array.from({ length: 90 }, () => 1),
+  })
+  const stabilize: ItemEffect = {
+    id: 'fx-stab',
+    enabled: true,
+    effect: {
+      type: 'gpu-effect',
+      gpuEffectType: 'gpu-stabilize',
+      params: { smoothness: 50, cropToFill: true, rollingShutter: 0, cameraPath },
+    },
+  }
+  const item = {
+    id: 'clip',
+    type: 'video',
+    trackId: 'track-1',
+    from: 100,
+    durationInFrames: 60,
+    label: 'clip.mp4',
+    src: 'blob:clip',
+    sourceStart: 30,
+    sourceFps: 30,
+    sourceWidth: 1920,
+    sourceHeight: 1080,
+  } as VideoItem
+  const transform = {
+    x: 100,
+    y: 0,
+    width: 960,
+    height: 960,
+    rotation: 0,
+    opacity: 1,
+    cornerRadius: 0,
+  }
+  const canvas = { width: 1920, height: 1080, fps: 30 }
+
+  it('leaves effect lists without stabilization untouched', () => {
+    const effects = [createGpuEffect('fx-1', 0.5)]
+    expect(resolveStabilizationEffects(effects, item, undefined, 110, transform, canvas)).toBe(
+      effects,
+    )
+  })
+
+  it('resolves the correction at the clip source time and the media rect', () => {
+    const blur = createGpuEffect('fx-1', 0.5)
+    const [resolved, untouched] = resolveStabilizationEffects(
+      [stabilize, blur],
+      item,
+      undefined,
+      110,
+      transform,
+      canvas,
+    )!
+
+    expect(untouched).toBe(blur)
+    // Frame 10 of a clip starting one second into the source
+    const correction = getStabilizationCorrection(cameraPath, 1 + 10 / 30, {
+      smoothness: 50,
+      cropToFill: true,
+      rollingShutter: 0,
+    })
+    expect(resolved!.effect.type === 'gpu-effect' && resolved!.effect.params).toMatchObject({
+      cameraPath,
+      frameCenterX: 1060,
+      frameCenterY: 540,
+      frameWidth: 960,
+      frameHeight: 540,
+      frameRotation: 0,
+      correctionX: correction.x,
+      correctionZoom: correction.zoom,
+    })
+    expect(correction.x).not.toBe(0)
+  })
+})
@@ -11,7 +11,18 @@ import { createLogger } from '@/shared/logging/logger'
 import type { EffectsPipeline, GpuEffectInstance } from '@/infrastructure/gpu-effects'
 import { applyMasks, type MaskCanvasSettings } from './canvas-masks'
 import type { CanvasPool } from './canvas-pool'
-import { resolveAnimatedColorEffects } from '@/features/export/deps/keyframes'
+import type { ItemTransform } from './canvas-item-renderer/types'
+import {
+  getItemNaturalSourceSeconds,
+  resolveAnimatedColorEffects,
+  resolveTimeRemapCurve,
+  sampleTimeRemap,
+} from '@/features/export/deps/keyframes'
+import { calculateContainedRect } from '@/shared/utils/media-crop'
+import { getStabilizationCorrection } from '@/infrastructure/gpu-effects/stabilization/camera-path'
+import { getEffectMatte, registerEffectMatte } from '@/infrastructure/gpu-effects/effect-mattes'
+import { matteToRgba } from '@/infrastructure/analysis/segmentation/matte'
+import type { SegmentationMatteCache } from './segmentation-mattes'
 
 const log = createLogger('CanvasEffects')
 
@@ -148,6 +159,190 @@ function applyGpuEffects(
   return null
 }
 
+// ============================================================================
+// Stabilization
+// ============================================================================
+
+function isStabilizeEffect(entry: ItemEffect): boolean {
+  return entry.effect.type === 'gpu-effect' && entry.effect.gpuEffectType === 'gpu-stabilize'
+}
+
+export interface MediaFrameRect {
+  frameCenterX: number
+  frameCenterY: number
+  frameWidth: number
+  frameHeight: number
+  /** Degrees */
+  frameRotation: number
+}
+
+/**
+ * Canvas-space rect of the clip's media (center, size, rotation), pivoting
+ * around the transform anchor like `applyItemTransformToContext`.
+ */
+export function getMediaFrameRect(
+  item: TimelineItem,
+  transform: ItemTransform,
+  canvas: EffectCanvasSettings,
+): MediaFrameRect {
+  const sourceWidth = item.type === 'video' ? item.sourceWidth : undefined
+  const sourceHeight = item.type === 'video' ? item.sourceHeight : undefined
+  const mediaRect = calculateContainedRect(
+    sourceWidth ?? transform.width,
+    sourceHeight ?? transform.height,
+    transform.width,
+    transform.height,
+  )
+  const flipX = item.transform?.flipHorizontal ? -1 : 1
+  const flipY = item.transform?.flipVertical ? -1 : 1
+  const left = canvas.width / 2 + transform.x - transform.width / 2
+  const top = canvas.height / 2 + transform.y - transform.height / 2
+  const pivotX = left + (transform.anchorX ?? transform.width / 2)
+  const pivotY = top + (transform.anchorY ?? transform.height / 2)
+  const offsetX = (left + mediaRect.x + mediaRect.width / 2 - pivotX) * flipX
+  const offsetY = (top + mediaRect.y + mediaRect.height / 2 - pivotY) * flipY
+  const theta = (transform.rotation * Math.PI) / 180
+
+  return {
+    frameCenterX: pivotX + offsetX * Math.cos(theta) - offsetY * Math.sin(theta),
+    frameCenterY: pivotY + offsetX * Math.sin(theta) + offsetY * Math.cos(theta),
+    frameWidth: mediaRect.width,
+    frameHeight: mediaRect.height,
+    frameRotation: transform.rotation,
+  }
+}
+
+/** Source time shown at `frame`, honoring speed, reverse and time remapping. */
+export function getItemSourceSecondsAtFrame(
+  item: TimelineItem,
+  itemKeyframes: ItemKeyframes | undefined,
+  frame: number,
+  fps: number,
+): number {
+  const itemFrame = frame - item.from
+  const timeRemapCurve = resolveTimeRemapCurve(item, itemKeyframes, fps)
+  return timeRemapCurve
+    ? sampleTimeRemap(timeRemapCurve, itemFrame)
+    : getItemNaturalSourceSeconds(item, itemFrame, fps)
+}
+
+/**
+ * Merge the per-frame camera correction into `gpu-stabilize` effects. The
+ * correction is looked up by the clip's source time at `frame` (honoring
+ * speed, reverse and time remapping), so preview and export render the same
+ * analyzed sample.
+ */
+export function resolveStabilizationEffects(
+  effects: ItemEffect[] | undefined,
+  item: TimelineItem,
+  itemKeyframes: ItemKeyframes | undefined,
+  frame: number,
+  transform: ItemTransform,
+  canvas: EffectCanvasSettings & { fps: number },
+): ItemEffect[] | undefined {
+  if (!effects?.some((entry) => entry.enabled && isStabilizeEffect(entry))) {
+    return effects
+  }
+
+  const sourceTime = getItemSourceSecondsAtFrame(item, itemKeyframes, frame, canvas.fps)
+  const frameRect = getMediaFrameRect(item, transform, canvas)
+
+  return effects.map((entry) => {
+    if (entry.effect.type !== 'gpu-effect' || !isStabilizeEffect(entry)) {
+      return entry
+    }
+
+    const params = entry.effect.params
+    const correction = getStabilizationCorrection(
+      typeof params.cameraPath === 'string' ? params.cameraPath : '',
+      sourceTime,
+      {
+        smoothness: typeof params.smoothness === 'number' ? params.smoothness : 50,
+        cropToFill: params.cropToFill !== false,
+        rollingShutter: typeof params.rollingShutter === 'number' ? params.rollingShutter : 0,
+      },
+    )
+
+    return {
+      ...entry,
+      effect: {
+        ...entry.effect,
+        params: {
+          ...params,
+          ...frameRect,
+          correctionX: correction.x,
+          correctionY: correction.y,
+          correctionRotation: correction.rotation,
+          correctionScale: correction.scale,
+          correctionShear: correction.shear,
+          correctionZoom: correction.zoom,
+        },
+      },
+    }
+  })
+}
+
+// ============================================================================
+// Background Removal
+// ============================================================================
+
+/**
+ * Point `gpu-remove-background` effects at the analyzed subject matte for the
+ * clip's source frame at `frame` and register it for the effect's data
+ * texture. Frames without a matte (not analyzed, or rendering without a
+ * workspace) get an empty key and pass through unchanged.
+ */
+export async function resolveRemoveBackgroundEffects(
+  effects: ItemEffect[] | undefined,
+  item: TimelineItem,
+  itemKeyframes: ItemKeyframes | undefined,
+  frame: number,
+  transform: ItemTransform,
+  canvas: EffectCanvasSettings & { fps: number },
+  matteCache: SegmentationMatteCache | undefined,
+): Promise<ItemEffect[] | undefined> {
+  if (!effects?.some((entry) => entry.enabled && isRemoveBackgroundEffect(entry))) {
+    return effects
+  }
+
+  let matteKey = ''
+  if (item.type === 'video' && item.mediaId && matteCache) {
+    const sourceTime = getItemSourceSecondsAtFrame(item, itemKeyframes, frame, canvas.fps)
+    const loaded = await matteCache.getMatte(item.mediaId, sourceTime)
+    if (loaded) {
+      matteKey = loaded.key
+      if (!getEffectMatte(matteKey)) {
+        const { width, height } = loaded.matte
+        const data = new Uint8Array(matteToRgba(loaded.matte).buffer)
+        registerEffectMatte(matteKey, { width, height, depth: 1, data })
+      }
+    }
+  }
+
+  const frameRect = getMediaFrameRect(item, transform, canvas)
+  const frameFlipX = item.transform?.flipHorizontal ? -1 : 1
+  const frameFlipY = item.transform?.flipVertical ? -1 : 1
+
+  return effects.map((entry) => {
+    if (entry.effect.type !== 'gpu-effect' || !isRemoveBackgroundEffect(entry)) {
+      return entry
+    }
+    return {
+      ...entry,
+      effect: {
+        ...entry.effect,
+        params: { ...entry.effect.params, ...frameRect, frameFlipX, frameFlipY, matteKey },
+      },
+    }
+  })
+}
+
+function isRemoveBackgroundEffect(entry: ItemEffect): boolean {
+  return (
+    entry.effect.type === 'gpu-effect' && entry.effect.gpuEffectType === 'gpu-remove-background'
+  )
+}
+
 // ============================================================================
 // Adjustment Layer Effects
 // ============================================================================
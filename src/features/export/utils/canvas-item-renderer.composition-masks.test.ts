@@ -19,6 +19,7 @@ const mockFns = vi.hoisted(() => ({
 
 vi.mock('./canvas-masks', () => ({
   applyMasks: mockFns.applyMasksMock,
+  buildMotionBlurredPreparedMask: vi.fn(() => null),
   buildPreparedMask: mockFns.buildPreparedMaskMock,
   svgPathToPath2D: mockFns.svgPathToPath2DMock,
 }))
@@ -8,14 +8,22 @@ import { doesMaskAffectTrack } from '@/shared/utils/mask-scope'
 import { hasMediaCrop } from '@/shared/utils/media-crop'
 import { applyPreviewPathVerticesToShape } from '@/features/export/deps/composition-runtime'
 import { hasCornerPin } from '@/features/export/deps/composition-runtime'
+import { resolveAnimatedMaskShape } from '@/features/export/deps/keyframes'
 import { getAnimatedCrop, getAnimatedTransform } from '../canvas-keyframes'
 import {
   renderEffectsFromMaskedSource,
   getAdjustmentLayerEffects,
   combineEffects,
+  resolveRemoveBackgroundEffects,
+  resolveStabilizationEffects,
   type AdjustmentLayerWithTrackOrder,
 } from '../canvas-effects'
-import { applyMasks, buildPreparedMask, type MaskCanvasSettings } from '../canvas-masks'
+import {
+  applyMasks,
+  buildMotionBlurredPreparedMask,
+  buildPreparedMask,
+  type MaskCanvasSettings,
+} from '../canvas-masks'
 import {
   getItemRenderTimelineSpan,
   getRenderTimelineSourceStart,
@@ -198,10 +206,24 @@ export async function renderCompositionItem(
           })
         }
 
-        const itemEffects =
-          (rctx.renderMode === 'preview'
-            ? rctx.getPreviewEffectsOverride?.(subItem.id)
-            : undefined) ?? subItem.effects
+        const itemEffects = await resolveRemoveBackgroundEffects(
+          resolveStabilizationEffects(
+            (rctx.renderMode === 'preview'
+              ? rctx.getPreviewEffectsOverride?.(subItem.id)
+              : undefined) ?? subItem.effects,
+            subItem,
+            subItemKeyframes,
+            localFrame,
+            subItemTransform,
+            subCanvasSettings,
+          ),
+          subItem,
+          subItemKeyframes,
+          localFrame,
+          subItemTransform,
+          subCanvasSettings,
+          rctx.segmentationMatteCache,
+        )
         const adjEffects = getAdjustmentLayerEffects(
           track.order,
           subAdjustmentLayers,
@@ -376,18 +398,30 @@ export function getActiveSubCompMasks(
         continue
       }
       if (subItem.type !== 'shape' || !subItem.isMask) continue
-      const effectiveMaskItem =
-        rctx.renderMode === 'preview'
-          ? applyPreviewPathVerticesToShape(subItem, rctx.getPreviewPathVerticesOverride)
-          : subItem
-      const maskTransform = getAnimatedTransform(
-        subItem,
-        subData.keyframesMap.get(subItem.id),
-        localFrame,
-        subCanvasSettings,
-      )
+      const resolveMaskAt = (frame: number) => {
+        const animatedMaskItem = resolveAnimatedMaskShape(subItem, frame - subItem.from)
+        return {
+          shape:
+            rctx.renderMode === 'preview'
+              ? applyPreviewPathVerticesToShape(
+                  animatedMaskItem,
+                  rctx.getPreviewPathVerticesOverride,
+                )
+              : animatedMaskItem,
+          transform: getAnimatedTransform(
+            subItem,
+            subData.keyframesMap.get(subItem.id),
+            frame,
+            subCanvasSettings,
+          ),
+        }
+      }
+      const { shape: effectiveMaskItem, transform: maskTransform } = resolveMaskAt(localFrame)
+      const prepared =
+        buildMotionBlurredPreparedMask(subItem, localFrame, subMaskSettings, resolveMaskAt) ??
+        buildPreparedMask(effectiveMaskItem, maskTransform, subMaskSettings)
       activeMasks.push({
-        ...buildPreparedMask(effectiveMaskItem, maskTransform, subMaskSettings),
+        ...prepared,
         shape: effectiveMaskItem,
         transform: maskTransform,
         trackOrder: track.order,
@@ -1073,7 +1073,9 @@ function renderGpuSubCompMaskToTexture(
       return false
     }
     const cache = rctx.gpuBitmapMaskTextureCache
-    const cacheKey = cache ? getGpuBitmapMaskTextureCacheKey(mask) : null
+    // Motion-blurred bitmaps depend on neighbouring frames, not just this pose.
+    const cacheKey =
+      cache && !mask.shape.maskMotionBlur ? getGpuBitmapMaskTextureCacheKey(mask) : null
     const cached = cacheKey ? cache?.get(cacheKey) : undefined
     if (cached) {
       cache?.delete(cacheKey!)
@@ -1182,6 +1184,7 @@ function getGpuTextTextureCacheKey(item: TextItem, width: number, height: number
     textPadding: item.textPadding,
     textShadow: item.textShadow,
     stroke: item.stroke,
+    karaokeWords: item.karaokeWords,
   })
 }
 
@@ -1210,6 +1213,8 @@ function getGpuBitmapMaskTextureCacheKey(
     points: mask.shape.points,
     innerRadius: mask.shape.innerRadius,
     pathVertices: mask.shape.pathVertices,
+    maskExpansion: mask.shape.maskExpansion,
+    maskFeather: mask.shape.maskFeather,
     maskType: mask.maskType,
     feather: mask.feather,
   })
@@ -10,6 +10,12 @@
 
 import type { SubtitleSegmentItem, TextItem } from '@/types/timeline'
 import { parseSubtitleCueText } from '@/shared/utils/subtitle-cue-format'
+import { resolveCueSpeakerStyle } from '@/shared/utils/subtitle-speakers'
+import {
+  layoutKaraokeWords,
+  resolveKaraokeCaptionFrame,
+  type KaraokeWordBox,
+} from '@/shared/typography/caption-karaoke'
 import {
   layoutTextBlock,
   lineInkWidth,
@@ -55,6 +61,7 @@ export function getTextRasterCacheKey(item: TextItem, boxWidth: number, boxHeigh
     stroke: item.stroke,
     textStyleScale: item.textStyleScale,
     textLayoutDrafts: item.textLayoutDrafts,
+    karaokeWords: item.karaokeWords,
   })
 }
 
@@ -101,6 +108,11 @@ function paintTextBlock(
     }
   }
 
+  const karaokeBoxes = item.karaokeWords ? layoutKaraokeWords(layout, measurer) : null
+  if (karaokeBoxes) {
+    paintKaraokeBackgrounds(ctx, item, karaokeBoxes, originX, originY)
+  }
+
   if (item.textShadow) {
     ctx.shadowColor = item.textShadow.color
     ctx.shadowBlur = item.textShadow.blur
@@ -112,7 +124,7 @@ function paintTextBlock(
   ctx.textAlign = 'left'
   const strokeWidth = item.stroke?.width ?? 0
 
-  for (const line of layout.lines) {
+  for (const [lineIndex, line] of layout.lines.entries()) {
     if (line.text.length === 0) continue
     const x = originX + line.startX
     const y = originY + line.baselineY
@@ -125,10 +137,14 @@ function paintTextBlock(
       ctx.strokeStyle = item.stroke.color
       ctx.lineWidth = strokeWidth * 2
       ctx.lineJoin = 'round'
-      ctx.strokeText(line.text, x, y)
     }
 
-    ctx.fillText(line.text, x, y)
+    if (karaokeBoxes) {
+      paintKaraokeLineWords(ctx, item, line, lineIndex, karaokeBoxes, originX, originY)
+    } else {
+      if (item.stroke && strokeWidth > 0) ctx.strokeText(line.text, x, y)
+      ctx.fillText(line.text, x, y)
+    }
 
     if (line.underline) {
       drawUnderline(ctx, line, x, y)
@@ -136,6 +152,74 @@ function paintTextBlock(
   }
 }
 
+/** Scale the context about a karaoke word's center (pop-in / active scale). */
+function scaleAboutWord(
+  ctx: OffscreenCanvasRenderingContext2D,
+  box: KaraokeWordBox,
+  originX: number,
+  originY: number,
+  scale: number,
+): void {
+  if (scale === 1) return
+  const cx = originX + box.centerX
+  const cy = originY + box.centerY
+  ctx.translate(cx, cy)
+  ctx.scale(scale, scale)
+  ctx.translate(-cx, -cy)
+}
+
+/** Highlight boxes behind active karaoke words; drawn before the shadow is set. */
+function paintKaraokeBackgrounds(
+  ctx: OffscreenCanvasRenderingContext2D,
+  item: TextItem,
+  boxes: readonly KaraokeWordBox[],
+  originX: number,
+  originY: number,
+): void {
+  for (const box of boxes) {
+    const paint = item.karaokeWords?.[box.index]
+    if (!paint?.backgroundColor || paint.hidden) continue
+    const bg = box.background
+    ctx.save()
+    scaleAboutWord(ctx, box, originX, originY, paint.scale ?? 1)
+    ctx.fillStyle = paint.backgroundColor
+    ctx.beginPath()
+    ctx.roundRect(originX + bg.x, originY + bg.y, bg.width, bg.height, bg.radius)
+    ctx.fill()
+    ctx.restore()
+  }
+}
+
+/**
+ * Karaoke lines are drawn word by word so each word can take its own color
+ * and scale, or stay hidden until it pops in. Font, letter spacing and stroke
+ * are already configured on `ctx` for the line.
+ */
+function paintKaraokeLineWords(
+  ctx: OffscreenCanvasRenderingContext2D,
+  item: TextItem,
+  line: LaidOutLine,
+  lineIndex: number,
+  boxes: readonly KaraokeWordBox[],
+  originX: number,
+  originY: number,
+): void {
+  const stroke = item.stroke && item.stroke.width > 0
+  for (const box of boxes) {
+    if (box.lineIndex !== lineIndex) continue
+    const paint = item.karaokeWords?.[box.index] ?? {}
+    if (paint.hidden) continue
+    const x = originX + box.x
+    const y = originY + line.baselineY
+    ctx.save()
+    scaleAboutWord(ctx, box, originX, originY, paint.scale ?? 1)
+    if (stroke) ctx.strokeText(box.text, x, y)
+    ctx.fillStyle = paint.color ?? line.color
+    ctx.fillText(box.text, x, y)
+    ctx.restore()
+  }
+}
+
 /**
  * Rasterize a text block into a standalone padded OffscreenCanvas. Padding
  * leaves room for shadow spread and glyph overflow so the cached image matches
@@ -243,6 +327,13 @@ export function renderSubtitleSegmentItem(
   if (!activeCue) return
   const parsed = parseSubtitleCueText(activeCue.text)
   if (parsed.isEmpty) return
+  const karaokeFrame = item.karaoke
+    ? resolveKaraokeCaptionFrame(
+        { ...activeCue, text: parsed.plainText },
+        secondsIntoSegment,
+        item.karaoke,
+      )
+    : null
 
   const ephemeralText: TextItem = {
     id: item.id,
@@ -252,8 +343,9 @@ export function renderSubtitleSegmentItem(
     durationInFrames: item.durationInFrames,
     label: item.label,
     mediaId: item.mediaId,
-    text: parsed.plainText,
-    textSpans: parsed.spans,
+    text: karaokeFrame?.text ?? parsed.plainText,
+    textSpans: karaokeFrame ? undefined : parsed.spans,
+    karaokeWords: karaokeFrame?.words,
     fontSize: item.fontSize,
     fontFamily: item.fontFamily,
     fontWeight: item.fontWeight,
@@ -269,6 +361,7 @@ export function renderSubtitleSegmentItem(
     textPadding: item.textPadding,
     textShadow: item.textShadow,
     stroke: item.stroke,
+    ...resolveCueSpeakerStyle(item.speakerStyles, activeCue.speakerId),
     transform: item.transform,
   }
   renderTextItem(ctx, ephemeralText, transform, rctx)
@@ -18,6 +18,8 @@ import type { CachedGifFrames } from '@/features/export/deps/timeline-gif-cache'
 import type { CanvasPool, TextMeasurementCache } from '../canvas-pool'
 import type { VideoFrameSource } from '../shared-video-extractor'
 import type { ReverseVideoFrameCache } from '../reverse-video-frame-cache'
+import type { FrameInterpolationCache } from '../frame-interpolation-cache'
+import type { SegmentationMatteCache } from '../segmentation-mattes'
 import type { PreviewPathVerticesOverride } from '@/features/export/deps/composition-runtime'
 import type { GpuTexturePool } from '@/infrastructure/gpu-compositor'
 import type {
@@ -117,6 +119,8 @@ export interface ItemRenderContext {
     maxWaitMs?: number,
   ) => Promise<ImageBitmap | null>
   reverseVideoFrameCache?: ReverseVideoFrameCache
+  frameInterpolationCache?: FrameInterpolationCache
+  segmentationMatteCache?: SegmentationMatteCache
 
   // Image / GIF state
   imageElements: Map<string, WorkerLoadedImage>
@@ -185,6 +189,33 @@ export interface ItemRenderContext {
 
   // Composition IDs currently resolving through the GPU subcomp path.
   gpuCompositionStack?: Set<string>
+
+  // Decoded-frame sink for video items composited in an HDR working space.
+  hdrVideo?: HdrVideoCollector | null
+}
+
+type PixelRect = { x: number; y: number; width: number; height: number }
+
+/** A decoded video frame to composite in the HDR working space. */
+export interface HdrVideoDraw {
+  /** Owned by the collector; closed by the compositor once drawn */
+  frame: VideoFrame
+  /** Media rect in output canvas pixels */
+  rect: PixelRect
+  /** Visible (cropped) region in output canvas pixels */
+  clip: PixelRect
+  opacity: number
+}
+
+/**
+ * Per-frame collector for HDR video. The engine lists the items whose video
+ * can bypass the 8-bit canvas (no effects, rotation, or corner pin); the
+ * video renderer still draws its SDR canvas and also submits the decoded
+ * frame, which the compositor prefers when it can upload it.
+ */
+export interface HdrVideoCollector {
+  eligibleItemIds: Set<string>
+  draws: Map<string, HdrVideoDraw>
 }
 
 /**
@@ -4,6 +4,11 @@
  */
 
 import type { VideoItem } from '@/types/timeline'
+import {
+  resolveTimeRemapCurve,
+  sampleTimeRemap,
+  type TimeRemapCurve,
+} from '@/features/export/deps/keyframes'
 import {
   getItemRenderTimelineSpan,
   getRenderTimelineSourceStart,
@@ -19,7 +24,9 @@ import {
   shouldUsePreviewStrictWaitingFallback,
 } from '../frame-source-policy'
 import type { CanvasPool } from '../canvas-pool'
-import type { CanvasSettings, ItemRenderContext, ItemTransform } from './types'
+import { isHdrUploadableFormat } from '@/infrastructure/gpu-color'
+import type { VideoFrameSource } from '../shared-video-extractor'
+import type { CanvasSettings, HdrVideoCollector, ItemRenderContext, ItemTransform } from './types'
 import {
   isFrameInsideItemTimelineSpan,
   log,
@@ -35,11 +42,58 @@ import {
 } from './media-draw'
 import { isPreviewTraceEnabled, recordRenderTrace } from '@/shared/logging/preview-trace'
 
+/**
+ * Hand the decoded frame behind a successful extractor draw to the HDR
+ * collector. Only axis-aligned, unflipped draws without rounded corners or
+ * crop feathering can be placed from the canvas transform; anything else
+ * keeps the SDR canvas result.
+ */
+async function submitHdrVideoFrame(
+  ctx: OffscreenCanvasRenderingContext2D,
+  itemId: string,
+  extractor: VideoFrameSource,
+  sourceTime: number,
+  transform: ItemTransform,
+  drawLayout: ReturnType<typeof calculateContainedMediaDrawLayout>,
+  collector: HdrVideoCollector,
+): Promise<void> {
+  if (!extractor.captureDecodedFrame || transform.cornerRadius > 0) return
+  if (hasCropFeather(drawLayout.featherPixels)) return
+  const m = ctx.getTransform()
+  if (m.b !== 0 || m.c !== 0 || m.a <= 0 || m.d <= 0) return
+
+  const frame = await extractor.captureDecodedFrame(sourceTime)
+  if (!frame) return
+  if (!isHdrUploadableFormat(frame.format)) {
+    frame.close()
+    return
+  }
+
+  const toCanvas = (rect: { x: number; y: number; width: number; height: number }) => ({
+    x: m.a * rect.x + m.e,
+    y: m.d * rect.y + m.f,
+    width: m.a * rect.width,
+    height: m.d * rect.height,
+  })
+  collector.draws.get(itemId)?.frame.close()
+  collector.draws.set(itemId, {
+    frame,
+    rect: toCanvas(drawLayout.mediaRect),
+    clip: toCanvas(drawLayout.viewportRect),
+    opacity: ctx.globalAlpha,
+  })
+}
+
 function getTier2VideoFrameToleranceSeconds(sourceFps: number): number {
   const normalizedSourceFps = Number.isFinite(sourceFps) && sourceFps > 0 ? sourceFps : 30
   return (1 / normalizedSourceFps) * TIER2_VIDEO_FRAME_TOLERANCE_FACTOR
 }
 
+function getVideoTimeRemapCurve(item: VideoItem, rctx: ItemRenderContext): TimeRemapCurve | null {
+  const itemKeyframes = rctx.getCurrentKeyframes?.(item.id) ?? rctx.keyframesMap.get(item.id)
+  return resolveTimeRemapCurve(item, itemKeyframes, rctx.fps)
+}
+
 function clampVideoSourceTime(
   sourceTime: number,
   sourceFps: number,
@@ -190,9 +244,14 @@ export async function renderVideoItem(
     effectiveRenderSpan.sourceTimeRamp && !item.isReversed
       ? getSourceFrameRampOffset(effectiveRenderSpan.sourceTimeRamp, frame)
       : 0
-  const unclampedSourceTime = item.isReversed
-    ? (reverseSourceEnd - localFrame * speed * (sourceFps / fps) - 1) / sourceFps
-    : adjustedSourceStart / sourceFps + localTime * speed + rampOffsetSourceFrames / sourceFps
+  // A time remap curve owns the source timing outright (speed, reverse and
+  // freezes), keyed by item-relative frame so transition handles extrapolate.
+  const timeRemapCurve = getVideoTimeRemapCurve(item, rctx)
+  const unclampedSourceTime = timeRemapCurve
+    ? sampleTimeRemap(timeRemapCurve, frame - item.from) + sourceFrameOffset / sourceFps
+    : item.isReversed
+      ? (reverseSourceEnd - localFrame * speed * (sourceFps / fps) - 1) / sourceFps
+      : adjustedSourceStart / sourceFps + localTime * speed + rampOffsetSourceFrames / sourceFps
   const rawSourceTime = clampVideoSourceTime(unclampedSourceTime, sourceFps, item.sourceDuration)
   const snappedSourceFrame = Math.round(rawSourceTime * sourceFps)
   const sourceTime =
@@ -200,6 +259,17 @@ export async function renderVideoItem(
       ? (snappedSourceFrame + 1e-4) / sourceFps
       : rawSourceTime
   const tier2ToleranceSeconds = getTier2VideoFrameToleranceSeconds(sourceFps)
+  // Frame blending builds in-between frames from extractor-decoded neighbours.
+  // Live <video> playback still holds frames; paused/scrub preview and export
+  // go through the extractor and get the synthesized frame.
+  const frameInterpolationCache =
+    (item.frameBlending ?? 'nearest') !== 'nearest' &&
+    !item.isReversed &&
+    sourceFrameOffset === 0 &&
+    useMediabunny.has(item.id) &&
+    !mediabunnyDisabledItems.has(item.id)
+      ? rctx.frameInterpolationCache
+      : undefined
   const domVideoElementProvider = rctx.domVideoElementProvider
   // During transitions, frame can lie outside item's natural span (the
   // participant's renderSpan is extended to cover the transition zone), and
@@ -371,6 +441,7 @@ export async function renderVideoItem(
   // This keeps large-jump and transition-entry stalls off the main thread while
   // preserving the same exact-frame preview path once the extractor is warm.
   if (
+    !frameInterpolationCache &&
     shouldTryPreviewWorkerBitmap({ renderMode: rctx.renderMode, hasReadyDomVideo: hasDomVideo })
   ) {
     const drewWorkerBitmap = await tryDrawWorkerPredecodedBitmap(
@@ -405,6 +476,32 @@ export async function renderVideoItem(
       item.crop,
     )
 
+    if (frameInterpolationCache) {
+      const interpolated = await frameInterpolationCache.getFrame({
+        item,
+        extractor,
+        sourceTime: clampedTime,
+        sourceFps,
+      })
+      if (
+        interpolated &&
+        drawContainedMediaSource(
+          ctx,
+          interpolated.canvas,
+          interpolated.sourceWidth,
+          interpolated.sourceHeight,
+          transform,
+          canvasSettings,
+          item.crop,
+          undefined,
+          rctx.canvasPool,
+        )
+      ) {
+        mediabunnyFailureCountByItem.set(item.id, 0)
+        return
+      }
+    }
+
     if (isPreviewMode && scrubbingCache) {
       const cachedEntry = scrubbingCache.getVideoFrameEntry(
         item.id,
@@ -435,6 +532,7 @@ export async function renderVideoItem(
     if (
       rctx.renderMode === 'export' &&
       item.isReversed &&
+      !timeRemapCurve &&
       sourceFrameOffset === 0 &&
       rctx.reverseVideoFrameCache
     ) {
@@ -532,6 +630,17 @@ export async function renderVideoItem(
       if (scrubbingCache && capturedFrame) {
         scrubbingCache.putVideoFrame(item.id, capturedFrame, capturedSourceTime ?? clampedTime)
       }
+      if (rctx.hdrVideo?.eligibleItemIds.has(item.id)) {
+        await submitHdrVideoFrame(
+          ctx,
+          item.id,
+          extractor,
+          clampedTime,
+          transform,
+          drawLayout,
+          rctx.hdrVideo,
+        )
+      }
       return
     }
     mediabunnyFailedThisFrame = true
@@ -698,9 +807,12 @@ export function resolveVideoParticipantSourceTime(
     renderSpan.sourceTimeRamp && !item.isReversed
       ? getSourceFrameRampOffset(renderSpan.sourceTimeRamp, frame)
       : 0
-  const unclampedSourceTime = item.isReversed
-    ? (reverseSourceEnd - localFrame * speed * (sourceFps / rctx.fps) - 1) / sourceFps
-    : sourceStart / sourceFps + localTime * speed + rampOffsetSourceFrames / sourceFps
+  const timeRemapCurve = getVideoTimeRemapCurve(item, rctx)
+  const unclampedSourceTime = timeRemapCurve
+    ? sampleTimeRemap(timeRemapCurve, frame - item.from)
+    : item.isReversed
+      ? (reverseSourceEnd - localFrame * speed * (sourceFps / rctx.fps) - 1) / sourceFps
+      : sourceStart / sourceFps + localTime * speed + rampOffsetSourceFrames / sourceFps
   const rawSourceTime = clampVideoSourceTime(unclampedSourceTime, sourceFps, item.sourceDuration)
   const snappedSourceFrame = Math.round(rawSourceTime * sourceFps)
   return Math.abs(rawSourceTime * sourceFps - snappedSourceFrame) < 1e-6
@@ -9,7 +9,7 @@ import type { TimelineItem } from '@/types/timeline'
 import type { ItemKeyframes } from '@/types/keyframe'
 import type { CropSettings, ResolvedTransform } from '@/types/transform'
 import { resolveItemTransformAtFrame } from '@/features/export/deps/composition-runtime'
-import { resolveAnimatedCrop } from '@/features/export/deps/keyframes'
+import { resolveAnimatedCornerPin, resolveAnimatedCrop } from '@/features/export/deps/keyframes'
 import { applyRenderTimelineSpan, type RenderTimelineSpan } from './render-span'
 
 function clamp01(value: number): number {
@@ -174,6 +174,18 @@ export function getAnimatedCrop(
   return resolveAnimatedCrop(resolvedItem.crop, keyframes, frame - resolvedItem.from, dimensions)
 }
 
+/**
+ * Get the corner pin for an item at a specific (global) frame, with any
+ * keyframed corner offsets applied.
+ */
+export function getAnimatedCornerPin(
+  item: TimelineItem,
+  keyframes: ItemKeyframes | undefined,
+  frame: number,
+): TimelineItem['cornerPin'] {
+  return resolveAnimatedCornerPin(item.cornerPin, keyframes, frame - item.from)
+}
+
 /**
  * Build a map of item ID to keyframes for efficient lookup
  */
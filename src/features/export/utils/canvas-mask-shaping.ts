@@ -0,0 +1,227 @@
+/**
+ * Mask edge shaping for canvas export.
+ *
+ * Rasterizes masks whose edge can't be expressed as a single path + uniform
+ * blur: expanded/contracted masks, per-vertex feather, and motion-blurred
+ * masks sampled across the shutter.
+ */
+
+import type { ShapeItem } from '@/types/timeline'
+import type { ResolvedTransform } from '@/types/transform'
+import type { MaskCanvasSettings } from './canvas-masks'
+
+type Point = [number, number]
+
+/** Farther than any canvas edge, so sector wedges cover the whole frame. */
+const SECTOR_REACH = 1e5
+const MIN_MOTION_BLUR_SAMPLES = 3
+const MAX_MOTION_BLUR_SAMPLES = 12
+
+export interface MaskMotionSample {
+  path?: Path2D
+  bitmapMask?: OffscreenCanvas
+  feather: number
+}
+
+export function hasVariableMaskFeather(mask: ShapeItem): boolean {
+  return (
+    mask.shapeType === 'path' &&
+    (mask.maskType ?? 'clip') === 'alpha' &&
+    (mask.pathVertices ?? []).some((vertex) => vertex.feather !== undefined)
+  )
+}
+
+/** Whether the mask edge needs rasterizing before it can be composited. */
+export function needsMaskShaping(mask: ShapeItem): boolean {
+  return (mask.maskExpansion ?? 0) !== 0 || hasVariableMaskFeather(mask)
+}
+
+/** Number of sub-frame samples for a shutter angle in degrees. */
+export function getMaskMotionBlurSampleCount(shutterAngle: number): number {
+  const samples = Math.ceil((Math.min(360, shutterAngle) / 360) * MAX_MOTION_BLUR_SAMPLES)
+  return Math.max(MIN_MOTION_BLUR_SAMPLES, samples)
+}
+
+/**
+ * Sub-frame offsets (in frames) for a shutter angle, centered on the frame so
+ * a blurred mask stays aligned with the unblurred content it cuts.
+ */
+export function getMaskMotionBlurOffsets(shutterAngle: number): number[] {
+  const shutter = Math.min(360, Math.max(0, shutterAngle)) / 360
+  if (shutter === 0) return [0]
+  const count = getMaskMotionBlurSampleCount(shutterAngle)
+  return Array.from({ length: count }, (_, i) => (i / (count - 1) - 0.5) * shutter)
+}
+
+function createMaskCanvas(canvas: MaskCanvasSettings) {
+  const maskCanvas = new OffscreenCanvas(canvas.width, canvas.height)
+  return { maskCanvas, maskCtx: maskCanvas.getContext('2d')! }
+}
+
+function blurMask(
+  source: OffscreenCanvas,
+  feather: number,
+  canvas: MaskCanvasSettings,
+): OffscreenCanvas {
+  if (feather <= 0) return source
+  const { maskCanvas, maskCtx } = createMaskCanvas(canvas)
+  maskCtx.filter = `blur(${feather}px)`
+  maskCtx.drawImage(source, 0, 0)
+  maskCtx.filter = 'none'
+  return maskCanvas
+}
+
+/**
+ * Mask path vertices in canvas pixels, matching `getShapePath` for path
+ * shapes with the transform rotation applied about the shape center.
+ */
+function getVertexCanvasPositions(
+  mask: ShapeItem,
+  transform: ResolvedTransform,
+  canvas: MaskCanvasSettings,
+): Point[] {
+  const centerX = canvas.width / 2 + transform.x
+  const centerY = canvas.height / 2 + transform.y
+  const left = centerX - transform.width / 2
+  const top = centerY - transform.height / 2
+  const angle = (transform.rotation * Math.PI) / 180
+  const cos = Math.cos(angle)
+  const sin = Math.sin(angle)
+
+  return (mask.pathVertices ?? []).map((vertex) => {
+    const dx = left + vertex.position[0] * transform.width - centerX
+    const dy = top + vertex.position[1] * transform.height - centerY
+    return [centerX + dx * cos - dy * sin, centerY + dx * sin + dy * cos]
+  })
+}
+
+function extendFrom(origin: Point, through: Point): Point {
+  const dx = through[0] - origin[0]
+  const dy = through[1] - origin[1]
+  const length = Math.hypot(dx, dy) || 1
+  return [origin[0] + (dx / length) * SECTOR_REACH, origin[1] + (dy / length) * SECTOR_REACH]
+}
+
+/**
+ * Wedge around the mask centroid owned by one vertex: bounded by rays through
+ * the midpoints of its two adjacent edges. Together the wedges tile the plane.
+ */
+function getVertexSector(points: Point[], index: number, centroid: Point): Path2D {
+  const point = points[index]!
+  const previous = points[(index - 1 + points.length) % points.length]!
+  const next = points[(index + 1) % points.length]!
+  const previousMid: Point = [(previous[0] + point[0]) / 2, (previous[1] + point[1]) / 2]
+  const nextMid: Point = [(point[0] + next[0]) / 2, (point[1] + next[1]) / 2]
+  const sector = new Path2D()
+  sector.moveTo(centroid[0], centroid[1])
+  for (const corner of [previousMid, point, nextMid]) {
+    const far = extendFrom(centroid, corner)
+    sector.lineTo(far[0], far[1])
+  }
+  sector.closePath()
+  return sector
+}
+
+/**
+ * Blend per-vertex feather by blurring the hard mask once per distinct
+ * feather amount and weighting each blur by the vertices that use it. The
+ * weight maps are softened by the largest feather so neighbouring vertices
+ * fade into each other instead of meeting at a seam.
+ */
+function applyVariableFeather(
+  hardMask: OffscreenCanvas,
+  mask: ShapeItem,
+  transform: ResolvedTransform,
+  feather: number,
+  canvas: MaskCanvasSettings,
+): OffscreenCanvas {
+  const vertices = mask.pathVertices ?? []
+  const points = getVertexCanvasPositions(mask, transform, canvas)
+  const feathers = vertices.map((vertex) => Math.max(0, Math.round(vertex.feather ?? feather)))
+  const levels = [...new Set(feathers)]
+  if (levels.length === 1) return blurMask(hardMask, levels[0]!, canvas)
+
+  const centroid: Point = [
+    points.reduce((sum, point) => sum + point[0], 0) / points.length,
+    points.reduce((sum, point) => sum + point[1], 0) / points.length,
+  ]
+  const maxFeather = Math.max(...levels)
+  const { maskCanvas: output, maskCtx: outputCtx } = createMaskCanvas(canvas)
+  outputCtx.globalCompositeOperation = 'lighter'
+
+  for (const level of levels) {
+    const { maskCanvas: weight, maskCtx: weightCtx } = createMaskCanvas(canvas)
+    weightCtx.fillStyle = 'white'
+    feathers.forEach((vertexFeather, index) => {
+      if (vertexFeather === level) weightCtx.fill(getVertexSector(points, index, centroid))
+    })
+
+    const { maskCanvas: layer, maskCtx: layerCtx } = createMaskCanvas(canvas)
+    layerCtx.drawImage(blurMask(hardMask, level, canvas), 0, 0)
+    layerCtx.globalCompositeOperation = 'destination-in'
+    layerCtx.drawImage(blurMask(weight, maxFeather, canvas), 0, 0)
+    outputCtx.drawImage(layer, 0, 0)
+  }
+
+  outputCtx.globalCompositeOperation = 'source-over'
+  return output
+}
+
+/**
+ * Rasterize a mask with expansion and per-vertex feather baked in. The
+ * result is an alpha bitmap that needs no further feathering.
+ */
+export function renderShapedMaskBitmap(
+  mask: ShapeItem,
+  path: Path2D,
+  transform: ResolvedTransform,
+  feather: number,
+  canvas: MaskCanvasSettings,
+): OffscreenCanvas {
+  const { maskCanvas, maskCtx } = createMaskCanvas(canvas)
+  maskCtx.fillStyle = 'white'
+  maskCtx.fill(path)
+
+  // Grow by stroking the outline; shrink by erasing the same stroke.
+  const expansion = mask.maskExpansion ?? 0
+  if (expansion !== 0) {
+    maskCtx.strokeStyle = 'white'
+    maskCtx.lineWidth = Math.abs(expansion) * 2
+    maskCtx.lineJoin = 'round'
+    if (expansion < 0) maskCtx.globalCompositeOperation = 'destination-out'
+    maskCtx.stroke(path)
+    maskCtx.globalCompositeOperation = 'source-over'
+  }
+
+  return hasVariableMaskFeather(mask)
+    ? applyVariableFeather(maskCanvas, mask, transform, feather, canvas)
+    : blurMask(maskCanvas, feather, canvas)
+}
+
+/**
+ * Average mask samples taken across the shutter into one alpha bitmap.
+ * Each sample's own feather is applied before averaging.
+ */
+export function renderMotionBlurredMaskBitmap(
+  samples: MaskMotionSample[],
+  canvas: MaskCanvasSettings,
+): OffscreenCanvas {
+  const { maskCanvas, maskCtx } = createMaskCanvas(canvas)
+  maskCtx.globalCompositeOperation = 'lighter'
+  maskCtx.globalAlpha = 1 / Math.max(1, samples.length)
+
+  for (const sample of samples) {
+    let sampleMask = sample.bitmapMask
+    if (!sampleMask && sample.path) {
+      const { maskCanvas: pathCanvas, maskCtx: pathCtx } = createMaskCanvas(canvas)
+      pathCtx.fillStyle = 'white'
+      pathCtx.fill(sample.path)
+      sampleMask = pathCanvas
+    }
+    if (sampleMask) maskCtx.drawImage(blurMask(sampleMask, sample.feather, canvas), 0, 0)
+  }
+
+  maskCtx.globalAlpha = 1
+  maskCtx.globalCompositeOperation = 'source-over'
+  return maskCanvas
+}
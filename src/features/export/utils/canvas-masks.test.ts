@@ -75,6 +75,9 @@ beforeAll(() => {
 
     addPath() {}
     rect() {}
+    moveTo() {}
+    lineTo() {}
+    closePath() {}
   }
 
   class MockCanvasRenderingContext2D {
@@ -226,6 +229,48 @@ describe('canvas mask animation', () => {
     expect((activeMasks[0]!.path as { value?: string }).value).toContain('77')
   })
 
+  it('bakes expansion into a feather-free bitmap mask', () => {
+    const preparedMask = buildPreparedMask(
+      { ...baseMask, maskType: 'alpha', maskFeather: 6, maskExpansion: -4 },
+      { x: 0, y: 0, width: 100, height: 100, rotation: 0, opacity: 1, cornerRadius: 0 },
+      canvas,
+    )
+
+    expect(preparedMask.bitmapMask).toBeDefined()
+    expect(preparedMask.path).toBeUndefined()
+    expect(preparedMask.feather).toBe(0)
+  })
+
+  it('samples moving masks across the shutter for motion blur', () => {
+    const index = buildMaskFrameIndex(
+      [{ ...track, items: [{ ...baseMask, maskMotionBlur: 180 }] }],
+      canvas,
+    )
+
+    const activeMasks = getActiveMasksForFrame(index, 10, canvas, new Map())
+
+    expect(activeMasks).toHaveLength(1)
+    expect(activeMasks[0]?.bitmapMask).toBeDefined()
+    expect(activeMasks[0]?.maskType).toBe('alpha')
+    const sampledFrames = mocks.resolveActiveShapeMasksAtFrameMock.mock.calls
+      .slice(1)
+      .map(([, options]) => options.frame)
+    expect(sampledFrames[0]).toBe(9.75)
+    expect(sampledFrames[sampledFrames.length - 1]).toBe(10.25)
+  })
+
+  it('keeps static masks unblurred when motion blur is enabled', () => {
+    const index = buildMaskFrameIndex(
+      [{ ...track, items: [{ ...baseMask, maskMotionBlur: 180 }] }],
+      canvas,
+    )
+
+    const activeMasks = getActiveMasksForFrame(index, 10, canvas, new Map(), () => ({ x: 5 }))
+
+    expect(activeMasks[0]?.path).toBeDefined()
+    expect(activeMasks[0]?.bitmapMask).toBeUndefined()
+  })
+
   it('rasterizes corner-pinned shape masks into bitmap masks', () => {
     const preparedMask = buildPreparedMask(
       {
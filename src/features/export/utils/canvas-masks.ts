@@ -2,7 +2,7 @@
  * Canvas Mask Rendering System
  *
  * Applies clip-path and alpha masks to canvas items for client-side export.
- * Supports shape masks with feathering and inversion.
+ * Supports shape masks with feathering, inversion, expansion and motion blur.
  */
 
 import type { ShapeItem, TimelineTrack } from '@/types/timeline'
@@ -17,6 +17,12 @@ import {
   rotatePath,
   resolveActiveShapeMasksAtFrame,
 } from '@/features/export/deps/composition-runtime'
+import {
+  getMaskMotionBlurOffsets,
+  needsMaskShaping,
+  renderMotionBlurredMaskBitmap,
+  renderShapedMaskBitmap,
+} from './canvas-mask-shaping'
 
 interface MaskEntry {
   mask: ShapeItem
@@ -47,6 +53,9 @@ export interface MaskCanvasSettings {
   fps: number
 }
 
+/** A mask shape and transform resolved at one (possibly sub-) frame. */
+export type ResolvedMaskSample = { shape: ShapeItem; transform: ResolvedTransform }
+
 type MaskKeyframeResolver =
   | Map<string, ItemKeyframes>
   | ((itemId: string) => ItemKeyframes | undefined)
@@ -188,9 +197,20 @@ export function buildPreparedMask(
   const maskType = mask.maskType ?? 'clip'
   // Feather only applies to alpha masks - clip masks are always hard-edged
   const feather = maskType === 'alpha' ? (mask.maskFeather ?? 0) : 0
+  const path = svgPathToPath2D(svgPath)
+
+  if (needsMaskShaping(mask)) {
+    return {
+      bitmapMask: renderShapedMaskBitmap(mask, path, transform, feather, canvas),
+      inverted: mask.maskInvert ?? false,
+      feather: 0,
+      maskType,
+      trackOrder: 0,
+    }
+  }
 
   return {
-    path: svgPathToPath2D(svgPath),
+    path,
     inverted: mask.maskInvert ?? false,
     feather,
     maskType,
@@ -198,6 +218,89 @@ export function buildPreparedMask(
   }
 }
 
+function isSameMaskPose(a: ResolvedMaskSample, b: ResolvedMaskSample): boolean {
+  return (
+    a.transform.x === b.transform.x &&
+    a.transform.y === b.transform.y &&
+    a.transform.width === b.transform.width &&
+    a.transform.height === b.transform.height &&
+    a.transform.rotation === b.transform.rotation &&
+    (a.shape.pathVertices === b.shape.pathVertices ||
+      JSON.stringify(a.shape.pathVertices) === JSON.stringify(b.shape.pathVertices))
+  )
+}
+
+/**
+ * Build a motion-blurred mask by resolving it across the shutter around
+ * `frame`. Returns null when motion blur is off or the mask holds still, so
+ * callers fall back to the single-sample mask.
+ *
+ * @param mask - The mask shape item as stored on the timeline
+ * @param resolveAt - Resolves the mask's shape and transform at a sub-frame
+ */
+export function buildMotionBlurredPreparedMask(
+  mask: ShapeItem,
+  frame: number,
+  canvas: MaskCanvasSettings,
+  resolveAt: (sampleFrame: number) => ResolvedMaskSample | undefined,
+): PreparedMask | null {
+  const shutterAngle = mask.maskMotionBlur ?? 0
+  if (shutterAngle <= 0) return null
+
+  const lastFrame = mask.from + mask.durationInFrames - 1
+  const samples = getMaskMotionBlurOffsets(shutterAngle)
+    .map((offset) => resolveAt(Math.min(lastFrame, Math.max(mask.from, frame + offset))))
+    .filter((sample): sample is ResolvedMaskSample => sample !== undefined)
+  const first = samples[0]
+  if (!first || samples.every((sample) => isSameMaskPose(sample, first))) return null
+
+  const prepared = samples.map((sample) =>
+    buildPreparedMask(sample.shape, sample.transform, canvas),
+  )
+  return {
+    bitmapMask: renderMotionBlurredMaskBitmap(prepared, canvas),
+    inverted: prepared[0]!.inverted,
+    feather: 0,
+    maskType: 'alpha',
+    trackOrder: 0,
+  }
+}
+
+/**
+ * Intersect a shape mask with a subject matte rendered at canvas size. The
+ * shape's feather softens its edge before intersecting; inversion is kept, so
+ * an inverted matte mask hides the subject inside the shape.
+ */
+export function refinePreparedMaskWithMatte(
+  prepared: PreparedMask,
+  matte: OffscreenCanvas,
+  canvas: MaskCanvasSettings,
+): PreparedMask {
+  const maskCanvas = new OffscreenCanvas(canvas.width, canvas.height)
+  const maskCtx = maskCanvas.getContext('2d')!
+  if (prepared.feather > 0) {
+    maskCtx.filter = `blur(${prepared.feather}px)`
+  }
+  if (prepared.bitmapMask) {
+    maskCtx.drawImage(prepared.bitmapMask, 0, 0)
+  } else if (prepared.path) {
+    maskCtx.fillStyle = 'white'
+    maskCtx.fill(prepared.path)
+  }
+  maskCtx.filter = 'none'
+  maskCtx.globalCompositeOperation = 'destination-in'
+  maskCtx.drawImage(matte, 0, 0)
+  maskCtx.globalCompositeOperation = 'source-over'
+
+  return {
+    bitmapMask: maskCanvas,
+    inverted: prepared.inverted,
+    feather: 0,
+    maskType: 'alpha',
+    trackOrder: prepared.trackOrder,
+  }
+}
+
 /**
  * Collect all mask items from visible tracks.
  *
@@ -469,6 +572,7 @@ export function getActiveMasksForFrame(
   getPreviewTransformOverride?: (itemId: string) => Partial<ResolvedTransform> | undefined,
   getPreviewPathVerticesOverride?: PreviewPathVerticesOverride,
   getLiveItem?: (itemId: string) => ShapeItem | undefined,
+  getMatteMask?: (maskItemId: string) => OffscreenCanvas | undefined,
 ): Array<{
   path?: Path2D
   bitmapMask?: OffscreenCanvas
@@ -489,17 +593,29 @@ export function getActiveMasksForFrame(
     mask: getLiveItem?.(mask.id) ?? mask,
     trackOrder,
   }))
-  const activeMaskShapes = resolveActiveShapeMasksAtFrame(liveMasks, {
+  const resolveOptions = {
     canvas,
-    frame,
-    getKeyframes: (itemId) => resolveMaskKeyframes(keyframes, itemId),
+    getKeyframes: (itemId: string) => resolveMaskKeyframes(keyframes, itemId),
     getPreviewTransform: getPreviewTransformOverride,
     getPreviewPathVertices: getPreviewPathVerticesOverride,
-  })
+  }
+  const activeMaskShapes = resolveActiveShapeMasksAtFrame(liveMasks, { ...resolveOptions, frame })
 
   for (const mask of activeMaskShapes) {
+    const liveMask = liveMasks.find((entry) => entry.mask.id === mask.shape.id)
+    const blurred = liveMask
+      ? buildMotionBlurredPreparedMask(liveMask.mask, frame, canvas, (sampleFrame) => {
+          const [sample] = resolveActiveShapeMasksAtFrame([liveMask], {
+            ...resolveOptions,
+            frame: sampleFrame,
+          })
+          return sample
+        })
+      : null
+    const prepared = blurred ?? buildPreparedMask(mask.shape, mask.transform, canvas)
+    const matte = getMatteMask?.(mask.shape.id)
     activeMasks.push({
-      ...buildPreparedMask(mask.shape, mask.transform, canvas),
+      ...(matte ? refinePreparedMaskWithMatte(prepared, matte, canvas) : prepared),
       trackOrder: mask.trackOrder,
     })
   }
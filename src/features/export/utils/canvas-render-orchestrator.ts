@@ -3,6 +3,8 @@
  *
  * Top-level entry points that drive the full render pipeline:
  * - {@link renderComposition} – renders a full video composition (video + audio)
+ * - {@link renderImageSequence} – renders numbered PNG frames (to a folder or a zip)
+ * - {@link renderAnimatedImage} – renders an animated GIF or WebP
  * - {@link renderAudioOnly}  – encodes only the audio tracks
  * - {@link renderSingleFrame} – renders one frame to a Blob (thumbnails)
  *
@@ -12,15 +14,34 @@
  */
 
 import type { CompositionInputProps } from '@/types/export'
+import { isHdrColorSpace, type ProjectColorSpace } from '@/types/color'
 import type { TimelineTrack, TimelineItem, VideoItem } from '@/types/timeline'
 import type { ClientExportSettings, RenderProgress, ClientRenderResult } from './client-renderer'
-import { createOutputFormat, getDefaultAudioCodec, getMimeType } from './client-renderer'
+import {
+  createOutputFormat,
+  DEFAULT_ANIMATED_IMAGE_OPTIONS,
+  getDefaultAudioCodec,
+  getMimeType,
+} from './client-renderer'
 import { createMediabunnyInputSource } from '@/infrastructure/browser/mediabunny-input-source'
+import {
+  getHdrCodecString,
+  getHdrVideoColorSpace,
+  type EncodedHdrFrame,
+} from '@/infrastructure/gpu-color'
 import { createLogger } from '@/shared/logging/logger'
 import { hasMediaCrop } from '@/shared/utils/media-crop'
 import { DEFAULT_PROJECT_HEIGHT, DEFAULT_PROJECT_WIDTH } from '@/shared/projects/defaults'
 import {
-  buildTranscriptSubtitleWebVtt,
+  createFolderSequenceSink,
+  createZipSequenceSink,
+  getSequenceFrameFileName,
+  IMAGE_SEQUENCE_MIME_TYPE,
+  type ImageSequenceSink,
+} from './image-sequence'
+import { createAnimatedImageEncoder, planAnimationFrames } from './animated-image-export'
+import {
+  buildEmbeddedSubtitleTracks,
   omitTranscriptSubtitleItemsForSoftSubtitleExport,
 } from './embedded-subtitle-export'
 
@@ -53,6 +74,13 @@ export interface RenderEngineOptions {
   composition: CompositionInputProps
   onProgress: (progress: RenderProgress) => void
   signal?: AbortSignal
+  /** Image sequences: write frames into this export folder instead of a zip. */
+  sequenceFolder?: FileSystemDirectoryHandle
+  /**
+   * Image sequences: hand frames to this sink instead (main-thread callers whose
+   * output folder isn't a directory handle, e.g. the headless driver).
+   */
+  sequenceSink?: ImageSequenceSink
 }
 
 interface AudioRenderOptions {
@@ -80,6 +108,49 @@ interface PacketRemuxPlan {
 
 const EPSILON = 1e-6
 
+/**
+ * Alpha exports render onto a transparent canvas unless the project sets an
+ * explicit background colour.
+ */
+function withAlphaBackground(
+  composition: CompositionInputProps,
+  settings: ClientExportSettings,
+): CompositionInputProps {
+  return settings.alpha ? { ...composition, transparentBackground: true } : composition
+}
+
+/** HDR exports only apply to projects composited in a PQ/HLG working space. */
+function resolveHdrExportColorSpace(
+  composition: CompositionInputProps,
+  settings: ClientExportSettings,
+): ProjectColorSpace | null {
+  const colorSpace = composition.colorSpace
+  return settings.hdr && colorSpace && isHdrColorSpace(colorSpace) ? colorSpace : null
+}
+
+/**
+ * Wrap a 10-bit readback as a tagged `VideoFrame`. Only the colour VUI
+ * (primaries/transfer/matrix) reaches the bitstream; WebCodecs has no way to
+ * write mastering-display or content-light-level metadata.
+ */
+function createHdrVideoFrame(
+  encoded: EncodedHdrFrame,
+  colorSpace: ProjectColorSpace,
+  timestamp: number,
+  duration: number,
+): VideoFrame {
+  return new VideoFrame(encoded.data, {
+    format: 'I420P10',
+    codedWidth: encoded.layout.lumaStride,
+    codedHeight: encoded.layout.lumaRows,
+    visibleRect: { x: 0, y: 0, width: encoded.width, height: encoded.height },
+    layout: encoded.layout.planes,
+    timestamp: Math.round(timestamp * 1_000_000),
+    duration: Math.round(duration * 1_000_000),
+    colorSpace: getHdrVideoColorSpace(colorSpace),
+  })
+}
+
 function isIdentityTransform(item: VideoItem): boolean {
   const transform = item.transform
   if (hasMediaCrop(item.crop)) return false
@@ -339,9 +410,18 @@ async function tryPacketRemuxComposition(
  * Main render function – orchestrates the entire client-side render.
  */
 export async function renderComposition(options: RenderEngineOptions): Promise<ClientRenderResult> {
-  const { settings, composition, onProgress, signal } = options
+  if (options.settings.mode === 'image-sequence') {
+    return renderImageSequence(options)
+  }
+  if (options.settings.mode === 'animated-image') {
+    return renderAnimatedImage(options)
+  }
+
+  const { settings, onProgress, signal } = options
+  const composition = withAlphaBackground(options.composition, settings)
   const { fps, durationInFrames = 0 } = composition
   const canvasAudio = await loadCanvasAudio()
+  const hdrColorSpace = resolveHdrExportColorSpace(composition, settings)
 
   getLog().info('Starting enhanced client render', {
     fps,
@@ -350,6 +430,8 @@ export async function renderComposition(options: RenderEngineOptions): Promise<C
     width: settings.resolution.width,
     height: settings.resolution.height,
     codec: settings.codec,
+    alpha: settings.alpha ?? false,
+    hdr: hdrColorSpace,
     tracksCount: composition.tracks?.length ?? 0,
     hasTransitions: (composition.transitions?.length ?? 0) > 0,
     hasKeyframes: (composition.keyframes?.length ?? 0) > 0,
@@ -376,7 +458,12 @@ export async function renderComposition(options: RenderEngineOptions): Promise<C
   }
 
   // Fast path: when the timeline is a single unmodified clip, remux packets directly.
-  const remuxResult = await tryPacketRemuxComposition(options)
+  // Alpha exports always re-encode so the alpha plane is written, HDR exports
+  // re-encode to 10-bit BT.2100, and loudness normalization needs the decoded mix.
+  const remuxResult =
+    settings.alpha || hdrColorSpace || settings.loudnessTarget
+      ? null
+      : await tryPacketRemuxComposition(options)
   if (remuxResult) {
     return remuxResult
   }
@@ -400,14 +487,17 @@ export async function renderComposition(options: RenderEngineOptions): Promise<C
   })
 
   // Process audio in parallel with setup
-  let audioData: { samples: Float32Array[]; sampleRate: number; channels: number } | null = null
+  let audioData: Awaited<ReturnType<CanvasAudioModule['processAudio']>> = null
   if (await canvasAudio.hasAudioContent(composition)) {
     try {
-      audioData = await canvasAudio.processAudio(composition, signal)
+      audioData = await canvasAudio.processAudio(composition, signal, {
+        loudnessTarget: settings.loudnessTarget,
+      })
       getLog().info('Audio processed', {
         hasAudio: !!audioData,
         sampleRate: audioData?.sampleRate,
         channels: audioData?.channels,
+        integratedLufs: audioData?.loudness.integratedLufs,
       })
     } catch (error) {
       getLog().error('Audio processing failed, continuing without audio', { error })
@@ -433,34 +523,43 @@ export async function renderComposition(options: RenderEngineOptions): Promise<C
     target,
   })
 
-  const transcriptSubtitleVtt = settings.embedSubtitles
-    ? buildTranscriptSubtitleWebVtt(composition)
-    : null
+  const embeddedSubtitleTracks = settings.embedSubtitles
+    ? buildEmbeddedSubtitleTracks(composition)
+    : []
   const supportsWebVttSubtitles = format.getSupportedSubtitleCodecs().includes('webvtt')
-  const embedTranscriptSubtitles = transcriptSubtitleVtt !== null && supportsWebVttSubtitles
+  const embedTranscriptSubtitles = embeddedSubtitleTracks.length > 0 && supportsWebVttSubtitles
   const renderCompositionInput = embedTranscriptSubtitles
     ? omitTranscriptSubtitleItemsForSoftSubtitleExport(composition)
     : composition
 
-  if (transcriptSubtitleVtt !== null && !supportsWebVttSubtitles) {
+  if (embeddedSubtitleTracks.length > 0 && !supportsWebVttSubtitles) {
     throw new Error(
       `${settings.container.toUpperCase()} export does not support embedded transcript subtitles. ` +
         'Use MP4, WebM, or MKV for embedded subtitles.',
     )
   }
 
-  let transcriptSubtitleSource: InstanceType<typeof TextSubtitleSource> | null = null
+  // One WebVTT track per language (transcript + translations), tagged so
+  // players list them by language.
+  const subtitleSources: Array<{
+    source: InstanceType<typeof TextSubtitleSource>
+    vtt: string
+  }> = []
   if (embedTranscriptSubtitles) {
-    transcriptSubtitleSource = new TextSubtitleSource('webvtt')
-    output.addSubtitleTrack(transcriptSubtitleSource, {
-      languageCode: 'eng',
-      name: 'Transcript',
-      disposition: {
-        default: true,
-      },
-    })
-    getLog().info('Transcript subtitles will be embedded as WebVTT track', {
+    for (const track of embeddedSubtitleTracks) {
+      const source = new TextSubtitleSource('webvtt')
+      output.addSubtitleTrack(source, {
+        languageCode: track.languageCode,
+        name: track.name,
+        disposition: {
+          default: track.isDefault,
+        },
+      })
+      subtitleSources.push({ source, vtt: track.vtt })
+    }
+    getLog().info('Subtitles will be embedded as WebVTT tracks', {
       container: settings.container,
+      languages: embeddedSubtitleTracks.map((track) => track.languageCode),
     })
   }
 
@@ -512,6 +611,11 @@ export async function renderComposition(options: RenderEngineOptions): Promise<C
     bitrate: settings.videoBitrate ?? 10_000_000,
     keyFrameInterval: 2, // Keyframe every 2 seconds for better seeking
     latencyMode: 'quality', // Enables B-frames and consistent frame quality for offline encoding
+    alpha: settings.alpha ? 'keep' : 'discard',
+    // Main 10 profile strings so the encoder accepts 10-bit input
+    ...(hdrColorSpace
+      ? { fullCodecString: getHdrCodecString(settings.codec === 'av1' ? 'av1' : 'h265') }
+      : {}),
   })
 
   // Add video track
@@ -560,9 +664,9 @@ export async function renderComposition(options: RenderEngineOptions): Promise<C
   // Start the output
   await output.start()
 
-  if (transcriptSubtitleSource && transcriptSubtitleVtt) {
-    await transcriptSubtitleSource.add(transcriptSubtitleVtt)
-    transcriptSubtitleSource.close()
+  for (const { source, vtt } of subtitleSources) {
+    await source.add(vtt)
+    source.close()
   }
 
   // Feed audio buffer after output has started
@@ -621,8 +725,17 @@ export async function renderComposition(options: RenderEngineOptions): Promise<C
       // its pixels, so writing to the canvas here cannot corrupt it.
       await frameRenderer.renderFrame(frame)
 
+      // HDR frames are read back from the half-float composite, resampled to
+      // the export size on the GPU.
+      const hdrFrame = hdrColorSpace
+        ? await frameRenderer.readHdrFrame(exportWidth, exportHeight)
+        : null
+      if (hdrColorSpace && !hdrFrame) {
+        throw new Error('HDR export requires WebGPU; the frame could not be composited in HDR')
+      }
+
       // Scale to output resolution if needed
-      if (needsScaling) {
+      if (needsScaling && !hdrFrame) {
         outputCtx.clearRect(0, 0, exportWidth, exportHeight)
         outputCtx.drawImage(renderCanvas, 0, 0, exportWidth, exportHeight)
       }
@@ -638,7 +751,11 @@ export async function renderComposition(options: RenderEngineOptions): Promise<C
 
       // Snapshot canvas pixels into a VideoSample. The constructor copies
       // pixel data immediately — the canvas is free for the next render.
-      const sample = new VideoSample(outputCanvas, { timestamp, duration: frameDuration })
+      const sampleSource =
+        hdrFrame && hdrColorSpace
+          ? createHdrVideoFrame(hdrFrame, hdrColorSpace, timestamp, frameDuration)
+          : outputCanvas
+      const sample = new VideoSample(sampleSource, { timestamp, duration: frameDuration })
 
       // Kick off encoding in the background. NOT awaited here — it runs
       // concurrently with the next iteration's renderFrame().
@@ -699,7 +816,8 @@ export async function renderComposition(options: RenderEngineOptions): Promise<C
       throw new Error('No output buffer generated')
     }
 
-    const blob = new Blob([buffer], { type: getMimeType(settings.container, settings.codec) })
+    const mimeType = getMimeType(settings.container, settings.codec, hdrColorSpace !== null)
+    const blob = new Blob([buffer], { type: mimeType })
 
     onProgress({
       phase: 'finalizing',
@@ -715,9 +833,10 @@ export async function renderComposition(options: RenderEngineOptions): Promise<C
 
     return {
       blob,
-      mimeType: getMimeType(settings.container, settings.codec),
+      mimeType,
       duration: durationSeconds,
       fileSize: blob.size,
+      loudness: audioData?.loudness,
     }
   } catch (error) {
     // Cleanup on error
@@ -736,6 +855,286 @@ export async function renderComposition(options: RenderEngineOptions): Promise<C
   }
 }
 
+// ---------------------------------------------------------------------------
+// Composited frames (image sequence + animated image)
+// ---------------------------------------------------------------------------
+
+/** Export-size canvas holding the frame just composited. */
+interface CompositedFrame {
+  canvas: OffscreenCanvas
+  ctx: OffscreenCanvasRenderingContext2D
+}
+
+interface CompositedFrameSource {
+  /** Composite `frame` and return it scaled to the export resolution. */
+  renderFrame(frame: number): Promise<CompositedFrame>
+  dispose(): void
+}
+
+/**
+ * Composite frames at the composition's size and scale them to the export
+ * resolution. The canvas-encoded modes (image sequence, animated image) render
+ * through this; the returned canvas is reused, so read it before the next frame.
+ */
+async function createCompositedFrameSource(
+  composition: CompositionInputProps,
+  settings: ClientExportSettings,
+): Promise<CompositedFrameSource> {
+  const compositionWidth = composition.width ?? settings.resolution.width
+  const compositionHeight = composition.height ?? settings.resolution.height
+  const exportWidth = settings.resolution.width
+  const exportHeight = settings.resolution.height
+  const needsScaling = exportWidth !== compositionWidth || exportHeight !== compositionHeight
+
+  const renderCanvas = new OffscreenCanvas(compositionWidth, compositionHeight)
+  const ctx = renderCanvas.getContext('2d')
+  if (!ctx) {
+    throw new Error('Failed to create OffscreenCanvas 2D context')
+  }
+  const outputCanvas = needsScaling ? new OffscreenCanvas(exportWidth, exportHeight) : renderCanvas
+  const outputCtx = needsScaling ? outputCanvas.getContext('2d')! : ctx
+  const output: CompositedFrame = { canvas: outputCanvas, ctx: outputCtx }
+
+  const frameRenderer = await createCompositionRenderer(composition, renderCanvas, ctx)
+  try {
+    await frameRenderer.preload()
+  } catch (error) {
+    frameRenderer.dispose()
+    throw error
+  }
+
+  return {
+    async renderFrame(frame) {
+      await frameRenderer.renderFrame(frame)
+      if (needsScaling) {
+        outputCtx.clearRect(0, 0, exportWidth, exportHeight)
+        outputCtx.drawImage(renderCanvas, 0, 0, exportWidth, exportHeight)
+      }
+      return output
+    },
+    dispose: () => frameRenderer.dispose(),
+  }
+}
+
+interface CompositedFrameLoopOptions {
+  signal?: AbortSignal
+  onProgress: (progress: RenderProgress) => void
+  /** Progress (percent) reported before the first and after the last frame. */
+  progressFrom: number
+  progressTo: number
+  /** Progress message prefix, e.g. `Rendering frame` → `Rendering frame 3/40`. */
+  label?: string
+}
+
+/**
+ * Render `frames` (composition frame numbers) in order and hand each composited
+ * frame to `onFrame`, checking for cancellation before every frame.
+ */
+async function forEachCompositedFrame(
+  source: CompositedFrameSource,
+  frames: readonly number[],
+  options: CompositedFrameLoopOptions,
+  onFrame: (frame: CompositedFrame, index: number) => Promise<void>,
+): Promise<void> {
+  const { signal, onProgress, progressFrom, progressTo, label = 'Rendering frame' } = options
+  const totalFrames = frames.length
+  for (let index = 0; index < totalFrames; index++) {
+    if (signal?.aborted) {
+      throw new DOMException('Render cancelled', 'AbortError')
+    }
+
+    await onFrame(await source.renderFrame(frames[index]!), index)
+
+    onProgress({
+      phase: 'rendering',
+      progress: Math.round(progressFrom + (index / totalFrames) * (progressTo - progressFrom)),
+      currentFrame: index,
+      totalFrames,
+      message: `${label} ${index + 1}/${totalFrames}`,
+    })
+  }
+}
+
+// ---------------------------------------------------------------------------
+// renderImageSequence
+// ---------------------------------------------------------------------------
+
+/**
+ * Render every frame to a numbered PNG (`frame_000000.png`, ...). Each frame is
+ * handed to `options.sequenceSink` or written to `options.sequenceFolder` as
+ * soon as it is encoded; without either the frames are streamed into one
+ * stored ZIP for download. With `settings.alpha` the frames keep transparency
+ * wherever nothing is drawn. No audio is written.
+ */
+export async function renderImageSequence(
+  options: RenderEngineOptions,
+): Promise<ClientRenderResult> {
+  const { settings, onProgress, signal } = options
+  const composition = withAlphaBackground(options.composition, settings)
+  const { fps, durationInFrames = 0 } = composition
+
+  getLog().info('Starting image sequence render', {
+    fps,
+    durationInFrames,
+    width: settings.resolution.width,
+    height: settings.resolution.height,
+    alpha: settings.alpha ?? false,
+  })
+
+  if (durationInFrames <= 0) {
+    throw new Error('Composition has no duration')
+  }
+
+  const totalFrames = durationInFrames
+  onProgress({ phase: 'preparing', progress: 0, totalFrames, message: 'Preparing frames...' })
+
+  if (signal?.aborted) {
+    throw new DOMException('Render cancelled', 'AbortError')
+  }
+
+  const sink =
+    options.sequenceSink ??
+    (options.sequenceFolder
+      ? createFolderSequenceSink(options.sequenceFolder)
+      : createZipSequenceSink())
+  const source = await createCompositedFrameSource(composition, settings)
+
+  try {
+    let bytesWritten = 0
+    const frames = Array.from({ length: totalFrames }, (_, frame) => frame)
+    await forEachCompositedFrame(
+      source,
+      frames,
+      { signal, onProgress, progressFrom: 0, progressTo: 100 },
+      async ({ canvas }, frame) => {
+        const png = await canvas.convertToBlob({ type: 'image/png' })
+        await sink.writeFrame(getSequenceFrameFileName(frame), png)
+        bytesWritten += png.size
+      },
+    )
+
+    // Frames already written to a folder leave nothing to download.
+    const zip = sink.finish()
+
+    onProgress({
+      phase: 'finalizing',
+      progress: 100,
+      currentFrame: totalFrames,
+      totalFrames,
+      message: 'Complete!',
+    })
+
+    return {
+      blob: zip ?? new Blob([], { type: IMAGE_SEQUENCE_MIME_TYPE }),
+      mimeType: IMAGE_SEQUENCE_MIME_TYPE,
+      duration: totalFrames / fps,
+      fileSize: zip?.size ?? bytesWritten,
+    }
+  } finally {
+    source.dispose()
+  }
+}
+
+// ---------------------------------------------------------------------------
+// renderAnimatedImage
+// ---------------------------------------------------------------------------
+
+/**
+ * Render the composition as an animated GIF or WebP. Frames are decimated to
+ * `settings.animatedImage.fps` (never above the project rate) and fed to the
+ * container's encoder; GIF frames are palettised here, WebP frames are
+ * encoded by the browser and muxed. A global GIF palette takes two passes over
+ * the frames (colour analysis, then indexing) so no frame is held in memory.
+ * No audio is written.
+ */
+export async function renderAnimatedImage(
+  options: RenderEngineOptions,
+): Promise<ClientRenderResult> {
+  const { settings, onProgress, signal } = options
+  const composition = withAlphaBackground(options.composition, settings)
+  const { fps, durationInFrames = 0 } = composition
+  const animation = settings.animatedImage ?? DEFAULT_ANIMATED_IMAGE_OPTIONS
+  const container = settings.container === 'webp' ? 'webp' : 'gif'
+
+  getLog().info('Starting animated image render', {
+    fps,
+    outputFps: Math.min(animation.fps, fps),
+    durationInFrames,
+    width: settings.resolution.width,
+    height: settings.resolution.height,
+    container,
+    palette: animation.palette,
+    dither: animation.dither,
+  })
+
+  if (durationInFrames <= 0) {
+    throw new Error('Composition has no duration')
+  }
+
+  const plan = planAnimationFrames(durationInFrames, fps, animation.fps)
+  const totalFrames = plan.length
+  onProgress({ phase: 'preparing', progress: 0, totalFrames, message: 'Preparing frames...' })
+
+  if (signal?.aborted) {
+    throw new DOMException('Render cancelled', 'AbortError')
+  }
+
+  const encoder = createAnimatedImageEncoder(
+    container,
+    animation,
+    { width: settings.resolution.width, height: settings.resolution.height },
+    settings.quality,
+  )
+  const source = await createCompositedFrameSource(composition, settings)
+
+  try {
+    const frames = plan.map((frame) => frame.sourceFrame)
+    const passSpan = 90 / encoder.passes
+    for (let pass = 0; pass < encoder.passes; pass++) {
+      await forEachCompositedFrame(
+        source,
+        frames,
+        {
+          signal,
+          onProgress,
+          progressFrom: pass * passSpan,
+          progressTo: (pass + 1) * passSpan,
+          label: pass < encoder.passes - 1 ? 'Analyzing colors, frame' : 'Rendering frame',
+        },
+        ({ canvas, ctx }, index) => encoder.addFrame(canvas, ctx, plan[index]!, pass),
+      )
+    }
+
+    onProgress({
+      phase: 'finalizing',
+      progress: 90,
+      currentFrame: totalFrames,
+      totalFrames,
+      message: container === 'gif' ? 'Encoding GIF...' : 'Muxing WebP...',
+    })
+
+    const mimeType = getMimeType(container)
+    const blob = new Blob([encoder.finish()], { type: mimeType })
+
+    onProgress({
+      phase: 'finalizing',
+      progress: 100,
+      currentFrame: totalFrames,
+      totalFrames,
+      message: 'Complete!',
+    })
+
+    return {
+      blob,
+      mimeType,
+      duration: durationInFrames / fps,
+      fileSize: blob.size,
+    }
+  } finally {
+    source.dispose()
+  }
+}
+
 // ---------------------------------------------------------------------------
 // renderSingleFrame
 // ---------------------------------------------------------------------------
@@ -886,7 +1285,9 @@ export async function renderAudioOnly(options: AudioRenderOptions): Promise<Clie
     throw new Error('No audio content found in composition')
   }
 
-  const audioData = await canvasAudio.processAudio(composition, signal)
+  const audioData = await canvasAudio.processAudio(composition, signal, {
+    loudnessTarget: settings.loudnessTarget,
+  })
   if (!audioData) {
     throw new Error('Failed to process audio')
   }
@@ -1009,5 +1410,6 @@ export async function renderAudioOnly(options: AudioRenderOptions): Promise<Clie
     mimeType: getMimeType(settings.container),
     duration: durationSeconds,
     fileSize: blob.size,
+    loudness: audioData.loudness,
   }
 }
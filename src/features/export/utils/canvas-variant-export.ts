@@ -0,0 +1,63 @@
+/**
+ * Canvas variants in exports: pick the canvas a render targets (the project's
+ * base canvas or one of its linked aspect-ratio variants) and lay the
+ * timeline out on it. Resolved at render time so every variant reflects the
+ * current base timeline.
+ */
+
+import type { Project, ProjectCanvasVariant } from '@/types/project'
+import type { TimelineItem } from '@/types/timeline'
+import type { ItemKeyframes } from '@/types/keyframe'
+import {
+  DEFAULT_PROJECT_FPS,
+  DEFAULT_PROJECT_HEIGHT,
+  DEFAULT_PROJECT_WIDTH,
+} from '@/shared/projects/defaults'
+import { getCanvasVariant, resolveCanvasVariant } from '@/features/export/deps/projects'
+
+interface Size {
+  width: number
+  height: number
+}
+
+export interface ExportCanvas extends Size {
+  items: TimelineItem[]
+  keyframes: ItemKeyframes[]
+  /** Set when rendering a variant rather than the base canvas */
+  variant?: ProjectCanvasVariant
+}
+
+export function resolveExportCanvas(
+  project: Pick<Project, 'metadata' | 'canvasVariants'> | null | undefined,
+  layers: { items: TimelineItem[]; keyframes: ItemKeyframes[] },
+  canvasVariantId?: string | null,
+): ExportCanvas {
+  const base = {
+    width: project?.metadata.width ?? DEFAULT_PROJECT_WIDTH,
+    height: project?.metadata.height ?? DEFAULT_PROJECT_HEIGHT,
+    fps: project?.metadata.fps ?? DEFAULT_PROJECT_FPS,
+  }
+  const variant = getCanvasVariant(project, canvasVariantId)
+  if (!variant) return { width: base.width, height: base.height, ...layers }
+
+  const resolved = resolveCanvasVariant(layers, base, variant)
+  return {
+    width: resolved.resolution.width,
+    height: resolved.resolution.height,
+    items: resolved.items,
+    keyframes: resolved.keyframes,
+    variant,
+  }
+}
+
+/**
+ * Carry an output resolution chosen for one canvas over to another, keeping
+ * the same scale (e.g. 720p of a 1080p base becomes 720x1280 for 9:16).
+ */
+export function scaleExportResolution(resolution: Size, from: Size, to: Size): Size {
+  const scale = Math.min(resolution.width / from.width, resolution.height / from.height)
+  return {
+    width: Math.max(2, Math.round((to.width * scale) / 2) * 2),
+    height: Math.max(2, Math.round((to.height * scale) / 2) * 2),
+  }
+}
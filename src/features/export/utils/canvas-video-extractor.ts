@@ -275,6 +275,39 @@ export class VideoFrameExtractor {
     }
   }
 
+  /**
+   * Clone of the raw decoded frame at `timestamp`, with its native pixel
+   * format and colour metadata intact (for HDR / 10-bit upload). Null when
+   * no sample covers the timestamp or the track needs rotating on draw.
+   * The caller owns the returned frame and must close it.
+   */
+  async captureDecodedFrame(timestamp: number): Promise<VideoFrame | null> {
+    const duration = this.duration
+    const clampedTime =
+      duration > 0
+        ? Math.max(0, Math.min(timestamp, duration - VideoFrameExtractor.TIMESTAMP_EPSILON))
+        : Math.max(0, timestamp)
+
+    try {
+      await this.ensureSampleForTimestamp(clampedTime)
+      if (!this.currentSample || !this.currentSampleCoversTimestamp(clampedTime)) return null
+      const frame = this.cloneCurrentVideoFrame()
+      if (
+        frame &&
+        this.videoTrack &&
+        (frame.displayWidth !== Math.round(this.videoTrack.displayWidth) ||
+          frame.displayHeight !== Math.round(this.videoTrack.displayHeight))
+      ) {
+        frame.close()
+        return null
+      }
+      return frame
+    } catch (error) {
+      this.sampleLoopError = error
+      return null
+    }
+  }
+
   private async ensureSampleForTimestamp(timestamp: number): Promise<void> {
     if (!this.sink) return
 
@@ -14,6 +14,9 @@
  */
 
 import type { CompositionInputProps } from '@/types/export'
+import { DEFAULT_PROJECT_COLOR_SPACE, isHdrColorSpace } from '@/types/color'
+import type { EncodedHdrFrame } from '@/infrastructure/gpu-color'
+import { convertWorkingColor, parseHexRgb } from '@/infrastructure/gpu-color'
 import type {
   TimelineItem,
   VideoItem,
@@ -27,11 +30,12 @@ import type { ResolvedTransform } from '@/types/transform'
 import { createLogger } from '@/shared/logging/logger'
 import { blobUrlManager } from '@/infrastructure/browser/blob-url-manager'
 import { resolveMediaUrl } from '@/features/export/deps/media-library'
+import { resolveTimeRemapCurve, sampleTimeRemap } from '@/features/export/deps/keyframes'
 import { VideoSourcePool } from '@/features/export/deps/player-contract'
 
 // Import subsystems
-import { buildKeyframesMap } from './canvas-keyframes'
-import { type AdjustmentLayerWithTrackOrder } from './canvas-effects'
+import { buildKeyframesMap, getAnimatedTransform } from './canvas-keyframes'
+import { getAdjustmentLayerEffects, type AdjustmentLayerWithTrackOrder } from './canvas-effects'
 import { GpuPipelineManager } from './gpu-pipeline-manager'
 import { isItemFullyOccluding, type FrameOcclusionContext } from './frame-occlusion'
 import {
@@ -88,6 +92,16 @@ import {
 import { ScrubbingCache } from '@/features/export/deps/preview'
 import { resolveFrameRenderOptimization } from './render-path-optimizer'
 import { ReverseVideoFrameCache } from './reverse-video-frame-cache'
+import {
+  FrameInterpolationCache,
+  PREVIEW_INTERPOLATION_MAX_PIXELS,
+} from './frame-interpolation-cache'
+import {
+  resolveMatteMaskCanvases,
+  SegmentationMatteCache,
+  type MatteMaskClip,
+  usesSegmentationMattes,
+} from './segmentation-mattes'
 import { resolveReverseConformedVideoItem } from '@/shared/utils/reverse-conform-item'
 import {
   itemHasEnabledGpuEffect,
@@ -100,8 +114,15 @@ function getLog() {
   return createLogger('ClientRenderEngine')
 }
 
-function getPrewarmVideoSourceTimeSeconds(item: VideoItem, frame: number, fps: number): number {
+function getPrewarmVideoSourceTimeSeconds(
+  item: VideoItem,
+  frame: number,
+  fps: number,
+  itemKeyframes: ItemKeyframes | undefined,
+): number {
   const localFrame = frame - item.from
+  const timeRemapCurve = resolveTimeRemapCurve(item, itemKeyframes, fps)
+  if (timeRemapCurve) return sampleTimeRemap(timeRemapCurve, localFrame)
   const sourceStart = item.sourceStart ?? item.trimStart ?? 0
   const sourceFps = item.sourceFps ?? fps
   const speed = item.speed ?? 1
@@ -191,13 +212,32 @@ export async function createCompositionRenderer(
     useProxyMedia?: boolean
   } = {},
 ) {
-  const { fps, transitions = [], backgroundColor = '#000000', keyframes = [] } = composition
+  const { fps, transitions = [], keyframes = [] } = composition
+  // Alpha exports leave the canvas transparent unless a background is set explicitly.
+  const backgroundColor =
+    composition.backgroundColor ?? (composition.transparentBackground ? null : '#000000')
   const renderMode = options.mode ?? 'export'
+  // HDR working spaces always composite on the GPU so values above SDR white survive.
+  const workingColorSpace = composition.colorSpace ?? DEFAULT_PROJECT_COLOR_SPACE
+  const isHdrProject = isHdrColorSpace(workingColorSpace)
+  let hdrSignalGpuCanvas: OffscreenCanvas | null = null
+  let hdrSignalGpuCtx: GPUCanvasContext | null = null
+  let hdrSignalCanvas: OffscreenCanvas | null = null
+  // Set only when the latest renderFrame finished on the GPU compositor, so
+  // HDR readback never picks up a composite left over from an earlier frame.
+  let hdrCompositeReady = false
+  // Remapped clips sample original source times, so they keep the unconformed media.
+  const isTimeRemappedVideo = (item: VideoItem, itemKeyframes: ItemKeyframes | undefined) =>
+    resolveTimeRemapCurve(item, itemKeyframes, fps) !== null
   const tracks =
     composition.tracks?.map((track) => ({
       ...track,
       items: (track.items ?? []).map((item) =>
-        item.type === 'video'
+        item.type === 'video' &&
+        !isTimeRemappedVideo(
+          item,
+          composition.keyframes?.find((entry) => entry.itemId === item.id),
+        )
           ? resolveReverseConformedVideoItem(item, fps, {
               mode: renderMode,
               useProxy: options.useProxyMedia,
@@ -279,6 +319,9 @@ export async function createCompositionRenderer(
     if (current.type !== 'video') {
       return current
     }
+    if (isTimeRemappedVideo(current, getCurrentKeyframes(current.id))) {
+      return current
+    }
 
     const resolvedVideoItem = resolveReverseConformedVideoItem(current, fps, {
       mode: renderMode,
@@ -529,6 +572,33 @@ export async function createCompositionRenderer(
   let prewarmCtx: OffscreenCanvasRenderingContext2D | CanvasRenderingContext2D | null = null
   let prewarmAttempted = false
   const reverseVideoFrameCache = renderMode === 'export' ? new ReverseVideoFrameCache() : undefined
+  const frameInterpolationCache = new FrameInterpolationCache({
+    maxPixels: renderMode === 'preview' ? PREVIEW_INTERPOLATION_MAX_PIXELS : undefined,
+  })
+  const segmentationMatteCache = new SegmentationMatteCache()
+  const matteMaskShapes = maskFrameIndex.masks
+    .map(({ mask }) => mask)
+    .filter((mask) => mask.maskMatteItemId)
+  const resolveMatteMaskClip = (itemId: string, frame: number): MatteMaskClip | undefined => {
+    const baseItem = videoItemsById.get(itemId)
+    if (!baseItem) return undefined
+    const item = getCurrentItem(baseItem)
+    const itemKeyframes = getCurrentKeyframes(item.id)
+    const transform = getAnimatedTransform(item, itemKeyframes, frame, canvasSettings)
+    const previewOverride =
+      renderMode === 'preview' ? getPreviewTransformOverride?.(item.id) : undefined
+    return {
+      item,
+      keyframes: itemKeyframes,
+      transform: previewOverride
+        ? {
+            ...transform,
+            ...previewOverride,
+            cornerRadius: previewOverride.cornerRadius ?? transform.cornerRadius,
+          }
+        : transform,
+    }
+  }
 
   // Build the shared ItemRenderContext used by canvas-item-renderer functions
   const itemRenderContext: ItemRenderContext = {
@@ -550,6 +620,8 @@ export async function createCompositionRenderer(
     mediabunnyDisabledItems,
     mediabunnyFailureCountByItem,
     reverseVideoFrameCache,
+    frameInterpolationCache,
+    segmentationMatteCache,
     imageElements,
     gifFramesMap,
     keyframesMap,
@@ -564,6 +636,7 @@ export async function createCompositionRenderer(
     gpuShapePipeline: null,
     gpuTextPipeline: null,
     gpuMaskCombinePipeline: null,
+    hdrVideo: null,
     gpuTextTextureCache: gpu.textTextureCache,
     gpuBitmapMaskTextureCache: gpu.bitmapMaskTextureCache,
     // Cross-frame text raster cache (preview scrub). Only populated in preview
@@ -774,6 +847,10 @@ export async function createCompositionRenderer(
       if (!hasDom && hasCompositionItems) {
         throw new Error('WORKER_REQUIRES_MAIN_THREAD:composition')
       }
+      // Subject mattes live in the workspace, which only the main thread can read.
+      if (!hasDom && usesSegmentationMattes(tracks)) {
+        throw new Error('WORKER_REQUIRES_MAIN_THREAD:segmentation')
+      }
 
       const priorityFrame = Number.isFinite(options.priorityFrame)
         ? Math.round(options.priorityFrame!)
@@ -1230,6 +1307,7 @@ export async function createCompositionRenderer(
 
     async renderFrame(frame: number) {
       const scrubPerfStartMs = scrubPerfStart()
+      hdrCompositeReady = false
       // 3-tier cache lookup (preview only)
       // Tier 1 (GPU texture) → Tier 3 (RAM ImageBitmap) → miss → full render
       if (scrubbingCache) {
@@ -1253,10 +1331,24 @@ export async function createCompositionRenderer(
       }
 
       // Clear canvas
-      ctx.fillStyle = backgroundColor
-      ctx.fillRect(0, 0, canvas.width, canvas.height)
+      if (backgroundColor) {
+        ctx.fillStyle = backgroundColor
+        ctx.fillRect(0, 0, canvas.width, canvas.height)
+      } else {
+        ctx.clearRect(0, 0, canvas.width, canvas.height)
+      }
 
       // Prepare masks for this frame
+      const matteMaskCanvases =
+        matteMaskShapes.length > 0
+          ? await resolveMatteMaskCanvases(
+              matteMaskShapes.map((mask) => getLiveMaskItem?.(mask.id) ?? mask),
+              frame,
+              canvasSettings,
+              segmentationMatteCache,
+              resolveMatteMaskClip,
+            )
+          : null
       const activeMasks = getActiveMasksForFrame(
         maskFrameIndex,
         frame,
@@ -1265,6 +1357,7 @@ export async function createCompositionRenderer(
         renderMode === 'preview' ? getPreviewTransformOverride : undefined,
         renderMode === 'preview' ? getPreviewPathVerticesOverride : undefined,
         renderMode === 'preview' ? getLiveMaskItem : undefined,
+        matteMaskCanvases ? (maskId) => matteMaskCanvases.get(maskId) : undefined,
       )
 
       const frameScene = frameSceneCache.resolve(
@@ -1498,6 +1591,32 @@ export async function createCompositionRenderer(
           hasGpuEffects: hasAnyGpuEffects,
           renderTaskCount: renderTasks.length,
         })
+      // Video items drawn without effects, adjustments or corner pin can bypass the 8-bit canvas
+      // and upload their decoded 10-bit frame straight into the HDR working space.
+      const collectHdrEligibleVideoItemIds = (): Set<string> => {
+        const eligible = new Set<string>()
+        for (const task of renderTasks) {
+          if (task.type !== 'item') continue
+          const item = getCurrentItem(task.item)
+          if (item.type !== 'video' || hasCornerPin(item.cornerPin)) continue
+          const effects =
+            (renderMode === 'preview' ? getPreviewEffectsOverride?.(item.id) : undefined) ??
+            item.effects
+          if (effects?.some((effect) => effect.enabled)) continue
+          const adjustmentEffects = getAdjustmentLayerEffects(
+            task.trackOrder,
+            adjustmentLayers,
+            frame,
+            renderMode === 'preview' ? getPreviewEffectsOverride : undefined,
+            renderMode === 'preview' ? getLiveItemSnapshot : undefined,
+            getCurrentKeyframes,
+          )
+          if (adjustmentEffects.length > 0) continue
+          eligible.add(item.id)
+        }
+        return eligible
+      }
+
       const hasNonNormalBlend = renderTasks.some(
         (t) =>
           t.type === 'item' &&
@@ -1507,23 +1626,35 @@ export async function createCompositionRenderer(
             return Boolean(blendMode && blendMode !== 'normal')
           })(),
       )
-      if (hasNonNormalBlend && !itemRenderContext.gpuPipeline) {
+      const needsGpuCompositor = hasNonNormalBlend || isHdrProject
+      if (needsGpuCompositor && !itemRenderContext.gpuPipeline) {
         itemRenderContext.gpuPipeline = await gpu.ensureEffects()
         if (!itemRenderContext.gpuPipeline) {
           getLog().warn('GPU pipeline init failed - blend modes will use Canvas2D fallback')
         }
       }
       const useGpuCompositor = Boolean(
-        hasNonNormalBlend && itemRenderContext.gpuPipeline && gpu.effects && gpu.ensureCompositor(),
+        needsGpuCompositor &&
+        itemRenderContext.gpuPipeline &&
+        gpu.effects &&
+        gpu.ensureCompositor(),
       )
+      gpu.compositor?.setWorkingColorSpace(workingColorSpace)
       const gpuCompositeOutput = useGpuCompositor
         ? gpu.ensureCompositeOutput(canvasSettings.width, canvasSettings.height)
         : null
+      const useHdrComposite = isHdrProject && useGpuCompositor && gpuCompositeOutput !== null
+      itemRenderContext.hdrVideo = useHdrComposite
+        ? {
+            eligibleItemIds: collectHdrEligibleVideoItemIds(),
+            draws: new Map(),
+          }
+        : null
       if (shouldUseDeferredGpuBatch && itemRenderContext.gpuPipeline) {
         itemRenderContext.gpuPipeline.beginBatch()
       }
 
-      if (shouldDirectRenderSingleTask) {
+      if (shouldDirectRenderSingleTask && !useHdrComposite) {
         const directTask = renderTasks[0]
         if (directTask?.type === 'item') {
           const blendMode = getEffectiveBlendMode(getCurrentItem(directTask.item))
@@ -1671,6 +1802,7 @@ export async function createCompositionRenderer(
           renderTransitionFallbackCanvas,
           renderItemWithEffects,
         })
+        hdrCompositeReady = useHdrComposite && finalCompositeSource === gpuCompositeOutput?.canvas
       }
 
       // Log occlusion culling stats periodically (only in development)
@@ -1706,7 +1838,12 @@ export async function createCompositionRenderer(
         const extractor = videoExtractors.get(item.id)
         if (!extractor) continue
 
-        const sourceTime = getPrewarmVideoSourceTimeSeconds(item, frame, fps)
+        const sourceTime = getPrewarmVideoSourceTimeSeconds(
+          item,
+          frame,
+          fps,
+          getCurrentKeyframes(item.id),
+        )
         const clampedTime = Math.max(0, Math.min(sourceTime, extractor.getDuration() - 0.01))
 
         try {
@@ -1774,7 +1911,12 @@ export async function createCompositionRenderer(
             continue
           }
 
-          const sourceTime = getPrewarmVideoSourceTimeSeconds(item, frame, fps)
+          const sourceTime = getPrewarmVideoSourceTimeSeconds(
+            item,
+            frame,
+            fps,
+            getCurrentKeyframes(item.id),
+          )
           const clampedTime = Math.max(0, Math.min(sourceTime, extractor.getDuration() - 0.01))
 
           const existing = batchByExtractor.get(item.id)
@@ -1816,7 +1958,12 @@ export async function createCompositionRenderer(
           if (!useMediabunny.has(item.id) || mediabunnyDisabledItems.has(item.id)) continue
           const extractor = videoExtractors.get(item.id)
           if (!extractor) continue
-          const sourceTime = getPrewarmVideoSourceTimeSeconds(item, frame, fps)
+          const sourceTime = getPrewarmVideoSourceTimeSeconds(
+            item,
+            frame,
+            fps,
+            getCurrentKeyframes(item.id),
+          )
           const clampedTime = Math.max(0, Math.min(sourceTime, extractor.getDuration() - 0.01))
           try {
             await extractor.drawFrame(ctx2d, clampedTime, 0, 0, 1, 1)
@@ -1870,7 +2017,12 @@ export async function createCompositionRenderer(
             if (!item || item.type !== 'video') return
             const localFrame = targetFrame - item.from
             if (localFrame >= item.durationInFrames) return
-            const baseSourceTime = getPrewarmVideoSourceTimeSeconds(item, targetFrame, fps)
+            const baseSourceTime = getPrewarmVideoSourceTimeSeconds(
+              item,
+              targetFrame,
+              fps,
+              getCurrentKeyframes(item.id),
+            )
             try {
               await extractor.drawFrame(ctx2d, Math.max(0, baseSourceTime), 0, 0, 1, 1)
             } catch {
@@ -1898,6 +2050,68 @@ export async function createCompositionRenderer(
       return canvas
     },
 
+    /** Working colour space the GPU compositor blends in. */
+    getWorkingColorSpace() {
+      return workingColorSpace
+    },
+
+    /**
+     * Encode the last HDR composite as 10-bit BT.2020 YUV for export. Returns
+     * null for SDR projects or when the frame fell back to Canvas2D.
+     */
+    async readHdrFrame(width: number, height: number): Promise<EncodedHdrFrame | null> {
+      if (!isHdrProject || !hdrCompositeReady) return null
+      const composite = gpu.compositor?.getLastComposite()
+      if (!composite || !gpu.ensureHdrEncoder()) return null
+      return gpu.hdrEncoder!.encode(
+        composite.view,
+        width,
+        height,
+        workingColorSpace,
+        parseHexRgb(backgroundColor ?? '#000000'),
+      )
+    },
+
+    /**
+     * Draw the last HDR composite as untone-mapped PQ/HLG code values so scopes
+     * measure the real signal. Returns null for SDR projects or cached frames.
+     */
+    captureHdrSignal(): OffscreenCanvas | null {
+      if (!isHdrProject || !hdrCompositeReady || !gpu.compositor || !gpu.effects) return null
+      const { width, height } = canvas
+      if (!hdrSignalGpuCanvas) {
+        hdrSignalGpuCanvas = new OffscreenCanvas(width, height)
+        hdrSignalCanvas = new OffscreenCanvas(width, height)
+      }
+      if (hdrSignalGpuCanvas.width !== width || hdrSignalGpuCanvas.height !== height) {
+        hdrSignalGpuCanvas.width = width
+        hdrSignalGpuCanvas.height = height
+        hdrSignalGpuCtx = null
+      }
+      hdrSignalGpuCtx ??= gpu.effects.configureCanvas(hdrSignalGpuCanvas)
+      if (!hdrSignalGpuCtx || !gpu.compositor.drawSignalToCanvas(hdrSignalGpuCtx)) return null
+
+      const output = hdrSignalCanvas!
+      if (output.width !== width || output.height !== height) {
+        output.width = width
+        output.height = height
+      }
+      const outputCtx = output.getContext('2d')
+      if (!outputCtx) return null
+      outputCtx.clearRect(0, 0, width, height)
+      if (backgroundColor) {
+        const [r, g, b] = convertWorkingColor(
+          parseHexRgb(backgroundColor),
+          'rec709',
+          workingColorSpace,
+        )
+        outputCtx.fillStyle = `rgb(${r * 255} ${g * 255} ${b * 255})`
+        outputCtx.fillRect(0, 0, width, height)
+      }
+      outputCtx.drawImage(hdrSignalGpuCanvas, 0, 0)
+      return output
+    },
+
     /**
      * Eagerly initialize the GPU effects + transition pipelines so the first
      * transition frame doesn't pay the ~100-150ms WebGPU device + shader
@@ -1968,12 +2182,17 @@ export async function createCompositionRenderer(
       // === PERFORMANCE: Clean up optimization resources ===
       scrubbingCache?.dispose()
       reverseVideoFrameCache?.dispose()
+      frameInterpolationCache.dispose()
+      segmentationMatteCache.dispose()
       // Preview-only cross-frame caches hold canvas backing stores (hundreds of
       // MB for text rasters / corner-pin warps); drop them now instead of at GC.
       itemRenderContext.textRasterCache?.clear()
       itemRenderContext.cornerPinWarpCache?.clear()
 
       gpu.dispose()
+      hdrSignalGpuCtx = null
+      hdrSignalGpuCanvas = null
+      hdrSignalCanvas = null
       frameSceneCache.invalidate()
       canvasPool.dispose()
       textMeasureCache.clear()
@@ -26,6 +26,7 @@ import {
   getVideoBitrateForQuality,
   mapToClientSettings,
   selectFallbackVideoCodec,
+  supportsAlphaVideo,
   validateSettings,
 } from './client-renderer'
 
@@ -99,6 +100,37 @@ describe('client-renderer export matrix', () => {
     })
   })
 
+  it('only allows alpha video for VP8/VP9 in Matroska containers', () => {
+    const base = {
+      mode: 'video' as const,
+      quality: 'high' as const,
+      resolution: { width: 1920, height: 1080 },
+      fps: 30,
+      alpha: true,
+    }
+
+    expect(validateSettings({ ...base, codec: 'vp9', container: 'webm' })).toEqual({ valid: true })
+    expect(validateSettings({ ...base, codec: 'av1', container: 'webm' })).toEqual({
+      valid: false,
+      error: 'Alpha video export requires VP8 or VP9 in WebM or MKV',
+    })
+    expect(supportsAlphaVideo('vp8', 'mkv')).toBe(true)
+    expect(supportsAlphaVideo('vp9', 'mp4')).toBe(false)
+  })
+
+  it('skips codec/container checks for image sequences', () => {
+    expect(
+      validateSettings({
+        mode: 'image-sequence',
+        codec: 'avc',
+        container: 'webm',
+        quality: 'high',
+        resolution: { width: 1920, height: 1080 },
+        fps: 30,
+      }),
+    ).toEqual({ valid: true })
+  })
+
   it('uses Mediabunny encoder checks for supported codec detection', async () => {
     mockCanEncodeVideo.mockImplementation(async (codec) => codec === 'avc' || codec === 'vp9')
 
@@ -12,8 +12,19 @@
  * 5. Finalize and return the video blob
  */
 
-import type { ExportSettings, ExtendedExportSettings } from '@/types/export'
+import type {
+  AnimatedImageContainer,
+  AnimatedImageOptions,
+  ExportMode,
+  ExportSettings,
+  ExportLoudnessReport,
+  ExtendedExportSettings,
+  LoudnessTarget,
+  LoudnessTargetPreset,
+} from '@/types/export'
 import { DEFAULT_PROJECT_HEIGHT } from '@/shared/projects/defaults'
+import { getHdrCodecString } from '@/infrastructure/gpu-color'
+import type { SidecarSubtitleFile, SidecarSubtitleFormat } from './sidecar-subtitle-export'
 
 // Codec mapping for mediabunny
 type ClientVideoCodec = 'avc' | 'hevc' | 'vp8' | 'vp9' | 'av1'
@@ -24,12 +35,16 @@ export type ClientCodec = ClientVideoCodec // Alias for backwards compatibility
 export type ClientVideoContainer = 'mp4' | 'webm' | 'mov' | 'mkv'
 // Audio-only containers
 export type ClientAudioContainer = 'mp3' | 'aac' | 'wav'
+// Animated image containers
+export type ClientAnimatedImageContainer = AnimatedImageContainer
 // All containers
-export type ClientContainer = ClientVideoContainer | ClientAudioContainer
+export type ClientContainer =
+  | ClientVideoContainer
+  | ClientAudioContainer
+  | ClientAnimatedImageContainer
 type ExportVideoCodec = Exclude<ExportSettings['codec'], 'prores'>
 
-// Export mode
-export type ExportMode = 'video' | 'audio'
+export type { ExportMode }
 
 export interface ClientExportSettings {
   mode: ExportMode
@@ -43,6 +58,33 @@ export interface ClientExportSettings {
   videoBitrate?: number
   sampleRate?: number // For audio exports (default: 48000)
   embedSubtitles?: boolean
+  /** Subtitle files to write alongside a video export, one per subtitle track. */
+  sidecarSubtitles?: SidecarSubtitleFormat
+  /** Preserve transparency (RGBA PNG frames, or VP8/VP9 alpha in WebM/MKV). */
+  alpha?: boolean
+  /** Encode 10-bit HDR in the project's PQ/HLG working space (H.265 / AV1 only). */
+  hdr?: boolean
+  /** GIF/WebP options (animated-image mode). */
+  animatedImage?: AnimatedImageOptions
+  /** Normalize the mix to this integrated loudness / true-peak ceiling. */
+  loudnessTarget?: LoudnessTarget
+}
+
+export const DEFAULT_ANIMATED_IMAGE_OPTIONS: AnimatedImageOptions = {
+  fps: 15,
+  loopCount: 0,
+  palette: 'global',
+  dither: 'floyd-steinberg',
+}
+
+/** GIF frame delays are whole centiseconds and browsers clamp < 2cs, so cap at 50fps. */
+export const MAX_ANIMATED_IMAGE_FPS = 50
+
+export const LOUDNESS_TARGET_PRESETS: Record<LoudnessTargetPreset, LoudnessTarget> = {
+  streaming: { integratedLufs: -14, truePeakDbtp: -1 },
+  podcast: { integratedLufs: -16, truePeakDbtp: -1 },
+  'ebu-r128': { integratedLufs: -23, truePeakDbtp: -1 },
+  'atsc-a85': { integratedLufs: -24, truePeakDbtp: -2 },
 }
 
 export interface RenderProgress {
@@ -54,10 +96,18 @@ export interface RenderProgress {
 }
 
 export interface ClientRenderResult {
+  /**
+   * Encoded file. For image sequences, a stored ZIP of the numbered PNGs, or
+   * empty when the frames were written to an export folder.
+   */
   blob: Blob
   mimeType: string
   duration: number
   fileSize: number
+  /** Loudness of the exported mix; absent when there was no audio. */
+  loudness?: ExportLoudnessReport
+  /** Subtitle files to write next to the video, when sidecar subtitles were requested. */
+  sidecarSubtitles?: SidecarSubtitleFile[]
 }
 
 export interface CodecSupportCheckOptions {
@@ -209,6 +259,12 @@ function isAudioOnlyContainer(container: ClientContainer): container is ClientAu
   return ['mp3', 'wav', 'aac'].includes(container)
 }
 
+function isAnimatedImageContainer(
+  container: ClientContainer,
+): container is ClientAnimatedImageContainer {
+  return container === 'gif' || container === 'webp'
+}
+
 /**
  * Check if a codec is supported by WebCodecs in this browser
  */
@@ -299,7 +355,14 @@ export async function createOutputFormat(
 /**
  * Get the MIME type for a container/codec combination
  */
-export function getMimeType(container: ClientContainer, codec?: ClientCodec): string {
+export function getMimeType(
+  container: ClientContainer,
+  codec?: ClientCodec,
+  hdr = false,
+): string {
+  if (container === 'gif') return 'image/gif'
+  if (container === 'webp') return 'image/webp'
+
   // Audio-only containers
   if (container === 'mp3') return 'audio/mpeg'
   if (container === 'aac') return 'audio/aac'
@@ -308,14 +371,19 @@ export function getMimeType(container: ClientContainer, codec?: ClientCodec): st
   // Video containers
   if (container === 'mp4' || container === 'mov') {
     if (codec === 'avc') return `video/${container}; codecs="avc1.42E01E"`
-    if (codec === 'hevc') return `video/${container}; codecs="hvc1.1.6.L93.B0"`
+    if (codec === 'hevc') {
+      const codecString = hdr ? getHdrCodecString('h265') : 'hvc1.1.6.L93.B0'
+      return `video/${container}; codecs="${codecString}"`
+    }
     return `video/${container}`
   }
   if (container === 'webm' || container === 'mkv') {
     const mimeBase = container === 'webm' ? 'video/webm' : 'video/x-matroska'
     if (codec === 'vp8') return `${mimeBase}; codecs="vp8"`
     if (codec === 'vp9') return `${mimeBase}; codecs="vp09.00.10.08"`
-    if (codec === 'av1') return `${mimeBase}; codecs="av01.0.04M.08"`
+    if (codec === 'av1') {
+      return `${mimeBase}; codecs="${hdr ? getHdrCodecString('av1') : 'av01.0.04M.08'}"`
+    }
     return mimeBase
   }
   return 'video/mp4'
@@ -340,8 +408,22 @@ export function validateSettings(settings: ClientExportSettings): {
     if (!isAudioOnlyContainer(settings.container)) {
       return { valid: false, error: 'Audio export must use an audio-only container' }
     }
-  } else {
-    if (isAudioOnlyContainer(settings.container)) {
+  } else if (settings.mode === 'animated-image') {
+    if (settings.container !== 'gif' && settings.container !== 'webp') {
+      return { valid: false, error: 'Animated image export must use GIF or WebP' }
+    }
+    const options = settings.animatedImage ?? DEFAULT_ANIMATED_IMAGE_OPTIONS
+    if (!(options.fps > 0 && options.fps <= MAX_ANIMATED_IMAGE_FPS)) {
+      return {
+        valid: false,
+        error: `Invalid animation frame rate (must be 1-${MAX_ANIMATED_IMAGE_FPS})`,
+      }
+    }
+    if (!Number.isInteger(options.loopCount) || options.loopCount < 0) {
+      return { valid: false, error: 'Loop count must be 0 (forever) or a positive whole number' }
+    }
+  } else if (settings.mode !== 'image-sequence') {
+    if (isAudioOnlyContainer(settings.container) || isAnimatedImageContainer(settings.container)) {
       return { valid: false, error: 'Video export must use a video container' }
     }
 
@@ -351,6 +433,20 @@ export function validateSettings(settings: ClientExportSettings): {
         error: `Codec ${settings.codec} is not supported in ${settings.container.toUpperCase()}`,
       }
     }
+
+    if (settings.alpha && !supportsAlphaVideo(settings.codec, settings.container)) {
+      return {
+        valid: false,
+        error: 'Alpha video export requires VP8 or VP9 in WebM or MKV',
+      }
+    }
+
+    if (settings.hdr && !supportsHdrVideo(settings.codec)) {
+      return { valid: false, error: 'HDR video export requires H.265 or AV1' }
+    }
+    if (settings.hdr && settings.alpha) {
+      return { valid: false, error: 'HDR video export cannot keep an alpha channel' }
+    }
   }
 
   // Auto-round odd dimensions to even (required by video codecs).
@@ -365,6 +461,20 @@ export function validateSettings(settings: ClientExportSettings): {
   return { valid: true }
 }
 
+/**
+ * Whether the codec/container pair can carry an alpha channel. WebCodecs only
+ * encodes alpha for VP8/VP9 (stored as Matroska BlockAdditions); ProRes 4444
+ * has no browser encoder.
+ */
+export function supportsAlphaVideo(codec: ClientCodec, container: ClientContainer): boolean {
+  return (codec === 'vp8' || codec === 'vp9') && (container === 'webm' || container === 'mkv')
+}
+
+/** WebCodecs only encodes 10-bit for HEVC Main 10 and AV1. */
+export function supportsHdrVideo(codec: ClientCodec): boolean {
+  return codec === 'hevc' || codec === 'av1'
+}
+
 /**
  * Estimate file size based on settings and duration
  */
@@ -376,6 +486,23 @@ export function estimateFileSize(settings: ClientExportSettings, durationSeconds
     return Math.round(totalBytes * 1.05) // 5% overhead for container
   }
 
+  if (settings.mode === 'animated-image') {
+    // Rough per-pixel costs: LZW on dithered 8-bit indices compresses poorly;
+    // lossy WebP frames land far smaller.
+    const { width, height } = settings.resolution
+    const options = settings.animatedImage ?? DEFAULT_ANIMATED_IMAGE_OPTIONS
+    const frames = Math.max(1, Math.floor(durationSeconds * Math.min(options.fps, settings.fps)))
+    const bytesPerPixel = settings.container === 'gif' ? 0.6 : 0.12
+    return Math.round(width * height * bytesPerPixel * frames)
+  }
+
+  if (settings.mode === 'image-sequence') {
+    // Lossless PNG lands around half of raw RGBA for typical graphics.
+    const { width, height } = settings.resolution
+    const bytesPerFrame = width * height * 4 * 0.5
+    return Math.round(bytesPerFrame * durationSeconds * settings.fps)
+  }
+
   const videoBits = (settings.videoBitrate ?? 5_000_000) * durationSeconds
   const audioBits = (settings.audioBitrate ?? 192_000) * durationSeconds
   const totalBytes = (videoBits + audioBits) / 8
@@ -3,6 +3,7 @@ import type { CompositionInputProps } from '@/types/export'
 import type { SubtitleSegmentItem, TimelineTrack } from '@/types/timeline'
 
 import {
+  buildEmbeddedSubtitleTracks,
   buildTranscriptSubtitleWebVtt,
   omitTranscriptSubtitleItemsForSoftSubtitleExport,
 } from './embedded-subtitle-export'
@@ -84,6 +85,36 @@ describe('embedded transcript subtitle export', () => {
     expect(composition.tracks[0]?.items?.map((item) => item.id)).toEqual(['subtitle-1', 'title-1'])
   })
 
+  it('embeds each translation language as its own tagged track after the transcript', () => {
+    const transcript = makeTranscriptSubtitle({
+      source: { type: 'transcript', mediaId: 'media-1', clipId: 'clip-1', language: 'en' },
+    })
+    const translation = (id: string, language: string, text: string) =>
+      makeTranscriptSubtitle({
+        id,
+        source: { type: 'translation', language, sourceItemId: 'subtitle-1', translatedAt: 1 },
+        cues: [{ id: `${id}-cue`, startSeconds: 0, endSeconds: 1, text }],
+      })
+    const composition = makeComposition([
+      transcript,
+      translation('subtitle-es', 'es', 'Hola'),
+      translation('subtitle-pt', 'pt-BR', 'Olá'),
+    ])
+
+    const tracks = buildEmbeddedSubtitleTracks(composition)
+
+    expect(tracks.map((track) => [track.languageCode, track.name, track.isDefault])).toEqual([
+      ['eng', 'Transcript', true],
+      ['spa', 'Spanish', false],
+      ['por', 'Brazilian Portuguese', false],
+    ])
+    expect(tracks[1]?.vtt).toContain('00:00:01.000 --> 00:00:02.000\nHola')
+    expect(tracks[0]?.vtt).not.toContain('Hola')
+    expect(omitTranscriptSubtitleItemsForSoftSubtitleExport(composition).tracks[0]?.items).toEqual(
+      [],
+    )
+  })
+
   it('returns null when there are no transcript subtitles to embed', () => {
     const embeddedSubtitle = makeTranscriptSubtitle({
       source: {
@@ -1,12 +1,38 @@
 import type { CompositionInputProps } from '@/types/export'
 import type { SubtitleSegmentItem, TimelineItem, TimelineTrack } from '@/types/timeline'
 import { serializeVtt, type SubtitleCue } from '@/shared/utils/subtitles'
+import { getSubtitleLanguage, toIso639_2LanguageCode } from '@/shared/utils/subtitle-languages'
+
+/** One soft-subtitle track to mux into the exported container. */
+export interface EmbeddedSubtitleExportTrack {
+  /** ISO 639-2 tag for the container track header (`und` when unknown). */
+  languageCode: string
+  name: string
+  vtt: string
+  /** Player-selected track; only the first (the original language) sets it. */
+  isDefault: boolean
+}
 
 function isTranscriptSubtitleItem(item: TimelineItem): item is SubtitleSegmentItem {
   return item.type === 'subtitle' && item.source.type === 'transcript'
 }
 
-export function buildTranscriptSubtitleWebVtt(composition: CompositionInputProps): string | null {
+/** Transcript captions and their translations leave the picture for soft subtitles. */
+function isEmbeddableSubtitleItem(item: TimelineItem): item is SubtitleSegmentItem {
+  return (
+    isTranscriptSubtitleItem(item) ||
+    (item.type === 'subtitle' && item.source.type === 'translation')
+  )
+}
+
+/**
+ * Cues of the matching subtitle items in composition time, clipped to their
+ * items and the composition, sorted chronologically.
+ */
+export function collectSubtitleCues(
+  composition: CompositionInputProps,
+  include: (item: TimelineItem) => item is SubtitleSegmentItem,
+): SubtitleCue[] {
   const fps = composition.fps
   const durationSeconds =
     composition.durationInFrames !== undefined ? composition.durationInFrames / fps : Infinity
@@ -17,7 +43,7 @@ export function buildTranscriptSubtitleWebVtt(composition: CompositionInputProps
     if (track.visible === false) continue
 
     for (const item of track.items ?? []) {
-      if (!isTranscriptSubtitleItem(item)) continue
+      if (!include(item)) continue
 
       const itemStartSeconds = item.from / fps
       const itemEndSeconds = (item.from + item.durationInFrames) / fps
@@ -42,12 +68,71 @@ export function buildTranscriptSubtitleWebVtt(composition: CompositionInputProps
     }
   }
 
-  if (cues.length === 0) return null
   // Items can be processed track-by-track in any order, but VTT consumers
   // expect cues sorted chronologically. Sort by start time, breaking ties
   // by end time so deterministically-overlapping cues don't reorder.
   cues.sort((a, b) => a.startSeconds - b.startSeconds || a.endSeconds - b.endSeconds)
-  return serializeVtt(cues)
+  return cues
+}
+
+export function buildTranscriptSubtitleWebVtt(composition: CompositionInputProps): string | null {
+  const cues = collectSubtitleCues(composition, isTranscriptSubtitleItem)
+  return cues.length > 0 ? serializeVtt(cues) : null
+}
+
+/**
+ * One WebVTT track per subtitle language: the transcript first, then each
+ * translation language in timeline order. Translations of the same language
+ * on several tracks merge into one container track.
+ */
+export function buildEmbeddedSubtitleTracks(
+  composition: CompositionInputProps,
+): EmbeddedSubtitleExportTrack[] {
+  const tracks: EmbeddedSubtitleExportTrack[] = []
+
+  const transcriptVtt = buildTranscriptSubtitleWebVtt(composition)
+  if (transcriptVtt !== null) {
+    const transcriptSource = composition.tracks
+      .flatMap((track) => track.items ?? [])
+      .find(isTranscriptSubtitleItem)?.source
+    const transcriptLanguage =
+      transcriptSource?.type === 'transcript' ? transcriptSource.language : undefined
+    tracks.push({
+      languageCode: toIso639_2LanguageCode(transcriptLanguage),
+      name: 'Transcript',
+      vtt: transcriptVtt,
+      isDefault: true,
+    })
+  }
+
+  const languages = new Set<string>()
+  for (const track of composition.tracks) {
+    if (track.visible === false) continue
+    for (const item of track.items ?? []) {
+      if (item.type === 'subtitle' && item.source.type === 'translation') {
+        languages.add(item.source.language)
+      }
+    }
+  }
+
+  for (const language of languages) {
+    const cues = collectSubtitleCues(
+      composition,
+      (item): item is SubtitleSegmentItem =>
+        item.type === 'subtitle' &&
+        item.source.type === 'translation' &&
+        item.source.language === language,
+    )
+    if (cues.length === 0) continue
+    tracks.push({
+      languageCode: toIso639_2LanguageCode(language),
+      name: getSubtitleLanguage(language)?.name ?? language,
+      vtt: serializeVtt(cues),
+      isDefault: tracks.length === 0,
+    })
+  }
+
+  return tracks
 }
 
 export function omitTranscriptSubtitleItemsForSoftSubtitleExport(
@@ -58,7 +143,7 @@ export function omitTranscriptSubtitleItemsForSoftSubtitleExport(
     tracks: composition.tracks.map(
       (track): TimelineTrack => ({
         ...track,
-        items: (track.items ?? []).filter((item) => !isTranscriptSubtitleItem(item)),
+        items: (track.items ?? []).filter((item) => !isEmbeddableSubtitleItem(item)),
       }),
     ),
   }
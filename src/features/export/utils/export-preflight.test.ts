@@ -1,6 +1,6 @@
 import { describe, expect, it } from 'vite-plus/test'
 import type { ExtendedExportSettings, CompositionInputProps } from '@/types/export'
-import type { TimelineItem, TimelineTrack } from '@/types/timeline'
+import type { SubtitleSegmentItem, TimelineItem, TimelineTrack } from '@/types/timeline'
 import { assessExportPreflight } from './export-preflight'
 
 const baseSettings: ExtendedExportSettings = {
@@ -65,6 +65,20 @@ function videoItem(overrides: Partial<Extract<TimelineItem, { type: 'video' }>>
   } satisfies Extract<TimelineItem, { type: 'video' }>
 }
 
+function subtitleItem(cues: SubtitleSegmentItem['cues']): SubtitleSegmentItem {
+  return {
+    id: 'subtitle-1',
+    trackId: 'track-1',
+    type: 'subtitle',
+    from: 0,
+    durationInFrames: 300,
+    label: 'Captions',
+    source: { type: 'subtitle-import', fileName: 'captions.srt', format: 'srt', importedAt: 0 },
+    cues,
+    color: '#ffffff',
+  }
+}
+
 function composition(items: TimelineItem[] = []): CompositionInputProps {
   return {
     fps: 30,
@@ -200,6 +214,85 @@ describe('assessExportPreflight', () => {
     expect(result.checks.map((check) => check.id)).not.toContain('video-codec-unavailable')
   })
 
+  it('warns when an animated GIF would be too large to share', async () => {
+    const settings: ExtendedExportSettings = {
+      ...baseSettings,
+      mode: 'animated-image',
+      videoContainer: undefined,
+      animatedImageContainer: 'gif',
+      animatedImage: { fps: 15 },
+    }
+    const assess = (resolution: { width: number; height: number }) =>
+      assessExportPreflight({
+        settings: { ...settings, resolution },
+        fps: 30,
+        composition: composition([videoItem()]),
+        durationFrames: 300,
+        supportedVideoCodecs: [],
+        workerAvailable: true,
+        offlineAudioContextAvailable: true,
+      })
+
+    const large = await assess({ width: 1920, height: 1080 })
+    expect(large.canExport).toBe(true)
+    expect(large.resolvedSettings?.container).toBe('gif')
+    expect(large.checks.map((check) => check.id)).not.toContain('video-codec-unavailable')
+    expect(large.checks).toContainEqual(
+      expect.objectContaining({
+        id: 'animated-image-size',
+        severity: 'warning',
+        detailParams: expect.objectContaining({ frames: '150', width: 1920, height: 1080 }),
+      }),
+    )
+
+    const small = await assess({ width: 320, height: 180 })
+    expect(small.checks.map((check) => check.id)).not.toContain('animated-image-size')
+  })
+
+  it('reports loudness and the normalization gain for the selected target', async () => {
+    const assess = (loudnessTarget?: 'streaming', truePeakDbtp = -8) =>
+      assessExportPreflight({
+        settings: { ...baseSettings, mode: 'audio', audioContainer: 'wav', loudnessTarget },
+        fps: 30,
+        composition: composition([audioItem()]),
+        durationFrames: 300,
+        workerAvailable: true,
+        offlineAudioContextAvailable: true,
+        loudness: { integratedLufs: -20, truePeakDbtp },
+      })
+
+    const normalized = await assess('streaming')
+    expect(normalized.resolvedSettings?.loudnessTarget).toEqual({
+      integratedLufs: -14,
+      truePeakDbtp: -1,
+    })
+    expect(normalized.checks).toContainEqual(
+      expect.objectContaining({
+        id: 'loudness-report',
+        detailParams: { integrated: '-20.0 LUFS', truePeak: '-8.0 dBTP' },
+      }),
+    )
+    expect(normalized.checks).toContainEqual(
+      expect.objectContaining({
+        id: 'loudness-normalize',
+        detailParams: expect.objectContaining({ gain: '+6.0 dB' }),
+      }),
+    )
+
+    const limited = await assess('streaming', -3)
+    expect(limited.checks).toContainEqual(
+      expect.objectContaining({
+        id: 'loudness-target-limited',
+        severity: 'warning',
+        detailParams: expect.objectContaining({ output: '-18.0 LUFS' }),
+      }),
+    )
+
+    const hot = await assess(undefined, -0.2)
+    expect(hot.checks.map((check) => check.id)).toContain('loudness-true-peak')
+    expect(hot.resolvedSettings?.loudnessTarget).toBeUndefined()
+  })
+
   it('blocks export when the composition references broken media', async () => {
     const result = await assessExportPreflight({
       settings: baseSettings,
@@ -222,6 +315,24 @@ describe('assessExportPreflight', () => {
     )
   })
 
+  it('notes that HDR exports carry color tags but no mastering metadata', async () => {
+    const result = await assessExportPreflight({
+      settings: { ...baseSettings, codec: 'h265', hdr: true },
+      fps: 30,
+      composition: composition(),
+      durationFrames: 300,
+      supportedVideoCodecs: ['hevc'],
+      workerAvailable: true,
+      offlineAudioContextAvailable: true,
+    })
+
+    expect(result.canExport).toBe(true)
+    expect(result.resolvedSettings?.hdr).toBe(true)
+    expect(result.checks).toContainEqual(
+      expect.objectContaining({ id: 'hdr-color-tags-only', severity: 'info' }),
+    )
+  })
+
   it('warns when the estimated export file size is very large', async () => {
     const result = await assessExportPreflight({
       settings: { ...baseSettings, quality: 'ultra' },
@@ -262,4 +373,128 @@ describe('assessExportPreflight', () => {
       }),
     )
   })
+
+  it('skips caption QC when subtitles are neither embedded nor written as files', async () => {
+    const result = await assessExportPreflight({
+      settings: baseSettings,
+      fps: 30,
+      composition: composition([
+        subtitleItem([{ id: 'a', startSeconds: 0, endSeconds: 0.2, text: 'Too quick to read' }]),
+      ]),
+      durationFrames: 300,
+      supportedVideoCodecs: ['avc'],
+      workerAvailable: true,
+      offlineAudioContextAvailable: true,
+    })
+
+    expect(result.checks.some((check) => check.id.startsWith('caption-'))).toBe(false)
+  })
+
+  it('flags caption reading speed, line length, duration and overlaps', async () => {
+    const result = await assessExportPreflight({
+      settings: { ...baseSettings, sidecarSubtitles: 'srt' },
+      fps: 30,
+      composition: composition([
+        subtitleItem([
+          {
+            id: 'a',
+            startSeconds: 0,
+            endSeconds: 1,
+            text: 'Way too many characters for one second',
+          },
+          { id: 'b', startSeconds: 2, endSeconds: 2.5, text: 'Hi' },
+          { id: 'c', startSeconds: 2.4, endSeconds: 5, text: 'Overlapping' },
+          {
+            id: 'd',
+            startSeconds: 6,
+            endSeconds: 10,
+            text: 'This single line runs well past forty-two characters',
+          },
+        ]),
+      ]),
+      durationFrames: 300,
+      supportedVideoCodecs: ['avc'],
+      workerAvailable: true,
+      offlineAudioContextAvailable: true,
+      rangeStartFrame: 30,
+    })
+
+    expect(result.canExport).toBe(true)
+    expect(result.checks).toContainEqual(
+      expect.objectContaining({
+        id: 'caption-reading-speed',
+        severity: 'warning',
+        detailParams: { count: 1, timecode: '00:00:01:00', limit: 20 },
+      }),
+    )
+    expect(result.checks).toContainEqual(
+      expect.objectContaining({
+        id: 'caption-line-length',
+        detailParams: { count: 1, timecode: '00:00:07:00', limit: 42 },
+      }),
+    )
+    expect(result.checks).toContainEqual(
+      expect.objectContaining({
+        id: 'caption-min-duration',
+        detailParams: { count: 1, timecode: '00:00:03:00', limit: '0.83' },
+      }),
+    )
+    expect(result.checks).toContainEqual(
+      expect.objectContaining({
+        id: 'caption-overlap',
+        detailParams: { count: 1, timecode: '00:00:03:12' },
+      }),
+    )
+  })
+
+  it('flags captions that cross a detected shot change', async () => {
+    const result = await assessExportPreflight({
+      settings: { ...baseSettings, embedSubtitles: true },
+      fps: 30,
+      composition: composition([
+        // Source 2s plays at timeline 1s, so source cuts at 4s and 8s land at 3s and 7s.
+        videoItem({ from: 30, durationInFrames: 270, sourceStart: 60, sourceFps: 30 }),
+        subtitleItem([
+          { id: 'a', startSeconds: 2, endSeconds: 3.5, text: 'Across the cut' },
+          { id: 'b', startSeconds: 3.5, endSeconds: 5, text: 'After the cut' },
+          { id: 'c', startSeconds: 7.05, endSeconds: 9, text: 'Just after a cut' },
+        ]),
+      ]),
+      durationFrames: 300,
+      supportedVideoCodecs: ['avc'],
+      workerAvailable: true,
+      offlineAudioContextAvailable: true,
+      sceneCuts: new Map([['media-video-1', [4, 8]]]),
+    })
+
+    expect(result.checks).toContainEqual(
+      expect.objectContaining({
+        id: 'caption-shot-change',
+        severity: 'warning',
+        detailParams: { count: 1, timecode: '00:00:02:00' },
+      }),
+    )
+  })
+
+  it('reports a clean caption pass', async () => {
+    const result = await assessExportPreflight({
+      settings: { ...baseSettings, sidecarSubtitles: 'vtt' },
+      fps: 30,
+      composition: composition([
+        subtitleItem([
+          { id: 'a', startSeconds: 1, endSeconds: 3, text: 'First caption' },
+          { id: 'b', startSeconds: 3, endSeconds: 5, text: 'Second caption' },
+        ]),
+      ]),
+      durationFrames: 300,
+      supportedVideoCodecs: ['avc'],
+      workerAvailable: true,
+      offlineAudioContextAvailable: true,
+    })
+
+    expect(result.checks).toContainEqual(
+      expect.objectContaining({ id: 'caption-qc-passed', severity: 'ok' }),
+    )
+    expect(result.checks.some((check) => check.id === 'caption-overlap')).toBe(false)
+  })
 })
@@ -1,6 +1,12 @@
 import type { CompositionInputProps, ExtendedExportSettings } from '@/types/export'
 import type { TimelineTrack } from '@/types/timeline'
-import { framesToSeconds } from '@/shared/utils/time-utils'
+import { formatTimecode, framesToSeconds } from '@/shared/utils/time-utils'
+import type { SubtitleCue } from '@/shared/utils/subtitles'
+import {
+  formatLoudness,
+  getLoudnessNormalizationGain,
+  type LoudnessSnapshot,
+} from '@/shared/utils/audio-loudness'
 import { isGifUrl, isWebpUrl } from '@/shared/utils/media-utils'
 import type { ClientCodec, ClientExportSettings, ClientVideoContainer } from './client-renderer'
 import {
@@ -12,7 +18,10 @@ import {
   getDefaultAudioCodec,
   getAudioBitrateForQuality,
   estimateFileSize,
+  DEFAULT_ANIMATED_IMAGE_OPTIONS,
+  LOUDNESS_TARGET_PRESETS,
 } from './client-renderer'
+import { collectSidecarSubtitleTracks } from './sidecar-subtitle-export'
 
 export type ExportPreflightSeverity = 'ok' | 'info' | 'warning' | 'error'
 
@@ -36,6 +45,26 @@ export interface AssessExportPreflightOptions {
   workerAvailable?: boolean
   offlineAudioContextAvailable?: boolean
   brokenMediaIds?: string[]
+  /** Result of analyzing the export mix, when the user has run it. */
+  loudness?: Pick<LoudnessSnapshot, 'integratedLufs' | 'truePeakDbtp'>
+  /** Detected shot changes per media id, in source seconds (from scene detection). */
+  sceneCuts?: ReadonlyMap<string, readonly number[]>
+  /** Timeline frame the export range starts at, so caption QC reports timeline timecodes. */
+  rangeStartFrame?: number
+  captionQcRules?: Partial<CaptionQcRules>
+}
+
+/** Caption delivery limits; the defaults follow common broadcast/streaming style guides. */
+export interface CaptionQcRules {
+  maxCharsPerSecond: number
+  maxLineLength: number
+  minDurationSeconds: number
+}
+
+export const DEFAULT_CAPTION_QC_RULES: CaptionQcRules = {
+  maxCharsPerSecond: 20,
+  maxLineLength: 42,
+  minDurationSeconds: 5 / 6,
 }
 
 export interface ExportPreflightResult {
@@ -92,12 +121,25 @@ function collectTimelineMediaIds(tracks: TimelineTrack[]): Set<string> {
   return mediaIds
 }
 
+/** Lossy encoders overshoot; above -1 dBTP the decoded file can clip. */
+const TRUE_PEAK_WARNING_DBTP = -1
+
+/** Cues may start or end this close to a shot change without crossing it. */
+const SHOT_CHANGE_TOLERANCE_FRAMES = 2
+
+/** Most chat apps and social uploads reject animated GIF/WebP above ~15 MB. */
+const ANIMATED_IMAGE_SIZE_WARNING_BYTES = 15 * 1024 * 1024
+
 function formatEstimatedBytes(bytes: number): string {
   if (bytes < 1024 * 1024) return `${Math.round(bytes / 1024)} KB`
   if (bytes < 1024 * 1024 * 1024) return `${(bytes / (1024 * 1024)).toFixed(1)} MB`
   return `${(bytes / (1024 * 1024 * 1024)).toFixed(2)} GB`
 }
 
+function formatGainDb(db: number): string {
+  return `${db >= 0 ? '+' : ''}${db.toFixed(1)} dB`
+}
+
 function describeCodec(codec: ClientCodec): string {
   switch (codec) {
     case 'avc':
@@ -139,6 +181,13 @@ async function resolveSettingsForPreflight(
   clientSettings.mode = exportMode
   clientSettings.embedSubtitles =
     exportMode === 'video' ? (settings.embedSubtitles ?? false) : false
+  if (settings.sidecarSubtitles && exportMode === 'video') {
+    clientSettings.sidecarSubtitles = settings.sidecarSubtitles
+  }
+
+  if (settings.loudnessTarget && (exportMode === 'video' || exportMode === 'audio')) {
+    clientSettings.loudnessTarget = LOUDNESS_TARGET_PRESETS[settings.loudnessTarget]
+  }
 
   if (exportMode === 'audio') {
     resolveAudioSettings(clientSettings, settings)
@@ -146,6 +195,24 @@ async function resolveSettingsForPreflight(
     return validation.valid ? { clientSettings } : { error: validation.error }
   }
 
+  if (settings.alpha) clientSettings.alpha = true
+  if (settings.hdr && exportMode === 'video') clientSettings.hdr = true
+
+  // PNG frames need no encoder probe.
+  if (exportMode === 'image-sequence') {
+    const validation = validateSettings(clientSettings)
+    return validation.valid ? { clientSettings } : { error: validation.error }
+  }
+
+  // GIF/WebP frames are encoded on the canvas, so no probe either.
+  if (exportMode === 'animated-image') {
+    clientSettings.container = settings.animatedImageContainer ?? 'gif'
+    clientSettings.animatedImage = { ...DEFAULT_ANIMATED_IMAGE_OPTIONS, ...settings.animatedImage }
+    if (clientSettings.container === 'gif') delete clientSettings.alpha
+    const validation = validateSettings(clientSettings)
+    return validation.valid ? { clientSettings } : { error: validation.error }
+  }
+
   if (settings.videoContainer) {
     clientSettings.container = settings.videoContainer
   }
@@ -210,6 +277,10 @@ export async function assessExportPreflight({
   workerAvailable = typeof Worker !== 'undefined',
   offlineAudioContextAvailable = typeof OfflineAudioContext !== 'undefined',
   brokenMediaIds = [],
+  loudness,
+  sceneCuts,
+  rangeStartFrame = 0,
+  captionQcRules,
 }: AssessExportPreflightOptions): Promise<ExportPreflightResult> {
   const checks: ExportPreflightCheck[] = []
   const estimatedDurationSeconds = framesToSeconds(durationFrames, fps)
@@ -285,6 +356,11 @@ export async function assessExportPreflight({
         codec: resolved.clientSettings.audioCodec,
       },
     })
+  } else if (
+    resolved.clientSettings.mode === 'image-sequence' ||
+    resolved.clientSettings.mode === 'animated-image'
+  ) {
+    // No encoder involved — nothing to report about codecs.
   } else if (resolved.codecFallback) {
     checks.push({
       id: 'video-codec-fallback',
@@ -310,6 +386,18 @@ export async function assessExportPreflight({
     })
   }
 
+  if (resolved.clientSettings.hdr) {
+    // WebCodecs only carries the VUI color description; there is no way to
+    // attach ST 2086 mastering display or MaxCLL/MaxFALL to the bitstream.
+    checks.push({
+      id: 'hdr-color-tags-only',
+      severity: 'info',
+      titleKey: 'export.preflight.checks.hdr-color-tags-only.title',
+      detailKey: 'export.preflight.checks.hdr-color-tags-only.detail',
+      fixKey: 'export.preflight.checks.hdr-color-tags-only.fix',
+    })
+  }
+
   let predictedRenderPath: ExportPreflightResult['predictedRenderPath'] = 'worker'
 
   if (!workerAvailable) {
@@ -321,7 +409,7 @@ export async function assessExportPreflight({
       detailKey: 'export.preflight.checks.worker-unavailable-fallback.detail',
       fixKey: 'export.preflight.checks.worker-unavailable-fallback.fix',
     })
-  } else if (resolved.clientSettings.mode === 'video' && hasAnimatedImage(tracks)) {
+  } else if (resolved.clientSettings.mode !== 'audio' && hasAnimatedImage(tracks)) {
     predictedRenderPath = 'main-thread'
     checks.push({
       id: 'worker-animated-image-fallback',
@@ -363,6 +451,39 @@ export async function assessExportPreflight({
     })
   }
 
+  if (
+    resolved.clientSettings.mode === 'animated-image' &&
+    estimatedFileSizeBytes >= ANIMATED_IMAGE_SIZE_WARNING_BYTES
+  ) {
+    const animation = resolved.clientSettings.animatedImage ?? DEFAULT_ANIMATED_IMAGE_OPTIONS
+    const frames = Math.max(
+      1,
+      Math.floor((durationFrames * Math.min(animation.fps, fps)) / Math.max(1, fps)),
+    )
+    checks.push({
+      id: 'animated-image-size',
+      severity: 'warning',
+      titleKey: 'export.preflight.checks.animated-image-size.title',
+      detailKey: 'export.preflight.checks.animated-image-size.detail',
+      detailParams: {
+        size: formatEstimatedBytes(estimatedFileSizeBytes),
+        frames: frames.toLocaleString(),
+        width: resolved.clientSettings.resolution.width,
+        height: resolved.clientSettings.resolution.height,
+      },
+      fixKey: 'export.preflight.checks.animated-image-size.fix',
+    })
+  }
+
+  checks.push(...assessLoudness(resolved.clientSettings, loudness))
+  checks.push(
+    ...assessCaptionQc(resolved.clientSettings, composition, {
+      sceneCuts,
+      rangeStartFrame,
+      rules: { ...DEFAULT_CAPTION_QC_RULES, ...captionQcRules },
+    }),
+  )
+
   if (estimatedDurationSeconds >= 30 * 60) {
     checks.push({
       id: 'long-export-risk',
@@ -384,6 +505,225 @@ export async function assessExportPreflight({
   }
 }
 
+function assessLoudness(
+  clientSettings: ClientExportSettings,
+  loudness: AssessExportPreflightOptions['loudness'],
+): ExportPreflightCheck[] {
+  if (clientSettings.mode !== 'video' && clientSettings.mode !== 'audio') return []
+  const checks: ExportPreflightCheck[] = []
+  const target = clientSettings.loudnessTarget
+
+  if (loudness) {
+    checks.push({
+      id: 'loudness-report',
+      severity: 'info',
+      titleKey: 'export.preflight.checks.loudness-report.title',
+      detailKey: 'export.preflight.checks.loudness-report.detail',
+      detailParams: {
+        integrated: formatLoudness(loudness.integratedLufs),
+        truePeak: formatLoudness(loudness.truePeakDbtp, 'dBTP'),
+      },
+    })
+  }
+
+  if (target) {
+    const targetParams = {
+      target: formatLoudness(target.integratedLufs),
+      ceiling: formatLoudness(target.truePeakDbtp, 'dBTP'),
+    }
+    const normalization = loudness ? getLoudnessNormalizationGain(loudness, target) : null
+
+    if (normalization?.limitedByTruePeak) {
+      checks.push({
+        id: 'loudness-target-limited',
+        severity: 'warning',
+        titleKey: 'export.preflight.checks.loudness-target-limited.title',
+        detailKey: 'export.preflight.checks.loudness-target-limited.detail',
+        detailParams: {
+          ...targetParams,
+          output: formatLoudness(loudness!.integratedLufs + normalization.gainDb),
+        },
+        fixKey: 'export.preflight.checks.loudness-target-limited.fix',
+      })
+    } else {
+      checks.push({
+        id: 'loudness-normalize',
+        severity: 'info',
+        titleKey: 'export.preflight.checks.loudness-normalize.title',
+        detailKey: normalization
+          ? 'export.preflight.checks.loudness-normalize.detail'
+          : 'export.preflight.checks.loudness-normalize.detailUnmeasured',
+        detailParams: {
+          ...targetParams,
+          gain: normalization ? formatGainDb(normalization.gainDb) : undefined,
+        },
+      })
+    }
+  } else if (loudness && loudness.truePeakDbtp > TRUE_PEAK_WARNING_DBTP) {
+    checks.push({
+      id: 'loudness-true-peak',
+      severity: 'warning',
+      titleKey: 'export.preflight.checks.loudness-true-peak.title',
+      detailKey: 'export.preflight.checks.loudness-true-peak.detail',
+      detailParams: { truePeak: formatLoudness(loudness.truePeakDbtp, 'dBTP') },
+      fixKey: 'export.preflight.checks.loudness-true-peak.fix',
+    })
+  }
+
+  return checks
+}
+
+function getCaptionLines(text: string): string[] {
+  return text
+    .replace(/<[^>]*>|\{[^}]*\}/g, '')
+    .split('\n')
+    .map((line) => line.trim())
+    .filter(Boolean)
+}
+
+/** Shot changes in export time, from every visible video item with detected scenes. */
+function collectShotChanges(
+  composition: CompositionInputProps,
+  sceneCuts: ReadonlyMap<string, readonly number[]>,
+): number[] {
+  const fps = composition.fps
+  const shotChanges: number[] = []
+
+  for (const track of composition.tracks) {
+    if (track.visible === false) continue
+    for (const item of track.items ?? []) {
+      // Reversed clips play the source backwards; their cuts aren't worth mapping.
+      if (item.type !== 'video' || !item.mediaId || item.isReversed) continue
+      const cuts = sceneCuts.get(item.mediaId)
+      if (!cuts?.length) continue
+
+      const speed = item.speed ?? 1
+      const sourceStartSeconds = (item.sourceStart ?? 0) / (item.sourceFps ?? fps)
+      const itemStart = item.from / fps
+      const itemEnd = (item.from + item.durationInFrames) / fps
+      for (const cut of cuts) {
+        const time = itemStart + (cut - sourceStartSeconds) / speed
+        if (time > itemStart && time < itemEnd) shotChanges.push(time)
+      }
+    }
+  }
+
+  return shotChanges.sort((a, b) => a - b)
+}
+
+interface CaptionQcViolations {
+  count: number
+  firstSeconds: number
+}
+
+function assessCaptionQc(
+  clientSettings: ClientExportSettings,
+  composition: CompositionInputProps,
+  {
+    sceneCuts,
+    rangeStartFrame,
+    rules,
+  }: {
+    sceneCuts?: ReadonlyMap<string, readonly number[]>
+    rangeStartFrame: number
+    rules: CaptionQcRules
+  },
+): ExportPreflightCheck[] {
+  if (clientSettings.mode !== 'video') return []
+  if (!clientSettings.embedSubtitles && !clientSettings.sidecarSubtitles) return []
+
+  const subtitleTracks = collectSidecarSubtitleTracks(composition)
+  if (subtitleTracks.length === 0) return []
+
+  const fps = composition.fps
+  const shotChanges = sceneCuts ? collectShotChanges(composition, sceneCuts) : []
+  const tolerance = SHOT_CHANGE_TOLERANCE_FRAMES / fps
+  const violations = {
+    readingSpeed: { count: 0, firstSeconds: Infinity },
+    lineLength: { count: 0, firstSeconds: Infinity },
+    minDuration: { count: 0, firstSeconds: Infinity },
+    overlap: { count: 0, firstSeconds: Infinity },
+    shotChange: { count: 0, firstSeconds: Infinity },
+  } satisfies Record<string, CaptionQcViolations>
+  const flag = (violation: CaptionQcViolations, cue: SubtitleCue) => {
+    violation.count += 1
+    violation.firstSeconds = Math.min(violation.firstSeconds, cue.startSeconds)
+  }
+
+  let cueCount = 0
+  for (const track of subtitleTracks) {
+    let previousEnd = -Infinity
+    for (const cue of track.cues) {
+      cueCount += 1
+      const duration = cue.endSeconds - cue.startSeconds
+      const lines = getCaptionLines(cue.text)
+      const characters = lines.reduce((total, line) => total + line.length, 0)
+
+      if (characters / duration > rules.maxCharsPerSecond) flag(violations.readingSpeed, cue)
+      if (lines.some((line) => line.length > rules.maxLineLength)) {
+        flag(violations.lineLength, cue)
+      }
+      if (duration < rules.minDurationSeconds - 1e-6) flag(violations.minDuration, cue)
+      if (cue.startSeconds < previousEnd - 1e-6) flag(violations.overlap, cue)
+      if (
+        shotChanges.some(
+          (time) => time > cue.startSeconds + tolerance && time < cue.endSeconds - tolerance,
+        )
+      ) {
+        flag(violations.shotChange, cue)
+      }
+      previousEnd = Math.max(previousEnd, cue.endSeconds)
+    }
+  }
+
+  const toTimecode = (seconds: number) =>
+    formatTimecode(rangeStartFrame + Math.round(seconds * fps), fps)
+  const checks: ExportPreflightCheck[] = []
+  const pushViolation = (
+    id: string,
+    violation: CaptionQcViolations,
+    params: Record<string, unknown> = {},
+  ) => {
+    if (violation.count === 0) return
+    checks.push({
+      id,
+      severity: 'warning',
+      titleKey: `export.preflight.checks.${id}.title`,
+      detailKey: `export.preflight.checks.${id}.detail`,
+      detailParams: {
+        count: violation.count,
+        timecode: toTimecode(violation.firstSeconds),
+        ...params,
+      },
+      fixKey: `export.preflight.checks.${id}.fix`,
+    })
+  }
+
+  pushViolation('caption-reading-speed', violations.readingSpeed, {
+    limit: rules.maxCharsPerSecond,
+  })
+  pushViolation('caption-line-length', violations.lineLength, { limit: rules.maxLineLength })
+  pushViolation('caption-min-duration', violations.minDuration, {
+    limit: rules.minDurationSeconds.toFixed(2),
+  })
+  pushViolation('caption-overlap', violations.overlap)
+  pushViolation('caption-shot-change', violations.shotChange)
+
+  if (checks.length === 0) {
+    checks.push({
+      id: 'caption-qc-passed',
+      severity: 'ok',
+      titleKey: 'export.preflight.checks.caption-qc-passed.title',
+      detailKey: shotChanges.length
+        ? 'export.preflight.checks.caption-qc-passed.detail'
+        : 'export.preflight.checks.caption-qc-passed.detailNoShotChanges',
+      detailParams: { count: cueCount },
+    })
+  }
+
+  return checks
+}
+
 export function summarizePreflightSeverity(
   checks: ExportPreflightCheck[],
 ): ExportPreflightSeverity {
@@ -1,4 +1,5 @@
 import type { TimelineItem } from '@/types/timeline'
+import { isHdrColorSpace } from '@/types/color'
 import { createLogger } from '@/shared/logging/logger'
 import { getCompositeOperation } from '@/types/blend-mode-css'
 import { doesMaskAffectTrack } from '@/shared/utils/mask-scope'
@@ -10,6 +11,7 @@ import type { CanvasPool } from './canvas-pool'
 import type { CanvasSettings, ItemRenderContext } from './canvas-item-renderer'
 import type { GpuPipelineManager } from './gpu-pipeline-manager'
 import type { RenderedTaskResult } from './frame-mask-helpers'
+import type { HdrVideoDraw } from './canvas-item-renderer/types'
 
 function getLog() {
   return createLogger('ClientRenderEngine')
@@ -52,6 +54,40 @@ export interface FrameCompositingDeps {
   ) => Promise<RenderedTaskResult | null>
 }
 
+/**
+ * Upload a submitted HDR video frame into a pooled half-float layer at full
+ * canvas size, already in the working colour space. Null when the frame's
+ * format can't be uploaded (the SDR canvas layer is used instead).
+ */
+async function uploadHdrVideoLayer(
+  draw: HdrVideoDraw,
+  gpu: GpuPipelineManager,
+  width: number,
+  height: number,
+): Promise<GPUTexture | null> {
+  if (!gpu.compositor || !gpu.texturePool || !gpu.ensureHdrUploader()) return null
+  const uploader = gpu.hdrUploader!
+  const uploaded = await uploader.upload(draw.frame, gpu.compositor.getWorkingColorSpace())
+  if (!uploaded) return null
+
+  const device = gpu.effects!.getDevice()
+  const texture = gpu.texturePool.acquire(width, height, 'rgba16float')
+  const encoder = device.createCommandEncoder()
+  uploader.draw({
+    source: uploaded,
+    encoder,
+    target: texture.createView(),
+    targetWidth: width,
+    targetHeight: height,
+    rect: draw.rect,
+    clip: draw.clip,
+    opacity: draw.opacity,
+    clear: true,
+  })
+  device.queue.submit([encoder.finish()])
+  return texture
+}
+
 /**
  * Composites all per-task render results in z-order and returns the canvas to
  * blit to the output. Uses the WebGPU blend-mode compositor when available
@@ -95,6 +131,9 @@ export async function compositeFrameResults(deps: FrameCompositingDeps): Promise
     const layers: CompositeLayer[] = []
     const layerTextures: GPUTexture[] = []
     const layerMaskTextures: GPUTexture[] = []
+    const workingColorSpace = gpu.compositor.getWorkingColorSpace()
+    const hdrDraws = itemRenderContext.hdrVideo?.draws
+    gpu.hdrUploader?.beginFrame()
     const compositedResults: Array<{
       task: (typeof renderTasks)[number]
       result: RenderedTaskResult
@@ -134,8 +173,17 @@ export async function compositeFrameResults(deps: FrameCompositingDeps): Promise
           ? (getEffectiveBlendMode(getCurrentItem(task.item)) ?? 'normal')
           : 'normal'
 
+      // HDR video bypasses the 8-bit item canvas, unless its masks had to be
+      // baked into that canvas.
+      const hdrDraw = task.type === 'item' ? hdrDraws?.get(task.item.id) : undefined
+      const hdrTexture =
+        hdrDraw && fallbackMasks.length === applicableMasks.length
+          ? await uploadHdrVideoLayer(hdrDraw, gpu, w, h)
+          : null
+
       // Upload item canvas to GPU texture (pooled — no per-frame alloc)
-      let tex = result.gpuTexture
+      let tex = hdrTexture ?? result.gpuTexture
+      if (hdrTexture && result.gpuTexture) gpu.texturePool!.release(result.gpuTexture)
       if (!tex) {
         if (!result.source) continue
         tex = gpu.texturePool!.acquire(w, h)
@@ -167,11 +215,13 @@ export async function compositeFrameResults(deps: FrameCompositingDeps): Promise
         },
         textureView: tex.createView(),
         maskView: maskInfo?.view ?? gpu.maskManager.getFallbackView(),
+        colorSpace: hdrTexture ? workingColorSpace : 'rec709',
       })
     }
 
     let compositedToGpuCanvas = false
-    if (layers.length > 0) {
+    // HDR frames always composite (an empty frame clears) so readback stays current.
+    if (layers.length > 0 || isHdrColorSpace(workingColorSpace)) {
       try {
         compositedToGpuCanvas = gpu.compositor.compositeToCanvas(
           layers,
@@ -286,5 +336,11 @@ export async function compositeFrameResults(deps: FrameCompositingDeps): Promise
     }
   }
 
+  const hdrDraws = itemRenderContext.hdrVideo?.draws
+  if (hdrDraws) {
+    for (const draw of hdrDraws.values()) draw.frame.close()
+    hdrDraws.clear()
+  }
+
   return finalCompositeSource
 }
@@ -0,0 +1,250 @@
+import type { VideoItem } from '@/types/timeline'
+import {
+  ANALYSIS_HEIGHT,
+  ANALYSIS_WIDTH,
+  blendFrames,
+  createFramePairFlowEstimator,
+  getFrameInterpolationSample,
+  interpolateFramesWithFlow,
+  type FlowField,
+  type FramePairFlowEstimator,
+} from '@/infrastructure/analysis/frame-interpolation'
+import { getOpticalFlow, saveOpticalFlow } from '@/infrastructure/storage/workspace-fs/optical-flow'
+import { getWorkspaceRoot } from '@/infrastructure/storage/workspace-fs/root'
+import { createLogger } from '@/shared/logging/logger'
+import type { VideoFrameSource } from './shared-video-extractor'
+
+const log = createLogger('FrameInterpolation')
+
+/** Decoded neighbours kept per item; walking forward only needs the last pair. */
+const MAX_DECODED_FRAMES_PER_ITEM = 3
+const MAX_CACHED_FLOWS = 120
+/** Interpolation is a per-pixel CPU pass, so preview works on a smaller copy. */
+export const PREVIEW_INTERPOLATION_MAX_PIXELS = 1280 * 720
+
+interface DecodedFrame {
+  full: ImageData
+  analysis: ImageData
+}
+
+interface ItemSurface {
+  canvas: OffscreenCanvas
+  ctx: OffscreenCanvasRenderingContext2D
+}
+
+export interface FrameInterpolationRequest {
+  item: VideoItem
+  extractor: VideoFrameSource
+  /** Source time the nearest-frame path would show */
+  sourceTime: number
+  sourceFps: number
+}
+
+export interface InterpolatedFrame {
+  canvas: OffscreenCanvas
+  /** Decoded source size (the canvas may be a scaled-down copy) */
+  sourceWidth: number
+  sourceHeight: number
+}
+
+/** Persistent flow storage; defaults to the workspace media cache. */
+export interface OpticalFlowStore {
+  load(mediaId: string, sourceFps: number, frameIndex: number): Promise<FlowField | undefined>
+  save(mediaId: string, sourceFps: number, frameIndex: number, flow: FlowField): Promise<void>
+}
+
+const workspaceFlowStore: OpticalFlowStore = {
+  load: getOpticalFlow,
+  save: saveOpticalFlow,
+}
+
+interface FrameInterpolationCacheOptions {
+  /** Cap on interpolated pixels per frame (export renders at source size) */
+  maxPixels?: number
+  /** Defaults to the workspace cache when a workspace is open on this thread */
+  flowStore?: OpticalFlowStore | null
+}
+
+/**
+ * Synthesizes in-between frames for clips with `frameBlending` set.
+ *
+ * Neighbouring source frames are decoded in ascending order and kept for the
+ * next timeline frame (slow motion revisits the same pair several times).
+ * Optical-flow fields are memoized here and in the workspace media cache, so
+ * re-exports and other clips of the same media skip the flow pass.
+ */
+export class FrameInterpolationCache {
+  private readonly decodedByItem = new Map<string, Map<number, DecodedFrame>>()
+  private readonly flows = new Map<string, FlowField>()
+  private readonly surfaces = new Map<string, ItemSurface>()
+  private estimator: Promise<FramePairFlowEstimator> | null = null
+  private readonly maxPixels: number
+  private readonly flowStore: OpticalFlowStore | null
+
+  constructor(options: FrameInterpolationCacheOptions = {}) {
+    this.maxPixels = options.maxPixels ?? Infinity
+    // The export worker has no workspace handle; it recomputes flow instead
+    const defaultStore = getWorkspaceRoot() ? workspaceFlowStore : null
+    this.flowStore = options.flowStore === undefined ? defaultStore : options.flowStore
+  }
+
+  /**
+   * Interpolated frame for the request, or null when the clip holds frames,
+   * the time lands on a real frame, or decoding fails (callers fall back to
+   * the nearest frame).
+   */
+  async getFrame(request: FrameInterpolationRequest): Promise<InterpolatedFrame | null> {
+    const mode = request.item.frameBlending ?? 'nearest'
+    if (mode === 'nearest' || typeof OffscreenCanvas === 'undefined') return null
+
+    const sample = getFrameInterpolationSample(
+      request.sourceTime,
+      request.sourceFps,
+      request.item.sourceDuration,
+    )
+    if (!sample) return null
+
+    const frameA = await this.getDecodedFrame(request, sample.frameA)
+    const frameB = frameA ? await this.getDecodedFrame(request, sample.frameB) : null
+    if (!frameA || !frameB) return null
+
+    let pixels: Uint8ClampedArray<ArrayBuffer>
+    if (mode === 'optical-flow') {
+      const flow = await this.getFlow(request, sample.frameA, frameA, frameB)
+      pixels = flow
+        ? interpolateFramesWithFlow(frameA.full, frameB.full, flow, sample.t)
+        : blendFrames(frameA.full, frameB.full, sample.t)
+    } else {
+      pixels = blendFrames(frameA.full, frameB.full, sample.t)
+    }
+
+    const { width, height } = frameA.full
+    const surface = this.getSurface(request.item.id, width, height)
+    surface.ctx.putImageData(new ImageData(pixels, width, height), 0, 0)
+    const dims = request.extractor.getDimensions()
+    return { canvas: surface.canvas, sourceWidth: dims.width, sourceHeight: dims.height }
+  }
+
+  dispose(): void {
+    this.decodedByItem.clear()
+    this.flows.clear()
+    this.surfaces.clear()
+    const estimator = this.estimator
+    this.estimator = null
+    void estimator?.then((instance) => instance.destroy()).catch(() => undefined)
+  }
+
+  private getWorkingSize(request: FrameInterpolationRequest): { width: number; height: number } {
+    const dims = request.extractor.getDimensions()
+    const pixels = dims.width * dims.height
+    if (pixels <= this.maxPixels) return dims
+    const scale = Math.sqrt(this.maxPixels / pixels)
+    return {
+      width: Math.max(1, Math.round(dims.width * scale)),
+      height: Math.max(1, Math.round(dims.height * scale)),
+    }
+  }
+
+  private async getDecodedFrame(
+    request: FrameInterpolationRequest,
+    frameIndex: number,
+  ): Promise<DecodedFrame | null> {
+    let frames = this.decodedByItem.get(request.item.id)
+    const cached = frames?.get(frameIndex)
+    if (cached) return cached
+
+    const captured = await request.extractor.captureFrame((frameIndex + 1e-4) / request.sourceFps)
+    if (!captured.success || !captured.frame) return null
+
+    try {
+      const { width, height } = this.getWorkingSize(request)
+      const fullCanvas = new OffscreenCanvas(width, height)
+      const fullCtx = fullCanvas.getContext('2d', { willReadFrequently: true })
+      const analysisCanvas = new OffscreenCanvas(ANALYSIS_WIDTH, ANALYSIS_HEIGHT)
+      const analysisCtx = analysisCanvas.getContext('2d', { willReadFrequently: true })
+      if (!fullCtx || !analysisCtx) return null
+
+      fullCtx.drawImage(captured.frame, 0, 0, width, height)
+      analysisCtx.drawImage(captured.frame, 0, 0, ANALYSIS_WIDTH, ANALYSIS_HEIGHT)
+      const decoded: DecodedFrame = {
+        full: fullCtx.getImageData(0, 0, width, height),
+        analysis: analysisCtx.getImageData(0, 0, ANALYSIS_WIDTH, ANALYSIS_HEIGHT),
+      }
+
+      if (!frames) {
+        frames = new Map()
+        this.decodedByItem.set(request.item.id, frames)
+      }
+      frames.set(frameIndex, decoded)
+      // Drop the lowest indices first: playback walks forward through the source
+      while (frames.size > MAX_DECODED_FRAMES_PER_ITEM) {
+        frames.delete(Math.min(...frames.keys()))
+      }
+      return decoded
+    } finally {
+      captured.frame.close()
+    }
+  }
+
+  private async getFlow(
+    request: FrameInterpolationRequest,
+    frameIndex: number,
+    frameA: DecodedFrame,
+    frameB: DecodedFrame,
+  ): Promise<FlowField | null> {
+    const { mediaId, src } = request.item
+    const sourceKey = mediaId ?? src
+    const key = `${sourceKey}:${request.sourceFps}:${frameIndex}`
+    const memoized = this.flows.get(key)
+    if (memoized) return memoized
+
+    try {
+      if (mediaId && this.flowStore) {
+        const stored = await this.flowStore.load(mediaId, request.sourceFps, frameIndex)
+        if (stored) {
+          this.rememberFlow(key, stored)
+          return stored
+        }
+      }
+
+      this.estimator ??= createFramePairFlowEstimator()
+      const estimator = await this.estimator
+      const frameKey = `${sourceKey}:${request.sourceFps}`
+      const flow = await estimator.computeFlow(
+        { key: `${frameKey}:${frameIndex}`, frame: frameA.analysis },
+        { key: `${frameKey}:${frameIndex + 1}`, frame: frameB.analysis },
+      )
+      if (!flow) return null
+
+      this.rememberFlow(key, flow)
+      if (mediaId && this.flowStore) {
+        void this.flowStore
+          .save(mediaId, request.sourceFps, frameIndex, flow)
+          .catch((error) => log.debug('Optical flow not cached', error))
+      }
+      return flow
+    } catch (error) {
+      log.warn('Optical flow failed; blending frames instead', error)
+      return null
+    }
+  }
+
+  private rememberFlow(key: string, flow: FlowField): void {
+    this.flows.set(key, flow)
+    if (this.flows.size > MAX_CACHED_FLOWS) {
+      const oldest = this.flows.keys().next().value
+      if (oldest !== undefined) this.flows.delete(oldest)
+    }
+  }
+
+  private getSurface(itemId: string, width: number, height: number): ItemSurface {
+    const existing = this.surfaces.get(itemId)
+    if (existing && existing.canvas.width === width && existing.canvas.height === height) {
+      return existing
+    }
+    const canvas = new OffscreenCanvas(width, height)
+    const surface = { canvas, ctx: canvas.getContext('2d')! }
+    this.surfaces.set(itemId, surface)
+    return surface
+  }
+}
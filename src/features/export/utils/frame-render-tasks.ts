@@ -4,12 +4,14 @@ import type { ItemEffect } from '@/types/effects'
 import type { ResolvedTransform } from '@/types/transform'
 import { createLogger } from '@/shared/logging/logger'
 import { doesMaskAffectTrack } from '@/shared/utils/mask-scope'
-import { getAnimatedTransform } from './canvas-keyframes'
+import { getAnimatedCornerPin, getAnimatedTransform } from './canvas-keyframes'
 import { resolveAnimatedColorEffects } from '@/features/export/deps/keyframes'
 import {
   combineEffects,
   getAdjustmentLayerEffects,
   renderEffectsFromMaskedSource,
+  resolveRemoveBackgroundEffects,
+  resolveStabilizationEffects,
   type AdjustmentLayerWithTrackOrder,
 } from './canvas-effects'
 import { hasCornerPin } from '@/features/export/deps/composition-runtime'
@@ -129,8 +131,10 @@ export async function renderItemWithEffects(
     }
   }
 
-  // Apply corner pin preview override during interactive drag
-  let effectiveItem = item
+  // Resolve keyframed corner offsets, then the preview override during interactive drag
+  const animatedCornerPin = getAnimatedCornerPin(item, itemKeyframes, frame)
+  let effectiveItem =
+    animatedCornerPin === item.cornerPin ? item : { ...item, cornerPin: animatedCornerPin }
   if (renderMode === 'preview') {
     const cornerPinOverride = getPreviewCornerPinOverride?.(item.id)
     if (cornerPinOverride !== undefined) {
@@ -142,10 +146,25 @@ export async function renderItemWithEffects(
   const baseItemEffects =
     (renderMode === 'preview' ? getPreviewEffectsOverride?.(item.id) : undefined) ??
     effectiveItem.effects
-  const itemEffects = resolveAnimatedColorEffects(
-    baseItemEffects,
-    getCurrentKeyframes(effectiveItem.id),
-    frame - effectiveItem.from,
+  const itemEffects = await resolveRemoveBackgroundEffects(
+    resolveStabilizationEffects(
+      resolveAnimatedColorEffects(
+        baseItemEffects,
+        getCurrentKeyframes(effectiveItem.id),
+        frame - effectiveItem.from,
+      ),
+      effectiveItem,
+      itemKeyframes,
+      frame,
+      transform,
+      canvasSettings,
+    ),
+    effectiveItem,
+    itemKeyframes,
+    frame,
+    transform,
+    canvasSettings,
+    itemRenderContext.segmentationMatteCache,
   )
   const adjEffects = getAdjustmentLayerEffects(
     trackOrder,
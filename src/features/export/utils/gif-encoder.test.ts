@@ -0,0 +1,151 @@
+import { describe, expect, it } from 'vite-plus/test'
+import {
+  addToHistogram,
+  buildPalette,
+  createColorHistogram,
+  createGifWriter,
+  indexFrame,
+} from './gif-encoder'
+
+function readSubBlocks(gif: Uint8Array, offset: number): { data: number[]; next: number } {
+  const data: number[] = []
+  while (gif[offset] !== 0) {
+    const length = gif[offset]!
+    data.push(...gif.subarray(offset + 1, offset + 1 + length))
+    offset += length + 1
+  }
+  return { data, next: offset + 1 }
+}
+
+function lzwDecode(data: number[], minCodeSize: number): number[] {
+  const clearCode = 1 << minCodeSize
+  const endCode = clearCode + 1
+  let codeSize = minCodeSize + 1
+  let table: number[][] = []
+  const reset = () => {
+    table = Array.from({ length: clearCode + 2 }, (_, i) => [i])
+    codeSize = minCodeSize + 1
+  }
+  reset()
+
+  const out: number[] = []
+  let bit = 0
+  let previous: number[] | null = null
+  for (;;) {
+    let code = 0
+    for (let i = 0; i < codeSize; i++, bit++) {
+      code |= ((data[bit >> 3]! >> (bit & 7)) & 1) << i
+    }
+    if (code === clearCode) {
+      reset()
+      previous = null
+      continue
+    }
+    if (code === endCode) break
+    const entry = code < table.length ? table[code]! : [...previous!, previous![0]!]
+    out.push(...entry)
+    if (previous && table.length < 4096) table.push([...previous, entry[0]!])
+    previous = entry
+    if (table.length === 1 << codeSize && codeSize < 12) codeSize++
+  }
+  return out
+}
+
+/** Minimal GIF reader: loop count plus each frame's delay and decoded indices. */
+function decodeGif(gif: Uint8Array) {
+  let offset = 13
+  if (gif[10]! & 0x80) offset += 3 * (1 << ((gif[10]! & 7) + 1))
+  const frames: Array<{ delay: number; indices: number[] }> = []
+  let loopCount: number | null = null
+  let delay = 0
+  while (gif[offset] !== 0x3b) {
+    if (gif[offset] === 0x21 && gif[offset + 1] === 0xff) {
+      loopCount = gif[offset + 16]! | (gif[offset + 17]! << 8)
+      offset = readSubBlocks(gif, offset + 2).next
+    } else if (gif[offset] === 0x21 && gif[offset + 1] === 0xf9) {
+      delay = gif[offset + 4]! | (gif[offset + 5]! << 8)
+      offset += 8
+    } else if (gif[offset] === 0x2c) {
+      const packed = gif[offset + 9]!
+      offset += 10
+      if (packed & 0x80) offset += 3 * (1 << ((packed & 7) + 1))
+      const { data, next } = readSubBlocks(gif, offset + 1)
+      frames.push({ delay, indices: lzwDecode(data, gif[offset]!) })
+      offset = next
+    } else {
+      throw new Error(`Unexpected GIF block 0x${gif[offset]!.toString(16)}`)
+    }
+  }
+  return { loopCount, frames }
+}
+
+describe('gif encoder', () => {
+  it('builds an exact palette when the frame has few colours', () => {
+    const rgba = new Uint8ClampedArray([
+      255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 255, 0, 0, 255,
+    ])
+    const histogram = createColorHistogram()
+    addToHistogram(histogram, rgba)
+    const palette = buildPalette(histogram)
+
+    expect(palette.length).toBe(9)
+    const colors = [0, 1, 2].map((i) => [...palette.subarray(i * 3, i * 3 + 3)].join(','))
+    expect(colors.toSorted()).toEqual(['0,0,255', '0,255,0', '255,0,0'])
+
+    for (const dither of ['none', 'ordered', 'floyd-steinberg'] as const) {
+      const indices = indexFrame(rgba, 4, 1, palette, dither)
+      const mapped = [...indices].map((i) => [...palette.subarray(i * 3, i * 3 + 3)].join(','))
+      expect(mapped).toEqual(['255,0,0', '0,255,0', '0,0,255', '255,0,0'])
+    }
+  })
+
+  it('caps the palette at the requested colour count', () => {
+    const rgba = new Uint8ClampedArray(64 * 64 * 4)
+    for (let p = 0; p < 64 * 64; p++) {
+      rgba[p * 4] = (p * 4) & 0xff
+      rgba[p * 4 + 1] = (p >> 4) & 0xff
+      rgba[p * 4 + 2] = (p * 7) & 0xff
+      rgba[p * 4 + 3] = 255
+    }
+    const histogram = createColorHistogram()
+    addToHistogram(histogram, rgba)
+
+    expect(buildPalette(histogram, 16).length).toBe(16 * 3)
+  })
+
+  it('round-trips LZW image data through table resets', () => {
+    // 128x128 noise over 256 colours overflows the 4096-entry code table.
+    const width = 128
+    const height = 128
+    let seed = 7
+    const random = () => (seed = (seed * 1103515245 + 12345) & 0x7fffffff)
+    const palette = Uint8Array.from({ length: 256 * 3 }, () => random() & 0xff)
+    const noise = Uint8Array.from({ length: width * height }, () => random() & 0xff)
+    const bands = Uint8Array.from({ length: width * height }, (_, i) => (i >> 5) % 3)
+
+    const writer = createGifWriter({ width, height, loopCount: 0, globalPalette: palette })
+    writer.addFrame(noise, 7)
+    writer.addFrame(bands, 3, palette.subarray(0, 9))
+    const gif = writer.finish()
+
+    expect(String.fromCharCode(...gif.subarray(0, 6))).toBe('GIF89a')
+    const decoded = decodeGif(gif)
+    expect(decoded.loopCount).toBe(0)
+    expect(decoded.frames.map((frame) => frame.delay)).toEqual([7, 3])
+    expect(decoded.frames[0]!.indices).toEqual([...noise])
+    expect(decoded.frames[1]!.indices).toEqual([...bands])
+  })
+
+  it('writes the repeat count as extra plays and omits it for a single play', () => {
+    const palette = new Uint8Array([0, 0, 0, 255, 255, 255])
+    const encode = (loopCount: number) => {
+      const writer = createGifWriter({ width: 2, height: 1, loopCount, globalPalette: palette })
+      writer.addFrame(new Uint8Array([0, 1]), 10)
+      return decodeGif(writer.finish())
+    }
+
+    expect(encode(0).loopCount).toBe(0)
+    expect(encode(3).loopCount).toBe(2)
+    expect(encode(1).loopCount).toBeNull()
+  })
+})
@@ -0,0 +1,424 @@
+/**
+ * Animated GIF encoding for export: median-cut palette generation, dithering
+ * (Floyd–Steinberg error diffusion or 4×4 ordered/Bayer) and a streaming
+ * GIF89a writer with LZW compression and a NETSCAPE2.0 loop block.
+ *
+ * Colours are bucketed on a 5-bit-per-channel grid (32768 bins) for both
+ * palette building and nearest-colour lookups, which keeps quantization fast
+ * enough to run per frame without visibly hurting a 256-colour result.
+ */
+
+import type { AnimatedImageDither } from '@/types/export'
+
+const BIN_COUNT = 1 << 15
+const MAX_CODE = 4095
+const HASH_SIZE = 5003
+
+/** Per-bin pixel counts and RGB sums (for averaging) on a 5-bit grid. */
+export interface ColorHistogram {
+  counts: Uint32Array
+  sums: Float64Array
+}
+
+export function createColorHistogram(): ColorHistogram {
+  return { counts: new Uint32Array(BIN_COUNT), sums: new Float64Array(BIN_COUNT * 3) }
+}
+
+function binOf(r: number, g: number, b: number): number {
+  return ((r >> 3) << 10) | ((g >> 3) << 5) | (b >> 3)
+}
+
+/** Accumulate an RGBA frame into the histogram (alpha is ignored). */
+export function addToHistogram(histogram: ColorHistogram, rgba: Uint8ClampedArray): void {
+  const { counts, sums } = histogram
+  for (let i = 0; i < rgba.length; i += 4) {
+    const r = rgba[i]!
+    const g = rgba[i + 1]!
+    const b = rgba[i + 2]!
+    const bin = binOf(r, g, b)
+    counts[bin] = counts[bin]! + 1
+    sums[bin * 3] = sums[bin * 3]! + r
+    sums[bin * 3 + 1] = sums[bin * 3 + 1]! + g
+    sums[bin * 3 + 2] = sums[bin * 3 + 2]! + b
+  }
+}
+
+interface ColorBin {
+  r: number
+  g: number
+  b: number
+  count: number
+}
+
+interface ColorBox {
+  bins: ColorBin[]
+  count: number
+  /** Channel with the widest spread (0 = r, 1 = g, 2 = b) and that spread. */
+  channel: 0 | 1 | 2
+  range: number
+}
+
+const channelOf = (bin: ColorBin, channel: 0 | 1 | 2): number =>
+  channel === 0 ? bin.r : channel === 1 ? bin.g : bin.b
+
+function makeBox(bins: ColorBin[]): ColorBox {
+  const min = [255, 255, 255]
+  const max = [0, 0, 0]
+  let count = 0
+  for (const bin of bins) {
+    count += bin.count
+    for (const channel of [0, 1, 2] as const) {
+      const value = channelOf(bin, channel)
+      if (value < min[channel]!) min[channel] = value
+      if (value > max[channel]!) max[channel] = value
+    }
+  }
+  const ranges = [max[0]! - min[0]!, max[1]! - min[1]!, max[2]! - min[2]!]
+  const [rRange, gRange, bRange] = ranges as [number, number, number]
+  const channel = gRange >= rRange && gRange >= bRange ? 1 : rRange >= bRange ? 0 : 2
+  return { bins, count, channel, range: ranges[channel]! }
+}
+
+/**
+ * Median-cut the histogram down to at most `maxColors` colours. Returns packed
+ * RGB triplets. The box with the largest population-weighted spread is split
+ * at its population median until the budget is spent or nothing can split.
+ */
+export function buildPalette(histogram: ColorHistogram, maxColors = 256): Uint8Array {
+  const bins: ColorBin[] = []
+  for (let bin = 0; bin < BIN_COUNT; bin++) {
+    const count = histogram.counts[bin]!
+    if (count === 0) continue
+    bins.push({
+      r: histogram.sums[bin * 3]! / count,
+      g: histogram.sums[bin * 3 + 1]! / count,
+      b: histogram.sums[bin * 3 + 2]! / count,
+      count,
+    })
+  }
+  if (bins.length === 0) return new Uint8Array(3)
+
+  const boxes = [makeBox(bins)]
+  while (boxes.length < maxColors) {
+    let target = -1
+    let best = 0
+    for (let i = 0; i < boxes.length; i++) {
+      const box = boxes[i]!
+      const score = box.range * Math.sqrt(box.count)
+      if (box.bins.length > 1 && score > best) {
+        best = score
+        target = i
+      }
+    }
+    if (target < 0) break
+
+    const box = boxes[target]!
+    const sorted = box.bins.toSorted(
+      (a, b) => channelOf(a, box.channel) - channelOf(b, box.channel),
+    )
+    let seen = 0
+    let split = 1
+    for (; split < sorted.length - 1; split++) {
+      seen += sorted[split - 1]!.count
+      if (seen >= box.count / 2) break
+    }
+    boxes.splice(target, 1, makeBox(sorted.slice(0, split)), makeBox(sorted.slice(split)))
+  }
+
+  const palette = new Uint8Array(boxes.length * 3)
+  boxes.forEach((box, i) => {
+    let r = 0
+    let g = 0
+    let b = 0
+    for (const bin of box.bins) {
+      r += bin.r * bin.count
+      g += bin.g * bin.count
+      b += bin.b * bin.count
+    }
+    palette[i * 3] = Math.round(r / box.count)
+    palette[i * 3 + 1] = Math.round(g / box.count)
+    palette[i * 3 + 2] = Math.round(b / box.count)
+  })
+  return palette
+}
+
+/** Nearest-palette-entry lookup, memoized per 5-bit colour bin. */
+function createNearestLookup(palette: Uint8Array): (r: number, g: number, b: number) => number {
+  const cache = new Int16Array(BIN_COUNT).fill(-1)
+  const size = palette.length / 3
+  return (r, g, b) => {
+    const bin = binOf(r, g, b)
+    const cached = cache[bin]!
+    if (cached >= 0) return cached
+    // Match against the bin centre so every colour in the bin agrees.
+    const cr = (r & 0xf8) | 4
+    const cg = (g & 0xf8) | 4
+    const cb = (b & 0xf8) | 4
+    let best = 0
+    let bestDistance = Infinity
+    for (let i = 0; i < size; i++) {
+      const dr = palette[i * 3]! - cr
+      const dg = palette[i * 3 + 1]! - cg
+      const db = palette[i * 3 + 2]! - cb
+      const distance = dr * dr + dg * dg + db * db
+      if (distance < bestDistance) {
+        bestDistance = distance
+        best = i
+      }
+    }
+    cache[bin] = best
+    return best
+  }
+}
+
+const BAYER_4X4 = [0, 8, 2, 10, 12, 4, 14, 6, 3, 11, 1, 9, 15, 7, 13, 5]
+
+const clampByte = (value: number): number => (value < 0 ? 0 : value > 255 ? 255 : value)
+
+/** Map an RGBA frame to palette indices with the requested dithering. */
+export function indexFrame(
+  rgba: Uint8ClampedArray,
+  width: number,
+  height: number,
+  palette: Uint8Array,
+  dither: AnimatedImageDither,
+): Uint8Array {
+  const nearest = createNearestLookup(palette)
+  const indices = new Uint8Array(width * height)
+
+  if (dither === 'none') {
+    for (let p = 0; p < indices.length; p++) {
+      indices[p] = nearest(rgba[p * 4]!, rgba[p * 4 + 1]!, rgba[p * 4 + 2]!)
+    }
+    return indices
+  }
+
+  if (dither === 'ordered') {
+    for (let y = 0; y < height; y++) {
+      for (let x = 0; x < width; x++) {
+        const p = y * width + x
+        // Threshold in [-16, 14]: about one 5-bit step either way.
+        const offset = BAYER_4X4[(y & 3) * 4 + (x & 3)]! * 2 - 16
+        indices[p] = nearest(
+          clampByte(rgba[p * 4]! + offset),
+          clampByte(rgba[p * 4 + 1]! + offset),
+          clampByte(rgba[p * 4 + 2]! + offset),
+        )
+      }
+    }
+    return indices
+  }
+
+  // Floyd–Steinberg: carry quantization error into the next pixel and row.
+  let current = new Float32Array((width + 2) * 3)
+  let next = new Float32Array((width + 2) * 3)
+  for (let y = 0; y < height; y++) {
+    next.fill(0)
+    for (let x = 0; x < width; x++) {
+      const p = y * width + x
+      const e = (x + 1) * 3
+      const r = clampByte(rgba[p * 4]! + current[e]!)
+      const g = clampByte(rgba[p * 4 + 1]! + current[e + 1]!)
+      const b = clampByte(rgba[p * 4 + 2]! + current[e + 2]!)
+      const index = nearest(r, g, b)
+      indices[p] = index
+      const errors = [
+        r - palette[index * 3]!,
+        g - palette[index * 3 + 1]!,
+        b - palette[index * 3 + 2]!,
+      ]
+      for (let c = 0; c < 3; c++) {
+        const error = errors[c]!
+        current[e + 3 + c] = current[e + 3 + c]! + (error * 7) / 16
+        next[e - 3 + c] = next[e - 3 + c]! + (error * 3) / 16
+        next[e + c] = next[e + c]! + (error * 5) / 16
+        next[e + 3 + c] = next[e + 3 + c]! + error / 16
+      }
+    }
+    ;[current, next] = [next, current]
+  }
+  return indices
+}
+
+/** Byte sink that grows in fixed-size pages. */
+function createByteWriter() {
+  const pages: Uint8Array[] = []
+  let page = new Uint8Array(64 * 1024)
+  let offset = 0
+  let total = 0
+  const byte = (value: number) => {
+    if (offset === page.length) {
+      pages.push(page)
+      page = new Uint8Array(page.length)
+      offset = 0
+    }
+    page[offset++] = value
+    total++
+  }
+  return {
+    byte,
+    bytes(values: ArrayLike<number>) {
+      for (let i = 0; i < values.length; i++) byte(values[i]!)
+    },
+    u16(value: number) {
+      byte(value & 0xff)
+      byte((value >> 8) & 0xff)
+    },
+    finish(): Uint8Array {
+      const out = new Uint8Array(total)
+      let at = 0
+      for (const full of pages) {
+        out.set(full, at)
+        at += full.length
+      }
+      out.set(page.subarray(0, offset), at)
+      return out
+    },
+  }
+}
+
+type ByteWriter = ReturnType<typeof createByteWriter>
+
+/** Smallest n (>= 1) with 2^n >= palette entries. */
+function paletteBits(palette: Uint8Array): number {
+  let bits = 1
+  while (1 << bits < palette.length / 3) bits++
+  return bits
+}
+
+function writeColorTable(out: ByteWriter, palette: Uint8Array, bits: number): void {
+  out.bytes(palette)
+  for (let i = palette.length; i < (1 << bits) * 3; i++) out.byte(0)
+}
+
+/** LZW-compress palette indices into GIF image data sub-blocks. */
+function writeLzwImageData(out: ByteWriter, indices: Uint8Array, minCodeSize: number): void {
+  const clearCode = 1 << minCodeSize
+  const endCode = clearCode + 1
+  const hashKeys = new Int32Array(HASH_SIZE).fill(-1)
+  const hashCodes = new Int32Array(HASH_SIZE)
+  let codeSize = minCodeSize + 1
+  let nextCode = endCode + 1
+
+  const block = new Uint8Array(255)
+  let blockLength = 0
+  let bitBuffer = 0
+  let bitCount = 0
+  const pushByte = (value: number) => {
+    block[blockLength++] = value
+    if (blockLength === 255) {
+      out.byte(255)
+      out.bytes(block)
+      blockLength = 0
+    }
+  }
+  const emit = (code: number) => {
+    bitBuffer |= code << bitCount
+    bitCount += codeSize
+    while (bitCount >= 8) {
+      pushByte(bitBuffer & 0xff)
+      bitBuffer >>>= 8
+      bitCount -= 8
+    }
+    // Widen once the decoder's table will need the next bit.
+    if (nextCode > (1 << codeSize) - 1 && codeSize < 12) codeSize++
+  }
+
+  out.byte(minCodeSize)
+  emit(clearCode)
+
+  if (indices.length > 0) {
+    let prefix = indices[0]!
+    for (let i = 1; i < indices.length; i++) {
+      const k = indices[i]!
+      const key = (k << 12) | prefix
+      let h = ((k << 4) ^ prefix) % HASH_SIZE
+      while (hashKeys[h] !== -1 && hashKeys[h] !== key) h = (h + 1) % HASH_SIZE
+      if (hashKeys[h] === key) {
+        prefix = hashCodes[h]!
+        continue
+      }
+      emit(prefix)
+      if (nextCode <= MAX_CODE) {
+        hashKeys[h] = key
+        hashCodes[h] = nextCode++
+      } else {
+        emit(clearCode)
+        hashKeys.fill(-1)
+        codeSize = minCodeSize + 1
+        nextCode = endCode + 1
+      }
+      prefix = k
+    }
+    emit(prefix)
+  }
+  emit(endCode)
+  if (bitCount > 0) pushByte(bitBuffer & 0xff)
+  if (blockLength > 0) {
+    out.byte(blockLength)
+    out.bytes(block.subarray(0, blockLength))
+  }
+  out.byte(0) // block terminator
+}
+
+export interface GifWriterOptions {
+  width: number
+  height: number
+  /** How many times the animation plays; 0 = forever. */
+  loopCount: number
+  /** Shared colour table; frames without a local palette use it. */
+  globalPalette?: Uint8Array
+}
+
+/**
+ * Streaming GIF89a writer. Frames are compressed as they're added, so
+ * per-frame-palette exports never hold more than one frame of pixels.
+ */
+export function createGifWriter({ width, height, loopCount, globalPalette }: GifWriterOptions) {
+  const out = createByteWriter()
+  out.bytes([0x47, 0x49, 0x46, 0x38, 0x39, 0x61]) // "GIF89a"
+  out.u16(width)
+  out.u16(height)
+  const globalBits = globalPalette ? paletteBits(globalPalette) : 0
+  out.byte(globalPalette ? 0x80 | 0x70 | (globalBits - 1) : 0x70)
+  out.byte(0) // background colour index
+  out.byte(0) // pixel aspect ratio
+  if (globalPalette) writeColorTable(out, globalPalette, globalBits)
+
+  // NETSCAPE2.0 repeat count = extra plays after the first; omit to play once.
+  if (loopCount !== 1) {
+    out.bytes([0x21, 0xff, 0x0b])
+    out.bytes([...'NETSCAPE2.0'].map((char) => char.charCodeAt(0)))
+    out.bytes([0x03, 0x01])
+    out.u16(loopCount === 0 ? 0 : Math.min(0xffff, loopCount - 1))
+    out.byte(0)
+  }
+
+  return {
+    addFrame(indices: Uint8Array, delayCentiseconds: number, localPalette?: Uint8Array): void {
+      const palette = localPalette ?? globalPalette
+      if (!palette) throw new Error('GIF frame has no palette')
+      const bits = paletteBits(palette)
+
+      // Graphic control extension: frame delay, no disposal, no transparency.
+      out.bytes([0x21, 0xf9, 0x04, 0x04])
+      out.u16(Math.max(0, Math.round(delayCentiseconds)))
+      out.bytes([0x00, 0x00])
+
+      out.byte(0x2c)
+      out.u16(0)
+      out.u16(0)
+      out.u16(width)
+      out.u16(height)
+      out.byte(localPalette ? 0x80 | (bits - 1) : 0)
+      if (localPalette) writeColorTable(out, localPalette, bits)
+
+      writeLzwImageData(out, indices, Math.max(2, bits))
+    },
+
+    finish(): Uint8Array {
+      out.byte(0x3b) // trailer
+      return out.finish()
+    },
+  }
+}
@@ -5,6 +5,7 @@ import { ShapeRenderPipeline } from '@/infrastructure/gpu-shapes'
 import { GlyphAtlasTextPipeline } from '@/infrastructure/gpu-text'
 import { CompositorPipeline, GpuTexturePool } from '@/infrastructure/gpu-compositor'
 import { MaskCombinePipeline, MaskTextureManager } from '@/infrastructure/gpu-masks'
+import { HdrFrameEncoder, HdrFrameUploader } from '@/infrastructure/gpu-color'
 import type {
   GpuBitmapMaskTextureCacheEntry,
   GpuTextTextureCacheEntry,
@@ -15,7 +16,7 @@ import type {
  * acquires the GPU device) plus every device-derived pipeline (transition,
  * media, media-blend, shape, text, mask-combine), the blend-mode compositor,
  * texture pool, mask-texture manager, the offscreen composite output target,
- * and the glyph/bitmap-mask texture caches.
+ * the HDR frame uploader/encoder, and the glyph/bitmap-mask texture caches.
  *
  * All pipelines are lazily initialized on first use to avoid blocking renderer
  * startup. The effects pipeline owns the GPU device; every other pipeline is
@@ -35,6 +36,8 @@ export class GpuPipelineManager {
   compositor: CompositorPipeline | null = null
   texturePool: GpuTexturePool | null = null
   maskManager: MaskTextureManager | null = null
+  hdrUploader: HdrFrameUploader | null = null
+  hdrEncoder: HdrFrameEncoder | null = null
 
   readonly textTextureCache = new Map<string, GpuTextTextureCacheEntry>()
   readonly bitmapMaskTextureCache = new Map<string, GpuBitmapMaskTextureCacheEntry>()
@@ -111,6 +114,21 @@ export class GpuPipelineManager {
     return true
   }
 
+  // === HDR working space (10-bit upload + I420P10 export encode) ===
+  ensureHdrUploader(): boolean {
+    if (this.hdrUploader) return true
+    if (!this.effects) return false
+    this.hdrUploader = new HdrFrameUploader(this.effects.getDevice())
+    return true
+  }
+
+  ensureHdrEncoder(): boolean {
+    if (this.hdrEncoder) return true
+    if (!this.effects) return false
+    this.hdrEncoder = new HdrFrameEncoder(this.effects.getDevice())
+    return true
+  }
+
   ensureTexturePool(): GpuTexturePool {
     if (this.texturePool) return this.texturePool
     if (!this.effects) {
@@ -166,6 +184,10 @@ export class GpuPipelineManager {
     this.texturePool = null
     this.maskManager?.destroy()
     this.maskManager = null
+    this.hdrUploader?.destroy()
+    this.hdrUploader = null
+    this.hdrEncoder?.destroy()
+    this.hdrEncoder = null
     this.compositeCtx = null
     this.compositeCanvas = null
     this.compositeW = 0
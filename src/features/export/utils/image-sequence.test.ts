@@ -0,0 +1,45 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { unzipSync } from 'fflate'
+import {
+  createFolderSequenceSink,
+  createZipSequenceSink,
+  getSequenceFrameFileName,
+} from './image-sequence'
+
+describe('image sequence helpers', () => {
+  it('zero-pads frame file names', () => {
+    expect(getSequenceFrameFileName(0)).toBe('frame_000000.png')
+    expect(getSequenceFrameFileName(1234)).toBe('frame_001234.png')
+  })
+
+  it('streams frames into a stored zip', async () => {
+    const sink = createZipSequenceSink()
+    await sink.writeFrame(getSequenceFrameFileName(0), new Blob([new Uint8Array([1, 2, 3])]))
+    await sink.writeFrame(getSequenceFrameFileName(1), new Blob([new Uint8Array([4, 5])]))
+
+    const blob = sink.finish()
+    expect(blob?.type).toBe('application/zip')
+    const files = unzipSync(new Uint8Array(await blob!.arrayBuffer()))
+    expect(Object.keys(files)).toEqual(['frame_000000.png', 'frame_000001.png'])
+    expect([...files['frame_000001.png']!]).toEqual([4, 5])
+  })
+
+  it('writes each frame into the folder as it arrives', async () => {
+    const written = new Map<string, unknown>()
+    const folder = {
+      getFileHandle: async (name: string) => ({
+        createWritable: async () => ({
+          write: async (data: unknown) => void written.set(name, data),
+          close: async () => {},
+        }),
+      }),
+    } as unknown as FileSystemDirectoryHandle
+
+    const sink = createFolderSequenceSink(folder)
+    const png = new Blob([new Uint8Array([7])])
+    await sink.writeFrame(getSequenceFrameFileName(3), png)
+
+    expect(written.get('frame_000003.png')).toBe(png)
+    expect(sink.finish()).toBeNull()
+  })
+})
@@ -0,0 +1,67 @@
+/**
+ * Image-sequence helpers: numbered frame names and the sinks frames are
+ * handed to as soon as each one is encoded. Queue renders write straight into
+ * the export folder; a ZIP is only built when the sequence is downloaded
+ * (export dialog, headless), so it can travel through the same
+ * `ClientRenderResult` / worker plumbing as an encoded video.
+ */
+
+import { Zip, ZipPassThrough } from 'fflate'
+
+export const IMAGE_SEQUENCE_MIME_TYPE = 'application/zip'
+
+/** Zero-padded file name for one frame: `frame_000042.png`. */
+export function getSequenceFrameFileName(frame: number): string {
+  return `frame_${String(frame).padStart(6, '0')}.png`
+}
+
+/** Where rendered frames go, one encoded PNG at a time. */
+export interface ImageSequenceSink {
+  writeFrame(name: string, png: Blob): Promise<void>
+  /** The downloadable ZIP, or null when frames went to a folder. */
+  finish(): Blob | null
+}
+
+/**
+ * Write each frame into `folder` (a workspace export folder) as it arrives, so
+ * no more than one frame is held in memory.
+ */
+export function createFolderSequenceSink(folder: FileSystemDirectoryHandle): ImageSequenceSink {
+  return {
+    async writeFrame(name, png) {
+      const handle = await folder.getFileHandle(name, { create: true })
+      const writable = await handle.createWritable()
+      await writable.write(png)
+      await writable.close()
+    },
+    finish: () => null,
+  }
+}
+
+/**
+ * Stream frames into a stored ZIP (PNG is already compressed). Only the zip
+ * output is kept, so each frame's bytes are held once.
+ */
+export function createZipSequenceSink(): ImageSequenceSink {
+  const chunks: Uint8Array[] = []
+  let zipError: Error | null = null
+  const zip = new Zip((error, chunk) => {
+    if (error) zipError = error
+    else chunks.push(chunk)
+  })
+
+  return {
+    async writeFrame(name, png) {
+      const bytes = new Uint8Array(await png.arrayBuffer())
+      const entry = new ZipPassThrough(name)
+      zip.add(entry)
+      entry.push(bytes, true)
+      if (zipError) throw zipError
+    },
+    finish() {
+      zip.end()
+      if (zipError) throw zipError
+      return new Blob(chunks, { type: IMAGE_SEQUENCE_MIME_TYPE })
+    },
+  }
+}
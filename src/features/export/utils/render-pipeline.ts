@@ -15,7 +15,12 @@
  *    always terminates it.
  */
 
-import type { ExportSettings, ExtendedExportSettings, CompositionInputProps } from '@/types/export'
+import type {
+  ExportMode,
+  ExportSettings,
+  ExtendedExportSettings,
+  CompositionInputProps,
+} from '@/types/export'
 import { createManagedWorker } from '@/shared/utils/managed-worker'
 import type {
   ClientExportSettings,
@@ -33,8 +38,11 @@ import {
   getAudioBitrateForQuality,
   getPreferredContainerForCodec,
   selectFallbackVideoCodec,
+  DEFAULT_ANIMATED_IMAGE_OPTIONS,
+  LOUDNESS_TARGET_PRESETS,
 } from './client-renderer'
 import { renderAudioOnly, renderComposition } from './canvas-render-orchestrator'
+import { buildSidecarSubtitleFiles } from './sidecar-subtitle-export'
 import type {
   ExportRenderWorkerRequest,
   ExportRenderWorkerResponse,
@@ -49,7 +57,7 @@ export function isExtendedSettings(
 
 export interface ResolvedClientSettings {
   clientSettings: ClientExportSettings
-  exportMode: 'video' | 'audio'
+  exportMode: ExportMode
   renderWholeProject: boolean
   /** The supported codec we fell back to, if the requested one was unavailable. */
   codecFallback?: ClientCodec
@@ -71,7 +79,10 @@ export async function resolveClientSettings(
   const videoContainer = extended ? settings.videoContainer : undefined
   const audioContainer = extended ? settings.audioContainer : undefined
   const embedSubtitles = extended ? (settings.embedSubtitles ?? false) : false
+  const sidecarSubtitles = extended ? settings.sidecarSubtitles : undefined
   const renderWholeProject = extended ? (settings.renderWholeProject ?? false) : false
+  const alpha = extended ? (settings.alpha ?? false) : false
+  const hdr = extended ? (settings.hdr ?? false) : false
 
   const clientSettings = mapToClientSettings(settings, fps)
 
@@ -87,9 +98,34 @@ export async function resolveClientSettings(
 
   clientSettings.mode = exportMode
   clientSettings.embedSubtitles = exportMode === 'video' ? embedSubtitles : false
+  if (sidecarSubtitles && exportMode === 'video') clientSettings.sidecarSubtitles = sidecarSubtitles
+  if (alpha && exportMode !== 'audio') clientSettings.alpha = true
+  if (hdr && exportMode === 'video') clientSettings.hdr = true
+
+  const loudnessTarget = extended ? settings.loudnessTarget : undefined
+  if (loudnessTarget && (exportMode === 'video' || exportMode === 'audio')) {
+    clientSettings.loudnessTarget = LOUDNESS_TARGET_PRESETS[loudnessTarget]
+  }
+
+  if (exportMode === 'animated-image') {
+    clientSettings.container = (extended && settings.animatedImageContainer) || 'gif'
+    clientSettings.animatedImage = {
+      ...DEFAULT_ANIMATED_IMAGE_OPTIONS,
+      ...(extended ? settings.animatedImage : undefined),
+    }
+    // GIF has 1-bit transparency at best; only WebP keeps the alpha channel.
+    if (clientSettings.container === 'gif') delete clientSettings.alpha
+  }
 
   let codecFallback: ClientCodec | undefined
 
+  // Image sequences and animated images are encoded on the canvas, so there's
+  // no WebCodecs encoder to probe.
+  if (exportMode === 'image-sequence' || exportMode === 'animated-image') {
+    const validation = validateSettings(clientSettings)
+    if (!validation.valid) throw new Error(validation.error)
+  }
+
   // Validate + check codec support (skip video codec validation for audio-only).
   if (exportMode === 'video') {
     const validation = validateSettings(clientSettings)
@@ -137,10 +173,12 @@ export async function resolveClientSettings(
 
 export interface RunRenderArgs {
   clientSettings: ClientExportSettings
-  exportMode: 'video' | 'audio'
+  exportMode: ExportMode
   composition: CompositionInputProps
   signal: AbortSignal
   onProgress: (progress: RenderProgress) => void
+  /** Image sequences: write frames into this export folder as they are encoded. */
+  sequenceFolder?: FileSystemDirectoryHandle
 }
 
 export interface RunRenderOutcome {
@@ -158,6 +196,7 @@ function renderInWorker(
   composition: CompositionInputProps,
   signal: AbortSignal,
   onProgress: (progress: RenderProgress) => void,
+  sequenceFolder: FileSystemDirectoryHandle | undefined,
 ): Promise<ClientRenderResult> {
   if (typeof Worker === 'undefined') {
     return Promise.reject(new Error('WORKER_UNAVAILABLE'))
@@ -214,22 +253,30 @@ function renderInWorker(
       requestId,
       settings: clientSettings,
       composition,
+      ...(sequenceFolder ? { sequenceFolder } : {}),
     }
     worker.postMessage(startMessage)
   })
 }
 
 function renderOnMainThread(
-  exportMode: 'video' | 'audio',
+  exportMode: ExportMode,
   clientSettings: ClientExportSettings,
   composition: CompositionInputProps,
   signal: AbortSignal,
   onProgress: (progress: RenderProgress) => void,
+  sequenceFolder: FileSystemDirectoryHandle | undefined,
 ): Promise<ClientRenderResult> {
   if (exportMode === 'audio') {
     return renderAudioOnly({ settings: clientSettings, composition, onProgress, signal })
   }
-  return renderComposition({ settings: clientSettings, composition, onProgress, signal })
+  return renderComposition({
+    settings: clientSettings,
+    composition,
+    onProgress,
+    signal,
+    sequenceFolder,
+  })
 }
 
 /**
@@ -237,12 +284,24 @@ function renderOnMainThread(
  * thread for compositions the worker can't handle. Owns a single worker for
  * the call and always terminates it. Re-throws AbortError on cancellation.
  */
+/** Sidecar files come from the same range-trimmed composition the video was rendered from. */
+function withSidecarSubtitles(
+  result: ClientRenderResult,
+  clientSettings: ClientExportSettings,
+  composition: CompositionInputProps,
+): ClientRenderResult {
+  if (clientSettings.mode !== 'video' || !clientSettings.sidecarSubtitles) return result
+  const sidecarSubtitles = buildSidecarSubtitleFiles(composition, clientSettings.sidecarSubtitles)
+  return sidecarSubtitles.length > 0 ? { ...result, sidecarSubtitles } : result
+}
+
 export async function runRender({
   clientSettings,
   exportMode,
   composition,
   signal,
   onProgress,
+  sequenceFolder,
 }: RunRenderArgs): Promise<RunRenderOutcome> {
   const workerManager = createManagedWorker<Worker>({
     createWorker: () =>
@@ -262,8 +321,12 @@ export async function runRender({
       composition,
       signal,
       onProgress,
+      sequenceFolder,
     )
-    return { result, renderPath: 'worker' }
+    return {
+      result: withSidecarSubtitles(result, clientSettings, composition),
+      renderPath: 'worker',
+    }
   } catch (workerError) {
     if (workerError instanceof DOMException && workerError.name === 'AbortError') {
       throw workerError
@@ -283,8 +346,13 @@ export async function runRender({
       composition,
       signal,
       onProgress,
+      sequenceFolder,
     )
-    return { result, renderPath: 'main-thread', fallbackReason: workerMessage }
+    return {
+      result: withSidecarSubtitles(result, clientSettings, composition),
+      renderPath: 'main-thread',
+      fallbackReason: workerMessage,
+    }
   } finally {
     workerManager.terminate()
   }
@@ -0,0 +1,102 @@
+import { describe, expect, it, vi } from 'vite-plus/test'
+import type { ShapeItem, TimelineTrack, VideoItem } from '@/types/timeline'
+import { SegmentationMatteCache, usesSegmentationMattes } from './segmentation-mattes'
+
+function createStore() {
+  let notify: (mediaId: string) => void = () => {}
+  const store = {
+    getInfo: vi.fn(async (mediaId: string) =>
+      mediaId === 'media-1'
+        ? { fps: 10, width: 2, height: 1, ranges: [{ start: 1, end: 2 }] }
+        : undefined,
+    ),
+    getMatte: vi.fn(async (_mediaId: string, _fps: number, frameIndex: number) =>
+      frameIndex === 15 ? undefined : { width: 2, height: 1, data: new Uint8Array([0, 255]) },
+    ),
+    subscribe: vi.fn((listener: (mediaId: string) => void) => {
+      notify = listener
+      return () => {
+        notify = () => {}
+      }
+    }),
+  }
+  return { store, notify: (mediaId: string) => notify(mediaId) }
+}
+
+describe('SegmentationMatteCache', () => {
+  it('loads the matte nearest the source time within analyzed ranges', async () => {
+    const { store } = createStore()
+    const cache = new SegmentationMatteCache({ store })
+
+    await expect(cache.getMatte('media-1', 1.21)).resolves.toMatchObject({
+      key: 'media-1:0:10:12',
+    })
+    await expect(cache.getMatte('media-1', 0.5)).resolves.toBeNull()
+    await expect(cache.getMatte('media-2', 1.2)).resolves.toBeNull()
+    expect(store.getInfo).toHaveBeenCalledTimes(2)
+  })
+
+  it('falls back to a neighbouring frame when the nearest matte is missing', async () => {
+    const { store } = createStore()
+    const cache = new SegmentationMatteCache({ store })
+
+    await expect(cache.getMatte('media-1', 1.5)).resolves.toMatchObject({
+      key: 'media-1:0:10:14',
+    })
+  })
+
+  it('dedupes matte loads and re-keys mattes after re-analysis', async () => {
+    const { store, notify } = createStore()
+    const cache = new SegmentationMatteCache({ store })
+
+    await Promise.all([cache.getMatte('media-1', 1.2), cache.getMatte('media-1', 1.2)])
+    expect(store.getMatte).toHaveBeenCalledTimes(1)
+
+    notify('media-1')
+    await expect(cache.getMatte('media-1', 1.2)).resolves.toMatchObject({
+      key: 'media-1:1:10:12',
+    })
+    expect(store.getInfo).toHaveBeenCalledTimes(2)
+    expect(store.getMatte).toHaveBeenCalledTimes(2)
+
+    cache.dispose()
+  })
+
+  it('renders unmatted without a store', async () => {
+    const cache = new SegmentationMatteCache({ store: null })
+    await expect(cache.getMatte('media-1', 1.2)).resolves.toBeNull()
+  })
+})
+
+describe('usesSegmentationMattes', () => {
+  const video = { id: 'clip-1', type: 'video', mediaId: 'media-1' } as VideoItem
+  const track = (items: TimelineTrack['items']) => ({ id: 'track-1', items }) as TimelineTrack
+
+  it('detects the remove background effect and matte masks', () => {
+    expect(usesSegmentationMattes([track([video])])).toBe(false)
+    expect(
+      usesSegmentationMattes([
+        track([
+          {
+            ...video,
+            effects: [
+              {
+                id: 'fx-1',
+                enabled: true,
+                effect: { type: 'gpu-effect', gpuEffectType: 'gpu-remove-background', params: {} },
+              },
+            ],
+          },
+        ]),
+      ]),
+    ).toBe(true)
+    expect(
+      usesSegmentationMattes([
+        track([
+          video,
+          { id: 'mask-1', type: 'shape', isMask: true, maskMatteItemId: 'clip-1' } as ShapeItem,
+        ]),
+      ]),
+    ).toBe(true)
+  })
+})
@@ -0,0 +1,254 @@
+import type { ShapeItem, TimelineItem, TimelineTrack } from '@/types/timeline'
+import type { ItemKeyframes } from '@/types/keyframe'
+import type { SegmentationMatte } from '@/infrastructure/analysis/segmentation/types'
+import { matteToRgba } from '@/infrastructure/analysis/segmentation/matte'
+import type { SegmentationPayload } from '@/infrastructure/storage/workspace-fs/ai-outputs'
+import {
+  getSegmentation,
+  getSegmentationMatte,
+  subscribeSegmentationChanges,
+} from '@/infrastructure/storage/workspace-fs/segmentation'
+import { getWorkspaceRoot } from '@/infrastructure/storage/workspace-fs/root'
+import { createLogger } from '@/shared/logging/logger'
+import { getItemSourceSecondsAtFrame, getMediaFrameRect } from './canvas-effects'
+import type { CanvasSettings, ItemTransform } from './canvas-item-renderer/types'
+
+const log = createLogger('SegmentationMattes')
+
+const MAX_CACHED_MATTES = 64
+
+export const REMOVE_BACKGROUND_EFFECT_TYPE = 'gpu-remove-background'
+
+/** Persistent matte storage; defaults to the workspace media cache. */
+export interface SegmentationMatteStore {
+  getInfo(mediaId: string): Promise<SegmentationPayload | undefined>
+  getMatte(
+    mediaId: string,
+    fps: number,
+    frameIndex: number,
+  ): Promise<SegmentationMatte | undefined>
+  /** Called with a media id whenever its analysis changes */
+  subscribe?(listener: (mediaId: string) => void): () => void
+}
+
+const workspaceMatteStore: SegmentationMatteStore = {
+  getInfo: async (mediaId) => (await getSegmentation(mediaId))?.data,
+  getMatte: getSegmentationMatte,
+  subscribe: subscribeSegmentationChanges,
+}
+
+export interface LoadedSegmentationMatte {
+  /** Stable per media frame; used as the effect matte / texture cache key */
+  key: string
+  matte: SegmentationMatte
+}
+
+interface SegmentationMatteCacheOptions {
+  /** Defaults to the workspace cache when a workspace is open on this thread */
+  store?: SegmentationMatteStore | null
+}
+
+/**
+ * True when any item renders through subject mattes, either with the
+ * "Remove background" effect or as a mask refined by a clip's matte.
+ */
+export function usesSegmentationMattes(tracks: TimelineTrack[]): boolean {
+  return tracks.some((track) =>
+    (track.items ?? []).some(
+      (item) =>
+        (item.type === 'shape' && item.isMask === true && !!item.maskMatteItemId) ||
+        (item.effects ?? []).some(
+          (entry) =>
+            entry.enabled &&
+            entry.effect.type === 'gpu-effect' &&
+            entry.effect.gpuEffectType === REMOVE_BACKGROUND_EFFECT_TYPE,
+        ),
+    ),
+  )
+}
+
+function isFrameAnalyzed(info: SegmentationPayload, frameIndex: number): boolean {
+  return info.ranges.some(
+    (range) =>
+      frameIndex >= Math.floor(range.start * info.fps) &&
+      frameIndex <= Math.ceil(range.end * info.fps),
+  )
+}
+
+/**
+ * Loads analyzed subject mattes for render. The analysis envelope is read
+ * once per media; mattes are kept in a small LRU with in-flight dedupe since
+ * preview, export and matte masks often ask for the same frame.
+ */
+export class SegmentationMatteCache {
+  private readonly infos = new Map<string, Promise<SegmentationPayload | null>>()
+  private readonly mattes = new Map<string, SegmentationMatte | null>()
+  private readonly inflight = new Map<string, Promise<SegmentationMatte | null>>()
+  /** Bumped on invalidation so re-analyzed mattes get fresh texture keys */
+  private readonly generations = new Map<string, number>()
+  private readonly store: SegmentationMatteStore | null
+  private readonly unsubscribe: () => void
+
+  constructor(options: SegmentationMatteCacheOptions = {}) {
+    // The export worker has no workspace handle; such renders run on the main thread
+    const defaultStore = getWorkspaceRoot() ? workspaceMatteStore : null
+    this.store = options.store === undefined ? defaultStore : options.store
+    // Preview keeps one cache across edits; pick up clips analyzed meanwhile
+    this.unsubscribe = this.store?.subscribe?.((mediaId) => this.invalidate(mediaId)) ?? (() => {})
+  }
+
+  /**
+   * Matte for the media frame nearest `sourceTime`, or null when the media
+   * has not been analyzed there (callers render the clip unmatted).
+   */
+  async getMatte(mediaId: string, sourceTime: number): Promise<LoadedSegmentationMatte | null> {
+    const info = await this.getInfo(mediaId)
+    if (!info || !Number.isFinite(sourceTime)) return null
+
+    const nearest = Math.max(0, Math.round(sourceTime * info.fps))
+    for (const frameIndex of [nearest, nearest - 1, nearest + 1]) {
+      if (frameIndex < 0 || !isFrameAnalyzed(info, frameIndex)) continue
+      const matte = await this.loadMatte(mediaId, info.fps, frameIndex)
+      if (!matte) continue
+      const generation = this.generations.get(mediaId) ?? 0
+      return { key: `${mediaId}:${generation}:${info.fps}:${frameIndex}`, matte }
+    }
+    return null
+  }
+
+  /** Forget what was memoized for a media, e.g. after it was re-analyzed. */
+  invalidate(mediaId: string): void {
+    this.infos.delete(mediaId)
+    this.generations.set(mediaId, (this.generations.get(mediaId) ?? 0) + 1)
+    for (const key of [...this.mattes.keys()]) {
+      if (key.startsWith(`${mediaId}:`)) this.mattes.delete(key)
+    }
+  }
+
+  dispose(): void {
+    this.unsubscribe()
+    this.infos.clear()
+    this.mattes.clear()
+    this.inflight.clear()
+  }
+
+  private getInfo(mediaId: string): Promise<SegmentationPayload | null> {
+    const store = this.store
+    if (!store) return Promise.resolve(null)
+
+    let info = this.infos.get(mediaId)
+    if (!info) {
+      info = store.getInfo(mediaId).then(
+        (payload) => payload ?? null,
+        (error) => {
+          log.warn('Failed to load segmentation info', { mediaId, error })
+          return null
+        },
+      )
+      this.infos.set(mediaId, info)
+    }
+    return info
+  }
+
+  private async loadMatte(
+    mediaId: string,
+    fps: number,
+    frameIndex: number,
+  ): Promise<SegmentationMatte | null> {
+    const key = `${mediaId}:${fps}:${frameIndex}`
+    if (this.mattes.has(key)) {
+      const cached = this.mattes.get(key) ?? null
+      this.mattes.delete(key)
+      this.mattes.set(key, cached)
+      return cached
+    }
+
+    let pending = this.inflight.get(key)
+    if (!pending) {
+      pending = (this.store?.getMatte(mediaId, fps, frameIndex) ?? Promise.resolve(undefined))
+        .then(
+          (matte) => matte ?? null,
+          () => null,
+        )
+        .then((matte) => {
+          this.inflight.delete(key)
+          this.mattes.set(key, matte)
+          while (this.mattes.size > MAX_CACHED_MATTES) {
+            const oldest = this.mattes.keys().next().value
+            if (oldest === undefined) break
+            this.mattes.delete(oldest)
+          }
+          return matte
+        })
+      this.inflight.set(key, pending)
+    }
+    return pending
+  }
+}
+
+/** The clip a matte mask follows, resolved at the frame being rendered. */
+export interface MatteMaskClip {
+  item: TimelineItem
+  keyframes: ItemKeyframes | undefined
+  transform: ItemTransform
+}
+
+/**
+ * Canvas-size subject coverage for each mask that follows a clip's matte,
+ * drawn where that clip lands on screen at `frame`. Masks whose clip is not
+ * on screen or not analyzed there are left out and render as plain shapes.
+ */
+export async function resolveMatteMaskCanvases(
+  masks: ShapeItem[],
+  frame: number,
+  canvas: CanvasSettings,
+  matteCache: SegmentationMatteCache,
+  resolveClip: (itemId: string, frame: number) => MatteMaskClip | undefined,
+): Promise<Map<string, OffscreenCanvas>> {
+  const canvases = new Map<string, OffscreenCanvas>()
+
+  for (const mask of masks) {
+    if (!mask.isMask || !mask.maskMatteItemId) continue
+    if (frame < mask.from || frame >= mask.from + mask.durationInFrames) continue
+
+    const clip = resolveClip(mask.maskMatteItemId, frame)
+    if (!clip || clip.item.type !== 'video' || !clip.item.mediaId) continue
+    const { item, keyframes, transform } = clip
+    if (frame < item.from || frame >= item.from + item.durationInFrames) continue
+
+    const sourceTime = getItemSourceSecondsAtFrame(item, keyframes, frame, canvas.fps)
+    const loaded = await matteCache.getMatte(item.mediaId, sourceTime)
+    if (!loaded) continue
+
+    canvases.set(mask.id, drawMatteAtClip(loaded.matte, item, transform, canvas))
+  }
+
+  return canvases
+}
+
+function drawMatteAtClip(
+  matte: SegmentationMatte,
+  item: TimelineItem,
+  transform: ItemTransform,
+  canvas: CanvasSettings,
+): OffscreenCanvas {
+  const source = new OffscreenCanvas(matte.width, matte.height)
+  source
+    .getContext('2d')!
+    .putImageData(new ImageData(matteToRgba(matte), matte.width, matte.height), 0, 0)
+
+  const output = new OffscreenCanvas(canvas.width, canvas.height)
+  const ctx = output.getContext('2d')!
+  const rect = getMediaFrameRect(item, transform, canvas)
+  ctx.translate(rect.frameCenterX, rect.frameCenterY)
+  ctx.rotate((transform.rotation * Math.PI) / 180)
+  ctx.scale(item.transform?.flipHorizontal ? -1 : 1, item.transform?.flipVertical ? -1 : 1)
+  ctx.drawImage(
+    source,
+    -rect.frameWidth / 2,
+    -rect.frameHeight / 2,
+    rect.frameWidth,
+    rect.frameHeight,
+  )
+  return output
+}
@@ -29,6 +29,8 @@ export interface VideoFrameSource {
     height: number,
   ): Promise<DrawFrameCaptureResult>
   captureFrame(timestamp: number): Promise<CaptureFrameResult>
+  /** Raw decoded frame for HDR upload; the caller closes it. */
+  captureDecodedFrame?(timestamp: number): Promise<VideoFrame | null>
   getLastFailureKind(): VideoFrameFailureKind
   getDimensions(): { width: number; height: number }
   getDuration(): number
@@ -110,6 +112,10 @@ class SharedItemVideoSource implements VideoFrameSource {
     return this.pool.captureItemFrame(this.itemId, this.src, timestamp)
   }
 
+  captureDecodedFrame(timestamp: number): Promise<VideoFrame | null> {
+    return this.pool.captureItemDecodedFrame(this.itemId, this.src, timestamp)
+  }
+
   getLastFailureKind(): VideoFrameFailureKind {
     return this.pool.getItemLastFailureKind(this.itemId, this.src)
   }
@@ -286,6 +292,26 @@ export class SharedVideoExtractorPool {
     return result
   }
 
+  async captureItemDecodedFrame(
+    itemId: string,
+    src: string,
+    timestamp: number,
+  ): Promise<VideoFrame | null> {
+    const state = this.ensureSourceState(src)
+    const sourceReady = await this.initSource(src)
+    if (!sourceReady) return null
+
+    const lane = await this.getInitializedLaneForItem(state, itemId)
+    if (!lane) return null
+    const prev = lane.drawLock ?? Promise.resolve()
+    const result = prev.then(() => lane.extractor.captureDecodedFrame(timestamp))
+    lane.drawLock = result.then(
+      () => undefined,
+      () => undefined,
+    )
+    return result
+  }
+
   getItemLastFailureKind(itemId: string, src: string): VideoFrameFailureKind {
     const extractor = this.getExtractorForItem(itemId, src)
     return extractor?.getLastFailureKind() ?? 'none'
@@ -0,0 +1,108 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { CompositionInputProps } from '@/types/export'
+import type { SubtitleSegmentItem, TimelineTrack } from '@/types/timeline'
+
+import { buildSidecarSubtitleFiles, getSidecarSubtitleFileName } from './sidecar-subtitle-export'
+
+function makeTrack(id: string, name: string, order: number, items: TimelineTrack['items']) {
+  return {
+    id,
+    name,
+    height: 100,
+    locked: false,
+    visible: true,
+    muted: false,
+    solo: false,
+    order,
+    items,
+  } satisfies TimelineTrack
+}
+
+function makeSubtitle(
+  trackId: string,
+  source: SubtitleSegmentItem['source'],
+  overrides: Partial<SubtitleSegmentItem> = {},
+): SubtitleSegmentItem {
+  return {
+    id: `subtitle-${trackId}`,
+    type: 'subtitle',
+    trackId,
+    from: 30,
+    durationInFrames: 60,
+    label: 'Subtitles',
+    source,
+    cues: [
+      { id: 'cue-1', startSeconds: 0, endSeconds: 1, text: 'Hello' },
+      { id: 'cue-2', startSeconds: 1.5, endSeconds: 4, text: 'Clipped <i>tail</i>' },
+    ],
+    color: '#ffffff',
+    ...overrides,
+  }
+}
+
+function makeComposition(tracks: TimelineTrack[]): CompositionInputProps {
+  return { fps: 30, durationInFrames: 120, width: 1920, height: 1080, tracks }
+}
+
+describe('sidecar subtitle export', () => {
+  it('writes one file per subtitle track in export time, top track first', () => {
+    const files = buildSidecarSubtitleFiles(
+      makeComposition([
+        makeTrack('track-2', 'Spanish', 1, [
+          makeSubtitle('track-2', {
+            type: 'translation',
+            language: 'es',
+            sourceItemId: 'subtitle-track-1',
+            translatedAt: 0,
+          }),
+        ]),
+        makeTrack('track-1', 'Captions', 0, [
+          makeSubtitle('track-1', { type: 'transcript', mediaId: 'media-1', clipId: 'clip-1' }),
+        ]),
+      ]),
+      'srt',
+    )
+
+    expect(files.map((file) => file.suffix)).toEqual(['Captions', 'es'])
+    expect(files[0]).toEqual({
+      suffix: 'Captions',
+      extension: 'srt',
+      mimeType: 'application/x-subrip',
+      text: '1\n00:00:01,000 --> 00:00:02,000\nHello\n\n2\n00:00:02,500 --> 00:00:03,000\nClipped <i>tail</i>\n',
+    })
+  })
+
+  it('keeps suffixes unique and skips hidden tracks', () => {
+    const importSource = {
+      type: 'subtitle-import',
+      fileName: 'a.srt',
+      format: 'srt',
+      importedAt: 0,
+    } as const
+    const hidden = {
+      ...makeTrack('track-3', 'Subs', 2, [makeSubtitle('track-3', importSource)]),
+      visible: false,
+    }
+
+    const files = buildSidecarSubtitleFiles(
+      makeComposition([
+        makeTrack('track-1', 'Subs', 0, [makeSubtitle('track-1', importSource)]),
+        makeTrack('track-2', 'Subs', 1, [makeSubtitle('track-2', importSource)]),
+        hidden,
+      ]),
+      'vtt',
+    )
+
+    expect(files.map((file) => file.suffix)).toEqual(['Subs', 'Subs-2'])
+    expect(files[0]?.text.startsWith('WEBVTT\n\n00:00:01.000 --> 00:00:02.000\nHello')).toBe(true)
+  })
+
+  it('names sidecar files after the exported video', () => {
+    expect(getSidecarSubtitleFileName('export-1.mp4', { suffix: 'en', extension: 'srt' })).toBe(
+      'export-1.en.srt',
+    )
+    expect(getSidecarSubtitleFileName('My cut', { suffix: 'es', extension: 'vtt' })).toBe(
+      'My cut.es.vtt',
+    )
+  })
+})
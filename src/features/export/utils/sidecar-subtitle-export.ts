@@ -0,0 +1,96 @@
+import type { CompositionInputProps } from '@/types/export'
+import type { SubtitleSegmentItem, TimelineItem, TimelineTrack } from '@/types/timeline'
+import { SUBTITLE_EXPORT_FORMATS } from '@/shared/utils/subtitle-files'
+import { serializeSrt, serializeVtt, type SubtitleCue } from '@/shared/utils/subtitles'
+import { collectSubtitleCues } from './embedded-subtitle-export'
+
+export type SidecarSubtitleFormat = 'srt' | 'vtt'
+
+/** Cues of one visible subtitle track, in export time. */
+export interface SidecarSubtitleTrack {
+  trackId: string
+  /** File-name tag: the track's subtitle language, else its name. Unique per export. */
+  suffix: string
+  cues: SubtitleCue[]
+}
+
+/** A subtitle file written next to the exported video. */
+export interface SidecarSubtitleFile {
+  suffix: string
+  extension: SidecarSubtitleFormat
+  mimeType: string
+  text: string
+}
+
+function isSubtitleItem(item: TimelineItem): item is SubtitleSegmentItem {
+  return item.type === 'subtitle'
+}
+
+function getTrackSuffix(track: TimelineTrack, items: readonly SubtitleSegmentItem[]): string {
+  for (const item of items) {
+    const source = item.source
+    if (source.type !== 'subtitle-import' && source.language) return source.language
+  }
+  const name = track.name
+    .trim()
+    .replace(/[\\/:*?"<>|.\s]+/g, '-')
+    .replace(/^-+|-+$/g, '')
+  return name || 'subtitles'
+}
+
+/**
+ * One cue list per visible subtitle track, top track first. The composition
+ * is already trimmed to the export range, so cue times start at its in-point.
+ */
+export function collectSidecarSubtitleTracks(
+  composition: CompositionInputProps,
+): SidecarSubtitleTrack[] {
+  const tracks: SidecarSubtitleTrack[] = []
+  const usedSuffixes = new Set<string>()
+
+  const orderedTracks = [...composition.tracks].sort((a, b) => a.order - b.order)
+  for (const track of orderedTracks) {
+    if (track.visible === false) continue
+    const items = (track.items ?? []).filter(isSubtitleItem)
+    if (items.length === 0) continue
+
+    const cues = collectSubtitleCues({ ...composition, tracks: [track] }, isSubtitleItem)
+    if (cues.length === 0) continue
+
+    const baseSuffix = getTrackSuffix(track, items)
+    let suffix = baseSuffix
+    for (let index = 2; usedSuffixes.has(suffix); index++) {
+      suffix = `${baseSuffix}-${index}`
+    }
+    usedSuffixes.add(suffix)
+    tracks.push({ trackId: track.id, suffix, cues })
+  }
+
+  return tracks
+}
+
+export function buildSidecarSubtitleFiles(
+  composition: CompositionInputProps,
+  format: SidecarSubtitleFormat,
+): SidecarSubtitleFile[] {
+  const mimeType =
+    SUBTITLE_EXPORT_FORMATS.find((entry) => entry.format === format)?.mimeType ?? 'text/plain'
+  const serialize = format === 'srt' ? serializeSrt : serializeVtt
+
+  return collectSidecarSubtitleTracks(composition).map((track) => ({
+    suffix: track.suffix,
+    extension: format,
+    mimeType,
+    text: `${serialize(track.cues)}\n`,
+  }))
+}
+
+/** `export-123.mp4` + `en` SRT → `export-123.en.srt`, so players pick the file up. */
+export function getSidecarSubtitleFileName(
+  exportFileName: string,
+  file: Pick<SidecarSubtitleFile, 'suffix' | 'extension'>,
+): string {
+  const dot = exportFileName.lastIndexOf('.')
+  const baseName = dot > 0 ? exportFileName.slice(0, dot) : exportFileName
+  return `${baseName}.${file.suffix}.${file.extension}`
+}
@@ -1,5 +1,10 @@
 import { describe, expect, it } from 'vite-plus/test'
-import type { SubtitleSegmentItem, TimelineTrack, VideoItem } from '@/types/timeline'
+import type {
+  MulticamItem,
+  SubtitleSegmentItem,
+  TimelineTrack,
+  VideoItem,
+} from '@/types/timeline'
 import { convertTimelineToComposition } from './timeline-to-composition'
 
 describe('convertTimelineToComposition IO marker conversion', () => {
@@ -170,4 +175,51 @@ describe('convertTimelineToComposition IO marker conversion', () => {
       { id: 'cue-overlap-end', startSeconds: 2.25, endSeconds: 2.5, text: 'Ends after' },
     ])
   })
+
+  it('renders multicam items as their angle cuts plus the audio angle', () => {
+    const track: TimelineTrack = {
+      id: 'track-1',
+      name: 'Track 1',
+      height: 72,
+      locked: false,
+      visible: true,
+      muted: false,
+      solo: false,
+      order: 0,
+      items: [],
+    }
+
+    const angle = { label: 'Angle', sourceFps: 30, sourceDuration: 300, syncOffset: 0 }
+    const item: MulticamItem = {
+      id: 'mc-1',
+      type: 'multicam',
+      trackId: 'track-1',
+      from: 0,
+      durationInFrames: 60,
+      label: 'Multicam',
+      sourceStart: 0,
+      sourceEnd: 60,
+      sourceDuration: 300,
+      sourceFps: 30,
+      angles: [
+        { ...angle, id: 'a', mediaId: 'media-a', src: 'blob:a' },
+        { ...angle, id: 'b', mediaId: 'media-b', src: 'blob:b' },
+      ],
+      angleSegments: [
+        { id: 's1', startFrame: 0, angleId: 'a' },
+        { id: 's2', startFrame: 30, angleId: 'b' },
+      ],
+      audioAngleId: 'b',
+      syncMethod: 'audio',
+    }
+
+    const composition = convertTimelineToComposition([track], [item], [], 30, 1920, 1080)
+
+    const exported = composition.tracks[0]!.items
+    expect(exported.map((entry) => [entry.type, entry.mediaId, entry.from])).toEqual([
+      ['video', 'media-a', 0],
+      ['video', 'media-b', 30],
+      ['audio', 'media-b', 0],
+    ])
+  })
 })
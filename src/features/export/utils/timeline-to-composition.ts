@@ -11,6 +11,7 @@ import {
 import { appendVirtualTranscriptCaptionTrack } from '@/features/export/deps/caption-items'
 import { createLogger } from '@/shared/logging/logger'
 import { resolveReverseConformedVideoItem } from '@/shared/utils/reverse-conform-item'
+import { expandMulticamItems, expandMulticamKeyframes } from '@/shared/utils/multicam'
 
 const log = createLogger('TimelineToComposition')
 
@@ -42,7 +43,11 @@ export function convertTimelineToComposition(
   busAudioEq?: AudioEqSettings,
   masterBusDb?: number,
 ): CompositionInputProps {
-  items = items.map((item) =>
+  // Multicam items render as their visible angle cuts plus the audio angle
+  if (keyframes) {
+    keyframes = expandMulticamKeyframes(keyframes, items, fps)
+  }
+  items = expandMulticamItems(items, fps).map((item) =>
     item.type === 'video' ? resolveReverseConformedVideoItem(item, fps, { mode: 'export' }) : item,
   )
 
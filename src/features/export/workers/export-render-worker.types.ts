@@ -10,6 +10,8 @@ export interface ExportRenderStartRequest {
   requestId: string
   settings: ClientExportSettings
   composition: CompositionInputProps
+  /** Image sequences: export folder the worker writes frames into. */
+  sequenceFolder?: FileSystemDirectoryHandle
 }
 
 export interface ExportRenderCancelRequest {
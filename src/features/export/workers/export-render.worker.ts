@@ -75,17 +75,22 @@ self.onmessage = async (event: MessageEvent<ExportRenderWorkerRequest>) => {
     return
   }
 
-  const { requestId, settings, composition } = message
+  const { requestId, settings, composition, sequenceFolder } = message
   const controller = new AbortController()
   activeRequests.set(requestId, controller)
 
   try {
     const tracks = composition.tracks ?? []
 
-    if (settings.mode === 'video' && compositionHasAnimatedImage(composition.tracks ?? [])) {
+    if (settings.mode !== 'audio' && compositionHasAnimatedImage(composition.tracks ?? [])) {
       throw new Error('WORKER_REQUIRES_MAIN_THREAD:animated-image')
     }
-    if (compositionHasAudio(tracks) && typeof OfflineAudioContext === 'undefined') {
+    if (
+      settings.mode !== 'image-sequence' &&
+      settings.mode !== 'animated-image' &&
+      compositionHasAudio(tracks) &&
+      typeof OfflineAudioContext === 'undefined'
+    ) {
       throw new Error('WORKER_REQUIRES_MAIN_THREAD:audio-context')
     }
 
@@ -111,6 +116,7 @@ self.onmessage = async (event: MessageEvent<ExportRenderWorkerRequest>) => {
             composition,
             onProgress,
             signal: controller.signal,
+            sequenceFolder,
           })
 
     const complete: ExportRenderWorkerResponse = {
@@ -31,11 +31,30 @@ const PROPERTY_GROUP_DEFINITIONS: Array<{
     label: 'Crop',
     properties: ['cropLeft', 'cropRight', 'cropTop', 'cropBottom', 'cropSoftness'],
   },
+  {
+    id: 'cornerPin',
+    label: 'Corner Pin',
+    properties: [
+      'cornerPinTopLeftX',
+      'cornerPinTopLeftY',
+      'cornerPinTopRightX',
+      'cornerPinTopRightY',
+      'cornerPinBottomRightX',
+      'cornerPinBottomRightY',
+      'cornerPinBottomLeftX',
+      'cornerPinBottomLeftY',
+    ],
+  },
   {
     id: 'audio',
     label: 'Audio',
     properties: ['volume'],
   },
+  {
+    id: 'time',
+    label: 'Time',
+    properties: ['timeRemap'],
+  },
 ]
 
 export function getPropertyAccordionGroups(
@@ -29,7 +29,64 @@ const BUILT_IN_PROPERTY_VALUE_RANGES: Record<BuiltInAnimatableProperty, Property
   cropTop: { property: 'cropTop', min: 0, max: 4000, unit: 'px', decimals: 0 },
   cropBottom: { property: 'cropBottom', min: 0, max: 4000, unit: 'px', decimals: 0 },
   cropSoftness: { property: 'cropSoftness', min: -2000, max: 2000, unit: 'px', decimals: 0 },
+  cornerPinTopLeftX: {
+    property: 'cornerPinTopLeftX',
+    min: -4000,
+    max: 4000,
+    unit: 'px',
+    decimals: 1,
+  },
+  cornerPinTopLeftY: {
+    property: 'cornerPinTopLeftY',
+    min: -4000,
+    max: 4000,
+    unit: 'px',
+    decimals: 1,
+  },
+  cornerPinTopRightX: {
+    property: 'cornerPinTopRightX',
+    min: -4000,
+    max: 4000,
+    unit: 'px',
+    decimals: 1,
+  },
+  cornerPinTopRightY: {
+    property: 'cornerPinTopRightY',
+    min: -4000,
+    max: 4000,
+    unit: 'px',
+    decimals: 1,
+  },
+  cornerPinBottomRightX: {
+    property: 'cornerPinBottomRightX',
+    min: -4000,
+    max: 4000,
+    unit: 'px',
+    decimals: 1,
+  },
+  cornerPinBottomRightY: {
+    property: 'cornerPinBottomRightY',
+    min: -4000,
+    max: 4000,
+    unit: 'px',
+    decimals: 1,
+  },
+  cornerPinBottomLeftX: {
+    property: 'cornerPinBottomLeftX',
+    min: -4000,
+    max: 4000,
+    unit: 'px',
+    decimals: 1,
+  },
+  cornerPinBottomLeftY: {
+    property: 'cornerPinBottomLeftY',
+    min: -4000,
+    max: 4000,
+    unit: 'px',
+    decimals: 1,
+  },
   volume: { property: 'volume', min: -60, max: 20, unit: 'dB', decimals: 1 },
+  timeRemap: { property: 'timeRemap', min: 0, max: 36000, unit: 's', decimals: 2 },
   textStyleScale: { property: 'textStyleScale', min: 0.5, max: 3, unit: 'x', decimals: 2 },
   fontSize: { property: 'fontSize', min: 8, max: 500, unit: 'px', decimals: 0 },
   lineHeight: { property: 'lineHeight', min: 0.5, max: 3, unit: 'x', decimals: 2 },
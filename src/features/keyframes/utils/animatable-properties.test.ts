@@ -33,6 +33,7 @@ describe('getAnimatablePropertiesForItem', () => {
       'cropBottom',
       'cropSoftness',
       'volume',
+      'timeRemap',
     ])
   })
 
@@ -58,6 +59,10 @@ describe('getAnimatablePropertiesForItem', () => {
     ])
   })
 
+  it('includes time remap for audio items', () => {
+    expect(getAnimatablePropertiesForItem(createItem('audio'))).toEqual(['volume', 'timeRemap'])
+  })
+
   it('does not expose anchor properties for non-video visual items', () => {
     expect(getAnimatablePropertiesForItem(createItem('image'))).toEqual([
       'x',
@@ -96,4 +101,29 @@ describe('getAnimatablePropertiesForItem', () => {
       'strokeWidth',
     ])
   })
+
+  it('exposes corner pin offsets once the item has a corner pin', () => {
+    expect(
+      getAnimatablePropertiesForItem({
+        ...createItem('image'),
+        cornerPin: { topLeft: [0, 0], topRight: [0, 0], bottomRight: [0, 0], bottomLeft: [0, 0] },
+      }),
+    ).toEqual([
+      'x',
+      'y',
+      'width',
+      'height',
+      'rotation',
+      'opacity',
+      'cornerRadius',
+      'cornerPinTopLeftX',
+      'cornerPinTopLeftY',
+      'cornerPinTopRightX',
+      'cornerPinTopRightY',
+      'cornerPinBottomRightX',
+      'cornerPinBottomRightY',
+      'cornerPinBottomLeftX',
+      'cornerPinBottomLeftY',
+    ])
+  })
 })
@@ -2,6 +2,7 @@ import type { AnimatableProperty } from '@/types/keyframe'
 import type { TimelineItem } from '@/types/timeline'
 import { getAnimatableEffectPropertiesForItem } from './effect-animatable-properties'
 import { TEXT_ANIMATABLE_PROPERTIES } from './animated-text-item'
+import { CORNER_PIN_ANIMATABLE_PROPERTIES } from './animated-corner-pin-resolver'
 
 const VISUAL_ANIMATABLE_PROPERTIES: AnimatableProperty[] = [
   'x',
@@ -25,17 +26,23 @@ const VIDEO_ANIMATABLE_PROPERTIES: AnimatableProperty[] = [
 
 const AUDIO_ANIMATABLE_PROPERTIES: AnimatableProperty[] = ['volume']
 
+const TIME_ANIMATABLE_PROPERTIES: AnimatableProperty[] = ['timeRemap']
+
 export function getAnimatablePropertiesForItem(item: TimelineItem): AnimatableProperty[] {
   const effectProperties = getAnimatableEffectPropertiesForItem(item)
+  // Corner pin offsets only show up once the item has a pin to animate.
+  const cornerPinProperties = item.cornerPin ? CORNER_PIN_ANIMATABLE_PROPERTIES : []
 
   switch (item.type) {
     case 'audio':
-      return [...AUDIO_ANIMATABLE_PROPERTIES, ...effectProperties]
+      return [...AUDIO_ANIMATABLE_PROPERTIES, ...TIME_ANIMATABLE_PROPERTIES, ...effectProperties]
     case 'video':
       return [
         ...VISUAL_ANIMATABLE_PROPERTIES,
         ...VIDEO_ANIMATABLE_PROPERTIES,
+        ...cornerPinProperties,
         ...AUDIO_ANIMATABLE_PROPERTIES,
+        ...TIME_ANIMATABLE_PROPERTIES,
         ...effectProperties,
       ]
     case 'composition':
@@ -43,12 +50,18 @@ export function getAnimatablePropertiesForItem(item: TimelineItem): AnimatablePr
         ...VISUAL_ANIMATABLE_PROPERTIES,
         'anchorX',
         'anchorY',
+        ...cornerPinProperties,
         ...AUDIO_ANIMATABLE_PROPERTIES,
         ...effectProperties,
       ]
     case 'text':
-      return [...VISUAL_ANIMATABLE_PROPERTIES, ...TEXT_ANIMATABLE_PROPERTIES, ...effectProperties]
+      return [
+        ...VISUAL_ANIMATABLE_PROPERTIES,
+        ...TEXT_ANIMATABLE_PROPERTIES,
+        ...cornerPinProperties,
+        ...effectProperties,
+      ]
     default:
-      return [...VISUAL_ANIMATABLE_PROPERTIES, ...effectProperties]
+      return [...VISUAL_ANIMATABLE_PROPERTIES, ...cornerPinProperties, ...effectProperties]
   }
 }
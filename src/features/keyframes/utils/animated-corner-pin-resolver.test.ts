@@ -0,0 +1,83 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { ItemKeyframes } from '@/types/keyframe'
+import type { TimelineItemCornerPin } from '@/types/timeline'
+import { getCornerPinPropertyValue, resolveAnimatedCornerPin } from './animated-corner-pin-resolver'
+
+const BASE_PIN: TimelineItemCornerPin = {
+  topLeft: [4, 2],
+  topRight: [0, 0],
+  bottomRight: [-6, 0],
+  bottomLeft: [0, 8],
+  referenceWidth: 640,
+  referenceHeight: 360,
+}
+
+describe('resolveAnimatedCornerPin', () => {
+  it('returns the stored pin when no corner is keyframed', () => {
+    const itemKeyframes: ItemKeyframes = {
+      itemId: 'video-1',
+      properties: [
+        {
+          property: 'x',
+          keyframes: [{ id: 'x-1', frame: 0, value: 10, easing: 'linear' }],
+        },
+      ],
+    }
+
+    expect(resolveAnimatedCornerPin(BASE_PIN, itemKeyframes, 5)).toBe(BASE_PIN)
+    expect(resolveAnimatedCornerPin(BASE_PIN, undefined, 5)).toBe(BASE_PIN)
+  })
+
+  it('interpolates keyframed corners and keeps the other offsets and reference size', () => {
+    const itemKeyframes: ItemKeyframes = {
+      itemId: 'video-1',
+      properties: [
+        {
+          property: 'cornerPinTopRightX',
+          keyframes: [
+            { id: 'trx-1', frame: 0, value: 0, easing: 'linear' },
+            { id: 'trx-2', frame: 10, value: 40, easing: 'linear' },
+          ],
+        },
+        {
+          property: 'cornerPinBottomLeftY',
+          keyframes: [
+            { id: 'bly-1', frame: 0, value: 8, easing: 'linear' },
+            { id: 'bly-2', frame: 10, value: -12, easing: 'linear' },
+          ],
+        },
+      ],
+    }
+
+    const resolved = resolveAnimatedCornerPin(BASE_PIN, itemKeyframes, 5)
+
+    expect(resolved?.topRight[0]).toBeCloseTo(20)
+    expect(resolved?.bottomLeft[1]).toBeCloseTo(-2)
+    expect(resolved?.topLeft).toEqual([4, 2])
+    expect(resolved?.bottomRight).toEqual([-6, 0])
+    expect(resolved?.referenceWidth).toBe(640)
+    expect(BASE_PIN.topRight).toEqual([0, 0])
+    expect(getCornerPinPropertyValue(resolved, 'cornerPinTopRightX')).toBeCloseTo(20)
+  })
+
+  it('creates a pin from zero offsets when the item has none stored', () => {
+    const itemKeyframes: ItemKeyframes = {
+      itemId: 'image-1',
+      properties: [
+        {
+          property: 'cornerPinTopLeftY',
+          keyframes: [{ id: 'tly-1', frame: 0, value: 15, easing: 'linear' }],
+        },
+      ],
+    }
+
+    expect(resolveAnimatedCornerPin(undefined, itemKeyframes, 0)).toEqual({
+      topLeft: [0, 15],
+      topRight: [0, 0],
+      bottomRight: [0, 0],
+      bottomLeft: [0, 0],
+      referenceWidth: undefined,
+      referenceHeight: undefined,
+    })
+  })
+})
@@ -0,0 +1,79 @@
+import type { TimelineItemCornerPin } from '@/types/timeline'
+import type { CornerPinAnimatableProperty, ItemKeyframes } from '@/types/keyframe'
+import { getPropertyKeyframes, interpolatePropertyValue } from './interpolation'
+
+type CornerPinCorner = 'topLeft' | 'topRight' | 'bottomRight' | 'bottomLeft'
+
+export const CORNER_PIN_ANIMATABLE_PROPERTIES: CornerPinAnimatableProperty[] = [
+  'cornerPinTopLeftX',
+  'cornerPinTopLeftY',
+  'cornerPinTopRightX',
+  'cornerPinTopRightY',
+  'cornerPinBottomRightX',
+  'cornerPinBottomRightY',
+  'cornerPinBottomLeftX',
+  'cornerPinBottomLeftY',
+]
+
+const CORNER_PIN_PROPERTY_TARGETS: Record<CornerPinAnimatableProperty, [CornerPinCorner, 0 | 1]> = {
+  cornerPinTopLeftX: ['topLeft', 0],
+  cornerPinTopLeftY: ['topLeft', 1],
+  cornerPinTopRightX: ['topRight', 0],
+  cornerPinTopRightY: ['topRight', 1],
+  cornerPinBottomRightX: ['bottomRight', 0],
+  cornerPinBottomRightY: ['bottomRight', 1],
+  cornerPinBottomLeftX: ['bottomLeft', 0],
+  cornerPinBottomLeftY: ['bottomLeft', 1],
+}
+
+function copyOffset(offset: [number, number] | undefined): [number, number] {
+  return [offset?.[0] ?? 0, offset?.[1] ?? 0]
+}
+
+export function isCornerPinAnimatableProperty(
+  property: string,
+): property is CornerPinAnimatableProperty {
+  return property in CORNER_PIN_PROPERTY_TARGETS
+}
+
+export function getCornerPinPropertyValue(
+  cornerPin: TimelineItemCornerPin | undefined,
+  property: CornerPinAnimatableProperty,
+): number {
+  const [corner, axis] = CORNER_PIN_PROPERTY_TARGETS[property]
+  return cornerPin?.[corner][axis] ?? 0
+}
+
+/**
+ * Resolve corner pin offsets at an item-relative frame. Keyframed values are
+ * in the same reference pixel space as the stored pin, so the result still
+ * scales through `resolveCornerPinForSize`. Returns the input pin unchanged
+ * when no corner is animated.
+ */
+export function resolveAnimatedCornerPin(
+  cornerPin: TimelineItemCornerPin | undefined,
+  itemKeyframes: ItemKeyframes | undefined,
+  frame: number,
+): TimelineItemCornerPin | undefined {
+  if (!itemKeyframes) return cornerPin
+
+  let resolved: TimelineItemCornerPin | undefined
+  for (const property of CORNER_PIN_ANIMATABLE_PROPERTIES) {
+    const keyframes = getPropertyKeyframes(itemKeyframes, property)
+    if (keyframes.length === 0) continue
+
+    resolved ??= {
+      topLeft: copyOffset(cornerPin?.topLeft),
+      topRight: copyOffset(cornerPin?.topRight),
+      bottomRight: copyOffset(cornerPin?.bottomRight),
+      bottomLeft: copyOffset(cornerPin?.bottomLeft),
+      referenceWidth: cornerPin?.referenceWidth,
+      referenceHeight: cornerPin?.referenceHeight,
+    }
+    const [corner, axis] = CORNER_PIN_PROPERTY_TARGETS[property]
+    const baseValue = getCornerPinPropertyValue(cornerPin, property)
+    resolved[corner][axis] = interpolatePropertyValue(keyframes, frame, baseValue)
+  }
+
+  return resolved ?? cornerPin
+}
@@ -0,0 +1,110 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { MaskPathKeyframe, MaskVertex } from '@/types/masks'
+import type { ShapeItem } from '@/types/timeline'
+import {
+  interpolateMaskPath,
+  removeMaskPathKeyframe,
+  resampleMaskPath,
+  resolveAnimatedMaskShape,
+  resolveMaskPathAtFrame,
+  setMaskPathKeyframe,
+} from './animated-mask-path'
+
+function corner(x: number, y: number, feather?: number): MaskVertex {
+  return feather === undefined
+    ? { position: [x, y], inHandle: [0, 0], outHandle: [0, 0] }
+    : { position: [x, y], inHandle: [0, 0], outHandle: [0, 0], feather }
+}
+
+const TRIANGLE: MaskVertex[] = [corner(0, 0), corner(1, 0), corner(0, 1)]
+const SQUARE: MaskVertex[] = [corner(0, 0), corner(1, 0), corner(1, 1), corner(0, 1)]
+
+function keyframe(frame: number, vertices: MaskVertex[]): MaskPathKeyframe {
+  return { id: `kf-${frame}`, frame, vertices, easing: 'linear' }
+}
+
+describe('resampleMaskPath', () => {
+  it('splits the longest segment without moving existing vertices', () => {
+    const resampled = resampleMaskPath(TRIANGLE, 4)
+
+    expect(resampled).toHaveLength(4)
+    // The hypotenuse (1,0) -> (0,1) is longest, so the new vertex is its midpoint.
+    expect(resampled.map((vertex) => vertex.position)).toEqual([
+      [0, 0],
+      [1, 0],
+      [0.5, 0.5],
+      [0, 1],
+    ])
+  })
+
+  it('interpolates per-vertex feather onto inserted vertices', () => {
+    const resampled = resampleMaskPath([corner(0, 0, 2), corner(4, 0, 10), corner(0, 1, 2)], 4)
+
+    expect(resampled[2]?.feather).toBe(6)
+  })
+
+  it('returns the input when it already has enough vertices', () => {
+    expect(resampleMaskPath(SQUARE, 3)).toBe(SQUARE)
+  })
+})
+
+describe('interpolateMaskPath', () => {
+  it('blends positions by index for matching vertex counts', () => {
+    const moved = SQUARE.map((vertex) => corner(vertex.position[0] + 2, vertex.position[1]))
+
+    expect(interpolateMaskPath(SQUARE, moved, 0.5)[2]?.position).toEqual([2, 1])
+  })
+
+  it('resamples paths with different vertex counts', () => {
+    const result = interpolateMaskPath(TRIANGLE, SQUARE, 0.5)
+
+    expect(result).toHaveLength(4)
+    expect(result[2]?.position).toEqual([0.75, 0.75])
+  })
+})
+
+describe('resolveMaskPathAtFrame', () => {
+  const wide = SQUARE.map((vertex) => corner(vertex.position[0] * 3, vertex.position[1]))
+  const keyframes = [keyframe(0, SQUARE), keyframe(10, wide)]
+
+  it('clamps outside the keyframed range', () => {
+    expect(resolveMaskPathAtFrame(keyframes, -5)).toBe(SQUARE)
+    expect(resolveMaskPathAtFrame(keyframes, 20)).toBe(wide)
+  })
+
+  it('eases between keyframes', () => {
+    expect(resolveMaskPathAtFrame(keyframes, 5)?.[1]?.position).toEqual([2, 0])
+  })
+
+  it('holds the previous path for hold easing', () => {
+    const held = [{ ...keyframes[0]!, easing: 'hold' as const }, keyframes[1]!]
+
+    expect(resolveMaskPathAtFrame(held, 9)).toBe(SQUARE)
+  })
+})
+
+describe('resolveAnimatedMaskShape', () => {
+  it('replaces the path vertices of animated path shapes', () => {
+    const shape = {
+      type: 'shape',
+      shapeType: 'path',
+      pathVertices: TRIANGLE,
+      pathKeyframes: [keyframe(0, SQUARE)],
+    } as ShapeItem
+
+    expect(resolveAnimatedMaskShape(shape, 3).pathVertices).toBe(SQUARE)
+  })
+})
+
+describe('setMaskPathKeyframe', () => {
+  it('inserts sorted and updates in place by frame', () => {
+    const first = setMaskPathKeyframe(undefined, 10, SQUARE)
+    const second = setMaskPathKeyframe(first, 0, TRIANGLE)
+    const updated = setMaskPathKeyframe(second, 10, TRIANGLE)
+
+    expect(second.map((entry) => entry.frame)).toEqual([0, 10])
+    expect(updated[1]?.id).toBe(first[0]!.id)
+    expect(updated[1]?.vertices).toBe(TRIANGLE)
+    expect(removeMaskPathKeyframe(updated, 0).map((entry) => entry.frame)).toEqual([10])
+  })
+})
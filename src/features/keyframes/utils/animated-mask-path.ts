@@ -0,0 +1,222 @@
+import type { EasingType } from '@/types/keyframe'
+import type { MaskPathKeyframe, MaskVertex } from '@/types/masks'
+import type { ShapeItem } from '@/types/timeline'
+import { applyEasing, applyEasingConfig } from './easing'
+
+type Point = [number, number]
+
+function lerp(a: number, b: number, t: number): number {
+  return a + (b - a) * t
+}
+
+function lerpPoint(a: Point, b: Point, t: number): Point {
+  return [lerp(a[0], b[0], t), lerp(a[1], b[1], t)]
+}
+
+function addPoint(a: Point, b: Point): Point {
+  return [a[0] + b[0], a[1] + b[1]]
+}
+
+function subtractPoint(a: Point, b: Point): Point {
+  return [a[0] - b[0], a[1] - b[1]]
+}
+
+function distance(a: Point, b: Point): number {
+  return Math.hypot(b[0] - a[0], b[1] - a[1])
+}
+
+function lerpFeather(a: MaskVertex, b: MaskVertex, t: number): number | undefined {
+  if (a.feather === undefined && b.feather === undefined) return undefined
+  return lerp(a.feather ?? b.feather!, b.feather ?? a.feather!, t)
+}
+
+function withFeather(vertex: Omit<MaskVertex, 'feather'>, feather: number | undefined): MaskVertex {
+  return feather === undefined ? vertex : { ...vertex, feather }
+}
+
+/** Approximate arc length of the closed-path segment from `a` to `b`. */
+function getSegmentLength(a: MaskVertex, b: MaskVertex): number {
+  const p0 = a.position
+  const p1 = addPoint(a.position, a.outHandle)
+  const p2 = addPoint(b.position, b.inHandle)
+  const p3 = b.position
+  const chord = distance(p0, p3)
+  const polygon = distance(p0, p1) + distance(p1, p2) + distance(p2, p3)
+  return (chord + polygon) / 2
+}
+
+/**
+ * Split the cubic segment from `a` to `b` at its midpoint (de Casteljau).
+ * The returned vertices replace `a` and `b` and keep the curve's shape.
+ */
+function splitSegment(a: MaskVertex, b: MaskVertex): [MaskVertex, MaskVertex, MaskVertex] {
+  const p0 = a.position
+  const p1 = addPoint(a.position, a.outHandle)
+  const p2 = addPoint(b.position, b.inHandle)
+  const p3 = b.position
+  const q0 = lerpPoint(p0, p1, 0.5)
+  const q1 = lerpPoint(p1, p2, 0.5)
+  const q2 = lerpPoint(p2, p3, 0.5)
+  const r0 = lerpPoint(q0, q1, 0.5)
+  const r1 = lerpPoint(q1, q2, 0.5)
+  const mid = lerpPoint(r0, r1, 0.5)
+
+  return [
+    { ...a, outHandle: subtractPoint(q0, p0) },
+    withFeather(
+      { position: mid, inHandle: subtractPoint(r0, mid), outHandle: subtractPoint(r1, mid) },
+      lerpFeather(a, b, 0.5),
+    ),
+    { ...b, inHandle: subtractPoint(q2, p3) },
+  ]
+}
+
+/**
+ * Add vertices to a closed path until it has `count` of them, splitting the
+ * longest segment each time so the drawn shape is unchanged.
+ */
+export function resampleMaskPath(vertices: MaskVertex[], count: number): MaskVertex[] {
+  if (vertices.length === 0 || vertices.length >= count) return vertices
+  const next = [...vertices]
+
+  while (next.length < count) {
+    let longest = 0
+    let longestLength = -1
+    for (let i = 0; i < next.length; i++) {
+      const length = getSegmentLength(next[i]!, next[(i + 1) % next.length]!)
+      if (length > longestLength) {
+        longest = i
+        longestLength = length
+      }
+    }
+
+    const nextIndex = (longest + 1) % next.length
+    const [start, mid, end] = splitSegment(next[longest]!, next[nextIndex]!)
+    next[longest] = start
+    next[nextIndex] = end
+    next.splice(longest + 1, 0, mid)
+  }
+
+  return next
+}
+
+/**
+ * Rotate `vertices` so its first vertex lines up with `reference`'s, picking
+ * the start that moves vertices the least. Resampled paths have no natural
+ * correspondence, so this keeps them from twisting mid-interpolation.
+ */
+function alignPathStart(vertices: MaskVertex[], reference: MaskVertex[]): MaskVertex[] {
+  let bestOffset = 0
+  let bestCost = Number.POSITIVE_INFINITY
+  for (let offset = 0; offset < vertices.length; offset++) {
+    let cost = 0
+    for (let i = 0; i < reference.length; i++) {
+      const vertex = vertices[(i + offset) % vertices.length]!
+      const [dx, dy] = subtractPoint(vertex.position, reference[i]!.position)
+      cost += dx * dx + dy * dy
+    }
+    if (cost < bestCost) {
+      bestCost = cost
+      bestOffset = offset
+    }
+  }
+  return bestOffset === 0
+    ? vertices
+    : [...vertices.slice(bestOffset), ...vertices.slice(0, bestOffset)]
+}
+
+/**
+ * Interpolate two closed paths. Vertices are matched by index; when the
+ * vertex counts differ the shorter path is subdivided to match first.
+ */
+export function interpolateMaskPath(
+  from: MaskVertex[],
+  to: MaskVertex[],
+  t: number,
+): MaskVertex[] {
+  if (from.length === 0 || t <= 0) return from
+  if (to.length === 0 || t >= 1) return to
+
+  let a = from
+  let b = to
+  if (a.length !== b.length) {
+    const count = Math.max(a.length, b.length)
+    a = resampleMaskPath(a, count)
+    b = alignPathStart(resampleMaskPath(b, count), a)
+  }
+
+  return a.map((vertex, index) => {
+    const target = b[index]!
+    return withFeather(
+      {
+        position: lerpPoint(vertex.position, target.position, t),
+        inHandle: lerpPoint(vertex.inHandle, target.inHandle, t),
+        outHandle: lerpPoint(vertex.outHandle, target.outHandle, t),
+      },
+      lerpFeather(vertex, target, t),
+    )
+  })
+}
+
+function getSortedKeyframes(keyframes: MaskPathKeyframe[]): MaskPathKeyframe[] {
+  return keyframes.every((keyframe, i) => i === 0 || keyframes[i - 1]!.frame <= keyframe.frame)
+    ? keyframes
+    : [...keyframes].sort((a, b) => a.frame - b.frame)
+}
+
+/** Path vertices at an item-relative (possibly fractional) frame. */
+export function resolveMaskPathAtFrame(
+  keyframes: MaskPathKeyframe[],
+  frame: number,
+): MaskVertex[] | undefined {
+  if (keyframes.length === 0) return undefined
+  const sorted = getSortedKeyframes(keyframes)
+  const first = sorted[0]!
+  const last = sorted[sorted.length - 1]!
+  if (frame <= first.frame) return first.vertices
+  if (frame >= last.frame) return last.vertices
+
+  const nextIndex = sorted.findIndex((keyframe) => keyframe.frame > frame)
+  const previous = sorted[nextIndex - 1]!
+  const next = sorted[nextIndex]!
+  if (previous.easing === 'hold') return previous.vertices
+
+  const progress = (frame - previous.frame) / (next.frame - previous.frame)
+  const eased = previous.easingConfig
+    ? applyEasingConfig(progress, previous.easingConfig)
+    : applyEasing(progress, previous.easing)
+  return interpolateMaskPath(previous.vertices, next.vertices, eased)
+}
+
+/** The shape with its path resolved at an item-relative frame. */
+export function resolveAnimatedMaskShape<TShape extends ShapeItem>(
+  shape: TShape,
+  frame: number,
+): TShape {
+  if (shape.shapeType !== 'path' || !shape.pathKeyframes?.length) return shape
+  const pathVertices = resolveMaskPathAtFrame(shape.pathKeyframes, frame)
+  return pathVertices ? { ...shape, pathVertices } : shape
+}
+
+/** Insert or replace the path keyframe at `frame`, keeping the list sorted. */
+export function setMaskPathKeyframe(
+  keyframes: MaskPathKeyframe[] | undefined,
+  frame: number,
+  vertices: MaskVertex[],
+  easing: EasingType = 'linear',
+): MaskPathKeyframe[] {
+  const existing = keyframes?.find((keyframe) => keyframe.frame === frame)
+  const keyframe: MaskPathKeyframe = existing
+    ? { ...existing, vertices }
+    : { id: crypto.randomUUID(), frame, vertices, easing }
+  return [...(keyframes ?? []).filter((entry) => entry.frame !== frame), keyframe].sort(
+    (a, b) => a.frame - b.frame,
+  )
+}
+
+export function removeMaskPathKeyframe(
+  keyframes: MaskPathKeyframe[] | undefined,
+  frame: number,
+): MaskPathKeyframe[] {
+  return (keyframes ?? []).filter((keyframe) => keyframe.frame !== frame)
+}
@@ -0,0 +1,103 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { ItemKeyframes, Keyframe } from '@/types/keyframe'
+import type { VideoItem } from '@/types/timeline'
+import {
+  getItemNaturalSourceSeconds,
+  getTimeRemapRate,
+  getTimeRemapSourceRange,
+  hasTimeRemap,
+  resolveTimeRemapCurve,
+  sampleTimeRemap,
+} from './time-remap'
+
+const FPS = 30
+
+function createVideo(overrides: Partial<VideoItem> = {}): VideoItem {
+  return {
+    id: 'video-1',
+    type: 'video',
+    trackId: 'track-1',
+    from: 100,
+    durationInFrames: 90,
+    label: 'clip.mp4',
+    src: 'blob:clip',
+    mediaId: 'media-1',
+    sourceStart: 60,
+    sourceEnd: 150,
+    sourceDuration: 300,
+    sourceFps: 30,
+    ...overrides,
+  } as VideoItem
+}
+
+function remapKeyframes(keyframes: Keyframe[]): ItemKeyframes {
+  return {
+    itemId: 'video-1',
+    properties: [{ property: 'timeRemap', keyframes }],
+  }
+}
+
+describe('getItemNaturalSourceSeconds', () => {
+  it('follows speed forwards and from the source end when reversed', () => {
+    expect(getItemNaturalSourceSeconds(createVideo(), 30, FPS)).toBeCloseTo(3)
+    expect(getItemNaturalSourceSeconds(createVideo({ speed: 2 }), 30, FPS)).toBeCloseTo(4)
+    expect(getItemNaturalSourceSeconds(createVideo({ isReversed: true }), 30, FPS)).toBeCloseTo(4)
+  })
+})
+
+describe('resolveTimeRemapCurve', () => {
+  it('needs at least two keyframes', () => {
+    const single = remapKeyframes([{ id: 'a', frame: 0, value: 2, easing: 'linear' }])
+    expect(hasTimeRemap(single.properties[0]!.keyframes)).toBe(false)
+    expect(resolveTimeRemapCurve(createVideo(), single, FPS)).toBeNull()
+  })
+
+  it('maps frames through the eased curve and extrapolates at the natural rate', () => {
+    const curve = resolveTimeRemapCurve(
+      createVideo(),
+      remapKeyframes([
+        { id: 'a', frame: 10, value: 2, easing: 'linear' },
+        { id: 'b', frame: 40, value: 3, easing: 'linear' },
+      ]),
+      FPS,
+    )!
+
+    expect(sampleTimeRemap(curve, 25)).toBeCloseTo(2.5)
+    expect(sampleTimeRemap(curve, 0)).toBeCloseTo(2 - 10 / FPS)
+    expect(sampleTimeRemap(curve, 70)).toBeCloseTo(4)
+    expect(getTimeRemapRate(curve, 25, FPS)).toBeCloseTo(1)
+    expect(getTimeRemapRate(curve, 60, FPS)).toBeCloseTo(1)
+  })
+
+  it('reports negative rates, freezes and the touched source range', () => {
+    const curve = resolveTimeRemapCurve(
+      createVideo(),
+      remapKeyframes([
+        { id: 'a', frame: 0, value: 5, easing: 'linear' },
+        { id: 'b', frame: 30, value: 4, easing: 'linear' },
+        { id: 'c', frame: 60, value: 4, easing: 'linear' },
+      ]),
+      FPS,
+    )!
+
+    expect(getTimeRemapRate(curve, 15, FPS)).toBeCloseTo(-1)
+    expect(getTimeRemapRate(curve, 45, FPS)).toBeCloseTo(0)
+    const range = getTimeRemapSourceRange(curve, 0, 89)
+    expect(range.start).toBeCloseTo(4)
+    expect(range.end).toBeCloseTo(5)
+  })
+
+  it('clamps to the media length', () => {
+    const curve = resolveTimeRemapCurve(
+      createVideo(),
+      remapKeyframes([
+        { id: 'a', frame: 0, value: 0, easing: 'linear' },
+        { id: 'b', frame: 30, value: 20, easing: 'linear' },
+      ]),
+      FPS,
+    )!
+
+    expect(sampleTimeRemap(curve, 30)).toBe(10)
+    expect(sampleTimeRemap(curve, -30)).toBe(0)
+  })
+})
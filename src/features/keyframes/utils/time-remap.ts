@@ -0,0 +1,142 @@
+/**
+ * Time remapping: a keyframed `timeRemap` curve maps item-relative timeline
+ * frames to source media time (seconds). The curve's easing gives speed ramps;
+ * a falling curve plays backwards and a flat segment freezes. Outside the
+ * keyframed range the clip keeps playing at its natural rate, so dropping two
+ * keyframes on the untouched curve never changes playback.
+ *
+ * Pure, no store access — preview, audio render, filmstrips and export all
+ * resolve the same curve through these helpers.
+ */
+
+import type { ItemKeyframes, Keyframe } from '@/types/keyframe'
+import type { TimelineItem } from '@/types/timeline'
+import { clamp } from './animation-easing'
+import { getPropertyKeyframes, interpolatePropertyValue } from './interpolation'
+
+export interface TimeRemapCurve {
+  keyframes: Keyframe[]
+  /** Source seconds advanced per timeline frame outside the keyframed range. */
+  naturalSecondsPerFrame: number
+  /** Upper clamp for resolved source time, when the media length is known. */
+  maxSourceSeconds: number
+}
+
+/** Fewer than two keyframes cannot describe a remap, so the clip plays as usual. */
+export function hasTimeRemap(keyframes: readonly Keyframe[] | undefined): boolean {
+  return (keyframes?.length ?? 0) >= 2
+}
+
+export function getTimeRemapKeyframes(itemKeyframes: ItemKeyframes | undefined): Keyframe[] {
+  return getPropertyKeyframes(itemKeyframes, 'timeRemap')
+}
+
+function getItemSourceFps(item: TimelineItem, fps: number): number {
+  if (item.type !== 'video' && item.type !== 'audio') return fps
+  return item.sourceFps && item.sourceFps > 0 ? item.sourceFps : fps
+}
+
+function getNaturalSecondsPerFrame(item: TimelineItem, fps: number): number {
+  if (item.type !== 'video' && item.type !== 'audio') return 1 / fps
+  const secondsPerFrame = (item.speed ?? 1) / fps
+  return item.isReversed ? -secondsPerFrame : secondsPerFrame
+}
+
+/**
+ * Source time (seconds) the item shows at `itemFrame` without a remap —
+ * the base value the graph editor offers for new `timeRemap` keyframes.
+ */
+export function getItemNaturalSourceSeconds(
+  item: TimelineItem,
+  itemFrame: number,
+  fps: number,
+): number {
+  if (item.type !== 'video' && item.type !== 'audio') return Math.max(0, itemFrame / fps)
+  const sourceFps = getItemSourceFps(item, fps)
+  const speed = item.speed ?? 1
+  const sourceStartSeconds = (item.sourceStart ?? 0) / sourceFps
+  if (item.isReversed) {
+    const sourceEndSeconds =
+      item.sourceEnd !== undefined
+        ? item.sourceEnd / sourceFps
+        : sourceStartSeconds + (item.durationInFrames * speed) / fps
+    return Math.max(0, sourceEndSeconds - (itemFrame * speed) / fps)
+  }
+  return sourceStartSeconds + (itemFrame * speed) / fps
+}
+
+/** Remap curve built from `timeRemap` keyframes, or null when they don't describe one. */
+export function createTimeRemapCurve(
+  item: TimelineItem,
+  keyframes: Keyframe[],
+  fps: number,
+): TimeRemapCurve | null {
+  if (item.type !== 'video' && item.type !== 'audio') return null
+  if (!hasTimeRemap(keyframes)) return null
+  const sourceFps = getItemSourceFps(item, fps)
+  return {
+    keyframes,
+    naturalSecondsPerFrame: getNaturalSecondsPerFrame(item, fps),
+    maxSourceSeconds:
+      item.sourceDuration !== undefined && item.sourceDuration > 0
+        ? item.sourceDuration / sourceFps
+        : Number.POSITIVE_INFINITY,
+  }
+}
+
+/** Active remap curve for an item, or null when the item plays at its constant speed. */
+export function resolveTimeRemapCurve(
+  item: TimelineItem,
+  itemKeyframes: ItemKeyframes | undefined,
+  fps: number,
+): TimeRemapCurve | null {
+  return createTimeRemapCurve(item, getTimeRemapKeyframes(itemKeyframes), fps)
+}
+
+/** Source time (seconds) shown at an item-relative timeline frame (fractional frames allowed). */
+export function sampleTimeRemap(curve: TimeRemapCurve, itemFrame: number): number {
+  const first = curve.keyframes[0]!
+  const last = curve.keyframes[curve.keyframes.length - 1]!
+  let seconds: number
+  if (itemFrame < first.frame) {
+    seconds = first.value - (first.frame - itemFrame) * curve.naturalSecondsPerFrame
+  } else if (itemFrame > last.frame) {
+    seconds = last.value + (itemFrame - last.frame) * curve.naturalSecondsPerFrame
+  } else {
+    seconds = interpolatePropertyValue(curve.keyframes, itemFrame, first.value)
+  }
+  return clamp(seconds, 0, curve.maxSourceSeconds)
+}
+
+/**
+ * Instantaneous playback rate (source seconds per timeline second) at an
+ * item frame. Negative while the curve plays backwards, 0 on a freeze.
+ */
+export function getTimeRemapRate(curve: TimeRemapCurve, itemFrame: number, fps: number): number {
+  const before = sampleTimeRemap(curve, itemFrame - 0.5)
+  const after = sampleTimeRemap(curve, itemFrame + 0.5)
+  return (after - before) * fps
+}
+
+/**
+ * Source time range (seconds) the curve touches between two item frames,
+ * sampled per frame so eased overshoots are included.
+ */
+export function getTimeRemapSourceRange(
+  curve: TimeRemapCurve,
+  fromFrame: number,
+  toFrame: number,
+): { start: number; end: number } {
+  let start = Number.POSITIVE_INFINITY
+  let end = Number.NEGATIVE_INFINITY
+  for (let frame = fromFrame; frame <= toFrame; frame++) {
+    const seconds = sampleTimeRemap(curve, frame)
+    start = Math.min(start, seconds)
+    end = Math.max(end, seconds)
+  }
+  const last = sampleTimeRemap(curve, toFrame)
+  return {
+    start: Math.min(start, last),
+    end: Math.max(end, last),
+  }
+}
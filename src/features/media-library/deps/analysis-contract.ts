@@ -17,3 +17,10 @@ export {
   buildEmbeddingText,
   extractDominantColors,
 } from '@/infrastructure/analysis/embeddings'
+export {
+  buildDiarizationWindows,
+  DIARIZATION_SAMPLE_RATE,
+  diarizationProvider,
+  resolveSegmentSpeakerLabels,
+} from '@/infrastructure/analysis/diarization'
+export type { DiarizationProgress } from '@/infrastructure/analysis/diarization'
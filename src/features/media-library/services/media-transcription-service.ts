@@ -8,16 +8,21 @@ import { usePlaybackStore } from '@/shared/state/playback'
 import { useSelectionStore } from '@/shared/state/selection'
 import { createLogger } from '@/shared/logging/logger'
 import { DEFAULT_PROJECT_HEIGHT, DEFAULT_PROJECT_WIDTH } from '@/shared/projects/defaults'
-import type { MediaTranscript, MediaTranscriptModel, MediaTranscriptSegment } from '@/types/storage'
+import type {
+  MediaTranscript,
+  MediaTranscriptModel,
+  MediaTranscriptSegment,
+  MediaTranscriptSpeaker,
+} from '@/types/storage'
 import type {
   AudioItem,
   SubtitleSegmentItem,
-  TimelineTranscriptCaptionCue,
   TimelineItem,
   TimelineTrack,
   VideoItem,
 } from '@/types/timeline'
 import type { TranscriptSegment, TranscribeOptions } from '../transcription/types'
+import { downmixToMono, resampleTo16kHz } from '../transcription/lib/resampler'
 import {
   getDefaultMediaTranscriptionAdapter,
   getMediaTranscriptionModelLabel,
@@ -25,6 +30,7 @@ import {
 import { importMediaLibraryService } from './media-library-service-loader'
 import {
   buildSubtitleSegmentForClip,
+  buildTranscriptCaptionCues,
   getCaptionStyleTemplateFromPreset,
   buildCaptionTrackAbove,
   type CaptionTextItemTemplate,
@@ -51,6 +57,17 @@ import {
   normalizeWhisperLanguage,
 } from '@/shared/utils/whisper-settings'
 import { TRANSCRIPTION_CANCELLED_MESSAGE } from '@/shared/utils/transcription-cancellation'
+import {
+  buildSubtitleSpeakerStyles,
+  createTranscriptSpeaker,
+} from '@/shared/utils/subtitle-speakers'
+import {
+  buildDiarizationWindows,
+  DIARIZATION_SAMPLE_RATE,
+  diarizationProvider,
+  resolveSegmentSpeakerLabels,
+  type DiarizationProgress,
+} from '../deps/analysis-contract'
 
 const logger = createLogger('MediaTranscriptionService')
 const DEFAULT_MODEL: MediaTranscriptModel = DEFAULT_WHISPER_MODEL
@@ -79,6 +96,13 @@ interface EnableTranscriptCaptionsResult {
   removedItemCount: number
 }
 
+interface DiarizeTranscriptOptions {
+  /** Cap on distinct voices when the user knows how many people speak */
+  maxSpeakers?: number
+  onProgress?: (progress: DiarizationProgress) => void
+  signal?: AbortSignal
+}
+
 function definedCaptionStyleFields(
   template: Partial<CaptionTextItemTemplate> | undefined,
 ): Partial<CaptionTextItemTemplate> {
@@ -223,6 +247,62 @@ function segmentTranscriptForCaptions(segments: TranscriptSegment[]): MediaTrans
   return captionSegments.toSorted((left, right) => left.start - right.start)
 }
 
+/**
+ * Stamp per-segment speaker labels onto a transcript. Speakers are numbered
+ * by first appearance so ids stay stable when a transcript is re-diarized.
+ */
+function applySpeakerLabels(
+  transcript: MediaTranscript,
+  labels: ReadonlyArray<number | null>,
+): MediaTranscript {
+  const speakerIndexByLabel = new Map<number, number>()
+  const segments = transcript.segments.map((segment, index) => {
+    const { speakerId: _previousSpeakerId, ...rest } = segment
+    const label = labels[index]
+    if (label === null || label === undefined) return rest
+    let speakerIndex = speakerIndexByLabel.get(label)
+    if (speakerIndex === undefined) {
+      speakerIndex = speakerIndexByLabel.size
+      speakerIndexByLabel.set(label, speakerIndex)
+    }
+    return { ...rest, speakerId: createTranscriptSpeaker(speakerIndex).id }
+  })
+  const speakers = Array.from({ length: speakerIndexByLabel.size }, (_, index) =>
+    createTranscriptSpeaker(index),
+  )
+
+  return {
+    ...transcript,
+    segments,
+    speakers: speakers.length > 0 ? speakers : undefined,
+    updatedAt: Date.now(),
+  }
+}
+
+async function decodeDiarizationAudio(blob: Blob) {
+  const AudioContextClass =
+    window.AudioContext ??
+    (window as Window & { webkitAudioContext?: typeof AudioContext }).webkitAudioContext
+  if (!AudioContextClass) {
+    throw new Error('AudioContext is not available in this browser')
+  }
+
+  const audioContext = new AudioContextClass()
+  try {
+    const audioBuffer = await audioContext.decodeAudioData(await blob.arrayBuffer())
+    const channels: Float32Array[] = []
+    for (let i = 0; i < audioBuffer.numberOfChannels; i++) {
+      channels.push(audioBuffer.getChannelData(i))
+    }
+    return {
+      samples: resampleTo16kHz(downmixToMono(channels), audioBuffer.sampleRate),
+      sampleRate: DIARIZATION_SAMPLE_RATE,
+    }
+  } finally {
+    void audioContext.close()
+  }
+}
+
 class MediaTranscriptionService {
   private readonly adapter = getDefaultMediaTranscriptionAdapter()
   private readonly transcriber = this.adapter.createTranscriber({
@@ -493,6 +573,69 @@ class MediaTranscriptionService {
     return transcript
   }
 
+  /**
+   * Label an existing transcript's segments with speakers. Embeddings and
+   * clustering run in the diarization worker; only the labeled transcript is
+   * persisted, so re-running replaces earlier speaker names and colors.
+   */
+  async diarizeTranscript(
+    mediaId: string,
+    options: DiarizeTranscriptOptions = {},
+  ): Promise<MediaTranscript> {
+    const transcript = await getTranscript(mediaId)
+    if (!transcript) {
+      throw new Error('No transcript found for this media item')
+    }
+
+    const { mediaLibraryService } = await importMediaLibraryService()
+    const media = await mediaLibraryService.getMedia(mediaId)
+    if (!media) {
+      throw new Error(`Media not found: ${mediaId}`)
+    }
+    const sourceBlob = await mediaLibraryService.getMediaFile(mediaId)
+    if (!sourceBlob) {
+      throw new Error(`Could not load media file: ${media.fileName}`)
+    }
+
+    const audio = await decodeDiarizationAudio(
+      await this.resolveTranscriptionBlob(media, sourceBlob),
+    )
+    const windows = buildDiarizationWindows(transcript.segments)
+    const labels = await diarizationProvider.diarize(audio, windows, {
+      maxSpeakers: options.maxSpeakers,
+      onProgress: options.onProgress,
+      signal: options.signal,
+    })
+    const diarized = applySpeakerLabels(
+      transcript,
+      resolveSegmentSpeakerLabels(transcript.segments, windows, labels),
+    )
+
+    await saveTranscript(diarized)
+    this.emitTranscriptChanged(mediaId)
+    logger.info('Diarized transcript', {
+      mediaId,
+      windows: windows.length,
+      speakers: diarized.speakers?.length ?? 0,
+    })
+    return diarized
+  }
+
+  /** Persist renamed or recolored speakers for a diarized transcript. */
+  async updateTranscriptSpeakers(
+    mediaId: string,
+    speakers: MediaTranscriptSpeaker[],
+  ): Promise<MediaTranscript> {
+    const transcript = await getTranscript(mediaId)
+    if (!transcript) {
+      throw new Error('No transcript found for this media item')
+    }
+
+    const updated = await saveTranscript({ ...transcript, speakers, updatedAt: Date.now() })
+    this.emitTranscriptChanged(mediaId)
+    return updated
+  }
+
   private async resolveTranscriptionBlob(
     media: { id: string; fileName: string; mimeType: string; codec: string; audioCodec?: string },
     sourceBlob: Blob,
@@ -592,12 +735,7 @@ class MediaTranscriptionService {
 
       const clipCaptionItem = buildSubtitleSegmentForClip({
         trackId: targetTrack.id,
-        cues: transcript.segments.map((segment, index) => ({
-          id: `transcript-${clip.id}-${index}`,
-          startSeconds: segment.start,
-          endSeconds: segment.end,
-          text: segment.text,
-        })),
+        cues: buildTranscriptCaptionCues(transcript.segments, `transcript-${clip.id}`),
         clip,
         timelineFps: timeline.fps,
         canvasWidth,
@@ -607,10 +745,17 @@ class MediaTranscriptionService {
           type: 'transcript',
           mediaId,
           clipId: clip.id,
+          ...(transcript.language ? { language: transcript.language } : {}),
         },
         styleTemplate: existingGeneratedCaptions[0]
           ? getCaptionTextItemTemplate(existingGeneratedCaptions[0])
           : defaultCaptionTemplate,
+        speakerStyles: buildSubtitleSpeakerStyles(
+          transcript.speakers,
+          existingGeneratedCaptions[0]?.type === 'subtitle'
+            ? existingGeneratedCaptions[0].speakerStyles
+            : undefined,
+        ),
       })
 
       if (!clipCaptionItem) {
@@ -670,14 +815,7 @@ class MediaTranscriptionService {
       canvasWidth,
       canvasHeight,
     )
-    const sourceCues: TimelineTranscriptCaptionCue[] = transcript.segments.map(
-      (segment, index) => ({
-        id: `transcript-${mediaId}-${index}`,
-        startSeconds: segment.start,
-        endSeconds: segment.end,
-        text: segment.text,
-      }),
-    )
+    const sourceCues = buildTranscriptCaptionCues(transcript.segments, `transcript-${mediaId}`)
     const generatedCaptionIdsToRemove = options.replaceExisting
       ? new Set(
           targetClips.flatMap((clip) =>
@@ -708,6 +846,10 @@ class MediaTranscriptionService {
       } as CaptionTextItemTemplate
       const styleTemplate =
         Object.keys(mergedStyleTemplate).length > 0 ? mergedStyleTemplate : undefined
+      const speakerStyles = buildSubtitleSpeakerStyles(
+        transcript.speakers,
+        clip.transcriptCaptions?.speakerStyles,
+      )
 
       timeline.updateItem(clip.id, {
         transcriptCaptions: {
@@ -717,6 +859,7 @@ class MediaTranscriptionService {
           updatedAt: Date.now(),
           cues: sourceCues,
           ...(styleTemplate ? { style: styleTemplate } : {}),
+          ...(speakerStyles ? { speakerStyles } : {}),
         },
       } as Partial<TimelineItem>)
       updatedClipCount += 1
@@ -8,16 +8,27 @@ import {
   extractMatroskaTextSubtitleTracksFromBlob,
   type EmbeddedSubtitleTrack,
 } from '@/shared/utils/matroska-subtitles'
+import { transformFromRegion } from '@/shared/utils/subtitle-document'
+import { parseSubtitleFile } from '@/shared/utils/subtitle-files'
+import { inferSubtitleFormat } from '@/shared/utils/subtitles'
 import { getEmbeddedSubtitleSidecar, saveEmbeddedSubtitleSidecar } from '@/infrastructure/storage'
 import { DEFAULT_PROJECT_HEIGHT, DEFAULT_PROJECT_WIDTH } from '@/shared/projects/defaults'
 import type { MediaMetadata } from '@/types/storage'
-import type { TimelineItem, TimelineTrack } from '@/types/timeline'
+import type {
+  AudioItem,
+  SubtitleSegmentCue,
+  SubtitleSegmentSource,
+  TimelineItem,
+  TimelineTrack,
+  VideoItem,
+} from '@/types/timeline'
 import {
   buildCaptionTrack,
   buildSubtitleSegmentForClip,
   consolidateCaptionTextItemsToSegments,
   findCaptionTargetClipsForMedia,
   findCompatibleCaptionTrackForRanges,
+  type CaptionTextItemTemplate,
 } from '../utils/caption-items'
 
 export interface ExtractEmbeddedSubtitlesResult {
@@ -26,6 +37,22 @@ export interface ExtractEmbeddedSubtitlesResult {
   trackLabel: string
 }
 
+export interface ImportSubtitleFileResult {
+  insertedItemCount: number
+  cueCount: number
+  /** Parse problems and file styling the timeline could not represent. */
+  warnings: string[]
+}
+
+interface SubtitleCueInsertion {
+  cues: readonly SubtitleSegmentCue[]
+  label: string
+  buildSource: (clip: AudioItem | VideoItem) => SubtitleSegmentSource
+  styleTemplate?: CaptionTextItemTemplate
+  /** Remove segments already attached to the target clips first. */
+  replaceExisting: boolean
+}
+
 interface EmbeddedSubtitleScanResult {
   tracks: readonly EmbeddedSubtitleTrack[]
   scannedAt: number
@@ -96,7 +123,21 @@ class SubtitleSidecarService {
     track: EmbeddedSubtitleTrack,
   ): ExtractEmbeddedSubtitlesResult {
     const trackLabel = formatEmbeddedSubtitleTrackLabel(track)
-    const inserted = this.insertSubtitleCuesAsSegmentForMedia(media, track, trackLabel)
+    const inserted = this.insertSubtitleCuesAsSegmentForMedia(media, {
+      cues: track.cues,
+      label: `${media.fileName} — ${trackLabel}`,
+      buildSource: (clip) => ({
+        type: 'embedded-subtitles',
+        mediaId: media.id,
+        clipId: clip.id,
+        trackNumber: track.trackNumber,
+        language: track.language,
+        trackName: track.name,
+        codecId: track.codecId,
+        importedAt: Date.now(),
+      }),
+      replaceExisting: true,
+    })
     return {
       insertedItemCount: inserted,
       cueCount: track.cues.length,
@@ -104,15 +145,43 @@ class SubtitleSidecarService {
     }
   }
 
+  /**
+   * Parse a sidecar subtitle file (SRT, WebVTT, ASS/SSA or TTML) and insert
+   * it as one segment per clip of `media`, styled and positioned the way the
+   * file describes. Cue times are read as source-media time. Existing
+   * segments are kept, so each imported language lands on its own track.
+   */
+  async importSubtitleFile(media: MediaMetadata, file: File): Promise<ImportSubtitleFileResult> {
+    const format = inferSubtitleFormat(file.name)
+    if (!format) throw new Error(`Unsupported subtitle file: ${file.name}`)
+    const { canvasWidth, canvasHeight } = getCanvasSize()
+    const frame = { frameWidth: canvasWidth, frameHeight: canvasHeight }
+    const parsed = parseSubtitleFile(await file.text(), format, frame)
+
+    const styleTemplate: CaptionTextItemTemplate = {
+      ...parsed.style,
+      ...(parsed.karaoke ? { karaoke: parsed.karaoke } : {}),
+      ...(parsed.region
+        ? { transform: { ...transformFromRegion(parsed.region, frame), rotation: 0, opacity: 1 } }
+        : {}),
+    }
+    const importedAt = Date.now()
+    const inserted = this.insertSubtitleCuesAsSegmentForMedia(media, {
+      cues: parsed.cues,
+      label: file.name,
+      buildSource: () => ({ type: 'subtitle-import', fileName: file.name, format, importedAt }),
+      styleTemplate,
+      replaceExisting: false,
+    })
+    return { insertedItemCount: inserted, cueCount: parsed.cues.length, warnings: parsed.warnings }
+  }
+
   private insertSubtitleCuesAsSegmentForMedia(
     media: MediaMetadata,
-    track: EmbeddedSubtitleTrack,
-    trackLabel: string,
+    insertion: SubtitleCueInsertion,
   ): number {
     const timeline = useTimelineStore.getState()
-    const project = useProjectStore.getState().currentProject
-    const canvasWidth = project?.metadata.width ?? DEFAULT_PROJECT_WIDTH
-    const canvasHeight = project?.metadata.height ?? DEFAULT_PROJECT_HEIGHT
+    const { canvasWidth, canvasHeight } = getCanvasSize()
     const clips = findCaptionTargetClipsForMedia(timeline.items, media.id)
     if (clips.length === 0) return 0
 
@@ -122,30 +191,22 @@ class SubtitleSidecarService {
     // legacy per-cue caption text items linked to those same clipIds —
     // they'd otherwise sit underneath the new segment with stale text.
     const clipIdSet = new Set(clips.map((c) => c.id))
-    const obsoleteIds = timeline.items
-      .filter((item) => isSubtitleForClip(item, clipIdSet))
-      .map((item) => item.id)
+    const obsoleteIds = insertion.replaceExisting
+      ? timeline.items.filter((item) => isSubtitleForClip(item, clipIdSet)).map((item) => item.id)
+      : []
 
     const segments: import('@/types/timeline').SubtitleSegmentItem[] = []
     for (const clip of clips) {
       const segment = buildSubtitleSegmentForClip({
         trackId: clip.trackId,
-        cues: track.cues,
+        cues: insertion.cues,
         clip,
         timelineFps: timeline.fps,
         canvasWidth,
         canvasHeight,
-        label: `${media.fileName} — ${trackLabel}`,
-        source: {
-          type: 'embedded-subtitles',
-          mediaId: media.id,
-          clipId: clip.id,
-          trackNumber: track.trackNumber,
-          language: track.language,
-          trackName: track.name,
-          codecId: track.codecId,
-          importedAt: Date.now(),
-        },
+        label: insertion.label,
+        source: insertion.buildSource(clip),
+        styleTemplate: insertion.styleTemplate,
       })
       if (segment) segments.push(segment)
     }
@@ -238,6 +299,14 @@ function isSubtitleForClip(item: TimelineItem, clipIds: ReadonlySet<string>): bo
 
 export const subtitleSidecarService = new SubtitleSidecarService()
 
+function getCanvasSize(): { canvasWidth: number; canvasHeight: number } {
+  const project = useProjectStore.getState().currentProject
+  return {
+    canvasWidth: project?.metadata.width ?? DEFAULT_PROJECT_WIDTH,
+    canvasHeight: project?.metadata.height ?? DEFAULT_PROJECT_HEIGHT,
+  }
+}
+
 export function chooseEmbeddedSubtitleTrackForMedia(
   tracks: readonly EmbeddedSubtitleTrack[],
 ): EmbeddedSubtitleTrack | null {
@@ -758,6 +758,83 @@ describe('caption-items', () => {
     expect(segment?.linkedGroupId).toBeUndefined()
   })
 
+  it('carries cue speakers and speaker styles onto the built subtitle segment', () => {
+    const clip: VideoItem = {
+      id: 'video-speakers',
+      type: 'video',
+      trackId: 'track-v',
+      from: 0,
+      durationInFrames: 120,
+      label: 'V',
+      mediaId: 'media-1',
+      src: 'blob:test',
+    }
+
+    const segment = buildSubtitleSegmentForClip({
+      trackId: 'track-captions',
+      cues: [
+        { id: 'c1', startSeconds: 0, endSeconds: 1, text: 'Hi', speakerId: 'speaker-1' },
+        { id: 'c2', startSeconds: 1, endSeconds: 2, text: 'Hello', speakerId: 'speaker-2' },
+      ],
+      clip,
+      timelineFps: 30,
+      canvasWidth: 1920,
+      canvasHeight: 1080,
+      source: { type: 'transcript', mediaId: 'media-1', clipId: clip.id },
+      speakerStyles: { 'speaker-2': { name: 'Guest', color: '#38bdf8' } },
+    })
+
+    expect(segment?.cues.map((cue) => cue.speakerId)).toEqual(['speaker-1', 'speaker-2'])
+    expect(segment?.speakerStyles).toEqual({ 'speaker-2': { name: 'Guest', color: '#38bdf8' } })
+  })
+
+  it('trims karaoke word timings and cue text to the clip source window', () => {
+    const clip: VideoItem = {
+      id: 'video-words',
+      type: 'video',
+      trackId: 'track-v',
+      from: 0,
+      durationInFrames: 60,
+      label: 'V',
+      mediaId: 'media-1',
+      src: 'blob:test',
+      sourceStart: 30,
+      sourceEnd: 90,
+      sourceFps: 30,
+    }
+
+    const segment = buildSubtitleSegmentForClip({
+      trackId: 'track-captions',
+      cues: [
+        {
+          id: 'c1',
+          startSeconds: 0,
+          endSeconds: 2,
+          text: 'one two three four',
+          words: [
+            { text: 'one', startSeconds: 0, endSeconds: 0.4 },
+            { text: 'two', startSeconds: 0.5, endSeconds: 0.9 },
+            { text: 'three', startSeconds: 1, endSeconds: 1.4 },
+            { text: 'four', startSeconds: 1.5, endSeconds: 2 },
+          ],
+        },
+      ],
+      clip,
+      timelineFps: 30,
+      canvasWidth: 1920,
+      canvasHeight: 1080,
+      source: { type: 'transcript', mediaId: 'media-1', clipId: clip.id },
+      styleTemplate: { karaoke: { activeColor: '#ffd400' } },
+    })
+
+    expect(segment?.karaoke).toEqual({ activeColor: '#ffd400' })
+    expect(segment?.cues[0]?.text).toBe('three four')
+    expect(segment?.cues[0]?.words).toEqual([
+      { text: 'three', startSeconds: 0, endSeconds: expect.closeTo(0.4) },
+      { text: 'four', startSeconds: expect.closeTo(0.5), endSeconds: 1 },
+    ])
+  })
+
   it('expands clip-owned transcript captions into a render-only top subtitle track', () => {
     const sourceTrack: TimelineTrack = {
       id: 'track-v',
@@ -8,19 +8,25 @@ import {
 import type { MediaTranscriptSegment } from '@/types/storage'
 import type { MediaCaption } from '@/infrastructure/analysis/media-tagger'
 import type { SubtitleCue, SubtitleFormat } from '@/shared/utils/subtitles'
+import type { CaptionKaraokeStyle } from '@/types/text'
 import type {
   AudioItem,
+  CaptionWordTiming,
   GeneratedCaptionSource,
+  SubtitleSegmentCue,
   SubtitleSegmentItem,
+  SubtitleSpeakerStyle,
   TextItem,
   TimelineItem,
   TimelineTrack,
+  TimelineTranscriptCaptionCue,
   VideoItem,
 } from '@/types/timeline'
 import {
   CAPTION_STYLE_PRESETS,
   resolveCaptionStylePatch,
 } from '@/shared/typography/caption-style-presets'
+import { alignWordTimingsToText } from '@/shared/typography/caption-karaoke'
 
 /**
  * Fallback segment duration when AI captions can't infer an `end` time from
@@ -91,7 +97,10 @@ export type CaptionTextItemTemplate = Pick<
   | 'textShadow'
   | 'stroke'
   | 'transform'
->
+> & {
+  /** Only subtitle segments highlight words; text-item builders drop it. */
+  karaoke?: CaptionKaraokeStyle
+}
 
 export const VIRTUAL_TRANSCRIPT_CAPTION_TRACK_ID = '__virtual-transcript-captions__'
 
@@ -520,7 +529,13 @@ export function getCaptionStyleTemplateFromPreset(
 ): CaptionTextItemTemplate | undefined {
   const preset = CAPTION_STYLE_PRESETS.find((p) => p.id === presetId)
   if (!preset) return undefined
-  return resolveCaptionStylePatch(preset, canvasWidth, canvasHeight)
+  const template: CaptionTextItemTemplate = resolveCaptionStylePatch(
+    preset,
+    canvasWidth,
+    canvasHeight,
+  )
+  if (preset.karaoke) template.karaoke = { ...preset.karaoke }
+  return template
 }
 
 export function getCaptionTextItemTemplate(
@@ -543,9 +558,50 @@ export function getCaptionTextItemTemplate(
     textShadow: item.textShadow ? { ...item.textShadow } : undefined,
     stroke: item.stroke ? { ...item.stroke } : undefined,
     transform: item.transform ? { ...item.transform } : undefined,
+    ...(item.type === 'subtitle' && item.karaoke ? { karaoke: { ...item.karaoke } } : {}),
   }
 }
 
+/** Style template minus the subtitle-only fields, for per-cue text items. */
+function textItemStyleFromTemplate(
+  template: CaptionTextItemTemplate | undefined,
+): Omit<CaptionTextItemTemplate, 'karaoke'> {
+  if (!template) return {}
+  const { karaoke: _karaoke, ...style } = template
+  return style
+}
+
+/**
+ * Caption cues for transcript segments, in source seconds. Word timestamps
+ * are regrouped onto the cue's whitespace-separated words so karaoke
+ * highlighting and clip trimming can address words one-to-one.
+ */
+export function buildTranscriptCaptionCues(
+  segments: readonly MediaTranscriptSegment[],
+  idPrefix: string,
+): TimelineTranscriptCaptionCue[] {
+  return segments.map((segment, index) => {
+    const words = segment.words
+      ? alignWordTimingsToText(
+          segment.text,
+          segment.words.map((word) => ({
+            text: word.text,
+            startSeconds: word.start,
+            endSeconds: word.end,
+          })),
+        )
+      : null
+    return {
+      id: `${idPrefix}-${index}`,
+      startSeconds: segment.start,
+      endSeconds: segment.end,
+      text: segment.text,
+      ...(segment.speakerId ? { speakerId: segment.speakerId } : {}),
+      ...(words && words.length > 0 ? { words } : {}),
+    }
+  })
+}
+
 export function buildCaptionTextItems({
   mediaId,
   trackId,
@@ -627,7 +683,7 @@ export function buildCaptionTextItems({
     return [
       {
         ...defaultCaptionItem,
-        ...styleTemplate,
+        ...textItemStyleFromTemplate(styleTemplate),
       },
     ]
   })
@@ -694,7 +750,7 @@ export function buildSubtitleTextItems({
     return [
       {
         ...defaultCaptionItem,
-        ...styleTemplate,
+        ...textItemStyleFromTemplate(styleTemplate),
       },
     ]
   })
@@ -785,7 +841,7 @@ export function buildSubtitleTextItemsForClip({
     return [
       {
         ...defaultCaptionItem,
-        ...styleTemplate,
+        ...textItemStyleFromTemplate(styleTemplate),
       },
     ]
   })
@@ -827,17 +883,47 @@ export function findCaptionTargetClipsForMedia(
 
 interface BuildSubtitleSegmentForClipOptions {
   trackId: string
-  cues: readonly SubtitleCue[]
+  cues: readonly SubtitleSegmentCue[]
   clip: AudioItem | VideoItem
   timelineFps: number
   canvasWidth: number
   canvasHeight: number
   source: import('@/types/timeline').SubtitleSegmentSource
   styleTemplate?: CaptionTextItemTemplate
+  /** Per-speaker overrides for diarized transcript cues. */
+  speakerStyles?: Record<string, SubtitleSpeakerStyle>
   /** Label shown in the timeline-item UI; defaults to the source-track label. */
   label?: string
 }
 
+/**
+ * Words of `cue` heard inside the clip's `[windowStart, windowEnd)` source
+ * window, or null when the cue has no word timings. A word belongs to the
+ * window holding its midpoint, so cutting words out in the transcript editor
+ * (which splits the clip between words) also drops them from the caption.
+ * While the words still line up with the cue text, the text is trimmed to
+ * the kept words too; hand-edited text that no longer lines up is kept whole.
+ */
+function clipCueWordsToWindow(
+  cue: SubtitleSegmentCue,
+  windowStart: number,
+  windowEnd: number,
+): { words: CaptionWordTiming[]; text: string } | null {
+  if (!cue.words || cue.words.length === 0) return null
+  const tokens = cue.text.split(/\s+/).filter(Boolean)
+  const aligned = tokens.length === cue.words.length
+  const keptTokens: string[] = []
+  const words: CaptionWordTiming[] = []
+  for (const [index, word] of cue.words.entries()) {
+    const midpoint = (word.startSeconds + word.endSeconds) / 2
+    if (midpoint < windowStart || midpoint >= windowEnd) continue
+    words.push(word)
+    if (aligned) keptTokens.push(tokens[index]!)
+  }
+  if (!aligned || words.length === cue.words.length) return { words, text: cue.text }
+  return { words, text: keptTokens.join(' ') }
+}
+
 /**
  * Build ONE {@link SubtitleSegmentItem} that owns all cues overlapping
  * `clip`'s source window. Replaces the per-cue {@link buildSubtitleTextItemsForClip}
@@ -859,6 +945,7 @@ export function buildSubtitleSegmentForClip(
     trackId,
     source,
     styleTemplate,
+    speakerStyles,
     label,
   } = options
   const { sourceStart, sourceEnd, sourceFps, speed } = getClipSourceBounds(clip, timelineFps)
@@ -882,11 +969,26 @@ export function buildSubtitleSegmentForClip(
     const cueEndFrames = Math.ceil(cueEndTimeline * timelineFps)
     if (cueEndFrames <= cueStartFrames) continue
 
+    const clipped = clipCueWordsToWindow(cue, sourceStartSeconds, sourceEndSeconds)
+    if (clipped && clipped.words.length === 0) continue
+    const toCueSeconds = (seconds: number) =>
+      (Math.min(Math.max(seconds, overlapStartSec), overlapEndSec) - sourceStartSeconds) / speed
+
     overlappingCues.push({
       id: cue.id,
       startSeconds: cueStartTimeline,
       endSeconds: cueEndTimeline,
-      text: cue.text,
+      text: clipped?.text ?? cue.text,
+      ...(cue.speakerId ? { speakerId: cue.speakerId } : {}),
+      ...(clipped
+        ? {
+            words: clipped.words.map((word) => ({
+              text: word.text,
+              startSeconds: toCueSeconds(word.startSeconds),
+              endSeconds: toCueSeconds(word.endSeconds),
+            })),
+          }
+        : {}),
     })
     if (cueStartFrames < firstFromOffset) firstFromOffset = cueStartFrames
     if (cueEndFrames > lastEndOffset) lastEndOffset = cueEndFrames
@@ -903,11 +1005,20 @@ export function buildSubtitleSegmentForClip(
   const durationInFrames = Math.max(1, segmentEndOffset - segmentFromOffset)
 
   // Cue times are now stored segment-relative (start = 0 at the segment's `from`).
+  const segmentOffsetSeconds = segmentFromOffset / timelineFps
   const segmentRelativeCues = overlappingCues.map((cue) => ({
-    id: cue.id,
-    startSeconds: cue.startSeconds - segmentFromOffset / timelineFps,
-    endSeconds: cue.endSeconds - segmentFromOffset / timelineFps,
-    text: cue.text,
+    ...cue,
+    startSeconds: cue.startSeconds - segmentOffsetSeconds,
+    endSeconds: cue.endSeconds - segmentOffsetSeconds,
+    ...(cue.words
+      ? {
+          words: cue.words.map((word) => ({
+            ...word,
+            startSeconds: word.startSeconds - segmentOffsetSeconds,
+            endSeconds: word.endSeconds - segmentOffsetSeconds,
+          })),
+        }
+      : {}),
   }))
 
   const defaultStyle = {
@@ -958,6 +1069,7 @@ export function buildSubtitleSegmentForClip(
     sourceLabel: label,
     source,
     cues: segmentRelativeCues,
+    ...(speakerStyles ? { speakerStyles } : {}),
     ...defaultStyle,
     ...styleTemplate,
   }
@@ -1002,6 +1114,7 @@ export function appendVirtualTranscriptCaptionTrack(
           clipId: item.id,
         },
         styleTemplate: transcriptCaptions.style as CaptionTextItemTemplate | undefined,
+        speakerStyles: transcriptCaptions.speakerStyles,
       })
 
       if (!segment) continue
@@ -1,5 +1,21 @@
+import { nitsToSignal } from '@/infrastructure/gpu-color'
+import { isHdrColorSpace, type ProjectColorSpace } from '@/types/color'
+
 export const SCOPE_LUMA_GUIDES = [0, 25, 50, 75, 100] as const
 
+// HLG is scene-referred with a 1000-nit nominal peak, so PQ alone gets the upper marks.
+const PQ_NITS_GUIDES = [100, 203, 1000, 4000, 10000] as const
+const HLG_NITS_GUIDES = [100, 203, 1000] as const
+
+/** Waveform guide lines for an HDR working space, placed at each level's signal value (0–100). */
+export function getScopeNitsGuides(
+  colorSpace: ProjectColorSpace,
+): Array<{ nits: number; level: number }> {
+  if (!isHdrColorSpace(colorSpace)) return []
+  const guides = colorSpace === 'rec2100-hlg' ? HLG_NITS_GUIDES : PQ_NITS_GUIDES
+  return guides.map((nits) => ({ nits, level: nitsToSignal(nits, colorSpace) * 100 }))
+}
+
 export const VECTOR_SCOPE_TARGETS = [
   { label: 'R', x: 39, y: 16 },
   { label: 'Mg', x: 66, y: 18 },
@@ -2,7 +2,11 @@ import { createRef } from 'react'
 import { render, screen } from '@testing-library/react'
 import { describe, expect, it } from 'vite-plus/test'
 import { ScopeCanvasFrame } from './color-scope-overlays'
-import { SCOPE_LUMA_GUIDES, VECTOR_SCOPE_TARGETS } from './color-scope-overlay-data'
+import {
+  getScopeNitsGuides,
+  SCOPE_LUMA_GUIDES,
+  VECTOR_SCOPE_TARGETS,
+} from './color-scope-overlay-data'
 
 describe('color scope overlays', () => {
   it('renders luma graticule labels over a waveform canvas', () => {
@@ -20,6 +24,23 @@ describe('color scope overlays', () => {
     }
   })
 
+  it('labels HDR waveforms in nits at their PQ signal levels', () => {
+    const ref = createRef<HTMLDivElement>()
+    const rendered = render(
+      <ScopeCanvasFrame containerRef={ref} kind="waveform" colorSpace="rec2100-pq">
+        <canvas aria-label="waveform canvas" />
+      </ScopeCanvasFrame>,
+    )
+
+    const guides = getScopeNitsGuides('rec2100-pq')
+    expect(guides.map((guide) => guide.nits)).toEqual([100, 203, 1000, 4000, 10000])
+    expect(guides[1]!.level).toBeCloseTo(58.07, 1)
+    expect(guides.at(-1)!.level).toBeCloseTo(100, 3)
+    expect(rendered.container).toHaveTextContent('203')
+    expect(rendered.container).toHaveTextContent('nits')
+    expect(getScopeNitsGuides('rec709')).toEqual([])
+  })
+
   it('renders vectorscope targets and skin-tone reference line label', () => {
     const ref = createRef<HTMLDivElement>()
     const rendered = render(
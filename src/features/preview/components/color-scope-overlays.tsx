@@ -1,6 +1,11 @@
 import type { ReactNode, RefObject } from 'react'
 import { cn } from '@/shared/ui/cn'
-import { SCOPE_LUMA_GUIDES, VECTOR_SCOPE_TARGETS } from './color-scope-overlay-data'
+import { DEFAULT_PROJECT_COLOR_SPACE, type ProjectColorSpace } from '@/types/color'
+import {
+  getScopeNitsGuides,
+  SCOPE_LUMA_GUIDES,
+  VECTOR_SCOPE_TARGETS,
+} from './color-scope-overlay-data'
 
 type ScopeOverlayKind = 'waveform' | 'parade' | 'histogram' | 'vectorscope'
 
@@ -9,6 +14,8 @@ interface ScopeCanvasFrameProps {
   className?: string
   containerRef: RefObject<HTMLDivElement | null>
   kind: ScopeOverlayKind
+  /** HDR working spaces label waveform/parade levels in nits instead of 0–100 */
+  colorSpace?: ProjectColorSpace
 }
 
 export function ScopeCanvasFrame({
@@ -16,6 +23,7 @@ export function ScopeCanvasFrame({
   className,
   containerRef,
   kind,
+  colorSpace = DEFAULT_PROJECT_COLOR_SPACE,
 }: ScopeCanvasFrameProps) {
   return (
     <div
@@ -26,33 +34,61 @@ export function ScopeCanvasFrame({
       )}
     >
       {children}
-      <ScopeOverlay kind={kind} />
+      <ScopeOverlay kind={kind} colorSpace={colorSpace} />
     </div>
   )
 }
 
-function ScopeOverlay({ kind }: { kind: ScopeOverlayKind }) {
+function ScopeOverlay({
+  kind,
+  colorSpace,
+}: {
+  kind: ScopeOverlayKind
+  colorSpace: ProjectColorSpace
+}) {
   if (kind === 'vectorscope') return <VectorScopeOverlay />
-  return <LumaScopeOverlay parade={kind === 'parade'} histogram={kind === 'histogram'} />
+  const nitsGuides = kind === 'histogram' ? [] : getScopeNitsGuides(colorSpace)
+  return (
+    <LumaScopeOverlay
+      parade={kind === 'parade'}
+      histogram={kind === 'histogram'}
+      nitsGuides={nitsGuides}
+    />
+  )
 }
 
-function LumaScopeOverlay({ parade, histogram }: { parade: boolean; histogram: boolean }) {
+function LumaScopeOverlay({
+  parade,
+  histogram,
+  nitsGuides,
+}: {
+  parade: boolean
+  histogram: boolean
+  nitsGuides: Array<{ nits: number; level: number }>
+}) {
+  const guides =
+    nitsGuides.length > 0
+      ? nitsGuides.map(({ nits, level }) => ({ key: nits, label: String(nits), level }))
+      : SCOPE_LUMA_GUIDES.map((level) => ({ key: level, label: String(level), level }))
   return (
     <div aria-hidden="true" className="pointer-events-none absolute inset-0">
-      {SCOPE_LUMA_GUIDES.map((level) => {
+      {guides.map(({ key, label, level }) => {
         const top = `${100 - level}%`
         return (
           <div
-            key={level}
+            key={key}
             className="absolute left-0 right-0 border-t border-slate-400/18"
             style={{ top }}
           >
             <span className="absolute left-1 -translate-y-1/2 rounded-sm bg-black/45 px-1 font-mono text-[9px] leading-4 text-slate-300/80">
-              {level}
+              {label}
             </span>
           </div>
         )
       })}
+      {nitsGuides.length > 0 ? (
+        <div className="absolute bottom-1 right-1 font-mono text-[9px] text-slate-400/80">nits</div>
+      ) : null}
       {histogram ? (
         <>
           <div className="absolute bottom-1 left-1 font-mono text-[9px] text-slate-400/80">0</div>
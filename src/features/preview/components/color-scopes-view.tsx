@@ -11,6 +11,7 @@ import {
   SelectValue,
 } from '@/components/ui/select'
 import { ScopeRenderer } from '@/infrastructure/gpu-scopes'
+import { isHdrColorSpace } from '@/types/color'
 import { ScopeCanvasFrame } from './color-scope-overlays'
 
 const SAMPLE_WIDTH_PAUSED = 384
@@ -33,12 +34,13 @@ interface GpuCanvasContextCacheEntry {
   height: number
 }
 
-type ScopeColorMatrix = 'bt709' | 'bt601'
+type ScopeColorMatrix = 'bt709' | 'bt601' | 'bt2020'
 type ScopeRangeMode = 'full' | 'legal'
 
 // Browser compositing is effectively sRGB/Rec.709 full-range, so the scopes
 // use fixed coefficients — like DaVinci, no matrix/range toggles in the
 // toolbar (Resolve keeps such options behind its scope settings menu).
+// HDR projects are measured on the untone-mapped BT.2100 signal with BT.2020 luma.
 const SCOPE_COLOR_MATRIX: ScopeColorMatrix = 'bt709'
 const SCOPE_RANGE_MODE: ScopeRangeMode = 'full'
 type ScopeViewMode = 'rgb' | 'r' | 'g' | 'b' | 'luma'
@@ -61,7 +63,9 @@ interface MatrixCoefficients {
 }
 
 function getMatrixCoefficients(matrix: ScopeColorMatrix): MatrixCoefficients {
-  return matrix === 'bt601' ? { kr: 0.299, kb: 0.114 } : { kr: 0.2126, kb: 0.0722 }
+  if (matrix === 'bt601') return { kr: 0.299, kb: 0.114 }
+  if (matrix === 'bt2020') return { kr: 0.2627, kb: 0.0593 }
+  return { kr: 0.2126, kb: 0.0722 }
 }
 
 function loadStackView(): StackScopeView {
@@ -459,6 +463,7 @@ export const ColorScopesView = memo(function ColorScopesView({
   const isPlaying = usePlaybackStore((s) => s.isPlaying)
   const captureFrameImageData = usePreviewBridgeStore((s) => s.captureFrameImageData)
   const captureFrame = usePreviewBridgeStore((s) => s.captureFrame)
+  const colorSpace = usePreviewBridgeStore((s) => s.colorSpace)
   const isEmbeddedStackLayout = embedded && embeddedLayout === 'stack'
   const stackShows = (scope: StackScopeView) => !isEmbeddedStackLayout || stackView === scope
   const showWaveform = stackShows('waveform')
@@ -614,17 +619,20 @@ export const ColorScopesView = memo(function ColorScopesView({
       // watchdog force-cleared this slow render and started another). Stale
       // renders must not touch the GPU — that's the overlapping-upload hazard.
       const isStale = () => cancelled || renderGeneration !== generation
-      const { kr, kb } = getMatrixCoefficients(SCOPE_COLOR_MATRIX)
+      const bridge = usePreviewBridgeStore.getState()
+      const hdrSignal = isHdrColorSpace(bridge.colorSpace)
+      const { kr, kb } = getMatrixCoefficients(hdrSignal ? 'bt2020' : SCOPE_COLOR_MATRIX)
       renderer.setMatrix(kr, kb)
       renderer.setRange(0, 1)
 
       try {
         // Try near-zero-copy canvas path first
-        const canvasSourceFn = usePreviewBridgeStore.getState().captureCanvasSource
+        const canvasSourceFn = bridge.captureCanvasSource
         if (canvasSourceFn) {
           const source = await canvasSourceFn({
             fresh: isPlayingRef.current,
             preferRenderedFrame: true,
+            hdrSignal,
           })
           if (source && !isStale()) {
             renderer.uploadFromCanvas(source)
@@ -1022,6 +1030,7 @@ export const ColorScopesView = memo(function ColorScopesView({
                 <ScopeCanvasFrame
                   containerRef={waveformContainerRef}
                   kind="waveform"
+                  colorSpace={colorSpace}
                   className="min-h-0 flex-1"
                 >
                   <canvas ref={waveformCanvasRef} className="w-full h-full" />
@@ -1035,6 +1044,7 @@ export const ColorScopesView = memo(function ColorScopesView({
                 <ScopeCanvasFrame
                   containerRef={paradeContainerRef}
                   kind="parade"
+                  colorSpace={colorSpace}
                   className="min-h-0 w-full aspect-[10/3]"
                 >
                   <canvas ref={paradeCanvasRef} className="w-full h-full" />
@@ -1085,6 +1095,7 @@ export const ColorScopesView = memo(function ColorScopesView({
                 <ScopeCanvasFrame
                   containerRef={waveformContainerRef}
                   kind="waveform"
+                  colorSpace={colorSpace}
                   className="flex-1 min-h-[160px]"
                 >
                   <canvas ref={waveformCanvasRef} className="w-full h-full" />
@@ -1095,6 +1106,7 @@ export const ColorScopesView = memo(function ColorScopesView({
                 <ScopeCanvasFrame
                   containerRef={paradeContainerRef}
                   kind="parade"
+                  colorSpace={colorSpace}
                   className="w-full aspect-[10/3]"
                 >
                   <canvas ref={paradeCanvasRef} className="w-full h-full" />
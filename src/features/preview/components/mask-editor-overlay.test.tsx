@@ -856,6 +856,48 @@ describe('MaskEditorOverlay edit mode', () => {
     })
   })
 
+  it('sets per-vertex feather on the selected point when requested', async () => {
+    seedEditablePath()
+    renderMaskEditorOverlay(PATH_ITEM_TRANSFORM)
+
+    act(() => {
+      useMaskEditorStore.getState().selectVertex(2)
+      useMaskEditorStore.getState().requestSetSelectedVertexFeather(12)
+    })
+
+    await waitFor(() => {
+      const updatedItem = useItemsStore.getState().items.find((item) => item.id === 'path-1') as
+        | ShapeItem
+        | undefined
+      expect(updatedItem?.pathVertices?.[2]?.feather).toBe(12)
+      expect(updatedItem?.pathVertices?.[1]?.feather).toBeUndefined()
+    })
+  })
+
+  it('stores edits to an animated path as a keyframe at the playhead', async () => {
+    seedEditablePath()
+    const item = useItemsStore.getState().items[0] as ShapeItem
+    useItemsStore.getState()._updateItem('path-1', {
+      pathKeyframes: [{ id: 'kf-0', frame: 0, vertices: item.pathVertices!, easing: 'linear' }],
+    })
+    usePlaybackStore.getState().setCurrentFrame(20)
+    renderMaskEditorOverlay(PATH_ITEM_TRANSFORM)
+
+    act(() => {
+      useMaskEditorStore.getState().selectVertex(1)
+      useMaskEditorStore.getState().requestConvertSelectedVertex('bezier')
+    })
+
+    await waitFor(() => {
+      const updatedItem = useItemsStore.getState().items.find((item) => item.id === 'path-1') as
+        | ShapeItem
+        | undefined
+      expect(updatedItem?.pathKeyframes?.map((keyframe) => keyframe.frame)).toEqual([0, 20])
+      expect(updatedItem?.pathVertices).toBe(item.pathVertices)
+      expect(updatedItem?.transform).toEqual(PATH_ITEM_TRANSFORM)
+    })
+  })
+
   it('moves the whole path when dragging inside the shape body', () => {
     seedEditablePath()
 
@@ -69,6 +69,8 @@ import {
 import {
   getAutoKeyframeOperation,
   isFrameInTransitionRegion,
+  resolveAnimatedMaskShape,
+  setMaskPathKeyframe,
   type AutoKeyframeOperation,
 } from '../deps/keyframes'
 
@@ -177,6 +179,12 @@ export const MaskEditorOverlay = memo(function MaskEditorOverlay({
   const convertSelectedVertexRequestMode = useMaskEditorStore(
     (s) => s.convertSelectedVertexRequestMode,
   )
+  const setVertexFeatherRequestVersion = useMaskEditorStore(
+    (s) => s.setVertexFeatherRequestVersion,
+  )
+  const setVertexFeatherRequestValue = useMaskEditorStore((s) => s.setVertexFeatherRequestValue)
+  // Animated paths show (and edit) the path at the playhead
+  const currentFrame = usePlaybackStore((s) => s.currentFrame)
   // Actions
   const commitMaskEdit = useTimelineStore((s) => s.commitMaskEdit)
   const selectVertices = useMaskEditorStore((s) => s.selectVertices)
@@ -269,10 +277,10 @@ export const MaskEditorOverlay = memo(function MaskEditorOverlay({
     const items = useItemsStore.getState().items
     const item = items.find((i) => i.id === editingItemId)
     if (item?.type === 'shape' && item.shapeType === 'path') {
-      return item.pathVertices ?? null
+      return resolveAnimatedMaskShape(item, currentFrame - item.from).pathVertices ?? null
     }
     return null
-  }, [committedEditSnapshot, editingItemId, previewVertices])
+  }, [committedEditSnapshot, currentFrame, editingItemId, previewVertices])
 
   const getMarqueeBounds = useCallback(
     (startScreenPos: [number, number], currentScreenPos: [number, number]): SelectionMarquee => ({
@@ -1122,11 +1130,13 @@ export const MaskEditorOverlay = memo(function MaskEditorOverlay({
   const lastHandledFinishRequestRef = useRef(0)
   const lastHandledCancelRequestRef = useRef(0)
   const lastHandledConvertRequestRef = useRef(0)
+  const lastHandledFeatherRequestRef = useRef(0)
 
   useEffect(() => {
     lastHandledFinishRequestRef.current = 0
     lastHandledCancelRequestRef.current = 0
     lastHandledConvertRequestRef.current = 0
+    lastHandledFeatherRequestRef.current = 0
   }, [editingItemId, penMode])
 
   useEffect(() => {
@@ -1309,6 +1319,28 @@ export const MaskEditorOverlay = memo(function MaskEditorOverlay({
     [],
   )
 
+  /**
+   * Animated paths keep their transform and store the edit as a path
+   * keyframe at the playhead; refitting the bounds would shift every other
+   * keyframe's path. Returns false for static paths.
+   */
+  const commitAnimatedPathVertices = useCallback(
+    (item: ShapeItem, vertices: MaskVertex[]): boolean => {
+      if (!item.pathKeyframes?.length) return false
+      const currentFrame = usePlaybackStore.getState().currentFrame
+      setCommittedEditSnapshot({ vertices: cloneVertices(vertices), transform: itemTransform })
+      commitMaskEdit(item.id, {
+        pathKeyframes: setMaskPathKeyframe(
+          item.pathKeyframes,
+          currentFrame - item.from,
+          cloneVertices(vertices),
+        ),
+      })
+      return true
+    },
+    [commitMaskEdit, itemTransform],
+  )
+
   // ============================================================
   // Commit vertices to timeline store
   // ============================================================
@@ -1318,6 +1350,10 @@ export const MaskEditorOverlay = memo(function MaskEditorOverlay({
       if (!editingItemId) return
       const item = useItemsStore.getState().itemById[editingItemId]
       if (item?.type === 'shape' && item.shapeType === 'path') {
+        if (commitAnimatedPathVertices(item, vertices)) {
+          scheduleEditCommitCleanup()
+          return
+        }
         const currentFrame = usePlaybackStore.getState().currentFrame
         const fitted = fitShapePathToBounds(vertices, itemTransform, item.transform)
         const { baseTransform, autoKeyframeOperations } = buildMaskTransformPersistence(
@@ -1344,6 +1380,7 @@ export const MaskEditorOverlay = memo(function MaskEditorOverlay({
     },
     [
       buildMaskTransformPersistence,
+      commitAnimatedPathVertices,
       commitMaskEdit,
       editingItemId,
       itemTransform,
@@ -1458,6 +1495,7 @@ export const MaskEditorOverlay = memo(function MaskEditorOverlay({
       const nextVertex = nextVertices[index]
       if (nextVertex) {
         convertedVertices[index] = {
+          ...convertedVertices[index]!,
           position: [...nextVertex.position] as [number, number],
           inHandle: [...nextVertex.inHandle] as [number, number],
           outHandle: [...nextVertex.outHandle] as [number, number],
@@ -1480,6 +1518,48 @@ export const MaskEditorOverlay = memo(function MaskEditorOverlay({
     selectVertex,
   ])
 
+  useEffect(() => {
+    if (!isEditing || penMode) return
+    if (setVertexFeatherRequestVersion === 0) return
+    if (setVertexFeatherRequestVersion === lastHandledFeatherRequestRef.current) return
+    if (draggingVertexIndex !== null || draggingHandle !== null) return
+
+    lastHandledFeatherRequestRef.current = setVertexFeatherRequestVersion
+
+    const vertices = getVertices()
+    const targetIndices =
+      selectedVertexIndices.length > 0
+        ? selectedVertexIndices
+        : selectedVertexIndex !== null
+          ? [selectedVertexIndex]
+          : []
+    if (!vertices || !targetIndices.some((index) => !!vertices[index])) return
+
+    const featheredVertices = cloneVertices(vertices)
+    for (const index of targetIndices) {
+      const vertex = featheredVertices[index]
+      if (!vertex) continue
+      if (setVertexFeatherRequestValue === null) {
+        delete vertex.feather
+      } else {
+        vertex.feather = Math.max(0, setVertexFeatherRequestValue)
+      }
+    }
+
+    commitVertices(featheredVertices)
+  }, [
+    isEditing,
+    penMode,
+    draggingVertexIndex,
+    draggingHandle,
+    selectedVertexIndices,
+    selectedVertexIndex,
+    setVertexFeatherRequestVersion,
+    setVertexFeatherRequestValue,
+    getVertices,
+    commitVertices,
+  ])
+
   const handleEditPointerDown = useCallback(
     (e: React.PointerEvent) => {
       if (editDraggingRef.current) return
@@ -1738,7 +1818,11 @@ export const MaskEditorOverlay = memo(function MaskEditorOverlay({
         scheduleEditCommitCleanup()
       } else if (finalVertices && itemId) {
         const item = useItemsStore.getState().items.find((candidate) => candidate.id === itemId)
-        if (item?.type === 'shape' && item.shapeType === 'path') {
+        if (
+          item?.type === 'shape' &&
+          item.shapeType === 'path' &&
+          !commitAnimatedPathVertices(item, finalVertices)
+        ) {
           const currentFrame = usePlaybackStore.getState().currentFrame
           const fitted = fitShapePathToBounds(finalVertices, itemTransform, item.transform)
           const { baseTransform, autoKeyframeOperations } = buildMaskTransformPersistence(
@@ -1770,6 +1854,7 @@ export const MaskEditorOverlay = memo(function MaskEditorOverlay({
     },
     [
       buildMaskTransformPersistence,
+      commitAnimatedPathVertices,
       commitMaskEdit,
       endInteraction,
       getMarqueeBounds,
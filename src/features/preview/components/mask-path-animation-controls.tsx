@@ -0,0 +1,160 @@
+import { useCallback, useEffect, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { toast } from 'sonner'
+import { Button } from '@/components/ui/button'
+import { Input } from '@/components/ui/input'
+import { usePlaybackStore } from '@/shared/state/playback'
+import type { CanvasSettings } from '@/types/transform'
+import { resolveAnimatedMaskShape } from '../deps/keyframes'
+import { useItemsStore } from '../deps/timeline-store'
+import { useMaskEditorStore } from '../stores/mask-editor-store'
+import type { MaskTrackDirection } from '../utils/mask-tracking'
+
+interface MaskPathAnimationControlsProps {
+  canvas: CanvasSettings
+}
+
+/**
+ * Path edit toolbar controls for animating a mask path: per-point feather,
+ * path keyframe toggle, and tracking the mask through the clip below it.
+ */
+export function MaskPathAnimationControls({ canvas }: MaskPathAnimationControlsProps) {
+  const { t } = useTranslation()
+  const editingItemId = useMaskEditorStore((s) => s.editingItemId)
+  const selectedVertexIndices = useMaskEditorStore((s) => s.selectedVertexIndices)
+  const maskTracking = useMaskEditorStore((s) => s.maskTracking)
+  const togglePathKeyframe = useMaskEditorStore((s) => s.togglePathKeyframe)
+  const trackMask = useMaskEditorStore((s) => s.trackMask)
+  const cancelMaskTracking = useMaskEditorStore((s) => s.cancelMaskTracking)
+  const requestSetSelectedVertexFeather = useMaskEditorStore(
+    (s) => s.requestSetSelectedVertexFeather,
+  )
+  const currentFrame = usePlaybackStore((s) => s.currentFrame)
+  const item = useItemsStore(
+    useCallback(
+      (s) => s.items.find((candidate) => candidate.id === editingItemId),
+      [editingItemId],
+    ),
+  )
+
+  const shape = item?.type === 'shape' && item.shapeType === 'path' ? item : undefined
+  const itemFrame = shape ? currentFrame - shape.from : -1
+  const isFrameInItem = !!shape && itemFrame >= 0 && itemFrame < shape.durationInFrames
+  const hasKeyframeAtFrame = !!shape?.pathKeyframes?.some(
+    (keyframe) => keyframe.frame === itemFrame,
+  )
+  const firstSelectedIndex = selectedVertexIndices[0]
+  const selectedFeather =
+    shape && firstSelectedIndex !== undefined
+      ? resolveAnimatedMaskShape(shape, itemFrame).pathVertices?.[firstSelectedIndex]?.feather
+      : undefined
+
+  const [featherDraft, setFeatherDraft] = useState('')
+  useEffect(() => {
+    setFeatherDraft(selectedFeather === undefined ? '' : String(Math.round(selectedFeather)))
+  }, [selectedFeather, firstSelectedIndex])
+
+  const commitFeather = useCallback(() => {
+    const trimmed = featherDraft.trim()
+    const value = Number(trimmed)
+    if (trimmed !== '' && !Number.isFinite(value)) return
+    requestSetSelectedVertexFeather(trimmed === '' ? null : Math.max(0, Math.min(200, value)))
+  }, [featherDraft, requestSetSelectedVertexFeather])
+
+  const handleTrack = useCallback(
+    async (direction: MaskTrackDirection) => {
+      try {
+        const tracked = await trackMask(direction, canvas)
+        if (tracked === 0) {
+          toast.info(t('preview.maskAnimation.trackNothing'))
+          return
+        }
+        toast.success(t('preview.maskAnimation.trackApplied', { n: tracked }))
+      } catch (error) {
+        const message = error instanceof Error ? error.message : String(error)
+        toast.error(t('preview.maskAnimation.trackFailed', { message }))
+      }
+    },
+    [canvas, t, trackMask],
+  )
+
+  if (!shape) return null
+
+  if (maskTracking) {
+    return (
+      <>
+        <span className="text-[11px] tabular-nums text-muted-foreground">
+          {t('preview.maskAnimation.trackingProgress', {
+            percent: Math.round(maskTracking.progress),
+          })}
+        </span>
+        <Button
+          type="button"
+          size="sm"
+          variant="ghost"
+          className="h-8 px-3 text-[11px]"
+          onClick={cancelMaskTracking}
+        >
+          {t('preview.maskAnimation.cancel')}
+        </Button>
+      </>
+    )
+  }
+
+  return (
+    <>
+      <label className="flex items-center gap-1.5 text-[11px] text-muted-foreground">
+        {t('preview.maskAnimation.pointFeather')}
+        <Input
+          type="number"
+          min={0}
+          max={200}
+          inputMode="numeric"
+          className="h-8 w-16 px-2 text-[11px] md:text-[11px]"
+          placeholder={t('preview.maskAnimation.pointFeatherAuto')}
+          disabled={selectedVertexIndices.length === 0}
+          value={featherDraft}
+          onChange={(event) => setFeatherDraft(event.target.value)}
+          onBlur={commitFeather}
+          onKeyDown={(event) => {
+            if (event.key === 'Enter') commitFeather()
+          }}
+        />
+      </label>
+      <Button
+        type="button"
+        size="sm"
+        variant={hasKeyframeAtFrame ? 'secondary' : 'outline'}
+        className="h-8 px-3 text-[11px]"
+        disabled={!isFrameInItem}
+        aria-pressed={hasKeyframeAtFrame}
+        title={t('preview.maskAnimation.keyframeTooltip')}
+        onClick={togglePathKeyframe}
+      >
+        {t('preview.maskAnimation.keyframe')}
+      </Button>
+      <Button
+        type="button"
+        size="sm"
+        variant="outline"
+        className="h-8 px-3 text-[11px]"
+        disabled={!isFrameInItem}
+        title={t('preview.maskAnimation.trackBackwardTooltip')}
+        onClick={() => void handleTrack('backward')}
+      >
+        {t('preview.maskAnimation.trackBackward')}
+      </Button>
+      <Button
+        type="button"
+        size="sm"
+        variant="outline"
+        className="h-8 px-3 text-[11px]"
+        disabled={!isFrameInItem}
+        title={t('preview.maskAnimation.trackForwardTooltip')}
+        onClick={() => void handleTrack('forward')}
+      >
+        {t('preview.maskAnimation.trackForward')}
+      </Button>
+    </>
+  )
+}
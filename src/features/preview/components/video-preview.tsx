@@ -2,6 +2,7 @@ import { useMemo, useCallback, memo, useEffect, useLayoutEffect, useRef, useStat
 import { usePreviewBridgeStore } from '@/shared/state/preview-bridge'
 import { usePlaybackStore } from '@/shared/state/playback'
 import type { ItemEffect } from '@/types/effects'
+import { DEFAULT_PROJECT_COLOR_SPACE, type ProjectColorSpace } from '@/types/color'
 import { GizmoOverlay } from './gizmo-overlay'
 import { MaskEditorContainer } from './mask-editor-container'
 import { CornerPinContainer } from './corner-pin-container'
@@ -41,6 +42,7 @@ interface VideoPreviewProps {
     width: number
     height: number
     backgroundColor?: string
+    colorSpace?: ProjectColorSpace
   }
   containerSize: {
     width: number
@@ -154,6 +156,11 @@ const VideoPreviewBase = memo(function VideoPreviewBase({
   const setCaptureFrame = usePreviewBridgeStore((s) => s.setCaptureFrame)
   const setCaptureFrameImageData = usePreviewBridgeStore((s) => s.setCaptureFrameImageData)
   const setDisplayedFrame = usePreviewBridgeStore((s) => s.setDisplayedFrame)
+  const setBridgeColorSpace = usePreviewBridgeStore((s) => s.setColorSpace)
+
+  useEffect(() => {
+    setBridgeColorSpace(project.colorSpace ?? DEFAULT_PROJECT_COLOR_SPACE)
+  }, [project.colorSpace, setBridgeColorSpace])
 
   const {
     isRenderedOverlayVisible,
@@ -321,6 +328,7 @@ const VideoPreviewBase = memo(function VideoPreviewBase({
         project.width,
         project.height,
         project.backgroundColor ?? '',
+        project.colorSpace ?? '',
         fastScrubTracksTopologyFingerprint,
         playbackTransitionFingerprint,
       ].join('::'),
@@ -329,6 +337,7 @@ const VideoPreviewBase = memo(function VideoPreviewBase({
       fps,
       playbackTransitionFingerprint,
       project.backgroundColor,
+      project.colorSpace,
       project.height,
       project.width,
     ],
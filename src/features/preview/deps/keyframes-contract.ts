@@ -11,3 +11,8 @@ export {
 } from '@/features/keyframes/utils/auto-keyframe'
 export { isFrameInTransitionRegion } from '@/features/keyframes/utils/transition-region'
 export { resolveAnimatedTextItem } from '@/features/keyframes/utils/animated-text-item'
+export {
+  removeMaskPathKeyframe,
+  resolveAnimatedMaskShape,
+  setMaskPathKeyframe,
+} from '@/features/keyframes/utils/animated-mask-path'
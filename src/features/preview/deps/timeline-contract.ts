@@ -15,6 +15,7 @@ export {
   createScrubThrottleState,
   findBestCanvasDropPlacement,
   getDroppedMediaDurationInFrames,
+  getItemContentRect,
   getTrackKind,
   performInsertEdit,
   performOverwriteEdit,
@@ -22,6 +23,7 @@ export {
   resolveSourceEditTrackTargets,
   shouldCommitScrubFrame,
   timelineToSourceFrames,
+  trackClipRegion,
   useCompositionNavigationStore,
   useCompositionsStore,
   useItemsStore,
@@ -19,3 +19,4 @@ export {
 export { createScrubThrottleState, shouldCommitScrubFrame } from './timeline-contract'
 export { timelineToSourceFrames } from './timeline-contract'
 export { useWaveform } from './timeline-contract'
+export { getItemContentRect, trackClipRegion } from './timeline-contract'
@@ -1,6 +1,7 @@
 import { useCallback, useMemo, useRef } from 'react'
 import type { CompositionInputProps } from '@/types/export'
 import type { AudioEqSettings } from '@/types/audio'
+import type { ProjectColorSpace } from '@/types/color'
 import type { ItemEffect } from '@/types/effects'
 import type { ItemKeyframes } from '@/types/keyframe'
 import type { TimelineItem, TimelineTrack } from '@/types/timeline'
@@ -11,6 +12,7 @@ import { usePlaybackStore } from '@/shared/state/playback'
 import { resolveEffectiveTrackStates } from '@/features/preview/deps/timeline-utils'
 import { useCompositionsStore, useItemsStore } from '@/features/preview/deps/timeline-store'
 import { appendVirtualTranscriptCaptionTrack } from '@/features/preview/deps/caption-items'
+import { expandMulticamItems, expandMulticamKeyframes } from '@/shared/utils/multicam'
 import { useCornerPinStore } from '../stores/corner-pin-store'
 import { useGizmoStore } from '../stores/gizmo-store'
 import { useMaskEditorStore } from '../stores/mask-editor-store'
@@ -55,6 +57,7 @@ interface PreviewProject {
   width: number
   height: number
   backgroundColor?: string
+  colorSpace?: ProjectColorSpace
 }
 
 interface BuildPreviewCompositionDataParams {
@@ -302,7 +305,8 @@ export function buildPreviewCompositionData({
     const resolvedItems: typeof track.items = []
     const fastScrubItems: typeof track.items = []
 
-    for (const item of track.items) {
+    // Multicam items preview as their visible angle cuts plus the audio angle
+    for (const item of expandMulticamItems(track.items, fps)) {
       if (
         !item.mediaId ||
         (item.type !== 'video' && item.type !== 'audio' && item.type !== 'image')
@@ -399,6 +403,7 @@ export function buildPreviewCompositionData({
     0,
   )
   const totalFrames = furthestItemEndFrame === 0 ? 900 : furthestItemEndFrame + fps * 5
+  const expandedKeyframes = expandMulticamKeyframes(keyframes, items, fps)
   const inputProps: CompositionInputProps = {
     fps,
     width: project.width,
@@ -406,7 +411,8 @@ export function buildPreviewCompositionData({
     tracks: resolvedTracks as CompositionInputProps['tracks'],
     transitions,
     backgroundColor: project.backgroundColor,
-    keyframes,
+    colorSpace: project.colorSpace,
+    keyframes: expandedKeyframes,
     busAudioEq,
   }
   const playerRenderSize = {
@@ -418,7 +424,7 @@ export function buildPreviewCompositionData({
     height: Math.max(2, Math.max(1, Math.round(project.height))),
   }
   const fastScrubScaledTracks = fastScrubTracks as CompositionInputProps['tracks']
-  const fastScrubScaledKeyframes = keyframes
+  const fastScrubScaledKeyframes = expandedKeyframes
   const fastScrubInputProps: CompositionInputProps = {
     fps,
     width: project.width,
@@ -426,6 +432,7 @@ export function buildPreviewCompositionData({
     tracks: fastScrubScaledTracks,
     transitions,
     backgroundColor: project.backgroundColor,
+    colorSpace: project.colorSpace,
     keyframes: fastScrubScaledKeyframes,
     busAudioEq,
   }
@@ -1384,10 +1384,13 @@ export function usePreviewRendererController({
 
       const task = (async () => {
         try {
-          const liveScopeSnapshot = await captureLiveScopeRenderedSnapshot(options)
-          if (liveScopeSnapshot) return liveScopeSnapshot
-          const displaySnapshot = captureRenderedDisplaySnapshot(options)
-          if (displaySnapshot) return displaySnapshot
+          // Display snapshots are already tone mapped; HDR signal reads need the renderer.
+          if (!options?.hdrSignal) {
+            const liveScopeSnapshot = await captureLiveScopeRenderedSnapshot(options)
+            if (liveScopeSnapshot) return liveScopeSnapshot
+            const displaySnapshot = captureRenderedDisplaySnapshot(options)
+            if (displaySnapshot) return displaySnapshot
+          }
 
           const targetFrame = resolveCaptureTargetFrame(options)
           const useLiveDomProvider =
@@ -1432,19 +1435,19 @@ export function usePreviewRendererController({
               scrubOffscreenRenderedFrameRef.current = targetFrame
             }
 
+            const source =
+              (options?.hdrSignal && 'captureHdrSignal' in renderer
+                ? renderer.captureHdrSignal()
+                : null) ?? offscreen
             let snapshot = captureSnapshotCanvasRef.current
-            if (
-              !snapshot ||
-              snapshot.width !== offscreen.width ||
-              snapshot.height !== offscreen.height
-            ) {
-              snapshot = new OffscreenCanvas(offscreen.width, offscreen.height)
+            if (!snapshot || snapshot.width !== source.width || snapshot.height !== source.height) {
+              snapshot = new OffscreenCanvas(source.width, source.height)
               captureSnapshotCanvasRef.current = snapshot
             }
             const snapshotCtx = snapshot.getContext('2d')
             if (!snapshotCtx) return null
             snapshotCtx.clearRect(0, 0, snapshot.width, snapshot.height)
-            snapshotCtx.drawImage(offscreen, 0, 0)
+            snapshotCtx.drawImage(source, 0, 0)
             return snapshot
           })
         } catch (error) {
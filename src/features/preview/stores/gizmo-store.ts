@@ -106,6 +106,8 @@ export interface ItemPropertiesPreview {
   innerRadius?: number
   // Mask properties
   maskFeather?: number
+  maskExpansion?: number
+  maskMotionBlur?: number
 }
 
 /**
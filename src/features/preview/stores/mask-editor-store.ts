@@ -3,11 +3,37 @@
  *
  * Manages state for the interactive bezier path editor overlay used by
  * shape masks. Tracks which path/vertex is being edited and provides
- * live preview during drag operations.
+ * live preview during drag operations. Also drives path animation: path
+ * keyframes at the playhead and tracking a mask forward/backward through
+ * the clip underneath it.
  */
 
 import { create } from 'zustand'
 import type { MaskVertex } from '@/types/masks'
+import type { CanvasSettings } from '@/types/transform'
+import { usePlaybackStore } from '@/shared/state/playback'
+import { useItemsStore, useKeyframesStore, useTimelineStore } from '../deps/timeline-store'
+import { resolveItemTransformAtFrame } from '../deps/composition-runtime'
+import {
+  removeMaskPathKeyframe,
+  resolveAnimatedMaskShape,
+  setMaskPathKeyframe,
+} from '../deps/keyframes'
+import {
+  findMaskTrackingClip,
+  getMaskTrackRegion,
+  planTrackedMaskPathKeyframes,
+  trackMaskRegionPlanar,
+  type MaskRegionTracker,
+  type MaskTrackDirection,
+} from '../utils/mask-tracking'
+
+let maskTrackingAbortController: AbortController | null = null
+
+function getEditingPathItem(itemId: string | null) {
+  const item = itemId ? useItemsStore.getState().itemById[itemId] : undefined
+  return item?.type === 'shape' && item.shapeType === 'path' ? item : null
+}
 
 function normalizeVertexSelection(vertexIndices: number[]): number[] {
   return [...new Set(vertexIndices.filter((index) => Number.isInteger(index) && index >= 0))].sort(
@@ -56,6 +82,14 @@ interface MaskEditorState {
   convertSelectedVertexRequestVersion: number
   /** Requested knot conversion mode for the current selection */
   convertSelectedVertexRequestMode: 'corner' | 'bezier' | null
+  /** Monotonic counter to request setting the selected knots' feather */
+  setVertexFeatherRequestVersion: number
+  /** Requested per-vertex feather in px (null = use the mask feather) */
+  setVertexFeatherRequestValue: number | null
+
+  // --- Mask tracking ---
+  /** Active mask tracking run (null when idle) */
+  maskTracking: { direction: MaskTrackDirection; progress: number } | null
 }
 
 interface MaskEditorActions {
@@ -105,6 +139,23 @@ interface MaskEditorActions {
   requestCancelPenMode: () => void
   /** Request converting the selected knot selection from external UI */
   requestConvertSelectedVertex: (mode: 'corner' | 'bezier') => void
+  /** Request setting the selected knots' feather from external UI */
+  requestSetSelectedVertexFeather: (feather: number | null) => void
+
+  // --- Path animation ---
+  /** Add a path keyframe at the playhead, or remove the one already there */
+  togglePathKeyframe: () => void
+  /**
+   * Track the edited mask from the playhead to its start or end and write
+   * the result as path keyframes. Resolves with the number of tracked frames.
+   */
+  trackMask: (
+    direction: MaskTrackDirection,
+    canvas: CanvasSettings,
+    tracker?: MaskRegionTracker,
+  ) => Promise<number>
+  /** Stop an active tracking run, keeping the frames tracked so far */
+  cancelMaskTracking: () => void
 }
 
 export const useMaskEditorStore = create<MaskEditorState & MaskEditorActions>()((set, get) => ({
@@ -126,6 +177,9 @@ export const useMaskEditorStore = create<MaskEditorState & MaskEditorActions>()(
   cancelPenRequestVersion: 0,
   convertSelectedVertexRequestVersion: 0,
   convertSelectedVertexRequestMode: null,
+  setVertexFeatherRequestVersion: 0,
+  setVertexFeatherRequestValue: null,
+  maskTracking: null,
 
   startEditing: (itemId) =>
     set({
@@ -147,6 +201,8 @@ export const useMaskEditorStore = create<MaskEditorState & MaskEditorActions>()(
       cancelPenRequestVersion: 0,
       convertSelectedVertexRequestVersion: 0,
       convertSelectedVertexRequestMode: null,
+      setVertexFeatherRequestVersion: 0,
+      setVertexFeatherRequestValue: null,
     }),
 
   stopEditing: () =>
@@ -169,6 +225,8 @@ export const useMaskEditorStore = create<MaskEditorState & MaskEditorActions>()(
       cancelPenRequestVersion: 0,
       convertSelectedVertexRequestVersion: 0,
       convertSelectedVertexRequestMode: null,
+      setVertexFeatherRequestVersion: 0,
+      setVertexFeatherRequestValue: null,
     }),
 
   selectVertices: (vertexIndices, primaryIndex = null) =>
@@ -244,6 +302,8 @@ export const useMaskEditorStore = create<MaskEditorState & MaskEditorActions>()(
       cancelPenRequestVersion: 0,
       convertSelectedVertexRequestVersion: 0,
       convertSelectedVertexRequestMode: null,
+      setVertexFeatherRequestVersion: 0,
+      setVertexFeatherRequestValue: null,
     }),
 
   cancelPenMode: () =>
@@ -266,6 +326,8 @@ export const useMaskEditorStore = create<MaskEditorState & MaskEditorActions>()(
       cancelPenRequestVersion: 0,
       convertSelectedVertexRequestVersion: 0,
       convertSelectedVertexRequestMode: null,
+      setVertexFeatherRequestVersion: 0,
+      setVertexFeatherRequestValue: null,
     }),
 
   addPenVertex: (vertex) =>
@@ -314,6 +376,8 @@ export const useMaskEditorStore = create<MaskEditorState & MaskEditorActions>()(
       cancelPenRequestVersion: 0,
       convertSelectedVertexRequestVersion: 0,
       convertSelectedVertexRequestMode: null,
+      setVertexFeatherRequestVersion: 0,
+      setVertexFeatherRequestValue: null,
     }),
 
   requestFinishPenMode: () =>
@@ -331,4 +395,104 @@ export const useMaskEditorStore = create<MaskEditorState & MaskEditorActions>()(
       convertSelectedVertexRequestVersion: state.convertSelectedVertexRequestVersion + 1,
       convertSelectedVertexRequestMode: mode,
     })),
+
+  requestSetSelectedVertexFeather: (feather) =>
+    set((state) => ({
+      setVertexFeatherRequestVersion: state.setVertexFeatherRequestVersion + 1,
+      setVertexFeatherRequestValue: feather,
+    })),
+
+  // --- Path animation ---
+  togglePathKeyframe: () => {
+    const item = getEditingPathItem(get().editingItemId)
+    if (!item) return
+    const frame = usePlaybackStore.getState().currentFrame - item.from
+    if (frame < 0 || frame >= item.durationInFrames) return
+
+    const vertices = resolveAnimatedMaskShape(item, frame).pathVertices ?? []
+    const { commitMaskEdit } = useTimelineStore.getState()
+    if (item.pathKeyframes?.some((keyframe) => keyframe.frame === frame)) {
+      const pathKeyframes = removeMaskPathKeyframe(item.pathKeyframes, frame)
+      // Keep the last animated pose as the static path once animation is gone.
+      commitMaskEdit(item.id, {
+        pathKeyframes,
+        ...(pathKeyframes.length === 0 ? { pathVertices: vertices } : {}),
+      })
+      return
+    }
+    if (vertices.length > 0) {
+      commitMaskEdit(item.id, {
+        pathKeyframes: setMaskPathKeyframe(item.pathKeyframes, frame, vertices),
+      })
+    }
+  },
+
+  trackMask: async (direction, canvas, tracker = trackMaskRegionPlanar) => {
+    const mask = getEditingPathItem(get().editingItemId)
+    if (!mask || get().maskTracking) return 0
+
+    const frame = usePlaybackStore.getState().currentFrame
+    const { items, tracks } = useItemsStore.getState()
+    const clip = findMaskTrackingClip(mask, tracks, items, frame)
+    if (!clip) throw new Error('No video clip under the mask at the playhead')
+
+    const { keyframesByItemId } = useKeyframesStore.getState()
+    const maskTransform = resolveItemTransformAtFrame(mask, {
+      canvas,
+      frame,
+      keyframes: keyframesByItemId[mask.id],
+    })
+    const vertices = resolveAnimatedMaskShape(mask, frame - mask.from).pathVertices ?? []
+    const region = getMaskTrackRegion(vertices, maskTransform, clip, canvas)
+    if (!region) throw new Error('The mask does not overlap the clip')
+
+    // Track to the mask's start or end, within the clip.
+    const startFrame = frame - clip.from
+    const endFrame =
+      direction === 'forward'
+        ? Math.min(clip.durationInFrames, mask.from + mask.durationInFrames - clip.from)
+        : Math.max(0, mask.from - clip.from) - 1
+    if (Math.abs(endFrame - startFrame) < 2) return 0
+
+    const abortController = new AbortController()
+    maskTrackingAbortController = abortController
+    set({ maskTracking: { direction, progress: 0 } })
+    try {
+      const track = await tracker({
+        clip,
+        clipKeyframes: keyframesByItemId[clip.id],
+        region,
+        startFrame,
+        endFrame,
+        fps: canvas.fps,
+        onProgress: (progress) => set({ maskTracking: { direction, progress } }),
+        signal: abortController.signal,
+      })
+      const latestMask = getEditingPathItem(mask.id)
+      if (!latestMask || track.samples.length === 0) return 0
+
+      useTimelineStore.getState().commitMaskEdit(mask.id, {
+        pathKeyframes: planTrackedMaskPathKeyframes({
+          track,
+          mask: latestMask,
+          maskTransform,
+          vertices,
+          clip,
+          startFrame,
+          direction,
+          canvas,
+        }),
+      })
+      return track.samples.length
+    } finally {
+      if (maskTrackingAbortController === abortController) {
+        maskTrackingAbortController = null
+      }
+      set({ maskTracking: null })
+    }
+  },
+
+  cancelMaskTracking: () => {
+    maskTrackingAbortController?.abort()
+  },
 }))
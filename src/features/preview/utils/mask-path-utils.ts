@@ -8,6 +8,7 @@ const DEFAULT_BEZIER_HANDLE_SCALE = 0.25
 
 function cloneVertex(vertex: MaskVertex): MaskVertex {
   return {
+    ...vertex,
     position: [...vertex.position] as [number, number],
     inHandle: [...vertex.inHandle] as [number, number],
     outHandle: [...vertex.outHandle] as [number, number],
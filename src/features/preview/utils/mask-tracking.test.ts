@@ -0,0 +1,202 @@
+import { describe, expect, it, vi } from 'vite-plus/test'
+import type { MotionTrack, MotionTrackSample } from '@/infrastructure/analysis/motion-tracking'
+import type { MaskVertex } from '@/types/masks'
+import type { ShapeItem, TimelineTrack, VideoItem } from '@/types/timeline'
+import type { ResolvedTransform } from '@/types/transform'
+
+vi.mock('../deps/timeline-utils', () => ({
+  // Clips fill the canvas in these tests.
+  getItemContentRect: (_item: unknown, canvas: { width: number; height: number }) => ({
+    x: 0,
+    y: 0,
+    width: canvas.width,
+    height: canvas.height,
+  }),
+  trackClipRegion: vi.fn(),
+}))
+
+import {
+  findMaskTrackingClip,
+  getMaskTrackRegion,
+  planTrackedMaskPathKeyframes,
+} from './mask-tracking'
+
+const CANVAS = { width: 1000, height: 500, fps: 30 }
+
+// A 200x100 box centered on the canvas: pixels 400..600 x 200..300.
+const MASK_TRANSFORM: ResolvedTransform = {
+  x: 0,
+  y: 0,
+  width: 200,
+  height: 100,
+  rotation: 0,
+  opacity: 1,
+  cornerRadius: 0,
+}
+
+const SQUARE: MaskVertex[] = [
+  { position: [0, 0], inHandle: [0, 0], outHandle: [0, 0] },
+  { position: [1, 0], inHandle: [0, 0], outHandle: [0, 0] },
+  { position: [1, 1], inHandle: [0, 0], outHandle: [0, 0] },
+  { position: [0, 1], inHandle: [0, 0], outHandle: [0, 0], feather: 8 },
+]
+
+function makeMask(overrides: Partial<ShapeItem> = {}): ShapeItem {
+  return {
+    id: 'mask-1',
+    type: 'shape',
+    trackId: 'track-mask',
+    from: 5,
+    durationInFrames: 20,
+    label: 'Mask',
+    shapeType: 'path',
+    fillColor: '#ffffff',
+    isMask: true,
+    pathVertices: SQUARE,
+    ...overrides,
+  } as ShapeItem
+}
+
+function makeClip(id: string, trackId: string, from = 0): VideoItem {
+  return {
+    id,
+    type: 'video',
+    trackId,
+    from,
+    durationInFrames: 60,
+    label: id,
+    src: 'blob:clip',
+  } as VideoItem
+}
+
+function sample(
+  frame: number,
+  center: [number, number],
+  { rotation = 0, scale = 1 } = {},
+): MotionTrackSample {
+  return {
+    frame,
+    center,
+    corners: [center, center, center, center],
+    rotation,
+    scale,
+    confidence: 1,
+  }
+}
+
+describe('findMaskTrackingClip', () => {
+  const tracks = [
+    { id: 'track-mask', order: 0 },
+    { id: 'track-hidden', order: 1, visible: false },
+    { id: 'track-near', order: 2 },
+    { id: 'track-far', order: 3 },
+  ] as TimelineTrack[]
+
+  it('picks the video under the playhead on the nearest visible track below', () => {
+    const items = [
+      makeClip('hidden', 'track-hidden'),
+      makeClip('far', 'track-far'),
+      makeClip('near', 'track-near'),
+      makeClip('later', 'track-near', 100),
+    ]
+
+    expect(findMaskTrackingClip(makeMask(), tracks, items, 10)?.id).toBe('near')
+    expect(findMaskTrackingClip(makeMask(), tracks, items, 120)?.id).toBe('later')
+    expect(findMaskTrackingClip(makeMask(), tracks, items, 70)).toBeUndefined()
+  })
+})
+
+describe('getMaskTrackRegion', () => {
+  it('returns the mask bounds normalized to the clip picture', () => {
+    const region = getMaskTrackRegion(SQUARE, MASK_TRANSFORM, makeClip('clip', 'track'), CANVAS)
+
+    expect(region?.x).toBeCloseTo(0.4)
+    expect(region?.y).toBeCloseTo(0.4)
+    expect(region?.width).toBeCloseTo(0.2)
+    expect(region?.height).toBeCloseTo(0.2)
+  })
+
+  it('returns null when the mask lies outside the clip', () => {
+    const offscreen = { ...MASK_TRANSFORM, x: 2000 }
+
+    expect(getMaskTrackRegion(SQUARE, offscreen, makeClip('clip', 'track'), CANVAS)).toBeNull()
+  })
+})
+
+describe('planTrackedMaskPathKeyframes', () => {
+  const clip = makeClip('clip', 'track-clip')
+
+  it('moves the start-frame path with the track on successive mask frames', () => {
+    const track: MotionTrack = {
+      mode: 'planar',
+      region: { x: 0.4, y: 0.4, width: 0.2, height: 0.2 },
+      samples: [sample(0, [0.5, 0.5]), sample(1, [0.6, 0.5])],
+    }
+
+    const keyframes = planTrackedMaskPathKeyframes({
+      track,
+      mask: makeMask(),
+      maskTransform: MASK_TRANSFORM,
+      vertices: SQUARE,
+      clip,
+      startFrame: 10,
+      direction: 'forward',
+      canvas: CANVAS,
+    })
+
+    expect(keyframes.map((keyframe) => keyframe.frame)).toEqual([5, 6])
+    expect(keyframes[0]?.vertices[1]?.position[0]).toBeCloseTo(1)
+    // 100px to the right is half the 200px mask box.
+    expect(keyframes[1]?.vertices[1]?.position[0]).toBeCloseTo(1.5)
+    expect(keyframes[1]?.vertices[3]?.feather).toBe(8)
+  })
+
+  it('applies planar scale about the tracked center and steps backward', () => {
+    const track: MotionTrack = {
+      mode: 'planar',
+      region: { x: 0.4, y: 0.4, width: 0.2, height: 0.2 },
+      samples: [sample(0, [0.5, 0.5]), sample(1, [0.5, 0.5], { scale: 2 })],
+    }
+
+    const keyframes = planTrackedMaskPathKeyframes({
+      track,
+      mask: makeMask(),
+      maskTransform: MASK_TRANSFORM,
+      vertices: SQUARE,
+      clip,
+      startFrame: 10,
+      direction: 'backward',
+      canvas: CANVAS,
+    })
+
+    expect(keyframes.map((keyframe) => keyframe.frame)).toEqual([4, 5])
+    expect(keyframes[0]?.vertices[0]?.position[0]).toBeCloseTo(-0.5)
+    expect(keyframes[0]?.vertices[0]?.position[1]).toBeCloseTo(-0.5)
+  })
+
+  it('drops samples outside the mask and keeps existing keyframes', () => {
+    const track: MotionTrack = {
+      mode: 'point',
+      region: { x: 0.4, y: 0.4, width: 0.2, height: 0.2 },
+      samples: [sample(0, [0.5, 0.5]), sample(1, [0.6, 0.5])],
+    }
+    const mask = makeMask({
+      durationInFrames: 1,
+      pathKeyframes: [{ id: 'existing', frame: 0, vertices: SQUARE, easing: 'linear' }],
+    })
+
+    const keyframes = planTrackedMaskPathKeyframes({
+      track,
+      mask,
+      maskTransform: MASK_TRANSFORM,
+      vertices: SQUARE,
+      clip,
+      startFrame: 5,
+      direction: 'forward',
+      canvas: CANVAS,
+    })
+
+    expect(keyframes).toHaveLength(1)
+    expect(keyframes[0]?.id).toBe('existing')
+  })
+})
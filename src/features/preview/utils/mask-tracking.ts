@@ -0,0 +1,205 @@
+/**
+ * Mask tracking: follow a region under a mask through a video clip and turn
+ * the track into path keyframes on the mask.
+ *
+ * The tracked region is the mask's bounding box over the clip. Each track
+ * sample's similarity transform (translation, plus rotation and scale for
+ * planar tracks) is applied to the mask path as it was on the start frame.
+ */
+
+import type {
+  MotionTrack,
+  MotionTrackRegion,
+  TrackPoint,
+} from '@/infrastructure/analysis/motion-tracking'
+import type { ItemKeyframes } from '@/types/keyframe'
+import type { MaskPathKeyframe, MaskVertex } from '@/types/masks'
+import type { ShapeItem, TimelineItem, TimelineTrack, VideoItem } from '@/types/timeline'
+import type { CanvasSettings, ResolvedTransform } from '@/types/transform'
+import { setMaskPathKeyframe } from '../deps/keyframes'
+import { getItemContentRect, trackClipRegion } from '../deps/timeline-utils'
+
+export type MaskTrackDirection = 'forward' | 'backward'
+
+const MIN_REGION_SIZE = 0.01
+
+export interface MaskRegionTrackRequest {
+  clip: VideoItem
+  clipKeyframes: ItemKeyframes | undefined
+  region: MotionTrackRegion
+  /** Clip-relative frame the region was taken from */
+  startFrame: number
+  /** Clip-relative end frame (exclusive); before `startFrame` to track backward */
+  endFrame: number
+  fps: number
+  onProgress?: (percent: number) => void
+  signal?: AbortSignal
+}
+
+/** Tracks a region through a clip; sample `i` is `i` frames from the start. */
+export type MaskRegionTracker = (request: MaskRegionTrackRequest) => Promise<MotionTrack>
+
+/** Default tracker: the planar optical-flow tracker used by motion tracking. */
+export const trackMaskRegionPlanar: MaskRegionTracker = ({ clip, clipKeyframes, ...request }) =>
+  trackClipRegion(clip, clipKeyframes, { mode: 'planar', ...request })
+
+/**
+ * The video clip a mask cuts at `frame`: the video item under the playhead on
+ * the nearest visible track below the mask.
+ */
+export function findMaskTrackingClip(
+  mask: ShapeItem,
+  tracks: TimelineTrack[],
+  items: TimelineItem[],
+  frame: number,
+): VideoItem | undefined {
+  const maskTrack = tracks.find((track) => track.id === mask.trackId)
+  if (!maskTrack) return undefined
+  const trackOrders = new Map(
+    tracks
+      .filter((track) => track.visible !== false && track.order > maskTrack.order)
+      .map((track) => [track.id, track.order]),
+  )
+
+  return items
+    .filter(
+      (item): item is VideoItem =>
+        item.type === 'video' &&
+        trackOrders.has(item.trackId) &&
+        frame >= item.from &&
+        frame < item.from + item.durationInFrames,
+    )
+    .sort((a, b) => trackOrders.get(a.trackId)! - trackOrders.get(b.trackId)!)[0]
+}
+
+/** Map between a mask's normalized path space and canvas pixels. */
+function createMaskSpace(transform: ResolvedTransform, canvas: CanvasSettings) {
+  const centerX = canvas.width / 2 + transform.x
+  const centerY = canvas.height / 2 + transform.y
+  const angle = (transform.rotation * Math.PI) / 180
+  const cos = Math.cos(angle)
+  const sin = Math.sin(angle)
+  const width = Math.max(transform.width, 1)
+  const height = Math.max(transform.height, 1)
+
+  const rotate = ([x, y]: TrackPoint, direction: 1 | -1): TrackPoint => [
+    x * cos - direction * y * sin,
+    direction * x * sin + y * cos,
+  ]
+
+  return {
+    toCanvas: ([x, y]: TrackPoint): TrackPoint => {
+      const [dx, dy] = rotate([(x - 0.5) * width, (y - 0.5) * height], 1)
+      return [centerX + dx, centerY + dy]
+    },
+    fromCanvas: ([x, y]: TrackPoint): TrackPoint => {
+      const [dx, dy] = rotate([x - centerX, y - centerY], -1)
+      return [dx / width + 0.5, dy / height + 0.5]
+    },
+    vectorToCanvas: ([x, y]: TrackPoint): TrackPoint => rotate([x * width, y * height], 1),
+    vectorFromCanvas: (vector: TrackPoint): TrackPoint => {
+      const [dx, dy] = rotate(vector, -1)
+      return [dx / width, dy / height]
+    },
+  }
+}
+
+/**
+ * Region to track: the mask path's bounds within the clip's picture,
+ * normalized to the clip source. Null when the mask doesn't overlap it.
+ */
+export function getMaskTrackRegion(
+  vertices: MaskVertex[],
+  maskTransform: ResolvedTransform,
+  clip: VideoItem,
+  canvas: CanvasSettings,
+): MotionTrackRegion | null {
+  if (vertices.length === 0) return null
+  const space = createMaskSpace(maskTransform, canvas)
+  const clipRect = getItemContentRect(clip, canvas)
+  if (clipRect.width <= 0 || clipRect.height <= 0) return null
+
+  const points = vertices.map((vertex) => space.toCanvas(vertex.position))
+  const clamp = (value: number) => Math.max(0, Math.min(1, value))
+  const left = clamp((Math.min(...points.map((p) => p[0])) - clipRect.x) / clipRect.width)
+  const right = clamp((Math.max(...points.map((p) => p[0])) - clipRect.x) / clipRect.width)
+  const top = clamp((Math.min(...points.map((p) => p[1])) - clipRect.y) / clipRect.height)
+  const bottom = clamp((Math.max(...points.map((p) => p[1])) - clipRect.y) / clipRect.height)
+  if (right - left < MIN_REGION_SIZE || bottom - top < MIN_REGION_SIZE) return null
+
+  return { x: left, y: top, width: right - left, height: bottom - top }
+}
+
+/**
+ * Turn a region track into path keyframes on `mask`, merged into its
+ * existing keyframes. Samples that land outside the mask's span are dropped.
+ *
+ * @param startFrame - Clip-relative frame the track started from
+ * @param vertices - Mask path on the start frame
+ * @param maskTransform - Mask transform on the start frame; tracked paths
+ *   are expressed in this box
+ */
+export function planTrackedMaskPathKeyframes({
+  track,
+  mask,
+  maskTransform,
+  vertices,
+  clip,
+  startFrame,
+  direction,
+  canvas,
+}: {
+  track: MotionTrack
+  mask: ShapeItem
+  maskTransform: ResolvedTransform
+  vertices: MaskVertex[]
+  clip: VideoItem
+  startFrame: number
+  direction: MaskTrackDirection
+  canvas: CanvasSettings
+}): MaskPathKeyframe[] {
+  const origin = track.samples[0]
+  if (!origin) return mask.pathKeyframes ?? []
+
+  const space = createMaskSpace(maskTransform, canvas)
+  const clipRect = getItemContentRect(clip, canvas)
+  const toClipCanvas = ([x, y]: TrackPoint): TrackPoint => [
+    clipRect.x + x * clipRect.width,
+    clipRect.y + y * clipRect.height,
+  ]
+  const originCenter = toClipCanvas(origin.center)
+  const isPlanar = track.mode === 'planar'
+  const step = direction === 'forward' ? 1 : -1
+
+  let keyframes = mask.pathKeyframes ?? []
+  for (const sample of track.samples) {
+    const maskFrame = clip.from + startFrame + step * sample.frame - mask.from
+    if (maskFrame < 0 || maskFrame >= mask.durationInFrames) continue
+
+    const center = toClipCanvas(sample.center)
+    const theta = isPlanar ? (sample.rotation * Math.PI) / 180 : 0
+    const scale = isPlanar ? sample.scale : 1
+    const cos = Math.cos(theta) * scale
+    const sin = Math.sin(theta) * scale
+    const transformVector = ([x, y]: TrackPoint): TrackPoint => [
+      cos * x - sin * y,
+      sin * x + cos * y,
+    ]
+    const transformHandle = (handle: TrackPoint) =>
+      space.vectorFromCanvas(transformVector(space.vectorToCanvas(handle)))
+
+    const tracked = vertices.map((vertex) => {
+      const [px, py] = space.toCanvas(vertex.position)
+      const [dx, dy] = transformVector([px - originCenter[0], py - originCenter[1]])
+      return {
+        ...vertex,
+        position: space.fromCanvas([center[0] + dx, center[1] + dy]),
+        inHandle: transformHandle(vertex.inHandle),
+        outHandle: transformHandle(vertex.outHandle),
+      }
+    })
+    keyframes = setMaskPathKeyframe(keyframes, maskFrame, tracked)
+  }
+
+  return keyframes
+}
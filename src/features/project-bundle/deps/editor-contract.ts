@@ -0,0 +1,6 @@
+/**
+ * Adapter exports for editor dependencies.
+ * Project-bundle modules should import project/media matching helpers from here.
+ */
+
+export { getProjectMediaMatchSuggestion } from '@/features/editor/utils/project-media-match'
@@ -0,0 +1,5 @@
+/**
+ * Compatibility adapter that re-exports through editor-contract.
+ */
+
+export * from './editor-contract'
@@ -0,0 +1,6 @@
+/**
+ * Adapter exports for export dependencies.
+ * Project-bundle modules should import render-timeline utilities from here.
+ */
+
+export { convertTimelineToComposition } from '@/features/export/utils/timeline-to-composition'
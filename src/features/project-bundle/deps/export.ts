@@ -0,0 +1,5 @@
+/**
+ * Compatibility adapter that re-exports through export-contract.
+ */
+
+export * from './export-contract'
@@ -75,6 +75,7 @@ const itemTypeSchema = z.enum([
   'shape',
   'composition',
   'adjustment',
+  'multicam',
   'subtitle',
 ])
 
@@ -154,6 +155,15 @@ const maskVertexSchema = z.object({
   position: z.tuple([z.number(), z.number()]),
   inHandle: z.tuple([z.number(), z.number()]),
   outHandle: z.tuple([z.number(), z.number()]),
+  feather: z.number().min(0).optional(),
+})
+
+const maskPathKeyframeSchema = z.object({
+  id: z.string().min(1),
+  frame: z.number().int().min(0),
+  vertices: z.array(maskVertexSchema),
+  easing: easingTypeSchema,
+  easingConfig: easingConfigSchema.optional(),
 })
 
 // ============================================================================
@@ -336,6 +346,24 @@ const cornerPinSchema = z.object({
   referenceHeight: z.number().positive().optional(),
 })
 
+const multicamAngleSchema = z.object({
+  id: z.string().min(1),
+  label: z.string(),
+  mediaId: z.string().min(1),
+  src: z.string(),
+  sourceFps: z.number().positive(),
+  sourceDuration: z.number().min(0),
+  sourceWidth: z.number().positive().optional(),
+  sourceHeight: z.number().positive().optional(),
+  syncOffset: z.number().min(0),
+})
+
+const multicamAngleSegmentSchema = z.object({
+  id: z.string().min(1),
+  startFrame: z.number(),
+  angleId: z.string().min(1),
+})
+
 const timelineItemSchema = z
   .object({
     id: z.string().min(1),
@@ -391,13 +419,18 @@ const timelineItemSchema = z
     points: z.number().optional(),
     innerRadius: z.number().optional(),
     pathVertices: z.array(maskVertexSchema).optional(),
+    pathKeyframes: z.array(maskPathKeyframeSchema).optional(),
     // Mask fields
     isMask: z.boolean().optional(),
     maskType: maskTypeSchema.optional(),
     maskFeather: z.number().min(0).max(100).optional(),
     maskInvert: z.boolean().optional(),
+    maskMatteItemId: z.string().optional(),
+    maskExpansion: z.number().optional(),
+    maskMotionBlur: z.number().min(0).max(360).optional(),
     // Speed
     speed: z.number().min(0.1).max(10).optional(),
+    frameBlending: z.enum(['nearest', 'blend', 'optical-flow']).optional(),
     // Source dimensions
     sourceWidth: z.number().optional(),
     sourceHeight: z.number().optional(),
@@ -464,6 +497,11 @@ const timelineItemSchema = z
     // Composition item fields
     compositionWidth: z.number().optional(),
     compositionHeight: z.number().optional(),
+    // Multicam item fields
+    angles: z.array(multicamAngleSchema).optional(),
+    angleSegments: z.array(multicamAngleSegmentSchema).optional(),
+    audioAngleId: z.string().optional(),
+    syncMethod: z.enum(['audio', 'timecode']).optional(),
     // Layer compositing
     blendMode: z.string().optional(),
     cornerPin: cornerPinSchema.optional(),
@@ -575,12 +613,48 @@ const projectResolutionSchema = z.object({
     .string()
     .regex(/^#[0-9A-Fa-f]{6}$/)
     .optional(),
+  colorSpace: z.enum(['rec709', 'rec2100-pq', 'rec2100-hlg']).optional(),
 })
 
 // ============================================================================
 // Project Schema
 // ============================================================================
 
+const projectReframeLinkSchema = z.object({
+  sourceProjectId: z.string().min(1),
+  aspect: z.enum(['16:9', '9:16', '1:1']),
+  reframedAt: z.number().int().min(0),
+})
+
+const canvasVariantOverrideSchema = z.object({
+  itemId: z.string().min(1),
+  transform: z
+    .object({
+      x: z.number().optional(),
+      y: z.number().optional(),
+      width: z.number().optional(),
+      height: z.number().optional(),
+      rotation: z.number().optional(),
+    })
+    .optional(),
+  crop: cropSchema.optional(),
+  text: z
+    .object({
+      fontSize: z.number().optional(),
+      textAlign: textAlignSchema.optional(),
+      verticalAlign: verticalAlignSchema.optional(),
+      lineHeight: z.number().optional(),
+    })
+    .optional(),
+})
+
+const canvasVariantSchema = z.object({
+  id: z.string().min(1),
+  name: z.string().min(1).max(100),
+  aspect: z.enum(['16:9', '9:16', '4:5', '1:1']),
+  overrides: z.array(canvasVariantOverrideSchema),
+})
+
 const projectSchema = z
   .object({
     id: z.string().min(1),
@@ -594,6 +668,8 @@ const projectSchema = z
     thumbnailId: z.string().optional(),
     metadata: projectResolutionSchema,
     timeline: timelineSchema.optional(),
+    reframe: projectReframeLinkSchema.optional(),
+    canvasVariants: z.array(canvasVariantSchema).optional(),
   })
   .passthrough()
 
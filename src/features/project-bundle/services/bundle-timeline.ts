@@ -25,6 +25,13 @@ function restoreItemsFromBundle(
     return {
       ...rest,
       ...(mediaRef && { mediaId: mediaIdMap.get(mediaRef) }),
+      ...(rest.angles && {
+        angles: rest.angles.map((angle) => ({
+          ...angle,
+          mediaId: mediaIdMap.get(angle.mediaId) ?? angle.mediaId,
+          src: '',
+        })),
+      }),
       src: undefined,
       thumbnailUrl: undefined,
     }
@@ -0,0 +1,237 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { ProjectTimeline } from '@/types/project'
+import type { InterchangeMediaReference } from '../types/interchange'
+import {
+  framesToTimecode,
+  parseEdl,
+  projectToEdl,
+  reelNameForFile,
+  timecodeToFrames,
+} from './edl-converter'
+import {
+  exportTimeline,
+  makeTimeline,
+  mediaFields,
+  TEST_CLIP,
+  TEST_METADATA,
+} from './interchange-test-helpers'
+
+const METADATA = { ...TEST_METADATA, fps: 25 }
+
+const CLIP: InterchangeMediaReference = { ...TEST_CLIP, duration: 60, fps: 25 }
+
+const INTERVIEW: InterchangeMediaReference = {
+  ...CLIP,
+  id: 'media-2',
+  fileName: 'Interview Take 2.mov',
+  path: '/Volumes/Footage/Interview Take 2.mov',
+  mimeType: 'video/quicktime',
+  hasAudio: false,
+}
+
+function sampleTimeline(): ProjectTimeline {
+  return makeTimeline({
+    items: [
+      {
+        id: 'title-1',
+        trackId: 'track-v2',
+        type: 'text',
+        from: 0,
+        durationInFrames: 50,
+        label: 'Title',
+        text: 'Hello',
+        color: '#ffffff',
+      },
+      {
+        id: 'clip-a',
+        trackId: 'track-v1',
+        type: 'video',
+        from: 0,
+        durationInFrames: 50,
+        label: 'clip.mp4',
+        linkedGroupId: 'link-a',
+        ...mediaFields(CLIP, 100, 150),
+      },
+      {
+        id: 'clip-a-audio',
+        trackId: 'track-a1',
+        type: 'audio',
+        from: 0,
+        durationInFrames: 50,
+        label: 'clip.mp4',
+        linkedGroupId: 'link-a',
+        ...mediaFields(CLIP, 100, 150),
+      },
+      {
+        id: 'clip-b',
+        trackId: 'track-v1',
+        type: 'video',
+        from: 50,
+        durationInFrames: 75,
+        label: 'Interview',
+        ...mediaFields(INTERVIEW, 25, 100),
+      },
+      {
+        id: 'clip-c',
+        trackId: 'track-v1',
+        type: 'video',
+        from: 150,
+        durationInFrames: 25,
+        label: 'clip.mp4',
+        ...mediaFields(CLIP, 0, 50, 2),
+      },
+    ],
+    transitions: [
+      {
+        id: 'transition-1',
+        type: 'crossfade',
+        presentation: 'fade',
+        timing: 'linear',
+        leftClipId: 'clip-a',
+        rightClipId: 'clip-b',
+        trackId: 'track-v1',
+        durationInFrames: 10,
+        alignment: 0.5,
+      },
+    ],
+  })
+}
+
+function exportEdl(timeline: ProjectTimeline) {
+  return exportTimeline(projectToEdl, timeline, { media: [CLIP, INTERVIEW], metadata: METADATA })
+}
+
+describe('EDL timecode and reels', () => {
+  it('converts between frames and non-drop timecode', () => {
+    expect(framesToTimecode(0, 25)).toBe('00:00:00:00')
+    expect(framesToTimecode(90_024, 25)).toBe('01:00:00:24')
+    expect(timecodeToFrames('01:00:01:20', 25)).toBe(90_045)
+    expect(timecodeToFrames('00:00:01;02', 29.97)).toBe(32)
+    expect(timecodeToFrames('garbage', 25)).toBeNaN()
+  })
+
+  it('derives CMX-safe reel names from file names', () => {
+    expect(reelNameForFile('clip.mp4')).toBe('CLIP')
+    expect(reelNameForFile('Interview Take 2.mov')).toBe('INTERVIE')
+    expect(reelNameForFile('...')).toBe('AX')
+  })
+})
+
+describe('projectToEdl', () => {
+  it('writes one event per clip with dissolves and motion effects', () => {
+    const { content, documents, warnings } = exportEdl(sampleTimeline())
+
+    expect(documents?.map((document) => document.suffix)).toEqual(['V1'])
+    expect(warnings).toEqual([expect.objectContaining({ code: 'unsupported_item', itemId: 'title-1' })])
+    expect(content.split('\n')).toEqual([
+      'TITLE: Cut - V1',
+      'FCM: NON-DROP FRAME',
+      '',
+      '001  CLIP     AA/V  C        00:00:04:00 00:00:05:20 01:00:00:00 01:00:01:20',
+      '* FROM CLIP NAME: clip.mp4',
+      '* SOURCE FILE: media/media-1/source.mp4',
+      '',
+      '002  CLIP     V     C        00:00:05:20 00:00:05:20 01:00:01:20 01:00:01:20',
+      '002  INTERVIE V     D    010 00:00:00:20 00:00:04:00 01:00:01:20 01:00:05:00',
+      '* FROM CLIP NAME: Interview',
+      '* SOURCE FILE: /Volumes/Footage/Interview Take 2.mov',
+      '',
+      '003  CLIP     V     C        00:00:00:00 00:00:01:00 01:00:06:00 01:00:07:00',
+      'M2   CLIP           050.0    00:00:00:00',
+      '* FROM CLIP NAME: clip.mp4',
+      '* SOURCE FILE: media/media-1/source.mp4',
+      '',
+    ])
+  })
+
+  it('writes a document per visible picture track', () => {
+    const timeline = sampleTimeline()
+    timeline.items.push({
+      id: 'clip-d',
+      trackId: 'track-v2',
+      type: 'video',
+      from: 200,
+      durationInFrames: 25,
+      label: 'clip.mp4',
+      ...mediaFields(CLIP, 0, 25),
+    })
+
+    expect(exportEdl(timeline).documents?.map((document) => document.suffix)).toEqual(['V1', 'V2'])
+
+    timeline.tracks[0]!.visible = false
+    expect(exportEdl(timeline).documents?.map((document) => document.suffix)).toEqual(['V1'])
+  })
+
+  it('writes reversed clips with the sign ahead of the padded motion speed', () => {
+    const timeline = sampleTimeline()
+    const clip = timeline.items.find((item) => item.id === 'clip-c')!
+    Object.assign(clip, { speed: 1, durationInFrames: 50, isReversed: true })
+
+    const { content } = exportEdl(timeline)
+    const motionLine = content.split('\n').find((line) => line.startsWith('M2'))
+
+    expect(motionLine).toMatch(/^M2 {3}CLIP {11}-25\.0 {4}\d{2}:\d{2}:\d{2}:\d{2}$/)
+    expect(parseEdl(content, [CLIP, INTERVIEW]).timeline.items).toContainEqual(
+      expect.objectContaining({ type: 'video', from: 150, isReversed: true }),
+    )
+  })
+})
+
+describe('parseEdl', () => {
+  it('rebuilds the timeline written by projectToEdl', () => {
+    const { content } = exportEdl(sampleTimeline())
+    const result = parseEdl(content, [CLIP, INTERVIEW])
+
+    expect(result.name).toBe('Cut - V1')
+    expect(result.metadata).toEqual(METADATA)
+    expect(result.warnings).toEqual([])
+    expect(result.mediaIds).toEqual(['media-1', 'media-2'])
+
+    const { tracks, items, transitions } = result.timeline
+    expect(tracks.map((track) => [track.name, track.kind])).toEqual([
+      ['V1', 'video'],
+      ['A1', 'audio'],
+    ])
+    const video = items.filter((item) => item.type === 'video').sort((a, b) => a.from - b.from)
+    const audio = items.filter((item) => item.type === 'audio')
+    expect(video).toEqual([
+      expect.objectContaining({ from: 0, durationInFrames: 50, sourceStart: 100, sourceEnd: 150 }),
+      expect.objectContaining({ from: 50, durationInFrames: 75, sourceStart: 25, mediaId: 'media-2' }),
+      expect.objectContaining({ from: 150, durationInFrames: 25, sourceStart: 0, speed: 2 }),
+    ])
+    expect(audio).toEqual([expect.objectContaining({ from: 0, durationInFrames: 50, sourceEnd: 150 })])
+    expect(audio[0]!.linkedGroupId).toBeDefined()
+    expect(audio[0]!.linkedGroupId).toBe(video[0]!.linkedGroupId)
+    expect(transitions).toEqual([
+      expect.objectContaining({
+        leftClipId: video[0]!.id,
+        rightClipId: video[1]!.id,
+        durationInFrames: 10,
+        alignment: 0.5,
+      }),
+    ])
+  })
+
+  it('matches bare reels and reports what it cannot conform', () => {
+    const edl = [
+      'TITLE: From the grade',
+      'FCM: DROP FRAME',
+      '',
+      '001  BL       V     C        00:00:00:00 00:00:01:00 00:00:00:00 00:00:01:00',
+      '002  CLIP     V     C        00:00:10:00 00:00:12:00 00:00:01:00 00:00:03:00',
+      '003  UNKNOWN  V     C        00:00:00:00 00:00:01:00 00:00:03:00 00:00:04:00',
+    ].join('\n')
+
+    const result = parseEdl(edl, [CLIP])
+
+    expect(result.name).toBe('From the grade')
+    expect(result.timeline.items).toEqual([
+      expect.objectContaining({ type: 'video', from: 25, durationInFrames: 50, sourceStart: 250 }),
+    ])
+    expect(result.warnings.map((warning) => warning.code)).toEqual(['lossy_value', 'unmatched_media'])
+  })
+
+  it('rejects files without events', () => {
+    expect(() => parseEdl('TITLE: Empty\n', [])).toThrow(/no events/)
+  })
+})
@@ -0,0 +1,634 @@
+/**
+ * EDL Converter
+ *
+ * Pure conversion between FreeCut timelines and CMX3600 edit decision lists.
+ *
+ * Export walks the render timeline from `convertTimelineToComposition`, so
+ * the EDL lists exactly what an export would show (group gating, hidden
+ * tracks, reverse conforms). CMX3600 holds a single picture track, so every
+ * visible video track becomes its own EDL document:
+ * - Reel names are derived from the media file name (8 chars, upper-case),
+ *   with `* FROM CLIP NAME:` / `* SOURCE FILE:` comments for conform tools.
+ * - Clips with a linked audio companion are written on the `AA/V` channel.
+ * - Speed changes are written as `M2` motion effects.
+ * - FreeCut's cut-centered transitions become CMX dissolves: the outgoing
+ *   clip ends where the dissolve starts and the incoming event carries `D`.
+ *
+ * Import rebuilds one video track (plus an audio track for `A`/`AA/V`
+ * events) by matching source files, clip names and reels against the media
+ * library.
+ */
+
+import type { ProjectResolution, ProjectTimeline } from '@/types/project'
+import type { TimelineItem, TimelineTrack } from '@/types/timeline'
+import type { Transition } from '@/types/transition'
+import { convertTimelineToComposition } from '@/features/project-bundle/deps/export'
+import { getProjectMediaMatchSuggestion } from '@/features/project-bundle/deps/editor'
+import type {
+  InterchangeExportInput,
+  InterchangeExportResult,
+  InterchangeImportResult,
+  InterchangeMediaReference,
+  InterchangeWarning,
+} from '../types/interchange'
+import { createInterchangeMediaMatcher, relinkCompanionItems } from './interchange-media'
+
+type TimelineItemRecord = ProjectTimeline['items'][number]
+
+const REEL_NAME_LENGTH = 8
+/** Conventional record start for broadcast timelines. */
+const RECORD_START_HOURS = 1
+const AUX_REEL = 'AX'
+const BLACK_REEL = 'BL'
+
+// ---------------------------------------------------------------------------
+// Timecode
+// ---------------------------------------------------------------------------
+
+/** CMX3600 counts whole frames; fractional rates use their nominal timebase. */
+function timebase(fps: number): number {
+  return Math.max(1, Math.round(fps))
+}
+
+/** `M2` speed field, five characters wide with the sign ahead of the padding (`050.0`, `-25.0`). */
+function formatMotionFps(value: number): string {
+  const magnitude = Math.abs(value).toFixed(1)
+  return value < 0 ? `-${magnitude.padStart(4, '0')}` : magnitude.padStart(5, '0')
+}
+
+export function framesToTimecode(frames: number, fps: number): string {
+  const base = timebase(fps)
+  const total = Math.max(0, Math.round(frames))
+  const hours = Math.floor(total / (base * 3600))
+  const minutes = Math.floor(total / (base * 60)) % 60
+  const seconds = Math.floor(total / base) % 60
+  const remainder = total % base
+  return [hours, minutes, seconds, remainder].map((part) => String(part).padStart(2, '0')).join(':')
+}
+
+export function timecodeToFrames(timecode: string, fps: number): number {
+  const match = /^(\d{1,2})[:;.](\d{2})[:;.](\d{2})[:;.](\d{2,3})$/.exec(timecode.trim())
+  if (!match) return Number.NaN
+  const base = timebase(fps)
+  const [hours, minutes, seconds, frames] = match.slice(1).map(Number) as [
+    number,
+    number,
+    number,
+    number,
+  ]
+  return ((hours * 60 + minutes) * 60 + seconds) * base + frames
+}
+
+// ---------------------------------------------------------------------------
+// Reels
+// ---------------------------------------------------------------------------
+
+/** Reel name for a media file: upper-case stem, CMX-safe, at most 8 chars. */
+export function reelNameForFile(fileName: string): string {
+  const dot = fileName.lastIndexOf('.')
+  const stem = dot > 0 ? fileName.slice(0, dot) : fileName
+  const reel = stem
+    .toUpperCase()
+    .replace(/[^A-Z0-9_]+/g, '_')
+    .replace(/^_+|_+$/g, '')
+    .slice(0, REEL_NAME_LENGTH)
+  return reel || AUX_REEL
+}
+
+/** Unique reel per media id; clashing truncations get a numeric suffix. */
+function createReelAssigner(): (media: InterchangeMediaReference) => string {
+  const reelByMedia = new Map<string, string>()
+  const usedReels = new Set([AUX_REEL, BLACK_REEL])
+  return (media) => {
+    const existing = reelByMedia.get(media.id)
+    if (existing) return existing
+    const base = reelNameForFile(media.fileName)
+    let reel = base
+    for (let counter = 2; usedReels.has(reel); counter++) {
+      const suffix = String(counter)
+      reel = `${base.slice(0, REEL_NAME_LENGTH - suffix.length)}${suffix}`
+    }
+    usedReels.add(reel)
+    reelByMedia.set(media.id, reel)
+    return reel
+  }
+}
+
+// ---------------------------------------------------------------------------
+// Export
+// ---------------------------------------------------------------------------
+
+interface EdlLine {
+  reel: string
+  channel: string
+  /** `C` for cuts, `D` for dissolves */
+  kind: 'C' | 'D'
+  transitionFrames?: number
+  sourceIn: number
+  sourceOut: number
+  recordIn: number
+  recordOut: number
+}
+
+function formatEventLine(eventNumber: number, line: EdlLine, fps: number): string {
+  const number = String(eventNumber).padStart(3, '0')
+  const duration =
+    line.transitionFrames !== undefined ? String(line.transitionFrames).padStart(3, '0') : '   '
+  const recordOffset = RECORD_START_HOURS * 3600 * timebase(fps)
+  return [
+    `${number}  ${line.reel.padEnd(REEL_NAME_LENGTH)} ${line.channel.padEnd(5)} ${line.kind.padEnd(4)} ${duration}`,
+    framesToTimecode(line.sourceIn, fps),
+    framesToTimecode(line.sourceOut, fps),
+    framesToTimecode(line.recordIn + recordOffset, fps),
+    framesToTimecode(line.recordOut + recordOffset, fps),
+  ].join(' ')
+}
+
+function isPictureTrack(track: TimelineTrack): boolean {
+  if (track.kind) return track.kind === 'video'
+  return !(track.items.length > 0 && track.items.every((item) => item.type === 'audio'))
+}
+
+export function projectToEdl(input: InterchangeExportInput): InterchangeExportResult {
+  const { metadata, timeline } = input
+  const fps = metadata.fps
+  const warnings: InterchangeWarning[] = []
+  const mediaById = new Map(input.media.map((entry) => [entry.id, entry]))
+  const reelFor = createReelAssigner()
+
+  const composition = convertTimelineToComposition(
+    timeline.tracks.map((track) => ({ ...track, items: [] })),
+    timeline.items as unknown as TimelineItem[],
+    timeline.transitions ?? [],
+    fps,
+    metadata.width,
+    metadata.height,
+    null,
+    null,
+    timeline.keyframes,
+  )
+
+  const audibleLinkGroups = new Set(
+    composition.tracks
+      .filter((track) => !track.muted)
+      .flatMap((track) => track.items)
+      .flatMap((item) => (item.type === 'audio' && item.linkedGroupId ? [item.linkedGroupId] : [])),
+  )
+  const transitionsByPair = new Map<string, Transition>()
+  for (const transition of composition.transitions ?? []) {
+    transitionsByPair.set(`${transition.leftClipId}:${transition.rightClipId}`, transition)
+  }
+
+  /** Source frame (at the timeline rate) `offset` timeline frames into a clip. */
+  const sourceFrameAt = (item: TimelineItem, offset: number): number => {
+    const speed = item.speed ?? 1
+    const media = item.mediaId ? mediaById.get(item.mediaId) : undefined
+    const sourceFps = item.sourceFps ?? (media && media.fps > 0 ? media.fps : fps)
+    if (item.type === 'image') return Math.max(0, offset)
+    const startSeconds = item.isReversed
+      ? (item.sourceEnd ?? item.sourceStart ?? 0) / sourceFps - (offset * speed) / fps
+      : (item.sourceStart ?? 0) / sourceFps + (offset * speed) / fps
+    return Math.max(0, Math.round(startSeconds * fps))
+  }
+
+  const documents: Array<{ suffix: string; content: string }> = []
+  // Composition tracks are bottom-most first; V1 leads the document list.
+  for (const track of composition.tracks) {
+    if (!track.visible || !isPictureTrack(track)) continue
+
+    const clips: Array<{ item: TimelineItem; media: InterchangeMediaReference }> = []
+    for (const item of [...track.items].sort((a, b) => a.from - b.from)) {
+      const media = item.mediaId ? mediaById.get(item.mediaId) : undefined
+      if ((item.type === 'video' || item.type === 'image') && media) {
+        clips.push({ item, media })
+      } else {
+        warnings.push({
+          code: item.type === 'video' || item.type === 'image' ? 'unmatched_media' : 'unsupported_item',
+          message: `${item.type} item "${item.label}" has no EDL source and was left out of ${track.name}`,
+          itemId: item.id,
+        })
+      }
+    }
+    if (clips.length === 0) continue
+
+    const lines: string[] = [`TITLE: ${input.name} - ${track.name}`, 'FCM: NON-DROP FRAME', '']
+    let eventNumber = 1
+    clips.forEach(({ item, media }, index) => {
+      const previous = clips[index - 1]
+      const next = clips[index + 1]
+      const incoming =
+        previous && previous.item.from + previous.item.durationInFrames === item.from
+          ? transitionsByPair.get(`${previous.item.id}:${item.id}`)
+          : undefined
+      const outgoing =
+        next && item.from + item.durationInFrames === next.item.from
+          ? transitionsByPair.get(`${item.id}:${next.item.id}`)
+          : undefined
+      const preroll = (transition: Transition | undefined) =>
+        transition ? Math.round(transition.durationInFrames * (transition.alignment ?? 0.5)) : 0
+
+      const recordIn = item.from - preroll(incoming)
+      const recordOut = item.from + item.durationInFrames - preroll(outgoing)
+      const sourceIn = sourceFrameAt(item, recordIn - item.from)
+      const reel = reelFor(media)
+      const channel = item.linkedGroupId && audibleLinkGroups.has(item.linkedGroupId) ? 'AA/V' : 'V'
+      const eventLines: string[] = []
+
+      if (incoming && previous) {
+        // Zero-length cut to the outgoing clip, then the dissolve into this one.
+        const outgoingSource = sourceFrameAt(previous.item, recordIn - previous.item.from)
+        eventLines.push(
+          formatEventLine(
+            eventNumber,
+            {
+              reel: reelFor(previous.media),
+              channel,
+              kind: 'C',
+              sourceIn: outgoingSource,
+              sourceOut: outgoingSource,
+              recordIn,
+              recordOut: recordIn,
+            },
+            fps,
+          ),
+        )
+      }
+      eventLines.push(
+        formatEventLine(
+          eventNumber,
+          {
+            reel,
+            channel,
+            kind: incoming ? 'D' : 'C',
+            transitionFrames: incoming?.durationInFrames,
+            sourceIn,
+            sourceOut: sourceIn + (recordOut - recordIn),
+            recordIn,
+            recordOut,
+          },
+          fps,
+        ),
+      )
+
+      const speed = item.speed ?? 1
+      if (speed !== 1 || item.isReversed) {
+        const motionFps = (item.isReversed ? -1 : 1) * speed * timebase(fps)
+        eventLines.push(
+          `M2   ${reel.padEnd(REEL_NAME_LENGTH)}       ${formatMotionFps(motionFps)}    ${framesToTimecode(sourceIn, fps)}`,
+        )
+      }
+      eventLines.push(`* FROM CLIP NAME: ${item.label || media.fileName}`)
+      eventLines.push(`* SOURCE FILE: ${media.path}`)
+      lines.push(...eventLines, '')
+      eventNumber++
+    })
+
+    documents.push({ suffix: track.name, content: `${lines.join('\n').trimEnd()}\n` })
+  }
+
+  for (const transition of composition.transitions ?? []) {
+    const written = composition.tracks.some(
+      (track) =>
+        track.visible &&
+        track.items.some((item) => item.id === transition.leftClipId) &&
+        track.items.some((item) => item.id === transition.rightClipId),
+    )
+    if (!written) {
+      warnings.push({
+        code: 'lossy_value',
+        message: 'A transition outside the exported picture tracks was dropped',
+        itemId: transition.leftClipId,
+      })
+    }
+  }
+
+  if (documents.length === 0) {
+    documents.push({
+      suffix: 'V1',
+      content: `TITLE: ${input.name}\nFCM: NON-DROP FRAME\n`,
+    })
+  }
+
+  return { content: documents[0]!.content, warnings, documents }
+}
+
+// ---------------------------------------------------------------------------
+// Import
+// ---------------------------------------------------------------------------
+
+interface ParsedEdlLine {
+  eventNumber: string
+  reel: string
+  channel: string
+  kind: string
+  transitionFrames?: number
+  sourceIn: string
+  sourceOut: string
+  recordIn: string
+  recordOut: string
+}
+
+interface ParsedEdlEvent {
+  lines: ParsedEdlLine[]
+  clipName?: string
+  sourceFile?: string
+  /** Motion effect speed in frames per second (negative = reverse) */
+  motionFps?: number
+}
+
+const TIMECODE_PATTERN = /^\d{1,2}[:;.]\d{2}[:;.]\d{2}[:;.]\d{2,3}$/
+
+function parseEventLine(line: string): ParsedEdlLine | null {
+  const tokens = line.trim().split(/\s+/)
+  if (tokens.length < 8 || !/^\d+$/.test(tokens[0]!)) return null
+  const timecodes = tokens.slice(-4)
+  if (!timecodes.every((token) => TIMECODE_PATTERN.test(token))) return null
+  const [sourceIn, sourceOut, recordIn, recordOut] = timecodes as [string, string, string, string]
+  const transitionFrames = tokens.length >= 9 ? Number(tokens[4]) : undefined
+  return {
+    eventNumber: tokens[0]!,
+    reel: tokens[1]!,
+    channel: tokens[2]!.toUpperCase(),
+    kind: tokens[3]!.toUpperCase(),
+    transitionFrames: Number.isFinite(transitionFrames) ? transitionFrames : undefined,
+    sourceIn,
+    sourceOut,
+    recordIn,
+    recordOut,
+  }
+}
+
+function parseEdlDocument(content: string): {
+  title?: string
+  dropFrame: boolean
+  events: ParsedEdlEvent[]
+} {
+  let title: string | undefined
+  let dropFrame = false
+  const events: ParsedEdlEvent[] = []
+  let current: ParsedEdlEvent | undefined
+
+  for (const rawLine of content.split(/\r?\n/)) {
+    const line = rawLine.trim()
+    if (!line) continue
+    const titleMatch = /^TITLE:\s*(.*)$/i.exec(line)
+    if (titleMatch) {
+      title = titleMatch[1]!.trim() || undefined
+      continue
+    }
+    const fcmMatch = /^FCM:\s*(.*)$/i.exec(line)
+    if (fcmMatch) {
+      dropFrame = /^DROP/i.test(fcmMatch[1]!.trim())
+      continue
+    }
+    const commentMatch = /^\*\s*(FROM CLIP NAME|SOURCE FILE)\s*:\s*(.*)$/i.exec(line)
+    if (commentMatch) {
+      if (!current) continue
+      if (commentMatch[1]!.toUpperCase() === 'SOURCE FILE') current.sourceFile = commentMatch[2]!.trim()
+      else current.clipName = commentMatch[2]!.trim()
+      continue
+    }
+    const motionMatch = /^M2\s+\S+\s+(-?[\d.]+)\s+/i.exec(line)
+    if (motionMatch) {
+      if (current) current.motionFps = Number(motionMatch[1])
+      continue
+    }
+    const parsed = parseEventLine(line)
+    if (!parsed) continue
+    if (current && current.lines[0]!.eventNumber === parsed.eventNumber) {
+      current.lines.push(parsed)
+    } else {
+      current = { lines: [parsed] }
+      events.push(current)
+    }
+  }
+  return { title, dropFrame, events }
+}
+
+export function parseEdl(
+  content: string,
+  media: readonly InterchangeMediaReference[],
+): InterchangeImportResult {
+  const { title, dropFrame, events } = parseEdlDocument(content)
+  if (events.length === 0) {
+    throw new Error('Invalid EDL: no events found')
+  }
+
+  const warnings: InterchangeWarning[] = []
+  if (dropFrame) {
+    warnings.push({
+      code: 'lossy_value',
+      message: 'Drop-frame timecode was read as non-drop frame counts',
+    })
+  }
+
+  // Resolve sources first: source file, then clip name, then reel.
+  const matchMedia = createInterchangeMediaMatcher(media)
+  const mediaByReel = new Map<string, InterchangeMediaReference>()
+  for (const entry of media) {
+    const reel = reelNameForFile(entry.fileName)
+    if (!mediaByReel.has(reel)) mediaByReel.set(reel, entry)
+  }
+  const resolveSource = (event: ParsedEdlEvent, line: ParsedEdlLine) => {
+    const isLastLine = line === event.lines[event.lines.length - 1]
+    return (
+      (isLastLine ? matchMedia(event.sourceFile, event.clipName) : undefined) ??
+      mediaByReel.get(line.reel.toUpperCase())
+    )
+  }
+
+  // EDLs carry no format: take it from the first matched video source.
+  const firstVideo = events
+    .flatMap((event) => event.lines.map((line) => resolveSource(event, line)))
+    .find(
+      (reference) =>
+        reference?.mimeType.startsWith('video/') && reference.fps > 0 && reference.width > 0,
+    )
+  const suggestion = firstVideo
+    ? getProjectMediaMatchSuggestion({ width: 1920, height: 1080, fps: 30 }, firstVideo)
+    : undefined
+  const metadata: ProjectResolution = {
+    width: suggestion?.width || 1920,
+    height: suggestion?.height || 1080,
+    fps: suggestion?.fps ?? 30,
+  }
+  const fps = metadata.fps
+  const toFrames = (timecode: string) => timecodeToFrames(timecode, fps)
+
+  // Record timecode usually starts at 01:00:00:00.
+  const hourFrames = RECORD_START_HOURS * 3600 * timebase(fps)
+  const earliestRecord = Math.min(
+    ...events.flatMap((event) => event.lines.map((line) => toFrames(line.recordIn))),
+  )
+  const recordOffset = earliestRecord >= hourFrames ? hourFrames : 0
+
+  const videoTrackId = crypto.randomUUID()
+  const audioTrackId = crypto.randomUUID()
+  const items: TimelineItemRecord[] = []
+  const transitions: Transition[] = []
+  /** Previous picture event (and its audio) — the outgoing side of a dissolve. */
+  let lastPicture: { items: TimelineItemRecord[]; recordOut: number } | undefined
+
+  const sourceFieldsFor = (
+    reference: InterchangeMediaReference,
+    sourceInFrames: number,
+    durationInFrames: number,
+    speed: number,
+  ) => {
+    const sourceFps = reference.fps > 0 ? reference.fps : fps
+    const sourceStart = Math.round((sourceInFrames / fps) * sourceFps)
+    const sourceSpan = Math.round(((durationInFrames * speed) / fps) * sourceFps)
+    return {
+      sourceStart,
+      sourceEnd: sourceStart + sourceSpan,
+      sourceDuration: Math.max(sourceStart + sourceSpan, Math.round(reference.duration * sourceFps)),
+      sourceFps,
+      speed,
+    }
+  }
+
+  for (const event of events) {
+    const line = event.lines[event.lines.length - 1]!
+    if (line.reel.toUpperCase() === BLACK_REEL) continue
+    if (line.kind.startsWith('K')) {
+      warnings.push({
+        code: 'unsupported_element',
+        message: `Key event ${line.eventNumber} is not supported and was skipped`,
+      })
+      continue
+    }
+    const reference = resolveSource(event, line)
+    if (!reference) {
+      warnings.push({
+        code: 'unmatched_media',
+        message: `No media in the library matches reel "${line.reel}"${event.clipName ? ` (${event.clipName})` : ''}; event ${line.eventNumber} skipped`,
+      })
+      continue
+    }
+
+    let recordIn = toFrames(line.recordIn) - recordOffset
+    const recordOut = toFrames(line.recordOut) - recordOffset
+    let sourceIn = toFrames(line.sourceIn)
+    if (!Number.isFinite(recordIn) || !Number.isFinite(recordOut) || recordOut <= recordIn) continue
+
+    const motionFps = event.motionFps
+    const speed = motionFps ? Math.abs(motionFps) / timebase(fps) : 1
+    const isReversed = motionFps !== undefined && motionFps < 0
+
+    // Dissolves start on the outgoing clip; FreeCut centers the cut in them.
+    const isDissolve = line.kind === 'D' || line.kind.startsWith('W')
+    let transition: Omit<Transition, 'rightClipId'> | undefined
+    if (isDissolve && line.transitionFrames) {
+      if (lastPicture && lastPicture.recordOut === recordIn) {
+        const durationInFrames = line.transitionFrames
+        const preroll = Math.min(Math.round(durationInFrames / 2), recordOut - recordIn - 1)
+        // The outgoing clip plays on into its handle up to the cut.
+        for (const outgoing of lastPicture.items) {
+          outgoing.durationInFrames += preroll
+          if (outgoing.sourceEnd !== undefined) {
+            outgoing.sourceEnd += Math.round(
+              ((preroll * (outgoing.speed ?? 1)) / fps) * (outgoing.sourceFps ?? fps),
+            )
+            outgoing.sourceDuration = Math.max(outgoing.sourceDuration ?? 0, outgoing.sourceEnd)
+          }
+        }
+        recordIn += preroll
+        sourceIn += Math.round(preroll * speed)
+        transition = {
+          id: crypto.randomUUID(),
+          type: 'crossfade',
+          presentation: line.kind === 'D' ? 'fade' : 'wipe',
+          timing: 'linear',
+          leftClipId: lastPicture.items[0]!.id,
+          trackId: videoTrackId,
+          durationInFrames,
+          alignment: Number((preroll / durationInFrames).toFixed(4)),
+        }
+      } else {
+        warnings.push({
+          code: 'unsupported_element',
+          message: `Transition in event ${line.eventNumber} has no outgoing clip and was cut instead`,
+        })
+      }
+    }
+
+    const durationInFrames = recordOut - recordIn
+    const channel = line.channel
+    const wantsVideo = reference.hasVideo && /V|B/.test(channel)
+    const wantsAudio = reference.hasAudio && (/^A/.test(channel) || channel === 'B')
+    const label = event.clipName || reference.fileName
+    const base = { from: recordIn, durationInFrames, label, mediaId: reference.id, src: '' }
+    const isImage = reference.mimeType.startsWith('image/')
+
+    const created: TimelineItemRecord[] = []
+    if (wantsVideo) {
+      const item: TimelineItemRecord = isImage
+        ? {
+            ...base,
+            id: crypto.randomUUID(),
+            trackId: videoTrackId,
+            type: 'image',
+            sourceWidth: reference.width || undefined,
+            sourceHeight: reference.height || undefined,
+          }
+        : {
+            ...base,
+            ...sourceFieldsFor(reference, sourceIn, durationInFrames, speed),
+            ...(isReversed ? { isReversed } : {}),
+            id: crypto.randomUUID(),
+            trackId: videoTrackId,
+            type: 'video',
+            sourceWidth: reference.width || undefined,
+            sourceHeight: reference.height || undefined,
+          }
+      created.push(item)
+      if (transition) transitions.push({ ...transition, rightClipId: item.id })
+    }
+    if (wantsAudio && !isImage) {
+      created.push({
+        ...base,
+        ...sourceFieldsFor(reference, sourceIn, durationInFrames, speed),
+        ...(isReversed ? { isReversed } : {}),
+        id: crypto.randomUUID(),
+        trackId: audioTrackId,
+        type: 'audio',
+      })
+    }
+    items.push(...created)
+    if (wantsVideo) lastPicture = { items: created, recordOut }
+    if (!wantsVideo && !wantsAudio) {
+      warnings.push({
+        code: 'unsupported_element',
+        message: `Event ${line.eventNumber} uses channel "${channel}", which its media doesn't have`,
+      })
+    }
+  }
+
+  relinkCompanionItems(items)
+
+  const trackDefaults = {
+    height: 100,
+    locked: false,
+    syncLock: true,
+    visible: true,
+    muted: false,
+    solo: false,
+    volume: 0,
+  }
+  const tracks: ProjectTimeline['tracks'] = [
+    { ...trackDefaults, id: videoTrackId, name: 'V1', kind: 'video', order: 0 },
+  ]
+  if (items.some((item) => item.trackId === audioTrackId)) {
+    tracks.push({ ...trackDefaults, id: audioTrackId, name: 'A1', kind: 'audio', order: 1 })
+  }
+
+  const mediaIds = [...new Set(items.flatMap((item) => (item.mediaId ? [item.mediaId] : [])))]
+
+  return {
+    name: title || 'Imported EDL',
+    metadata,
+    timeline: { tracks, items, markers: [], transitions },
+    mediaIds,
+    warnings,
+  }
+}
@@ -0,0 +1,236 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { ProjectTimeline } from '@/types/project'
+import {
+  framesToFcpxmlTime,
+  parseFcpxml,
+  parseFcpxmlTime,
+  projectToFcpxml,
+} from './fcpxml-converter'
+import {
+  exportTimeline,
+  makeTimeline,
+  mediaFields,
+  TEST_CLIP,
+  TEST_METADATA,
+} from './interchange-test-helpers'
+
+function sampleTimeline(): ProjectTimeline {
+  return makeTimeline({
+    items: [
+      {
+        id: 'title-1',
+        trackId: 'track-v2',
+        type: 'text',
+        from: 15,
+        durationInFrames: 30,
+        label: 'Lower third',
+        text: 'Hello',
+        color: '#ff0000',
+        fontSize: 64,
+      },
+      {
+        id: 'video-1',
+        trackId: 'track-v1',
+        type: 'video',
+        from: 0,
+        durationInFrames: 60,
+        label: 'clip.mp4',
+        linkedGroupId: 'link-1',
+        ...mediaFields(TEST_CLIP, 30, 90),
+      },
+      {
+        id: 'audio-1',
+        trackId: 'track-a1',
+        type: 'audio',
+        from: 0,
+        durationInFrames: 60,
+        label: 'clip.mp4',
+        linkedGroupId: 'link-1',
+        volume: -6,
+        ...mediaFields(TEST_CLIP, 30, 90),
+      },
+      {
+        id: 'video-2',
+        trackId: 'track-v1',
+        type: 'video',
+        from: 60,
+        durationInFrames: 60,
+        label: 'clip.mp4',
+        linkedGroupId: 'link-2',
+        ...mediaFields(TEST_CLIP, 0, 120, 2),
+      },
+      {
+        id: 'audio-2',
+        trackId: 'track-a1',
+        type: 'audio',
+        from: 60,
+        durationInFrames: 60,
+        label: 'clip.mp4',
+        linkedGroupId: 'link-2',
+        ...mediaFields(TEST_CLIP, 0, 120, 2),
+      },
+    ],
+    markers: [{ id: 'marker-1', frame: 45, color: '#ff0000', label: 'Beat' }],
+    transitions: [
+      {
+        id: 'transition-1',
+        type: 'crossfade',
+        presentation: 'fade',
+        timing: 'linear',
+        leftClipId: 'video-1',
+        rightClipId: 'video-2',
+        trackId: 'track-v1',
+        durationInFrames: 10,
+        alignment: 0.5,
+      },
+    ],
+  })
+}
+
+describe('FCPXML rational time', () => {
+  it('writes integer frame rates as reduced fractions of a second', () => {
+    expect(framesToFcpxmlTime(0, 30)).toBe('0s')
+    expect(framesToFcpxmlTime(30, 30)).toBe('1s')
+    expect(framesToFcpxmlTime(45, 30)).toBe('3/2s')
+  })
+
+  it('writes NTSC rates on the 1001 grid', () => {
+    expect(framesToFcpxmlTime(1, 29.97)).toBe('1001/30000s')
+    expect(framesToFcpxmlTime(24, 23.976)).toBe('1001/1000s')
+  })
+
+  it('parses fractional, decimal and empty values', () => {
+    expect(parseFcpxmlTime('3/2s')).toBe(1.5)
+    expect(parseFcpxmlTime('2.5s')).toBe(2.5)
+    expect(parseFcpxmlTime('10s')).toBe(10)
+    expect(parseFcpxmlTime(undefined)).toBe(0)
+    expect(parseFcpxmlTime('garbage')).toBe(0)
+  })
+})
+
+describe('projectToFcpxml', () => {
+  it('writes the bottom video track as the primary storyline', () => {
+    const { content, warnings } = exportTimeline(projectToFcpxml, sampleTimeline())
+
+    expect(warnings).toEqual([])
+    expect(content).toContain('<fcpxml version="1.10">')
+    expect(content).toContain('<media-rep kind="original-media" src="media/media-1/source.mp4"/>')
+    expect(content).toContain('srcEnable="video"')
+    expect(content).toContain('<spine lane="1"')
+    expect(content).toContain('<spine lane="-1"')
+    expect(content).toContain('<adjust-volume amount="-6dB"/>')
+    expect(content).toContain('<transition name="Cross Dissolve" offset="11/6s" duration="1/3s">')
+  })
+
+  it('writes unsupported items as annotated gaps with a warning', () => {
+    const timeline = sampleTimeline()
+    timeline.items.push({
+      id: 'shape-1',
+      trackId: 'track-v2',
+      type: 'shape',
+      shapeType: 'rectangle',
+      from: 90,
+      durationInFrames: 15,
+      label: 'Box',
+      fillColor: '#ffffff',
+    })
+
+    const { content, warnings } = exportTimeline(projectToFcpxml, timeline)
+
+    expect(content).toContain('<note>freecut:shape</note>')
+    expect(warnings).toEqual([expect.objectContaining({ code: 'unsupported_item', itemId: 'shape-1' })])
+  })
+})
+
+describe('parseFcpxml', () => {
+  it('round-trips tracks, linked clips, trims, speed, transitions and markers', () => {
+    const { content } = exportTimeline(projectToFcpxml, sampleTimeline())
+    const result = parseFcpxml(content, [TEST_CLIP])
+
+    expect(result.name).toBe('Cut')
+    expect(result.metadata).toEqual(TEST_METADATA)
+    expect(result.mediaIds).toEqual(['media-1'])
+    expect(result.warnings).toEqual([])
+
+    const { tracks, items, markers, transitions } = result.timeline
+    expect(tracks.map((track) => [track.name, track.kind, track.order])).toEqual([
+      ['V2', 'video', 0],
+      ['V1', 'video', 1],
+      ['A1', 'audio', 2],
+    ])
+
+    const onTrack = (name: string) => {
+      const trackId = tracks.find((track) => track.name === name)!.id
+      return items.filter((item) => item.trackId === trackId).sort((a, b) => a.from - b.from)
+    }
+
+    const [title] = onTrack('V2')
+    expect(title).toMatchObject({ type: 'text', from: 15, durationInFrames: 30, text: 'Hello' })
+    expect(title).toMatchObject({ color: '#ff0000', fontSize: 64 })
+
+    const [video1, video2] = onTrack('V1')
+    const [audio1, audio2] = onTrack('A1')
+    expect(video1).toMatchObject({ type: 'video', from: 0, durationInFrames: 60, sourceStart: 30 })
+    expect(video2).toMatchObject({ type: 'video', from: 60, durationInFrames: 60, speed: 2 })
+    expect(audio1).toMatchObject({ type: 'audio', from: 0, volume: -6, sourceStart: 30 })
+    expect(audio2).toMatchObject({ type: 'audio', from: 60, speed: 2 })
+    expect(video1!.linkedGroupId).toBeDefined()
+    expect(video1!.linkedGroupId).toBe(audio1!.linkedGroupId)
+    expect(video2!.linkedGroupId).toBe(audio2!.linkedGroupId)
+    expect(video1!.linkedGroupId).not.toBe(video2!.linkedGroupId)
+
+    expect(transitions).toEqual([
+      expect.objectContaining({
+        leftClipId: video1!.id,
+        rightClipId: video2!.id,
+        durationInFrames: 10,
+        alignment: 0.5,
+        presentation: 'fade',
+      }),
+    ])
+    expect(markers).toEqual([expect.objectContaining({ frame: 45, label: 'Beat', color: '#ff0000' })])
+  })
+
+  it('matches media by file name and warns about clips it cannot resolve', () => {
+    const xml = `<?xml version="1.0" encoding="UTF-8"?>
+<fcpxml version="1.10">
+  <resources>
+    <format id="r1" frameDuration="1/25s" width="1280" height="720"/>
+    <asset id="r2" name="clip.mp4" start="0s" duration="20s" hasVideo="1">
+      <media-rep kind="original-media" src="file:///Volumes/Footage/CLIP.MP4"/>
+    </asset>
+    <asset id="r3" name="missing.mov" start="0s" duration="5s" hasVideo="1">
+      <media-rep kind="original-media" src="file:///Volumes/Footage/missing.mov"/>
+    </asset>
+  </resources>
+  <library>
+    <event name="Event">
+      <project name="Imported">
+        <sequence format="r1" duration="4s">
+          <spine>
+            <asset-clip ref="r2" offset="0s" start="2s" duration="2s"/>
+            <asset-clip ref="r3" offset="2s" start="0s" duration="2s"/>
+          </spine>
+        </sequence>
+      </project>
+    </event>
+  </library>
+</fcpxml>`
+
+    const result = parseFcpxml(xml, [TEST_CLIP])
+
+    expect(result.metadata).toEqual({ width: 1280, height: 720, fps: 25 })
+    expect(result.timeline.items).toHaveLength(1)
+    expect(result.timeline.items[0]).toMatchObject({
+      mediaId: 'media-1',
+      from: 0,
+      durationInFrames: 50,
+      sourceStart: 60,
+    })
+    expect(result.warnings).toEqual([expect.objectContaining({ code: 'unmatched_media' })])
+  })
+
+  it('rejects documents without an fcpxml root', () => {
+    expect(() => parseFcpxml('<xmeml version="5"/>', [])).toThrow(/fcpxml/)
+  })
+})
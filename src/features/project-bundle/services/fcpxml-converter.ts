@@ -0,0 +1,1015 @@
+/**
+ * FCPXML Converter
+ *
+ * Pure conversion between FreeCut timelines and FCPXML 1.10 documents.
+ *
+ * Layout on export:
+ * - The bottom-most video track becomes the primary storyline (`sequence/spine`).
+ * - Every other track becomes a secondary storyline (`<spine lane="n">`)
+ *   anchored at the start of the primary storyline. Video tracks above the
+ *   primary get positive lanes, audio tracks negative lanes.
+ * - Linked video/audio companions are written as two clips on the same asset
+ *   (`srcEnable="video"` / `srcEnable="audio"`) and re-linked on import.
+ * - Transitions keep FreeCut's cut-centered model: clips stay back to back and
+ *   the `<transition>` element's offset encodes the alignment around the cut.
+ */
+
+import type { ProjectResolution, ProjectTimeline } from '@/types/project'
+import type { Transition } from '@/types/transition'
+import type {
+  InterchangeExportInput,
+  InterchangeExportResult,
+  InterchangeImportResult,
+  InterchangeMediaReference,
+  InterchangeWarning,
+} from '../types/interchange'
+import {
+  createInterchangeMediaMatcher,
+  interchangeTrackKind,
+  relinkCompanionItems,
+} from './interchange-media'
+
+type TimelineItemRecord = ProjectTimeline['items'][number]
+type TimelineTrackRecord = ProjectTimeline['tracks'][number]
+
+export const FCPXML_VERSION = '1.10'
+
+const DEFAULT_MARKER_COLOR = '#3B82F6'
+const FREECUT_METADATA_PREFIX = 'com.freecut.'
+const BASIC_TITLE_UID =
+  '.../Titles.localized/Bumper:Opener.localized/Basic Title.localized/Basic Title.moti'
+
+// ---------------------------------------------------------------------------
+// Rational time
+// ---------------------------------------------------------------------------
+
+/** Frame duration as a rational number of seconds (`num/den`). */
+interface FrameDuration {
+  num: number
+  den: number
+}
+
+function gcd(a: number, b: number): number {
+  return b === 0 ? a : gcd(b, a % b)
+}
+
+export function frameDurationForFps(fps: number): FrameDuration {
+  const rounded = Math.round(fps)
+  if (Math.abs(fps - rounded) < 0.001) {
+    return { num: 1, den: rounded }
+  }
+  // NTSC rates (23.976, 29.97, 59.94) are expressed as 1001/N000.
+  const ntscBase = Math.round(fps * 1.001)
+  if (Math.abs(fps - (ntscBase * 1000) / 1001) < 0.01) {
+    return { num: 1001, den: ntscBase * 1000 }
+  }
+  return { num: 100, den: Math.round(fps * 100) }
+}
+
+function formatRational(numerator: number, denominator: number): string {
+  if (numerator === 0) return '0s'
+  const divisor = gcd(Math.abs(numerator), denominator)
+  const n = numerator / divisor
+  const d = denominator / divisor
+  return d === 1 ? `${n}s` : `${n}/${d}s`
+}
+
+export function framesToFcpxmlTime(frames: number, fps: number): string {
+  const { num, den } = frameDurationForFps(fps)
+  return formatRational(Math.round(frames) * num, den)
+}
+
+/** Parse an FCPXML time into an exact `numerator/denominator` pair. */
+function parseRational(value: string): { numerator: number; denominator: number } {
+  const trimmed = value.trim().replace(/s$/, '')
+  const slash = trimmed.indexOf('/')
+  if (slash >= 0) {
+    return {
+      numerator: Number(trimmed.slice(0, slash)),
+      denominator: Number(trimmed.slice(slash + 1)),
+    }
+  }
+  const [whole = '0', fraction = ''] = trimmed.split('.')
+  const denominator = 10 ** fraction.length
+  return { numerator: Number(whole + fraction), denominator }
+}
+
+/** `time + frames` at `fps`, kept exact so it lands on the parent's grid. */
+function addFramesToFcpxmlTime(time: string, frames: number, fps: number): string {
+  const { numerator, denominator } = parseRational(time)
+  const { num, den } = frameDurationForFps(fps)
+  return formatRational(
+    numerator * den + Math.round(frames) * num * denominator,
+    denominator * den,
+  )
+}
+
+export function parseFcpxmlTime(value: string | null | undefined): number {
+  if (!value) return 0
+  const { numerator, denominator } = parseRational(value)
+  const seconds = numerator / denominator
+  return Number.isFinite(seconds) ? seconds : 0
+}
+
+function secondsToFrames(seconds: number, fps: number): number {
+  return Math.round(seconds * fps)
+}
+
+// ---------------------------------------------------------------------------
+// Minimal XML writer
+// ---------------------------------------------------------------------------
+
+interface XmlNode {
+  name: string
+  attrs: Record<string, string | number | undefined>
+  children: XmlNode[]
+  text?: string
+}
+
+function el(
+  name: string,
+  attrs: XmlNode['attrs'] = {},
+  children: XmlNode[] = [],
+  text?: string,
+): XmlNode {
+  return { name, attrs, children, text }
+}
+
+function escapeXml(value: string): string {
+  return value
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;')
+    .replace(/'/g, '&apos;')
+}
+
+function renderXml(node: XmlNode, depth = 0): string {
+  const indent = '    '.repeat(depth)
+  const attrs = Object.entries(node.attrs)
+    .filter(([, value]) => value !== undefined)
+    .map(([key, value]) => ` ${key}="${escapeXml(String(value))}"`)
+    .join('')
+  if (node.text !== undefined) {
+    return `${indent}<${node.name}${attrs}>${escapeXml(node.text)}</${node.name}>`
+  }
+  if (node.children.length === 0) {
+    return `${indent}<${node.name}${attrs}/>`
+  }
+  const children = node.children.map((child) => renderXml(child, depth + 1)).join('\n')
+  return `${indent}<${node.name}${attrs}>\n${children}\n${indent}</${node.name}>`
+}
+
+// ---------------------------------------------------------------------------
+// Export
+// ---------------------------------------------------------------------------
+
+type StorylineEntry =
+  | { kind: 'item'; item: TimelineItemRecord; offset: number; duration: number }
+  | { kind: 'gap'; offset: number; duration: number }
+
+/**
+ * Lay out one track's items as a gapless storyline. Items that overlap an
+ * earlier item can't live in the same storyline and are returned separately.
+ */
+function layoutStoryline(
+  items: readonly TimelineItemRecord[],
+  totalDuration: number,
+): { entries: StorylineEntry[]; overflow: TimelineItemRecord[] } {
+  const entries: StorylineEntry[] = []
+  const overflow: TimelineItemRecord[] = []
+  let cursor = 0
+  for (const item of [...items].sort((a, b) => a.from - b.from)) {
+    if (item.from < cursor) {
+      overflow.push(item)
+      continue
+    }
+    if (item.from > cursor) {
+      entries.push({ kind: 'gap', offset: cursor, duration: item.from - cursor })
+    }
+    entries.push({ kind: 'item', item, offset: item.from, duration: item.durationInFrames })
+    cursor = item.from + item.durationInFrames
+  }
+  if (cursor < totalDuration) {
+    entries.push({ kind: 'gap', offset: cursor, duration: totalDuration - cursor })
+  }
+  return { entries, overflow }
+}
+
+function hexToFcpxmlColor(color: string | undefined): string | undefined {
+  if (!color) return undefined
+  const match = /^#?([0-9a-f]{6})([0-9a-f]{2})?$/i.exec(color.trim())
+  if (!match) return undefined
+  const hex = match[1]!
+  const channels = [0, 2, 4].map((index) => parseInt(hex.slice(index, index + 2), 16) / 255)
+  const alpha = match[2] ? parseInt(match[2], 16) / 255 : 1
+  return [...channels, alpha].map((value) => Number(value.toFixed(4))).join(' ')
+}
+
+function fcpxmlColorToHex(color: string | null): string | undefined {
+  if (!color) return undefined
+  const parts = color.trim().split(/\s+/).map(Number)
+  if (parts.length < 3 || parts.some((value) => !Number.isFinite(value))) return undefined
+  return `#${parts
+    .slice(0, 3)
+    .map((value) =>
+      Math.round(Math.min(1, Math.max(0, value)) * 255)
+        .toString(16)
+        .padStart(2, '0'),
+    )
+    .join('')}`
+}
+
+function metadataNode(entries: Record<string, string | number | undefined>): XmlNode | null {
+  const mds = Object.entries(entries)
+    .filter(([, value]) => value !== undefined)
+    .map(([key, value]) => el('md', { key: `${FREECUT_METADATA_PREFIX}${key}`, value }))
+  return mds.length > 0 ? el('metadata', {}, mds) : null
+}
+
+export function projectToFcpxml(input: InterchangeExportInput): InterchangeExportResult {
+  const { metadata, timeline } = input
+  const fps = metadata.fps
+  const warnings: InterchangeWarning[] = []
+  const mediaById = new Map(input.media.map((entry) => [entry.id, entry]))
+
+  const tracks = timeline.tracks.filter((track) => !track.isGroup).sort((a, b) => a.order - b.order)
+  const itemsByTrack = new Map<string, TimelineItemRecord[]>()
+  for (const track of tracks) itemsByTrack.set(track.id, [])
+  for (const item of timeline.items) itemsByTrack.get(item.trackId)?.push(item)
+
+  const markerEnd = Math.max(0, ...(timeline.markers ?? []).map((marker) => marker.frame + 1))
+  const totalDuration = Math.max(
+    1,
+    markerEnd,
+    ...timeline.items.map((item) => item.from + item.durationInFrames),
+  )
+
+  // Resources: sequence format, one asset (+format) per referenced media, title effect.
+  const resources: XmlNode[] = [
+    el('format', {
+      id: 'r1',
+      name: `FFVideoFormat${metadata.height}p${Math.round(fps * 100) / 100}`,
+      frameDuration: framesToFcpxmlTime(1, fps),
+      width: metadata.width,
+      height: metadata.height,
+    }),
+  ]
+  let nextResourceId = 2
+  const assetIds = new Map<string, string>()
+  const assetFps = new Map<string, number>()
+  const ensureAsset = (mediaId: string): string | undefined => {
+    const existing = assetIds.get(mediaId)
+    if (existing) return existing
+    const media = mediaById.get(mediaId)
+    if (!media) return undefined
+    const isImage = media.mimeType.startsWith('image/')
+    const mediaFps = media.fps > 0 ? media.fps : fps
+    let formatId: string | undefined
+    if (media.hasVideo) {
+      formatId = `r${nextResourceId++}`
+      resources.push(
+        el('format', {
+          id: formatId,
+          frameDuration: isImage ? undefined : framesToFcpxmlTime(1, mediaFps),
+          width: media.width || undefined,
+          height: media.height || undefined,
+        }),
+      )
+    }
+    const assetId = `r${nextResourceId++}`
+    resources.push(
+      el(
+        'asset',
+        {
+          id: assetId,
+          name: media.fileName,
+          uid: media.id,
+          start: '0s',
+          duration: isImage ? '0s' : framesToFcpxmlTime(media.duration * mediaFps, mediaFps),
+          hasVideo: media.hasVideo ? 1 : undefined,
+          hasAudio: media.hasAudio ? 1 : undefined,
+          format: formatId,
+          audioSources: media.hasAudio ? 1 : undefined,
+          audioChannels: media.hasAudio ? 2 : undefined,
+        },
+        [el('media-rep', { kind: 'original-media', src: media.path })],
+      ),
+    )
+    assetIds.set(mediaId, assetId)
+    assetFps.set(mediaId, mediaFps)
+    return assetId
+  }
+  let titleEffectId: string | undefined
+  const ensureTitleEffect = (): string => {
+    if (!titleEffectId) {
+      titleEffectId = `r${nextResourceId++}`
+      resources.push(el('effect', { id: titleEffectId, name: 'Basic Title', uid: BASIC_TITLE_UID }))
+    }
+    return titleEffectId
+  }
+  let nextTextStyleId = 1
+
+  const transitionsByPair = new Map<string, Transition>()
+  for (const transition of timeline.transitions ?? []) {
+    transitionsByPair.set(`${transition.leftClipId}:${transition.rightClipId}`, transition)
+  }
+
+  /** Local start time (`start` attribute) of an entry, as written. */
+  const entryStart = (entry: StorylineEntry): string => {
+    if (entry.kind === 'item' && entry.item.mediaId && mediaById.has(entry.item.mediaId)) {
+      const sourceFps = entry.item.sourceFps ?? assetFps.get(entry.item.mediaId) ?? fps
+      return framesToFcpxmlTime(entry.item.sourceStart ?? 0, sourceFps)
+    }
+    return '0s'
+  }
+
+  const renderEntry = (entry: StorylineEntry, localOffset: number, children: XmlNode[]): XmlNode => {
+    const offset = framesToFcpxmlTime(localOffset, fps)
+    const duration = framesToFcpxmlTime(entry.duration, fps)
+    if (entry.kind === 'gap') {
+      return el('gap', { name: 'Gap', offset, start: '0s', duration }, children)
+    }
+
+    const { item } = entry
+    const media = item.mediaId ? mediaById.get(item.mediaId) : undefined
+    if ((item.type === 'video' || item.type === 'audio' || item.type === 'image') && media) {
+      const assetId = ensureAsset(media.id)!
+      const speed = item.speed ?? 1
+      const clipChildren: XmlNode[] = []
+      if (speed !== 1 || item.isReversed) {
+        const sourceSpan = (entry.duration * speed) / fps
+        const [first, last] = item.isReversed ? [sourceSpan, 0] : [0, sourceSpan]
+        clipChildren.push(
+          el('timeMap', {}, [
+            el('timept', { time: '0s', value: formatSeconds(first), interp: 'linear' }),
+            el('timept', { time: duration, value: formatSeconds(last), interp: 'linear' }),
+          ]),
+        )
+      }
+      if (item.volume !== undefined && item.volume !== 0 && item.type !== 'image') {
+        clipChildren.push(el('adjust-volume', { amount: `${item.volume}dB` }))
+      }
+      clipChildren.push(...children)
+      let srcEnable: string | undefined
+      if (item.type === 'audio' && media.hasVideo) srcEnable = 'audio'
+      if (item.type === 'video' && media.hasAudio) srcEnable = 'video'
+      return el(
+        'asset-clip',
+        {
+          ref: assetId,
+          name: item.label || media.fileName,
+          offset,
+          start: entryStart(entry),
+          duration,
+          srcEnable,
+          audioRole: item.type === 'audio' ? 'dialogue' : undefined,
+        },
+        clipChildren,
+      )
+    }
+
+    if (item.type === 'text') {
+      const styleId = `ts${nextTextStyleId++}`
+      return el(
+        'title',
+        { ref: ensureTitleEffect(), name: item.label || 'Title', offset, start: '0s', duration },
+        [
+          el('text', {}, [el('text-style', { ref: styleId }, [], item.text ?? '')]),
+          el('text-style-def', { id: styleId }, [
+            el('text-style', {
+              font: item.fontFamily,
+              fontSize: item.fontSize,
+              fontColor: hexToFcpxmlColor(item.color),
+              alignment: item.textAlign,
+            }),
+          ]),
+          ...children,
+        ],
+      )
+    }
+
+    warnings.push({
+      code: item.mediaId && !media ? 'unmatched_media' : 'unsupported_item',
+      message:
+        item.mediaId && !media
+          ? `Media for "${item.label}" is unavailable; written as a gap`
+          : `${item.type} item "${item.label}" has no FCPXML equivalent; written as a gap`,
+      itemId: item.id,
+    })
+    return el('gap', { name: item.label || 'Gap', offset, start: '0s', duration }, [
+      el('note', {}, [], `freecut:${item.type}`),
+      ...children,
+    ])
+  }
+
+  const renderStoryline = (
+    entries: StorylineEntry[],
+    childrenFor: (entry: StorylineEntry) => XmlNode[],
+  ): XmlNode[] => {
+    const nodes: XmlNode[] = []
+    entries.forEach((entry, index) => {
+      const previous = index > 0 ? entries[index - 1] : undefined
+      if (previous?.kind === 'item' && entry.kind === 'item') {
+        const transition = transitionsByPair.get(`${previous.item.id}:${entry.item.id}`)
+        if (transition) {
+          const alignment = transition.alignment ?? 0.5
+          const start = entry.offset - Math.round(transition.durationInFrames * alignment)
+          nodes.push(
+            el(
+              'transition',
+              {
+                name: 'Cross Dissolve',
+                offset: framesToFcpxmlTime(start, fps),
+                duration: framesToFcpxmlTime(transition.durationInFrames, fps),
+              },
+              [
+                metadataNode({
+                  presentation: transition.presentation,
+                  alignment,
+                  timing: transition.timing,
+                  direction: transition.direction,
+                }),
+              ].filter((node): node is XmlNode => node !== null),
+            ),
+          )
+        }
+      }
+      nodes.push(renderEntry(entry, entry.offset, childrenFor(entry)))
+    })
+    return nodes
+  }
+
+  // Assign storylines to tracks.
+  const videoTracks = tracks.filter(
+    (track) => interchangeTrackKind(track, itemsByTrack.get(track.id) ?? []) === 'video',
+  )
+  const audioTracks = tracks.filter(
+    (track) => interchangeTrackKind(track, itemsByTrack.get(track.id) ?? []) === 'audio',
+  )
+  const primaryTrack = [...videoTracks]
+    .reverse()
+    .find((track) => (itemsByTrack.get(track.id) ?? []).length > 0)
+
+  const primary = layoutStoryline(
+    primaryTrack ? (itemsByTrack.get(primaryTrack.id) ?? []) : [],
+    totalDuration,
+  )
+  if (primary.entries.length === 0) {
+    primary.entries.push({ kind: 'gap', offset: 0, duration: totalDuration })
+  }
+
+  const secondary: Array<{ lane: number; track: TimelineTrackRecord; entries: StorylineEntry[] }> = []
+  const pushSecondary = (track: TimelineTrackRecord, items: TimelineItemRecord[], lane: number) => {
+    let remaining = items
+    let nextLane = lane
+    while (remaining.length > 0) {
+      const layout = layoutStoryline(remaining, 0)
+      secondary.push({ lane: nextLane, track, entries: layout.entries })
+      remaining = layout.overflow
+      nextLane += Math.sign(lane)
+    }
+    return nextLane
+  }
+  let nextVideoLane = 1
+  if (primary.overflow.length > 0 && primaryTrack) {
+    nextVideoLane = pushSecondary(primaryTrack, primary.overflow, nextVideoLane)
+  }
+  for (const track of [...videoTracks].reverse()) {
+    if (track === primaryTrack) continue
+    nextVideoLane = pushSecondary(track, itemsByTrack.get(track.id) ?? [], nextVideoLane)
+  }
+  let nextAudioLane = -1
+  for (const track of audioTracks) {
+    nextAudioLane = pushSecondary(track, itemsByTrack.get(track.id) ?? [], nextAudioLane)
+  }
+
+  // Secondary storylines hang off the first primary element; markers hang off
+  // whichever primary element covers their frame.
+  const markersByEntry = new Map<StorylineEntry, XmlNode[]>()
+  for (const marker of timeline.markers ?? []) {
+    const entry =
+      primary.entries.find(
+        (candidate) =>
+          marker.frame >= candidate.offset && marker.frame < candidate.offset + candidate.duration,
+      ) ?? primary.entries[primary.entries.length - 1]!
+    // Marker start lives in the parent's local time (relative to its `start`).
+    const nodes = markersByEntry.get(entry) ?? []
+    nodes.push(
+      el('marker', {
+        start: addFramesToFcpxmlTime(entryStart(entry), marker.frame - entry.offset, fps),
+        duration: framesToFcpxmlTime(1, fps),
+        value: marker.label ?? '',
+        note: marker.color,
+      }),
+    )
+    markersByEntry.set(entry, nodes)
+  }
+
+  const anchorEntry = primary.entries[0]!
+  const primaryNodes = renderStoryline(primary.entries, (entry) => {
+    const children: XmlNode[] = []
+    if (entry === anchorEntry) {
+      for (const storyline of secondary) {
+        children.push(
+          el(
+            'spine',
+            { lane: storyline.lane, offset: entryStart(anchorEntry), name: storyline.track.name },
+            renderStoryline(storyline.entries, () => []),
+          ),
+        )
+      }
+    }
+    children.push(...(markersByEntry.get(entry) ?? []))
+    return children
+  })
+
+  const document = el('fcpxml', { version: FCPXML_VERSION }, [
+    el('resources', {}, resources),
+    el('library', {}, [
+      el('event', { name: input.name }, [
+        el('project', { name: input.name }, [
+          el(
+            'sequence',
+            {
+              format: 'r1',
+              duration: framesToFcpxmlTime(totalDuration, fps),
+              tcStart: '0s',
+              tcFormat: 'NDF',
+              audioLayout: 'stereo',
+              audioRate: '48k',
+            },
+            [el('spine', {}, primaryNodes)],
+          ),
+        ]),
+      ]),
+    ]),
+  ])
+
+  return {
+    content: `<?xml version="1.0" encoding="UTF-8"?>\n<!DOCTYPE fcpxml>\n${renderXml(document)}\n`,
+    warnings,
+  }
+}
+
+/** Format seconds as a millisecond-precision FCPXML time. */
+function formatSeconds(seconds: number): string {
+  return formatRational(Math.round(seconds * 1000), 1000)
+}
+
+// ---------------------------------------------------------------------------
+// Import
+// ---------------------------------------------------------------------------
+
+interface ParsedAsset {
+  name: string
+  src?: string
+  start: number
+  hasVideo: boolean
+  hasAudio: boolean
+  fps?: number
+}
+
+interface Placement {
+  lane: number
+  item: TimelineItemRecord
+}
+
+interface TransitionPlacement {
+  lane: number
+  storylineKey: number
+  start: number
+  duration: number
+  metadata: Record<string, string>
+  leftIndex: number
+}
+
+function childElements(element: Element): Element[] {
+  return Array.from(element.children)
+}
+
+function readMetadata(element: Element): Record<string, string> {
+  const values: Record<string, string> = {}
+  for (const md of Array.from(element.querySelectorAll(':scope > metadata > md'))) {
+    const key = md.getAttribute('key') ?? ''
+    if (key.startsWith(FREECUT_METADATA_PREFIX)) {
+      values[key.slice(FREECUT_METADATA_PREFIX.length)] = md.getAttribute('value') ?? ''
+    }
+  }
+  return values
+}
+
+export function parseFcpxml(
+  xml: string,
+  media: readonly InterchangeMediaReference[],
+): InterchangeImportResult {
+  const doc = new DOMParser().parseFromString(xml, 'application/xml')
+  const parseError = doc.getElementsByTagName('parsererror')[0]
+  if (parseError) {
+    throw new Error(`Invalid FCPXML: ${parseError.textContent?.trim() || 'parse error'}`)
+  }
+  const root = doc.documentElement
+  if (root.tagName !== 'fcpxml') {
+    throw new Error('Invalid FCPXML: missing <fcpxml> root element')
+  }
+
+  const warnings: InterchangeWarning[] = []
+  const matchMedia = createInterchangeMediaMatcher(media)
+
+  const formats = new Map<string, { fps?: number; width?: number; height?: number }>()
+  for (const format of Array.from(root.querySelectorAll('resources > format'))) {
+    const frameDuration = parseFcpxmlTime(format.getAttribute('frameDuration'))
+    formats.set(format.getAttribute('id') ?? '', {
+      fps: frameDuration > 0 ? 1 / frameDuration : undefined,
+      width: Number(format.getAttribute('width')) || undefined,
+      height: Number(format.getAttribute('height')) || undefined,
+    })
+  }
+  const assets = new Map<string, ParsedAsset>()
+  for (const asset of Array.from(root.querySelectorAll('resources > asset'))) {
+    const mediaRep = asset.querySelector('media-rep')
+    assets.set(asset.getAttribute('id') ?? '', {
+      name: asset.getAttribute('name') ?? '',
+      src: mediaRep?.getAttribute('src') ?? asset.getAttribute('src') ?? undefined,
+      start: parseFcpxmlTime(asset.getAttribute('start')),
+      hasVideo: asset.getAttribute('hasVideo') === '1',
+      hasAudio: asset.getAttribute('hasAudio') === '1',
+      fps: formats.get(asset.getAttribute('format') ?? '')?.fps,
+    })
+  }
+
+  const sequence = root.querySelector('sequence')
+  if (!sequence) {
+    throw new Error('Invalid FCPXML: no <sequence> found')
+  }
+  const projectElement = sequence.closest('project')
+  const sequenceFormat = formats.get(sequence.getAttribute('format') ?? '')
+  const fps = sequenceFormat?.fps ? Number(sequenceFormat.fps.toFixed(3)) : 30
+  const metadata: ProjectResolution = {
+    width: sequenceFormat?.width ?? 1920,
+    height: sequenceFormat?.height ?? 1080,
+    fps,
+  }
+
+  const placements: Placement[] = []
+  const transitions: TransitionPlacement[] = []
+  const markers: NonNullable<ProjectTimeline['markers']> = []
+  /** Primary item created for each slot of each storyline (null for gaps). */
+  const storylineItems = new Map<number, Array<TimelineItemRecord | null>>()
+  let nextStorylineKey = 0
+
+  const toFrames = (seconds: number) => secondsToFrames(seconds, fps)
+
+  const createMediaItem = (
+    element: Element,
+    asset: ParsedAsset,
+    absoluteSeconds: number,
+  ): TimelineItemRecord[] => {
+    const name = element.getAttribute('name') ?? asset.name
+    const reference = matchMedia(asset.src, asset.name)
+    if (!reference) {
+      warnings.push({
+        code: 'unmatched_media',
+        message: `No media in the library matches "${asset.src ?? asset.name}"; clip "${name}" skipped`,
+      })
+      return []
+    }
+    const from = toFrames(absoluteSeconds)
+    const durationInFrames = Math.max(1, toFrames(parseFcpxmlTime(element.getAttribute('duration'))))
+    const sourceFps = reference.fps > 0 ? reference.fps : (asset.fps ?? fps)
+    const clipStart = parseFcpxmlTime(element.getAttribute('start')) - asset.start
+
+    let speed = 1
+    let isReversed = false
+    const timepts = Array.from(element.querySelectorAll(':scope > timeMap > timept'))
+    if (timepts.length >= 2) {
+      const first = timepts[0]!
+      const last = timepts[timepts.length - 1]!
+      const time = parseFcpxmlTime(last.getAttribute('time')) - parseFcpxmlTime(first.getAttribute('time'))
+      const value =
+        parseFcpxmlTime(last.getAttribute('value')) - parseFcpxmlTime(first.getAttribute('value'))
+      if (time > 0 && value !== 0) {
+        speed = Number(Math.abs(value / time).toFixed(4))
+        isReversed = value < 0
+      }
+    }
+
+    const volumeElement = element.querySelector(':scope > adjust-volume')
+    const volume = volumeElement ? parseFloat(volumeElement.getAttribute('amount') ?? '0') : 0
+
+    const sourceStart = Math.max(0, Math.round(clipStart * sourceFps))
+    const sourceSpan = Math.round(((durationInFrames * speed) / fps) * sourceFps)
+    const sourceDuration = Math.max(
+      sourceStart + sourceSpan,
+      Math.round(reference.duration * sourceFps),
+    )
+    const srcEnable = element.getAttribute('srcEnable') ?? 'all'
+    const isImage = reference.mimeType.startsWith('image/')
+    const base = {
+      from,
+      durationInFrames,
+      label: name,
+      mediaId: reference.id,
+      src: '',
+    }
+    const mediaFields = {
+      sourceStart,
+      sourceEnd: sourceStart + sourceSpan,
+      sourceDuration,
+      sourceFps,
+      speed,
+      ...(isReversed ? { isReversed } : {}),
+    }
+
+    if (isImage) {
+      return [
+        {
+          ...base,
+          id: crypto.randomUUID(),
+          trackId: '',
+          type: 'image',
+          sourceWidth: reference.width || undefined,
+          sourceHeight: reference.height || undefined,
+        },
+      ]
+    }
+
+    const wantsVideo = reference.hasVideo && asset.hasVideo && srcEnable !== 'audio'
+    const wantsAudio = reference.hasAudio && asset.hasAudio && srcEnable !== 'video'
+    const linkedGroupId = wantsVideo && wantsAudio ? crypto.randomUUID() : undefined
+    const items: TimelineItemRecord[] = []
+    if (wantsVideo) {
+      items.push({
+        ...base,
+        ...mediaFields,
+        id: crypto.randomUUID(),
+        trackId: '',
+        type: 'video',
+        ...(linkedGroupId ? { linkedGroupId } : {}),
+        ...(!wantsAudio && volume !== 0 ? { volume } : {}),
+        sourceWidth: reference.width || undefined,
+        sourceHeight: reference.height || undefined,
+      })
+    }
+    if (wantsAudio) {
+      items.push({
+        ...base,
+        ...mediaFields,
+        id: crypto.randomUUID(),
+        trackId: '',
+        type: 'audio',
+        ...(linkedGroupId ? { linkedGroupId } : {}),
+        volume,
+      })
+    }
+    return items
+  }
+
+  const createTitleItem = (element: Element, absoluteSeconds: number): TimelineItemRecord => {
+    const textStyle = element.querySelector('text-style-def > text-style')
+    const text = Array.from(element.querySelectorAll(':scope > text > text-style'))
+      .map((node) => node.textContent ?? '')
+      .join('')
+    const fontSize = Number(textStyle?.getAttribute('fontSize'))
+    const alignment = textStyle?.getAttribute('alignment')
+    return {
+      id: crypto.randomUUID(),
+      trackId: '',
+      type: 'text',
+      from: toFrames(absoluteSeconds),
+      durationInFrames: Math.max(1, toFrames(parseFcpxmlTime(element.getAttribute('duration')))),
+      label: element.getAttribute('name') ?? 'Title',
+      text,
+      color: fcpxmlColorToHex(textStyle?.getAttribute('fontColor') ?? null) ?? '#ffffff',
+      ...(textStyle?.getAttribute('font') ? { fontFamily: textStyle.getAttribute('font')! } : {}),
+      ...(Number.isFinite(fontSize) && fontSize > 0 ? { fontSize } : {}),
+      ...(alignment === 'left' || alignment === 'center' || alignment === 'right'
+        ? { textAlign: alignment }
+        : {}),
+    }
+  }
+
+  /** Walk a storyline; `absoluteStart` is where its first element begins. */
+  const walkStoryline = (spine: Element, absoluteStart: number, lane: number) => {
+    const storylineKey = nextStorylineKey++
+    const entries: Array<TimelineItemRecord | null> = []
+    storylineItems.set(storylineKey, entries)
+    const children = childElements(spine).filter((child) => child.hasAttribute('offset'))
+    const base = children.length > 0 ? parseFcpxmlTime(children[0]!.getAttribute('offset')) : 0
+
+    for (const child of children) {
+      const absolute = absoluteStart + parseFcpxmlTime(child.getAttribute('offset')) - base
+      if (child.tagName === 'transition') {
+        transitions.push({
+          lane,
+          storylineKey,
+          start: absolute,
+          duration: parseFcpxmlTime(child.getAttribute('duration')),
+          metadata: readMetadata(child),
+          leftIndex: entries.length - 1,
+        })
+        continue
+      }
+      const created = walkElement(child, absolute, lane)
+      // Only the primary element of each spine slot takes part in transitions.
+      entries.push(created.find((item) => item.type !== 'audio') ?? created[0] ?? null)
+    }
+  }
+
+  /** Place one story element (and its anchored children) at `absolute` seconds. */
+  const walkElement = (element: Element, absolute: number, lane: number): TimelineItemRecord[] => {
+    const localStart = parseFcpxmlTime(element.getAttribute('start'))
+    let created: TimelineItemRecord[] = []
+
+    switch (element.tagName) {
+      case 'gap': {
+        const note = element.querySelector(':scope > note')?.textContent ?? ''
+        if (note.startsWith('freecut:')) {
+          warnings.push({
+            code: 'unsupported_item',
+            message: `${note.slice('freecut:'.length)} item "${element.getAttribute('name') ?? ''}" was exported as a placeholder gap and could not be restored`,
+          })
+        }
+        break
+      }
+      case 'asset-clip': {
+        const asset = assets.get(element.getAttribute('ref') ?? '')
+        if (asset) created = createMediaItem(element, asset, absolute)
+        break
+      }
+      case 'clip':
+      case 'video':
+      case 'audio': {
+        const refElement = element.hasAttribute('ref')
+          ? element
+          : element.querySelector(':scope > video[ref], :scope > audio[ref], :scope > asset-clip[ref]')
+        const asset = refElement ? assets.get(refElement.getAttribute('ref') ?? '') : undefined
+        if (asset) {
+          const effectiveAsset =
+            element.tagName === 'audio'
+              ? { ...asset, hasVideo: false }
+              : element.tagName === 'video'
+                ? { ...asset, hasAudio: false }
+                : asset
+          created = createMediaItem(element, effectiveAsset, absolute)
+        }
+        break
+      }
+      case 'title':
+        created = [createTitleItem(element, absolute)]
+        break
+      default:
+        warnings.push({
+          code: 'unsupported_element',
+          message: `<${element.tagName}> elements are not supported and were skipped`,
+        })
+        return []
+    }
+
+    for (const item of created) placements.push({ lane, item })
+
+    for (const marker of Array.from(element.querySelectorAll(':scope > marker'))) {
+      const frame = toFrames(absolute + parseFcpxmlTime(marker.getAttribute('start')) - localStart)
+      const note = marker.getAttribute('note') ?? ''
+      markers.push({
+        id: crypto.randomUUID(),
+        frame,
+        label: marker.getAttribute('value') || undefined,
+        color: /^#[0-9a-f]{6}$/i.test(note) ? note : DEFAULT_MARKER_COLOR,
+      })
+    }
+
+    for (const anchored of childElements(element)) {
+      const anchoredLane = Number(anchored.getAttribute('lane'))
+      if (!anchored.hasAttribute('lane') || !Number.isFinite(anchoredLane)) continue
+      const anchoredAbsolute =
+        absolute + parseFcpxmlTime(anchored.getAttribute('offset')) - localStart
+      if (anchored.tagName === 'spine') {
+        walkStoryline(anchored, anchoredAbsolute, anchoredLane)
+      } else {
+        walkElement(anchored, anchoredAbsolute, anchoredLane)
+      }
+    }
+    return created
+  }
+
+  const primarySpine = sequence.querySelector(':scope > spine')
+  if (primarySpine) walkStoryline(primarySpine, 0, 0)
+
+  // Build tracks: one per lane and kind, splitting lanes whose items overlap.
+  const laneKey = (placement: Placement) =>
+    `${placement.item.type === 'audio' ? 'audio' : 'video'}:${placement.lane}`
+  const lanes = new Map<string, Placement[]>()
+  for (const placement of placements) {
+    const key = laneKey(placement)
+    const list = lanes.get(key) ?? []
+    list.push(placement)
+    lanes.set(key, list)
+  }
+
+  const videoLanes = [...lanes.keys()]
+    .filter((key) => key.startsWith('video:'))
+    .map((key) => Number(key.slice('video:'.length)))
+    .sort((a, b) => b - a)
+  const audioLanes = [...lanes.keys()]
+    .filter((key) => key.startsWith('audio:'))
+    .map((key) => Number(key.slice('audio:'.length)))
+    // Audio companions of lane-0 clips sit on the first audio track.
+    .sort((a, b) => (a === 0 ? -1 : b === 0 ? 1 : b - a))
+
+  const tracks: ProjectTimeline['tracks'] = []
+  const items: ProjectTimeline['items'] = []
+  const buildLaneTracks = (kind: 'video' | 'audio', lane: number) => {
+    const laneTracks: Array<{ track: TimelineTrackRecord; end: number }> = []
+    const laneItems = [...(lanes.get(`${kind}:${lane}`) ?? [])].sort(
+      (a, b) => a.item.from - b.item.from,
+    )
+    for (const { item } of laneItems) {
+      let target = laneTracks.find((candidate) => candidate.end <= item.from)
+      if (!target) {
+        target = {
+          track: {
+            id: crypto.randomUUID(),
+            name: '',
+            kind,
+            height: 100,
+            locked: false,
+            syncLock: true,
+            visible: true,
+            muted: false,
+            solo: false,
+            volume: 0,
+            order: 0,
+          },
+          end: 0,
+        }
+        laneTracks.push(target)
+      }
+      target.end = item.from + item.durationInFrames
+      items.push({ ...item, trackId: target.track.id })
+    }
+    tracks.push(...laneTracks.map((entry) => entry.track))
+  }
+  for (const lane of videoLanes) buildLaneTracks('video', lane)
+  for (const lane of audioLanes) buildLaneTracks('audio', lane)
+
+  let videoCount = tracks.filter((track) => track.kind === 'video').length
+  let audioCount = 0
+  tracks.forEach((track, order) => {
+    track.order = order
+    track.name = track.kind === 'video' ? `V${videoCount--}` : `A${++audioCount}`
+  })
+
+  relinkCompanionItems(items)
+
+  const rebuiltTransitions: Transition[] = []
+  for (const placement of transitions) {
+    const entries = storylineItems.get(placement.storylineKey) ?? []
+    const left = entries[placement.leftIndex]
+    const right = entries[placement.leftIndex + 1]
+    if (!left || !right) {
+      warnings.push({
+        code: 'unsupported_element',
+        message: 'A transition without clips on both sides was skipped',
+      })
+      continue
+    }
+    const leftItem = items.find((item) => item.id === left.id)
+    const rightItem = items.find((item) => item.id === right.id)
+    if (!leftItem || !rightItem || leftItem.trackId !== rightItem.trackId) continue
+    const durationInFrames = Math.max(1, toFrames(placement.duration))
+    const alignment = Number(
+      Math.min(1, Math.max(0, (rightItem.from - toFrames(placement.start)) / durationInFrames)).toFixed(
+        4,
+      ),
+    )
+    const { presentation, timing, direction } = placement.metadata
+    rebuiltTransitions.push({
+      id: crypto.randomUUID(),
+      type: 'crossfade',
+      presentation: presentation || 'fade',
+      timing: (timing as Transition['timing']) || 'linear',
+      leftClipId: leftItem.id,
+      rightClipId: rightItem.id,
+      trackId: leftItem.trackId,
+      durationInFrames,
+      alignment,
+      ...(direction ? { direction: direction as Transition['direction'] } : {}),
+    })
+  }
+
+  const mediaIds = [...new Set(items.flatMap((item) => (item.mediaId ? [item.mediaId] : [])))]
+
+  return {
+    name: projectElement?.getAttribute('name') ?? sequence.getAttribute('name') ?? 'Imported FCPXML',
+    metadata,
+    timeline: {
+      tracks,
+      items,
+      markers: markers.sort((a, b) => a.frame - b.frame),
+      transitions: rebuiltTransitions,
+    },
+    mediaIds,
+    warnings,
+  }
+}
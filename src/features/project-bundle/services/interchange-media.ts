@@ -0,0 +1,146 @@
+import type { ProjectTimeline } from '@/types/project'
+import type { MediaMetadata } from '@/types/storage'
+import {
+  INTERCHANGE_EXTENSIONS,
+  type InterchangeFormat,
+  type InterchangeMediaReference,
+} from '../types/interchange'
+
+/** Interchange format for a file name, or null when it isn't one we read. */
+export function detectInterchangeFormat(fileName: string): InterchangeFormat | null {
+  const lower = fileName.toLowerCase()
+  for (const [format, extension] of Object.entries(INTERCHANGE_EXTENSIONS)) {
+    if (lower.endsWith(extension)) return format as InterchangeFormat
+  }
+  return null
+}
+
+/**
+ * Build the interchange view of a media entry. Linked-in-place media keeps
+ * its original on-disk path; everything else points at the workspace copy.
+ */
+export function toInterchangeMediaReference(
+  media: MediaMetadata,
+  options: { linkedPath?: string | null; workspacePath: string },
+): InterchangeMediaReference {
+  const isAudio = media.mimeType.startsWith('audio/')
+  const isImage = media.mimeType.startsWith('image/')
+  return {
+    id: media.id,
+    fileName: media.fileName,
+    path: options.linkedPath || options.workspacePath,
+    mimeType: media.mimeType,
+    duration: media.duration,
+    width: media.width,
+    height: media.height,
+    fps: media.fps,
+    hasVideo: !isAudio,
+    hasAudio: isAudio || (!isImage && media.audioCodec !== undefined),
+  }
+}
+
+/** Normalize file URLs and Windows separators so paths compare reliably. */
+export function normalizeInterchangePath(path: string): string {
+  let normalized = path.trim()
+  if (/^file:/i.test(normalized)) {
+    normalized = normalized.replace(/^file:\/\/(localhost)?/i, '')
+    try {
+      normalized = decodeURIComponent(normalized)
+    } catch {
+      // Keep the raw path when it isn't valid percent-encoding.
+    }
+    // file:///C:/foo -> C:/foo
+    normalized = normalized.replace(/^\/([A-Za-z]:\/)/, '$1')
+  }
+  return normalized.replace(/\\/g, '/')
+}
+
+function basename(path: string): string {
+  const normalized = normalizeInterchangePath(path)
+  const slash = normalized.lastIndexOf('/')
+  return slash >= 0 ? normalized.slice(slash + 1) : normalized
+}
+
+function stem(fileName: string): string {
+  const dot = fileName.lastIndexOf('.')
+  return dot > 0 ? fileName.slice(0, dot) : fileName
+}
+
+/**
+ * Create a resolver from interchange paths/clip names to known media.
+ *
+ * Matching order, most to least specific:
+ * 1. a `media/<id>/` segment naming a known media id (FreeCut exports)
+ * 2. exact normalized path
+ * 3. file name (case-insensitive)
+ * 4. clip/reel name against the file name stem
+ */
+export function createInterchangeMediaMatcher(
+  media: readonly InterchangeMediaReference[],
+): (path: string | undefined, name?: string) => InterchangeMediaReference | undefined {
+  const byId = new Map(media.map((entry) => [entry.id, entry]))
+  const byPath = new Map(media.map((entry) => [normalizeInterchangePath(entry.path), entry]))
+  const byFileName = new Map<string, InterchangeMediaReference>()
+  const byStem = new Map<string, InterchangeMediaReference>()
+  for (const entry of media) {
+    const lowerName = entry.fileName.toLowerCase()
+    if (!byFileName.has(lowerName)) byFileName.set(lowerName, entry)
+    const lowerStem = stem(lowerName)
+    if (!byStem.has(lowerStem)) byStem.set(lowerStem, entry)
+  }
+
+  return (path, name) => {
+    if (path) {
+      const normalized = normalizeInterchangePath(path)
+      const idMatch = /(?:^|\/)media\/([^/]+)\/[^/]+$/.exec(normalized)
+      const fromId = idMatch ? byId.get(idMatch[1]!) : undefined
+      if (fromId) return fromId
+
+      const fromPath = byPath.get(normalized)
+      if (fromPath) return fromPath
+
+      const fromFileName = byFileName.get(basename(normalized).toLowerCase())
+      if (fromFileName) return fromFileName
+    }
+
+    if (name) {
+      const lowerName = name.trim().toLowerCase()
+      return byFileName.get(lowerName) ?? byStem.get(stem(lowerName))
+    }
+    return undefined
+  }
+}
+
+type TimelineItemRecord = ProjectTimeline['items'][number]
+type TimelineTrackRecord = ProjectTimeline['tracks'][number]
+
+/** Track kind, falling back to the item types for legacy untyped tracks. */
+export function interchangeTrackKind(
+  track: TimelineTrackRecord,
+  items: readonly TimelineItemRecord[],
+): 'video' | 'audio' {
+  if (track.kind) return track.kind
+  return items.length > 0 && items.every((item) => item.type === 'audio') ? 'audio' : 'video'
+}
+
+/**
+ * Re-link separated video/audio clips of the same media at the same place.
+ * Most formats carry picture and sound as independent clips; FreeCut pairs
+ * them through `linkedGroupId`. Mutates the items in place.
+ */
+export function relinkCompanionItems(items: readonly TimelineItemRecord[]): void {
+  const unlinkedAudio = items.filter((item) => item.type === 'audio' && !item.linkedGroupId)
+  for (const video of items.filter((item) => item.type === 'video' && !item.linkedGroupId)) {
+    const index = unlinkedAudio.findIndex(
+      (audio) =>
+        audio.mediaId === video.mediaId &&
+        audio.from === video.from &&
+        audio.durationInFrames === video.durationInFrames,
+    )
+    if (index < 0) continue
+    const [audio] = unlinkedAudio.splice(index, 1)
+    const linkedGroupId = crypto.randomUUID()
+    video.linkedGroupId = linkedGroupId
+    audio!.linkedGroupId = linkedGroupId
+  }
+}
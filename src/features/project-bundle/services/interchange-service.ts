@@ -0,0 +1,195 @@
+/**
+ * Interchange Service
+ *
+ * Storage-facing wrappers around the pure interchange converters: loads the
+ * project and its media references, and persists imported timelines as new
+ * projects matched against the current media library.
+ */
+
+import { strToU8, zipSync } from 'fflate'
+import type { Project } from '@/types/project'
+import type { MediaMetadata } from '@/types/storage'
+import {
+  associateMediaWithProject,
+  createProject,
+  getAllMedia,
+  getProject,
+  getProjectMediaIds,
+  mediaSourceByFileName,
+  readMediaSourceLink,
+} from '@/infrastructure/storage'
+import { CURRENT_SCHEMA_VERSION } from '@/shared/projects/migrations'
+import { importMediaLibraryService } from '@/features/project-bundle/deps/media-library'
+import {
+  INTERCHANGE_EXTENSIONS,
+  type InterchangeExportInput,
+  type InterchangeExportResult,
+  type InterchangeFormat,
+  type InterchangeImportResult,
+  type InterchangeMediaReference,
+  type InterchangeWarning,
+} from '../types/interchange'
+import { toInterchangeMediaReference } from './interchange-media'
+import { parseEdl, projectToEdl } from './edl-converter'
+import { parseFcpxml, projectToFcpxml } from './fcpxml-converter'
+import { parseOtio, projectToOtio } from './otio-converter'
+import { sanitizeDownloadFilename } from './pure-utils'
+
+export interface InterchangeProjectImport {
+  project: Project
+  warnings: InterchangeWarning[]
+}
+
+async function toReference(media: MediaMetadata): Promise<InterchangeMediaReference> {
+  const link = await readMediaSourceLink(media.id).catch(() => null)
+  return toInterchangeMediaReference(media, {
+    linkedPath: link?.path,
+    workspacePath: mediaSourceByFileName(media.id, media.fileName).join('/'),
+  })
+}
+
+async function loadProjectWithMedia(
+  projectId: string,
+): Promise<{ project: Project; media: InterchangeMediaReference[] }> {
+  const project = await getProject(projectId)
+  if (!project) {
+    throw new Error(`Project not found: ${projectId}`)
+  }
+  const { mediaLibraryService } = await importMediaLibraryService()
+  const media: InterchangeMediaReference[] = []
+  for (const mediaId of await getProjectMediaIds(projectId)) {
+    const metadata = await mediaLibraryService.getMedia(mediaId)
+    if (metadata) media.push(await toReference(metadata))
+  }
+  return { project, media }
+}
+
+async function loadLibraryMedia(): Promise<InterchangeMediaReference[]> {
+  return Promise.all((await getAllMedia()).map(toReference))
+}
+
+async function persistImportedTimeline(
+  result: InterchangeImportResult,
+  name?: string,
+): Promise<InterchangeProjectImport> {
+  const now = Date.now()
+  const project: Project = {
+    id: crypto.randomUUID(),
+    name: name || result.name,
+    description: '',
+    createdAt: now,
+    updatedAt: now,
+    duration: 0,
+    schemaVersion: CURRENT_SCHEMA_VERSION,
+    metadata: result.metadata,
+    timeline: result.timeline,
+  }
+  await createProject(project)
+  for (const mediaId of result.mediaIds) {
+    await associateMediaWithProject(project.id, mediaId)
+  }
+  return { project, warnings: result.warnings }
+}
+
+function projectExporter(convert: (input: InterchangeExportInput) => InterchangeExportResult) {
+  return async (projectId: string): Promise<InterchangeExportResult> => {
+    const { project, media } = await loadProjectWithMedia(projectId)
+    return convert({
+      name: project.name,
+      metadata: project.metadata,
+      timeline: project.timeline ?? { tracks: [], items: [] },
+      media,
+    })
+  }
+}
+
+const INTERCHANGE_FORMATS: Record<
+  InterchangeFormat,
+  {
+    mimeType: string
+    exportProject: (projectId: string) => Promise<InterchangeExportResult>
+    parse: (content: string, media: InterchangeMediaReference[]) => InterchangeImportResult
+  }
+> = {
+  fcpxml: {
+    mimeType: 'application/xml',
+    exportProject: projectExporter(projectToFcpxml),
+    parse: parseFcpxml,
+  },
+  otio: {
+    mimeType: 'application/json',
+    exportProject: projectExporter(projectToOtio),
+    parse: parseOtio,
+  },
+  edl: {
+    mimeType: 'text/plain',
+    exportProject: projectExporter(projectToEdl),
+    parse: parseEdl,
+  },
+}
+
+/**
+ * Import an interchange document as a new project, resolving clips against
+ * the media library by path and clip name.
+ */
+export async function importProjectFromInterchange(
+  format: InterchangeFormat,
+  content: string,
+  options: { newProjectName?: string } = {},
+): Promise<InterchangeProjectImport> {
+  const result = INTERCHANGE_FORMATS[format].parse(content, await loadLibraryMedia())
+  return persistImportedTimeline(result, options.newProjectName)
+}
+
+/**
+ * Export a project's timeline in an interchange format.
+ */
+export async function exportProjectInterchange(
+  projectId: string,
+  format: InterchangeFormat,
+): Promise<InterchangeExportResult> {
+  return INTERCHANGE_FORMATS[format].exportProject(projectId)
+}
+
+/**
+ * Export a project in an interchange format and download it. Returns the
+ * converter warnings (items that could not be represented).
+ */
+export async function downloadProjectInterchange(
+  projectId: string,
+  format: InterchangeFormat,
+): Promise<InterchangeWarning[]> {
+  const exporter = INTERCHANGE_FORMATS[format]
+  const project = await getProject(projectId)
+  const { content, warnings, documents } = await exporter.exportProject(projectId)
+
+  const safeName = sanitizeDownloadFilename(project?.name ?? '', { fallback: 'project' })
+  const extension = INTERCHANGE_EXTENSIONS[format]
+  let blob: Blob
+  let fileName: string
+  if (documents && documents.length > 1) {
+    // One document per track: hand them over together as a zip.
+    const files = Object.fromEntries(
+      documents.map((entry) => [
+        `${safeName}-${sanitizeDownloadFilename(entry.suffix, { fallback: 'track' })}${extension}`,
+        strToU8(entry.content),
+      ]),
+    )
+    blob = new Blob([zipSync(files)], { type: 'application/zip' })
+    fileName = `${safeName}${extension}.zip`
+  } else {
+    blob = new Blob([content], { type: exporter.mimeType })
+    fileName = `${safeName}${extension}`
+  }
+
+  const url = URL.createObjectURL(blob)
+  const a = document.createElement('a')
+  a.href = url
+  a.download = fileName
+  document.body.appendChild(a)
+  a.click()
+  document.body.removeChild(a)
+  URL.revokeObjectURL(url)
+
+  return warnings
+}
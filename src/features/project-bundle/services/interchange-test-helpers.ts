@@ -0,0 +1,87 @@
+import type { ProjectResolution, ProjectTimeline } from '@/types/project'
+import type {
+  InterchangeExportInput,
+  InterchangeExportResult,
+  InterchangeMediaReference,
+} from '../types/interchange'
+
+type TrackRecord = ProjectTimeline['tracks'][number]
+
+export const TEST_METADATA: ProjectResolution = { width: 1920, height: 1080, fps: 30 }
+
+export const TEST_CLIP: InterchangeMediaReference = {
+  id: 'media-1',
+  fileName: 'clip.mp4',
+  path: 'media/media-1/source.mp4',
+  mimeType: 'video/mp4',
+  duration: 20,
+  width: 1920,
+  height: 1080,
+  fps: 30,
+  hasVideo: true,
+  hasAudio: true,
+}
+
+export function makeTrack(
+  id: string,
+  name: string,
+  order: number,
+  kind: 'video' | 'audio',
+): TrackRecord {
+  return {
+    id,
+    name,
+    kind,
+    height: 100,
+    locked: false,
+    visible: true,
+    muted: false,
+    solo: false,
+    order,
+  }
+}
+
+/** Media-backed item fields for a clip cut from `media`, at the media's own frame rate. */
+export function mediaFields(
+  media: InterchangeMediaReference,
+  sourceStart: number,
+  sourceEnd: number,
+  speed = 1,
+) {
+  return {
+    mediaId: media.id,
+    src: '',
+    sourceStart,
+    sourceEnd,
+    sourceDuration: Math.round(media.duration * media.fps),
+    sourceFps: media.fps,
+    speed,
+  }
+}
+
+/** A timeline on the converters' usual tracks: a title track above V1, and A1. */
+export function makeTimeline(
+  overrides: Partial<ProjectTimeline> = {},
+  titleTrackName = 'V2',
+): ProjectTimeline {
+  return {
+    tracks: [
+      makeTrack('track-v2', titleTrackName, 0, 'video'),
+      makeTrack('track-v1', 'V1', 1, 'video'),
+      makeTrack('track-a1', 'A1', 2, 'audio'),
+    ],
+    items: [],
+    ...overrides,
+  }
+}
+
+export function exportTimeline(
+  convert: (input: InterchangeExportInput) => InterchangeExportResult,
+  timeline: ProjectTimeline,
+  {
+    media = [TEST_CLIP],
+    metadata = TEST_METADATA,
+  }: { media?: readonly InterchangeMediaReference[]; metadata?: ProjectResolution } = {},
+): InterchangeExportResult {
+  return convert({ name: 'Cut', metadata, timeline, media })
+}
@@ -234,6 +234,16 @@ async function importProjectFromSnapshot(
         transitions: newTransitions,
         keyframes: newKeyframes,
       }
+
+      // Remap canvas variant overrides to the new item IDs
+      project.canvasVariants = project.canvasVariants?.map((variant) => ({
+        ...variant,
+        id: crypto.randomUUID(),
+        overrides: variant.overrides.map((override) => ({
+          ...override,
+          itemId: itemIdMap.get(override.itemId) || override.itemId,
+        })),
+      }))
     }
   }
 
@@ -309,6 +319,16 @@ async function importProjectFromSnapshot(
       const mediaIdMap = new Map(matchedMedia.map((m) => [m.snapshotMediaId, m.localMediaId]))
 
       project.timeline.items = project.timeline.items.map((item) => {
+        if (item.angles) {
+          item = {
+            ...item,
+            angles: item.angles.map((angle) => ({
+              ...angle,
+              mediaId: mediaIdMap.get(angle.mediaId) ?? angle.mediaId,
+              src: '',
+            })),
+          }
+        }
         if (item.mediaId && mediaIdMap.has(item.mediaId)) {
           return {
             ...item,
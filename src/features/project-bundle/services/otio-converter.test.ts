@@ -0,0 +1,290 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { ProjectTimeline } from '@/types/project'
+import {
+  exportTimeline,
+  makeTimeline,
+  mediaFields,
+  TEST_CLIP,
+  TEST_METADATA,
+} from './interchange-test-helpers'
+import { parseOtio, projectToOtio } from './otio-converter'
+
+function sampleTimeline(): ProjectTimeline {
+  return makeTimeline(
+    {
+      items: [
+        {
+          id: 'title-1',
+          trackId: 'track-v2',
+          type: 'text',
+          from: 15,
+          durationInFrames: 30,
+          label: 'Lower third',
+          text: 'Hello',
+          color: '#ff0000',
+        },
+        {
+          id: 'shape-1',
+          trackId: 'track-v2',
+          type: 'shape',
+          shapeType: 'star',
+          from: 60,
+          durationInFrames: 20,
+          label: 'Star',
+          fillColor: '#00ff00',
+          points: 7,
+        },
+        {
+          id: 'video-1',
+          trackId: 'track-v1',
+          type: 'video',
+          from: 0,
+          durationInFrames: 60,
+          label: 'clip.mp4',
+          linkedGroupId: 'link-1',
+          trimStart: 0,
+          trimEnd: 0,
+          transform: { x: 10, y: 20, opacity: 0.5 },
+          ...mediaFields(TEST_CLIP, 30, 90),
+        },
+        {
+          id: 'audio-1',
+          trackId: 'track-a1',
+          type: 'audio',
+          from: 0,
+          durationInFrames: 60,
+          label: 'clip.mp4',
+          linkedGroupId: 'link-1',
+          volume: -6,
+          ...mediaFields(TEST_CLIP, 30, 90),
+        },
+        {
+          id: 'video-2',
+          trackId: 'track-v1',
+          type: 'video',
+          from: 60,
+          durationInFrames: 60,
+          label: 'clip.mp4',
+          isReversed: true,
+          ...mediaFields(TEST_CLIP, 0, 120, 2),
+        },
+      ],
+      markers: [{ id: 'marker-1', frame: 45, color: '#123456', label: 'Beat' }],
+      transitions: [
+        {
+          id: 'transition-1',
+          type: 'crossfade',
+          presentation: 'wipe',
+          timing: 'linear',
+          direction: 'from-left',
+          leftClipId: 'video-1',
+          rightClipId: 'video-2',
+          trackId: 'track-v1',
+          durationInFrames: 12,
+          alignment: 0.75,
+        },
+      ],
+      keyframes: [
+        {
+          itemId: 'title-1',
+          properties: [
+            {
+              property: 'opacity',
+              keyframes: [
+                { id: 'kf-1', frame: 0, value: 0, easing: 'linear' },
+                { id: 'kf-2', frame: 10, value: 1, easing: 'linear' },
+              ],
+            },
+          ],
+        },
+      ],
+    },
+    'Titles',
+  )
+}
+
+describe('projectToOtio', () => {
+  it('writes a stack with video tracks bottom-up followed by audio tracks', () => {
+    const { content } = exportTimeline(projectToOtio, sampleTimeline())
+    const doc = JSON.parse(content)
+
+    expect(doc.OTIO_SCHEMA).toBe('Timeline.1')
+    expect(doc.tracks.OTIO_SCHEMA).toBe('Stack.1')
+    expect(
+      doc.tracks.children.map((track: { name: string; kind: string }) => [track.name, track.kind]),
+    ).toEqual([
+      ['V1', 'Video'],
+      ['Titles', 'Video'],
+      ['A1', 'Audio'],
+    ])
+
+    const [v1] = doc.tracks.children
+    expect(v1.children.map((child: { OTIO_SCHEMA: string }) => child.OTIO_SCHEMA)).toEqual([
+      'Clip.2',
+      'Transition.1',
+      'Clip.2',
+    ])
+    const [firstClip, transition, secondClip] = v1.children
+    expect(firstClip.source_range.start_time).toMatchObject({ value: 30, rate: 30 })
+    expect(firstClip.media_references.DEFAULT_MEDIA).toMatchObject({
+      OTIO_SCHEMA: 'ExternalReference.1',
+      target_url: 'media/media-1/source.mp4',
+    })
+    expect(transition.in_offset.value).toBe(9)
+    expect(transition.out_offset.value).toBe(3)
+    expect(secondClip.effects[0]).toMatchObject({
+      OTIO_SCHEMA: 'LinearTimeWarp.1',
+      time_scalar: -2,
+    })
+    expect(firstClip.metadata.freecut.item.src).toBeUndefined()
+  })
+
+  it('writes unsupported items as gaps carrying the original item', () => {
+    const { content, warnings } = exportTimeline(projectToOtio, sampleTimeline())
+    const titles = JSON.parse(content).tracks.children[1]
+
+    expect(titles.children.map((child: { OTIO_SCHEMA: string }) => child.OTIO_SCHEMA)).toEqual([
+      'Gap.1',
+      'Gap.1',
+      'Gap.1',
+      'Gap.1',
+    ])
+    expect(titles.children[1].metadata.freecut.item).toMatchObject({ type: 'text', text: 'Hello' })
+    expect(warnings.map((warning) => warning.itemId)).toEqual(['title-1', 'shape-1'])
+  })
+})
+
+describe('parseOtio', () => {
+  it('round-trips a FreeCut timeline losslessly', () => {
+    const { content } = exportTimeline(projectToOtio, sampleTimeline())
+    const result = parseOtio(content, [TEST_CLIP])
+
+    expect(result.name).toBe('Cut')
+    expect(result.metadata).toEqual(TEST_METADATA)
+    expect(result.mediaIds).toEqual(['media-1'])
+    expect(result.warnings).toEqual([])
+
+    const { tracks, items, markers, transitions, keyframes } = result.timeline
+    expect(tracks.map((track) => [track.name, track.kind, track.order])).toEqual([
+      ['Titles', 'video', 0],
+      ['V1', 'video', 1],
+      ['A1', 'audio', 2],
+    ])
+
+    const byLabel = (label: string) => items.filter((item) => item.label === label)
+    const [title] = byLabel('Lower third')
+    const [shape] = byLabel('Star')
+    expect(title).toMatchObject({ type: 'text', from: 15, durationInFrames: 30, text: 'Hello' })
+    expect(shape).toMatchObject({ type: 'shape', shapeType: 'star', from: 60, points: 7 })
+    expect(title!.trackId).toBe(tracks[0]!.id)
+
+    const clips = byLabel('clip.mp4').sort((a, b) => a.from - b.from || a.type.localeCompare(b.type))
+    const [audio1, video1, video2] = clips
+    expect(video1).toMatchObject({
+      type: 'video',
+      from: 0,
+      sourceStart: 30,
+      sourceEnd: 90,
+      linkedGroupId: 'link-1',
+      transform: { x: 10, y: 20, opacity: 0.5 },
+      src: '',
+    })
+    expect(audio1).toMatchObject({ type: 'audio', volume: -6, linkedGroupId: 'link-1' })
+    expect(video2).toMatchObject({ from: 60, speed: 2, isReversed: true, sourceEnd: 120 })
+    expect(video1!.id).not.toBe('video-1')
+
+    expect(transitions).toEqual([
+      expect.objectContaining({
+        leftClipId: video1!.id,
+        rightClipId: video2!.id,
+        trackId: tracks[1]!.id,
+        durationInFrames: 12,
+        alignment: 0.75,
+        presentation: 'wipe',
+        direction: 'from-left',
+      }),
+    ])
+    expect(markers).toEqual([expect.objectContaining({ frame: 45, label: 'Beat', color: '#123456' })])
+    expect(keyframes).toEqual([expect.objectContaining({ itemId: title!.id })])
+  })
+
+  it('imports documents written by other tools', () => {
+    const rate = 24
+    const time = (value: number) => ({ OTIO_SCHEMA: 'RationalTime.1', rate, value })
+    const range = (start: number, duration: number) => ({
+      OTIO_SCHEMA: 'TimeRange.1',
+      start_time: time(start),
+      duration: time(duration),
+    })
+    const clip = (name: string, start: number, duration: number) => ({
+      OTIO_SCHEMA: 'Clip.1',
+      name,
+      source_range: range(start, duration),
+      media_reference: {
+        OTIO_SCHEMA: 'ExternalReference.1',
+        target_url: `file:///Volumes/Footage/${name}`,
+      },
+      markers: [],
+      effects: [],
+    })
+    const doc = {
+      OTIO_SCHEMA: 'Timeline.1',
+      name: 'From Resolve',
+      global_start_time: time(86400),
+      tracks: {
+        OTIO_SCHEMA: 'Stack.1',
+        children: [
+          {
+            OTIO_SCHEMA: 'Track.1',
+            name: 'Video 1',
+            kind: 'Video',
+            children: [
+              { OTIO_SCHEMA: 'Gap.1', source_range: range(0, 24) },
+              clip('clip.mp4', 48, 24),
+            ],
+          },
+          {
+            OTIO_SCHEMA: 'Track.1',
+            name: 'Audio 1',
+            kind: 'Audio',
+            children: [
+              { OTIO_SCHEMA: 'Gap.1', source_range: range(0, 24) },
+              clip('clip.mp4', 48, 24),
+              clip('missing.wav', 0, 24),
+            ],
+          },
+        ],
+        markers: [
+          {
+            OTIO_SCHEMA: 'Marker.2',
+            name: 'Note',
+            color: 'RED',
+            marked_range: range(12, 0),
+          },
+        ],
+      },
+    }
+
+    const result = parseOtio(JSON.stringify(doc), [{ ...TEST_CLIP, fps: 24 }])
+
+    expect(result.metadata).toEqual({ width: 1920, height: 1080, fps: 24 })
+    expect(result.timeline.tracks.map((track) => [track.name, track.kind])).toEqual([
+      ['V1', 'video'],
+      ['A1', 'audio'],
+    ])
+    const [video, audio] = result.timeline.items
+    expect(video).toMatchObject({ type: 'video', from: 24, durationInFrames: 24, sourceStart: 48 })
+    expect(audio).toMatchObject({ type: 'audio', from: 24, durationInFrames: 24 })
+    expect(video!.linkedGroupId).toBeDefined()
+    expect(video!.linkedGroupId).toBe(audio!.linkedGroupId)
+    expect(result.timeline.markers).toEqual([
+      expect.objectContaining({ frame: 12, label: 'Note', color: '#EF4444' }),
+    ])
+    expect(result.warnings).toEqual([expect.objectContaining({ code: 'unmatched_media' })])
+  })
+
+  it('rejects documents that are not OTIO timelines', () => {
+    expect(() => parseOtio('not json', [])).toThrow(/Invalid OTIO/)
+    expect(() => parseOtio(JSON.stringify({ OTIO_SCHEMA: 'Clip.2' }), [])).toThrow(/Timeline/)
+  })
+})
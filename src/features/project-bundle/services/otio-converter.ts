@@ -0,0 +1,757 @@
+/**
+ * OTIO Converter
+ *
+ * Pure conversion between FreeCut timelines and OpenTimelineIO JSON (.otio).
+ *
+ * Layout on export:
+ * - The timeline is one Stack of Tracks. Video tracks are written bottom-most
+ *   first (OTIO composites later children over earlier ones), followed by the
+ *   audio tracks in A1, A2… order. Items that overlap on one FreeCut track
+ *   spill into an extra OTIO track, since OTIO tracks are strictly sequential.
+ * - Media clips reference their workspace `media/<id>/source.*` file (or the
+ *   original path for linked-in-place media) through an ExternalReference.
+ * - Every clip carries its FreeCut item in `metadata.freecut`. Items OTIO has
+ *   no schema for (text, shapes, adjustment layers, compound clips) are
+ *   written as Gaps with the same metadata, so FreeCut → OTIO → FreeCut is
+ *   lossless while other tools still see correct timing.
+ * - Transitions keep FreeCut's cut-centered model: `in_offset` is the part
+ *   of the transition before the cut, `out_offset` the part after it.
+ */
+
+import type { ProjectResolution, ProjectTimeline } from '@/types/project'
+import type { Transition } from '@/types/transition'
+import type {
+  InterchangeExportInput,
+  InterchangeExportResult,
+  InterchangeImportResult,
+  InterchangeMediaReference,
+  InterchangeWarning,
+} from '../types/interchange'
+import {
+  createInterchangeMediaMatcher,
+  interchangeTrackKind,
+  relinkCompanionItems,
+} from './interchange-media'
+
+type TimelineItemRecord = ProjectTimeline['items'][number]
+type TimelineTrackRecord = ProjectTimeline['tracks'][number]
+type ItemKeyframes = NonNullable<ProjectTimeline['keyframes']>[number]
+
+const DEFAULT_MARKER_COLOR = '#3B82F6'
+const DEFAULT_MEDIA_KEY = 'DEFAULT_MEDIA'
+
+/** OTIO's fixed marker palette, with the FreeCut color each name maps to. */
+const OTIO_MARKER_COLORS: Record<string, string> = {
+  PINK: '#EC4899',
+  RED: '#EF4444',
+  ORANGE: '#F97316',
+  YELLOW: '#EAB308',
+  GREEN: '#22C55E',
+  CYAN: '#06B6D4',
+  BLUE: '#3B82F6',
+  PURPLE: '#8B5CF6',
+  MAGENTA: '#D946EF',
+  BLACK: '#000000',
+  WHITE: '#FFFFFF',
+}
+
+/** Runtime-only item fields that must not leak into an interchange file. */
+const VOLATILE_ITEM_FIELDS = [
+  'src',
+  'thumbnailUrl',
+  'waveformData',
+  'reverseConformSrc',
+  'reverseConformPreviewSrc',
+] as const
+
+// ---------------------------------------------------------------------------
+// OTIO JSON shapes
+// ---------------------------------------------------------------------------
+
+interface OtioRationalTime {
+  OTIO_SCHEMA: 'RationalTime.1'
+  rate: number
+  value: number
+}
+
+interface OtioTimeRange {
+  OTIO_SCHEMA: 'TimeRange.1'
+  start_time: OtioRationalTime
+  duration: OtioRationalTime
+}
+
+/** Any OTIO object. Reading is defensive: files may come from any tool. */
+interface OtioObject {
+  OTIO_SCHEMA: string
+  name?: string
+  metadata?: Record<string, unknown>
+  [key: string]: unknown
+}
+
+/** FreeCut's namespace inside OTIO `metadata`. */
+interface FreecutOtioMetadata {
+  metadata?: ProjectResolution
+  track?: TimelineTrackRecord
+  item?: TimelineItemRecord
+  keyframes?: ItemKeyframes['properties']
+  transition?: Partial<Transition>
+  mediaId?: string
+  color?: string
+}
+
+function rationalTime(value: number, rate: number): OtioRationalTime {
+  return { OTIO_SCHEMA: 'RationalTime.1', rate, value }
+}
+
+function timeRange(
+  start: number,
+  duration: number,
+  rate: number,
+  startRate: number = rate,
+): OtioTimeRange {
+  return {
+    OTIO_SCHEMA: 'TimeRange.1',
+    start_time: rationalTime(start, startRate),
+    duration: rationalTime(duration, rate),
+  }
+}
+
+function otioObject(
+  schema: string,
+  name: string,
+  fields: Record<string, unknown>,
+  freecut?: FreecutOtioMetadata,
+): OtioObject {
+  return {
+    OTIO_SCHEMA: schema,
+    name,
+    metadata: freecut ? { freecut } : {},
+    ...fields,
+  }
+}
+
+/** Nearest OTIO marker color name for a hex color. */
+function toOtioMarkerColor(color: string | undefined): string {
+  const match = /^#?([0-9a-f]{6})$/i.exec(color?.trim() ?? '')
+  if (!match) return 'BLUE'
+  const rgb = (hex: string) => [0, 2, 4].map((index) => parseInt(hex.slice(index, index + 2), 16))
+  const target = rgb(match[1]!)
+  let best = 'BLUE'
+  let bestDistance = Infinity
+  for (const [name, hex] of Object.entries(OTIO_MARKER_COLORS)) {
+    const distance = rgb(hex.slice(1)).reduce(
+      (sum, channel, index) => sum + (channel - target[index]!) ** 2,
+      0,
+    )
+    if (distance < bestDistance) {
+      best = name
+      bestDistance = distance
+    }
+  }
+  return best
+}
+
+function storableItem(item: TimelineItemRecord): TimelineItemRecord {
+  const stored = { ...item }
+  for (const field of VOLATILE_ITEM_FIELDS) delete stored[field]
+  return stored
+}
+
+// ---------------------------------------------------------------------------
+// Export
+// ---------------------------------------------------------------------------
+
+export function projectToOtio(input: InterchangeExportInput): InterchangeExportResult {
+  const { metadata, timeline } = input
+  const fps = metadata.fps
+  const warnings: InterchangeWarning[] = []
+  const mediaById = new Map(input.media.map((entry) => [entry.id, entry]))
+  const keyframesByItem = new Map(
+    (timeline.keyframes ?? []).map((entry) => [entry.itemId, entry.properties]),
+  )
+  const transitionsByPair = new Map<string, Transition>()
+  for (const transition of timeline.transitions ?? []) {
+    transitionsByPair.set(`${transition.leftClipId}:${transition.rightClipId}`, transition)
+  }
+  const writtenTransitions = new Set<string>()
+
+  const tracks = timeline.tracks.filter((track) => !track.isGroup).sort((a, b) => a.order - b.order)
+  const itemsByTrack = new Map<string, TimelineItemRecord[]>()
+  for (const track of tracks) itemsByTrack.set(track.id, [])
+  for (const item of timeline.items) itemsByTrack.get(item.trackId)?.push(item)
+
+  const itemMetadata = (item: TimelineItemRecord): FreecutOtioMetadata => {
+    const keyframes = keyframesByItem.get(item.id)
+    return { item: storableItem(item), ...(keyframes ? { keyframes } : {}) }
+  }
+
+  const writeItem = (item: TimelineItemRecord): OtioObject => {
+    const media = item.mediaId ? mediaById.get(item.mediaId) : undefined
+    const isMediaItem = item.type === 'video' || item.type === 'audio' || item.type === 'image'
+    if (!isMediaItem) {
+      warnings.push({
+        code: 'unsupported_item',
+        message: `${item.type} item "${item.label}" has no OTIO equivalent; written as a gap that FreeCut restores on import`,
+        itemId: item.id,
+      })
+      return otioObject(
+        'Gap.1',
+        item.label,
+        { source_range: timeRange(0, item.durationInFrames, fps), effects: [], markers: [] },
+        itemMetadata(item),
+      )
+    }
+
+    const mediaFps = media && media.fps > 0 ? media.fps : fps
+    const sourceFps = item.sourceFps ?? mediaFps
+    let mediaReference: OtioObject
+    if (media) {
+      const isImage = media.mimeType.startsWith('image/')
+      mediaReference = otioObject(
+        'ExternalReference.1',
+        media.fileName,
+        {
+          target_url: media.path,
+          available_range: isImage
+            ? null
+            : timeRange(0, Math.round(media.duration * mediaFps), mediaFps),
+        },
+        { mediaId: media.id },
+      )
+    } else {
+      warnings.push({
+        code: 'unmatched_media',
+        message: `Media for "${item.label}" is unavailable; written with a missing media reference`,
+        itemId: item.id,
+      })
+      mediaReference = otioObject('MissingReference.1', item.label, { available_range: null })
+    }
+
+    const speed = item.speed ?? 1
+    const effects =
+      speed !== 1 || item.isReversed
+        ? [
+            otioObject('LinearTimeWarp.1', '', {
+              effect_name: 'LinearTimeWarp',
+              time_scalar: item.isReversed ? -speed : speed,
+            }),
+          ]
+        : []
+
+    return otioObject(
+      'Clip.2',
+      item.label || media?.fileName || '',
+      {
+        // Source in-point at the source rate, duration at the timeline rate:
+        // both are exact frame counts in FreeCut.
+        source_range: timeRange(item.sourceStart ?? 0, item.durationInFrames, fps, sourceFps),
+        media_references: { [DEFAULT_MEDIA_KEY]: mediaReference },
+        active_media_reference_key: DEFAULT_MEDIA_KEY,
+        effects,
+        markers: [],
+        enabled: true,
+      },
+      itemMetadata(item),
+    )
+  }
+
+  /** Lay a track's items out sequentially; overlapping items are returned. */
+  const writeTrackChildren = (
+    items: readonly TimelineItemRecord[],
+  ): { children: OtioObject[]; overflow: TimelineItemRecord[] } => {
+    const children: OtioObject[] = []
+    const overflow: TimelineItemRecord[] = []
+    let cursor = 0
+    let previous: TimelineItemRecord | undefined
+    for (const item of [...items].sort((a, b) => a.from - b.from)) {
+      if (item.from < cursor) {
+        overflow.push(item)
+        continue
+      }
+      if (item.from > cursor) {
+        children.push(
+          otioObject('Gap.1', '', {
+            source_range: timeRange(0, item.from - cursor, fps),
+            effects: [],
+            markers: [],
+          }),
+        )
+        previous = undefined
+      }
+      const transition = previous
+        ? transitionsByPair.get(`${previous.id}:${item.id}`)
+        : undefined
+      if (transition) {
+        const inOffset = Math.round(transition.durationInFrames * (transition.alignment ?? 0.5))
+        const { id: _id, leftClipId: _left, rightClipId: _right, trackId: _track, ...stored } =
+          transition
+        children.push(
+          otioObject(
+            'Transition.1',
+            'Cross Dissolve',
+            {
+              transition_type: 'SMPTE_Dissolve',
+              in_offset: rationalTime(inOffset, fps),
+              out_offset: rationalTime(transition.durationInFrames - inOffset, fps),
+            },
+            { transition: stored },
+          ),
+        )
+        writtenTransitions.add(transition.id)
+      }
+      children.push(writeItem(item))
+      cursor = item.from + item.durationInFrames
+      previous = item
+    }
+    return { children, overflow }
+  }
+
+  const writeTrack = (track: TimelineTrackRecord, kind: 'video' | 'audio'): OtioObject[] => {
+    const written: OtioObject[] = []
+    let remaining = itemsByTrack.get(track.id) ?? []
+    do {
+      const { children, overflow } = writeTrackChildren(remaining)
+      const isFirst = written.length === 0
+      written.push(
+        otioObject(
+          'Track.1',
+          isFirst ? track.name : `${track.name} (${written.length + 1})`,
+          {
+            kind: kind === 'audio' ? 'Audio' : 'Video',
+            source_range: null,
+            effects: [],
+            markers: [],
+            enabled: kind === 'audio' ? !track.muted : track.visible,
+            children,
+          },
+          isFirst ? { track } : undefined,
+        ),
+      )
+      remaining = overflow
+    } while (remaining.length > 0)
+    return written
+  }
+
+  const videoTracks = tracks.filter(
+    (track) => interchangeTrackKind(track, itemsByTrack.get(track.id) ?? []) === 'video',
+  )
+  const audioTracks = tracks.filter(
+    (track) => interchangeTrackKind(track, itemsByTrack.get(track.id) ?? []) === 'audio',
+  )
+  const stackChildren = [
+    ...[...videoTracks].reverse().flatMap((track) => writeTrack(track, 'video')),
+    ...audioTracks.flatMap((track) => writeTrack(track, 'audio')),
+  ]
+
+  for (const transition of timeline.transitions ?? []) {
+    if (!writtenTransitions.has(transition.id)) {
+      warnings.push({
+        code: 'lossy_value',
+        message: 'A transition between clips that are not back to back was dropped',
+        itemId: transition.leftClipId,
+      })
+    }
+  }
+
+  const markers = (timeline.markers ?? []).map((marker) =>
+    otioObject(
+      'Marker.2',
+      marker.label ?? '',
+      {
+        color: toOtioMarkerColor(marker.color),
+        marked_range: timeRange(marker.frame, 0, fps),
+        comment: '',
+      },
+      { color: marker.color },
+    ),
+  )
+
+  const document = otioObject(
+    'Timeline.1',
+    input.name,
+    {
+      global_start_time: rationalTime(0, fps),
+      tracks: otioObject('Stack.1', 'tracks', {
+        source_range: null,
+        effects: [],
+        markers,
+        children: stackChildren,
+      }),
+    },
+    { metadata },
+  )
+
+  return { content: `${JSON.stringify(document, null, 4)}\n`, warnings }
+}
+
+// ---------------------------------------------------------------------------
+// Import
+// ---------------------------------------------------------------------------
+
+function isOtioObject(value: unknown): value is OtioObject {
+  return (
+    typeof value === 'object' &&
+    value !== null &&
+    typeof (value as { OTIO_SCHEMA?: unknown }).OTIO_SCHEMA === 'string'
+  )
+}
+
+/** Schema name without its version (`Clip.2` → `Clip`). */
+function schemaName(value: unknown): string | undefined {
+  return isOtioObject(value) ? value.OTIO_SCHEMA.split('.')[0] : undefined
+}
+
+function readFreecut(value: unknown): FreecutOtioMetadata | undefined {
+  if (!isOtioObject(value)) return undefined
+  const freecut = value.metadata?.freecut
+  return typeof freecut === 'object' && freecut !== null ? (freecut as FreecutOtioMetadata) : undefined
+}
+
+function readRationalTime(value: unknown): OtioRationalTime | undefined {
+  if (!isOtioObject(value)) return undefined
+  const rate = Number(value.rate)
+  const timeValue = Number(value.value)
+  if (!Number.isFinite(rate) || rate <= 0 || !Number.isFinite(timeValue)) return undefined
+  return rationalTime(timeValue, rate)
+}
+
+function readTimeRange(value: unknown): OtioTimeRange | undefined {
+  if (!isOtioObject(value)) return undefined
+  const start = readRationalTime(value.start_time)
+  const duration = readRationalTime(value.duration)
+  if (!start || !duration) return undefined
+  return { OTIO_SCHEMA: 'TimeRange.1', start_time: start, duration }
+}
+
+function seconds(time: OtioRationalTime | undefined): number {
+  return time ? time.value / time.rate : 0
+}
+
+function children(value: unknown): unknown[] {
+  return isOtioObject(value) && Array.isArray(value.children) ? value.children : []
+}
+
+/** The clip's active media reference (OTIO ≥ 0.15) or its single legacy one. */
+function activeMediaReference(clip: OtioObject): OtioObject | undefined {
+  const references = clip.media_references
+  if (typeof references === 'object' && references !== null) {
+    const key =
+      typeof clip.active_media_reference_key === 'string'
+        ? clip.active_media_reference_key
+        : DEFAULT_MEDIA_KEY
+    const reference = (references as Record<string, unknown>)[key]
+    if (isOtioObject(reference)) return reference
+  }
+  return isOtioObject(clip.media_reference) ? clip.media_reference : undefined
+}
+
+/** Timeline rate for documents FreeCut didn't write: first rate we can find. */
+function findRate(stack: unknown): number | undefined {
+  for (const track of children(stack)) {
+    for (const child of children(track)) {
+      const range = isOtioObject(child) ? readTimeRange(child.source_range) : undefined
+      if (range) return range.duration.rate
+    }
+  }
+  return undefined
+}
+
+interface TransitionPlacement {
+  trackId: string
+  leftIndex: number
+  inOffset: number
+  outOffset: number
+  stored?: Partial<Transition>
+}
+
+export function parseOtio(
+  content: string,
+  media: readonly InterchangeMediaReference[],
+): InterchangeImportResult {
+  let doc: unknown
+  try {
+    doc = JSON.parse(content)
+  } catch (error) {
+    throw new Error(
+      `Invalid OTIO: ${error instanceof Error ? error.message : 'not a JSON document'}`,
+    )
+  }
+  if (schemaName(doc) !== 'Timeline' || !isOtioObject(doc)) {
+    throw new Error('Invalid OTIO: missing Timeline root object')
+  }
+  const stack = doc.tracks
+  if (schemaName(stack) !== 'Stack') {
+    throw new Error('Invalid OTIO: timeline has no track stack')
+  }
+
+  const warnings: InterchangeWarning[] = []
+  const matchMedia = createInterchangeMediaMatcher(media)
+  const mediaById = new Map(media.map((entry) => [entry.id, entry]))
+
+  const storedMetadata = readFreecut(doc)?.metadata
+  const fps =
+    storedMetadata?.fps ?? readRationalTime(doc.global_start_time)?.rate ?? findRate(stack) ?? 30
+  const metadata: ProjectResolution = storedMetadata ?? { width: 1920, height: 1080, fps }
+  const toFrames = (value: number) => Math.round(value * fps)
+
+  const items: TimelineItemRecord[] = []
+  const keyframes: ItemKeyframes[] = []
+  const markers: NonNullable<ProjectTimeline['markers']> = []
+  const placements: TransitionPlacement[] = []
+  /** Item created for each child slot of each track (null for gaps). */
+  const trackSlots = new Map<string, Array<TimelineItemRecord | null>>()
+
+  const pushMarkers = (owner: unknown, toTimelineFrame: (markSeconds: number) => number) => {
+    if (!isOtioObject(owner) || !Array.isArray(owner.markers)) return
+    for (const marker of owner.markers) {
+      if (!isOtioObject(marker)) continue
+      const range = readTimeRange(marker.marked_range)
+      const storedColor = readFreecut(marker)?.color
+      const namedColor =
+        typeof marker.color === 'string' ? OTIO_MARKER_COLORS[marker.color.toUpperCase()] : undefined
+      markers.push({
+        id: crypto.randomUUID(),
+        frame: Math.max(0, toTimelineFrame(seconds(range?.start_time))),
+        label: marker.name || undefined,
+        color: storedColor ?? namedColor ?? DEFAULT_MARKER_COLOR,
+      })
+    }
+  }
+
+  /** Restore a stored FreeCut item at its new place, with a fresh id. */
+  const restoreItem = (
+    stored: TimelineItemRecord,
+    freecut: FreecutOtioMetadata,
+    fields: Partial<TimelineItemRecord>,
+  ): TimelineItemRecord => {
+    const item: TimelineItemRecord = { ...stored, ...fields, id: crypto.randomUUID() }
+    if (freecut.keyframes) keyframes.push({ itemId: item.id, properties: freecut.keyframes })
+    return item
+  }
+
+  const createClipItem = (
+    clip: OtioObject,
+    trackId: string,
+    kind: 'video' | 'audio',
+    from: number,
+    durationInFrames: number,
+    sourceRange: OtioTimeRange | undefined,
+  ): TimelineItemRecord | null => {
+    const freecut = readFreecut(clip) ?? {}
+    const mediaReference = activeMediaReference(clip)
+    const targetUrl =
+      typeof mediaReference?.target_url === 'string' ? mediaReference.target_url : undefined
+    const storedMediaId = freecut.item?.mediaId ?? readFreecut(mediaReference)?.mediaId
+    const reference =
+      (storedMediaId ? mediaById.get(storedMediaId) : undefined) ??
+      matchMedia(targetUrl, clip.name || mediaReference?.name)
+    if (!reference) {
+      warnings.push({
+        code: 'unmatched_media',
+        message: `No media in the library matches "${targetUrl ?? clip.name ?? ''}"; clip "${clip.name ?? ''}" skipped`,
+      })
+      return null
+    }
+
+    const timeWarp = (Array.isArray(clip.effects) ? clip.effects : []).find(
+      (effect) => schemaName(effect) === 'LinearTimeWarp',
+    ) as OtioObject | undefined
+    const timeScalar = Number(timeWarp?.time_scalar ?? 1)
+    const speed = Number.isFinite(timeScalar) && timeScalar !== 0 ? Math.abs(timeScalar) : 1
+    const isReversed = Number.isFinite(timeScalar) && timeScalar < 0
+
+    const isImage = reference.mimeType.startsWith('image/')
+    const type = freecut.item?.type ?? (isImage ? 'image' : kind === 'audio' ? 'audio' : 'video')
+    const sourceFps =
+      freecut.item?.sourceFps ?? (reference.fps > 0 ? reference.fps : fps)
+    const sourceStart = Math.max(0, Math.round(seconds(sourceRange?.start_time) * sourceFps))
+    const sourceSpan = Math.round(((durationInFrames * speed) / fps) * sourceFps)
+    const fields: Partial<TimelineItemRecord> = {
+      trackId,
+      type,
+      from,
+      durationInFrames,
+      label: clip.name || reference.fileName,
+      mediaId: reference.id,
+      src: '',
+      ...(isImage
+        ? {}
+        : {
+            sourceStart,
+            sourceEnd: sourceStart + sourceSpan,
+            sourceDuration: Math.max(
+              sourceStart + sourceSpan,
+              Math.round(reference.duration * sourceFps),
+            ),
+            sourceFps,
+            speed,
+          }),
+    }
+
+    const item = freecut.item
+      ? restoreItem(freecut.item, freecut, fields)
+      : ({
+          ...fields,
+          id: crypto.randomUUID(),
+          ...(reference.hasVideo && type !== 'audio'
+            ? {
+                sourceWidth: reference.width || undefined,
+                sourceHeight: reference.height || undefined,
+              }
+            : {}),
+        } as TimelineItemRecord)
+    if (isReversed) {
+      item.isReversed = true
+    } else {
+      delete item.isReversed
+    }
+    return item
+  }
+
+  const walkTrack = (track: OtioObject, trackId: string, kind: 'video' | 'audio') => {
+    const slots: Array<TimelineItemRecord | null> = []
+    trackSlots.set(trackId, slots)
+    let cursor = 0
+    for (const child of children(track)) {
+      if (!isOtioObject(child)) continue
+      const schema = schemaName(child)
+      if (schema === 'Transition') {
+        placements.push({
+          trackId,
+          leftIndex: slots.length - 1,
+          inOffset: toFrames(seconds(readRationalTime(child.in_offset))),
+          outOffset: toFrames(seconds(readRationalTime(child.out_offset))),
+          stored: readFreecut(child)?.transition,
+        })
+        continue
+      }
+
+      const sourceRange =
+        readTimeRange(child.source_range) ??
+        readTimeRange(activeMediaReference(child)?.available_range)
+      const durationInFrames = toFrames(seconds(sourceRange?.duration))
+      const from = cursor
+      cursor += durationInFrames
+      if (durationInFrames <= 0) continue
+
+      let item: TimelineItemRecord | null = null
+      if (schema === 'Clip') {
+        item = createClipItem(child, trackId, kind, from, durationInFrames, sourceRange)
+      } else if (schema === 'Gap') {
+        const freecut = readFreecut(child)
+        if (freecut?.item) {
+          item = restoreItem(freecut.item, freecut, { trackId, from, durationInFrames })
+        }
+      } else {
+        warnings.push({
+          code: 'unsupported_element',
+          message: `${child.OTIO_SCHEMA} "${child.name ?? ''}" is not supported; its time was left empty`,
+        })
+      }
+      if (item) items.push(item)
+      slots.push(item)
+      pushMarkers(
+        child,
+        (markSeconds) => from + toFrames(markSeconds - seconds(sourceRange?.start_time)),
+      )
+    }
+    pushMarkers(track, (markSeconds) => toFrames(markSeconds))
+  }
+
+  // Tracks: video top-most first (OTIO lists them bottom-up), then audio.
+  const otioTracks = children(stack).filter((child): child is OtioObject => {
+    if (schemaName(child) === 'Track') return true
+    warnings.push({
+      code: 'unsupported_element',
+      message: `Nested ${isOtioObject(child) ? child.OTIO_SCHEMA : 'object'} in the track stack was skipped`,
+    })
+    return false
+  })
+  const trackKindOf = (track: OtioObject): 'video' | 'audio' =>
+    track.kind === 'Audio' ? 'audio' : 'video'
+  const orderedTracks = [
+    ...otioTracks.filter((track) => trackKindOf(track) === 'video').reverse(),
+    ...otioTracks.filter((track) => trackKindOf(track) === 'audio'),
+  ]
+  const storedOrders = orderedTracks.map((track) => readFreecut(track)?.track?.order)
+  if (storedOrders.every((order) => typeof order === 'number')) {
+    const orderOf = new Map(orderedTracks.map((track, index) => [track, storedOrders[index]!]))
+    orderedTracks.sort((a, b) => orderOf.get(a)! - orderOf.get(b)!)
+  }
+
+  let videoCount = orderedTracks.filter((track) => trackKindOf(track) === 'video').length
+  let audioCount = 0
+  const tracks: ProjectTimeline['tracks'] = orderedTracks.map((otioTrack, order) => {
+    const kind = trackKindOf(otioTrack)
+    const stored = readFreecut(otioTrack)?.track
+    const fallbackName = kind === 'video' ? `V${videoCount}` : `A${audioCount + 1}`
+    if (kind === 'video') videoCount--
+    else audioCount++
+    const track: TimelineTrackRecord = {
+      height: 100,
+      locked: false,
+      syncLock: true,
+      visible: true,
+      muted: false,
+      solo: false,
+      volume: 0,
+      ...stored,
+      id: crypto.randomUUID(),
+      name: stored?.name ?? fallbackName,
+      kind,
+      order,
+    }
+    if (!stored && otioTrack.enabled === false) {
+      if (kind === 'video') track.visible = false
+      else track.muted = true
+    }
+    walkTrack(otioTrack, track.id, kind)
+    return track
+  })
+  pushMarkers(stack, (markSeconds) => toFrames(markSeconds))
+
+  relinkCompanionItems(items)
+
+  const transitions: Transition[] = []
+  for (const placement of placements) {
+    const slots = trackSlots.get(placement.trackId) ?? []
+    const left = slots[placement.leftIndex]
+    const right = slots[placement.leftIndex + 1]
+    const durationInFrames = placement.inOffset + placement.outOffset
+    if (!left || !right || durationInFrames <= 0) {
+      warnings.push({
+        code: 'unsupported_element',
+        message: 'A transition without clips on both sides was skipped',
+      })
+      continue
+    }
+    transitions.push({
+      type: 'crossfade',
+      presentation: 'fade',
+      timing: 'linear',
+      ...placement.stored,
+      id: crypto.randomUUID(),
+      leftClipId: left.id,
+      rightClipId: right.id,
+      trackId: placement.trackId,
+      durationInFrames,
+      alignment: Number((placement.inOffset / durationInFrames).toFixed(4)),
+    })
+  }
+
+  const mediaIds = [...new Set(items.flatMap((item) => (item.mediaId ? [item.mediaId] : [])))]
+
+  return {
+    name: doc.name || 'Imported OTIO',
+    metadata,
+    timeline: {
+      tracks,
+      items,
+      markers: markers.sort((a, b) => a.frame - b.frame),
+      transitions,
+      ...(keyframes.length > 0 ? { keyframes } : {}),
+    },
+    mediaIds,
+    warnings,
+  }
+}
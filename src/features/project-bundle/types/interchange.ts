@@ -0,0 +1,76 @@
+/**
+ * Interchange Types
+ *
+ * Shared shapes for timeline interchange formats (FCPXML, OTIO, EDL) that
+ * move a cut between FreeCut and other NLEs. Converters are pure: they take a
+ * project plus resolved media references and never touch storage themselves.
+ */
+
+import type { ProjectResolution, ProjectTimeline } from '@/types/project'
+
+/** Interchange formats a project can be exported to / imported from. */
+export type InterchangeFormat = 'fcpxml' | 'otio' | 'edl'
+
+/** File extension per interchange format (matched case-insensitively). */
+export const INTERCHANGE_EXTENSIONS: Record<InterchangeFormat, string> = {
+  fcpxml: '.fcpxml',
+  otio: '.otio',
+  edl: '.edl',
+}
+
+/**
+ * Media as seen by an interchange converter.
+ * `path` is what gets written into the interchange file and what is matched
+ * against on import — a workspace-relative `media/<id>/<file>` path, or the
+ * original on-disk path when the media is linked in place.
+ */
+export interface InterchangeMediaReference {
+  id: string
+  fileName: string
+  path: string
+  mimeType: string
+  /** Duration in seconds */
+  duration: number
+  width: number
+  height: number
+  fps: number
+  hasVideo: boolean
+  hasAudio: boolean
+}
+
+/** What an exporter needs: the project's cut plus its resolved media. */
+export interface InterchangeExportInput {
+  name: string
+  metadata: ProjectResolution
+  timeline: ProjectTimeline
+  media: readonly InterchangeMediaReference[]
+}
+
+export interface InterchangeWarning {
+  code: 'unsupported_item' | 'unmatched_media' | 'unsupported_element' | 'lossy_value'
+  message: string
+  itemId?: string
+}
+
+/**
+ * Result of parsing an interchange document back into FreeCut structures.
+ * The caller decides whether to persist it as a new project.
+ */
+export interface InterchangeImportResult {
+  name: string
+  metadata: ProjectResolution
+  timeline: ProjectTimeline
+  /** Media ids referenced by the rebuilt timeline */
+  mediaIds: string[]
+  warnings: InterchangeWarning[]
+}
+
+export interface InterchangeExportResult {
+  content: string
+  warnings: InterchangeWarning[]
+  /**
+   * Formats that hold one track per document (EDL) return every document,
+   * keyed by a file-name suffix; `content` is then the first of them.
+   */
+  documents?: Array<{ suffix: string; content: string }>
+}
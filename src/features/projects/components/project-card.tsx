@@ -11,6 +11,9 @@ import {
   AlertTriangle,
   HardDrive,
   Check,
+  Crop,
+  RefreshCw,
+  Link2,
 } from 'lucide-react'
 import {
   DropdownMenu,
@@ -30,11 +33,13 @@ import {
   AlertDialogTitle,
 } from '@/components/ui/alert-dialog'
 import { Button } from '@/components/ui/button'
-import type { Project } from '@/types/project'
+import type { Project, ReframeAspect } from '@/types/project'
 import { formatRelativeTime } from '../utils/project-helpers'
+import { getReframeAspect, REFRAME_ASPECTS } from '../utils/reframe'
 import {
   useDeleteProject,
   useDuplicateProject,
+  useReframeProject,
   useRestoreProject,
 } from '../hooks/use-project-actions'
 import { useProjectThumbnail } from '../hooks/use-project-thumbnail'
@@ -63,11 +68,13 @@ export function ProjectCard({
   const { t } = useTranslation()
   const [isDeleting, setIsDeleting] = useState(false)
   const [isDuplicating, setIsDuplicating] = useState(false)
+  const [isReframing, setIsReframing] = useState(false)
   const [showDeleteDialog, setShowDeleteDialog] = useState(false)
   const [clearLocalFiles, setClearLocalFiles] = useState(false)
   const deleteProject = useDeleteProject()
   const restoreProject = useRestoreProject()
   const duplicateProject = useDuplicateProject()
+  const reframeProject = useReframeProject()
   const thumbnailUrl = useProjectThumbnail(project)
 
   const handleDeleteClick = (e: React.MouseEvent) => {
@@ -129,6 +136,47 @@ export function ProjectCard({
     }
   }
 
+  const runReframe = async (sourceProjectId: string, aspect: ReframeAspect) => {
+    setIsReframing(true)
+    const toastId = toast.loading(t('projects.reframe.analyzing', { aspect, percent: 0 }))
+    const result = await reframeProject(sourceProjectId, aspect, {
+      onProgress: (percent) => {
+        toast.loading(t('projects.reframe.analyzing', { aspect, percent: Math.round(percent) }), {
+          id: toastId,
+        })
+      },
+    })
+    setIsReframing(false)
+
+    if (!result.success) {
+      toast.error(t('projects.toasts.reframeFailed'), { id: toastId, description: result.error })
+      return
+    }
+    if (!result.project) {
+      toast.dismiss(toastId)
+      return
+    }
+    toast.success(
+      result.created
+        ? t('projects.reframe.created', { name: result.project.name })
+        : t('projects.reframe.updated', { name: result.project.name }),
+      { id: toastId },
+    )
+  }
+
+  const handleReframe = (e: React.MouseEvent, aspect: ReframeAspect) => {
+    e.preventDefault()
+    e.stopPropagation()
+    void runReframe(project.id, aspect)
+  }
+
+  const handleUpdateFromSource = (e: React.MouseEvent) => {
+    e.preventDefault()
+    e.stopPropagation()
+    if (!project.reframe) return
+    void runReframe(project.reframe.sourceProjectId, project.reframe.aspect)
+  }
+
   const handleEdit = (e: React.MouseEvent) => {
     e.preventDefault()
     e.stopPropagation()
@@ -183,6 +231,8 @@ export function ProjectCard({
           : Math.abs(aspectRatio - 21 / 9) < 0.01
             ? '21:9'
             : `${width}:${height}`
+  const currentAspect = getReframeAspect(width, height)
+  const reframeAspects = REFRAME_ASPECTS.filter((aspect) => aspect !== currentAspect)
 
   return (
     <div
@@ -293,6 +343,29 @@ export function ProjectCard({
                 {isDuplicating ? t('projects.card.duplicating') : t('projects.card.duplicate')}
               </DropdownMenuItem>
               <DropdownMenuSeparator />
+              {project.reframe ? (
+                <DropdownMenuItem
+                  onClick={handleUpdateFromSource}
+                  disabled={isReframing}
+                  className="flex items-center gap-2"
+                >
+                  <RefreshCw className="w-4 h-4" />
+                  {t('projects.reframe.updateFromSource')}
+                </DropdownMenuItem>
+              ) : (
+                reframeAspects.map((aspect) => (
+                  <DropdownMenuItem
+                    key={aspect}
+                    onClick={(e) => handleReframe(e, aspect)}
+                    disabled={isReframing}
+                    className="flex items-center gap-2"
+                  >
+                    <Crop className="w-4 h-4" />
+                    {t('projects.reframe.reframeTo', { aspect })}
+                  </DropdownMenuItem>
+                ))
+              )}
+              <DropdownMenuSeparator />
               <DropdownMenuItem
                 onClick={handleDeleteClick}
                 disabled={isDeleting}
@@ -375,6 +448,18 @@ export function ProjectCard({
           <div className="flex items-center gap-1.5">
             <span>{formatRelativeTime(project.updatedAt)}</span>
           </div>
+          {project.reframe && (
+            <>
+              <div className="w-1 h-1 rounded-full bg-muted-foreground/40" />
+              <div
+                className="flex items-center gap-1"
+                title={t('projects.reframe.linkedVariantHint')}
+              >
+                <Link2 className="w-3 h-3" />
+                <span>{t('projects.reframe.linkedVariant')}</span>
+              </div>
+            </>
+          )}
         </div>
       </div>
     </div>
@@ -0,0 +1,6 @@
+/**
+ * Adapter exports for effects dependencies.
+ * Projects modules should import effects analysis helpers from here.
+ */
+
+export { loadAnalysisVideo } from '@/features/effects/utils/stabilization-analysis'
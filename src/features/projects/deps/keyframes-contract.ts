@@ -0,0 +1,10 @@
+/**
+ * Adapter exports for keyframe dependencies.
+ * Projects modules should import keyframe utilities from here.
+ */
+
+export {
+  getItemNaturalSourceSeconds,
+  resolveTimeRemapCurve,
+  sampleTimeRemap,
+} from '@/features/keyframes/utils/time-remap'
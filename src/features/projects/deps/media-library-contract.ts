@@ -4,3 +4,4 @@
  */
 
 export { importMediaLibraryService } from '@/features/media-library/services/media-library-service-loader'
+export { resolveMediaUrl } from '@/features/media-library/utils/media-resolver'
@@ -1,6 +1,8 @@
 import { useProjectStore } from '../stores/project-store'
 import { useCallback } from 'react'
 import type { ProjectFormData } from '../utils/validation'
+import type { ReframeAspect } from '@/types/project'
+import type { ReframeOptions } from '../services/reframe-service'
 
 /**
  * Hook for project CRUD actions
@@ -173,3 +175,32 @@ export const useDuplicateProject = () => {
     [duplicateProject],
   )
 }
+
+/**
+ * Hook for smart-reframing a project into a linked aspect-ratio variant
+ */
+export const useReframeProject = () => {
+  const reframeProject = useProjectStore((s) => s.reframeProject)
+
+  return useCallback(
+    async (id: string, aspect: ReframeAspect, options?: ReframeOptions) => {
+      try {
+        const result = await reframeProject(id, aspect, options)
+        return {
+          success: true,
+          project: result?.project ?? null,
+          created: result?.created ?? false,
+          error: null,
+        }
+      } catch (error) {
+        return {
+          success: false,
+          project: null,
+          created: false,
+          error: error instanceof Error ? error.message : 'Failed to reframe project',
+        }
+      }
+    },
+    [reframeProject],
+  )
+}
@@ -0,0 +1,220 @@
+import type { Project, ProjectResolution, ProjectTimeline, ReframeAspect } from '@/types/project'
+import type { SubjectDetector } from '@/infrastructure/analysis/reframe'
+import { smoothSubjectPath } from '@/infrastructure/analysis/reframe/subject-path'
+import { getSubjects, saveSubjects } from '@/infrastructure/storage/workspace-fs/subjects'
+import { createLogger } from '@/shared/logging/logger'
+import { resolveMediaUrl } from '@/features/projects/deps/media-library-contract'
+import { loadAnalysisVideo } from '@/features/projects/deps/effects-contract'
+import {
+  buildReframedTimeline,
+  getReframeResolution,
+  getReframeSourceSeconds,
+  type ReframeSubject,
+} from '../utils/reframe'
+
+const logger = createLogger('ReframeService')
+
+/** Subject samples per source second; the framing filter spans several anyway */
+const SUBJECT_ANALYSIS_FPS = 4
+/** Tolerance when checking whether a cached path covers the clips (one sample) */
+const RANGE_EPSILON_SECONDS = 1 / SUBJECT_ANALYSIS_FPS
+
+type SourceRange = { start: number; end: number }
+
+interface MediaUsage {
+  kind: 'video' | 'image'
+  range: SourceRange
+}
+
+export interface ReframeOptions {
+  /** Defaults to on-device face detection with saliency fallback */
+  detector?: SubjectDetector
+  onProgress?: (percent: number) => void
+  signal?: AbortSignal
+}
+
+interface ReframedContent {
+  metadata: ProjectResolution
+  timeline?: ProjectTimeline
+}
+
+// Analysis pulls in the detectors; only load it when a reframe runs
+const importReframeAnalysis = () => import('@/infrastructure/analysis/reframe')
+
+/** Source seconds each video or image is shown for across the top-level clips. */
+function collectMediaUsage(timeline: ProjectTimeline, fps: number): Map<string, MediaUsage> {
+  const usage = new Map<string, MediaUsage>()
+  const keyframesByItem = new Map(timeline.keyframes?.map((entry) => [entry.itemId, entry]))
+
+  for (const item of timeline.items) {
+    if ((item.type !== 'video' && item.type !== 'image') || !item.mediaId) continue
+
+    let range: SourceRange = { start: 0, end: 0 }
+    if (item.type === 'video') {
+      // Sample every half second so time-remap excursions are included
+      const step = Math.max(1, Math.round(fps / 2))
+      let start = Infinity
+      let end = -Infinity
+      for (let frame = 0; ; frame = Math.min(frame + step, item.durationInFrames)) {
+        const seconds = getReframeSourceSeconds(item, keyframesByItem.get(item.id), frame, fps)
+        start = Math.min(start, seconds)
+        end = Math.max(end, seconds)
+        if (frame >= item.durationInFrames) break
+      }
+      range = { start: Math.max(0, start), end }
+    }
+
+    const existing = usage.get(item.mediaId)
+    usage.set(
+      item.mediaId,
+      existing
+        ? {
+            kind: item.type,
+            range: {
+              start: Math.min(existing.range.start, range.start),
+              end: Math.max(existing.range.end, range.end),
+            },
+          }
+        : { kind: item.type, range },
+    )
+  }
+
+  return usage
+}
+
+async function analyzeVideoSubject(
+  mediaId: string,
+  range: SourceRange,
+  detector: SubjectDetector,
+  onProgress: (percent: number) => void,
+  signal: AbortSignal | undefined,
+): Promise<ReframeSubject | undefined> {
+  const cached = await getSubjects(mediaId).catch(() => undefined)
+  if (
+    cached &&
+    cached.model === detector.id &&
+    cached.data.path.fps === SUBJECT_ANALYSIS_FPS &&
+    cached.data.path.startTime <= range.start + RANGE_EPSILON_SECONDS &&
+    cached.data.endTime >= range.end - RANGE_EPSILON_SECONDS
+  ) {
+    return { aspect: cached.data.path.aspect, path: smoothSubjectPath(cached.data.path) }
+  }
+
+  const url = await resolveMediaUrl(mediaId)
+  if (!url) return undefined
+
+  const { analyzeSubjectPath } = await importReframeAnalysis()
+  let video: HTMLVideoElement | null = null
+  try {
+    video = await loadAnalysisVideo(url, signal)
+    const endTime = Math.min(range.end, video.duration || range.end)
+    const path = await analyzeSubjectPath(video, {
+      startTime: range.start,
+      endTime,
+      fps: SUBJECT_ANALYSIS_FPS,
+      detector,
+      onProgress,
+      signal,
+    })
+    if (signal?.aborted) return undefined
+
+    await saveSubjects({ mediaId, model: detector.id, path, endTime }).catch((error) => {
+      logger.warn('Failed to cache subject path', { mediaId, error })
+    })
+    return { aspect: path.aspect, path: smoothSubjectPath(path) }
+  } finally {
+    if (video) {
+      video.onloadedmetadata = null
+      video.onerror = null
+      video.src = ''
+    }
+  }
+}
+
+async function analyzeImageSubject(
+  mediaId: string,
+  detector: SubjectDetector,
+): Promise<ReframeSubject | undefined> {
+  const url = await resolveMediaUrl(mediaId)
+  if (!url) return undefined
+
+  const { detectImageSubject } = await importReframeAnalysis()
+  const image = new Image()
+  image.src = url
+  await image.decode()
+  const { naturalWidth: width, naturalHeight: height } = image
+  if (!width || !height) return undefined
+
+  const region = await detectImageSubject(image, width, height, detector)
+  return {
+    aspect: width / height,
+    framing: region
+      ? { x: region.x, y: region.y, size: Math.max(region.width, region.height) }
+      : undefined,
+  }
+}
+
+/**
+ * Find the subject of every video and image the project shows. Subject paths
+ * are cached per media, so re-running a reframe only analyzes footage that
+ * is new to the edit. Media that fails to analyze is framed centered.
+ * Resolves with null when aborted.
+ */
+async function analyzeReframeSubjects(
+  project: Project,
+  options: ReframeOptions = {},
+): Promise<Map<string, ReframeSubject> | null> {
+  const { onProgress, signal } = options
+  const subjects = new Map<string, ReframeSubject>()
+  if (!project.timeline) return subjects
+
+  const usage = [...collectMediaUsage(project.timeline, project.metadata.fps)]
+  if (usage.length === 0) return subjects
+  const detector = options.detector ?? (await importReframeAnalysis()).createSubjectDetector()
+
+  for (const [index, [mediaId, { kind, range }]] of usage.entries()) {
+    if (signal?.aborted) return null
+    const reportProgress = (percent: number) =>
+      onProgress?.(((index + percent / 100) / usage.length) * 100)
+
+    try {
+      const subject =
+        kind === 'video'
+          ? await analyzeVideoSubject(mediaId, range, detector, reportProgress, signal)
+          : await analyzeImageSubject(mediaId, detector)
+      if (subject) subjects.set(mediaId, subject)
+    } catch (error) {
+      if (signal?.aborted) return null
+      logger.warn('Subject analysis failed; framing centered', { mediaId, error })
+    }
+    reportProgress(100)
+  }
+
+  return signal?.aborted ? null : subjects
+}
+
+/**
+ * Canvas and timeline of a project reframed to another aspect ratio.
+ * Resolves with null when aborted.
+ */
+export async function reframeProjectContent(
+  project: Project,
+  aspect: ReframeAspect,
+  options: ReframeOptions = {},
+): Promise<ReframedContent | null> {
+  const metadata = getReframeResolution(project.metadata, aspect)
+  const subjects = await analyzeReframeSubjects(project, options)
+  if (!subjects) return null
+
+  logger.info('Reframing project', {
+    projectId: project.id,
+    aspect,
+    analyzedMedia: subjects.size,
+  })
+  return {
+    metadata,
+    timeline: project.timeline
+      ? buildReframedTimeline(project.timeline, project.metadata, metadata, subjects)
+      : undefined,
+  }
+}
@@ -31,6 +31,10 @@ vi.mock('@/features/projects/deps/settings-contract', () => ({
   },
 }))
 
+const reframeProjectContent = vi.hoisted(() => vi.fn())
+
+vi.mock('../services/reframe-service', () => ({ reframeProjectContent }))
+
 const { useProjectStore } = await import('./project-store')
 
 function makeProject(id: string): Project {
@@ -51,21 +55,23 @@ function makeProject(id: string): Project {
   }
 }
 
-describe('project-store deleteProject', () => {
-  beforeEach(() => {
-    vi.clearAllMocks()
-    useProjectStore.setState({
-      projects: [],
-      currentProject: null,
-      isLoading: false,
-      error: null,
-      searchQuery: '',
-      sortField: 'updatedAt',
-      sortDirection: 'desc',
-      filterResolution: undefined,
-      filterFps: undefined,
-    })
+function resetStore() {
+  vi.clearAllMocks()
+  useProjectStore.setState({
+    projects: [],
+    currentProject: null,
+    isLoading: false,
+    error: null,
+    searchQuery: '',
+    sortField: 'updatedAt',
+    sortDirection: 'desc',
+    filterResolution: undefined,
+    filterFps: undefined,
   })
+}
+
+describe('project-store deleteProject', () => {
+  beforeEach(resetStore)
 
   it('keeps a deleted project pruned if a stale reload lands while soft-delete is pending', async () => {
     const project = makeProject('p1')
@@ -95,3 +101,46 @@ describe('project-store deleteProject', () => {
     expect(useProjectStore.getState().currentProject).toBeNull()
   })
 })
+
+describe('project-store reframeProject', () => {
+  beforeEach(resetStore)
+
+  it('creates a linked variant once and updates it on later runs', async () => {
+    const source = makeProject('p1')
+    const metadata = { ...source.metadata, width: 1080, height: 1920 }
+    storageMocks.getProject.mockResolvedValue(source)
+    storageMocks.getProjectMediaIds.mockResolvedValue(['media-1'])
+    storageMocks.updateProject.mockImplementation(
+      async (id: string, updates: Partial<Project>) => ({
+        ...useProjectStore.getState().projects.find((p) => p.id === id)!,
+        ...updates,
+      }),
+    )
+    reframeProjectContent.mockResolvedValue({ metadata })
+    useProjectStore.setState({ projects: [source] })
+
+    const first = await useProjectStore.getState().reframeProject('p1', '9:16')
+    expect(first?.created).toBe(true)
+    expect(first?.project.metadata).toEqual(metadata)
+    expect(first?.project.reframe).toMatchObject({ sourceProjectId: 'p1', aspect: '9:16' })
+    expect(storageMocks.createProject).toHaveBeenCalledWith(first?.project)
+    expect(storageMocks.associateMediaWithProject).toHaveBeenCalledWith(
+      first?.project.id,
+      'media-1',
+    )
+
+    const second = await useProjectStore.getState().reframeProject('p1', '9:16')
+    expect(second?.created).toBe(false)
+    expect(second?.project.id).toBe(first?.project.id)
+    expect(storageMocks.createProject).toHaveBeenCalledTimes(1)
+    expect(useProjectStore.getState().projects).toHaveLength(2)
+  })
+
+  it('leaves projects untouched when the analysis is aborted', async () => {
+    storageMocks.getProject.mockResolvedValue(makeProject('p1'))
+    reframeProjectContent.mockResolvedValue(null)
+
+    await expect(useProjectStore.getState().reframeProject('p1', '1:1')).resolves.toBeNull()
+    expect(storageMocks.createProject).not.toHaveBeenCalled()
+  })
+})
@@ -1,7 +1,7 @@
 import { create } from 'zustand'
 import { devtools } from 'zustand/middleware'
 import { temporal } from 'zundo'
-import type { Project } from '@/types/project'
+import type { Project, ProjectCanvasVariant, ReframeAspect } from '@/types/project'
 import type { ProjectFormData } from '../utils/validation'
 import { useSettingsStore } from '@/features/projects/deps/settings-contract'
 import {
@@ -16,7 +16,12 @@ import {
   restoreProject as restoreProjectDB,
   getTrashedProjectMediaIds,
 } from '@/infrastructure/storage'
-import { createProjectObject, duplicateProject } from '../utils/project-helpers'
+import {
+  createProjectObject,
+  createReframeVariant,
+  duplicateProject,
+} from '../utils/project-helpers'
+import { reframeProjectContent, type ReframeOptions } from '../services/reframe-service'
 // v3: Lazy-load media service for cascade operations
 import { importMediaLibraryService } from '@/features/projects/deps/media-library-contract'
 import { createLogger } from '@/shared/logging/logger'
@@ -71,6 +76,21 @@ interface ProjectActions {
    */
   permanentlyDeleteProject: (id: string) => Promise<void>
   duplicateProject: (id: string) => Promise<Project>
+  /**
+   * Smart reframe: analyze the project's footage and write a variant at
+   * another aspect ratio. Updates the existing variant for that aspect when
+   * there is one. Resolves with null when aborted.
+   */
+  reframeProject: (
+    id: string,
+    aspect: ReframeAspect,
+    options?: ReframeOptions,
+  ) => Promise<{ project: Project; created: boolean } | null>
+  /**
+   * Replace the project's canvas variants (linked aspect-ratio deliverables
+   * that share its timeline). An empty list removes them.
+   */
+  setCanvasVariants: (id: string, variants: ProjectCanvasVariant[]) => Promise<void>
 
   // Project folder management
   setProjectRootFolder: (id: string, handle: FileSystemDirectoryHandle) => Promise<void>
@@ -193,6 +213,7 @@ export const useProjectStore = create<ProjectState & ProjectActions>()(
               height: data.height ?? existingProject.metadata.height,
               fps: data.fps ?? existingProject.metadata.fps,
               backgroundColor: data.backgroundColor ?? existingProject.metadata.backgroundColor,
+              colorSpace: data.colorSpace ?? existingProject.metadata.colorSpace,
             },
             updatedAt: Date.now(),
           }
@@ -432,6 +453,89 @@ export const useProjectStore = create<ProjectState & ProjectActions>()(
           }
         },
 
+        reframeProject: async (id: string, aspect: ReframeAspect, options?: ReframeOptions) => {
+          set({ error: null })
+
+          try {
+            // Read from storage so edits saved since the list loaded are included
+            const source = (await getProject(id)) ?? get().projects.find((p) => p.id === id)
+            if (!source) {
+              throw new Error(`Project not found: ${id}`)
+            }
+
+            const content = await reframeProjectContent(source, aspect, options)
+            if (!content) return null
+
+            const reframe = { sourceProjectId: id, aspect, reframedAt: Date.now() }
+            const existing = get().projects.find(
+              (p) => p.reframe?.sourceProjectId === id && p.reframe.aspect === aspect,
+            )
+
+            let project: Project
+            if (existing) {
+              project = await updateProjectDB(existing.id, {
+                ...content,
+                duration: source.duration,
+                reframe,
+              })
+              set((state) => ({
+                projects: state.projects.map((p) => (p.id === project.id ? project : p)),
+                currentProject:
+                  state.currentProject?.id === project.id ? project : state.currentProject,
+              }))
+            } else {
+              project = createReframeVariant(source, content, reframe)
+              await createProjectDB(project)
+              set((state) => ({ projects: [...state.projects, project] }))
+            }
+
+            // The source may use media added since the variant was created
+            const mediaIds = await getProjectMediaIds(id)
+            for (const mediaId of mediaIds) {
+              await associateMediaWithProject(project.id, mediaId)
+            }
+
+            return { project, created: !existing }
+          } catch (error) {
+            const errorMessage =
+              error instanceof Error ? error.message : 'Failed to reframe project'
+            set({ error: errorMessage })
+            throw error
+          }
+        },
+
+        setCanvasVariants: async (id: string, variants: ProjectCanvasVariant[]) => {
+          const previousProjects = get().projects
+          const currentProject = get().currentProject
+          const canvasVariants = variants.length > 0 ? variants : undefined
+
+          // Optimistic update
+          const updateProjectInList = (project: Project) => ({
+            ...project,
+            canvasVariants,
+            updatedAt: Date.now(),
+          })
+
+          if (currentProject?.id === id) {
+            set({ currentProject: updateProjectInList(currentProject) })
+          }
+
+          const projectIndex = previousProjects.findIndex((p) => p.id === id)
+          if (projectIndex !== -1) {
+            const optimisticProjects = [...previousProjects]
+            optimisticProjects[projectIndex] = updateProjectInList(previousProjects[projectIndex]!)
+            set({ projects: optimisticProjects })
+          }
+
+          try {
+            await updateProjectDB(id, { canvasVariants })
+          } catch (error) {
+            // Rollback on error
+            set({ projects: previousProjects, currentProject })
+            throw error
+          }
+        },
+
         // Project folder management
         setProjectRootFolder: async (id: string, handle: FileSystemDirectoryHandle) => {
           const previousProjects = get().projects
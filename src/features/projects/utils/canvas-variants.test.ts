@@ -0,0 +1,123 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { ProjectCanvasVariant, ProjectResolution } from '@/types/project'
+import type { ItemKeyframes } from '@/types/keyframe'
+import type { TextItem, VideoItem } from '@/types/timeline'
+import {
+  clearCanvasVariantOverride,
+  getCanvasVariantResolution,
+  resolveCanvasVariant,
+  setCanvasVariantOverride,
+} from './canvas-variants'
+
+const base: ProjectResolution = { width: 1920, height: 1080, fps: 30 }
+
+const video: VideoItem = {
+  id: 'clip-1',
+  trackId: 'track-1',
+  type: 'video',
+  from: 0,
+  durationInFrames: 60,
+  label: 'clip.mp4',
+  src: '',
+  mediaId: 'media-1',
+  sourceWidth: 1920,
+  sourceHeight: 1080,
+}
+
+const title: TextItem = {
+  id: 'title-1',
+  trackId: 'track-2',
+  type: 'text',
+  from: 0,
+  durationInFrames: 60,
+  label: 'Title',
+  text: 'Hello',
+  color: '#ffffff',
+  fontSize: 60,
+  transform: { x: 0, y: 300, width: 800, height: 100 },
+}
+
+const titleKeyframes: ItemKeyframes = {
+  itemId: 'title-1',
+  properties: [
+    {
+      property: 'y',
+      keyframes: [{ id: 'kf-1', frame: 0, value: 300, easing: 'linear' }],
+    },
+    {
+      property: 'opacity',
+      keyframes: [{ id: 'kf-2', frame: 0, value: 0, easing: 'linear' }],
+    },
+  ],
+}
+
+function createVariant(overrides: ProjectCanvasVariant['overrides'] = []): ProjectCanvasVariant {
+  return { id: 'variant-1', name: 'Portrait', aspect: '4:5', overrides }
+}
+
+describe('resolveCanvasVariant', () => {
+  it('sizes the canvas from the base short side', () => {
+    expect(getCanvasVariantResolution(base, '4:5')).toEqual({ width: 1080, height: 1350, fps: 30 })
+    expect(getCanvasVariantResolution(base, '9:16')).toMatchObject({ width: 1080, height: 1920 })
+  })
+
+  it('auto-lays out items the variant does not override', () => {
+    const resolved = resolveCanvasVariant(
+      { items: [video, title], keyframes: [] },
+      base,
+      createVariant(),
+    )
+
+    expect(resolved.resolution).toMatchObject({ width: 1080, height: 1350 })
+    expect(resolved.items[0]!.transform).toEqual({
+      x: 0,
+      y: 0,
+      width: 2400,
+      height: 1350,
+      aspectRatioLocked: true,
+    })
+    expect(resolved.items[1]!.transform).toMatchObject({ y: 375, width: 450 })
+  })
+
+  it('applies overrides over the auto layout and drops their keyframes', () => {
+    const resolved = resolveCanvasVariant(
+      { items: [video, title], keyframes: [titleKeyframes] },
+      base,
+      createVariant([
+        { itemId: 'clip-1', transform: { x: 300 }, crop: { left: 0.1 } },
+        { itemId: 'title-1', transform: { y: 500 }, text: { fontSize: 48, textAlign: 'left' } },
+        { itemId: 'deleted-item', transform: { x: 10 } },
+      ]),
+    )
+
+    expect(resolved.items[0]).toMatchObject({
+      transform: { x: 300, width: 2400 },
+      crop: { left: 0.1 },
+    })
+    expect(resolved.items[1]).toMatchObject({
+      fontSize: 48,
+      textAlign: 'left',
+      transform: { y: 500 },
+    })
+    expect(resolved.keyframes).toEqual([
+      { itemId: 'title-1', properties: [titleKeyframes.properties[1]] },
+    ])
+    expect(resolved.items).toHaveLength(2)
+  })
+})
+
+describe('setCanvasVariantOverride', () => {
+  it('merges fields and removes the override once every field is cleared', () => {
+    let variant = setCanvasVariantOverride(createVariant(), 'clip-1', { transform: { x: 10 } })
+    variant = setCanvasVariantOverride(variant, 'clip-1', { transform: { y: 20 } })
+    expect(variant.overrides).toEqual([{ itemId: 'clip-1', transform: { x: 10, y: 20 } }])
+
+    variant = setCanvasVariantOverride(variant, 'clip-1', {
+      transform: { x: undefined, y: undefined },
+    })
+    expect(variant.overrides).toEqual([])
+
+    variant = setCanvasVariantOverride(variant, 'title-1', { text: { fontSize: 40 } })
+    expect(clearCanvasVariantOverride(variant, 'title-1').overrides).toEqual([])
+  })
+})
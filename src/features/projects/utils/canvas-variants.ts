@@ -0,0 +1,165 @@
+import type {
+  CanvasVariantAspect,
+  Project,
+  ProjectCanvasVariant,
+  ProjectCanvasVariantOverride,
+  ProjectResolution,
+  ProjectTimeline,
+} from '@/types/project'
+import type { ItemKeyframes } from '@/types/keyframe'
+import type { TimelineItem } from '@/types/timeline'
+import { buildReframedTimeline, getAspectResolution } from './reframe'
+
+export const CANVAS_VARIANT_ASPECTS: CanvasVariantAspect[] = ['16:9', '9:16', '4:5', '1:1']
+
+const ASPECT_RATIOS: Record<CanvasVariantAspect, number> = {
+  '16:9': 16 / 9,
+  '9:16': 9 / 16,
+  '4:5': 4 / 5,
+  '1:1': 1,
+}
+
+const CROP_PROPERTIES: Record<keyof NonNullable<ProjectCanvasVariantOverride['crop']>, string> = {
+  left: 'cropLeft',
+  right: 'cropRight',
+  top: 'cropTop',
+  bottom: 'cropBottom',
+  softness: 'cropSoftness',
+}
+
+type OverridePatch = Omit<ProjectCanvasVariantOverride, 'itemId'>
+
+/** The timeline layers a variant rearranges; tracks, audio and timing are shared. */
+export interface CanvasVariantLayers {
+  items: TimelineItem[]
+  keyframes: ItemKeyframes[]
+}
+
+export interface ResolvedCanvasVariant extends CanvasVariantLayers {
+  resolution: ProjectResolution
+}
+
+export function getCanvasVariantResolution(
+  base: ProjectResolution,
+  aspect: CanvasVariantAspect,
+): ProjectResolution {
+  return getAspectResolution(base, ASPECT_RATIOS[aspect])
+}
+
+/** Whether a variant at this aspect would just repeat the base canvas. */
+export function isBaseCanvasAspect(base: ProjectResolution, aspect: CanvasVariantAspect): boolean {
+  return Math.abs(base.width / base.height - ASPECT_RATIOS[aspect]) < 0.01
+}
+
+export function getCanvasVariant(
+  project: Pick<Project, 'canvasVariants'> | null | undefined,
+  variantId: string | null | undefined,
+): ProjectCanvasVariant | undefined {
+  if (!variantId) return undefined
+  return project?.canvasVariants?.find((variant) => variant.id === variantId)
+}
+
+export function createCanvasVariant(
+  aspect: CanvasVariantAspect,
+  name: string,
+): ProjectCanvasVariant {
+  return { id: crypto.randomUUID(), name, aspect, overrides: [] }
+}
+
+function isEmptyPatch(patch: OverridePatch): boolean {
+  return !patch.transform && !patch.crop && !patch.text
+}
+
+/** Drop undefined fields so cleared properties fall back to the auto layout. */
+function compact<T extends object>(value: T | undefined): T | undefined {
+  if (!value) return undefined
+  const entries = Object.entries(value).filter(([, field]) => field !== undefined)
+  return entries.length > 0 ? (Object.fromEntries(entries) as T) : undefined
+}
+
+/**
+ * Merge layout overrides for one item into a variant. Passing `undefined` for
+ * a field clears it; an item left without overrides follows the base layout.
+ */
+export function setCanvasVariantOverride(
+  variant: ProjectCanvasVariant,
+  itemId: string,
+  patch: OverridePatch,
+): ProjectCanvasVariant {
+  const existing = variant.overrides.find((override) => override.itemId === itemId)
+  const next: OverridePatch = {
+    transform: compact({ ...existing?.transform, ...patch.transform }),
+    crop: 'crop' in patch ? compact(patch.crop) : existing?.crop,
+    text: compact({ ...existing?.text, ...patch.text }),
+  }
+  const overrides = variant.overrides.filter((override) => override.itemId !== itemId)
+  if (!isEmptyPatch(next)) overrides.push({ itemId, ...compact(next) })
+  return { ...variant, overrides }
+}
+
+export function clearCanvasVariantOverride(
+  variant: ProjectCanvasVariant,
+  itemId: string,
+): ProjectCanvasVariant {
+  return {
+    ...variant,
+    overrides: variant.overrides.filter((override) => override.itemId !== itemId),
+  }
+}
+
+function getOverriddenProperties(override: ProjectCanvasVariantOverride): Set<string> {
+  const properties = new Set<string>(Object.keys(override.transform ?? {}))
+  // A crop override replaces the whole crop, animated edges included
+  if (override.crop) {
+    for (const property of Object.values(CROP_PROPERTIES)) properties.add(property)
+  }
+  if (override.text?.fontSize !== undefined) properties.add('fontSize')
+  return properties
+}
+
+function applyOverride(item: TimelineItem, override: ProjectCanvasVariantOverride): TimelineItem {
+  const next = { ...item }
+  if (override.transform) next.transform = { ...item.transform, ...override.transform }
+  if (override.crop) next.crop = { ...override.crop }
+  if (override.text && next.type === 'text') Object.assign(next, override.text)
+  return next
+}
+
+/**
+ * Lay a timeline out on a variant canvas. Items without overrides are
+ * auto-laid-out (full-frame clips cover the canvas, overlays keep their
+ * relative position), then each override replaces the values it sets. Edits
+ * to the base timeline therefore reach every variant on the next render.
+ */
+export function resolveCanvasVariant(
+  layers: CanvasVariantLayers,
+  base: ProjectResolution,
+  variant: ProjectCanvasVariant,
+): ResolvedCanvasVariant {
+  const resolution = getCanvasVariantResolution(base, variant.aspect)
+  const laidOut = buildReframedTimeline(
+    {
+      tracks: [],
+      items: layers.items as unknown as ProjectTimeline['items'],
+      keyframes: layers.keyframes as unknown as ProjectTimeline['keyframes'],
+    },
+    base,
+    resolution,
+    new Map(),
+  )
+  const overrides = new Map(variant.overrides.map((override) => [override.itemId, override]))
+
+  const items = (laidOut.items as unknown as TimelineItem[]).map((item) => {
+    const override = overrides.get(item.id)
+    return override ? applyOverride(item, override) : item
+  })
+  const keyframes = (laidOut.keyframes as unknown as ItemKeyframes[]).flatMap((entry) => {
+    const override = overrides.get(entry.itemId)
+    if (!override) return [entry]
+    const overridden = getOverriddenProperties(override)
+    const properties = entry.properties.filter((property) => !overridden.has(property.property))
+    return properties.length > 0 ? [{ ...entry, properties }] : []
+  })
+
+  return { resolution, items, keyframes }
+}
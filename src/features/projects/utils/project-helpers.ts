@@ -1,6 +1,6 @@
 import { CURRENT_SCHEMA_VERSION } from '@/shared/projects/migrations'
 import { i18n } from '@/i18n'
-import type { Project } from '@/types/project'
+import type { Project, ProjectReframeLink } from '@/types/project'
 
 /**
  * Generate a unique project ID (8-character base62 hash)
@@ -177,6 +177,33 @@ export function duplicateProject(project: Project): Project {
     name: i18n.t('projects.copySuffix', { name: project.name }),
     createdAt: now,
     updatedAt: now,
+    // A copy is independent; re-running the source's reframe must not touch it
+    reframe: undefined,
+  }
+}
+
+/**
+ * Create a reframe variant of a project: same edit, new canvas, linked back
+ * to the source so later reframes update it instead of adding another copy.
+ */
+export function createReframeVariant(
+  source: Project,
+  content: Pick<Project, 'metadata' | 'timeline'>,
+  reframe: ProjectReframeLink,
+): Project {
+  const now = Date.now()
+
+  return {
+    id: generateProjectId(),
+    name: i18n.t('projects.reframe.variantName', { name: source.name, aspect: reframe.aspect }),
+    description: source.description,
+    metadata: content.metadata,
+    timeline: content.timeline,
+    createdAt: now,
+    updatedAt: now,
+    duration: source.duration,
+    schemaVersion: source.schemaVersion,
+    reframe,
   }
 }
 
@@ -0,0 +1,153 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { ProjectResolution, ProjectTimeline } from '@/types/project'
+import type { SmoothedSubjectPath } from '@/infrastructure/analysis/reframe'
+import {
+  buildReframedTimeline,
+  getReframeAspect,
+  getReframeResolution,
+  type ReframeSubject,
+} from './reframe'
+
+type TimelineItem = ProjectTimeline['items'][number]
+
+const source: ProjectResolution = { width: 1920, height: 1080, fps: 30 }
+const vertical = getReframeResolution(source, '9:16')
+
+function createVideo(overrides: Partial<TimelineItem> = {}): TimelineItem {
+  return {
+    id: 'clip-1',
+    trackId: 'track-1',
+    type: 'video',
+    from: 0,
+    durationInFrames: 60,
+    label: 'clip.mp4',
+    mediaId: 'media-1',
+    sourceStart: 0,
+    sourceFps: 30,
+    ...overrides,
+  }
+}
+
+function createTimeline(
+  items: TimelineItem[],
+  keyframes: ProjectTimeline['keyframes'] = [],
+): ProjectTimeline {
+  return { tracks: [], items, keyframes }
+}
+
+function createPath(x: number[], cuts: number[] = []): SmoothedSubjectPath {
+  return { fps: 4, startTime: 0, x, y: x.map(() => 0.5), size: x.map(() => 1), cuts }
+}
+
+function reframe(
+  items: TimelineItem[],
+  subject: ReframeSubject,
+  keyframes: ProjectTimeline['keyframes'] = [],
+) {
+  return buildReframedTimeline(
+    createTimeline(items, keyframes),
+    source,
+    vertical,
+    new Map([['media-1', subject]]),
+  )
+}
+
+function getKeyframes(timeline: ProjectTimeline, itemId: string, property: string) {
+  return timeline.keyframes
+    ?.find((entry) => entry.itemId === itemId)
+    ?.properties.find((entry) => entry.property === property)
+    ?.keyframes.map(({ frame, value }) => ({ frame, value }))
+}
+
+describe('reframe resolution', () => {
+  it('keeps the short side of the source canvas', () => {
+    expect(vertical).toEqual({ width: 1080, height: 1920, fps: 30 })
+    expect(getReframeResolution(source, '1:1')).toMatchObject({ width: 1080, height: 1080 })
+    expect(getReframeResolution(vertical, '16:9')).toMatchObject({ width: 1920, height: 1080 })
+    expect(getReframeAspect(1080, 1920)).toBe('9:16')
+    expect(getReframeAspect(1440, 1080)).toBeNull()
+  })
+})
+
+describe('buildReframedTimeline', () => {
+  it('covers the new canvas and holds a still subject without keyframes', () => {
+    const timeline = reframe([createVideo()], {
+      aspect: 16 / 9,
+      framing: { x: 0.75, y: 0.5, size: 1 },
+    })
+
+    expect(timeline.items[0]!.transform).toEqual({
+      x: -853,
+      y: 0,
+      width: 3413,
+      height: 1920,
+      aspectRatioLocked: true,
+    })
+    expect(timeline.keyframes).toEqual([])
+  })
+
+  it('follows a moving subject with editable position keyframes', () => {
+    const timeline = reframe([createVideo()], {
+      aspect: 16 / 9,
+      path: createPath([0.2, 0.275, 0.35, 0.425, 0.5, 0.575, 0.65, 0.725, 0.8]),
+    })
+
+    const x = getKeyframes(timeline, 'clip-1', 'x')!
+    expect(x[0]).toEqual({ frame: 0, value: 1024 })
+    expect(x[x.length - 1]).toEqual({ frame: 59, value: -1024 })
+    expect(getKeyframes(timeline, 'clip-1', 'width')).toBeUndefined()
+    expect(timeline.items[0]!.transform?.x).toBe(1024)
+  })
+
+  it('switches framing between adjacent frames at a source cut', () => {
+    const timeline = reframe([createVideo()], {
+      aspect: 16 / 9,
+      path: createPath([0.3, 0.3, 0.3, 0.3, 0.7, 0.7, 0.7, 0.7, 0.7], [4]),
+    })
+
+    expect(getKeyframes(timeline, 'clip-1', 'x')).toEqual([
+      { frame: 0, value: 683 },
+      { frame: 26, value: 683 },
+      { frame: 27, value: -683 },
+      { frame: 59, value: -683 },
+    ])
+  })
+
+  it('remaps overlays proportionally and leaves audio alone', () => {
+    const title: TimelineItem = {
+      id: 'title-1',
+      trackId: 'track-2',
+      type: 'text',
+      from: 0,
+      durationInFrames: 60,
+      label: 'Title',
+      fontSize: 60,
+      transform: { x: 400, y: 300, width: 600, height: 100, opacity: 0.8 },
+    }
+    const audio = createVideo({ id: 'audio-1', type: 'audio' })
+    const timeline = reframe(
+      [createVideo({ transform: { x: 200, width: 960, height: 540 } }), title, audio],
+      { aspect: 16 / 9 },
+      [
+        {
+          itemId: 'title-1',
+          properties: [
+            {
+              property: 'y',
+              keyframes: [{ id: 'kf-1', frame: 0, value: 300, easing: 'linear' }],
+            },
+          ],
+        },
+      ],
+    )
+
+    expect(timeline.items[0]!.transform).toEqual({ x: 112.5, width: 540, height: 303.75 })
+    expect(timeline.items[1]).toMatchObject({
+      fontSize: 33.75,
+      transform: { x: 225, width: 337.5, height: 56.25, opacity: 0.8 },
+    })
+    expect(timeline.items[1]!.transform?.y).toBeCloseTo(533.33)
+    expect(getKeyframes(timeline, 'title-1', 'y')?.[0]?.value).toBeCloseTo(533.33)
+    expect(timeline.items[2]).toBe(audio)
+  })
+})
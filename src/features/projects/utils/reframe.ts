@@ -0,0 +1,458 @@
+import type { ProjectResolution, ProjectTimeline, ReframeAspect } from '@/types/project'
+import type { AnimatableProperty, ItemKeyframes } from '@/types/keyframe'
+import type { TimelineItem } from '@/types/timeline'
+import type { SmoothedSubjectPath, SubjectFraming } from '@/infrastructure/analysis/reframe'
+import {
+  getSubjectFramingAt,
+  getSubjectPathCutTimes,
+} from '@/infrastructure/analysis/reframe/subject-path'
+import {
+  getItemNaturalSourceSeconds,
+  resolveTimeRemapCurve,
+  sampleTimeRemap,
+} from '@/features/projects/deps/keyframes-contract'
+
+type ProjectTimelineItem = ProjectTimeline['items'][number]
+type ProjectItemKeyframes = NonNullable<ProjectTimeline['keyframes']>[number]
+type ProjectPropertyKeyframes = ProjectItemKeyframes['properties'][number]
+
+export const REFRAME_ASPECTS: ReframeAspect[] = ['16:9', '9:16', '1:1']
+
+const ASPECT_RATIOS: Record<ReframeAspect, number> = {
+  '16:9': 16 / 9,
+  '9:16': 9 / 16,
+  '1:1': 1,
+}
+
+/** Spacing of reframe keyframes before redundant ones are dropped */
+const KEYFRAME_INTERVAL_SECONDS = 0.5
+/** Keyframes this close (px) to the line between their neighbours are dropped */
+const KEYFRAME_TOLERANCE_PX = 2
+/** Zoom in until the subject spans this fraction of the visible frame... */
+const TARGET_SUBJECT_FILL = 0.6
+/** ...but never further than this past covering the canvas */
+const MAX_ZOOM = 1.4
+/** Slack when deciding whether an item fills the canvas */
+const FULL_FRAME_TOLERANCE_PX = 2
+
+const POSITION_PROPERTIES = new Set<AnimatableProperty>(['x', 'y', 'width', 'height'])
+
+/** Analyzed subject of a media source, keyed by media id */
+export interface ReframeSubject {
+  /** Source frame aspect ratio (width / height) */
+  aspect: number
+  /** Subject over time (video) */
+  path?: SmoothedSubjectPath
+  /** Fixed subject (stills) */
+  framing?: SubjectFraming
+}
+
+export function getReframeAspect(width: number, height: number): ReframeAspect | null {
+  if (width <= 0 || height <= 0) return null
+  const ratio = width / height
+  return REFRAME_ASPECTS.find((aspect) => Math.abs(ASPECT_RATIOS[aspect] - ratio) < 0.01) ?? null
+}
+
+function toEven(value: number): number {
+  return Math.max(2, Math.round(value / 2) * 2)
+}
+
+/** Resolution at another aspect ratio (width / height), keeping the source's short side. */
+export function getAspectResolution(source: ProjectResolution, ratio: number): ProjectResolution {
+  const shortSide = Math.min(source.width, source.height)
+  return {
+    ...source,
+    width: toEven(ratio >= 1 ? shortSide * ratio : shortSide),
+    height: toEven(ratio >= 1 ? shortSide : shortSide / ratio),
+  }
+}
+
+/** Output resolution for an aspect, keeping the source's short side. */
+export function getReframeResolution(
+  source: ProjectResolution,
+  aspect: ReframeAspect,
+): ProjectResolution {
+  return getAspectResolution(source, ASPECT_RATIOS[aspect])
+}
+
+/** Source seconds an item shows at an item-relative frame, honoring speed and time remap. */
+export function getReframeSourceSeconds(
+  item: ProjectTimelineItem,
+  itemKeyframes: ProjectItemKeyframes | undefined,
+  frame: number,
+  fps: number,
+): number {
+  const timelineItem = item as unknown as TimelineItem
+  const curve = resolveTimeRemapCurve(timelineItem, itemKeyframes as ItemKeyframes, fps)
+  return curve
+    ? sampleTimeRemap(curve, frame)
+    : getItemNaturalSourceSeconds(timelineItem, frame, fps)
+}
+
+function getItemSourceSize(
+  item: ProjectTimelineItem,
+  subjects: ReadonlyMap<string, ReframeSubject>,
+): { width: number; height: number } | null {
+  if (item.type === 'video' || item.type === 'image') {
+    // Framing only depends on the source aspect ratio, not its pixel size
+    const subject = item.mediaId ? subjects.get(item.mediaId) : undefined
+    if (subject) return { width: subject.aspect, height: 1 }
+    return item.sourceWidth && item.sourceHeight
+      ? { width: item.sourceWidth, height: item.sourceHeight }
+      : null
+  }
+  if (item.type === 'composition' && item.compositionWidth && item.compositionHeight) {
+    return { width: item.compositionWidth, height: item.compositionHeight }
+  }
+  return null
+}
+
+function hasPositionKeyframes(itemKeyframes: ProjectItemKeyframes | undefined): boolean {
+  return (
+    itemKeyframes?.properties.some(
+      (entry) => POSITION_PROPERTIES.has(entry.property) && entry.keyframes.length > 0,
+    ) ?? false
+  )
+}
+
+/**
+ * Whether a visual item fills the canvas at its default framing — centered,
+ * unrotated and either fit or covering. Only these are reframed; overlays,
+ * picture-in-picture and animated layouts keep their design.
+ */
+function isFullFrameItem(
+  item: ProjectTimelineItem,
+  itemKeyframes: ProjectItemKeyframes | undefined,
+  canvas: ProjectResolution,
+  sourceSize: { width: number; height: number },
+): boolean {
+  if (hasPositionKeyframes(itemKeyframes)) return false
+  const transform = item.transform
+  if ((transform?.rotation ?? 0) !== 0) return false
+  if (
+    Math.abs(transform?.x ?? 0) > FULL_FRAME_TOLERANCE_PX ||
+    Math.abs(transform?.y ?? 0) > FULL_FRAME_TOLERANCE_PX
+  ) {
+    return false
+  }
+
+  const fitScale = Math.min(canvas.width / sourceSize.width, canvas.height / sourceSize.height)
+  const fitWidth = sourceSize.width * fitScale
+  const fitHeight = sourceSize.height * fitScale
+  const width = transform?.width ?? fitWidth
+  const height = transform?.height ?? fitHeight
+  const isFit =
+    Math.abs(width - fitWidth) <= FULL_FRAME_TOLERANCE_PX &&
+    Math.abs(height - fitHeight) <= FULL_FRAME_TOLERANCE_PX
+  const isCover =
+    width >= canvas.width - FULL_FRAME_TOLERANCE_PX &&
+    height >= canvas.height - FULL_FRAME_TOLERANCE_PX
+  return isFit || isCover
+}
+
+interface FramePoint {
+  frame: number
+  value: number
+}
+
+/** Douglas-Peucker over one span, keeping the endpoints. */
+function simplifySpan(points: FramePoint[], tolerance: number, keep: Set<number>): void {
+  if (points.length <= 2) return
+  const first = points[0]!
+  const last = points[points.length - 1]!
+  let worstIndex = -1
+  let worstError = tolerance
+  for (let i = 1; i < points.length - 1; i++) {
+    const point = points[i]!
+    const t = (point.frame - first.frame) / (last.frame - first.frame)
+    const error = Math.abs(point.value - (first.value + (last.value - first.value) * t))
+    if (error > worstError) {
+      worstError = error
+      worstIndex = i
+    }
+  }
+  if (worstIndex === -1) return
+  keep.add(points[worstIndex]!.frame)
+  simplifySpan(points.slice(0, worstIndex + 1), tolerance, keep)
+  simplifySpan(points.slice(worstIndex), tolerance, keep)
+}
+
+/**
+ * Drop keyframes that a straight line between their neighbours reproduces.
+ * Pinned frames (clip ends and both sides of a cut) are always kept.
+ */
+function simplifyFramePoints(
+  points: FramePoint[],
+  pinned: ReadonlySet<number>,
+  tolerance: number,
+): FramePoint[] {
+  if (points.length <= 2) return points
+  const keep = new Set<number>(pinned)
+  keep.add(points[0]!.frame)
+  keep.add(points[points.length - 1]!.frame)
+
+  let spanStart = 0
+  for (let i = 1; i < points.length; i++) {
+    if (!keep.has(points[i]!.frame)) continue
+    simplifySpan(points.slice(spanStart, i + 1), tolerance, keep)
+    spanStart = i
+  }
+  return points.filter((point) => keep.has(point.frame))
+}
+
+/** Transform values that frame the subject in the target canvas. */
+function getFramedTransform(
+  framing: SubjectFraming,
+  sourceSize: { width: number; height: number },
+  target: ProjectResolution,
+  flipHorizontal: boolean,
+  flipVertical: boolean,
+): Record<'x' | 'y' | 'width' | 'height', number> {
+  const coverScale = Math.max(target.width / sourceSize.width, target.height / sourceSize.height)
+  const baseWidth = sourceSize.width * coverScale
+  const baseHeight = sourceSize.height * coverScale
+  const visible = Math.min(target.width / baseWidth, target.height / baseHeight)
+  const zoom = Math.min(
+    MAX_ZOOM,
+    Math.max(1, (TARGET_SUBJECT_FILL * visible) / Math.max(framing.size, 1e-3)),
+  )
+  const width = baseWidth * zoom
+  const height = baseHeight * zoom
+  const subjectX = flipHorizontal ? 1 - framing.x : framing.x
+  const subjectY = flipVertical ? 1 - framing.y : framing.y
+  const maxX = (width - target.width) / 2
+  const maxY = (height - target.height) / 2
+
+  return {
+    x: Math.round(Math.min(maxX, Math.max(-maxX, (0.5 - subjectX) * width))),
+    y: Math.round(Math.min(maxY, Math.max(-maxY, (0.5 - subjectY) * height))),
+    width: Math.round(width),
+    height: Math.round(height),
+  }
+}
+
+function createPropertyKeyframes(
+  property: 'x' | 'y' | 'width' | 'height',
+  points: FramePoint[],
+): ProjectPropertyKeyframes {
+  return {
+    property,
+    keyframes: points.map((point) => ({
+      id: crypto.randomUUID(),
+      frame: point.frame,
+      value: point.value,
+      easing: 'linear' as const,
+    })),
+  }
+}
+
+/**
+ * Reframe a full-frame clip: cover the target canvas and follow the subject
+ * with x/y/width/height keyframes sampled along its smoothed path.
+ */
+function reframeFullFrameItem(
+  item: ProjectTimelineItem,
+  itemKeyframes: ProjectItemKeyframes | undefined,
+  subject: ReframeSubject | undefined,
+  sourceSize: { width: number; height: number },
+  target: ProjectResolution,
+): { item: ProjectTimelineItem; keyframes: ProjectPropertyKeyframes[] } {
+  const fps = target.fps
+  const lastFrame = Math.max(0, item.durationInFrames - 1)
+  const step = Math.max(1, Math.round(fps * KEYFRAME_INTERVAL_SECONDS))
+  const flipHorizontal = item.transform?.flipHorizontal ?? false
+  const flipVertical = item.transform?.flipVertical ?? false
+  const sourceSecondsAt = (frame: number) =>
+    getReframeSourceSeconds(item, itemKeyframes, frame, fps)
+  const framingAt = (frame: number): SubjectFraming =>
+    subject?.path
+      ? getSubjectFramingAt(subject.path, sourceSecondsAt(frame))
+      : (subject?.framing ?? { x: 0.5, y: 0.5, size: 1 })
+
+  const frames = new Set<number>([0, lastFrame])
+  for (let frame = step; frame < lastFrame; frame += step) frames.add(frame)
+
+  // A cut in the source switches framing between two adjacent clip frames
+  const pinned = new Set<number>([0, lastFrame])
+  const cutTimes = subject?.path ? getSubjectPathCutTimes(subject.path) : []
+  if (cutTimes.length > 0) {
+    let previous = sourceSecondsAt(0)
+    for (let frame = 1; frame <= lastFrame; frame++) {
+      const current = sourceSecondsAt(frame)
+      const low = Math.min(previous, current)
+      const high = Math.max(previous, current)
+      if (cutTimes.some((time) => time > low && time <= high)) {
+        for (const boundary of [frame - 1, frame]) {
+          frames.add(boundary)
+          pinned.add(boundary)
+        }
+      }
+      previous = current
+    }
+  }
+
+  const samples = [...frames]
+    .sort((a, b) => a - b)
+    .map((frame) => ({
+      frame,
+      values: getFramedTransform(
+        framingAt(frame),
+        sourceSize,
+        target,
+        flipHorizontal,
+        flipVertical,
+      ),
+    }))
+
+  const transform = { ...item.transform }
+  delete transform.anchorX
+  delete transform.anchorY
+  const keyframes: ProjectPropertyKeyframes[] = []
+  for (const property of ['x', 'y', 'width', 'height'] as const) {
+    const points = simplifyFramePoints(
+      samples.map(({ frame, values }) => ({ frame, value: values[property] })),
+      pinned,
+      KEYFRAME_TOLERANCE_PX,
+    )
+    transform[property] = points[0]!.value
+    if (points.some((point) => Math.abs(point.value - points[0]!.value) > KEYFRAME_TOLERANCE_PX)) {
+      keyframes.push(createPropertyKeyframes(property, points))
+    }
+  }
+  transform.aspectRatioLocked = true
+
+  return { item: { ...item, transform }, keyframes }
+}
+
+const LAYOUT_SCALES: Partial<Record<AnimatableProperty, 'x' | 'y' | 'uniform'>> = {
+  x: 'x',
+  y: 'y',
+  width: 'uniform',
+  height: 'uniform',
+  anchorX: 'uniform',
+  anchorY: 'uniform',
+  cornerRadius: 'uniform',
+  fontSize: 'uniform',
+}
+
+const LAYOUT_TRANSFORM_KEYS = [
+  'x',
+  'y',
+  'width',
+  'height',
+  'anchorX',
+  'anchorY',
+  'cornerRadius',
+] as const
+
+/**
+ * Carry an overlay's layout over to the new canvas: offsets follow the canvas
+ * axes, sizes scale uniformly so titles and graphics keep their proportions.
+ */
+function remapOverlayItem(
+  item: ProjectTimelineItem,
+  itemKeyframes: ProjectItemKeyframes | undefined,
+  source: ProjectResolution,
+  target: ProjectResolution,
+): { item: ProjectTimelineItem; keyframes: ProjectPropertyKeyframes[] } {
+  const scales = {
+    x: target.width / source.width,
+    y: target.height / source.height,
+    uniform: Math.min(target.width / source.width, target.height / source.height),
+  }
+  const scaleValue = (property: AnimatableProperty, value: number) => {
+    const axis = LAYOUT_SCALES[property]
+    return axis ? value * scales[axis] : value
+  }
+
+  const next: ProjectTimelineItem = { ...item }
+  if (item.transform) {
+    const transform = { ...item.transform }
+    for (const key of LAYOUT_TRANSFORM_KEYS) {
+      const value = transform[key]
+      if (value !== undefined) transform[key] = scaleValue(key, value)
+    }
+    next.transform = transform
+  }
+  if (typeof item.fontSize === 'number') next.fontSize = scaleValue('fontSize', item.fontSize)
+
+  const keyframes = (itemKeyframes?.properties ?? []).map((entry) =>
+    LAYOUT_SCALES[entry.property]
+      ? {
+          ...entry,
+          keyframes: entry.keyframes.map((keyframe) => ({
+            ...keyframe,
+            value: scaleValue(entry.property, keyframe.value),
+          })),
+        }
+      : entry,
+  )
+  return { item: next, keyframes }
+}
+
+function hasExplicitLayout(
+  item: ProjectTimelineItem,
+  itemKeyframes: ProjectItemKeyframes | undefined,
+): boolean {
+  const transform = item.transform
+  return (
+    transform?.x !== undefined ||
+    transform?.y !== undefined ||
+    transform?.width !== undefined ||
+    transform?.height !== undefined ||
+    typeof item.fontSize === 'number' ||
+    hasPositionKeyframes(itemKeyframes)
+  )
+}
+
+/**
+ * Rebuild a timeline for another canvas size. Full-frame video, image and
+ * compound clips are cropped to the new aspect with keyframes that keep the
+ * analyzed subject in frame; other visual layers are proportionally remapped.
+ * The generated keyframes are ordinary keyframes and stay editable.
+ */
+export function buildReframedTimeline(
+  timeline: ProjectTimeline,
+  source: ProjectResolution,
+  target: ProjectResolution,
+  subjects: ReadonlyMap<string, ReframeSubject>,
+): ProjectTimeline {
+  const keyframesByItem = new Map(timeline.keyframes?.map((entry) => [entry.itemId, entry]))
+  const keyframes: ProjectItemKeyframes[] = []
+
+  const items = timeline.items.map((item) => {
+    const itemKeyframes = keyframesByItem.get(item.id)
+    const keepKeyframes = () => {
+      if (itemKeyframes) keyframes.push(itemKeyframes)
+      return item
+    }
+    if (item.type === 'audio' || item.type === 'adjustment') return keepKeyframes()
+
+    const sourceSize = getItemSourceSize(item, subjects)
+    let result: { item: ProjectTimelineItem; keyframes: ProjectPropertyKeyframes[] }
+    if (sourceSize && isFullFrameItem(item, itemKeyframes, source, sourceSize)) {
+      const reframed = reframeFullFrameItem(
+        item,
+        itemKeyframes,
+        item.mediaId ? subjects.get(item.mediaId) : undefined,
+        sourceSize,
+        target,
+      )
+      const otherKeyframes = (itemKeyframes?.properties ?? []).filter(
+        (entry) => !POSITION_PROPERTIES.has(entry.property),
+      )
+      result = { item: reframed.item, keyframes: [...otherKeyframes, ...reframed.keyframes] }
+    } else if (hasExplicitLayout(item, itemKeyframes)) {
+      result = remapOverlayItem(item, itemKeyframes, source, target)
+    } else {
+      return keepKeyframes()
+    }
+
+    if (result.keyframes.length > 0) {
+      keyframes.push({ itemId: item.id, properties: result.keyframes })
+    }
+    return result.item
+  })
+
+  return { ...timeline, items, keyframes }
+}
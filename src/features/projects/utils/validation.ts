@@ -51,6 +51,8 @@ export function createProjectFormSchema(t: (key: string) => string) {
       .string()
       .regex(/^#[0-9A-Fa-f]{6}$/, t('projects.validation.invalidHexColor'))
       .optional(),
+
+    colorSpace: z.enum(['rec709', 'rec2100-pq', 'rec2100-hlg']).optional(),
   })
 }
 
@@ -208,6 +208,26 @@ export const HOTKEY_EDITOR_SECTIONS: readonly HotkeyEditorSection[] = [
       },
     ],
   },
+  {
+    titleKey: 'projects.settings.hotkeys.sections.multicam.title',
+    blurbKey: 'projects.settings.hotkeys.sections.multicam.blurb',
+    items: [
+      {
+        labelKey: 'projects.settings.hotkeys.items.multicamSwitchAngle',
+        keys: [
+          'MULTICAM_ANGLE_1',
+          'MULTICAM_ANGLE_2',
+          'MULTICAM_ANGLE_3',
+          'MULTICAM_ANGLE_4',
+          'MULTICAM_ANGLE_5',
+          'MULTICAM_ANGLE_6',
+          'MULTICAM_ANGLE_7',
+          'MULTICAM_ANGLE_8',
+          'MULTICAM_ANGLE_9',
+        ],
+      },
+    ],
+  },
   {
     titleKey: 'projects.settings.hotkeys.sections.sourceMonitor.title',
     blurbKey: 'projects.settings.hotkeys.sections.sourceMonitor.blurb',
@@ -0,0 +1,368 @@
+import { useCallback, useEffect, useMemo, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { Button } from '@/components/ui/button'
+import { FloatingPanel } from '@/components/ui/floating-panel'
+import { Input } from '@/components/ui/input'
+import { Label } from '@/components/ui/label'
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select'
+import { Slider } from '@/components/ui/slider'
+import { Switch } from '@/components/ui/switch'
+import { toast } from 'sonner'
+import { useTimelineStore } from '../stores/timeline-store'
+import { useItemsStore } from '../stores/items-store'
+import { useKeyframesStore } from '../stores/keyframes-store'
+import { useTimelineSettingsStore } from '../stores/timeline-settings-store'
+import { useAutoDuckingDialogStore } from '../stores/auto-ducking-dialog-store'
+import {
+  detectDialogueSpeech,
+  planAutoDucking,
+  type AutoDuckingSettings,
+  type AutoDuckingSpeechSource,
+} from '../utils/auto-ducking'
+import { isAudioVideoItem } from '../utils/removal-preview-overlays'
+import { createLogger } from '@/shared/logging/logger'
+
+const logger = createLogger('AutoDuckingDialog')
+const AUTO_DUCKING_PANEL_STORAGE_KEY = 'timeline:autoDuckingPanelBounds'
+const AUTO_DUCKING_PANEL_DEFAULT_BOUNDS = { x: -1, y: -1, width: 420, height: 460 }
+
+function clampNumber(value: number, min: number, max: number): number {
+  if (!Number.isFinite(value)) return min
+  return Math.max(min, Math.min(max, value))
+}
+
+function SettingControl({
+  id,
+  label,
+  value,
+  min,
+  max,
+  step,
+  suffix,
+  onChange,
+}: {
+  id: string
+  label: string
+  value: number
+  min: number
+  max: number
+  step: number
+  suffix: string
+  onChange: (value: number) => void
+}) {
+  const commitValue = useCallback(
+    (nextValue: number) => {
+      onChange(clampNumber(nextValue, min, max))
+    },
+    [max, min, onChange],
+  )
+
+  return (
+    <div className="space-y-1.5">
+      <div className="flex items-center justify-between gap-3">
+        <Label htmlFor={id} className="text-xs font-medium">
+          {label}
+        </Label>
+        <div className="flex items-center gap-1">
+          <Input
+            id={id}
+            type="number"
+            value={value}
+            min={min}
+            max={max}
+            step={step}
+            onChange={(event) => commitValue(Number(event.target.value))}
+            className="h-7 w-16 px-2 text-right text-xs"
+          />
+          <span className="w-7 text-xs text-muted-foreground">{suffix}</span>
+        </div>
+      </div>
+      <Slider
+        value={[value]}
+        min={min}
+        max={max}
+        step={step}
+        onValueChange={([nextValue]) => {
+          if (nextValue !== undefined) commitValue(nextValue)
+        }}
+      />
+    </div>
+  )
+}
+
+export function AutoDuckingDialog() {
+  const { t } = useTranslation()
+  const isOpen = useAutoDuckingDialogStore((state) => state.isOpen)
+  const musicTrackIds = useAutoDuckingDialogStore((state) => state.musicTrackIds)
+  const dialogueTrackIds = useAutoDuckingDialogStore((state) => state.dialogueTrackIds)
+  const settings = useAutoDuckingDialogStore((state) => state.settings)
+  const setTracks = useAutoDuckingDialogStore((state) => state.setTracks)
+  const setSettings = useAutoDuckingDialogStore((state) => state.setSettings)
+  const close = useAutoDuckingDialogStore((state) => state.close)
+  const tracks = useItemsStore((state) => state.tracks)
+  const itemsByTrackId = useItemsStore((state) => state.itemsByTrackId)
+  const [draft, setDraft] = useState<AutoDuckingSettings>(settings)
+  const [isAnalyzing, setIsAnalyzing] = useState(false)
+
+  useEffect(() => {
+    if (isOpen) {
+      setDraft(settings)
+    }
+  }, [isOpen, settings])
+
+  // Only tracks that hold clips with audio can duck or be ducked.
+  const audioTracks = useMemo(
+    () =>
+      tracks
+        .filter(
+          (track) => !track.isGroup && (itemsByTrackId[track.id] ?? []).some(isAudioVideoItem),
+        )
+        .toSorted((a, b) => a.order - b.order),
+    [itemsByTrackId, tracks],
+  )
+
+  const handleClose = useCallback(() => {
+    close()
+  }, [close])
+
+  const handlePanelClose = useCallback(() => {
+    if (!isAnalyzing) {
+      handleClose()
+    }
+  }, [handleClose, isAnalyzing])
+
+  useEffect(() => {
+    if (!isOpen) return
+
+    const handleKeyDown = (event: KeyboardEvent) => {
+      if (event.key !== 'Escape' || isAnalyzing) return
+      event.preventDefault()
+      event.stopPropagation()
+      handleClose()
+    }
+
+    window.addEventListener('keydown', handleKeyDown, { capture: true })
+    return () => window.removeEventListener('keydown', handleKeyDown, { capture: true })
+  }, [handleClose, isAnalyzing, isOpen])
+
+  const handleMusicTrackChange = useCallback(
+    (trackId: string) => {
+      setTracks({
+        musicTrackIds: [trackId],
+        dialogueTrackIds: dialogueTrackIds.filter((id) => id !== trackId),
+      })
+    },
+    [dialogueTrackIds, setTracks],
+  )
+
+  const handleDialogueTrackToggle = useCallback(
+    (trackId: string, checked: boolean) => {
+      setTracks({
+        musicTrackIds,
+        dialogueTrackIds: checked
+          ? [...dialogueTrackIds, trackId]
+          : dialogueTrackIds.filter((id) => id !== trackId),
+      })
+    },
+    [dialogueTrackIds, musicTrackIds, setTracks],
+  )
+
+  const handleApply = useCallback(() => {
+    const run = async () => {
+      setIsAnalyzing(true)
+      setSettings(draft)
+      try {
+        const fps = useTimelineSettingsStore.getState().fps
+        const { itemsByTrackId: currentItemsByTrackId } = useItemsStore.getState()
+        const dialogueItems = dialogueTrackIds.flatMap((id) => currentItemsByTrackId[id] ?? [])
+        const musicItems = musicTrackIds.flatMap((id) => currentItemsByTrackId[id] ?? [])
+        const speech = await detectDialogueSpeech(dialogueItems, draft, fps)
+        const envelopes = planAutoDucking({
+          musicItems,
+          speechRanges: speech.ranges,
+          settings: draft,
+          fps,
+          keyframesByItemId: useKeyframesStore.getState().keyframesByItemId,
+        })
+
+        const duckedCount =
+          envelopes.length > 0 ? useTimelineStore.getState().replaceVolumeKeyframes(envelopes) : 0
+        if (duckedCount === 0) {
+          toast.info(t('timeline.autoDucking.toastNoSpeech'))
+          return
+        }
+
+        close()
+        toast.success(t('timeline.autoDucking.toastApplied', { count: duckedCount }))
+      } catch (error) {
+        logger.warn('Auto ducking failed', error)
+        toast.error(error instanceof Error ? error.message : t('timeline.autoDucking.toastFailed'))
+      } finally {
+        setIsAnalyzing(false)
+      }
+    }
+
+    void run()
+  }, [close, dialogueTrackIds, draft, musicTrackIds, setSettings, t])
+
+  if (!isOpen) {
+    return null
+  }
+
+  const musicTrackId = musicTrackIds[0]
+
+  return (
+    <FloatingPanel
+      title={t('timeline.autoDucking.title')}
+      defaultBounds={AUTO_DUCKING_PANEL_DEFAULT_BOUNDS}
+      minWidth={340}
+      minHeight={360}
+      storageKey={AUTO_DUCKING_PANEL_STORAGE_KEY}
+      onClose={handlePanelClose}
+      resizable={false}
+      autoHeight
+      className="bg-background/95 backdrop-blur supports-[backdrop-filter]:bg-background/90"
+    >
+      <section
+        role="dialog"
+        aria-label={t('timeline.autoDucking.title')}
+        aria-modal="false"
+        className="flex flex-col"
+      >
+        <div className="space-y-4 p-3">
+          <div className="space-y-1.5">
+            <Label className="text-xs font-medium">{t('timeline.autoDucking.musicTrack')}</Label>
+            <Select value={musicTrackId} onValueChange={handleMusicTrackChange}>
+              <SelectTrigger className="h-8 text-xs">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                {audioTracks.map((track) => (
+                  <SelectItem key={track.id} value={track.id} className="text-xs">
+                    {track.name}
+                  </SelectItem>
+                ))}
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="space-y-1.5">
+            <Label className="text-xs font-medium">
+              {t('timeline.autoDucking.dialogueTracks')}
+            </Label>
+            <div className="space-y-1 rounded-md border bg-muted/35 px-3 py-2">
+              {audioTracks
+                .filter((track) => track.id !== musicTrackId)
+                .map((track) => (
+                  <label key={track.id} className="flex items-center justify-between gap-3 text-xs">
+                    <span className="truncate">{track.name}</span>
+                    <Switch
+                      checked={dialogueTrackIds.includes(track.id)}
+                      onCheckedChange={(checked) => handleDialogueTrackToggle(track.id, checked)}
+                    />
+                  </label>
+                ))}
+            </div>
+          </div>
+
+          <div className="space-y-1.5">
+            <Label className="text-xs font-medium">{t('timeline.autoDucking.detectSpeech')}</Label>
+            <Select
+              value={draft.speechSource}
+              onValueChange={(speechSource) =>
+                setDraft((current) => ({
+                  ...current,
+                  speechSource: speechSource as AutoDuckingSpeechSource,
+                }))
+              }
+            >
+              <SelectTrigger className="h-8 text-xs">
+                <SelectValue />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="transcript" className="text-xs">
+                  {t('timeline.autoDucking.sourceTranscript')}
+                </SelectItem>
+                <SelectItem value="energy" className="text-xs">
+                  {t('timeline.autoDucking.sourceLevel')}
+                </SelectItem>
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="space-y-3.5">
+            <SettingControl
+              id="ducking-depth"
+              label={t('timeline.autoDucking.depth')}
+              value={draft.depthDb}
+              min={1}
+              max={40}
+              step={1}
+              suffix="dB"
+              onChange={(depthDb) => setDraft((current) => ({ ...current, depthDb }))}
+            />
+            <SettingControl
+              id="ducking-attack"
+              label={t('timeline.autoDucking.attack')}
+              value={draft.attackMs}
+              min={0}
+              max={2000}
+              step={25}
+              suffix="ms"
+              onChange={(attackMs) => setDraft((current) => ({ ...current, attackMs }))}
+            />
+            <SettingControl
+              id="ducking-release"
+              label={t('timeline.autoDucking.release')}
+              value={draft.releaseMs}
+              min={0}
+              max={3000}
+              step={25}
+              suffix="ms"
+              onChange={(releaseMs) => setDraft((current) => ({ ...current, releaseMs }))}
+            />
+            <SettingControl
+              id="ducking-hold"
+              label={t('timeline.autoDucking.hold')}
+              value={draft.holdMs}
+              min={0}
+              max={3000}
+              step={50}
+              suffix="ms"
+              onChange={(holdMs) => setDraft((current) => ({ ...current, holdMs }))}
+            />
+            <SettingControl
+              id="ducking-threshold"
+              label={t('timeline.autoDucking.threshold')}
+              value={draft.thresholdDb}
+              min={-80}
+              max={-20}
+              step={1}
+              suffix="dB"
+              onChange={(thresholdDb) => setDraft((current) => ({ ...current, thresholdDb }))}
+            />
+          </div>
+        </div>
+
+        <div className="flex flex-col-reverse gap-2 border-t border-border bg-secondary/10 p-3 sm:flex-row sm:justify-end">
+          <Button variant="ghost" size="sm" onClick={handleClose} disabled={isAnalyzing}>
+            {t('common.cancel')}
+          </Button>
+          <Button
+            size="sm"
+            onClick={handleApply}
+            disabled={isAnalyzing || !musicTrackId || dialogueTrackIds.length === 0}
+          >
+            {isAnalyzing ? t('timeline.autoDucking.analyzing') : t('timeline.autoDucking.apply')}
+          </Button>
+        </div>
+      </section>
+    </FloatingPanel>
+  )
+}
@@ -35,6 +35,8 @@ interface ClipFilmstripProps {
   speed: number
   /** Whether the clip plays source media in reverse */
   isReversed?: boolean
+  /** Source seconds shown at a clip-relative time; overrides speed/reverse (time remap) */
+  sourceTimeAt?: (clipSeconds: number) => number
   /** Frames per second */
   fps: number
   /** Whether the clip is visible (from IntersectionObserver) */
@@ -211,6 +213,7 @@ export const ClipFilmstrip = memo(function ClipFilmstrip({
   trimStart,
   speed,
   isReversed = false,
+  sourceTimeAt,
   isVisible,
   visibleStartRatio = 0,
   visibleEndRatio = 1,
@@ -295,9 +298,11 @@ export const ClipFilmstrip = memo(function ClipFilmstrip({
     const indices = new Set<number>()
     for (let slot = startTile; slot < endTile; slot++) {
       const slotCenterX = slot * tileWidth + tileWidth * 0.5
-      const slotCenterTime = isReversed
-        ? effectiveEnd - slotCenterX / pixelsPerSourceSecond
-        : effectiveStart + slotCenterX / pixelsPerSourceSecond
+      const slotCenterTime = sourceTimeAt
+        ? sourceTimeAt(slotCenterX / renderPixelsPerSecond)
+        : isReversed
+          ? effectiveEnd - slotCenterX / pixelsPerSourceSecond
+          : effectiveStart + slotCenterX / pixelsPerSourceSecond
       indices.add(Math.max(0, Math.min(totalFrameCount - 1, Math.floor(slotCenterTime))))
     }
 
@@ -316,6 +321,7 @@ export const ClipFilmstrip = memo(function ClipFilmstrip({
     visibleStartRatio,
     visibleEndRatio,
     isReversed,
+    sourceTimeAt,
   ])
 
   // Load blob URL lazily when visible, and retry after global invalidation.
@@ -462,9 +468,11 @@ export const ClipFilmstrip = memo(function ClipFilmstrip({
     for (let slot = startTile; slot < endTile; slot++) {
       const slotX = slot * tileWidth
       const slotCenterX = slotX + tileWidth * 0.5
-      const slotCenterTime = isReversed
-        ? effectiveEnd - slotCenterX / pixelsPerSourceSecond
-        : effectiveStart + slotCenterX / pixelsPerSourceSecond
+      const slotCenterTime = sourceTimeAt
+        ? sourceTimeAt(slotCenterX / renderPixelsPerSecond)
+        : isReversed
+          ? effectiveEnd - slotCenterX / pixelsPerSourceSecond
+          : effectiveStart + slotCenterX / pixelsPerSourceSecond
       const frame = findClosestFrame(slotCenterTime)
       if (!frame) continue
       result.push({ slot, frame, x: slotX, width: tileWidth })
@@ -481,6 +489,7 @@ export const ClipFilmstrip = memo(function ClipFilmstrip({
     effectiveStart,
     effectiveEnd,
     isReversed,
+    sourceTimeAt,
     speed,
     thumbnailWidth,
   ])
@@ -23,12 +23,17 @@ import {
   SelectValue,
 } from '@/components/ui/select'
 import {
+  createTimeRemapCurve,
   getBezierPresetForEasing,
+  getCornerPinPropertyValue,
   getCropPropertyValue,
+  getItemNaturalSourceSeconds,
   getTransitionBlockedRanges,
   interpolatePropertyValue,
   getTextAnimatableBaseValue,
+  isCornerPinAnimatableProperty,
   isTextAnimatableProperty,
+  sampleTimeRemap,
 } from '@/features/timeline/deps/keyframes'
 import {
   DopesheetEditor,
@@ -218,10 +223,33 @@ function getBaseKeyframeValue(
     })
   }
 
+  if (isCornerPinAnimatableProperty(property)) {
+    return getCornerPinPropertyValue(item.cornerPin, property)
+  }
+
   const resolved = resolveTransform(item, canvas, getSourceDimensions(item))
   return property in resolved ? resolved[property as keyof typeof resolved] : 0
 }
 
+function getKeyframeValueAtFrame(
+  item: TimelineItem,
+  property: AnimatableProperty,
+  keyframes: Keyframe[],
+  frame: number,
+  canvas: CanvasSettings,
+): number {
+  // Time remap runs at the clip's natural rate until its curve has two keyframes,
+  // and keeps that rate outside the keyframed range.
+  if (property === 'timeRemap') {
+    const curve = createTimeRemapCurve(item, keyframes, canvas.fps)
+    return curve
+      ? sampleTimeRemap(curve, frame)
+      : getItemNaturalSourceSeconds(item, frame, canvas.fps)
+  }
+
+  return interpolatePropertyValue(keyframes, frame, getBaseKeyframeValue(item, property, canvas))
+}
+
 function buildEasingConfig(
   easing: EasingType,
   existingConfig?: EasingConfig,
@@ -1374,8 +1402,13 @@ export const KeyframeGraphPanel = memo(function KeyframeGraphPanel({
       if (!selectedItemForEditor) return
 
       const propKeyframes = keyframesByProperty[property] ?? []
-      const baseValue = getBaseKeyframeValue(selectedItemForEditor, property, canvas)
-      const value = interpolatePropertyValue(propKeyframes, frame, baseValue)
+      const value = getKeyframeValueAtFrame(
+        selectedItemForEditor,
+        property,
+        propKeyframes,
+        frame,
+        canvas,
+      )
 
       timelineActions.addKeyframe(selectedItemForEditor.id, property, frame, value)
     },
@@ -1387,8 +1420,13 @@ export const KeyframeGraphPanel = memo(function KeyframeGraphPanel({
 
       const payloads = entries.map(({ property, frame }) => {
         const propKeyframes = keyframesByProperty[property] ?? []
-        const baseValue = getBaseKeyframeValue(selectedItemForEditor, property, canvas)
-        const value = interpolatePropertyValue(propKeyframes, frame, baseValue)
+        const value = getKeyframeValueAtFrame(
+          selectedItemForEditor,
+          property,
+          propKeyframes,
+          frame,
+          canvas,
+        )
 
         return {
           itemId: selectedItemForEditor.id,
@@ -1448,8 +1486,13 @@ export const KeyframeGraphPanel = memo(function KeyframeGraphPanel({
     const values: Partial<Record<AnimatableProperty, number>> = {}
     for (const property of availableProperties) {
       const propKeyframes = keyframesByProperty[property] ?? []
-      const baseValue = getBaseKeyframeValue(selectedItemForEditor, property, canvas)
-      values[property] = interpolatePropertyValue(propKeyframes, relativeFrame, baseValue)
+      values[property] = getKeyframeValueAtFrame(
+        selectedItemForEditor,
+        property,
+        propKeyframes,
+        relativeFrame,
+        canvas,
+      )
     }
     return values
   }, [availableProperties, canvas, keyframesByProperty, relativeFrame, selectedItemForEditor])
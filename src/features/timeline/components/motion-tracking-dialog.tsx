@@ -0,0 +1,404 @@
+import { useCallback, useEffect, useMemo, useRef, useState } from 'react'
+import { useTranslation } from 'react-i18next'
+import { Button } from '@/components/ui/button'
+import { FloatingPanel } from '@/components/ui/floating-panel'
+import { Input } from '@/components/ui/input'
+import { Label } from '@/components/ui/label'
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select'
+import { Switch } from '@/components/ui/switch'
+import { toast } from 'sonner'
+import type { TimelineItem } from '@/types/timeline'
+import type { MotionTrackMode, MotionTrackRegion } from '@/infrastructure/analysis/motion-tracking'
+import { usePlaybackStore } from '@/shared/state/playback'
+import {
+  DEFAULT_PROJECT_FPS,
+  DEFAULT_PROJECT_HEIGHT,
+  DEFAULT_PROJECT_WIDTH,
+} from '@/shared/projects/defaults'
+import { useProjectStore } from '@/features/timeline/deps/projects'
+import { useTimelineStore } from '../stores/timeline-store'
+import { useItemsStore } from '../stores/items-store'
+import { useKeyframesStore } from '../stores/keyframes-store'
+import { useTimelineSettingsStore } from '../stores/timeline-settings-store'
+import { useMotionTrackingDialogStore } from '../stores/motion-tracking-dialog-store'
+import {
+  getDefaultTrackRegion,
+  planMotionTrackKeyframes,
+  trackClipRegion,
+  type MotionTrackTarget,
+} from '../utils/motion-tracking'
+import { createLogger } from '@/shared/logging/logger'
+
+const logger = createLogger('MotionTrackingDialog')
+const MOTION_TRACKING_PANEL_STORAGE_KEY = 'timeline:motionTrackingPanelBounds'
+const MOTION_TRACKING_PANEL_DEFAULT_BOUNDS = { x: -1, y: -1, width: 400, height: 480 }
+
+const REGION_FIELDS: Array<{
+  key: keyof MotionTrackRegion
+  labelKey: 'regionX' | 'regionY' | 'regionWidth' | 'regionHeight'
+}> = [
+  { key: 'x', labelKey: 'regionX' },
+  { key: 'y', labelKey: 'regionY' },
+  { key: 'width', labelKey: 'regionWidth' },
+  { key: 'height', labelKey: 'regionHeight' },
+]
+
+function isTrackTargetItem(item: TimelineItem): boolean {
+  return item.type !== 'audio' && item.type !== 'adjustment'
+}
+
+function clampRegion(region: MotionTrackRegion): MotionTrackRegion {
+  const x = Math.max(0, Math.min(0.99, region.x))
+  const y = Math.max(0, Math.min(0.99, region.y))
+  return {
+    x,
+    y,
+    width: Math.max(0.01, Math.min(1 - x, region.width)),
+    height: Math.max(0.01, Math.min(1 - y, region.height)),
+  }
+}
+
+export function MotionTrackingDialog() {
+  const { t } = useTranslation()
+  const isOpen = useMotionTrackingDialogStore((state) => state.isOpen)
+  const itemId = useMotionTrackingDialogStore((state) => state.itemId)
+  const settings = useMotionTrackingDialogStore((state) => state.settings)
+  const setSettings = useMotionTrackingDialogStore((state) => state.setSettings)
+  const close = useMotionTrackingDialogStore((state) => state.close)
+  const items = useItemsStore((state) => state.items)
+  const currentProject = useProjectStore((state) => state.currentProject)
+  const [targetItemId, setTargetItemId] = useState<string | null>(null)
+  const [region, setRegion] = useState<MotionTrackRegion>({
+    x: 0.4,
+    y: 0.4,
+    width: 0.2,
+    height: 0.2,
+  })
+  const [progress, setProgress] = useState<number | null>(null)
+  const abortRef = useRef<AbortController | null>(null)
+  const isTracking = progress !== null
+
+  const canvas = useMemo(
+    () => ({
+      width: currentProject?.metadata.width ?? DEFAULT_PROJECT_WIDTH,
+      height: currentProject?.metadata.height ?? DEFAULT_PROJECT_HEIGHT,
+      fps: currentProject?.metadata.fps ?? DEFAULT_PROJECT_FPS,
+    }),
+    [currentProject],
+  )
+
+  const trackedItem = useMemo(() => items.find((item) => item.id === itemId), [itemId, items])
+
+  // Layers that are visible at some point during the tracked clip.
+  const targetItems = useMemo(() => {
+    if (!trackedItem) return []
+    const clipEnd = trackedItem.from + trackedItem.durationInFrames
+    return items.filter(
+      (item) =>
+        item.id !== trackedItem.id &&
+        isTrackTargetItem(item) &&
+        item.from < clipEnd &&
+        item.from + item.durationInFrames > trackedItem.from,
+    )
+  }, [items, trackedItem])
+
+  const targetItem = targetItems.find((item) => item.id === targetItemId)
+
+  useEffect(() => {
+    if (!isOpen) return
+    if (!targetItemId || !targetItems.some((item) => item.id === targetItemId)) {
+      setTargetItemId(targetItems[0]?.id ?? null)
+    }
+  }, [isOpen, targetItemId, targetItems])
+
+  // Start from the target's footprint whenever the target changes or moves.
+  useEffect(() => {
+    if (!trackedItem) return
+    setRegion(getDefaultTrackRegion(trackedItem, targetItem, canvas))
+  }, [canvas, targetItem, trackedItem])
+
+  // Track from the playhead (if inside the clip) to the end of the target overlap.
+  const range = useMemo(() => {
+    if (!trackedItem || !targetItem) return null
+    const playhead = usePlaybackStore.getState().currentFrame - trackedItem.from
+    const targetStart = targetItem.from - trackedItem.from
+    const targetEnd = targetStart + targetItem.durationInFrames
+    const start = Math.max(
+      0,
+      targetStart,
+      playhead >= 0 && playhead < trackedItem.durationInFrames ? playhead : 0,
+    )
+    const end = Math.min(trackedItem.durationInFrames, targetEnd)
+    return end - start >= 2 ? { start, end } : null
+  }, [targetItem, trackedItem])
+
+  const handleClose = useCallback(() => {
+    close()
+  }, [close])
+
+  const handlePanelClose = useCallback(() => {
+    if (!isTracking) {
+      handleClose()
+    }
+  }, [handleClose, isTracking])
+
+  const handleCancel = useCallback(() => {
+    if (isTracking) {
+      abortRef.current?.abort()
+      return
+    }
+    handleClose()
+  }, [handleClose, isTracking])
+
+  useEffect(() => {
+    if (!isOpen) return
+
+    const handleKeyDown = (event: KeyboardEvent) => {
+      if (event.key !== 'Escape' || isTracking) return
+      event.preventDefault()
+      event.stopPropagation()
+      handleClose()
+    }
+
+    window.addEventListener('keydown', handleKeyDown, { capture: true })
+    return () => window.removeEventListener('keydown', handleKeyDown, { capture: true })
+  }, [handleClose, isTracking, isOpen])
+
+  useEffect(() => () => abortRef.current?.abort(), [])
+
+  const handleTrack = useCallback(() => {
+    if (!trackedItem || !targetItem || !range) return
+
+    const run = async () => {
+      const abortController = new AbortController()
+      abortRef.current = abortController
+      setProgress(0)
+      try {
+        const fps = useTimelineSettingsStore.getState().fps
+        const track = await trackClipRegion(
+          trackedItem,
+          useKeyframesStore.getState().keyframesByItemId[trackedItem.id],
+          {
+            mode: settings.mode,
+            region: clampRegion(region),
+            startFrame: range.start,
+            endFrame: range.end,
+            fps,
+            onProgress: setProgress,
+            signal: abortController.signal,
+          },
+        )
+        if (abortController.signal.aborted) return
+
+        const plan = planMotionTrackKeyframes({
+          track,
+          trackedItem,
+          targetItem,
+          startFrame: range.start,
+          canvas,
+          options: settings,
+        })
+        const written = useTimelineStore.getState().applyMotionTrackKeyframes(plan)
+        if (written === 0) {
+          toast.info(t('timeline.motionTracking.toastNothingApplied'))
+          return
+        }
+
+        close()
+        toast.success(t('timeline.motionTracking.toastApplied', { count: track.samples.length }))
+      } catch (error) {
+        logger.warn('Motion tracking failed', error)
+        toast.error(
+          error instanceof Error ? error.message : t('timeline.motionTracking.toastFailed'),
+        )
+      } finally {
+        abortRef.current = null
+        setProgress(null)
+      }
+    }
+
+    void run()
+  }, [canvas, close, range, region, settings, t, targetItem, trackedItem])
+
+  if (!isOpen || !trackedItem) {
+    return null
+  }
+
+  const isPlanar = settings.mode === 'planar'
+  const usesTransform = settings.target === 'transform'
+
+  return (
+    <FloatingPanel
+      title={t('timeline.motionTracking.title')}
+      defaultBounds={MOTION_TRACKING_PANEL_DEFAULT_BOUNDS}
+      minWidth={340}
+      minHeight={360}
+      storageKey={MOTION_TRACKING_PANEL_STORAGE_KEY}
+      onClose={handlePanelClose}
+      resizable={false}
+      autoHeight
+      className="bg-background/95 backdrop-blur supports-[backdrop-filter]:bg-background/90"
+    >
+      <section
+        role="dialog"
+        aria-label={t('timeline.motionTracking.title')}
+        aria-modal="false"
+        className="flex flex-col"
+      >
+        <div className="space-y-4 p-3">
+          <div className="space-y-1.5">
+            <Label className="text-xs font-medium">{t('timeline.motionTracking.target')}</Label>
+            {targetItems.length > 0 ? (
+              <Select
+                value={targetItemId ?? undefined}
+                onValueChange={setTargetItemId}
+                disabled={isTracking}
+              >
+                <SelectTrigger className="h-8 text-xs">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {targetItems.map((item) => (
+                    <SelectItem key={item.id} value={item.id} className="text-xs">
+                      {item.type === 'shape' && item.isMask
+                        ? t('timeline.motionTracking.maskItem', { name: item.label })
+                        : item.label}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            ) : (
+              <p className="text-xs text-muted-foreground">
+                {t('timeline.motionTracking.noTargets')}
+              </p>
+            )}
+          </div>
+
+          <div className="grid grid-cols-2 gap-3">
+            <div className="space-y-1.5">
+              <Label className="text-xs font-medium">{t('timeline.motionTracking.mode')}</Label>
+              <Select
+                value={settings.mode}
+                onValueChange={(mode) =>
+                  setSettings({ ...settings, mode: mode as MotionTrackMode })
+                }
+                disabled={isTracking}
+              >
+                <SelectTrigger className="h-8 text-xs">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value="point" className="text-xs">
+                    {t('timeline.motionTracking.modePoint')}
+                  </SelectItem>
+                  <SelectItem value="planar" className="text-xs">
+                    {t('timeline.motionTracking.modePlanar')}
+                  </SelectItem>
+                </SelectContent>
+              </Select>
+            </div>
+
+            <div className="space-y-1.5">
+              <Label className="text-xs font-medium">{t('timeline.motionTracking.output')}</Label>
+              <Select
+                value={settings.target}
+                onValueChange={(target) =>
+                  setSettings({ ...settings, target: target as MotionTrackTarget })
+                }
+                disabled={isTracking}
+              >
+                <SelectTrigger className="h-8 text-xs">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  <SelectItem value="transform" className="text-xs">
+                    {t('timeline.motionTracking.outputTransform')}
+                  </SelectItem>
+                  <SelectItem value="cornerPin" className="text-xs">
+                    {t('timeline.motionTracking.outputCornerPin')}
+                  </SelectItem>
+                </SelectContent>
+              </Select>
+            </div>
+          </div>
+
+          {isPlanar && usesTransform && (
+            <div className="space-y-1 rounded-md border bg-muted/35 px-3 py-2">
+              <label className="flex items-center justify-between gap-3 text-xs">
+                <span>{t('timeline.motionTracking.includeRotation')}</span>
+                <Switch
+                  checked={settings.includeRotation}
+                  onCheckedChange={(includeRotation) =>
+                    setSettings({ ...settings, includeRotation })
+                  }
+                  disabled={isTracking}
+                />
+              </label>
+              <label className="flex items-center justify-between gap-3 text-xs">
+                <span>{t('timeline.motionTracking.includeScale')}</span>
+                <Switch
+                  checked={settings.includeScale}
+                  onCheckedChange={(includeScale) => setSettings({ ...settings, includeScale })}
+                  disabled={isTracking}
+                />
+              </label>
+            </div>
+          )}
+
+          <div className="space-y-1.5">
+            <Label className="text-xs font-medium">{t('timeline.motionTracking.region')}</Label>
+            <div className="grid grid-cols-4 gap-2">
+              {REGION_FIELDS.map(({ key, labelKey }) => (
+                <div key={key} className="space-y-1">
+                  <Label
+                    htmlFor={`motion-track-region-${key}`}
+                    className="text-[11px] text-muted-foreground"
+                  >
+                    {t(`timeline.motionTracking.${labelKey}`)}
+                  </Label>
+                  <Input
+                    id={`motion-track-region-${key}`}
+                    type="number"
+                    min={0}
+                    max={100}
+                    step={1}
+                    value={Math.round(region[key] * 1000) / 10}
+                    onChange={(event) => {
+                      const value = Number(event.target.value)
+                      if (!Number.isFinite(value)) return
+                      setRegion((current) => ({ ...current, [key]: value / 100 }))
+                    }}
+                    disabled={isTracking}
+                    className="h-7 px-2 text-right text-xs"
+                  />
+                </div>
+              ))}
+            </div>
+            {range && (
+              <p className="text-xs text-muted-foreground">
+                {t('timeline.motionTracking.rangeHint', { start: range.start, end: range.end })}
+              </p>
+            )}
+          </div>
+        </div>
+
+        <div className="flex flex-col-reverse gap-2 border-t border-border bg-secondary/10 p-3 sm:flex-row sm:justify-end">
+          <Button variant="ghost" size="sm" onClick={handleCancel}>
+            {t('common.cancel')}
+          </Button>
+          <Button size="sm" onClick={handleTrack} disabled={isTracking || !targetItem || !range}>
+            {isTracking
+              ? t('timeline.motionTracking.tracking', { percent: Math.round(progress ?? 0) })
+              : t('timeline.motionTracking.track')}
+          </Button>
+        </div>
+      </section>
+    </FloatingPanel>
+  )
+}
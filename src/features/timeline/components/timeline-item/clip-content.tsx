@@ -6,6 +6,7 @@ import { useSettingsStore } from '@/features/timeline/deps/settings'
 import { useMediaLibraryStore } from '@/features/timeline/deps/media-library-store'
 import { useCompositionsStore } from '../../stores/compositions-store'
 import { useItemsStore } from '../../stores/items-store'
+import { useKeyframesStore } from '../../stores/keyframes-store'
 import { useClipVisibility } from '../../hooks/use-clip-visibility'
 import { useZoomStore } from '../../stores/zoom-store'
 import { EDITOR_LAYOUT_CSS_VALUES } from '@/config/editor-layout'
@@ -15,7 +16,13 @@ import {
   summarizeCompositionClipContent,
   type CompositionVisualSegment,
 } from '../../utils/composition-clip-summary'
+import {
+  getTimeRemapSourceRange,
+  resolveTimeRemapCurve,
+  sampleTimeRemap,
+} from '@/features/timeline/deps/keyframes'
 import { hasLinkedAudioCompanion } from '@/shared/utils/linked-media'
+import { getMulticamCutRanges } from '@/shared/utils/multicam'
 import { formatSignedFrameDelta } from '@/shared/utils/time-utils'
 import { isGifUrl, isWebpUrl } from '@/shared/utils/media-utils'
 
@@ -164,6 +171,7 @@ interface ClipContentProps {
  * - Video: 2-row layout — label | filmstrip
  * - Audio: Label row + waveform
  * - Composition (with video): Label | filmstrip | waveform
+ * - Multicam: Label | filmstrip per angle cut
  * - Text: Text content preview
  * - Adjustment: Effects summary
  * - Image/Shape: Simple label
@@ -284,6 +292,29 @@ export const ClipContent = memo(function ClipContent({
       compositionById,
     })
   }, [item, fps, composition, compositionById])
+  // Multicam cuts reuse the compound filmstrip segments, one per visible angle cut
+  const multicamCuts = useMemo(() => {
+    if (item.type !== 'multicam') return []
+    const clockStart = item.sourceStart ?? 0
+    return getMulticamCutRanges(item, fps).map((range) => {
+      const clockSeconds = (clockStart + range.from - item.from) / fps
+      const segment: CompositionVisualSegment = {
+        itemId: `${range.segmentId}-${range.from}`,
+        mediaId: range.angle.mediaId,
+        sourceStart: Math.max(
+          0,
+          Math.round((clockSeconds - range.angle.syncOffset) * range.angle.sourceFps),
+        ),
+        sourceDurationFrames: range.angle.sourceDuration,
+        sourceFps: range.angle.sourceFps,
+        speed: 1,
+        from: range.from - item.from,
+        durationInFrames: range.durationInFrames,
+        trackOrder: 0,
+      }
+      return { segment, angleNumber: item.angles.indexOf(range.angle) + 1 }
+    })
+  }, [item, fps])
   const showCompositionWaveform =
     showWaveforms && compositionSummary.hasOwnedAudio && !hasCompositionAudioCompanion
   const linkedSyncOffsetLabel =
@@ -379,6 +410,25 @@ export const ClipContent = memo(function ClipContent({
   const trimStart = (item.trimStart ?? 0) / fps
   const speed = item.speed ?? 1
   const isReversed = item.isReversed === true
+
+  // Remapped clips show the frame the curve lands on under each tile.
+  const itemKeyframes = useKeyframesStore((s) => s.keyframesByItemId[item.id])
+  const timeRemapCurve = useMemo(
+    () => resolveTimeRemapCurve(item, itemKeyframes, fps),
+    [item, itemKeyframes, fps],
+  )
+  const timeRemapSourceRange = useMemo(
+    () =>
+      timeRemapCurve ? getTimeRemapSourceRange(timeRemapCurve, 0, item.durationInFrames) : null,
+    [timeRemapCurve, item.durationInFrames],
+  )
+  const filmstripSourceTimeAt = useMemo(
+    () =>
+      timeRemapCurve
+        ? (clipSeconds: number) => sampleTimeRemap(timeRemapCurve, clipSeconds * fps)
+        : undefined,
+    [timeRemapCurve, fps],
+  )
   const compoundClipTimelineFps = composition?.fps ?? fps
   const compoundClipSourceDuration = compositionSourceDurationFrames / compoundClipTimelineFps
   const compoundClipSourceStart = compositionSourceStartFrames / compoundClipTimelineFps
@@ -426,12 +476,13 @@ export const ClipContent = memo(function ClipContent({
                   mediaId={item.mediaId}
                   clipWidth={clipWidth}
                   renderWidth={renderWidth}
-                  sourceStart={sourceStart}
-                  sourceEnd={sourceEnd}
+                  sourceStart={timeRemapSourceRange?.start ?? sourceStart}
+                  sourceEnd={timeRemapSourceRange?.end ?? sourceEnd}
                   sourceDuration={sourceDuration}
-                  trimStart={trimStart}
+                  trimStart={timeRemapSourceRange ? 0 : trimStart}
                   speed={speed}
                   isReversed={isReversed}
+                  sourceTimeAt={filmstripSourceTimeAt}
                   fps={fps}
                   isVisible={clipVisibility.isVisible}
                   visibleStartRatio={clipVisibility.visibleStartRatio}
@@ -618,6 +669,57 @@ export const ClipContent = memo(function ClipContent({
     )
   }
 
+  // Multicam item - filmstrip per angle cut with the angle number on each cut
+  if (item.type === 'multicam') {
+    return (
+      <div className="absolute inset-0 flex flex-col">
+        <div
+          className="flex items-center gap-1.5 px-2 text-[11px] font-medium truncate shrink-0"
+          style={{
+            height: EDITOR_LAYOUT_CSS_VALUES.timelineClipLabelRowHeight,
+            lineHeight: EDITOR_LAYOUT_CSS_VALUES.timelineClipLabelRowHeight,
+          }}
+        >
+          <span className="rounded bg-sky-950/40 px-1.5 text-[9px] font-semibold uppercase tracking-[0.08em] text-sky-100/90">
+            {`Multicam · ${item.angles.length}`}
+          </span>
+          <div className="min-w-0 flex-1">{renderTitleText(item.label)}</div>
+        </div>
+        {showVisualContent && (
+          <div className="relative overflow-hidden flex-1 min-h-0">
+            {showVideoFilmstrips &&
+              multicamCuts.map(({ segment }) => (
+                <CompositionFilmstripSegment
+                  key={segment.itemId}
+                  segment={segment}
+                  wrapperDurationFrames={item.durationInFrames}
+                  wrapperClipWidthPx={clipWidth}
+                  wrapperRenderWidthPx={renderWidth}
+                  wrapperVisibleStartRatio={clipVisibility.visibleStartRatio}
+                  wrapperVisibleEndRatio={clipVisibility.visibleEndRatio}
+                  wrapperIsVisible={clipVisibility.isVisible}
+                  fps={fps}
+                  pixelsPerSecond={pixelsPerSecond}
+                  preferImmediateRendering={preferImmediateRendering}
+                />
+              ))}
+            {multicamCuts.map(({ segment, angleNumber }) => (
+              <div
+                key={`${segment.itemId}-label`}
+                className="absolute top-0.5 rounded-sm bg-black/60 px-1 text-[9px] font-semibold text-white/90 pointer-events-none"
+                style={{
+                  left: `calc(${(segment.from / Math.max(1, item.durationInFrames)) * 100}% + 2px)`,
+                }}
+              >
+                {angleNumber}
+              </div>
+            ))}
+          </div>
+        )}
+      </div>
+    )
+  }
+
   // Subtitle segment - label + cue count + first-cue snippet so the strip is
   // skimmable without expanding into a full-text preview that won't fit.
   if (item.type === 'subtitle') {
@@ -18,6 +18,7 @@ const SLIP_SLIDE_ITEM_TYPES: ReadonlySet<TimelineItem['type']> = new Set([
   'video',
   'audio',
   'composition',
+  'multicam',
 ])
 
 /**
@@ -579,6 +579,8 @@ export const TimelineItem = memo(function TimelineItem({
         return 'bg-purple-500/30 border-purple-400'
       case 'composition':
         return 'bg-violet-600/40 border-violet-400'
+      case 'multicam':
+        return 'bg-sky-700/40 border-sky-400'
       default:
         return 'bg-timeline-video border-timeline-video'
     }
@@ -634,6 +636,7 @@ export const TimelineItem = memo(function TimelineItem({
     hasSpeakableText,
     isSceneDetectionActive,
     isCompositionItem,
+    isMulticamItem,
     handleJoinSelected,
     handleJoinLeft,
     handleJoinRight,
@@ -651,9 +654,13 @@ export const TimelineItem = memo(function TimelineItem({
     handleCreatePreComp,
     handleEnterComposition,
     handleDissolveComposition,
+    handleCreateMulticam,
+    handleFlattenMulticam,
     handleDetectScenes,
     handleRemoveSilence,
     handleRemoveFillers,
+    handleAutoDuck,
+    handleTrackMotion,
     isRemovingSilence,
     isRemovingFillers,
   } = useTimelineItemActions({
@@ -882,6 +889,11 @@ export const TimelineItem = memo(function TimelineItem({
             (item.type === 'video' || item.type === 'audio') && !!item.mediaId && !isBroken,
           isRemovingFillers,
           onRemoveFillers: handleRemoveFillers,
+          canAutoDuck:
+            (item.type === 'video' || item.type === 'audio') && !!item.mediaId && !isBroken,
+          onAutoDuck: handleAutoDuck,
+          canTrackMotion: item.type === 'video' && !!item.mediaId && !isBroken,
+          onTrackMotion: handleTrackMotion,
         }}
         captionActions={{
           canManageCaptions: caption.canManageCaptions,
@@ -891,6 +903,8 @@ export const TimelineItem = memo(function TimelineItem({
           onOpenCaptionDialog: caption.openDialog,
           canExtractEmbeddedSubtitles: caption.canExtractEmbeddedSubtitles,
           onExtractEmbeddedSubtitles: caption.handleExtractEmbeddedSubtitles,
+          canImportSubtitleFile: caption.canImportSubtitleFile,
+          onImportSubtitleFile: caption.handleImportSubtitleFile,
           canConsolidateCaptionsToSegment: caption.hasConsolidatablePerCueCaptions,
           onConsolidateCaptionsToSegment: caption.handleConsolidateCaptionsToSegment,
         }}
@@ -900,6 +914,10 @@ export const TimelineItem = memo(function TimelineItem({
           onDissolveComposition: handleDissolveComposition,
           canCreatePreComp: isSelected,
           onCreatePreComp: handleCreatePreComp,
+          isMulticamItem,
+          canCreateMulticam: isSelected && item.type === 'video',
+          onCreateMulticam: handleCreateMulticam,
+          onFlattenMulticam: handleFlattenMulticam,
         }}
         sceneDetectionActions={{
           canDetectScenes: item.type === 'video' && !!item.mediaId && !isBroken,
@@ -73,9 +73,11 @@ type CaptionActionsProps = ItemContextMenuSectionProps & {
   hasCaptions?: boolean
   isGeneratingCaptions?: boolean
   canExtractEmbeddedSubtitles?: boolean
+  canImportSubtitleFile?: boolean
   canConsolidateCaptionsToSegment?: boolean
   onOpenCaptionDialog?: () => void
   onExtractEmbeddedSubtitles?: () => void
+  onImportSubtitleFile?: () => void
   onConsolidateCaptionsToSegment?: () => void
 }
 
@@ -85,6 +87,10 @@ type CompositionActionsProps = ItemContextMenuSectionProps & {
   onEnterComposition?: () => void
   onDissolveComposition?: () => void
   onCreatePreComp?: () => void
+  isMulticamItem?: boolean
+  canCreateMulticam?: boolean
+  onCreateMulticam?: (syncMethod: 'audio' | 'timecode') => void
+  onFlattenMulticam?: () => void
 }
 
 type MediaActionsProps = ItemContextMenuSectionProps & {
@@ -96,11 +102,15 @@ type MediaActionsProps = ItemContextMenuSectionProps & {
   isRemovingSilence?: boolean
   canRemoveFillers?: boolean
   isRemovingFillers?: boolean
+  canAutoDuck?: boolean
+  canTrackMotion?: boolean
   isTextItem?: boolean
   onReverse?: () => void
   onFreezeFrame?: () => void
   onRemoveSilence?: () => void
   onRemoveFillers?: () => void
+  onAutoDuck?: () => void
+  onTrackMotion?: () => void
   onGenerateAudioFromText?: () => void
 }
 
@@ -504,11 +514,15 @@ function MediaActions({
   isRemovingSilence,
   canRemoveFillers,
   isRemovingFillers,
+  canAutoDuck,
+  canTrackMotion,
   isTextItem,
   onReverse,
   onFreezeFrame,
   onRemoveSilence,
   onRemoveFillers,
+  onAutoDuck,
+  onTrackMotion,
   onGenerateAudioFromText,
 }: MediaActionsProps) {
   return (
@@ -554,6 +568,24 @@ function MediaActions({
         </>
       )}
 
+      {canAutoDuck && onAutoDuck && (
+        <>
+          <ContextMenuItem onClick={onAutoDuck}>
+            {t('timeline.contextMenu.autoDuck')}
+          </ContextMenuItem>
+          <ContextMenuSeparator />
+        </>
+      )}
+
+      {canTrackMotion && onTrackMotion && (
+        <>
+          <ContextMenuItem onClick={onTrackMotion}>
+            {t('timeline.contextMenu.trackMotion')}
+          </ContextMenuItem>
+          <ContextMenuSeparator />
+        </>
+      )}
+
       {isTextItem && onGenerateAudioFromText && (
         <>
           <ContextMenuItem onClick={onGenerateAudioFromText}>
@@ -610,9 +642,11 @@ function CaptionActions({
   hasCaptions,
   isGeneratingCaptions,
   canExtractEmbeddedSubtitles,
+  canImportSubtitleFile,
   canConsolidateCaptionsToSegment,
   onOpenCaptionDialog,
   onExtractEmbeddedSubtitles,
+  onImportSubtitleFile,
   onConsolidateCaptionsToSegment,
 }: CaptionActionsProps) {
   const captionActionLabel = hasCaptions
@@ -641,6 +675,15 @@ function CaptionActions({
         </>
       )}
 
+      {canImportSubtitleFile && onImportSubtitleFile && (
+        <>
+          <ContextMenuItem onClick={onImportSubtitleFile}>
+            {t('timeline.contextMenu.importSubtitleFile')}
+          </ContextMenuItem>
+          <ContextMenuSeparator />
+        </>
+      )}
+
       {canConsolidateCaptionsToSegment && onConsolidateCaptionsToSegment && (
         <>
           <ContextMenuItem onClick={onConsolidateCaptionsToSegment}>
@@ -660,10 +703,16 @@ function CompositionActions({
   onEnterComposition,
   onDissolveComposition,
   onCreatePreComp,
+  isMulticamItem,
+  canCreateMulticam,
+  onCreateMulticam,
+  onFlattenMulticam,
 }: CompositionActionsProps) {
   const hasCompositionActions =
     (isCompositionItem && (onEnterComposition || onDissolveComposition)) ||
-    (canCreatePreComp && onCreatePreComp)
+    (canCreatePreComp && onCreatePreComp) ||
+    (canCreateMulticam && onCreateMulticam) ||
+    (isMulticamItem && onFlattenMulticam)
 
   if (!hasCompositionActions) return null
 
@@ -684,6 +733,24 @@ function CompositionActions({
           {t('timeline.contextMenu.createCompoundClip')}
         </ContextMenuItem>
       )}
+      {canCreateMulticam && onCreateMulticam && (
+        <ContextMenuSub>
+          <ContextMenuSubTrigger>{t('timeline.contextMenu.createMulticam')}</ContextMenuSubTrigger>
+          <ContextMenuSubContent className="w-48">
+            <ContextMenuItem onClick={() => onCreateMulticam('audio')}>
+              {t('timeline.contextMenu.syncByAudio')}
+            </ContextMenuItem>
+            <ContextMenuItem onClick={() => onCreateMulticam('timecode')}>
+              {t('timeline.contextMenu.syncByTimecode')}
+            </ContextMenuItem>
+          </ContextMenuSubContent>
+        </ContextMenuSub>
+      )}
+      {isMulticamItem && onFlattenMulticam && (
+        <ContextMenuItem onClick={onFlattenMulticam}>
+          {t('timeline.contextMenu.flattenMulticam')}
+        </ContextMenuItem>
+      )}
       <ContextMenuSeparator />
     </>
   )
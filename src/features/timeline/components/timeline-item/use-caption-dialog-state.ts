@@ -1,5 +1,6 @@
 import { useCallback, useEffect, useMemo, useRef, useState } from 'react'
 import type { TimelineItem as TimelineItemType } from '@/types/timeline'
+import { SUBTITLE_FILE_ACCEPT } from '@/shared/utils/subtitle-files'
 import { useTimelineStore } from '../../stores/timeline-store'
 import { useMediaLibraryStore } from '@/features/timeline/deps/media-library-store'
 import {
@@ -36,6 +37,7 @@ type TranscriptProgress = NonNullable<
 export interface CaptionDialogState {
   canManageCaptions: boolean
   canExtractEmbeddedSubtitles: boolean
+  canImportSubtitleFile: boolean
   hasConsolidatablePerCueCaptions: boolean
   mediaHasTranscript: boolean
   transcriptStatus: string
@@ -50,6 +52,7 @@ export interface CaptionDialogState {
   markCaptionEnded: () => void
   markCaptionStopRequested: () => void
   handleExtractEmbeddedSubtitles: (() => Promise<void>) | undefined
+  handleImportSubtitleFile: (() => void) | undefined
   handleConsolidateCaptionsToSegment: (() => Promise<void>) | undefined
 }
 
@@ -156,6 +159,46 @@ export function useCaptionDialogState({
     }
   }, [mediaForItem])
 
+  const canImportSubtitleFile = !!(
+    mediaForItem &&
+    !isBroken &&
+    (item.type === 'video' || item.type === 'audio')
+  )
+
+  const handleImportSubtitleFile = useCallback(() => {
+    if (!mediaForItem) return
+    const input = document.createElement('input')
+    input.type = 'file'
+    input.accept = SUBTITLE_FILE_ACCEPT
+    input.onchange = async () => {
+      const file = input.files?.[0]
+      if (!file) return
+      const mediaStore = useMediaLibraryStore.getState()
+      try {
+        const { subtitleSidecarService } =
+          await import('@/features/timeline/deps/subtitle-sidecar-service')
+        const result = await subtitleSidecarService.importSubtitleFile(mediaForItem, file)
+        const summary =
+          result.insertedItemCount > 0
+            ? `Imported ${result.cueCount} subtitle${result.cueCount === 1 ? '' : 's'} from "${file.name}".`
+            : `No cues in "${file.name}" fell inside the clip's range.`
+        mediaStore.showNotification?.({
+          type: result.warnings.length > 0 ? 'warning' : 'success',
+          message:
+            result.warnings.length > 0
+              ? `${summary} Notes: ${result.warnings.join('; ')}.`
+              : summary,
+        })
+      } catch (error) {
+        mediaStore.showNotification?.({
+          type: 'error',
+          message: error instanceof Error ? error.message : `Failed to import "${file.name}".`,
+        })
+      }
+    }
+    input.click()
+  }, [mediaForItem])
+
   const hasConsolidatablePerCueCaptions = useTimelineStore(
     useCallback(
       (s) =>
@@ -216,6 +259,7 @@ export function useCaptionDialogState({
   return {
     canManageCaptions,
     canExtractEmbeddedSubtitles,
+    canImportSubtitleFile,
     hasConsolidatablePerCueCaptions,
     mediaHasTranscript,
     transcriptStatus,
@@ -232,6 +276,7 @@ export function useCaptionDialogState({
     handleExtractEmbeddedSubtitles: canExtractEmbeddedSubtitles
       ? handleExtractEmbeddedSubtitles
       : undefined,
+    handleImportSubtitleFile: canImportSubtitleFile ? handleImportSubtitleFile : undefined,
     handleConsolidateCaptionsToSegment: hasConsolidatablePerCueCaptions
       ? handleConsolidateCaptionsToSegment
       : undefined,
@@ -159,7 +159,10 @@ export function useSmartTrimHover({
           height: rect.height,
           labelRowHeight: getTimelineClipLabelRowHeightPx(e.currentTarget),
           isMediaItem:
-            item.type === 'video' || item.type === 'audio' || item.type === 'composition',
+            item.type === 'video' ||
+            item.type === 'audio' ||
+            item.type === 'composition' ||
+            item.type === 'multicam',
           currentIntent: smartBodyIntentRef.current,
         })
         if (smartBodyIntentRef.current !== nextBodyIntent) {
@@ -30,14 +30,19 @@ import {
   unlinkItems,
 } from '../../stores/actions/item-actions'
 import { createPreComp, dissolvePreComp } from '../../stores/actions/composition-actions'
+import { createMulticamFromItems, flattenMulticam } from '../../stores/actions/multicam-actions'
+import type { MulticamSyncMethod } from '../../utils/multicam-sync'
 import {
   type TimelineItemOverlay,
   useTimelineItemOverlayStore,
 } from '../../stores/timeline-item-overlay-store'
 import { useSilenceRemovalDialogStore } from '../../stores/silence-removal-dialog-store'
 import { useFillerRemovalDialogStore } from '../../stores/filler-removal-dialog-store'
+import { useAutoDuckingDialogStore } from '../../stores/auto-ducking-dialog-store'
+import { useMotionTrackingDialogStore } from '../../stores/motion-tracking-dialog-store'
 import { canJoinMultipleItems } from '../../utils/clip-utils'
 import { canLinkSelection, hasLinkedItems } from '../../utils/linked-items'
+import { isAudioVideoItem } from '../../utils/removal-preview-overlays'
 import {
   getSceneVerificationModelLabel,
   importSceneDetection,
@@ -339,6 +344,36 @@ export function useTimelineItemActions({
     dissolvePreComp(item.id)
   }, [isCompositionItem, item.id])
 
+  const isMulticamItem = item.type === 'multicam'
+
+  const handleCreateMulticam = useCallback((syncMethod: MulticamSyncMethod) => {
+    // Capture selection synchronously - syncing runs before the item is created.
+    const ids = useSelectionStore.getState().selectedItemIds
+    const toastId = toast.loading(i18n.t('timeline.multicam.syncing'))
+    void createMulticamFromItems(ids, syncMethod)
+      .then((created) => {
+        if (created) {
+          toast.success(i18n.t('timeline.multicam.created', { count: created.angles.length }), {
+            id: toastId,
+          })
+        } else {
+          toast.error(i18n.t('timeline.multicam.createFailed'), { id: toastId })
+        }
+      })
+      .catch((error) => {
+        logger.error('Failed to create multicam clip:', error)
+        toast.error(i18n.t('timeline.multicam.createFailed'), { id: toastId })
+      })
+  }, [])
+
+  const handleFlattenMulticam = useCallback(() => {
+    if (!isMulticamItem) {
+      return
+    }
+
+    flattenMulticam(item.id)
+  }, [isMulticamItem, item.id])
+
   const sceneDetectionAbortRef = useRef<AbortController | null>(null)
   const [isRemovingSilence, setIsRemovingSilence] = useState(false)
   const [isRemovingFillers, setIsRemovingFillers] = useState(false)
@@ -601,6 +636,29 @@ export function useTimelineItemActions({
     void run()
   }, [item.id])
 
+  const handleAutoDuck = useCallback(() => {
+    const { tracks, itemsByTrackId } = useItemsStore.getState()
+    // Every other track carrying audio is treated as dialogue to start with.
+    const dialogueTrackIds = tracks
+      .filter(
+        (track) =>
+          track.id !== item.trackId &&
+          !track.isGroup &&
+          !track.muted &&
+          (itemsByTrackId[track.id] ?? []).some(isAudioVideoItem),
+      )
+      .map((track) => track.id)
+
+    useAutoDuckingDialogStore.getState().open({
+      musicTrackIds: [item.trackId],
+      dialogueTrackIds,
+    })
+  }, [item.trackId])
+
+  const handleTrackMotion = useCallback(() => {
+    useMotionTrackingDialogStore.getState().open({ itemId: item.id })
+  }, [item.id])
+
   return {
     getCanJoinSelected,
     getCanLinkSelected,
@@ -610,6 +668,7 @@ export function useTimelineItemActions({
     isRemovingSilence,
     isRemovingFillers,
     isCompositionItem,
+    isMulticamItem,
     handleJoinSelected,
     handleJoinLeft,
     handleJoinRight,
@@ -627,8 +686,12 @@ export function useTimelineItemActions({
     handleCreatePreComp,
     handleEnterComposition,
     handleDissolveComposition,
+    handleCreateMulticam,
+    handleFlattenMulticam,
     handleDetectScenes,
     handleRemoveSilence,
     handleRemoveFillers,
+    handleAutoDuck,
+    handleTrackMotion,
   }
 }
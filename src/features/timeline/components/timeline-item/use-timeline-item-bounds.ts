@@ -174,7 +174,10 @@ export function useTimelineItemBounds({
       nextItem.type === 'composition' || (nextItem.type === 'audio' && !!nextItem.compositionId)
 
     const supportsStartTrimSourceShift =
-      previewBaseItem.type === 'video' || previewBaseItem.type === 'audio' || isCompositionWrapper
+      previewBaseItem.type === 'video' ||
+      previewBaseItem.type === 'audio' ||
+      previewBaseItem.type === 'multicam' ||
+      isCompositionWrapper
     if (supportsStartTrimSourceShift && previewStartTrimDelta !== 0) {
       const sourceFramesDelta = timelineToSourceFrames(
         previewStartTrimDelta,
@@ -281,14 +281,22 @@ export function useTimelineItemPointerHandlers({
             height: rect.height,
             labelRowHeight: getTimelineClipLabelRowHeightPx(e.currentTarget),
             isMediaItem:
-              item.type === 'video' || item.type === 'audio' || item.type === 'composition',
+              item.type === 'video' ||
+              item.type === 'audio' ||
+              item.type === 'composition' ||
+              item.type === 'multicam',
             currentIntent: smartBodyIntent,
           })
         }
       }
 
       if (activeTool === 'trim-edit' && !trackLocked && bodyIntentAtPointer) {
-        if (item.type === 'video' || item.type === 'audio' || item.type === 'composition') {
+        if (
+          item.type === 'video' ||
+          item.type === 'audio' ||
+          item.type === 'composition' ||
+          item.type === 'multicam'
+        ) {
           handleSlipSlideStart(e, bodyIntentAtPointer === 'slide-body' ? 'slide' : 'slip', {
             activateOnMoveThreshold: true,
           })
@@ -298,7 +306,12 @@ export function useTimelineItemPointerHandlers({
 
       // Slip/Slide tool: initiate on clip body for media items
       if ((activeTool === 'slip' || activeTool === 'slide') && !trackLocked) {
-        if (item.type === 'video' || item.type === 'audio' || item.type === 'composition') {
+        if (
+          item.type === 'video' ||
+          item.type === 'audio' ||
+          item.type === 'composition' ||
+          item.type === 'multicam'
+        ) {
           handleSlipSlideStart(e, activeTool)
         } else {
           setPointerHint({
@@ -18,8 +18,10 @@ import {
   RotateCcw,
   Scissors,
   Search,
+  TextSelect,
   Trash2,
   Undo2,
+  Users,
   X,
 } from 'lucide-react'
 import { toast } from 'sonner'
@@ -31,7 +33,7 @@ import { useSelectionStore } from '@/shared/state/selection'
 import { usePlaybackStore } from '@/shared/state/playback'
 import { useClipboardStore } from '@/shared/state/clipboard'
 import { useEditorStore } from '@/shared/state/editor'
-import type { MediaTranscript } from '@/types/storage'
+import type { MediaTranscript, MediaTranscriptSpeaker } from '@/types/storage'
 import { useItemsStore } from '../../stores/items-store'
 import { useTimelineSettingsStore } from '../../stores/timeline-settings-store'
 import { useTimelineStore } from '../../stores/timeline-store'
@@ -47,10 +49,11 @@ import {
   runMediaTranscriptionJob,
 } from '../../deps/media-transcription-service'
 import {
-  buildRemovalRangesByMediaId,
+  buildRemovalRangesForRuns,
   buildTranscriptTokens,
   findActiveTokenIndex,
   getSelectedTokenSlice,
+  getSpeakerTokenRuns,
   isTranscriptableItem,
   type TranscriptToken,
 } from '../../utils/transcript-edit-model'
@@ -84,6 +87,12 @@ interface MediaEntry {
   transcript?: MediaTranscript
 }
 
+/** Every word of one diarized speaker, selected via "select all of speaker". */
+interface SpeakerSelection {
+  mediaId: string
+  speakerId: string
+}
+
 function hasWordTimings(
   transcript: MediaTranscript | null | undefined,
 ): transcript is MediaTranscript {
@@ -100,14 +109,18 @@ interface TranscriptSegment {
   indices: number[]
   /** True when this paragraph opens a new source clip (draws a divider). */
   isClipStart: boolean
+  mediaId: string
+  speakerId?: string
+  /** True when a different speaker than the previous paragraph's takes over. */
+  isSpeakerTurn: boolean
 }
 
 const SENTENCE_END = /[.?!]["')\]]?$/
 
 /**
  * Group the flat token stream into timestamped paragraphs. Breaks fall at clip
- * changes and real pauses; soft/hard word caps keep pause-less speech from
- * collapsing back into a wall.
+ * changes, speaker turns and real pauses; soft/hard word caps keep pause-less
+ * speech from collapsing back into a wall.
  */
 function buildSegments(
   tokens: readonly TranscriptToken[],
@@ -120,12 +133,14 @@ function buildSegments(
   tokens.forEach((token, index) => {
     const prev = index > 0 ? tokens[index - 1] : undefined
     const clipChange = !!prev && prev.itemId !== token.itemId
+    const speakerChange = !!prev && prev.speakerId !== token.speakerId
     const pause = !!prev && token.sourceStart - prev.sourceEnd >= PARAGRAPH_GAP_SECONDS
     const sentenceWrap =
       !!prev && wordCount >= SEGMENT_SOFT_MAX_WORDS && SENTENCE_END.test(prev.text)
     const overflow = wordCount >= SEGMENT_HARD_MAX_WORDS
 
-    if (!current || clipChange || pause || sentenceWrap || overflow) {
+    if (!current || clipChange || speakerChange || pause || sentenceWrap || overflow) {
+      const previousSegment: TranscriptSegment | null = current
       current = {
         key: token.key,
         startFrame: token.startFrame,
@@ -134,6 +149,14 @@ function buildSegments(
         lastIndex: index,
         indices: [index],
         isClipStart: clipChange,
+        mediaId: token.mediaId,
+        speakerId: token.speakerId,
+        isSpeakerTurn:
+          !!token.speakerId &&
+          (previousSegment === null ||
+            clipChange ||
+            previousSegment.mediaId !== token.mediaId ||
+            previousSegment.speakerId !== token.speakerId),
       }
       segments.push(current)
       wordCount = 1
@@ -156,8 +179,11 @@ interface TranscriptSegmentRowProps {
   matchKeys: ReadonlySet<string>
   ignoredKeys: ReadonlySet<string>
   matchesApproximate: boolean
+  /** Speaker who opens this paragraph; only set on speaker turns. */
+  speaker?: MediaTranscriptSpeaker
   onSeek: (frame: number) => void
   onPointerDown: (index: number, event: ReactPointerEvent) => void
+  onSelectSpeaker: (mediaId: string, speakerId: string) => void
 }
 
 /**
@@ -174,8 +200,10 @@ const TranscriptSegmentRow = memo(function TranscriptSegmentRow({
   matchKeys,
   ignoredKeys,
   matchesApproximate,
+  speaker,
   onSeek,
   onPointerDown,
+  onSelectSpeaker,
 }: TranscriptSegmentRowProps) {
   const { t } = useTranslation()
   return (
@@ -202,39 +230,56 @@ const TranscriptSegmentRow = memo(function TranscriptSegmentRow({
         >
           {formatTimecode(segment.startSeconds)}
         </button>
-        <p className="text-[13px] leading-7">
-          {segment.indices.map((index) => {
-            const token = tokens[index]
-            if (!token) return null
-            const isActive = index === activeIndex
-            const isSelected = selectedKeys.has(token.key)
-            const isMatch = matchKeys.has(token.key)
-            const isIgnored = ignoredKeys.has(token.key)
-            return (
+        <div className="min-w-0">
+          {speaker && (
+            <button
+              type="button"
+              onPointerDown={(event) => event.stopPropagation()}
+              onClick={() => onSelectSpeaker(segment.mediaId, speaker.id)}
+              data-tooltip={t('transcript.selectSpeaker', { name: speaker.name })}
+              className="flex max-w-full select-none items-center gap-1.5 rounded pt-1 text-[11px] font-semibold text-muted-foreground transition-colors hover:text-foreground focus-visible:outline-none focus-visible:ring-1 focus-visible:ring-primary"
+            >
               <span
-                key={token.key}
-                data-token-key={token.key}
-                data-token-index={index}
-                onPointerDown={(event) => onPointerDown(index, event)}
-                className={cn(
-                  'cursor-text rounded px-0.5',
-                  isSelected
-                    ? 'bg-primary text-primary-foreground'
-                    : isActive
-                      ? 'bg-yellow-300 text-neutral-900 shadow-sm'
-                      : isMatch
-                        ? matchesApproximate
-                          ? 'text-foreground ring-1 ring-inset ring-amber-500/40'
-                          : 'text-foreground ring-1 ring-inset ring-amber-500/70'
-                        : 'text-foreground/85 hover:bg-secondary/60 hover:text-foreground',
-                  isIgnored && 'line-through decoration-from-font opacity-45',
-                )}
-              >
-                {token.text}{' '}
-              </span>
-            )
-          })}
-        </p>
+                className="h-2 w-2 shrink-0 rounded-full"
+                style={{ backgroundColor: speaker.color }}
+              />
+              <span className="truncate">{speaker.name}</span>
+            </button>
+          )}
+          <p className="text-[13px] leading-7">
+            {segment.indices.map((index) => {
+              const token = tokens[index]
+              if (!token) return null
+              const isActive = index === activeIndex
+              const isSelected = selectedKeys.has(token.key)
+              const isMatch = matchKeys.has(token.key)
+              const isIgnored = ignoredKeys.has(token.key)
+              return (
+                <span
+                  key={token.key}
+                  data-token-key={token.key}
+                  data-token-index={index}
+                  onPointerDown={(event) => onPointerDown(index, event)}
+                  className={cn(
+                    'cursor-text rounded px-0.5',
+                    isSelected
+                      ? 'bg-primary text-primary-foreground'
+                      : isActive
+                        ? 'bg-yellow-300 text-neutral-900 shadow-sm'
+                        : isMatch
+                          ? matchesApproximate
+                            ? 'text-foreground ring-1 ring-inset ring-amber-500/40'
+                            : 'text-foreground ring-1 ring-inset ring-amber-500/70'
+                          : 'text-foreground/85 hover:bg-secondary/60 hover:text-foreground',
+                    isIgnored && 'line-through decoration-from-font opacity-45',
+                  )}
+                >
+                  {token.text}{' '}
+                </span>
+              )
+            })}
+          </p>
+        </div>
       </div>
     </Fragment>
   )
@@ -265,6 +310,8 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
   const [mediaState, setMediaState] = useState<Record<string, MediaEntry>>({})
   const [anchorIndex, setAnchorIndex] = useState(-1)
   const [focusIndex, setFocusIndex] = useState(-1)
+  const [speakerSelection, setSpeakerSelection] = useState<SpeakerSelection | null>(null)
+  const [diarizingMediaIds, setDiarizingMediaIds] = useState<ReadonlySet<string>>(new Set())
   const [query, setQuery] = useState('')
   // -1 means "no match shown yet", so the first Next/Enter lands on match 0.
   const [matchCursor, setMatchCursor] = useState(-1)
@@ -282,6 +329,9 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
   // depending on `mediaState` (which would re-run it and cancel its own fetch).
   const requestedRef = useRef<Set<string>>(new Set())
   const mountedRef = useRef(true)
+  // mediaIds whose next change notification is our own speaker edit — we already hold
+  // the saved transcript, so skip the invalidate + re-fetch round trip for those.
+  const selfUpdatesRef = useRef<Set<string>>(new Set())
   // Mirror anchorIndex so handlePointerDown can read it without listing it as a
   // dependency — otherwise every selection click swaps the callback reference and
   // re-renders all TranscriptSegmentRows, defeating their React.memo.
@@ -326,10 +376,24 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
 
   const segments = useMemo(() => buildSegments(tokens, timelineFps), [tokens, timelineFps])
 
-  const selectedSlice = useMemo(
-    () => getSelectedTokenSlice(tokens, anchorIndex, focusIndex),
-    [tokens, anchorIndex, focusIndex],
+  // A speaker selection spans many disjoint runs; a drag selection is one run.
+  const selectedRuns = useMemo(
+    () =>
+      speakerSelection
+        ? getSpeakerTokenRuns(tokens, speakerSelection.mediaId, speakerSelection.speakerId)
+        : [getSelectedTokenSlice(tokens, anchorIndex, focusIndex)],
+    [tokens, speakerSelection, anchorIndex, focusIndex],
   )
+  const selectedSlice = useMemo(() => selectedRuns.flat(), [selectedRuns])
+
+  const speakersByMediaId = useMemo(() => {
+    const map: Record<string, Map<string, MediaTranscriptSpeaker>> = {}
+    for (const id of uniqueMediaIds) {
+      const speakers = mediaState[id]?.transcript?.speakers
+      if (speakers?.length) map[id] = new Map(speakers.map((speaker) => [speaker.id, speaker]))
+    }
+    return map
+  }, [uniqueMediaIds, mediaState])
   const selectedKeys = useMemo(
     () => new Set(selectedSlice.map((token) => token.key)),
     [selectedSlice],
@@ -377,6 +441,7 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
   useEffect(() => {
     setAnchorIndex(-1)
     setFocusIndex(-1)
+    setSpeakerSelection(null)
     setMatchCursor(0)
   }, [uniqueMediaIds, scope])
 
@@ -392,6 +457,7 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
   // nudge the load effect so it re-fetches the current state instead of the stale copy.
   useEffect(() => {
     return mediaTranscriptionService.onTranscriptChanged((mediaId) => {
+      if (selfUpdatesRef.current.delete(mediaId)) return
       if (!requestedRef.current.has(mediaId)) return
       requestedRef.current.delete(mediaId)
       setMediaState((prev) => {
@@ -467,6 +533,7 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
     (index: number, event: ReactPointerEvent) => {
       const token = tokens[index]
       if (!token) return
+      setSpeakerSelection(null)
       if (event.shiftKey && anchorIndexRef.current >= 0) {
         setFocusIndex(index)
       } else {
@@ -504,14 +571,14 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
   // than cutting the timeline. Re-striking an already-ignored selection restores it.
   const handleIgnoreToggle = useCallback(() => {
     if (selectedSlice.length === 0) return
-    const ranges = buildRemovalRangesByMediaId(selectedSlice)
+    const ranges = buildRemovalRangesForRuns(selectedRuns)
     const allIgnored = selectedSlice.every((token) => ignoredKeys.has(token.key))
     if (allIgnored) {
       useTranscriptIgnoreStore.getState().restore(ranges)
     } else {
       useTranscriptIgnoreStore.getState().ignore(ranges)
     }
-  }, [selectedSlice, ignoredKeys])
+  }, [selectedRuns, selectedSlice, ignoredKeys])
 
   // Word-level copy/cut that carries the media: each run of selected words
   // becomes a trimmed clone of its clip, placed on the shared clipboard so the
@@ -520,7 +587,9 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
   const handleCopyWords = useCallback(
     (cut: boolean) => {
       if (selectedSlice.length === 0) return
-      const clones = buildTranscriptClipboardItems(selectedSlice, itemById, timelineFps)
+      const clones = selectedRuns.flatMap((run) =>
+        buildTranscriptClipboardItems(run, itemById, timelineFps),
+      )
       if (clones.length === 0) return
 
       const currentFrame = usePlaybackStore.getState().currentFrame
@@ -537,7 +606,7 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
         return
       }
 
-      const rangesByMediaId = buildRemovalRangesByMediaId(selectedSlice)
+      const rangesByMediaId = buildRemovalRangesForRuns(selectedRuns)
       const itemIds = Array.from(new Set(selectedSlice.map((token) => token.itemId)))
       try {
         useTimelineStore.getState().removeTranscriptRangesFromItems(itemIds, rangesByMediaId)
@@ -548,9 +617,10 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
       }
       setAnchorIndex(-1)
       setFocusIndex(-1)
+      setSpeakerSelection(null)
       toast.success(t('transcript.toastCut', { defaultValue: 'Cut {{count}} words', count }))
     },
-    [selectedSlice, itemById, timelineFps, t],
+    [selectedRuns, selectedSlice, itemById, timelineFps, t],
   )
 
   // Bridge Ctrl+C / Ctrl+X to word-level copy/cut. The global clipboard hotkeys
@@ -595,6 +665,7 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
 
     setAnchorIndex(-1)
     setFocusIndex(-1)
+    setSpeakerSelection(null)
 
     if (!result || result.removedItemCount === 0) {
       toast.info(t('transcript.toastNothingRemoved'))
@@ -629,6 +700,7 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
       const token = tokens[span.start]
       if (!token) return
       // Select the whole matched run so a phrase jump highlights the phrase.
+      setSpeakerSelection(null)
       setAnchorIndex(span.start)
       setFocusIndex(span.end)
       seekToToken(token.startFrame)
@@ -663,6 +735,7 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
       } else if (event.key === 'Escape') {
         setAnchorIndex(-1)
         setFocusIndex(-1)
+        setSpeakerSelection(null)
       }
     },
     [selectedKeys.size, handleIgnoreToggle, ignoredSpanCount, handleApply],
@@ -717,6 +790,71 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
     )
   }, [uniqueMediaIds, mediaState, t])
 
+  const handleSelectSpeaker = useCallback((mediaId: string, speakerId: string) => {
+    setAnchorIndex(-1)
+    setFocusIndex(-1)
+    setSpeakerSelection({ mediaId, speakerId })
+    rootRef.current?.focus({ preventScroll: true })
+  }, [])
+
+  const diarizableMediaIds = uniqueMediaIds.filter((id) => mediaState[id]?.status === 'ready')
+  const hasSpeakers = Object.keys(speakersByMediaId).length > 0
+
+  // Identify who speaks when in every loaded transcript. Re-running replaces the
+  // previous labels (names and colors reset to the defaults).
+  const handleDiarize = useCallback(() => {
+    const targets = diarizableMediaIds.filter((id) => !diarizingMediaIds.has(id))
+    if (targets.length === 0) return
+
+    setSpeakerSelection(null)
+    setDiarizingMediaIds((prev) => new Set([...prev, ...targets]))
+
+    void Promise.all(
+      targets.map(async (mediaId) => {
+        try {
+          selfUpdatesRef.current.add(mediaId)
+          const transcript = await mediaTranscriptionService.diarizeTranscript(mediaId)
+          if (!mountedRef.current) return
+          setMediaState((prev) => ({ ...prev, [mediaId]: { status: 'ready', transcript } }))
+        } catch (error) {
+          selfUpdatesRef.current.delete(mediaId)
+          logger.warn('Speaker diarization failed', { mediaId, error })
+          toast.error(t('transcript.toastDiarizeFailed'))
+        } finally {
+          if (mountedRef.current) {
+            setDiarizingMediaIds((prev) => {
+              const next = new Set(prev)
+              next.delete(mediaId)
+              return next
+            })
+          }
+        }
+      }),
+    )
+  }, [diarizableMediaIds, diarizingMediaIds, t])
+
+  const handleUpdateSpeaker = useCallback(
+    (mediaId: string, speakerId: string, patch: Partial<Omit<MediaTranscriptSpeaker, 'id'>>) => {
+      const speakers = mediaState[mediaId]?.transcript?.speakers
+      if (!speakers) return
+      const next = speakers.map((speaker) =>
+        speaker.id === speakerId ? { ...speaker, ...patch } : speaker,
+      )
+      selfUpdatesRef.current.add(mediaId)
+      mediaTranscriptionService
+        .updateTranscriptSpeakers(mediaId, next)
+        .then((transcript) => {
+          if (!mountedRef.current) return
+          setMediaState((prev) => ({ ...prev, [mediaId]: { status: 'ready', transcript } }))
+        })
+        .catch((error: unknown) => {
+          selfUpdatesRef.current.delete(mediaId)
+          logger.warn('Failed to update transcript speakers', { mediaId, error })
+        })
+    },
+    [mediaState],
+  )
+
   const selectionCount = selectedKeys.size
 
   return (
@@ -813,6 +951,20 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
         )}
       </div>
 
+      {/* Speakers */}
+      {tokens.length > 0 && (
+        <TranscriptSpeakersBar
+          speakersByMediaId={speakersByMediaId}
+          hasSpeakers={hasSpeakers}
+          isDiarizing={diarizingMediaIds.size > 0}
+          canDiarize={diarizableMediaIds.length > 0}
+          onDiarize={handleDiarize}
+          onSelectSpeaker={handleSelectSpeaker}
+          onUpdateSpeaker={handleUpdateSpeaker}
+          t={t}
+        />
+      )}
+
       {/* Transcript body */}
       <div
         ref={scrollRef}
@@ -860,8 +1012,14 @@ export function TranscriptEditorPanel({ active }: TranscriptEditorPanelProps) {
                 matchKeys={matchKeys}
                 ignoredKeys={ignoredKeys}
                 matchesApproximate={matchesApproximate}
+                speaker={
+                  segment.isSpeakerTurn && segment.speakerId
+                    ? speakersByMediaId[segment.mediaId]?.get(segment.speakerId)
+                    : undefined
+                }
                 onSeek={seekToToken}
                 onPointerDown={handlePointerDown}
+                onSelectSpeaker={handleSelectSpeaker}
               />
             ))}
           </div>
@@ -1015,3 +1173,103 @@ function ScopeToggle({
     </div>
   )
 }
+
+function TranscriptSpeakersBar({
+  speakersByMediaId,
+  hasSpeakers,
+  isDiarizing,
+  canDiarize,
+  onDiarize,
+  onSelectSpeaker,
+  onUpdateSpeaker,
+  t,
+}: {
+  speakersByMediaId: Record<string, Map<string, MediaTranscriptSpeaker>>
+  hasSpeakers: boolean
+  isDiarizing: boolean
+  canDiarize: boolean
+  onDiarize: () => void
+  onSelectSpeaker: (mediaId: string, speakerId: string) => void
+  onUpdateSpeaker: (
+    mediaId: string,
+    speakerId: string,
+    patch: Partial<Omit<MediaTranscriptSpeaker, 'id'>>,
+  ) => void
+  t: (key: string, options?: Record<string, unknown>) => string
+}) {
+  return (
+    <div className="flex flex-col gap-1.5 border-b border-border p-2">
+      <div className="flex items-center justify-between gap-2">
+        <span className="text-[11px] font-medium uppercase tracking-wider text-muted-foreground">
+          {t('transcript.speakers')}
+        </span>
+        <Button
+          size="sm"
+          variant="ghost"
+          className="h-7 gap-1.5 px-2 text-xs text-muted-foreground"
+          onClick={onDiarize}
+          disabled={!canDiarize || isDiarizing}
+        >
+          {isDiarizing ? (
+            <Loader2 className="h-3.5 w-3.5 animate-spin" />
+          ) : (
+            <Users className="h-3.5 w-3.5" />
+          )}
+          {isDiarizing
+            ? t('transcript.identifyingSpeakers')
+            : hasSpeakers
+              ? t('transcript.reidentifySpeakers')
+              : t('transcript.identifySpeakers')}
+        </Button>
+      </div>
+      {Object.entries(speakersByMediaId).flatMap(([mediaId, speakers]) =>
+        Array.from(speakers.values(), (speaker) => (
+          <div key={`${mediaId}:${speaker.id}`} className="flex items-center gap-1.5">
+            <input
+              // Keyed by color so an external change resets the uncontrolled value.
+              key={speaker.color}
+              type="color"
+              defaultValue={speaker.color}
+              // Commit once the picker closes instead of saving every drag step.
+              onBlur={(event) => {
+                if (event.target.value !== speaker.color) {
+                  onUpdateSpeaker(mediaId, speaker.id, { color: event.target.value })
+                }
+              }}
+              aria-label={t('transcript.speakerColor')}
+              className="h-6 w-6 shrink-0 cursor-pointer rounded border border-border bg-transparent p-0.5"
+            />
+            <Input
+              key={speaker.name}
+              defaultValue={speaker.name}
+              onBlur={(event) => {
+                const name = event.target.value.trim()
+                if (!name) {
+                  event.target.value = speaker.name
+                  return
+                }
+                if (name !== speaker.name) onUpdateSpeaker(mediaId, speaker.id, { name })
+              }}
+              onKeyDown={(event) => {
+                if (event.key === 'Enter') event.currentTarget.blur()
+              }}
+              aria-label={t('transcript.renameSpeaker')}
+              className="h-7 min-w-0 flex-1 text-xs"
+            />
+            <Button
+              type="button"
+              variant="ghost"
+              size="icon"
+              className="h-7 w-7 shrink-0"
+              onClick={() => onSelectSpeaker(mediaId, speaker.id)}
+              aria-label={t('transcript.selectSpeaker', { name: speaker.name })}
+              data-tooltip={t('transcript.selectSpeaker', { name: speaker.name })}
+            >
+              <TextSelect className="h-3.5 w-3.5" />
+            </Button>
+          </div>
+        )),
+      )}
+    </div>
+  )
+}
@@ -13,6 +13,8 @@ export { useBentoLayoutDialogStore } from '../components/bento-layout-dialog-sto
 export { useReverseConformDialogStore } from '../stores/reverse-conform-dialog-store'
 export { useSilenceRemovalDialogStore } from '../stores/silence-removal-dialog-store'
 export { useFillerRemovalDialogStore } from '../stores/filler-removal-dialog-store'
+export { useAutoDuckingDialogStore } from '../stores/auto-ducking-dialog-store'
+export { useMotionTrackingDialogStore } from '../stores/motion-tracking-dialog-store'
 export { captureSnapshot } from '../stores/commands/snapshot'
 export { execute as executeTimelineCommand } from '../stores/actions/shared'
 export { Timeline } from '../components/timeline'
@@ -53,3 +55,5 @@ export const importBentoLayoutDialog = () => import('../components/bento-layout-
 export const importReverseConformDialog = () => import('../components/reverse-conform-dialog')
 export const importSilenceRemovalDialog = () => import('../components/silence-removal-dialog')
 export const importFillerRemovalDialog = () => import('../components/filler-removal-dialog')
+export const importAutoDuckingDialog = () => import('../components/auto-ducking-dialog')
+export const importMotionTrackingDialog = () => import('../components/motion-tracking-dialog')
@@ -39,4 +39,5 @@ export {
   collectSubCompositionMediaIds,
 } from '../utils/sub-composition-preview'
 export { createScrubThrottleState, shouldCommitScrubFrame } from '../utils/scrub-throttle'
+export { getItemContentRect, trackClipRegion } from '../utils/motion-tracking'
 export { useWaveform } from '../hooks/use-waveform'
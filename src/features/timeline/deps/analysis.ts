@@ -5,6 +5,7 @@ import {
 } from '@/shared/utils/scene-verification-models'
 
 export const importSceneDetection = () => import('@/infrastructure/analysis/scene-detection')
+export const importMotionTracking = () => import('@/infrastructure/analysis/motion-tracking')
 
 export function getSceneVerificationModelLabel(model: SceneVerificationModelId): string {
   return SCENE_VERIFICATION_MODEL_LABELS[model]
@@ -5,11 +5,27 @@
 
 export type { AutoKeyframeOperation } from '@/features/keyframes/utils/auto-keyframe'
 export { getCropPropertyValue } from '@/features/keyframes/utils/animated-crop-resolver'
-export { interpolatePropertyValue } from '@/features/keyframes/utils/interpolation'
+export {
+  getCornerPinPropertyValue,
+  isCornerPinAnimatableProperty,
+} from '@/features/keyframes/utils/animated-corner-pin-resolver'
+export {
+  getPropertyKeyframes,
+  interpolatePropertyValue,
+} from '@/features/keyframes/utils/interpolation'
 export {
   getTextAnimatableBaseValue,
   isTextAnimatableProperty,
 } from '@/features/keyframes/utils/animated-text-item'
+export {
+  createTimeRemapCurve,
+  getItemNaturalSourceSeconds,
+  getTimeRemapSourceRange,
+  hasTimeRemap,
+  resolveTimeRemapCurve,
+  sampleTimeRemap,
+} from '@/features/keyframes/utils/time-remap'
+export type { TimeRemapCurve } from '@/features/keyframes/utils/time-remap'
 export { getBezierPresetForEasing } from '@/features/keyframes/utils/easing-presets'
 export {
   getTransitionBlockedRanges,
@@ -0,0 +1,58 @@
+/**
+ * Multicam shortcuts: 1-9 switch the multicam clip under the playhead to that angle.
+ * While playing each press records a cut; while paused it replaces the current cut's angle.
+ */
+
+import { useHotkeys } from 'react-hotkeys-hook'
+import { usePlaybackStore } from '@/shared/state/playback'
+import { useSelectionStore } from '@/shared/state/selection'
+import { HOTKEY_OPTIONS } from '@/config/hotkeys'
+import type { MulticamItem } from '@/types/timeline'
+import { useItemsStore } from '../../stores/items-store'
+import { switchMulticamAngle } from '../../stores/actions/multicam-actions'
+import { useResolvedHotkeys } from '@/features/timeline/deps/settings'
+
+/** Multicam item at `frame`, preferring a selected one when clips stack. */
+function getMulticamItemAtFrame(frame: number): MulticamItem | null {
+  const { items } = useItemsStore.getState()
+  const selectedIds = new Set(useSelectionStore.getState().selectedItemIds)
+  let fallback: MulticamItem | null = null
+  for (const item of items) {
+    if (item.type !== 'multicam') continue
+    if (frame < item.from || frame >= item.from + item.durationInFrames) continue
+    if (selectedIds.has(item.id)) return item
+    fallback ??= item
+  }
+  return fallback
+}
+
+function useMulticamAngleHotkey(binding: string, angleIndex: number) {
+  useHotkeys(
+    binding,
+    (event) => {
+      const { currentFrame, previewFrame, isPlaying } = usePlaybackStore.getState()
+      const frame = isPlaying ? currentFrame : (previewFrame ?? currentFrame)
+      const item = getMulticamItemAtFrame(frame)
+      if (!item || angleIndex >= item.angles.length) return
+
+      event.preventDefault()
+      switchMulticamAngle(item.id, angleIndex, frame, isPlaying ? 'cut' : 'replace')
+    },
+    HOTKEY_OPTIONS,
+    [angleIndex],
+  )
+}
+
+export function useMulticamShortcuts() {
+  const hotkeys = useResolvedHotkeys()
+
+  useMulticamAngleHotkey(hotkeys.MULTICAM_ANGLE_1, 0)
+  useMulticamAngleHotkey(hotkeys.MULTICAM_ANGLE_2, 1)
+  useMulticamAngleHotkey(hotkeys.MULTICAM_ANGLE_3, 2)
+  useMulticamAngleHotkey(hotkeys.MULTICAM_ANGLE_4, 3)
+  useMulticamAngleHotkey(hotkeys.MULTICAM_ANGLE_5, 4)
+  useMulticamAngleHotkey(hotkeys.MULTICAM_ANGLE_6, 5)
+  useMulticamAngleHotkey(hotkeys.MULTICAM_ANGLE_7, 6)
+  useMulticamAngleHotkey(hotkeys.MULTICAM_ANGLE_8, 7)
+  useMulticamAngleHotkey(hotkeys.MULTICAM_ANGLE_9, 8)
+}
@@ -6,6 +6,7 @@ import { useInOutShortcuts } from './shortcuts/use-in-out-shortcuts'
 import { useUIShortcuts } from './shortcuts/use-ui-shortcuts'
 import { useClipboardShortcuts } from './shortcuts/use-clipboard-shortcuts'
 import { useSourceMonitorShortcuts } from './shortcuts/use-source-monitor-shortcuts'
+import { useMulticamShortcuts } from './shortcuts/use-multicam-shortcuts'
 
 export interface TimelineShortcutCallbacks {
   onPlay?: () => void
@@ -28,6 +29,7 @@ export interface TimelineShortcutCallbacks {
  * - In/Out markers (I, O, Shift+I/O, Alt+X)
  * - UI (S snap, Z zoom, undo/redo)
  * - Clipboard (Ctrl+C/X/V)
+ * - Multicam (1-9 angle switching)
  *
  * Note: Zoom is handled via Ctrl+Scroll only (see TimelineContent component)
  */
@@ -40,4 +42,5 @@ export function useTimelineShortcuts(callbacks: TimelineShortcutCallbacks = {})
   useUIShortcuts(callbacks)
   useClipboardShortcuts()
   useSourceMonitorShortcuts()
+  useMulticamShortcuts()
 }
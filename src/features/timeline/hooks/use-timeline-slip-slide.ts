@@ -763,7 +763,8 @@ export function useTimelineSlipSlide(
           continuitySourceDelta !== 0 &&
           (slidItemPreview.type === 'video' ||
             slidItemPreview.type === 'audio' ||
-            slidItemPreview.type === 'composition') &&
+            slidItemPreview.type === 'composition' ||
+            slidItemPreview.type === 'multicam') &&
           slidItemPreview.sourceEnd !== undefined
         ) {
           slidItemPreview = {
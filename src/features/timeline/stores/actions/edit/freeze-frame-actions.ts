@@ -1,4 +1,4 @@
-import type { ImageItem } from '@/types/timeline'
+import type { ImageItem, VideoItem } from '@/types/timeline'
 import { useItemsStore } from '../../items-store'
 import { useTransitionsStore } from '../../transitions-store'
 import { useTimelineSettingsStore } from '../../timeline-settings-store'
@@ -34,15 +34,6 @@ export async function insertFreezeFrame(itemId: string, playheadFrame: number):
     return false
   }
 
-  const fps = useTimelineSettingsStore.getState().fps
-  const speed = item.speed ?? 1
-  const sourceStart = item.sourceStart ?? 0
-  const sourceFps = item.sourceFps ?? fps
-
-  // Calculate source frame at playhead in source-native FPS
-  const timelineOffset = playheadFrame - itemStart
-  const sourceFrame = sourceStart + timelineToSourceFrames(timelineOffset, speed, fps, sourceFps)
-
   // Get media metadata for resolution and fps info
   const media = item.mediaId ? useMediaLibraryStore.getState().mediaById[item.mediaId] : undefined
   if (!media) {
@@ -50,9 +41,8 @@ export async function insertFreezeFrame(itemId: string, playheadFrame: number):
     return false
   }
 
-  // Calculate timestamp in seconds for frame extraction
-  const mediaFps = media.fps || 30
-  const timestampSeconds = sourceFrame / mediaFps
+  const fps = useTimelineSettingsStore.getState().fps
+  const timestampSeconds = getFreezeFrameSourceSeconds(item, playheadFrame, fps, media.fps)
 
   try {
     const { mediaLibraryService } = await importMediaLibraryService()
@@ -65,52 +55,9 @@ export async function insertFreezeFrame(itemId: string, playheadFrame: number):
     }
 
     // Step 2: Extract frame using mediabunny at native resolution
-    const { Input, BlobSource, CanvasSink, ALL_FORMATS } = await import('mediabunny')
-    const input = new Input({
-      source: new BlobSource(blob as File),
-      formats: ALL_FORMATS,
-    })
-
-    const videoTrack = await input.getPrimaryVideoTrack()
-    if (!videoTrack) {
-      input.dispose()
-      getLogger().error('[insertFreezeFrame] No video track found')
-      return false
-    }
-
-    const frameWidth = videoTrack.displayWidth
-    const frameHeight = videoTrack.displayHeight
-
-    const sink = new CanvasSink(videoTrack, {
-      width: frameWidth,
-      height: frameHeight,
-      fit: 'fill',
-    })
-
-    const wrapped = await sink.getCanvas(timestampSeconds)
-    if (!wrapped) {
-      ;(sink as unknown as { dispose?: () => void }).dispose?.()
-      input.dispose()
-      getLogger().error('[insertFreezeFrame] Failed to extract frame')
-      return false
-    }
-
-    const canvas = wrapped.canvas as OffscreenCanvas | HTMLCanvasElement
-    let frameBlob: Blob
-    if ('convertToBlob' in canvas) {
-      frameBlob = await canvas.convertToBlob({ type: 'image/png' })
-    } else {
-      frameBlob = await new Promise<Blob>((resolve, reject) => {
-        canvas.toBlob(
-          (b) => (b ? resolve(b) : reject(new Error('Failed to create blob'))),
-          'image/png',
-        )
-      })
-    }
-
-    // Clean up mediabunny resources
-    ;(sink as unknown as { dispose?: () => void }).dispose?.()
-    input.dispose()
+    const captured = await captureVideoFrame(blob, timestampSeconds)
+    if (!captured) return false
+    const { blob: frameBlob, width: frameWidth, height: frameHeight } = captured
 
     // Step 3: Persist the frame as a media item. Delegates to the shared
     // import path (mediaLibraryService -> persistGeneratedMediaAsset) which
@@ -125,7 +72,7 @@ export async function insertFreezeFrame(itemId: string, playheadFrame: number):
       return false
     }
 
-    const fileName = `freeze-frame-${item.label || 'video'}-${Math.round(timestampSeconds * 100) / 100}s.png`
+    const fileName = getFreezeFrameFileName(item.label, timestampSeconds)
     const frameFile = new File([frameBlob], fileName, {
       type: 'image/png',
       lastModified: Date.now(),
@@ -148,77 +95,13 @@ export async function insertFreezeFrame(itemId: string, playheadFrame: number):
     // Prepend the media item to the store only after execute() succeeds so a
     // failed _splitItem (e.g. the source clip was removed between validation
     // and execute) doesn't leave an orphaned entry in the media library UI.
-    const freezeDurationFrames = Math.round(fps * 2) // 2 seconds
-
-    const success = execute<boolean>(
-      'INSERT_FREEZE_FRAME',
-      (): boolean => {
-        // Split the video at playhead
-        const splitResult = useItemsStore.getState()._splitItem(itemId, playheadFrame)
-        if (!splitResult) {
-          getLogger().error('[insertFreezeFrame] Split failed')
-          return false
-        }
-
-        const { leftItem, rightItem } = splitResult
-
-        // Update transitions pointing to split item
-        const transitions = useTransitionsStore.getState().transitions
-        const updatedTransitions = transitions.map((t) => {
-          if (t.leftClipId === itemId) {
-            return { ...t, leftClipId: rightItem.id }
-          }
-          return t
-        })
-        useTransitionsStore.getState().setTransitions(updatedTransitions)
-
-        // Create ImageItem for the freeze frame
-        const freezeFrameItem: ImageItem = {
-          id: crypto.randomUUID(),
-          type: 'image',
-          trackId: item.trackId,
-          from: playheadFrame,
-          durationInFrames: freezeDurationFrames,
-          label: fileName,
-          mediaId: frameMediaId,
-          src: frameBlobUrl,
-          sourceWidth: frameWidth,
-          sourceHeight: frameHeight,
-          transform: item.transform ? { ...item.transform } : undefined,
-        }
-
-        useItemsStore.getState()._addItem(freezeFrameItem)
-
-        // Shift the right half forward by freeze frame duration
-        const newRightFrom = rightItem.from + freezeDurationFrames
-        useItemsStore.getState()._moveItem(rightItem.id, newRightFrom)
-
-        // Also shift all items on same track that come after the right half
-        const allItems = useItemsStore.getState().items
-        const itemsToShift = allItems.filter(
-          (i) =>
-            i.trackId === item.trackId &&
-            i.id !== rightItem.id &&
-            i.id !== leftItem.id &&
-            i.id !== freezeFrameItem.id &&
-            i.from > playheadFrame,
-        )
-
-        for (const shiftItem of itemsToShift) {
-          useItemsStore.getState()._moveItem(shiftItem.id, shiftItem.from + freezeDurationFrames)
-        }
-
-        // Repair transitions
-        applyTransitionRepairs([leftItem.id, rightItem.id])
-
-        // Select the freeze frame item
-        useSelectionStore.getState().selectItems([freezeFrameItem.id])
-
-        useTimelineSettingsStore.getState().markDirty()
-        return true
-      },
-      { itemId, playheadFrame, freezeDurationFrames },
-    )
+    const success = insertFreezeFrameStill(itemId, playheadFrame, {
+      mediaId: frameMediaId,
+      src: frameBlobUrl,
+      label: fileName,
+      width: frameWidth,
+      height: frameHeight,
+    })
 
     if (!success) {
       // Roll back the persisted media so a failed split (rare — only if the
@@ -251,3 +134,192 @@ export async function insertFreezeFrame(itemId: string, playheadFrame: number):
     return false
   }
 }
+
+/**
+ * Source time (seconds) shown at `playheadFrame` of a video clip. The source
+ * frame is computed in source-native fps, then converted with the media's fps.
+ */
+export function getFreezeFrameSourceSeconds(
+  item: VideoItem,
+  playheadFrame: number,
+  fps: number,
+  mediaFps: number | undefined,
+): number {
+  const sourceStart = item.sourceStart ?? 0
+  const sourceFps = item.sourceFps ?? fps
+  const timelineOffset = playheadFrame - item.from
+  const sourceFrame =
+    sourceStart + timelineToSourceFrames(timelineOffset, item.speed ?? 1, fps, sourceFps)
+  return sourceFrame / (mediaFps || 30)
+}
+
+/** `freeze-frame-{label}-{seconds}s.png`, the name freeze-frame stills are saved under. */
+export function getFreezeFrameFileName(
+  label: string | undefined,
+  timestampSeconds: number,
+): string {
+  return `freeze-frame-${label || 'video'}-${Math.round(timestampSeconds * 100) / 100}s.png`
+}
+
+/** A video frame captured as PNG at the source's display resolution. */
+export interface CapturedVideoFrame {
+  blob: Blob
+  width: number
+  height: number
+}
+
+/**
+ * Decode the frame at `timestampSeconds` from a video file and encode it as a
+ * PNG at native display resolution. Returns null when the file has no video
+ * track or the frame can't be decoded.
+ */
+export async function captureVideoFrame(
+  source: Blob,
+  timestampSeconds: number,
+): Promise<CapturedVideoFrame | null> {
+  const { Input, BlobSource, CanvasSink, ALL_FORMATS } = await import('mediabunny')
+  const input = new Input({
+    source: new BlobSource(source as File),
+    formats: ALL_FORMATS,
+  })
+
+  const videoTrack = await input.getPrimaryVideoTrack()
+  if (!videoTrack) {
+    input.dispose()
+    getLogger().error('[insertFreezeFrame] No video track found')
+    return null
+  }
+
+  const width = videoTrack.displayWidth
+  const height = videoTrack.displayHeight
+
+  const sink = new CanvasSink(videoTrack, {
+    width,
+    height,
+    fit: 'fill',
+  })
+
+  try {
+    const wrapped = await sink.getCanvas(timestampSeconds)
+    if (!wrapped) {
+      getLogger().error('[insertFreezeFrame] Failed to extract frame')
+      return null
+    }
+
+    const canvas = wrapped.canvas as OffscreenCanvas | HTMLCanvasElement
+    let blob: Blob
+    if ('convertToBlob' in canvas) {
+      blob = await canvas.convertToBlob({ type: 'image/png' })
+    } else {
+      blob = await new Promise<Blob>((resolve, reject) => {
+        canvas.toBlob(
+          (b) => (b ? resolve(b) : reject(new Error('Failed to create blob'))),
+          'image/png',
+        )
+      })
+    }
+    return { blob, width, height }
+  } finally {
+    // Clean up mediabunny resources
+    ;(sink as unknown as { dispose?: () => void }).dispose?.()
+    input.dispose()
+  }
+}
+
+/** An already-persisted still image to hold on. */
+export interface FreezeFrameStill {
+  mediaId: string
+  src: string
+  label: string
+  width?: number
+  height?: number
+}
+
+/**
+ * Timeline half of a freeze frame: split the video clip at `playheadFrame`,
+ * insert `still` as a 2-second image between the halves and push the rest of
+ * the track right. Runs as one undoable command. Callers that already have the
+ * frame as media (e.g. the headless editor) use this directly.
+ */
+export function insertFreezeFrameStill(
+  itemId: string,
+  playheadFrame: number,
+  still: FreezeFrameStill,
+): boolean {
+  const item = useItemsStore.getState().items.find((i) => i.id === itemId)
+  if (!item || item.type !== 'video') return false
+  if (playheadFrame <= item.from || playheadFrame >= item.from + item.durationInFrames) return false
+  if (isInTransitionOverlap(itemId, playheadFrame - item.from, item.durationInFrames)) return false
+
+  const freezeDurationFrames = Math.round(useTimelineSettingsStore.getState().fps * 2) // 2 seconds
+
+  return execute<boolean>(
+    'INSERT_FREEZE_FRAME',
+    (): boolean => {
+      // Split the video at playhead
+      const splitResult = useItemsStore.getState()._splitItem(itemId, playheadFrame)
+      if (!splitResult) {
+        getLogger().error('[insertFreezeFrame] Split failed')
+        return false
+      }
+
+      const { leftItem, rightItem } = splitResult
+
+      // Update transitions pointing to split item
+      const transitions = useTransitionsStore.getState().transitions
+      const updatedTransitions = transitions.map((t) => {
+        if (t.leftClipId === itemId) {
+          return { ...t, leftClipId: rightItem.id }
+        }
+        return t
+      })
+      useTransitionsStore.getState().setTransitions(updatedTransitions)
+
+      // Create ImageItem for the freeze frame
+      const freezeFrameItem: ImageItem = {
+        id: crypto.randomUUID(),
+        type: 'image',
+        trackId: item.trackId,
+        from: playheadFrame,
+        durationInFrames: freezeDurationFrames,
+        label: still.label,
+        mediaId: still.mediaId,
+        src: still.src,
+        sourceWidth: still.width,
+        sourceHeight: still.height,
+        transform: item.transform ? { ...item.transform } : undefined,
+      }
+
+      useItemsStore.getState()._addItem(freezeFrameItem)
+
+      // Shift the right half forward by freeze frame duration
+      const newRightFrom = rightItem.from + freezeDurationFrames
+      useItemsStore.getState()._moveItem(rightItem.id, newRightFrom)
+
+      // Also shift all items on same track that come after the right half
+      const allItems = useItemsStore.getState().items
+      const itemsToShift = allItems.filter(
+        (i) =>
+          i.trackId === item.trackId &&
+          i.id !== rightItem.id &&
+          i.id !== leftItem.id &&
+          i.id !== freezeFrameItem.id &&
+          i.from > playheadFrame,
+      )
+
+      for (const shiftItem of itemsToShift) {
+        useItemsStore.getState()._moveItem(shiftItem.id, shiftItem.from + freezeDurationFrames)
+      }
+
+      // Repair transitions
+      applyTransitionRepairs([leftItem.id, rightItem.id])
+
+      // Select the freeze frame item
+      useSelectionStore.getState().selectItems([freezeFrameItem.id])
+
+      useTimelineSettingsStore.getState().markDirty()
+      return true
+    },
+    { itemId, playheadFrame, freezeDurationFrames },
+  )
+}
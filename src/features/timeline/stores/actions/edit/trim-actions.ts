@@ -394,7 +394,13 @@ export function slipItem(id: string, slipDelta: number): void {
       const items = itemsStore.items
       const item = items.find((i) => i.id === id)
       if (!item) return
-      if (item.type !== 'video' && item.type !== 'audio' && item.type !== 'composition') return
+      if (
+        item.type !== 'video' &&
+        item.type !== 'audio' &&
+        item.type !== 'composition' &&
+        item.type !== 'multicam'
+      )
+        return
       const synchronizedItems = getSynchronizedLinkedItemsForEdit(
         items,
         id,
@@ -536,7 +542,10 @@ export function slideItem(
       itemsStore._moveItem(id, item.from + clampedSlideDelta)
       if (
         continuitySourceDelta !== 0 &&
-        (item.type === 'video' || item.type === 'audio' || item.type === 'composition') &&
+        (item.type === 'video' ||
+          item.type === 'audio' ||
+          item.type === 'composition' ||
+          item.type === 'multicam') &&
         item.sourceEnd !== undefined
       ) {
         itemsStore._updateItem(id, {
@@ -0,0 +1,63 @@
+import { beforeEach, describe, expect, it } from 'vite-plus/test'
+import {
+  makeTimelineVideoItem,
+  resetTimelineItemsTestState,
+  setDefaultRootTimelineTracks,
+} from '@/features/timeline/test-helpers'
+import { useItemsStore } from '../items-store'
+import { getFreezeFrameSourceSeconds, insertFreezeFrameStill } from './item-actions'
+
+const STILL = {
+  mediaId: 'still-1',
+  src: 'blob:still',
+  label: 'still.png',
+  width: 1920,
+  height: 1080,
+}
+
+describe('insertFreezeFrameStill', () => {
+  beforeEach(() => {
+    resetTimelineItemsTestState()
+    setDefaultRootTimelineTracks()
+  })
+
+  it('splits the clip, holds the still for two seconds and pushes the rest of the track', () => {
+    useItemsStore
+      .getState()
+      .setItems([
+        makeTimelineVideoItem({ id: 'clip', from: 0, durationInFrames: 60 }),
+        makeTimelineVideoItem({ id: 'next', from: 60, durationInFrames: 30 }),
+      ])
+
+    expect(insertFreezeFrameStill('clip', 30, STILL)).toBe(true)
+
+    const items = useItemsStore.getState().items.toSorted((a, b) => a.from - b.from)
+    expect(items.map((item) => [item.type, item.from, item.durationInFrames])).toEqual([
+      ['video', 0, 30],
+      ['image', 30, 60],
+      ['video', 90, 30],
+      ['video', 120, 30],
+    ])
+    expect(items[1]).toMatchObject({ mediaId: 'still-1', sourceWidth: 1920, label: 'still.png' })
+    expect(items[3]!.id).toBe('next')
+  })
+
+  it('refuses frames on the clip edges or non-video items', () => {
+    useItemsStore.getState().setItems([makeTimelineVideoItem({ id: 'clip' })])
+
+    expect(insertFreezeFrameStill('clip', 0, STILL)).toBe(false)
+    expect(insertFreezeFrameStill('clip', 60, STILL)).toBe(false)
+    expect(insertFreezeFrameStill('missing', 30, STILL)).toBe(false)
+    expect(useItemsStore.getState().items).toHaveLength(1)
+  })
+})
+
+describe('getFreezeFrameSourceSeconds', () => {
+  it('maps the playhead through source start, speed and source fps', () => {
+    const item = makeTimelineVideoItem({ from: 30, sourceStart: 60, sourceFps: 60, speed: 2 })
+
+    // 15 timeline frames at 30fps, 2x speed → 60 source frames past sourceStart.
+    expect(getFreezeFrameSourceSeconds(item, 45, 30, 60)).toBe(2)
+    expect(getFreezeFrameSourceSeconds(item, 45, 30, undefined)).toBe(4)
+  })
+})
@@ -26,4 +26,11 @@ export {
   rateStretchItem,
   resetSpeedWithRipple,
 } from './edit/rate-stretch-actions'
-export { insertFreezeFrame } from './edit/freeze-frame-actions'
+export type { CapturedVideoFrame, FreezeFrameStill } from './edit/freeze-frame-actions'
+export {
+  captureVideoFrame,
+  getFreezeFrameFileName,
+  getFreezeFrameSourceSeconds,
+  insertFreezeFrame,
+  insertFreezeFrameStill,
+} from './edit/freeze-frame-actions'
@@ -11,10 +11,12 @@ import {
   addKeyframe,
   addKeyframes,
   applyAutoKeyframeOperations,
+  applyMotionTrackKeyframes,
   removeKeyframe,
   removeKeyframes,
   removeKeyframesForItem,
   removeKeyframesForProperty,
+  replaceVolumeKeyframes,
   updateKeyframe,
 } from './keyframe-actions'
 
@@ -192,6 +194,102 @@ describe('keyframe actions', () => {
     })
   })
 
+  describe('replaceVolumeKeyframes', () => {
+    it('swaps the volume envelope in one undo block and skips transition regions', () => {
+      useTransitionsStore.getState().setTransitions([makeFade()])
+      addKeyframe('a', 'volume', 5, -6)
+      addKeyframe('a', 'opacity', 5, 0.5)
+      const undoDepth = useTimelineCommandStore.getState().undoStack.length
+
+      const changed = replaceVolumeKeyframes([
+        {
+          itemId: 'a',
+          keyframes: [
+            { frame: 10, value: 0 },
+            { frame: 20, value: -12 },
+            { frame: 58, value: -12 },
+          ],
+        },
+      ])
+
+      expect(changed).toBe(1)
+      expect(getKeyframes('a', 'volume').map(({ frame, value }) => [frame, value])).toEqual([
+        [10, 0],
+        [20, -12],
+      ])
+      expect(getKeyframes('a', 'opacity')).toHaveLength(1)
+      expect(useTimelineCommandStore.getState().undoStack.length).toBe(undoDepth + 1)
+
+      useTimelineCommandStore.getState().undo()
+      expect(getKeyframes('a', 'volume').map(({ frame, value }) => [frame, value])).toEqual([
+        [5, -6],
+      ])
+    })
+
+    it('keeps auto-ducking marks until the user edits the value', () => {
+      replaceVolumeKeyframes([
+        {
+          itemId: 'a',
+          keyframes: [
+            { frame: 20, value: -12, duckBase: null },
+            { frame: 30, value: -18, duckBase: -6 },
+          ],
+        },
+      ])
+      expect(getKeyframes('a', 'volume').map((keyframe) => keyframe.duckBase)).toEqual([null, -6])
+
+      const lowered = getKeyframes('a', 'volume')[1]!
+      updateKeyframe('a', 'volume', lowered.id, { easing: 'ease-in' })
+      expect(getKeyframes('a', 'volume')[1]?.duckBase).toBe(-6)
+      updateKeyframe('a', 'volume', lowered.id, { value: -9 })
+      expect(getKeyframes('a', 'volume')[1]).not.toHaveProperty('duckBase')
+    })
+  })
+
+  describe('applyMotionTrackKeyframes', () => {
+    it('replaces tracked properties and sets the base corner pin in one undo block', () => {
+      addKeyframe('a', 'cornerPinTopLeftX', 5, 3)
+      const undoDepth = useTimelineCommandStore.getState().undoStack.length
+      const cornerPin = {
+        topLeft: [8, -8] as [number, number],
+        topRight: [-8, -8] as [number, number],
+        bottomRight: [-8, 8] as [number, number],
+        bottomLeft: [8, 8] as [number, number],
+        referenceWidth: 400,
+        referenceHeight: 200,
+      }
+
+      const written = applyMotionTrackKeyframes({
+        itemId: 'a',
+        cornerPin,
+        properties: [
+          {
+            property: 'cornerPinTopLeftX',
+            keyframes: [
+              { frame: 0, value: 8 },
+              { frame: 1, value: 12 },
+            ],
+          },
+          { property: 'cornerPinTopLeftY', keyframes: [{ frame: 0, value: -8 }] },
+        ],
+      })
+
+      expect(written).toBe(3)
+      expect(
+        getKeyframes('a', 'cornerPinTopLeftX').map(({ frame, value }) => [frame, value]),
+      ).toEqual([
+        [0, 8],
+        [1, 12],
+      ])
+      expect(useItemsStore.getState().itemById.a?.cornerPin).toEqual(cornerPin)
+      expect(useTimelineCommandStore.getState().undoStack.length).toBe(undoDepth + 1)
+
+      useTimelineCommandStore.getState().undo()
+      expect(getKeyframes('a', 'cornerPinTopLeftX').map(({ frame }) => frame)).toEqual([5])
+      expect(useItemsStore.getState().itemById.a?.cornerPin).toBeUndefined()
+    })
+  })
+
   describe('removal', () => {
     it('removeKeyframe deletes a single keyframe', () => {
       const id = addKeyframe('a', 'opacity', 10, 0.5)
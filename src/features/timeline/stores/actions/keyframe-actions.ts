@@ -5,6 +5,8 @@
 import type { AnimatableProperty, EasingType, Keyframe, KeyframeRef } from '@/types/keyframe'
 import type { KeyframeAddPayload, KeyframeUpdatePayload } from '../keyframes-store'
 import type { AutoKeyframeOperation } from '@/features/timeline/deps/keyframes'
+import type { MotionTrackKeyframePlan } from '../../utils/motion-tracking'
+import { useItemsStore } from '../items-store'
 import { useKeyframesStore } from '../keyframes-store'
 import { useTimelineSettingsStore } from '../timeline-settings-store'
 import { execute, getLogger, canAddKeyframeAtFrame } from './shared'
@@ -177,6 +179,87 @@ export function applyAutoKeyframeOperations(operations: AutoKeyframeOperation[])
   )
 }
 
+/**
+ * Replace each item's volume automation with a generated envelope (e.g.
+ * auto-ducking) in a single undo block. Points in transition regions are
+ * dropped; auto-ducking marks are kept. Returns how many items changed.
+ */
+export function replaceVolumeKeyframes(
+  envelopes: Array<{
+    itemId: string
+    keyframes: Array<{ frame: number; value: number; duckBase?: number | null }>
+  }>,
+): number {
+  if (envelopes.length === 0) return 0
+
+  return execute(
+    'REPLACE_VOLUME_KEYFRAMES',
+    () => {
+      const keyframesStore = useKeyframesStore.getState()
+      let changed = 0
+
+      for (const { itemId, keyframes } of envelopes) {
+        const payloads = keyframes
+          .filter((keyframe) => canAddKeyframeAtFrame(itemId, keyframe.frame))
+          .map((keyframe) => ({
+            itemId,
+            property: 'volume' as const,
+            frame: keyframe.frame,
+            value: keyframe.value,
+            ...(keyframe.duckBase !== undefined && { duckBase: keyframe.duckBase }),
+          }))
+        keyframesStore._removeKeyframesForProperty(itemId, 'volume')
+        keyframesStore._addKeyframes(payloads)
+        changed += 1
+      }
+
+      if (changed > 0) {
+        useTimelineSettingsStore.getState().markDirty()
+      }
+      return changed
+    },
+    { count: envelopes.length },
+  )
+}
+
+/**
+ * Replace the keyframes of every property in a motion-track plan (and set the
+ * base corner pin for corner-pin targets) as one undo step.
+ * Returns the number of keyframes written.
+ */
+export function applyMotionTrackKeyframes(plan: MotionTrackKeyframePlan): number {
+  if (plan.properties.every((entry) => entry.keyframes.length === 0)) return 0
+
+  return execute(
+    'APPLY_MOTION_TRACK_KEYFRAMES',
+    () => {
+      const keyframesStore = useKeyframesStore.getState()
+      if (plan.cornerPin) {
+        useItemsStore.getState()._updateItem(plan.itemId, { cornerPin: plan.cornerPin })
+      }
+
+      let written = 0
+      for (const { property, keyframes } of plan.properties) {
+        const payloads = keyframes
+          .filter((keyframe) => canAddKeyframeAtFrame(plan.itemId, keyframe.frame))
+          .map((keyframe) => ({
+            itemId: plan.itemId,
+            property,
+            frame: keyframe.frame,
+            value: keyframe.value,
+          }))
+        keyframesStore._removeKeyframesForProperty(plan.itemId, property)
+        keyframesStore._addKeyframes(payloads)
+        written += payloads.length
+      }
+
+      useTimelineSettingsStore.getState().markDirty()
+      return written
+    },
+    { itemId: plan.itemId, properties: plan.properties.map((entry) => entry.property) },
+  )
+}
+
 export function removeKeyframe(
   itemId: string,
   property: AnimatableProperty,
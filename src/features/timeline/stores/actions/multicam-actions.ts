@@ -0,0 +1,348 @@
+/**
+ * Multicam Actions — build multicam clips from synced angles, cut between
+ * angles, and flatten the cut list back into ordinary clips.
+ */
+
+import type { MediaMetadata } from '@/types/storage'
+import type {
+  MulticamAngle,
+  MulticamAngleSegment,
+  MulticamItem,
+  TimelineItem,
+  VideoItem,
+} from '@/types/timeline'
+import type { ItemKeyframes } from '@/types/keyframe'
+import {
+  expandMulticamItem,
+  expandMulticamKeyframes,
+  getMulticamClockFrame,
+  getMulticamSegmentIndexAtClockFrame,
+} from '@/shared/utils/multicam'
+import { useSelectionStore } from '@/shared/state/selection'
+import { useMediaLibraryStore } from '@/features/timeline/deps/media-library-store'
+import {
+  createClassicTrack,
+  findNearestTrackByKind,
+  getAdjacentTrackOrder,
+} from '../../utils/classic-tracks'
+import { expandSelectionWithLinkedItems } from '../../utils/linked-items'
+import { computeMulticamSyncOffsets, type MulticamSyncMethod } from '../../utils/multicam-sync'
+import { DEFAULT_TRACK_HEIGHT } from '../../constants'
+import { useItemsStore } from '../items-store'
+import { useTransitionsStore } from '../transitions-store'
+import { useKeyframesStore } from '../keyframes-store'
+import { useTimelineSettingsStore } from '../timeline-settings-store'
+import { execute, getLogger } from './shared'
+
+function isMulticamSourceItem(item: TimelineItem | undefined): item is VideoItem {
+  return item?.type === 'video' && !!item.mediaId
+}
+
+/** Whether the given items can be combined into a multicam clip. */
+export function canCreateMulticam(itemIds: string[]): boolean {
+  const { itemById } = useItemsStore.getState()
+  return itemIds.filter((id) => isMulticamSourceItem(itemById[id])).length >= 2
+}
+
+/** Merge neighbouring segments that select the same angle. */
+function mergeAngleSegments(segments: MulticamAngleSegment[]): MulticamAngleSegment[] {
+  const merged: MulticamAngleSegment[] = []
+  for (const segment of segments) {
+    if (merged[merged.length - 1]?.angleId === segment.angleId) continue
+    merged.push(segment)
+  }
+  return merged
+}
+
+function buildAngle(item: VideoItem, media: MediaMetadata, syncOffset: number): MulticamAngle {
+  const sourceFps = media.fps || item.sourceFps || 30
+  return {
+    id: crypto.randomUUID(),
+    label: media.fileName || item.label,
+    mediaId: media.id,
+    src: item.src,
+    sourceFps,
+    sourceDuration: item.sourceDuration ?? Math.round(media.duration * sourceFps),
+    sourceWidth: media.width || item.sourceWidth,
+    sourceHeight: media.height || item.sourceHeight,
+    syncOffset,
+  }
+}
+
+/**
+ * Combine the selected video clips into one multicam clip. Angles are synced
+ * by audio cross-correlation or recording timecode, the clip keeps the
+ * selection's span, and it starts on the earliest clip's angle.
+ */
+export async function createMulticamFromItems(
+  itemIds: string[],
+  syncMethod: MulticamSyncMethod,
+): Promise<MulticamItem | null> {
+  const { itemById } = useItemsStore.getState()
+  const sourceItems = itemIds
+    .map((id) => itemById[id])
+    .filter(isMulticamSourceItem)
+    .sort((left, right) => left.from - right.from)
+  if (sourceItems.length < 2) return null
+
+  const { mediaById } = useMediaLibraryStore.getState()
+  const medias = sourceItems.map((item) => mediaById[item.mediaId!])
+  if (medias.some((media) => !media)) {
+    getLogger().warn('[createMulticamFromItems] Missing media metadata for angle')
+    return null
+  }
+
+  const syncOffsets = await computeMulticamSyncOffsets(medias as MediaMetadata[], syncMethod)
+
+  return execute(
+    'CREATE_MULTICAM',
+    () => {
+      const { items, itemById: currentById, tracks } = useItemsStore.getState()
+      // The selection may have changed while syncing
+      if (sourceItems.some((item) => !currentById[item.id])) return null
+
+      const fps = useTimelineSettingsStore.getState().fps
+      const angles = sourceItems.map((item, index) =>
+        buildAngle(item, medias[index]!, syncOffsets[index] ?? 0),
+      )
+
+      const reference = sourceItems[0]!
+      const referenceAngle = angles[0]!
+      const minFrom = reference.from
+      const maxEnd = Math.max(...sourceItems.map((item) => item.from + item.durationInFrames))
+      // Keep the earliest clip's picture where it was on the timeline
+      const referenceSeconds =
+        (reference.sourceStart ?? 0) / referenceAngle.sourceFps + referenceAngle.syncOffset
+      const clockStart = Math.max(0, Math.round(referenceSeconds * fps))
+      const clockDuration = Math.max(
+        ...angles.map((angle) =>
+          Math.floor((angle.syncOffset + angle.sourceDuration / angle.sourceFps) * fps),
+        ),
+      )
+      const durationInFrames = Math.max(1, Math.min(clockDuration - clockStart, maxEnd - minFrom))
+
+      const removedIds = expandSelectionWithLinkedItems(
+        items,
+        sourceItems.map((item) => item.id),
+      )
+      const removedIdSet = new Set(removedIds)
+
+      let nextTracks = tracks
+      let targetTrackId = reference.trackId
+      const isRangeFree = items.every(
+        (item) =>
+          removedIdSet.has(item.id) ||
+          item.trackId !== targetTrackId ||
+          item.from >= minFrom + durationInFrames ||
+          item.from + item.durationInFrames <= minFrom,
+      )
+      if (!isRangeFree) {
+        const referenceTrack = tracks.find((track) => track.id === reference.trackId)
+        const createdTrack = createClassicTrack({
+          tracks: nextTracks,
+          kind: 'video',
+          order: referenceTrack ? getAdjacentTrackOrder(nextTracks, referenceTrack, 'above') : 0,
+          height: referenceTrack?.height ?? DEFAULT_TRACK_HEIGHT,
+        })
+        nextTracks = [...nextTracks, createdTrack]
+        targetTrackId = createdTrack.id
+      }
+
+      useItemsStore.getState()._removeItems(removedIds)
+      useTransitionsStore
+        .getState()
+        .setTransitions(
+          useTransitionsStore
+            .getState()
+            .transitions.filter(
+              (t) => !removedIdSet.has(t.leftClipId) && !removedIdSet.has(t.rightClipId),
+            ),
+        )
+      useKeyframesStore.getState()._removeKeyframesForItems(removedIds)
+      if (nextTracks !== tracks) {
+        useItemsStore.getState().setTracks(nextTracks)
+      }
+
+      const multicamCount = items.filter((item) => item.type === 'multicam').length
+      const multicamItem: MulticamItem = {
+        id: crypto.randomUUID(),
+        type: 'multicam',
+        trackId: targetTrackId,
+        from: minFrom,
+        durationInFrames,
+        label: `Multicam ${multicamCount + 1}`,
+        sourceStart: clockStart,
+        sourceEnd: clockStart + durationInFrames,
+        sourceDuration: clockDuration,
+        sourceFps: fps,
+        speed: 1,
+        angles,
+        angleSegments: [
+          { id: crypto.randomUUID(), startFrame: clockStart, angleId: referenceAngle.id },
+        ],
+        audioAngleId: referenceAngle.id,
+        syncMethod,
+      }
+      useItemsStore.getState()._addItem(multicamItem)
+      useSelectionStore.getState().selectItems([multicamItem.id])
+      useTimelineSettingsStore.getState().markDirty()
+
+      return multicamItem
+    },
+    { itemIds, syncMethod },
+  )
+}
+
+/**
+ * Switch a multicam clip to another angle at a timeline frame.
+ *
+ * `cut` inserts a new cut at the frame (live switching during playback);
+ * `replace` swaps the angle of the segment under the frame.
+ */
+export function switchMulticamAngle(
+  itemId: string,
+  angleIndex: number,
+  frame: number,
+  mode: 'cut' | 'replace',
+): boolean {
+  return execute(
+    'SWITCH_MULTICAM_ANGLE',
+    () => {
+      const item = useItemsStore.getState().itemById[itemId]
+      if (item?.type !== 'multicam') return false
+      const angle = item.angles[angleIndex]
+      if (!angle) return false
+
+      const localFrame = Math.min(Math.max(frame, item.from), item.from + item.durationInFrames - 1)
+      const clockFrame = getMulticamClockFrame(item, localFrame)
+      const segments: MulticamAngleSegment[] =
+        item.angleSegments.length > 0
+          ? [...item.angleSegments]
+          : [
+              {
+                id: crypto.randomUUID(),
+                startFrame: item.sourceStart ?? 0,
+                angleId: item.angles[0]!.id,
+              },
+            ]
+      const index = Math.max(
+        0,
+        getMulticamSegmentIndexAtClockFrame({ ...item, angleSegments: segments }, clockFrame),
+      )
+      const current = segments[index]!
+      if (current.angleId === angle.id) return false
+
+      if (mode === 'replace' || clockFrame <= current.startFrame) {
+        segments[index] = { ...current, angleId: angle.id }
+      } else {
+        segments.splice(index + 1, 0, {
+          id: crypto.randomUUID(),
+          startFrame: clockFrame,
+          angleId: angle.id,
+        })
+      }
+
+      useItemsStore.getState()._updateItem(itemId, {
+        angleSegments: mergeAngleSegments(segments),
+      } as Partial<TimelineItem>)
+      useTimelineSettingsStore.getState().markDirty()
+      return true
+    },
+    { itemId, angleIndex, frame, mode },
+  )
+}
+
+/**
+ * Replace a multicam clip with its visible cuts as ordinary video clips,
+ * plus the audio angle as an audio clip on the nearest audio track.
+ */
+export function flattenMulticam(itemId: string): boolean {
+  return execute(
+    'FLATTEN_MULTICAM',
+    () => {
+      const { itemById, tracks } = useItemsStore.getState()
+      const item = itemById[itemId]
+      if (item?.type !== 'multicam') return false
+
+      const fps = useTimelineSettingsStore.getState().fps
+      const pieces = expandMulticamItem(item, fps)
+      if (pieces.length === 0) return false
+
+      const itemKeyframes = useKeyframesStore.getState().getKeyframesForItem(itemId)
+      const pieceKeyframes = itemKeyframes
+        ? expandMulticamKeyframes([itemKeyframes], [item], fps).filter(
+            (entry) => entry.itemId !== itemId,
+          )
+        : []
+
+      let nextTracks = tracks
+      const multicamTrack = tracks.find((track) => track.id === item.trackId)
+      let audioTrackId: string | null = null
+      if (pieces.some((piece) => piece.type === 'audio') && multicamTrack) {
+        const nearestAudioTrack = findNearestTrackByKind({
+          tracks: nextTracks,
+          targetTrack: multicamTrack,
+          kind: 'audio',
+          direction: 'below',
+        })
+        if (nearestAudioTrack) {
+          audioTrackId = nearestAudioTrack.id
+        } else {
+          const createdTrack = createClassicTrack({
+            tracks: nextTracks,
+            kind: 'audio',
+            order: getAdjacentTrackOrder(nextTracks, multicamTrack, 'below'),
+            height: multicamTrack.height ?? DEFAULT_TRACK_HEIGHT,
+          })
+          nextTracks = [...nextTracks, createdTrack]
+          audioTrackId = createdTrack.id
+        }
+      }
+
+      const idMapping = new Map<string, string>()
+      const flattenedItems: TimelineItem[] = []
+      for (const piece of pieces) {
+        if (piece.type === 'audio' && !audioTrackId) continue
+        const id = crypto.randomUUID()
+        idMapping.set(piece.id, id)
+        flattenedItems.push({
+          ...piece,
+          id,
+          trackId: piece.type === 'audio' ? audioTrackId! : item.trackId,
+        })
+      }
+      const flattenedKeyframes: ItemKeyframes[] = pieceKeyframes.flatMap((entry) => {
+        const id = idMapping.get(entry.itemId)
+        return id ? [{ ...entry, itemId: id }] : []
+      })
+
+      useItemsStore.getState()._removeItems([itemId])
+      useTransitionsStore
+        .getState()
+        .setTransitions(
+          useTransitionsStore
+            .getState()
+            .transitions.filter((t) => t.leftClipId !== itemId && t.rightClipId !== itemId),
+        )
+      useKeyframesStore.getState()._removeKeyframesForItem(itemId)
+      if (nextTracks !== tracks) {
+        useItemsStore.getState().setTracks(nextTracks)
+      }
+
+      useItemsStore.getState()._addItems(flattenedItems)
+      if (flattenedKeyframes.length > 0) {
+        useKeyframesStore
+          .getState()
+          .setKeyframes([...useKeyframesStore.getState().keyframes, ...flattenedKeyframes])
+      }
+      useSelectionStore
+        .getState()
+        .selectItems(
+          flattenedItems.filter((entry) => entry.type === 'video').map((entry) => entry.id),
+        )
+      useTimelineSettingsStore.getState().markDirty()
+      return true
+    },
+    { itemId },
+  )
+}
@@ -4,7 +4,7 @@
 
 import type { TransformProperties } from '@/types/transform'
 import type { AnimatableProperty } from '@/types/keyframe'
-import type { MaskVertex } from '@/types/masks'
+import type { MaskPathKeyframe, MaskVertex } from '@/types/masks'
 import type { LayoutConfig } from '../../utils/bento-layout'
 import type { TransformCommandOptions, TransformHistoryOperation } from '../../types'
 import type { AutoKeyframeOperation } from '@/features/timeline/deps/keyframes'
@@ -73,6 +73,8 @@ function inferTransformOperationFromMap(
 
 interface MaskEditCommit {
   pathVertices?: MaskVertex[]
+  /** Replaces the mask's animated path; an empty list removes the animation. */
+  pathKeyframes?: MaskPathKeyframe[]
   transform?: Partial<TransformProperties>
   autoKeyframeOperations?: AutoKeyframeOperation[]
 }
@@ -106,13 +108,13 @@ export function commitMaskEdit(
   const transformKeys = getTransformKeys(transform)
   const autoKeyframeOperations = commit.autoKeyframeOperations ?? []
 
-  if (!commit.pathVertices && transformKeys.size === 0 && autoKeyframeOperations.length === 0) {
+  const hasPathEdit = Boolean(commit.pathVertices || commit.pathKeyframes)
+  if (!hasPathEdit && transformKeys.size === 0 && autoKeyframeOperations.length === 0) {
     return
   }
 
   const operation =
-    options?.operation ??
-    (commit.pathVertices ? 'transform' : inferTransformOperation(transformKeys))
+    options?.operation ?? (hasPathEdit ? 'transform' : inferTransformOperation(transformKeys))
 
   execute(
     'COMMIT_MASK_EDIT',
@@ -124,6 +126,13 @@ export function commitMaskEdit(
         changed = true
       }
 
+      if (commit.pathKeyframes) {
+        useItemsStore.getState()._updateItem(id, {
+          pathKeyframes: commit.pathKeyframes.length > 0 ? commit.pathKeyframes : undefined,
+        })
+        changed = true
+      }
+
       if (transformKeys.size > 0) {
         useItemsStore.getState()._updateItemTransform(id, transform)
         changed = true
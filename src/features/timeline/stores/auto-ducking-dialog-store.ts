@@ -0,0 +1,53 @@
+import { create } from 'zustand'
+import { DEFAULT_AUTO_DUCKING_SETTINGS, type AutoDuckingSettings } from '../utils/auto-ducking'
+
+interface AutoDuckingDialogState {
+  isOpen: boolean
+  musicTrackIds: string[]
+  dialogueTrackIds: string[]
+  settings: AutoDuckingSettings
+}
+
+interface AutoDuckingDialogActions {
+  open: (request: {
+    musicTrackIds: string[]
+    dialogueTrackIds: string[]
+    settings?: AutoDuckingSettings
+  }) => void
+  setTracks: (request: { musicTrackIds: string[]; dialogueTrackIds: string[] }) => void
+  setSettings: (settings: AutoDuckingSettings) => void
+  close: () => void
+}
+
+export const useAutoDuckingDialogStore = create<AutoDuckingDialogState & AutoDuckingDialogActions>(
+  (set, get) => ({
+    isOpen: false,
+    musicTrackIds: [],
+    dialogueTrackIds: [],
+    settings: DEFAULT_AUTO_DUCKING_SETTINGS,
+
+    open: (request) =>
+      set({
+        isOpen: true,
+        musicTrackIds: request.musicTrackIds,
+        dialogueTrackIds: request.dialogueTrackIds,
+        // Settings stick between runs so re-ducking uses the last tweak.
+        settings: request.settings ?? get().settings,
+      }),
+
+    setTracks: (request) =>
+      set({
+        musicTrackIds: request.musicTrackIds,
+        dialogueTrackIds: request.dialogueTrackIds,
+      }),
+
+    setSettings: (settings) => set({ settings }),
+
+    close: () =>
+      set({
+        isOpen: false,
+        musicTrackIds: [],
+        dialogueTrackIds: [],
+      }),
+  }),
+)
@@ -75,6 +75,10 @@ export function formatTimelineCommandLabel(command: TimelineCommand): string {
       return 'Change canvas background'
     }
 
+    if (fields.includes('colorSpace')) {
+      return 'Change working color space'
+    }
+
     return 'Update project settings'
   }
 
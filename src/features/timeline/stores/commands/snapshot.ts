@@ -29,7 +29,8 @@ function projectMetadataEqual(
     left.width === right.width &&
     left.height === right.height &&
     left.fps === right.fps &&
-    (left.backgroundColor ?? '#000000') === (right.backgroundColor ?? '#000000')
+    (left.backgroundColor ?? '#000000') === (right.backgroundColor ?? '#000000') &&
+    (left.colorSpace ?? 'rec709') === (right.colorSpace ?? 'rec709')
   )
 }
 
@@ -257,6 +257,11 @@ export function buildItemsMediaDependencyIds(items: TimelineItem[]): string[] {
     if (item.mediaId) {
       mediaIds.add(item.mediaId)
     }
+    if (item.type === 'multicam') {
+      for (const angle of item.angles) {
+        mediaIds.add(angle.mediaId)
+      }
+    }
   }
   return [...mediaIds].sort()
 }
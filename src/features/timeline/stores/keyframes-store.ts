@@ -41,6 +41,7 @@ export interface KeyframeAddPayload {
   value: number
   easing?: EasingType
   easingConfig?: EasingConfig
+  duckBase?: number | null
 }
 
 interface KeyframesActions {
@@ -99,6 +100,19 @@ function buildKeyframesByItemId(keyframes: ItemKeyframes[]): Record<string, Item
   return map
 }
 
+/**
+ * Apply updates to one keyframe. Setting a new value makes the point the
+ * user's own, so its auto-ducking mark goes unless the update sets one.
+ */
+function applyKeyframeUpdates(
+  keyframe: Keyframe,
+  updates: Partial<Omit<Keyframe, 'id'>>,
+): Keyframe {
+  const updated = { ...keyframe, ...updates }
+  if (updates.value !== undefined && !('duckBase' in updates)) delete updated.duckBase
+  return updated
+}
+
 function dedupeKeyframesByFrame(
   keyframes: Keyframe[],
   preferredIds: ReadonlySet<string> = new Set(),
@@ -162,7 +176,9 @@ export const useKeyframesStore = create<KeyframesState & KeyframesActions>()((se
                           ? {
                               ...pk,
                               keyframes: pk.keyframes.map((k) =>
-                                k.frame === frame ? { ...k, value, easing, easingConfig } : k,
+                                k.frame === frame
+                                  ? applyKeyframeUpdates(k, { value, easing, easingConfig })
+                                  : k,
                               ),
                             }
                           : pk,
@@ -233,9 +249,25 @@ export const useKeyframesStore = create<KeyframesState & KeyframesActions>()((se
       let newKeyframes = [...state.keyframes]
 
       for (const payload of payloads) {
-        const { itemId, property, frame, value, easing = 'linear', easingConfig } = payload
+        const {
+          itemId,
+          property,
+          frame,
+          value,
+          easing = 'linear',
+          easingConfig,
+          duckBase,
+        } = payload
         const keyframeId = crypto.randomUUID()
-        const newKeyframe: Keyframe = { id: keyframeId, frame, value, easing, easingConfig }
+        const marks = duckBase !== undefined ? { duckBase } : {}
+        const newKeyframe: Keyframe = {
+          id: keyframeId,
+          frame,
+          value,
+          easing,
+          easingConfig,
+          ...marks,
+        }
         const existingItemIndex = newKeyframes.findIndex((k) => k.itemId === itemId)
 
         if (existingItemIndex !== -1) {
@@ -252,12 +284,10 @@ export const useKeyframesStore = create<KeyframesState & KeyframesActions>()((se
             if (existingAtFrameIndex !== -1) {
               // Update existing keyframe
               const updatedKeyframes = [...existingProp.keyframes]
-              updatedKeyframes[existingAtFrameIndex] = {
-                ...updatedKeyframes[existingAtFrameIndex]!,
-                value,
-                easing,
-                easingConfig,
-              }
+              updatedKeyframes[existingAtFrameIndex] = applyKeyframeUpdates(
+                updatedKeyframes[existingAtFrameIndex]!,
+                { value, easing, easingConfig, ...marks },
+              )
               newIds.push(updatedKeyframes[existingAtFrameIndex]!.id)
 
               const updatedProperties = [...existingItem.properties]
@@ -329,7 +359,9 @@ export const useKeyframesStore = create<KeyframesState & KeyframesActions>()((se
                   ? {
                       ...pk,
                       keyframes: dedupeKeyframesByFrame(
-                        pk.keyframes.map((k) => (k.id === keyframeId ? { ...k, ...updates } : k)),
+                        pk.keyframes.map((k) =>
+                          k.id === keyframeId ? applyKeyframeUpdates(k, updates) : k,
+                        ),
                         new Set([keyframeId]),
                       ),
                     }
@@ -484,7 +516,9 @@ export const useKeyframesStore = create<KeyframesState & KeyframesActions>()((se
                         ...pk,
                         keyframes: dedupeKeyframesByFrame(
                           pk.keyframes.map((k) =>
-                            k.id === update.keyframeId ? { ...k, ...update.updates } : k,
+                            k.id === update.keyframeId
+                              ? applyKeyframeUpdates(k, update.updates)
+                              : k,
                           ),
                           preferredIdsByKey.get(`${update.itemId}:${update.property}`) ?? new Set(),
                         ),
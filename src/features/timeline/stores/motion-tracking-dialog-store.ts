@@ -0,0 +1,40 @@
+import { create } from 'zustand'
+import type { MotionTrackMode } from '@/infrastructure/analysis/motion-tracking'
+import type { MotionTrackApplyOptions } from '../utils/motion-tracking'
+
+interface MotionTrackingSettings extends MotionTrackApplyOptions {
+  mode: MotionTrackMode
+}
+
+interface MotionTrackingDialogState {
+  isOpen: boolean
+  /** Video clip being tracked */
+  itemId: string | null
+  settings: MotionTrackingSettings
+}
+
+interface MotionTrackingDialogActions {
+  open: (request: { itemId: string }) => void
+  setSettings: (settings: MotionTrackingSettings) => void
+  close: () => void
+}
+
+export const useMotionTrackingDialogStore = create<
+  MotionTrackingDialogState & MotionTrackingDialogActions
+>((set) => ({
+  isOpen: false,
+  itemId: null,
+  settings: {
+    mode: 'point',
+    target: 'transform',
+    includeRotation: true,
+    includeScale: true,
+  },
+
+  // Settings stick between runs so tracking several callouts reuses them.
+  open: (request) => set({ isOpen: true, itemId: request.itemId }),
+
+  setSettings: (settings) => set({ settings }),
+
+  close: () => set({ isOpen: false, itemId: null }),
+}))
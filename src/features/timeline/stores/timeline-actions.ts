@@ -22,5 +22,6 @@ export * from './actions/marker-actions'
 export * from './actions/settings-actions'
 export * from './actions/source-edit-actions'
 export * from './actions/composition-actions'
+export * from './actions/multicam-actions'
 export * from './actions/project-item-actions'
 export * from './actions/legacy-av-actions'
@@ -722,6 +722,7 @@ export function buildTimelineFromStores(): ProjectTimeline {
             value: k.value,
             easing: k.easing,
             ...(k.easingConfig && { easingConfig: k.easingConfig }),
+            ...(k.duckBase !== undefined && { duckBase: k.duckBase }),
           })),
         })),
       })),
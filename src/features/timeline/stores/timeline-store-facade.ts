@@ -164,6 +164,8 @@ function getSnapshot(): TimelineState & TimelineActions {
       addKeyframes: timelineActions.addKeyframes,
       updateKeyframe: timelineActions.updateKeyframe,
       applyAutoKeyframeOperations: timelineActions.applyAutoKeyframeOperations,
+      replaceVolumeKeyframes: timelineActions.replaceVolumeKeyframes,
+      applyMotionTrackKeyframes: timelineActions.applyMotionTrackKeyframes,
       removeKeyframe: timelineActions.removeKeyframe,
       removeKeyframesForItem: timelineActions.removeKeyframesForItem,
       removeKeyframesForProperty: timelineActions.removeKeyframesForProperty,
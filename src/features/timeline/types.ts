@@ -16,8 +16,9 @@ import type {
   EasingType,
   EasingConfig,
 } from '@/types/keyframe'
-import type { MaskVertex } from '@/types/masks'
+import type { MaskPathKeyframe, MaskVertex } from '@/types/masks'
 import type { AutoKeyframeOperation } from '@/features/timeline/deps/keyframes'
+import type { MotionTrackKeyframePlan } from './utils/motion-tracking'
 
 export type TransformHistoryOperation =
   | 'move'
@@ -126,6 +127,7 @@ export interface TimelineActions {
     id: string,
     commit: {
       pathVertices?: MaskVertex[]
+      pathKeyframes?: MaskPathKeyframe[]
       transform?: Partial<TransformProperties>
       autoKeyframeOperations?: AutoKeyframeOperation[]
     },
@@ -210,6 +212,13 @@ export interface TimelineActions {
     updates: Partial<Omit<Keyframe, 'id'>>,
   ) => void
   applyAutoKeyframeOperations: (operations: AutoKeyframeOperation[]) => void
+  replaceVolumeKeyframes: (
+    envelopes: Array<{
+      itemId: string
+      keyframes: Array<{ frame: number; value: number; duckBase?: number | null }>
+    }>,
+  ) => number
+  applyMotionTrackKeyframes: (plan: MotionTrackKeyframePlan) => number
   removeKeyframe: (itemId: string, property: AnimatableProperty, keyframeId: string) => void
   removeKeyframesForItem: (itemId: string) => void
   removeKeyframesForProperty: (itemId: string, property: AnimatableProperty) => void
@@ -0,0 +1,113 @@
+import type { ItemKeyframes } from '@/types/keyframe'
+import type { TimelineItem } from '@/types/timeline'
+import {
+  DEFAULT_AUTO_DUCKING_SETTINGS,
+  mapSourceRangesToTimeline,
+  planAutoDucking,
+} from './auto-ducking'
+
+function audioItem(id: string, overrides: Partial<TimelineItem> = {}): TimelineItem {
+  return {
+    id,
+    type: 'audio',
+    name: id,
+    trackId: 'track-1',
+    from: 30,
+    durationInFrames: 90,
+    mediaId: `${id}-media`,
+    sourceStart: 0,
+    sourceDuration: 90,
+    sourceFps: 30,
+    ...overrides,
+  } as unknown as TimelineItem
+}
+
+describe('mapSourceRangesToTimeline', () => {
+  it('maps source seconds onto the timeline and drops ranges outside the clip', () => {
+    expect(
+      mapSourceRangesToTimeline(
+        audioItem('dialogue'),
+        [
+          { start: 0.5, end: 1 },
+          { start: 5, end: 6 },
+        ],
+        30,
+      ),
+    ).toEqual([{ start: 45, end: 60 }])
+  })
+
+  it('mirrors ranges for reversed clips', () => {
+    expect(
+      mapSourceRangesToTimeline(
+        audioItem('dialogue', { isReversed: true } as Partial<TimelineItem>),
+        [{ start: 0.5, end: 1 }],
+        30,
+      ),
+    ).toEqual([{ start: 90, end: 105 }])
+  })
+})
+
+describe('planAutoDucking', () => {
+  it('builds item-relative envelopes only for music clips under speech', () => {
+    const envelopes = planAutoDucking({
+      musicItems: [
+        audioItem('music', { volume: -3 } as Partial<TimelineItem>),
+        audioItem('later', { from: 600 }),
+      ],
+      speechRanges: [{ start: 60, end: 90 }],
+      settings: { ...DEFAULT_AUTO_DUCKING_SETTINGS, attackMs: 200, releaseMs: 400 },
+      fps: 30,
+      keyframesByItemId: {},
+    })
+
+    expect(envelopes).toEqual([
+      {
+        itemId: 'music',
+        keyframes: [
+          { frame: 24, value: -3, duckBase: null },
+          { frame: 30, value: -15, duckBase: null },
+          { frame: 60, value: -15, duckBase: null },
+          { frame: 72, value: -3, duckBase: null },
+        ],
+      },
+    ])
+  })
+
+  it('replaces the previous duck when run again instead of stacking it', () => {
+    const music = audioItem('music')
+    const volumeKeyframes = (points: Array<{ frame: number; value: number }>): ItemKeyframes => ({
+      itemId: 'music',
+      properties: [
+        {
+          property: 'volume',
+          keyframes: points.map((point, index) => ({
+            ...point,
+            id: `kf-${index}`,
+            easing: 'linear',
+          })),
+        },
+      ],
+    })
+    const run = (depthDb: number, keyframes?: ItemKeyframes) =>
+      planAutoDucking({
+        musicItems: [music],
+        speechRanges: [{ start: 60, end: 90 }],
+        settings: { ...DEFAULT_AUTO_DUCKING_SETTINGS, depthDb, attackMs: 200, releaseMs: 400 },
+        fps: 30,
+        keyframesByItemId: { music: keyframes },
+      })[0]!.keyframes
+
+    // Hand-drawn -6 dB automation, ducked by 12 dB, then re-ducked by 8 dB.
+    const first = run(12, volumeKeyframes([{ frame: 45, value: -6 }]))
+    expect(first.find((point) => point.frame === 30)?.value).toBe(-18)
+
+    const second = run(8, volumeKeyframes(first))
+    expect(second.find((point) => point.frame === 30)?.value).toBe(-14)
+    expect(second.find((point) => point.frame === 45)).toEqual({
+      frame: 45,
+      value: -14,
+      duckBase: -6,
+    })
+    expect(second).toEqual(run(8, volumeKeyframes([{ frame: 45, value: -6 }])))
+  })
+})
@@ -0,0 +1,208 @@
+import type { MediaTranscript } from '@/types/storage'
+import type { ItemKeyframes } from '@/types/keyframe'
+import type { TimelineItem } from '@/types/timeline'
+import { getOrDecodeAudio } from '@/features/timeline/deps/composition-runtime'
+import { resolveMediaUrl } from '@/features/timeline/deps/media-library-resolver'
+import { mediaTranscriptionService } from '@/features/timeline/deps/media-transcription-service'
+import { getPropertyKeyframes, interpolatePropertyValue } from '@/features/timeline/deps/keyframes'
+import { createLogger } from '@/shared/logging/logger'
+import {
+  buildDuckingKeyframes,
+  detectSpeechRanges,
+  mergeDuckingRanges,
+  removeDucking,
+  type AudioDuckingRange,
+  type DuckingKeyframePoint,
+} from '@/shared/utils/audio-ducking'
+import { getItemSourceSpanSeconds, sourceSecondsToTimelineFrame } from './media-item-frames'
+import { isAudioVideoItem } from './removal-preview-overlays'
+
+const logger = createLogger('AutoDucking')
+
+export type AutoDuckingSpeechSource = 'transcript' | 'energy'
+
+export interface AutoDuckingSettings {
+  /** Transcripts first (falling back to the level detector per clip), or level only. */
+  speechSource: AutoDuckingSpeechSource
+  depthDb: number
+  attackMs: number
+  releaseMs: number
+  /** Pauses shorter than this stay ducked. */
+  holdMs: number
+  /** Level detector threshold. */
+  thresholdDb: number
+}
+
+export const DEFAULT_AUTO_DUCKING_SETTINGS: AutoDuckingSettings = {
+  speechSource: 'transcript',
+  depthDb: 12,
+  attackMs: 250,
+  releaseMs: 600,
+  holdMs: 400,
+  thresholdDb: -40,
+}
+
+export interface DialogueSpeechResult {
+  /** Merged speech ranges in timeline frames. */
+  ranges: AudioDuckingRange[]
+  transcriptMediaCount: number
+  levelMediaCount: number
+}
+
+export interface AutoDuckingEnvelope {
+  itemId: string
+  keyframes: DuckingKeyframePoint[]
+}
+
+/**
+ * Map source-time ranges (seconds into the media) onto the timeline (frames),
+ * clipped to the part of the source the item actually plays.
+ */
+export function mapSourceRangesToTimeline(
+  item: TimelineItem,
+  ranges: readonly AudioDuckingRange[],
+  fps: number,
+): AudioDuckingRange[] {
+  const span = getItemSourceSpanSeconds(item, fps)
+  if (!span) return []
+  const itemEnd = item.from + item.durationInFrames
+  const isReversed = (item.type === 'video' || item.type === 'audio') && item.isReversed === true
+
+  const mapped: AudioDuckingRange[] = []
+  for (const range of ranges) {
+    const start = Math.max(range.start, span.start)
+    const end = Math.min(range.end, span.end)
+    if (end <= start) continue
+    const startFrame = sourceSecondsToTimelineFrame(item, start, fps)
+    const endFrame = sourceSecondsToTimelineFrame(item, end, fps)
+    mapped.push(
+      isReversed
+        ? { start: item.from + itemEnd - endFrame, end: item.from + itemEnd - startFrame }
+        : { start: startFrame, end: endFrame },
+    )
+  }
+  return mapped
+}
+
+function transcriptSpeechRanges(transcript: MediaTranscript): AudioDuckingRange[] {
+  return transcript.segments.flatMap((segment) =>
+    segment.words && segment.words.length > 0
+      ? segment.words.map((word) => ({ start: word.start, end: word.end }))
+      : [{ start: segment.start, end: segment.end }],
+  )
+}
+
+async function levelSpeechRanges(
+  mediaId: string,
+  settings: AutoDuckingSettings,
+): Promise<AudioDuckingRange[]> {
+  const url = await resolveMediaUrl(mediaId)
+  if (!url) {
+    throw new Error('Could not load media for speech detection')
+  }
+  const audioBuffer = await getOrDecodeAudio(mediaId, url)
+  return detectSpeechRanges(audioBuffer, { thresholdDb: settings.thresholdDb })
+}
+
+/**
+ * Find where the dialogue clips speak, on the timeline. Transcripts are used
+ * when present (word timings if available); otherwise the decoded audio goes
+ * through the level detector.
+ */
+export async function detectDialogueSpeech(
+  dialogueItems: readonly TimelineItem[],
+  settings: AutoDuckingSettings,
+  fps: number,
+): Promise<DialogueSpeechResult> {
+  const items = dialogueItems.filter(isAudioVideoItem)
+  const mediaIds = Array.from(new Set(items.map((item) => item.mediaId)))
+  const rangesByMediaId = new Map<string, AudioDuckingRange[]>()
+  let transcriptMediaCount = 0
+  let levelMediaCount = 0
+
+  const results = await Promise.allSettled(
+    mediaIds.map(async (mediaId) => {
+      if (settings.speechSource === 'transcript') {
+        const transcript = await mediaTranscriptionService.getTranscript(mediaId).catch(() => null)
+        if (transcript && transcript.segments.length > 0) {
+          return { mediaId, ranges: transcriptSpeechRanges(transcript), fromTranscript: true }
+        }
+      }
+      return { mediaId, ranges: await levelSpeechRanges(mediaId, settings), fromTranscript: false }
+    }),
+  )
+
+  for (const result of results) {
+    if (result.status === 'rejected') {
+      logger.warn('Speech detection failed for media', { reason: result.reason })
+      continue
+    }
+    rangesByMediaId.set(result.value.mediaId, result.value.ranges)
+    if (result.value.fromTranscript) transcriptMediaCount += 1
+    else levelMediaCount += 1
+  }
+
+  if (rangesByMediaId.size === 0 && mediaIds.length > 0) {
+    throw new Error('Could not load dialogue audio for speech detection')
+  }
+
+  const ranges = items.flatMap((item) =>
+    mapSourceRangesToTimeline(item, rangesByMediaId.get(item.mediaId) ?? [], fps),
+  )
+  return {
+    ranges: mergeDuckingRanges(ranges, (settings.holdMs / 1000) * fps),
+    transcriptMediaCount,
+    levelMediaCount,
+  }
+}
+
+/**
+ * Volume envelopes for the music clips that overlap speech. Existing volume
+ * keyframes are folded in, minus any duck a previous run wrote, so re-running
+ * with new settings replaces the duck instead of stacking another on top.
+ */
+export function planAutoDucking({
+  musicItems,
+  speechRanges,
+  settings,
+  fps,
+  keyframesByItemId,
+}: {
+  musicItems: readonly TimelineItem[]
+  speechRanges: readonly AudioDuckingRange[]
+  settings: AutoDuckingSettings
+  fps: number
+  keyframesByItemId: Record<string, ItemKeyframes | undefined>
+}): AutoDuckingEnvelope[] {
+  const attackFrames = (settings.attackMs / 1000) * fps
+  const releaseFrames = (settings.releaseMs / 1000) * fps
+  const envelopes: AutoDuckingEnvelope[] = []
+
+  for (const item of musicItems) {
+    if (item.type !== 'audio' && item.type !== 'video') continue
+    const itemEnd = item.from + item.durationInFrames
+    const ranges = speechRanges
+      .filter(
+        (range) => range.end + releaseFrames > item.from && range.start - attackFrames < itemEnd,
+      )
+      .map((range) => ({ start: range.start - item.from, end: range.end - item.from }))
+    if (ranges.length === 0) continue
+
+    const existing = removeDucking(getPropertyKeyframes(keyframesByItemId[item.id], 'volume'))
+    const staticVolumeDb = item.volume ?? 0
+    envelopes.push({
+      itemId: item.id,
+      keyframes: buildDuckingKeyframes({
+        ranges,
+        durationInFrames: item.durationInFrames,
+        depthDb: settings.depthDb,
+        attackFrames,
+        releaseFrames,
+        baseVolumeDbAt: (frame) => interpolatePropertyValue(existing, frame, staticVolumeDb),
+        existing: existing.map((keyframe) => ({ frame: keyframe.frame, value: keyframe.value })),
+      }),
+    })
+  }
+
+  return envelopes
+}
@@ -22,7 +22,12 @@ export function applyTrimStartPreview(
     durationInFrames: item.durationInFrames - trimDelta,
   }
 
-  if (item.type === 'video' || item.type === 'audio' || item.type === 'composition') {
+  if (
+    item.type === 'video' ||
+    item.type === 'audio' ||
+    item.type === 'composition' ||
+    item.type === 'multicam'
+  ) {
     const sourceStart = item.sourceStart ?? 0
     const sourceEnd = item.sourceEnd
     const speed = item.speed ?? 1
@@ -48,7 +53,12 @@ export function applyTrimEndPreview(
     durationInFrames: item.durationInFrames + trimDelta,
   }
 
-  if (item.type === 'video' || item.type === 'audio' || item.type === 'composition') {
+  if (
+    item.type === 'video' ||
+    item.type === 'audio' ||
+    item.type === 'composition' ||
+    item.type === 'multicam'
+  ) {
     const sourceStart = item.sourceStart ?? 0
     const speed = item.speed ?? 1
     const sourceFps = item.sourceFps ?? fps
@@ -73,7 +83,10 @@ export function applyMovePreview(item: TimelineItem, fromDelta: number): Preview
 
 export function applySlipPreview(item: TimelineItem, slipDelta: number): PreviewItemUpdate {
   if (
-    (item.type !== 'video' && item.type !== 'audio' && item.type !== 'composition') ||
+    (item.type !== 'video' &&
+      item.type !== 'audio' &&
+      item.type !== 'composition' &&
+      item.type !== 'multicam') ||
     item.sourceEnd === undefined
   ) {
     return { id: item.id }
@@ -9,11 +9,16 @@ function lookupMediaFps(mediaId: string | undefined): number | undefined {
 }
 
 export function getMediaSourceFps(item: TimelineItem, timelineFps: number): number {
-  if (item.type !== 'video' && item.type !== 'audio' && item.type !== 'composition') {
+  if (
+    item.type !== 'video' &&
+    item.type !== 'audio' &&
+    item.type !== 'composition' &&
+    item.type !== 'multicam'
+  ) {
     return timelineFps
   }
   if (item.sourceFps !== undefined) return item.sourceFps
-  const mediaId = item.type === 'composition' ? undefined : item.mediaId
+  const mediaId = item.type === 'video' || item.type === 'audio' ? item.mediaId : undefined
   return lookupMediaFps(mediaId) ?? timelineFps
 }
 
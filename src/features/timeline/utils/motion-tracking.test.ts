@@ -0,0 +1,199 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { TimelineItem } from '@/types/timeline'
+import type { MotionTrack } from '@/infrastructure/analysis/motion-tracking'
+import {
+  getDefaultTrackRegion,
+  getTrackSourceTimes,
+  planMotionTrackKeyframes,
+} from './motion-tracking'
+
+const canvas = { width: 1920, height: 1080, fps: 30 }
+
+function videoItem(overrides: Partial<TimelineItem> = {}): TimelineItem {
+  return {
+    id: 'clip',
+    type: 'video',
+    trackId: 'track-1',
+    from: 10,
+    durationInFrames: 120,
+    label: 'clip.mp4',
+    mediaId: 'media-1',
+    src: 'blob:clip',
+    sourceWidth: 1920,
+    sourceHeight: 1080,
+    sourceFps: 30,
+    transform: { x: 0, y: 0, width: 1920, height: 1080 },
+    ...overrides,
+  } as unknown as TimelineItem
+}
+
+function textItem(overrides: Partial<TimelineItem> = {}): TimelineItem {
+  return {
+    id: 'callout',
+    type: 'text',
+    trackId: 'track-2',
+    from: 12,
+    durationInFrames: 30,
+    label: 'Callout',
+    text: 'Look here',
+    color: '#ffffff',
+    transform: { x: -200, y: 100, width: 400, height: 100, rotation: 0 },
+    ...overrides,
+  } as unknown as TimelineItem
+}
+
+const track: MotionTrack = {
+  mode: 'planar',
+  region: { x: 0.4, y: 0.4, width: 0.2, height: 0.2 },
+  samples: [
+    {
+      frame: 0,
+      center: [0.5, 0.5],
+      corners: [
+        [0.4, 0.4],
+        [0.6, 0.4],
+        [0.6, 0.6],
+        [0.4, 0.6],
+      ],
+      rotation: 0,
+      scale: 1,
+      confidence: 1,
+    },
+    {
+      frame: 1,
+      center: [0.55, 0.5],
+      corners: [
+        [0.45, 0.4],
+        [0.65, 0.4],
+        [0.65, 0.6],
+        [0.45, 0.6],
+      ],
+      rotation: 90,
+      scale: 2,
+      confidence: 1,
+    },
+  ],
+}
+
+describe('planMotionTrackKeyframes', () => {
+  it('parents a target to the track, rotating and scaling around the tracked center', () => {
+    const plan = planMotionTrackKeyframes({
+      track,
+      trackedItem: videoItem(),
+      targetItem: textItem(),
+      startFrame: 5,
+      canvas,
+      options: { target: 'transform', includeRotation: true, includeScale: true },
+    })
+
+    expect(plan.itemId).toBe('callout')
+    expect(plan.cornerPin).toBeUndefined()
+    const byProperty = Object.fromEntries(plan.properties.map((p) => [p.property, p.keyframes]))
+    expect(Object.keys(byProperty)).toEqual(['x', 'y', 'rotation', 'width', 'height'])
+
+    // Clip starts at 10, tracking starts 5 frames in, target starts at 12.
+    expect(byProperty.x!.map((k) => k.frame)).toEqual([3, 4])
+    expect(byProperty.x![0]!.value).toBeCloseTo(-200)
+    expect(byProperty.y![0]!.value).toBeCloseTo(100)
+    expect(byProperty.x![1]!.value).toBeCloseTo(-104)
+    expect(byProperty.y![1]!.value).toBeCloseTo(-400)
+    expect(byProperty.rotation![1]!.value).toBe(90)
+    expect(byProperty.width![1]!.value).toBe(800)
+    expect(byProperty.height![1]!.value).toBe(200)
+  })
+
+  it('only writes position for point tracks', () => {
+    const plan = planMotionTrackKeyframes({
+      track: { ...track, mode: 'point' },
+      trackedItem: videoItem(),
+      targetItem: textItem(),
+      startFrame: 5,
+      canvas,
+      options: { target: 'transform', includeRotation: true, includeScale: true },
+    })
+
+    expect(plan.properties.map((p) => p.property)).toEqual(['x', 'y'])
+    expect(plan.properties[0]!.keyframes[1]!.value).toBeCloseTo(-104)
+    expect(plan.properties[1]!.keyframes[1]!.value).toBeCloseTo(100)
+  })
+
+  it('drops samples outside the target item', () => {
+    const plan = planMotionTrackKeyframes({
+      track,
+      trackedItem: videoItem(),
+      targetItem: textItem({ durationInFrames: 4 }),
+      startFrame: 5,
+      canvas,
+      options: { target: 'transform', includeRotation: false, includeScale: false },
+    })
+
+    expect(plan.properties[0]!.keyframes.map((k) => k.frame)).toEqual([3])
+  })
+
+  it('pins target corners to the tracked quad', () => {
+    const plan = planMotionTrackKeyframes({
+      track,
+      trackedItem: videoItem(),
+      targetItem: videoItem({
+        id: 'screen',
+        from: 0,
+        sourceWidth: 400,
+        sourceHeight: 200,
+        transform: { x: 0, y: 0, width: 400, height: 200 },
+      } as Partial<TimelineItem>),
+      startFrame: 0,
+      canvas,
+      options: { target: 'cornerPin', includeRotation: false, includeScale: false },
+    })
+
+    // Target rect is (760, 440) 400×200; tracked quad is (768, 432)-(1152, 648).
+    expect(plan.cornerPin).toEqual({
+      topLeft: [8, -8],
+      topRight: [-8, -8],
+      bottomRight: [-8, 8],
+      bottomLeft: [8, 8],
+      referenceWidth: 400,
+      referenceHeight: 200,
+    })
+    expect(plan.properties).toHaveLength(8)
+    const topLeftX = plan.properties.find((p) => p.property === 'cornerPinTopLeftX')!
+    expect(topLeftX.keyframes).toEqual([
+      { frame: 10, value: 8 },
+      { frame: 11, value: 104 },
+    ])
+  })
+})
+
+describe('getDefaultTrackRegion', () => {
+  it("uses the target's footprint over the clip", () => {
+    const region = getDefaultTrackRegion(videoItem(), textItem(), canvas)
+    expect(region.x).toBeCloseTo(560 / 1920)
+    expect(region.y).toBeCloseTo(590 / 1080)
+    expect(region.width).toBeCloseTo(400 / 1920)
+    expect(region.height).toBeCloseTo(100 / 1080)
+  })
+
+  it('falls back to a centered box without a target', () => {
+    expect(getDefaultTrackRegion(videoItem(), undefined, canvas)).toEqual({
+      x: 0.4,
+      y: 0.4,
+      width: 0.2,
+      height: 0.2,
+    })
+  })
+})
+
+describe('getTrackSourceTimes', () => {
+  it('follows source start and speed', () => {
+    const times = getTrackSourceTimes(
+      videoItem({ sourceStart: 30, speed: 2 } as Partial<TimelineItem>),
+      undefined,
+      0,
+      3,
+      30,
+    )
+    expect(times[0]).toBeCloseTo(1)
+    expect(times[1]).toBeCloseTo(1 + 2 / 30)
+    expect(times[2]).toBeCloseTo(1 + 4 / 30)
+  })
+})
@@ -0,0 +1,318 @@
+import type {
+  AnimatableProperty,
+  CornerPinAnimatableProperty,
+  ItemKeyframes,
+} from '@/types/keyframe'
+import type { TimelineItem, TimelineItemCornerPin } from '@/types/timeline'
+import type { CanvasSettings } from '@/types/transform'
+import type {
+  MotionTrack,
+  MotionTrackMode,
+  MotionTrackRegion,
+  TrackPoint,
+} from '@/infrastructure/analysis/motion-tracking'
+import {
+  getSourceDimensions,
+  resolveCornerPinTargetRect,
+  resolveTransform,
+} from '@/features/timeline/deps/composition-runtime'
+import {
+  getItemNaturalSourceSeconds,
+  resolveTimeRemapCurve,
+  sampleTimeRemap,
+} from '@/features/timeline/deps/keyframes'
+import { resolveMediaUrl } from '@/features/timeline/deps/media-library-resolver'
+import { importMotionTracking } from '../deps/analysis'
+
+/**
+ * - `transform`: the target follows the track with x/y (and, for planar
+ *   tracks, rotation and scale) keyframes. Used for "parent to tracker"
+ *   callouts and for moving mask shapes with the tracked object.
+ * - `cornerPin`: the target's corners are pinned to the tracked quad.
+ */
+export type MotionTrackTarget = 'transform' | 'cornerPin'
+
+export interface MotionTrackApplyOptions {
+  target: MotionTrackTarget
+  /** Planar tracks only */
+  includeRotation: boolean
+  /** Planar tracks only */
+  includeScale: boolean
+}
+
+export interface MotionTrackKeyframePlan {
+  itemId: string
+  /** Base corner pin for `cornerPin` targets (first tracked frame) */
+  cornerPin?: TimelineItemCornerPin
+  properties: Array<{
+    property: AnimatableProperty
+    keyframes: Array<{ frame: number; value: number }>
+  }>
+}
+
+interface CanvasRect {
+  x: number
+  y: number
+  width: number
+  height: number
+}
+
+const DEFAULT_TRACK_REGION: MotionTrackRegion = { x: 0.4, y: 0.4, width: 0.2, height: 0.2 }
+const MIN_REGION_SIZE = 0.02
+
+const CORNER_PIN_PROPERTIES: Array<[CornerPinAnimatableProperty, CornerPinAnimatableProperty]> = [
+  ['cornerPinTopLeftX', 'cornerPinTopLeftY'],
+  ['cornerPinTopRightX', 'cornerPinTopRightY'],
+  ['cornerPinBottomRightX', 'cornerPinBottomRightY'],
+  ['cornerPinBottomLeftX', 'cornerPinBottomLeftY'],
+]
+
+function roundValue(value: number): number {
+  return Math.round(value * 100) / 100
+}
+
+function clamp01(value: number): number {
+  return Math.max(0, Math.min(1, value))
+}
+
+/**
+ * Where an item's pixels land on the canvas (top-left origin), ignoring
+ * rotation. Contain-fit media resolve to the media rect inside the box, which
+ * is also the corner pin target.
+ */
+export function getItemContentRect(item: TimelineItem, canvas: CanvasSettings): CanvasRect {
+  const sourceDimensions = getSourceDimensions(item)
+  const resolved = resolveTransform(item, canvas, sourceDimensions)
+  const content = resolveCornerPinTargetRect(resolved.width, resolved.height, {
+    sourceWidth: sourceDimensions?.width,
+    sourceHeight: sourceDimensions?.height,
+  })
+  return {
+    x: canvas.width / 2 + resolved.x - resolved.width / 2 + content.x,
+    y: canvas.height / 2 + resolved.y - resolved.height / 2 + content.y,
+    width: content.width,
+    height: content.height,
+  }
+}
+
+function toCanvasPoint(rect: CanvasRect, point: TrackPoint): TrackPoint {
+  return [rect.x + point[0] * rect.width, rect.y + point[1] * rect.height]
+}
+
+/**
+ * Region to start tracking from: the target's footprint over the tracked
+ * clip when it overlaps, otherwise a small centered box.
+ */
+export function getDefaultTrackRegion(
+  trackedItem: TimelineItem,
+  targetItem: TimelineItem | undefined,
+  canvas: CanvasSettings,
+): MotionTrackRegion {
+  if (!targetItem) return DEFAULT_TRACK_REGION
+
+  const clipRect = getItemContentRect(trackedItem, canvas)
+  const targetRect = getItemContentRect(targetItem, canvas)
+  if (clipRect.width <= 0 || clipRect.height <= 0) return DEFAULT_TRACK_REGION
+
+  const left = clamp01((targetRect.x - clipRect.x) / clipRect.width)
+  const top = clamp01((targetRect.y - clipRect.y) / clipRect.height)
+  const right = clamp01((targetRect.x + targetRect.width - clipRect.x) / clipRect.width)
+  const bottom = clamp01((targetRect.y + targetRect.height - clipRect.y) / clipRect.height)
+  if (right - left < MIN_REGION_SIZE || bottom - top < MIN_REGION_SIZE) {
+    return DEFAULT_TRACK_REGION
+  }
+
+  return { x: left, y: top, width: right - left, height: bottom - top }
+}
+
+/** Source time (seconds) for every item frame in [startFrame, endFrame). */
+export function getTrackSourceTimes(
+  item: TimelineItem,
+  itemKeyframes: ItemKeyframes | undefined,
+  startFrame: number,
+  endFrame: number,
+  fps: number,
+): number[] {
+  const curve = resolveTimeRemapCurve(item, itemKeyframes, fps)
+  const times: number[] = []
+  for (let frame = startFrame; frame < endFrame; frame++) {
+    times.push(
+      curve ? sampleTimeRemap(curve, frame) : getItemNaturalSourceSeconds(item, frame, fps),
+    )
+  }
+  return times
+}
+
+/**
+ * Convert a track (sampled from `startFrame` of `trackedItem`, one sample per
+ * timeline frame) into keyframes on `targetItem`. Samples outside the
+ * target's duration are dropped.
+ */
+export function planMotionTrackKeyframes({
+  track,
+  trackedItem,
+  targetItem,
+  startFrame,
+  canvas,
+  options,
+}: {
+  track: MotionTrack
+  trackedItem: TimelineItem
+  targetItem: TimelineItem
+  startFrame: number
+  canvas: CanvasSettings
+  options: MotionTrackApplyOptions
+}): MotionTrackKeyframePlan {
+  const clipRect = getItemContentRect(trackedItem, canvas)
+  const samples = track.samples.flatMap((sample) => {
+    const frame = trackedItem.from + startFrame + sample.frame - targetItem.from
+    return frame >= 0 && frame < targetItem.durationInFrames ? [{ sample, frame }] : []
+  })
+
+  if (options.target === 'cornerPin') {
+    const targetRect = getItemContentRect(targetItem, canvas)
+    const rectCorners: TrackPoint[] = [
+      [targetRect.x, targetRect.y],
+      [targetRect.x + targetRect.width, targetRect.y],
+      [targetRect.x + targetRect.width, targetRect.y + targetRect.height],
+      [targetRect.x, targetRect.y + targetRect.height],
+    ]
+    const offsetsFor = (corners: readonly TrackPoint[]): Array<[number, number]> =>
+      corners.map((corner, index) => {
+        const [cx, cy] = toCanvasPoint(clipRect, corner)
+        return [roundValue(cx - rectCorners[index]![0]), roundValue(cy - rectCorners[index]![1])]
+      })
+
+    const offsetsPerSample = samples.map(({ sample }) => offsetsFor(sample.corners))
+    const first = offsetsPerSample[0]
+    return {
+      itemId: targetItem.id,
+      cornerPin: first
+        ? {
+            topLeft: first[0]!,
+            topRight: first[1]!,
+            bottomRight: first[2]!,
+            bottomLeft: first[3]!,
+            referenceWidth: targetRect.width,
+            referenceHeight: targetRect.height,
+          }
+        : undefined,
+      properties: CORNER_PIN_PROPERTIES.flatMap(([propertyX, propertyY], corner) => [
+        {
+          property: propertyX,
+          keyframes: samples.map(({ frame }, index) => ({
+            frame,
+            value: offsetsPerSample[index]![corner]![0],
+          })),
+        },
+        {
+          property: propertyY,
+          keyframes: samples.map(({ frame }, index) => ({
+            frame,
+            value: offsetsPerSample[index]![corner]![1],
+          })),
+        },
+      ]),
+    }
+  }
+
+  const origin = track.samples[0]
+  const base = resolveTransform(targetItem, canvas, getSourceDimensions(targetItem))
+  const isPlanar = track.mode === 'planar'
+  const includeRotation = isPlanar && options.includeRotation
+  const includeScale = isPlanar && options.includeScale
+  const originCenter: TrackPoint = origin ? toCanvasPoint(clipRect, origin.center) : [0, 0]
+  // Target center relative to the tracked center; rotated and scaled with the track.
+  const relX = canvas.width / 2 + base.x - originCenter[0]
+  const relY = canvas.height / 2 + base.y - originCenter[1]
+
+  const resolved = samples.map(({ sample, frame }) => {
+    const [cx, cy] = toCanvasPoint(clipRect, sample.center)
+    const theta = includeRotation ? (sample.rotation * Math.PI) / 180 : 0
+    const scale = includeScale ? sample.scale : 1
+    const cos = Math.cos(theta) * scale
+    const sin = Math.sin(theta) * scale
+    return {
+      frame,
+      x: cx + cos * relX - sin * relY - canvas.width / 2,
+      y: cy + sin * relX + cos * relY - canvas.height / 2,
+      rotation: base.rotation + (includeRotation ? sample.rotation : 0),
+      width: base.width * scale,
+      height: base.height * scale,
+    }
+  })
+
+  const properties: Array<'x' | 'y' | 'rotation' | 'width' | 'height'> = ['x', 'y']
+  if (includeRotation) properties.push('rotation')
+  if (includeScale) properties.push('width', 'height')
+
+  return {
+    itemId: targetItem.id,
+    properties: properties.map((property) => ({
+      property,
+      keyframes: resolved.map((entry) => ({
+        frame: entry.frame,
+        value: roundValue(entry[property]),
+      })),
+    })),
+  }
+}
+
+/**
+ * Track a region of a video clip from item frame `startFrame` up to (not
+ * including) `endFrame`. When `endFrame` is before `startFrame` the clip is
+ * tracked backward, so sample `i` is item frame `startFrame - i`. Resolves
+ * with the samples gathered so far if aborted.
+ */
+export async function trackClipRegion(
+  item: TimelineItem,
+  itemKeyframes: ItemKeyframes | undefined,
+  {
+    mode,
+    region,
+    startFrame,
+    endFrame,
+    fps,
+    onProgress,
+    signal,
+  }: {
+    mode: MotionTrackMode
+    region: MotionTrackRegion
+    startFrame: number
+    endFrame: number
+    fps: number
+    onProgress?: (percent: number) => void
+    signal?: AbortSignal
+  },
+): Promise<MotionTrack> {
+  if (item.type !== 'video' || !item.mediaId) {
+    throw new Error('Motion tracking needs a video clip')
+  }
+
+  const video = document.createElement('video')
+  video.src = await resolveMediaUrl(item.mediaId)
+  video.muted = true
+  video.preload = 'auto'
+
+  try {
+    await new Promise<void>((resolve, reject) => {
+      video.onloadedmetadata = () => resolve()
+      video.onerror = () => reject(new Error('Failed to load video for motion tracking'))
+    })
+
+    const { trackVideoRegion } = await importMotionTracking()
+    return await trackVideoRegion(video, {
+      mode,
+      region,
+      sourceTimes:
+        endFrame < startFrame
+          ? getTrackSourceTimes(item, itemKeyframes, endFrame + 1, startFrame + 1, fps).reverse()
+          : getTrackSourceTimes(item, itemKeyframes, startFrame, endFrame, fps),
+      onProgress: (progress) => onProgress?.(progress.percent),
+      signal,
+    })
+  } finally {
+    video.removeAttribute('src')
+    video.load()
+  }
+}
@@ -0,0 +1,64 @@
+import { describe, expect, it, vi } from 'vite-plus/test'
+
+vi.mock('../services/waveform-cache', () => ({
+  getMonoPeaks: vi.fn(),
+  waveformCache: { getWaveform: vi.fn() },
+}))
+vi.mock('@/features/timeline/deps/media-library-resolver', () => ({ resolveMediaUrl: vi.fn() }))
+vi.mock('@/features/timeline/deps/media-library-service', () => ({
+  importMediaLibraryService: vi.fn(),
+}))
+
+import {
+  findAudioOffset,
+  MIN_AUDIO_SYNC_CONFIDENCE,
+  normalizeSyncOffsets,
+} from './multicam-sync'
+
+const RATE = 500
+
+/** Deterministic bursty loudness envelope (syllable-like bumps). */
+function createEnvelope(seconds: number, seed: number): Float32Array {
+  const out = new Float32Array(seconds * RATE)
+  let state = seed
+  let level = 0
+  for (let i = 0; i < out.length; i++) {
+    if (i % 50 === 0) {
+      state = (state * 1103515245 + 12345) % 2147483648
+      level = state / 2147483648
+    }
+    out[i] = level
+  }
+  return out
+}
+
+describe('findAudioOffset', () => {
+  const reference = createEnvelope(30, 7)
+
+  it('finds where a later-starting angle begins', () => {
+    const target = reference.slice(3.2 * RATE, 18 * RATE)
+    const result = findAudioOffset(reference, target, RATE)
+    expect(result.offsetSeconds).toBeCloseTo(3.2, 1)
+    expect(result.confidence).toBeGreaterThan(0.9)
+  })
+
+  it('returns negative offsets for angles that started first', () => {
+    const lead = createEnvelope(4, 99)
+    const target = new Float32Array(lead.length + 10 * RATE)
+    target.set(lead)
+    target.set(reference.subarray(0, 10 * RATE), lead.length)
+    const result = findAudioOffset(reference, target, RATE)
+    expect(result.offsetSeconds).toBeCloseTo(-4, 1)
+  })
+
+  it('reports low confidence for unrelated audio', () => {
+    const result = findAudioOffset(reference, createEnvelope(10, 12345), RATE)
+    expect(result.confidence).toBeLessThan(MIN_AUDIO_SYNC_CONFIDENCE)
+  })
+})
+
+describe('normalizeSyncOffsets', () => {
+  it('moves the earliest angle to the clock origin', () => {
+    expect(normalizeSyncOffsets([0, -4, 2.5])).toEqual([4, 0, 6.5])
+  })
+})
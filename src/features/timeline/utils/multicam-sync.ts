@@ -0,0 +1,297 @@
+import type { MediaMetadata } from '@/types/storage'
+import { resolveMediaUrl } from '@/features/timeline/deps/media-library-resolver'
+import { importMediaLibraryService } from '@/features/timeline/deps/media-library-service'
+import { createLogger } from '@/shared/logging/logger'
+import { getMonoPeaks, waveformCache } from '../services/waveform-cache'
+
+const logger = createLogger('MulticamSync')
+
+/** Envelope rate for the full-range lag search. */
+const COARSE_RATE = 10
+/** Envelope rate for the local refinement around the coarse peak. */
+const FINE_RATE = 100
+const REFINE_WINDOW_SECONDS = 0.2
+/** Lags whose overlap is shorter than this are ignored (avoids edge flukes). */
+const MIN_OVERLAP_SECONDS = 2
+/** Correlations below this are treated as "no common audio". */
+export const MIN_AUDIO_SYNC_CONFIDENCE = 0.5
+
+export type MulticamSyncMethod = 'audio' | 'timecode'
+
+export interface AudioOffsetResult {
+  /** Reference-time seconds where the target's first sample lands */
+  offsetSeconds: number
+  /** Pearson correlation of the aligned envelopes (-1..1) */
+  confidence: number
+}
+
+function resampleEnvelope(
+  peaks: ArrayLike<number>,
+  sampleRate: number,
+  rate: number,
+): Float64Array {
+  const factor = sampleRate / rate
+  const length = Math.floor(peaks.length / factor)
+  const out = new Float64Array(length)
+  for (let i = 0; i < length; i++) {
+    const start = Math.floor(i * factor)
+    const end = Math.max(start + 1, Math.floor((i + 1) * factor))
+    let sum = 0
+    for (let j = start; j < end; j++) sum += peaks[j] ?? 0
+    out[i] = sum / (end - start)
+  }
+  return out
+}
+
+function standardize(signal: Float64Array): Float64Array {
+  let mean = 0
+  for (const value of signal) mean += value
+  mean /= signal.length || 1
+  let variance = 0
+  for (const value of signal) variance += (value - mean) ** 2
+  const std = Math.sqrt(variance / (signal.length || 1))
+  const out = new Float64Array(signal.length)
+  if (std === 0) return out
+  for (let i = 0; i < signal.length; i++) out[i] = (signal[i]! - mean) / std
+  return out
+}
+
+function fft(re: Float64Array, im: Float64Array, invert: boolean): void {
+  const n = re.length
+  for (let i = 1, j = 0; i < n; i++) {
+    let bit = n >> 1
+    for (; j & bit; bit >>= 1) j ^= bit
+    j ^= bit
+    if (i < j) {
+      ;[re[i], re[j]] = [re[j]!, re[i]!]
+      ;[im[i], im[j]] = [im[j]!, im[i]!]
+    }
+  }
+  for (let len = 2; len <= n; len <<= 1) {
+    const angle = ((invert ? 2 : -2) * Math.PI) / len
+    const wRe = Math.cos(angle)
+    const wIm = Math.sin(angle)
+    for (let i = 0; i < n; i += len) {
+      let curRe = 1
+      let curIm = 0
+      for (let k = 0; k < len / 2; k++) {
+        const aRe = re[i + k]!
+        const aIm = im[i + k]!
+        const bRe = re[i + k + len / 2]! * curRe - im[i + k + len / 2]! * curIm
+        const bIm = re[i + k + len / 2]! * curIm + im[i + k + len / 2]! * curRe
+        re[i + k] = aRe + bRe
+        im[i + k] = aIm + bIm
+        re[i + k + len / 2] = aRe - bRe
+        im[i + k + len / 2] = aIm - bIm
+        const nextRe = curRe * wRe - curIm * wIm
+        curIm = curRe * wIm + curIm * wRe
+        curRe = nextRe
+      }
+    }
+  }
+  if (invert) {
+    for (let i = 0; i < n; i++) {
+      re[i] = re[i]! / n
+      im[i] = im[i]! / n
+    }
+  }
+}
+
+/**
+ * Full cross-correlation via FFT. Index `k` holds `sum_i a[i + lag] * b[i]`
+ * for `lag = k - (b.length - 1)`.
+ */
+function crossCorrelate(a: Float64Array, b: Float64Array): Float64Array {
+  const outLength = a.length + b.length - 1
+  let size = 1
+  while (size < outLength) size <<= 1
+
+  const aRe = new Float64Array(size)
+  const aIm = new Float64Array(size)
+  const bRe = new Float64Array(size)
+  const bIm = new Float64Array(size)
+  aRe.set(a)
+  // Reversed b turns the convolution into a correlation
+  for (let i = 0; i < b.length; i++) bRe[i] = b[b.length - 1 - i]!
+
+  fft(aRe, aIm, false)
+  fft(bRe, bIm, false)
+  for (let i = 0; i < size; i++) {
+    const re = aRe[i]! * bRe[i]! - aIm[i]! * bIm[i]!
+    aIm[i] = aRe[i]! * bIm[i]! + aIm[i]! * bRe[i]!
+    aRe[i] = re
+  }
+  fft(aRe, aIm, true)
+  return aRe.subarray(0, outLength)
+}
+
+function overlapAt(aLength: number, bLength: number, lag: number): number {
+  return Math.min(aLength, lag + bLength) - Math.max(0, lag)
+}
+
+/** Pearson correlation of `a[i + lag]` against `b[i]` over their overlap. */
+function pearsonAtLag(a: Float64Array, b: Float64Array, lag: number): number {
+  const start = Math.max(0, -lag)
+  const end = Math.min(b.length, a.length - lag)
+  const n = end - start
+  if (n <= 1) return 0
+
+  let sumA = 0
+  let sumB = 0
+  for (let i = start; i < end; i++) {
+    sumA += a[i + lag]!
+    sumB += b[i]!
+  }
+  const meanA = sumA / n
+  const meanB = sumB / n
+  let cov = 0
+  let varA = 0
+  let varB = 0
+  for (let i = start; i < end; i++) {
+    const da = a[i + lag]! - meanA
+    const db = b[i]! - meanB
+    cov += da * db
+    varA += da * da
+    varB += db * db
+  }
+  return varA > 0 && varB > 0 ? cov / Math.sqrt(varA * varB) : 0
+}
+
+/**
+ * Find where `target` starts inside `reference` by correlating loudness
+ * envelopes: a coarse FFT search over every lag, then an exact Pearson
+ * refinement around the best candidate. Inputs are peak envelopes at
+ * `sampleRate` samples per second (waveform peaks work directly).
+ */
+export function findAudioOffset(
+  reference: ArrayLike<number>,
+  target: ArrayLike<number>,
+  sampleRate: number,
+): AudioOffsetResult {
+  const coarseA = standardize(resampleEnvelope(reference, sampleRate, COARSE_RATE))
+  const coarseB = standardize(resampleEnvelope(target, sampleRate, COARSE_RATE))
+  if (coarseA.length === 0 || coarseB.length === 0) {
+    return { offsetSeconds: 0, confidence: 0 }
+  }
+
+  const correlation = crossCorrelate(coarseA, coarseB)
+  const minOverlap = Math.min(MIN_OVERLAP_SECONDS * COARSE_RATE, coarseA.length, coarseB.length)
+  let bestLag = 0
+  let bestScore = -Infinity
+  for (let k = 0; k < correlation.length; k++) {
+    const lag = k - (coarseB.length - 1)
+    const overlap = overlapAt(coarseA.length, coarseB.length, lag)
+    if (overlap < minOverlap) continue
+    const score = correlation[k]! / overlap
+    if (score > bestScore) {
+      bestScore = score
+      bestLag = lag
+    }
+  }
+
+  const fineA = resampleEnvelope(reference, sampleRate, FINE_RATE)
+  const fineB = resampleEnvelope(target, sampleRate, FINE_RATE)
+  const center = Math.round((bestLag * FINE_RATE) / COARSE_RATE)
+  const radius = Math.ceil(REFINE_WINDOW_SECONDS * FINE_RATE)
+  let refinedLag = center
+  let confidence = -Infinity
+  for (let lag = center - radius; lag <= center + radius; lag++) {
+    const score = pearsonAtLag(fineA, fineB, lag)
+    if (score > confidence) {
+      confidence = score
+      refinedLag = lag
+    }
+  }
+
+  return {
+    offsetSeconds: refinedLag / FINE_RATE,
+    confidence: Number.isFinite(confidence) ? confidence : 0,
+  }
+}
+
+/** Shift offsets so the earliest angle sits at the clock origin. */
+export function normalizeSyncOffsets(offsets: number[]): number[] {
+  const min = Math.min(...offsets)
+  return offsets.map((offset) => offset - min)
+}
+
+async function loadEnvelope(mediaId: string): Promise<{ peaks: Float32Array; sampleRate: number }> {
+  const waveform = await waveformCache.getWaveform(mediaId, await resolveMediaUrl(mediaId))
+  return { peaks: getMonoPeaks(waveform), sampleRate: waveform.sampleRate }
+}
+
+/**
+ * Wall-clock recording start of a media file in ms. Prefers the container's
+ * creation date and falls back to the file's modification time minus its
+ * duration (cameras stamp files when recording stops).
+ */
+async function getRecordingStartMs(media: MediaMetadata): Promise<number | null> {
+  try {
+    const { mediaLibraryService } = await importMediaLibraryService()
+    const blob = await mediaLibraryService.getMediaFile(media.id)
+    if (blob) {
+      const { Input, BlobSource, ALL_FORMATS } = await import('mediabunny')
+      const input = new Input({ source: new BlobSource(blob as File), formats: ALL_FORMATS })
+      try {
+        const tags = await input.getMetadataTags()
+        if (tags.date && !Number.isNaN(tags.date.getTime())) {
+          return tags.date.getTime()
+        }
+      } finally {
+        input.dispose()
+      }
+    }
+  } catch (error) {
+    logger.warn(`Failed to read recording date for ${media.id}`, error)
+  }
+
+  return typeof media.fileLastModified === 'number'
+    ? media.fileLastModified - media.duration * 1000
+    : null
+}
+
+/**
+ * Seconds from the multicam clock origin to each angle's first frame. Audio
+ * sync correlates every angle against the first; angles without usable
+ * common audio (or without a recording date) stay at the origin.
+ */
+export async function computeMulticamSyncOffsets(
+  medias: MediaMetadata[],
+  method: MulticamSyncMethod,
+): Promise<number[]> {
+  if (medias.length === 0) return []
+
+  if (method === 'timecode') {
+    const starts = await Promise.all(medias.map(getRecordingStartMs))
+    const known = starts.filter((start): start is number => start !== null)
+    if (known.length === 0) return medias.map(() => 0)
+    const origin = Math.min(...known)
+    return normalizeSyncOffsets(
+      starts.map((start) => (start === null ? 0 : (start - origin) / 1000)),
+    )
+  }
+
+  const reference = await loadEnvelope(medias[0]!.id)
+  const offsets = [0]
+  for (const media of medias.slice(1)) {
+    try {
+      const target = await loadEnvelope(media.id)
+      // Waveform peaks share one rate across media; guard anyway
+      const targetPeaks =
+        target.sampleRate === reference.sampleRate
+          ? target.peaks
+          : resampleEnvelope(target.peaks, target.sampleRate, reference.sampleRate)
+      const result = findAudioOffset(reference.peaks, targetPeaks, reference.sampleRate)
+      if (result.confidence < MIN_AUDIO_SYNC_CONFIDENCE) {
+        logger.warn(`Low audio sync confidence for ${media.id}`, result)
+        offsets.push(0)
+        continue
+      }
+      offsets.push(result.offsetSeconds)
+    } catch (error) {
+      logger.warn(`Audio sync failed for ${media.id}`, error)
+      offsets.push(0)
+    }
+  }
+  return normalizeSyncOffsets(offsets)
+}
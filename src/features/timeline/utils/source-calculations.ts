@@ -36,7 +36,12 @@ interface SourceProperties {
 }
 
 export function getSourceProperties(item: TimelineItem): SourceProperties {
-  if (item.type !== 'video' && item.type !== 'audio' && item.type !== 'composition') {
+  if (
+    item.type !== 'video' &&
+    item.type !== 'audio' &&
+    item.type !== 'composition' &&
+    item.type !== 'multicam'
+  ) {
     return {
       sourceStart: 0,
       sourceEnd: undefined,
@@ -268,14 +273,19 @@ export function getSafeTrimBefore(
  * Check if an item is a media item (has source properties).
  */
 export function isMediaItem(item: TimelineItem): item is TimelineItem & {
-  type: 'video' | 'audio' | 'composition'
+  type: 'video' | 'audio' | 'composition' | 'multicam'
   sourceDuration?: number
   sourceStart?: number
   sourceEnd?: number
   sourceFps?: number
   speed?: number
 } {
-  return item.type === 'video' || item.type === 'audio' || item.type === 'composition'
+  return (
+    item.type === 'video' ||
+    item.type === 'audio' ||
+    item.type === 'composition' ||
+    item.type === 'multicam'
+  )
 }
 
 /**
@@ -3,9 +3,11 @@ import type { TimelineItem } from '@/types/timeline'
 import type { MediaTranscript } from '@/types/storage'
 import {
   buildRemovalRangesByMediaId,
+  buildRemovalRangesForRuns,
   buildTranscriptTokens,
   findActiveTokenIndex,
   getSelectedTokenSlice,
+  getSpeakerTokenRuns,
 } from './transcript-edit-model'
 
 function makeItem(
@@ -271,3 +273,61 @@ describe('getSelectedTokenSlice', () => {
     expect(getSelectedTokenSlice(tokens, -1, 2)).toEqual([])
   })
 })
+
+describe('getSpeakerTokenRuns', () => {
+  const transcript: MediaTranscript = {
+    ...makeTranscript('m1', []),
+    segments: [
+      {
+        text: 'a b',
+        start: 0,
+        end: 1,
+        speakerId: 'speaker-1',
+        words: [
+          { text: 'a', start: 0.0, end: 0.5 },
+          { text: 'b', start: 0.5, end: 1.0 },
+        ],
+      },
+      {
+        text: 'c',
+        start: 1,
+        end: 1.5,
+        speakerId: 'speaker-2',
+        words: [{ text: 'c', start: 1.0, end: 1.5 }],
+      },
+      {
+        text: 'd',
+        start: 1.5,
+        end: 2,
+        speakerId: 'speaker-1',
+        words: [{ text: 'd', start: 1.5, end: 2.0 }],
+      },
+    ],
+  }
+  const tokens = buildTranscriptTokens(
+    [makeItem({ id: 'a', mediaId: 'm1' })],
+    { m1: transcript },
+    FPS,
+  )
+
+  it('tags tokens with their segment speaker', () => {
+    expect(tokens.map((t) => t.speakerId)).toEqual([
+      'speaker-1',
+      'speaker-1',
+      'speaker-2',
+      'speaker-1',
+    ])
+  })
+
+  it('splits a speaker selection where another speaker talks', () => {
+    const runs = getSpeakerTokenRuns(tokens, 'm1', 'speaker-1')
+
+    expect(runs.map((run) => run.map((t) => t.text))).toEqual([['a', 'b'], ['d']])
+    expect(buildRemovalRangesForRuns(runs)).toEqual({
+      m1: [
+        { start: 0.0, end: 1.0 },
+        { start: 1.5, end: 2.0 },
+      ],
+    })
+  })
+})
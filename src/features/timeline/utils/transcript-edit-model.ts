@@ -29,6 +29,8 @@ export interface TranscriptToken {
   startFrame: number
   /** Exclusive timeline frame where the word ends. */
   endFrame: number
+  /** Diarized speaker of the word's transcript segment, scoped to `mediaId`. */
+  speakerId?: string
 }
 
 export type TranscriptableItem = Extract<TimelineItem, { type: 'video' | 'audio' }> & {
@@ -39,9 +41,15 @@ export function isTranscriptableItem(item: TimelineItem | undefined): item is Tr
   return !!item && (item.type === 'video' || item.type === 'audio') && !!item.mediaId
 }
 
-function collectWords(transcript: MediaTranscript): MediaTranscriptWord[] {
+type SpokenWord = MediaTranscriptWord & { speakerId?: string }
+
+function collectWords(transcript: MediaTranscript): SpokenWord[] {
   return transcript.segments
-    .flatMap((segment) => segment.words ?? [])
+    .flatMap((segment) =>
+      (segment.words ?? []).map((word) =>
+        segment.speakerId ? { ...word, speakerId: segment.speakerId } : word,
+      ),
+    )
     .filter((word) => word.end > word.start && word.text.trim().length > 0)
     .toSorted((left, right) => left.start - right.start)
 }
@@ -97,6 +105,7 @@ export function buildTranscriptTokens(
         sourceEnd: clampedEnd,
         startFrame: sourceSecondsToTimelineFrame(item, clampedStart, timelineFps),
         endFrame: sourceSecondsToTimelineFrame(item, clampedEnd, timelineFps),
+        ...(word.speakerId ? { speakerId: word.speakerId } : {}),
       })
     })
   }
@@ -180,3 +189,42 @@ export function getSelectedTokenSlice(
   const hi = Math.max(anchorIndex, focusIndex)
   return tokens.slice(lo, hi + 1)
 }
+
+/**
+ * Every word spoken by one speaker, as runs of document-adjacent tokens. Words
+ * by other speakers split the runs, so removing or copying the runs never
+ * takes the other speaker's words with them.
+ */
+export function getSpeakerTokenRuns(
+  tokens: readonly TranscriptToken[],
+  mediaId: string,
+  speakerId: string,
+): TranscriptToken[][] {
+  const runs: TranscriptToken[][] = []
+  let current: TranscriptToken[] | null = null
+  for (const token of tokens) {
+    if (token.mediaId !== mediaId || token.speakerId !== speakerId) {
+      current = null
+      continue
+    }
+    if (!current) {
+      current = []
+      runs.push(current)
+    }
+    current.push(token)
+  }
+  return runs
+}
+
+/** {@link buildRemovalRangesByMediaId} over several disjoint selection runs. */
+export function buildRemovalRangesForRuns(
+  runs: readonly (readonly TranscriptToken[])[],
+): Record<string, RemoveSilenceRange[]> {
+  const rangesByMediaId: Record<string, RemoveSilenceRange[]> = {}
+  for (const run of runs) {
+    for (const [mediaId, ranges] of Object.entries(buildRemovalRangesByMediaId(run))) {
+      ;(rangesByMediaId[mediaId] ??= []).push(...ranges)
+    }
+  }
+  return rangesByMediaId
+}
@@ -30,7 +30,10 @@ import {
 import { useItemsStore } from '@/features/timeline/stores/items-store'
 import { useTimelineSettingsStore } from '@/features/timeline/stores/timeline-settings-store'
 import { useMediaLibraryStore } from '@/features/media-library/stores/media-library-store'
+import { useTransitionsStore } from '@/features/timeline/stores/transitions-store'
 import { createClassicTrack } from '@/features/timeline/utils/classic-tracks'
+import { findEditNeighborsWithTransitions } from '@/features/timeline/utils/transition-linked-neighbors'
+import { useEditorStore } from '@/shared/state/editor'
 import { seedMediaLibrary } from './seed-media'
 import {
   addItem,
@@ -47,7 +50,22 @@ import {
   addEffect,
   removeEffect,
   updateItemTransform,
+  rippleDeleteItems,
+  rippleTrimItem,
+  rollingTrimItems,
+  slipItem,
+  slideItem,
+  rateStretchItem,
+  resetSpeedWithRipple,
+  removeTranscriptRangesFromItems,
+  joinItems,
+  insertFreezeFrameStill,
+  captureVideoFrame,
+  getFreezeFrameFileName,
+  getFreezeFrameSourceSeconds,
 } from '@/features/timeline/stores/timeline-actions'
+import { buildGeneratedMediaOpfsPath } from '@/features/media-library/services/media-asset-helpers'
+import type { RemoveSilenceRange } from '@/features/timeline/stores/actions/edit/range-removal-actions'
 
 const log = createLogger('HeadlessEdit')
 
@@ -57,8 +75,19 @@ export type EditOp = Record<string, unknown> & { op: string }
 export interface HeadlessEditInput {
   project: Project
   ops: EditOp[]
-  /** MediaMetadata for any media referenced by ops (e.g. addClip), keyed for codec/fps/duration lookups. */
-  media?: Array<{ mediaId: string; metadata?: MediaMetadata }>
+  /**
+   * MediaMetadata for any media referenced by ops (e.g. addClip), keyed for
+   * codec/fps/duration lookups. `url` serves the source bytes for ops that
+   * decode frames (freezeFrame).
+   */
+  media?: Array<{ mediaId: string; url?: string; metadata?: MediaMetadata }>
+}
+
+/** Media created by an op (e.g. a freeze-frame still). The driver writes it to the workspace. */
+export interface HeadlessGeneratedMedia {
+  metadata: MediaMetadata
+  /** Source file bytes, base64-encoded so they survive the page → driver hop. */
+  base64: string
 }
 
 export interface HeadlessEditResult {
@@ -67,6 +96,14 @@ export interface HeadlessEditResult {
   project: Project
   applied: number
   results: Array<{ op: string; ok: boolean; detail?: unknown; error?: string }>
+  /** New media the edited project references; empty when no op generated any. */
+  generatedMedia: HeadlessGeneratedMedia[]
+}
+
+/** Per-run state ops can read (source URLs) and append to (generated media). */
+interface EditContext {
+  mediaUrls: Map<string, string>
+  generatedMedia: HeadlessGeneratedMedia[]
 }
 
 const asString = (value: unknown, fallback?: string): string | undefined =>
@@ -89,10 +126,43 @@ function resolveTrackId(preferred: unknown, kind: 'video' | 'audio' = 'video'):
   return fallback.id
 }
 
+const asStringArray = (value: unknown): string[] =>
+  Array.isArray(value) ? (value.filter((x) => typeof x === 'string') as string[]) : []
+
+function findItem(id: string | undefined, opName: string): TimelineItem {
+  const item = id ? useItemsStore.getState().items.find((i) => i.id === id) : undefined
+  if (!item) throw new Error(`${opName}: no item ${id ?? '(missing id)'}`)
+  return item
+}
+
+/** Snapshot of an item's timing, for reporting what an edit actually did (actions clamp). */
+function timing(id: string) {
+  const item = useItemsStore.getState().items.find((i) => i.id === id)
+  if (!item) return null
+  return {
+    id,
+    from: item.from,
+    durationInFrames: item.durationInFrames,
+    ...('sourceStart' in item && item.sourceStart !== undefined
+      ? { sourceStart: item.sourceStart, sourceEnd: item.sourceEnd }
+      : {}),
+    ...('speed' in item && item.speed !== undefined ? { speed: item.speed } : {}),
+  }
+}
+
 function newId(): string {
   return crypto.randomUUID()
 }
 
+export async function blobToBase64(blob: Blob): Promise<string> {
+  const bytes = new Uint8Array(await blob.arrayBuffer())
+  let binary = ''
+  for (let i = 0; i < bytes.length; i += 0x8000) {
+    binary += String.fromCharCode(...bytes.subarray(i, i + 0x8000))
+  }
+  return btoa(binary)
+}
+
 /** Find a non-group track of the given kind, or create one (video on top, audio at bottom). */
 function getOrCreateTrack(kind: 'video' | 'audio'): string {
   const all = tracks()
@@ -145,7 +215,7 @@ function buildTextItem(op: EditOp): TextItem {
 }
 
 /** Apply a single op by driving the real timeline action modules. Throws on bad input. */
-function applyOp(op: EditOp): unknown {
+async function applyOp(op: EditOp, context: EditContext): Promise<unknown> {
   switch (op.op) {
     case 'addText': {
       const item = buildTextItem(op)
@@ -371,6 +441,163 @@ function applyOp(op: EditOp): unknown {
       updateItemTransform(id, op.transform as Partial<TransformProperties>)
       return { id }
     }
+    case 'setLinkedSelection': {
+      if (typeof op.enabled !== 'boolean') throw new Error('setLinkedSelection requires `enabled`')
+      useEditorStore.getState().setLinkedSelectionEnabled(op.enabled)
+      return { enabled: op.enabled }
+    }
+    case 'rippleDelete': {
+      const ids = asStringArray(op.ids)
+      if (ids.length === 0) throw new Error('rippleDelete requires non-empty `ids`')
+      rippleDeleteItems(ids)
+      return { removed: ids }
+    }
+    case 'rippleTrim': {
+      const id = asString(op.id)
+      const amount = asNumber(op.amount)
+      if (!id || amount === undefined || (op.handle !== 'start' && op.handle !== 'end')) {
+        throw new Error('rippleTrim requires `id`, `handle` ("start"|"end") and `amount`')
+      }
+      findItem(id, 'rippleTrim')
+      rippleTrimItem(id, op.handle, amount)
+      return timing(id)
+    }
+    case 'roll': {
+      const left = asString(op.leftClipId)
+      const right = asString(op.rightClipId)
+      const delta = asNumber(op.delta)
+      if (!left || !right || delta === undefined) {
+        throw new Error('roll requires `leftClipId`, `rightClipId` and `delta`')
+      }
+      findItem(left, 'roll')
+      findItem(right, 'roll')
+      rollingTrimItems(left, right, delta)
+      return { left: timing(left), right: timing(right) }
+    }
+    case 'slip': {
+      const id = asString(op.id)
+      const delta = asNumber(op.delta)
+      if (!id || delta === undefined) throw new Error('slip requires `id` and `delta`')
+      findItem(id, 'slip')
+      slipItem(id, delta)
+      return timing(id)
+    }
+    case 'slide': {
+      const id = asString(op.id)
+      const delta = asNumber(op.delta)
+      if (!id || delta === undefined) throw new Error('slide requires `id` and `delta`')
+      const item = findItem(id, 'slide')
+      // Same neighbor resolution as the slide tool: adjacent clips, else transition-linked ones.
+      const neighbors = findEditNeighborsWithTransitions(
+        item,
+        useItemsStore.getState().items,
+        useTransitionsStore.getState().transitions,
+      )
+      // An explicit `null` neighbor id means "no neighbor" (slide into a gap).
+      const leftId =
+        op.leftNeighborId === null
+          ? null
+          : (asString(op.leftNeighborId) ?? neighbors.leftNeighbor?.id ?? null)
+      const rightId =
+        op.rightNeighborId === null
+          ? null
+          : (asString(op.rightNeighborId) ?? neighbors.rightNeighbor?.id ?? null)
+      slideItem(id, delta, leftId, rightId)
+      return { item: timing(id), left: leftId && timing(leftId), right: rightId && timing(rightId) }
+    }
+    case 'rateStretch': {
+      const id = asString(op.id)
+      const item = findItem(id, 'rateStretch')
+      const currentSpeed = ('speed' in item && item.speed) || 1
+      const speed = asNumber(op.speed)
+      const duration = asNumber(op.durationInFrames)
+      if (speed === undefined && duration === undefined) {
+        throw new Error('rateStretch requires `speed` or `durationInFrames`')
+      }
+      // Source coverage stays constant (duration × speed), as with the rate-stretch tool.
+      const newDuration =
+        duration ?? Math.max(1, Math.round((item.durationInFrames * currentSpeed) / speed!))
+      const newSpeed = speed ?? (item.durationInFrames * currentSpeed) / newDuration
+      rateStretchItem(item.id, asNumber(op.from) ?? item.from, newDuration, newSpeed)
+      return timing(item.id)
+    }
+    case 'resetSpeed': {
+      const ids = asStringArray(op.ids)
+      if (ids.length === 0) throw new Error('resetSpeed requires non-empty `ids`')
+      resetSpeedWithRipple(ids)
+      return { ids }
+    }
+    case 'removeRanges': {
+      const ids = asStringArray(op.ids)
+      const ranges = op.ranges as Record<string, RemoveSilenceRange[]> | undefined
+      if (ids.length === 0 || !ranges || typeof ranges !== 'object') {
+        throw new Error('removeRanges requires `ids` and `ranges` ({ mediaId: [{ start, end }] })')
+      }
+      return removeTranscriptRangesFromItems(ids, ranges)
+    }
+    case 'join': {
+      const ids = asStringArray(op.ids)
+      if (ids.length < 2) throw new Error('join requires at least two `ids`')
+      const before = useItemsStore.getState().items.length
+      joinItems(ids)
+      const joined = before - useItemsStore.getState().items.length
+      if (joined === 0) throw new Error('join: items are not contiguous cuts of the same source')
+      return { joined }
+    }
+    case 'freezeFrame': {
+      const id = asString(op.id)
+      const frame = asNumber(op.frame)
+      if (!id || frame === undefined) throw new Error('freezeFrame requires `id` and `frame`')
+      const item = findItem(id, 'freezeFrame')
+      if (item.type !== 'video') throw new Error(`freezeFrame: ${id} is not a video clip`)
+      const mediaId = item.mediaId ?? ''
+      const media = useMediaLibraryStore.getState().mediaById[mediaId]
+      const url = context.mediaUrls.get(mediaId)
+      if (!media || !url) {
+        throw new Error(`freezeFrame: media ${mediaId || '(none)'} is not in the workspace`)
+      }
+
+      const fps = useTimelineSettingsStore.getState().fps
+      const timestampSeconds = getFreezeFrameSourceSeconds(item, frame, fps, media.fps)
+      const response = await fetch(url)
+      if (!response.ok) throw new Error(`freezeFrame: could not read ${media.fileName}`)
+      const captured = await captureVideoFrame(await response.blob(), timestampSeconds)
+      if (!captured) throw new Error(`freezeFrame: no frame at ${timestampSeconds}s`)
+
+      const stillId = newId()
+      const fileName = getFreezeFrameFileName(item.label, timestampSeconds)
+      const ok = insertFreezeFrameStill(id, frame, {
+        mediaId: stillId,
+        src: '',
+        label: fileName,
+        width: captured.width,
+        height: captured.height,
+      })
+      if (!ok) throw new Error(`freezeFrame failed (item ${id} @ frame ${frame})`)
+
+      const createdAt = Date.now()
+      context.generatedMedia.push({
+        metadata: {
+          id: stillId,
+          storageType: 'opfs',
+          opfsPath: buildGeneratedMediaOpfsPath(stillId),
+          fileName,
+          fileSize: captured.blob.size,
+          mimeType: 'image/png',
+          duration: 0,
+          width: captured.width,
+          height: captured.height,
+          fps: 0,
+          codec: 'png',
+          bitrate: 0,
+          tags: ['freeze-frame'],
+          createdAt,
+          updatedAt: createdAt,
+        },
+        base64: await blobToBase64(captured.blob),
+      })
+      return { itemId: id, frame, mediaId: stillId, fileName }
+    }
     default:
       throw new Error(`Unknown edit op: ${String(op.op)}`)
   }
@@ -380,13 +607,19 @@ export async function editProject(input: HeadlessEditInput): Promise<HeadlessEdi
   const { project: migrated } = migrateProject(input.project)
   await hydrateTimelineStoresFromProject(migrated)
   seedMediaLibrary(input.media)
+  const context: EditContext = {
+    mediaUrls: new Map(
+      (input.media ?? []).flatMap((m) => (m.url ? [[m.mediaId, m.url] as const] : [])),
+    ),
+    generatedMedia: [],
+  }
 
   log.info('Headless edit starting', { ops: input.ops.length })
 
   const results: HeadlessEditResult['results'] = []
   for (const op of input.ops) {
     try {
-      const detail = applyOp(op)
+      const detail = await applyOp(op, context)
       results.push({ op: op.op, ok: true, detail })
     } catch (error) {
       results.push({
@@ -408,5 +641,6 @@ export async function editProject(input: HeadlessEditInput): Promise<HeadlessEdi
     project: { ...migrated, timeline },
     applied: results.length,
     results,
+    generatedMedia: context.generatedMedia,
   }
 }
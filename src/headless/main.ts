@@ -20,31 +20,45 @@ import type { TimelineTrack, TimelineItem } from '@/types/timeline'
 import type { Transition } from '@/types/transition'
 import type { ItemKeyframes } from '@/types/keyframe'
 import type { AudioEqSettings } from '@/types/audio'
-import type { CompositionInputProps } from '@/types/export'
+import type { ProjectColorSpace } from '@/types/color'
+import type { CompositionInputProps, ExportLoudnessReport } from '@/types/export'
 import type { MediaMetadata } from '@/types/storage'
 import type { ItemEffect } from '@/types/effects'
 
 import { createLogger } from '@/shared/logging/logger'
 import { migrateProject } from '@/shared/projects/migrations'
 import { convertTimelineToComposition } from '@/features/export/utils/timeline-to-composition'
+import { resolveExportCanvas } from '@/features/export/utils/canvas-variant-export'
 import {
   renderComposition,
   renderAudioOnly,
 } from '@/features/export/utils/canvas-render-orchestrator'
-import type { ClientExportSettings, RenderProgress } from '@/features/export/utils/client-renderer'
+import type {
+  ClientExportSettings,
+  ClientRenderResult,
+  RenderProgress,
+} from '@/features/export/utils/client-renderer'
 import {
   getSupportedCodecs,
   selectFallbackVideoCodec,
   getPreferredContainerForCodec,
+  supportsAlphaVideo,
 } from '@/features/export/utils/client-renderer'
 import type { ClientVideoContainer } from '@/features/export/utils/client-renderer'
+import type { ImageSequenceSink } from '@/features/export/utils/image-sequence'
+import {
+  abortJob,
+  registerJobController,
+  unregisterJobController,
+} from '@/features/export/utils/render-queue-control'
 import { resolveMediaUrls } from '@/features/media-library/utils/media-resolver'
 import { blobUrlManager } from '@/infrastructure/browser/blob-url-manager'
 import {
   useCompositionsStore,
   type SubComposition,
 } from '@/features/export/deps/timeline-compositions'
-import { editProject } from './edit'
+import { blobToBase64, editProject } from './edit'
+import { mcpListTools, mcpCallTool } from './mcp'
 import { seedMediaLibrary } from './seed-media'
 
 const log = createLogger('Headless')
@@ -73,12 +87,15 @@ interface HeadlessTimelineInput {
   outPoint?: number | null
   keyframes?: ItemKeyframes[]
   backgroundColor?: string
+  colorSpace?: ProjectColorSpace
   busAudioEq?: AudioEqSettings
   masterBusDb?: number
   compositions?: SubComposition[]
   media?: HeadlessMediaSource[]
   settings: ClientExportSettings
   outputFileName?: string
+  /** Driver job id; registers an AbortController so `cancelRender(jobId)` can interrupt it. */
+  jobId?: string
 }
 
 /** Render a full Project object (runs migrations, then extracts the timeline). */
@@ -95,7 +112,10 @@ interface HeadlessProjectInput {
    */
   inPoint?: number | null
   outPoint?: number | null
+  /** Render one of the project's canvas variants instead of its base canvas. */
+  canvasVariantId?: string
   outputFileName?: string
+  jobId?: string
 }
 
 interface HeadlessRenderSummary {
@@ -106,6 +126,10 @@ interface HeadlessRenderSummary {
   fileName: string
   /** Non-fatal issues (e.g. audio codec not encodable here, so audio was omitted). */
   warnings: string[]
+  /** Measured mix loudness and the normalization applied (audio/video renders). */
+  loudness?: ExportLoudnessReport
+  /** Image sequences the driver wrote frame by frame: how many frames it received. */
+  frameCount?: number
 }
 
 /**
@@ -135,6 +159,23 @@ async function audioCodecUnsupported(audioCodec: string | undefined): Promise<bo
 }
 
 type ProgressSink = (progress: RenderProgress) => void
+type SequenceFrameWriter = (name: string, base64: string) => Promise<void>
+
+interface DriverSequenceSink extends ImageSequenceSink {
+  frameCount(): number
+}
+
+/**
+ * Job ids the driver has cancelled. The driver assigns a fresh id per job, so a
+ * cancel can be recorded whenever it arrives — before the render starts, while
+ * it sets up, or after its controller is gone — and checked when the render
+ * reaches each of those points.
+ */
+const pendingCancels = new Set<string>()
+
+function abortError(): DOMException {
+  return new DOMException('Render cancelled', 'AbortError')
+}
 
 function reportProgress(progress: RenderProgress): void {
   const sink = (globalThis as unknown as { __freecutProgress?: ProgressSink }).__freecutProgress
@@ -146,6 +187,27 @@ function reportProgress(progress: RenderProgress): void {
   }
 }
 
+/**
+ * Hand image-sequence frames to the driver one at a time as they are encoded;
+ * it writes each into the output folder, so the sequence is never held in
+ * memory. Null when the driver exposes no writer: the frames are then zipped
+ * and downloaded.
+ */
+function createDriverSequenceSink(): DriverSequenceSink | null {
+  const writeToDriver = (globalThis as unknown as { __freecutSequenceFrame?: SequenceFrameWriter })
+    .__freecutSequenceFrame
+  if (!writeToDriver) return null
+  let frameCount = 0
+  return {
+    async writeFrame(name, png) {
+      await writeToDriver(name, await blobToBase64(png))
+      frameCount++
+    },
+    finish: () => null,
+    frameCount: () => frameCount,
+  }
+}
+
 /**
  * Register media URLs so resolveMediaUrls() + the engine's sub-comp media
  * lookup (blobUrlManager.get) resolve to them. We register the URL WITHOUT
@@ -173,7 +235,9 @@ function triggerDownload(blob: Blob, fileName: string): void {
 }
 
 function defaultFileName(settings: ClientExportSettings): string {
-  return `freecut-export.${settings.container}`
+  return settings.mode === 'image-sequence'
+    ? 'freecut-export.zip'
+    : `freecut-export.${settings.container}`
 }
 
 async function detectWebGpu(): Promise<boolean> {
@@ -232,13 +296,13 @@ async function assertGpuForComposition(
  * returns the settings.
  */
 async function adaptVideoSettings(settings: ClientExportSettings): Promise<ClientExportSettings> {
-  if (settings.mode === 'audio') return settings
+  if (settings.mode !== 'video') return settings
   const supported = await getSupportedCodecs({
     width: settings.resolution.width,
     height: settings.resolution.height,
     bitrate: settings.videoBitrate,
   })
-  if (supported.includes(settings.codec)) return settings
+  if (supported.includes(settings.codec)) return assertAlphaEncodable(settings)
 
   const container = settings.container as ClientVideoContainer
   const fallback =
@@ -255,10 +319,32 @@ async function adaptVideoSettings(settings: ClientExportSettings): Promise<Clien
   })
   settings.codec = fallback
   settings.container = getPreferredContainerForCodec(fallback)
+  return assertAlphaEncodable(settings)
+}
+
+/** Alpha video only survives VP8/VP9 in WebM/MKV; fail rather than silently flatten it. */
+function assertAlphaEncodable(settings: ClientExportSettings): ClientExportSettings {
+  if (settings.alpha && !supportsAlphaVideo(settings.codec, settings.container)) {
+    throw new Error(
+      `Alpha video needs VP8/VP9 in WebM or MKV (got ${settings.codec} in ${settings.container})`,
+    )
+  }
   return settings
 }
 
 async function renderTimeline(input: HeadlessTimelineInput): Promise<HeadlessRenderSummary> {
+  const { jobId } = input
+  if (!jobId) return renderTimelineJob(input)
+  if (pendingCancels.delete(jobId)) throw abortError()
+
+  try {
+    return await renderTimelineJob(input)
+  } finally {
+    pendingCancels.delete(jobId)
+  }
+}
+
+async function renderTimelineJob(input: HeadlessTimelineInput): Promise<HeadlessRenderSummary> {
   const {
     tracks,
     items,
@@ -270,17 +356,22 @@ async function renderTimeline(input: HeadlessTimelineInput): Promise<HeadlessRen
     outPoint = null,
     keyframes = [],
     backgroundColor,
+    colorSpace,
     busAudioEq,
     masterBusDb,
     compositions = [],
     media,
     settings,
+    jobId,
   } = input
 
   log.info('Headless render starting', {
     mode: settings.mode,
     codec: settings.codec,
     container: settings.container,
+    ...(settings.mode === 'animated-image' ? { animation: settings.animatedImage } : {}),
+    alpha: settings.alpha ?? false,
+    hdr: settings.hdr ?? false,
     resolution: `${settings.resolution.width}x${settings.resolution.height}`,
     fps,
     tracks: tracks.length,
@@ -325,6 +416,7 @@ async function renderTimeline(input: HeadlessTimelineInput): Promise<HeadlessRen
     busAudioEq,
     masterBusDb,
   )
+  composition.colorSpace = colorSpace
 
   // Fail loudly if the project needs WebGPU (effects) but it isn't available.
   await assertGpuForComposition(composition, compositions)
@@ -332,13 +424,31 @@ async function renderTimeline(input: HeadlessTimelineInput): Promise<HeadlessRen
   // Resolve top-level media (mediaId -> seeded blob URL). Export never uses proxies.
   composition.tracks = await resolveMediaUrls(composition.tracks, { useProxy: false })
 
-  const result =
-    settings.mode === 'audio'
-      ? await renderAudioOnly({ settings, composition, onProgress: reportProgress })
-      : await renderComposition({ settings, composition, onProgress: reportProgress })
+  // Cancellation goes through the same controller registry as the in-app render queue.
+  const controller = new AbortController()
+  if (jobId) {
+    registerJobController(jobId, controller)
+    if (pendingCancels.has(jobId)) controller.abort()
+  }
+  const sequenceSink = settings.mode === 'image-sequence' ? createDriverSequenceSink() : null
+  let result: ClientRenderResult
+  try {
+    const options = {
+      settings,
+      composition,
+      onProgress: reportProgress,
+      signal: controller.signal,
+      ...(sequenceSink ? { sequenceSink } : {}),
+    }
+    result =
+      settings.mode === 'audio' ? await renderAudioOnly(options) : await renderComposition(options)
+  } finally {
+    if (jobId) unregisterJobController(jobId)
+  }
 
   const fileName = input.outputFileName ?? defaultFileName(settings)
-  triggerDownload(result.blob, fileName)
+  // Frames the driver already wrote leave nothing to download.
+  if (!sequenceSink) triggerDownload(result.blob, fileName)
 
   log.info('Headless render complete', {
     mimeType: result.mimeType,
@@ -354,17 +464,41 @@ async function renderTimeline(input: HeadlessTimelineInput): Promise<HeadlessRen
     durationSeconds: result.duration,
     fileName,
     warnings,
+    ...(result.loudness ? { loudness: result.loudness } : {}),
+    ...(sequenceSink ? { frameCount: sequenceSink.frameCount() } : {}),
   }
 }
 
 async function renderProject(input: HeadlessProjectInput): Promise<HeadlessRenderSummary> {
-  const { project: rawProject, settings, media, renderWholeProject = true, outputFileName } = input
+  const {
+    project: rawProject,
+    settings,
+    media,
+    renderWholeProject = true,
+    outputFileName,
+    jobId,
+  } = input
   const { project } = migrateProject(rawProject)
   const timeline = project.timeline
   if (!timeline) {
     throw new Error('Project has no timeline to render')
   }
 
+  if (
+    input.canvasVariantId &&
+    !project.canvasVariants?.some((variant) => variant.id === input.canvasVariantId)
+  ) {
+    throw new Error(`Project has no canvas variant "${input.canvasVariantId}"`)
+  }
+  const canvas = resolveExportCanvas(
+    project,
+    {
+      items: (timeline.items ?? []) as unknown as TimelineItem[],
+      keyframes: (timeline.keyframes ?? []) as unknown as ItemKeyframes[],
+    },
+    input.canvasVariantId,
+  )
+
   const meta = project.metadata
   const hasExplicitRange = input.inPoint != null || input.outPoint != null
   const inPoint = hasExplicitRange
@@ -380,29 +514,45 @@ async function renderProject(input: HeadlessProjectInput): Promise<HeadlessRende
 
   return renderTimeline({
     tracks: (timeline.tracks ?? []) as unknown as TimelineTrack[],
-    items: (timeline.items ?? []) as unknown as TimelineItem[],
+    items: canvas.items,
     transitions: (timeline.transitions ?? []) as Transition[],
     fps: meta?.fps ?? 30,
-    width: meta?.width ?? 1920,
-    height: meta?.height ?? 1080,
+    width: canvas.width,
+    height: canvas.height,
     inPoint,
     outPoint,
-    keyframes: (timeline.keyframes ?? []) as unknown as ItemKeyframes[],
+    keyframes: canvas.keyframes,
     backgroundColor: meta?.backgroundColor,
+    colorSpace: meta?.colorSpace,
     busAudioEq: timeline.busAudioEq,
     masterBusDb: timeline.masterBusDb,
     compositions: (timeline.compositions ?? []) as unknown as SubComposition[],
     media,
     settings,
     outputFileName,
+    jobId,
   })
 }
 
+/**
+ * Abort the render started with `jobId`. The cancel is always recorded, so one
+ * that lands before the render starts or registers its controller (media
+ * setup, codec probing) is applied as soon as it does; the driver checks its
+ * own cancel flag for one that lands after the render finished.
+ */
+function cancelRender(jobId: string): void {
+  pendingCancels.add(jobId)
+  abortJob(jobId)
+}
+
 interface FreecutHeadlessApi {
   ready: true
   renderTimeline: typeof renderTimeline
   renderProject: typeof renderProject
+  cancelRender: typeof cancelRender
   editProject: typeof editProject
+  mcpListTools: typeof mcpListTools
+  mcpCallTool: typeof mcpCallTool
 }
 
 declare global {
@@ -411,5 +561,13 @@ declare global {
   }
 }
 
-window.freecut = { ready: true, renderTimeline, renderProject, editProject }
+window.freecut = {
+  ready: true,
+  renderTimeline,
+  renderProject,
+  cancelRender,
+  editProject,
+  mcpListTools,
+  mcpCallTool,
+}
 log.info('Headless harness ready')
@@ -0,0 +1,76 @@
+/**
+ * Headless MCP bridge.
+ *
+ * Exposes the editor tool registry's MCP mapping (`listMcpTools` /
+ * `callMcpTool`) to the Node stdio server. Each call hydrates the real timeline
+ * stores from the driver's current Project, runs the tool exactly as the
+ * in-editor agent would, then serializes the stores back so the driver can
+ * persist the result. The driver owns the project between calls; the harness
+ * stays stateless.
+ */
+import type { Project } from '@/types/project'
+import type { MediaMetadata } from '@/types/storage'
+
+import { createLogger } from '@/shared/logging/logger'
+import { migrateProject } from '@/shared/projects/migrations'
+import {
+  hydrateTimelineStoresFromProject,
+  buildTimelineFromStores,
+} from '@/features/timeline/stores/timeline-persistence'
+import { useProjectStore } from '@/features/projects/stores/project-store'
+import { usePlaybackStore } from '@/shared/state/playback'
+import { useSelectionStore } from '@/shared/state/selection'
+import {
+  callMcpTool,
+  listMcpTools,
+  type McpCallResult,
+  type McpToolDescriptor,
+} from '@/features/editor/agent/tools/mcp'
+import { seedMediaLibrary } from './seed-media'
+
+const log = createLogger('HeadlessMcp')
+
+export interface HeadlessMcpCallInput {
+  project: Project
+  name: string
+  args?: unknown
+  /** MediaMetadata for the project's media, so tools can read fps/duration/codecs. */
+  media?: Array<{ mediaId: string; metadata?: MediaMetadata }>
+  /** Playhead frame carried over from the previous call (tools like `add_title` default to it). */
+  currentFrame?: number
+  /** Selection carried over from the previous call (`select_clips` → later tools). */
+  selectedItemIds?: string[]
+}
+
+export interface HeadlessMcpCallResult {
+  result: McpCallResult
+  /** The project after the call (timeline rebuilt from stores). Unchanged for read-only tools. */
+  project: Project
+  currentFrame: number
+  selectedItemIds: string[]
+}
+
+/** MCP `tools/list`. */
+export function mcpListTools(): McpToolDescriptor[] {
+  return listMcpTools()
+}
+
+/** MCP `tools/call` against the given project. */
+export async function mcpCallTool(input: HeadlessMcpCallInput): Promise<HeadlessMcpCallResult> {
+  const { project: migrated } = migrateProject(input.project)
+  await hydrateTimelineStoresFromProject(migrated)
+  seedMediaLibrary(input.media)
+  useProjectStore.getState().setCurrentProject(migrated)
+  usePlaybackStore.getState().setCurrentFrame(input.currentFrame ?? 0)
+  useSelectionStore.getState().selectItems(input.selectedItemIds ?? [])
+
+  log.info('Headless MCP call', { tool: input.name })
+  const result = await callMcpTool(input.name, input.args ?? {})
+
+  return {
+    result,
+    project: { ...migrated, timeline: buildTimelineFromStores() },
+    currentFrame: usePlaybackStore.getState().currentFrame,
+    selectedItemIds: [...useSelectionStore.getState().selectedItemIds],
+  }
+}
@@ -0,0 +1,35 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { clusterSpeakerEmbeddings } from './clustering'
+
+function voice(base: number[], jitter: number): Float32Array {
+  return Float32Array.from(base, (value, index) => value + (index % 2 === 0 ? jitter : -jitter))
+}
+
+const voiceA = [1, 0.2, 0, 0]
+const voiceB = [0, 0, 1, 0.3]
+
+describe('clusterSpeakerEmbeddings', () => {
+  it('separates two distinct voices and labels by first appearance', () => {
+    const labels = clusterSpeakerEmbeddings([
+      voice(voiceB, 0.02),
+      voice(voiceA, 0.01),
+      voice(voiceB, -0.03),
+      voice(voiceA, 0.04),
+      voice(voiceA, -0.02),
+    ])
+
+    expect(labels).toEqual([0, 1, 0, 1, 1])
+  })
+
+  it('keeps merging past the threshold to honor maxSpeakers', () => {
+    const embeddings = [voice(voiceA, 0), voice(voiceB, 0), voice(voiceA, 0.01)]
+
+    expect(clusterSpeakerEmbeddings(embeddings, { maxSpeakers: 1 })).toEqual([0, 0, 0])
+    expect(clusterSpeakerEmbeddings(embeddings, { threshold: 0 })).toEqual([0, 1, 2])
+  })
+
+  it('handles empty and single inputs', () => {
+    expect(clusterSpeakerEmbeddings([])).toEqual([])
+    expect(clusterSpeakerEmbeddings([voice(voiceA, 0)])).toEqual([0])
+  })
+})
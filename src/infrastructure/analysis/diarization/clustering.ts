@@ -0,0 +1,148 @@
+/**
+ * Agglomerative clustering of speaker embeddings.
+ *
+ * Average linkage over cosine distance, built with the nearest-neighbor chain
+ * algorithm so long recordings (a thousand-plus windows) cluster in O(n²)
+ * time instead of the naive O(n³). Average linkage is reducible, so the chain
+ * yields the same merges as the textbook algorithm; replaying them in height
+ * order then cuts the dendrogram at the distance threshold.
+ */
+
+export const DEFAULT_SPEAKER_DISTANCE_THRESHOLD = 0.55
+
+export interface SpeakerClusteringOptions {
+  /** Merge clusters whose average cosine distance is below this */
+  threshold?: number
+  /** Keep merging past the threshold until at most this many clusters remain */
+  maxSpeakers?: number
+}
+
+interface Merge {
+  a: number
+  b: number
+  distance: number
+}
+
+function cosineDistance(left: Float32Array, right: Float32Array): number {
+  let dot = 0
+  let leftNorm = 0
+  let rightNorm = 0
+  const length = Math.min(left.length, right.length)
+  for (let i = 0; i < length; i++) {
+    const l = left[i]!
+    const r = right[i]!
+    dot += l * r
+    leftNorm += l * l
+    rightNorm += r * r
+  }
+  if (leftNorm === 0 || rightNorm === 0) return 1
+  return 1 - dot / Math.sqrt(leftNorm * rightNorm)
+}
+
+function buildMerges(embeddings: readonly Float32Array[]): Merge[] {
+  const n = embeddings.length
+  const distances = new Float64Array(n * n)
+  for (let i = 0; i < n; i++) {
+    for (let j = i + 1; j < n; j++) {
+      const distance = cosineDistance(embeddings[i]!, embeddings[j]!)
+      distances[i * n + j] = distance
+      distances[j * n + i] = distance
+    }
+  }
+
+  const sizes = new Array<number>(n).fill(1)
+  const active = new Array<boolean>(n).fill(true)
+  let activeCount = n
+  const chain: number[] = []
+  const merges: Merge[] = []
+
+  while (activeCount > 1) {
+    if (chain.length === 0) chain.push(active.indexOf(true))
+    const current = chain.at(-1)!
+    const previous = chain.length > 1 ? chain.at(-2)! : -1
+
+    // Nearest active neighbor; ties go to the previous chain link so the chain terminates.
+    let nearest = previous
+    let nearestDistance = previous >= 0 ? distances[current * n + previous]! : Infinity
+    for (let k = 0; k < n; k++) {
+      if (!active[k] || k === current) continue
+      const distance = distances[current * n + k]!
+      if (distance < nearestDistance) {
+        nearest = k
+        nearestDistance = distance
+      }
+    }
+
+    if (nearest !== previous) {
+      chain.push(nearest)
+      continue
+    }
+
+    // Reciprocal nearest neighbors: merge `current` into `previous`.
+    chain.pop()
+    chain.pop()
+    merges.push({ a: previous, b: current, distance: nearestDistance })
+    const keptSize = sizes[previous]!
+    const mergedSize = sizes[current]!
+    for (let k = 0; k < n; k++) {
+      if (!active[k] || k === previous || k === current) continue
+      const distance =
+        (keptSize * distances[previous * n + k]! + mergedSize * distances[current * n + k]!) /
+        (keptSize + mergedSize)
+      distances[previous * n + k] = distance
+      distances[k * n + previous] = distance
+    }
+    sizes[previous] = keptSize + mergedSize
+    active[current] = false
+    activeCount--
+  }
+
+  return merges.toSorted((left, right) => left.distance - right.distance)
+}
+
+/**
+ * Cluster embeddings into speakers. Returns one zero-based label per
+ * embedding, numbered in order of first appearance.
+ */
+export function clusterSpeakerEmbeddings(
+  embeddings: readonly Float32Array[],
+  options: SpeakerClusteringOptions = {},
+): number[] {
+  const n = embeddings.length
+  if (n === 0) return []
+  const threshold = options.threshold ?? DEFAULT_SPEAKER_DISTANCE_THRESHOLD
+  const maxSpeakers = Math.max(1, options.maxSpeakers ?? n)
+
+  const parents = Array.from({ length: n }, (_, index) => index)
+  const find = (index: number): number => {
+    let root = index
+    while (parents[root] !== root) root = parents[root]!
+    while (parents[index] !== root) {
+      const next = parents[index]!
+      parents[index] = root
+      index = next
+    }
+    return root
+  }
+
+  let clusterCount = n
+  for (const merge of buildMerges(embeddings)) {
+    if (merge.distance >= threshold && clusterCount <= maxSpeakers) break
+    const rootA = find(merge.a)
+    const rootB = find(merge.b)
+    if (rootA === rootB) continue
+    parents[rootB] = rootA
+    clusterCount--
+  }
+
+  const labelsByRoot = new Map<number, number>()
+  return embeddings.map((_, index) => {
+    const root = find(index)
+    let label = labelsByRoot.get(root)
+    if (label === undefined) {
+      label = labelsByRoot.size
+      labelsByRoot.set(root, label)
+    }
+    return label
+  })
+}
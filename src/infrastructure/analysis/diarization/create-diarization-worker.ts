@@ -0,0 +1,5 @@
+import DiarizationWorker from './diarization-worker.ts?worker'
+
+export function createDiarizationWorker(): Worker {
+  return new DiarizationWorker()
+}
@@ -0,0 +1,174 @@
+/**
+ * Singleton provider over the speaker-diarization worker.
+ *
+ * The model downloads once into the transformers.js browser cache (listed
+ * in Settings → local models) and stays resident until `dispose`, so
+ * diarizing several transcripts in a row only pays the load cost once.
+ */
+
+import { createLogger } from '@/shared/logging/logger'
+import { addAbortableWorkerMessageListener } from '../embeddings/worker-message-listener'
+import { createDiarizationWorker } from './create-diarization-worker'
+import {
+  DIARIZATION_MODEL_ID,
+  type DiarizationAudio,
+  type DiarizationOptions,
+  type DiarizationProvider,
+  type DiarizationWindow,
+} from './types'
+
+const log = createLogger('DiarizationProvider')
+
+const INIT_TIMEOUT_MS = 120_000
+
+let worker: Worker | null = null
+let readyPromise: Promise<void> | null = null
+let nextId = 0
+
+function getWorker(): Worker {
+  if (!worker) {
+    worker = createDiarizationWorker()
+    worker.addEventListener('error', (event) => {
+      log.error('Diarization worker errored', event.message)
+    })
+  }
+  return worker
+}
+
+function ensureReady(options: DiarizationOptions = {}): Promise<void> {
+  if (readyPromise) return readyPromise
+  const w = getWorker()
+
+  readyPromise = new Promise<void>((resolve, reject) => {
+    let removeWorkerMessageListener = () => {}
+    const timeout = setTimeout(() => {
+      cleanup()
+      reject(new Error('Diarization worker init timed out'))
+    }, INIT_TIMEOUT_MS)
+
+    const cleanup = () => {
+      clearTimeout(timeout)
+      removeWorkerMessageListener()
+    }
+
+    const onAbort = () => {
+      cleanup()
+      reject(options.signal?.reason ?? new Error('Diarization init aborted'))
+    }
+
+    const onMessage = (event: MessageEvent) => {
+      const message = event.data
+      if (message.type === 'ready') {
+        cleanup()
+        resolve()
+        return
+      }
+      if (message.type === 'progress' && message.id === undefined) {
+        options.onProgress?.({ stage: 'loading-model', percent: message.percent ?? 0 })
+        return
+      }
+      if (message.type === 'error' && message.id === undefined) {
+        cleanup()
+        reject(new Error(message.message ?? 'Diarization worker init failed'))
+      }
+    }
+
+    const detachListener = addAbortableWorkerMessageListener({
+      worker: w,
+      signal: options.signal,
+      onAbort,
+      onMessage,
+    })
+    if (!detachListener) return
+    removeWorkerMessageListener = detachListener
+
+    w.postMessage({ type: 'init' })
+  })
+
+  readyPromise.catch(() => {
+    readyPromise = null
+  })
+
+  return readyPromise
+}
+
+function diarize(
+  audio: DiarizationAudio,
+  windows: readonly DiarizationWindow[],
+  options: DiarizationOptions = {},
+): Promise<number[]> {
+  if (windows.length === 0) return Promise.resolve([])
+  return ensureReady(options).then(
+    () =>
+      new Promise<number[]>((resolve, reject) => {
+        const id = ++nextId
+        const w = getWorker()
+        let removeWorkerMessageListener = () => {}
+
+        const cleanup = () => {
+          removeWorkerMessageListener()
+        }
+
+        const onAbort = () => {
+          cleanup()
+          reject(options.signal?.reason ?? new Error('Diarization aborted'))
+        }
+
+        const onMessage = (event: MessageEvent) => {
+          const message = event.data
+          if (message.id !== id) return
+          if (message.type === 'progress') {
+            options.onProgress?.({ stage: 'embedding', percent: message.percent ?? 0 })
+            return
+          }
+          if (message.type === 'labels') {
+            cleanup()
+            resolve(message.labels)
+            return
+          }
+          if (message.type === 'error') {
+            cleanup()
+            reject(new Error(message.message ?? 'Diarization failed'))
+          }
+        }
+
+        const detachListener = addAbortableWorkerMessageListener({
+          worker: w,
+          signal: options.signal,
+          onAbort,
+          onMessage,
+        })
+        if (!detachListener) return
+        removeWorkerMessageListener = detachListener
+
+        // Copy so the caller's audio stays usable after the transfer
+        const samples = audio.samples.slice().buffer
+        w.postMessage(
+          {
+            type: 'diarize',
+            id,
+            samples,
+            sampleRate: audio.sampleRate,
+            windows,
+            threshold: options.threshold,
+            maxSpeakers: options.maxSpeakers,
+          },
+          [samples],
+        )
+      }),
+  )
+}
+
+export const diarizationProvider: DiarizationProvider = {
+  modelId: DIARIZATION_MODEL_ID,
+  ensureReady,
+  diarize,
+
+  dispose(): void {
+    if (!worker) return
+    worker.postMessage({ type: 'dispose' })
+    worker.terminate()
+    worker = null
+    readyPromise = null
+  },
+}
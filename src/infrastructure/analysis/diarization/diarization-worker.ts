@@ -0,0 +1,159 @@
+/**
+ * Web Worker for speaker diarization.
+ *
+ * Runs `Xenova/wavlm-base-plus-sv` (WavLM x-vector speaker verification,
+ * ~95 MB q8) through transformers.js to embed each window, then clusters the
+ * embeddings here so only the labels cross back to the main thread.
+ *
+ * Messages:
+ *   → { type: 'init' }
+ *   → { type: 'diarize', id, samples: ArrayBuffer, sampleRate, windows,
+ *       threshold?, maxSpeakers? }
+ *   → { type: 'dispose' }
+ *   ← { type: 'ready' }
+ *   ← { type: 'progress', id?, percent: number }
+ *   ← { type: 'labels', id, labels: number[] }
+ *   ← { type: 'error', id?, message }
+ */
+
+import {
+  AutoModel,
+  AutoProcessor,
+  env,
+  type PreTrainedModel,
+  type Processor,
+} from '@huggingface/transformers'
+import { clusterSpeakerEmbeddings } from './clustering'
+import type { DiarizationWindow } from './types'
+
+const MODEL_ID = 'Xenova/wavlm-base-plus-sv'
+
+env.useBrowserCache = true
+env.allowLocalModels = false
+
+/* eslint-disable @typescript-eslint/no-explicit-any -- transformers.js
+   tensor types vary by version; the worker stays schema-stable. */
+let processor: Processor | null = null
+let model: PreTrainedModel | null = null
+let loading = false
+let disposed = false
+let loadGeneration = 0
+
+function post(msg: Record<string, unknown>): void {
+  self.postMessage(msg)
+}
+
+async function loadModel(): Promise<void> {
+  if (processor && model) {
+    post({ type: 'ready' })
+    return
+  }
+  if (loading) return
+  loading = true
+  disposed = false
+  const thisGen = ++loadGeneration
+
+  try {
+    let lastPct = 0
+    const onProgress = (info: { status?: string; total?: number; loaded?: number }) => {
+      if (info.status === 'progress' && info.total && info.loaded) {
+        const pct = (info.loaded / info.total) * 100
+        if (pct - lastPct > 2) {
+          lastPct = pct
+          post({ type: 'progress', percent: Math.round(pct) })
+        }
+      }
+    }
+
+    const [loadedProcessor, loadedModel] = await Promise.all([
+      AutoProcessor.from_pretrained(MODEL_ID),
+      AutoModel.from_pretrained(MODEL_ID, {
+        dtype: 'q8',
+        progress_callback: onProgress,
+      } as any),
+    ])
+
+    if (disposed || thisGen !== loadGeneration) return
+
+    processor = loadedProcessor
+    model = loadedModel
+    post({ type: 'ready' })
+  } catch (error) {
+    post({ type: 'error', message: error instanceof Error ? error.message : String(error) })
+  } finally {
+    loading = false
+  }
+}
+
+async function diarize(
+  id: number,
+  samples: Float32Array,
+  sampleRate: number,
+  windows: readonly DiarizationWindow[],
+  threshold: number | undefined,
+  maxSpeakers: number | undefined,
+): Promise<void> {
+  if (!processor || !model) {
+    post({ type: 'error', id, message: 'Diarization worker not ready' })
+    return
+  }
+  try {
+    const embeddings: Float32Array[] = []
+    let lastPct = 0
+    for (const [index, window] of windows.entries()) {
+      const start = Math.max(0, Math.floor(window.start * sampleRate))
+      const end = Math.min(samples.length, Math.ceil(window.end * sampleRate))
+      const inputs = await (processor as any)(samples.subarray(start, Math.max(start + 1, end)))
+      const { embeddings: output } = (await (model as any)(inputs)) as any
+      if (!output) throw new Error('Speaker model returned no embedding')
+      embeddings.push(Float32Array.from(output.data as Float32Array))
+
+      const pct = ((index + 1) / windows.length) * 100
+      if (pct - lastPct >= 1) {
+        lastPct = pct
+        post({ type: 'progress', id, percent: Math.round(pct) })
+      }
+    }
+
+    const labels = clusterSpeakerEmbeddings(embeddings, { threshold, maxSpeakers })
+    post({ type: 'labels', id, labels })
+  } catch (error) {
+    post({ type: 'error', id, message: error instanceof Error ? error.message : String(error) })
+  }
+}
+
+self.addEventListener('message', (event: MessageEvent) => {
+  const message = event.data
+  if (!message || typeof message.type !== 'string') return
+
+  if (message.type === 'init') {
+    void loadModel()
+    return
+  }
+
+  if (message.type === 'diarize') {
+    const id = typeof message.id === 'number' ? message.id : 0
+    if (!(message.samples instanceof ArrayBuffer) || !Array.isArray(message.windows)) {
+      post({ type: 'error', id, message: 'Diarization request has no audio' })
+      return
+    }
+    void diarize(
+      id,
+      new Float32Array(message.samples),
+      Number(message.sampleRate),
+      message.windows,
+      typeof message.threshold === 'number' ? message.threshold : undefined,
+      typeof message.maxSpeakers === 'number' ? message.maxSpeakers : undefined,
+    )
+    return
+  }
+
+  if (message.type === 'dispose') {
+    disposed = true
+    processor = null
+    model = null
+    loading = false
+    return
+  }
+})
+/* eslint-enable @typescript-eslint/no-explicit-any */
@@ -0,0 +1,16 @@
+export { diarizationProvider } from './diarization-provider'
+export { clusterSpeakerEmbeddings, DEFAULT_SPEAKER_DISTANCE_THRESHOLD } from './clustering'
+export {
+  buildDiarizationWindows,
+  MAX_WINDOW_SECONDS,
+  MIN_WINDOW_SECONDS,
+  resolveSegmentSpeakerLabels,
+} from './speaker-turns'
+export { DIARIZATION_MODEL_ID, DIARIZATION_SAMPLE_RATE } from './types'
+export type {
+  DiarizationAudio,
+  DiarizationOptions,
+  DiarizationProgress,
+  DiarizationProvider,
+  DiarizationWindow,
+} from './types'
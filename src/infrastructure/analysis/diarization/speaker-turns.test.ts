@@ -0,0 +1,34 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { buildDiarizationWindows, resolveSegmentSpeakerLabels } from './speaker-turns'
+
+describe('speaker turns', () => {
+  it('cuts segments into even windows and skips ones too short to embed', () => {
+    const windows = buildDiarizationWindows([
+      { start: 0, end: 7 },
+      { start: 7, end: 7.2 },
+      { start: 8, end: 9 },
+    ])
+
+    expect(windows).toEqual([
+      { start: 0, end: 3.5, segmentIndex: 0 },
+      { start: 3.5, end: 7, segmentIndex: 0 },
+      { start: 8, end: 9, segmentIndex: 2 },
+    ])
+  })
+
+  it('picks the dominant label and fills short segments from the previous neighbor', () => {
+    const segments = [
+      { start: 0, end: 4 },
+      { start: 4.1, end: 4.3 },
+      { start: 5, end: 6 },
+    ]
+    const windows = [
+      { start: 0, end: 3, segmentIndex: 0 },
+      { start: 3, end: 4, segmentIndex: 0 },
+      { start: 5, end: 6, segmentIndex: 2 },
+    ]
+
+    expect(resolveSegmentSpeakerLabels(segments, windows, [1, 0, 0])).toEqual([1, 1, 0])
+    expect(resolveSegmentSpeakerLabels(segments, [], [])).toEqual([null, null, null])
+  })
+})
@@ -0,0 +1,94 @@
+/**
+ * Glue between transcript segments and the diarization windows.
+ *
+ * Segments are cut into roughly equal windows of at most
+ * {@link MAX_WINDOW_SECONDS}, which is long enough for a stable speaker
+ * embedding and short enough that one window rarely spans a speaker change.
+ * After clustering, each segment takes the label covering most of its
+ * windowed duration.
+ */
+
+import type { DiarizationWindow } from './types'
+
+export const MAX_WINDOW_SECONDS = 3
+/** Segments shorter than this are too short to embed and inherit a neighbor's speaker. */
+export const MIN_WINDOW_SECONDS = 0.4
+
+interface TimedSegment {
+  start: number
+  end: number
+}
+
+export function buildDiarizationWindows(
+  segments: readonly TimedSegment[],
+  maxWindowSeconds = MAX_WINDOW_SECONDS,
+): DiarizationWindow[] {
+  const windows: DiarizationWindow[] = []
+  segments.forEach((segment, segmentIndex) => {
+    const duration = segment.end - segment.start
+    if (!(duration >= MIN_WINDOW_SECONDS)) return
+    const count = Math.max(1, Math.round(duration / maxWindowSeconds))
+    const step = duration / count
+    for (let i = 0; i < count; i++) {
+      windows.push({
+        start: segment.start + i * step,
+        end: i === count - 1 ? segment.end : segment.start + (i + 1) * step,
+        segmentIndex,
+      })
+    }
+  })
+  return windows
+}
+
+/**
+ * Resolve one label per segment from per-window labels. Segments without
+ * windows take the label of the nearest labeled segment in time, preferring
+ * the one before. Returns null entries only when no window was labeled.
+ */
+export function resolveSegmentSpeakerLabels(
+  segments: readonly TimedSegment[],
+  windows: readonly DiarizationWindow[],
+  labels: readonly number[],
+): Array<number | null> {
+  const weights = segments.map(() => new Map<number, number>())
+  windows.forEach((window, index) => {
+    const label = labels[index]
+    const segmentWeights = weights[window.segmentIndex]
+    if (label === undefined || label < 0 || !segmentWeights) return
+    segmentWeights.set(label, (segmentWeights.get(label) ?? 0) + (window.end - window.start))
+  })
+
+  const resolved: Array<number | null> = weights.map((segmentWeights) => {
+    let best: number | null = null
+    let bestWeight = 0
+    for (const [label, weight] of segmentWeights) {
+      if (weight > bestWeight) {
+        best = label
+        bestWeight = weight
+      }
+    }
+    return best
+  })
+
+  return resolved.map((label, index) => {
+    if (label !== null) return label
+    const segment = segments[index]!
+    let nearest: number | null = null
+    let nearestGap = Infinity
+    resolved.forEach((candidate, candidateIndex) => {
+      if (candidate === null) return
+      const other = segments[candidateIndex]!
+      const gap =
+        other.end <= segment.start
+          ? segment.start - other.end
+          : other.start >= segment.end
+            ? other.start - segment.end + 1e-6
+            : 0
+      if (gap < nearestGap) {
+        nearest = candidate
+        nearestGap = gap
+      }
+    })
+    return nearest
+  })
+}
@@ -0,0 +1,56 @@
+/**
+ * Public types for speaker diarization.
+ *
+ * Diarization runs after transcription: transcript segments are cut into
+ * short windows, each window gets a speaker embedding, and the embeddings
+ * are clustered so every window carries a speaker label. Labels are
+ * zero-based and numbered by first appearance.
+ */
+
+export const DIARIZATION_MODEL_ID = 'Xenova/wavlm-base-plus-sv'
+
+/** Sample rate the speaker-embedding model expects. */
+export const DIARIZATION_SAMPLE_RATE = 16_000
+
+/** Mono PCM at {@link DIARIZATION_SAMPLE_RATE}. */
+export interface DiarizationAudio {
+  samples: Float32Array
+  sampleRate: number
+}
+
+/** A span of source audio (seconds) that gets one speaker embedding. */
+export interface DiarizationWindow {
+  start: number
+  end: number
+  /** Transcript segment the window was cut from */
+  segmentIndex: number
+}
+
+export interface DiarizationProgress {
+  stage: 'loading-model' | 'embedding'
+  percent: number
+}
+
+export interface DiarizationOptions {
+  onProgress?: (progress: DiarizationProgress) => void
+  signal?: AbortSignal
+  /** Cosine distance below which two voices merge (default 0.55) */
+  threshold?: number
+  /** Upper bound on distinct speakers, e.g. when the user knows the cast size */
+  maxSpeakers?: number
+}
+
+export interface DiarizationProvider {
+  /** Identifier persisted with diarized transcripts */
+  readonly modelId: string
+  /** Ensures the model is loaded; safe to call repeatedly. */
+  ensureReady(options?: DiarizationOptions): Promise<void>
+  /** One speaker label per window, in window order. */
+  diarize(
+    audio: DiarizationAudio,
+    windows: readonly DiarizationWindow[],
+    options?: DiarizationOptions,
+  ): Promise<number[]>
+  /** Release the worker and free the underlying model memory. */
+  dispose(): void
+}
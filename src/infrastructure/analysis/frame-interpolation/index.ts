@@ -0,0 +1,13 @@
+export {
+  blendFrames,
+  getFrameInterpolationSample,
+  interpolateFramesWithFlow,
+  type FrameInterpolationSample,
+} from './interpolate'
+export {
+  createFramePairFlowEstimator,
+  FramePairFlowEstimator,
+  type KeyedAnalysisFrame,
+} from './pair-flow'
+export { ANALYSIS_WIDTH, ANALYSIS_HEIGHT } from '../optical-flow-shaders'
+export type { FlowField } from '../motion-tracking/types'
@@ -0,0 +1,88 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { AnalysisFrame, FlowField } from '../motion-tracking/types'
+import {
+  blendFrames,
+  getFrameInterpolationSample,
+  interpolateFramesWithFlow,
+} from './interpolate'
+
+function makeFrame(width: number, height: number, brightX: number[] = []): AnalysisFrame {
+  const data = new Uint8ClampedArray(width * height * 4)
+  for (let i = 0; i < width * height; i++) {
+    const value = brightX.includes(i % width) ? 255 : 0
+    data[i * 4] = value
+    data[i * 4 + 1] = value
+    data[i * 4 + 2] = value
+    data[i * 4 + 3] = 255
+  }
+  return { width, height, data }
+}
+
+function uniformFlow(width: number, height: number, vx: number, vy: number): FlowField {
+  const data = new Float32Array(width * height * 2)
+  for (let i = 0; i < width * height; i++) {
+    data[i * 2] = vx
+    data[i * 2 + 1] = vy
+  }
+  return { width, height, data }
+}
+
+describe('getFrameInterpolationSample', () => {
+  it('places a 24p project frame between two 30p source frames', () => {
+    const sample = getFrameInterpolationSample(1 / 24, 30)
+    expect(sample?.frameA).toBe(1)
+    expect(sample?.frameB).toBe(2)
+    expect(sample?.t).toBeCloseTo(0.25)
+  })
+
+  it('returns null when the time lands on a source frame', () => {
+    expect(getFrameInterpolationSample(2 / 30, 30)).toBeNull()
+    expect(getFrameInterpolationSample(2 / 30 + 0.0001, 30)).toBeNull()
+  })
+
+  it('returns null when there is no later frame to blend towards', () => {
+    expect(getFrameInterpolationSample(9.5 / 30, 30, 10)).toBeNull()
+    expect(getFrameInterpolationSample(8.5 / 30, 30, 10)).not.toBeNull()
+  })
+
+  it('rejects invalid input', () => {
+    expect(getFrameInterpolationSample(Number.NaN, 30)).toBeNull()
+    expect(getFrameInterpolationSample(1, 0)).toBeNull()
+  })
+})
+
+describe('blendFrames', () => {
+  it('cross-fades by the interpolation weight', () => {
+    const a = makeFrame(2, 1)
+    const b = makeFrame(2, 1, [0, 1])
+    const out = blendFrames(a, b, 0.25)
+    expect(out[0]).toBe(64)
+    expect(out[3]).toBe(255)
+  })
+})
+
+describe('interpolateFramesWithFlow', () => {
+  it('moves content along the flow instead of ghosting it', () => {
+    const a = makeFrame(8, 1, [2])
+    const b = makeFrame(8, 1, [4])
+    const flow = uniformFlow(8, 1, 2, 0)
+
+    const out = interpolateFramesWithFlow(a, b, flow, 0.5)
+    expect(out[3 * 4]).toBe(255)
+    expect(out[2 * 4]).toBe(0)
+    expect(out[4 * 4]).toBe(0)
+
+    const blended = blendFrames(a, b, 0.5)
+    expect(blended[2 * 4]).toBe(128)
+    expect(blended[3 * 4]).toBe(0)
+  })
+
+  it('scales analysis-resolution flow up to the frame size', () => {
+    const a = makeFrame(16, 1, [4])
+    const b = makeFrame(16, 1, [8])
+    const flow = uniformFlow(8, 1, 2, 0)
+
+    const out = interpolateFramesWithFlow(a, b, flow, 0.5)
+    expect(out[6 * 4]).toBe(255)
+  })
+})
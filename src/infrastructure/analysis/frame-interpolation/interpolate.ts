@@ -0,0 +1,148 @@
+/**
+ * In-between frame synthesis for slow motion and frame-rate conform.
+ *
+ * Frames are RGBA buffers at the same size. Flow fields come from the
+ * motion-tracking optical-flow passes: a dense field on the *later* frame's
+ * grid, at analysis resolution, where `b(p) ≈ a(p - v(p))`.
+ */
+
+import type { AnalysisFrame, FlowField } from '../motion-tracking/types'
+
+/** Blend weights closer than this to a source frame just show that frame. */
+const MIN_BLEND_WEIGHT = 0.02
+
+export interface FrameInterpolationSample {
+  /** Earlier source frame index */
+  frameA: number
+  /** Later source frame index (`frameA + 1`) */
+  frameB: number
+  /** Position between the two frames (0 = frameA, 1 = frameB) */
+  t: number
+}
+
+/**
+ * Where a source time falls between two source frames. Returns null when the
+ * time lands on (or within a hair of) a real frame, or when there is no later
+ * frame to interpolate towards.
+ */
+export function getFrameInterpolationSample(
+  sourceTime: number,
+  sourceFps: number,
+  sourceDurationFrames?: number,
+): FrameInterpolationSample | null {
+  if (!Number.isFinite(sourceTime) || !(sourceFps > 0)) return null
+
+  const position = Math.max(0, sourceTime * sourceFps)
+  const frameA = Math.floor(position)
+  const t = position - frameA
+  if (t < MIN_BLEND_WEIGHT || t > 1 - MIN_BLEND_WEIGHT) return null
+  if (sourceDurationFrames !== undefined && frameA + 1 > sourceDurationFrames - 1) return null
+
+  return { frameA, frameB: frameA + 1, t }
+}
+
+/** Cross-fade two frames: `(1 - t) * a + t * b`. */
+export function blendFrames(
+  a: AnalysisFrame,
+  b: AnalysisFrame,
+  t: number,
+  out: Uint8ClampedArray<ArrayBuffer> = new Uint8ClampedArray(a.data.length),
+): Uint8ClampedArray<ArrayBuffer> {
+  const wa = 1 - t
+  for (let i = 0; i < out.length; i++) {
+    out[i] = (a.data[i] ?? 0) * wa + (b.data[i] ?? 0) * t
+  }
+  return out
+}
+
+function sampleFlow(flow: FlowField, x: number, y: number): [number, number] {
+  const cx = Math.max(0, Math.min(flow.width - 1, x))
+  const cy = Math.max(0, Math.min(flow.height - 1, y))
+  const x0 = Math.floor(cx)
+  const y0 = Math.floor(cy)
+  const x1 = Math.min(flow.width - 1, x0 + 1)
+  const y1 = Math.min(flow.height - 1, y0 + 1)
+  const tx = cx - x0
+  const ty = cy - y0
+  const i00 = (y0 * flow.width + x0) * 2
+  const i10 = (y0 * flow.width + x1) * 2
+  const i01 = (y1 * flow.width + x0) * 2
+  const i11 = (y1 * flow.width + x1) * 2
+  const d = flow.data
+  const vx =
+    (d[i00]! * (1 - tx) + d[i10]! * tx) * (1 - ty) + (d[i01]! * (1 - tx) + d[i11]! * tx) * ty
+  const vy =
+    (d[i00 + 1]! * (1 - tx) + d[i10 + 1]! * tx) * (1 - ty) +
+    (d[i01 + 1]! * (1 - tx) + d[i11 + 1]! * tx) * ty
+  return [vx, vy]
+}
+
+/** Bilinear RGBA sample, clamped to the frame edges, accumulated into `acc`. */
+function accumulateBilinear(
+  frame: AnalysisFrame,
+  x: number,
+  y: number,
+  weight: number,
+  acc: Float32Array,
+): void {
+  const cx = Math.max(0, Math.min(frame.width - 1, x))
+  const cy = Math.max(0, Math.min(frame.height - 1, y))
+  const x0 = Math.floor(cx)
+  const y0 = Math.floor(cy)
+  const x1 = Math.min(frame.width - 1, x0 + 1)
+  const y1 = Math.min(frame.height - 1, y0 + 1)
+  const tx = cx - x0
+  const ty = cy - y0
+  const w00 = (1 - tx) * (1 - ty) * weight
+  const w10 = tx * (1 - ty) * weight
+  const w01 = (1 - tx) * ty * weight
+  const w11 = tx * ty * weight
+  const i00 = (y0 * frame.width + x0) * 4
+  const i10 = (y0 * frame.width + x1) * 4
+  const i01 = (y1 * frame.width + x0) * 4
+  const i11 = (y1 * frame.width + x1) * 4
+  const d = frame.data
+  for (let c = 0; c < 4; c++) {
+    acc[c] = acc[c]! + d[i00 + c]! * w00 + d[i10 + c]! * w10 + d[i01 + c]! * w01 + d[i11 + c]! * w11
+  }
+}
+
+/**
+ * Motion-compensated in-between frame. Each output pixel follows its flow
+ * vector back `t` of the way into `a` and forward `1 - t` into `b`, then the
+ * two samples are blended by temporal distance.
+ */
+export function interpolateFramesWithFlow(
+  a: AnalysisFrame,
+  b: AnalysisFrame,
+  flow: FlowField,
+  t: number,
+  out: Uint8ClampedArray<ArrayBuffer> = new Uint8ClampedArray(a.data.length),
+): Uint8ClampedArray<ArrayBuffer> {
+  const { width, height } = a
+  // Flow is in analysis pixels; scale vectors and lookups to frame pixels
+  const scaleX = width / flow.width
+  const scaleY = height / flow.height
+  const acc = new Float32Array(4)
+
+  for (let y = 0; y < height; y++) {
+    const fy = (y + 0.5) / scaleY - 0.5
+    for (let x = 0; x < width; x++) {
+      const [fvx, fvy] = sampleFlow(flow, (x + 0.5) / scaleX - 0.5, fy)
+      const vx = fvx * scaleX
+      const vy = fvy * scaleY
+
+      acc.fill(0)
+      accumulateBilinear(a, x - t * vx, y - t * vy, 1 - t, acc)
+      accumulateBilinear(b, x + (1 - t) * vx, y + (1 - t) * vy, t, acc)
+
+      const i = (y * width + x) * 4
+      out[i] = acc[0]!
+      out[i + 1] = acc[1]!
+      out[i + 2] = acc[2]!
+      out[i + 3] = acc[3]!
+    }
+  }
+
+  return out
+}
@@ -0,0 +1,47 @@
+import {
+  createFlowFieldProvider,
+  type FlowFieldProvider,
+} from '../motion-tracking/flow-field-provider'
+import type { AnalysisFrame, FlowField } from '../motion-tracking/types'
+
+export interface KeyedAnalysisFrame {
+  /** Stable identity of the frame (e.g. `${mediaId}:${sourceFrame}`) */
+  key: string
+  frame: AnalysisFrame
+}
+
+/**
+ * Flow between arbitrary frame pairs on top of the sequential flow providers.
+ * Consecutive pairs that share a frame (n→n+1, n+1→n+2) only push the new
+ * frame, so walking a clip forward costs one flow pass per source frame.
+ */
+export class FramePairFlowEstimator {
+  private lastKey: string | null = null
+
+  constructor(private readonly provider: FlowFieldProvider) {}
+
+  get backend(): FlowFieldProvider['backend'] {
+    return this.provider.backend
+  }
+
+  /** Flow on `b`'s grid such that `b(p) ≈ a(p - v(p))`. */
+  async computeFlow(a: KeyedAnalysisFrame, b: KeyedAnalysisFrame): Promise<FlowField | null> {
+    if (this.lastKey !== a.key) {
+      this.lastKey = null
+      await this.provider.computeFlow(a.frame)
+    }
+    const flow = await this.provider.computeFlow(b.frame)
+    this.lastKey = b.key
+    return flow
+  }
+
+  destroy(): void {
+    this.provider.destroy()
+    this.lastKey = null
+  }
+}
+
+/** WebGPU optical flow when available, otherwise the CPU port. */
+export async function createFramePairFlowEstimator(): Promise<FramePairFlowEstimator> {
+  return new FramePairFlowEstimator(await createFlowFieldProvider())
+}
@@ -28,7 +28,7 @@ const HIST_HEIGHT = 90
  * frames score < 0.15. Threshold of 0.3 catches most hard cuts with
  * few false positives.
  */
-const CHI_SQUARED_THRESHOLD = 0.3
+export const CHI_SQUARED_THRESHOLD = 0.3
 
 /** Minimum gap in seconds between cuts to avoid micro-segments */
 const MIN_CUT_GAP_SEC = 2.0
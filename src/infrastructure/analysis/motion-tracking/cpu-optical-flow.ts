@@ -0,0 +1,215 @@
+/**
+ * CPU Optical Flow
+ *
+ * Port of the tracking passes in `optical-flow-shaders.ts` (grayscale →
+ * Gaussian pyramid → Scharr gradients → coarse-to-fine warped Lucas-Kanade)
+ * so the tracker runs without WebGPU — in tests, and on browsers where no
+ * adapter is available. Slow compared to the GPU path but
+ * the analysis resolution is only 160x90.
+ */
+
+import { ANALYSIS_WIDTH, ANALYSIS_HEIGHT, PYRAMID_LEVELS } from '../optical-flow-shaders'
+import type { AnalysisFrame, FlowField } from './types'
+
+// Must match the uniforms OpticalFlowAnalyzer writes for the LK pass.
+const LK_WINDOW_RADIUS = 2
+const LK_MIN_EIGENVALUE = 0.001
+
+const GAUSS_KERNEL = [
+  0.0625, 0.125, 0.125, 0.0625, 0.125, 0.25, 0.25, 0.125, 0.125, 0.25, 0.25, 0.125, 0.0625, 0.125,
+  0.125, 0.0625,
+]
+
+interface Plane {
+  width: number
+  height: number
+  data: Float32Array
+}
+
+function clampIndex(value: number, max: number): number {
+  return value < 0 ? 0 : value > max ? max : value
+}
+
+/** BT.601 luma, nearest-sampled to the analysis resolution if needed. */
+function toGrayscale(frame: AnalysisFrame): Plane {
+  const data = new Float32Array(ANALYSIS_WIDTH * ANALYSIS_HEIGHT)
+  const scaleX = frame.width / ANALYSIS_WIDTH
+  const scaleY = frame.height / ANALYSIS_HEIGHT
+
+  for (let y = 0; y < ANALYSIS_HEIGHT; y++) {
+    const sy = Math.min(frame.height - 1, Math.floor(y * scaleY))
+    for (let x = 0; x < ANALYSIS_WIDTH; x++) {
+      const sx = Math.min(frame.width - 1, Math.floor(x * scaleX))
+      const i = (sy * frame.width + sx) * 4
+      data[y * ANALYSIS_WIDTH + x] =
+        ((frame.data[i] ?? 0) * 0.299 +
+          (frame.data[i + 1] ?? 0) * 0.587 +
+          (frame.data[i + 2] ?? 0) * 0.114) /
+        255
+    }
+  }
+
+  return { width: ANALYSIS_WIDTH, height: ANALYSIS_HEIGHT, data }
+}
+
+function downsample(input: Plane): Plane {
+  const width = Math.max(1, Math.floor(input.width / 2))
+  const height = Math.max(1, Math.floor(input.height / 2))
+  const data = new Float32Array(width * height)
+
+  for (let y = 0; y < height; y++) {
+    for (let x = 0; x < width; x++) {
+      let sum = 0
+      for (let dy = 0; dy < 4; dy++) {
+        const sy = clampIndex(y * 2 + dy - 1, input.height - 1)
+        for (let dx = 0; dx < 4; dx++) {
+          const sx = clampIndex(x * 2 + dx - 1, input.width - 1)
+          sum += input.data[sy * input.width + sx]! * GAUSS_KERNEL[dy * 4 + dx]!
+        }
+      }
+      data[y * width + x] = sum
+    }
+  }
+
+  return { width, height, data }
+}
+
+function buildPyramid(gray: Plane): Plane[] {
+  const levels = [gray]
+  for (let level = 1; level < PYRAMID_LEVELS; level++) {
+    levels.push(downsample(levels[level - 1]!))
+  }
+  return levels
+}
+
+function spatialGradients(input: Plane): { ix: Float32Array; iy: Float32Array } {
+  const { width, height, data } = input
+  const ix = new Float32Array(width * height)
+  const iy = new Float32Array(width * height)
+  const w = width - 1
+  const h = height - 1
+  const at = (x: number, y: number) => data[clampIndex(y, h) * width + clampIndex(x, w)]!
+
+  for (let y = 0; y < height; y++) {
+    for (let x = 0; x < width; x++) {
+      const tl = at(x - 1, y - 1)
+      const tc = at(x, y - 1)
+      const tr = at(x + 1, y - 1)
+      const ml = at(x - 1, y)
+      const mr = at(x + 1, y)
+      const bl = at(x - 1, y + 1)
+      const bc = at(x, y + 1)
+      const br = at(x + 1, y + 1)
+      // Scharr kernels, normalized by 32 like the shader
+      ix[y * width + x] = (-3 * tl + 3 * tr - 10 * ml + 10 * mr - 3 * bl + 3 * br) / 32
+      iy[y * width + x] = (-3 * tl - 10 * tc - 3 * tr + 3 * bl + 10 * bc + 3 * br) / 32
+    }
+  }
+
+  return { ix, iy }
+}
+
+/** Bilinear sample of a plane at a fractional position (clamped to the edges). */
+function sampleBilinear(plane: Plane, x: number, y: number): number {
+  const cx = Math.max(0, Math.min(plane.width - 1, x))
+  const cy = Math.max(0, Math.min(plane.height - 1, y))
+  const x0 = Math.floor(cx)
+  const y0 = Math.floor(cy)
+  const x1 = Math.min(plane.width - 1, x0 + 1)
+  const y1 = Math.min(plane.height - 1, y0 + 1)
+  const tx = cx - x0
+  const ty = cy - y0
+  const read = (px: number, py: number) => plane.data[py * plane.width + px]!
+  const top = read(x0, y0) * (1 - tx) + read(x1, y0) * tx
+  const bottom = read(x0, y1) * (1 - tx) + read(x1, y1) * tx
+  return top * (1 - ty) + bottom * ty
+}
+
+/**
+ * One Lucas-Kanade level. The previous frame is warped by the upsampled
+ * coarser flow before taking the temporal gradient, so each level solves
+ * only for the residual motion (matches `lucasKanadeWarpedMain`).
+ */
+function lucasKanade(current: Plane, previous: Plane, coarserFlow: Plane | null): Plane {
+  const { width, height } = current
+  const { ix, iy } = spatialGradients(current)
+
+  const flow = new Float32Array(width * height * 2)
+  for (let y = 0; y < height; y++) {
+    for (let x = 0; x < width; x++) {
+      let initX = 0
+      let initY = 0
+      if (coarserFlow) {
+        const px = clampIndex(Math.floor(x / 2), coarserFlow.width - 1)
+        const py = clampIndex(Math.floor(y / 2), coarserFlow.height - 1)
+        const pi = (py * coarserFlow.width + px) * 2
+        initX = coarserFlow.data[pi]! * 2
+        initY = coarserFlow.data[pi + 1]! * 2
+      }
+
+      let sumIxIx = 0
+      let sumIyIy = 0
+      let sumIxIy = 0
+      let sumIxIt = 0
+      let sumIyIt = 0
+      for (let dy = -LK_WINDOW_RADIUS; dy <= LK_WINDOW_RADIUS; dy++) {
+        const sy = clampIndex(y + dy, height - 1)
+        for (let dx = -LK_WINDOW_RADIUS; dx <= LK_WINDOW_RADIUS; dx++) {
+          const sx = clampIndex(x + dx, width - 1)
+          const si = sy * width + sx
+          const gx = ix[si]!
+          const gy = iy[si]!
+          const gt = current.data[si]! - sampleBilinear(previous, sx - initX, sy - initY)
+          sumIxIx += gx * gx
+          sumIyIy += gy * gy
+          sumIxIy += gx * gy
+          sumIxIt += gx * gt
+          sumIyIt += gy * gt
+        }
+      }
+
+      const det = sumIxIx * sumIyIy - sumIxIy * sumIxIy
+      const trace = sumIxIx + sumIyIy
+      const eigenMin = (trace - Math.sqrt(Math.max(trace * trace - 4 * det, 0))) * 0.5
+
+      let vx = initX
+      let vy = initY
+      if (eigenMin > LK_MIN_EIGENVALUE && Math.abs(det) > 0.0001) {
+        vx += -(sumIyIy * sumIxIt - sumIxIy * sumIyIt) / det
+        vy += -(sumIxIx * sumIyIt - sumIxIy * sumIxIt) / det
+      }
+
+      const oi = (y * width + x) * 2
+      flow[oi] = vx
+      flow[oi + 1] = vy
+    }
+  }
+
+  return { width, height, data: flow }
+}
+
+export class CpuOpticalFlow {
+  private previousPyramid: Plane[] | null = null
+
+  /**
+   * Flow from the previous frame to this one. Must be called sequentially;
+   * the first frame returns null (nothing to compare against).
+   */
+  computeFlow(frame: AnalysisFrame): FlowField | null {
+    const pyramid = buildPyramid(toGrayscale(frame))
+    const previousPyramid = this.previousPyramid
+    this.previousPyramid = pyramid
+    if (!previousPyramid) return null
+
+    let flow: Plane | null = null
+    for (let level = PYRAMID_LEVELS - 1; level >= 0; level--) {
+      flow = lucasKanade(pyramid[level]!, previousPyramid[level]!, flow)
+    }
+
+    return flow
+  }
+
+  reset(): void {
+    this.previousPyramid = null
+  }
+}
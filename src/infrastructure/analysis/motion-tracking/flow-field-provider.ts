@@ -0,0 +1,72 @@
+import { OpticalFlowAnalyzer } from '../optical-flow-analyzer'
+import { ANALYSIS_WIDTH, ANALYSIS_HEIGHT } from '../optical-flow-shaders'
+import { CpuOpticalFlow } from './cpu-optical-flow'
+import type { AnalysisFrame, FlowField } from './types'
+import { createLogger } from '@/shared/logging/logger'
+
+const log = createLogger('MotionTracking')
+
+/**
+ * Produces a dense flow field per frame. Frames must be pushed in playback
+ * order; the first frame yields null.
+ */
+export interface FlowFieldProvider {
+  readonly backend: 'webgpu' | 'cpu'
+  computeFlow(frame: AnalysisFrame): Promise<FlowField | null>
+  destroy(): void
+}
+
+export function createCpuFlowFieldProvider(): FlowFieldProvider {
+  const flow = new CpuOpticalFlow()
+  return {
+    backend: 'cpu',
+    computeFlow: async (frame) => flow.computeFlow(frame),
+    destroy: () => flow.reset(),
+  }
+}
+
+async function createGpuFlowFieldProvider(): Promise<FlowFieldProvider | null> {
+  if (typeof navigator === 'undefined' || !navigator.gpu) return null
+
+  const adapter = await navigator.gpu.requestAdapter()
+  if (!adapter) return null
+  const device = await adapter.requestDevice()
+  const analyzer = new OpticalFlowAnalyzer(device)
+
+  if (!(await analyzer.checkShaderCompilation())) {
+    analyzer.destroy()
+    device.destroy()
+    return null
+  }
+
+  return {
+    backend: 'webgpu',
+    computeFlow: async (frame) => {
+      const bitmap = await createImageBitmap(
+        new ImageData(new Uint8ClampedArray(frame.data), frame.width, frame.height),
+        { resizeWidth: ANALYSIS_WIDTH, resizeHeight: ANALYSIS_HEIGHT },
+      )
+      try {
+        const { flow } = await analyzer.analyzeFrameWithFlow(bitmap)
+        return flow ? { width: ANALYSIS_WIDTH, height: ANALYSIS_HEIGHT, data: flow } : null
+      } finally {
+        bitmap.close()
+      }
+    },
+    destroy: () => {
+      analyzer.destroy()
+      device.destroy()
+    },
+  }
+}
+
+/** WebGPU optical flow when available, otherwise the CPU port of the same passes. */
+export async function createFlowFieldProvider(): Promise<FlowFieldProvider> {
+  try {
+    const gpuProvider = await createGpuFlowFieldProvider()
+    if (gpuProvider) return gpuProvider
+  } catch (error) {
+    log.warn('WebGPU optical flow unavailable, falling back to CPU', error)
+  }
+  return createCpuFlowFieldProvider()
+}
@@ -0,0 +1,7 @@
+export { trackVideoRegion } from './track-video'
+export type {
+  MotionTrack,
+  MotionTrackMode,
+  MotionTrackRegion,
+  TrackPoint,
+} from './types'
@@ -0,0 +1,107 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { createCpuFlowFieldProvider } from './flow-field-provider'
+import { MotionTracker, advanceTrackSample, createInitialTrackSample } from './motion-tracker'
+import type { AnalysisFrame, FlowField } from './types'
+
+const WIDTH = 160
+const HEIGHT = 90
+
+/** Smooth, textured grayscale pattern shifted by (shiftX, shiftY) pixels. */
+function createShiftedFrame(shiftX: number, shiftY: number): AnalysisFrame {
+  const data = new Uint8ClampedArray(WIDTH * HEIGHT * 4)
+  for (let y = 0; y < HEIGHT; y++) {
+    for (let x = 0; x < WIDTH; x++) {
+      const sx = x - shiftX
+      const sy = y - shiftY
+      const value =
+        0.5 +
+        0.2 * Math.sin(sx * 0.31 + Math.cos(sy * 0.17) * 2) +
+        0.2 * Math.cos(sy * 0.27 + sx * 0.05)
+      const i = (y * WIDTH + x) * 4
+      data[i] = data[i + 1] = data[i + 2] = Math.round(value * 255)
+      data[i + 3] = 255
+    }
+  }
+  return { width: WIDTH, height: HEIGHT, data }
+}
+
+/** Flow field of a rotation by `degrees` about (cx, cy) plus a translation. */
+function createRigidFlow(degrees: number, cx: number, cy: number, tx: number): FlowField {
+  const data = new Float32Array(WIDTH * HEIGHT * 2)
+  const theta = (degrees * Math.PI) / 180
+  for (let y = 0; y < HEIGHT; y++) {
+    for (let x = 0; x < WIDTH; x++) {
+      const dx = x - cx
+      const dy = y - cy
+      const nx = cx + Math.cos(theta) * dx - Math.sin(theta) * dy + tx
+      const ny = cy + Math.sin(theta) * dx + Math.cos(theta) * dy
+      data[(y * WIDTH + x) * 2] = nx - x
+      data[(y * WIDTH + x) * 2 + 1] = ny - y
+    }
+  }
+  return { width: WIDTH, height: HEIGHT, data }
+}
+
+describe('MotionTracker', () => {
+  it('follows a translating region with the CPU flow provider', async () => {
+    const provider = createCpuFlowFieldProvider()
+    const tracker = new MotionTracker(provider, 'point', {
+      x: 0.4,
+      y: 0.4,
+      width: 0.2,
+      height: 0.2,
+    })
+
+    for (let i = 0; i < 6; i++) {
+      await tracker.pushFrame(createShiftedFrame(i, i * 0.5))
+    }
+    provider.destroy()
+
+    const { samples } = tracker.getTrack()
+    expect(samples).toHaveLength(6)
+    expect(samples[0]!.center).toEqual([0.5, 0.5])
+
+    const last = samples[5]!
+    expect(last.center[0]).toBeCloseTo(0.5 + 5 / WIDTH, 2)
+    expect(last.center[1]).toBeCloseTo(0.5 + 2.5 / HEIGHT, 2)
+    expect(last.rotation).toBe(0)
+    expect(last.scale).toBe(1)
+    expect(last.confidence).toBeGreaterThan(0.9)
+  })
+})
+
+describe('advanceTrackSample', () => {
+  it('recovers rotation and translation in planar mode', () => {
+    const initial = createInitialTrackSample({ x: 0.4, y: 0.3, width: 0.2, height: 0.4 })
+    const next = advanceTrackSample(initial, createRigidFlow(2, 80, 45, 1), 'planar', 1)
+
+    expect(next.frame).toBe(1)
+    expect(next.rotation).toBeCloseTo(2, 2)
+    expect(next.scale).toBeCloseTo(1, 3)
+    expect(next.center[0]).toBeCloseTo(0.5 + 1 / WIDTH, 4)
+    expect(next.center[1]).toBeCloseTo(0.5, 4)
+  })
+
+  it('only translates in point mode', () => {
+    const initial = createInitialTrackSample({ x: 0.4, y: 0.3, width: 0.2, height: 0.4 })
+    const next = advanceTrackSample(initial, createRigidFlow(0, 80, 45, 3), 'point', 1)
+
+    expect(next.rotation).toBe(0)
+    expect(next.corners[0][0]).toBeCloseTo(0.4 + 3 / WIDTH, 4)
+    expect(next.corners[0][1]).toBeCloseTo(0.3, 4)
+  })
+
+  it('holds the previous sample when the flow is incoherent', () => {
+    const initial = createInitialTrackSample({ x: 0.4, y: 0.4, width: 0.2, height: 0.2 })
+    const data = new Float32Array(WIDTH * HEIGHT * 2)
+    for (let i = 0; i < data.length; i++) {
+      // Deterministic noise: every sample points somewhere different
+      data[i] = Math.sin(i * 12.9898) * 20
+    }
+    const next = advanceTrackSample(initial, { width: WIDTH, height: HEIGHT, data }, 'point', 1)
+
+    expect(next.confidence).toBeLessThan(0.3)
+    expect(next.center).toEqual(initial.center)
+    expect(next.frame).toBe(1)
+  })
+})
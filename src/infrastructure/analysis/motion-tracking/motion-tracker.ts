@@ -0,0 +1,319 @@
+/**
+ * Motion Tracker
+ *
+ * Follows a region through a clip by sampling the dense optical-flow field
+ * on a grid inside the tracked quad each frame and fitting a motion model to
+ * the displacements:
+ * - `point`: robust (median) translation
+ * - `planar`: least-squares affine, refit on inliers
+ *
+ * The fitted per-frame motion is accumulated into the quad corners, center,
+ * rotation and scale. When too few samples agree (occlusion, motion blur,
+ * textureless region) the previous sample is held and its confidence drops.
+ */
+
+import type { FlowFieldProvider } from './flow-field-provider'
+import type {
+  AnalysisFrame,
+  FlowField,
+  MotionTrack,
+  MotionTrackMode,
+  MotionTrackRegion,
+  MotionTrackSample,
+  TrackPoint,
+  TrackQuad,
+} from './types'
+
+/** Grid resolution of flow samples inside the tracked quad */
+const SAMPLE_GRID_SIZE = 7
+/** Residuals below this many analysis pixels always count as inliers */
+const MIN_INLIER_TOLERANCE_PX = 0.35
+/** Residuals above this many analysis pixels never count as inliers */
+const MAX_INLIER_TOLERANCE_PX = 1.5
+/** Residual tolerance as a multiple of the median residual */
+const INLIER_MEDIAN_FACTOR = 2.5
+/** Below this inlier ratio the frame is treated as lost and the track holds */
+const MIN_TRACK_CONFIDENCE = 0.3
+/** Affine fits need a reasonable spread of agreeing points */
+const MIN_AFFINE_INLIERS = 6
+
+/** x' = a*x + b*y + c, y' = d*x + e*y + f */
+type Affine = [number, number, number, number, number, number]
+
+const IDENTITY_AFFINE: Affine = [1, 0, 0, 0, 1, 0]
+
+interface FrameMotionEstimate {
+  affine: Affine
+  confidence: number
+}
+
+function regionToQuad(region: MotionTrackRegion): TrackQuad {
+  const { x, y, width, height } = region
+  return [
+    [x, y],
+    [x + width, y],
+    [x + width, y + height],
+    [x, y + height],
+  ]
+}
+
+function quadCenter(quad: TrackQuad): TrackPoint {
+  return [
+    (quad[0][0] + quad[1][0] + quad[2][0] + quad[3][0]) / 4,
+    (quad[0][1] + quad[1][1] + quad[2][1] + quad[3][1]) / 4,
+  ]
+}
+
+export function createInitialTrackSample(region: MotionTrackRegion): MotionTrackSample {
+  const corners = regionToQuad(region)
+  return {
+    frame: 0,
+    center: quadCenter(corners),
+    corners,
+    rotation: 0,
+    scale: 1,
+    confidence: 1,
+  }
+}
+
+/** Bilinear flow lookup at an analysis-pixel position (clamped to the field). */
+function sampleFlowAt(flow: FlowField, x: number, y: number): TrackPoint {
+  const maxX = flow.width - 1
+  const maxY = flow.height - 1
+  const cx = Math.max(0, Math.min(maxX, x))
+  const cy = Math.max(0, Math.min(maxY, y))
+  const x0 = Math.floor(cx)
+  const y0 = Math.floor(cy)
+  const x1 = Math.min(maxX, x0 + 1)
+  const y1 = Math.min(maxY, y0 + 1)
+  const tx = cx - x0
+  const ty = cy - y0
+
+  const read = (px: number, py: number, channel: 0 | 1) =>
+    flow.data[(py * flow.width + px) * 2 + channel] ?? 0
+  const lerp2 = (channel: 0 | 1) => {
+    const top = read(x0, y0, channel) * (1 - tx) + read(x1, y0, channel) * tx
+    const bottom = read(x0, y1, channel) * (1 - tx) + read(x1, y1, channel) * tx
+    return top * (1 - ty) + bottom * ty
+  }
+
+  return [lerp2(0), lerp2(1)]
+}
+
+/** Grid of points inside the quad (bilinear in the corners), in quad units. */
+function getQuadSamplePoints(quad: TrackQuad): TrackPoint[] {
+  const points: TrackPoint[] = []
+  for (let row = 0; row < SAMPLE_GRID_SIZE; row++) {
+    const v = (row + 0.5) / SAMPLE_GRID_SIZE
+    for (let col = 0; col < SAMPLE_GRID_SIZE; col++) {
+      const u = (col + 0.5) / SAMPLE_GRID_SIZE
+      const topX = quad[0][0] + (quad[1][0] - quad[0][0]) * u
+      const topY = quad[0][1] + (quad[1][1] - quad[0][1]) * u
+      const bottomX = quad[3][0] + (quad[2][0] - quad[3][0]) * u
+      const bottomY = quad[3][1] + (quad[2][1] - quad[3][1]) * u
+      points.push([topX + (bottomX - topX) * v, topY + (bottomY - topY) * v])
+    }
+  }
+  return points
+}
+
+function median(values: number[]): number {
+  if (values.length === 0) return 0
+  const sorted = values.toSorted((a, b) => a - b)
+  const mid = Math.floor(sorted.length / 2)
+  return sorted.length % 2 === 0 ? (sorted[mid - 1]! + sorted[mid]!) / 2 : sorted[mid]!
+}
+
+function applyAffine(affine: Affine, point: TrackPoint): TrackPoint {
+  const [a, b, c, d, e, f] = affine
+  return [a * point[0] + b * point[1] + c, d * point[0] + e * point[1] + f]
+}
+
+function selectInliers(residuals: number[]): boolean[] {
+  const tolerance = Math.min(
+    MAX_INLIER_TOLERANCE_PX,
+    Math.max(MIN_INLIER_TOLERANCE_PX, median(residuals) * INLIER_MEDIAN_FACTOR),
+  )
+  return residuals.map((residual) => residual <= tolerance)
+}
+
+/** Solve a 3x3 linear system with Cramer's rule; null when singular. */
+function solve3(m: number[], r: [number, number, number]): [number, number, number] | null {
+  const det3 = (n: number[]) =>
+    n[0]! * (n[4]! * n[8]! - n[5]! * n[7]!) -
+    n[1]! * (n[3]! * n[8]! - n[5]! * n[6]!) +
+    n[2]! * (n[3]! * n[7]! - n[4]! * n[6]!)
+
+  const det = det3(m)
+  if (Math.abs(det) < 1e-9) return null
+
+  const withColumn = (column: number) => {
+    const copy = [...m]
+    for (let row = 0; row < 3; row++) copy[row * 3 + column] = r[row]!
+    return det3(copy) / det
+  }
+
+  return [withColumn(0), withColumn(1), withColumn(2)]
+}
+
+function fitAffine(points: TrackPoint[], targets: TrackPoint[]): Affine | null {
+  // Normal equations share the [x y 1]ᵀ[x y 1] matrix for both output rows.
+  let sxx = 0
+  let sxy = 0
+  let syy = 0
+  let sx = 0
+  let sy = 0
+  let tx = [0, 0, 0] as [number, number, number]
+  let ty = [0, 0, 0] as [number, number, number]
+
+  for (let i = 0; i < points.length; i++) {
+    const [x, y] = points[i]!
+    const [u, v] = targets[i]!
+    sxx += x * x
+    sxy += x * y
+    syy += y * y
+    sx += x
+    sy += y
+    tx = [tx[0] + x * u, tx[1] + y * u, tx[2] + u]
+    ty = [ty[0] + x * v, ty[1] + y * v, ty[2] + v]
+  }
+
+  const normal = [sxx, sxy, sx, sxy, syy, sy, sx, sy, points.length]
+  const rowX = solve3(normal, tx)
+  const rowY = solve3(normal, ty)
+  if (!rowX || !rowY) return null
+
+  return [rowX[0], rowX[1], rowX[2], rowY[0], rowY[1], rowY[2]]
+}
+
+function estimateTranslation(vectors: TrackPoint[]): FrameMotionEstimate {
+  const mx = median(vectors.map((vector) => vector[0]))
+  const my = median(vectors.map((vector) => vector[1]))
+  const inliers = selectInliers(vectors.map((v) => Math.hypot(v[0] - mx, v[1] - my)))
+  const kept = vectors.filter((_, index) => inliers[index])
+  if (kept.length === 0) {
+    return { affine: IDENTITY_AFFINE, confidence: 0 }
+  }
+
+  const dx = kept.reduce((sum, vector) => sum + vector[0], 0) / kept.length
+  const dy = kept.reduce((sum, vector) => sum + vector[1], 0) / kept.length
+  return { affine: [1, 0, dx, 0, 1, dy], confidence: kept.length / vectors.length }
+}
+
+/**
+ * Fit the frame-to-frame motion of a quad (given in analysis pixels) from a
+ * flow field.
+ */
+function estimateQuadMotion(
+  flow: FlowField,
+  quad: TrackQuad,
+  mode: MotionTrackMode,
+): FrameMotionEstimate {
+  const points = getQuadSamplePoints(quad).filter(
+    ([x, y]) => x >= 0 && y >= 0 && x <= flow.width - 1 && y <= flow.height - 1,
+  )
+  if (points.length === 0) {
+    return { affine: IDENTITY_AFFINE, confidence: 0 }
+  }
+
+  const vectors = points.map(([x, y]) => sampleFlowAt(flow, x, y))
+  const translation = estimateTranslation(vectors)
+  if (mode === 'point') {
+    return translation
+  }
+
+  const targets = points.map(
+    (point, index): TrackPoint => [point[0] + vectors[index]![0], point[1] + vectors[index]![1]],
+  )
+  const initial = fitAffine(points, targets)
+  if (!initial) return translation
+
+  const inliers = selectInliers(
+    points.map((point, index) => {
+      const [px, py] = applyAffine(initial, point)
+      return Math.hypot(px - targets[index]![0], py - targets[index]![1])
+    }),
+  )
+  const inlierPoints = points.filter((_, index) => inliers[index])
+  if (inlierPoints.length < MIN_AFFINE_INLIERS) return translation
+
+  const refined = fitAffine(
+    inlierPoints,
+    targets.filter((_, index) => inliers[index]),
+  )
+  if (!refined) return translation
+
+  return { affine: refined, confidence: inlierPoints.length / points.length }
+}
+
+/**
+ * Advance a track sample by one frame of flow. Samples are normalized; the
+ * flow field defines the analysis pixel space.
+ */
+export function advanceTrackSample(
+  previous: MotionTrackSample,
+  flow: FlowField,
+  mode: MotionTrackMode,
+  frame: number,
+): MotionTrackSample {
+  const toPixels = (point: TrackPoint): TrackPoint => [
+    point[0] * flow.width,
+    point[1] * flow.height,
+  ]
+  const toNormalized = (point: TrackPoint): TrackPoint => [
+    point[0] / flow.width,
+    point[1] / flow.height,
+  ]
+
+  const pixelQuad = previous.corners.map(toPixels) as TrackQuad
+  const { affine, confidence } = estimateQuadMotion(flow, pixelQuad, mode)
+  if (confidence < MIN_TRACK_CONFIDENCE) {
+    return { ...previous, frame, confidence }
+  }
+
+  const corners = pixelQuad.map((corner) => toNormalized(applyAffine(affine, corner))) as TrackQuad
+  const [a, b, , d, e] = affine
+  const rotationDelta = (Math.atan2(d - b, a + e) * 180) / Math.PI
+  const scaleDelta = Math.sqrt(Math.abs(a * e - b * d))
+
+  return {
+    frame,
+    center: toNormalized(applyAffine(affine, toPixels(previous.center))),
+    corners,
+    rotation: previous.rotation + rotationDelta,
+    scale: previous.scale * (Number.isFinite(scaleDelta) && scaleDelta > 0 ? scaleDelta : 1),
+    confidence,
+  }
+}
+
+/**
+ * Stateful tracker: push frames in order and it returns one sample per frame.
+ * Owns nothing but the provider it was given; call `destroy` on the provider
+ * when done.
+ */
+export class MotionTracker {
+  private readonly samples: MotionTrackSample[] = []
+
+  constructor(
+    private readonly provider: FlowFieldProvider,
+    private readonly mode: MotionTrackMode,
+    private readonly region: MotionTrackRegion,
+  ) {}
+
+  async pushFrame(frame: AnalysisFrame): Promise<MotionTrackSample> {
+    const flow = await this.provider.computeFlow(frame)
+    const previous = this.samples[this.samples.length - 1]
+    const sample =
+      previous && flow
+        ? advanceTrackSample(previous, flow, this.mode, this.samples.length)
+        : previous
+          ? { ...previous, frame: this.samples.length }
+          : createInitialTrackSample(this.region)
+    this.samples.push(sample)
+    return sample
+  }
+
+  getTrack(): MotionTrack {
+    return { mode: this.mode, region: this.region, samples: [...this.samples] }
+  }
+}
@@ -0,0 +1,62 @@
+import { ANALYSIS_WIDTH, ANALYSIS_HEIGHT } from '../optical-flow-shaders'
+import { seekVideo } from '../scene-detection-utils'
+import { createFlowFieldProvider, type FlowFieldProvider } from './flow-field-provider'
+import { MotionTracker } from './motion-tracker'
+import type { MotionTrack, MotionTrackMode, MotionTrackRegion } from './types'
+import { createLogger } from '@/shared/logging/logger'
+
+const log = createLogger('MotionTracking')
+
+interface TrackVideoRegionOptions {
+  mode: MotionTrackMode
+  /** Region on the first frame, normalized to the source frame */
+  region: MotionTrackRegion
+  /**
+   * Source time (seconds) for every frame to track, in tracking order. The
+   * first entry is the frame the region was drawn on; pass times in
+   * descending order to track backward.
+   */
+  sourceTimes: number[]
+  /** Defaults to WebGPU with CPU fallback */
+  provider?: FlowFieldProvider
+  onProgress?: (progress: { percent: number; frame: number; totalFrames: number }) => void
+  signal?: AbortSignal
+}
+
+/**
+ * Track a region through a video element by seeking frame by frame.
+ * Resolves with the samples gathered so far if aborted.
+ */
+export async function trackVideoRegion(
+  video: HTMLVideoElement,
+  options: TrackVideoRegionOptions,
+): Promise<MotionTrack> {
+  const { mode, region, sourceTimes, onProgress, signal } = options
+  const provider = options.provider ?? (await createFlowFieldProvider())
+  const ownsProvider = !options.provider
+  const tracker = new MotionTracker(provider, mode, region)
+  const canvas = new OffscreenCanvas(ANALYSIS_WIDTH, ANALYSIS_HEIGHT)
+  const ctx = canvas.getContext('2d', { willReadFrequently: true })!
+
+  log.info('Tracking region', { mode, frames: sourceTimes.length, backend: provider.backend })
+
+  try {
+    for (let i = 0; i < sourceTimes.length; i++) {
+      if (signal?.aborted) break
+
+      await seekVideo(video, sourceTimes[i]!)
+      ctx.drawImage(video, 0, 0, ANALYSIS_WIDTH, ANALYSIS_HEIGHT)
+      await tracker.pushFrame(ctx.getImageData(0, 0, ANALYSIS_WIDTH, ANALYSIS_HEIGHT))
+
+      onProgress?.({
+        percent: ((i + 1) / sourceTimes.length) * 100,
+        frame: i,
+        totalFrames: sourceTimes.length,
+      })
+    }
+  } finally {
+    if (ownsProvider) provider.destroy()
+  }
+
+  return tracker.getTrack()
+}
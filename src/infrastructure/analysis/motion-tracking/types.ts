@@ -0,0 +1,61 @@
+/**
+ * Motion tracking types.
+ *
+ * Coordinates in regions and samples are normalized to the source frame
+ * (0-1, top-left origin) so a track is independent of the analysis
+ * resolution and of how the clip is placed on the canvas.
+ */
+
+export type TrackPoint = [number, number]
+
+/** Quad corners in top-left, top-right, bottom-right, bottom-left order */
+export type TrackQuad = [TrackPoint, TrackPoint, TrackPoint, TrackPoint]
+
+/**
+ * - `point`: translation only (position callouts, parent-to-tracker)
+ * - `planar`: affine fit over the region (position, rotation, scale, corners)
+ */
+export type MotionTrackMode = 'point' | 'planar'
+
+/** Axis-aligned region selected on the first tracked frame */
+export interface MotionTrackRegion {
+  x: number
+  y: number
+  width: number
+  height: number
+}
+
+export interface MotionTrackSample {
+  /** Index of the tracked frame (0 = the frame the region was selected on) */
+  frame: number
+  /** Region center */
+  center: TrackPoint
+  /** Tracked region corners */
+  corners: TrackQuad
+  /** Accumulated rotation in degrees since the first frame (clockwise positive) */
+  rotation: number
+  /** Accumulated uniform scale since the first frame */
+  scale: number
+  /** Fraction of sample points agreeing with the fitted motion (0-1) */
+  confidence: number
+}
+
+export interface MotionTrack {
+  mode: MotionTrackMode
+  region: MotionTrackRegion
+  samples: MotionTrackSample[]
+}
+
+/** RGBA pixels at analysis resolution, structurally compatible with `ImageData` */
+export interface AnalysisFrame {
+  width: number
+  height: number
+  data: Uint8ClampedArray
+}
+
+/** Dense (vx, vy) displacement from the previous frame, interleaved, in analysis pixels */
+export interface FlowField {
+  width: number
+  height: number
+  data: Float32Array
+}
@@ -5,7 +5,8 @@
  * Processes frames at 160x90 resolution for real-time analysis speed.
  *
  * Outputs motion magnitude, direction coherence, and scene cut detection
- * per frame by comparing current and previous frame.
+ * per frame by comparing current and previous frame. The finest-level flow
+ * field can also be read back for motion tracking.
  */
 
 import {
@@ -34,6 +35,27 @@ const COVERAGE_THRESHOLD = 0.7
 const MAGNITUDE_THRESHOLD = 0.5
 const COHERENCE_THRESHOLD = 0.6
 const STATS_BUFFER_SIZE = 64 // 15 × u32 padded to 64 (matches masterselects)
+// rg32float finest level: 160 × 8 = 1280 bytes per row (already 256-aligned)
+const FLOW_BYTES_PER_ROW = ANALYSIS_WIDTH * 8
+const FLOW_BUFFER_SIZE = FLOW_BYTES_PER_ROW * ANALYSIS_HEIGHT
+
+const ZERO_MOTION: MotionResult = {
+  totalMotion: 0,
+  globalMotion: 0,
+  localMotion: 0,
+  isSceneCut: false,
+  dominantDirection: 0,
+  directionCoherence: 0,
+}
+
+interface FrameFlowResult {
+  motion: MotionResult
+  /**
+   * Per-pixel (vx, vy) displacement from the previous frame, interleaved, at
+   * ANALYSIS_WIDTH × ANALYSIS_HEIGHT. Null for the first frame.
+   */
+  flow: Float32Array | null
+}
 
 export class OpticalFlowAnalyzer {
   private device: GPUDevice
@@ -44,6 +66,7 @@ export class OpticalFlowAnalyzer {
   private spatialGradPipeline!: GPUComputePipeline
   private temporalGradPipeline!: GPUComputePipeline
   private lucasKanadePipeline!: GPUComputePipeline
+  private lucasKanadeWarpedPipeline!: GPUComputePipeline
   private flowStatsPipeline!: GPUComputePipeline
   private clearStatsPipeline!: GPUComputePipeline
 
@@ -53,6 +76,7 @@ export class OpticalFlowAnalyzer {
   private spatialGradLayout!: GPUBindGroupLayout
   private temporalGradLayout!: GPUBindGroupLayout
   private lucasKanadeLayout!: GPUBindGroupLayout
+  private lucasKanadeWarpedLayout!: GPUBindGroupLayout
   private flowStatsLayout!: GPUBindGroupLayout
   private clearStatsLayout!: GPUBindGroupLayout
 
@@ -75,6 +99,7 @@ export class OpticalFlowAnalyzer {
   private stagingBuffer!: GPUBuffer
   private lkParamsBuffer!: GPUBuffer
   private statsParamsBuffer!: GPUBuffer
+  private flowStagingBuffer: GPUBuffer | null = null
 
   private frameIndex = 0
   private initialized = false
@@ -211,6 +236,33 @@ export class OpticalFlowAnalyzer {
       compute: { module, entryPoint: 'lucasKanadeMain' },
     })
 
+    // Warped Lucas-Kanade for tracking (gradients + both pyramid levels + prev flow)
+    this.lucasKanadeWarpedLayout = this.device.createBindGroupLayout({
+      entries: [
+        { binding: 0, visibility: GPUShaderStage.COMPUTE, texture: r32Texture },
+        { binding: 1, visibility: GPUShaderStage.COMPUTE, texture: r32Texture },
+        { binding: 2, visibility: GPUShaderStage.COMPUTE, texture: r32Texture },
+        { binding: 3, visibility: GPUShaderStage.COMPUTE, texture: r32Texture },
+        {
+          binding: 4,
+          visibility: GPUShaderStage.COMPUTE,
+          storageTexture: { access: 'write-only', format: 'rg32float' },
+        },
+        {
+          binding: 5,
+          visibility: GPUShaderStage.COMPUTE,
+          texture: { sampleType: 'unfilterable-float' as GPUTextureSampleType },
+        },
+        { binding: 6, visibility: GPUShaderStage.COMPUTE, buffer: { type: 'uniform' } },
+      ],
+    })
+    this.lucasKanadeWarpedPipeline = this.device.createComputePipeline({
+      layout: this.device.createPipelineLayout({
+        bindGroupLayouts: [this.lucasKanadeWarpedLayout],
+      }),
+      compute: { module, entryPoint: 'lucasKanadeWarpedMain' },
+    })
+
     // Flow statistics (reads rg32float flow)
     this.flowStatsLayout = this.device.createBindGroupLayout({
       entries: [
@@ -352,6 +404,19 @@ export class OpticalFlowAnalyzer {
    * First frame returns zero motion (no previous frame to compare).
    */
   async analyzeFrame(source: ImageBitmap): Promise<MotionResult> {
+    const { motion } = await this.runFrame(source, false)
+    return motion
+  }
+
+  /**
+   * Like `analyzeFrame`, but also reads back the finest-level flow field.
+   * Used by the motion tracker; costs one extra ~115KB readback per frame.
+   */
+  async analyzeFrameWithFlow(source: ImageBitmap): Promise<FrameFlowResult> {
+    return this.runFrame(source, true)
+  }
+
+  private async runFrame(source: ImageBitmap, readFlow: boolean): Promise<FrameFlowResult> {
     this.init()
 
     const currentIdx = (this.frameIndex % 2) as 0 | 1
@@ -404,14 +469,7 @@ export class OpticalFlowAnalyzer {
     if (this.frameIndex === 0) {
       this.device.queue.submit([encoder.finish()])
       this.frameIndex++
-      return {
-        totalMotion: 0,
-        globalMotion: 0,
-        localMotion: 0,
-        isSceneCut: false,
-        dominantDirection: 0,
-        directionCoherence: 0,
-      }
+      return { motion: { ...ZERO_MOTION }, flow: null }
     }
 
     // 3. Clear stats
@@ -442,17 +500,6 @@ export class OpticalFlowAnalyzer {
       })
       this.dispatch(encoder, this.spatialGradPipeline, spatialBG, dim.w, dim.h)
 
-      // Temporal gradient
-      const temporalBG = this.device.createBindGroup({
-        layout: this.temporalGradLayout,
-        entries: [
-          { binding: 0, resource: currentPyramid[level]!.createView() },
-          { binding: 1, resource: previousPyramid[level]!.createView() },
-          { binding: 2, resource: this.gradItTextures[level]!.createView() },
-        ],
-      })
-      this.dispatch(encoder, this.temporalGradPipeline, temporalBG, dim.w, dim.h)
-
       // Lucas-Kanade (matches masterselects exactly)
       const pyramidScale = level < PYRAMID_LEVELS - 1 ? 2.0 : 0.0
       const prevFlowTexture =
@@ -466,6 +513,36 @@ export class OpticalFlowAnalyzer {
       lkView.setUint32(12, 0, true) // pad
       this.device.queue.writeBuffer(this.lkParamsBuffer, 0, lkData)
 
+      if (readFlow) {
+        // Tracking needs accurate displacements: warp the previous level instead
+        // of using a plain temporal difference.
+        const warpedBG = this.device.createBindGroup({
+          layout: this.lucasKanadeWarpedLayout,
+          entries: [
+            { binding: 0, resource: this.gradIxTextures[level]!.createView() },
+            { binding: 1, resource: this.gradIyTextures[level]!.createView() },
+            { binding: 2, resource: currentPyramid[level]!.createView() },
+            { binding: 3, resource: previousPyramid[level]!.createView() },
+            { binding: 4, resource: this.flowTextures[level]!.createView() },
+            { binding: 5, resource: prevFlowTexture.createView() },
+            { binding: 6, resource: { buffer: this.lkParamsBuffer } },
+          ],
+        })
+        this.dispatch(encoder, this.lucasKanadeWarpedPipeline, warpedBG, dim.w, dim.h)
+        continue
+      }
+
+      // Temporal gradient
+      const temporalBG = this.device.createBindGroup({
+        layout: this.temporalGradLayout,
+        entries: [
+          { binding: 0, resource: currentPyramid[level]!.createView() },
+          { binding: 1, resource: previousPyramid[level]!.createView() },
+          { binding: 2, resource: this.gradItTextures[level]!.createView() },
+        ],
+      })
+      this.dispatch(encoder, this.temporalGradPipeline, temporalBG, dim.w, dim.h)
+
       const lkBG = this.device.createBindGroup({
         layout: this.lucasKanadeLayout,
         entries: [
@@ -494,6 +571,16 @@ export class OpticalFlowAnalyzer {
     // 6. Copy stats to staging
     encoder.copyBufferToBuffer(this.statsBuffer, 0, this.stagingBuffer, 0, STATS_BUFFER_SIZE)
 
+    // 6b. Optionally copy the finest flow field for tracking
+    const flowStagingBuffer = readFlow ? this.getFlowStagingBuffer() : null
+    if (flowStagingBuffer) {
+      encoder.copyTextureToBuffer(
+        { texture: this.flowTextures[0]! },
+        { buffer: flowStagingBuffer, bytesPerRow: FLOW_BYTES_PER_ROW },
+        { width: ANALYSIS_WIDTH, height: ANALYSIS_HEIGHT },
+      )
+    }
+
     this.device.queue.submit([encoder.finish()])
 
     // 7. Readback and classify
@@ -501,9 +588,24 @@ export class OpticalFlowAnalyzer {
     const data = new Uint32Array(this.stagingBuffer.getMappedRange().slice(0))
     this.stagingBuffer.unmap()
 
+    let flow: Float32Array | null = null
+    if (flowStagingBuffer) {
+      await flowStagingBuffer.mapAsync(GPUMapMode.READ)
+      flow = new Float32Array(flowStagingBuffer.getMappedRange().slice(0))
+      flowStagingBuffer.unmap()
+    }
+
     const result = this.classifyMotion(data)
     this.frameIndex++
-    return result
+    return { motion: result, flow }
+  }
+
+  private getFlowStagingBuffer(): GPUBuffer {
+    this.flowStagingBuffer ??= this.device.createBuffer({
+      size: FLOW_BUFFER_SIZE,
+      usage: GPUBufferUsage.MAP_READ | GPUBufferUsage.COPY_DST,
+    })
+    return this.flowStagingBuffer
   }
 
   private classifyMotion(data: Uint32Array): MotionResult {
@@ -520,14 +622,7 @@ export class OpticalFlowAnalyzer {
     const maxMagnitude = (data[6] ?? 0) / 1000
 
     if (pixelCount === 0) {
-      return {
-        totalMotion: 0,
-        globalMotion: 0,
-        localMotion: 0,
-        isSceneCut: false,
-        dominantDirection: 0,
-        directionCoherence: 0,
-      }
+      return { ...ZERO_MOTION }
     }
 
     const meanMagnitude = sumMagnitude / pixelCount
@@ -605,6 +700,8 @@ export class OpticalFlowAnalyzer {
     this.stagingBuffer.destroy()
     this.lkParamsBuffer.destroy()
     this.statsParamsBuffer.destroy()
+    this.flowStagingBuffer?.destroy()
+    this.flowStagingBuffer = null
     this.initialized = false
   }
 }
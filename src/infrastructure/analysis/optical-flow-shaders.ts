@@ -3,6 +3,7 @@
  *
  * Pipeline: grayscale → pyramid downsample → spatial gradients →
  *           temporal gradient → Lucas-Kanade → flow statistics → clear stats
+ * Tracking swaps temporal gradient + Lucas-Kanade for warped Lucas-Kanade.
  *
  * Analysis resolution: 160×90 (base), 3-level Gaussian pyramid.
  */
@@ -176,6 +177,88 @@ fn lucasKanadeMain(@builtin(global_invocation_id) gid: vec3u) {
   textureStore(lkFlow, pos, vec4f(flow, 0.0, 0.0));
 }
 
+// ─── Warped Lucas-Kanade (motion tracking) ───
+// Same solve as lucasKanadeMain, but the previous level is bilinearly warped
+// by the upsampled coarser flow before taking the temporal gradient, so each
+// level only estimates the residual motion. Gives accurate displacements for
+// tracking; lucasKanadeMain stays as-is for the scene-detection statistics.
+
+@group(0) @binding(0) var lkwIx: texture_2d<f32>;
+@group(0) @binding(1) var lkwIy: texture_2d<f32>;
+@group(0) @binding(2) var lkwCurrent: texture_2d<f32>;
+@group(0) @binding(3) var lkwPrevious: texture_2d<f32>;
+@group(0) @binding(4) var lkwFlow: texture_storage_2d<rg32float, write>;
+@group(0) @binding(5) var lkwPrevFlow: texture_2d<f32>;
+@group(0) @binding(6) var<uniform> lkwParams: LKParams;
+
+fn sampleWarpedPrevious(p: vec2f, dims: vec2i) -> f32 {
+  let c = clamp(p, vec2f(0.0), vec2f(dims - 1));
+  let p0 = vec2i(floor(c));
+  let p1 = min(p0 + 1, dims - 1);
+  let t = c - vec2f(p0);
+  let top = mix(
+    textureLoad(lkwPrevious, p0, 0).r,
+    textureLoad(lkwPrevious, vec2i(p1.x, p0.y), 0).r,
+    t.x
+  );
+  let bottom = mix(
+    textureLoad(lkwPrevious, vec2i(p0.x, p1.y), 0).r,
+    textureLoad(lkwPrevious, p1, 0).r,
+    t.x
+  );
+  return mix(top, bottom, t.y);
+}
+
+@compute @workgroup_size(8, 8)
+fn lucasKanadeWarpedMain(@builtin(global_invocation_id) gid: vec3u) {
+  let dims = textureDimensions(lkwIx);
+  if (gid.x >= dims.x || gid.y >= dims.y) { return; }
+  let pos = vec2i(gid.xy);
+  let idims = vec2i(dims);
+  let radius = i32(lkwParams.windowRadius);
+
+  var initFlow = vec2f(0.0);
+  let prevDims = textureDimensions(lkwPrevFlow);
+  if (prevDims.x > 1u) {
+    let prevCoord = clamp(vec2i(gid.xy / 2u), vec2i(0), vec2i(prevDims) - 1);
+    initFlow = textureLoad(lkwPrevFlow, prevCoord, 0).rg * 2.0;
+  }
+
+  var sumIxIx = 0.0;
+  var sumIyIy = 0.0;
+  var sumIxIy = 0.0;
+  var sumIxIt = 0.0;
+  var sumIyIt = 0.0;
+
+  for (var dy = -radius; dy <= radius; dy++) {
+    for (var dx = -radius; dx <= radius; dx++) {
+      let sp = clamp(pos + vec2i(dx, dy), vec2i(0), idims - 1);
+      let ix = textureLoad(lkwIx, sp, 0).r;
+      let iy = textureLoad(lkwIy, sp, 0).r;
+      let curr = textureLoad(lkwCurrent, sp, 0).r;
+      let it = curr - sampleWarpedPrevious(vec2f(sp) - initFlow, idims);
+      sumIxIx += ix * ix;
+      sumIyIy += iy * iy;
+      sumIxIy += ix * iy;
+      sumIxIt += ix * it;
+      sumIyIt += iy * it;
+    }
+  }
+
+  let det = sumIxIx * sumIyIy - sumIxIy * sumIxIy;
+  let trace = sumIxIx + sumIyIy;
+  let eigenMin = (trace - sqrt(max(trace * trace - 4.0 * det, 0.0))) * 0.5;
+
+  var flow = initFlow;
+  if (eigenMin > lkwParams.minEigenvalue && abs(det) > 0.0001) {
+    let vx = -(sumIyIy * sumIxIt - sumIxIy * sumIyIt) / det;
+    let vy = -(sumIxIx * sumIyIt - sumIxIy * sumIxIt) / det;
+    flow = initFlow + vec2f(vx, vy);
+  }
+
+  textureStore(lkwFlow, pos, vec4f(flow, 0.0, 0.0));
+}
+
 // ─── Flow statistics (atomic reduction) ───
 // Matches masterselects FlowStats layout exactly.
 // 7 scalars + 8 histogram bins = 15 × 4 = 60 bytes, padded to 64.
@@ -0,0 +1,91 @@
+import { seekVideo } from '../scene-detection-utils'
+import {
+  CHI_SQUARED_THRESHOLD,
+  chiSquaredDistance,
+  computeHistogram,
+} from '../histogram-scene-detection'
+import { appendSubjectSample, createSubjectPath } from './subject-path'
+import { createSubjectDetector } from './subject-detector'
+import type { SubjectDetector, SubjectPath, SubjectRegion } from './types'
+import { createLogger } from '@/shared/logging/logger'
+
+const log = createLogger('SubjectAnalysis')
+
+/** Long side of the analysis frame; enough for faces and coarse saliency */
+const ANALYSIS_LONG_SIDE = 256
+
+interface AnalyzeSubjectPathOptions {
+  /** Source range to analyze, in seconds */
+  startTime: number
+  endTime: number
+  /** Analysis rate (samples per source second) */
+  fps: number
+  /** Defaults to face detection with saliency fallback */
+  detector?: SubjectDetector
+  onProgress?: (percent: number) => void
+  signal?: AbortSignal
+}
+
+function getAnalysisSize(width: number, height: number): { width: number; height: number } {
+  if (width <= 0 || height <= 0) return { width: ANALYSIS_LONG_SIDE, height: 144 }
+  const scale = Math.min(1, ANALYSIS_LONG_SIDE / Math.max(width, height))
+  return {
+    width: Math.max(1, Math.round(width * scale)),
+    height: Math.max(1, Math.round(height * scale)),
+  }
+}
+
+/**
+ * Follow the salient subject of a video element over a source range by
+ * seeking sample by sample. Hard cuts are found from color histograms of the
+ * same frames. Resolves with the samples gathered so far if aborted.
+ */
+export async function analyzeSubjectPath(
+  video: HTMLVideoElement,
+  options: AnalyzeSubjectPathOptions,
+): Promise<SubjectPath> {
+  const { startTime, endTime, fps, onProgress, signal } = options
+  const detector = options.detector ?? createSubjectDetector()
+  const size = getAnalysisSize(video.videoWidth, video.videoHeight)
+  const canvas = new OffscreenCanvas(size.width, size.height)
+  const ctx = canvas.getContext('2d', { willReadFrequently: true })!
+  const aspect =
+    video.videoWidth > 0 && video.videoHeight > 0 ? video.videoWidth / video.videoHeight : 16 / 9
+  const sampleCount = Math.max(1, Math.floor((endTime - startTime) * fps) + 1)
+  const path = createSubjectPath(fps, startTime, aspect)
+
+  log.info('Analyzing subject path', { samples: sampleCount, detector: detector.id })
+
+  let previousHistogram: Float32Array | null = null
+  for (let i = 0; i < sampleCount; i++) {
+    if (signal?.aborted) break
+
+    await seekVideo(video, startTime + i / fps)
+    ctx.drawImage(video, 0, 0, size.width, size.height)
+    const frame = ctx.getImageData(0, 0, size.width, size.height)
+    const histogram = computeHistogram(frame.data)
+    const startsShot =
+      previousHistogram !== null &&
+      chiSquaredDistance(previousHistogram, histogram) >= CHI_SQUARED_THRESHOLD
+    previousHistogram = histogram
+
+    appendSubjectSample(path, await detector.detect(frame), startsShot)
+    onProgress?.(((i + 1) / sampleCount) * 100)
+  }
+
+  return path
+}
+
+/** Subject of a still image (image clips are framed once, without a path). */
+export async function detectImageSubject(
+  image: CanvasImageSource,
+  width: number,
+  height: number,
+  detector: SubjectDetector = createSubjectDetector(),
+): Promise<SubjectRegion | null> {
+  const size = getAnalysisSize(width, height)
+  const canvas = new OffscreenCanvas(size.width, size.height)
+  const ctx = canvas.getContext('2d', { willReadFrequently: true })!
+  ctx.drawImage(image, 0, 0, size.width, size.height)
+  return detector.detect(ctx.getImageData(0, 0, size.width, size.height))
+}
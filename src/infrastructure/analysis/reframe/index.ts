@@ -0,0 +1,15 @@
+export { analyzeSubjectPath, detectImageSubject } from './analyze-video'
+export { createSubjectDetector } from './subject-detector'
+export {
+  getSubjectFramingAt,
+  getSubjectPathCutTimes,
+  getSubjectPathEndTime,
+  smoothSubjectPath,
+} from './subject-path'
+export type {
+  SmoothedSubjectPath,
+  SubjectDetector,
+  SubjectFraming,
+  SubjectPath,
+  SubjectRegion,
+} from './types'
@@ -0,0 +1,114 @@
+import type { SubjectFrame, SubjectRegion } from './types'
+
+/** Spread of the center prior, as a fraction of the frame */
+const CENTER_PRIOR_SIGMA = 0.35
+/** Mean color contrast below which a frame is treated as flat (no subject) */
+const FLAT_FRAME_CONTRAST = 2
+/** Pixels this many standard deviations above the mean contrast count as subject */
+const SUBJECT_THRESHOLD_STD = 0.5
+/** Regions are never reported smaller than this fraction of the frame */
+const MIN_REGION_SIZE = 0.08
+/** Width of a uniform box is sqrt(12) times its standard deviation */
+const BOX_EXTENT_PER_STD = Math.sqrt(12)
+
+/**
+ * CPU saliency for frames without a detectable face: global color contrast
+ * (distance from the frame's mean color in an opponent color space) weighted
+ * by a center prior. The subject is the contrast-weighted centroid of the
+ * pixels that stand out; its extent comes from their spread. Returns null for
+ * flat frames.
+ */
+export function detectSalientRegion(frame: SubjectFrame): SubjectRegion | null {
+  const { width, height, data } = frame
+  const count = width * height
+  if (count === 0) return null
+
+  const lum = new Float32Array(count)
+  const redGreen = new Float32Array(count)
+  const blueYellow = new Float32Array(count)
+  let meanLum = 0
+  let meanRedGreen = 0
+  let meanBlueYellow = 0
+  for (let i = 0; i < count; i++) {
+    const r = data[i * 4]!
+    const g = data[i * 4 + 1]!
+    const b = data[i * 4 + 2]!
+    lum[i] = 0.299 * r + 0.587 * g + 0.114 * b
+    redGreen[i] = r - g
+    blueYellow[i] = (r + g) / 2 - b
+    meanLum += lum[i]!
+    meanRedGreen += redGreen[i]!
+    meanBlueYellow += blueYellow[i]!
+  }
+  meanLum /= count
+  meanRedGreen /= count
+  meanBlueYellow /= count
+
+  const saliency = new Float32Array(count)
+  const twoSigmaSq = 2 * CENTER_PRIOR_SIGMA * CENTER_PRIOR_SIGMA
+  let sum = 0
+  let sumSq = 0
+  for (let y = 0; y < height; y++) {
+    const dy = (y + 0.5) / height - 0.5
+    for (let x = 0; x < width; x++) {
+      const i = y * width + x
+      const dx = (x + 0.5) / width - 0.5
+      const prior = 0.5 + 0.5 * Math.exp(-(dx * dx + dy * dy) / twoSigmaSq)
+      const contrast = Math.hypot(
+        lum[i]! - meanLum,
+        redGreen[i]! - meanRedGreen,
+        blueYellow[i]! - meanBlueYellow,
+      )
+      const value = contrast * prior
+      saliency[i] = value
+      sum += value
+      sumSq += value * value
+    }
+  }
+
+  const mean = sum / count
+  if (mean < FLAT_FRAME_CONTRAST) return null
+  const std = Math.sqrt(Math.max(0, sumSq / count - mean * mean))
+  const threshold = mean + std * SUBJECT_THRESHOLD_STD
+
+  let weightSum = 0
+  let cx = 0
+  let cy = 0
+  for (let y = 0; y < height; y++) {
+    for (let x = 0; x < width; x++) {
+      const weight = saliency[y * width + x]! - threshold
+      if (weight <= 0) continue
+      weightSum += weight
+      cx += weight * ((x + 0.5) / width)
+      cy += weight * ((y + 0.5) / height)
+    }
+  }
+  if (weightSum <= 0) return null
+  cx /= weightSum
+  cy /= weightSum
+
+  let varX = 0
+  let varY = 0
+  for (let y = 0; y < height; y++) {
+    for (let x = 0; x < width; x++) {
+      const weight = saliency[y * width + x]! - threshold
+      if (weight <= 0) continue
+      const dx = (x + 0.5) / width - cx
+      const dy = (y + 0.5) / height - cy
+      varX += weight * dx * dx
+      varY += weight * dy * dy
+    }
+  }
+
+  return {
+    x: cx,
+    y: cy,
+    width: clampExtent(Math.sqrt(varX / weightSum) * BOX_EXTENT_PER_STD),
+    height: clampExtent(Math.sqrt(varY / weightSum) * BOX_EXTENT_PER_STD),
+    confidence: Math.min(1, std / mean),
+  }
+}
+
+function clampExtent(value: number): number {
+  return Math.min(1, Math.max(MIN_REGION_SIZE, value))
+}
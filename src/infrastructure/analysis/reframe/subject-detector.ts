@@ -0,0 +1,105 @@
+import { createLogger } from '@/shared/logging/logger'
+import { detectSalientRegion } from './saliency'
+import type { SubjectDetector, SubjectFrame, SubjectRegion } from './types'
+
+const log = createLogger('SubjectDetector')
+
+const SALIENCY_DETECTOR_ID = 'saliency-v1'
+const FACE_DETECTOR_ID = 'face-saliency-v1'
+
+/**
+ * A detected face is the head; the framed subject also includes the
+ * shoulders, so the region is grown and shifted down by these factors.
+ */
+const FACE_REGION_WIDTH_SCALE = 2
+const FACE_REGION_HEIGHT_SCALE = 2.5
+const FACE_REGION_DOWN_SHIFT = 0.5
+
+interface DetectedFace {
+  boundingBox: { x: number; y: number; width: number; height: number }
+}
+
+/** Shape Detection API face detector (Chromium); runs on-device. */
+interface BrowserFaceDetector {
+  detect(image: ImageData): Promise<DetectedFace[]>
+}
+
+type BrowserFaceDetectorConstructor = new (options?: {
+  fastMode?: boolean
+  maxDetectedFaces?: number
+}) => BrowserFaceDetector
+
+function createBrowserFaceDetector(): BrowserFaceDetector | null {
+  const FaceDetector = (globalThis as { FaceDetector?: BrowserFaceDetectorConstructor })
+    .FaceDetector
+  if (typeof FaceDetector !== 'function') return null
+  try {
+    return new FaceDetector({ fastMode: true, maxDetectedFaces: 4 })
+  } catch (error) {
+    log.warn('FaceDetector unavailable, using saliency only', error)
+    return null
+  }
+}
+
+/**
+ * Union of the detected faces, grown from heads to head-and-shoulders and
+ * normalized to the frame.
+ */
+export function facesToRegion(
+  faces: DetectedFace[],
+  frameWidth: number,
+  frameHeight: number,
+): SubjectRegion | null {
+  if (faces.length === 0 || frameWidth <= 0 || frameHeight <= 0) return null
+
+  let left = Infinity
+  let top = Infinity
+  let right = -Infinity
+  let bottom = -Infinity
+  for (const { boundingBox: box } of faces) {
+    left = Math.min(left, box.x)
+    top = Math.min(top, box.y)
+    right = Math.max(right, box.x + box.width)
+    bottom = Math.max(bottom, box.y + box.height)
+  }
+
+  const faceWidth = (right - left) / frameWidth
+  const faceHeight = (bottom - top) / frameHeight
+  return {
+    x: (left + right) / 2 / frameWidth,
+    y: Math.min(1, (top + bottom) / 2 / frameHeight + faceHeight * FACE_REGION_DOWN_SHIFT),
+    width: Math.min(1, faceWidth * FACE_REGION_WIDTH_SCALE),
+    height: Math.min(1, faceHeight * FACE_REGION_HEIGHT_SCALE),
+    confidence: 1,
+  }
+}
+
+/**
+ * Subject detector that frames faces when the browser can find them and
+ * falls back to color-contrast saliency otherwise. Everything runs locally.
+ */
+export function createSubjectDetector(): SubjectDetector {
+  const faceDetector = createBrowserFaceDetector()
+  if (!faceDetector) {
+    return {
+      id: SALIENCY_DETECTOR_ID,
+      detect: async (frame) => detectSalientRegion(frame),
+    }
+  }
+
+  return {
+    id: FACE_DETECTOR_ID,
+    detect: async (frame: SubjectFrame) => {
+      try {
+        const faces = await faceDetector.detect(
+          new ImageData(frame.data, frame.width, frame.height),
+        )
+        const region = facesToRegion(faces, frame.width, frame.height)
+        if (region) return region
+      } catch (error) {
+        log.debug('Face detection failed for frame', error)
+      }
+      return detectSalientRegion(frame)
+    },
+  }
+}
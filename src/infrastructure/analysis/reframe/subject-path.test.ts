@@ -0,0 +1,100 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { detectSalientRegion } from './saliency'
+import { facesToRegion } from './subject-detector'
+import {
+  appendSubjectSample,
+  createSubjectPath,
+  getSubjectFramingAt,
+  getSubjectPathCutTimes,
+  smoothSubjectPath,
+} from './subject-path'
+
+/** Dark backdrop with a bright square subject. */
+function createFrame(
+  width: number,
+  height: number,
+  subject: { x: number; y: number; size: number },
+) {
+  const data = new Uint8ClampedArray(width * height * 4)
+  for (let y = 0; y < height; y++) {
+    for (let x = 0; x < width; x++) {
+      const i = (y * width + x) * 4
+      const inside =
+        x >= subject.x &&
+        x < subject.x + subject.size &&
+        y >= subject.y &&
+        y < subject.y + subject.size
+      data[i] = inside ? 240 : 20
+      data[i + 1] = inside ? 200 : 30
+      data[i + 2] = inside ? 160 : 40
+      data[i + 3] = 255
+    }
+  }
+  return { width, height, data }
+}
+
+const region = (x: number, confidence = 1) => ({ x, y: 0.5, width: 0.2, height: 0.2, confidence })
+
+describe('detectSalientRegion', () => {
+  it('finds an off-center subject', () => {
+    const result = detectSalientRegion(createFrame(32, 18, { x: 22, y: 4, size: 6 }))
+
+    expect(result).not.toBeNull()
+    expect(result!.x).toBeCloseTo(25 / 32, 1)
+    expect(result!.y).toBeCloseTo(7 / 18, 1)
+    expect(result!.width).toBeLessThan(0.5)
+    expect(result!.confidence).toBeGreaterThan(0.5)
+  })
+
+  it('reports nothing for a flat frame', () => {
+    expect(detectSalientRegion(createFrame(8, 8, { x: 0, y: 0, size: 0 }))).toBeNull()
+  })
+})
+
+describe('facesToRegion', () => {
+  it('frames the union of faces as head and shoulders', () => {
+    const result = facesToRegion(
+      [
+        { boundingBox: { x: 10, y: 10, width: 10, height: 10 } },
+        { boundingBox: { x: 30, y: 10, width: 10, height: 10 } },
+      ],
+      100,
+      100,
+    )
+
+    expect(result).toMatchObject({ x: 0.25, width: 0.6, confidence: 1 })
+    expect(result!.y).toBeCloseTo(0.2)
+    expect(facesToRegion([], 100, 100)).toBeNull()
+  })
+})
+
+describe('smoothSubjectPath', () => {
+  it('bridges unconfident samples from their confident neighbours', () => {
+    const path = createSubjectPath(4, 0, 16 / 9)
+    appendSubjectSample(path, region(0.2), false)
+    appendSubjectSample(path, null, false)
+    appendSubjectSample(path, region(0.9, 0.05), false)
+    appendSubjectSample(path, region(0.5), false)
+
+    const smoothed = smoothSubjectPath(path, { timeConstant: 0 })
+    expect(smoothed.x[1]).toBeCloseTo(0.3)
+    expect(smoothed.x[2]).toBeCloseTo(0.4)
+  })
+
+  it('eases within a shot but never across a cut', () => {
+    const path = createSubjectPath(4, 10, 16 / 9)
+    for (let i = 0; i < 8; i++) appendSubjectSample(path, region(i % 2 ? 0.3 : 0.35), false)
+    for (let i = 0; i < 8; i++) appendSubjectSample(path, region(0.8), i === 0)
+
+    const smoothed = smoothSubjectPath(path)
+    expect(smoothed.cuts).toEqual([8])
+    expect(Math.abs(smoothed.x[3]! - smoothed.x[4]!)).toBeLessThan(0.02)
+    expect(smoothed.x[7]).toBeLessThan(0.4)
+    expect(smoothed.x[8]).toBeCloseTo(0.8)
+
+    const [cutTime] = getSubjectPathCutTimes(smoothed)
+    expect(cutTime).toBeCloseTo(11.875)
+    expect(getSubjectFramingAt(smoothed, cutTime! - 0.01).x).toBeLessThan(0.4)
+    expect(getSubjectFramingAt(smoothed, cutTime! + 0.01).x).toBeCloseTo(0.8)
+  })
+})
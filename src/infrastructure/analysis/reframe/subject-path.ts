@@ -0,0 +1,125 @@
+/**
+ * Subject Path
+ *
+ * Collects per-sample subject detections into a path and turns it into a
+ * steady framing track: samples the detector was unsure about are bridged
+ * from their confident neighbours, then each shot is smoothed with a
+ * zero-phase exponential filter so the virtual camera eases after the subject
+ * instead of jittering. Smoothing never crosses a cut.
+ */
+
+import type { SmoothedSubjectPath, SubjectFraming, SubjectPath, SubjectRegion } from './types'
+
+/** Time constant of the framing filter; larger values give a lazier camera */
+const SMOOTHING_SECONDS = 0.6
+/** Detections below this confidence are replaced by interpolation */
+const MIN_CONFIDENCE = 0.2
+
+export function createSubjectPath(fps: number, startTime: number, aspect: number): SubjectPath {
+  return { fps, startTime, aspect, x: [], y: [], size: [], confidence: [], cuts: [] }
+}
+
+/** Append the next sample; `startsShot` marks a hard cut before it. */
+export function appendSubjectSample(
+  path: SubjectPath,
+  region: SubjectRegion | null,
+  startsShot: boolean,
+): void {
+  if (startsShot && path.x.length > 0) path.cuts.push(path.x.length)
+  path.x.push(region?.x ?? 0.5)
+  path.y.push(region?.y ?? 0.5)
+  path.size.push(region ? Math.max(region.width, region.height) : 1)
+  path.confidence.push(region?.confidence ?? 0)
+}
+
+/** Source time covered by the last sample of the path. */
+export function getSubjectPathEndTime(path: { fps: number; startTime: number; x: number[] }) {
+  return path.startTime + Math.max(0, path.x.length - 1) / path.fps
+}
+
+function getShotRanges(length: number, cuts: number[]): Array<[number, number]> {
+  const ranges: Array<[number, number]> = []
+  let start = 0
+  for (const cut of cuts) {
+    if (cut <= start || cut >= length) continue
+    ranges.push([start, cut])
+    start = cut
+  }
+  if (start < length) ranges.push([start, length])
+  return ranges
+}
+
+/** Replace unconfident samples in `[start, end)` by interpolating between confident ones. */
+function bridgeGaps(
+  values: number[],
+  confidence: number[],
+  start: number,
+  end: number,
+  fallback: number,
+): void {
+  let previous = -1
+  for (let i = start; i <= end; i++) {
+    if (i < end && confidence[i]! < MIN_CONFIDENCE) continue
+    const next = i < end ? i : -1
+    const gapStart = previous === -1 ? start : previous + 1
+    for (let j = gapStart; j < (next === -1 ? end : next); j++) {
+      if (previous === -1 && next === -1) values[j] = fallback
+      else if (previous === -1) values[j] = values[next]!
+      else if (next === -1) values[j] = values[previous]!
+      else {
+        const t = (j - previous) / (next - previous)
+        values[j] = values[previous]! + (values[next]! - values[previous]!) * t
+      }
+    }
+    previous = next
+  }
+}
+
+/** Forward-backward exponential smoothing (no lag) of `[start, end)` in place. */
+function smoothRange(values: number[], start: number, end: number, alpha: number): void {
+  for (let i = start + 1; i < end; i++) {
+    values[i] = values[i - 1]! + alpha * (values[i]! - values[i - 1]!)
+  }
+  for (let i = end - 2; i >= start; i--) {
+    values[i] = values[i + 1]! + alpha * (values[i]! - values[i + 1]!)
+  }
+}
+
+export function smoothSubjectPath(
+  path: SubjectPath,
+  options: { timeConstant?: number } = {},
+): SmoothedSubjectPath {
+  const timeConstant = options.timeConstant ?? SMOOTHING_SECONDS
+  const alpha = timeConstant > 0 ? 1 - Math.exp(-1 / (path.fps * timeConstant)) : 1
+  const x = [...path.x]
+  const y = [...path.y]
+  const size = [...path.size]
+
+  for (const [start, end] of getShotRanges(x.length, path.cuts)) {
+    bridgeGaps(x, path.confidence, start, end, 0.5)
+    bridgeGaps(y, path.confidence, start, end, 0.5)
+    bridgeGaps(size, path.confidence, start, end, 1)
+    smoothRange(x, start, end, alpha)
+    smoothRange(y, start, end, alpha)
+    smoothRange(size, start, end, alpha)
+  }
+
+  return { fps: path.fps, startTime: path.startTime, x, y, size, cuts: [...path.cuts] }
+}
+
+/**
+ * Framing at a source time. Uses the nearest sample rather than blending two,
+ * so the framing switches cleanly at a cut; the caller keyframes densely
+ * enough that this is smooth within a shot.
+ */
+export function getSubjectFramingAt(path: SmoothedSubjectPath, time: number): SubjectFraming {
+  if (path.x.length === 0) return { x: 0.5, y: 0.5, size: 1 }
+  const position = (time - path.startTime) * path.fps
+  const index = Math.min(path.x.length - 1, Math.max(0, Math.round(position)))
+  return { x: path.x[index]!, y: path.y[index]!, size: path.size[index]! }
+}
+
+/** Source times of the cuts, halfway between the samples either side. */
+export function getSubjectPathCutTimes(path: SmoothedSubjectPath): number[] {
+  return path.cuts.map((cut) => path.startTime + (cut - 0.5) / path.fps)
+}
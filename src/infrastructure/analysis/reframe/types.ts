@@ -0,0 +1,66 @@
+/**
+ * Smart reframe analysis types.
+ *
+ * Subject positions are normalized to the source frame (0-1 of width/height)
+ * so a path is independent of the analysis resolution and can be applied to
+ * any output aspect ratio.
+ */
+
+/** Region of interest in a single frame, normalized to the frame */
+export interface SubjectRegion {
+  /** Center of the subject */
+  x: number
+  y: number
+  /** Extent of the subject */
+  width: number
+  height: number
+  /** How sure the detector is (0-1); low-confidence samples are interpolated over */
+  confidence: number
+}
+
+/** Pixels of one analysis frame (an `ImageData` or anything shaped like it) */
+export interface SubjectFrame {
+  width: number
+  height: number
+  data: Uint8ClampedArray
+}
+
+export interface SubjectDetector {
+  /** Stable id stored with cached paths; a different id invalidates them */
+  readonly id: string
+  detect(frame: SubjectFrame): Promise<SubjectRegion | null>
+}
+
+/** Subject trajectory over a source range, sampled at a fixed rate */
+export interface SubjectPath {
+  /** Analysis rate; sample `i` is at `startTime + i / fps` */
+  fps: number
+  /** Source time (seconds) of the first sample */
+  startTime: number
+  /** Source frame aspect ratio (width / height) */
+  aspect: number
+  x: number[]
+  y: number[]
+  /** Larger of the subject's normalized width and height */
+  size: number[]
+  confidence: number[]
+  /** Sample indices that start a new shot (hard cuts in the source) */
+  cuts: number[]
+}
+
+/** Subject framing at a point in source time, after smoothing */
+export interface SubjectFraming {
+  x: number
+  y: number
+  size: number
+}
+
+/** Subject path with gaps filled and jitter removed, ready to keyframe */
+export interface SmoothedSubjectPath {
+  fps: number
+  startTime: number
+  x: number[]
+  y: number[]
+  size: number[]
+  cuts: number[]
+}
@@ -0,0 +1,5 @@
+import SegmentationWorker from './segmentation-worker.ts?worker'
+
+export function createSegmentationWorker(): Worker {
+  return new SegmentationWorker()
+}
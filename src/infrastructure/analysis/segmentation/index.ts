@@ -0,0 +1,16 @@
+export { segmentationProvider } from './segmentation-provider'
+export {
+  createStubSegmentationProvider,
+  segmentByBorderDistance,
+  STUB_SEGMENTATION_MODEL_ID,
+} from './stub-provider'
+export { segmentVideo } from './segment-video'
+export { getMatteSize, matteToRgba, MATTE_MAX_DIMENSION, resizeMatte } from './matte'
+export { SEGMENTATION_MODEL_ID } from './types'
+export type {
+  SegmentationFrame,
+  SegmentationMatte,
+  SegmentationOptions,
+  SegmentationProgress,
+  SegmentationProvider,
+} from './types'
@@ -0,0 +1,65 @@
+import type { SegmentationMatte } from './types'
+
+/** Long side of stored mattes; edges are refined by the effect's softness. */
+export const MATTE_MAX_DIMENSION = 384
+
+/** Matte size for a source frame: source aspect, long side capped. */
+export function getMatteSize(
+  sourceWidth: number,
+  sourceHeight: number,
+  maxDimension = MATTE_MAX_DIMENSION,
+): { width: number; height: number } {
+  const width = Math.max(1, sourceWidth)
+  const height = Math.max(1, sourceHeight)
+  const scale = Math.min(1, maxDimension / Math.max(width, height))
+  return {
+    width: Math.max(1, Math.round(width * scale)),
+    height: Math.max(1, Math.round(height * scale)),
+  }
+}
+
+/** Bilinear resample of a matte to a new size. */
+export function resizeMatte(
+  matte: SegmentationMatte,
+  width: number,
+  height: number,
+): SegmentationMatte {
+  if (matte.width === width && matte.height === height) return matte
+
+  const data = new Uint8Array(width * height)
+  const scaleX = matte.width / width
+  const scaleY = matte.height / height
+  const src = matte.data
+  for (let y = 0; y < height; y++) {
+    const sy = Math.max(0, Math.min(matte.height - 1, (y + 0.5) * scaleY - 0.5))
+    const y0 = Math.floor(sy)
+    const ty = sy - y0
+    const row0 = y0 * matte.width
+    const row1 = Math.min(matte.height - 1, y0 + 1) * matte.width
+    for (let x = 0; x < width; x++) {
+      const sx = Math.max(0, Math.min(matte.width - 1, (x + 0.5) * scaleX - 0.5))
+      const x0 = Math.floor(sx)
+      const x1 = Math.min(matte.width - 1, x0 + 1)
+      const tx = sx - x0
+      const top = src[row0 + x0]! * (1 - tx) + src[row0 + x1]! * tx
+      const bottom = src[row1 + x0]! * (1 - tx) + src[row1 + x1]! * tx
+      data[y * width + x] = Math.round(top * (1 - ty) + bottom * ty)
+    }
+  }
+  return { width, height, data }
+}
+
+/**
+ * White RGBA pixels carrying the matte as alpha — usable both as an effect
+ * data texture and as a canvas alpha mask.
+ */
+export function matteToRgba(matte: SegmentationMatte): Uint8ClampedArray<ArrayBuffer> {
+  const rgba = new Uint8ClampedArray(matte.width * matte.height * 4)
+  for (let i = 0; i < matte.data.length; i++) {
+    rgba[i * 4] = 255
+    rgba[i * 4 + 1] = 255
+    rgba[i * 4 + 2] = 255
+    rgba[i * 4 + 3] = matte.data[i]!
+  }
+  return rgba
+}
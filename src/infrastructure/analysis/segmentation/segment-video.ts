@@ -0,0 +1,60 @@
+import { seekVideo } from '../scene-detection-utils'
+import { getMatteSize, resizeMatte } from './matte'
+import type { SegmentationMatte, SegmentationProvider } from './types'
+import { createLogger } from '@/shared/logging/logger'
+
+const log = createLogger('Segmentation')
+
+/** Long side of the frame handed to the model (it resizes internally anyway). */
+const MODEL_INPUT_MAX_DIMENSION = 512
+
+interface SegmentVideoOptions {
+  /** Source range to analyze, in seconds */
+  startTime: number
+  endTime: number
+  /** Matte rate (mattes per source second); frame `N` is at `N / fps` */
+  fps: number
+  provider: SegmentationProvider
+  /** Frames that already have a matte are skipped */
+  hasMatte?: (frameIndex: number) => boolean
+  onMatte: (frameIndex: number, matte: SegmentationMatte) => Promise<void> | void
+  onProgress?: (percent: number) => void
+  signal?: AbortSignal
+}
+
+/**
+ * Generate subject mattes for a video element over a source range by seeking
+ * frame by frame. Stops early (without throwing) when aborted.
+ */
+export async function segmentVideo(
+  video: HTMLVideoElement,
+  options: SegmentVideoOptions,
+): Promise<void> {
+  const { startTime, endTime, fps, provider, hasMatte, onMatte, onProgress, signal } = options
+  const input = getMatteSize(video.videoWidth, video.videoHeight, MODEL_INPUT_MAX_DIMENSION)
+  const output = getMatteSize(video.videoWidth, video.videoHeight)
+  const canvas = new OffscreenCanvas(input.width, input.height)
+  const ctx = canvas.getContext('2d', { willReadFrequently: true })!
+  const firstFrame = Math.max(0, Math.floor(startTime * fps))
+  const lastFrame = Math.max(firstFrame, Math.ceil(endTime * fps))
+  const frameCount = lastFrame - firstFrame + 1
+
+  await provider.ensureReady({ signal })
+  log.info('Segmenting video', { frames: frameCount, model: provider.modelId })
+
+  for (let i = 0; i < frameCount; i++) {
+    if (signal?.aborted) break
+    const frameIndex = firstFrame + i
+
+    if (!hasMatte?.(frameIndex)) {
+      await seekVideo(video, frameIndex / fps)
+      ctx.drawImage(video, 0, 0, input.width, input.height)
+      const matte = await provider.segment(ctx.getImageData(0, 0, input.width, input.height), {
+        signal,
+      })
+      await onMatte(frameIndex, resizeMatte(matte, output.width, output.height))
+    }
+
+    onProgress?.(((i + 1) / frameCount) * 100)
+  }
+}
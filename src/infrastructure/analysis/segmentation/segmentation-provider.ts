@@ -0,0 +1,160 @@
+/**
+ * Singleton provider over the subject-segmentation worker.
+ *
+ * The model downloads once into the transformers.js browser cache (listed
+ * in Settings → local models) and stays resident until `dispose`, so
+ * analyzing a clip frame by frame only pays the load cost on the first call.
+ */
+
+import { createLogger } from '@/shared/logging/logger'
+import { addAbortableWorkerMessageListener } from '../embeddings/worker-message-listener'
+import { createSegmentationWorker } from './create-segmentation-worker'
+import {
+  SEGMENTATION_MODEL_ID,
+  type SegmentationFrame,
+  type SegmentationMatte,
+  type SegmentationOptions,
+  type SegmentationProvider,
+} from './types'
+
+const log = createLogger('SegmentationProvider')
+
+const INIT_TIMEOUT_MS = 120_000
+
+let worker: Worker | null = null
+let readyPromise: Promise<void> | null = null
+let nextId = 0
+
+function getWorker(): Worker {
+  if (!worker) {
+    worker = createSegmentationWorker()
+    worker.addEventListener('error', (event) => {
+      log.error('Segmentation worker errored', event.message)
+    })
+  }
+  return worker
+}
+
+function ensureReady(options: SegmentationOptions = {}): Promise<void> {
+  if (readyPromise) return readyPromise
+  const w = getWorker()
+
+  readyPromise = new Promise<void>((resolve, reject) => {
+    let removeWorkerMessageListener = () => {}
+    const timeout = setTimeout(() => {
+      cleanup()
+      reject(new Error('Segmentation worker init timed out'))
+    }, INIT_TIMEOUT_MS)
+
+    const cleanup = () => {
+      clearTimeout(timeout)
+      removeWorkerMessageListener()
+    }
+
+    const onAbort = () => {
+      cleanup()
+      reject(options.signal?.reason ?? new Error('Segmentation init aborted'))
+    }
+
+    const onMessage = (event: MessageEvent) => {
+      const message = event.data
+      if (message.type === 'ready') {
+        cleanup()
+        resolve()
+        return
+      }
+      if (message.type === 'progress') {
+        options.onProgress?.({ stage: 'loading-model', percent: message.percent ?? 0 })
+        return
+      }
+      if (message.type === 'error' && message.id === undefined) {
+        cleanup()
+        reject(new Error(message.message ?? 'Segmentation worker init failed'))
+      }
+    }
+
+    const detachListener = addAbortableWorkerMessageListener({
+      worker: w,
+      signal: options.signal,
+      onAbort,
+      onMessage,
+    })
+    if (!detachListener) return
+    removeWorkerMessageListener = detachListener
+
+    w.postMessage({ type: 'init' })
+  })
+
+  readyPromise.catch(() => {
+    readyPromise = null
+  })
+
+  return readyPromise
+}
+
+function segment(
+  frame: SegmentationFrame,
+  options: SegmentationOptions = {},
+): Promise<SegmentationMatte> {
+  return ensureReady(options).then(
+    () =>
+      new Promise<SegmentationMatte>((resolve, reject) => {
+        const id = ++nextId
+        const w = getWorker()
+        let removeWorkerMessageListener = () => {}
+
+        const cleanup = () => {
+          removeWorkerMessageListener()
+        }
+
+        const onAbort = () => {
+          cleanup()
+          reject(options.signal?.reason ?? new Error('Segmentation aborted'))
+        }
+
+        const onMessage = (event: MessageEvent) => {
+          const message = event.data
+          if (message.id !== id) return
+          if (message.type === 'matte') {
+            cleanup()
+            resolve({ width: message.width, height: message.height, data: message.data })
+            return
+          }
+          if (message.type === 'error') {
+            cleanup()
+            reject(new Error(message.message ?? 'Segmentation failed'))
+          }
+        }
+
+        const detachListener = addAbortableWorkerMessageListener({
+          worker: w,
+          signal: options.signal,
+          onAbort,
+          onMessage,
+        })
+        if (!detachListener) return
+        removeWorkerMessageListener = detachListener
+
+        // Copy so the caller's frame stays usable after the transfer
+        const pixels = frame.data.slice().buffer
+        w.postMessage(
+          { type: 'segment', id, width: frame.width, height: frame.height, pixels },
+          [pixels],
+        )
+      }),
+  )
+}
+
+export const segmentationProvider: SegmentationProvider = {
+  modelId: SEGMENTATION_MODEL_ID,
+  ensureReady,
+  segment,
+
+  dispose(): void {
+    if (!worker) return
+    worker.postMessage({ type: 'dispose' })
+    worker.terminate()
+    worker = null
+    readyPromise = null
+  },
+}
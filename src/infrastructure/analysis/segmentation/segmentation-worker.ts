@@ -0,0 +1,141 @@
+/**
+ * Web Worker for subject segmentation (background removal).
+ *
+ * Runs `Xenova/modnet` (portrait/subject matting, ~25 MB q8) through
+ * transformers.js. Frames arrive as raw RGBA so the caller can decode video
+ * on the main thread; mattes come back at the frame's resolution.
+ *
+ * Messages:
+ *   → { type: 'init' }
+ *   → { type: 'segment', id, width, height, pixels: ArrayBuffer }
+ *   → { type: 'dispose' }
+ *   ← { type: 'ready' }
+ *   ← { type: 'progress', percent: number }
+ *   ← { type: 'matte', id, width, height, data: Uint8Array }
+ *   ← { type: 'error', id?, message }
+ */
+
+import {
+  AutoModel,
+  AutoProcessor,
+  RawImage,
+  env,
+  type PreTrainedModel,
+  type Processor,
+} from '@huggingface/transformers'
+
+const MODEL_ID = 'Xenova/modnet'
+
+env.useBrowserCache = true
+env.allowLocalModels = false
+
+/* eslint-disable @typescript-eslint/no-explicit-any -- transformers.js
+   tensor types vary by version; the worker stays schema-stable. */
+let processor: Processor | null = null
+let model: PreTrainedModel | null = null
+let loading = false
+let disposed = false
+let loadGeneration = 0
+
+function post(msg: Record<string, unknown>, transfer: Transferable[] = []): void {
+  self.postMessage(msg, { transfer })
+}
+
+async function loadModel(): Promise<void> {
+  if (processor && model) {
+    post({ type: 'ready' })
+    return
+  }
+  if (loading) return
+  loading = true
+  disposed = false
+  const thisGen = ++loadGeneration
+
+  try {
+    let lastPct = 0
+    const onProgress = (info: { status?: string; total?: number; loaded?: number }) => {
+      if (info.status === 'progress' && info.total && info.loaded) {
+        const pct = (info.loaded / info.total) * 100
+        if (pct - lastPct > 2) {
+          lastPct = pct
+          post({ type: 'progress', percent: Math.round(pct) })
+        }
+      }
+    }
+
+    const [loadedProcessor, loadedModel] = await Promise.all([
+      AutoProcessor.from_pretrained(MODEL_ID),
+      AutoModel.from_pretrained(MODEL_ID, {
+        dtype: 'q8',
+        progress_callback: onProgress,
+      } as any),
+    ])
+
+    if (disposed || thisGen !== loadGeneration) return
+
+    processor = loadedProcessor
+    model = loadedModel
+    post({ type: 'ready' })
+  } catch (error) {
+    post({ type: 'error', message: error instanceof Error ? error.message : String(error) })
+  } finally {
+    loading = false
+  }
+}
+
+async function segment(
+  id: number,
+  width: number,
+  height: number,
+  pixels: ArrayBuffer,
+): Promise<void> {
+  if (!processor || !model) {
+    post({ type: 'error', id, message: 'Segmentation worker not ready' })
+    return
+  }
+  try {
+    const image = new RawImage(new Uint8ClampedArray(pixels), width, height, 4)
+    const { pixel_values } = await (processor as any)(image)
+    const { output } = (await (model as any)({ input: pixel_values })) as any
+    if (!output) throw new Error('Segmentation model returned no output')
+
+    // Model output is a [1, 1, h, w] coverage tensor in 0..1 at model resolution
+    const matte = await RawImage.fromTensor(output[0].mul(255).to('uint8')).resize(width, height)
+    const data = new Uint8Array(matte.width * matte.height)
+    const channels = matte.channels
+    for (let i = 0; i < data.length; i++) data[i] = matte.data[i * channels]!
+
+    post({ type: 'matte', id, width: matte.width, height: matte.height, data }, [data.buffer])
+  } catch (error) {
+    post({ type: 'error', id, message: error instanceof Error ? error.message : String(error) })
+  }
+}
+
+self.addEventListener('message', (event: MessageEvent) => {
+  const message = event.data
+  if (!message || typeof message.type !== 'string') return
+
+  if (message.type === 'init') {
+    void loadModel()
+    return
+  }
+
+  if (message.type === 'segment') {
+    const id = typeof message.id === 'number' ? message.id : 0
+    if (!(message.pixels instanceof ArrayBuffer)) {
+      post({ type: 'error', id, message: 'Segmentation request has no pixels' })
+      return
+    }
+    void segment(id, Number(message.width), Number(message.height), message.pixels)
+    return
+  }
+
+  if (message.type === 'dispose') {
+    disposed = true
+    processor = null
+    model = null
+    loading = false
+    return
+  }
+})
+/* eslint-enable @typescript-eslint/no-explicit-any */
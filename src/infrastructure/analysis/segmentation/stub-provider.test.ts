@@ -0,0 +1,73 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { getMatteSize, matteToRgba, resizeMatte } from './matte'
+import { createStubSegmentationProvider, segmentByBorderDistance } from './stub-provider'
+
+/** Solid backdrop with a square subject of another color in the middle. */
+function createFrame(size: number, subject: { from: number; to: number }) {
+  const data = new Uint8ClampedArray(size * size * 4)
+  for (let y = 0; y < size; y++) {
+    for (let x = 0; x < size; x++) {
+      const i = (y * size + x) * 4
+      const inside = x >= subject.from && x < subject.to && y >= subject.from && y < subject.to
+      data[i] = inside ? 230 : 20
+      data[i + 1] = inside ? 180 : 160
+      data[i + 2] = inside ? 140 : 40
+      data[i + 3] = 255
+    }
+  }
+  return { width: size, height: size, data }
+}
+
+describe('segmentByBorderDistance', () => {
+  it('covers the subject and clears the backdrop', () => {
+    const matte = segmentByBorderDistance(createFrame(8, { from: 2, to: 6 }))
+
+    expect(matte.width).toBe(8)
+    expect(matte.height).toBe(8)
+    expect(matte.data[0]).toBe(0)
+    expect(matte.data[3 * 8 + 3]).toBe(255)
+    expect(matte.data[4 * 8 + 5]).toBe(255)
+    expect(matte.data[7 * 8 + 7]).toBe(0)
+  })
+
+  it('returns an empty matte for a flat frame', () => {
+    const matte = segmentByBorderDistance(createFrame(4, { from: 0, to: 0 }))
+    expect(Array.from(matte.data).every((value) => value === 0)).toBe(true)
+  })
+})
+
+describe('createStubSegmentationProvider', () => {
+  it('segments deterministically and honors abort', async () => {
+    const provider = createStubSegmentationProvider()
+    const frame = createFrame(8, { from: 2, to: 6 })
+
+    await provider.ensureReady()
+    const first = await provider.segment(frame)
+    const second = await provider.segment(frame)
+    expect(Array.from(first.data)).toEqual(Array.from(second.data))
+
+    const controller = new AbortController()
+    controller.abort()
+    await expect(provider.segment(frame, { signal: controller.signal })).rejects.toBeDefined()
+  })
+})
+
+describe('matte helpers', () => {
+  it('caps the long side while keeping the aspect ratio', () => {
+    expect(getMatteSize(1920, 1080)).toEqual({ width: 384, height: 216 })
+    expect(getMatteSize(200, 100)).toEqual({ width: 200, height: 100 })
+    expect(getMatteSize(1080, 1920, 512)).toEqual({ width: 288, height: 512 })
+  })
+
+  it('resamples mattes bilinearly', () => {
+    const matte = { width: 2, height: 1, data: new Uint8Array([0, 255]) }
+
+    expect(resizeMatte(matte, 2, 1)).toBe(matte)
+    expect(Array.from(resizeMatte(matte, 4, 1).data)).toEqual([0, 64, 191, 255])
+  })
+
+  it('packs coverage into the alpha channel of white pixels', () => {
+    const rgba = matteToRgba({ width: 2, height: 1, data: new Uint8Array([0, 128]) })
+    expect(Array.from(rgba)).toEqual([255, 255, 255, 0, 255, 255, 255, 128])
+  })
+})
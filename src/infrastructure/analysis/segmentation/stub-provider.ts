@@ -0,0 +1,70 @@
+import type {
+  SegmentationFrame,
+  SegmentationMatte,
+  SegmentationOptions,
+  SegmentationProvider,
+} from './types'
+
+export const STUB_SEGMENTATION_MODEL_ID = 'stub-border-distance'
+
+/** Color distance (0-441) below which a pixel matches the background. */
+const BACKGROUND_DISTANCE = 48
+/** Distance range over which coverage ramps from background to subject. */
+const EDGE_RAMP = 32
+
+/**
+ * Deterministic segmentation without a model: the background color is the
+ * mean of the frame border and every pixel is scored by its distance from
+ * it. Good enough for flat backdrops and for tests, which need mattes
+ * without downloading weights or touching a GPU.
+ */
+export function segmentByBorderDistance(frame: SegmentationFrame): SegmentationMatte {
+  const { width, height, data } = frame
+  let r = 0
+  let g = 0
+  let b = 0
+  let count = 0
+  const addBorderPixel = (x: number, y: number) => {
+    const i = (y * width + x) * 4
+    r += data[i]!
+    g += data[i + 1]!
+    b += data[i + 2]!
+    count++
+  }
+  for (let x = 0; x < width; x++) {
+    addBorderPixel(x, 0)
+    if (height > 1) addBorderPixel(x, height - 1)
+  }
+  for (let y = 1; y < height - 1; y++) {
+    addBorderPixel(0, y)
+    if (width > 1) addBorderPixel(width - 1, y)
+  }
+  r /= count
+  g /= count
+  b /= count
+
+  const matte = new Uint8Array(width * height)
+  for (let i = 0; i < matte.length; i++) {
+    const dr = data[i * 4]! - r
+    const dg = data[i * 4 + 1]! - g
+    const db = data[i * 4 + 2]! - b
+    const distance = Math.sqrt(dr * dr + dg * dg + db * db)
+    const t = Math.max(0, Math.min(1, (distance - BACKGROUND_DISTANCE) / EDGE_RAMP))
+    matte[i] = Math.round(t * 255)
+  }
+  return { width, height, data: matte }
+}
+
+export function createStubSegmentationProvider(): SegmentationProvider {
+  return {
+    modelId: STUB_SEGMENTATION_MODEL_ID,
+    ensureReady: () => Promise.resolve(),
+    segment(frame: SegmentationFrame, options?: SegmentationOptions) {
+      if (options?.signal?.aborted) {
+        return Promise.reject(options.signal.reason ?? new Error('Segmentation aborted'))
+      }
+      return Promise.resolve(segmentByBorderDistance(frame))
+    },
+    dispose: () => undefined,
+  }
+}
@@ -0,0 +1,44 @@
+/**
+ * Public types for subject segmentation (background removal).
+ *
+ * A matte is a single-channel coverage map of the source frame: 255 is
+ * subject, 0 is background. Mattes are stored at a reduced resolution with
+ * the source aspect ratio and scaled up at render time.
+ */
+
+export const SEGMENTATION_MODEL_ID = 'Xenova/modnet'
+
+export interface SegmentationMatte {
+  width: number
+  height: number
+  /** width * height coverage values (0 = background, 255 = subject) */
+  data: Uint8Array
+}
+
+/** RGBA frame handed to a provider (ImageData-compatible). */
+export interface SegmentationFrame {
+  width: number
+  height: number
+  data: Uint8ClampedArray
+}
+
+export interface SegmentationProgress {
+  stage: 'loading-model' | 'idle'
+  percent: number
+}
+
+export interface SegmentationOptions {
+  onProgress?: (progress: SegmentationProgress) => void
+  signal?: AbortSignal
+}
+
+export interface SegmentationProvider {
+  /** Identifier persisted with cached mattes so a model change invalidates them */
+  readonly modelId: string
+  /** Ensures the model is loaded; safe to call repeatedly. */
+  ensureReady(options?: SegmentationOptions): Promise<void>
+  /** Subject matte for one frame, at the frame's resolution. */
+  segment(frame: SegmentationFrame, options?: SegmentationOptions): Promise<SegmentationMatte>
+  /** Release the worker and free the underlying model memory. */
+  dispose(): void
+}
@@ -0,0 +1,70 @@
+import { ANALYSIS_WIDTH, ANALYSIS_HEIGHT } from '../optical-flow-shaders'
+import { seekVideo } from '../scene-detection-utils'
+import {
+  createFlowFieldProvider,
+  type FlowFieldProvider,
+} from '../motion-tracking/flow-field-provider'
+import { appendFrameMotion, createCameraPath, estimateGlobalMotion } from './global-motion'
+import type { CameraPath } from './types'
+import { createLogger } from '@/shared/logging/logger'
+
+const log = createLogger('Stabilization')
+
+interface AnalyzeCameraPathOptions {
+  /** Source range to analyze, in seconds */
+  startTime: number
+  endTime: number
+  /** Analysis rate (samples per source second) */
+  fps: number
+  /** Defaults to WebGPU with CPU fallback */
+  provider?: FlowFieldProvider
+  onProgress?: (percent: number) => void
+  signal?: AbortSignal
+}
+
+/**
+ * Measure the camera path of a video element over a source range by seeking
+ * frame by frame. Resolves with the samples gathered so far if aborted.
+ */
+export async function analyzeCameraPath(
+  video: HTMLVideoElement,
+  options: AnalyzeCameraPathOptions,
+): Promise<CameraPath> {
+  const { startTime, endTime, fps, onProgress, signal } = options
+  const provider = options.provider ?? (await createFlowFieldProvider())
+  const ownsProvider = !options.provider
+  const canvas = new OffscreenCanvas(ANALYSIS_WIDTH, ANALYSIS_HEIGHT)
+  const ctx = canvas.getContext('2d', { willReadFrequently: true })!
+  const aspect =
+    video.videoWidth > 0 && video.videoHeight > 0 ? video.videoWidth / video.videoHeight : 16 / 9
+  const frameCount = Math.max(1, Math.floor((endTime - startTime) * fps) + 1)
+  const path = createCameraPath(fps, startTime, aspect)
+
+  log.info('Analyzing camera path', { frames: frameCount, backend: provider.backend })
+
+  try {
+    for (let i = 0; i < frameCount; i++) {
+      if (signal?.aborted) break
+
+      await seekVideo(video, startTime + i / fps)
+      ctx.drawImage(video, 0, 0, ANALYSIS_WIDTH, ANALYSIS_HEIGHT)
+      const flow = await provider.computeFlow(
+        ctx.getImageData(0, 0, ANALYSIS_WIDTH, ANALYSIS_HEIGHT),
+      )
+      if (i > 0) {
+        appendFrameMotion(
+          path,
+          flow
+            ? estimateGlobalMotion(flow)
+            : { dx: 0, dy: 0, rotation: 0, scale: 1, confidence: 0 },
+        )
+      }
+
+      onProgress?.(((i + 1) / frameCount) * 100)
+    }
+  } finally {
+    if (ownsProvider) provider.destroy()
+  }
+
+  return path
+}
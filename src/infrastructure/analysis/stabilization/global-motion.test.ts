@@ -0,0 +1,60 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { FlowField } from '../motion-tracking/types'
+import { appendFrameMotion, createCameraPath, estimateGlobalMotion } from './global-motion'
+
+const WIDTH = 160
+const HEIGHT = 90
+
+/** Flow field of a rotation by `degrees` about the frame center plus a translation. */
+function createCameraFlow(degrees: number, tx: number, ty: number): FlowField {
+  const data = new Float32Array(WIDTH * HEIGHT * 2)
+  const theta = (degrees * Math.PI) / 180
+  const cx = WIDTH / 2
+  const cy = HEIGHT / 2
+  for (let y = 0; y < HEIGHT; y++) {
+    for (let x = 0; x < WIDTH; x++) {
+      const dx = x - cx
+      const dy = y - cy
+      data[(y * WIDTH + x) * 2] = cx + Math.cos(theta) * dx - Math.sin(theta) * dy + tx - x
+      data[(y * WIDTH + x) * 2 + 1] = cy + Math.sin(theta) * dx + Math.cos(theta) * dy + ty - y
+    }
+  }
+  return { width: WIDTH, height: HEIGHT, data }
+}
+
+describe('estimateGlobalMotion', () => {
+  it('recovers camera translation and roll', () => {
+    const motion = estimateGlobalMotion(createCameraFlow(1.5, 4, -2))
+
+    expect(motion.dx).toBeCloseTo(4 / WIDTH, 4)
+    expect(motion.dy).toBeCloseTo(-2 / HEIGHT, 4)
+    expect(motion.rotation).toBeCloseTo(1.5, 2)
+    expect(motion.scale).toBeCloseTo(1, 3)
+    expect(motion.confidence).toBeGreaterThan(0.9)
+  })
+
+  it('ignores a moving subject covering part of the frame', () => {
+    const flow = createCameraFlow(0, 2, 0)
+    // A subject in the left quarter of the frame moving the other way
+    for (let y = 20; y < 70; y++) {
+      for (let x = 10; x < 40; x++) {
+        flow.data[(y * WIDTH + x) * 2] = -6
+      }
+    }
+
+    expect(estimateGlobalMotion(flow).dx).toBeCloseTo(2 / WIDTH, 4)
+  })
+})
+
+describe('appendFrameMotion', () => {
+  it('accumulates motion from the first frame', () => {
+    const path = createCameraPath(30, 1.5, 16 / 9)
+    appendFrameMotion(path, { dx: 0.01, dy: -0.02, rotation: 1, scale: 1.1, confidence: 1 })
+    appendFrameMotion(path, { dx: 0.01, dy: 0, rotation: -0.5, scale: 1.1, confidence: 1 })
+
+    expect(path.x).toEqual([0, 0.01, 0.02])
+    expect(path.y).toEqual([0, -0.02, -0.02])
+    expect(path.rotation).toEqual([0, 1, 0.5])
+    expect(path.scale[2]).toBeCloseTo(1.21)
+  })
+})
@@ -0,0 +1,40 @@
+/**
+ * Global Motion
+ *
+ * Estimates the frame-to-frame camera motion from a dense optical-flow field
+ * by fitting the planar motion model of the region tracker over (almost) the
+ * whole frame, then accumulates it into a camera path. Frames where the fit
+ * is unreliable (cuts, heavy motion blur, flat frames) contribute no motion.
+ */
+
+import { advanceTrackSample, createInitialTrackSample } from '../motion-tracking/motion-tracker'
+import type { FlowField, MotionTrackRegion } from '../motion-tracking/types'
+import type { CameraPath, FrameMotion } from './types'
+
+/** Inset from the frame edges, where flow is least reliable */
+const GLOBAL_MOTION_REGION: MotionTrackRegion = { x: 0.05, y: 0.05, width: 0.9, height: 0.9 }
+
+export function estimateGlobalMotion(flow: FlowField): FrameMotion {
+  const initial = createInitialTrackSample(GLOBAL_MOTION_REGION)
+  const next = advanceTrackSample(initial, flow, 'planar', 1)
+  return {
+    dx: next.center[0] - initial.center[0],
+    dy: next.center[1] - initial.center[1],
+    rotation: next.rotation,
+    scale: next.scale,
+    confidence: next.confidence,
+  }
+}
+
+export function createCameraPath(fps: number, startTime: number, aspect: number): CameraPath {
+  return { fps, startTime, aspect, x: [0], y: [0], rotation: [0], scale: [1] }
+}
+
+/** Append the next frame's position given its motion from the previous frame. */
+export function appendFrameMotion(path: CameraPath, motion: FrameMotion): void {
+  const last = path.x.length - 1
+  path.x.push(path.x[last]! + motion.dx)
+  path.y.push(path.y[last]! + motion.dy)
+  path.rotation.push(path.rotation[last]! + motion.rotation)
+  path.scale.push(path.scale[last]! * motion.scale)
+}
@@ -0,0 +1,2 @@
+export { analyzeCameraPath } from './analyze-video'
+export type { CameraPath } from './types'
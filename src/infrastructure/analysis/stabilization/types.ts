@@ -0,0 +1,32 @@
+/**
+ * Stabilization analysis types.
+ *
+ * The camera path is the accumulated global motion of the image content from
+ * the first analyzed frame. Translation is normalized to the frame (0-1 of
+ * width/height) so the path is independent of the analysis resolution.
+ */
+
+export interface CameraPath {
+  /** Analysis rate; sample `i` is at `startTime + i / fps` */
+  fps: number
+  /** Source time (seconds) of the first sample */
+  startTime: number
+  /** Source frame aspect ratio (width / height) */
+  aspect: number
+  x: number[]
+  y: number[]
+  /** Degrees, clockwise positive */
+  rotation: number[]
+  /** Multiplicative zoom */
+  scale: number[]
+}
+
+/** Motion of the frame center between two consecutive frames */
+export interface FrameMotion {
+  dx: number
+  dy: number
+  rotation: number
+  scale: number
+  /** Fraction of flow samples agreeing with the fitted motion (0-1) */
+  confidence: number
+}
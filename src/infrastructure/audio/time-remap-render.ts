@@ -0,0 +1,236 @@
+/**
+ * Offline renderer for time-remapped (variable speed) audio.
+ *
+ * The remap curve is split into forward, backward and frozen runs. Each moving
+ * run streams its source span through one WSOLA processor whose tempo is
+ * re-tuned every timeline frame, so pitch is preserved through speed ramps;
+ * backward runs play the source reversed and frozen runs are silent. Runs
+ * overlap by a few milliseconds and are cross-faded to hide the seams.
+ *
+ * Preview and export both render through here, so a remapped clip sounds the
+ * same in both.
+ */
+
+import { TimeStretchFilter, TimeStretchProcessor } from './time-stretch'
+
+export interface TimeRemapAudioRenderInput {
+  /** Decoded source channels (mono or stereo). */
+  channels: Float32Array[]
+  sampleRate: number
+  /** Source time (seconds) of `channels[c][0]`. */
+  channelsStartSeconds: number
+  /**
+   * Source time (seconds) at each timeline frame boundary; `length - 1`
+   * frames are rendered.
+   */
+  frameSourceSeconds: ArrayLike<number>
+  fps: number
+}
+
+/** Below this rate the source is treated as frozen and rendered silent. */
+const FREEZE_RATE = 0.02
+const MIN_TEMPO = 0.1
+const MAX_TEMPO = 8
+/** Extra source fed around each run so the stretcher's windows start and end on real audio. */
+const SOURCE_PAD_SECONDS = 0.15
+const CROSSFADE_SECONDS = 0.004
+/** Matches the processor's input block size; fed as silence to flush a run. */
+const FLUSH_FRAMES = 16384
+
+type RunDirection = 'forward' | 'backward' | 'freeze'
+
+interface RemapRun {
+  startFrame: number
+  endFrame: number
+  direction: RunDirection
+}
+
+function getRunDirection(rate: number): RunDirection {
+  if (!Number.isFinite(rate) || Math.abs(rate) < FREEZE_RATE) return 'freeze'
+  return rate < 0 ? 'backward' : 'forward'
+}
+
+/**
+ * Runs break only where playback changes direction or freezes; speed changes
+ * within a run are followed by re-tuning one continuous processor.
+ */
+function splitIntoRuns(frameSourceSeconds: ArrayLike<number>, fps: number): RemapRun[] {
+  const runs: RemapRun[] = []
+  for (let frame = 0; frame < frameSourceSeconds.length - 1; frame++) {
+    const rate = (frameSourceSeconds[frame + 1]! - frameSourceSeconds[frame]!) * fps
+    const direction = getRunDirection(rate)
+    const current = runs[runs.length - 1]
+    if (current && current.direction === direction) {
+      current.endFrame = frame + 1
+      continue
+    }
+    runs.push({ startFrame: frame, endFrame: frame + 1, direction })
+  }
+  return runs
+}
+
+function interleaveStereo(channels: Float32Array[], start: number, end: number): Float32Array {
+  const left = channels[0]!
+  const right = channels[1] ?? left
+  const length = Math.max(0, end - start)
+  const interleaved = new Float32Array(length * 2)
+  for (let i = 0; i < length; i++) {
+    interleaved[i * 2] = left[start + i] ?? 0
+    interleaved[i * 2 + 1] = right[start + i] ?? 0
+  }
+  return interleaved
+}
+
+function reverseStereo(interleaved: Float32Array): Float32Array {
+  const frames = interleaved.length / 2
+  const reversed = new Float32Array(interleaved.length)
+  for (let i = 0; i < frames; i++) {
+    const from = (frames - 1 - i) * 2
+    reversed[i * 2] = interleaved[from]!
+    reversed[i * 2 + 1] = interleaved[from + 1]!
+  }
+  return reversed
+}
+
+function clampTempo(tempo: number): number {
+  return Math.max(MIN_TEMPO, Math.min(MAX_TEMPO, tempo))
+}
+
+/**
+ * Render a run as interleaved stereo: `outputFrames` frames (the run plus
+ * its crossfade tail), starting where the run's source span begins.
+ */
+function renderRun(
+  input: TimeRemapAudioRenderInput,
+  run: RemapRun,
+  outputFrames: number,
+): Float32Array {
+  const { channels, sampleRate, channelsStartSeconds, frameSourceSeconds, fps } = input
+  const rendered = new Float32Array(outputFrames * 2)
+  const available = channels[0]!.length
+  const isBackwards = run.direction === 'backward'
+  const toSample = (seconds: number) => Math.round((seconds - channelsStartSeconds) * sampleRate)
+
+  let lowSample = Number.POSITIVE_INFINITY
+  let highSample = Number.NEGATIVE_INFINITY
+  for (let frame = run.startFrame; frame <= run.endFrame; frame++) {
+    const sample = toSample(frameSourceSeconds[frame]!)
+    lowSample = Math.min(lowSample, sample)
+    highSample = Math.max(highSample, sample)
+  }
+  lowSample = Math.max(0, Math.min(available, lowSample))
+  highSample = Math.max(lowSample, Math.min(available, highSample))
+  if (highSample <= lowSample) return rendered
+
+  const padFrames = Math.round(SOURCE_PAD_SECONDS * sampleRate)
+  const paddedLow = Math.max(0, lowSample - padFrames)
+  const paddedHigh = Math.min(available, highSample + padFrames)
+  const forward = interleaveStereo(channels, paddedLow, paddedHigh)
+  const source = isBackwards ? reverseStereo(forward) : forward
+  const sourceFrames = source.length / 2
+  // Position within `source` (which always plays forwards) of a source time.
+  const toPosition = (seconds: number) => {
+    const sample = Math.max(paddedLow, Math.min(paddedHigh, toSample(seconds)))
+    return isBackwards ? paddedHigh - sample : sample - paddedLow
+  }
+
+  const processor = new TimeStretchProcessor()
+  processor.pitch = 1
+  processor.rate = 1
+  const collected: Float32Array[] = []
+  let collectedFrames = 0
+  const feed = (samples: Float32Array, tempo: number) => {
+    processor.tempo = tempo
+    processor.inputBuffer.putSamples(samples, 0, samples.length / 2)
+    processor.process()
+    const ready = processor.outputBuffer.frameCount
+    if (ready === 0) return
+    const chunk = new Float32Array(ready * 2)
+    processor.outputBuffer.receiveSamples(chunk, ready)
+    collected.push(chunk)
+    collectedFrames += ready
+  }
+
+  // Input queued inside the processor, not yet turned into output.
+  const pendingFrames = () =>
+    processor.inputBuffer.frameCount + processor.stretch.inputBuffer.frameCount
+
+  const runStartPosition = toPosition(frameSourceSeconds[run.startFrame]!)
+  const samplesPerFrame = sampleRate / fps
+  const firstTempo = clampTempo(
+    Math.abs(toPosition(frameSourceSeconds[run.startFrame + 1]!) - runStartPosition) /
+      samplesPerFrame,
+  )
+  const leadOutputFrames = runStartPosition / firstTempo
+  feed(source.subarray(0, runStartPosition * 2), firstTempo)
+
+  let position = runStartPosition
+  let lastTempo = firstTempo
+  for (let frame = run.startFrame; frame < run.endFrame; frame++) {
+    const nextPosition = Math.max(position, toPosition(frameSourceSeconds[frame + 1]!))
+    const nominalTempo = (nextPosition - position) / samplesPerFrame
+    // The processor lags its input, so steer the tempo to keep consumed input
+    // and produced output on the curve instead of trusting the nominal rate.
+    const consumed = position - pendingFrames()
+    const targetOutput = leadOutputFrames + (frame + 1 - run.startFrame) * samplesPerFrame
+    const remainingOutput = targetOutput - collectedFrames
+    const steeredTempo =
+      remainingOutput > 0 ? (nextPosition - consumed) / remainingOutput : MAX_TEMPO
+    const tempo = clampTempo(
+      Math.max(nominalTempo * 0.5, Math.min(nominalTempo * 2, steeredTempo)),
+    )
+    feed(source.subarray(position * 2, nextPosition * 2), tempo)
+    position = nextPosition
+    lastTempo = tempo
+  }
+  feed(source.subarray(position * 2, sourceFrames * 2), lastTempo)
+  feed(new Float32Array(FLUSH_FRAMES * 2), lastTempo)
+
+  const stretched = new Float32Array(collectedFrames * 2)
+  let writeOffset = 0
+  for (const chunk of collected) {
+    stretched.set(chunk, writeOffset)
+    writeOffset += chunk.length
+  }
+  const offset = Math.round(leadOutputFrames) * 2
+  rendered.set(stretched.subarray(offset, Math.min(stretched.length, offset + rendered.length)))
+  return rendered
+}
+
+/**
+ * Render a remapped clip to `round(frames / fps * sampleRate)` output frames.
+ * Output has the same channel count as the input (mono stays mono).
+ */
+export function renderTimeRemappedAudio(input: TimeRemapAudioRenderInput): Float32Array[] {
+  const { channels, sampleRate, fps, frameSourceSeconds } = input
+  const frameCount = Math.max(0, frameSourceSeconds.length - 1)
+  const totalFrames = Math.round((frameCount / fps) * sampleRate)
+  const outLeft = new Float32Array(totalFrames)
+  const outRight = new Float32Array(totalFrames)
+  if (channels.length === 0 || channels[0]!.length === 0 || totalFrames === 0) {
+    return channels.length >= 2 ? [outLeft, outRight] : [outLeft]
+  }
+
+  const crossfadeFrames = Math.max(1, Math.round(CROSSFADE_SECONDS * sampleRate))
+  const toOutputFrame = (frame: number) => Math.round((frame / fps) * sampleRate)
+
+  for (const [index, run] of splitIntoRuns(frameSourceSeconds, fps).entries()) {
+    if (run.direction === 'freeze') continue
+    const start = toOutputFrame(run.startFrame)
+    const end = toOutputFrame(run.endFrame)
+    const rendered = renderRun(input, run, end - start + crossfadeFrames)
+    const fadeIn = index > 0
+
+    for (let i = 0; i < rendered.length / 2; i++) {
+      const target = start + i
+      if (target >= totalFrames) break
+      let gain = 1
+      if (fadeIn && i < crossfadeFrames) gain = i / crossfadeFrames
+      else if (target >= end) gain = 1 - (target - end) / crossfadeFrames
+      outLeft[target] = outLeft[target]! + rendered[i * 2]! * gain
+      outRight[target] = outRight[target]! + rendered[i * 2 + 1]! * gain
+    }
+  }
+
+  return channels.length >= 2 ? [outLeft, outRight] : [outLeft]
+}
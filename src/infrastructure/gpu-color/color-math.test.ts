@@ -0,0 +1,112 @@
+import { describe, expect, it } from 'vite-plus/test'
+import {
+  convertWorkingColor,
+  eetf,
+  hlgInverseOetf,
+  hlgOetf,
+  isHdrSourceColorInfo,
+  nitsToSignal,
+  parseHexRgb,
+  pqDecode,
+  pqEncode,
+  resolveSourceColorInfo,
+  SDR_REFERENCE_WHITE_NITS,
+  toneMapToSdr,
+  workingToNits,
+} from './color-math'
+
+describe('transfer functions', () => {
+  it('round-trips PQ and hits the published reference levels', () => {
+    expect(pqEncode(10000)).toBeCloseTo(1, 5)
+    expect(pqEncode(100)).toBeCloseTo(0.508, 3)
+    expect(pqEncode(203)).toBeCloseTo(0.58, 2)
+    for (const nits of [0.1, 1, 100, 1000, 4000]) {
+      expect(pqDecode(pqEncode(nits))).toBeCloseTo(nits, 3)
+    }
+  })
+
+  it('round-trips HLG across the gamma and log segments', () => {
+    expect(hlgOetf(1 / 12)).toBeCloseTo(0.5, 6)
+    expect(hlgOetf(1)).toBeCloseTo(1, 5)
+    for (const scene of [0.01, 0.05, 0.3, 0.9]) {
+      expect(hlgInverseOetf(hlgOetf(scene))).toBeCloseTo(scene, 6)
+    }
+  })
+})
+
+describe('working space conversion', () => {
+  it('places SDR white on the HDR reference white', () => {
+    const nits = workingToNits([1, 1, 1], 'rec709')
+    for (const channel of nits) expect(channel).toBeCloseTo(SDR_REFERENCE_WHITE_NITS, 0)
+
+    const pq = convertWorkingColor([1, 1, 1], 'rec709', 'rec2100-pq')
+    expect(pq[0]).toBeCloseTo(pqEncode(SDR_REFERENCE_WHITE_NITS), 3)
+  })
+
+  it('round-trips between PQ and HLG working spaces', () => {
+    const original: [number, number, number] = [0.4, 0.55, 0.3]
+    const back = convertWorkingColor(
+      convertWorkingColor(original, 'rec2100-pq', 'rec2100-hlg'),
+      'rec2100-hlg',
+      'rec2100-pq',
+    )
+    back.forEach((channel, index) => expect(channel).toBeCloseTo(original[index]!, 3))
+  })
+
+  it('maps HDR reference white to the BT.2408 HLG signal level', () => {
+    expect(nitsToSignal(SDR_REFERENCE_WHITE_NITS, 'rec2100-hlg')).toBeCloseTo(0.75, 2)
+  })
+})
+
+describe('tone mapping', () => {
+  it('leaves levels below the knee untouched and compresses the peak to SDR white', () => {
+    expect(eetf(50, 1000, SDR_REFERENCE_WHITE_NITS)).toBeCloseTo(50, 6)
+    expect(eetf(1000, 1000, SDR_REFERENCE_WHITE_NITS)).toBeCloseTo(SDR_REFERENCE_WHITE_NITS, 0)
+    expect(eetf(500, 1000, SDR_REFERENCE_WHITE_NITS)).toBeLessThan(SDR_REFERENCE_WHITE_NITS)
+  })
+
+  it('keeps tone-mapped output monotonic and in range', () => {
+    let previous = -1
+    for (const nits of [0, 10, 100, 203, 400, 800, 1000]) {
+      const [r] = toneMapToSdr([nits, nits, nits])
+      expect(r).toBeGreaterThanOrEqual(previous)
+      expect(r).toBeLessThanOrEqual(1)
+      previous = r
+    }
+    expect(toneMapToSdr([1000, 1000, 1000])[0]).toBeCloseTo(1, 2)
+  })
+})
+
+describe('resolveSourceColorInfo', () => {
+  it('reads HDR transfer, primaries, and bit depth from a decoded frame', () => {
+    const info = resolveSourceColorInfo({
+      format: 'I420P10',
+      colorSpace: { primaries: 'bt2020', transfer: 'pq', matrix: 'bt2020-ncl', fullRange: false },
+    })
+
+    expect(info).toEqual({
+      primaries: 'bt2020',
+      transfer: 'pq',
+      matrix: 'bt2020-ncl',
+      fullRange: false,
+      bitDepth: 10,
+    })
+    expect(isHdrSourceColorInfo(info)).toBe(true)
+  })
+
+  it('treats untagged 8-bit frames as SDR Rec.709', () => {
+    const info = resolveSourceColorInfo({ format: 'I420', colorSpace: null })
+
+    expect(info.transfer).toBe('bt709')
+    expect(info.bitDepth).toBe(8)
+    expect(isHdrSourceColorInfo(info)).toBe(false)
+  })
+})
+
+describe('parseHexRgb', () => {
+  it('parses short and long hex colours and falls back to black', () => {
+    expect(parseHexRgb('#ffffff')).toEqual([1, 1, 1])
+    expect(parseHexRgb('#f00')).toEqual([1, 0, 0])
+    expect(parseHexRgb('rgb(1, 2, 3)')).toEqual([0, 0, 0])
+  })
+})
@@ -0,0 +1,274 @@
+/**
+ * Colour management math (CPU reference).
+ *
+ * Every conversion goes through one connection space: linear light with
+ * Rec.2020 primaries, in nits. SDR Rec.709 content is anchored so diffuse
+ * white lands on the BT.2408 reference white (203 nits); HLG uses the
+ * nominal 1000-nit reference display. The WGSL in `color-wgsl.ts` mirrors
+ * these functions for the GPU passes.
+ */
+
+import type {
+  ColorPrimaries,
+  ColorTransfer,
+  ProjectColorSpace,
+  SourceColorInfo,
+} from '@/types/color'
+
+export type Rgb = [number, number, number]
+
+/** Nits that SDR diffuse white maps to in HDR (ITU-R BT.2408) */
+export const SDR_REFERENCE_WHITE_NITS = 203
+/** Peak of the nominal HLG reference display */
+export const HLG_NOMINAL_PEAK_NITS = 1000
+export const PQ_MAX_NITS = 10000
+/** Assumed mastering peak when tone mapping HDR for an SDR display */
+export const DEFAULT_HDR_PEAK_NITS = 1000
+
+const HLG_SYSTEM_GAMMA = 1.2
+
+const PQ_M1 = 2610 / 16384
+const PQ_M2 = (2523 / 4096) * 128
+const PQ_C1 = 3424 / 4096
+const PQ_C2 = (2413 / 4096) * 32
+const PQ_C3 = (2392 / 4096) * 32
+
+const HLG_A = 0.17883277
+const HLG_B = 1 - 4 * HLG_A
+const HLG_C = 0.5 - HLG_A * Math.log(4 * HLG_A)
+
+/** BT.2020 luma weights, used for the HLG OOTF and tone mapping */
+const BT2020_LUMA: Rgb = [0.2627, 0.678, 0.0593]
+
+const BT709_TO_BT2020: [Rgb, Rgb, Rgb] = [
+  [0.6274, 0.3293, 0.0433],
+  [0.0691, 0.9195, 0.0114],
+  [0.0164, 0.088, 0.8956],
+]
+
+const BT2020_TO_BT709: [Rgb, Rgb, Rgb] = [
+  [1.6605, -0.5876, -0.0728],
+  [-0.1246, 1.1329, -0.0083],
+  [-0.0182, -0.1006, 1.1187],
+]
+
+const clamp01 = (value: number) => Math.max(0, Math.min(1, value))
+
+function mapRgb(rgb: Rgb, fn: (value: number) => number): Rgb {
+  return [fn(rgb[0]), fn(rgb[1]), fn(rgb[2])]
+}
+
+function multiply(matrix: [Rgb, Rgb, Rgb], rgb: Rgb): Rgb {
+  return matrix.map((row) => row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2]) as Rgb
+}
+
+function luminance(rgb: Rgb): number {
+  return BT2020_LUMA[0] * rgb[0] + BT2020_LUMA[1] * rgb[1] + BT2020_LUMA[2] * rgb[2]
+}
+
+export function bt709ToBt2020(rgb: Rgb): Rgb {
+  return multiply(BT709_TO_BT2020, rgb)
+}
+
+export function bt2020ToBt709(rgb: Rgb): Rgb {
+  return multiply(BT2020_TO_BT709, rgb)
+}
+
+// ─── Transfer functions ───
+
+/** SMPTE ST 2084 inverse EOTF: nits → signal */
+export function pqEncode(nits: number): number {
+  const y = Math.pow(clamp01(nits / PQ_MAX_NITS), PQ_M1)
+  return Math.pow((PQ_C1 + PQ_C2 * y) / (1 + PQ_C3 * y), PQ_M2)
+}
+
+/** SMPTE ST 2084 EOTF: signal → nits */
+export function pqDecode(signal: number): number {
+  const e = Math.pow(clamp01(signal), 1 / PQ_M2)
+  return PQ_MAX_NITS * Math.pow(Math.max(e - PQ_C1, 0) / (PQ_C2 - PQ_C3 * e), 1 / PQ_M1)
+}
+
+/** ARIB STD-B67 OETF: normalized scene light → signal */
+export function hlgOetf(scene: number): number {
+  const e = Math.max(0, scene)
+  return e <= 1 / 12 ? Math.sqrt(3 * e) : HLG_A * Math.log(12 * e - HLG_B) + HLG_C
+}
+
+/** ARIB STD-B67 inverse OETF: signal → normalized scene light */
+export function hlgInverseOetf(signal: number): number {
+  const e = Math.max(0, signal)
+  return e <= 0.5 ? (e * e) / 3 : (Math.exp((e - HLG_C) / HLG_A) + HLG_B) / 12
+}
+
+export function srgbDecode(signal: number): number {
+  const e = Math.max(0, signal)
+  return e <= 0.04045 ? e / 12.92 : Math.pow((e + 0.055) / 1.055, 2.4)
+}
+
+export function srgbEncode(linear: number): number {
+  const e = clamp01(linear)
+  return e <= 0.0031308 ? e * 12.92 : 1.055 * Math.pow(e, 1 / 2.4) - 0.055
+}
+
+/** HLG signal → display light in nits (includes the 1000-nit OOTF) */
+export function hlgToNits(signal: Rgb): Rgb {
+  const scene = mapRgb(signal, hlgInverseOetf)
+  const gain = Math.pow(Math.max(luminance(scene), 1e-6), HLG_SYSTEM_GAMMA - 1)
+  return mapRgb(scene, (value) => HLG_NOMINAL_PEAK_NITS * gain * value)
+}
+
+/** Display light in nits → HLG signal (inverse OOTF, then OETF) */
+export function nitsToHlg(nits: Rgb): Rgb {
+  const displayLuma = Math.max(luminance(nits) / HLG_NOMINAL_PEAK_NITS, 1e-6)
+  const gain = Math.pow(displayLuma, (1 - HLG_SYSTEM_GAMMA) / HLG_SYSTEM_GAMMA)
+  return mapRgb(nits, (value) => hlgOetf((value / HLG_NOMINAL_PEAK_NITS) * gain))
+}
+
+// ─── Working space conversion ───
+
+/** Working-space signal → linear Rec.2020 nits */
+export function workingToNits(rgb: Rgb, space: ProjectColorSpace): Rgb {
+  switch (space) {
+    case 'rec2100-pq':
+      return mapRgb(rgb, pqDecode)
+    case 'rec2100-hlg':
+      return hlgToNits(rgb)
+    default:
+      return bt709ToBt2020(mapRgb(rgb, (v) => srgbDecode(v) * SDR_REFERENCE_WHITE_NITS))
+  }
+}
+
+/** Linear Rec.2020 nits → working-space signal. SDR clips above reference white. */
+export function nitsToWorking(nits: Rgb, space: ProjectColorSpace): Rgb {
+  switch (space) {
+    case 'rec2100-pq':
+      return mapRgb(nits, pqEncode)
+    case 'rec2100-hlg':
+      return nitsToHlg(nits)
+    default:
+      return mapRgb(bt2020ToBt709(nits), (v) => srgbEncode(v / SDR_REFERENCE_WHITE_NITS))
+  }
+}
+
+export function convertWorkingColor(rgb: Rgb, from: ProjectColorSpace, to: ProjectColorSpace): Rgb {
+  if (from === to) return rgb
+  return nitsToWorking(workingToNits(rgb, from), to)
+}
+
+/** `#rgb` / `#rrggbb` CSS colour → sRGB Rec.709 signal (0–1); anything else is black. */
+export function parseHexRgb(color: string): Rgb {
+  const hex = color.startsWith('#') ? color.slice(1) : ''
+  const full = hex.length === 3 ? hex.replace(/./g, (ch) => ch + ch) : hex.slice(0, 6)
+  if (!/^[0-9a-f]{6}$/i.test(full)) return [0, 0, 0]
+  return [0, 2, 4].map((i) => parseInt(full.slice(i, i + 2), 16) / 255) as Rgb
+}
+
+/** Decoded source RGB (still transfer-encoded) → linear Rec.2020 nits */
+export function sourceToNits(rgb: Rgb, transfer: ColorTransfer, primaries: ColorPrimaries): Rgb {
+  let linear: Rgb
+  switch (transfer) {
+    case 'pq':
+      return mapRgb(rgb, pqDecode)
+    case 'hlg':
+      return hlgToNits(rgb)
+    case 'linear':
+      linear = mapRgb(rgb, (v) => Math.max(0, v) * SDR_REFERENCE_WHITE_NITS)
+      break
+    case 'bt709':
+      // BT.1886 reference display gamma
+      linear = mapRgb(rgb, (v) => Math.pow(Math.max(0, v), 2.4) * SDR_REFERENCE_WHITE_NITS)
+      break
+    default:
+      linear = mapRgb(rgb, (v) => srgbDecode(v) * SDR_REFERENCE_WHITE_NITS)
+  }
+  return primaries === 'bt2020' ? linear : bt709ToBt2020(linear)
+}
+
+// ─── Tone mapping ───
+
+/**
+ * BT.2390 EETF: compress luminance above a knee so `sourcePeak` lands on
+ * `targetPeak`, leaving everything below the knee untouched.
+ */
+export function eetf(nits: number, sourcePeak: number, targetPeak: number): number {
+  if (nits <= 0) return 0
+  if (targetPeak >= sourcePeak) return nits
+  const sourcePeakPq = pqEncode(sourcePeak)
+  const e = clamp01(pqEncode(nits) / sourcePeakPq)
+  const maxLum = pqEncode(targetPeak) / sourcePeakPq
+  const kneeStart = 1.5 * maxLum - 0.5
+  if (e < kneeStart) return nits
+
+  const t = (e - kneeStart) / (1 - kneeStart)
+  const t2 = t * t
+  const t3 = t2 * t
+  const mapped =
+    (2 * t3 - 3 * t2 + 1) * kneeStart +
+    (t3 - 2 * t2 + t) * (1 - kneeStart) +
+    (-2 * t3 + 3 * t2) * maxLum
+  return pqDecode(mapped * sourcePeakPq)
+}
+
+/**
+ * Tone map linear Rec.2020 nits for an SDR display: the EETF is applied to
+ * the brightest channel (hue-preserving), reference white becomes SDR
+ * white, and the result is returned sRGB-encoded with Rec.709 primaries.
+ */
+export function toneMapToSdr(nits: Rgb, sourcePeak = DEFAULT_HDR_PEAK_NITS): Rgb {
+  const peak = Math.max(nits[0], nits[1], nits[2])
+  const scale = peak > 0 ? eetf(peak, sourcePeak, SDR_REFERENCE_WHITE_NITS) / peak : 0
+  const bt709 = bt2020ToBt709(mapRgb(nits, (v) => Math.max(0, v) * scale))
+  return mapRgb(bt709, (v) => srgbEncode(v / SDR_REFERENCE_WHITE_NITS))
+}
+
+/** Signal level of an achromatic `nits` patch; used for scope graticules. */
+export function nitsToSignal(nits: number, space: ProjectColorSpace): number {
+  return nitsToWorking([nits, nits, nits], space)[0]
+}
+
+// ─── Source colour description ───
+
+const HIGH_BIT_DEPTH_FORMAT = /P(10|12)$/
+
+/** Resolve a decoded frame's colour description from its WebCodecs metadata. */
+export function resolveSourceColorInfo(frame: {
+  format: VideoPixelFormat | null
+  colorSpace?: VideoColorSpaceInit | null
+}): SourceColorInfo {
+  const colorSpace = frame.colorSpace ?? {}
+  const transfer: ColorTransfer =
+    colorSpace.transfer === 'pq'
+      ? 'pq'
+      : colorSpace.transfer === 'hlg'
+        ? 'hlg'
+        : colorSpace.transfer === 'linear'
+          ? 'linear'
+          : colorSpace.transfer === 'iec61966-2-1'
+            ? 'srgb'
+            : 'bt709'
+  const matrix =
+    colorSpace.matrix === 'bt2020-ncl'
+      ? 'bt2020-ncl'
+      : colorSpace.matrix === 'bt470bg' || colorSpace.matrix === 'smpte170m'
+        ? 'bt601'
+        : 'bt709'
+  const depthMatch = frame.format ? HIGH_BIT_DEPTH_FORMAT.exec(frame.format) : null
+
+  return {
+    primaries: colorSpace.primaries === 'bt2020' ? 'bt2020' : 'bt709',
+    transfer,
+    matrix,
+    fullRange: colorSpace.fullRange ?? false,
+    bitDepth: depthMatch ? Number(depthMatch[1]) : 8,
+  }
+}
+
+/** Sources that lose range or gamut when drawn through an 8-bit SDR canvas */
+export function isHdrSourceColorInfo(info: SourceColorInfo): boolean {
+  return (
+    info.transfer === 'pq' ||
+    info.transfer === 'hlg' ||
+    info.primaries === 'bt2020' ||
+    info.bitDepth > 8
+  )
+}
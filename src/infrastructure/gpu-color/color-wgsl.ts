@@ -0,0 +1,174 @@
+/**
+ * WGSL colour management functions, mirroring `color-math.ts`.
+ *
+ * The connection space is linear Rec.2020 in nits. Colour spaces, transfers
+ * and primaries are passed to shaders as u32 indices (see the maps below).
+ */
+
+import type { ColorPrimaries, ColorTransfer, ProjectColorSpace } from '@/types/color'
+import { HLG_NOMINAL_PEAK_NITS, SDR_REFERENCE_WHITE_NITS } from './color-math'
+
+export const COLOR_SPACE_INDEX: Record<ProjectColorSpace, number> = {
+  rec709: 0,
+  'rec2100-pq': 1,
+  'rec2100-hlg': 2,
+}
+
+export const COLOR_TRANSFER_INDEX: Record<ColorTransfer, number> = {
+  srgb: 0,
+  bt709: 1,
+  pq: 2,
+  hlg: 3,
+  linear: 4,
+}
+
+export const COLOR_PRIMARIES_INDEX: Record<ColorPrimaries, number> = {
+  bt709: 0,
+  bt2020: 1,
+}
+
+export const COLOR_MANAGEMENT_WGSL = /* wgsl */ `
+const COLOR_SDR_WHITE_NITS: f32 = ${SDR_REFERENCE_WHITE_NITS.toFixed(1)};
+const COLOR_HLG_PEAK_NITS: f32 = ${HLG_NOMINAL_PEAK_NITS.toFixed(1)};
+const COLOR_PQ_MAX_NITS: f32 = 10000.0;
+const COLOR_BT2020_LUMA = vec3f(0.2627, 0.678, 0.0593);
+
+fn color_bt709_to_bt2020(c: vec3f) -> vec3f {
+  return vec3f(
+    dot(vec3f(0.6274, 0.3293, 0.0433), c),
+    dot(vec3f(0.0691, 0.9195, 0.0114), c),
+    dot(vec3f(0.0164, 0.0880, 0.8956), c)
+  );
+}
+
+fn color_bt2020_to_bt709(c: vec3f) -> vec3f {
+  return vec3f(
+    dot(vec3f(1.6605, -0.5876, -0.0728), c),
+    dot(vec3f(-0.1246, 1.1329, -0.0083), c),
+    dot(vec3f(-0.0182, -0.1006, 1.1187), c)
+  );
+}
+
+// ─── Transfer functions ───
+
+fn color_pq_encode(nits: vec3f) -> vec3f {
+  let y = pow(clamp(nits / COLOR_PQ_MAX_NITS, vec3f(0.0), vec3f(1.0)), vec3f(0.1593017578125));
+  let num = 0.8359375 + 18.8515625 * y;
+  let den = 1.0 + 18.6875 * y;
+  return pow(num / den, vec3f(78.84375));
+}
+
+fn color_pq_decode(signal: vec3f) -> vec3f {
+  let e = pow(clamp(signal, vec3f(0.0), vec3f(1.0)), vec3f(1.0 / 78.84375));
+  let num = max(e - 0.8359375, vec3f(0.0));
+  let den = 18.8515625 - 18.6875 * e;
+  return COLOR_PQ_MAX_NITS * pow(num / den, vec3f(1.0 / 0.1593017578125));
+}
+
+fn color_hlg_oetf1(e: f32) -> f32 {
+  let v = max(e, 0.0);
+  if (v <= 1.0 / 12.0) { return sqrt(3.0 * v); }
+  return 0.17883277 * log(12.0 * v - 0.28466892) + 0.55991073;
+}
+
+fn color_hlg_inverse_oetf1(e: f32) -> f32 {
+  let v = max(e, 0.0);
+  if (v <= 0.5) { return v * v / 3.0; }
+  return (exp((v - 0.55991073) / 0.17883277) + 0.28466892) / 12.0;
+}
+
+fn color_hlg_to_nits(signal: vec3f) -> vec3f {
+  let scene = vec3f(
+    color_hlg_inverse_oetf1(signal.r),
+    color_hlg_inverse_oetf1(signal.g),
+    color_hlg_inverse_oetf1(signal.b)
+  );
+  let gain = pow(max(dot(COLOR_BT2020_LUMA, scene), 1e-6), 0.2);
+  return COLOR_HLG_PEAK_NITS * gain * scene;
+}
+
+fn color_nits_to_hlg(nits: vec3f) -> vec3f {
+  let displayLuma = max(dot(COLOR_BT2020_LUMA, nits) / COLOR_HLG_PEAK_NITS, 1e-6);
+  let scene = nits / COLOR_HLG_PEAK_NITS * pow(displayLuma, -0.2 / 1.2);
+  return vec3f(color_hlg_oetf1(scene.r), color_hlg_oetf1(scene.g), color_hlg_oetf1(scene.b));
+}
+
+fn color_srgb_decode(c: vec3f) -> vec3f {
+  let v = max(c, vec3f(0.0));
+  return select(pow((v + 0.055) / 1.055, vec3f(2.4)), v / 12.92, v <= vec3f(0.04045));
+}
+
+fn color_srgb_encode(c: vec3f) -> vec3f {
+  let v = clamp(c, vec3f(0.0), vec3f(1.0));
+  return select(1.055 * pow(v, vec3f(1.0 / 2.4)) - 0.055, v * 12.92, v <= vec3f(0.0031308));
+}
+
+// ─── Working spaces (0 = rec709, 1 = PQ, 2 = HLG) ───
+
+fn color_working_to_nits(c: vec3f, space: u32) -> vec3f {
+  if (space == 1u) { return color_pq_decode(c); }
+  if (space == 2u) { return color_hlg_to_nits(c); }
+  return color_bt709_to_bt2020(color_srgb_decode(c) * COLOR_SDR_WHITE_NITS);
+}
+
+fn color_nits_to_working(nits: vec3f, space: u32) -> vec3f {
+  if (space == 1u) { return color_pq_encode(nits); }
+  if (space == 2u) { return color_nits_to_hlg(nits); }
+  return color_srgb_encode(color_bt2020_to_bt709(nits) / COLOR_SDR_WHITE_NITS);
+}
+
+fn color_convert_working(c: vec3f, fromSpace: u32, toSpace: u32) -> vec3f {
+  if (fromSpace == toSpace) { return c; }
+  return color_nits_to_working(color_working_to_nits(c, fromSpace), toSpace);
+}
+
+// Decoded source RGB → nits. Transfer: 0 sRGB, 1 BT.709, 2 PQ, 3 HLG, 4 linear.
+fn color_source_to_nits(c: vec3f, transfer: u32, primaries: u32) -> vec3f {
+  if (transfer == 2u) { return color_pq_decode(c); }
+  if (transfer == 3u) { return color_hlg_to_nits(c); }
+  var lin: vec3f;
+  if (transfer == 4u) {
+    lin = max(c, vec3f(0.0));
+  } else if (transfer == 1u) {
+    lin = pow(max(c, vec3f(0.0)), vec3f(2.4));
+  } else {
+    lin = color_srgb_decode(c);
+  }
+  lin *= COLOR_SDR_WHITE_NITS;
+  if (primaries == 1u) { return lin; }
+  return color_bt709_to_bt2020(lin);
+}
+
+// ─── Tone mapping (BT.2390 EETF on maxRGB) ───
+
+fn color_pq_encode1(nits: f32) -> f32 {
+  return color_pq_encode(vec3f(nits)).x;
+}
+
+fn color_eetf(nits: f32, sourcePeak: f32, targetPeak: f32) -> f32 {
+  if (nits <= 0.0) { return 0.0; }
+  if (targetPeak >= sourcePeak) { return nits; }
+  let sourcePeakPq = color_pq_encode1(sourcePeak);
+  let e = clamp(color_pq_encode1(nits) / sourcePeakPq, 0.0, 1.0);
+  let maxLum = color_pq_encode1(targetPeak) / sourcePeakPq;
+  let ks = 1.5 * maxLum - 0.5;
+  if (e < ks) { return nits; }
+  let t = (e - ks) / (1.0 - ks);
+  let t2 = t * t;
+  let t3 = t2 * t;
+  let mapped = (2.0 * t3 - 3.0 * t2 + 1.0) * ks
+    + (t3 - 2.0 * t2 + t) * (1.0 - ks)
+    + (-2.0 * t3 + 3.0 * t2) * maxLum;
+  return color_pq_decode(vec3f(mapped * sourcePeakPq)).x;
+}
+
+fn color_tone_map_to_sdr(nits: vec3f, sourcePeak: f32) -> vec3f {
+  let peak = max(max(nits.r, nits.g), nits.b);
+  var scale = 0.0;
+  if (peak > 0.0) {
+    scale = color_eetf(peak, sourcePeak, COLOR_SDR_WHITE_NITS) / peak;
+  }
+  let bt709 = color_bt2020_to_bt709(max(nits, vec3f(0.0)) * scale);
+  return color_srgb_encode(bt709 / COLOR_SDR_WHITE_NITS);
+}
+`
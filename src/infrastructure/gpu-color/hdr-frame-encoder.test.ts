@@ -0,0 +1,44 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { getHdrCodecString, getHdrVideoColorSpace, getI420P10Layout } from './hdr-frame-encoder'
+
+describe('getHdrVideoColorSpace', () => {
+  it('tags PQ and HLG projects as BT.2100 limited range', () => {
+    expect(getHdrVideoColorSpace('rec2100-pq')).toEqual({
+      primaries: 'bt2020',
+      transfer: 'pq',
+      matrix: 'bt2020-ncl',
+      fullRange: false,
+    })
+    expect(getHdrVideoColorSpace('rec2100-hlg').transfer).toBe('hlg')
+  })
+})
+
+describe('getHdrCodecString', () => {
+  it('selects 10-bit profiles', () => {
+    expect(getHdrCodecString('h265')).toBe('hvc1.2.4.L153.B0')
+    expect(getHdrCodecString('av1')).toBe('av01.0.08M.10')
+  })
+})
+
+describe('getI420P10Layout', () => {
+  it('packs planes tightly when the size is already aligned', () => {
+    const layout = getI420P10Layout(1920, 1080)
+
+    expect(layout.planes).toEqual([
+      { offset: 0, stride: 3840 },
+      { offset: 1920 * 1080 * 2, stride: 1920 },
+      { offset: 1920 * 1080 * 2 + 960 * 540 * 2, stride: 1920 },
+    ])
+    expect(layout.byteLength).toBe(1920 * 1080 * 3)
+  })
+
+  it('pads odd sizes to whole 4×2 blocks', () => {
+    const layout = getI420P10Layout(1366, 767)
+
+    expect(layout.lumaStride).toBe(1368)
+    expect(layout.lumaRows).toBe(768)
+    expect(layout.chromaStride).toBe(684)
+    expect(layout.chromaRows).toBe(384)
+    expect(layout.byteLength % 4).toBe(0)
+  })
+})
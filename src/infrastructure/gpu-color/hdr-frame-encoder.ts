@@ -0,0 +1,277 @@
+/**
+ * HDR Frame Encoder
+ *
+ * Converts a composite in an HDR working space into 10-bit 4:2:0 BT.2020
+ * non-constant-luminance YUV (limited range) and reads it back as an
+ * `I420P10` buffer for WebCodecs. The composite is resampled to the output
+ * size and flattened over the project background colour.
+ *
+ * Each compute invocation handles a 4×2 pixel block so two luma samples and
+ * one pair of chroma samples pack into whole u32 words. Planes are padded to
+ * a multiple of 4 columns / 2 rows; `getI420P10Layout` describes the padding
+ * so the buffer can be handed to `VideoFrame` without repacking.
+ */
+
+import type { ProjectColorSpace } from '@/types/color'
+import { createLogger } from '@/shared/logging/logger'
+import { COLOR_MANAGEMENT_WGSL, COLOR_SPACE_INDEX } from './color-wgsl'
+
+const logger = createLogger('HdrFrameEncoder')
+
+export type HdrVideoCodec = 'h265' | 'av1'
+
+/** VideoColorSpace tags for an HDR working space (BT.2100). */
+export function getHdrVideoColorSpace(space: ProjectColorSpace): VideoColorSpaceInit {
+  return {
+    primaries: 'bt2020',
+    transfer: space === 'rec2100-hlg' ? 'hlg' : 'pq',
+    matrix: 'bt2020-ncl',
+    fullRange: false,
+  }
+}
+
+/** Main 10 codec strings for HDR export. */
+export function getHdrCodecString(codec: HdrVideoCodec): string {
+  // HEVC Main 10, level 5.1; AV1 Main profile, level 4.0, 10-bit
+  return codec === 'h265' ? 'hvc1.2.4.L153.B0' : 'av01.0.08M.10'
+}
+
+export interface I420P10Layout {
+  /** Luma stride in samples */
+  lumaStride: number
+  lumaRows: number
+  chromaStride: number
+  chromaRows: number
+  /** Plane layouts in bytes, for `VideoFrameBufferInit.layout` */
+  planes: PlaneLayout[]
+  byteLength: number
+}
+
+export function getI420P10Layout(width: number, height: number): I420P10Layout {
+  const lumaStride = Math.ceil(width / 4) * 4
+  const lumaRows = Math.ceil(height / 2) * 2
+  const chromaStride = lumaStride / 2
+  const chromaRows = lumaRows / 2
+  const lumaBytes = lumaStride * lumaRows * 2
+  const chromaBytes = chromaStride * chromaRows * 2
+  return {
+    lumaStride,
+    lumaRows,
+    chromaStride,
+    chromaRows,
+    planes: [
+      { offset: 0, stride: lumaStride * 2 },
+      { offset: lumaBytes, stride: chromaStride * 2 },
+      { offset: lumaBytes + chromaBytes, stride: chromaStride * 2 },
+    ],
+    byteLength: lumaBytes + chromaBytes * 2,
+  }
+}
+
+const ENCODE_SHADER = /* wgsl */ `
+${COLOR_MANAGEMENT_WGSL}
+
+struct EncodeUniforms {
+  background: vec4f,
+  outW: u32,
+  outH: u32,
+  lumaStride: u32,
+  lumaRows: u32,
+  // Offsets into the output buffer, in u32 words
+  uOffset: u32,
+  vOffset: u32,
+  workingColorSpace: u32,
+  _pad: u32,
+};
+
+@group(0) @binding(0) var texSampler: sampler;
+@group(0) @binding(1) var inputTex: texture_2d<f32>;
+@group(0) @binding(2) var<uniform> u: EncodeUniforms;
+@group(0) @binding(3) var<storage, read_write> out: array<u32>;
+
+const KR = 0.2627;
+const KB = 0.0593;
+
+fn samplePixel(x: u32, y: u32, bg: vec3f) -> vec3f {
+  let px = min(x, u.outW - 1u);
+  let py = min(y, u.outH - 1u);
+  let uv = (vec2f(f32(px), f32(py)) + 0.5) / vec2f(f32(u.outW), f32(u.outH));
+  let c = textureSampleLevel(inputTex, texSampler, uv, 0.0);
+  return clamp(mix(bg, c.rgb, c.a), vec3f(0.0), vec3f(1.0));
+}
+
+fn lumaOf(rgb: vec3f) -> f32 {
+  return KR * rgb.r + (1.0 - KR - KB) * rgb.g + KB * rgb.b;
+}
+
+fn lumaCode(rgb: vec3f) -> u32 {
+  return u32(clamp(round(64.0 + 876.0 * lumaOf(rgb)), 4.0, 1019.0));
+}
+
+fn chromaCode(value: f32) -> u32 {
+  return u32(clamp(round(512.0 + 896.0 * value), 4.0, 1019.0));
+}
+
+@compute @workgroup_size(8, 8)
+fn encodeMain(@builtin(global_invocation_id) id: vec3u) {
+  let blockX = id.x;
+  let blockY = id.y;
+  if (blockX * 4u >= u.lumaStride || blockY * 2u >= u.lumaRows) { return; }
+
+  let bg = color_convert_working(u.background.rgb, 0u, u.workingColorSpace);
+  var chromaSum = array<vec3f, 2>(vec3f(0.0), vec3f(0.0));
+
+  for (var dy = 0u; dy < 2u; dy++) {
+    let y = blockY * 2u + dy;
+    var codes = array<u32, 4>(0u, 0u, 0u, 0u);
+    for (var dx = 0u; dx < 4u; dx++) {
+      let rgb = samplePixel(blockX * 4u + dx, y, bg);
+      codes[dx] = lumaCode(rgb);
+      chromaSum[dx / 2u] += rgb;
+    }
+    let row = (y * u.lumaStride + blockX * 4u) / 2u;
+    out[row] = codes[0] | (codes[1] << 16u);
+    out[row + 1u] = codes[2] | (codes[3] << 16u);
+  }
+
+  var cb = array<u32, 2>(0u, 0u);
+  var cr = array<u32, 2>(0u, 0u);
+  for (var i = 0u; i < 2u; i++) {
+    let rgb = chromaSum[i] * 0.25;
+    let y = lumaOf(rgb);
+    cb[i] = chromaCode((rgb.b - y) / (2.0 * (1.0 - KB)));
+    cr[i] = chromaCode((rgb.r - y) / (2.0 * (1.0 - KR)));
+  }
+  let chromaWord = blockY * (u.lumaStride / 4u) + blockX;
+  out[u.uOffset + chromaWord] = cb[0] | (cb[1] << 16u);
+  out[u.vOffset + chromaWord] = cr[0] | (cr[1] << 16u);
+}
+`
+
+export interface EncodedHdrFrame {
+  data: Uint16Array
+  layout: I420P10Layout
+  width: number
+  height: number
+}
+
+export class HdrFrameEncoder {
+  private device: GPUDevice
+  private sampler: GPUSampler
+  private pipeline: GPUComputePipeline | null = null
+  private layout: GPUBindGroupLayout | null = null
+  private uniformBuffer: GPUBuffer
+  private storageBuffer: GPUBuffer | null = null
+  private readbackBuffer: GPUBuffer | null = null
+  private bufferSize = 0
+
+  constructor(device: GPUDevice) {
+    this.device = device
+    this.sampler = device.createSampler({ magFilter: 'linear', minFilter: 'linear' })
+    this.uniformBuffer = device.createBuffer({
+      size: 48,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+    })
+    try {
+      const module = device.createShaderModule({ label: 'hdr-encode', code: ENCODE_SHADER })
+      this.layout = device.createBindGroupLayout({
+        label: 'hdr-encode-layout',
+        entries: [
+          { binding: 0, visibility: GPUShaderStage.COMPUTE, sampler: {} },
+          { binding: 1, visibility: GPUShaderStage.COMPUTE, texture: {} },
+          { binding: 2, visibility: GPUShaderStage.COMPUTE, buffer: { type: 'uniform' } },
+          { binding: 3, visibility: GPUShaderStage.COMPUTE, buffer: { type: 'storage' } },
+        ],
+      })
+      this.pipeline = device.createComputePipeline({
+        label: 'hdr-encode-pipeline',
+        layout: device.createPipelineLayout({ bindGroupLayouts: [this.layout] }),
+        compute: { module, entryPoint: 'encodeMain' },
+      })
+    } catch (e) {
+      logger.warn('Failed to create HDR encode pipeline', e)
+    }
+  }
+
+  private ensureBuffers(byteLength: number): void {
+    if (this.storageBuffer && this.bufferSize === byteLength) return
+    this.storageBuffer?.destroy()
+    this.readbackBuffer?.destroy()
+    this.storageBuffer = this.device.createBuffer({
+      size: byteLength,
+      usage: GPUBufferUsage.STORAGE | GPUBufferUsage.COPY_SRC,
+    })
+    this.readbackBuffer = this.device.createBuffer({
+      size: byteLength,
+      usage: GPUBufferUsage.MAP_READ | GPUBufferUsage.COPY_DST,
+    })
+    this.bufferSize = byteLength
+  }
+
+  /**
+   * Encode a working-space composite to I420P10 at `width`×`height`.
+   * `background` is an sRGB Rec.709 colour (0–1) drawn under transparent areas.
+   */
+  async encode(
+    source: GPUTextureView,
+    width: number,
+    height: number,
+    workingColorSpace: ProjectColorSpace,
+    background: [number, number, number] = [0, 0, 0],
+  ): Promise<EncodedHdrFrame | null> {
+    if (!this.pipeline || !this.layout) return null
+
+    const layout = getI420P10Layout(width, height)
+    this.ensureBuffers(layout.byteLength)
+    const uniforms = new ArrayBuffer(48)
+    new Float32Array(uniforms, 0, 4).set([...background, 1])
+    new Uint32Array(uniforms, 16, 8).set([
+      width,
+      height,
+      layout.lumaStride,
+      layout.lumaRows,
+      layout.planes[1]!.offset / 4,
+      layout.planes[2]!.offset / 4,
+      COLOR_SPACE_INDEX[workingColorSpace],
+      0,
+    ])
+    this.device.queue.writeBuffer(this.uniformBuffer, 0, uniforms)
+
+    const encoder = this.device.createCommandEncoder()
+    const pass = encoder.beginComputePass()
+    pass.setPipeline(this.pipeline)
+    pass.setBindGroup(
+      0,
+      this.device.createBindGroup({
+        layout: this.layout,
+        entries: [
+          { binding: 0, resource: this.sampler },
+          { binding: 1, resource: source },
+          { binding: 2, resource: { buffer: this.uniformBuffer } },
+          { binding: 3, resource: { buffer: this.storageBuffer! } },
+        ],
+      }),
+    )
+    pass.dispatchWorkgroups(
+      Math.ceil(layout.lumaStride / 4 / 8),
+      Math.ceil(layout.lumaRows / 2 / 8),
+    )
+    pass.end()
+    encoder.copyBufferToBuffer(this.storageBuffer!, 0, this.readbackBuffer!, 0, layout.byteLength)
+    this.device.queue.submit([encoder.finish()])
+
+    await this.readbackBuffer!.mapAsync(GPUMapMode.READ)
+    const data = new Uint16Array(this.readbackBuffer!.getMappedRange().slice(0))
+    this.readbackBuffer!.unmap()
+    return { data, layout, width, height }
+  }
+
+  destroy(): void {
+    this.storageBuffer?.destroy()
+    this.readbackBuffer?.destroy()
+    this.uniformBuffer.destroy()
+    this.storageBuffer = null
+    this.readbackBuffer = null
+    this.pipeline = null
+  }
+}
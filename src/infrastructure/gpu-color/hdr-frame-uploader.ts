@@ -0,0 +1,510 @@
+/**
+ * HDR Frame Uploader
+ *
+ * Uploads decoded YUV VideoFrames (8/10/12-bit planar, NV12) straight from
+ * their planes instead of going through an 8-bit canvas, so HDR and wide
+ * gamut sources keep their range. Two passes:
+ *
+ * 1. Convert — YUV → RGB with the frame's matrix and range, then source
+ *    transfer/primaries → working colour space, into an rgba16float texture
+ *    at the frame's visible size.
+ * 2. Draw — place that texture into a layer texture at a destination rect,
+ *    with optional scissor clip and opacity.
+ */
+
+import type { ProjectColorSpace, SourceColorInfo } from '@/types/color'
+import { createLogger } from '@/shared/logging/logger'
+import { FULLSCREEN_QUAD_WGSL } from '@/infrastructure/gpu-shared/fullscreen-quad'
+import { resolveSourceColorInfo } from './color-math'
+import {
+  COLOR_MANAGEMENT_WGSL,
+  COLOR_PRIMARIES_INDEX,
+  COLOR_SPACE_INDEX,
+  COLOR_TRANSFER_INDEX,
+} from './color-wgsl'
+
+const logger = createLogger('HdrFrameUploader')
+
+interface PlaneFormat {
+  bitDepth: number
+  /** log2 chroma subsampling in x / y */
+  chromaShiftX: number
+  chromaShiftY: number
+  /** U and V share one interleaved plane (NV12) */
+  interleaved: boolean
+}
+
+const PLANE_FORMATS: Partial<Record<VideoPixelFormat, PlaneFormat>> = {
+  I420: { bitDepth: 8, chromaShiftX: 1, chromaShiftY: 1, interleaved: false },
+  I420P10: { bitDepth: 10, chromaShiftX: 1, chromaShiftY: 1, interleaved: false },
+  I420P12: { bitDepth: 12, chromaShiftX: 1, chromaShiftY: 1, interleaved: false },
+  I422P10: { bitDepth: 10, chromaShiftX: 1, chromaShiftY: 0, interleaved: false },
+  I422P12: { bitDepth: 12, chromaShiftX: 1, chromaShiftY: 0, interleaved: false },
+  I444P10: { bitDepth: 10, chromaShiftX: 0, chromaShiftY: 0, interleaved: false },
+  I444P12: { bitDepth: 12, chromaShiftX: 0, chromaShiftY: 0, interleaved: false },
+  NV12: { bitDepth: 8, chromaShiftX: 1, chromaShiftY: 1, interleaved: true },
+}
+
+const MATRIX_INDEX: Record<SourceColorInfo['matrix'], number> = {
+  bt709: 0,
+  bt601: 1,
+  'bt2020-ncl': 2,
+}
+
+export function isHdrUploadableFormat(format: VideoPixelFormat | null): boolean {
+  return format !== null && PLANE_FORMATS[format] !== undefined
+}
+
+const CONVERT_SHADER = /* wgsl */ `
+${FULLSCREEN_QUAD_WGSL}
+${COLOR_MANAGEMENT_WGSL}
+
+struct ConvertUniforms {
+  chromaShiftX: u32,
+  chromaShiftY: u32,
+  interleaved: u32,
+  fullRange: u32,
+  matrix: u32,
+  transfer: u32,
+  primaries: u32,
+  workingColorSpace: u32,
+  // 2^(bitDepth - 8)
+  depthScale: f32,
+  _pad0: f32,
+  _pad1: f32,
+  _pad2: f32,
+};
+
+@group(0) @binding(0) var yTex: texture_2d<u32>;
+@group(0) @binding(1) var uTex: texture_2d<u32>;
+@group(0) @binding(2) var vTex: texture_2d<u32>;
+@group(0) @binding(3) var<uniform> u: ConvertUniforms;
+
+@fragment
+fn convertFragment(input: VertexOutput) -> @location(0) vec4f {
+  let p = vec2u(floor(input.position.xy));
+  let cp = vec2u(p.x >> u.chromaShiftX, p.y >> u.chromaShiftY);
+  let yCode = f32(textureLoad(yTex, p, 0).r);
+  var uCode: f32;
+  var vCode: f32;
+  if (u.interleaved != 0u) {
+    let uv = textureLoad(uTex, cp, 0);
+    uCode = f32(uv.r);
+    vCode = f32(uv.g);
+  } else {
+    uCode = f32(textureLoad(uTex, cp, 0).r);
+    vCode = f32(textureLoad(vTex, cp, 0).r);
+  }
+
+  let maxCode = 255.0 * u.depthScale + (u.depthScale - 1.0);
+  let center = 128.0 * u.depthScale;
+  var yN: f32;
+  var cb: f32;
+  var cr: f32;
+  if (u.fullRange != 0u) {
+    yN = yCode / maxCode;
+    cb = (uCode - center) / maxCode;
+    cr = (vCode - center) / maxCode;
+  } else {
+    yN = (yCode - 16.0 * u.depthScale) / (219.0 * u.depthScale);
+    cb = (uCode - center) / (224.0 * u.depthScale);
+    cr = (vCode - center) / (224.0 * u.depthScale);
+  }
+
+  var kr = 0.2126;
+  var kb = 0.0722;
+  if (u.matrix == 1u) {
+    kr = 0.299;
+    kb = 0.114;
+  } else if (u.matrix == 2u) {
+    kr = 0.2627;
+    kb = 0.0593;
+  }
+  let r = yN + 2.0 * (1.0 - kr) * cr;
+  let b = yN + 2.0 * (1.0 - kb) * cb;
+  let g = (yN - kr * r - kb * b) / (1.0 - kr - kb);
+  let rgb = clamp(vec3f(r, g, b), vec3f(0.0), vec3f(1.0));
+
+  let nits = color_source_to_nits(rgb, u.transfer, u.primaries);
+  return vec4f(color_nits_to_working(nits, u.workingColorSpace), 1.0);
+}
+`
+
+const DRAW_SHADER = /* wgsl */ `
+struct DrawUniforms {
+  // Destination rect in target pixels
+  rect: vec4f,
+  targetSize: vec2f,
+  opacity: f32,
+  _pad: f32,
+};
+
+struct VertexOutput {
+  @builtin(position) position: vec4f,
+  @location(0) uv: vec2f,
+};
+
+@group(0) @binding(0) var texSampler: sampler;
+@group(0) @binding(1) var sourceTex: texture_2d<f32>;
+@group(0) @binding(2) var<uniform> u: DrawUniforms;
+
+@vertex
+fn vertexMain(@builtin(vertex_index) vertexIndex: u32) -> VertexOutput {
+  var corners = array<vec2f, 6>(
+    vec2f(0.0, 0.0),
+    vec2f(1.0, 0.0),
+    vec2f(0.0, 1.0),
+    vec2f(0.0, 1.0),
+    vec2f(1.0, 0.0),
+    vec2f(1.0, 1.0)
+  );
+  let corner = corners[vertexIndex];
+  let pixel = u.rect.xy + corner * u.rect.zw;
+  let ndc = vec2f(pixel.x / u.targetSize.x * 2.0 - 1.0, 1.0 - pixel.y / u.targetSize.y * 2.0);
+  var output: VertexOutput;
+  output.position = vec4f(ndc, 0.0, 1.0);
+  output.uv = corner;
+  return output;
+}
+
+@fragment
+fn drawFragment(input: VertexOutput) -> @location(0) vec4f {
+  let c = textureSample(sourceTex, texSampler, input.uv);
+  return vec4f(c.rgb, c.a * u.opacity);
+}
+`
+
+export interface HdrUploadedFrame {
+  view: GPUTextureView
+  width: number
+  height: number
+  colorInfo: SourceColorInfo
+}
+
+export interface HdrFrameDrawOptions {
+  source: HdrUploadedFrame
+  encoder: GPUCommandEncoder
+  target: GPUTextureView
+  targetWidth: number
+  targetHeight: number
+  /** Destination rect in target pixels */
+  rect: { x: number; y: number; width: number; height: number }
+  /** Scissor rect in target pixels; drawing outside it is discarded */
+  clip?: { x: number; y: number; width: number; height: number }
+  opacity?: number
+  /** Clear the target before drawing (first draw into a fresh layer) */
+  clear?: boolean
+}
+
+interface PlaneTextures {
+  key: string
+  y: GPUTexture
+  u: GPUTexture
+  v: GPUTexture | null
+  output: GPUTexture
+  outputView: GPUTextureView
+}
+
+export class HdrFrameUploader {
+  private device: GPUDevice
+  private sampler: GPUSampler
+  private convertPipeline: GPURenderPipeline | null = null
+  private convertLayout: GPUBindGroupLayout | null = null
+  private drawPipeline: GPURenderPipeline | null = null
+  private drawLayout: GPUBindGroupLayout | null = null
+  private convertUniformBuffer: GPUBuffer
+  private drawUniformBuffers: GPUBuffer[] = []
+  private drawCount = 0
+  private planes: PlaneTextures[] = []
+  private planeCursor = 0
+
+  constructor(device: GPUDevice) {
+    this.device = device
+    this.sampler = device.createSampler({ magFilter: 'linear', minFilter: 'linear' })
+    this.convertUniformBuffer = device.createBuffer({
+      size: 48,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+    })
+    this.createPipelines()
+  }
+
+  private createPipelines(): void {
+    try {
+      const module = this.device.createShaderModule({
+        label: 'hdr-convert',
+        code: CONVERT_SHADER,
+      })
+      this.convertLayout = this.device.createBindGroupLayout({
+        label: 'hdr-convert-layout',
+        entries: [
+          { binding: 0, visibility: GPUShaderStage.FRAGMENT, texture: { sampleType: 'uint' } },
+          { binding: 1, visibility: GPUShaderStage.FRAGMENT, texture: { sampleType: 'uint' } },
+          { binding: 2, visibility: GPUShaderStage.FRAGMENT, texture: { sampleType: 'uint' } },
+          { binding: 3, visibility: GPUShaderStage.FRAGMENT, buffer: { type: 'uniform' } },
+        ],
+      })
+      this.convertPipeline = this.device.createRenderPipeline({
+        label: 'hdr-convert-pipeline',
+        layout: this.device.createPipelineLayout({ bindGroupLayouts: [this.convertLayout] }),
+        vertex: { module, entryPoint: 'vertexMain' },
+        fragment: { module, entryPoint: 'convertFragment', targets: [{ format: 'rgba16float' }] },
+        primitive: { topology: 'triangle-list' },
+      })
+
+      const drawModule = this.device.createShaderModule({
+        label: 'hdr-draw',
+        code: DRAW_SHADER,
+      })
+      this.drawLayout = this.device.createBindGroupLayout({
+        label: 'hdr-draw-layout',
+        entries: [
+          { binding: 0, visibility: GPUShaderStage.FRAGMENT, sampler: {} },
+          { binding: 1, visibility: GPUShaderStage.FRAGMENT, texture: {} },
+          {
+            binding: 2,
+            visibility: GPUShaderStage.VERTEX | GPUShaderStage.FRAGMENT,
+            buffer: { type: 'uniform' },
+          },
+        ],
+      })
+      this.drawPipeline = this.device.createRenderPipeline({
+        label: 'hdr-draw-pipeline',
+        layout: this.device.createPipelineLayout({ bindGroupLayouts: [this.drawLayout] }),
+        vertex: { module: drawModule, entryPoint: 'vertexMain' },
+        fragment: {
+          module: drawModule,
+          entryPoint: 'drawFragment',
+          targets: [
+            {
+              format: 'rgba16float',
+              blend: {
+                color: { srcFactor: 'src-alpha', dstFactor: 'one-minus-src-alpha' },
+                alpha: { srcFactor: 'one', dstFactor: 'one-minus-src-alpha' },
+              },
+            },
+          ],
+        },
+        primitive: { topology: 'triangle-list' },
+      })
+    } catch (e) {
+      logger.warn('Failed to create HDR upload pipelines', e)
+      this.convertPipeline = null
+      this.drawPipeline = null
+    }
+  }
+
+  /** Call once per rendered frame so per-frame plane textures can be reused. */
+  beginFrame(): void {
+    this.planeCursor = 0
+    this.drawCount = 0
+  }
+
+  private getPlaneTextures(
+    width: number,
+    height: number,
+    format: VideoPixelFormat,
+    planeFormat: PlaneFormat,
+  ): PlaneTextures {
+    const key = `${format}:${width}x${height}`
+    const existing = this.planes[this.planeCursor]
+    if (existing?.key === key) {
+      this.planeCursor++
+      return existing
+    }
+    if (existing) this.destroyPlaneTextures(existing)
+
+    const usage = GPUTextureUsage.TEXTURE_BINDING | GPUTextureUsage.COPY_DST
+    const lumaFormat: GPUTextureFormat = planeFormat.bitDepth > 8 ? 'r16uint' : 'r8uint'
+    const chromaWidth = Math.ceil(width / (1 << planeFormat.chromaShiftX))
+    const chromaHeight = Math.ceil(height / (1 << planeFormat.chromaShiftY))
+    const chromaFormat: GPUTextureFormat = planeFormat.interleaved ? 'rg8uint' : lumaFormat
+    const createChroma = () =>
+      this.device.createTexture({
+        size: { width: chromaWidth, height: chromaHeight },
+        format: chromaFormat,
+        usage,
+      })
+    const output = this.device.createTexture({
+      size: { width, height },
+      format: 'rgba16float',
+      usage: GPUTextureUsage.TEXTURE_BINDING | GPUTextureUsage.RENDER_ATTACHMENT,
+    })
+
+    const planes: PlaneTextures = {
+      key,
+      y: this.device.createTexture({ size: { width, height }, format: lumaFormat, usage }),
+      u: createChroma(),
+      v: planeFormat.interleaved ? null : createChroma(),
+      output,
+      outputView: output.createView(),
+    }
+    this.planes[this.planeCursor++] = planes
+    return planes
+  }
+
+  /**
+   * Upload a frame's planes and convert them to the working colour space.
+   * Returns null for pixel formats that can't be read plane-by-plane (the
+   * caller should fall back to the canvas path).
+   */
+  async upload(
+    frame: VideoFrame,
+    workingColorSpace: ProjectColorSpace,
+  ): Promise<HdrUploadedFrame | null> {
+    const format = frame.format
+    const planeFormat = format ? PLANE_FORMATS[format] : undefined
+    if (!format || !planeFormat || !this.convertPipeline || !this.convertLayout) return null
+
+    const width = frame.visibleRect?.width ?? frame.displayWidth
+    const height = frame.visibleRect?.height ?? frame.displayHeight
+    const data = new Uint8Array(frame.allocationSize())
+    const layouts = await frame.copyTo(data)
+    const planes = this.getPlaneTextures(width, height, format, planeFormat)
+    const chromaWidth = Math.ceil(width / (1 << planeFormat.chromaShiftX))
+    const chromaHeight = Math.ceil(height / (1 << planeFormat.chromaShiftY))
+
+    const writePlane = (
+      texture: GPUTexture,
+      layout: PlaneLayout | undefined,
+      w: number,
+      h: number,
+    ) => {
+      if (!layout) return
+      this.device.queue.writeTexture(
+        { texture },
+        data,
+        { offset: layout.offset, bytesPerRow: layout.stride, rowsPerImage: h },
+        { width: w, height: h },
+      )
+    }
+    writePlane(planes.y, layouts[0], width, height)
+    writePlane(planes.u, layouts[1], chromaWidth, chromaHeight)
+    if (planes.v) writePlane(planes.v, layouts[2], chromaWidth, chromaHeight)
+
+    const colorInfo = resolveSourceColorInfo(frame)
+    const uniforms = new ArrayBuffer(48)
+    const u32 = new Uint32Array(uniforms, 0, 8)
+    u32[0] = planeFormat.chromaShiftX
+    u32[1] = planeFormat.chromaShiftY
+    u32[2] = planeFormat.interleaved ? 1 : 0
+    u32[3] = colorInfo.fullRange ? 1 : 0
+    u32[4] = MATRIX_INDEX[colorInfo.matrix]
+    u32[5] = COLOR_TRANSFER_INDEX[colorInfo.transfer]
+    u32[6] = COLOR_PRIMARIES_INDEX[colorInfo.primaries]
+    u32[7] = COLOR_SPACE_INDEX[workingColorSpace]
+    new Float32Array(uniforms, 32, 1)[0] = 1 << (planeFormat.bitDepth - 8)
+    this.device.queue.writeBuffer(this.convertUniformBuffer, 0, uniforms)
+
+    const uView = planes.u.createView()
+    const bindGroup = this.device.createBindGroup({
+      layout: this.convertLayout,
+      entries: [
+        { binding: 0, resource: planes.y.createView() },
+        { binding: 1, resource: uView },
+        { binding: 2, resource: planes.v?.createView() ?? uView },
+        { binding: 3, resource: { buffer: this.convertUniformBuffer } },
+      ],
+    })
+    // Submitted on its own: the convert uniform buffer is shared, so this
+    // pass must run before the next upload rewrites it.
+    const encoder = this.device.createCommandEncoder()
+    const pass = encoder.beginRenderPass({
+      colorAttachments: [{ view: planes.outputView, loadOp: 'clear', storeOp: 'store' }],
+    })
+    pass.setPipeline(this.convertPipeline)
+    pass.setBindGroup(0, bindGroup)
+    pass.draw(6)
+    pass.end()
+    this.device.queue.submit([encoder.finish()])
+
+    return { view: planes.outputView, width, height, colorInfo }
+  }
+
+  /** Draw an uploaded frame into a rgba16float layer texture. */
+  draw({
+    source,
+    encoder,
+    target,
+    targetWidth,
+    targetHeight,
+    rect,
+    clip,
+    opacity = 1,
+    clear = false,
+  }: HdrFrameDrawOptions): void {
+    if (!this.drawPipeline || !this.drawLayout) return
+
+    const buffer = (this.drawUniformBuffers[this.drawCount] ??= this.device.createBuffer({
+      size: 32,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+    }))
+    this.drawCount++
+    this.device.queue.writeBuffer(
+      buffer,
+      0,
+      new Float32Array([
+        rect.x,
+        rect.y,
+        rect.width,
+        rect.height,
+        targetWidth,
+        targetHeight,
+        opacity,
+        0,
+      ]),
+    )
+
+    const pass = encoder.beginRenderPass({
+      colorAttachments: [
+        {
+          view: target,
+          loadOp: clear ? 'clear' : 'load',
+          clearValue: { r: 0, g: 0, b: 0, a: 0 },
+          storeOp: 'store',
+        },
+      ],
+    })
+    if (clip) {
+      const x = Math.max(0, Math.floor(clip.x))
+      const y = Math.max(0, Math.floor(clip.y))
+      const right = Math.min(targetWidth, Math.ceil(clip.x + clip.width))
+      const bottom = Math.min(targetHeight, Math.ceil(clip.y + clip.height))
+      if (right <= x || bottom <= y) {
+        pass.end()
+        return
+      }
+      pass.setScissorRect(x, y, right - x, bottom - y)
+    }
+    pass.setPipeline(this.drawPipeline)
+    pass.setBindGroup(
+      0,
+      this.device.createBindGroup({
+        layout: this.drawLayout,
+        entries: [
+          { binding: 0, resource: this.sampler },
+          { binding: 1, resource: source.view },
+          { binding: 2, resource: { buffer } },
+        ],
+      }),
+    )
+    pass.draw(6)
+    pass.end()
+  }
+
+  private destroyPlaneTextures(planes: PlaneTextures): void {
+    planes.y.destroy()
+    planes.u.destroy()
+    planes.v?.destroy()
+    planes.output.destroy()
+  }
+
+  destroy(): void {
+    for (const planes of this.planes) this.destroyPlaneTextures(planes)
+    for (const buffer of this.drawUniformBuffers) buffer.destroy()
+    this.convertUniformBuffer.destroy()
+    this.planes = []
+    this.drawUniformBuffers = []
+    this.convertPipeline = null
+    this.drawPipeline = null
+  }
+}
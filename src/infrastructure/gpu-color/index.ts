@@ -0,0 +1,22 @@
+export {
+  DEFAULT_HDR_PEAK_NITS,
+  HLG_NOMINAL_PEAK_NITS,
+  SDR_REFERENCE_WHITE_NITS,
+  convertWorkingColor,
+  isHdrSourceColorInfo,
+  nitsToSignal,
+  parseHexRgb,
+  resolveSourceColorInfo,
+  toneMapToSdr,
+} from './color-math'
+export type { Rgb } from './color-math'
+export { COLOR_MANAGEMENT_WGSL, COLOR_SPACE_INDEX } from './color-wgsl'
+export { HdrFrameUploader, isHdrUploadableFormat } from './hdr-frame-uploader'
+export type { HdrFrameDrawOptions, HdrUploadedFrame } from './hdr-frame-uploader'
+export {
+  HdrFrameEncoder,
+  getHdrCodecString,
+  getHdrVideoColorSpace,
+  getI420P10Layout,
+} from './hdr-frame-encoder'
+export type { EncodedHdrFrame, HdrVideoCodec, I420P10Layout } from './hdr-frame-encoder'
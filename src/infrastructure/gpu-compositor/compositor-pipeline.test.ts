@@ -71,6 +71,11 @@ function readBlendMode(writeCall: unknown[]): number {
   return new Uint32Array(buffer, 4, 1)[0] ?? 0
 }
 
+function readColorSpaces(writeCall: unknown[]): number[] {
+  const buffer = writeCall[2] as ArrayBuffer
+  return Array.from(new Uint32Array(buffer, 64, 2))
+}
+
 describe('CompositorPipeline', () => {
   it('keeps mask coverage separate from dissolve opacity coverage in shader code', () => {
     const { device } = createPipelineHarness()
@@ -175,4 +180,48 @@ describe('CompositorPipeline', () => {
       expect(readHasMask(queue.writeBuffer.mock.calls[1]!)).toBe(0)
     },
   )
+
+  it('composites HDR working spaces in half-float with per-layer source conversion', () => {
+    const { commandEncoder, device, pipeline, queue } = createPipelineHarness()
+    pipeline.setWorkingColorSpace('rec2100-pq')
+
+    const layers: CompositeLayer[] = [
+      {
+        params: DEFAULT_LAYER_PARAMS,
+        textureView: 'hdr-video-view' as unknown as GPUTextureView,
+        maskView: 'fallback-mask-view' as unknown as GPUTextureView,
+        colorSpace: 'rec2100-pq',
+      },
+      {
+        params: DEFAULT_LAYER_PARAMS,
+        textureView: 'title-view' as unknown as GPUTextureView,
+        maskView: 'fallback-mask-view' as unknown as GPUTextureView,
+      },
+    ]
+    pipeline.compositeToTexture(layers, 640, 360, commandEncoder as unknown as GPUCommandEncoder)
+
+    const textureFormats = (
+      device.createTexture.mock.calls as unknown as Array<[GPUTextureDescriptor]>
+    ).map(([descriptor]) => descriptor.format)
+    expect(textureFormats).toEqual(['rgba16float', 'rgba16float'])
+
+    const pipelineFormats = (
+      device.createRenderPipeline.mock.calls as unknown as Array<[GPURenderPipelineDescriptor]>
+    ).map(([descriptor]) => descriptor.fragment?.targets[0]?.format)
+    expect(pipelineFormats.slice(-3, -1)).toEqual(['rgba16float', 'rgba16float'])
+
+    // PQ source stays PQ; the SDR title is converted from rec709 (0) into PQ (1).
+    expect(readColorSpaces(queue.writeBuffer.mock.calls[0]!)).toEqual([1, 1])
+    expect(readColorSpaces(queue.writeBuffer.mock.calls[1]!)).toEqual([0, 1])
+  })
+
+  it('keeps SDR projects on 8-bit textures without rebuilding pipelines', () => {
+    const { device, pipeline } = createPipelineHarness()
+    const pipelineCount = device.createRenderPipeline.mock.calls.length
+
+    pipeline.setWorkingColorSpace('rec709')
+
+    expect(device.createRenderPipeline.mock.calls.length).toBe(pipelineCount)
+    expect(pipeline.getWorkingColorSpace()).toBe('rec709')
+  })
 })
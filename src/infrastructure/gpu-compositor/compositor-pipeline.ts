@@ -9,12 +9,20 @@
  * 1. Regular — for GPUTexture inputs (images, pre-rendered canvases)
  * 2. External — for GPUExternalTexture inputs (zero-copy video)
  * 3. Blit — copies the final composite to a canvas, converting straight → premultiplied alpha
+ *
+ * Layers are composited in the project working colour space. Each layer is
+ * converted from its own colour space on sampling; HDR working spaces use
+ * rgba16float ping-pong textures and are tone mapped by the blit for display.
  */
 
 import type { BlendMode } from '@/types/blend-modes'
 import { BLEND_MODE_INDEX } from '@/types/blend-modes'
+import type { ProjectColorSpace } from '@/types/color'
+import { DEFAULT_PROJECT_COLOR_SPACE, isHdrColorSpace } from '@/types/color'
 import { createLogger } from '@/shared/logging/logger'
 import { BLEND_MODES_WGSL } from '@/infrastructure/gpu-shared/blend-modes'
+import { DEFAULT_HDR_PEAK_NITS } from '@/infrastructure/gpu-color/color-math'
+import { COLOR_MANAGEMENT_WGSL, COLOR_SPACE_INDEX } from '@/infrastructure/gpu-color/color-wgsl'
 import { drawFullscreenCanvasPass } from '@/infrastructure/gpu-shared/fullscreen-canvas-pass'
 import { FULLSCREEN_QUAD_WGSL } from '@/infrastructure/gpu-shared/fullscreen-quad'
 
@@ -25,14 +33,29 @@ const logger = createLogger('CompositorPipeline')
 const BLIT_SHADER = /* wgsl */ `
 ${FULLSCREEN_QUAD_WGSL}
 
+${COLOR_MANAGEMENT_WGSL}
+
+struct BlitUniforms {
+  workingColorSpace: u32,
+  peakNits: f32,
+  // 0 = display (tone map HDR to SDR), 1 = raw working-space signal
+  mode: u32,
+  _pad: u32,
+};
+
 @group(0) @binding(0) var texSampler: sampler;
 @group(0) @binding(1) var inputTex: texture_2d<f32>;
+@group(0) @binding(2) var<uniform> blit: BlitUniforms;
 
 @fragment
 fn blitFragment(input: VertexOutput) -> @location(0) vec4f {
   // Composite textures store straight alpha; canvas expects premultiplied
   let c = textureSample(inputTex, texSampler, input.uv);
-  return vec4f(c.rgb * c.a, c.a);
+  var rgb = clamp(c.rgb, vec3f(0.0), vec3f(1.0));
+  if (blit.workingColorSpace != 0u && blit.mode == 0u) {
+    rgb = color_tone_map_to_sdr(color_working_to_nits(rgb, blit.workingColorSpace), blit.peakNits);
+  }
+  return vec4f(rgb * c.a, c.a);
 }
 `
 
@@ -56,11 +79,16 @@ struct CompositeUniforms {
   rotationY: f32,          // 13
   perspective: f32,        // 14
   maskFeather: f32,        // 15
+  sourceColorSpace: u32,   // 16
+  workingColorSpace: u32,  // 17
+  _pad0: u32,              // 18
+  _pad1: u32,              // 19
 };
 `
 
 const COMPOSITE_FRAGMENT = /* wgsl */ `
 ${BLEND_MODES_WGSL}
+${COLOR_MANAGEMENT_WGSL}
 ${COMPOSITE_UNIFORMS}
 
 @group(0) @binding(0) var texSampler: sampler;
@@ -119,6 +147,10 @@ fn compositeFragment(input: VertexOutput) -> @location(0) vec4f {
   let inBounds = layerUV.x >= 0.0 && layerUV.x <= 1.0 && layerUV.y >= 0.0 && layerUV.y <= 1.0;
   let sampleUV = clamp(layerUV, vec2f(0.0), vec2f(1.0));
   var layerColor = textureSampleLevel(layerTex, texSampler, sampleUV, 0.0);
+  layerColor = vec4f(
+    color_convert_working(layerColor.rgb, u.sourceColorSpace, u.workingColorSpace),
+    layerColor.a
+  );
 
   // Apply mask
   var maskValue = 1.0;
@@ -149,6 +181,7 @@ fn compositeFragment(input: VertexOutput) -> @location(0) vec4f {
 
 const COMPOSITE_EXTERNAL_FRAGMENT = /* wgsl */ `
 ${BLEND_MODES_WGSL}
+${COLOR_MANAGEMENT_WGSL}
 ${COMPOSITE_UNIFORMS}
 
 @group(0) @binding(0) var texSampler: sampler;
@@ -188,6 +221,10 @@ fn compositeExternalFragment(input: VertexOutput) -> @location(0) vec4f {
   let inBounds = layerUV.x >= 0.0 && layerUV.x <= 1.0 && layerUV.y >= 0.0 && layerUV.y <= 1.0;
   let sampleUV = clamp(layerUV, vec2f(0.0), vec2f(1.0));
   var layerColor = textureSampleBaseClampToEdge(layerTex, texSampler, sampleUV);
+  layerColor = vec4f(
+    color_convert_working(layerColor.rgb, u.sourceColorSpace, u.workingColorSpace),
+    layerColor.a
+  );
   var maskValue = 1.0;
   if (u.hasMask != 0u) {
     maskValue = textureSampleLevel(maskTex, texSampler, input.uv, 0.0).a;
@@ -210,7 +247,10 @@ fn compositeExternalFragment(input: VertexOutput) -> @location(0) vec4f {
 
 // ─── Uniform packing ───
 
-const UNIFORM_SIZE = 64 // 16 floats × 4 bytes
+const UNIFORM_SIZE = 80 // 20 × 4 bytes
+const BLIT_UNIFORM_SIZE = 16
+
+type BlitMode = 'display' | 'signal'
 
 export interface CompositeLayerParams {
   opacity: number
@@ -250,8 +290,12 @@ export const DEFAULT_LAYER_PARAMS: CompositeLayerParams = {
   maskFeather: 0,
 }
 
-function packUniforms(p: CompositeLayerParams): Float32Array {
-  const buf = new Float32Array(16)
+function packUniforms(
+  p: CompositeLayerParams,
+  sourceColorSpace: ProjectColorSpace,
+  workingColorSpace: ProjectColorSpace,
+): Float32Array {
+  const buf = new Float32Array(20)
   buf[0] = p.opacity
   // Store blend mode as u32 in float bits
   new Uint32Array(buf.buffer, 4, 1)[0] = BLEND_MODE_INDEX[p.blendMode] ?? 0
@@ -269,6 +313,17 @@ function packUniforms(p: CompositeLayerParams): Float32Array {
   buf[13] = p.rotationY
   buf[14] = p.perspective
   buf[15] = p.maskFeather
+  const colorSpaces = new Uint32Array(buf.buffer, 64, 2)
+  colorSpaces[0] = COLOR_SPACE_INDEX[sourceColorSpace]
+  colorSpaces[1] = COLOR_SPACE_INDEX[workingColorSpace]
+  return buf
+}
+
+function packBlitUniforms(workingColorSpace: ProjectColorSpace, mode: BlitMode): ArrayBuffer {
+  const buf = new ArrayBuffer(BLIT_UNIFORM_SIZE)
+  new Uint32Array(buf, 0, 1)[0] = COLOR_SPACE_INDEX[workingColorSpace]
+  new Float32Array(buf, 4, 1)[0] = DEFAULT_HDR_PEAK_NITS
+  new Uint32Array(buf, 8, 1)[0] = mode === 'signal' ? 1 : 0
   return buf
 }
 
@@ -296,6 +351,10 @@ export class CompositorPipeline {
   private texH = 0
   private blitBindGroupPing: GPUBindGroup | null = null
   private blitBindGroupPong: GPUBindGroup | null = null
+  private blitUniformBuffers: Record<BlitMode, GPUBuffer | null> = { display: null, signal: null }
+  private lastComposite: { texture: GPUTexture; view: GPUTextureView } | null = null
+
+  private workingColorSpace: ProjectColorSpace = DEFAULT_PROJECT_COLOR_SPACE
 
   constructor(device: GPUDevice) {
     this.device = device
@@ -304,6 +363,33 @@ export class CompositorPipeline {
     this.createPipelines()
   }
 
+  /** Ping-pong format: 8-bit for SDR, half float so HDR keeps its range and precision */
+  private get compositeFormat(): GPUTextureFormat {
+    return isHdrColorSpace(this.workingColorSpace) ? 'rgba16float' : 'rgba8unorm'
+  }
+
+  /**
+   * Set the colour space layers are composited in. Switching between SDR and
+   * HDR rebuilds the composite pipelines and ping-pong textures.
+   */
+  setWorkingColorSpace(space: ProjectColorSpace): void {
+    if (space === this.workingColorSpace) return
+    const previousFormat = this.compositeFormat
+    this.workingColorSpace = space
+    if (this.compositeFormat === previousFormat) return
+
+    this.pingTexture?.destroy()
+    this.pongTexture?.destroy()
+    this.pingTexture = null
+    this.pongTexture = null
+    this.lastComposite = null
+    this.createPipelines()
+  }
+
+  getWorkingColorSpace(): ProjectColorSpace {
+    return this.workingColorSpace
+  }
+
   private createPipelines(): void {
     // Regular composite pipeline (texture_2d layer)
     try {
@@ -325,7 +411,11 @@ export class CompositorPipeline {
         label: 'compositor-regular-pipeline',
         layout: this.device.createPipelineLayout({ bindGroupLayouts: [this.regularLayout] }),
         vertex: { module, entryPoint: 'vertexMain' },
-        fragment: { module, entryPoint: 'compositeFragment', targets: [{ format: 'rgba8unorm' }] },
+        fragment: {
+          module,
+          entryPoint: 'compositeFragment',
+          targets: [{ format: this.compositeFormat }],
+        },
         primitive: { topology: 'triangle-list' },
       })
     } catch (e) {
@@ -355,7 +445,7 @@ export class CompositorPipeline {
         fragment: {
           module,
           entryPoint: 'compositeExternalFragment',
-          targets: [{ format: 'rgba8unorm' }],
+          targets: [{ format: this.compositeFormat }],
         },
         primitive: { topology: 'triangle-list' },
       })
@@ -375,6 +465,7 @@ export class CompositorPipeline {
         entries: [
           { binding: 0, visibility: GPUShaderStage.FRAGMENT, sampler: {} },
           { binding: 1, visibility: GPUShaderStage.FRAGMENT, texture: {} },
+          { binding: 2, visibility: GPUShaderStage.FRAGMENT, buffer: { type: 'uniform' } },
         ],
       })
       this.blitPipeline = this.device.createRenderPipeline({
@@ -395,7 +486,7 @@ export class CompositorPipeline {
     this.pongTexture?.destroy()
     const desc: GPUTextureDescriptor = {
       size: { width: w, height: h },
-      format: 'rgba8unorm',
+      format: this.compositeFormat,
       usage:
         GPUTextureUsage.TEXTURE_BINDING |
         GPUTextureUsage.RENDER_ATTACHMENT |
@@ -424,11 +515,35 @@ export class CompositorPipeline {
     return buffer
   }
 
-  private writeUniforms(buffer: GPUBuffer, params: CompositeLayerParams): void {
-    const data = packUniforms(params)
+  private writeUniforms(buffer: GPUBuffer, layer: CompositeLayer): void {
+    const data = packUniforms(
+      layer.params,
+      layer.colorSpace ?? DEFAULT_PROJECT_COLOR_SPACE,
+      this.workingColorSpace,
+    )
     this.device.queue.writeBuffer(buffer, 0, data.buffer)
   }
 
+  private getBlitUniformBuffer(mode: BlitMode): GPUBuffer {
+    const buffer = (this.blitUniformBuffers[mode] ??= this.device.createBuffer({
+      size: BLIT_UNIFORM_SIZE,
+      usage: GPUBufferUsage.UNIFORM | GPUBufferUsage.COPY_DST,
+    }))
+    this.device.queue.writeBuffer(buffer, 0, packBlitUniforms(this.workingColorSpace, mode))
+    return buffer
+  }
+
+  private createBlitBindGroup(view: GPUTextureView, mode: BlitMode): GPUBindGroup {
+    return this.device.createBindGroup({
+      layout: this.blitLayout!,
+      entries: [
+        { binding: 0, resource: this.sampler },
+        { binding: 1, resource: view },
+        { binding: 2, resource: { buffer: this.getBlitUniformBuffer(mode) } },
+      ],
+    })
+  }
+
   /**
    * Composite a sequence of layers onto a canvas.
    * Returns the result texture (ping or pong) containing the final composite.
@@ -476,7 +591,7 @@ export class CompositorPipeline {
     for (let layerIndex = 0; layerIndex < layers.length; layerIndex++) {
       const layer = layers[layerIndex]!
       const layerUniformBuffer = this.getLayerUniformBuffer(layerIndex)
-      this.writeUniforms(layerUniformBuffer, layer.params)
+      this.writeUniforms(layerUniformBuffer, layer)
 
       let bindGroup: GPUBindGroup
       let pipeline: GPURenderPipeline
@@ -534,7 +649,8 @@ export class CompositorPipeline {
       outputView = tmpView
     }
 
-    return { texture: inputTex, view: inputView }
+    this.lastComposite = { texture: inputTex, view: inputView }
+    return this.lastComposite
   }
 
   compositeToCanvas(
@@ -551,6 +667,7 @@ export class CompositorPipeline {
       return false
     }
 
+    const blitUniformBuffer = this.getBlitUniformBuffer('display')
     const blitBindGroup =
       composited.texture === this.pingTexture
         ? (this.blitBindGroupPing ??= this.device.createBindGroup({
@@ -558,6 +675,7 @@ export class CompositorPipeline {
             entries: [
               { binding: 0, resource: this.sampler },
               { binding: 1, resource: this.pingView! },
+              { binding: 2, resource: { buffer: blitUniformBuffer } },
             ],
           }))
         : (this.blitBindGroupPong ??= this.device.createBindGroup({
@@ -565,6 +683,7 @@ export class CompositorPipeline {
             entries: [
               { binding: 0, resource: this.sampler },
               { binding: 1, resource: this.pongView! },
+              { binding: 2, resource: { buffer: blitUniformBuffer } },
             ],
           }))
 
@@ -578,6 +697,26 @@ export class CompositorPipeline {
     return true
   }
 
+  /** The most recent composite, still in the working colour space. */
+  getLastComposite(): { texture: GPUTexture; view: GPUTextureView } | null {
+    return this.lastComposite
+  }
+
+  /**
+   * Draw the last composite to a canvas without tone mapping, so HDR code
+   * values can be measured (scopes). Returns false when nothing was composited.
+   */
+  drawSignalToCanvas(outputCtx: GPUCanvasContext): boolean {
+    if (!this.blitPipeline || !this.blitLayout || !this.lastComposite) return false
+    drawFullscreenCanvasPass({
+      device: this.device,
+      context: outputCtx,
+      pipeline: this.blitPipeline,
+      bindGroup: this.createBlitBindGroup(this.lastComposite.view, 'signal'),
+    })
+    return true
+  }
+
   getDevice(): GPUDevice {
     return this.device
   }
@@ -586,6 +725,10 @@ export class CompositorPipeline {
     this.pingTexture?.destroy()
     this.pongTexture?.destroy()
     for (const buffer of this.layerUniformBuffers) buffer.destroy()
+    this.blitUniformBuffers.display?.destroy()
+    this.blitUniformBuffers.signal?.destroy()
+    this.blitUniformBuffers = { display: null, signal: null }
+    this.lastComposite = null
     this.pingTexture = null
     this.pongTexture = null
     this.pingView = null
@@ -607,4 +750,6 @@ export interface CompositeLayer {
   externalTexture?: GPUExternalTexture
   /** Mask texture view (use MaskTextureManager.getFallbackView() if no mask) */
   maskView: GPUTextureView
+  /** Colour space the layer's pixels are encoded in (default `rec709`) */
+  colorSpace?: ProjectColorSpace
 }
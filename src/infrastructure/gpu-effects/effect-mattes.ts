@@ -0,0 +1,31 @@
+import type { EffectDataTexturePayload } from './types'
+
+/**
+ * Per-frame mattes for effects that cut out a subject. Render code loads the
+ * matte for the frame being drawn, registers it here, and passes the key in
+ * the effect params; the effect's data texture reads it back by key. Only the
+ * most recent entries are kept — a frame only needs the mattes it just
+ * registered.
+ */
+
+const MAX_EFFECT_MATTES = 32
+
+const mattes = new Map<string, EffectDataTexturePayload>()
+
+export function registerEffectMatte(key: string, payload: EffectDataTexturePayload): void {
+  mattes.delete(key)
+  mattes.set(key, payload)
+  while (mattes.size > MAX_EFFECT_MATTES) {
+    const oldest = mattes.keys().next().value
+    if (oldest === undefined) break
+    mattes.delete(oldest)
+  }
+}
+
+export function getEffectMatte(key: string): EffectDataTexturePayload | undefined {
+  return mattes.get(key)
+}
+
+export function clearEffectMattes(): void {
+  mattes.clear()
+}
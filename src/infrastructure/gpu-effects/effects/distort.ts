@@ -974,3 +974,98 @@ fn flutedGlassFragment(input: VertexOutput) -> @location(0) vec4f {
     ])
   },
 }
+
+/**
+ * Applies the smoothed inverse camera motion measured by the stabilization
+ * analysis. The camera path lives in the `cameraPath` param; per-frame
+ * `frame*` (item media rect, canvas px) and `correction*` values are resolved
+ * at render time from the clip's source time and merged into the params.
+ * Without them the effect passes through.
+ */
+export const stabilize: GpuEffectDefinition = {
+  id: 'gpu-stabilize',
+  name: 'Stabilize',
+  category: 'distort',
+  entryPoint: 'stabilizeFragment',
+  uniformSize: 64,
+  shader: /* wgsl */ `
+struct StabilizeParams {
+  frameCenter: vec2f,
+  frameSize: vec2f,
+  canvasSize: vec2f,
+  frameRotation: f32,
+  zoom: f32,
+  translate: vec2f,
+  rotation: f32,
+  scale: f32,
+  shear: f32,
+  _p1: f32,
+  _p2: f32,
+  _p3: f32,
+};
+@group(0) @binding(0) var texSampler: sampler;
+@group(0) @binding(1) var inputTex: texture_2d<f32>;
+@group(0) @binding(2) var<uniform> params: StabilizeParams;
+fn rotate2(v: vec2f, angle: f32) -> vec2f {
+  let c = cos(angle);
+  let s = sin(angle);
+  return vec2f(v.x * c - v.y * s, v.x * s + v.y * c);
+}
+@fragment
+fn stabilizeFragment(input: VertexOutput) -> @location(0) vec4f {
+  let halfSize = params.frameSize * 0.5;
+  let local = rotate2(input.uv * params.canvasSize - params.frameCenter, -params.frameRotation);
+  var source = rotate2(local / params.zoom - params.translate, -params.rotation) / params.scale;
+  source.x = source.x + params.shear * source.y;
+  let sourceUV = (rotate2(source, params.frameRotation) + params.frameCenter) / params.canvasSize;
+  let color = textureSample(inputTex, texSampler, sourceUV);
+  let inFrame = all(abs(local) <= halfSize) && all(abs(source) <= halfSize);
+  return select(vec4f(0.0), color, inFrame);
+}`,
+  params: {
+    smoothness: {
+      type: 'number',
+      label: 'Smoothness',
+      default: 50,
+      min: 0,
+      max: 100,
+      step: 1,
+    },
+    cropToFill: { type: 'boolean', label: 'Crop to Fill', default: true },
+    rollingShutter: {
+      type: 'number',
+      label: 'Rolling Shutter',
+      default: 0,
+      min: 0,
+      max: 1,
+      step: 0.01,
+    },
+    cameraPath: { type: 'json', label: 'Camera Path', default: '' },
+  },
+  packUniforms: (p, w, h) => {
+    const read = (key: string, fallback: number) => {
+      const value = p[key]
+      return typeof value === 'number' && Number.isFinite(value) ? value : fallback
+    }
+    const frameWidth = read('frameWidth', w)
+    const frameHeight = read('frameHeight', h)
+    return new Float32Array([
+      read('frameCenterX', w / 2),
+      read('frameCenterY', h / 2),
+      frameWidth,
+      frameHeight,
+      w,
+      h,
+      (read('frameRotation', 0) * Math.PI) / 180,
+      Math.max(0.01, read('correctionZoom', 1)),
+      read('correctionX', 0) * frameHeight,
+      read('correctionY', 0) * frameHeight,
+      (read('correctionRotation', 0) * Math.PI) / 180,
+      Math.max(0.01, read('correctionScale', 1)),
+      read('correctionShear', 0),
+      0,
+      0,
+      0,
+    ])
+  },
+}
@@ -1,4 +1,8 @@
 import type { GpuEffectDefinition } from '../types'
+import { getEffectMatte } from '../effect-mattes'
+
+/** Fully opaque 1x1 matte: keeps everything until a real matte is resolved. */
+const EMPTY_MATTE = { width: 1, height: 1, depth: 1, data: new Uint8Array([255, 255, 255, 255]) }
 
 export const chromaKey: GpuEffectDefinition = {
   id: 'gpu-chroma-key',
@@ -107,3 +111,130 @@ fn chromaKeyFragment(input: VertexOutput) -> @location(0) vec4f {
     ])
   },
 }
+
+/**
+ * Cuts the subject out using an AI segmentation matte instead of a key
+ * color. The matte covers the clip's source frame; per-frame `matteKey`
+ * (see `effect-mattes.ts`) and `frame*` values (item media rect, canvas px)
+ * are resolved at render time from the clip's source time and merged into
+ * the params. Without a matte the effect passes through.
+ */
+export const removeBackground: GpuEffectDefinition = {
+  id: 'gpu-remove-background',
+  name: 'Remove Background',
+  category: 'keying',
+  entryPoint: 'removeBackgroundFragment',
+  uniformSize: 64,
+  shader: /* wgsl */ `
+struct RemoveBackgroundParams {
+  frameCenter: vec2f,
+  frameSize: vec2f,
+  canvasSize: vec2f,
+  frameRotation: f32,
+  threshold: f32,
+  softness: f32,
+  invert: f32,
+  showMatte: f32,
+  hasMatte: f32,
+  frameFlip: vec2f,
+  _p1: f32,
+  _p2: f32,
+};
+@group(0) @binding(0) var texSampler: sampler;
+@group(0) @binding(1) var inputTex: texture_2d<f32>;
+@group(0) @binding(2) var<uniform> params: RemoveBackgroundParams;
+@group(0) @binding(3) var matteTex: texture_2d<f32>;
+@fragment
+fn removeBackgroundFragment(input: VertexOutput) -> @location(0) vec4f {
+  let color = textureSample(inputTex, texSampler, input.uv);
+  let offset = input.uv * params.canvasSize - params.frameCenter;
+  let c = cos(-params.frameRotation);
+  let s = sin(-params.frameRotation);
+  let local = vec2f(offset.x * c - offset.y * s, offset.x * s + offset.y * c) * params.frameFlip;
+  let matteUV = local / params.frameSize + vec2f(0.5);
+  let inFrame = all(matteUV >= vec2f(0.0)) && all(matteUV <= vec2f(1.0));
+  var coverage = select(0.0, textureSample(matteTex, texSampler, matteUV).a, inFrame);
+  if (params.invert > 0.5) {
+    coverage = 1.0 - coverage;
+  }
+  let halfEdge = params.softness * 0.5;
+  let refined = select(
+    step(params.threshold, coverage),
+    smoothstep(params.threshold - halfEdge, params.threshold + halfEdge, coverage),
+    halfEdge > 0.0,
+  );
+  if (params.hasMatte < 0.5) {
+    return color;
+  }
+  if (params.showMatte > 0.5) {
+    return vec4f(vec3f(refined), color.a);
+  }
+  return vec4f(color.rgb, color.a * refined);
+}`,
+  params: {
+    threshold: {
+      type: 'number',
+      label: 'Threshold',
+      default: 0.5,
+      min: 0,
+      max: 1,
+      step: 0.01,
+      animatable: true,
+    },
+    softness: {
+      type: 'number',
+      label: 'Edge Softness',
+      default: 0.3,
+      min: 0,
+      max: 1,
+      step: 0.01,
+      animatable: true,
+    },
+    invert: { type: 'boolean', label: 'Remove Subject', default: false },
+    output: {
+      type: 'select',
+      label: 'Output',
+      default: 'cutout',
+      options: [
+        { value: 'cutout', label: 'Cutout' },
+        { value: 'matte', label: 'Matte' },
+      ],
+    },
+    matteKey: { type: 'json', label: 'Matte', default: '' },
+    // Bumped after analysis so cached preview frames of the clip are re-rendered
+    matteRevision: { type: 'json', label: 'Matte Revision', default: '' },
+  },
+  packUniforms: (p, w, h) => {
+    const read = (key: string, fallback: number) => {
+      const value = p[key]
+      return typeof value === 'number' && Number.isFinite(value) ? value : fallback
+    }
+    const hasMatte = typeof p.matteKey === 'string' && getEffectMatte(p.matteKey) !== undefined
+    return new Float32Array([
+      read('frameCenterX', w / 2),
+      read('frameCenterY', h / 2),
+      Math.max(1, read('frameWidth', w)),
+      Math.max(1, read('frameHeight', h)),
+      w,
+      h,
+      (read('frameRotation', 0) * Math.PI) / 180,
+      read('threshold', 0.5),
+      read('softness', 0.3),
+      p.invert === true ? 1 : 0,
+      p.output === 'matte' ? 1 : 0,
+      hasMatte ? 1 : 0,
+      read('frameFlipX', 1) < 0 ? -1 : 1,
+      read('frameFlipY', 1) < 0 ? -1 : 1,
+      0,
+      0,
+    ])
+  },
+  dataTexture: {
+    dimension: '2d',
+    key: (p) => (typeof p.matteKey === 'string' ? p.matteKey : ''),
+    build: (p) => {
+      const matte = typeof p.matteKey === 'string' ? getEffectMatte(p.matteKey) : undefined
+      return matte ?? EMPTY_MATTE
+    },
+  },
+}
@@ -6,6 +6,7 @@ import {
   getGpuEffectDefaultParams,
   getGpuEffectsByCategory,
 } from './index'
+import { clearEffectMattes, registerEffectMatte } from './effect-mattes'
 
 describe('GPU effect registry', () => {
   it('registers every effect with shader metadata and valid default uniforms', () => {
@@ -246,6 +247,78 @@ describe('GPU effect registry', () => {
     expect(rectMatte[15]).toBe(2160)
   })
 
+  it('registers stabilize as a pass-through until per-frame corrections are resolved', () => {
+    const effect = getGpuEffect('gpu-stabilize')
+    expect(effect?.category).toBe('distort')
+
+    const defaults = getGpuEffectDefaultParams('gpu-stabilize')
+    expect(defaults).toEqual({
+      smoothness: 50,
+      cropToFill: true,
+      rollingShutter: 0,
+      cameraPath: '',
+    })
+    expect(Array.from(effect!.packUniforms(defaults, 1920, 1080)!)).toEqual([
+      960, 540, 1920, 1080, 1920, 1080, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0,
+    ])
+
+    const resolved = Array.from(
+      effect!.packUniforms(
+        {
+          ...defaults,
+          frameCenterX: 800,
+          frameCenterY: 400,
+          frameWidth: 640,
+          frameHeight: 360,
+          correctionX: 0.1,
+          correctionY: -0.05,
+          correctionZoom: 1.2,
+        },
+        1920,
+        1080,
+      )!,
+    )
+    expect(resolved.slice(0, 6)).toEqual([800, 400, 640, 360, 1920, 1080])
+    expect(resolved[7]).toBeCloseTo(1.2)
+    expect(resolved[8]).toBeCloseTo(36)
+    expect(resolved[9]).toBeCloseTo(-18)
+  })
+
+  it('registers remove background as a pass-through until a matte is registered', () => {
+    const effect = getGpuEffect('gpu-remove-background')
+    expect(effect?.category).toBe('keying')
+
+    const defaults = getGpuEffectDefaultParams('gpu-remove-background')
+    expect(defaults).toEqual({
+      threshold: 0.5,
+      softness: 0.3,
+      invert: false,
+      output: 'cutout',
+      matteKey: '',
+      matteRevision: '',
+    })
+    const passThrough = Array.from(effect!.packUniforms(defaults, 1920, 1080)!)
+    expect(passThrough.slice(0, 7)).toEqual([960, 540, 1920, 1080, 1920, 1080, 0])
+    expect(passThrough.slice(9)).toEqual([0, 0, 0, 1, 1, 0, 0])
+    expect(effect!.dataTexture!.build(defaults).width).toBe(1)
+
+    clearEffectMattes()
+    const matte = { width: 2, height: 1, depth: 1, data: new Uint8Array(8).fill(255) }
+    registerEffectMatte('media-1:0:30:12', matte)
+    const params = {
+      ...defaults,
+      matteKey: 'media-1:0:30:12',
+      invert: true,
+      output: 'matte',
+      frameFlipX: -1,
+    }
+    const resolved = Array.from(effect!.packUniforms(params, 1920, 1080)!)
+    expect(resolved.slice(9, 14)).toEqual([1, 1, 1, -1, 1])
+    expect(effect!.dataTexture!.key(params)).toBe('media-1:0:30:12')
+    expect(effect!.dataTexture!.build(params)).toBe(matte)
+    clearEffectMattes()
+  })
+
   it('returns undefined for unknown effect ids without throwing', () => {
     expect(getGpuEffect('nope-not-here')).toBeUndefined()
     expect(getGpuEffect('')).toBeUndefined()
@@ -0,0 +1,95 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { CameraPath } from '@/infrastructure/analysis/stabilization'
+import { decodeCameraPath, encodeCameraPath, getStabilizationCorrection } from './camera-path'
+
+const ASPECT = 16 / 9
+
+/** Slow pan to the right with frame-to-frame jitter in position and roll. */
+function createShakyPath(frames: number): CameraPath {
+  const path: CameraPath = {
+    fps: 30,
+    startTime: 2,
+    aspect: ASPECT,
+    x: [],
+    y: [],
+    rotation: [],
+    scale: [],
+  }
+  for (let i = 0; i < frames; i++) {
+    const jitter = i % 2 === 0 ? -1 : 1
+    path.x.push(0.001 * i + 0.01 * jitter)
+    path.y.push(0)
+    path.rotation.push(0.5 * jitter)
+    path.scale.push(1)
+  }
+  return path
+}
+
+const settings = { smoothness: 50, cropToFill: false, rollingShutter: 0 }
+
+describe('camera path encoding', () => {
+  it('round-trips through the effect param', () => {
+    const decoded = decodeCameraPath(encodeCameraPath(createShakyPath(3)))
+    expect(decoded?.fps).toBe(30)
+    expect(decoded?.startTime).toBe(2)
+    expect(decoded?.x).toEqual([-0.01, 0.011, -0.008])
+  })
+
+  it('rejects missing or malformed paths', () => {
+    expect(decodeCameraPath('')).toBeNull()
+    expect(decodeCameraPath('not json')).toBeNull()
+    expect(decodeCameraPath('{"fps":30,"startTime":0,"aspect":1,"x":[0]}')).toBeNull()
+  })
+})
+
+describe('getStabilizationCorrection', () => {
+  const encoded = encodeCameraPath(createShakyPath(301))
+
+  it('cancels jitter while keeping the intended pan', () => {
+    // Frame 150 is displaced 0.01 to the left of the pan and rolled -0.5°.
+    const even = getStabilizationCorrection(encoded, 2 + 150 / 30, settings)
+    expect(even.x).toBeCloseTo(0.01 * ASPECT, 3)
+    expect(even.y).toBeCloseTo(0)
+    expect(even.rotation).toBeCloseTo(0.5, 1)
+    expect(even.scale).toBeCloseTo(1)
+    expect(even.zoom).toBe(1)
+
+    const odd = getStabilizationCorrection(encoded, 2 + 151 / 30, settings)
+    expect(odd.x).toBeCloseTo(-0.01 * ASPECT, 3)
+    expect(odd.rotation).toBeCloseTo(-0.5, 1)
+  })
+
+  it('interpolates between analysis samples', () => {
+    const between = getStabilizationCorrection(encoded, 2 + 150.5 / 30, settings)
+    expect(between.x).toBeCloseTo(0, 3)
+  })
+
+  it('passes footage through with zero smoothness and no rolling-shutter fix', () => {
+    expect(
+      getStabilizationCorrection(encoded, 4, {
+        smoothness: 0,
+        cropToFill: true,
+        rollingShutter: 0,
+      }),
+    ).toEqual({ x: 0, y: 0, rotation: 0, scale: 1, shear: 0, zoom: 1 })
+  })
+
+  it('zooms in to hide the corrected frame edges', () => {
+    const cropped = getStabilizationCorrection(encoded, 4, { ...settings, cropToFill: true })
+    expect(cropped.zoom).toBeGreaterThan(1)
+    expect(cropped.zoom).toBeLessThan(1.2)
+  })
+
+  it('skews against horizontal motion for rolling shutter', () => {
+    const pan = createShakyPath(31)
+    pan.x = pan.x.map((_, i) => 0.002 * i)
+    const correction = getStabilizationCorrection(encodeCameraPath(pan), 2.5, {
+      smoothness: 0,
+      cropToFill: false,
+      rollingShutter: 1,
+    })
+    // 0.06 frame widths per second, read out over 1/30 s
+    expect(correction.shear).toBeCloseTo((0.06 * ASPECT) / 30, 5)
+    expect(correction.x).toBe(0)
+  })
+})
@@ -0,0 +1,249 @@
+/**
+ * Camera path smoothing for the stabilization effect.
+ *
+ * The measured path is embedded in the effect params as compact JSON so it
+ * travels with the project. At render time it is smoothed with a Gaussian,
+ * and the difference between the smoothed and measured path gives the
+ * per-frame correction. Corrections are in frame-height units about the
+ * frame center so they apply at any render size.
+ */
+
+import type { CameraPath } from '@/infrastructure/analysis/stabilization'
+
+export interface StabilizationSettings {
+  /** 0-100; 0 leaves the footage untouched */
+  smoothness: number
+  /** Zoom in just enough that no frame edge is ever revealed */
+  cropToFill: boolean
+  /** Sensor readout time as a fraction of a 30 fps frame (0 disables skew correction) */
+  rollingShutter: number
+}
+
+export interface StabilizationCorrection {
+  x: number
+  y: number
+  /** Degrees, clockwise positive */
+  rotation: number
+  scale: number
+  /** Horizontal skew per unit of height (rolling-shutter wobble) */
+  shear: number
+  /** Constant zoom over the clip (1 without crop-to-fill) */
+  zoom: number
+}
+
+interface ResolvedCorrections {
+  path: CameraPath
+  x: number[]
+  y: number[]
+  rotation: number[]
+  scale: number[]
+  shear: number[]
+  zoom: number
+}
+
+const IDENTITY_CORRECTION: StabilizationCorrection = {
+  x: 0,
+  y: 0,
+  rotation: 0,
+  scale: 1,
+  shear: 0,
+  zoom: 1,
+}
+
+/** Gaussian sigma at smoothness 100 */
+const MAX_SMOOTHING_SECONDS = 2
+const MAX_CROP_ZOOM = 2
+const ROLLING_SHUTTER_REFERENCE_FPS = 30
+const MAX_CACHED_PATHS = 8
+const MAX_CACHED_SETTINGS = 4
+
+/**
+ * Keyed by the encoded path itself: params hold the same string instance
+ * across frames, so the lookup stays a cheap same-reference hit.
+ */
+const resolvedCache = new Map<string, Map<string, ResolvedCorrections | null>>()
+
+function round(value: number, digits: number): number {
+  const factor = 10 ** digits
+  return Math.round(value * factor) / factor
+}
+
+export function encodeCameraPath(path: CameraPath): string {
+  return JSON.stringify({
+    fps: path.fps,
+    startTime: round(path.startTime, 4),
+    aspect: round(path.aspect, 4),
+    x: path.x.map((value) => round(value, 5)),
+    y: path.y.map((value) => round(value, 5)),
+    rotation: path.rotation.map((value) => round(value, 3)),
+    scale: path.scale.map((value) => round(value, 5)),
+  })
+}
+
+export function decodeCameraPath(encoded: string): CameraPath | null {
+  if (!encoded) return null
+  try {
+    const parsed = JSON.parse(encoded) as Partial<CameraPath>
+    const length = parsed.x?.length ?? 0
+    if (
+      typeof parsed.fps !== 'number' ||
+      parsed.fps <= 0 ||
+      typeof parsed.startTime !== 'number' ||
+      typeof parsed.aspect !== 'number' ||
+      parsed.aspect <= 0 ||
+      length === 0 ||
+      parsed.y?.length !== length ||
+      parsed.rotation?.length !== length ||
+      parsed.scale?.length !== length
+    ) {
+      return null
+    }
+    return parsed as CameraPath
+  } catch {
+    return null
+  }
+}
+
+function gaussianSmooth(values: number[], sigma: number): number[] {
+  if (sigma <= 0) return values
+  const radius = Math.ceil(sigma * 3)
+  const weights = Array.from({ length: radius * 2 + 1 }, (_, index) =>
+    Math.exp(-((index - radius) ** 2) / (2 * sigma * sigma)),
+  )
+  const last = values.length - 1
+  return values.map((_, center) => {
+    let sum = 0
+    let weightSum = 0
+    for (let offset = -radius; offset <= radius; offset++) {
+      // Clamp at the ends so the path settles instead of drifting toward zero
+      const value = values[Math.max(0, Math.min(last, center + offset))]!
+      const weight = weights[offset + radius]!
+      sum += value * weight
+      weightSum += weight
+    }
+    return sum / weightSum
+  })
+}
+
+/**
+ * Smallest zoom such that the corrected frame still covers the output frame
+ * on every sample.
+ */
+function getCropZoom(corrections: Omit<ResolvedCorrections, 'zoom'>): number {
+  const halfWidth = corrections.path.aspect / 2
+  const halfHeight = 0.5
+  let zoom = 1
+
+  for (let i = 0; i < corrections.x.length; i++) {
+    const theta = (-corrections.rotation[i]! * Math.PI) / 180
+    const cos = Math.cos(theta)
+    const sin = Math.sin(theta)
+    const scale = corrections.scale[i]!
+    const tx = corrections.x[i]!
+    const ty = corrections.y[i]!
+    const ux = Math.abs(cos * tx - sin * ty)
+    const uy = Math.abs(sin * tx + cos * ty)
+    const availableX = scale * (halfWidth - Math.abs(corrections.shear[i]!) * halfHeight) - ux
+    const availableY = scale * halfHeight - uy
+
+    for (const cornerY of [halfHeight, -halfHeight]) {
+      const ax = Math.abs(cos * halfWidth - sin * cornerY)
+      const ay = Math.abs(sin * halfWidth + cos * cornerY)
+      const needed = Math.max(
+        availableX > 0 ? ax / availableX : MAX_CROP_ZOOM,
+        availableY > 0 ? ay / availableY : MAX_CROP_ZOOM,
+      )
+      zoom = Math.max(zoom, needed)
+    }
+  }
+
+  return Math.min(MAX_CROP_ZOOM, zoom)
+}
+
+function resolveCorrections(
+  encodedPath: string,
+  settings: StabilizationSettings,
+): ResolvedCorrections | null {
+  let bySettings = resolvedCache.get(encodedPath)
+  if (!bySettings) {
+    if (resolvedCache.size >= MAX_CACHED_PATHS) {
+      resolvedCache.delete(resolvedCache.keys().next().value!)
+    }
+    bySettings = new Map()
+    resolvedCache.set(encodedPath, bySettings)
+  }
+  const settingsKey = `${settings.smoothness}|${settings.cropToFill}|${settings.rollingShutter}`
+  const cached = bySettings.get(settingsKey)
+  if (cached !== undefined) return cached
+
+  const path = decodeCameraPath(encodedPath)
+  let resolved: ResolvedCorrections | null = null
+  if (path) {
+    const sigma = (Math.max(0, Math.min(100, settings.smoothness)) / 100) * MAX_SMOOTHING_SECONDS
+    const sigmaFrames = sigma * path.fps
+    const logScale = path.scale.map((value) => Math.log(Math.max(1e-3, value)))
+    const smoothX = gaussianSmooth(path.x, sigmaFrames)
+    const smoothY = gaussianSmooth(path.y, sigmaFrames)
+    const smoothRotation = gaussianSmooth(path.rotation, sigmaFrames)
+    const smoothLogScale = gaussianSmooth(logScale, sigmaFrames)
+    const last = path.x.length - 1
+    const readout = settings.rollingShutter / ROLLING_SHUTTER_REFERENCE_FPS
+
+    const corrections = {
+      path,
+      x: path.x.map((value, i) => (smoothX[i]! - value) * path.aspect),
+      y: path.y.map((value, i) => smoothY[i]! - value),
+      rotation: path.rotation.map((value, i) => smoothRotation[i]! - value),
+      scale: logScale.map((value, i) => Math.exp(smoothLogScale[i]! - value)),
+      shear: path.x.map((_, i) => {
+        if (readout <= 0 || last === 0) return 0
+        const before = path.x[Math.max(0, i - 1)]!
+        const after = path.x[Math.min(last, i + 1)]!
+        const span = Math.min(last, i + 1) - Math.max(0, i - 1)
+        const velocity = ((after - before) / span) * path.fps * path.aspect
+        return velocity * readout
+      }),
+    }
+    resolved = {
+      ...corrections,
+      zoom: settings.cropToFill ? getCropZoom(corrections) : 1,
+    }
+  }
+
+  // Dragging a slider resolves every intermediate value; keep only the recent ones
+  if (bySettings.size >= MAX_CACHED_SETTINGS) {
+    bySettings.delete(bySettings.keys().next().value!)
+  }
+  bySettings.set(settingsKey, resolved)
+  return resolved
+}
+
+/** Correction for the frame shown at `sourceTime` (seconds). */
+export function getStabilizationCorrection(
+  encodedPath: string,
+  sourceTime: number,
+  settings: StabilizationSettings,
+): StabilizationCorrection {
+  if (settings.smoothness <= 0 && settings.rollingShutter <= 0) return IDENTITY_CORRECTION
+  const resolved = resolveCorrections(encodedPath, settings)
+  if (!resolved) return IDENTITY_CORRECTION
+
+  const last = resolved.x.length - 1
+  const position = Math.max(
+    0,
+    Math.min(last, (sourceTime - resolved.path.startTime) * resolved.path.fps),
+  )
+  const index = Math.floor(position)
+  const next = Math.min(last, index + 1)
+  const t = position - index
+  const lerp = (values: number[]) => values[index]! + (values[next]! - values[index]!) * t
+
+  return {
+    x: lerp(resolved.x),
+    y: lerp(resolved.y),
+    rotation: lerp(resolved.rotation),
+    scale: lerp(resolved.scale),
+    shear: lerp(resolved.shear),
+    zoom: resolved.zoom,
+  }
+}
@@ -251,6 +251,40 @@ describe('GlyphAtlasTextPipeline', () => {
     expect(pass.draw).toHaveBeenCalledWith(18)
   })
 
+  it('paints karaoke highlights and skips words that have not popped in yet', () => {
+    const { outputTexture, pass, pipeline, queue } = createPipelineHarness()
+
+    const rendered = pipeline.renderTextToTexture(outputTexture, {
+      outputWidth: 640,
+      outputHeight: 180,
+      width: 640,
+      height: 180,
+      item: {
+        id: 'text',
+        type: 'text',
+        trackId: 'track',
+        from: 0,
+        durationInFrames: 30,
+        text: 'A B C',
+        color: '#ffffff',
+        fontSize: 48,
+        fontFamily: 'Inter',
+        karaokeWords: [{}, { color: '#ff0000', backgroundColor: '#0000ff' }, { hidden: true }],
+      } as TextItem,
+    })
+
+    expect(rendered).toBe(true)
+    const vertexData = queue.writeBuffer.mock.calls[0]?.[2] as Float32Array
+    expect(vertexData[4]).toBeCloseTo(0)
+    expect(vertexData[6]).toBeCloseTo(1)
+    expect(vertexData[8]).toBeCloseTo(1)
+    expect(vertexData[124]).toBeCloseTo(1)
+    expect(vertexData[125]).toBeCloseTo(1)
+    expect(vertexData[244]).toBeCloseTo(1)
+    expect(vertexData[245]).toBeCloseTo(0)
+    expect(pass.draw).toHaveBeenCalledWith(18)
+  })
+
   it('packs text stroke color and width for SDF outline rendering', () => {
     const { outputTexture, pipeline, queue } = createPipelineHarness()
 
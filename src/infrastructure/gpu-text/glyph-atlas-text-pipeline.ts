@@ -1,6 +1,7 @@
 import type { TextItem } from '@/types/timeline'
 import { layoutTextBlock, lineInkWidth } from '@/shared/typography/text-block-layout'
 import { parseFontSizePx, type TextMeasurer } from '@/shared/typography/text-measurer'
+import { layoutKaraokeWords, type KaraokeWordBox } from '@/shared/typography/caption-karaoke'
 
 export interface GpuTextRenderParams {
   outputWidth: number
@@ -325,6 +326,22 @@ export class GlyphAtlasTextPipeline {
       })
     }
 
+    const karaokeBoxes = item.karaokeWords ? layoutKaraokeWords(layout, measurer) : null
+    for (const box of karaokeBoxes ?? []) {
+      const paint = item.karaokeWords?.[box.index]
+      if (!paint?.backgroundColor || paint.hidden) continue
+      const highlightColor = parseGpuTextColor(paint.backgroundColor)
+      const highlightGlyph = this.ensureSolidGlyph()
+      if (!highlightColor || !highlightGlyph) return null
+      const scale = paint.scale ?? 1
+      glyphs.push({
+        metrics: highlightGlyph,
+        ...scaleRectAbout(box.background, box, scale),
+        color: highlightColor,
+        solidRadius: box.background.radius * scale,
+      })
+    }
+
     const shadow = item.textShadow
     const shadowColor = shadow ? parseGpuTextColor(shadow.color) : undefined
     if (shadow && !shadowColor) return null
@@ -336,36 +353,47 @@ export class GlyphAtlasTextPipeline {
       strokeColor = parsedStrokeColor
     }
 
-    for (const line of layout.lines) {
-      const color = parseGpuTextColor(line.color)
-      if (!color) return null
+    for (const [lineIndex, line] of layout.lines.entries()) {
+      const lineColor = parseGpuTextColor(line.color)
+      if (!lineColor) return null
+      const lineBoxes = karaokeBoxes?.filter((box) => box.lineIndex === lineIndex)
       const baselineY = line.baselineY
       let currentX = line.startX
+      // Karaoke word index on this line; spaces separate words like layoutKaraokeWords.
+      let wordOnLine = -1
+      let inWord = false
       for (const char of line.text) {
         const metrics = this.ensureGlyph(char, line.cssFont, line.fontSize)
         if (!metrics) return null
-        if (char !== ' ') {
-          if (shadow && shadowColor) {
-            glyphs.push({
-              metrics,
-              x: currentX + metrics.offsetX + shadow.offsetX,
-              y: baselineY + metrics.offsetY + shadow.offsetY,
-              width: metrics.contentWidth,
-              height: metrics.contentHeight,
-              color: shadowColor,
-              shadowBlur: Math.max(0, shadow.blur),
-            })
-          }
-          glyphs.push({
-            metrics,
+        if (char === ' ') {
+          inWord = false
+        } else {
+          if (!inWord) wordOnLine += 1
+          inWord = true
+          const box = lineBoxes?.[wordOnLine]
+          const paint = box ? item.karaokeWords?.[box.index] : undefined
+          const color = paint?.color ? parseGpuTextColor(paint.color) : lineColor
+          if (!color) return null
+          const rect = {
             x: currentX + metrics.offsetX,
             y: baselineY + metrics.offsetY,
             width: metrics.contentWidth,
             height: metrics.contentHeight,
-            color,
-            strokeColor,
-            strokeWidth,
-          })
+          }
+          const glyphRect = box && paint?.scale ? scaleRectAbout(rect, box, paint.scale) : rect
+          if (!paint?.hidden) {
+            if (shadow && shadowColor) {
+              glyphs.push({
+                metrics,
+                ...glyphRect,
+                x: glyphRect.x + shadow.offsetX,
+                y: glyphRect.y + shadow.offsetY,
+                color: shadowColor,
+                shadowBlur: Math.max(0, shadow.blur),
+              })
+            }
+            glyphs.push({ metrics, ...glyphRect, color, strokeColor, strokeWidth })
+          }
         }
         currentX += metrics.advance + line.letterSpacing
       }
@@ -392,7 +420,7 @@ export class GlyphAtlasTextPipeline {
           y: underlineY,
           width: underlineWidth,
           height: underlineHeight,
-          color,
+          color: lineColor,
           solidRadius: 0,
         })
       }
@@ -541,6 +569,20 @@ export class GlyphAtlasTextPipeline {
   }
 }
 
+/** Scale a rect about a karaoke word's center (pop-in / active scale). */
+function scaleRectAbout(
+  rect: { x: number; y: number; width: number; height: number },
+  box: KaraokeWordBox,
+  scale: number,
+): { x: number; y: number; width: number; height: number } {
+  return {
+    x: box.centerX + (rect.x - box.centerX) * scale,
+    y: box.centerY + (rect.y - box.centerY) * scale,
+    width: rect.width * scale,
+    height: rect.height * scale,
+  }
+}
+
 function writeGlyphVertices(data: Float32Array, offset: number, glyph: PackedGlyph): number {
   const { metrics } = glyph
   const { color } = glyph
@@ -10,3 +10,4 @@ export {
   getLlmAdapter,
   listLlmAdapters,
 } from './llm-registry'
+export { createMockLlmAdapter, type MockLlmAdapter } from './mock-llm-adapter'
@@ -0,0 +1,36 @@
+/**
+ * Deterministic in-memory {@link LlmAdapter} for tests and headless flows.
+ * No weights, no worker: `respond` maps the conversation to a reply, and every
+ * call is recorded so tests can assert on the prompts a feature sends.
+ */
+
+import type { LlmAdapter, LlmGenerateOptions, LlmMessage } from './types'
+
+export interface MockLlmAdapter extends LlmAdapter {
+  /** Every `generate` call, in order. */
+  readonly calls: LlmMessage[][]
+}
+
+export function createMockLlmAdapter(
+  respond: (messages: LlmMessage[]) => string,
+  id = 'mock',
+): MockLlmAdapter {
+  const calls: LlmMessage[][] = []
+  return {
+    id,
+    label: 'Mock LLM',
+    calls,
+    isSupported: () => true,
+    load: async (onProgress) => {
+      onProgress?.({ stage: 'ready', percent: 100 })
+    },
+    generate: async (messages: LlmMessage[], options?: LlmGenerateOptions) => {
+      if (options?.signal?.aborted) throw new DOMException('Aborted', 'AbortError')
+      calls.push(messages)
+      const text = respond(messages)
+      options?.onToken?.(text, text)
+      return text
+    },
+    dispose: () => {},
+  }
+}
@@ -110,7 +110,9 @@ export {
 export {
   hasMediaSource,
   readMediaSource,
+  readMediaSourceLink,
   writeMediaSource,
+  type MediaSourceLink,
 } from '@/infrastructure/storage/workspace-fs/media-source'
 
 // Workspace cache mirror helpers
@@ -122,7 +124,12 @@ export {
 } from '@/infrastructure/storage/workspace-fs/cache-mirror'
 
 // Workspace cache path helpers
-export { proxyDir, proxyFilePath, proxyMetaPath } from '@/infrastructure/storage/workspace-fs/paths'
+export {
+  mediaSourceByFileName,
+  proxyDir,
+  proxyFilePath,
+  proxyMetaPath,
+} from '@/infrastructure/storage/workspace-fs/paths'
 
 // Embedded text-subtitle track cache (parsed once per source fingerprint)
 export {
@@ -131,7 +138,7 @@ export {
 } from '@/infrastructure/storage/workspace-fs/embedded-subtitles'
 
 // Scene-detection results
-export { deleteScenes } from '@/infrastructure/storage/workspace-fs/scenes'
+export { deleteScenes, getScenes } from '@/infrastructure/storage/workspace-fs/scenes'
 
 // Generic AI-output envelope (use these directly for new AI services)
 export { readAiOutput } from '@/infrastructure/storage/workspace-fs/ai-outputs'
@@ -146,6 +153,7 @@ export {
 // Final render outputs (export queue)
 export {
   saveExportFile,
+  createExportSequenceFolder,
   listExportFiles,
   readExportFile,
   deleteExportFile,
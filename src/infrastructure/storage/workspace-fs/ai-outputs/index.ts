@@ -1,4 +1,11 @@
-export type { AiOutput, ScenesPayload, SceneCutPayload } from './types'
+export type {
+  AiOutput,
+  ScenesPayload,
+  SceneCutPayload,
+  SegmentationPayload,
+  StabilizationPayload,
+  SubjectsPayload,
+} from './types'
 export { AI_OUTPUT_SCHEMA_VERSION, transcriptFromLegacy, transcriptToLegacy } from './types'
 export {
   readAiOutput,
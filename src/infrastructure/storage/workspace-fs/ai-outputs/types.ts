@@ -8,10 +8,14 @@
  */
 
 import type { MediaCaption } from '@/infrastructure/analysis/media-tagger'
+import type { SubjectPath } from '@/infrastructure/analysis/reframe'
+import type { CameraPath } from '@/infrastructure/analysis/stabilization'
 import type {
   MediaTranscript,
   MediaTranscriptModel,
   MediaTranscriptQuantization,
+  MediaTranscriptSegment,
+  MediaTranscriptSpeaker,
 } from '@/types/storage'
 
 /**
@@ -21,7 +25,13 @@ import type {
  * 3. (Optional) Add a thin wrapper in `workspace-fs/` that calls
  *    `readAiOutput/writeAiOutput` with that kind.
  */
-export type AiOutputKind = 'transcript' | 'captions' | 'scenes'
+export type AiOutputKind =
+  | 'transcript'
+  | 'captions'
+  | 'scenes'
+  | 'stabilization'
+  | 'segmentation'
+  | 'subjects'
 
 /**
  * Typed payload per kind. Matches the `data` field on `AiOutput<T>`.
@@ -31,6 +41,9 @@ export interface AiOutputPayloads {
   transcript: TranscriptPayload
   captions: CaptionsPayload
   scenes: ScenesPayload
+  stabilization: StabilizationPayload
+  segmentation: SegmentationPayload
+  subjects: SubjectsPayload
 }
 
 /**
@@ -61,7 +74,9 @@ export interface TranscriptPayload {
   quantization: MediaTranscriptQuantization
   modelVariant: MediaTranscriptModel
   text: string
-  segments: Array<{ text: string; start: number; end: number }>
+  segments: MediaTranscriptSegment[]
+  /** Diarized voices referenced by `segments[].speakerId` */
+  speakers?: MediaTranscriptSpeaker[]
 }
 
 export type CaptionsPayload = {
@@ -109,6 +124,39 @@ export interface ScenesPayload {
   cuts: SceneCutPayload[]
 }
 
+/**
+ * Global camera motion over the analyzed source range. Consumers re-run the
+ * analysis when their clip uses source time outside `[startTime, endTime]`.
+ */
+export interface StabilizationPayload {
+  endTime: number
+  path: CameraPath
+}
+
+/**
+ * Subject mattes generated for the media. The per-frame mattes live next to
+ * the envelope under `segmentation/fps-{milliFps}/`; `ranges` records which
+ * source seconds have been analyzed so callers can tell what is missing.
+ */
+export interface SegmentationPayload {
+  /** Matte rate; matte `N` covers source time `N / fps` */
+  fps: number
+  width: number
+  height: number
+  /** Analyzed source ranges in seconds, sorted and non-overlapping */
+  ranges: Array<{ start: number; end: number }>
+}
+
+/**
+ * Salient-subject trajectory used by smart reframe. Like stabilization, one
+ * contiguous range is cached; consumers re-run the analysis when their clips
+ * use source time outside `[path.startTime, endTime]`.
+ */
+export interface SubjectsPayload {
+  endTime: number
+  path: SubjectPath
+}
+
 /* ───────────────── Conversions ───────────────── */
 
 /**
@@ -132,6 +180,7 @@ export function transcriptFromLegacy(record: MediaTranscript): AiOutput<'transcr
       modelVariant: record.model,
       text: record.text,
       segments: record.segments,
+      ...(record.speakers ? { speakers: record.speakers } : {}),
     },
   }
 }
@@ -146,6 +195,7 @@ export function transcriptToLegacy(envelope: AiOutput<'transcript'>): MediaTrans
     quantization: envelope.data.quantization,
     text: envelope.data.text,
     segments: envelope.data.segments,
+    ...(envelope.data.speakers ? { speakers: envelope.data.speakers } : {}),
     createdAt: envelope.createdAt,
     updatedAt: envelope.updatedAt,
   }
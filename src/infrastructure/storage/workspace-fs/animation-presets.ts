@@ -54,6 +54,7 @@ const VALID_ITEM_TYPES = new Set<TimelineItem['type']>([
   'shape',
   'adjustment',
   'composition',
+  'multicam',
   'subtitle',
 ])
 
@@ -8,7 +8,14 @@
  */
 
 import { getWorkspaceRoot, requireWorkspaceRoot } from './root'
-import { exists, readBlob, readDirectoryFiles, removeEntry, writeBlob } from './fs-primitives'
+import {
+  createDirectory,
+  exists,
+  readBlob,
+  readDirectoryFiles,
+  removeEntry,
+  writeBlob,
+} from './fs-primitives'
 import {
   EXPORTS_DIR,
   PROJECTS_DIR,
@@ -25,6 +32,14 @@ export interface SavedExport {
   relPath: string
 }
 
+/** An image-sequence folder created ahead of the render. */
+export interface ExportSequenceFolder extends SavedExport {
+  /** Workspace-relative path segments — used to delete a failed render. */
+  path: string[]
+  /** The folder itself; frames are written into it as they are rendered. */
+  handle: FileSystemDirectoryHandle
+}
+
 export interface ExportFileEntry {
   name: string
   size: number
@@ -43,19 +58,25 @@ function suffixFileName(fileName: string, n: number): string {
   return `${stem} (${n})${ext}`
 }
 
+/** Folders have no extension: `clip` → `clip (2)`. */
+function suffixFolderName(folderName: string, n: number): string {
+  return `${folderName} (${n})`
+}
+
 async function uniqueFileName(
   root: FileSystemDirectoryHandle,
   pathOf: (name: string) => string[],
   fileName: string,
+  suffix: (name: string, n: number) => string = suffixFileName,
 ): Promise<string> {
   const safe = sanitizeWorkspaceFileName(fileName)
   if (!(await exists(root, pathOf(safe)))) return safe
   for (let n = 2; n < 1000; n++) {
-    const candidate = suffixFileName(safe, n)
+    const candidate = suffix(safe, n)
     if (!(await exists(root, pathOf(candidate)))) return candidate
   }
   // Pathological fallback — guaranteed unique enough.
-  return suffixFileName(safe, Date.now())
+  return suffix(safe, Date.now())
 }
 
 /**
@@ -80,6 +101,27 @@ export async function saveExportFile(
   return { fileName: name, relPath: `${relBase}/${name}` }
 }
 
+/**
+ * Create the folder an image sequence renders into, inside the project's
+ * `exports/` folder. The folder name is de-duplicated like a file. The render
+ * writes each frame through the returned handle as soon as it is encoded, so
+ * the sequence is never held in memory.
+ */
+export async function createExportSequenceFolder(
+  projectId: string | undefined,
+  folderName: string,
+): Promise<ExportSequenceFolder> {
+  const root = requireWorkspaceRoot()
+  const pathOf = projectId
+    ? (name: string) => projectExportFilePath(projectId, name)
+    : (name: string) => exportFilePath(name)
+  const relBase = projectId ? `${PROJECTS_DIR}/${projectId}/${EXPORTS_DIR}` : EXPORTS_DIR
+
+  const name = await uniqueFileName(root, pathOf, folderName, suffixFolderName)
+  const handle = await createDirectory(root, pathOf(name))
+  return { fileName: name, relPath: `${relBase}/${name}`, path: pathOf(name), handle }
+}
+
 /** List a project's saved export files, newest first. Empty when none. */
 export async function listExportFiles(projectId: string): Promise<ExportFileEntry[]> {
   const files = await readDirectoryFiles(requireWorkspaceRoot(), projectExportsDir(projectId))
@@ -100,9 +142,9 @@ export function readExportFile(path: string[]): Promise<Blob | null> {
   return readBlob(requireWorkspaceRoot(), path)
 }
 
-/** Delete an export by path. No-op when missing. */
+/** Delete an export by path (a sequence folder with its frames). No-op when missing. */
 export function deleteExportFile(path: string[]): Promise<void> {
-  return removeEntry(requireWorkspaceRoot(), path)
+  return removeEntry(requireWorkspaceRoot(), path, { recursive: true })
 }
 
 /** The user-picked workspace folder's name (for telling users where files land). */
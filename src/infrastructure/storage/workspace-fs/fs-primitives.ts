@@ -216,6 +216,17 @@ export async function writeBlob(
   )
 }
 
+/**
+ * Create a directory (and any missing parents) and return its handle, for
+ * callers that stream many files into it.
+ */
+export async function createDirectory(
+  root: FileSystemDirectoryHandle,
+  segments: string[],
+): Promise<FileSystemDirectoryHandle> {
+  return wrap('createDirectory', () => resolveDir(root, segments, true))
+}
+
 /* ────────────────────────────── Delete helpers ───────────────────────── */
 
 /**
@@ -17,8 +17,8 @@
 import { createLogger } from '@/shared/logging/logger'
 
 import { requireWorkspaceRoot } from './root'
-import { listDirectory, readBlob, writeBlob } from './fs-primitives'
-import { mediaDir, mediaSourceByFileName } from './paths'
+import { listDirectory, readBlob, readJson, writeBlob } from './fs-primitives'
+import { mediaDir, mediaSourceByFileName, mediaSourceLinkPath } from './paths'
 
 const logger = createLogger('WorkspaceFS:MediaSource')
 
@@ -101,6 +101,31 @@ export async function writeMediaSource(
   }
 }
 
+/**
+ * Descriptor stored at `media/{id}/source.link.json` when the source is
+ * referenced in place on disk instead of being copied into the workspace.
+ */
+export interface MediaSourceLink {
+  /** Absolute path (or file URL) of the original source file */
+  path: string
+  fileName?: string
+}
+
+/**
+ * Read the in-place link descriptor for a media entry, or null when the
+ * source lives inside the workspace (or the descriptor is unreadable).
+ */
+export async function readMediaSourceLink(mediaId: string): Promise<MediaSourceLink | null> {
+  const root = requireWorkspaceRoot()
+  try {
+    const link = await readJson<MediaSourceLink>(root, mediaSourceLinkPath(mediaId))
+    return link && typeof link.path === 'string' && link.path.length > 0 ? link : null
+  } catch (error) {
+    logger.warn(`readMediaSourceLink(${mediaId}) failed`, error)
+    return null
+  }
+}
+
 async function blobToArrayBuffer(blob: Blob): Promise<ArrayBuffer> {
   if (typeof blob.arrayBuffer === 'function') return blob.arrayBuffer()
   return new Response(blob).arrayBuffer()
@@ -0,0 +1,69 @@
+/**
+ * Optical-flow fields for frame interpolation, backed by the workspace folder.
+ *
+ *   media/{mediaId}/cache/optical-flow/fps-{milliFps}/{N}.bin
+ *
+ * Each file holds the flow from source frame N to N+1 at analysis
+ * resolution: a `Uint32` width/height header followed by interleaved
+ * `Float32` (vx, vy) pairs. Flow is a property of the source media, so every
+ * clip (and every export) using the same frames shares it.
+ */
+
+import type { FlowField } from '@/infrastructure/analysis/frame-interpolation'
+import { createLogger } from '@/shared/logging/logger'
+
+import { requireWorkspaceRoot } from './root'
+import { readArrayBuffer, removeEntry, writeBlob } from './fs-primitives'
+import { opticalFlowDir, opticalFlowPairPath } from './paths'
+
+const logger = createLogger('WorkspaceFS:OpticalFlow')
+
+const HEADER_BYTES = 8
+
+export async function getOpticalFlow(
+  mediaId: string,
+  sourceFps: number,
+  frameIndex: number,
+): Promise<FlowField | undefined> {
+  const root = requireWorkspaceRoot()
+  try {
+    const buffer = await readArrayBuffer(root, opticalFlowPairPath(mediaId, sourceFps, frameIndex))
+    if (!buffer || buffer.byteLength < HEADER_BYTES) return undefined
+
+    const [width, height] = new Uint32Array(buffer, 0, 2)
+    const data = new Float32Array(buffer, HEADER_BYTES)
+    if (!width || !height || data.length !== width * height * 2) return undefined
+    return { width, height, data }
+  } catch (error) {
+    logger.warn(`getOpticalFlow(${mediaId}, ${frameIndex}) failed`, error)
+    return undefined
+  }
+}
+
+export async function saveOpticalFlow(
+  mediaId: string,
+  sourceFps: number,
+  frameIndex: number,
+  flow: FlowField,
+): Promise<void> {
+  const root = requireWorkspaceRoot()
+  try {
+    const bytes = new Uint8Array(HEADER_BYTES + flow.data.byteLength)
+    new Uint32Array(bytes.buffer, 0, 2).set([flow.width, flow.height])
+    new Float32Array(bytes.buffer, HEADER_BYTES).set(flow.data)
+    await writeBlob(root, opticalFlowPairPath(mediaId, sourceFps, frameIndex), bytes)
+  } catch (error) {
+    logger.error(`saveOpticalFlow(${mediaId}, ${frameIndex}) failed`, error)
+    throw new Error(`Failed to save optical flow: ${mediaId}`)
+  }
+}
+
+export async function deleteOpticalFlow(mediaId: string): Promise<void> {
+  const root = requireWorkspaceRoot()
+  try {
+    await removeEntry(root, opticalFlowDir(mediaId), { recursive: true })
+  } catch (error) {
+    logger.error(`deleteOpticalFlow(${mediaId}) failed`, error)
+    throw new Error(`Failed to delete optical flow: ${mediaId}`)
+  }
+}
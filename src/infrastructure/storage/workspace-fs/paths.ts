@@ -28,11 +28,16 @@
  * │           ├── waveform/{meta.json,bin-N.bin,multi-res.bin}
  * │           ├── gif-frames/{meta.json,frame-N.png}
  * │           ├── decoded-audio/{meta.json,left-N.bin,right-N.bin}
+ * │           ├── optical-flow/fps-{milliFps}/N.bin   # flow from source frame N to N+1
  * │           ├── preview-audio.wav
  * │           └── ai/
  * │               ├── transcript.json
  * │               ├── captions.json
  * │               ├── scenes.json
+ * │               ├── stabilization.json
+ * │               ├── segmentation.json
+ * │               ├── segmentation/fps-{milliFps}/N.bin   # subject matte of frame N
+ * │               ├── subjects.json
  * │               └── {kind}.json          # new AI outputs go here, one file per kind
  * └── content/
  *     ├── {hash[0:2]}/{hash}/            # content-addressable source dedup (reserved)
@@ -89,6 +94,7 @@ const PROJECT_ANIMATION_PRESETS_FILENAME = 'animation-presets.json'
 const PROJECT_TRASHED_MARKER_FILENAME = '.freecut-trashed.json'
 
 const MEDIA_METADATA_FILENAME = 'metadata.json'
+const MEDIA_SOURCE_LINK_FILENAME = 'source.link.json'
 const MEDIA_THUMBNAIL_FILENAME = 'thumbnail.jpg'
 const MEDIA_CACHE_DIR = 'cache'
 
@@ -96,6 +102,7 @@ const CACHE_WAVEFORM_DIR = 'waveform'
 const CACHE_FILMSTRIP_DIR = 'filmstrip'
 const CACHE_GIF_FRAMES_DIR = 'gif-frames'
 const CACHE_DECODED_AUDIO_DIR = 'decoded-audio'
+const CACHE_OPTICAL_FLOW_DIR = 'optical-flow'
 const CACHE_AI_DIR = 'ai'
 /** Single file per media under cache/. Non-browser audio codecs are decoded
  *  once to WAV and reused for preview playback. */
@@ -105,6 +112,8 @@ const CACHE_PREVIEW_AUDIO_FILENAME = 'preview-audio.wav'
 const CACHE_WAVEFORM_MULTI_RES_FILENAME = 'multi-res.bin'
 /** Per-caption thumbnail JPEGs captured alongside LFM caption generation. */
 const CACHE_CAPTION_THUMBS_DIR = 'captions-thumbs'
+/** Per-frame subject mattes; the envelope is the sibling `segmentation.json`. */
+const CACHE_SEGMENTATION_MATTES_DIR = 'segmentation'
 /**
  * Legacy path for transcripts — was `cache/transcript.json` before AI outputs
  * were consolidated under `cache/ai/`. Readers fall back to this on miss; a
@@ -184,6 +193,11 @@ export function mediaSourceByFileName(id: string, fileName: string): string[] {
   return [...mediaDir(id), sanitizeWorkspaceFileName(fileName)]
 }
 
+/** Segments for `media/{id}/source.link.json` — descriptor for media referenced in place. */
+export function mediaSourceLinkPath(id: string): string[] {
+  return [...mediaDir(id), MEDIA_SOURCE_LINK_FILENAME]
+}
+
 /** Never-allowed characters, per NTFS + ext4 intersection. */
 // eslint-disable-next-line no-control-regex -- control chars are exactly what we want to strip
 const INVALID_FILENAME_CHARS = /[<>:"/\\|?*\u0000-\u001F]/g
@@ -311,6 +325,24 @@ export function decodedAudioBinPath(
   return [...decodedAudioDir(mediaId), `${channel}-${binIndex}.bin`]
 }
 
+/** Segments for `media/{id}/cache/optical-flow/`. */
+export function opticalFlowDir(mediaId: string): string[] {
+  return [...mediaCacheDir(mediaId), CACHE_OPTICAL_FLOW_DIR]
+}
+
+/**
+ * Segments for `media/{id}/cache/optical-flow/fps-{milliFps}/{N}.bin` — the
+ * flow field from source frame N to N+1. Frame indices only mean something
+ * on one frame grid, so each source frame rate gets its own folder.
+ */
+export function opticalFlowPairPath(
+  mediaId: string,
+  sourceFps: number,
+  frameIndex: number,
+): string[] {
+  return [...opticalFlowDir(mediaId), `fps-${Math.round(sourceFps * 1000)}`, `${frameIndex}.bin`]
+}
+
 /**
  * Segments for `media/{id}/cache/ai/` — home for AI-derived analysis outputs
  * (transcripts, captions, scene cuts, etc.). One file per `AiOutputKind`.
@@ -337,6 +369,19 @@ export function captionThumbPath(mediaId: string, index: number): string[] {
   return [...captionThumbsDir(mediaId), `${index}.jpg`]
 }
 
+/** Segments for `media/{id}/cache/ai/segmentation/`. */
+export function segmentationMattesDir(mediaId: string): string[] {
+  return [...aiOutputsDir(mediaId), CACHE_SEGMENTATION_MATTES_DIR]
+}
+
+/**
+ * Segments for `media/{id}/cache/ai/segmentation/fps-{milliFps}/{N}.bin` —
+ * the subject matte of frame N on the matte frame grid.
+ */
+export function segmentationMattePath(mediaId: string, fps: number, frameIndex: number): string[] {
+  return [...segmentationMattesDir(mediaId), `fps-${Math.round(fps * 1000)}`, `${frameIndex}.bin`]
+}
+
 /**
  * Segments for `media/{id}/cache/ai/captions-embeddings.bin`. Stored as a
  * contiguous `Float32Array` so 384-dim * N-caption embeddings stay compact
@@ -13,7 +13,7 @@
 import type { SceneCut } from '@/infrastructure/analysis/scene-detection'
 import { createLogger } from '@/shared/logging/logger'
 
-import { writeAiOutput, deleteAiOutput } from './ai-outputs'
+import { readAiOutput, writeAiOutput, deleteAiOutput } from './ai-outputs'
 import type { ScenesPayload, SceneCutPayload } from './ai-outputs'
 
 const logger = createLogger('WorkspaceFS:Scenes')
@@ -43,6 +43,29 @@ function cutsToPayload(cuts: SceneCut[]): SceneCutPayload[] {
   }))
 }
 
+export async function getScenes(mediaId: string): Promise<SavedScenes | undefined> {
+  try {
+    const envelope = await readAiOutput(mediaId, 'scenes')
+    if (!envelope) return undefined
+    const { data } = envelope
+    return {
+      method: data.method,
+      sampleIntervalMs: data.sampleIntervalMs,
+      verificationModel: data.verificationModel,
+      fps: data.fps,
+      cuts: data.cuts.map((cut) => ({
+        frame: cut.frame,
+        time: cut.time,
+        motion: cut.motion as SceneCut['motion'],
+        verified: cut.verified,
+      })),
+    }
+  } catch (error) {
+    logger.error(`getScenes(${mediaId}) failed`, error)
+    throw new Error(`Failed to load scenes: ${mediaId}`)
+  }
+}
+
 export async function saveScenes(input: SaveScenesInput): Promise<SavedScenes> {
   try {
     const payload: ScenesPayload = {
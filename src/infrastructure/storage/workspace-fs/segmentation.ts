@@ -0,0 +1,130 @@
+/**
+ * Per-media subject mattes for background removal.
+ *
+ *   media/{mediaId}/cache/ai/segmentation.json              (AiOutput envelope)
+ *   media/{mediaId}/cache/ai/segmentation/fps-{milliFps}/{N}.bin
+ *
+ * Each matte file holds a `Uint32` width/height header followed by one
+ * coverage byte per pixel. The envelope records the model, matte grid and
+ * analyzed ranges; a model or grid change invalidates the mattes.
+ */
+
+import type { SegmentationMatte } from '@/infrastructure/analysis/segmentation/types'
+import { createLogger } from '@/shared/logging/logger'
+
+import { readAiOutput, writeAiOutput, deleteAiOutput } from './ai-outputs'
+import type { SegmentationPayload } from './ai-outputs'
+import { requireWorkspaceRoot } from './root'
+import { readArrayBuffer, removeEntry, writeBlob } from './fs-primitives'
+import { segmentationMattePath, segmentationMattesDir } from './paths'
+
+const logger = createLogger('WorkspaceFS:Segmentation')
+
+const SEGMENTATION_SERVICE = 'subject-segmentation'
+const HEADER_BYTES = 8
+
+type SegmentationChangeListener = (mediaId: string) => void
+
+const changeListeners = new Set<SegmentationChangeListener>()
+
+/**
+ * Notified after a media's segmentation is saved or deleted, so render-side
+ * caches can drop the envelope they memoized. Returns an unsubscribe.
+ */
+export function subscribeSegmentationChanges(listener: SegmentationChangeListener): () => void {
+  changeListeners.add(listener)
+  return () => {
+    changeListeners.delete(listener)
+  }
+}
+
+function notifySegmentationChanged(mediaId: string): void {
+  for (const listener of changeListeners) listener(mediaId)
+}
+
+export interface SegmentationRecord {
+  model: string
+  data: SegmentationPayload
+}
+
+export async function getSegmentation(mediaId: string): Promise<SegmentationRecord | undefined> {
+  try {
+    const envelope = await readAiOutput(mediaId, 'segmentation')
+    return envelope ? { model: envelope.model, data: envelope.data } : undefined
+  } catch (error) {
+    logger.error(`getSegmentation(${mediaId}) failed`, error)
+    throw new Error(`Failed to load segmentation: ${mediaId}`)
+  }
+}
+
+export async function saveSegmentation(input: {
+  mediaId: string
+  model: string
+  data: SegmentationPayload
+}): Promise<void> {
+  try {
+    await writeAiOutput({
+      mediaId: input.mediaId,
+      kind: 'segmentation',
+      service: SEGMENTATION_SERVICE,
+      model: input.model,
+      params: { fps: input.data.fps, width: input.data.width, height: input.data.height },
+      data: input.data,
+    })
+    notifySegmentationChanged(input.mediaId)
+  } catch (error) {
+    logger.error(`saveSegmentation(${input.mediaId}) failed`, error)
+    throw new Error(`Failed to save segmentation: ${input.mediaId}`)
+  }
+}
+
+export async function getSegmentationMatte(
+  mediaId: string,
+  fps: number,
+  frameIndex: number,
+): Promise<SegmentationMatte | undefined> {
+  const root = requireWorkspaceRoot()
+  try {
+    const buffer = await readArrayBuffer(root, segmentationMattePath(mediaId, fps, frameIndex))
+    if (!buffer || buffer.byteLength < HEADER_BYTES) return undefined
+
+    const [width, height] = new Uint32Array(buffer, 0, 2)
+    const data = new Uint8Array(buffer, HEADER_BYTES)
+    if (!width || !height || data.length !== width * height) return undefined
+    return { width, height, data }
+  } catch (error) {
+    logger.warn(`getSegmentationMatte(${mediaId}, ${frameIndex}) failed`, error)
+    return undefined
+  }
+}
+
+export async function saveSegmentationMatte(
+  mediaId: string,
+  fps: number,
+  frameIndex: number,
+  matte: SegmentationMatte,
+): Promise<void> {
+  const root = requireWorkspaceRoot()
+  try {
+    const bytes = new Uint8Array(HEADER_BYTES + matte.data.byteLength)
+    new Uint32Array(bytes.buffer, 0, 2).set([matte.width, matte.height])
+    bytes.set(matte.data, HEADER_BYTES)
+    await writeBlob(root, segmentationMattePath(mediaId, fps, frameIndex), bytes)
+  } catch (error) {
+    logger.error(`saveSegmentationMatte(${mediaId}, ${frameIndex}) failed`, error)
+    throw new Error(`Failed to save segmentation matte: ${mediaId}`)
+  }
+}
+
+/** Drop the envelope and every matte, e.g. before re-analyzing with a new model. */
+export async function deleteSegmentation(mediaId: string): Promise<void> {
+  const root = requireWorkspaceRoot()
+  try {
+    await removeEntry(root, segmentationMattesDir(mediaId), { recursive: true })
+    await deleteAiOutput(mediaId, 'segmentation')
+    notifySegmentationChanged(mediaId)
+  } catch (error) {
+    logger.error(`deleteSegmentation(${mediaId}) failed`, error)
+    throw new Error(`Failed to delete segmentation: ${mediaId}`)
+  }
+}
@@ -0,0 +1,53 @@
+/**
+ * Per-media stabilization analysis.
+ *
+ * Stored at `media/{mediaId}/cache/ai/stabilization.json` as an
+ * {@link AiOutput} envelope. Camera motion is a property of the source media,
+ * so re-stabilizing a trimmed or split clip reuses the cached path as long as
+ * the analyzed range covers the clip.
+ */
+
+import type { CameraPath } from '@/infrastructure/analysis/stabilization'
+import { createLogger } from '@/shared/logging/logger'
+
+import { readAiOutput, writeAiOutput } from './ai-outputs'
+import type { StabilizationPayload } from './ai-outputs'
+
+const logger = createLogger('WorkspaceFS:Stabilization')
+
+const STABILIZATION_SERVICE = 'stabilization-optical-flow'
+const STABILIZATION_MODEL = 'pyramid-lucas-kanade'
+
+export async function getStabilization(mediaId: string): Promise<StabilizationPayload | undefined> {
+  try {
+    const envelope = await readAiOutput(mediaId, 'stabilization')
+    return envelope?.data
+  } catch (error) {
+    logger.error(`getStabilization(${mediaId}) failed`, error)
+    throw new Error(`Failed to load stabilization: ${mediaId}`)
+  }
+}
+
+export async function saveStabilization(input: {
+  mediaId: string
+  path: CameraPath
+  endTime: number
+}): Promise<void> {
+  try {
+    await writeAiOutput({
+      mediaId: input.mediaId,
+      kind: 'stabilization',
+      service: STABILIZATION_SERVICE,
+      model: STABILIZATION_MODEL,
+      params: {
+        fps: input.path.fps,
+        startTime: input.path.startTime,
+        endTime: input.endTime,
+      },
+      data: { endTime: input.endTime, path: input.path },
+    })
+  } catch (error) {
+    logger.error(`saveStabilization(${input.mediaId}) failed`, error)
+    throw new Error(`Failed to save stabilization: ${input.mediaId}`)
+  }
+}
@@ -0,0 +1,59 @@
+/**
+ * Per-media subject paths for smart reframe.
+ *
+ * Stored at `media/{mediaId}/cache/ai/subjects.json` as an {@link AiOutput}
+ * envelope. The subject's position is a property of the source media, so
+ * reframing another project (or re-running a reframe) reuses the cached path
+ * as long as it was made by the same detector and covers the clips.
+ */
+
+import type { SubjectPath } from '@/infrastructure/analysis/reframe'
+import { createLogger } from '@/shared/logging/logger'
+
+import { readAiOutput, writeAiOutput } from './ai-outputs'
+import type { SubjectsPayload } from './ai-outputs'
+
+const logger = createLogger('WorkspaceFS:Subjects')
+
+const SUBJECTS_SERVICE = 'smart-reframe'
+
+export interface SubjectsRecord {
+  /** Detector that produced the path */
+  model: string
+  data: SubjectsPayload
+}
+
+export async function getSubjects(mediaId: string): Promise<SubjectsRecord | undefined> {
+  try {
+    const envelope = await readAiOutput(mediaId, 'subjects')
+    return envelope ? { model: envelope.model, data: envelope.data } : undefined
+  } catch (error) {
+    logger.error(`getSubjects(${mediaId}) failed`, error)
+    throw new Error(`Failed to load subjects: ${mediaId}`)
+  }
+}
+
+export async function saveSubjects(input: {
+  mediaId: string
+  model: string
+  path: SubjectPath
+  endTime: number
+}): Promise<void> {
+  try {
+    await writeAiOutput({
+      mediaId: input.mediaId,
+      kind: 'subjects',
+      service: SUBJECTS_SERVICE,
+      model: input.model,
+      params: {
+        fps: input.path.fps,
+        startTime: input.path.startTime,
+        endTime: input.endTime,
+      },
+      data: { endTime: input.endTime, path: input.path },
+    })
+  } catch (error) {
+    logger.error(`saveSubjects(${input.mediaId}) failed`, error)
+    throw new Error(`Failed to save subjects: ${input.mediaId}`)
+  }
+}
@@ -36,6 +36,19 @@ describe('workspace-fs transcripts', () => {
     expect(t!.segments[0]!.text).toBe('hello')
   })
 
+  it('round-trips diarized speakers and segment speaker ids', async () => {
+    const root = createRoot()
+    setWorkspaceRoot(asHandle(root))
+    await saveTranscript({
+      ...makeTranscript('m1'),
+      segments: [{ start: 0, end: 1, text: 'hello', speakerId: 'speaker-1' }],
+      speakers: [{ id: 'speaker-1', name: 'Host', color: '#facc15' }],
+    })
+    const t = await getTranscript('m1')
+    expect(t!.segments[0]!.speakerId).toBe('speaker-1')
+    expect(t!.speakers).toEqual([{ id: 'speaker-1', name: 'Host', color: '#facc15' }])
+  })
+
   it('getTranscript returns undefined when missing', async () => {
     const root = createRoot()
     setWorkspaceRoot(asHandle(root))
@@ -31,6 +31,11 @@ import type { Project } from '@/types/project'
 import type { ProjectFormData } from '@/features/projects/utils/validation'
 import type { ImportProgress } from '@/features/project-bundle/types/bundle'
 import { BUNDLE_EXTENSION } from '@/features/project-bundle/types/bundle'
+import {
+  INTERCHANGE_EXTENSIONS,
+  type InterchangeFormat,
+} from '@/features/project-bundle/types/interchange'
+import { detectInterchangeFormat } from '@/features/project-bundle/services/interchange-media'
 import { LegacyMigrationBanner } from '@/features/projects/components/legacy-migration-banner'
 import { LegacyMigrationErrors } from '@/features/projects/components/legacy-migration-errors'
 import { TrashSection } from '@/features/projects/components/trash-section'
@@ -113,9 +118,21 @@ function ProjectsIndex() {
     // Reset file input for next selection
     event.target.value = ''
 
+    // Timeline interchange files carry no media, so they import straight into
+    // the workspace without the destination-folder step.
+    const interchangeFormat = detectInterchangeFormat(file.name)
+    if (interchangeFormat) {
+      void handleInterchangeImport(file, interchangeFormat)
+      return
+    }
+
     // Validate file extension (handles browser-renamed files like "project.freecut (1).zip")
     if (!isValidBundleFile(file.name)) {
-      setImportError(t('projects.import.invalidFile', { extension: BUNDLE_EXTENSION }))
+      setImportError(
+        t('projects.import.invalidFile', {
+          extension: [BUNDLE_EXTENSION, ...Object.values(INTERCHANGE_EXTENSIONS)].join(', '),
+        }),
+      )
       setImportDialogOpen(true)
       return
     }
@@ -131,6 +148,24 @@ function ProjectsIndex() {
     setImportDialogOpen(true)
   }
 
+  const handleInterchangeImport = async (file: File, format: InterchangeFormat) => {
+    try {
+      const { importProjectFromInterchange } =
+        await import('@/features/project-bundle/services/interchange-service')
+      const { project, warnings } = await importProjectFromInterchange(format, await file.text())
+      if (warnings.length > 0) {
+        logger.warn(`Imported ${file.name} with ${warnings.length} warning(s)`, warnings)
+        toast.warning(t('projects.import.interchangeWarnings', { count: warnings.length }))
+      }
+      await loadProjects()
+      navigate({ to: '/editor/$projectId', params: { projectId: project.id } })
+    } catch (err) {
+      logger.error('Interchange import failed:', err)
+      setImportError(err instanceof Error ? err.message : t('projects.import.importFailed'))
+      setImportDialogOpen(true)
+    }
+  }
+
   // Step 2: User clicks to select destination folder (fresh user gesture!)
   const handleSelectDestination = async () => {
     try {
@@ -304,7 +339,7 @@ function ProjectsIndex() {
             <input
               ref={fileInputRef}
               type="file"
-              accept=".zip"
+              accept={['.zip', ...Object.values(INTERCHANGE_EXTENSIONS)].join(',')}
               onChange={handleFileSelect}
               className="hidden"
             />
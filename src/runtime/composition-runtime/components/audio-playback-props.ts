@@ -1,5 +1,6 @@
 import type { AudioClipFadeSpan } from '@/shared/utils/audio-fade-curve'
 import type { ResolvedAudioEqSettings } from '@/types/audio'
+import type { TimeRemapCurve } from '@/runtime/composition-runtime/deps/keyframes'
 
 /**
  * Shared clip-level playback controls used by all preview audio backends.
@@ -10,8 +11,13 @@ export interface AudioPlaybackProps {
   liveGainItemIds?: string[]
   trimBefore?: number
   sourceFps?: number
+  /** Item + track gain in dB. */
   volume?: number
+  /** Track gain folded into `volume`; kept on top of animated item volume. */
+  trackVolumeDb?: number
   playbackRate?: number
+  /** Keyframed time remap; replaces `playbackRate`/`isReversed` timing when set. */
+  timeRemapCurve?: TimeRemapCurve
   isReversed?: boolean
   reverseSourceEnd?: number
   muted?: boolean
@@ -5,11 +5,15 @@ import { NativePitchCorrectedAudio } from './pitch-corrected-audio'
 import type { AudioPlaybackProps } from './audio-playback-props'
 import { getOrDecodeAudio, getOrDecodeAudioSliceForPlayback } from '../utils/audio-decode-cache'
 import { audioBufferToWavBlob } from '../utils/audio-buffer-wav'
-import { createReversedAudioBuffer } from '../utils/audio-buffer-utils'
+import {
+  createReversedAudioBuffer,
+  createTimeRemappedAudioBuffer,
+} from '../utils/audio-buffer-utils'
 import { createLogger } from '@/shared/logging/logger'
 import { getAudioTargetTimeSeconds } from '../utils/video-timing'
 import { useAudioPlaybackState } from './hooks/use-audio-playback-state'
 import { useGizmoStore } from '@/runtime/composition-runtime/deps/stores'
+import { sampleTimeRemap } from '@/runtime/composition-runtime/deps/keyframes'
 import {
   hasAudioPitchOverride,
   isAudioPitchShiftActive,
@@ -49,6 +53,7 @@ const DecodedPitchFallbackAudio: React.FC<DecodedPitchFallbackAudioProps> = ({
   trimBefore,
   sourceFps,
   volume,
+  trackVolumeDb,
   playbackRate,
   isReversed,
   reverseSourceEnd,
@@ -96,6 +101,7 @@ const DecodedPitchFallbackAudio: React.FC<DecodedPitchFallbackAudioProps> = ({
       sourceFps={sourceFps}
       sourceStartOffsetSec={sourceStartOffsetSec}
       volume={volume}
+      trackVolumeDb={trackVolumeDb}
       playbackRate={playbackRate}
       isReversed={isReversed}
       reverseSourceEnd={reverseSourceEnd}
@@ -157,7 +163,9 @@ const CustomDecoderPitchPreservedAudio: React.FC<CustomDecoderAudioProps> = ({
   trimBefore = 0,
   sourceFps,
   volume = 0,
+  trackVolumeDb,
   playbackRate = 1,
+  timeRemapCurve,
   isReversed,
   reverseSourceEnd,
   muted = false,
@@ -185,6 +193,7 @@ const CustomDecoderPitchPreservedAudio: React.FC<CustomDecoderAudioProps> = ({
     itemId,
     liveGainItemIds,
     volume,
+    trackVolumeDb,
     muted,
     durationInFrames,
     audioFadeIn,
@@ -208,6 +217,7 @@ const CustomDecoderPitchPreservedAudio: React.FC<CustomDecoderAudioProps> = ({
   })
   const [decodedSource, setDecodedSource] = useState<DecodedPitchSource | null>(null)
   const pendingExtensionKeyRef = useRef<string | null>(null)
+  const isTimeRemapped = timeRemapCurve !== undefined
 
   useEffect(() => {
     if (!mediaId || !src) return
@@ -264,6 +274,13 @@ const CustomDecoderPitchPreservedAudio: React.FC<CustomDecoderAudioProps> = ({
     }
     setDecodedSource(null)
     pendingExtensionKeyRef.current = null
+    // A remap can reach anywhere in the source, so render it from the full decode.
+    if (isTimeRemapped) {
+      startFullDecode()
+      return () => {
+        cancelled = true
+      }
+    }
     scheduleFullDecode(BACKGROUND_FULL_DECODE_BACKSTOP_MS)
 
     getOrDecodeAudioSliceForPlayback(mediaId, src, {
@@ -305,11 +322,11 @@ const CustomDecoderPitchPreservedAudio: React.FC<CustomDecoderAudioProps> = ({
       cancelled = true
       clearScheduledFullDecode()
     }
-  }, [isReversed, mediaId, reverseSourceEnd, sourceFps, src, trimBefore])
+  }, [isReversed, isTimeRemapped, mediaId, reverseSourceEnd, sourceFps, src, trimBefore])
 
   useEffect(() => {
     const currentSource = decodedSource
-    if (!currentSource || currentSource.isComplete || !playing) {
+    if (!currentSource || currentSource.isComplete || !playing || isTimeRemapped) {
       pendingExtensionKeyRef.current = null
       return
     }
@@ -385,6 +402,7 @@ const CustomDecoderPitchPreservedAudio: React.FC<CustomDecoderAudioProps> = ({
     fps,
     frame,
     isReversed,
+    isTimeRemapped,
     mediaId,
     playbackRate,
     playing,
@@ -394,8 +412,24 @@ const CustomDecoderPitchPreservedAudio: React.FC<CustomDecoderAudioProps> = ({
     trimBefore,
   ])
 
+  const remappedPlayback = React.useMemo(() => {
+    if (!decodedSource || !timeRemapCurve || !decodedSource.isComplete) {
+      return null
+    }
+    // Segment frames run from the transition handle before the item, so shift
+    // them back to item-relative frames before sampling the curve.
+    const startOffset = contentStartOffsetFrames ?? 0
+    const frameSourceSeconds = Array.from({ length: durationInFrames + 1 }, (_, frame) =>
+      sampleTimeRemap(timeRemapCurve, frame - startOffset),
+    )
+    return {
+      buffer: createTimeRemappedAudioBuffer(decodedSource.buffer, frameSourceSeconds, fps),
+      trimBefore: 0,
+    }
+  }, [contentStartOffsetFrames, decodedSource, durationInFrames, fps, timeRemapCurve])
+
   const reversedPlayback = React.useMemo(() => {
-    if (!decodedSource || !isReversed || !decodedSource.isComplete) {
+    if (!decodedSource || !isReversed || timeRemapCurve || !decodedSource.isComplete) {
       return null
     }
     const effectiveSourceFps = sourceFps ?? fps
@@ -408,13 +442,18 @@ const CustomDecoderPitchPreservedAudio: React.FC<CustomDecoderAudioProps> = ({
       buffer: createReversedAudioBuffer(decodedSource.buffer),
       trimBefore: reversedTrimBefore,
     }
-  }, [decodedSource, fps, isReversed, reverseSourceEnd, sourceFps, trimBefore])
+  }, [decodedSource, fps, isReversed, reverseSourceEnd, sourceFps, timeRemapCurve, trimBefore])
 
-  if (!decodedSource) return null
+  if (!decodedSource || (isTimeRemapped && !remappedPlayback)) return null
 
-  const playbackBuffer = reversedPlayback?.buffer ?? decodedSource.buffer
-  const playbackTrimBefore = reversedPlayback?.trimBefore ?? trimBefore
-  const playbackSourceStartOffsetSec = reversedPlayback ? 0 : decodedSource.sourceStartOffsetSec
+  // The remapped buffer already bakes in speed and direction, so it plays
+  // forwards at rate 1 from its first sample.
+  const renderedPlayback = remappedPlayback ?? reversedPlayback
+  const playbackBuffer = renderedPlayback?.buffer ?? decodedSource.buffer
+  const playbackTrimBefore = renderedPlayback?.trimBefore ?? trimBefore
+  const playbackSourceStartOffsetSec = renderedPlayback ? 0 : decodedSource.sourceStartOffsetSec
+  const playbackRateForBuffer = remappedPlayback ? 1 : playbackRate
+  const playbackIsReversed = isReversed === true && !renderedPlayback
 
   const fallback = (
     <DecodedPitchFallbackAudio
@@ -425,9 +464,10 @@ const CustomDecoderPitchPreservedAudio: React.FC<CustomDecoderAudioProps> = ({
       trimBefore={playbackTrimBefore}
       sourceFps={sourceFps}
       volume={volume}
-      playbackRate={playbackRate}
-      isReversed={isReversed && !reversedPlayback}
-      reverseSourceEnd={reversedPlayback ? undefined : reverseSourceEnd}
+      trackVolumeDb={trackVolumeDb}
+      playbackRate={playbackRateForBuffer}
+      isReversed={playbackIsReversed}
+      reverseSourceEnd={renderedPlayback ? undefined : reverseSourceEnd}
       audioPitchSemitones={audioPitchSemitones}
       audioPitchCents={audioPitchCents}
       audioPitchShiftSemitones={audioPitchShiftSemitones}
@@ -462,9 +502,10 @@ const CustomDecoderPitchPreservedAudio: React.FC<CustomDecoderAudioProps> = ({
       sourceStartOffsetSec={playbackSourceStartOffsetSec}
       isComplete={decodedSource.isComplete}
       volume={volume}
-      playbackRate={playbackRate}
-      isReversed={isReversed && !reversedPlayback}
-      reverseSourceEnd={reversedPlayback ? undefined : reverseSourceEnd}
+      trackVolumeDb={trackVolumeDb}
+      playbackRate={playbackRateForBuffer}
+      isReversed={playbackIsReversed}
+      reverseSourceEnd={renderedPlayback ? undefined : reverseSourceEnd}
       audioPitchSemitones={audioPitchSemitones}
       audioPitchCents={audioPitchCents}
       audioPitchShiftSemitones={audioPitchShiftSemitones}
@@ -497,6 +538,8 @@ const CustomDecoderPitchPreservedAudio: React.FC<CustomDecoderAudioProps> = ({
  *   the fastest startup and scrubbing response.
  * - playbackRate !== 1: use a local SoundTouch worklet path directly from
  *   decoded AudioBuffers, avoiding WAV/object-URL round-trips before preview.
+ * - time remap: render the curve offline from the full decode and play the
+ *   result through the same worklet path at rate 1.
  */
 export const CustomDecoderAudio: React.FC<CustomDecoderAudioProps> = React.memo((props) => {
   const playbackRate = props.playbackRate ?? 1
@@ -516,6 +559,7 @@ export const CustomDecoderAudio: React.FC<CustomDecoderAudioProps> = React.memo(
   const hasActivePitchPreview = hasAudioPitchOverride(itemPreview?.properties)
   const shouldUseBufferedPlayback =
     props.isReversed !== true &&
+    props.timeRemapCurve === undefined &&
     Math.abs(playbackRate - 1) <= 0.0001 &&
     !hasActivePitchPreview &&
     !isAudioPitchShiftActive(resolvedPitchShiftSemitones)
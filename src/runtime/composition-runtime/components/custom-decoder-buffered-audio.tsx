@@ -106,6 +106,7 @@ export const CustomDecoderBufferedAudio: React.FC<CustomDecoderBufferedAudioProp
     trimBefore = 0,
     sourceFps,
     volume = 0,
+    trackVolumeDb,
     playbackRate = 1,
     muted = false,
     durationInFrames,
@@ -136,6 +137,7 @@ export const CustomDecoderBufferedAudio: React.FC<CustomDecoderBufferedAudioProp
       itemId,
       liveGainItemIds,
       volume,
+      trackVolumeDb,
       muted,
       durationInFrames,
       audioFadeIn,
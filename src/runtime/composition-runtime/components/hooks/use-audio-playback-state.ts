@@ -29,6 +29,7 @@ export function useAudioPlaybackState({
   itemId,
   liveGainItemIds,
   volume = 0,
+  trackVolumeDb = 0,
   muted = false,
   durationInFrames,
   audioFadeIn = 0,
@@ -73,9 +74,15 @@ export function useAudioPlaybackState({
 
   const volumeKeyframes = getPropertyKeyframes(itemKeyframes, 'volume')
   const staticVolumeDb = preview?.volume ?? volume
+  // Keyframes are item-relative and animate the item's own volume; transition
+  // handles shift the segment start and the track gain still applies on top.
   const effectiveVolumeDb =
     volumeKeyframes.length > 0
-      ? interpolatePropertyValue(volumeKeyframes, frame, staticVolumeDb)
+      ? interpolatePropertyValue(
+          volumeKeyframes,
+          frame - contentStartOffsetFrames,
+          staticVolumeDb - trackVolumeDb,
+        ) + trackVolumeDb
       : staticVolumeDb
 
   const clipFadeMultiplier = clipFadeSpans
@@ -6,7 +6,8 @@ import {
   type ItemPropertiesPreview,
 } from '@/runtime/composition-runtime/deps/stores'
 import { useMaskEditorStore } from '@/runtime/composition-runtime/deps/stores'
-import type { TimelineItem } from '@/types/timeline'
+import { resolveAnimatedMaskShape } from '@/runtime/composition-runtime/deps/keyframes'
+import type { TimelineItem, TimelineItemCornerPin } from '@/types/timeline'
 import type { ResolvedTransform, CanvasSettings, CropSettings } from '@/types/transform'
 import { toTransformStyle, getSourceDimensions } from '../../utils/transform-resolver'
 import { getShapePath, rotatePath } from '../../utils/shape-path'
@@ -23,6 +24,7 @@ import {
 import { applyPreviewPathVerticesToShape } from '../../utils/preview-path-override'
 import { expandTextTransformToFitContent } from '../../utils/text-layout'
 import {
+  resolveAnimatedCornerPin,
   resolveAnimatedCrop,
   resolveAnimatedTextItem,
 } from '@/runtime/composition-runtime/deps/keyframes'
@@ -42,6 +44,8 @@ interface ItemVisualState {
   finalOpacity: number
   /** Source-relative crop after keyframe interpolation */
   animatedCrop: CropSettings | undefined
+  /** Corner pin after keyframe interpolation (e.g. planar track output) */
+  animatedCornerPin: TimelineItemCornerPin | undefined
 
   /** Combined CSS filter string (legacy — always empty, effects render via GPU) */
   cssFilter: string
@@ -144,6 +148,12 @@ export function useItemVisualState(
     ),
   )
 
+  // Corner pin offsets can be keyframed (e.g. by a planar track)
+  const animatedCornerPin = useMemo(
+    () => resolveAnimatedCornerPin(item.cornerPin, itemKeyframes ?? undefined, visualFrame),
+    [item.cornerPin, itemKeyframes, visualFrame],
+  )
+
   // === TRANSFORM COMPUTATION ===
   const { transform, transformStyle, fadeOpacity, finalOpacity, animatedCrop } = useMemo(() => {
     // Check if this item has an active single-item gizmo preview
@@ -174,7 +184,7 @@ export function useItemVisualState(
       resolved = applyTransformOverride(animatedResolved, previewTransform)
     }
 
-    if (item.type === 'text' && !hasCornerPin(item.cornerPin)) {
+    if (item.type === 'text' && !hasCornerPin(animatedCornerPin)) {
       resolved = expandTextTransformToFitContent(
         resolveAnimatedTextItem(item, itemKeyframes ?? undefined, visualFrame, logicalCanvas),
         resolved,
@@ -264,6 +274,7 @@ export function useItemVisualState(
     previewTransform,
     itemPreview,
     item,
+    animatedCornerPin,
     logicalCanvas,
     renderCanvas,
     itemKeyframes,
@@ -286,6 +297,9 @@ export function useItemVisualState(
   }, [])
 
   // === MASK COMPUTATION ===
+  // Only animated mask paths need the mask recomputed as the playhead moves.
+  const hasAnimatedMaskPath = !!masks?.some(({ shape }) => !!shape.pathKeyframes?.length)
+  const maskTimelineFrame = hasAnimatedMaskPath ? item.from + visualFrame : 0
   const maskState = useMemo(() => {
     if (!masks || masks.length === 0) {
       return {
@@ -339,7 +353,10 @@ export function useItemVisualState(
         width: resolvedMaskTransform.width * scaleX,
         height: resolvedMaskTransform.height * scaleY,
       }
-      const effectiveShape = applyPreviewPathVerticesToShape(shape, getPreviewPathVertices)
+      const effectiveShape = applyPreviewPathVerticesToShape(
+        resolveAnimatedMaskShape(shape, maskTimelineFrame - shape.from),
+        getPreviewPathVertices,
+      )
 
       let path = getShapePath(effectiveShape, scaledMaskTransform, {
         canvasWidth: renderWidth,
@@ -406,6 +423,7 @@ export function useItemVisualState(
     }
   }, [
     masks,
+    maskTimelineFrame,
     activeGizmo,
     previewTransform,
     projectWidth,
@@ -428,6 +446,7 @@ export function useItemVisualState(
     fadeOpacity,
     finalOpacity,
     animatedCrop,
+    animatedCornerPin,
     cssFilter,
     scanlinesEffect,
     halftoneStyles,
@@ -35,6 +35,11 @@ import {
 import { needsCustomAudioDecoder } from '../utils/audio-codec-detection'
 import { resolveReverseConformedVideoItem } from '@/shared/utils/reverse-conform-item'
 import { useNestedMediaResolutionMode } from '../contexts/nested-media-resolution-context'
+import { useRuntimeItemKeyframes } from './hooks/use-runtime-item-keyframes'
+import {
+  resolveTimeRemapCurve,
+  type TimeRemapCurve,
+} from '@/runtime/composition-runtime/deps/keyframes'
 
 function getLogger() {
   return createLogger('CompositionItem')
@@ -85,6 +90,7 @@ function getItemAudioPlaybackProps({
   item,
   trimBefore,
   volume,
+  trackVolumeDb,
   playbackRate,
   isReversed,
   reverseSourceEnd,
@@ -94,10 +100,12 @@ function getItemAudioPlaybackProps({
   audioEqStages,
   liveGainItemIds,
   volumeMultiplier,
+  timeRemapCurve,
 }: {
   item: TimelineItem
   trimBefore: number
   volume: number
+  trackVolumeDb: number
   playbackRate: number
   isReversed: boolean
   reverseSourceEnd: number
@@ -107,12 +115,15 @@ function getItemAudioPlaybackProps({
   audioEqStages: ResolvedAudioEqSettings[]
   liveGainItemIds?: string[]
   volumeMultiplier: number
+  timeRemapCurve?: TimeRemapCurve
 }): AudioPlaybackProps {
   return {
     itemId: item.id,
     trimBefore,
     volume,
+    trackVolumeDb,
     playbackRate,
+    timeRemapCurve,
     isReversed,
     reverseSourceEnd,
     audioPitchSemitones: item.audioPitchSemitones,
@@ -194,11 +205,20 @@ export const ItemContent = React.memo<ItemProps>(
         }),
       [item.audioPitchSemitones, item.audioPitchCents, itemPreviewProperties],
     )
+    const itemKeyframes = useRuntimeItemKeyframes(item.id)
+    const timeRemapCurve = React.useMemo(
+      () => resolveTimeRemapCurve(item, itemKeyframes, timelineFps) ?? undefined,
+      [item, itemKeyframes, timelineFps],
+    )
 
     if (item.type === 'video') {
-      item = resolveReverseConformedVideoItem(item, timelineFps, {
-        useProxy: nestedMediaResolutionMode === 'proxy',
-      })
+      // The remap curve addresses the original source, so remapped clips skip
+      // the reverse conform (which only covers constant-speed playback).
+      if (!timeRemapCurve) {
+        item = resolveReverseConformedVideoItem(item, timelineFps, {
+          useProxy: nestedMediaResolutionMode === 'proxy',
+        })
+      }
       const mediaSource = getSourceDimensions(item)
       // Guard against missing src (media resolution failed)
       if (!item.src) {
@@ -333,8 +353,11 @@ export const ItemContent = React.memo<ItemProps>(
       const requiresPitchShiftedVideoAudio = isAudioPitchShiftActive(
         audioPitchShiftSemitones + itemLocalPitchShiftSemitones,
       )
+      // Remapped audio is rendered offline from the full decode.
       const shouldUseCustomDecodedVideoAudio =
-        !muted && needsCustomAudioDecoder(mediaItem?.audioCodec ?? mediaItem?.codec)
+        !muted &&
+        (timeRemapCurve !== undefined ||
+          needsCustomAudioDecoder(mediaItem?.audioCodec ?? mediaItem?.codec))
       const shouldRenderExternalVideoAudio =
         !muted &&
         !!videoAudioSrc &&
@@ -343,6 +366,7 @@ export const ItemContent = React.memo<ItemProps>(
         item,
         trimBefore: safeTrimBefore,
         volume: (item.volume ?? 0) + trackVolumeDb,
+        trackVolumeDb,
         playbackRate,
         isReversed,
         reverseSourceEnd,
@@ -352,6 +376,7 @@ export const ItemContent = React.memo<ItemProps>(
         audioEqStages: itemAudioEqStages,
         liveGainItemIds: audioGainLiveItemIds,
         volumeMultiplier: audioGainMultiplier,
+        timeRemapCurve,
       })
       const externalVideoAudio = shouldRenderExternalVideoAudio ? (
         shouldUseCustomDecodedVideoAudio ? (
@@ -379,6 +404,7 @@ export const ItemContent = React.memo<ItemProps>(
             sourceFps={sourceFps}
             isReversed={isReversed}
             reverseSourceEnd={reverseSourceEnd}
+            timeRemapCurve={timeRemapCurve}
             audioEqStages={itemAudioEqStages}
             forceCssComposite={masks.length > 0}
           />
@@ -463,6 +489,7 @@ export const ItemContent = React.memo<ItemProps>(
         item,
         trimBefore,
         volume: (item.volume ?? 0) + trackVolumeDb,
+        trackVolumeDb,
         playbackRate,
         isReversed: item.isReversed === true,
         reverseSourceEnd,
@@ -472,8 +499,19 @@ export const ItemContent = React.memo<ItemProps>(
         audioEqStages: itemAudioEqStages,
         liveGainItemIds: audioGainLiveItemIds,
         volumeMultiplier: audioGainMultiplier,
+        timeRemapCurve,
       })
 
+      if (timeRemapCurve) {
+        return (
+          <CustomDecoderAudio
+            {...audioPlaybackProps}
+            src={item.src}
+            mediaId={item.mediaId ?? `legacy-src:${item.src}`}
+          />
+        )
+      }
+
       // Use PitchCorrectedAudio for pitch-preserved playback during preview
       // and toneFrequency correction during rendering
       return <PitchCorrectedAudio {...audioPlaybackProps} src={item.src} mediaId={item.mediaId} />
@@ -346,7 +346,7 @@ export const ItemVisualWrapper: React.FC<ItemVisualWrapperProps> = ({
   const cornerPinPreview = useCornerPinStore((s) =>
     s.editingItemId === item.id ? s.previewCornerPin : null,
   )
-  const effectiveCornerPin = cornerPinPreview ?? item.cornerPin
+  const effectiveCornerPin = cornerPinPreview ?? state.animatedCornerPin
   const effectiveCrop = state.propertiesPreview?.crop ?? state.animatedCrop ?? mediaContent?.crop
   const cornerPinTargetRect = useMemo(() => {
     if (state.maskType !== null) {
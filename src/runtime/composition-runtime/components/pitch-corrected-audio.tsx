@@ -111,6 +111,7 @@ export const NativePitchCorrectedAudio: React.FC<PitchCorrectedAudioProps> = Rea
     src,
     itemId,
     volume = 0,
+    trackVolumeDb,
     playbackRate = 1,
     isReversed = false,
     reverseSourceEnd,
@@ -146,6 +147,7 @@ export const NativePitchCorrectedAudio: React.FC<PitchCorrectedAudioProps> = Rea
       itemId,
       liveGainItemIds,
       volume,
+      trackVolumeDb,
       muted,
       durationInFrames,
       audioFadeIn,
@@ -431,6 +433,7 @@ const DecodedPitchCorrectedAudio: React.FC<DecodedPitchCorrectedAudioProps> = Re
       sourceFps,
       sourceStartOffsetSec = 0,
       volume = 0,
+      trackVolumeDb,
       playbackRate = 1,
       isReversed = false,
       reverseSourceEnd,
@@ -458,6 +461,7 @@ const DecodedPitchCorrectedAudio: React.FC<DecodedPitchCorrectedAudioProps> = Re
       itemId,
       liveGainItemIds,
       volume,
+      trackVolumeDb,
       muted,
       durationInFrames,
       audioFadeIn,
@@ -696,6 +700,7 @@ const DecodedPitchCorrectedAudio: React.FC<DecodedPitchCorrectedAudioProps> = Re
         sourceStartOffsetSec={playbackSourceStartOffsetSec}
         isComplete={decodedSource.isComplete}
         volume={volume}
+        trackVolumeDb={trackVolumeDb}
         playbackRate={playbackRate}
         isReversed={isReversed && !reversedPlayback}
         reverseSourceEnd={reversedPlayback ? undefined : reverseSourceEnd}
@@ -36,6 +36,7 @@ export const SoundTouchWorkletAudio: React.FC<SoundTouchWorkletAudioProps> = Rea
     audioBuffer,
     itemId,
     volume = 0,
+    trackVolumeDb,
     playbackRate = 1,
     isReversed = false,
     reverseSourceEnd,
@@ -76,6 +77,7 @@ export const SoundTouchWorkletAudio: React.FC<SoundTouchWorkletAudioProps> = Rea
       itemId,
       liveGainItemIds,
       volume,
+      trackVolumeDb,
       muted,
       durationInFrames,
       audioFadeIn,
@@ -1,7 +1,9 @@
 import React, { useMemo } from 'react'
 
 import { useSequenceContext } from '@/runtime/composition-runtime/deps/player'
+import { resolveKaraokeCaptionFrame } from '@/shared/typography/caption-karaoke'
 import { parseSubtitleCueText } from '@/shared/utils/subtitle-cue-format'
+import { resolveCueSpeakerStyle } from '@/shared/utils/subtitle-speakers'
 import type { SubtitleSegmentItem, TextItem } from '@/types/timeline'
 
 import { useVideoConfig } from '../hooks/use-player-compat'
@@ -36,10 +38,29 @@ export const SubtitleSegmentContent: React.FC<{
     [activeCue],
   )
 
+  // Karaoke swaps the cue text for the current page of words and paints the
+  // spoken word; inline markup spans are dropped since paint is per word.
+  const karaokeFrame = useMemo(
+    () =>
+      item.karaoke && activeCue && parsed
+        ? resolveKaraokeCaptionFrame(
+            { ...activeCue, text: parsed.plainText },
+            secondsIntoSegment,
+            item.karaoke,
+          )
+        : null,
+    [item.karaoke, activeCue, parsed, secondsIntoSegment],
+  )
+
+  const speakerStyle = useMemo(
+    () => resolveCueSpeakerStyle(item.speakerStyles, activeCue?.speakerId),
+    [item.speakerStyles, activeCue?.speakerId],
+  )
+
   // Synthesize an ephemeral TextItem that carries the active cue's text and
-  // the segment's typography. Keyframe/gizmo lookups by id will miss (the
-  // segment isn't a TextItem) — that's fine for now; segment-level keyframes
-  // are a planned follow-up.
+  // the segment's typography, with the cue speaker's overrides on top.
+  // Keyframe/gizmo lookups by id will miss (the segment isn't a TextItem) —
+  // that's fine for now; segment-level keyframes are a planned follow-up.
   const syntheticTextItem = useMemo<TextItem & { _sequenceFrameOffset?: number }>(
     () => ({
       id: item.id,
@@ -50,11 +71,12 @@ export const SubtitleSegmentContent: React.FC<{
       label: item.label,
       mediaId: item.mediaId,
       transform: item.transform,
-      text: parsed?.plainText ?? '',
+      text: karaokeFrame?.text ?? parsed?.plainText ?? '',
       // textSpans drives styled per-run rendering — italic / bold / colored
       // fragments inside one cue. TextContent prefers spans over `text`
       // when both are present.
-      textSpans: parsed?.spans,
+      textSpans: karaokeFrame ? undefined : parsed?.spans,
+      karaokeWords: karaokeFrame?.words,
       fontSize: item.fontSize,
       fontFamily: item.fontFamily,
       fontWeight: item.fontWeight,
@@ -70,9 +92,10 @@ export const SubtitleSegmentContent: React.FC<{
       textPadding: item.textPadding,
       textShadow: item.textShadow,
       stroke: item.stroke,
+      ...speakerStyle,
       _sequenceFrameOffset: item._sequenceFrameOffset,
     }),
-    [parsed, item],
+    [parsed, karaokeFrame, item, speakerStyle],
   )
 
   if (!activeCue || !parsed || parsed.isEmpty) return null
@@ -2,6 +2,11 @@ import React, { useCallback, useMemo } from 'react'
 import { useGizmoStore, useTimelineStore } from '@/runtime/composition-runtime/deps/stores'
 import { DEFAULT_PROJECT_HEIGHT, DEFAULT_PROJECT_WIDTH } from '@/shared/projects/defaults'
 import type { TextItem } from '@/types/timeline'
+import type { KaraokeWordPaint } from '@/types/text'
+import {
+  KARAOKE_BOX_PADDING_EM,
+  KARAOKE_BOX_RADIUS_EM,
+} from '@/shared/typography/caption-karaoke'
 import { resolveSpanStyles, resolveTextStyle } from '@/shared/typography/text-style'
 import { loadFont } from '../utils/fonts'
 import { useCompositionSpace } from '../contexts/composition-space-context'
@@ -100,6 +105,15 @@ export const TextContent: React.FC<{ item: TextItem & { _sequenceFrameOffset?: n
     ? `${style.textShadow.offsetX * scaleX}px ${style.textShadow.offsetY * scaleY}px ${style.textShadow.blur * scale}px ${style.textShadow.color}`
     : undefined
   const strokeWidth = style.stroke?.width ? `${style.stroke.width * scale * 2}px` : undefined
+  // Karaoke word indices run across spans in reading order.
+  const karaokeWordOffsets: number[] = []
+  if (mergedItem.karaokeWords) {
+    let wordCount = 0
+    for (const span of spanStyles) {
+      karaokeWordOffsets.push(wordCount)
+      wordCount += countWords(span.text)
+    }
+  }
 
   return (
     <div
@@ -152,10 +166,55 @@ export const TextContent: React.FC<{ item: TextItem & { _sequenceFrameOffset?: n
               width: '100%',
             }}
           >
-            {span.text}
+            {mergedItem.karaokeWords
+              ? renderKaraokeWords(span.text, karaokeWordOffsets[index]!, mergedItem.karaokeWords)
+              : span.text}
           </div>
         ))}
       </div>
     </div>
   )
 }
+
+function countWords(text: string): number {
+  return text.split(' ').filter(Boolean).length
+}
+
+/**
+ * Split a span into per-word inline boxes so each word takes its karaoke paint.
+ * Highlight insets use the same em constants as the canvas and GPU paths.
+ */
+function renderKaraokeWords(
+  text: string,
+  firstWordIndex: number,
+  paints: readonly KaraokeWordPaint[],
+): React.ReactNode[] {
+  let wordIndex = firstWordIndex
+  return text.split(' ').map((word, index) => {
+    const separator = index > 0 ? ' ' : ''
+    if (!word) return separator
+    const paint = paints[wordIndex] ?? {}
+    wordIndex += 1
+    return (
+      <React.Fragment key={index}>
+        {separator}
+        <span
+          style={{
+            display: 'inline-block',
+            lineHeight: 'normal',
+            visibility: paint.hidden ? 'hidden' : undefined,
+            color: paint.color,
+            backgroundColor: paint.backgroundColor,
+            padding: paint.backgroundColor ? `0 ${KARAOKE_BOX_PADDING_EM}em` : undefined,
+            margin: paint.backgroundColor ? `0 -${KARAOKE_BOX_PADDING_EM}em` : undefined,
+            borderRadius: paint.backgroundColor ? `${KARAOKE_BOX_RADIUS_EM}em` : undefined,
+            transform: paint.scale ? `scale(${paint.scale})` : undefined,
+            transformOrigin: 'center',
+          }}
+        >
+          {word}
+        </span>
+      </React.Fragment>
+    )
+  })
+}
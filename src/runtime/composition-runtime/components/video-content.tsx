@@ -11,6 +11,7 @@ import { isVideoPoolAbortError } from '@/runtime/composition-runtime/deps/player
 import { createLogger } from '@/shared/logging/logger'
 import { blobUrlManager } from '@/infrastructure/browser/blob-url-manager'
 import { getVideoTargetTimeSeconds } from '../utils/video-timing'
+import { sampleTimeRemap, type TimeRemapCurve } from '@/runtime/composition-runtime/deps/keyframes'
 import {
   getVideoSyncTargetContext,
   planLayoutVideoSync,
@@ -55,6 +56,36 @@ function isRecoverableVideoLoadError(message: string): boolean {
  * Uses pooled video elements instead of creating new ones per clip.
  * Split clips from the same source share video elements for efficiency.
  */
+/**
+ * Source time for a preview frame. A time-remap curve replaces the
+ * constant-rate mapping; its frames are item-relative, like keyframes.
+ */
+function getPreviewVideoTargetTimeSeconds(
+  timeRemapCurve: TimeRemapCurve | undefined,
+  safeTrimBefore: number,
+  sourceFps: number,
+  sequenceLocalFrame: number,
+  playbackRate: number,
+  timelineFps: number,
+  sequenceFrameOffset: number,
+  isReversed: boolean,
+  reverseSourceEnd: number | undefined,
+): number {
+  if (timeRemapCurve) {
+    return sampleTimeRemap(timeRemapCurve, sequenceLocalFrame - sequenceFrameOffset)
+  }
+  return getVideoTargetTimeSeconds(
+    safeTrimBefore,
+    sourceFps,
+    sequenceLocalFrame,
+    playbackRate,
+    timelineFps,
+    sequenceFrameOffset,
+    isReversed,
+    reverseSourceEnd,
+  )
+}
+
 const NativePreviewVideo: React.FC<{
   poolClipId: string
   itemId: string
@@ -65,6 +96,7 @@ const NativePreviewVideo: React.FC<{
   playbackRate: number
   isReversed?: boolean
   reverseSourceEnd?: number
+  timeRemapCurve?: TimeRemapCurve
   audioVolume: number
   audioEqStages: ReadonlyArray<ResolvedAudioEqSettings>
   onError: (error: Error) => void
@@ -82,6 +114,7 @@ const NativePreviewVideo: React.FC<{
   playbackRate,
   isReversed = false,
   reverseSourceEnd,
+  timeRemapCurve,
   audioVolume,
   audioEqStages,
   onError,
@@ -155,7 +188,12 @@ const NativePreviewVideo: React.FC<{
   const safeTrimBeforeRef = useRef(safeTrimBefore)
   const sourceFpsRef = useRef(sourceFps)
   const playbackRateRef = useRef(playbackRate)
+  // Remapped clips step like reversed ones: the element stays paused and is
+  // seeked to each frame's source time instead of free-running.
+  const isFrameStepped = isReversed || timeRemapCurve !== undefined
   const isReversedRef = useRef(isReversed)
+  const isFrameSteppedRef = useRef(isFrameStepped)
+  const timeRemapCurveRef = useRef(timeRemapCurve)
   const reverseSourceEndRef = useRef(reverseSourceEnd)
   const fpsRef = useRef(fps)
   const sequenceFrameOffsetRef = useRef(sequenceFrameOffset)
@@ -163,6 +201,8 @@ const NativePreviewVideo: React.FC<{
   sourceFpsRef.current = sourceFps
   playbackRateRef.current = playbackRate
   isReversedRef.current = isReversed
+  isFrameSteppedRef.current = isFrameStepped
+  timeRemapCurveRef.current = timeRemapCurve
   reverseSourceEndRef.current = reverseSourceEnd
   fpsRef.current = fps
   sequenceFrameOffsetRef.current = sequenceFrameOffset
@@ -174,7 +214,8 @@ const NativePreviewVideo: React.FC<{
   // safeTrimBefore is in SOURCE frames (where playback starts in the source)
   // frame is in TIMELINE frames (current position within the Sequence)
   // For seeking, convert source start to seconds using source FPS.
-  const targetTime = getVideoTargetTimeSeconds(
+  const targetTime = getPreviewVideoTargetTimeSeconds(
+    timeRemapCurve,
     safeTrimBefore,
     sourceFps,
     frame,
@@ -294,10 +335,12 @@ const NativePreviewVideo: React.FC<{
     const initialFrame = frameRef.current
     const initialPlaybackRate = playbackRateRef.current
     const initialIsReversed = isReversedRef.current
+    const initialIsFrameStepped = isFrameSteppedRef.current
     const initialReverseSourceEnd = reverseSourceEndRef.current
     const initialFps = fpsRef.current
     const initialSequenceFrameOffset = sequenceFrameOffsetRef.current
-    const initialTargetTime = getVideoTargetTimeSeconds(
+    const initialTargetTime = getPreviewVideoTargetTimeSeconds(
+      timeRemapCurveRef.current,
       initialSafeTrimBefore,
       initialSourceFps,
       initialFrame,
@@ -311,13 +354,13 @@ const NativePreviewVideo: React.FC<{
     const currentlyPlaying = usePlaybackStore.getState().isPlaying
     const isNearTarget = Math.abs(element.currentTime - clampedInitial) < 0.2
     const isContinuousPlayback =
-      !initialIsReversed && currentlyPlaying && isNearTarget && element.readyState >= 2
+      !initialIsFrameStepped && currentlyPlaying && isNearTarget && element.readyState >= 2
 
     elementRef.current = element
     syncRegisteredVideoElement(itemIdRef.current, element)
     applyVideoElementAudioState(element, audioVolumeRef.current, audioEqStagesRef.current)
 
-    if (initialIsReversed) {
+    if (initialIsFrameStepped) {
       element.pause()
       element.playbackRate = 1
       element.currentTime = clampedInitial
@@ -349,7 +392,8 @@ const NativePreviewVideo: React.FC<{
     const handleCanPlay = () => {
       videoLog.debug(`[${shortId}] canplay:`, element.readyState)
       if (usePlaybackStore.getState().isPlaying && element.paused && element.readyState >= 2) {
-        const liveTargetTime = getVideoTargetTimeSeconds(
+        const liveTargetTime = getPreviewVideoTargetTimeSeconds(
+          timeRemapCurveRef.current,
           safeTrimBeforeRef.current,
           sourceFpsRef.current,
           frameRef.current,
@@ -370,7 +414,7 @@ const NativePreviewVideo: React.FC<{
             // Seek failed - element may still be stabilizing.
           }
         }
-        if (isReversedRef.current) {
+        if (isFrameSteppedRef.current) {
           element.pause()
           element.playbackRate = 1
         } else {
@@ -667,7 +711,7 @@ const NativePreviewVideo: React.FC<{
       })
     }
 
-    if (isReversed && isPlaying) {
+    if (isFrameStepped && isPlaying) {
       if (!video.paused) {
         video.pause()
       }
@@ -810,6 +854,7 @@ const NativePreviewVideo: React.FC<{
   }, [
     frame,
     fps,
+    isFrameStepped,
     isPlaying,
     isReversed,
     playbackRate,
@@ -842,7 +887,7 @@ const NativePreviewVideo: React.FC<{
   // jitter pattern that hard-seek-only correction causes.
   useEffect(() => {
     const video = elementRef.current
-    if (!video || !isPlaying || isReversed || !supportsRVFC || sharedTransitionSync) return
+    if (!video || !isPlaying || isFrameStepped || !supportsRVFC || sharedTransitionSync) return
 
     // Pre-resume AudioContext so audio starts immediately with video.
     // Without this, suspended AudioContext adds 50-100ms audio delay on cold resume.
@@ -871,7 +916,8 @@ const NativePreviewVideo: React.FC<{
       const timelineFps = fpsRef.current
       const clipSourceFps = sourceFpsRef.current
       const trim = safeTrimBeforeRef.current
-      const target = getVideoTargetTimeSeconds(
+      const target = getPreviewVideoTargetTimeSeconds(
+        timeRemapCurveRef.current,
         trim,
         clipSourceFps,
         localFrame,
@@ -913,7 +959,7 @@ const NativePreviewVideo: React.FC<{
         elementRef.current.playbackRate = playbackRateRef.current
       }
     }
-  }, [clock, isPlaying, isReversed, poolClipId, sharedTransitionSync])
+  }, [clock, isFrameStepped, isPlaying, poolClipId, sharedTransitionSync])
 
   // Keep volume/gain in sync for pooled element.
   useEffect(() => {
@@ -978,6 +1024,7 @@ export const VideoContent: React.FC<{
   sourceFps: number
   isReversed?: boolean
   reverseSourceEnd?: number
+  timeRemapCurve?: TimeRemapCurve
   audioEqStages: ReadonlyArray<ResolvedAudioEqSettings>
   forceCssComposite?: boolean
 }> = ({
@@ -988,6 +1035,7 @@ export const VideoContent: React.FC<{
   sourceFps,
   isReversed = false,
   reverseSourceEnd,
+  timeRemapCurve,
   audioEqStages,
   forceCssComposite = false,
 }) => {
@@ -1060,6 +1108,7 @@ export const VideoContent: React.FC<{
       playbackRate={playbackRate}
       isReversed={isReversed}
       reverseSourceEnd={reverseSourceEnd}
+      timeRemapCurve={timeRemapCurve}
       audioVolume={audioVolume}
       audioEqStages={resolvedAudioEqStages}
       onError={handleError}
@@ -1,6 +1,11 @@
 import React, { useEffect, useMemo, useCallback } from 'react'
 import { AbsoluteFill, Sequence, useClock } from '@/runtime/composition-runtime/deps/player'
 import { timelineToSourceFrames } from '@/runtime/composition-runtime/deps/timeline'
+import {
+  getPropertyKeyframes,
+  resolveTimeRemapCurve,
+  type TimeRemapCurve,
+} from '@/runtime/composition-runtime/deps/keyframes'
 import { useCurrentFrame, useVideoConfig } from '../hooks/use-player-compat'
 import type { CompositionInputProps } from '@/types/export'
 import type { TimelineItem } from '@/types/timeline'
@@ -227,12 +232,36 @@ export const MainComposition: React.FC<MainCompositionProps> = ({
     [managedCompoundAudioItems],
   )
 
+  const timeRemapCurvesByItemId = useMemo(() => {
+    const curves = new Map<string, TimeRemapCurve>()
+    if (!keyframes || keyframes.length === 0) return curves
+    const keyframesByItemId = new Map(keyframes.map((entry) => [entry.itemId, entry]))
+    for (const track of tracks) {
+      for (const item of track.items) {
+        const curve = resolveTimeRemapCurve(item, keyframesByItemId.get(item.id), fps)
+        if (curve) curves.set(item.id, curve)
+      }
+    }
+    return curves
+  }, [fps, keyframes, tracks])
+
+  const keyframedAudioItemIds = useMemo(
+    () =>
+      new Set([
+        ...(keyframes ?? [])
+          .filter((itemKeyframes) => getPropertyKeyframes(itemKeyframes, 'volume').length > 0)
+          .map((itemKeyframes) => itemKeyframes.itemId),
+        ...timeRemapCurvesByItemId.keys(),
+      ]),
+    [keyframes, timeRemapCurvesByItemId],
+  )
+
   // Merge continuous split audio clips into single segments to prevent
   // audio element remount (click/gap) at split boundaries.
   // Mirrors the videoAudioSegments merging pattern.
   const audioSegments = useMemo(
-    () => buildStandaloneAudioSegments(standaloneAudioItems, fps),
-    [standaloneAudioItems, fps],
+    () => buildStandaloneAudioSegments(standaloneAudioItems, fps, keyframedAudioItemIds),
+    [standaloneAudioItems, fps, keyframedAudioItemIds],
   )
 
   // Video audio is rendered in a dedicated audio layer to decouple audio
@@ -249,8 +278,9 @@ export const MainComposition: React.FC<MainCompositionProps> = ({
   // - One continuous segment per clip (decoupled from visual transitions)
   // - Segments are expanded into transition handles so both clips overlap chronologically
   const videoAudioSegments = useMemo(
-    () => buildTransitionVideoAudioSegments(videoAudioItems, transitions, fps),
-    [videoAudioItems, transitions, fps],
+    () =>
+      buildTransitionVideoAudioSegments(videoAudioItems, transitions, fps, keyframedAudioItemIds),
+    [videoAudioItems, transitions, fps, keyframedAudioItemIds],
   )
   const linkedAudioTransitionSegments = useMemo(
     () =>
@@ -258,8 +288,9 @@ export const MainComposition: React.FC<MainCompositionProps> = ({
         managedLinkedAudioItems,
         managedLinkedAudioTransitionDefs,
         fps,
+        keyframedAudioItemIds,
       ),
-    [managedLinkedAudioItems, managedLinkedAudioTransitionDefs, fps],
+    [managedLinkedAudioItems, managedLinkedAudioTransitionDefs, fps, keyframedAudioItemIds],
   )
   const managedCompoundAudioSegments = useMemo<CompoundAudioSegment[]>(
     () =>
@@ -303,6 +334,9 @@ export const MainComposition: React.FC<MainCompositionProps> = ({
 
   const shouldUseCustomDecoder = useCallback(
     (segment: VideoAudioSegment | AudioSegment): boolean => {
+      // Remapped audio is rendered offline from the full decode.
+      if (timeRemapCurvesByItemId.has(segment.itemId)) return true
+
       if (!segment.mediaId) {
         // Legacy clips without media linkage: safest fallback is custom decode.
         return true
@@ -323,7 +357,7 @@ export const MainComposition: React.FC<MainCompositionProps> = ({
       // Audio-only assets persist their codec in media.codec.
       return needsCustomAudioDecoder(media.audioCodec ?? media.codec)
     },
-    [mediaById],
+    [mediaById, timeRemapCurvesByItemId],
   )
 
   // Collect adjustment layers from VISIBLE tracks (for effect application)
@@ -419,7 +453,9 @@ export const MainComposition: React.FC<MainCompositionProps> = ({
                       trimBefore={segment.trimBefore}
                       sourceFps={segment.sourceFps}
                       volume={segment.volumeDb}
+                      trackVolumeDb={segment.trackVolumeDb}
                       playbackRate={segment.playbackRate}
+                      timeRemapCurve={timeRemapCurvesByItemId.get(segment.itemId)}
                       isReversed={segment.isReversed}
                       reverseSourceEnd={segment.reverseSourceEnd}
                       audioPitchSemitones={segment.audioPitchSemitones}
@@ -449,6 +485,7 @@ export const MainComposition: React.FC<MainCompositionProps> = ({
                       trimBefore={segment.trimBefore}
                       sourceFps={segment.sourceFps}
                       volume={segment.volumeDb}
+                      trackVolumeDb={segment.trackVolumeDb}
                       playbackRate={segment.playbackRate}
                       isReversed={segment.isReversed}
                       reverseSourceEnd={segment.reverseSourceEnd}
@@ -495,7 +532,9 @@ export const MainComposition: React.FC<MainCompositionProps> = ({
                       trimBefore={segment.trimBefore}
                       sourceFps={segment.sourceFps}
                       volume={segment.volumeDb}
+                      trackVolumeDb={segment.trackVolumeDb}
                       playbackRate={segment.playbackRate}
+                      timeRemapCurve={timeRemapCurvesByItemId.get(segment.itemId)}
                       isReversed={segment.isReversed}
                       reverseSourceEnd={segment.reverseSourceEnd}
                       audioPitchSemitones={segment.audioPitchSemitones}
@@ -519,6 +558,7 @@ export const MainComposition: React.FC<MainCompositionProps> = ({
                       trimBefore={segment.trimBefore}
                       sourceFps={segment.sourceFps}
                       volume={segment.volumeDb}
+                      trackVolumeDb={segment.trackVolumeDb}
                       playbackRate={segment.playbackRate}
                       isReversed={segment.isReversed}
                       reverseSourceEnd={segment.reverseSourceEnd}
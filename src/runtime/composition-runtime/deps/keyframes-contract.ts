@@ -7,8 +7,16 @@ export {
   hasKeyframeAnimation,
 } from '@/features/keyframes/utils/animated-transform-resolver'
 export { resolveAnimatedCrop } from '@/features/keyframes/utils/animated-crop-resolver'
+export { resolveAnimatedCornerPin } from '@/features/keyframes/utils/animated-corner-pin-resolver'
+export { resolveAnimatedMaskShape } from '@/features/keyframes/utils/animated-mask-path'
 export {
   getPropertyKeyframes,
   interpolatePropertyValue,
 } from '@/features/keyframes/utils/interpolation'
+export {
+  getTimeRemapSourceRange,
+  resolveTimeRemapCurve,
+  sampleTimeRemap,
+} from '@/features/keyframes/utils/time-remap'
+export type { TimeRemapCurve } from '@/features/keyframes/utils/time-remap'
 export { resolveAnimatedTextItem } from '@/features/keyframes/utils/animated-text-item'
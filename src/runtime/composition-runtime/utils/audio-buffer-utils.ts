@@ -1,3 +1,5 @@
+import { renderTimeRemappedAudio } from '@/infrastructure/audio/time-remap-render'
+
 export function createReversedAudioBuffer(buffer: AudioBuffer): AudioBuffer {
   const reversed = new AudioBuffer({
     numberOfChannels: buffer.numberOfChannels,
@@ -13,3 +15,32 @@ export function createReversedAudioBuffer(buffer: AudioBuffer): AudioBuffer {
   }
   return reversed
 }
+
+/**
+ * Render a time-remapped clip into a buffer that plays at rate 1 from its
+ * start. `frameSourceSeconds` holds the source time at each frame boundary.
+ */
+export function createTimeRemappedAudioBuffer(
+  buffer: AudioBuffer,
+  frameSourceSeconds: ArrayLike<number>,
+  fps: number,
+): AudioBuffer {
+  const channels: Float32Array[] = []
+  for (let channel = 0; channel < Math.min(2, buffer.numberOfChannels); channel += 1) {
+    channels.push(buffer.getChannelData(channel))
+  }
+  const rendered = renderTimeRemappedAudio({
+    channels,
+    sampleRate: buffer.sampleRate,
+    channelsStartSeconds: 0,
+    frameSourceSeconds,
+    fps,
+  })
+  const remapped = new AudioBuffer({
+    numberOfChannels: rendered.length,
+    length: Math.max(1, rendered[0]?.length ?? 0),
+    sampleRate: buffer.sampleRate,
+  })
+  rendered.forEach((samples, channel) => remapped.copyToChannel(samples, channel))
+  return remapped
+}
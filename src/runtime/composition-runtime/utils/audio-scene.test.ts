@@ -53,6 +53,33 @@ describe('audio scene', () => {
     ])
   })
 
+  it('keeps split clips with volume keyframes as separate segments', () => {
+    const clip = {
+      type: 'audio' as const,
+      trackId: 'track-1',
+      src: 'audio.mp3',
+      mediaId: 'media-1',
+      sourceFps: 30,
+      muted: false,
+      trackVolumeDb: -3,
+      trackVisible: true,
+    }
+    const segments = buildStandaloneAudioSegments(
+      [
+        { ...clip, id: 'audio-1', from: 0, durationInFrames: 30, label: 'Audio 1', sourceStart: 0 },
+        { ...clip, id: 'audio-2', from: 30, durationInFrames: 20, label: 'Audio 2' },
+      ],
+      30,
+      new Set(['audio-2']),
+    )
+
+    expect(segments.map((segment) => [segment.itemId, segment.from])).toEqual([
+      ['audio-1', 0],
+      ['audio-2', 30],
+    ])
+    expect(segments[1]?.trackVolumeDb).toBe(-3)
+  })
+
   it('adds track gain to clip gain for standalone audio', () => {
     const segments = buildStandaloneAudioSegments(
       [
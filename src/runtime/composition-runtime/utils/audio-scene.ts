@@ -72,6 +72,8 @@ export interface AudioSegment {
   reverseSourceEnd?: number
   sourceFps?: number
   volumeDb: number
+  /** Track gain included in `volumeDb`, re-applied on top of volume keyframes. */
+  trackVolumeDb: number
   muted: boolean
   audioFadeIn: number
   audioFadeOut: number
@@ -255,9 +257,16 @@ function resolveContinuousClipTrimStarts<TItem extends ContinuousAudioItem>(
   return resolvedTrimBeforeById
 }
 
+/**
+ * `keyframedAudioItemIds` keeps clips with volume automation or a time remap
+ * out of split merging: keyframes are item-relative, so a merged segment would
+ * play the first clip's envelope across its neighbours (export never merges
+ * these).
+ */
 export function buildStandaloneAudioSegments(
   items: StandaloneAudioItem[],
   fps: number,
+  keyframedAudioItemIds: ReadonlySet<string> = new Set(),
 ): AudioSegment[] {
   const sortedItems = sortAudioItemsByTimelineOrder(items)
   const resolvedTrimBeforeById = resolveContinuousClipTrimStarts(sortedItems, fps)
@@ -289,6 +298,7 @@ export function buildStandaloneAudioSegments(
       ),
       sourceFps: item.sourceFps,
       volumeDb: (item.volume ?? 0) + (item.trackVolumeDb ?? 0),
+      trackVolumeDb: item.trackVolumeDb ?? 0,
       muted: item.muted || !item.trackVisible,
       audioFadeIn: item.audioFadeIn ?? 0,
       audioFadeOut: item.audioFadeOut ?? 0,
@@ -335,6 +345,8 @@ export function buildStandaloneAudioSegments(
       active.isReversed !== true &&
       segment.isReversed !== true &&
       Math.abs(active.volumeDb - segment.volumeDb) <= 0.0001 &&
+      !keyframedAudioItemIds.has(active.clip.id) &&
+      !keyframedAudioItemIds.has(segment.itemId) &&
       active.muted === segment.muted &&
       active.audioPitchSemitones === segment.audioPitchSemitones &&
       active.audioPitchCents === segment.audioPitchCents &&
@@ -370,6 +382,7 @@ export function buildStandaloneAudioSegments(
       reverseSourceEnd: active.reverseSourceEnd,
       sourceFps: active.sourceFps,
       volumeDb: active.volumeDb,
+      trackVolumeDb: active.trackVolumeDb,
       muted: active.muted,
       audioFadeIn: active.audioFadeIn,
       audioFadeOut: active.audioFadeOut,
@@ -399,6 +412,7 @@ export function buildStandaloneAudioSegments(
       reverseSourceEnd: active.reverseSourceEnd,
       sourceFps: active.sourceFps,
       volumeDb: active.volumeDb,
+      trackVolumeDb: active.trackVolumeDb,
       muted: active.muted,
       audioFadeIn: active.audioFadeIn,
       audioFadeOut: active.audioFadeOut,
@@ -420,6 +434,7 @@ export function buildTransitionVideoAudioSegments(
   items: TransitionAudioItem[],
   transitions: Transition[],
   fps: number,
+  keyframedAudioItemIds: ReadonlySet<string> = new Set(),
 ): VideoAudioSegment[] {
   const sortedItems = sortAudioItemsByTimelineOrder(items)
   const resolvedTrimBeforeById = resolveContinuousClipTrimStarts(sortedItems, fps)
@@ -534,6 +549,7 @@ export function buildTransitionVideoAudioSegments(
           : undefined,
       sourceFps: item.sourceFps,
       volumeDb: (item.volume ?? 0) + (item.trackVolumeDb ?? 0),
+      trackVolumeDb: item.trackVolumeDb ?? 0,
       muted: item.muted || !item.trackVisible,
       audioFadeIn: item.audioFadeIn ?? 0,
       audioFadeOut: item.audioFadeOut ?? 0,
@@ -591,6 +607,8 @@ export function buildTransitionVideoAudioSegments(
       active.isReversed !== true &&
       segment.isReversed !== true &&
       Math.abs(active.volumeDb - segment.volumeDb) <= 0.0001 &&
+      !keyframedAudioItemIds.has(active.clip.id) &&
+      !keyframedAudioItemIds.has(segment.itemId) &&
       active.muted === segment.muted &&
       active.audioPitchSemitones === segment.audioPitchSemitones &&
       active.audioPitchCents === segment.audioPitchCents &&
@@ -632,6 +650,7 @@ export function buildTransitionVideoAudioSegments(
       reverseSourceEnd: active.reverseSourceEnd,
       sourceFps: active.sourceFps,
       volumeDb: active.volumeDb,
+      trackVolumeDb: active.trackVolumeDb,
       muted: active.muted,
       audioFadeIn: active.audioFadeIn,
       audioFadeOut: active.audioFadeOut,
@@ -667,6 +686,7 @@ export function buildTransitionVideoAudioSegments(
       reverseSourceEnd: active.reverseSourceEnd,
       sourceFps: active.sourceFps,
       volumeDb: active.volumeDb,
+      trackVolumeDb: active.trackVolumeDb,
       muted: active.muted,
       audioFadeIn: active.audioFadeIn,
       audioFadeOut: active.audioFadeOut,
@@ -13,6 +13,7 @@ import {
   resolveAnimatedTransform,
   hasKeyframeAnimation,
   resolveAnimatedTextItem,
+  resolveAnimatedMaskShape,
 } from '../deps/keyframes'
 import { resolveTransitionFrameState, type TransitionFrameState } from './transition-scene'
 import {
@@ -138,7 +139,10 @@ export function resolveActiveShapeMasksAtFrame(
       return frame >= start && frame < end
     })
     .map(({ mask, trackOrder }) => {
-      const shape = applyPreviewPathVerticesToShape(mask, getPreviewPathVertices)
+      const shape = applyPreviewPathVerticesToShape(
+        resolveAnimatedMaskShape(mask, frame - mask.from),
+        getPreviewPathVertices,
+      )
 
       return {
         shape,
@@ -12,6 +12,7 @@ import {
 } from '@/shared/utils/audio-eq'
 import {
   createPreviewClipAudioGraph,
+  getPreviewMasterBus,
   rampPreviewClipEq,
   setPreviewClipEq,
 } from './preview-audio-graph'
@@ -155,6 +156,16 @@ describe('preview-audio-graph', () => {
     expect(getConnections(secondStage.outputGainNode)).toEqual([graph!.outputGainNode])
   })
 
+  it('routes every clip graph through one master bus into the destination', () => {
+    const first = createPreviewClipAudioGraph()
+    const second = createPreviewClipAudioGraph()
+    const bus = getPreviewMasterBus(first!.context)
+
+    expect(getConnections(first!.outputGainNode)).toEqual([bus])
+    expect(getConnections(second!.outputGainNode)).toEqual([bus])
+    expect(getConnections(bus)).toEqual([first!.context.destination])
+  })
+
   it('creates cut nodes when needed and ramps frequency, gain, and Q parameters', () => {
     const graph = createPreviewClipAudioGraph({ eqStageCount: 1 })
     expect(graph).not.toBeNull()
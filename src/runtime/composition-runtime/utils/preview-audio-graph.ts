@@ -6,6 +6,8 @@ import {
   clampAudioEqFrequencyForSampleRate,
 } from '@/shared/utils/audio-eq'
 import type { ResolvedAudioEqSettings } from '@/types/audio'
+import { setPreviewLoudnessTapController } from '@/shared/state/preview-loudness'
+import { createPreviewLoudnessTap } from './preview-loudness-tap'
 
 export const PREVIEW_AUDIO_GAIN_RAMP_SECONDS = 0.008
 const PREVIEW_AUDIO_EQ_RAMP_SECONDS = 0.012
@@ -62,6 +64,23 @@ export function peekSharedPreviewAudioContext(): AudioContext | null {
     : null
 }
 
+const previewMasterBuses = new WeakMap<AudioContext, GainNode>()
+
+/**
+ * Unity-gain bus every preview clip graph feeds before the destination, so the
+ * whole preview mix has one node to meter.
+ */
+export function getPreviewMasterBus(context: AudioContext): GainNode {
+  let bus = previewMasterBuses.get(context)
+  if (!bus) {
+    bus = context.createGain()
+    bus.connect(context.destination)
+    previewMasterBuses.set(context, bus)
+    setPreviewLoudnessTapController(createPreviewLoudnessTap(context, bus))
+  }
+  return bus
+}
+
 function createPassNodes(
   context: AudioContext,
   type: 'highpass' | 'lowpass',
@@ -515,7 +534,7 @@ export function createPreviewClipAudioGraph(options?: {
   }
 
   reconnectPreviewClipAudioGraph(graph)
-  outputGainNode.connect(context.destination)
+  outputGainNode.connect(getPreviewMasterBus(context))
   return graph
 }
 
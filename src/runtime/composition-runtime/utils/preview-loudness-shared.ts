@@ -0,0 +1,12 @@
+import type { LoudnessSnapshot } from '@/shared/utils/audio-loudness'
+
+export const PREVIEW_LOUDNESS_PROCESSOR_NAME = 'freecut-preview-loudness-tap'
+
+export interface PreviewLoudnessResetMessage {
+  type: 'reset'
+}
+
+export interface PreviewLoudnessSnapshotMessage {
+  type: 'snapshot'
+  snapshot: LoudnessSnapshot
+}
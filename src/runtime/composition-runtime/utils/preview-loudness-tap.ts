@@ -0,0 +1,86 @@
+import { createLogger } from '@/shared/logging/logger'
+import {
+  publishPreviewLoudness,
+  type PreviewLoudnessTapController,
+} from '@/shared/state/preview-loudness'
+import {
+  PREVIEW_LOUDNESS_PROCESSOR_NAME,
+  type PreviewLoudnessResetMessage,
+  type PreviewLoudnessSnapshotMessage,
+} from './preview-loudness-shared'
+import workletModuleUrl from '../worklets/preview-loudness-tap.worklet.ts?worker&url'
+
+const log = createLogger('PreviewLoudnessTap')
+const pendingWorkletLoads = new WeakMap<AudioContext, Promise<boolean>>()
+
+function ensurePreviewLoudnessWorkletLoaded(context: AudioContext): Promise<boolean> {
+  if (typeof AudioWorkletNode === 'undefined' || typeof context.audioWorklet === 'undefined') {
+    return Promise.resolve(false)
+  }
+
+  const pending = pendingWorkletLoads.get(context)
+  if (pending) {
+    return pending
+  }
+
+  const loadPromise = context.audioWorklet
+    .addModule(workletModuleUrl)
+    .then(() => true)
+    .catch((error) => {
+      log.warn('Failed to load preview loudness worklet module', { error })
+      pendingWorkletLoads.delete(context)
+      return false
+    })
+
+  pendingWorkletLoads.set(context, loadPromise)
+  return loadPromise
+}
+
+/**
+ * Meter `source` (the preview master bus) with a BS.1770 worklet while enabled.
+ * Snapshots land in the shared preview-loudness store.
+ */
+export function createPreviewLoudnessTap(
+  context: AudioContext,
+  source: AudioNode,
+): PreviewLoudnessTapController {
+  let node: AudioWorkletNode | null = null
+  let enabled = false
+
+  return {
+    enable() {
+      if (enabled) return
+      enabled = true
+      void ensurePreviewLoudnessWorkletLoaded(context).then((loaded) => {
+        if (!loaded || !enabled || node) return
+        node = new AudioWorkletNode(context, PREVIEW_LOUDNESS_PROCESSOR_NAME, {
+          numberOfInputs: 1,
+          numberOfOutputs: 1,
+          outputChannelCount: [2],
+          channelCount: 2,
+          channelCountMode: 'explicit',
+          channelInterpretation: 'speakers',
+        })
+        node.port.onmessage = (event: MessageEvent<PreviewLoudnessSnapshotMessage>) => {
+          if (event.data.type === 'snapshot') publishPreviewLoudness(event.data.snapshot)
+        }
+        source.connect(node)
+        node.connect(context.destination)
+      })
+    },
+
+    disable() {
+      enabled = false
+      if (!node) return
+      source.disconnect(node)
+      node.disconnect()
+      node.port.onmessage = null
+      node = null
+    },
+
+    reset() {
+      const message: PreviewLoudnessResetMessage = { type: 'reset' }
+      node?.port.postMessage(message)
+    },
+  }
+}
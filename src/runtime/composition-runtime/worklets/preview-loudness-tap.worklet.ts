@@ -0,0 +1,70 @@
+import { createLoudnessMeter } from '@/shared/utils/audio-loudness'
+import {
+  PREVIEW_LOUDNESS_PROCESSOR_NAME,
+  type PreviewLoudnessResetMessage,
+  type PreviewLoudnessSnapshotMessage,
+} from '../utils/preview-loudness-shared'
+
+declare abstract class AudioWorkletProcessor {
+  readonly port: MessagePort
+  constructor(options?: unknown)
+  abstract process(
+    inputs: Float32Array[][],
+    outputs: Float32Array[][],
+    parameters: Record<string, Float32Array>,
+  ): boolean
+}
+
+declare function registerProcessor(
+  name: string,
+  processorCtor: new (options?: unknown) => AudioWorkletProcessor,
+): void
+
+declare const sampleRate: number
+
+/**
+ * Measures whatever reaches the preview master bus and posts a loudness
+ * snapshot every ~100 ms. Outputs silence; it's connected to the destination
+ * only so the graph keeps pulling it.
+ */
+class PreviewLoudnessTapProcessor extends AudioWorkletProcessor {
+  private readonly meter = createLoudnessMeter(sampleRate, 2)
+  private readonly postInterval = Math.round(sampleRate / 10)
+  private silence = new Float32Array(128)
+  private framesSincePost = 0
+
+  constructor() {
+    super()
+    this.port.onmessage = (event: MessageEvent<PreviewLoudnessResetMessage>) => {
+      if (event.data.type === 'reset') {
+        this.meter.reset()
+        this.framesSincePost = 0
+      }
+    }
+  }
+
+  process(inputs: Float32Array[][], outputs: Float32Array[][]): boolean {
+    const input = inputs[0]
+    const frames = input?.[0]?.length ?? outputs[0]?.[0]?.length ?? 128
+    if (input && input.length > 0) {
+      this.meter.push([input[0]!, input[1] ?? input[0]!])
+    } else {
+      // No sources connected right now: keep time moving so momentary decays.
+      if (this.silence.length !== frames) this.silence = new Float32Array(frames)
+      this.meter.push([this.silence, this.silence])
+    }
+
+    this.framesSincePost += frames
+    if (this.framesSincePost >= this.postInterval) {
+      this.framesSincePost = 0
+      const message: PreviewLoudnessSnapshotMessage = {
+        type: 'snapshot',
+        snapshot: this.meter.snapshot(),
+      }
+      this.port.postMessage(message)
+    }
+    return true
+  }
+}
+
+registerProcessor(PREVIEW_LOUDNESS_PROCESSOR_NAME, PreviewLoudnessTapProcessor)
@@ -19,6 +19,11 @@ export interface CaptureOptions {
    * preview instead of an adjacent live player frame.
    */
   preferRenderedFrame?: boolean
+  /**
+   * If true and the project works in an HDR space, return the composite as
+   * PQ/HLG code values (before display tone mapping) so scopes read nits.
+   */
+  hdrSignal?: boolean
 }
 
 export type PreviewQuality = 1 | 0.5 | 0.33 | 0.25
@@ -1,6 +1,7 @@
 import { create } from 'zustand'
 import type { PreviewBridgeActions, PreviewBridgeState } from './types'
 import { createLogger } from '@/shared/logging/logger'
+import { DEFAULT_PROJECT_COLOR_SPACE } from '@/types/color'
 
 const log = createLogger('PreviewBridge')
 
@@ -35,6 +36,7 @@ export const usePreviewBridgeStore = create<PreviewBridgeState & PreviewBridgeAc
   captureFrameImageData: null,
   captureCanvasSource: null,
   postEditWarmRequest: null,
+  colorSpace: DEFAULT_PROJECT_COLOR_SPACE,
 
   setDisplayedFrame: (frame) =>
     set((state) => {
@@ -45,6 +47,7 @@ export const usePreviewBridgeStore = create<PreviewBridgeState & PreviewBridgeAc
   setCaptureFrame: (fn) => set({ captureFrame: fn }),
   setCaptureFrameImageData: (fn) => set({ captureFrameImageData: fn }),
   setCaptureCanvasSource: (fn) => set({ captureCanvasSource: fn }),
+  setColorSpace: (colorSpace) => set({ colorSpace }),
   requestPostEditWarm: (frame, itemIds, frames = []) =>
     set((state) => {
       const normalizedFrame = normalizeFrame(frame) ?? 0
@@ -1,4 +1,5 @@
 import type { CaptureOptions } from '@/shared/state/playback'
+import type { ProjectColorSpace } from '@/types/color'
 
 export interface PostEditWarmRequest {
   frame: number
@@ -20,6 +21,8 @@ export interface PreviewBridgeState {
     | null
   /** Latest request to prewarm the preview renderer after an edit commit. */
   postEditWarmRequest: PostEditWarmRequest | null
+  /** Working colour space of the previewed project; scopes switch to nits graticules for HDR */
+  colorSpace: ProjectColorSpace
 }
 
 export interface PreviewBridgeActions {
@@ -35,4 +38,5 @@ export interface PreviewBridgeActions {
     fn: ((options?: CaptureOptions) => Promise<OffscreenCanvas | HTMLCanvasElement | null>) | null,
   ) => void
   requestPostEditWarm: (frame: number, itemIds: string[], frames?: number[]) => void
+  setColorSpace: (colorSpace: ProjectColorSpace) => void
 }
@@ -0,0 +1,56 @@
+import type { LoudnessSnapshot } from '@/shared/utils/audio-loudness'
+
+/** Implemented by the preview runtime; attaches the loudness tap on demand. */
+export interface PreviewLoudnessTapController {
+  enable(): void
+  disable(): void
+  reset(): void
+}
+
+let currentSnapshot: LoudnessSnapshot | null = null
+let version = 0
+let controller: PreviewLoudnessTapController | null = null
+const listeners = new Set<() => void>()
+
+function notify(): void {
+  version += 1
+  for (const listener of listeners) {
+    listener()
+  }
+}
+
+export function publishPreviewLoudness(snapshot: LoudnessSnapshot): void {
+  currentSnapshot = snapshot
+  notify()
+}
+
+export function getPreviewLoudness(): LoudnessSnapshot | null {
+  return currentSnapshot
+}
+
+/** Restart integration (integrated / max values / true peak). */
+export function resetPreviewLoudness(): void {
+  controller?.reset()
+  currentSnapshot = null
+  notify()
+}
+
+/** The tap only runs while someone is subscribed. */
+export function subscribePreviewLoudness(callback: () => void): () => void {
+  listeners.add(callback)
+  if (listeners.size === 1) controller?.enable()
+  return () => {
+    listeners.delete(callback)
+    if (listeners.size === 0) controller?.disable()
+  }
+}
+
+export function getPreviewLoudnessVersion(): number {
+  return version
+}
+
+export function setPreviewLoudnessTapController(next: PreviewLoudnessTapController | null): void {
+  controller?.disable()
+  controller = next
+  if (next && listeners.size > 0) next.enable()
+}
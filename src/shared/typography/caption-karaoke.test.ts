@@ -0,0 +1,102 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { TextItem } from '@/types/timeline'
+import {
+  alignWordTimingsToText,
+  layoutKaraokeWords,
+  resolveCueWordTimings,
+  resolveKaraokeCaptionFrame,
+} from './caption-karaoke'
+import { layoutTextBlock } from './text-block-layout'
+import { parseFontSizePx, type TextMeasurer } from './text-measurer'
+
+const measurer: TextMeasurer = {
+  measure(text, cssFont, letterSpacing) {
+    return text.length * (parseFontSizePx(cssFont) * 0.5 + letterSpacing)
+  },
+  fontMetrics(cssFont) {
+    const fontSize = parseFontSizePx(cssFont)
+    return { ascent: fontSize * 0.8, descent: fontSize * 0.2 }
+  },
+}
+
+const cue = {
+  startSeconds: 0,
+  endSeconds: 2,
+  text: 'one two three four',
+  words: [
+    { text: 'one', startSeconds: 0, endSeconds: 0.4 },
+    { text: 'two', startSeconds: 0.5, endSeconds: 0.9 },
+    { text: 'three', startSeconds: 1, endSeconds: 1.4 },
+    { text: 'four', startSeconds: 1.5, endSeconds: 2 },
+  ],
+}
+
+describe('caption karaoke', () => {
+  it('merges recognizer word pieces into text tokens', () => {
+    const pieces = [
+      { text: ' Don', startSeconds: 0, endSeconds: 0.2 },
+      { text: "'t", startSeconds: 0.2, endSeconds: 0.3 },
+      { text: ' stop', startSeconds: 0.4, endSeconds: 0.8 },
+      { text: '.', startSeconds: 0.8, endSeconds: 0.8 },
+    ]
+
+    expect(alignWordTimingsToText("Don't stop.", pieces)).toEqual([
+      { text: "Don't", startSeconds: 0, endSeconds: 0.3 },
+      { text: 'stop.', startSeconds: 0.4, endSeconds: 0.8 },
+    ])
+    expect(alignWordTimingsToText('Do not stop.', pieces)).toBeNull()
+  })
+
+  it('keeps word timings for typo fixes and spreads them once the word count changes', () => {
+    expect(resolveCueWordTimings({ ...cue, text: 'one too three four' })[1]).toEqual({
+      text: 'too',
+      startSeconds: 0.5,
+      endSeconds: 0.9,
+    })
+
+    const respread = resolveCueWordTimings({ ...cue, text: 'ab cd' })
+    expect(respread).toEqual([
+      { text: 'ab', startSeconds: 0, endSeconds: 1 },
+      { text: 'cd', startSeconds: 1, endSeconds: 2 },
+    ])
+  })
+
+  it('highlights the latest started word and holds it through pauses', () => {
+    const style = { activeColor: '#ffd400', activeScale: 1.2 }
+    const frame = resolveKaraokeCaptionFrame(cue, 0.95, style)
+
+    expect(frame?.text).toBe('one two three four')
+    expect(frame?.words).toEqual([{}, { color: '#ffd400', scale: 1.2 }, {}, {}])
+  })
+
+  it('pages words and pops each one in as it is spoken', () => {
+    const frame = resolveKaraokeCaptionFrame(cue, 1.06, { wordsPerPage: 2, popIn: true })
+
+    expect(frame?.text).toBe('three four')
+    expect(frame?.words[0]?.scale).toBeGreaterThan(0.6)
+    expect(frame?.words[0]?.scale).toBeLessThan(1)
+    expect(frame?.words[1]).toEqual({ hidden: true })
+  })
+
+  it('lays out word boxes across wrapped lines', () => {
+    const item = {
+      text: 'one two three four',
+      fontSize: 20,
+      textAlign: 'left',
+      textPadding: 0,
+      color: '#ffffff',
+    } as TextItem
+    const layout = layoutTextBlock(item, 90, 200, measurer)
+    const boxes = layoutKaraokeWords(layout, measurer)
+
+    expect(layout.lines.map((line) => line.text)).toEqual(['one two', 'three', 'four'])
+    expect(boxes.map((box) => [box.index, box.lineIndex])).toEqual([
+      [0, 0],
+      [1, 0],
+      [2, 1],
+      [3, 2],
+    ])
+    expect(boxes[1]?.x).toBeCloseTo(40)
+    expect(boxes[1]?.width).toBeCloseTo(30)
+  })
+})
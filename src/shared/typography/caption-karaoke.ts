@@ -0,0 +1,214 @@
+/**
+ * Word-level karaoke captions.
+ *
+ * {@link resolveKaraokeCaptionFrame} turns a cue plus the playhead into the
+ * words on screen (the current page) and a {@link KaraokeWordPaint} per word.
+ * {@link layoutKaraokeWords} then splits the shared text-block layout into
+ * word boxes, so the DOM preview, Canvas 2D export and the GPU glyph atlas
+ * paint the highlight from identical geometry.
+ */
+
+import type { CaptionKaraokeStyle, KaraokeWordPaint } from '@/types/text'
+import type { CaptionWordTiming } from '@/types/timeline'
+import type { TextBlockLayout } from './text-block-layout'
+import type { TextMeasurer } from './text-measurer'
+
+/** Seconds a popped-in word takes to grow to full size. */
+export const KARAOKE_POP_IN_SECONDS = 0.12
+const POP_IN_START_SCALE = 0.6
+
+/** Highlight box insets, in ems of the line's font size (DOM uses the same). */
+export const KARAOKE_BOX_PADDING_EM = 0.12
+export const KARAOKE_BOX_RADIUS_EM = 0.18
+
+export interface KaraokeCue {
+  startSeconds: number
+  endSeconds: number
+  text: string
+  words?: readonly CaptionWordTiming[]
+}
+
+export interface KaraokeCaptionFrame {
+  /** Space-joined words of the current page. */
+  text: string
+  /** One entry per word of `text`. */
+  words: KaraokeWordPaint[]
+}
+
+export interface KaraokeWordBox {
+  /** Index into {@link KaraokeCaptionFrame.words}. */
+  index: number
+  lineIndex: number
+  text: string
+  /** Box-local left edge of the word's ink. */
+  x: number
+  width: number
+  centerX: number
+  centerY: number
+  /** Rounded highlight rect behind the word. */
+  background: { x: number; y: number; width: number; height: number; radius: number }
+}
+
+/**
+ * Regroup recognizer word timings so there is exactly one per whitespace token
+ * of `text`. Whisper often emits punctuation or word pieces as separate
+ * entries ("don", "'t"); consecutive pieces are merged until they spell the
+ * token. Returns null when the pieces don't spell the text.
+ */
+export function alignWordTimingsToText(
+  text: string,
+  words: readonly CaptionWordTiming[],
+): CaptionWordTiming[] | null {
+  const tokens = text.split(/\s+/).filter(Boolean)
+  const aligned: CaptionWordTiming[] = []
+  let cursor = 0
+  for (const token of tokens) {
+    let spelled = ''
+    let startSeconds = 0
+    let endSeconds = 0
+    while (spelled.length < token.length && cursor < words.length) {
+      const word = words[cursor]!
+      cursor += 1
+      const piece = word.text.trim()
+      if (!piece) continue
+      if (!spelled) startSeconds = word.startSeconds
+      spelled += piece
+      endSeconds = word.endSeconds
+    }
+    if (spelled !== token) return null
+    aligned.push({ text: token, startSeconds, endSeconds })
+  }
+  if (words.slice(cursor).some((word) => word.text.trim())) return null
+  return aligned
+}
+
+/**
+ * Timings for each word of the cue text. Stored word timings are used as-is
+ * while they still line up with the text; once the cue text has been edited
+ * (or never had timings) the cue span is shared out by word length so the
+ * highlight still sweeps across the line.
+ */
+export function resolveCueWordTimings(cue: KaraokeCue): CaptionWordTiming[] {
+  const tokens = cue.text.split(/\s+/).filter(Boolean)
+  if (tokens.length === 0) return []
+
+  const words = cue.words
+  if (words && words.length === tokens.length) {
+    return tokens.map((text, index) => ({
+      text,
+      startSeconds: words[index]!.startSeconds,
+      endSeconds: words[index]!.endSeconds,
+    }))
+  }
+
+  const totalChars = tokens.reduce((sum, token) => sum + token.length, 0)
+  const span = Math.max(0, cue.endSeconds - cue.startSeconds)
+  let cursor = cue.startSeconds
+  return tokens.map((text) => {
+    const duration = (span * text.length) / totalChars
+    const timing = { text, startSeconds: cursor, endSeconds: cursor + duration }
+    cursor += duration
+    return timing
+  })
+}
+
+/**
+ * Resolve what a karaoke cue shows at `seconds` (same clock as the cue). The
+ * most recently started word stays active through pauses until the next word
+ * begins, and pages flip when the active word crosses a page boundary.
+ */
+export function resolveKaraokeCaptionFrame(
+  cue: KaraokeCue,
+  seconds: number,
+  style: CaptionKaraokeStyle,
+): KaraokeCaptionFrame | null {
+  const timings = resolveCueWordTimings(cue)
+  if (timings.length === 0) return null
+
+  let spoken = -1
+  for (const [index, word] of timings.entries()) {
+    if (word.startSeconds > seconds) break
+    spoken = index
+  }
+
+  const pageSize =
+    style.wordsPerPage && style.wordsPerPage > 0
+      ? Math.max(1, Math.floor(style.wordsPerPage))
+      : timings.length
+  const pageStart = Math.floor(Math.max(0, spoken) / pageSize) * pageSize
+  const page = timings.slice(pageStart, pageStart + pageSize)
+
+  const words = page.map((word, offset): KaraokeWordPaint => {
+    const index = pageStart + offset
+    if (index > spoken) return style.popIn ? { hidden: true } : {}
+
+    const paint: KaraokeWordPaint = {}
+    let scale = 1
+    if (index === spoken) {
+      if (style.activeColor) paint.color = style.activeColor
+      if (style.activeBackgroundColor) paint.backgroundColor = style.activeBackgroundColor
+      scale = style.activeScale ?? 1
+    }
+    if (style.popIn) {
+      const progress = (seconds - word.startSeconds) / KARAOKE_POP_IN_SECONDS
+      if (progress < 1) {
+        const eased = 1 - (1 - Math.max(0, progress)) ** 3
+        scale *= POP_IN_START_SCALE + (1 - POP_IN_START_SCALE) * eased
+      }
+    }
+    if (scale !== 1) paint.scale = scale
+    return paint
+  })
+
+  return { text: page.map((word) => word.text).join(' '), words }
+}
+
+/**
+ * Split a laid-out text block into word boxes. Word indices run across lines
+ * in reading order, matching the space-separated words of the source text
+ * (a word force-broken across lines counts once per piece).
+ */
+export function layoutKaraokeWords(
+  layout: TextBlockLayout,
+  measurer: TextMeasurer,
+): KaraokeWordBox[] {
+  const boxes: KaraokeWordBox[] = []
+  let index = 0
+  for (const [lineIndex, line] of layout.lines.entries()) {
+    const { ascent, descent } = measurer.fontMetrics(line.cssFont)
+    const padding = line.fontSize * KARAOKE_BOX_PADDING_EM
+    let charOffset = 0
+    for (const word of line.text.split(' ')) {
+      if (word) {
+        const prefix = line.text.slice(0, charOffset)
+        const x =
+          line.startX + (prefix ? measurer.measure(prefix, line.cssFont, line.letterSpacing) : 0)
+        const width = Math.max(
+          0,
+          measurer.measure(word, line.cssFont, line.letterSpacing) - line.letterSpacing,
+        )
+        const height = ascent + descent
+        const background = {
+          x: x - padding,
+          y: line.baselineY - ascent,
+          width: width + padding * 2,
+          height,
+          radius: Math.min(line.fontSize * KARAOKE_BOX_RADIUS_EM, height / 2),
+        }
+        boxes.push({
+          index,
+          lineIndex,
+          text: word,
+          x,
+          width,
+          centerX: x + width / 2,
+          centerY: line.baselineY - (ascent - descent) / 2,
+          background,
+        })
+        index += 1
+      }
+      charOffset += word.length + 1
+    }
+  }
+  return boxes
+}
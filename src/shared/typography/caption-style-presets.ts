@@ -10,8 +10,13 @@
  * looks right on a 720p edit, a 1080p edit, and a vertical 9:16 edit
  * without per-resolution tuning. Resolved into absolute pixels at apply
  * time by `resolveCaptionStylePatch`.
+ *
+ * `karaoke` turns on word-level highlighting for subtitle segments whose cues
+ * carry word timestamps (see `caption-karaoke.ts`). Presets without it render
+ * static cues, so applying one switches karaoke back off.
  */
 
+import type { CaptionKaraokeStyle } from '@/types/text'
 import type { TransformProperties } from '@/types/transform'
 import type { SubtitleSegmentItem, TextItem } from '@/types/timeline'
 
@@ -51,8 +56,17 @@ export interface CaptionStylePreset {
     /** transform.height = round(canvasHeight * heightRatio). */
     heightRatio?: number
   }
+  karaoke?: CaptionKaraokeStyle
 }
 
+/** Karaoke look used when highlighting is switched on outside a preset. */
+export const DEFAULT_CAPTION_KARAOKE_STYLE = {
+  activeColor: '#FFD400',
+  activeScale: 1.1,
+  popIn: false,
+  wordsPerPage: 0,
+} satisfies CaptionKaraokeStyle
+
 export const CAPTION_STYLE_PRESETS: readonly CaptionStylePreset[] = [
   {
     id: 'netflix',
@@ -164,6 +178,58 @@ export const CAPTION_STYLE_PRESETS: readonly CaptionStylePreset[] = [
     },
     layout: { fontSizeRatio: 0.075, yRatio: 0, widthRatio: 0.9, heightRatio: 0.22 },
   },
+  {
+    id: 'karaoke',
+    label: 'Karaoke',
+    hintKey: 'editor.captionPresets.karaokeHint',
+    patch: {
+      fontFamily: 'Inter',
+      fontWeight: 'bold',
+      fontStyle: 'normal',
+      underline: false,
+      color: '#ffffff',
+      backgroundColor: undefined,
+      backgroundRadius: 0,
+      textAlign: 'center',
+      verticalAlign: 'middle',
+      lineHeight: 1.2,
+      letterSpacing: 0,
+      textPadding: 0,
+      textShadow: { offsetX: 0, offsetY: 3, blur: 8, color: 'rgba(0, 0, 0, 0.85)' },
+      stroke: undefined,
+    },
+    layout: { fontSizeRatio: 0.05, yRatio: 0.34, widthRatio: 0.85, heightRatio: 0.18 },
+    karaoke: {
+      activeColor: '#111111',
+      activeBackgroundColor: '#FFD400',
+      activeScale: 1,
+      popIn: false,
+      wordsPerPage: 0,
+    },
+  },
+  {
+    id: 'word-pop',
+    label: 'Word Pop',
+    hintKey: 'editor.captionPresets.wordPopHint',
+    patch: {
+      fontFamily: 'Anton',
+      fontWeight: 'normal',
+      fontStyle: 'normal',
+      underline: false,
+      color: '#ffffff',
+      backgroundColor: undefined,
+      backgroundRadius: 0,
+      textAlign: 'center',
+      verticalAlign: 'middle',
+      lineHeight: 1.05,
+      letterSpacing: 1,
+      textPadding: 0,
+      textShadow: { offsetX: 0, offsetY: 4, blur: 8, color: 'rgba(0, 0, 0, 0.9)' },
+      stroke: { width: 2, color: '#000000' },
+    },
+    layout: { fontSizeRatio: 0.085, yRatio: 0, widthRatio: 0.9, heightRatio: 0.22 },
+    karaoke: { activeColor: '#39FF14', activeScale: 1.15, popIn: true, wordsPerPage: 3 },
+  },
 ] as const
 
 /**
@@ -174,8 +240,9 @@ export const CAPTION_STYLE_PRESETS: readonly CaptionStylePreset[] = [
 export function detectActiveCaptionPreset(
   item: SubtitleSegmentItem | (TextItem & { textRole?: 'caption' }),
 ): CaptionStylePreset | null {
+  const karaoke = item.type === 'subtitle' ? item.karaoke : undefined
   for (const preset of CAPTION_STYLE_PRESETS) {
-    if (matchesPreset(item, preset.patch)) return preset
+    if (matchesPreset(item, preset.patch) && equalShallow(preset.karaoke, karaoke)) return preset
   }
   return null
 }
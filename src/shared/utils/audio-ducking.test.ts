@@ -0,0 +1,140 @@
+import { describe, expect, it } from 'vite-plus/test'
+import {
+  buildDuckingKeyframes,
+  detectSpeechRanges,
+  mergeDuckingRanges,
+  removeDucking,
+} from './audio-ducking'
+
+function createBuffer(samples: Float32Array, sampleRate: number) {
+  return {
+    length: samples.length,
+    numberOfChannels: 1,
+    sampleRate,
+    getChannelData: () => samples,
+  }
+}
+
+describe('detectSpeechRanges', () => {
+  it('finds voiced regions and ignores short blips', () => {
+    const sampleRate = 1000
+    const samples = new Float32Array(3000)
+    for (let i = 1000; i < 1500; i += 1) samples[i] = i % 2 === 0 ? 0.1 : -0.1
+    for (let i = 2500; i < 2550; i += 1) samples[i] = i % 2 === 0 ? 0.1 : -0.1
+
+    expect(detectSpeechRanges(createBuffer(samples, sampleRate))).toEqual([{ start: 1, end: 1.5 }])
+  })
+})
+
+describe('mergeDuckingRanges', () => {
+  it('sorts and bridges short gaps', () => {
+    expect(
+      mergeDuckingRanges(
+        [
+          { start: 5, end: 6 },
+          { start: 0, end: 1 },
+          { start: 1.2, end: 2 },
+        ],
+        0.25,
+      ),
+    ).toEqual([
+      { start: 0, end: 2 },
+      { start: 5, end: 6 },
+    ])
+  })
+})
+
+describe('buildDuckingKeyframes', () => {
+  const base = {
+    durationInFrames: 120,
+    depthDb: 12,
+    attackFrames: 6,
+    releaseFrames: 12,
+    baseVolumeDbAt: () => -3,
+  }
+
+  it('ramps down over the attack and back up over the release', () => {
+    expect(buildDuckingKeyframes({ ...base, ranges: [{ start: 30, end: 60 }] })).toEqual([
+      { frame: 24, value: -3, duckBase: null },
+      { frame: 30, value: -15, duckBase: null },
+      { frame: 60, value: -15, duckBase: null },
+      { frame: 72, value: -3, duckBase: null },
+    ])
+  })
+
+  it('holds the duck through gaps shorter than attack + release', () => {
+    const points = buildDuckingKeyframes({
+      ...base,
+      ranges: [
+        { start: 30, end: 60 },
+        { start: 70, end: 90 },
+      ],
+    })
+    expect(points.map((point) => point.frame)).toEqual([24, 30, 90, 102])
+  })
+
+  it('starts part-way down when speech begins inside the attack', () => {
+    const [first] = buildDuckingKeyframes({
+      ...base,
+      attackFrames: 10,
+      ranges: [{ start: 2, end: 20 }],
+    })
+    expect(first?.frame).toBe(0)
+    expect(first?.value).toBeCloseTo(-12.6)
+  })
+
+  it('keeps existing automation outside ducks and lowers it under them', () => {
+    const existing = [
+      { frame: 0, value: -6 },
+      { frame: 45, value: -2 },
+      { frame: 120, value: -6 },
+    ]
+    const points = buildDuckingKeyframes({
+      ...base,
+      baseVolumeDbAt: () => -4,
+      existing,
+      ranges: [{ start: 30, end: 60 }],
+    })
+
+    expect(points).toEqual([
+      { frame: 0, value: -6 },
+      { frame: 24, value: -4, duckBase: null },
+      { frame: 30, value: -16, duckBase: null },
+      { frame: 45, value: -14, duckBase: -2 },
+      { frame: 60, value: -16, duckBase: null },
+      { frame: 72, value: -4, duckBase: null },
+      { frame: 120, value: -6 },
+    ])
+    expect(buildDuckingKeyframes({ ...base, existing, ranges: [{ start: 300, end: 400 }] })).toEqual(
+      existing,
+    )
+  })
+
+  it('lowers existing points on the ramps by the duck at their frame', () => {
+    const points = buildDuckingKeyframes({
+      ...base,
+      existing: [{ frame: 27, value: -3 }],
+      ranges: [{ start: 30, end: 60 }],
+    })
+    expect(points.find((point) => point.frame === 27)).toEqual({
+      frame: 27,
+      value: -9,
+      duckBase: -3,
+    })
+  })
+})
+
+describe('removeDucking', () => {
+  it('drops added points and restores lowered ones', () => {
+    expect(
+      removeDucking([
+        { frame: 0, value: -6 },
+        { frame: 24, value: -4, duckBase: null },
+        { frame: 45, value: -14, duckBase: -2 },
+      ]),
+    ).toEqual([
+      { frame: 0, value: -6 },
+      { frame: 45, value: -2 },
+    ])
+  })
+})
@@ -0,0 +1,208 @@
+/**
+ * Music ducking under dialogue.
+ *
+ * - `detectSpeechRanges()` is a simple energy VAD over a decoded buffer.
+ * - `mergeDuckingRanges()` sorts ranges and bridges short pauses so the music
+ *   doesn't pump between words.
+ * - `buildDuckingKeyframes()` turns ranges into a volume envelope (dB points,
+ *   item-relative frames) that ramps down over `attack` before speech starts
+ *   and back up over `release` after it ends, on top of any existing volume
+ *   automation. Every point it writes is marked, so `removeDucking()` can
+ *   recover the automation underneath and a re-run replaces the duck.
+ */
+
+export interface AudioDuckingRange {
+  start: number
+  end: number
+}
+
+export interface SpeechDetectionOptions {
+  thresholdDb?: number
+  minSpeechMs?: number
+  windowMs?: number
+}
+
+export interface DuckingKeyframePoint {
+  frame: number
+  value: number
+  /** Un-ducked value of a point the duck lowered; null for a point the duck added. */
+  duckBase?: number | null
+}
+
+interface AudioBufferLike {
+  length: number
+  numberOfChannels: number
+  sampleRate: number
+  getChannelData(channel: number): Float32Array
+}
+
+const DEFAULT_SPEECH_THRESHOLD_DB = -40
+const DEFAULT_MIN_SPEECH_MS = 120
+const DEFAULT_SPEECH_WINDOW_MS = 20
+const MIN_VOLUME_DB = -60
+const MAX_VOLUME_DB = 12
+
+function clampVolumeDb(value: number): number {
+  return Math.max(MIN_VOLUME_DB, Math.min(MAX_VOLUME_DB, value))
+}
+
+export function detectSpeechRanges(
+  audioBuffer: AudioBufferLike,
+  options: SpeechDetectionOptions = {},
+): AudioDuckingRange[] {
+  if (audioBuffer.length <= 0 || audioBuffer.sampleRate <= 0 || audioBuffer.numberOfChannels <= 0) {
+    return []
+  }
+
+  const threshold = 10 ** ((options.thresholdDb ?? DEFAULT_SPEECH_THRESHOLD_DB) / 20)
+  const windowSamples = Math.max(
+    1,
+    Math.round(((options.windowMs ?? DEFAULT_SPEECH_WINDOW_MS) / 1000) * audioBuffer.sampleRate),
+  )
+  const minSpeechSamples = Math.round(
+    ((options.minSpeechMs ?? DEFAULT_MIN_SPEECH_MS) / 1000) * audioBuffer.sampleRate,
+  )
+  const channels = Array.from({ length: audioBuffer.numberOfChannels }, (_, channel) =>
+    audioBuffer.getChannelData(channel),
+  )
+
+  const ranges: AudioDuckingRange[] = []
+  let speechStartSample: number | null = null
+  const closeRange = (endSample: number) => {
+    if (speechStartSample !== null && endSample - speechStartSample >= minSpeechSamples) {
+      ranges.push({
+        start: speechStartSample / audioBuffer.sampleRate,
+        end: endSample / audioBuffer.sampleRate,
+      })
+    }
+    speechStartSample = null
+  }
+
+  for (let startSample = 0; startSample < audioBuffer.length; startSample += windowSamples) {
+    const endSample = Math.min(audioBuffer.length, startSample + windowSamples)
+    let maxRms = 0
+    for (const channel of channels) {
+      let sumSquares = 0
+      for (let sample = startSample; sample < endSample; sample += 1) {
+        const value = channel[sample] ?? 0
+        sumSquares += value * value
+      }
+      maxRms = Math.max(maxRms, Math.sqrt(sumSquares / (endSample - startSample)))
+    }
+
+    if (maxRms > threshold) {
+      speechStartSample ??= startSample
+    } else {
+      closeRange(startSample)
+    }
+  }
+  closeRange(audioBuffer.length)
+
+  return ranges
+}
+
+/** Sort, then join ranges that overlap or sit less than `gap` apart. */
+export function mergeDuckingRanges(
+  ranges: readonly AudioDuckingRange[],
+  gap = 0,
+): AudioDuckingRange[] {
+  const sorted = ranges
+    .filter((range) => range.end > range.start)
+    .toSorted((a, b) => a.start - b.start)
+  const merged: AudioDuckingRange[] = []
+  for (const range of sorted) {
+    const last = merged.at(-1)
+    if (last && range.start - last.end <= gap) {
+      last.end = Math.max(last.end, range.end)
+    } else {
+      merged.push({ ...range })
+    }
+  }
+  return merged
+}
+
+/** Undo a previous duck: drop the points it added and restore the ones it lowered. */
+export function removeDucking<T extends DuckingKeyframePoint>(points: readonly T[]): T[] {
+  return points.flatMap((point) => {
+    if (point.duckBase === undefined) return [point]
+    if (point.duckBase === null) return []
+    const { duckBase, ...rest } = point
+    return [{ ...rest, value: duckBase } as T]
+  })
+}
+
+export interface BuildDuckingKeyframesOptions {
+  /** Speech ranges in item-relative frames. */
+  ranges: readonly AudioDuckingRange[]
+  durationInFrames: number
+  /** How far the music drops under speech, in dB (positive). */
+  depthDb: number
+  attackFrames: number
+  releaseFrames: number
+  /** Un-ducked volume at a frame; defaults to 0 dB. */
+  baseVolumeDbAt?: (frame: number) => number
+  /**
+   * Existing volume keyframes; kept outside ducked windows, lowered inside them.
+   * A previous duck in them is removed first.
+   */
+  existing?: readonly DuckingKeyframePoint[]
+}
+
+/**
+ * Build the item's full volume keyframe list with ducking applied. Returns
+ * the un-ducked `existing` points when no range touches the item.
+ */
+export function buildDuckingKeyframes({
+  ranges,
+  durationInFrames,
+  depthDb,
+  attackFrames,
+  releaseFrames,
+  baseVolumeDbAt = () => 0,
+  existing = [],
+}: BuildDuckingKeyframesOptions): DuckingKeyframePoint[] {
+  const base = removeDucking(existing)
+  const attack = Math.max(0, attackFrames)
+  const release = Math.max(0, releaseFrames)
+  // Ducks whose ramps would collide are held down through the gap instead.
+  const ducks = mergeDuckingRanges(ranges, attack + release).filter(
+    (range) => range.end + release > 0 && range.start - attack < durationInFrames,
+  )
+  if (ducks.length === 0 || depthDb <= 0) return base.map((point) => ({ ...point }))
+
+  // Depth (0..1) of the duck at any frame, following the linear ramps.
+  const depthAt = (frame: number): number => {
+    for (const range of ducks) {
+      if (frame < range.start - attack || frame > range.end + release) continue
+      if (frame < range.start) return attack > 0 ? 1 - (range.start - frame) / attack : 1
+      if (frame > range.end) return release > 0 ? 1 - (frame - range.end) / release : 1
+      return 1
+    }
+    return 0
+  }
+  const valueAt = (frame: number) => clampVolumeDb(baseVolumeDbAt(frame) - depthDb * depthAt(frame))
+
+  const frames = new Set<number>()
+  for (const range of ducks) {
+    for (const frame of [range.start - attack, range.start, range.end, range.end + release]) {
+      frames.add(Math.round(Math.max(0, Math.min(durationInFrames, frame))))
+    }
+  }
+  // Existing points keep the user's shape, lowered by the duck at their frame.
+  const points = new Map<number, DuckingKeyframePoint>()
+  for (const point of base) {
+    const frame = Math.round(point.frame)
+    const depth = depthAt(point.frame)
+    points.set(
+      frame,
+      depth > 0
+        ? { frame, value: clampVolumeDb(point.value - depthDb * depth), duckBase: point.value }
+        : { frame, value: point.value },
+    )
+  }
+  for (const frame of frames) {
+    if (!points.has(frame)) points.set(frame, { frame, value: valueAt(frame), duckBase: null })
+  }
+
+  return Array.from(points.values()).toSorted((a, b) => a.frame - b.frame)
+}
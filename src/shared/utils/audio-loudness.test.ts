@@ -0,0 +1,124 @@
+import { describe, expect, it } from 'vite-plus/test'
+import {
+  createLoudnessMeter,
+  formatLoudness,
+  getLoudnessNormalizationGain,
+  measureLoudness,
+} from './audio-loudness'
+
+const SAMPLE_RATE = 48000
+
+function sine(seconds: number, amplitudeDb: number, frequency = 997, phase = 0): Float32Array {
+  const amplitude = Math.pow(10, amplitudeDb / 20)
+  return Float32Array.from(
+    { length: Math.round(seconds * SAMPLE_RATE) },
+    (_, i) => amplitude * Math.sin((2 * Math.PI * frequency * i) / SAMPLE_RATE + phase),
+  )
+}
+
+function concat(...parts: Float32Array[]): Float32Array {
+  const out = new Float32Array(parts.reduce((sum, part) => sum + part.length, 0))
+  let offset = 0
+  for (const part of parts) {
+    out.set(part, offset)
+    offset += part.length
+  }
+  return out
+}
+
+describe('measureLoudness', () => {
+  it('reads a stereo 997 Hz tone at its level in LUFS', () => {
+    const tone = sine(5, -23)
+    const result = measureLoudness([tone, tone], SAMPLE_RATE)
+
+    expect(result.integratedLufs).toBeCloseTo(-23, 1)
+    expect(result.momentaryLufs).toBeCloseTo(-23, 1)
+    expect(result.shortTermLufs).toBeCloseTo(-23, 1)
+    expect(result.durationSeconds).toBe(5)
+  })
+
+  it('reads a mono 0 dBFS tone at -3.01 LUFS', () => {
+    expect(measureLoudness([sine(3, 0)], SAMPLE_RATE).integratedLufs).toBeCloseTo(-3.01, 1)
+  })
+
+  it('gates silence and quiet passages out of the integrated value', () => {
+    const withSilence = concat(sine(5, -20), new Float32Array(5 * SAMPLE_RATE))
+    expect(measureLoudness([withSilence], SAMPLE_RATE).integratedLufs).toBeCloseTo(-23, 0)
+
+    const withQuiet = concat(sine(5, -20), sine(5, -40))
+    expect(measureLoudness([withQuiet], SAMPLE_RATE).integratedLufs).toBeCloseTo(-23, 0)
+
+    expect(measureLoudness([new Float32Array(SAMPLE_RATE)], SAMPLE_RATE).integratedLufs).toBe(
+      -Infinity,
+    )
+  })
+
+  it('finds inter-sample peaks above the sample peak', () => {
+    // fs/4 at 45° lands every sample at ±0.707 of the true amplitude.
+    const tone = sine(1, -6, SAMPLE_RATE / 4, Math.PI / 4)
+    const samplePeakDb = 20 * Math.log10(Math.max(...tone.map(Math.abs)))
+    const { truePeakDbtp } = measureLoudness([tone], SAMPLE_RATE)
+
+    expect(samplePeakDb).toBeCloseTo(-9.02, 1)
+    expect(truePeakDbtp).toBeGreaterThan(-6.5)
+    expect(truePeakDbtp).toBeLessThan(-5.5)
+  })
+})
+
+describe('createLoudnessMeter', () => {
+  it('gives the same result fed in small blocks and clears on reset', () => {
+    const tone = sine(4, -18)
+    const meter = createLoudnessMeter(SAMPLE_RATE, 1)
+    for (let offset = 0; offset < tone.length; offset += 128) {
+      meter.push([tone.subarray(offset, offset + 128)])
+    }
+
+    expect(meter.snapshot().integratedLufs).toBeCloseTo(
+      measureLoudness([tone], SAMPLE_RATE).integratedLufs,
+      6,
+    )
+    expect(meter.snapshot().shortTermMaxLufs).toBeCloseTo(-21.01, 1)
+
+    meter.reset()
+    expect(meter.snapshot()).toMatchObject({
+      integratedLufs: -Infinity,
+      momentaryLufs: -Infinity,
+      truePeakDbtp: -Infinity,
+      durationSeconds: 0,
+    })
+  })
+})
+
+describe('getLoudnessNormalizationGain', () => {
+  const streaming = { integratedLufs: -14, truePeakDbtp: -1 }
+
+  it('moves integrated loudness to the target', () => {
+    expect(
+      getLoudnessNormalizationGain({ integratedLufs: -20, truePeakDbtp: -10 }, streaming),
+    ).toEqual({ gainDb: 6, limitedByTruePeak: false })
+    expect(
+      getLoudnessNormalizationGain({ integratedLufs: -9, truePeakDbtp: 0.5 }, streaming),
+    ).toEqual({ gainDb: -5, limitedByTruePeak: false })
+  })
+
+  it('stops at the true-peak ceiling', () => {
+    expect(
+      getLoudnessNormalizationGain({ integratedLufs: -20, truePeakDbtp: -4 }, streaming),
+    ).toEqual({ gainDb: 3, limitedByTruePeak: true })
+  })
+
+  it('leaves silence alone', () => {
+    const silence = { integratedLufs: -Infinity, truePeakDbtp: -Infinity }
+    expect(getLoudnessNormalizationGain(silence, streaming)).toEqual({
+      gainDb: 0,
+      limitedByTruePeak: false,
+    })
+  })
+})
+
+describe('formatLoudness', () => {
+  it('formats finite values and silence', () => {
+    expect(formatLoudness(-14.04)).toBe('-14.0 LUFS')
+    expect(formatLoudness(-Infinity, 'dBTP')).toBe('-inf dBTP')
+  })
+})
@@ -0,0 +1,278 @@
+/**
+ * ITU-R BS.1770-4 / EBU R128 loudness measurement.
+ *
+ * - K-weighting: the two-stage pre-filter (high shelf + RLB high-pass),
+ *   with coefficients derived for any sample rate.
+ * - Momentary (400 ms) and short-term (3 s) loudness, updated every 100 ms.
+ * - Integrated loudness over 400 ms blocks with 75% overlap, gated at
+ *   -70 LUFS absolute and -10 LU relative.
+ * - True peak via 4× polyphase oversampling (2× at 96 kHz and up).
+ *
+ * Channels are weighted equally (L/R/mono); surround weights aren't needed
+ * because the editor mixes to stereo.
+ */
+
+export interface LoudnessTarget {
+  integratedLufs: number
+  truePeakDbtp: number
+}
+
+export interface LoudnessSnapshot {
+  /** Last 400 ms. -Infinity when silent or not enough audio yet. */
+  momentaryLufs: number
+  /** Last 3 s. */
+  shortTermLufs: number
+  /** Gated programme loudness so far. */
+  integratedLufs: number
+  momentaryMaxLufs: number
+  shortTermMaxLufs: number
+  truePeakDbtp: number
+  durationSeconds: number
+}
+
+export interface LoudnessMeter {
+  /** Feed one block of planar samples (mono or stereo, equal lengths). */
+  push(channels: readonly Float32Array[]): void
+  snapshot(): LoudnessSnapshot
+  reset(): void
+}
+
+const ABSOLUTE_GATE_LUFS = -70
+const RELATIVE_GATE_LU = -10
+const TRUE_PEAK_TAPS_PER_PHASE = 12
+
+interface Biquad {
+  b0: number
+  b1: number
+  b2: number
+  a1: number
+  a2: number
+}
+
+function kWeightingFilters(sampleRate: number): [Biquad, Biquad] {
+  // Stage 1: high shelf (+4 dB above ~1.5 kHz).
+  let K = Math.tan((Math.PI * 1681.974450955533) / sampleRate)
+  const Vh = Math.pow(10, 3.999843853973347 / 20)
+  const Vb = Math.pow(Vh, 0.4996667741545416)
+  let Q = 0.7071752369554196
+  let a0 = 1 + K / Q + K * K
+  const shelf: Biquad = {
+    b0: (Vh + (Vb * K) / Q + K * K) / a0,
+    b1: (2 * (K * K - Vh)) / a0,
+    b2: (Vh - (Vb * K) / Q + K * K) / a0,
+    a1: (2 * (K * K - 1)) / a0,
+    a2: (1 - K / Q + K * K) / a0,
+  }
+
+  // Stage 2: RLB high-pass (~38 Hz).
+  K = Math.tan((Math.PI * 38.13547087602444) / sampleRate)
+  Q = 0.5003270373238773
+  a0 = 1 + K / Q + K * K
+  const highPass: Biquad = {
+    b0: 1,
+    b1: -2,
+    b2: 1,
+    a1: (2 * (K * K - 1)) / a0,
+    a2: (1 - K / Q + K * K) / a0,
+  }
+  return [shelf, highPass]
+}
+
+/** Windowed-sinc interpolation filter split into `factor` polyphase branches. */
+function truePeakPhases(factor: number): Float64Array[] {
+  const length = factor * TRUE_PEAK_TAPS_PER_PHASE
+  const centre = (length - 1) / 2
+  const taps = new Float64Array(length)
+  for (let n = 0; n < length; n++) {
+    const x = (n - centre) / factor
+    const sinc = x === 0 ? 1 : Math.sin(Math.PI * x) / (Math.PI * x)
+    const window = 0.5 - 0.5 * Math.cos((2 * Math.PI * (n + 0.5)) / length)
+    taps[n] = sinc * window
+  }
+  return Array.from({ length: factor }, (_, phase) => {
+    const branch = new Float64Array(TRUE_PEAK_TAPS_PER_PHASE)
+    for (let k = 0; k < TRUE_PEAK_TAPS_PER_PHASE; k++) branch[k] = taps[k * factor + phase]!
+    return branch
+  })
+}
+
+export function energyToLufs(meanSquare: number): number {
+  return meanSquare > 0 ? -0.691 + 10 * Math.log10(meanSquare) : -Infinity
+}
+
+function lufsToEnergy(lufs: number): number {
+  return Math.pow(10, (lufs + 0.691) / 10)
+}
+
+export function createLoudnessMeter(sampleRate: number, channelCount: number): LoudnessMeter {
+  const [shelf, highPass] = kWeightingFilters(sampleRate)
+  const subBlockLength = Math.round(sampleRate / 10)
+  const oversampling = sampleRate >= 96000 ? 2 : 4
+  const phases = truePeakPhases(oversampling)
+
+  // Per channel: biquad states (x1, x2, y1, y2 for both stages) + true-peak history.
+  let filterState = new Float64Array(channelCount * 8)
+  let peakHistory = Array.from(
+    { length: channelCount },
+    () => new Float64Array(TRUE_PEAK_TAPS_PER_PHASE),
+  )
+  let subBlockEnergy = 0
+  let subBlockFill = 0
+  // Rolling 100 ms energies (sum over channels of mean squares); 30 = 3 s.
+  let recent: number[] = []
+  let gatingBlocks: number[] = []
+  let momentaryMax = -Infinity
+  let shortTermMax = -Infinity
+  let truePeak = 0
+  let totalSamples = 0
+
+  const windowEnergy = (count: number) => {
+    if (recent.length < count) return 0
+    let sum = 0
+    for (let i = recent.length - count; i < recent.length; i++) sum += recent[i]!
+    return sum / count
+  }
+
+  const completeSubBlock = () => {
+    recent.push(subBlockEnergy / subBlockLength)
+    if (recent.length > 30) recent.shift()
+    subBlockEnergy = 0
+    subBlockFill = 0
+
+    if (recent.length >= 4) {
+      const momentary = windowEnergy(4)
+      gatingBlocks.push(momentary)
+      momentaryMax = Math.max(momentaryMax, energyToLufs(momentary))
+    }
+    if (recent.length >= 30) {
+      shortTermMax = Math.max(shortTermMax, energyToLufs(windowEnergy(30)))
+    }
+  }
+
+  return {
+    push(channels) {
+      const frames = channels[0]?.length ?? 0
+      for (let i = 0; i < frames; i++) {
+        let energy = 0
+        for (let c = 0; c < channelCount; c++) {
+          const input = channels[c]?.[i] ?? 0
+          const s = c * 8
+
+          const y1 =
+            shelf.b0 * input +
+            shelf.b1 * filterState[s]! +
+            shelf.b2 * filterState[s + 1]! -
+            shelf.a1 * filterState[s + 2]! -
+            shelf.a2 * filterState[s + 3]!
+          filterState[s + 1] = filterState[s]!
+          filterState[s] = input
+          filterState[s + 3] = filterState[s + 2]!
+          filterState[s + 2] = y1
+
+          const y2 =
+            highPass.b0 * y1 +
+            highPass.b1 * filterState[s + 4]! +
+            highPass.b2 * filterState[s + 5]! -
+            highPass.a1 * filterState[s + 6]! -
+            highPass.a2 * filterState[s + 7]!
+          filterState[s + 5] = filterState[s + 4]!
+          filterState[s + 4] = y1
+          filterState[s + 7] = filterState[s + 6]!
+          filterState[s + 6] = y2
+          energy += y2 * y2
+
+          const history = peakHistory[c]!
+          history.copyWithin(1, 0)
+          history[0] = input
+          for (const branch of phases) {
+            let value = 0
+            for (let k = 0; k < TRUE_PEAK_TAPS_PER_PHASE; k++) value += branch[k]! * history[k]!
+            const magnitude = Math.abs(value)
+            if (magnitude > truePeak) truePeak = magnitude
+          }
+        }
+
+        subBlockEnergy += energy
+        subBlockFill++
+        totalSamples++
+        if (subBlockFill === subBlockLength) completeSubBlock()
+      }
+    },
+
+    snapshot() {
+      const absoluteGated = gatingBlocks.filter((e) => e > lufsToEnergy(ABSOLUTE_GATE_LUFS))
+      let integrated = -Infinity
+      if (absoluteGated.length > 0) {
+        const mean = absoluteGated.reduce((sum, e) => sum + e, 0) / absoluteGated.length
+        const relativeGate = lufsToEnergy(energyToLufs(mean) + RELATIVE_GATE_LU)
+        const gated = absoluteGated.filter((e) => e > relativeGate)
+        if (gated.length > 0) {
+          integrated = energyToLufs(gated.reduce((sum, e) => sum + e, 0) / gated.length)
+        }
+      }
+
+      return {
+        momentaryLufs: recent.length >= 4 ? energyToLufs(windowEnergy(4)) : -Infinity,
+        shortTermLufs: recent.length >= 30 ? energyToLufs(windowEnergy(30)) : -Infinity,
+        integratedLufs: integrated,
+        momentaryMaxLufs: momentaryMax,
+        shortTermMaxLufs: shortTermMax,
+        truePeakDbtp: truePeak > 0 ? 20 * Math.log10(truePeak) : -Infinity,
+        durationSeconds: totalSamples / sampleRate,
+      }
+    },
+
+    reset() {
+      filterState = new Float64Array(channelCount * 8)
+      peakHistory = Array.from(
+        { length: channelCount },
+        () => new Float64Array(TRUE_PEAK_TAPS_PER_PHASE),
+      )
+      subBlockEnergy = 0
+      subBlockFill = 0
+      recent = []
+      gatingBlocks = []
+      momentaryMax = -Infinity
+      shortTermMax = -Infinity
+      truePeak = 0
+      totalSamples = 0
+    },
+  }
+}
+
+/** Measure a complete planar buffer in one pass. */
+export function measureLoudness(
+  channels: readonly Float32Array[],
+  sampleRate: number,
+): LoudnessSnapshot {
+  const meter = createLoudnessMeter(sampleRate, Math.max(1, channels.length))
+  meter.push(channels)
+  return meter.snapshot()
+}
+
+/**
+ * Gain that brings `measured` to the target's integrated loudness without
+ * pushing true peak past its ceiling. When the ceiling wins, the result
+ * lands quieter than the target rather than being limited.
+ */
+export function getLoudnessNormalizationGain(
+  measured: Pick<LoudnessSnapshot, 'integratedLufs' | 'truePeakDbtp'>,
+  target: LoudnessTarget,
+): { gainDb: number; limitedByTruePeak: boolean } {
+  if (!Number.isFinite(measured.integratedLufs)) {
+    return { gainDb: 0, limitedByTruePeak: false }
+  }
+  const loudnessGain = target.integratedLufs - measured.integratedLufs
+  const peakGain = Number.isFinite(measured.truePeakDbtp)
+    ? target.truePeakDbtp - measured.truePeakDbtp
+    : Infinity
+  return loudnessGain <= peakGain
+    ? { gainDb: loudnessGain, limitedByTruePeak: false }
+    : { gainDb: peakGain, limitedByTruePeak: true }
+}
+
+/** `-14.2 LUFS`-style label; silence reads as `-inf`. */
+export function formatLoudness(value: number, unit: 'LUFS' | 'dBTP' | 'LU' = 'LUFS'): string {
+  if (!Number.isFinite(value)) return `-inf ${unit}`
+  return `${value.toFixed(1)} ${unit}`
+}
@@ -137,7 +137,7 @@ describe('local-model-cache', () => {
   it('inspects configured local model caches without creating missing caches', async () => {
     const summaries = await inspectAllLocalModelCaches()
 
-    expect(summaries).toHaveLength(7)
+    expect(summaries).toHaveLength(9)
     expect(summaries.map((summary) => summary.id)).toEqual([
       'whisper',
       ...SCENE_VERIFICATION_MODEL_IDS,
@@ -145,6 +145,8 @@ describe('local-model-cache', () => {
       'kokoro-tts',
       'parakeet',
       'supertonic-tts',
+      'subject-segmentation',
+      'speaker-diarization',
     ])
 
     expect(summaries).toContainEqual(
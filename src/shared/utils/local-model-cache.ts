@@ -23,6 +23,8 @@ export type LocalModelCacheId =
   | 'kokoro-tts'
   | 'parakeet'
   | 'supertonic-tts'
+  | 'subject-segmentation'
+  | 'speaker-diarization'
 
 export interface LocalModelCacheDefinition {
   id: LocalModelCacheId
@@ -96,6 +98,20 @@ export const LOCAL_MODEL_CACHE_DEFINITIONS: LocalModelCacheDefinition[] = [
     cacheName: ONNX_MODEL_CACHE_NAME,
     matchPathFragments: ['/supertonic-3/'],
   },
+  {
+    id: 'subject-segmentation',
+    label: 'Subject Segmentation',
+    description: 'MODNet matting model used by Remove Background and subject matte masks.',
+    cacheName: TRANSFORMERS_CACHE_NAME,
+    matchPathFragments: ['/xenova/modnet/'],
+  },
+  {
+    id: 'speaker-diarization',
+    label: 'Speaker Diarization',
+    description: 'WavLM speaker-embedding model used to label who is speaking in transcripts.',
+    cacheName: TRANSFORMERS_CACHE_NAME,
+    matchPathFragments: ['/xenova/wavlm-base-plus-sv/'],
+  },
 ]
 
 function getCacheStorage(): CacheStorage | null {
@@ -0,0 +1,133 @@
+import { describe, expect, it } from 'vite-plus/test'
+import type { ItemKeyframes } from '@/types/keyframe'
+import type { MulticamItem, TimelineItem } from '@/types/timeline'
+import {
+  expandMulticamItem,
+  expandMulticamItems,
+  expandMulticamKeyframes,
+  getMulticamAngleAtClockFrame,
+  getMulticamCutRanges,
+} from './multicam'
+
+function makeMulticamItem(overrides: Partial<MulticamItem> = {}): MulticamItem {
+  return {
+    id: 'mc-1',
+    type: 'multicam',
+    trackId: 'track-v1',
+    from: 100,
+    durationInFrames: 90,
+    label: 'Multicam',
+    sourceStart: 30,
+    sourceEnd: 120,
+    sourceDuration: 300,
+    sourceFps: 30,
+    angles: [
+      {
+        id: 'a',
+        label: 'Angle 1',
+        mediaId: 'media-a',
+        src: 'a.mp4',
+        sourceFps: 30,
+        sourceDuration: 300,
+        syncOffset: 0,
+      },
+      {
+        id: 'b',
+        label: 'Angle 2',
+        mediaId: 'media-b',
+        src: 'b.mp4',
+        sourceFps: 60,
+        sourceDuration: 600,
+        syncOffset: 0.5,
+      },
+    ],
+    angleSegments: [
+      { id: 's1', startFrame: 0, angleId: 'a' },
+      { id: 's2', startFrame: 60, angleId: 'b' },
+    ],
+    audioAngleId: 'a',
+    syncMethod: 'audio',
+    ...overrides,
+  }
+}
+
+describe('multicam', () => {
+  it('resolves the angle selected at a clock frame', () => {
+    const item = makeMulticamItem()
+    expect(getMulticamAngleAtClockFrame(item, 10)?.id).toBe('a')
+    expect(getMulticamAngleAtClockFrame(item, 60)?.id).toBe('b')
+  })
+
+  it('maps cut segments onto timeline frames within the item bounds', () => {
+    const ranges = getMulticamCutRanges(makeMulticamItem(), 30)
+    expect(ranges.map(({ angle, from, durationInFrames }) => [angle.id, from, durationInFrames]))
+      .toEqual([
+        ['a', 100, 30],
+        ['b', 130, 60],
+      ])
+  })
+
+  it('clips cuts to the footage an angle has', () => {
+    const item = makeMulticamItem({
+      sourceStart: 0,
+      angleSegments: [{ id: 's1', startFrame: 0, angleId: 'b' }],
+    })
+    // Angle b starts 0.5s (15 clock frames) after the clock origin
+    expect(getMulticamCutRanges(item, 30)[0]).toMatchObject({ from: 115, durationInFrames: 75 })
+  })
+
+  it('expands into muted video pieces and one audio item', () => {
+    const pieces = expandMulticamItem(makeMulticamItem(), 30)
+    expect(pieces).toHaveLength(3)
+
+    const [first, second, audio] = pieces
+    expect(first).toMatchObject({
+      type: 'video',
+      id: 'mc-1::s1',
+      mediaId: 'media-a',
+      sourceStart: 30,
+      sourceEnd: 60,
+      embeddedAudioMuted: true,
+    })
+    // Clock frame 60 = 2s; angle b is 0.5s late and runs at 60fps
+    expect(second).toMatchObject({
+      type: 'video',
+      mediaId: 'media-b',
+      from: 130,
+      sourceStart: 90,
+      sourceEnd: 210,
+      sourceFps: 60,
+    })
+    expect(audio).toMatchObject({
+      type: 'audio',
+      id: 'mc-1::audio',
+      mediaId: 'media-a',
+      from: 100,
+      durationInFrames: 90,
+      sourceStart: 30,
+    })
+  })
+
+  it('leaves item lists without multicam items untouched', () => {
+    const items: TimelineItem[] = []
+    expect(expandMulticamItems(items, 30)).toBe(items)
+  })
+
+  it('shifts keyframes onto each piece', () => {
+    const item = makeMulticamItem()
+    const keyframes: ItemKeyframes[] = [
+      {
+        itemId: 'mc-1',
+        properties: [
+          {
+            property: 'opacity',
+            keyframes: [{ id: 'k1', frame: 45, value: 0.5, easing: 'linear' }],
+          },
+        ],
+      },
+    ]
+    const expanded = expandMulticamKeyframes(keyframes, [item], 30)
+    const second = expanded.find((entry) => entry.itemId === 'mc-1::s2')
+    expect(second?.properties[0]?.keyframes[0]?.frame).toBe(15)
+  })
+})
@@ -0,0 +1,252 @@
+import type { ItemKeyframes } from '@/types/keyframe'
+import type {
+  AudioItem,
+  MulticamAngle,
+  MulticamItem,
+  TimelineItem,
+  VideoItem,
+} from '@/types/timeline'
+
+/**
+ * Multicam items run on their own clock: `sourceStart`/`sourceEnd` are
+ * project-fps frames (like compound wrappers) and every angle is placed on
+ * that clock by its `syncOffset`. Rendering never sees the multicam item
+ * itself — it is expanded into ordinary video pieces (one per visible cut)
+ * plus a single audio item for the audio angle.
+ */
+
+export interface MulticamCutRange {
+  segmentId: string
+  angle: MulticamAngle
+  /** Timeline frame where the cut becomes visible */
+  from: number
+  durationInFrames: number
+}
+
+/** Multicam clock frame shown at `timelineFrame`. */
+export function getMulticamClockFrame(item: MulticamItem, timelineFrame: number): number {
+  return (item.sourceStart ?? 0) + (timelineFrame - item.from)
+}
+
+/** Angle selected by the cut list at a multicam clock frame. */
+export function getMulticamAngleAtClockFrame(
+  item: MulticamItem,
+  clockFrame: number,
+): MulticamAngle | undefined {
+  let angleId = item.angleSegments[0]?.angleId ?? item.angles[0]?.id
+  for (const segment of item.angleSegments) {
+    if (segment.startFrame > clockFrame) break
+    angleId = segment.angleId
+  }
+  return item.angles.find((angle) => angle.id === angleId)
+}
+
+/** Index into `angleSegments` of the segment active at a clock frame (-1 when none). */
+export function getMulticamSegmentIndexAtClockFrame(
+  item: MulticamItem,
+  clockFrame: number,
+): number {
+  if (item.angleSegments.length === 0) return -1
+  let index = 0
+  for (let i = 1; i < item.angleSegments.length; i++) {
+    if (item.angleSegments[i]!.startFrame > clockFrame) break
+    index = i
+  }
+  return index
+}
+
+/** Clock frames covered by an angle's footage. */
+function getAngleClockRange(angle: MulticamAngle, fps: number): { start: number; end: number } {
+  const start = Math.ceil(angle.syncOffset * fps)
+  const end = Math.floor((angle.syncOffset + angle.sourceDuration / angle.sourceFps) * fps)
+  return { start, end }
+}
+
+/**
+ * Visible cuts of a multicam item in timeline frames, clipped to the item's
+ * bounds and to the footage each angle actually has. Adjacent segments that
+ * select the same angle are merged.
+ */
+export function getMulticamCutRanges(item: MulticamItem, fps: number): MulticamCutRange[] {
+  const clockStart = item.sourceStart ?? 0
+  const clockEnd = clockStart + item.durationInFrames
+  const segments =
+    item.angleSegments.length > 0
+      ? item.angleSegments
+      : item.angles[0]
+        ? [{ id: 'default', startFrame: clockStart, angleId: item.angles[0].id }]
+        : []
+
+  const ranges: MulticamCutRange[] = []
+  for (let i = 0; i < segments.length; i++) {
+    const segment = segments[i]!
+    const angle = item.angles.find((candidate) => candidate.id === segment.angleId)
+    if (!angle) continue
+
+    const segmentStart = i === 0 ? clockStart : segment.startFrame
+    const segmentEnd = segments[i + 1]?.startFrame ?? clockEnd
+    const footage = getAngleClockRange(angle, fps)
+    const start = Math.max(segmentStart, clockStart, footage.start)
+    const end = Math.min(segmentEnd, clockEnd, footage.end)
+    if (end <= start) continue
+
+    const from = item.from + (start - clockStart)
+    const previous = ranges[ranges.length - 1]
+    if (
+      previous &&
+      previous.angle.id === angle.id &&
+      previous.from + previous.durationInFrames === from
+    ) {
+      previous.durationInFrames += end - start
+      continue
+    }
+    ranges.push({ segmentId: segment.id, angle, from, durationInFrames: end - start })
+  }
+  return ranges
+}
+
+function toAngleSourceFrame(angle: MulticamAngle, clockFrame: number, fps: number): number {
+  return Math.max(0, Math.round((clockFrame / fps - angle.syncOffset) * angle.sourceFps))
+}
+
+/**
+ * Expand a multicam item into the plain video/audio items it renders as.
+ * Piece ids are derived from the item id so keyframes and caches stay stable.
+ */
+export function expandMulticamItem(item: MulticamItem, fps: number): Array<VideoItem | AudioItem> {
+  const {
+    angles: _angles,
+    angleSegments: _angleSegments,
+    audioAngleId,
+    syncMethod: _syncMethod,
+    type: _type,
+    ...base
+  } = item
+  const clockStart = item.sourceStart ?? 0
+  const itemEnd = item.from + item.durationInFrames
+  const pieces: Array<VideoItem | AudioItem> = []
+
+  for (const range of getMulticamCutRanges(item, fps)) {
+    const { angle } = range
+    const clockFrame = clockStart + (range.from - item.from)
+    const sourceStart = toAngleSourceFrame(angle, clockFrame, fps)
+    const sourceEnd = Math.min(
+      angle.sourceDuration,
+      sourceStart + Math.round((range.durationInFrames / fps) * angle.sourceFps),
+    )
+    pieces.push({
+      ...base,
+      type: 'video',
+      id: `${item.id}::${range.segmentId}`,
+      originId: undefined,
+      linkedGroupId: undefined,
+      compositionId: undefined,
+      from: range.from,
+      durationInFrames: range.durationInFrames,
+      mediaId: angle.mediaId,
+      src: angle.src,
+      sourceStart,
+      sourceEnd,
+      sourceDuration: angle.sourceDuration,
+      sourceFps: angle.sourceFps,
+      sourceWidth: angle.sourceWidth,
+      sourceHeight: angle.sourceHeight,
+      trimStart: undefined,
+      trimEnd: undefined,
+      speed: 1,
+      embeddedAudioMuted: true,
+      // Video fades only belong on the outer edges of the multicam clip
+      fadeIn: range.from === item.from ? item.fadeIn : undefined,
+      fadeOut: range.from + range.durationInFrames === itemEnd ? item.fadeOut : undefined,
+    })
+  }
+
+  const audioAngle = item.angles.find((angle) => angle.id === audioAngleId) ?? item.angles[0]
+  if (audioAngle) {
+    const footage = getAngleClockRange(audioAngle, fps)
+    const start = Math.max(clockStart, footage.start)
+    const end = Math.min(clockStart + item.durationInFrames, footage.end)
+    if (end > start) {
+      const sourceStart = toAngleSourceFrame(audioAngle, start, fps)
+      pieces.push({
+        ...base,
+        type: 'audio',
+        id: `${item.id}::audio`,
+        originId: undefined,
+        linkedGroupId: undefined,
+        compositionId: undefined,
+        from: item.from + (start - clockStart),
+        durationInFrames: end - start,
+        mediaId: audioAngle.mediaId,
+        src: audioAngle.src,
+        sourceStart,
+        sourceEnd: Math.min(
+          audioAngle.sourceDuration,
+          sourceStart + Math.round(((end - start) / fps) * audioAngle.sourceFps),
+        ),
+        sourceDuration: audioAngle.sourceDuration,
+        sourceFps: audioAngle.sourceFps,
+        trimStart: undefined,
+        trimEnd: undefined,
+        speed: 1,
+        transform: undefined,
+        crop: undefined,
+        effects: undefined,
+        blendMode: undefined,
+        cornerPin: undefined,
+        fadeIn: undefined,
+        fadeOut: undefined,
+      })
+    }
+  }
+
+  return pieces
+}
+
+/**
+ * Replace every multicam item with its rendered pieces. Returns `items`
+ * untouched when there are none.
+ */
+export function expandMulticamItems<TItem extends TimelineItem>(
+  items: TItem[],
+  fps: number,
+): Array<TItem | VideoItem | AudioItem> {
+  if (!items.some((item) => item.type === 'multicam')) return items
+  return items.flatMap((item) =>
+    item.type === 'multicam' ? expandMulticamItem(item as MulticamItem, fps) : [item],
+  )
+}
+
+/**
+ * Copy each multicam item's keyframes onto its expanded pieces, shifted so
+ * they stay anchored to the same timeline frames.
+ */
+export function expandMulticamKeyframes(
+  keyframes: ItemKeyframes[],
+  items: TimelineItem[],
+  fps: number,
+): ItemKeyframes[] {
+  const multicamItems = items.filter((item): item is MulticamItem => item.type === 'multicam')
+  if (multicamItems.length === 0) return keyframes
+
+  const keyframesByItemId = new Map(keyframes.map((entry) => [entry.itemId, entry]))
+  const expanded = [...keyframes]
+  for (const item of multicamItems) {
+    const entry = keyframesByItemId.get(item.id)
+    if (!entry) continue
+    for (const piece of expandMulticamItem(item, fps)) {
+      const shift = piece.from - item.from
+      expanded.push({
+        itemId: piece.id,
+        properties: entry.properties.map((property) => ({
+          ...property,
+          keyframes: property.keyframes.map((keyframe) => ({
+            ...keyframe,
+            frame: keyframe.frame - shift,
+          })),
+        })),
+      })
+    }
+  }
+  return expanded
+}
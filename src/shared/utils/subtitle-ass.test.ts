@@ -0,0 +1,279 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { parseAss, serializeAss } from './subtitle-ass'
+
+const FRAME = { frameWidth: 1920, frameHeight: 1080 }
+
+const STYLE_FORMAT =
+  'Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding'
+const EVENT_FORMAT =
+  'Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text'
+
+function script(styles: string[], events: string[], playRes = [1920, 1080]): string {
+  return [
+    '[Script Info]',
+    'ScriptType: v4.00+',
+    `PlayResX: ${playRes[0]}`,
+    `PlayResY: ${playRes[1]}`,
+    '',
+    '[V4+ Styles]',
+    STYLE_FORMAT,
+    ...styles,
+    '',
+    '[Events]',
+    EVENT_FORMAT,
+    ...events,
+  ].join('\n')
+}
+
+describe('parseAss', () => {
+  it('maps the most used style onto text style fields and a caption region', () => {
+    const result = parseAss(
+      script(
+        [
+          'Style: Default,Roboto,54,&H0000FFFF,&H000000FF,&H00101010,&H80000000,-1,0,0,0,100,100,0,0,1,3,2,2,96,96,60,1',
+        ],
+        ['Dialogue: 0,0:00:01.00,0:00:03.50,Default,,0,0,0,,Hello'],
+      ),
+      FRAME,
+    )
+
+    expect(result.style).toMatchObject({
+      fontFamily: 'Roboto',
+      fontSize: 54,
+      fontWeight: 'bold',
+      fontStyle: 'normal',
+      color: '#ffff00',
+      stroke: { width: 3, color: '#101010' },
+      textShadow: { offsetX: 2, offsetY: 2, blur: 0, color: 'rgba(0, 0, 0, 0.498)' },
+      backgroundColor: undefined,
+      textAlign: 'center',
+      verticalAlign: 'bottom',
+    })
+    expect(result.region?.left).toBeCloseTo(0.05)
+    expect(result.region?.width).toBeCloseTo(0.9)
+    expect(result.region?.top).toBeCloseTo(1 - 60 / 1080 - 0.16)
+    expect(result.cues).toEqual([{ id: 'cue-1', startSeconds: 1, endSeconds: 3.5, text: 'Hello' }])
+  })
+
+  it('scales sizes from PlayRes to the frame and turns opaque boxes into backgrounds', () => {
+    const result = parseAss(
+      script(
+        [
+          'Style: Default,Arial,20,&H00FFFFFF,&H000000FF,&H40000000,&H00000000,0,0,0,0,100,100,0,0,3,4,0,2,10,10,10,1',
+        ],
+        ['Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,Boxed'],
+        [384, 288],
+      ),
+      FRAME,
+    )
+
+    expect(result.style?.fontSize).toBe(75)
+    expect(result.style?.backgroundColor).toBe('rgba(0, 0, 0, 0.749)')
+    expect(result.style?.textPadding).toBe(15)
+    expect(result.style?.stroke).toBeUndefined()
+  })
+
+  it('converts override tags to cue markup and reports the ones it cannot keep', () => {
+    const result = parseAss(
+      script(
+        [
+          'Style: Default,Arial,48,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1',
+        ],
+        [
+          'Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,Hello {\\i1}there{\\i0}\\Nworld',
+          'Dialogue: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,{\\an8}{\\c&H0000FF&}Red{\\c} text',
+          'Dialogue: 0,0:00:05.00,0:00:06.00,Default,,0,0,0,,{\\blur3\\fad(200,200)}Soft {comment}edge',
+          'Dialogue: 0,0:00:07.00,0:00:08.00,Default,,0,0,0,,{\\pos(960,100)}Top',
+        ],
+      ),
+      FRAME,
+    )
+
+    expect(result.cues.map((cue) => cue.text)).toEqual([
+      'Hello <i>there</i>\nworld',
+      '{\\an8}<font color="#ff0000">Red</font> text',
+      'Soft edge',
+      '{\\an8}Top',
+    ])
+    expect(result.warnings).toEqual([
+      'Override edge blur (\\be/\\blur) is not supported',
+      'Override fades (\\fad) is not supported',
+      'Exact positions (\\pos) were snapped to the nearest screen position',
+    ])
+  })
+
+  it('keeps other styles as inline markup and names what was lost', () => {
+    const result = parseAss(
+      script(
+        [
+          'Style: Default,Arial,48,&H00FFFFFF,&H000000FF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1',
+          'Style: Sign,Georgia,48,&H0000FFFF,&H000000FF,&H00000000,&H00000000,0,-1,0,0,100,100,0,0,1,2,0,8,10,10,10,1',
+        ],
+        [
+          'Dialogue: 0,0:00:01.00,0:00:02.00,Default,,0,0,0,,One',
+          'Dialogue: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,Two',
+          'Dialogue: 0,0:00:05.00,0:00:06.00,Sign,,0,0,0,,Exit',
+        ],
+      ),
+      FRAME,
+    )
+
+    expect(result.style?.fontFamily).toBe('Arial')
+    expect(result.cues[2]?.text).toBe('{\\an8}<i><font color="#ffff00">Exit</font></i>')
+    expect(result.warnings).toContain('Style "Sign": font not kept (uses "Default")')
+  })
+
+  it('reads karaoke syllables as word timings', () => {
+    const result = parseAss(
+      script(
+        [
+          'Style: Default,Arial,48,&H0000FFFF,&H00FFFFFF,&H00000000,&H00000000,0,0,0,0,100,100,0,0,1,2,0,2,10,10,10,1',
+        ],
+        ['Dialogue: 0,0:00:10.00,0:00:12.00,Default,,0,0,0,,{\\k50}Ka{\\k30}ra {\\k70}oke'],
+      ),
+      FRAME,
+    )
+
+    expect(result.cues[0]?.text).toBe('Kara oke')
+    expect(result.cues[0]?.words).toEqual([
+      { text: 'Kara', startSeconds: 10, endSeconds: 10.8 },
+      { text: 'oke', startSeconds: 10.8, endSeconds: 11.5 },
+    ])
+    expect(result.style?.color).toBe('#ffffff')
+    expect(result.karaoke).toEqual({ activeColor: '#ffff00' })
+  })
+
+  it('reads SSA v4 styles with legacy alignment', () => {
+    const result = parseAss(
+      [
+        '[Script Info]',
+        'ScriptType: v4.00',
+        'PlayResY: 480',
+        '',
+        '[V4 Styles]',
+        'Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, TertiaryColour, BackColour, Bold, Italic, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, AlphaLevel, Encoding',
+        'Style: Default,Tahoma,24,16777215,65535,0,0,0,0,1,2,0,6,20,20,20,0,1',
+        '',
+        '[Events]',
+        'Format: Marked, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text',
+        'Dialogue: Marked=0,0:00:01.00,0:00:02.00,Default,,0000,0000,0000,,Top line',
+      ].join('\n'),
+      FRAME,
+      'ssa',
+    )
+
+    expect(result.style).toMatchObject({
+      fontFamily: 'Tahoma',
+      fontSize: 54,
+      color: '#ffffff',
+      verticalAlign: 'top',
+    })
+    expect(result.cues[0]?.text).toBe('Top line')
+  })
+})
+
+describe('serializeAss', () => {
+  const document = {
+    ...FRAME,
+    cues: [
+      { id: 'a', startSeconds: 1, endSeconds: 2.5, text: 'Hi <b>there</b>' },
+      { id: 'b', startSeconds: 3, endSeconds: 4, text: 'Second {line}', speakerId: 'spk-1' },
+    ],
+    style: {
+      fontFamily: 'Inter',
+      fontSize: 48,
+      fontWeight: 'semibold' as const,
+      color: '#ffffff',
+      backgroundColor: 'rgba(0, 0, 0, 0.5)',
+      backgroundRadius: 6,
+      textPadding: 8,
+      textAlign: 'center' as const,
+      verticalAlign: 'bottom' as const,
+    },
+    region: { left: 0.1, top: 0.7, width: 0.8, height: 0.2 },
+    speakerStyles: { 'spk-1': { name: 'Ana', color: '#ffff00' } },
+  }
+
+  it('writes styles, speaker styles and dialogue lines in timeline time', () => {
+    const result = serializeAss(document)
+    const lines = result.text.split('\n')
+
+    expect(lines).toContain('PlayResX: 1920')
+    expect(lines).toContain(
+      'Style: Default,Inter,48,&H00FFFFFF,&H00FFFFFF,&H80000000,&H80000000,-1,0,0,0,100,100,0,0,3,8,0,2,192,192,108,1',
+    )
+    expect(lines.some((line) => line.startsWith('Style: Speaker Ana,Inter,48,&H0000FFFF'))).toBe(
+      true,
+    )
+    expect(lines).toContain(
+      'Dialogue: 0,0:00:01.00,0:00:02.50,Default,,0000,0000,0000,,Hi {\\b1}there{\\b0}',
+    )
+    expect(lines).toContain(
+      'Dialogue: 0,0:00:03.00,0:00:04.00,Speaker Ana,Ana,0000,0000,0000,,Second (line)',
+    )
+    expect(result.warnings).toEqual([
+      'Font weight "semibold" was exported as bold',
+      'Rounded background corners are not supported',
+    ])
+  })
+
+  it('round-trips through parseAss', () => {
+    const parsed = parseAss(serializeAss(document).text, FRAME)
+
+    expect(parsed.cues.map((cue) => [cue.startSeconds, cue.endSeconds])).toEqual([
+      [1, 2.5],
+      [3, 4],
+    ])
+    expect(parsed.cues[0]?.text).toBe('Hi <b>there</b>')
+    expect(parsed.style).toMatchObject({
+      fontFamily: 'Inter',
+      fontSize: 48,
+      textAlign: 'center',
+      verticalAlign: 'bottom',
+      textPadding: 8,
+    })
+    expect(parsed.region?.left).toBeCloseTo(0.1)
+    expect(parsed.region?.width).toBeCloseTo(0.8)
+  })
+
+  it('writes karaoke word timings as \\k syllables', () => {
+    const result = serializeAss({
+      ...FRAME,
+      cues: [
+        {
+          id: 'k',
+          startSeconds: 10,
+          endSeconds: 12,
+          text: 'Sing it',
+          words: [
+            { text: 'Sing', startSeconds: 10.2, endSeconds: 10.6 },
+            { text: 'it', startSeconds: 10.6, endSeconds: 11 },
+          ],
+        },
+      ],
+      style: { color: '#ffffff' },
+      karaoke: { activeColor: '#ff0000', activeScale: 1.2 },
+    })
+
+    expect(result.text).toContain(',,{\\k20}{\\k40}Sing {\\k40}it\n')
+    expect(result.text).toContain('Style: Default,Arial,48,&H000000FF,&H00FFFFFF,')
+    expect(result.warnings).toContain('Karaoke word scaling is not supported')
+  })
+
+  it('writes SSA with legacy alignment and without underline', () => {
+    const result = serializeAss(
+      {
+        ...FRAME,
+        cues: [{ id: 'a', startSeconds: 1, endSeconds: 2, text: '{\\an8}<u>Top</u>' }],
+        style: { color: '#ffffff', verticalAlign: 'bottom' },
+      },
+      'ssa',
+    )
+
+    expect(result.text).toContain('[V4 Styles]')
+    expect(result.text).toContain(
+      'Dialogue: Marked=0,0:00:01.00,0:00:02.00,Default,,0000,0000,0000,,{\\a6}Top',
+    )
+    expect(result.warnings).toContain('Underline is not supported in SSA')
+  })
+})
@@ -0,0 +1,1144 @@
+/**
+ * Advanced SubStation Alpha (`.ass`) and its SSA v4 predecessor (`.ssa`).
+ *
+ * Import keeps what the timeline can show. The most-used style becomes the
+ * segment style (font, colors, outline or opaque box, shadow, alignment, and
+ * margins as the caption box), with geometry scaled from the script's
+ * PlayRes to the frame. Other styles and inline overrides become the cue
+ * markup `subtitle-cue-format` renders, and `\k` karaoke becomes word
+ * timings. Everything else is reported.
+ *
+ * Export writes one style for the segment plus one per diarized speaker,
+ * at a PlayRes equal to the frame so sizes are plain pixels.
+ */
+import type { TextStyleFields } from '@/types/text'
+import type { CaptionWordTiming, SubtitleSegmentCue } from '@/types/timeline'
+import {
+  DEFAULT_SUBTITLE_REGION,
+  SubtitleWarningCollector,
+  formatCssColor,
+  isVisibleColor,
+  parseCssColor,
+  toHexByte,
+  type Rgba,
+  type SubtitleExportDocument,
+  type SubtitleExportResult,
+  type SubtitleFileParseResult,
+  type SubtitleFrameSize,
+  type SubtitleRegion,
+} from './subtitle-document'
+
+export type AssVariant = 'ass' | 'ssa'
+
+interface AssStyle {
+  name: string
+  fontName: string
+  fontSize: number
+  primary: Rgba
+  secondary: Rgba
+  outline: Rgba
+  back: Rgba
+  bold: boolean
+  italic: boolean
+  underline: boolean
+  strikeOut: boolean
+  scaleX: number
+  scaleY: number
+  spacing: number
+  angle: number
+  borderStyle: number
+  outlineWidth: number
+  shadow: number
+  /** Numpad alignment (`\an`), already converted from SSA's legacy numbering. */
+  alignment: number
+  marginL: number
+  marginR: number
+  marginV: number
+}
+
+interface AssEvent {
+  startSeconds: number
+  endSeconds: number
+  style: string
+  text: string
+  hasOwnMargins: boolean
+}
+
+const WHITE: Rgba = { r: 255, g: 255, b: 255, a: 1 }
+const BLACK: Rgba = { r: 0, g: 0, b: 0, a: 1 }
+
+/** What libass falls back to for a script without styles. */
+const DEFAULT_ASS_STYLE: AssStyle = {
+  name: 'Default',
+  fontName: 'Arial',
+  fontSize: 18,
+  primary: WHITE,
+  secondary: { r: 255, g: 0, b: 0, a: 1 },
+  outline: BLACK,
+  back: BLACK,
+  bold: false,
+  italic: false,
+  underline: false,
+  strikeOut: false,
+  scaleX: 100,
+  scaleY: 100,
+  spacing: 0,
+  angle: 0,
+  borderStyle: 1,
+  outlineWidth: 2,
+  shadow: 2,
+  alignment: 2,
+  marginL: 10,
+  marginR: 10,
+  marginV: 10,
+}
+
+const ASS_STYLE_FORMAT = [
+  'Name',
+  'Fontname',
+  'Fontsize',
+  'PrimaryColour',
+  'SecondaryColour',
+  'OutlineColour',
+  'BackColour',
+  'Bold',
+  'Italic',
+  'Underline',
+  'StrikeOut',
+  'ScaleX',
+  'ScaleY',
+  'Spacing',
+  'Angle',
+  'BorderStyle',
+  'Outline',
+  'Shadow',
+  'Alignment',
+  'MarginL',
+  'MarginR',
+  'MarginV',
+  'Encoding',
+]
+const SSA_STYLE_FORMAT = [
+  'Name',
+  'Fontname',
+  'Fontsize',
+  'PrimaryColour',
+  'SecondaryColour',
+  'TertiaryColour',
+  'BackColour',
+  'Bold',
+  'Italic',
+  'BorderStyle',
+  'Outline',
+  'Shadow',
+  'Alignment',
+  'MarginL',
+  'MarginR',
+  'MarginV',
+  'AlphaLevel',
+  'Encoding',
+]
+const ASS_EVENT_FORMAT = [
+  'Layer',
+  'Start',
+  'End',
+  'Style',
+  'Name',
+  'MarginL',
+  'MarginR',
+  'MarginV',
+  'Effect',
+  'Text',
+]
+const SSA_EVENT_FORMAT = ['Marked', ...ASS_EVENT_FORMAT.slice(1)]
+
+/** Override tag names, longest first so `\fscx` isn't read as `\fs`. */
+const OVERRIDE_TAG_NAMES = [
+  'alpha',
+  'fscx',
+  'fscy',
+  'xbord',
+  'ybord',
+  'xshad',
+  'yshad',
+  'iclip',
+  'bord',
+  'shad',
+  'blur',
+  'move',
+  'clip',
+  'fade',
+  'fax',
+  'fay',
+  'fsp',
+  'frx',
+  'fry',
+  'frz',
+  'pbo',
+  'org',
+  'pos',
+  'fad',
+  'an',
+  'fn',
+  'fs',
+  'fr',
+  'fe',
+  'be',
+  'kf',
+  'ko',
+  '1c',
+  '2c',
+  '3c',
+  '4c',
+  '1a',
+  '2a',
+  '3a',
+  '4a',
+  '[abiuscpqrkKt]',
+]
+const OVERRIDE_TAG_PATTERN = new RegExp(`^(${OVERRIDE_TAG_NAMES.join('|')})(.*)$`, 's')
+
+const UNSUPPORTED_OVERRIDE_LABELS: Record<string, string> = {
+  fn: 'per-line fonts (\\fn)',
+  fs: 'per-line font sizes (\\fs)',
+  fsp: 'per-line letter spacing (\\fsp)',
+  fscx: 'text scaling (\\fscx/\\fscy)',
+  fscy: 'text scaling (\\fscx/\\fscy)',
+  frx: 'rotation (\\fr)',
+  fry: 'rotation (\\fr)',
+  frz: 'rotation (\\fr)',
+  fr: 'rotation (\\fr)',
+  fax: 'shearing (\\fax/\\fay)',
+  fay: 'shearing (\\fax/\\fay)',
+  fe: 'font encodings (\\fe)',
+  bord: 'per-line outlines (\\bord)',
+  xbord: 'per-line outlines (\\bord)',
+  ybord: 'per-line outlines (\\bord)',
+  shad: 'per-line shadows (\\shad)',
+  xshad: 'per-line shadows (\\shad)',
+  yshad: 'per-line shadows (\\shad)',
+  be: 'edge blur (\\be/\\blur)',
+  blur: 'edge blur (\\be/\\blur)',
+  '2c': 'per-line karaoke, outline and shadow colors (\\2c-\\4c)',
+  '3c': 'per-line karaoke, outline and shadow colors (\\2c-\\4c)',
+  '4c': 'per-line karaoke, outline and shadow colors (\\2c-\\4c)',
+  alpha: 'transparency overrides (\\alpha)',
+  '1a': 'transparency overrides (\\alpha)',
+  '2a': 'transparency overrides (\\alpha)',
+  '3a': 'transparency overrides (\\alpha)',
+  '4a': 'transparency overrides (\\alpha)',
+  org: 'rotation origins (\\org)',
+  fad: 'fades (\\fad)',
+  fade: 'fades (\\fad)',
+  t: 'animated transforms (\\t)',
+  clip: 'clipping (\\clip)',
+  iclip: 'clipping (\\clip)',
+  pbo: 'vector drawings (\\p)',
+  q: 'wrapping styles (\\q)',
+}
+
+export function parseAss(
+  text: string,
+  frame: SubtitleFrameSize,
+  variant: AssVariant = 'ass',
+): SubtitleFileParseResult {
+  const warnings = new SubtitleWarningCollector()
+  const scriptInfo = new Map<string, string>()
+  const styles = new Map<string, AssStyle>()
+  const events: AssEvent[] = []
+  let section = ''
+  let styleFormat = variant === 'ssa' ? SSA_STYLE_FORMAT : ASS_STYLE_FORMAT
+  let eventFormat = variant === 'ssa' ? SSA_EVENT_FORMAT : ASS_EVENT_FORMAT
+  let legacyAlignment = variant === 'ssa'
+
+  const lines = stripBom(text).replace(/\r\n?/g, '\n').split('\n')
+  for (const [lineIndex, rawLine] of lines.entries()) {
+    const line = rawLine.trim()
+    if (!line || line.startsWith(';') || line.startsWith('!:')) continue
+
+    const header = /^\[(.+)\]$/.exec(line)
+    if (header) {
+      section = header[1]!.trim().toLowerCase()
+      if (section === 'v4 styles') legacyAlignment = true
+      if (section === 'v4+ styles') legacyAlignment = false
+      continue
+    }
+
+    const separator = line.indexOf(':')
+    if (separator < 0) continue
+    const key = line.slice(0, separator).trim()
+    const value = line.slice(separator + 1).trim()
+
+    if (section === 'script info') {
+      scriptInfo.set(key.toLowerCase(), value)
+    } else if (section.includes('styles')) {
+      if (key === 'Format') {
+        styleFormat = value.split(',').map((field) => field.trim())
+      } else if (key === 'Style') {
+        const style = parseStyleLine(value, styleFormat, legacyAlignment)
+        styles.set(normalizeStyleName(style.name), style)
+      }
+    } else if (section === 'events') {
+      if (key === 'Format') {
+        eventFormat = value.split(',').map((field) => field.trim())
+      } else if (key === 'Dialogue') {
+        const event = parseEventLine(value, eventFormat)
+        if (!event) {
+          warnings.add(`Skipped line ${lineIndex + 1}: invalid timestamp`)
+        } else if (event.endSeconds <= event.startSeconds) {
+          warnings.add(`Skipped line ${lineIndex + 1}: end time must be after start time`)
+        } else {
+          events.push(event)
+        }
+      }
+    }
+  }
+
+  const { playResX, playResY } = resolvePlayRes(scriptInfo)
+  const scale = frame.frameHeight / playResY
+
+  const usage = new Map<string, number>()
+  for (const event of events) {
+    const name = normalizeStyleName(event.style)
+    usage.set(name, (usage.get(name) ?? 0) + 1)
+  }
+  const baseName =
+    Array.from(usage).sort((a, b) => b[1] - a[1])[0]?.[0] ?? styles.keys().next().value
+  const base = (baseName !== undefined ? styles.get(baseName) : undefined) ?? DEFAULT_ASS_STYLE
+
+  const cues: SubtitleSegmentCue[] = []
+  let hasKaraoke = false
+  for (const event of events) {
+    const eventStyle = styles.get(normalizeStyleName(event.style))
+    if (!eventStyle && styles.size > 0) {
+      warnings.add(`Style "${event.style}" is not defined; used the default style`)
+    }
+    const style = eventStyle ?? base
+    if (event.hasOwnMargins) warnings.add('Per-line margins are not supported')
+
+    const converted = convertAssText(event.text, {
+      base,
+      style,
+      playResX,
+      playResY,
+      legacyAlignment,
+      warnings,
+    })
+    if (!converted.text.trim()) continue
+    const cue: SubtitleSegmentCue = {
+      id: `cue-${cues.length + 1}`,
+      startSeconds: event.startSeconds,
+      endSeconds: event.endSeconds,
+      text: converted.text,
+    }
+    if (converted.karaoke) {
+      hasKaraoke = true
+      cue.words = converted.karaoke.map((word) => ({
+        text: word.text,
+        startSeconds: event.startSeconds + word.startSeconds,
+        endSeconds: Math.min(event.endSeconds, event.startSeconds + word.endSeconds),
+      }))
+    }
+    cues.push(cue)
+  }
+  cues.sort((a, b) => a.startSeconds - b.startSeconds)
+
+  const styleResult = assStyleToTextStyle(base, scale, warnings)
+  const result: SubtitleFileParseResult = {
+    cues,
+    style: styleResult,
+    region: assStyleRegion(base, playResX, playResY),
+    warnings: [],
+  }
+  if (hasKaraoke) {
+    // ASS paints unsung syllables in SecondaryColour and sung ones in
+    // PrimaryColour; the timeline highlights only the word being spoken.
+    result.style = { ...styleResult, color: formatCssColor(base.secondary) }
+    result.karaoke = { activeColor: formatCssColor(base.primary) }
+    warnings.add('Karaoke: sung words go back to the unsung color once the next word starts')
+  }
+  result.warnings = warnings.toArray()
+  return result
+}
+
+function parseStyleLine(value: string, format: string[], legacyAlignment: boolean): AssStyle {
+  const fields = splitFields(value, format.length)
+  const get = (name: string) => {
+    const index = format.findIndex((field) => field.toLowerCase() === name.toLowerCase())
+    return index >= 0 ? fields[index]?.trim() : undefined
+  }
+  const number = (name: string, fallback: number) => {
+    const parsed = Number.parseFloat(get(name) ?? '')
+    return Number.isFinite(parsed) ? parsed : fallback
+  }
+  const flag = (name: string) => number(name, 0) !== 0
+  const alignment = number('Alignment', 2)
+
+  return {
+    name: get('Name') ?? 'Default',
+    fontName: get('Fontname') ?? DEFAULT_ASS_STYLE.fontName,
+    fontSize: number('Fontsize', DEFAULT_ASS_STYLE.fontSize),
+    primary: parseAssColor(get('PrimaryColour')) ?? WHITE,
+    secondary: parseAssColor(get('SecondaryColour')) ?? DEFAULT_ASS_STYLE.secondary,
+    outline: parseAssColor(get('OutlineColour') ?? get('TertiaryColour')) ?? BLACK,
+    back: parseAssColor(get('BackColour')) ?? BLACK,
+    bold: flag('Bold'),
+    italic: flag('Italic'),
+    underline: flag('Underline'),
+    strikeOut: flag('StrikeOut'),
+    scaleX: number('ScaleX', 100),
+    scaleY: number('ScaleY', 100),
+    spacing: number('Spacing', 0),
+    angle: number('Angle', 0),
+    borderStyle: number('BorderStyle', 1),
+    outlineWidth: number('Outline', 0),
+    shadow: number('Shadow', 0),
+    alignment: legacyAlignment ? legacyToNumpad(alignment) : alignment,
+    marginL: number('MarginL', 0),
+    marginR: number('MarginR', 0),
+    marginV: number('MarginV', 0),
+  }
+}
+
+function parseEventLine(value: string, format: string[]): AssEvent | null {
+  const fields = splitFields(value, format.length)
+  const get = (name: string) => {
+    const index = format.findIndex((field) => field.toLowerCase() === name.toLowerCase())
+    return index >= 0 ? fields[index] : undefined
+  }
+  const startSeconds = parseAssTimestamp(get('Start'))
+  const endSeconds = parseAssTimestamp(get('End'))
+  if (startSeconds === null || endSeconds === null) return null
+  const hasOwnMargins = ['MarginL', 'MarginR', 'MarginV'].some(
+    (name) => Number.parseInt(get(name) ?? '0', 10) > 0,
+  )
+  return {
+    startSeconds,
+    endSeconds,
+    style: get('Style')?.trim() || 'Default',
+    text: get('Text') ?? '',
+    hasOwnMargins,
+  }
+}
+
+/** Split on commas; the last field (event text) keeps any commas it contains. */
+function splitFields(value: string, count: number): string[] {
+  const fields: string[] = []
+  let rest = value
+  for (let index = 0; index < count - 1; index++) {
+    const comma = rest.indexOf(',')
+    if (comma < 0) break
+    fields.push(rest.slice(0, comma))
+    rest = rest.slice(comma + 1)
+  }
+  fields.push(rest)
+  return fields
+}
+
+function parseAssTimestamp(value: string | undefined): number | null {
+  const match = /^(\d+):(\d{1,2}):(\d{1,2})(?:[.,](\d+))?$/.exec(value?.trim() ?? '')
+  if (!match) return null
+  const fraction = match[4] ? Number(`0.${match[4]}`) : 0
+  return Number(match[1]) * 3600 + Number(match[2]) * 60 + Number(match[3]) + fraction
+}
+
+function formatAssTimestamp(seconds: number): string {
+  const totalCs = Math.max(0, Math.round(seconds * 100))
+  const cs = totalCs % 100
+  const totalSeconds = Math.floor(totalCs / 100)
+  const s = totalSeconds % 60
+  const m = Math.floor(totalSeconds / 60) % 60
+  const h = Math.floor(totalSeconds / 3600)
+  return `${h}:${pad2(m)}:${pad2(s)}.${pad2(cs)}`
+}
+
+/** `&HAABBGGRR`, `&HBBGGRR&` or a decimal BGR integer (old SSA). */
+function parseAssColor(value: string | undefined): Rgba | null {
+  if (!value) return null
+  const trimmed = value.trim()
+  const hex = /^&H([0-9a-f]+)&?$/i.exec(trimmed)?.[1]
+  const numeric = hex !== undefined ? Number.parseInt(hex, 16) : Number.parseInt(trimmed, 10)
+  if (!Number.isFinite(numeric)) return null
+  return {
+    r: numeric & 0xff,
+    g: (numeric >>> 8) & 0xff,
+    b: (numeric >>> 16) & 0xff,
+    a: 1 - ((numeric >>> 24) & 0xff) / 255,
+  }
+}
+
+function formatAssColor(color: Rgba, withAlpha = true): string {
+  const alpha = withAlpha ? toHexByte(255 - color.a * 255) : ''
+  return `&H${alpha}${toHexByte(color.b)}${toHexByte(color.g)}${toHexByte(color.r)}`.toUpperCase()
+}
+
+function formatAssOverrideColor(color: Rgba): string {
+  return `&H${toHexByte(color.b)}${toHexByte(color.g)}${toHexByte(color.r)}&`.toUpperCase()
+}
+
+/** SSA numbers alignment 1–3 (bottom), 5–7 (top), 9–11 (middle). */
+function legacyToNumpad(value: number): number {
+  if (value >= 9) return value - 8 + 3
+  if (value >= 5) return value - 4 + 6
+  return value
+}
+
+function numpadToLegacy(value: number): number {
+  if (value >= 7) return value - 6 + 4
+  if (value >= 4) return value - 3 + 8
+  return value
+}
+
+function numpadToAlign(value: number): Pick<TextStyleFields, 'textAlign' | 'verticalAlign'> {
+  const column = ((value - 1) % 3) as 0 | 1 | 2
+  const row = Math.floor((value - 1) / 3)
+  return {
+    textAlign: (['left', 'center', 'right'] as const)[column],
+    verticalAlign: row === 0 ? 'bottom' : row === 1 ? 'middle' : 'top',
+  }
+}
+
+function alignToNumpad(
+  textAlign: TextStyleFields['textAlign'],
+  verticalAlign: TextStyleFields['verticalAlign'],
+): number {
+  const column = textAlign === 'left' ? 1 : textAlign === 'right' ? 3 : 2
+  const rowOffset = verticalAlign === 'top' ? 6 : verticalAlign === 'middle' ? 3 : 0
+  return column + rowOffset
+}
+
+function resolvePlayRes(info: Map<string, string>): { playResX: number; playResY: number } {
+  const x = Number.parseFloat(info.get('playresx') ?? '')
+  const y = Number.parseFloat(info.get('playresy') ?? '')
+  const hasX = Number.isFinite(x) && x > 0
+  const hasY = Number.isFinite(y) && y > 0
+  if (hasX && hasY) return { playResX: x, playResY: y }
+  if (hasY) return { playResX: (y * 4) / 3, playResY: y }
+  if (hasX) return { playResX: x, playResY: (x * 3) / 4 }
+  return { playResX: 384, playResY: 288 }
+}
+
+function normalizeStyleName(name: string): string {
+  return name.trim().replace(/^\*/, '').toLowerCase()
+}
+
+function assStyleToTextStyle(
+  style: AssStyle,
+  scale: number,
+  warnings: SubtitleWarningCollector,
+): TextStyleFields {
+  const px = (value: number) => Math.round(value * scale * 100) / 100
+  const result: TextStyleFields = {
+    fontFamily: style.fontName.replace(/^@/, ''),
+    fontSize: Math.max(1, Math.round(style.fontSize * scale)),
+    fontWeight: style.bold ? 'bold' : 'normal',
+    fontStyle: style.italic ? 'italic' : 'normal',
+    underline: style.underline,
+    color: formatCssColor(style.primary),
+    letterSpacing: px(style.spacing),
+    backgroundColor: undefined,
+    textPadding: undefined,
+    stroke: undefined,
+    textShadow: undefined,
+    ...numpadToAlign(style.alignment),
+  }
+
+  if (style.borderStyle === 3) {
+    // Opaque box: VSFilter paints it in OutlineColour, padded by Outline.
+    result.backgroundColor = formatCssColor(style.outline)
+    result.textPadding = px(style.outlineWidth)
+    if (style.shadow > 0) warnings.add('Shadows behind an opaque box are not supported')
+  } else {
+    if (style.borderStyle === 4) result.backgroundColor = formatCssColor(style.back)
+    if (style.outlineWidth > 0) {
+      result.stroke = { width: px(style.outlineWidth), color: formatCssColor(style.outline) }
+    }
+    if (style.shadow > 0 && style.borderStyle !== 4) {
+      const offset = px(style.shadow)
+      result.textShadow = {
+        offsetX: offset,
+        offsetY: offset,
+        blur: 0,
+        color: formatCssColor(style.back),
+      }
+    }
+  }
+
+  if (style.fontName.startsWith('@')) warnings.add('Vertical fonts are not supported')
+  if (style.strikeOut) warnings.add('Strikeout is not supported')
+  if (style.scaleX !== 100 || style.scaleY !== 100) {
+    warnings.add(`Style "${style.name}": text scaling is not supported`)
+  }
+  if (style.angle !== 0) warnings.add(`Style "${style.name}": rotation is not supported`)
+  return result
+}
+
+function assStyleRegion(style: AssStyle, playResX: number, playResY: number): SubtitleRegion {
+  const height = DEFAULT_SUBTITLE_REGION.height
+  const left = clamp01(style.marginL / playResX)
+  const width = Math.max(0.05, 1 - left - clamp01(style.marginR / playResX))
+  const { verticalAlign } = numpadToAlign(style.alignment)
+  const marginV = clamp01(style.marginV / playResY)
+  const top =
+    verticalAlign === 'top'
+      ? marginV
+      : verticalAlign === 'middle'
+        ? 0.5 - height / 2
+        : Math.max(0, 1 - marginV - height)
+  return { left, top, width, height }
+}
+
+interface ConvertContext {
+  base: AssStyle
+  style: AssStyle
+  playResX: number
+  playResY: number
+  legacyAlignment: boolean
+  warnings: SubtitleWarningCollector
+}
+
+interface KaraokeSyllable {
+  startSeconds: number
+  endSeconds: number
+}
+
+type OpenTag = { name: 'i' | 'b' | 'u' } | { name: 'font'; color: string }
+
+/**
+ * Turn one event's text into cue markup. Karaoke lines come back as plain
+ * text plus word timings (relative to the event start), since the word
+ * highlighter works on whitespace-separated words of unformatted text.
+ */
+function convertAssText(
+  raw: string,
+  context: ConvertContext,
+): { text: string; karaoke?: CaptionWordTiming[] } {
+  const { base, style, warnings } = context
+  const open: OpenTag[] = []
+  let markup = ''
+  let plain = ''
+  let alignment: number | null = style.alignment !== base.alignment ? style.alignment : null
+  let drawing = false
+  let hadInlineFormatting = false
+
+  const syllables: KaraokeSyllable[] = []
+  const plainSyllable: number[] = []
+  let karaokeCursor = 0
+  let currentSyllable = -1
+
+  const openTag = (tag: OpenTag) => {
+    open.push(tag)
+    markup += tag.name === 'font' ? `<font color="${tag.color}">` : `<${tag.name}>`
+    hadInlineFormatting = true
+  }
+  const closeTag = (name: OpenTag['name']) => {
+    const index = open.map((tag) => tag.name).lastIndexOf(name)
+    if (index < 0) return
+    // Close anything opened inside it, then reopen, so nesting stays valid
+    // for the stack-based cue parser.
+    const reopen = open.splice(index + 1)
+    for (const tag of [...reopen].reverse()) markup += `</${tag.name}>`
+    markup += `</${name}>`
+    open.splice(index, 1)
+    for (const tag of reopen) openTag(tag)
+  }
+  const closeAll = () => {
+    while (open.length > 0) markup += `</${open.pop()!.name}>`
+  }
+  const applyStyleDifferences = (target: AssStyle) => {
+    if (target.italic && !base.italic) openTag({ name: 'i' })
+    if (target.bold && !base.bold) openTag({ name: 'b' })
+    if (target.underline && !base.underline) openTag({ name: 'u' })
+    if (!sameColor(target.primary, base.primary)) {
+      openTag({ name: 'font', color: formatCssColor({ ...target.primary, a: 1 }) })
+    }
+  }
+
+  if (style !== base) {
+    applyStyleDifferences(style)
+    const lost: string[] = []
+    if ((base.italic && !style.italic) || (base.bold && !style.bold)) lost.push('weight/slant')
+    if (normalizeStyleName(style.fontName) !== normalizeStyleName(base.fontName)) lost.push('font')
+    if (style.fontSize !== base.fontSize) lost.push('size')
+    if (style.outlineWidth !== base.outlineWidth || !sameColor(style.outline, base.outline)) {
+      lost.push('outline')
+    }
+    if (style.borderStyle !== base.borderStyle) lost.push('border style')
+    if (lost.length > 0) {
+      warnings.add(`Style "${style.name}": ${lost.join(', ')} not kept (uses "${base.name}")`)
+    }
+  }
+
+  const appendText = (value: string) => {
+    if (drawing || !value) return
+    const text = value.replace(/\\N/g, '\n').replace(/\\n/g, ' ').replace(/\\h/g, '\u00a0')
+    markup += text
+    for (const char of text) {
+      plain += char
+      plainSyllable.push(currentSyllable)
+    }
+  }
+
+  const applyTag = (name: string, arg: string) => {
+    switch (name) {
+      case 'i':
+      case 'b':
+      case 'u': {
+        const value = Number.parseInt(arg, 10)
+        const on = name === 'b' ? value === 1 || value >= 600 : value === 1
+        if (on) {
+          if (!open.some((tag) => tag.name === name)) openTag({ name })
+        } else {
+          closeTag(name)
+        }
+        return
+      }
+      case 's':
+        if (arg.trim() === '1') warnings.add('Strikeout is not supported')
+        return
+      case 'c':
+      case '1c': {
+        const color = parseAssColor(arg.trim())
+        closeTag('font')
+        if (color && !sameColor(color, base.primary)) {
+          openTag({ name: 'font', color: formatCssColor({ ...color, a: 1 }) })
+        }
+        return
+      }
+      case 'an': {
+        const value = Number.parseInt(arg, 10)
+        if (value >= 1 && value <= 9) alignment = value
+        return
+      }
+      case 'a': {
+        const value = Number.parseInt(arg, 10)
+        if (value >= 1 && value <= 11) alignment = legacyToNumpad(value)
+        return
+      }
+      case 'pos':
+      case 'move': {
+        const [x, y] = arg
+          .replace(/[()]/g, '')
+          .split(',')
+          .map((part) => Number.parseFloat(part))
+        if (Number.isFinite(x) && Number.isFinite(y)) {
+          alignment = nearestNumpad(x! / context.playResX, y! / context.playResY)
+          warnings.add('Exact positions (\\pos) were snapped to the nearest screen position')
+        }
+        if (name === 'move') warnings.add('Moving text (\\move) is not supported')
+        return
+      }
+      case 'k':
+      case 'K':
+      case 'kf':
+      case 'ko': {
+        const duration = Math.max(0, Number.parseFloat(arg) || 0) / 100
+        syllables.push({ startSeconds: karaokeCursor, endSeconds: karaokeCursor + duration })
+        currentSyllable = syllables.length - 1
+        karaokeCursor += duration
+        if (name !== 'k') {
+          warnings.add('Karaoke fill sweeps (\\kf, \\ko) are shown as word highlights')
+        }
+        return
+      }
+      case 'r': {
+        closeAll()
+        if (arg.trim()) {
+          warnings.add('Switching styles mid-line (\\r) is not supported')
+        } else if (style !== base) {
+          applyStyleDifferences(style)
+        }
+        return
+      }
+      case 'p': {
+        drawing = Number.parseInt(arg, 10) > 0
+        if (drawing) warnings.add('Vector drawings (\\p) are not supported')
+        return
+      }
+      default: {
+        const label = UNSUPPORTED_OVERRIDE_LABELS[name]
+        if (label) warnings.add(`Override ${label} is not supported`)
+      }
+    }
+  }
+
+  for (const part of raw.split(/(\{[^}]*\})/)) {
+    if (part.startsWith('{') && part.endsWith('}')) {
+      const body = part.slice(1, -1)
+      // Braces without a backslash are comments.
+      if (!body.includes('\\')) continue
+      for (const tag of splitOverrideTags(body)) {
+        const match = OVERRIDE_TAG_PATTERN.exec(tag)
+        if (match) applyTag(match[1]!, match[2] ?? '')
+      }
+      continue
+    }
+    appendText(part)
+  }
+  closeAll()
+
+  const prefix = alignment !== null && alignment !== base.alignment ? `{\\an${alignment}}` : ''
+  if (syllables.length === 0) {
+    return { text: prefix + markup.replace(/[ \t]+\n/g, '\n').trim() }
+  }
+
+  if (hadInlineFormatting) warnings.add('Inline formatting inside karaoke lines was not kept')
+  const words: CaptionWordTiming[] = []
+  const tokenPattern = /\S+/g
+  for (const match of plain.matchAll(tokenPattern)) {
+    const start = match.index ?? 0
+    const first = plainSyllable[start]!
+    const last = plainSyllable[start + match[0].length - 1]!
+    words.push({
+      text: match[0],
+      startSeconds: syllables[first]?.startSeconds ?? 0,
+      endSeconds: syllables[last]?.endSeconds ?? 0,
+    })
+  }
+  return { text: plain.replace(/\s+/g, ' ').trim(), karaoke: words }
+}
+
+/** Split an override block into tags, keeping `\t(...\...)` arguments whole. */
+function splitOverrideTags(body: string): string[] {
+  const tags: string[] = []
+  let depth = 0
+  let current = ''
+  for (const char of body) {
+    if (char === '(') depth++
+    if (char === ')') depth = Math.max(0, depth - 1)
+    if (char === '\\' && depth === 0) {
+      if (current) tags.push(current)
+      current = ''
+      continue
+    }
+    current += char
+  }
+  if (current) tags.push(current)
+  return tags
+}
+
+function nearestNumpad(x: number, y: number): number {
+  const column = x < 1 / 3 ? 1 : x > 2 / 3 ? 3 : 2
+  const rowOffset = y < 1 / 3 ? 6 : y > 2 / 3 ? 0 : 3
+  return column + rowOffset
+}
+
+function sameColor(a: Rgba, b: Rgba): boolean {
+  return a.r === b.r && a.g === b.g && a.b === b.b
+}
+
+export function serializeAss(
+  document: SubtitleExportDocument,
+  variant: AssVariant = 'ass',
+): SubtitleExportResult {
+  const warnings = new SubtitleWarningCollector()
+  const { style, karaoke, frameWidth, frameHeight } = document
+  const region = document.region ?? DEFAULT_SUBTITLE_REGION
+  const { verticalAlign, marginV } = resolveExportVerticalPlacement(
+    style.verticalAlign ?? 'bottom',
+    region,
+    frameHeight,
+    style.fontSize ?? 48,
+  )
+  const numpad = alignToNumpad(style.textAlign, verticalAlign)
+  const margins = {
+    l: Math.max(0, Math.round(region.left * frameWidth)),
+    r: Math.max(0, Math.round((1 - region.left - region.width) * frameWidth)),
+    v: marginV,
+  }
+
+  const color = parseCssColor(style.color) ?? WHITE
+  const base = buildExportStyle('Default', style, numpad, margins, warnings)
+  if (karaoke) {
+    base.primary = parseCssColor(karaoke.activeColor) ?? color
+    base.secondary = color
+    if (karaoke.activeBackgroundColor) warnings.add('Karaoke word boxes are not supported')
+    if ((karaoke.activeScale ?? 1) !== 1) warnings.add('Karaoke word scaling is not supported')
+    if (karaoke.popIn) warnings.add('Karaoke pop-in is not supported')
+    if ((karaoke.wordsPerPage ?? 0) > 0) warnings.add('Karaoke paging is not supported')
+  }
+
+  const speakerStyles = new Map<string, { style: AssStyle; name: string }>()
+  const styles = [base]
+  for (const [speakerId, speaker] of Object.entries(document.speakerStyles ?? {})) {
+    const name = sanitizeStyleName(`Speaker ${speaker.name ?? speakerId}`)
+    // Speaker styles repeat the segment style; only report what's new in them.
+    const speakerWarnings = new SubtitleWarningCollector()
+    const speakerStyle = buildExportStyle(
+      name,
+      { ...style, ...speaker },
+      numpad,
+      margins,
+      speakerWarnings,
+    )
+    warnings.addMissing(speakerWarnings)
+    speakerStyles.set(speakerId, { style: speakerStyle, name: speaker.name ?? speakerId })
+    styles.push(speakerStyle)
+  }
+
+  const lines: string[] = ['[Script Info]', '; Script generated by FreeCut']
+  if (document.title) lines.push(`Title: ${document.title}`)
+  lines.push(
+    `ScriptType: ${variant === 'ssa' ? 'v4.00' : 'v4.00+'}`,
+    'WrapStyle: 0',
+    'ScaledBorderAndShadow: yes',
+    `PlayResX: ${frameWidth}`,
+    `PlayResY: ${frameHeight}`,
+  )
+  if (document.language && variant === 'ass') lines.push(`Language: ${document.language}`)
+
+  lines.push('', variant === 'ssa' ? '[V4 Styles]' : '[V4+ Styles]')
+  lines.push(`Format: ${(variant === 'ssa' ? SSA_STYLE_FORMAT : ASS_STYLE_FORMAT).join(', ')}`)
+  for (const entry of styles) lines.push(`Style: ${formatStyleLine(entry, variant)}`)
+
+  lines.push('', '[Events]')
+  lines.push(`Format: ${(variant === 'ssa' ? SSA_EVENT_FORMAT : ASS_EVENT_FORMAT).join(', ')}`)
+  const cues = [...document.cues]
+    .filter((cue) => cue.text.trim() && cue.endSeconds > cue.startSeconds)
+    .sort((a, b) => a.startSeconds - b.startSeconds)
+  for (const cue of cues) {
+    const speaker = cue.speakerId ? speakerStyles.get(cue.speakerId) : undefined
+    const text =
+      karaoke && cue.words && cue.words.length > 0
+        ? karaokeText(cue, warnings)
+        : markupToAss(cue.text, speaker?.style.primary ?? color, variant, warnings)
+    const marked = variant === 'ssa' ? 'Marked=0' : '0'
+    lines.push(
+      `Dialogue: ${[
+        marked,
+        formatAssTimestamp(cue.startSeconds),
+        formatAssTimestamp(cue.endSeconds),
+        speaker?.style.name ?? base.name,
+        speaker?.name.replace(/,/g, ' ') ?? '',
+        '0000',
+        '0000',
+        '0000',
+        '',
+        text,
+      ].join(',')}`,
+    )
+  }
+
+  return { text: `${lines.join('\n')}\n`, warnings: warnings.toArray() }
+}
+
+/**
+ * ASS anchors text to a screen edge plus a margin. A caption centered in a
+ * box (the timeline default) is re-anchored to the nearer edge, with the
+ * margin placing the line where the box center was.
+ */
+function resolveExportVerticalPlacement(
+  verticalAlign: NonNullable<TextStyleFields['verticalAlign']>,
+  region: SubtitleRegion,
+  frameHeight: number,
+  fontSize: number,
+): { verticalAlign: 'top' | 'middle' | 'bottom'; marginV: number } {
+  const px = (fraction: number) => Math.max(0, Math.round(fraction * frameHeight))
+  if (verticalAlign === 'top') return { verticalAlign, marginV: px(region.top) }
+  if (verticalAlign === 'bottom') {
+    return { verticalAlign, marginV: px(1 - region.top - region.height) }
+  }
+  const center = region.top + region.height / 2
+  if (Math.abs(center - 0.5) < 0.05) return { verticalAlign: 'middle', marginV: 0 }
+  const halfLine = fontSize / 2 / frameHeight
+  return center > 0.5
+    ? { verticalAlign: 'bottom', marginV: px(1 - center - halfLine) }
+    : { verticalAlign: 'top', marginV: px(center - halfLine) }
+}
+
+function buildExportStyle(
+  name: string,
+  style: TextStyleFields,
+  numpad: number,
+  margins: { l: number; r: number; v: number },
+  warnings: SubtitleWarningCollector,
+): AssStyle {
+  const color = parseCssColor(style.color) ?? WHITE
+  const weight = style.fontWeight ?? 'normal'
+  const bold = weight === 'bold' || weight === 'semibold'
+  if (weight === 'semibold' || weight === 'medium') {
+    warnings.add(`Font weight "${weight}" was exported as ${bold ? 'bold' : 'regular'}`)
+  }
+  const result: AssStyle = {
+    ...DEFAULT_ASS_STYLE,
+    name,
+    fontName: style.fontFamily ?? 'Arial',
+    fontSize: Math.round(style.fontSize ?? 48),
+    primary: color,
+    secondary: color,
+    bold,
+    italic: style.fontStyle === 'italic',
+    underline: style.underline === true,
+    spacing: Math.round((style.letterSpacing ?? 0) * 100) / 100,
+    alignment: numpad,
+    marginL: margins.l,
+    marginR: margins.r,
+    marginV: margins.v,
+    outlineWidth: 0,
+    shadow: 0,
+  }
+
+  const shadow = style.textShadow
+  if (isVisibleColor(style.backgroundColor)) {
+    const background = parseCssColor(style.backgroundColor)!
+    result.borderStyle = 3
+    result.outline = background
+    result.back = background
+    result.outlineWidth = Math.round(style.textPadding ?? 0)
+    if (style.stroke && style.stroke.width > 0) {
+      warnings.add('Text outlines cannot be combined with a background box in ASS')
+    }
+    if (shadow) warnings.add('Text shadows cannot be combined with a background box in ASS')
+  } else {
+    result.borderStyle = 1
+    if (style.stroke && style.stroke.width > 0) {
+      result.outlineWidth = Math.round(style.stroke.width * 100) / 100
+      result.outline = parseCssColor(style.stroke.color) ?? BLACK
+    }
+    if (shadow) {
+      result.shadow = Math.round(Math.max(Math.abs(shadow.offsetX), Math.abs(shadow.offsetY)))
+      result.back = parseCssColor(shadow.color) ?? BLACK
+      if (shadow.blur > 0) warnings.add('Shadow blur is not supported; exported as a hard shadow')
+      if (shadow.offsetX !== shadow.offsetY || shadow.offsetX < 0) {
+        warnings.add('Shadow direction was approximated (ASS shadows fall down and right)')
+      }
+    }
+  }
+  if ((style.backgroundRadius ?? 0) > 0) {
+    warnings.add('Rounded background corners are not supported')
+  }
+  if (style.lineHeight !== undefined && (style.lineHeight < 0.9 || style.lineHeight > 1.3)) {
+    warnings.add('Line spacing is not supported')
+  }
+  return result
+}
+
+function formatStyleLine(style: AssStyle, variant: AssVariant): string {
+  const flag = (value: boolean) => (value ? '-1' : '0')
+  const color = (value: Rgba) => formatAssColor(value, variant === 'ass')
+  if (variant === 'ssa') {
+    return [
+      style.name,
+      style.fontName,
+      style.fontSize,
+      color(style.primary),
+      color(style.secondary),
+      color(style.outline),
+      color(style.back),
+      flag(style.bold),
+      flag(style.italic),
+      style.borderStyle,
+      style.outlineWidth,
+      style.shadow,
+      numpadToLegacy(style.alignment),
+      style.marginL,
+      style.marginR,
+      style.marginV,
+      0,
+      1,
+    ].join(',')
+  }
+  return [
+    style.name,
+    style.fontName,
+    style.fontSize,
+    color(style.primary),
+    color(style.secondary),
+    color(style.outline),
+    color(style.back),
+    flag(style.bold),
+    flag(style.italic),
+    flag(style.underline),
+    flag(style.strikeOut),
+    style.scaleX,
+    style.scaleY,
+    style.spacing,
+    style.angle,
+    style.borderStyle,
+    style.outlineWidth,
+    style.shadow,
+    style.alignment,
+    style.marginL,
+    style.marginR,
+    style.marginV,
+    1,
+  ].join(',')
+}
+
+function sanitizeStyleName(name: string): string {
+  return name.replace(/[,\n\r]/g, ' ').trim()
+}
+
+/** `{\kNN}` per word; silent gaps become empty syllables so timing stays exact. */
+function karaokeText(cue: SubtitleSegmentCue, warnings: SubtitleWarningCollector): string {
+  if (/<[^>]+>|\{\\/.test(cue.text)) warnings.add('Inline formatting in karaoke lines was dropped')
+  let cursor = cue.startSeconds
+  const parts: string[] = []
+  for (const [index, word] of cue.words!.entries()) {
+    const gap = Math.round((word.startSeconds - cursor) * 100)
+    if (gap > 0) parts.push(`{\\k${gap}}`)
+    const duration = Math.max(0, Math.round((word.endSeconds - word.startSeconds) * 100))
+    const separator = index < cue.words!.length - 1 ? ' ' : ''
+    parts.push(`{\\k${duration}}${escapeAssText(word.text)}${separator}`)
+    cursor = Math.max(cursor, word.endSeconds)
+  }
+  return parts.join('')
+}
+
+function markupToAss(
+  text: string,
+  styleColor: Rgba,
+  variant: AssVariant,
+  warnings: SubtitleWarningCollector,
+): string {
+  const colors: Rgba[] = []
+  let result = ''
+  let lastIndex = 0
+  const pattern = /<\/?(i|b|u|font)\b([^>]*)>|\{\\an([1-9])\}|<[^>]+>/gi
+  for (const match of text.matchAll(pattern)) {
+    const start = match.index ?? 0
+    result += escapeAssText(text.slice(lastIndex, start))
+    lastIndex = start + match[0].length
+    const tag = match[1]?.toLowerCase()
+    const closing = match[0].startsWith('</')
+    if (match[3]) {
+      const numpad = Number(match[3])
+      result += variant === 'ssa' ? `{\\a${numpadToLegacy(numpad)}}` : `{\\an${numpad}}`
+    } else if (tag === 'font') {
+      if (closing) {
+        colors.pop()
+        result += `{\\c${formatAssOverrideColor(colors.at(-1) ?? styleColor)}}`
+      } else {
+        const value = /color\s*=\s*"?([^"\s>]+)"?/i.exec(match[2] ?? '')?.[1]
+        const parsed = parseCssColor(value) ?? colors.at(-1) ?? styleColor
+        colors.push(parsed)
+        result += `{\\c${formatAssOverrideColor(parsed)}}`
+      }
+    } else if (tag) {
+      if (tag === 'u' && variant === 'ssa') {
+        if (!closing) warnings.add('Underline is not supported in SSA')
+        continue
+      }
+      result += `{\\${tag}${closing ? 0 : 1}}`
+    }
+  }
+  result += escapeAssText(text.slice(lastIndex))
+  return result.trim().replace(/\n/g, '\\N')
+}
+
+/** ASS has no escape for braces; swap them for look-alikes so they stay text. */
+function escapeAssText(text: string): string {
+  return text.replace(/\{/g, '(').replace(/\}/g, ')')
+}
+
+function stripBom(text: string): string {
+  return text.charCodeAt(0) === 0xfeff ? text.slice(1) : text
+}
+
+function clamp01(value: number): number {
+  return Math.min(1, Math.max(0, value))
+}
+
+function pad2(value: number): string {
+  return String(value).padStart(2, '0')
+}
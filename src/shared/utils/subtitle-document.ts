@@ -0,0 +1,213 @@
+/**
+ * Format-neutral model of a styled subtitle file, shared by the ASS/SSA,
+ * TTML and SCC readers and writers.
+ *
+ * Cue text keeps the inline markup {@link parseSubtitleCueText} understands
+ * (`<i>`, `<b>`, `<u>`, `<font color>`, `{\anN}`); block-level look lives on
+ * `style`, the on-screen box on `region`. Readers and writers never drop
+ * styling quietly: whatever has no equivalent on the other side is described
+ * in `warnings` so the UI can tell the user what changed.
+ */
+import type { CaptionKaraokeStyle, TextStyleFields } from '@/types/text'
+import type { SubtitleSegmentCue, SubtitleSpeakerStyle } from '@/types/timeline'
+
+/** Text box as fractions of the frame (0–1), measured from the top-left corner. */
+export interface SubtitleRegion {
+  left: number
+  top: number
+  width: number
+  height: number
+}
+
+export interface SubtitleFrameSize {
+  frameWidth: number
+  frameHeight: number
+}
+
+export interface SubtitleFileParseResult {
+  /** Cues in file time; karaoke files carry per-word timings. */
+  cues: SubtitleSegmentCue[]
+  /** Segment-wide look; lengths are pixels at the requested frame size. */
+  style?: TextStyleFields
+  karaoke?: CaptionKaraokeStyle
+  region?: SubtitleRegion
+  /** Malformed input plus any styling with no timeline equivalent. */
+  warnings: string[]
+}
+
+export interface SubtitleExportDocument extends SubtitleFrameSize {
+  /** Cues in output time. */
+  cues: readonly SubtitleSegmentCue[]
+  style: TextStyleFields
+  karaoke?: CaptionKaraokeStyle
+  region?: SubtitleRegion
+  speakerStyles?: Readonly<Record<string, SubtitleSpeakerStyle>>
+  /** BCP 47 language of the cues, when known. */
+  language?: string
+  title?: string
+}
+
+export interface SubtitleExportResult {
+  text: string
+  /** Styling the format could not carry, one line per feature. */
+  warnings: string[]
+}
+
+/**
+ * Box the timeline gives a fresh caption (see `buildSubtitleSegmentForClip`):
+ * 82% wide, 16% tall, centered 32% of the frame height below the middle.
+ */
+export const DEFAULT_SUBTITLE_REGION: SubtitleRegion = {
+  left: 0.09,
+  top: 0.74,
+  width: 0.82,
+  height: 0.16,
+}
+
+export interface Rgba {
+  r: number
+  g: number
+  b: number
+  /** 0 (transparent) – 1 (opaque). */
+  a: number
+}
+
+const NAMED_COLORS: Record<string, string> = {
+  black: '#000000',
+  silver: '#c0c0c0',
+  gray: '#808080',
+  grey: '#808080',
+  white: '#ffffff',
+  maroon: '#800000',
+  red: '#ff0000',
+  purple: '#800080',
+  fuchsia: '#ff00ff',
+  magenta: '#ff00ff',
+  green: '#008000',
+  lime: '#00ff00',
+  olive: '#808000',
+  yellow: '#ffff00',
+  navy: '#000080',
+  blue: '#0000ff',
+  teal: '#008080',
+  aqua: '#00ffff',
+  cyan: '#00ffff',
+  orange: '#ffa500',
+}
+
+/**
+ * Parse the CSS colors the timeline stores (`#rgb`, `#rrggbb`, `#rrggbbaa`,
+ * `rgb()`, `rgba()`, a handful of names). `rgbaAlphaScale` is 255 for TTML,
+ * whose `rgba()` alpha runs 0–255 instead of 0–1.
+ */
+export function parseCssColor(color: string | undefined, rgbaAlphaScale = 1): Rgba | null {
+  if (!color) return null
+  const value = color.trim().toLowerCase()
+  if (value === 'transparent') return { r: 0, g: 0, b: 0, a: 0 }
+  const named = NAMED_COLORS[value]
+  if (named) return parseCssColor(named)
+
+  const hex = /^#([0-9a-f]{3,8})$/.exec(value)?.[1]
+  if (hex) {
+    const full =
+      hex.length === 3 || hex.length === 4
+        ? Array.from(hex, (digit) => digit + digit).join('')
+        : hex
+    if (full.length !== 6 && full.length !== 8) return null
+    return {
+      r: Number.parseInt(full.slice(0, 2), 16),
+      g: Number.parseInt(full.slice(2, 4), 16),
+      b: Number.parseInt(full.slice(4, 6), 16),
+      a: full.length === 8 ? Number.parseInt(full.slice(6, 8), 16) / 255 : 1,
+    }
+  }
+
+  const fn = /^rgba?\(([^)]*)\)$/.exec(value)?.[1]
+  if (fn) {
+    const parts = fn.split(/[\s,/]+/).filter(Boolean).map(Number)
+    if (parts.length < 3 || parts.some((part) => !Number.isFinite(part))) return null
+    const alpha = parts[3] === undefined ? 1 : parts[3] / rgbaAlphaScale
+    return {
+      r: clampByte(parts[0]!),
+      g: clampByte(parts[1]!),
+      b: clampByte(parts[2]!),
+      a: Math.min(1, Math.max(0, alpha)),
+    }
+  }
+  return null
+}
+
+/** `#rrggbb` when opaque, `rgba(r, g, b, a)` otherwise. */
+export function formatCssColor({ r, g, b, a }: Rgba): string {
+  if (a >= 1) return `#${toHexByte(r)}${toHexByte(g)}${toHexByte(b)}`
+  return `rgba(${r}, ${g}, ${b}, ${Math.round(a * 1000) / 1000})`
+}
+
+export function toHexByte(value: number): string {
+  return clampByte(value).toString(16).padStart(2, '0')
+}
+
+function clampByte(value: number): number {
+  return Math.min(255, Math.max(0, Math.round(value)))
+}
+
+export function isVisibleColor(color: string | undefined): boolean {
+  const parsed = parseCssColor(color)
+  return parsed !== null && parsed.a > 0
+}
+
+/**
+ * Collects "could not represent" notes, merging repeats of the same note
+ * into one line with a count so a 600-cue file doesn't produce 600 lines.
+ */
+export class SubtitleWarningCollector {
+  private readonly counts = new Map<string, number>()
+
+  add(message: string): void {
+    this.counts.set(message, (this.counts.get(message) ?? 0) + 1)
+  }
+
+  /** Take over `other`'s notes that this collector doesn't already have. */
+  addMissing(other: SubtitleWarningCollector): void {
+    for (const [message, count] of other.counts) {
+      if (!this.counts.has(message)) this.counts.set(message, count)
+    }
+  }
+
+  toArray(): string[] {
+    return Array.from(this.counts, ([message, count]) =>
+      count > 1 ? `${message} (${count} times)` : message,
+    )
+  }
+}
+
+/** Whether a cue carries the word timings karaoke highlighting needs. */
+export function hasKaraokeWords(cue: SubtitleSegmentCue): boolean {
+  return (cue.words?.length ?? 0) > 0
+}
+
+/** Convert a segment transform (canvas-centered pixels) into a frame-relative region. */
+export function regionFromTransform(
+  transform: { x: number; y: number; width: number; height: number },
+  frame: SubtitleFrameSize,
+): SubtitleRegion {
+  return {
+    left: (frame.frameWidth / 2 + transform.x - transform.width / 2) / frame.frameWidth,
+    top: (frame.frameHeight / 2 + transform.y - transform.height / 2) / frame.frameHeight,
+    width: transform.width / frame.frameWidth,
+    height: transform.height / frame.frameHeight,
+  }
+}
+
+/** Inverse of {@link regionFromTransform}. */
+export function transformFromRegion(
+  region: SubtitleRegion,
+  frame: SubtitleFrameSize,
+): { x: number; y: number; width: number; height: number } {
+  return {
+    x: Math.round((region.left + region.width / 2 - 0.5) * frame.frameWidth),
+    y: Math.round((region.top + region.height / 2 - 0.5) * frame.frameHeight),
+    width: Math.round(region.width * frame.frameWidth),
+    height: Math.round(region.height * frame.frameHeight),
+  }
+}
@@ -0,0 +1,112 @@
+/**
+ * One entry point for reading and writing every subtitle file format the
+ * editor supports, so import/export UI doesn't switch on formats itself.
+ */
+import { parseAss, serializeAss } from './subtitle-ass'
+import {
+  SubtitleWarningCollector,
+  hasKaraokeWords,
+  type SubtitleExportDocument,
+  type SubtitleExportResult,
+  type SubtitleFileParseResult,
+  type SubtitleFrameSize,
+} from './subtitle-document'
+import { serializeScc } from './subtitle-scc'
+import { parseTtml, serializeTtml } from './subtitle-ttml'
+import {
+  parseSrt,
+  parseVtt,
+  serializeSrt,
+  serializeVtt,
+  type SubtitleCue,
+  type SubtitleFormat,
+} from './subtitles'
+
+/** Formats that can be exported; SCC is write-only. */
+export type SubtitleExportFormat = SubtitleFormat | 'scc'
+
+/** `accept` attribute for subtitle file pickers. */
+export const SUBTITLE_FILE_ACCEPT = '.srt,.vtt,.ass,.ssa,.ttml,.dfxp'
+
+export const SUBTITLE_EXPORT_FORMATS: ReadonlyArray<{
+  format: SubtitleExportFormat
+  label: string
+  extension: string
+  mimeType: string
+}> = [
+  { format: 'srt', label: 'SubRip (.srt)', extension: 'srt', mimeType: 'application/x-subrip' },
+  { format: 'vtt', label: 'WebVTT (.vtt)', extension: 'vtt', mimeType: 'text/vtt' },
+  { format: 'ass', label: 'Advanced SubStation (.ass)', extension: 'ass', mimeType: 'text/x-ssa' },
+  { format: 'ssa', label: 'SubStation Alpha (.ssa)', extension: 'ssa', mimeType: 'text/x-ssa' },
+  {
+    format: 'ttml',
+    label: 'TTML / IMSC1 (.ttml)',
+    extension: 'ttml',
+    mimeType: 'application/ttml+xml',
+  },
+  { format: 'scc', label: 'Scenarist CEA-608 (.scc)', extension: 'scc', mimeType: 'text/plain' },
+]
+
+/**
+ * Parse a subtitle file. Lengths in the returned style are pixels at
+ * `frame`, which should be the project canvas size.
+ */
+export function parseSubtitleFile(
+  text: string,
+  format: SubtitleFormat,
+  frame: SubtitleFrameSize,
+): SubtitleFileParseResult {
+  switch (format) {
+    case 'srt':
+      return parseSrt(text)
+    case 'vtt':
+      return parseVtt(text)
+    case 'ass':
+    case 'ssa':
+      return parseAss(text, frame, format)
+    case 'ttml':
+      return parseTtml(text, frame)
+  }
+}
+
+export function serializeSubtitleFile(
+  document: SubtitleExportDocument,
+  format: SubtitleExportFormat,
+): SubtitleExportResult {
+  switch (format) {
+    case 'srt':
+      return serializePlainSubtitles(document, serializeSrt, 'SRT')
+    case 'vtt':
+      return serializePlainSubtitles(document, serializeVtt, 'WebVTT')
+    case 'ass':
+    case 'ssa':
+      return serializeAss(document, format)
+    case 'ttml':
+      return serializeTtml(document)
+    case 'scc':
+      return serializeScc(document)
+  }
+}
+
+/** SRT and WebVTT carry cue text (and its inline tags) only. */
+function serializePlainSubtitles(
+  document: SubtitleExportDocument,
+  serialize: (cues: readonly SubtitleCue[]) => string,
+  label: string,
+): SubtitleExportResult {
+  const warnings = new SubtitleWarningCollector()
+  warnings.add(`${label} files carry text only; font, colors and position were not exported`)
+  if (document.speakerStyles && Object.keys(document.speakerStyles).length > 0) {
+    warnings.add('Per-speaker styles were not exported')
+  }
+  if (document.karaoke && document.cues.some(hasKaraokeWords)) {
+    warnings.add('Karaoke word highlighting was exported as plain lines')
+  }
+  const cues = document.cues.map(({ id, startSeconds, endSeconds, text }) => ({
+    id,
+    startSeconds,
+    endSeconds,
+    text,
+  }))
+  return { text: `${serialize(cues)}\n`, warnings: warnings.toArray() }
+}
@@ -0,0 +1,76 @@
+/**
+ * Languages offered for subtitle translation, and the tag mapping used when
+ * subtitle tracks are embedded in an exported container. Timeline items store
+ * short BCP 47 codes (`es`, `pt-BR`), the same shape Whisper reports; MP4,
+ * WebM and MKV tag tracks with ISO 639-2 codes (`spa`, `por`).
+ */
+
+export interface SubtitleLanguage {
+  /** BCP 47 code stored on timeline items. */
+  code: string
+  /** English name — also what the translation prompt asks the model for. */
+  name: string
+  /** ISO 639-2/T code written to container track headers. */
+  iso639_2: string
+}
+
+export const SUBTITLE_TRANSLATION_LANGUAGES: readonly SubtitleLanguage[] = [
+  { code: 'en', name: 'English', iso639_2: 'eng' },
+  { code: 'es', name: 'Spanish', iso639_2: 'spa' },
+  { code: 'fr', name: 'French', iso639_2: 'fra' },
+  { code: 'de', name: 'German', iso639_2: 'deu' },
+  { code: 'it', name: 'Italian', iso639_2: 'ita' },
+  { code: 'pt-BR', name: 'Brazilian Portuguese', iso639_2: 'por' },
+  { code: 'pt', name: 'Portuguese', iso639_2: 'por' },
+  { code: 'nl', name: 'Dutch', iso639_2: 'nld' },
+  { code: 'pl', name: 'Polish', iso639_2: 'pol' },
+  { code: 'tr', name: 'Turkish', iso639_2: 'tur' },
+  { code: 'ru', name: 'Russian', iso639_2: 'rus' },
+  { code: 'uk', name: 'Ukrainian', iso639_2: 'ukr' },
+  { code: 'ar', name: 'Arabic', iso639_2: 'ara' },
+  { code: 'hi', name: 'Hindi', iso639_2: 'hin' },
+  { code: 'id', name: 'Indonesian', iso639_2: 'ind' },
+  { code: 'vi', name: 'Vietnamese', iso639_2: 'vie' },
+  { code: 'ja', name: 'Japanese', iso639_2: 'jpn' },
+  { code: 'ko', name: 'Korean', iso639_2: 'kor' },
+  { code: 'zh', name: 'Simplified Chinese', iso639_2: 'zho' },
+]
+
+/** ISO 639-1 → 639-2/T for languages Whisper can report beyond the list above. */
+const EXTRA_ISO_639_2: Record<string, string> = {
+  ca: 'cat',
+  cs: 'ces',
+  da: 'dan',
+  el: 'ell',
+  fi: 'fin',
+  he: 'heb',
+  hu: 'hun',
+  ms: 'msa',
+  no: 'nor',
+  ro: 'ron',
+  sv: 'swe',
+  th: 'tha',
+}
+
+export function getSubtitleLanguage(code: string | undefined): SubtitleLanguage | null {
+  if (!code) return null
+  const normalized = code.toLowerCase()
+  const match = SUBTITLE_TRANSLATION_LANGUAGES.find(
+    (language) => language.code.toLowerCase() === normalized,
+  )
+  return match ?? null
+}
+
+/**
+ * Container language tag for a stored language code. Three-letter codes (as
+ * read from MKV headers) pass through; unknown or missing codes become `und`.
+ */
+export function toIso639_2LanguageCode(code: string | undefined): string {
+  if (!code) return 'und'
+  const normalized = code.trim().toLowerCase()
+  if (/^[a-z]{3}$/.test(normalized)) return normalized
+  const known = getSubtitleLanguage(normalized)?.iso639_2
+  if (known) return known
+  const primary = normalized.split('-')[0] ?? ''
+  return getSubtitleLanguage(primary)?.iso639_2 ?? EXTRA_ISO_639_2[primary] ?? 'und'
+}
@@ -0,0 +1,88 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { formatSccTimecode, serializeScc, withOddParity } from './subtitle-scc'
+
+const FRAME = { frameWidth: 1920, frameHeight: 1080 }
+
+describe('withOddParity', () => {
+  it('sets the parity bit only when the byte has an even number of ones', () => {
+    expect(withOddParity(0x14)).toBe(0x94)
+    expect(withOddParity(0x20)).toBe(0x20)
+    expect(withOddParity(0x2e)).toBe(0xae)
+    expect(withOddParity(0x2f)).toBe(0x2f)
+    expect(withOddParity(0x00)).toBe(0x80)
+  })
+})
+
+describe('formatSccTimecode', () => {
+  it('skips the dropped labels at each minute except every tenth', () => {
+    expect(formatSccTimecode(0)).toBe('00:00:00;00')
+    expect(formatSccTimecode(1800)).toBe('00:01:00;02')
+    expect(formatSccTimecode(17982)).toBe('00:10:00;00')
+  })
+
+  it('counts frames straight through for non-drop-frame', () => {
+    expect(formatSccTimecode(1800, false)).toBe('00:01:00:00')
+  })
+})
+
+describe('serializeScc', () => {
+  it('loads a pop-on caption before its start frame and erases it at the end', () => {
+    const result = serializeScc({
+      ...FRAME,
+      cues: [{ id: 'a', startSeconds: 1, endSeconds: 2, text: 'Hello' }],
+      style: {
+        color: '#ffffff',
+        backgroundColor: '#000000',
+        textAlign: 'center',
+        verticalAlign: 'bottom',
+      },
+    })
+
+    expect(result.text).toBe(
+      [
+        'Scenarist_SCC V1.0',
+        '',
+        '00:00:00;19\t94ae 94ae 9420 9420 9476 9476 97a1 97a1 c8e5 ecec ef80 942f 942f',
+        '',
+        '00:00:02;00\t942c 942c',
+        '',
+      ].join('\n'),
+    )
+    expect(result.warnings).toEqual([
+      'Font family and size are chosen by the caption decoder and were not exported',
+    ])
+  })
+
+  it('places aligned cues and switches to italics with a mid-row code', () => {
+    const result = serializeScc({
+      ...FRAME,
+      cues: [{ id: 'a', startSeconds: 1, endSeconds: 2, text: '{\\an8}<i>Hi</i>' }],
+      style: { color: '#ffffff', backgroundColor: '#000000' },
+    })
+
+    // Row 1 at column 12, tab over 2, italics, then "Hi".
+    expect(result.text).toContain('91d6 91d6 97a2 97a2 91ae 91ae c8e9')
+  })
+
+  it('reports styling and text that CEA-608 cannot carry', () => {
+    const result = serializeScc({
+      ...FRAME,
+      cues: [{ id: 'a', startSeconds: 1, endSeconds: 3, text: '€1\nB\nC\nD\nE' }],
+      style: {
+        color: '#ff8800',
+        fontWeight: 'bold',
+        stroke: { width: 2, color: '#000000' },
+      },
+    })
+
+    expect(result.warnings).toEqual([
+      'Font family and size are chosen by the caption decoder and were not exported',
+      'Bold text is not supported',
+      'Text outlines and shadows are not supported',
+      'Caption decoders draw their own black background box',
+      'Colors were matched to the nearest CEA-608 color',
+      'Captions longer than 4 rows were truncated',
+      'Characters outside the CEA-608 character set were replaced',
+    ])
+  })
+})
@@ -0,0 +1,510 @@
+/**
+ * Scenarist SCC export: CEA-608 pop-on captions on channel CC1 at 29.97 fps.
+ *
+ * Each cue is loaded into the decoder's off-screen memory ahead of its start
+ * time and flipped on screen with End Of Caption, so it appears on the right
+ * frame; Erase Displayed Memory clears it unless the next cue replaces it
+ * first. 608 has one decoder font, a 32-column grid, four caption rows and a
+ * seven-color palette, so most timeline styling is reported as lost.
+ */
+import type { TextStyleFields } from '@/types/text'
+import type { SubtitleSegmentCue } from '@/types/timeline'
+import { parseSubtitleCueText } from './subtitle-cue-format'
+import {
+  DEFAULT_SUBTITLE_REGION,
+  SubtitleWarningCollector,
+  hasKaraokeWords,
+  parseCssColor,
+  toHexByte,
+  type SubtitleExportDocument,
+  type SubtitleExportResult,
+} from './subtitle-document'
+
+export interface SccExportOptions {
+  /** Drop-frame (`;`) timecode; the broadcast default. */
+  dropFrame?: boolean
+}
+
+const SCC_COLUMNS = 32
+const SCC_MAX_ROWS = 4
+const SCC_FRAME_RATE = 30000 / 1001
+
+// Miscellaneous control codes, CC1.
+const RESUME_CAPTION_LOADING = [0x14, 0x20] as const
+const ERASE_DISPLAYED_MEMORY = [0x14, 0x2c] as const
+const ERASE_NON_DISPLAYED_MEMORY = [0x14, 0x2e] as const
+const END_OF_CAPTION = [0x14, 0x2f] as const
+
+/** Preamble address code first byte and second-byte base per screen row 1–15. */
+const PAC_ROWS: Record<number, readonly [number, number]> = {
+  1: [0x11, 0x40],
+  2: [0x11, 0x60],
+  3: [0x12, 0x40],
+  4: [0x12, 0x60],
+  5: [0x15, 0x40],
+  6: [0x15, 0x60],
+  7: [0x16, 0x40],
+  8: [0x16, 0x60],
+  9: [0x17, 0x40],
+  10: [0x17, 0x60],
+  11: [0x10, 0x40],
+  12: [0x13, 0x40],
+  13: [0x13, 0x60],
+  14: [0x14, 0x40],
+  15: [0x14, 0x60],
+}
+
+/** Mid-row color codes (second byte after 0x11); +1 adds underline. */
+const PALETTE: ReadonlyArray<{ code: number; r: number; g: number; b: number }> = [
+  { code: 0x20, r: 255, g: 255, b: 255 },
+  { code: 0x22, r: 0, g: 255, b: 0 },
+  { code: 0x24, r: 0, g: 0, b: 255 },
+  { code: 0x26, r: 0, g: 255, b: 255 },
+  { code: 0x28, r: 255, g: 0, b: 0 },
+  { code: 0x2a, r: 255, g: 255, b: 0 },
+  { code: 0x2c, r: 255, g: 0, b: 255 },
+]
+const MIDROW_WHITE = 0x20
+const MIDROW_ITALICS = 0x2e
+
+/** Basic-set positions that differ from ASCII. */
+const BASIC_SUBSTITUTIONS: Record<string, number> = {
+  á: 0x2a,
+  é: 0x5c,
+  í: 0x5e,
+  ó: 0x5f,
+  ú: 0x60,
+  ç: 0x7b,
+  '÷': 0x7c,
+  Ñ: 0x7d,
+  ñ: 0x7e,
+  '█': 0x7f,
+}
+/** ASCII characters whose basic-set slot holds something else. */
+const ASCII_REPLACED = new Set(['*', '\\', '^', '_', '`', '{', '|', '}', '~'])
+
+const SPECIAL_CHARACTERS = '®°½¿™¢£♪à\u0000èâêîôû'
+const EXTENDED_CHARACTERS_12 = 'ÁÉÓÚÜü‘¡*’—©℠•“”ÀÂÇÈÊËëÎÏïÔÙùÛ«»'
+const EXTENDED_CHARACTERS_13 = 'ÃãÍÌìÒòÕõ{}\\^_|~ÄäÖöß¥¤¦ÅåØø┌┐└┘'
+
+/** Basic character shown by decoders without the extended set. */
+const EXTENDED_FALLBACKS: Record<string, string> = {
+  '‘': "'",
+  '’': "'",
+  '“': '"',
+  '”': '"',
+  '«': '"',
+  '»': '"',
+  '—': '-',
+  '•': '.',
+  '¡': '!',
+  '*': '.',
+  '{': '(',
+  '}': ')',
+  '\\': '/',
+  '^': "'",
+  '_': '-',
+  '|': '!',
+  '~': '-',
+}
+
+const TEXT_NORMALIZATION: Record<string, string> = {
+  '…': '...',
+  '–': '-',
+  '\u00a0': ' ',
+  '\t': ' ',
+  '`': "'",
+}
+
+interface SccAttributes {
+  color: number
+  italic: boolean
+  underline: boolean
+}
+
+interface StyledChar {
+  char: string
+  attributes: SccAttributes
+}
+
+type SccToken =
+  | { type: 'basic'; byte: number }
+  | { type: 'control'; bytes: readonly [number, number] }
+  | { type: 'extended'; fallback: number; bytes: readonly [number, number] }
+
+const PLAIN: SccAttributes = { color: MIDROW_WHITE, italic: false, underline: false }
+
+export function serializeScc(
+  document: SubtitleExportDocument,
+  options: SccExportOptions = {},
+): SubtitleExportResult {
+  const dropFrame = options.dropFrame ?? true
+  const warnings = new SubtitleWarningCollector()
+  const { style } = document
+
+  warnings.add('Font family and size are chosen by the caption decoder and were not exported')
+  if (style.fontWeight === 'bold' || style.fontWeight === 'semibold') {
+    warnings.add('Bold text is not supported')
+  }
+  if (style.stroke || style.textShadow) warnings.add('Text outlines and shadows are not supported')
+  const background = parseCssColor(style.backgroundColor)
+  if (!background || background.a < 1 || background.r + background.g + background.b > 0) {
+    warnings.add('Caption decoders draw their own black background box')
+  }
+  if (document.karaoke && document.cues.some(hasKaraokeWords)) {
+    warnings.add('Karaoke word highlighting was exported as plain lines')
+  }
+
+  const cues = [...document.cues]
+    .filter((cue) => cue.text.trim() && cue.endSeconds > cue.startSeconds)
+    .sort((a, b) => a.startSeconds - b.startSeconds)
+
+  const occupied = new Map<number, string>()
+  let floor = 0
+  for (const [index, cue] of cues.entries()) {
+    const load = buildLoadWords(cue, document, warnings)
+    const startFrame = secondsToFrame(cue.startSeconds)
+    const endFrame = secondsToFrame(cue.endSeconds)
+
+    // End Of Caption lands on the start frame; the load fills the free
+    // frames before it, but never before the previous caption's flip.
+    let eocFrame = Math.max(startFrame, floor + load.length)
+    while (!isFree(occupied, eocFrame, 2) || countFree(occupied, floor, eocFrame) < load.length) {
+      eocFrame++
+    }
+    if (eocFrame > startFrame) {
+      warnings.add('Captions too close together were delayed to leave time to load them')
+    }
+    let frame = eocFrame - 1
+    for (let word = load.length - 1; word >= 0; word--) {
+      while (occupied.has(frame)) frame--
+      occupied.set(frame, load[word]!)
+      frame--
+    }
+    const eoc = formatWord(END_OF_CAPTION)
+    occupied.set(eocFrame, eoc)
+    occupied.set(eocFrame + 1, eoc)
+    floor = eocFrame + 2
+
+    // A caption flipped on within two frames replaces this one anyway.
+    const next = cues[index + 1]
+    if (!next || secondsToFrame(next.startSeconds) > endFrame + 2) {
+      let clearFrame = Math.max(endFrame, floor)
+      while (!isFree(occupied, clearFrame, 2)) clearFrame++
+      const edm = formatWord(ERASE_DISPLAYED_MEMORY)
+      occupied.set(clearFrame, edm)
+      occupied.set(clearFrame + 1, edm)
+    }
+  }
+
+  const blocks: string[] = []
+  let block: { frame: number; words: string[] } | null = null
+  for (const frame of Array.from(occupied.keys()).sort((a, b) => a - b)) {
+    if (block && frame === block.frame + block.words.length) {
+      block.words.push(occupied.get(frame)!)
+      continue
+    }
+    if (block) blocks.push(`${formatSccTimecode(block.frame, dropFrame)}\t${block.words.join(' ')}`)
+    block = { frame, words: [occupied.get(frame)!] }
+  }
+  if (block) blocks.push(`${formatSccTimecode(block.frame, dropFrame)}\t${block.words.join(' ')}`)
+
+  const text = ['Scenarist_SCC V1.0', ...blocks].join('\n\n')
+  return { text: `${text}\n`, warnings: warnings.toArray() }
+}
+
+/** Words that erase off-screen memory and paint one cue into it. */
+function buildLoadWords(
+  cue: SubtitleSegmentCue,
+  document: SubtitleExportDocument,
+  warnings: SubtitleWarningCollector,
+): string[] {
+  const { style } = document
+  const parsed = parseSubtitleCueText(cue.text)
+  const speaker = cue.speakerId ? document.speakerStyles?.[cue.speakerId] : undefined
+  const baseColor = speaker?.color ?? style.color
+  const baseItalic = (speaker?.fontStyle ?? style.fontStyle) === 'italic'
+  const baseUnderline = speaker?.underline ?? style.underline ?? false
+
+  const hardLines: StyledChar[][] = [[]]
+  let styled = false
+  for (const span of parsed.spans) {
+    if (span.fontWeight === 'bold' || span.fontWeight === 'semibold') {
+      warnings.add('Bold text is not supported')
+    }
+    const attributes: SccAttributes = {
+      color: nearestPaletteCode(span.color ?? baseColor, warnings),
+      italic: span.fontStyle ? span.fontStyle === 'italic' : baseItalic,
+      underline: span.underline ?? baseUnderline,
+    }
+    if (!sameAttributes(attributes, PLAIN)) styled = true
+    for (const raw of Array.from(span.text)) {
+      for (const char of Array.from(TEXT_NORMALIZATION[raw] ?? raw)) {
+        if (char === '\n') hardLines.push([])
+        else if (char !== '\r') hardLines[hardLines.length - 1]!.push({ char, attributes })
+      }
+    }
+  }
+
+  // A leading mid-row code takes a column, so styled cues wrap one earlier.
+  const width = styled ? SCC_COLUMNS - 1 : SCC_COLUMNS
+  let rows = hardLines.flatMap((line) => wrapStyledLine(trimStyledLine(line), width))
+  rows = rows.filter((row) => row.length > 0)
+  if (rows.length > SCC_MAX_ROWS) {
+    warnings.add(`Captions longer than ${SCC_MAX_ROWS} rows were truncated`)
+    rows = rows.slice(0, SCC_MAX_ROWS)
+  }
+
+  const placement = resolvePlacement(parsed.alignment, style, document)
+  const firstRow =
+    placement.verticalAlign === 'top'
+      ? 1
+      : placement.verticalAlign === 'middle'
+        ? Math.round(8 - rows.length / 2)
+        : 16 - rows.length
+
+  const writer = new SccWordWriter()
+  writer.control(ERASE_NON_DISPLAYED_MEMORY)
+  writer.control(RESUME_CAPTION_LOADING)
+  for (const [index, row] of rows.entries()) {
+    const tokens = tokenizeRow(row, warnings)
+    const column =
+      placement.textAlign === 'left'
+        ? 0
+        : placement.textAlign === 'right'
+          ? SCC_COLUMNS - tokens.length
+          : Math.floor((SCC_COLUMNS - tokens.length) / 2)
+    const indent = Math.floor(Math.max(0, column) / 4) * 4
+    const [pacFirst, pacBase] = PAC_ROWS[firstRow + index]!
+    writer.control([pacFirst, pacBase + 0x10 + (indent / 4) * 2])
+    const tab = Math.max(0, column) - indent
+    if (tab > 0) writer.control([0x17, 0x20 + tab])
+    for (const token of tokens) writer.token(token)
+  }
+  writer.flush()
+  return writer.words
+}
+
+function resolvePlacement(
+  alignment: ReturnType<typeof parseSubtitleCueText>['alignment'],
+  style: TextStyleFields,
+  document: SubtitleExportDocument,
+): { textAlign: 'left' | 'center' | 'right'; verticalAlign: 'top' | 'middle' | 'bottom' } {
+  if (alignment) return alignment
+  const textAlign = style.textAlign ?? 'center'
+  if (style.verticalAlign === 'top' || style.verticalAlign === 'bottom') {
+    return { textAlign, verticalAlign: style.verticalAlign }
+  }
+  // Centered in a box: use where the box sits on screen.
+  const region = document.region ?? DEFAULT_SUBTITLE_REGION
+  const center = region.top + region.height / 2
+  const verticalAlign = center > 0.6 ? 'bottom' : center < 0.4 ? 'top' : 'middle'
+  return { textAlign, verticalAlign }
+}
+
+function trimStyledLine(line: StyledChar[]): StyledChar[] {
+  let start = 0
+  let end = line.length
+  while (start < end && line[start]!.char === ' ') start++
+  while (end > start && line[end - 1]!.char === ' ') end--
+  return line.slice(start, end)
+}
+
+function wrapStyledLine(chars: StyledChar[], width: number): StyledChar[][] {
+  const lines: StyledChar[][] = []
+  let start = 0
+  while (chars.length - start > width) {
+    let breakAt = -1
+    for (let index = start + width; index > start; index--) {
+      if (chars[index]!.char === ' ') {
+        breakAt = index
+        break
+      }
+    }
+    if (breakAt === -1) {
+      lines.push(chars.slice(start, start + width))
+      start += width
+    } else {
+      lines.push(trimStyledLine(chars.slice(start, breakAt)))
+      start = breakAt + 1
+    }
+  }
+  lines.push(chars.slice(start))
+  return lines
+}
+
+/** One token per screen column; style changes take over an adjacent space. */
+function tokenizeRow(row: StyledChar[], warnings: SubtitleWarningCollector): SccToken[] {
+  const tokens: SccToken[] = []
+  let current = PLAIN
+  for (const { char, attributes } of row) {
+    if (!sameAttributes(attributes, current)) {
+      current = attributes
+      const midrow: SccToken = { type: 'control', bytes: [0x11, midrowCode(attributes)] }
+      if (char === ' ') {
+        tokens.push(midrow)
+        continue
+      }
+      const last = tokens[tokens.length - 1]
+      if (last?.type === 'basic' && last.byte === 0x20) tokens.pop()
+      tokens.push(midrow)
+    }
+    tokens.push(encodeCharacter(char, warnings))
+  }
+  return tokens
+}
+
+function encodeCharacter(char: string, warnings: SubtitleWarningCollector): SccToken {
+  const substitution = BASIC_SUBSTITUTIONS[char]
+  if (substitution !== undefined) return { type: 'basic', byte: substitution }
+  const code = char.charCodeAt(0)
+  if (char.length === 1 && code >= 0x20 && code < 0x7f && !ASCII_REPLACED.has(char)) {
+    return { type: 'basic', byte: code }
+  }
+  const special = SPECIAL_CHARACTERS.indexOf(char)
+  if (special >= 0 && char !== '\u0000') {
+    return { type: 'control', bytes: [0x11, 0x30 + special] }
+  }
+  for (const [first, table] of [
+    [0x12, EXTENDED_CHARACTERS_12],
+    [0x13, EXTENDED_CHARACTERS_13],
+  ] as const) {
+    const index = table.indexOf(char)
+    if (index >= 0) {
+      return {
+        type: 'extended',
+        fallback: basicFallback(EXTENDED_FALLBACKS[char] ?? char),
+        bytes: [first, 0x20 + index],
+      }
+    }
+  }
+  const base = char.normalize('NFD').replace(/[\u0300-\u036f]/g, '')
+  warnings.add('Characters outside the CEA-608 character set were replaced')
+  return base !== char && base.length === 1 && base !== '\u0000'
+    ? encodeCharacter(base, warnings)
+    : { type: 'basic', byte: 0x3f }
+}
+
+function basicFallback(char: string): number {
+  const base = char.normalize('NFD').replace(/[\u0300-\u036f]/g, '')
+  const code = base.charCodeAt(0)
+  return base.length === 1 && code >= 0x20 && code < 0x7f && !ASCII_REPLACED.has(base)
+    ? code
+    : 0x20
+}
+
+function midrowCode(attributes: SccAttributes): number {
+  const base = attributes.italic ? MIDROW_ITALICS : attributes.color
+  return base + (attributes.underline ? 1 : 0)
+}
+
+function nearestPaletteCode(
+  color: string | undefined,
+  warnings: SubtitleWarningCollector,
+): number {
+  const parsed = parseCssColor(color)
+  if (!parsed) return MIDROW_WHITE
+  let best = PALETTE[0]!
+  let bestDistance = Number.POSITIVE_INFINITY
+  for (const entry of PALETTE) {
+    const distance =
+      (entry.r - parsed.r) ** 2 + (entry.g - parsed.g) ** 2 + (entry.b - parsed.b) ** 2
+    if (distance < bestDistance) {
+      best = entry
+      bestDistance = distance
+    }
+  }
+  if (bestDistance > 0) warnings.add('Colors were matched to the nearest CEA-608 color')
+  return best.code
+}
+
+function sameAttributes(a: SccAttributes, b: SccAttributes): boolean {
+  return a.color === b.color && a.italic === b.italic && a.underline === b.underline
+}
+
+/** Packs characters two per word and sends control codes twice, word-aligned. */
+class SccWordWriter {
+  readonly words: string[] = []
+  private pending: number | null = null
+
+  token(token: SccToken): void {
+    if (token.type === 'basic') {
+      this.char(token.byte)
+    } else if (token.type === 'control') {
+      this.control(token.bytes)
+    } else {
+      // Decoders with the extended set back over the fallback.
+      this.char(token.fallback)
+      this.control(token.bytes)
+    }
+  }
+
+  control(bytes: readonly [number, number]): void {
+    this.flush()
+    const word = formatWord(bytes)
+    this.words.push(word, word)
+  }
+
+  flush(): void {
+    if (this.pending === null) return
+    this.words.push(formatWord([this.pending, 0x00]))
+    this.pending = null
+  }
+
+  private char(byte: number): void {
+    if (this.pending === null) {
+      this.pending = byte
+      return
+    }
+    this.words.push(formatWord([this.pending, byte]))
+    this.pending = null
+  }
+}
+
+function formatWord(bytes: readonly [number, number]): string {
+  return `${toHexByte(withOddParity(bytes[0]))}${toHexByte(withOddParity(bytes[1]))}`
+}
+
+/** Set bit 7 so the byte has an odd number of one bits. */
+export function withOddParity(byte: number): number {
+  let ones = 0
+  for (let value = byte & 0x7f; value > 0; value >>= 1) ones += value & 1
+  return ones % 2 === 0 ? (byte & 0x7f) | 0x80 : byte & 0x7f
+}
+
+function isFree(occupied: Map<number, string>, frame: number, count: number): boolean {
+  for (let offset = 0; offset < count; offset++) {
+    if (occupied.has(frame + offset)) return false
+  }
+  return true
+}
+
+function countFree(occupied: Map<number, string>, from: number, to: number): number {
+  let free = 0
+  for (let frame = from; frame < to; frame++) if (!occupied.has(frame)) free++
+  return free
+}
+
+function secondsToFrame(seconds: number): number {
+  return Math.max(0, Math.round(seconds * SCC_FRAME_RATE))
+}
+
+/** `HH:MM:SS;FF` drop-frame (or `HH:MM:SS:FF`) timecode for a 29.97 fps frame count. */
+export function formatSccTimecode(frame: number, dropFrame = true): string {
+  let label = frame
+  if (dropFrame) {
+    // Skip labels :00 and :01 at every minute except each tenth.
+    const tenMinutes = Math.floor(frame / 17982)
+    const remainder = frame % 17982
+    label += 18 * tenMinutes + (remainder < 2 ? 0 : 2 * Math.floor((remainder - 2) / 1798))
+  }
+  const ff = label % 30
+  const ss = Math.floor(label / 30) % 60
+  const mm = Math.floor(label / 1800) % 60
+  const hh = Math.floor(label / 108000)
+  return `${pad2(hh)}:${pad2(mm)}:${pad2(ss)}${dropFrame ? ';' : ':'}${pad2(ff)}`
+}
+
+function pad2(value: number): string {
+  return String(value).padStart(2, '0')
+}
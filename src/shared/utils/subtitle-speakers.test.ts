@@ -0,0 +1,46 @@
+import { describe, expect, it } from 'vite-plus/test'
+import {
+  SPEAKER_COLORS,
+  buildSubtitleSpeakerStyles,
+  createTranscriptSpeaker,
+  getSpeakerColor,
+  resolveCueSpeakerStyle,
+} from './subtitle-speakers'
+
+describe('subtitle speakers', () => {
+  it('numbers default speakers from one and cycles the palette', () => {
+    expect(createTranscriptSpeaker(0)).toEqual({
+      id: 'speaker-1',
+      name: 'Speaker 1',
+      color: SPEAKER_COLORS[0],
+    })
+    expect(getSpeakerColor(SPEAKER_COLORS.length + 1)).toBe(SPEAKER_COLORS[1])
+  })
+
+  it('keeps caption style edits but follows transcript renames', () => {
+    const styles = buildSubtitleSpeakerStyles(
+      [
+        { id: 'speaker-1', name: 'Host', color: '#facc15' },
+        { id: 'speaker-2', name: 'Guest', color: '#38bdf8' },
+      ],
+      { 'speaker-1': { name: 'Speaker 1', color: '#ffffff', fontWeight: 'bold' } },
+    )
+
+    expect(styles).toEqual({
+      'speaker-1': { name: 'Host', color: '#ffffff', fontWeight: 'bold' },
+      'speaker-2': { name: 'Guest', color: '#38bdf8' },
+    })
+    expect(buildSubtitleSpeakerStyles(undefined)).toBeUndefined()
+  })
+
+  it('resolves cue overrides without the display name', () => {
+    const styles = { 'speaker-1': { name: 'Host', color: '#facc15', fontStyle: 'italic' as const } }
+
+    expect(resolveCueSpeakerStyle(styles, 'speaker-1')).toEqual({
+      color: '#facc15',
+      fontStyle: 'italic',
+    })
+    expect(resolveCueSpeakerStyle(styles, 'speaker-9')).toBeNull()
+    expect(resolveCueSpeakerStyle(styles, undefined)).toBeNull()
+  })
+})
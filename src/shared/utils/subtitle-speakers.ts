@@ -0,0 +1,78 @@
+/**
+ * Speaker helpers shared by transcript diarization, caption generation and
+ * the subtitle renderers.
+ *
+ * Speaker ids are per-media (`speaker-1`, `speaker-2`, …) and assigned in
+ * order of first appearance, so the same voice keeps its id, name and color
+ * when a clip's captions are regenerated.
+ */
+import type { MediaTranscriptSpeaker } from '@/types/storage'
+import type { TextInlineStyleFields } from '@/types/text'
+import type { SubtitleSpeakerStyle } from '@/types/timeline'
+
+/** Caption colors handed out to speakers in order; readable on dark backdrops. */
+export const SPEAKER_COLORS = [
+  '#facc15',
+  '#38bdf8',
+  '#f472b6',
+  '#4ade80',
+  '#fb923c',
+  '#a78bfa',
+  '#2dd4bf',
+  '#f87171',
+] as const
+
+export function getSpeakerColor(index: number): string {
+  return SPEAKER_COLORS[
+    ((index % SPEAKER_COLORS.length) + SPEAKER_COLORS.length) % SPEAKER_COLORS.length
+  ]!
+}
+
+/** Default speaker record for the `index`-th voice (zero-based). */
+export function createTranscriptSpeaker(index: number): MediaTranscriptSpeaker {
+  return {
+    id: `speaker-${index + 1}`,
+    name: `Speaker ${index + 1}`,
+    color: getSpeakerColor(index),
+  }
+}
+
+/**
+ * Caption styles for a transcript's speakers. Styles already on the caption
+ * (user edits in the properties panel) win over the transcript color; the
+ * display name always follows the transcript so renames carry through.
+ */
+export function buildSubtitleSpeakerStyles(
+  speakers: readonly MediaTranscriptSpeaker[] | undefined,
+  existing?: Readonly<Record<string, SubtitleSpeakerStyle>>,
+): Record<string, SubtitleSpeakerStyle> | undefined {
+  if (!speakers || speakers.length === 0) return undefined
+  const styles: Record<string, SubtitleSpeakerStyle> = {}
+  for (const speaker of speakers) {
+    styles[speaker.id] = {
+      color: speaker.color,
+      ...existing?.[speaker.id],
+      name: speaker.name,
+    }
+  }
+  return styles
+}
+
+/**
+ * Inline style overrides for one cue, or null when the cue has no speaker
+ * or its speaker has no style. Renderers spread this over the segment style.
+ */
+export function resolveCueSpeakerStyle(
+  speakerStyles: Readonly<Record<string, SubtitleSpeakerStyle>> | undefined,
+  speakerId: string | undefined,
+): TextInlineStyleFields | null {
+  if (!speakerStyles || !speakerId) return null
+  const style = speakerStyles[speakerId]
+  if (!style) return null
+  const overrides: TextInlineStyleFields = {}
+  for (const key of Object.keys(style) as Array<keyof SubtitleSpeakerStyle>) {
+    if (key === 'name' || style[key] === undefined) continue
+    ;(overrides as Record<string, unknown>)[key] = style[key]
+  }
+  return overrides
+}
@@ -0,0 +1,184 @@
+import { describe, expect, it } from 'vite-plus/test'
+import { parseTtml, parseTtmlTime, serializeTtml } from './subtitle-ttml'
+
+const FRAME = { frameWidth: 1920, frameHeight: 1080 }
+
+const NAMESPACES = [
+  'xmlns="http://www.w3.org/ns/ttml"',
+  'xmlns:tts="http://www.w3.org/ns/ttml#styling"',
+  'xmlns:ttp="http://www.w3.org/ns/ttml#parameter"',
+].join(' ')
+
+describe('parseTtmlTime', () => {
+  it('reads clock times, frames and offset times', () => {
+    const timing = { frameRate: 24, subFrameRate: 1, tickRate: 10_000_000 }
+
+    expect(parseTtmlTime('00:00:01.500', timing)).toBe(1.5)
+    expect(parseTtmlTime('00:00:02:12', timing)).toBe(2.5)
+    expect(parseTtmlTime('1.5s', timing)).toBe(1.5)
+    expect(parseTtmlTime('250ms', timing)).toBe(0.25)
+    expect(parseTtmlTime('48f', timing)).toBe(2)
+    expect(parseTtmlTime('20000000t', timing)).toBe(2)
+    expect(parseTtmlTime('soon', timing)).toBeNull()
+  })
+})
+
+describe('parseTtml', () => {
+  it('resolves referential styles, regions and nested timing', () => {
+    const result = parseTtml(
+      `<?xml version="1.0" encoding="UTF-8"?>
+<tt ${NAMESPACES} ttp:frameRate="25" tts:extent="1280px 720px">
+  <head>
+    <styling>
+      <style xml:id="s1" tts:fontFamily="Verdana, sansSerif" tts:fontSize="48px"
+        tts:color="white"/>
+      <style xml:id="s2" style="s1" tts:textOutline="black 2px"/>
+    </styling>
+    <layout>
+      <region xml:id="bottom" tts:origin="10% 80%" tts:extent="80% 15%"
+        tts:displayAlign="after" tts:textAlign="center"/>
+    </layout>
+  </head>
+  <body style="s2" region="bottom">
+    <div begin="00:00:10.000">
+      <p begin="00:00:01.000" end="00:00:03:00">
+        Hello <span tts:fontStyle="italic" tts:color="yellow">world</span>
+      </p>
+      <p begin="5s" dur="1s">Second<br/>line</p>
+    </div>
+  </body>
+</tt>`,
+      FRAME,
+    )
+
+    expect(result.cues).toEqual([
+      {
+        id: 'cue-1',
+        startSeconds: 11,
+        endSeconds: 13,
+        text: 'Hello <i><font color="#ffff00">world</font></i>',
+      },
+      { id: 'cue-2', startSeconds: 15, endSeconds: 16, text: 'Second\nline' },
+    ])
+    expect(result.style).toMatchObject({
+      fontFamily: 'Verdana',
+      fontSize: 72,
+      color: '#ffffff',
+      stroke: { width: 3, color: '#000000' },
+      textAlign: 'center',
+      verticalAlign: 'bottom',
+    })
+    expect(result.region?.left).toBeCloseTo(0.1)
+    expect(result.region?.top).toBeCloseTo(0.8)
+    expect(result.region?.width).toBeCloseTo(0.8)
+    expect(result.region?.height).toBeCloseTo(0.15)
+    expect(result.warnings).toEqual([])
+  })
+
+  it('snaps cues in other regions and reports styling it cannot keep', () => {
+    const result = parseTtml(
+      `<tt ${NAMESPACES}>
+  <head>
+    <layout>
+      <region xml:id="bottom" tts:displayAlign="after"/>
+      <region xml:id="top" tts:displayAlign="before"/>
+    </layout>
+  </head>
+  <body>
+    <div>
+      <p region="bottom" begin="1s" end="2s">One</p>
+      <p region="bottom" begin="3s" end="4s">Two</p>
+      <p region="top" begin="5s" end="6s">Three</p>
+      <p region="bottom" begin="7s" end="8s">
+        <span tts:fontSize="60px" tts:textCombine="all">Big</span>
+      </p>
+    </div>
+  </body>
+</tt>`,
+      FRAME,
+    )
+
+    expect(result.cues.map((cue) => cue.text)).toEqual(['One', 'Two', '{\\an8}Three', 'Big'])
+    expect(result.style?.verticalAlign).toBe('bottom')
+    expect(result.warnings).toEqual([
+      'TTML style tts:textCombine is not supported',
+      'Per-span font sizes are not supported',
+      'Cues in other regions were snapped to the nearest screen position',
+    ])
+  })
+
+  it('rejects documents that are not TTML', () => {
+    expect(parseTtml('<html><body/></html>', FRAME)).toEqual({
+      cues: [],
+      warnings: ['Not a valid TTML document'],
+    })
+  })
+})
+
+describe('serializeTtml', () => {
+  const document = {
+    ...FRAME,
+    cues: [
+      { id: 'a', startSeconds: 1, endSeconds: 2.5, text: 'Hi <i>there</i>' },
+      { id: 'b', startSeconds: 3, endSeconds: 4, text: '{\\an8}Top\nline', speakerId: 'spk-1' },
+    ],
+    style: {
+      fontFamily: 'Inter',
+      fontSize: 48,
+      color: '#ffffff',
+      backgroundColor: 'rgba(0, 0, 0, 0.5)',
+      textPadding: 8,
+      textAlign: 'center' as const,
+      verticalAlign: 'bottom' as const,
+    },
+    region: { left: 0.1, top: 0.7, width: 0.8, height: 0.2 },
+    speakerStyles: { 'spk-1': { name: 'Ana', color: '#ffff00' } },
+    language: 'en',
+    title: 'Captions & more',
+  }
+
+  it('writes an IMSC1 text profile document in timeline time', () => {
+    const result = serializeTtml(document)
+    const lines = result.text.split('\n').map((line) => line.trim())
+
+    expect(lines[1]).toContain('ttp:profile="http://www.w3.org/ns/ttml/profile/imsc1/text"')
+    expect(lines[1]).toContain('tts:extent="1920px 1080px"')
+    expect(lines[1]).toContain('xml:lang="en"')
+    expect(lines).toContain('<ttm:title>Captions &amp; more</ttm:title>')
+    expect(lines).toContain(
+      '<style xml:id="base" tts:fontFamily="Inter" tts:fontSize="48px" tts:color="#ffffffff" tts:fontWeight="normal" tts:fontStyle="normal" tts:textAlign="center"/>',
+    )
+    expect(lines).toContain('<style xml:id="box" tts:backgroundColor="#00000080"/>')
+    expect(lines).toContain('<style xml:id="speaker1" tts:color="#ffff00ff"/>')
+    expect(lines).toContain(
+      '<region xml:id="main" tts:origin="10% 70%" tts:extent="80% 20%" tts:displayAlign="after"/>',
+    )
+    expect(lines).toContain(
+      '<p xml:id="cue1" begin="00:00:01.000" end="00:00:02.500"><span style="box">Hi <span tts:fontStyle="italic">there</span></span></p>',
+    )
+    expect(lines).toContain(
+      '<p xml:id="cue2" begin="00:00:03.000" end="00:00:04.000" style="speaker1" region="topSafe" tts:textAlign="center"><span style="box">Top<br/>line</span></p>',
+    )
+    expect(result.warnings).toEqual(['Background padding is not supported in IMSC1'])
+  })
+
+  it('round-trips through parseTtml', () => {
+    const parsed = parseTtml(serializeTtml(document).text, FRAME)
+
+    expect(parsed.cues.map((cue) => [cue.startSeconds, cue.endSeconds, cue.text])).toEqual([
+      [1, 2.5, 'Hi <i>there</i>'],
+      [3, 4, '{\\an8}<font color="#ffff00">Top\nline</font>'],
+    ])
+    expect(parsed.style).toMatchObject({
+      fontFamily: 'Inter',
+      fontSize: 48,
+      color: '#ffffff',
+      backgroundColor: 'rgba(0, 0, 0, 0.502)',
+      textAlign: 'center',
+      verticalAlign: 'bottom',
+    })
+    expect(parsed.region?.left).toBeCloseTo(0.1)
+    expect(parsed.region?.top).toBeCloseTo(0.7)
+    expect(parsed.region?.width).toBeCloseTo(0.8)
+  })
+})
@@ -0,0 +1,944 @@
+/**
+ * TTML, including the IMSC1 text profile used for broadcast and OTT
+ * deliveries (and DFXP, its older name).
+ *
+ * Import resolves referential, region and inline styling the way TTML's
+ * inheritance does, takes the most common paragraph style as the segment
+ * style, keeps span-level italic/bold/underline/color as cue markup, and
+ * maps the region to the caption box. Export writes an IMSC1 text profile
+ * document with pixel lengths against a root extent equal to the frame.
+ */
+import type { TextStyleFields } from '@/types/text'
+import type { SubtitleSegmentCue } from '@/types/timeline'
+import { parseSubtitleCueText } from './subtitle-cue-format'
+import {
+  DEFAULT_SUBTITLE_REGION,
+  SubtitleWarningCollector,
+  formatCssColor,
+  hasKaraokeWords,
+  isVisibleColor,
+  parseCssColor,
+  toHexByte,
+  type Rgba,
+  type SubtitleExportDocument,
+  type SubtitleExportResult,
+  type SubtitleFileParseResult,
+  type SubtitleFrameSize,
+  type SubtitleRegion,
+} from './subtitle-document'
+
+const TT_NS = 'http://www.w3.org/ns/ttml'
+const TTS_NS = 'http://www.w3.org/ns/ttml#styling'
+const TTP_NS = 'http://www.w3.org/ns/ttml#parameter'
+const TTM_NS = 'http://www.w3.org/ns/ttml#metadata'
+const XML_NS = 'http://www.w3.org/XML/1998/namespace'
+const IMSC1_TEXT_PROFILE = 'http://www.w3.org/ns/ttml/profile/imsc1/text'
+
+/** Styling attributes the timeline has an equivalent for. */
+const SUPPORTED_STYLE_ATTRIBUTES = new Set([
+  'color',
+  'backgroundColor',
+  'fontFamily',
+  'fontSize',
+  'fontStyle',
+  'fontWeight',
+  'textDecoration',
+  'textAlign',
+  'displayAlign',
+  'lineHeight',
+  'textOutline',
+  'textShadow',
+  'origin',
+  'extent',
+  // Layout or script properties with nothing to lose for left-to-right captions.
+  'direction',
+  'unicodeBidi',
+  'showBackground',
+  'zIndex',
+  'overflow',
+])
+
+/** Properties that flow from regions and ancestors into the text. */
+const INHERITED_STYLE_ATTRIBUTES = new Set([
+  'color',
+  'fontFamily',
+  'fontSize',
+  'fontStyle',
+  'fontWeight',
+  'textDecoration',
+  'textAlign',
+  'lineHeight',
+  'textOutline',
+  'textShadow',
+  'wrapOption',
+  'visibility',
+  'writingMode',
+])
+
+const GENERIC_FONT_FAMILIES = new Set([
+  'default',
+  'monospace',
+  'sansserif',
+  'serif',
+  'monospacesansserif',
+  'monospaceserif',
+  'proportionalsansserif',
+  'proportionalserif',
+])
+
+type StyleAttributes = Record<string, string>
+
+interface TimingParameters {
+  frameRate: number
+  subFrameRate: number
+  tickRate: number
+}
+
+interface LengthContext extends SubtitleFrameSize {
+  /** Height of one `c` cell in frame pixels. */
+  cellHeight: number
+  /** Frame pixels per document `px`, when the root declares a pixel extent. */
+  pxScaleX: number
+  pxScaleY: number
+}
+
+interface ParagraphEntry {
+  startSeconds: number
+  endSeconds: number
+  text: string
+  style: StyleAttributes
+  regionId: string | undefined
+}
+
+export function parseTtml(text: string, frame: SubtitleFrameSize): SubtitleFileParseResult {
+  const warnings = new SubtitleWarningCollector()
+  const document = new DOMParser().parseFromString(text, 'application/xml')
+  const root = document.documentElement
+  if (document.getElementsByTagName('parsererror').length > 0 || root.localName !== 'tt') {
+    return { cues: [], warnings: ['Not a valid TTML document'] }
+  }
+
+  const timing = readTimingParameters(root)
+  const lengths = readLengthContext(root, frame)
+  const styles = readReferentialStyles(root)
+  const regions = readRegions(root, styles)
+
+  const body = childElements(root).find((element) => element.localName === 'body')
+  const paragraphs: ParagraphEntry[] = []
+  if (body) {
+    collectParagraphs(body, {
+      begin: 0,
+      end: Number.POSITIVE_INFINITY,
+      style: {},
+      regionId: undefined,
+      styles,
+      regions,
+      timing,
+      warnings,
+      out: paragraphs,
+    })
+  }
+
+  // The most common paragraph look becomes the segment style; cues that
+  // differ carry what they can as inline markup.
+  const usage = new Map<string, { count: number; entry: ParagraphEntry }>()
+  for (const paragraph of paragraphs) {
+    const key = JSON.stringify([paragraphStyleKey(paragraph.style), paragraph.regionId ?? ''])
+    const current = usage.get(key)
+    usage.set(key, { count: (current?.count ?? 0) + 1, entry: current?.entry ?? paragraph })
+  }
+  const base = Array.from(usage.values()).sort((a, b) => b.count - a.count)[0]?.entry
+
+  const cues: SubtitleSegmentCue[] = []
+  for (const paragraph of paragraphs) {
+    if (paragraph.endSeconds <= paragraph.startSeconds) {
+      warnings.add('Skipped a paragraph whose end time is not after its start time')
+      continue
+    }
+    let cueText = paragraph.text
+    if (base && paragraph !== base) {
+      cueText = wrapParagraphDifferences(cueText, paragraph, base, warnings)
+    }
+    if (!cueText.replace(/<[^>]*>|\{[^}]*\}/g, '').trim()) continue
+    cues.push({
+      id: `cue-${cues.length + 1}`,
+      startSeconds: paragraph.startSeconds,
+      endSeconds: Number.isFinite(paragraph.endSeconds)
+        ? paragraph.endSeconds
+        : paragraph.startSeconds + 2,
+      text: cueText,
+    })
+  }
+  cues.sort((a, b) => a.startSeconds - b.startSeconds)
+
+  const result: SubtitleFileParseResult = { cues, warnings: [] }
+  if (base) {
+    result.style = ttmlStyleToTextStyle(base.style, lengths, warnings)
+    const region = base.regionId ? regions.get(base.regionId) : undefined
+    if (region) {
+      result.region = regionGeometry(region, lengths)
+      noteUnsupportedStyles(region, warnings)
+      if (isVisibleColor(region.backgroundColor)) {
+        warnings.add('Region background fills are not supported')
+      }
+    }
+  }
+  result.warnings = warnings.toArray()
+  return result
+}
+
+function readTimingParameters(root: Element): TimingParameters {
+  const frameRate = Number.parseFloat(getParameter(root, 'frameRate') ?? '') || 30
+  const [numerator, denominator] = (getParameter(root, 'frameRateMultiplier') ?? '1 1')
+    .split(/\s+/)
+    .map(Number)
+  const multiplier = numerator && denominator ? numerator / denominator : 1
+  const subFrameRate = Number.parseFloat(getParameter(root, 'subFrameRate') ?? '') || 1
+  const tickRate =
+    Number.parseFloat(getParameter(root, 'tickRate') ?? '') ||
+    (getParameter(root, 'frameRate') ? frameRate * subFrameRate : 1)
+  return { frameRate: frameRate * multiplier, subFrameRate, tickRate }
+}
+
+function readLengthContext(root: Element, frame: SubtitleFrameSize): LengthContext {
+  const [, rows] = (getParameter(root, 'cellResolution') ?? '32 15').split(/\s+/).map(Number)
+  const extent = getStyleAttribute(root, 'extent')?.trim().split(/\s+/)
+  const extentWidth = extent?.[0]?.endsWith('px') ? Number.parseFloat(extent[0]) : NaN
+  const extentHeight = extent?.[1]?.endsWith('px') ? Number.parseFloat(extent[1]) : NaN
+  return {
+    ...frame,
+    cellHeight: frame.frameHeight / (rows && rows > 0 ? rows : 15),
+    pxScaleX: extentWidth > 0 ? frame.frameWidth / extentWidth : 1,
+    pxScaleY: extentHeight > 0 ? frame.frameHeight / extentHeight : 1,
+  }
+}
+
+/** `<style>` elements under `head/styling`, with `style` references resolved. */
+function readReferentialStyles(root: Element): Map<string, StyleAttributes> {
+  const raw = new Map<string, Element>()
+  for (const element of descendants(root, 'style')) {
+    const parent = element.parentElement?.localName
+    const id = element.getAttributeNS(XML_NS, 'id') ?? element.getAttribute('xml:id')
+    if (id && parent === 'styling') raw.set(id, element)
+  }
+
+  const resolved = new Map<string, StyleAttributes>()
+  const resolve = (id: string, seen: Set<string>): StyleAttributes => {
+    const cached = resolved.get(id)
+    if (cached) return cached
+    const element = raw.get(id)
+    if (!element || seen.has(id)) return {}
+    seen.add(id)
+    const attributes: StyleAttributes = {}
+    for (const ref of splitIdRefs(element.getAttribute('style'))) {
+      Object.assign(attributes, resolve(ref, seen))
+    }
+    Object.assign(attributes, styleAttributesOf(element))
+    resolved.set(id, attributes)
+    return attributes
+  }
+  for (const id of raw.keys()) resolve(id, new Set())
+  return resolved
+}
+
+function readRegions(
+  root: Element,
+  styles: Map<string, StyleAttributes>,
+): Map<string, StyleAttributes> {
+  const regions = new Map<string, StyleAttributes>()
+  for (const element of descendants(root, 'region')) {
+    const id = element.getAttributeNS(XML_NS, 'id') ?? element.getAttribute('xml:id')
+    if (!id) continue
+    const attributes: StyleAttributes = {}
+    for (const ref of splitIdRefs(element.getAttribute('style'))) {
+      Object.assign(attributes, styles.get(ref))
+    }
+    for (const nested of childElements(element)) {
+      if (nested.localName === 'style') Object.assign(attributes, styleAttributesOf(nested))
+    }
+    Object.assign(attributes, styleAttributesOf(element))
+    regions.set(id, attributes)
+  }
+  return regions
+}
+
+interface CollectContext {
+  begin: number
+  end: number
+  style: StyleAttributes
+  regionId: string | undefined
+  styles: Map<string, StyleAttributes>
+  regions: Map<string, StyleAttributes>
+  timing: TimingParameters
+  warnings: SubtitleWarningCollector
+  out: ParagraphEntry[]
+}
+
+/** Walk body/div/p, resolving par-container timing and inherited styling. */
+function collectParagraphs(element: Element, parent: CollectContext): void {
+  const { begin, end } = resolveInterval(element, parent)
+  const regionId = element.getAttribute('region') ?? parent.regionId
+  const style = computeStyle(element, parent.style, parent.styles)
+  const context: CollectContext = { ...parent, begin, end, style, regionId }
+
+  if (element.localName === 'p') {
+    const regionStyle = regionId ? inheritableOnly(parent.regions.get(regionId) ?? {}) : {}
+    const paragraphStyle = { ...regionStyle, ...style }
+    noteUnsupportedStyles(paragraphStyle, context.warnings)
+    const { text, spanBackgrounds } = collectInlineText(element, paragraphStyle, context)
+    // Backgrounds set on the spans (common in IMSC) paint the same box the
+    // timeline draws behind the cue.
+    if (!paragraphStyle.backgroundColor && spanBackgrounds.size === 1) {
+      paragraphStyle.backgroundColor = spanBackgrounds.values().next().value!
+    } else if (spanBackgrounds.size > 1) {
+      context.warnings.add('Per-span background colors are not supported')
+    }
+    context.out.push({
+      startSeconds: begin,
+      endSeconds: end,
+      text,
+      style: paragraphStyle,
+      regionId,
+    })
+    return
+  }
+
+  for (const child of childElements(element)) {
+    if (child.localName === 'div' || child.localName === 'p') collectParagraphs(child, context)
+  }
+}
+
+function resolveInterval(
+  element: Element,
+  parent: Pick<CollectContext, 'begin' | 'end' | 'timing'>,
+): { begin: number; end: number } {
+  const beginOffset = parseTtmlTime(element.getAttribute('begin'), parent.timing)
+  const endOffset = parseTtmlTime(element.getAttribute('end'), parent.timing)
+  const duration = parseTtmlTime(element.getAttribute('dur'), parent.timing)
+  const begin = parent.begin + (beginOffset ?? 0)
+  let end = parent.end
+  if (endOffset !== null) end = Math.min(end, parent.begin + endOffset)
+  if (duration !== null) end = Math.min(end, begin + duration)
+  return { begin, end }
+}
+
+function computeStyle(
+  element: Element,
+  inherited: StyleAttributes,
+  styles: Map<string, StyleAttributes>,
+): StyleAttributes {
+  const result = inheritableOnly(inherited)
+  for (const ref of splitIdRefs(element.getAttribute('style'))) {
+    Object.assign(result, styles.get(ref))
+  }
+  Object.assign(result, styleAttributesOf(element))
+  return result
+}
+
+/** Paragraph content as cue markup; spans that differ open `<i>`/`<b>`/`<u>`/`<font>`. */
+function collectInlineText(
+  paragraph: Element,
+  paragraphStyle: StyleAttributes,
+  context: CollectContext,
+): { text: string; spanBackgrounds: Set<string> } {
+  let text = ''
+  const spanBackgrounds = new Set<string>()
+  const walk = (node: Node, style: StyleAttributes) => {
+    for (const child of Array.from(node.childNodes)) {
+      if (child.nodeType === 3 || child.nodeType === 4) {
+        text += (child.nodeValue ?? '').replace(/[ \t\r\n]+/g, ' ')
+        continue
+      }
+      if (child.nodeType !== 1) continue
+      const element = child as Element
+      if (element.localName === 'br') {
+        text += '\n'
+        continue
+      }
+      if (element.localName !== 'span') continue
+      if (element.hasAttribute('begin') || element.hasAttribute('end')) {
+        context.warnings.add('Timed spans were merged into their paragraph')
+      }
+      const spanStyle = computeStyle(element, style, context.styles)
+      if (spanStyle.backgroundColor) spanBackgrounds.add(spanStyle.backgroundColor)
+      noteUnsupportedStyles(spanStyle, context.warnings)
+      const { open, close } = inlineMarkupFor(spanStyle, style, context.warnings)
+      text += open
+      walk(element, { ...inheritableOnly(spanStyle) })
+      text += close
+    }
+  }
+  walk(paragraph, paragraphStyle)
+  return {
+    text: text
+      .split('\n')
+      .map((line) => line.trim())
+      .join('\n')
+      .trim(),
+    spanBackgrounds,
+  }
+}
+
+/** Markup for the inline properties `style` changes relative to `parent`. */
+function inlineMarkupFor(
+  style: StyleAttributes,
+  parent: StyleAttributes,
+  warnings: SubtitleWarningCollector,
+): { open: string; close: string } {
+  const opens: string[] = []
+  const closes: string[] = []
+  const push = (open: string, close: string) => {
+    opens.push(open)
+    closes.unshift(close)
+  }
+  if (isItalic(style) && !isItalic(parent)) push('<i>', '</i>')
+  if (isBold(style) && !isBold(parent)) push('<b>', '</b>')
+  if (isUnderline(style) && !isUnderline(parent)) push('<u>', '</u>')
+  const color = parseCssColor(style.color, 255)
+  const parentColor = parseCssColor(parent.color, 255)
+  if (color && (!parentColor || formatCssColor(color) !== formatCssColor(parentColor))) {
+    push(`<font color="${formatCssColor({ ...color, a: 1 })}">`, '</font>')
+  }
+  if ((isItalic(parent) && !isItalic(style)) || (isBold(parent) && !isBold(style))) {
+    warnings.add('Spans that turn italic or bold back off are not supported')
+  }
+  if (style.fontFamily !== parent.fontFamily) warnings.add('Per-span fonts are not supported')
+  if (style.fontSize !== parent.fontSize) warnings.add('Per-span font sizes are not supported')
+  return { open: opens.join(''), close: closes.join('') }
+}
+
+/** Carry a non-base paragraph's representable differences as markup. */
+function wrapParagraphDifferences(
+  text: string,
+  paragraph: ParagraphEntry,
+  base: ParagraphEntry,
+  warnings: SubtitleWarningCollector,
+): string {
+  const { open, close } = inlineMarkupFor(paragraph.style, base.style, warnings)
+  if (paragraph.style.backgroundColor !== base.style.backgroundColor) {
+    warnings.add('Per-cue background colors are not supported')
+  }
+  const alignment =
+    paragraph.style.textAlign !== base.style.textAlign ||
+    paragraph.regionId !== base.regionId
+      ? alignmentOverride(paragraph)
+      : ''
+  if (paragraph.regionId !== base.regionId) {
+    warnings.add('Cues in other regions were snapped to the nearest screen position')
+  }
+  return `${alignment}${open}${text}${close}`
+}
+
+function alignmentOverride(paragraph: ParagraphEntry): string {
+  const column = ({ left: 1, center: 2, right: 3 } as const)[
+    mapTextAlign(paragraph.style.textAlign) ?? 'center'
+  ]
+  const displayAlign = paragraph.style.displayAlign
+  const row = displayAlign === 'before' ? 6 : displayAlign === 'center' ? 3 : 0
+  return `{\\an${column + row}}`
+}
+
+function ttmlStyleToTextStyle(
+  style: StyleAttributes,
+  lengths: LengthContext,
+  warnings: SubtitleWarningCollector,
+): TextStyleFields {
+  const fontSize = parseFontSize(style.fontSize, lengths)
+  const result: TextStyleFields = {
+    fontWeight: isBold(style) ? 'bold' : 'normal',
+    fontStyle: isItalic(style) ? 'italic' : 'normal',
+    underline: isUnderline(style),
+    backgroundColor: undefined,
+    stroke: undefined,
+    textShadow: undefined,
+  }
+  const family = parseFontFamily(style.fontFamily)
+  if (family) result.fontFamily = family
+  if (fontSize !== null) result.fontSize = Math.round(fontSize)
+  const color = parseCssColor(style.color, 255)
+  if (color) result.color = formatCssColor(color)
+  const background = parseCssColor(style.backgroundColor, 255)
+  if (background && background.a > 0) result.backgroundColor = formatCssColor(background)
+  const textAlign = mapTextAlign(style.textAlign)
+  if (textAlign) result.textAlign = textAlign
+  const verticalAlign = mapDisplayAlign(style.displayAlign)
+  if (verticalAlign) result.verticalAlign = verticalAlign
+
+  const effectiveFontSize = fontSize ?? lengths.cellHeight
+  if (style.lineHeight && style.lineHeight !== 'normal') {
+    const lineHeight = parseLength(style.lineHeight, lengths, 'y', effectiveFontSize)
+    if (lineHeight !== null) {
+      result.lineHeight = Math.round((lineHeight / effectiveFontSize) * 100) / 100
+    }
+  }
+  if (style.textOutline && style.textOutline !== 'none') {
+    const outline = parseOutline(style.textOutline, lengths, effectiveFontSize)
+    if (outline) {
+      result.stroke = { width: outline.width, color: outline.color ?? result.color ?? '#000000' }
+      if (outline.blur > 0) warnings.add('Blurred text outlines are exported without blur')
+    }
+  }
+  if (style.textShadow && style.textShadow !== 'none') {
+    const shadow = parseShadow(style.textShadow, lengths, effectiveFontSize)
+    if (shadow) result.textShadow = shadow
+  }
+  if (style.textDecoration?.includes('lineThrough')) warnings.add('Strikethrough is not supported')
+  if (style.textDecoration?.includes('overline')) warnings.add('Overlines are not supported')
+  if (style.textAlign === 'justify') warnings.add('Justified text was aligned left')
+  return result
+}
+
+function regionGeometry(region: StyleAttributes, lengths: LengthContext): SubtitleRegion {
+  const [originX, originY] = (region.origin ?? '0% 0%').trim().split(/\s+/)
+  const [extentX, extentY] = (region.extent ?? '100% 100%').trim().split(/\s+/)
+  const fraction = (value: string | undefined, axis: 'x' | 'y', fallback: number) => {
+    if (!value || value === 'auto') return fallback
+    // Region percentages are of the root container, i.e. the frame.
+    const size = axis === 'x' ? lengths.frameWidth : lengths.frameHeight
+    const px = parseLength(value, lengths, axis, size)
+    if (px === null) return fallback
+    return px / size
+  }
+  return {
+    left: fraction(originX, 'x', 0),
+    top: fraction(originY, 'y', 0),
+    width: fraction(extentX, 'x', 1),
+    height: fraction(extentY, 'y', 1),
+  }
+}
+
+function noteUnsupportedStyles(style: StyleAttributes, warnings: SubtitleWarningCollector) {
+  for (const [name, value] of Object.entries(style)) {
+    if (SUPPORTED_STYLE_ATTRIBUTES.has(name)) continue
+    if (name === 'opacity' && Number.parseFloat(value) >= 1) continue
+    if (name === 'visibility' && value === 'visible') continue
+    if (name === 'wrapOption' && value === 'wrap') continue
+    if (name === 'writingMode' && /^(lr|lrtb)$/.test(value)) continue
+    if (name === 'padding' && /^0(px|%|c)?(\s+0(px|%|c)?)*$/.test(value.trim())) continue
+    warnings.add(`TTML style tts:${name} is not supported`)
+  }
+}
+
+function parseFontFamily(value: string | undefined): string | undefined {
+  if (!value) return undefined
+  for (const entry of value.split(',')) {
+    const family = entry.trim().replace(/^["']|["']$/g, '')
+    if (family && !GENERIC_FONT_FAMILIES.has(family.toLowerCase())) return family
+  }
+  return undefined
+}
+
+function parseFontSize(value: string | undefined, lengths: LengthContext): number | null {
+  if (!value) return null
+  const parts = value.trim().split(/\s+/)
+  // Two values are width then height; the height is the font size.
+  return parseLength(parts[parts.length - 1]!, lengths, 'y', lengths.cellHeight)
+}
+
+/** A TTML length in frame pixels; `%` and `em` resolve against `relativeTo`. */
+function parseLength(
+  value: string,
+  lengths: LengthContext,
+  axis: 'x' | 'y',
+  relativeTo: number,
+): number | null {
+  const match = /^([+-]?\d*\.?\d+)(px|em|c|%|rw|rh)$/.exec(value.trim())
+  if (!match) return null
+  const amount = Number.parseFloat(match[1]!)
+  switch (match[2]) {
+    case 'px':
+      return amount * (axis === 'x' ? lengths.pxScaleX : lengths.pxScaleY)
+    case 'em':
+      return amount * relativeTo
+    case 'c':
+      return amount * (axis === 'x' ? lengths.frameWidth / 32 : lengths.cellHeight)
+    case '%':
+      return (amount / 100) * relativeTo
+    case 'rw':
+      return (amount / 100) * lengths.frameWidth
+    case 'rh':
+      return (amount / 100) * lengths.frameHeight
+    default:
+      return null
+  }
+}
+
+function parseOutline(
+  value: string,
+  lengths: LengthContext,
+  fontSize: number,
+): { color?: string; width: number; blur: number } | null {
+  const tokens = value.trim().split(/\s+(?![^(]*\))/)
+  const lengthsOnly: number[] = []
+  let color: string | undefined
+  for (const token of tokens) {
+    const length = parseLength(token, lengths, 'y', fontSize)
+    if (length !== null) {
+      lengthsOnly.push(length)
+      continue
+    }
+    const parsed = parseCssColor(token, 255)
+    if (parsed) color = formatCssColor(parsed)
+  }
+  if (lengthsOnly.length === 0) return null
+  return {
+    color,
+    width: Math.round(lengthsOnly[0]! * 100) / 100,
+    blur: lengthsOnly[1] ?? 0,
+  }
+}
+
+function parseShadow(
+  value: string,
+  lengths: LengthContext,
+  fontSize: number,
+): NonNullable<TextStyleFields['textShadow']> | null {
+  const first = value.split(/,(?![^(]*\))/)[0]!.trim()
+  const tokens = first.split(/\s+(?![^(]*\))/)
+  const numbers: number[] = []
+  let color = '#000000'
+  for (const token of tokens) {
+    const length = parseLength(token, lengths, 'y', fontSize)
+    if (length !== null) {
+      numbers.push(length)
+      continue
+    }
+    const parsed = parseCssColor(token, 255)
+    if (parsed) color = formatCssColor(parsed)
+  }
+  if (numbers.length < 2) return null
+  return { offsetX: numbers[0]!, offsetY: numbers[1]!, blur: numbers[2] ?? 0, color }
+}
+
+/** TTML clock-time or offset-time expression, in seconds. */
+export function parseTtmlTime(
+  value: string | null | undefined,
+  timing: TimingParameters = { frameRate: 30, subFrameRate: 1, tickRate: 1 },
+): number | null {
+  if (!value) return null
+  const trimmed = value.trim()
+  const clock = /^(\d{2,}):(\d{2}):(\d{2})(?:\.(\d+)|:(\d{2,})(?:\.(\d+))?)?$/.exec(trimmed)
+  if (clock) {
+    const base = Number(clock[1]) * 3600 + Number(clock[2]) * 60 + Number(clock[3])
+    if (clock[4]) return base + Number(`0.${clock[4]}`)
+    const frames = clock[5] ? Number(clock[5]) : 0
+    const subFrames = clock[6] ? Number(clock[6]) : 0
+    return base + (frames + subFrames / timing.subFrameRate) / timing.frameRate
+  }
+  const offset = /^(\d+(?:\.\d+)?)(h|m|s|ms|f|t)$/.exec(trimmed)
+  if (!offset) return null
+  const amount = Number(offset[1])
+  switch (offset[2]) {
+    case 'h':
+      return amount * 3600
+    case 'm':
+      return amount * 60
+    case 's':
+      return amount
+    case 'ms':
+      return amount / 1000
+    case 'f':
+      return amount / timing.frameRate
+    default:
+      return amount / timing.tickRate
+  }
+}
+
+function mapTextAlign(value: string | undefined): TextStyleFields['textAlign'] | undefined {
+  switch (value) {
+    case 'left':
+    case 'start':
+    case 'justify':
+      return 'left'
+    case 'right':
+    case 'end':
+      return 'right'
+    case 'center':
+      return 'center'
+    default:
+      return undefined
+  }
+}
+
+function mapDisplayAlign(value: string | undefined): TextStyleFields['verticalAlign'] | undefined {
+  switch (value) {
+    case 'before':
+      return 'top'
+    case 'center':
+      return 'middle'
+    case 'after':
+      return 'bottom'
+    default:
+      return undefined
+  }
+}
+
+function isItalic(style: StyleAttributes): boolean {
+  return style.fontStyle === 'italic' || style.fontStyle === 'oblique'
+}
+
+function isBold(style: StyleAttributes): boolean {
+  return style.fontWeight === 'bold'
+}
+
+function isUnderline(style: StyleAttributes): boolean {
+  return /(^|\s)underline(\s|$)/.test(style.textDecoration ?? '')
+}
+
+function paragraphStyleKey(style: StyleAttributes): string {
+  return JSON.stringify(Object.entries(style).sort(([a], [b]) => a.localeCompare(b)))
+}
+
+function inheritableOnly(style: StyleAttributes): StyleAttributes {
+  const result: StyleAttributes = {}
+  for (const [name, value] of Object.entries(style)) {
+    if (INHERITED_STYLE_ATTRIBUTES.has(name) || name === 'displayAlign') result[name] = value
+  }
+  return result
+}
+
+function styleAttributesOf(element: Element): StyleAttributes {
+  const attributes: StyleAttributes = {}
+  for (const attribute of Array.from(element.attributes)) {
+    if (attribute.namespaceURI === TTS_NS || attribute.prefix === 'tts') {
+      attributes[attribute.localName] = attribute.value.trim()
+    }
+  }
+  return attributes
+}
+
+function getParameter(root: Element, name: string): string | null {
+  return root.getAttributeNS(TTP_NS, name) || root.getAttribute(`ttp:${name}`)
+}
+
+function getStyleAttribute(element: Element, name: string): string | null {
+  return element.getAttributeNS(TTS_NS, name) || element.getAttribute(`tts:${name}`)
+}
+
+function splitIdRefs(value: string | null): string[] {
+  return value ? value.trim().split(/\s+/).filter(Boolean) : []
+}
+
+function childElements(element: Element): Element[] {
+  return Array.from(element.children)
+}
+
+function descendants(root: Element, localName: string): Element[] {
+  return Array.from(root.getElementsByTagNameNS('*', localName))
+}
+
+export function serializeTtml(document: SubtitleExportDocument): SubtitleExportResult {
+  const warnings = new SubtitleWarningCollector()
+  const { style, frameWidth, frameHeight } = document
+  const region = document.region ?? DEFAULT_SUBTITLE_REGION
+
+  // tts:backgroundColor doesn't inherit, so the cue box goes on a span
+  // around each paragraph's content rather than on the body.
+  const { 'tts:backgroundColor': boxColor, ...baseAttributes } = textStyleToTtml(style, warnings)
+  if ((style.backgroundRadius ?? 0) > 0) {
+    warnings.add('Rounded background corners are not supported')
+  }
+  if ((style.textPadding ?? 0) > 0) warnings.add('Background padding is not supported in IMSC1')
+  if ((style.letterSpacing ?? 0) !== 0) warnings.add('Letter spacing is not supported')
+  if (style.textShadow) warnings.add('Text shadows are not part of the IMSC1 text profile')
+  if (document.karaoke && document.cues.some(hasKaraokeWords)) {
+    warnings.add('Karaoke word highlighting was exported as plain lines')
+  }
+
+  const styleLines = [`      <style xml:id="base"${formatAttributes(baseAttributes)}/>`]
+  if (boxColor) {
+    styleLines.push(
+      `      <style xml:id="box"${formatAttributes({ 'tts:backgroundColor': boxColor })}/>`,
+    )
+  }
+  const speakerStyleIds = new Map<string, string>()
+  const speakerEntries = Object.entries(document.speakerStyles ?? {})
+  for (const [index, [speakerId, speaker]] of speakerEntries.entries()) {
+    const id = `speaker${index + 1}`
+    speakerStyleIds.set(speakerId, id)
+    // Speaker styles repeat the segment style; only report what's new in them.
+    const speakerWarnings = new SubtitleWarningCollector()
+    const attributes = textStyleToTtml({ ...style, ...speaker }, speakerWarnings)
+    warnings.addMissing(speakerWarnings)
+    const overrides = Object.fromEntries(
+      Object.entries(attributes).filter(
+        ([name, value]) => name !== 'tts:backgroundColor' && baseAttributes[name] !== value,
+      ),
+    )
+    styleLines.push(`      <style xml:id="${id}"${formatAttributes(overrides)}/>`)
+  }
+
+  const regionLines = [
+    `      <region xml:id="main"${formatAttributes({
+      'tts:origin': `${percent(region.left)} ${percent(region.top)}`,
+      'tts:extent': `${percent(region.width)} ${percent(region.height)}`,
+      'tts:displayAlign': toDisplayAlign(style.verticalAlign),
+    })}/>`,
+  ]
+  const alignmentRegions = new Set<string>()
+
+  const paragraphs: string[] = []
+  const cues = [...document.cues]
+    .filter((cue) => cue.text.trim() && cue.endSeconds > cue.startSeconds)
+    .sort((a, b) => a.startSeconds - b.startSeconds)
+  for (const [index, cue] of cues.entries()) {
+    const parsed = parseSubtitleCueText(cue.text)
+    const attributes: Record<string, string> = {
+      'xml:id': `cue${index + 1}`,
+      begin: formatTtmlTime(cue.startSeconds),
+      end: formatTtmlTime(cue.endSeconds),
+    }
+    const speakerStyle = cue.speakerId ? speakerStyleIds.get(cue.speakerId) : undefined
+    if (speakerStyle) attributes.style = speakerStyle
+    if (parsed.alignment) {
+      // Cue-level `{\anN}` positions against the whole frame, not the box.
+      const regionId = `${parsed.alignment.verticalAlign}Safe`
+      attributes.region = regionId
+      attributes['tts:textAlign'] = parsed.alignment.textAlign
+      if (!alignmentRegions.has(regionId)) {
+        alignmentRegions.add(regionId)
+        regionLines.push(
+          `      <region xml:id="${regionId}"${formatAttributes({
+            'tts:origin': '5% 5%',
+            'tts:extent': '90% 90%',
+            'tts:displayAlign': toDisplayAlign(parsed.alignment.verticalAlign),
+          })}/>`,
+        )
+      }
+    }
+    const content = parsed.spans
+      .map((span) => {
+        const spanAttributes: Record<string, string> = {}
+        if (span.fontStyle === 'italic' && style.fontStyle !== 'italic') {
+          spanAttributes['tts:fontStyle'] = 'italic'
+        }
+        if (span.fontWeight === 'bold' && style.fontWeight !== 'bold') {
+          spanAttributes['tts:fontWeight'] = 'bold'
+        }
+        if (span.underline && !style.underline) spanAttributes['tts:textDecoration'] = 'underline'
+        const spanColor = parseCssColor(span.color)
+        if (spanColor) spanAttributes['tts:color'] = formatTtmlColor(spanColor)
+        const text = span.text.split('\n').map(escapeXml).join('<br/>')
+        return Object.keys(spanAttributes).length > 0
+          ? `<span${formatAttributes(spanAttributes)}>${text}</span>`
+          : text
+      })
+      .join('')
+    const body = boxColor ? `<span style="box">${content}</span>` : content
+    paragraphs.push(`      <p${formatAttributes(attributes)}>${body}</p>`)
+  }
+
+  const rootAttributes: Record<string, string> = {
+    xmlns: TT_NS,
+    'xmlns:ttp': TTP_NS,
+    'xmlns:tts': TTS_NS,
+    'xmlns:ttm': TTM_NS,
+    'ttp:profile': IMSC1_TEXT_PROFILE,
+    'ttp:timeBase': 'media',
+    'ttp:cellResolution': '32 15',
+    'tts:extent': `${frameWidth}px ${frameHeight}px`,
+    'xml:lang': document.language ?? '',
+  }
+  const lines = [
+    '<?xml version="1.0" encoding="UTF-8"?>',
+    `<tt${formatAttributes(rootAttributes)}>`,
+    '  <head>',
+  ]
+  if (document.title) {
+    lines.push(
+      '    <metadata>',
+      `      <ttm:title>${escapeXml(document.title)}</ttm:title>`,
+      '    </metadata>',
+    )
+  }
+  lines.push(
+    '    <styling>',
+    ...styleLines,
+    '    </styling>',
+    '    <layout>',
+    ...regionLines,
+    '    </layout>',
+    '  </head>',
+    '  <body region="main" style="base">',
+    '    <div>',
+    ...paragraphs,
+    '    </div>',
+    '  </body>',
+    '</tt>',
+  )
+  return { text: `${lines.join('\n')}\n`, warnings: warnings.toArray() }
+}
+
+function textStyleToTtml(
+  style: TextStyleFields,
+  warnings: SubtitleWarningCollector,
+): Record<string, string> {
+  const attributes: Record<string, string> = {}
+  if (style.fontFamily) attributes['tts:fontFamily'] = style.fontFamily
+  if (style.fontSize) attributes['tts:fontSize'] = `${Math.round(style.fontSize)}px`
+  const color = parseCssColor(style.color)
+  if (color) attributes['tts:color'] = formatTtmlColor(color)
+  const background = parseCssColor(style.backgroundColor)
+  if (background && background.a > 0) {
+    attributes['tts:backgroundColor'] = formatTtmlColor(background)
+  }
+  const weight = style.fontWeight ?? 'normal'
+  if (weight === 'semibold' || weight === 'medium') {
+    warnings.add(
+      `Font weight "${weight}" was exported as ${weight === 'semibold' ? 'bold' : 'normal'}`,
+    )
+  }
+  attributes['tts:fontWeight'] = weight === 'bold' || weight === 'semibold' ? 'bold' : 'normal'
+  attributes['tts:fontStyle'] = style.fontStyle === 'italic' ? 'italic' : 'normal'
+  if (style.underline) attributes['tts:textDecoration'] = 'underline'
+  attributes['tts:textAlign'] = style.textAlign ?? 'center'
+  if (style.lineHeight && style.fontSize) {
+    attributes['tts:lineHeight'] = `${Math.round(style.lineHeight * 100)}%`
+  }
+  if (style.stroke && style.stroke.width > 0) {
+    const strokeColor = parseCssColor(style.stroke.color)
+    attributes['tts:textOutline'] =
+      `${strokeColor ? formatTtmlColor(strokeColor) : 'black'} ${style.stroke.width}px`
+  }
+  return attributes
+}
+
+function toDisplayAlign(verticalAlign: TextStyleFields['verticalAlign']): string {
+  return verticalAlign === 'top' ? 'before' : verticalAlign === 'middle' ? 'center' : 'after'
+}
+
+function formatTtmlColor({ r, g, b, a }: Rgba): string {
+  return `#${toHexByte(r)}${toHexByte(g)}${toHexByte(b)}${toHexByte(a * 255)}`
+}
+
+function formatTtmlTime(seconds: number): string {
+  const totalMs = Math.max(0, Math.round(seconds * 1000))
+  const ms = totalMs % 1000
+  const totalSeconds = Math.floor(totalMs / 1000)
+  const s = totalSeconds % 60
+  const m = Math.floor(totalSeconds / 60) % 60
+  const h = Math.floor(totalSeconds / 3600)
+  return `${pad2(h)}:${pad2(m)}:${pad2(s)}.${String(ms).padStart(3, '0')}`
+}
+
+function percent(fraction: number): string {
+  return `${Math.round(fraction * 10000) / 100}%`
+}
+
+function formatAttributes(attributes: Record<string, string>): string {
+  return Object.entries(attributes)
+    .map(([name, value]) => ` ${name}="${escapeXml(value)}"`)
+    .join('')
+}
+
+function escapeXml(value: string): string {
+  return value
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;')
+}
+
+function pad2(value: number): string {
+  return String(value).padStart(2, '0')
+}
@@ -5,6 +5,9 @@ describe('subtitles', () => {
   it('infers subtitle formats from filenames', () => {
     expect(inferSubtitleFormat('captions.srt')).toBe('srt')
     expect(inferSubtitleFormat('captions.VTT')).toBe('vtt')
+    expect(inferSubtitleFormat('captions.ass')).toBe('ass')
+    expect(inferSubtitleFormat('captions.ssa')).toBe('ssa')
+    expect(inferSubtitleFormat('captions.dfxp')).toBe('ttml')
     expect(inferSubtitleFormat('captions.txt')).toBeNull()
   })
 
@@ -1,4 +1,4 @@
-export type SubtitleFormat = 'srt' | 'vtt'
+export type SubtitleFormat = 'srt' | 'vtt' | 'ass' | 'ssa' | 'ttml'
 
 export interface SubtitleCue {
   id: string
@@ -19,6 +19,9 @@ export function inferSubtitleFormat(fileName: string): SubtitleFormat | null {
   const lower = fileName.toLowerCase()
   if (lower.endsWith('.srt')) return 'srt'
   if (lower.endsWith('.vtt')) return 'vtt'
+  if (lower.endsWith('.ass')) return 'ass'
+  if (lower.endsWith('.ssa')) return 'ssa'
+  if (lower.endsWith('.ttml') || lower.endsWith('.dfxp')) return 'ttml'
   return null
 }
 
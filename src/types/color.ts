@@ -0,0 +1,36 @@
+/**
+ * Project working colour spaces.
+ *
+ * - `rec709`: SDR Rec.709 primaries with sRGB-style display encoding (default)
+ * - `rec2100-pq`: HDR Rec.2020 primaries, SMPTE ST 2084 (PQ) encoding
+ * - `rec2100-hlg`: HDR Rec.2020 primaries, ARIB STD-B67 (HLG) encoding
+ */
+export type ProjectColorSpace = 'rec709' | 'rec2100-pq' | 'rec2100-hlg'
+
+export const DEFAULT_PROJECT_COLOR_SPACE: ProjectColorSpace = 'rec709'
+
+export const PROJECT_COLOR_SPACES: readonly ProjectColorSpace[] = [
+  'rec709',
+  'rec2100-pq',
+  'rec2100-hlg',
+]
+
+export function isHdrColorSpace(space: ProjectColorSpace | undefined): boolean {
+  return space === 'rec2100-pq' || space === 'rec2100-hlg'
+}
+
+/** Transfer characteristics of a decoded source or an output signal */
+export type ColorTransfer = 'srgb' | 'bt709' | 'pq' | 'hlg' | 'linear'
+
+export type ColorPrimaries = 'bt709' | 'bt2020'
+
+/** Colour description of a decoded source, resolved from its VideoFrame */
+export interface SourceColorInfo {
+  primaries: ColorPrimaries
+  transfer: ColorTransfer
+  /** YUV→RGB matrix; only meaningful for YUV sources */
+  matrix: 'bt709' | 'bt601' | 'bt2020-ncl'
+  fullRange: boolean
+  /** Bits per sample of the source planes */
+  bitDepth: number
+}
@@ -1,14 +1,55 @@
 import type { AudioEqSettings } from './audio'
+import type { ProjectColorSpace } from './color'
 import type { TimelineTrack } from './timeline'
 import type { Transition } from './transition'
 import type { ItemKeyframes } from './keyframe'
 
 // Export modes
-export type ExportMode = 'video' | 'audio'
+export type ExportMode = 'video' | 'audio' | 'image-sequence' | 'animated-image'
 
 // Container formats
 export type VideoContainer = 'mp4' | 'mov' | 'webm' | 'mkv'
 export type AudioContainer = 'mp3' | 'aac' | 'wav'
+export type AnimatedImageContainer = 'gif' | 'webp'
+
+/** GIF dithering: error diffusion, 4×4 ordered (Bayer), or nearest colour only. */
+export type AnimatedImageDither = 'floyd-steinberg' | 'ordered' | 'none'
+
+export interface AnimatedImageOptions {
+  /** Output frame rate; project frames are decimated down to it. */
+  fps: number
+  /** How many times the animation plays; 0 = forever. */
+  loopCount: number
+  /** GIF only: one palette for the whole animation, or one per frame. */
+  palette: 'global' | 'per-frame'
+  /** GIF only. */
+  dither: AnimatedImageDither
+}
+
+/** Loudness normalization presets (integrated LUFS / true-peak ceiling). */
+export type LoudnessTargetPreset = 'streaming' | 'podcast' | 'ebu-r128' | 'atsc-a85'
+
+export interface LoudnessTarget {
+  integratedLufs: number
+  truePeakDbtp: number
+}
+
+/** BS.1770 measurement of the exported mix, before and after normalization. */
+export interface ExportLoudnessReport {
+  integratedLufs: number
+  shortTermMaxLufs: number
+  momentaryMaxLufs: number
+  truePeakDbtp: number
+  /** Set when normalization was requested. */
+  target?: LoudnessTarget
+  /** Gain applied to the mix (0 when no target). */
+  gainDb: number
+  /** Normalization stopped short of the target to respect the true-peak ceiling. */
+  limitedByTruePeak: boolean
+  /** Loudness of the file as written (measured values plus `gainDb`). */
+  outputIntegratedLufs: number
+  outputTruePeakDbtp: number
+}
 
 export interface ExportSettings {
   codec: 'h264' | 'h265' | 'vp8' | 'vp9' | 'av1' | 'prores'
@@ -27,10 +68,29 @@ export interface ExtendedExportSettings extends ExportSettings {
   mode: ExportMode
   videoContainer?: VideoContainer
   audioContainer?: AudioContainer
+  animatedImageContainer?: AnimatedImageContainer
+  /** GIF/WebP options; unset fields use the defaults. */
+  animatedImage?: Partial<AnimatedImageOptions>
   /** Embed timeline subtitle segments as a soft subtitle track when the container supports it. */
   embedSubtitles?: boolean
+  /** Write each subtitle track as a standalone file next to the video export. */
+  sidecarSubtitles?: 'srt' | 'vtt'
   /** When true, ignores in/out points and exports the full timeline */
   renderWholeProject?: boolean
+  /**
+   * Keep the alpha channel: transparent PNG frames, or VP8/VP9 alpha in WebM/MKV.
+   * The canvas stays transparent wherever nothing is drawn unless `backgroundColor` is set.
+   */
+  alpha?: boolean
+  /**
+   * Encode 10-bit BT.2100 (PQ or HLG, following the project's working space) instead of
+   * tone-mapped SDR. Video mode only; requires H.265 or AV1.
+   */
+  hdr?: boolean
+  /** Normalize the mix to a loudness preset; unset leaves levels untouched. */
+  loudnessTarget?: LoudnessTargetPreset
+  /** Render one of the project's canvas variants instead of the base canvas. */
+  canvasVariantId?: string
 }
 
 export interface CompositionInputProps {
@@ -41,8 +101,12 @@ export interface CompositionInputProps {
   tracks: TimelineTrack[]
   transitions?: Transition[] // Transitions between clips
   backgroundColor?: string // Hex color for canvas background
+  /** Leave the canvas transparent (instead of black) when `backgroundColor` is unset. */
+  transparentBackground?: boolean
   keyframes?: ItemKeyframes[] // Keyframe animations for items
   busAudioEq?: AudioEqSettings
   /** Project-scoped master bus gain in dB (0 = unity). Applied to final mix. */
   masterBusDb?: number
+  /** Working colour space layers are composited in (default `rec709`). */
+  colorSpace?: ProjectColorSpace
 }
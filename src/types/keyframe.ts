@@ -19,7 +19,16 @@ export type BuiltInAnimatableProperty =
   | 'cropTop'
   | 'cropBottom'
   | 'cropSoftness'
+  | 'cornerPinTopLeftX'
+  | 'cornerPinTopLeftY'
+  | 'cornerPinTopRightX'
+  | 'cornerPinTopRightY'
+  | 'cornerPinBottomRightX'
+  | 'cornerPinBottomRightY'
+  | 'cornerPinBottomLeftX'
+  | 'cornerPinBottomLeftY'
   | 'volume'
+  | 'timeRemap'
   | 'textStyleScale'
   | 'fontSize'
   | 'lineHeight'
@@ -53,6 +62,17 @@ export type CropAnimatableProperty =
   | 'cropBottom'
   | 'cropSoftness'
 
+/** Corner pin offsets, in the pin's reference pixel space */
+export type CornerPinAnimatableProperty =
+  | 'cornerPinTopLeftX'
+  | 'cornerPinTopLeftY'
+  | 'cornerPinTopRightX'
+  | 'cornerPinTopRightY'
+  | 'cornerPinBottomRightX'
+  | 'cornerPinBottomRightY'
+  | 'cornerPinBottomLeftX'
+  | 'cornerPinBottomLeftY'
+
 /**
  * Basic easing functions for interpolation between keyframes.
  * `hold` is a step interpolation — the value stays at the keyframe's
@@ -121,6 +141,11 @@ export interface Keyframe {
   easing: EasingType
   /** Advanced easing configuration (required for cubic-bezier and spring types) */
   easingConfig?: EasingConfig
+  /**
+   * Set on volume keyframes written by auto-ducking: the un-ducked value, or
+   * null for a point the duck added. Lets a re-run undo the previous duck.
+   */
+  duckBase?: number | null
 }
 
 /**
@@ -200,7 +225,16 @@ const BUILT_IN_PROPERTY_LABELS: Record<BuiltInAnimatableProperty, string> = {
   cropTop: 'Crop Top',
   cropBottom: 'Crop Bottom',
   cropSoftness: 'Crop Softness',
+  cornerPinTopLeftX: 'Pin Top Left X',
+  cornerPinTopLeftY: 'Pin Top Left Y',
+  cornerPinTopRightX: 'Pin Top Right X',
+  cornerPinTopRightY: 'Pin Top Right Y',
+  cornerPinBottomRightX: 'Pin Bottom Right X',
+  cornerPinBottomRightY: 'Pin Bottom Right Y',
+  cornerPinBottomLeftX: 'Pin Bottom Left X',
+  cornerPinBottomLeftY: 'Pin Bottom Left Y',
   volume: 'Volume (dB)',
+  timeRemap: 'Time Remap (s)',
   textStyleScale: 'Preset Scale',
   fontSize: 'Font Size',
   lineHeight: 'Line Height',
@@ -1,6 +1,24 @@
+import type { EasingConfig, EasingType } from './keyframe'
+
 /** Bezier mask vertex (normalized 0-1 relative to item bounds) */
 export interface MaskVertex {
   position: [number, number]
   inHandle: [number, number]
   outHandle: [number, number]
+  /** Edge feather near this vertex in px (alpha masks); falls back to the shape's feather */
+  feather?: number
+}
+
+/**
+ * Path of an animated path shape at one frame. Paths between keyframes are
+ * interpolated vertex by vertex; keyframes may differ in vertex count.
+ */
+export interface MaskPathKeyframe {
+  id: string
+  /** Frame relative to item start */
+  frame: number
+  vertices: MaskVertex[]
+  /** Easing used when interpolating TO the next path keyframe */
+  easing: EasingType
+  easingConfig?: EasingConfig
 }
@@ -1,3 +1,4 @@
+import type { ProjectColorSpace } from './color'
 import type { AnimatableProperty, EasingType, EasingConfig } from './keyframe'
 import type { AudioEqSettings } from './audio'
 import type { Transition } from './transition'
@@ -32,6 +33,55 @@ export interface Project {
    * Updated when rootFolderHandle is set.
    */
   rootFolderName?: string
+  /**
+   * Set on projects generated by smart reframe. Links the variant back to the
+   * project it was reframed from so re-running the reframe updates it.
+   */
+  reframe?: ProjectReframeLink
+  /**
+   * Extra deliverable canvases (e.g. 9:16, 4:5) that share this project's
+   * timeline. Each variant only stores layout overrides; everything else is
+   * resolved from the base timeline at render time.
+   */
+  canvasVariants?: ProjectCanvasVariant[]
+}
+
+/** Output aspect ratios smart reframe can generate */
+export type ReframeAspect = '16:9' | '9:16' | '1:1'
+
+export interface ProjectReframeLink {
+  sourceProjectId: string
+  aspect: ReframeAspect
+  /** When the variant was last regenerated from the source */
+  reframedAt: number
+}
+
+/** Aspect ratios a canvas variant can target */
+export type CanvasVariantAspect = '16:9' | '9:16' | '4:5' | '1:1'
+
+export interface ProjectCanvasVariant {
+  id: string
+  name: string
+  /** Canvas size follows the base canvas's short side at this aspect */
+  aspect: CanvasVariantAspect
+  overrides: ProjectCanvasVariantOverride[]
+}
+
+/**
+ * Per-item layout in one canvas variant. Overridden properties replace the
+ * auto-laid-out value (and any keyframes) of that property in the variant.
+ */
+export interface ProjectCanvasVariantOverride {
+  itemId: string
+  transform?: {
+    x?: number
+    y?: number
+    width?: number
+    height?: number
+    rotation?: number
+  }
+  crop?: CropSettings
+  text?: Pick<TextStyleFields, 'fontSize' | 'textAlign' | 'verticalAlign' | 'lineHeight'>
 }
 
 export interface ProjectTimeline {
@@ -71,7 +121,15 @@ export interface ProjectTimeline {
       mediaId?: string
       originId?: string // Tracks lineage for stable React keys
       linkedGroupId?: string
-      type: 'video' | 'audio' | 'text' | 'image' | 'shape' | 'composition' | 'adjustment'
+      type:
+        | 'video'
+        | 'audio'
+        | 'text'
+        | 'image'
+        | 'shape'
+        | 'composition'
+        | 'adjustment'
+        | 'multicam'
       // Type-specific fields stored as optional for flexibility
       src?: string
       thumbnailUrl?: string
@@ -99,7 +157,7 @@ export interface ProjectTimeline {
         clipId: string
         mediaId: string
         fileName?: string
-        format?: 'srt' | 'vtt'
+        format?: 'srt' | 'vtt' | 'ass' | 'ssa' | 'ttml'
         importedAt?: number
       }
       textStylePresetId?: TextStylePresetId
@@ -114,10 +172,16 @@ export interface ProjectTimeline {
       points?: number
       innerRadius?: number
       speed?: number // Playback speed multiplier (default 1.0)
+      frameBlending?: 'nearest' | 'blend' | 'optical-flow'
       // Composition item fields
       compositionId?: string // Reference to a sub-composition
       compositionWidth?: number
       compositionHeight?: number
+      // Multicam item fields
+      angles?: import('./timeline').MulticamAngle[]
+      angleSegments?: import('./timeline').MulticamAngleSegment[]
+      audioAngleId?: string
+      syncMethod?: 'audio' | 'timecode'
       // Source dimensions (for video/image items)
       sourceWidth?: number
       sourceHeight?: number
@@ -234,6 +298,7 @@ export interface ProjectTimeline {
         value: number
         easing: EasingType
         easingConfig?: EasingConfig
+        duckBase?: number | null
       }>
     }>
   }>
@@ -244,4 +309,6 @@ export interface ProjectResolution {
   height: number
   fps: number
   backgroundColor?: string // Hex color, defaults to #000000
+  /** Working colour space; unset is SDR Rec.709 */
+  colorSpace?: ProjectColorSpace
 }
@@ -142,6 +142,8 @@ export interface MediaTranscriptSegment {
   start: number
   end: number
   words?: MediaTranscriptWord[]
+  /** Id of a {@link MediaTranscriptSpeaker} once the transcript is diarized */
+  speakerId?: string
 }
 
 export interface MediaTranscriptWord {
@@ -151,6 +153,13 @@ export interface MediaTranscriptWord {
   confidence?: number
 }
 
+/** A voice found by speaker diarization; name and color are user-editable. */
+export interface MediaTranscriptSpeaker {
+  id: string
+  name: string
+  color: string
+}
+
 export interface MediaTranscript {
   id: string // Same as mediaId
   mediaId: string
@@ -159,6 +168,8 @@ export interface MediaTranscript {
   quantization: MediaTranscriptQuantization
   text: string
   segments: MediaTranscriptSegment[]
+  /** Present once diarization has labeled `segments` with speaker ids */
+  speakers?: MediaTranscriptSpeaker[]
   createdAt: number
   updatedAt: number
 }
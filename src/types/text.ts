@@ -49,3 +49,32 @@ export type TextVisualStyleFields = {
 }
 
 export type TextStyleFields = TextInlineStyleFields & TextVisualStyleFields
+
+/**
+ * Word-by-word highlighting for captions with word timestamps. The word
+ * being spoken takes the `active*` look; everything else keeps the base
+ * caption style.
+ */
+export type CaptionKaraokeStyle = {
+  activeColor?: string
+  /** Rounded box painted behind the spoken word. */
+  activeBackgroundColor?: string
+  /** Scale of the spoken word about its center; 1 = unscaled. */
+  activeScale?: number
+  /** Hide upcoming words and pop each one in as it is spoken. */
+  popIn?: boolean
+  /** Words shown at once; 0 or unset shows the whole cue. */
+  wordsPerPage?: number
+}
+
+/**
+ * Resolved look of one caption word at one frame. Render-time only: every
+ * renderer paints these the same way on top of the shared text layout.
+ */
+export type KaraokeWordPaint = {
+  hidden?: boolean
+  color?: string
+  backgroundColor?: string
+  /** Scale about the word's center; 1 = unscaled. */
+  scale?: number
+}
@@ -3,7 +3,14 @@ import type { ItemEffect } from './effects'
 import type { BlendMode } from './blend-modes'
 import type { AudioEqSettings } from './audio'
 import type { TextStylePresetId } from '@/shared/typography/text-style-preset-ids'
-import type { TextLayoutDrafts, TextSpan, TextStyleFields } from './text'
+import type {
+  CaptionKaraokeStyle,
+  KaraokeWordPaint,
+  TextInlineStyleFields,
+  TextLayoutDrafts,
+  TextSpan,
+  TextStyleFields,
+} from './text'
 
 export interface TimelineItemCornerPin {
   topLeft: [number, number]
@@ -14,15 +21,25 @@ export interface TimelineItemCornerPin {
   referenceHeight?: number
 }
 
+/** One spoken word inside a caption cue, timed on the same clock as its cue. */
+export interface CaptionWordTiming {
+  text: string
+  startSeconds: number
+  endSeconds: number
+}
+
 export interface TimelineTranscriptCaptionCue {
   id: string
   startSeconds: number
   endSeconds: number
   text: string
+  speakerId?: string
+  words?: CaptionWordTiming[]
 }
 
 export type TimelineTranscriptCaptionStyle = TextStyleFields & {
   transform?: TransformProperties
+  karaoke?: CaptionKaraokeStyle
 }
 
 export interface TimelineTranscriptCaptions {
@@ -33,6 +50,8 @@ export interface TimelineTranscriptCaptions {
   /** Source-relative transcript cues. Render/export trims them to the clip. */
   cues: TimelineTranscriptCaptionCue[]
   style?: TimelineTranscriptCaptionStyle
+  /** Per-speaker overrides layered on `style`, keyed by cue `speakerId`. */
+  speakerStyles?: Record<string, SubtitleSpeakerStyle>
 }
 
 // Base type for all timeline items (following Composition pattern)
@@ -147,10 +166,19 @@ export interface GeneratedCaptionSource {
   clipId: string
   mediaId: string
   fileName?: string
-  format?: 'srt' | 'vtt'
+  format?: 'srt' | 'vtt' | 'ass' | 'ssa' | 'ttml'
   importedAt?: number
 }
 
+/**
+ * How a video clip fills timeline frames that fall between source frames
+ * (slow motion, frame-rate conform):
+ * - `nearest`: hold the closest earlier source frame (default)
+ * - `blend`: cross-fade the two neighbouring source frames
+ * - `optical-flow`: warp both neighbours along their motion and blend
+ */
+export type FrameBlendingMode = 'nearest' | 'blend' | 'optical-flow'
+
 // Discriminated union types for different item types
 export type VideoItem = BaseTimelineItem & {
   type: 'video'
@@ -159,6 +187,7 @@ export type VideoItem = BaseTimelineItem & {
   thumbnailUrl?: string
   offset?: number // Trim offset in source video
   embeddedAudioMuted?: boolean // Suppress embedded audio after unlinking from audio companion
+  frameBlending?: FrameBlendingMode // In-between frame synthesis (default: 'nearest')
   // Source dimensions (intrinsic size from media metadata)
   sourceWidth?: number
   sourceHeight?: number
@@ -183,6 +212,11 @@ export type TextItem = BaseTimelineItem &
     textStyleScale?: number
     textRole?: 'caption'
     captionSource?: GeneratedCaptionSource
+    /**
+     * Per-word paint for karaoke captions, indexed by space-separated word of
+     * `text`. Only set on the text synthesized from a subtitle cue per frame.
+     */
+    karaokeWords?: KaraokeWordPaint[]
     color: string // Text color (hex or oklch)
   }
 
@@ -220,11 +254,15 @@ export type ShapeItem = BaseTimelineItem & {
   innerRadius?: number // Star only (ratio 0-1 of outer)
   // Path shape (custom bezier path drawn with pen tool)
   pathVertices?: import('@/types/masks').MaskVertex[] // Normalized 0-1 vertices for 'path' shapeType
+  pathKeyframes?: import('@/types/masks').MaskPathKeyframe[] // Animated path; wins over pathVertices
   // Mask properties
   isMask?: boolean // When true, shape acts as mask for lower tracks
   maskType?: 'clip' | 'alpha' // clip = hard edges, alpha = soft edges
   maskFeather?: number // Feather amount for alpha masks (0-100px, default: 10)
   maskInvert?: boolean // Invert mask (show outside, hide inside)
+  maskMatteItemId?: string // Video clip whose subject matte refines the mask
+  maskExpansion?: number // Grow (positive) or shrink (negative) the mask edge in px (default: 0)
+  maskMotionBlur?: number // Shutter angle in degrees for moving masks (0-360, default: 0 = off)
 }
 
 // Adjustment layer - applies effects to all items on tracks ABOVE this track
@@ -246,6 +284,43 @@ export type CompositionItem = BaseTimelineItem & {
   compositionHeight: number
 }
 
+/** One synced camera angle of a {@link MulticamItem}. */
+export interface MulticamAngle {
+  id: string
+  label: string
+  mediaId: string
+  src: string
+  /** Source media frame rate */
+  sourceFps: number
+  /** Source media length in native source frames */
+  sourceDuration: number
+  sourceWidth?: number
+  sourceHeight?: number
+  /** Seconds from the multicam clock origin to this angle's first frame (sync result) */
+  syncOffset: number
+}
+
+/**
+ * A cut to `angleId` that lasts until the next segment. Start frames are on the
+ * multicam clock (project fps), so trims and splits never have to rewrite them.
+ */
+export interface MulticamAngleSegment {
+  id: string
+  startFrame: number
+  angleId: string
+}
+
+// Multicam item - several synced angles with a cut list choosing the visible one
+export type MulticamItem = BaseTimelineItem & {
+  type: 'multicam'
+  angles: MulticamAngle[]
+  /** Sorted by `startFrame`; the first segment also covers anything before it */
+  angleSegments: MulticamAngleSegment[]
+  /** Angle whose audio plays under every cut */
+  audioAngleId: string
+  syncMethod: 'audio' | 'timecode'
+}
+
 /**
  * A single cue inside a {@link SubtitleSegmentItem}. Times are seconds
  * relative to the segment's `from` (after speed scaling) — i.e. the same
@@ -257,6 +332,18 @@ export interface SubtitleSegmentCue {
   startSeconds: number
   endSeconds: number
   text: string
+  /** Transcript speaker that says this cue; keys into `speakerStyles`. */
+  speakerId?: string
+  /** Word timestamps (segment-relative) that drive karaoke highlighting. */
+  words?: CaptionWordTiming[]
+}
+
+/**
+ * Typography a speaker's cues use on top of the segment style. `name` is a
+ * display copy of the transcript speaker name for the properties panel.
+ */
+export type SubtitleSpeakerStyle = TextInlineStyleFields & {
+  name?: string
 }
 
 /**
@@ -268,8 +355,8 @@ export interface SubtitleSegmentCue {
  * frame from `cues`, applying the segment's style block uniformly.
  *
  * Style fields mirror the subset of {@link TextItem}'s typography that
- * makes sense applied to all cues at once. Per-cue styling can be
- * layered on later via `cue.style?` if needed.
+ * makes sense applied to all cues at once. Diarized transcripts layer
+ * per-speaker overrides from `speakerStyles` on top.
  */
 export type SubtitleSegmentItem = BaseTimelineItem &
   TextStyleFields & {
@@ -280,6 +367,10 @@ export type SubtitleSegmentItem = BaseTimelineItem &
     source: SubtitleSegmentSource
     /** Cue list, sorted by `startSeconds`. Times are segment-relative. */
     cues: SubtitleSegmentCue[]
+    /** Per-speaker style overrides, keyed by cue `speakerId`. */
+    speakerStyles?: Record<string, SubtitleSpeakerStyle>
+    /** Word-level highlighting; static cues when unset. */
+    karaoke?: CaptionKaraokeStyle
     color: string
   }
 
@@ -288,6 +379,8 @@ export type SubtitleSegmentSource =
       type: 'transcript'
       mediaId: string
       clipId: string
+      /** Whisper language code (e.g. `en`) when the transcript reported one. */
+      language?: string
     }
   | {
       type: 'embedded-subtitles'
@@ -302,9 +395,20 @@ export type SubtitleSegmentSource =
   | {
       type: 'subtitle-import'
       fileName: string
-      format: 'srt' | 'vtt'
+      format: 'srt' | 'vtt' | 'ass' | 'ssa' | 'ttml'
       importedAt: number
     }
+  | {
+      /** Machine translation of another subtitle segment, on its own track. */
+      type: 'translation'
+      /** BCP 47 target language, e.g. `es` or `pt-BR`. */
+      language: string
+      /** Segment the cues were translated from. */
+      sourceItemId: string
+      /** Language of the source cues, when known. */
+      sourceLanguage?: string
+      translatedAt: number
+    }
 
 // Union type for all timeline items
 export type TimelineItem =
@@ -315,6 +419,7 @@ export type TimelineItem =
   | ShapeItem
   | AdjustmentItem
   | CompositionItem
+  | MulticamItem
   | SubtitleSegmentItem
 
 export interface TimelineTrack {